@@ -98,6 +98,10 @@ export default defineConfig({
             { text: 'Linear Algebra', link: '/api/linear-algebra' },
             { text: 'Signal Processing', link: '/api/signal-processing' },
             { text: 'Window Functions', link: '/api/window-functions' },
+            { text: 'Preprocessing', link: '/api/preprocessing' },
+            { text: 'Activation Functions', link: '/api/activations' },
+            { text: 'Similarity Search', link: '/api/similarity-search' },
+            { text: 'Image Processing', link: '/api/image-processing' },
           ]
         },
         {
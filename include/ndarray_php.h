@@ -28,6 +28,14 @@
 
 #define ERR_MATH 7
 
+#define ERR_OVERFLOW 8
+
+#define ERR_NOT_IMPLEMENTED 9
+
+#define ERR_SINGULAR_MATRIX 10
+
+#define ERR_VALUE 11
+
 /**
  * Opaque pointer type for FFI.
  *
@@ -62,13 +70,78 @@ typedef struct ArrayMetadata {
   uintptr_t ndim;
 } ArrayMetadata;
 
+/**
+ * Opaque pointer type for iterator handles.
+ *
+ * PHP holds this pointer and passes it back to Rust for `ndarray_iter_*` calls.
+ */
+typedef struct NdIterHandle {
+  uint8_t _private[0];
+} NdIterHandle;
+
+/**
+ * Opaque pointer type for random generator handles.
+ *
+ * PHP holds this pointer and passes it to random generators; it is released
+ * with `ndarray_rng_free`.
+ */
+typedef struct RngHandle {
+  uint8_t _private[0];
+} RngHandle;
+
+/**
+ * The last error with its code and the operation that raised it.
+ *
+ * Strings are NUL-terminated and truncated to fit.
+ */
+typedef struct NdArrayError {
+  /**
+   * Error code returned by the failing call, or `SUCCESS` if none.
+   */
+  int32_t code;
+  /**
+   * Failing FFI function without its `ndarray_` prefix, e.g. `"matmul"`.
+   * Empty when the call failed before its arguments were validated.
+   */
+  char operation[64];
+  char message[1024];
+} NdArrayError;
+
+/**
+ * Gufunc kernel, called once per loop position.
+ *
+ * `inputs` holds one pointer per operand to its core block, `output` points
+ * to the core block to fill, and `dims` lists the size bound to each core
+ * dimension name in order of first appearance in the signature. Returns 0
+ * on success; any other value aborts the call.
+ */
+typedef int32_t (*NdGufuncKernel)(const double *const *inputs, double *output, const uintptr_t *dims);
+
+/**
+ * Map callback: read `count` values from `input`, write `count` results to `output`.
+ *
+ * Returns 0 on success; any other value aborts the map.
+ */
+typedef int32_t (*NdMapCallback)(const double *input, double *output, uintptr_t count);
+
+/**
+ * Reduce callback: fold `count` values from `values` into `*acc`, in order.
+ *
+ * Returns 0 on success; any other value aborts the reduction.
+ */
+typedef int32_t (*NdReduceCallback)(double *acc, const double *values, uintptr_t count);
+
 /**
  * Add two arrays.
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
  */
 int32_t ndarray_add(const struct NdArrayHandle *a,
                     const struct ArrayMetadata *a_meta,
                     const struct NdArrayHandle *b,
                     const struct ArrayMetadata *b_meta,
+                    int32_t overflow,
                     struct NdArrayHandle **out,
                     uint8_t *out_dtype_ptr,
                     uintptr_t *out_ndim,
@@ -77,17 +150,42 @@ int32_t ndarray_add(const struct NdArrayHandle *a,
 
 /**
  * Add a scalar to an array.
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
  */
 int32_t ndarray_add_scalar(const struct NdArrayHandle *a,
                            const struct ArrayMetadata *a_meta,
                            const void *scalar,
                            uint8_t scalar_dtype,
+                           int32_t overflow,
                            struct NdArrayHandle **out,
                            uint8_t *out_dtype,
                            uintptr_t *out_ndim,
                            uintptr_t *out_shape,
                            uintptr_t max_ndim);
 
+/**
+ * Apply a binary arithmetic operation, writing into an existing array.
+ *
+ * `out` must already have the broadcast shape of `a` and `b` and their
+ * promoted dtype; it may be a strided view and may alias either operand.
+ * Float16, BFloat16 and Bool destinations are not supported.
+ *
+ * # Arguments
+ * * `op` - Operation (0 = add, 1 = sub, 2 = mul, 3 = div, 4 = rem, 5 = maximum, 6 = minimum)
+ * * `a` / `a_meta` - Left operand and view
+ * * `b` / `b_meta` - Right operand and view
+ * * `out` / `out_meta` - Destination array and view
+ */
+int32_t ndarray_binary_op_out(int32_t op,
+                              const struct NdArrayHandle *a,
+                              const struct ArrayMetadata *a_meta,
+                              const struct NdArrayHandle *b,
+                              const struct ArrayMetadata *b_meta,
+                              const struct NdArrayHandle *out,
+                              const struct ArrayMetadata *out_meta);
+
 /**
  * Divide two arrays.
  */
@@ -114,6 +212,75 @@ int32_t ndarray_div_scalar(const struct NdArrayHandle *a,
                            uintptr_t *out_shape,
                            uintptr_t max_ndim);
 
+/**
+ * Divide a scalar by an array (`scalar / a`).
+ */
+int32_t ndarray_rdiv_scalar(const struct NdArrayHandle *a,
+                            const struct ArrayMetadata *a_meta,
+                            const void *scalar,
+                            uint8_t scalar_dtype,
+                            struct NdArrayHandle **out,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Compute the floor division of two arrays.
+ *
+ * Integer division by zero returns `ERR_MATH`; float division follows IEEE 754.
+ */
+int32_t ndarray_floordiv(const struct NdArrayHandle *a,
+                         const struct ArrayMetadata *a_meta,
+                         const struct NdArrayHandle *b,
+                         const struct ArrayMetadata *b_meta,
+                         struct NdArrayHandle **out,
+                         uint8_t *out_dtype_ptr,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * Compute the floor division of an array by a scalar.
+ */
+int32_t ndarray_floordiv_scalar(const struct NdArrayHandle *a,
+                                const struct ArrayMetadata *a_meta,
+                                const void *scalar,
+                                uint8_t scalar_dtype,
+                                struct NdArrayHandle **out,
+                                uint8_t *out_dtype,
+                                uintptr_t *out_ndim,
+                                uintptr_t *out_shape,
+                                uintptr_t max_ndim);
+
+/**
+ * Compute the C-style remainder of two arrays.
+ *
+ * Integer division by zero returns `ERR_MATH` rather than panicking.
+ */
+int32_t ndarray_fmod(const struct NdArrayHandle *a,
+                     const struct ArrayMetadata *a_meta,
+                     const struct NdArrayHandle *b,
+                     const struct ArrayMetadata *b_meta,
+                     struct NdArrayHandle **out,
+                     uint8_t *out_dtype_ptr,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
+/**
+ * Compute the C-style remainder of an array by a scalar.
+ */
+int32_t ndarray_fmod_scalar(const struct NdArrayHandle *a,
+                            const struct ArrayMetadata *a_meta,
+                            const void *scalar,
+                            uint8_t scalar_dtype,
+                            struct NdArrayHandle **out,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
 /**
  * Element-wise maximum with broadcasting.
  */
@@ -164,13 +331,45 @@ int32_t ndarray_minimum_scalar(const struct NdArrayHandle *a,
                                uintptr_t *out_shape,
                                uintptr_t max_ndim);
 
+/**
+ * Compute the modulo of two arrays with the sign of the divisor.
+ *
+ * Integer division by zero returns `ERR_MATH`; float division by zero gives NaN.
+ */
+int32_t ndarray_mod(const struct NdArrayHandle *a,
+                    const struct ArrayMetadata *a_meta,
+                    const struct NdArrayHandle *b,
+                    const struct ArrayMetadata *b_meta,
+                    struct NdArrayHandle **out,
+                    uint8_t *out_dtype_ptr,
+                    uintptr_t *out_ndim,
+                    uintptr_t *out_shape,
+                    uintptr_t max_ndim);
+
+/**
+ * Compute the modulo of an array by a scalar with the sign of the divisor.
+ */
+int32_t ndarray_mod_scalar(const struct NdArrayHandle *a,
+                           const struct ArrayMetadata *a_meta,
+                           const void *scalar,
+                           uint8_t scalar_dtype,
+                           struct NdArrayHandle **out,
+                           uint8_t *out_dtype,
+                           uintptr_t *out_ndim,
+                           uintptr_t *out_shape,
+                           uintptr_t max_ndim);
+
 /**
  * Multiply two arrays.
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
  */
 int32_t ndarray_mul(const struct NdArrayHandle *a,
                     const struct ArrayMetadata *a_meta,
                     const struct NdArrayHandle *b,
                     const struct ArrayMetadata *b_meta,
+                    int32_t overflow,
                     struct NdArrayHandle **out,
                     uint8_t *out_dtype_ptr,
                     uintptr_t *out_ndim,
@@ -179,11 +378,15 @@ int32_t ndarray_mul(const struct NdArrayHandle *a,
 
 /**
  * Multiply an array by a scalar.
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
  */
 int32_t ndarray_mul_scalar(const struct NdArrayHandle *a,
                            const struct ArrayMetadata *a_meta,
                            const void *scalar,
                            uint8_t scalar_dtype,
+                           int32_t overflow,
                            struct NdArrayHandle **out,
                            uint8_t *out_dtype,
                            uintptr_t *out_ndim,
@@ -216,13 +419,33 @@ int32_t ndarray_rem_scalar(const struct NdArrayHandle *a,
                            uintptr_t *out_shape,
                            uintptr_t max_ndim);
 
+/**
+ * Raise a scalar to the power of each array element (`scalar ** a`).
+ *
+ * Dtypes are promoted as in `ndarray_mul_scalar`. Integer results wrap on
+ * overflow; negative integer exponents return `ERR_MATH`.
+ */
+int32_t ndarray_rpow_scalar(const struct NdArrayHandle *a,
+                            const struct ArrayMetadata *a_meta,
+                            const void *scalar,
+                            uint8_t scalar_dtype,
+                            struct NdArrayHandle **out,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
 /**
  * Subtract two arrays.
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
  */
 int32_t ndarray_sub(const struct NdArrayHandle *a,
                     const struct ArrayMetadata *a_meta,
                     const struct NdArrayHandle *b,
                     const struct ArrayMetadata *b_meta,
+                    int32_t overflow,
                     struct NdArrayHandle **out,
                     uint8_t *out_dtype_ptr,
                     uintptr_t *out_ndim,
@@ -231,17 +454,38 @@ int32_t ndarray_sub(const struct NdArrayHandle *a,
 
 /**
  * Subtract a scalar from an array.
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
  */
 int32_t ndarray_sub_scalar(const struct NdArrayHandle *a,
                            const struct ArrayMetadata *a_meta,
                            const void *scalar,
                            uint8_t scalar_dtype,
+                           int32_t overflow,
                            struct NdArrayHandle **out,
                            uint8_t *out_dtype,
                            uintptr_t *out_ndim,
                            uintptr_t *out_shape,
                            uintptr_t max_ndim);
 
+/**
+ * Subtract an array from a scalar (`scalar - a`).
+ *
+ * Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+ * `ERR_OVERFLOW`); float and complex results ignore it.
+ */
+int32_t ndarray_rsub_scalar(const struct NdArrayHandle *a,
+                            const struct ArrayMetadata *a_meta,
+                            const void *scalar,
+                            uint8_t scalar_dtype,
+                            int32_t overflow,
+                            struct NdArrayHandle **out,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
 /**
  * Extract the scalar value from a 0-dimensional array or view.
  *
@@ -265,14 +509,40 @@ int32_t ndarray_copy(const struct NdArrayHandle *handle,
 
 /**
  * Create an NDArray from raw data with specified dtype.
+ *
+ * `data` holds the elements in the memory order selected by `order`:
+ * 0 for C (row-major), 1 for Fortran (column-major). Fortran data is kept
+ * as-is in column-major storage, so no reordering copy is made.
  */
 int32_t ndarray_create(const void *data,
                        uintptr_t len,
                        const uintptr_t *shape,
                        uintptr_t ndim,
                        int32_t dtype,
+                       int32_t order,
                        struct NdArrayHandle **out_handle);
 
+/**
+ * Query layout flags of a view.
+ *
+ * # Arguments
+ * * `handle` - Array handle
+ * * `meta` - View metadata
+ * * `out_itemsize` - Output: bytes per element
+ * * `out_nbytes` - Output: bytes spanned by the view's elements (`size * itemsize`)
+ * * `out_c_contiguous` - Output: elements are laid out in C (row-major) order
+ * * `out_f_contiguous` - Output: elements are laid out in Fortran (column-major) order
+ * * `out_owns_data` - Output: the view covers the whole underlying buffer with its
+ *   native offset, shape and strides, i.e. it is not a view of a larger array
+ */
+int32_t ndarray_flags(const struct NdArrayHandle *handle,
+                      const struct ArrayMetadata *meta,
+                      uintptr_t *out_itemsize,
+                      uintptr_t *out_nbytes,
+                      bool *out_c_contiguous,
+                      bool *out_f_contiguous,
+                      bool *out_owns_data);
+
 /**
  * Destroy an NDArray and free its memory.
  */
@@ -296,6 +566,44 @@ int32_t ndarray_get_data(const struct NdArrayHandle *handle,
                          void *out_data,
                          uintptr_t *out_len);
 
+/**
+ * Create an iterator over an array view.
+ *
+ * # Arguments
+ * * `handle` - Array handle
+ * * `meta` - View metadata
+ * * `out_iter` - Output iterator handle, released with `ndarray_iter_free`
+ */
+int32_t ndarray_iter_new(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         struct NdIterHandle **out_iter);
+
+/**
+ * Read the next block of elements from an iterator.
+ *
+ * # Arguments
+ * * `iter` - Iterator handle
+ * * `max_count` - Maximum number of elements to read
+ * * `out_values` - Output buffer for at least `max_count` values (type must match array dtype)
+ * * `out_indices` - Optional output buffer for `max_count * ndim` indices (row per element); may be null
+ * * `out_count` - Output: number of elements written (0 once exhausted)
+ */
+int32_t ndarray_iter_next_block(struct NdIterHandle *iter,
+                                uintptr_t max_count,
+                                void *out_values,
+                                uintptr_t *out_indices,
+                                uintptr_t *out_count);
+
+/**
+ * Rewind an iterator to the first element.
+ */
+int32_t ndarray_iter_reset(struct NdIterHandle *iter);
+
+/**
+ * Destroy an iterator and release its reference to the array storage.
+ */
+int32_t ndarray_iter_free(struct NdIterHandle *iter);
+
 /**
  * Compute the bitwise AND of two arrays.
  */
@@ -568,12 +876,53 @@ int32_t ndarray_ne_scalar(const struct NdArrayHandle *a,
                           uintptr_t max_ndim);
 
 /**
- * FFI entry point for einsum (1 or 2 operands, null b for single-op).
+ * Cosine similarity between every row of `a` `(m, d)` and every row of `b` `(n, d)`.
+ *
+ * Rows are normalized to unit length and multiplied with a BLAS matmul,
+ * giving an `(m, n)` matrix. Similarities involving a zero vector are 0.
+ * Float32 inputs yield Float32; any other real dtype yields Float64.
  */
-int32_t ndarray_einsum(const struct NdArrayHandle *a,
-                       const struct ArrayMetadata *a_meta,
-                       const struct NdArrayHandle *b,
-                       const struct ArrayMetadata *b_meta,
+int32_t ndarray_cosine_similarity(const struct NdArrayHandle *a,
+                                  const struct ArrayMetadata *a_meta,
+                                  const struct NdArrayHandle *b,
+                                  const struct ArrayMetadata *b_meta,
+                                  struct NdArrayHandle **out_handle,
+                                  uint8_t *out_dtype,
+                                  uintptr_t *out_ndim,
+                                  uintptr_t *out_shape,
+                                  uintptr_t max_ndim);
+
+/**
+ * Find the `k` nearest rows of `database` `(n, d)` for each row of `query` `(m, d)`.
+ *
+ * `metric`: 0 Euclidean, 1 squared Euclidean, 2 Manhattan, 3 cosine distance
+ * (`1 - cosine similarity`, with zero vectors at distance 1). `k` is clamped
+ * to `n`. Writes Int64 indices and distances, both `(m, min(k, n))`, sorted by
+ * ascending distance with ties broken by lower index. Distances are Float32
+ * when both inputs are Float32, otherwise Float64.
+ */
+int32_t ndarray_argmin_distance(const struct NdArrayHandle *query,
+                                const struct ArrayMetadata *query_meta,
+                                const struct NdArrayHandle *database,
+                                const struct ArrayMetadata *database_meta,
+                                int32_t metric,
+                                uintptr_t k,
+                                struct NdArrayHandle **out_indices,
+                                struct NdArrayHandle **out_distances,
+                                uintptr_t *out_shape,
+                                uintptr_t max_ndim);
+
+/**
+ * Einstein summation over `num_operands` arrays.
+ *
+ * `subscripts` uses NumPy notation, e.g. `ij,jk->ik` (matrix product),
+ * `bqd,bkd->bqk` (batched contraction), `ij->ji` (transpose), `ii->`
+ * (trace) or `ij,jk,kl->il` (chained contraction). Operands are promoted to
+ * a common float dtype; the result is Float32 or Float64.
+ */
+int32_t ndarray_einsum(const struct NdArrayHandle *const *handles,
+                       const struct ArrayMetadata *const *metas,
+                       uintptr_t num_operands,
                        const char *subscripts,
                        struct NdArrayHandle **out_handle,
                        uint8_t *out_dtype,
@@ -738,6 +1087,121 @@ int32_t ndarray_arange(double start,
                        uint8_t dtype,
                        struct NdArrayHandle **out_handle);
 
+/**
+ * Create an integer range from exact 64-bit signed bounds.
+ *
+ * Unlike [`ndarray_arange`], no value passes through f64, so ranges beyond
+ * 2^53 are exact. `dtype` must be an integer type; any element that does not
+ * fit it is an error. The number of elements is written to `out_len`.
+ */
+int32_t ndarray_arange_i64(int64_t start,
+                           int64_t stop,
+                           int64_t step,
+                           uint8_t dtype,
+                           struct NdArrayHandle **out_handle,
+                           uintptr_t *out_len);
+
+/**
+ * Create an integer range from exact 64-bit unsigned bounds.
+ *
+ * Same as [`ndarray_arange_i64`] for bounds above `i64::MAX`; `step` stays
+ * signed so descending ranges are possible.
+ */
+int32_t ndarray_arange_u64(uint64_t start,
+                           uint64_t stop,
+                           int64_t step,
+                           uint8_t dtype,
+                           struct NdArrayHandle **out_handle,
+                           uintptr_t *out_len);
+
+/**
+ * Create an array of random values in `[0, 1]` from the beta distribution
+ * with shape parameters `a` and `b`.
+ *
+ * Supports Float32 and Float64 only. Requires `a > 0` and `b > 0`.
+ */
+int32_t ndarray_beta(double a,
+                     double b,
+                     const uintptr_t *shape,
+                     uintptr_t ndim,
+                     uint8_t dtype,
+                     bool has_seed,
+                     uint64_t seed,
+                     const struct RngHandle *rng,
+                     struct NdArrayHandle **out_handle);
+
+/**
+ * Create an Int64 array of binomial samples: the number of successes in `n`
+ * trials with success probability `p`.
+ *
+ * Requires `0 <= p <= 1`.
+ */
+int32_t ndarray_binomial(uint64_t n,
+                         double p,
+                         const uintptr_t *shape,
+                         uintptr_t ndim,
+                         bool has_seed,
+                         uint64_t seed,
+                         const struct RngHandle *rng,
+                         struct NdArrayHandle **out_handle);
+
+/**
+ * Create an array of random values from the chi-square distribution with
+ * `df` degrees of freedom.
+ *
+ * Supports Float32 and Float64 only. Requires `df > 0`.
+ */
+int32_t ndarray_chisquare(double df,
+                          const uintptr_t *shape,
+                          uintptr_t ndim,
+                          uint8_t dtype,
+                          bool has_seed,
+                          uint64_t seed,
+                          const struct RngHandle *rng,
+                          struct NdArrayHandle **out_handle);
+
+/**
+ * Sample Int64 indices from `0..n` into an array of the given shape.
+ *
+ * With `replace == false` every index appears at most once. `p` (nullable,
+ * with `p_meta`) holds one non-negative weight per index; weights are
+ * normalized by their sum. Callers gather values from a 1-D array with
+ * `ndarray_take`.
+ *
+ * # Arguments
+ * * `n` - Population size
+ * * `shape` / `ndim` - Output shape
+ * * `replace` - Whether an index may be drawn more than once
+ * * `p` / `p_meta` - Optional 1-D weights of length `n`; both null for uniform
+ * * `has_seed` / `seed` - Optional seed, ignored when `rng` is non-null
+ * * `rng` - Optional generator handle
+ */
+int32_t ndarray_choice(uintptr_t n,
+                       const uintptr_t *shape,
+                       uintptr_t ndim,
+                       bool replace,
+                       const struct NdArrayHandle *p,
+                       const struct ArrayMetadata *p_meta,
+                       bool has_seed,
+                       uint64_t seed,
+                       const struct RngHandle *rng,
+                       struct NdArrayHandle **out_handle);
+
+/**
+ * Create an array of random values from the exponential distribution
+ * with the given `scale` (the mean, i.e. `1 / lambda`).
+ *
+ * Supports Float32 and Float64 only. Requires `scale > 0`.
+ */
+int32_t ndarray_exponential(double scale,
+                            const uintptr_t *shape,
+                            uintptr_t ndim,
+                            uint8_t dtype,
+                            bool has_seed,
+                            uint64_t seed,
+                            const struct RngHandle *rng,
+                            struct NdArrayHandle **out_handle);
+
 /**
  * Create a 2D identity matrix.
  */
@@ -749,13 +1213,46 @@ int32_t ndarray_eye(uintptr_t n,
 
 /**
  * Create an array filled with a specific value.
+ *
+ * `order` selects C (0) or Fortran (1) storage layout.
  */
 int32_t ndarray_full(const uintptr_t *shape,
                      uintptr_t ndim,
                      const void *value,
                      uint8_t dtype,
+                     int32_t order,
                      struct NdArrayHandle **out_handle);
 
+/**
+ * Create an array of random values from the gamma distribution with shape
+ * `k` and scale `theta`.
+ *
+ * Supports Float32 and Float64 only. Requires `k > 0` and `theta > 0`.
+ */
+int32_t ndarray_gamma(double k,
+                      double theta,
+                      const uintptr_t *shape,
+                      uintptr_t ndim,
+                      uint8_t dtype,
+                      bool has_seed,
+                      uint64_t seed,
+                      const struct RngHandle *rng,
+                      struct NdArrayHandle **out_handle);
+
+/**
+ * Create an Int64 array of geometric samples with success probability `p`.
+ *
+ * Like NumPy, each value is the number of trials up to and including the
+ * first success, so samples are >= 1. Requires `0 < p <= 1`.
+ */
+int32_t ndarray_geometric(double p,
+                          const uintptr_t *shape,
+                          uintptr_t ndim,
+                          bool has_seed,
+                          uint64_t seed,
+                          const struct RngHandle *rng,
+                          struct NdArrayHandle **out_handle);
+
 /**
  * Create numbers spaced geometrically from start to stop.
  *
@@ -768,10 +1265,29 @@ int32_t ndarray_geomspace(double start,
                           uint8_t dtype,
                           struct NdArrayHandle **out_handle);
 
+/**
+ * Create an Int64 array of hypergeometric samples.
+ *
+ * Each value is the number of "good" items among `nsample` items drawn
+ * without replacement from `ngood` good and `nbad` bad items. Requires
+ * `nsample <= ngood + nbad`.
+ */
+int32_t ndarray_hypergeometric(uint64_t ngood,
+                               uint64_t nbad,
+                               uint64_t nsample,
+                               const uintptr_t *shape,
+                               uintptr_t ndim,
+                               bool has_seed,
+                               uint64_t seed,
+                               const struct RngHandle *rng,
+                               struct NdArrayHandle **out_handle);
+
 /**
  * Create evenly spaced numbers over a specified interval.
  *
- * Only supports Float32 and Float64 dtypes.
+ * Float dtypes hold the exact samples. Integer dtypes round each sample
+ * toward negative infinity, as NumPy does; a sample outside the dtype's
+ * range is an error. Bool and complex dtypes are not supported.
  * The endpoint parameter controls whether stop is included.
  */
 int32_t ndarray_linspace(double start,
@@ -794,6 +1310,35 @@ int32_t ndarray_logspace(double start,
                          uint8_t dtype,
                          struct NdArrayHandle **out_handle);
 
+/**
+ * Create an array of samples from a multivariate normal distribution.
+ *
+ * The output has shape `shape + [d]`, where `d` is the length of `mean`;
+ * `cov` is the `[d, d]` covariance matrix and must be symmetric
+ * positive-definite. Sampling goes through its Cholesky factor.
+ *
+ * Supports Float32 and Float64 output; inputs of any real dtype are read as f64.
+ *
+ * # Arguments
+ * * `mean` / `mean_meta` - 1-D mean vector
+ * * `cov` / `cov_meta` - 2-D covariance matrix
+ * * `shape` / `ndim` - Number of samples, as a shape
+ * * `dtype` - Output dtype
+ * * `has_seed` / `seed` - Optional seed, ignored when `rng` is non-null
+ * * `rng` - Optional generator handle
+ */
+int32_t ndarray_multivariate_normal(const struct NdArrayHandle *mean,
+                                    const struct ArrayMetadata *mean_meta,
+                                    const struct NdArrayHandle *cov,
+                                    const struct ArrayMetadata *cov_meta,
+                                    const uintptr_t *shape,
+                                    uintptr_t ndim,
+                                    uint8_t dtype,
+                                    bool has_seed,
+                                    uint64_t seed,
+                                    const struct RngHandle *rng,
+                                    struct NdArrayHandle **out_handle);
+
 /**
  * Create an array of random values sampled from N(mean, std).
  *
@@ -806,38 +1351,69 @@ int32_t ndarray_normal(double mean,
                        uint8_t dtype,
                        bool has_seed,
                        uint64_t seed,
+                       const struct RngHandle *rng,
                        struct NdArrayHandle **out_handle);
 
 /**
  * Create an array filled with ones.
+ *
+ * `order` selects C (0) or Fortran (1) storage layout.
  */
 int32_t ndarray_ones(const uintptr_t *shape,
                      uintptr_t ndim,
                      uint8_t dtype,
+                     int32_t order,
                      struct NdArrayHandle **out_handle);
 
 /**
- * Create an array of random values sampled from N(0, 1).
+ * Create an Int64 array holding a random permutation of `0..n`.
  *
- * Supports Float32 and Float64 only.
+ * Uses the same swap sequence as `ndarray_shuffle`, so the result matches
+ * shuffling `arange(n)` with the same seed or generator state.
  */
-int32_t ndarray_randn(const uintptr_t *shape,
-                      uintptr_t ndim,
-                      uint8_t dtype,
-                      bool has_seed,
-                      uint64_t seed,
-                      struct NdArrayHandle **out_handle);
+int32_t ndarray_permutation(uintptr_t n,
+                            bool has_seed,
+                            uint64_t seed,
+                            const struct RngHandle *rng,
+                            struct NdArrayHandle **out_handle);
 
 /**
- * Create an array of random values sampled uniformly from [0, 1).
+ * Create an Int64 array of Poisson samples with expected count `lam`.
  *
- * Supports Float32 and Float64 only.
+ * Requires `0 <= lam < Poisson::MAX_LAMBDA`; `lam == 0` yields all zeros.
  */
-int32_t ndarray_random(const uintptr_t *shape,
-                       uintptr_t ndim,
+int32_t ndarray_poisson(double lam,
+                        const uintptr_t *shape,
+                        uintptr_t ndim,
+                        bool has_seed,
+                        uint64_t seed,
+                        const struct RngHandle *rng,
+                        struct NdArrayHandle **out_handle);
+
+/**
+ * Create an array of random values sampled from N(0, 1).
+ *
+ * Supports Float32 and Float64 only.
+ */
+int32_t ndarray_randn(const uintptr_t *shape,
+                      uintptr_t ndim,
+                      uint8_t dtype,
+                      bool has_seed,
+                      uint64_t seed,
+                      const struct RngHandle *rng,
+                      struct NdArrayHandle **out_handle);
+
+/**
+ * Create an array of random values sampled uniformly from [0, 1).
+ *
+ * Supports Float32 and Float64 only.
+ */
+int32_t ndarray_random(const uintptr_t *shape,
+                       uintptr_t ndim,
                        uint8_t dtype,
                        bool has_seed,
                        uint64_t seed,
+                       const struct RngHandle *rng,
                        struct NdArrayHandle **out_handle);
 
 /**
@@ -852,8 +1428,88 @@ int32_t ndarray_random_int(int64_t low,
                            uint8_t dtype,
                            bool has_seed,
                            uint64_t seed,
+                           const struct RngHandle *rng,
                            struct NdArrayHandle **out_handle);
 
+/**
+ * Create a generator handle seeded with `seed`.
+ *
+ * Equal seeds produce equal sequences of draws.
+ */
+int32_t ndarray_rng_new(uint64_t seed,
+                        struct RngHandle **out_rng);
+
+/**
+ * Create a generator handle seeded from operating-system entropy.
+ */
+int32_t ndarray_rng_from_entropy(struct RngHandle **out_rng);
+
+/**
+ * Release a generator handle. Null is a no-op.
+ */
+int32_t ndarray_rng_free(struct RngHandle *rng);
+
+/**
+ * Shuffle an array in place along `axis`.
+ *
+ * Sub-arrays along the axis are reordered together, so shuffling a
+ * `[samples, features]` matrix along axis 0 permutes its rows. Works for
+ * every dtype and for strided views.
+ *
+ * # Arguments
+ * * `handle` / `meta` - Array and view to shuffle
+ * * `axis` - Axis to shuffle along (negative counts from the end)
+ * * `has_seed` / `seed` - Optional seed, ignored when `rng` is non-null
+ * * `rng` - Optional generator handle
+ */
+int32_t ndarray_shuffle(const struct NdArrayHandle *handle,
+                        const struct ArrayMetadata *meta,
+                        int32_t axis,
+                        bool has_seed,
+                        uint64_t seed,
+                        const struct RngHandle *rng);
+
+/**
+ * Create an array of random values from the standard Cauchy distribution
+ * (median 0, scale 1).
+ *
+ * Supports Float32 and Float64 only.
+ */
+int32_t ndarray_standard_cauchy(const uintptr_t *shape,
+                                uintptr_t ndim,
+                                uint8_t dtype,
+                                bool has_seed,
+                                uint64_t seed,
+                                const struct RngHandle *rng,
+                                struct NdArrayHandle **out_handle);
+
+/**
+ * Create an array of random values from Student's t distribution with
+ * `df` degrees of freedom.
+ *
+ * Supports Float32 and Float64 only. Requires `df > 0`.
+ */
+int32_t ndarray_standard_t(double df,
+                           const uintptr_t *shape,
+                           uintptr_t ndim,
+                           uint8_t dtype,
+                           bool has_seed,
+                           uint64_t seed,
+                           const struct RngHandle *rng,
+                           struct NdArrayHandle **out_handle);
+
+/**
+ * Create an n x m matrix with ones at and below the k-th diagonal.
+ *
+ * Element `(i, j)` is one when `j <= i + k` and zero otherwise, so `k > 0`
+ * extends the ones above the main diagonal and `k < 0` lowers their edge.
+ */
+int32_t ndarray_tri(uintptr_t n,
+                    uintptr_t m,
+                    intptr_t k,
+                    uint8_t dtype,
+                    struct NdArrayHandle **out_handle);
+
 /**
  * Create an array of random values sampled uniformly from [low, high).
  *
@@ -866,19 +1522,143 @@ int32_t ndarray_uniform(double low,
                         uint8_t dtype,
                         bool has_seed,
                         uint64_t seed,
+                        const struct RngHandle *rng,
                         struct NdArrayHandle **out_handle);
 
 /**
  * Create an array filled with zeros.
+ *
+ * `order` selects C (0) or Fortran (1) storage layout.
  */
 int32_t ndarray_zeros(const uintptr_t *shape,
                       uintptr_t ndim,
                       uint8_t dtype,
+                      int32_t order,
                       struct NdArrayHandle **out_handle);
 
+/**
+ * Decode a PNG or JPEG file into a Uint8 `(H, W, C)` array.
+ *
+ * The format is detected from the file signature. PNG keeps its channel
+ * count (gray, gray+alpha, RGB, RGBA; palettes expand to RGB/RGBA and 16-bit
+ * samples are reduced to 8 bits). JPEG decodes to 1 channel for grayscale
+ * files and RGB otherwise. Images whose decoded size would exceed 1 GiB are
+ * rejected before any pixel buffer is allocated. Requires the `image` feature.
+ */
+int32_t ndarray_image_decode(const uint8_t *bytes,
+                             uintptr_t len,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Encode a Uint8 `(H, W)` or `(H, W, C)` image as PNG or JPEG bytes.
+ *
+ * `format` is 0 for PNG (1-4 channels, lossless) or 1 for JPEG (1 or 3
+ * channels, baseline, `quality` 1-100). The encoded file is returned as a
+ * 1D Uint8 array. Requires the `image` feature.
+ */
+int32_t ndarray_image_encode(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             int32_t format,
+                             uint8_t quality,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Convert an RGB (or RGBA, alpha ignored) image to grayscale with BT.601 weights.
+ *
+ * The result is `(H, W)`, or keeps a single-channel axis in `layout` when
+ * `keep_channel_axis` is set. Uint8 output is rounded.
+ */
+int32_t ndarray_image_rgb_to_gray(const struct NdArrayHandle *handle,
+                                  const struct ArrayMetadata *meta,
+                                  int32_t layout,
+                                  bool keep_channel_axis,
+                                  struct NdArrayHandle **out_handle,
+                                  uint8_t *out_dtype,
+                                  uintptr_t *out_ndim,
+                                  uintptr_t *out_shape,
+                                  uintptr_t max_ndim);
+
+/**
+ * Replicate a grayscale image (2D, or 3D with one channel) into three RGB channels.
+ */
+int32_t ndarray_image_gray_to_rgb(const struct NdArrayHandle *handle,
+                                  const struct ArrayMetadata *meta,
+                                  int32_t layout,
+                                  struct NdArrayHandle **out_handle,
+                                  uint8_t *out_dtype,
+                                  uintptr_t *out_ndim,
+                                  uintptr_t *out_shape,
+                                  uintptr_t max_ndim);
+
+/**
+ * Swap the first and third channels, converting RGB <-> BGR (alpha is kept).
+ */
+int32_t ndarray_image_swap_rb(const struct NdArrayHandle *handle,
+                              const struct ArrayMetadata *meta,
+                              int32_t layout,
+                              struct NdArrayHandle **out_handle,
+                              uint8_t *out_dtype,
+                              uintptr_t *out_ndim,
+                              uintptr_t *out_shape,
+                              uintptr_t max_ndim);
+
+/**
+ * Convert an `(H, W, C)` image or `(N, H, W, C)` batch to contiguous CHW / NCHW.
+ */
+int32_t ndarray_image_hwc_to_chw(const struct NdArrayHandle *handle,
+                                 const struct ArrayMetadata *meta,
+                                 struct NdArrayHandle **out_handle,
+                                 uint8_t *out_dtype,
+                                 uintptr_t *out_ndim,
+                                 uintptr_t *out_shape,
+                                 uintptr_t max_ndim);
+
+/**
+ * Convert a `(C, H, W)` image or `(N, C, H, W)` batch to contiguous HWC / NHWC.
+ */
+int32_t ndarray_image_chw_to_hwc(const struct NdArrayHandle *handle,
+                                 const struct ArrayMetadata *meta,
+                                 struct NdArrayHandle **out_handle,
+                                 uint8_t *out_dtype,
+                                 uintptr_t *out_ndim,
+                                 uintptr_t *out_shape,
+                                 uintptr_t max_ndim);
+
+/**
+ * Resize an image to `out_height` x `out_width`.
+ *
+ * Accepts 2D `(H, W)` arrays or 3D arrays in the given `layout` (0 = HWC, 1 = CHW)
+ * of dtype Uint8, Float32 or Float64; the output keeps the dtype, layout and
+ * channel count. `interpolation` is 0 for nearest, 1 for bilinear. `antialias`
+ * widens the bilinear filter when downscaling; it has no effect on nearest.
+ */
+int32_t ndarray_image_resize(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             uintptr_t out_height,
+                             uintptr_t out_width,
+                             int32_t interpolation,
+                             bool antialias,
+                             int32_t layout,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
 /**
  * Assign values from source view to destination view.
  *
+ * The source shape must broadcast to the destination shape; leading
+ * length-1 source axes beyond the destination's rank are ignored.
+ *
  * # Arguments
  * * `dst` - Destination array handle
  * * `dst_meta` - Destination view metadata
@@ -890,6 +1670,27 @@ int32_t ndarray_assign(const struct NdArrayHandle *dst,
                        const struct NdArrayHandle *src,
                        const struct ArrayMetadata *src_meta);
 
+/**
+ * Copy `src` into the `dst` view in place, optionally only where `mask` is true.
+ *
+ * The source and mask shapes must broadcast to the destination shape;
+ * leading length-1 axes beyond the destination's rank are ignored. The
+ * source is converted to the destination dtype when `casting` allows it.
+ *
+ * # Arguments
+ * * `dst` / `dst_meta` - Destination array and view
+ * * `src` / `src_meta` - Source array and view
+ * * `mask` / `mask_meta` - Bool mask; both null to copy every element
+ * * `casting` - Casting rule (0 = no, 1 = equiv, 2 = safe, 3 = same_kind, 4 = unsafe)
+ */
+int32_t ndarray_copyto(const struct NdArrayHandle *dst,
+                       const struct ArrayMetadata *dst_meta,
+                       const struct NdArrayHandle *src,
+                       const struct ArrayMetadata *src_meta,
+                       const struct NdArrayHandle *mask,
+                       const struct ArrayMetadata *mask_meta,
+                       int32_t casting);
+
 /**
  * Fill a slice with a value.
  *
@@ -916,19 +1717,65 @@ int32_t ndarray_get_element(const struct NdArrayHandle *handle,
                             uintptr_t flat_index,
                             void *out_value);
 
+/**
+ * Get many elements of an array or view in one call.
+ *
+ * # Arguments
+ * * `handle` - Array handle
+ * * `meta` - View metadata (offset, shape, strides)
+ * * `indices` - `n * index_ndim` indices stored back to back
+ * * `n` - Number of elements to read
+ * * `index_ndim` - 1 for logical C-order flat indices, or `ndim` for coordinate tuples
+ * * `out_values` - Buffer of `n` elements in the array's dtype (complex as re/im pairs, bool as u8)
+ *
+ * Negative indices count from the end. All indices are validated before any
+ * value is written; an out-of-range index returns ERR_INDEX.
+ */
+int32_t ndarray_get_many(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         const int64_t *indices,
+                         uintptr_t n,
+                         uintptr_t index_ndim,
+                         void *out_values);
+
 /**
  * Put values by flattened logical indices.
+ *
+ * Pass either `values_handle` / `values_meta` or null for both and a
+ * `scalar` of `scalar_dtype`. Array values are converted to the input dtype
+ * and broadcast against the indices shape; values that do not broadcast are
+ * repeated in C order, as in NumPy's `put`. Returns an updated copy.
  */
 int32_t ndarray_put(const struct NdArrayHandle *handle,
                     const struct ArrayMetadata *meta,
                     const struct NdArrayHandle *indices_handle,
                     const struct ArrayMetadata *indices_meta,
-                    const void *values,
-                    uintptr_t values_len,
-                    double scalar_value,
-                    bool has_scalar,
+                    const struct NdArrayHandle *values_handle,
+                    const struct ArrayMetadata *values_meta,
+                    const void *scalar,
+                    uint8_t scalar_dtype,
                     struct NdArrayHandle **out_handle);
 
+/**
+ * Put sub-arrays along an axis, the inverse of `ndarray_take_axis`.
+ *
+ * Each index selects a whole sub-array along `axis`. Array values must
+ * broadcast to the shape `take_axis` would return,
+ * `shape[..axis] + indices.shape + shape[axis + 1..]`; a scalar fills every
+ * selected sub-array. Repeated indices keep the last write. Returns an
+ * updated copy.
+ */
+int32_t ndarray_put_axis(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         const struct NdArrayHandle *indices_handle,
+                         const struct ArrayMetadata *indices_meta,
+                         int32_t axis,
+                         const struct NdArrayHandle *values_handle,
+                         const struct ArrayMetadata *values_meta,
+                         const void *scalar,
+                         uint8_t scalar_dtype,
+                         struct NdArrayHandle **out_handle);
+
 /**
  * Scatter values along an axis and return a mutated copy.
  */
@@ -943,6 +1790,57 @@ int32_t ndarray_put_along_axis(const struct NdArrayHandle *handle,
                                bool has_scalar,
                                struct NdArrayHandle **out_handle);
 
+/**
+ * Scatter slices of `updates` into a copy of the array along `axis`, reducing by `mode`.
+ *
+ * `indices` is a 1D integer array; `updates` must match the array's shape except
+ * along `axis`, where its length equals `indices.len()`. `mode` is a
+ * [`ScatterMode`] code. Updates must share the array's dtype; Bool and complex
+ * arrays are not supported.
+ */
+int32_t ndarray_scatter(const struct NdArrayHandle *handle,
+                        const struct ArrayMetadata *meta,
+                        const struct NdArrayHandle *indices_handle,
+                        const struct ArrayMetadata *indices_meta,
+                        const struct NdArrayHandle *updates_handle,
+                        const struct ArrayMetadata *updates_meta,
+                        int32_t axis,
+                        int32_t mode,
+                        bool include_self,
+                        struct NdArrayHandle **out_handle,
+                        uint8_t *out_dtype,
+                        uintptr_t *out_ndim,
+                        uintptr_t *out_shape,
+                        uintptr_t max_ndim);
+
+/**
+ * Scatter updates into flattened indices of a copy of the array, reducing by `mode`.
+ *
+ * The flat counterpart of [`ndarray_scatter`] and a generalisation of
+ * `ndarray_scatter_add_flat`: `Min`, `Max` and `Mean` reduce repeated
+ * indices in a single pass, with `Mean` counting contributions per position.
+ * Pass either `updates_handle` / `updates_meta`, converted to the array's
+ * dtype and broadcast against the indices shape (repeated in order when
+ * they do not broadcast), or null for both and a `scalar` of `scalar_dtype`.
+ * Indices may have any integer dtype and shape. Bool and complex arrays are
+ * not supported.
+ */
+int32_t ndarray_scatter_flat(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             const struct NdArrayHandle *indices_handle,
+                             const struct ArrayMetadata *indices_meta,
+                             const struct NdArrayHandle *updates_handle,
+                             const struct ArrayMetadata *updates_meta,
+                             const void *scalar,
+                             uint8_t scalar_dtype,
+                             int32_t mode,
+                             bool include_self,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
 /**
  * Add updates into flattened indices and return a mutated copy.
  */
@@ -968,6 +1866,27 @@ int32_t ndarray_scatter_add_flat(const struct NdArrayHandle *handle,
  */
 int32_t ndarray_set_element(struct NdArrayHandle *handle, uintptr_t flat_index, const void *value);
 
+/**
+ * Set many elements of an array or view in one call.
+ *
+ * # Arguments
+ * * `handle` - Array handle
+ * * `meta` - View metadata (offset, shape, strides)
+ * * `indices` - `n * index_ndim` indices stored back to back
+ * * `n` - Number of elements to write
+ * * `index_ndim` - 1 for logical C-order flat indices, or `ndim` for coordinate tuples
+ * * `values` - Buffer of `n` elements in the array's dtype (complex as re/im pairs, bool as u8)
+ *
+ * Negative indices count from the end. All indices are validated before the
+ * array is modified. When an index repeats, the last value wins.
+ */
+int32_t ndarray_set_many(struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         const int64_t *indices,
+                         uintptr_t n,
+                         uintptr_t index_ndim,
+                         const void *values);
+
 /**
  * Gather values by flattened logical indices.
  */
@@ -1011,8 +1930,27 @@ int32_t ndarray_take_along_axis(const struct NdArrayHandle *handle,
                                 uintptr_t *out_shape,
                                 uintptr_t max_ndim);
 
+/**
+ * Gather rows of a 2D `(vocab, dim)` table by an integer index array of any shape.
+ *
+ * The result has shape `indices.shape + (dim,)` and the table's dtype.
+ * Negative indices count from the end; out-of-range indices return ERR_INDEX.
+ */
+int32_t ndarray_take_rows(const struct NdArrayHandle *handle,
+                          const struct ArrayMetadata *meta,
+                          const struct NdArrayHandle *indices_handle,
+                          const struct ArrayMetadata *indices_meta,
+                          struct NdArrayHandle **out_handle,
+                          uint8_t *out_dtype,
+                          uintptr_t *out_ndim,
+                          uintptr_t *out_shape,
+                          uintptr_t max_ndim);
+
 /**
  * Select values from x and y depending on condition.
+ *
+ * All three operands broadcast together. The result dtype is the promotion
+ * of x and y; a condition that is not Bool selects x where it is non-zero.
  */
 int32_t ndarray_where(const struct NdArrayHandle *cond_handle,
                       const struct ArrayMetadata *cond_meta,
@@ -1026,6 +1964,265 @@ int32_t ndarray_where(const struct NdArrayHandle *cond_handle,
                       uintptr_t *out_shape,
                       uintptr_t max_ndim);
 
+/**
+ * Select between an array and a scalar depending on condition.
+ *
+ * The scalar is `x` when `scalar_is_x` is true and `y` otherwise. The
+ * result dtype follows scalar promotion: the array's dtype is kept unless
+ * the scalar needs a float or complex result.
+ */
+int32_t ndarray_where_scalar(const struct NdArrayHandle *cond_handle,
+                             const struct ArrayMetadata *cond_meta,
+                             const struct NdArrayHandle *array_handle,
+                             const struct ArrayMetadata *array_meta,
+                             const void *scalar,
+                             uint8_t scalar_dtype,
+                             bool scalar_is_x,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype_ptr,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Select the elements (or trailing sub-arrays) where `mask` is true.
+ *
+ * The mask must be Bool and match the leading axes of the array. The result
+ * is a new C-contiguous array of shape `[count, ...trailing]`.
+ */
+int32_t ndarray_boolean_select(const struct NdArrayHandle *handle,
+                               const struct ArrayMetadata *meta,
+                               const struct NdArrayHandle *mask,
+                               const struct ArrayMetadata *mask_meta,
+                               struct NdArrayHandle **out_handle,
+                               uint8_t *out_dtype,
+                               uintptr_t *out_ndim,
+                               uintptr_t *out_shape,
+                               uintptr_t max_ndim);
+
+/**
+ * Assign to the elements (or trailing sub-arrays) where `mask` is true, in place.
+ *
+ * Pass either `values` / `values_meta`, whose shape must broadcast to the
+ * selection shape `[count, ...trailing]`, or null for both and a `scalar`
+ * of `scalar_dtype` to write to every selected element. Values are
+ * converted to the array's dtype; complex values require a complex array.
+ */
+int32_t ndarray_boolean_assign(const struct NdArrayHandle *handle,
+                               const struct ArrayMetadata *meta,
+                               const struct NdArrayHandle *mask,
+                               const struct ArrayMetadata *mask_meta,
+                               const struct NdArrayHandle *values,
+                               const struct ArrayMetadata *values_meta,
+                               const void *scalar,
+                               uint8_t scalar_dtype);
+
+/**
+ * Save an array or view to a NumPy `.npy` file.
+ *
+ * Data is written in C order and native byte order, with a version 1.0
+ * header (2.0 when the header does not fit). Every dtype except BFloat16,
+ * which NumPy has no type for, can be saved. An existing file is replaced.
+ *
+ * # Arguments
+ * * `handle` / `meta` - Array and view to save
+ * * `path` - Nul-terminated UTF-8 file path
+ */
+int32_t ndarray_save_npy(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         const char *path);
+
+/**
+ * Load a NumPy `.npy` file into a new array.
+ *
+ * Accepts format versions 1.0 to 3.0, either byte order and Fortran-ordered
+ * data; the result is always native-endian and C-contiguous. Structured
+ * and object dtypes are rejected.
+ *
+ * # Arguments
+ * * `path` - Nul-terminated UTF-8 file path
+ */
+int32_t ndarray_load_npy(const char *path,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * Save arrays to a NumPy `.npz` archive.
+ *
+ * Array `i` is stored as the entry `names[i] + ".npy"`, in the same format
+ * as `ndarray_save_npy`. With `compressed` the entries are deflated (like
+ * `numpy.savez_compressed`), otherwise they are stored uncompressed. Names
+ * must be unique. An existing file is replaced.
+ *
+ * # Arguments
+ * * `path` - Nul-terminated UTF-8 file path
+ * * `names` - `num_arrays` nul-terminated UTF-8 array names
+ * * `handles` / `metas` - `num_arrays` arrays and views to save
+ * * `compressed` - Whether to deflate the entries
+ */
+int32_t ndarray_save_npz(const char *path,
+                         const char *const *names,
+                         const struct NdArrayHandle *const *handles,
+                         const struct ArrayMetadata *const *metas,
+                         uintptr_t num_arrays,
+                         bool compressed);
+
+/**
+ * List the array names stored in a `.npz` archive, in archive order.
+ *
+ * Names are entry names without their `.npy` suffix, each followed by a NUL
+ * byte. `out_len` receives the total length in bytes; when it exceeds
+ * `len` nothing is written, so callers can retry with a larger buffer.
+ *
+ * # Arguments
+ * * `path` - Nul-terminated UTF-8 file path
+ * * `buf` / `len` - Output buffer for the names (may be null when `len` is 0)
+ * * `out_len` - Output: bytes needed for all names
+ */
+int32_t ndarray_npz_names(const char *path,
+                          char *buf,
+                          uintptr_t len,
+                          uintptr_t *out_len);
+
+/**
+ * Load one named array from a NumPy `.npz` archive.
+ *
+ * `name` is matched against entry names with their `.npy` suffix removed,
+ * as listed by `ndarray_npz_names`. The entry is decoded like
+ * `ndarray_load_npy` and its CRC is verified.
+ *
+ * # Arguments
+ * * `path` - Nul-terminated UTF-8 file path
+ * * `name` - Nul-terminated UTF-8 array name
+ */
+int32_t ndarray_load_npz(const char *path,
+                         const char *name,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * Serialize an array or view into a self-describing byte buffer.
+ *
+ * The buffer holds a small header (dtype, ndim, shape) followed by the raw
+ * native-endian elements in C order, so it can be cached as an opaque
+ * string and restored with `ndarray_from_bytes`. `out_len` receives the
+ * buffer size; when it exceeds `len` nothing is written, so callers can
+ * query the size with a null buffer first.
+ *
+ * # Arguments
+ * * `handle` / `meta` - Array or view to serialize
+ * * `buf` / `len` - Output buffer (may be null when `len` is 0)
+ * * `out_len` - Output: bytes needed for the serialized array
+ */
+int32_t ndarray_to_bytes(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         uint8_t *buf,
+                         uintptr_t len,
+                         uintptr_t *out_len);
+
+/**
+ * Restore an array from a buffer written by `ndarray_to_bytes`.
+ *
+ * Buffers written on a host of the other byte order are swapped on load.
+ * The buffer length must match its header exactly.
+ *
+ * # Arguments
+ * * `buf` / `len` - Serialized array
+ */
+int32_t ndarray_from_bytes(const uint8_t *buf,
+                           uintptr_t len,
+                           struct NdArrayHandle **out_handle,
+                           uint8_t *out_dtype,
+                           uintptr_t *out_ndim,
+                           uintptr_t *out_shape,
+                           uintptr_t max_ndim);
+
+/**
+ * Save an array or view to a CSV file.
+ *
+ * Rows of a 2-D array become lines and a 1-D array is written as one
+ * column. Real and bool dtypes are supported. An existing file is replaced.
+ *
+ * # Arguments
+ * * `path` - Nul-terminated UTF-8 file path
+ * * `delimiter` - ASCII field delimiter, e.g. `b','`
+ * * `header` - Optional nul-terminated line written before the data (may be null)
+ * * `nan_repr` - Optional nul-terminated text for NaN (null writes `nan`)
+ */
+int32_t ndarray_to_csv(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       const char *path,
+                       uint8_t delimiter,
+                       const char *header,
+                       const char *nan_repr);
+
+/**
+ * Load a CSV file as a 2-D `[rows, columns]` array.
+ *
+ * Every record must have the same number of fields. Float dtypes accept
+ * `nan` and `inf`; bools accept `true`/`false`/`1`/`0`. Complex dtypes are
+ * not supported.
+ *
+ * # Arguments
+ * * `path` - Nul-terminated UTF-8 file path
+ * * `delimiter` - ASCII field delimiter, e.g. `b','`
+ * * `skip_rows` - Number of leading records to skip, e.g. 1 for a header
+ * * `dtype` - Output dtype
+ * * `fill_value` - Value for empty fields (NaN: only allowed for float dtypes)
+ */
+int32_t ndarray_from_csv(const char *path,
+                         uint8_t delimiter,
+                         uintptr_t skip_rows,
+                         uint8_t dtype,
+                         double fill_value,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * Encode an array or view as an Arrow IPC stream.
+ *
+ * The stream holds one record batch with a non-nullable `data` column:
+ * primitive for 0-D and 1-D arrays, an `arrow.fixed_shape_tensor` column
+ * with one row per index of the first axis otherwise. 0-D arrays carry
+ * `ndarray:ndim = 0` field metadata. BFloat16 and complex arrays have no
+ * Arrow type. The values are always copied into the stream, which is
+ * returned as a 1D Uint8 array. Requires the `arrow` feature.
+ */
+int32_t ndarray_to_arrow_buffer(const struct NdArrayHandle *handle,
+                                const struct ArrayMetadata *meta,
+                                struct NdArrayHandle **out_handle,
+                                uint8_t *out_dtype,
+                                uintptr_t *out_ndim,
+                                uintptr_t *out_shape,
+                                uintptr_t max_ndim);
+
+/**
+ * Decode an Arrow IPC stream into an array.
+ *
+ * Accepts a single primitive column (1-D result, or 0-D when marked with
+ * `ndarray:ndim = 0`), a single fixed-size list column (`[rows, list_size]`,
+ * or `[rows, ...shape]` for `arrow.fixed_shape_tensor`), or several
+ * primitive columns of one type (`[rows, columns]`). All record batches are
+ * concatenated into a new array; nulls are rejected. Requires the `arrow`
+ * feature.
+ */
+int32_t ndarray_from_arrow_buffer(const uint8_t *bytes,
+                                  uintptr_t len,
+                                  struct NdArrayHandle **out_handle,
+                                  uint8_t *out_dtype,
+                                  uintptr_t *out_ndim,
+                                  uintptr_t *out_shape,
+                                  uintptr_t max_ndim);
+
 /**
  * Compute Cholesky decomposition of a Hermitian positive-definite matrix.
  *
@@ -1050,13 +2247,36 @@ int32_t ndarray_cond(const struct NdArrayHandle *a,
                      void *out_value,
                      uint8_t *out_dtype);
 
+/**
+ * Compute the cross product of 3-vectors along the last axis.
+ *
+ * Both operands must have a last axis of length 3; leading axes broadcast
+ * and dtypes are promoted as in `ndarray_mul`. Bool operands are rejected.
+ */
+int32_t ndarray_cross(const struct NdArrayHandle *a,
+                      const struct ArrayMetadata *a_meta,
+                      const struct NdArrayHandle *b,
+                      const struct ArrayMetadata *b_meta,
+                      struct NdArrayHandle **out_handle,
+                      uint8_t *out_dtype_ptr,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
 /**
  * Compute the determinant of a square matrix.
+ *
+ * Inputs with more than two dimensions are treated as stacks of matrices; the
+ * result holds one determinant per trailing `n x n` block and is 0-D for a
+ * single matrix.
  */
 int32_t ndarray_det(const struct NdArrayHandle *a,
                     const struct ArrayMetadata *a_meta,
-                    void *out_value,
-                    uint8_t *out_dtype);
+                    struct NdArrayHandle **out_handle,
+                    uint8_t *out_dtype,
+                    uintptr_t *out_ndim,
+                    uintptr_t *out_shape,
+                    uintptr_t max_ndim);
 
 /**
  * Extract diagonal elements with an optional offset.
@@ -1193,6 +2413,10 @@ int32_t ndarray_from_diag(const struct NdArrayHandle *handle,
 
 /**
  * Compute the inverse of a square matrix.
+ *
+ * Inputs with more than two dimensions are treated as stacks of matrices and
+ * each trailing `n x n` block is inverted. Singular matrices fail with
+ * `ERR_MATH`.
  */
 int32_t ndarray_inv(const struct NdArrayHandle *a,
                     const struct ArrayMetadata *a_meta,
@@ -1202,6 +2426,23 @@ int32_t ndarray_inv(const struct NdArrayHandle *a,
                     uintptr_t *out_shape,
                     uintptr_t max_ndim);
 
+/**
+ * Compute the Kronecker product of two arrays.
+ *
+ * Operands are promoted to a common dtype (integers stay integers); the
+ * result has `max(a.ndim, b.ndim)` dimensions, each the product of the
+ * operands' lengths along it. Bool operands are rejected.
+ */
+int32_t ndarray_kron(const struct NdArrayHandle *a,
+                     const struct ArrayMetadata *a_meta,
+                     const struct NdArrayHandle *b,
+                     const struct ArrayMetadata *b_meta,
+                     struct NdArrayHandle **out_handle,
+                     uint8_t *out_dtype_ptr,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
 /**
  * Solve a least-squares problem.
  */
@@ -1225,13 +2466,49 @@ int32_t ndarray_lstsq(const struct NdArrayHandle *a,
                       uintptr_t max_ndim);
 
 /**
- * Matrix multiplication with NumPy-style 1D/2D handling and dtype promotion.
- * When BLAS is enabled, automatically uses BLAS gemm.
+ * Compute the LU decomposition with partial pivoting: `A[perm] = L * U`.
+ *
+ * For an `m x n` input with `k = min(m, n)`, L is `m x k` with a unit
+ * diagonal, U is `k x n`, and `perm` is an Int64 array of `m` row indices.
+ */
+int32_t ndarray_lu(const struct NdArrayHandle *a,
+                   const struct ArrayMetadata *a_meta,
+                   struct NdArrayHandle **out_l,
+                   uint8_t *out_dtype_l,
+                   uintptr_t *out_ndim_l,
+                   uintptr_t *out_shape_l,
+                   uintptr_t max_ndim,
+                   struct NdArrayHandle **out_u,
+                   uint8_t *out_dtype_u,
+                   uintptr_t *out_ndim_u,
+                   uintptr_t *out_shape_u,
+                   struct NdArrayHandle **out_perm,
+                   uint8_t *out_dtype_perm,
+                   uintptr_t *out_ndim_perm,
+                   uintptr_t *out_shape_perm);
+
+/**
+ * Matrix multiplication with NumPy-style 1D/2D handling, stacked matrices
+ * (`[..., M, K] @ [..., K, N]` with broadcast batch dimensions) and dtype
+ * promotion. When BLAS is enabled, automatically uses BLAS gemm.
+ *
+ * # Arguments
+ * * `acc_dtype` / `has_acc_dtype` - Dtype the operands are converted to and
+ *   the product is accumulated in. Defaults to the promoted dtype (integers
+ *   compute in `Float64`); must be real for real inputs and complex for
+ *   complex inputs.
+ * * `result_dtype` / `has_result_dtype` - Dtype of the returned array.
+ *   Defaults to the promoted dtype, so widening the accumulator alone keeps
+ *   the input precision of the output.
  */
 int32_t ndarray_matmul(const struct NdArrayHandle *a,
                        const struct ArrayMetadata *a_meta,
                        const struct NdArrayHandle *b,
                        const struct ArrayMetadata *b_meta,
+                       uint8_t acc_dtype,
+                       bool has_acc_dtype,
+                       uint8_t result_dtype,
+                       bool has_result_dtype,
                        struct NdArrayHandle **out_handle,
                        uint8_t *out_dtype_ptr,
                        uintptr_t *out_ndim,
@@ -1257,6 +2534,22 @@ int32_t ndarray_norm_axis(const struct NdArrayHandle *handle,
                           int32_t ord,
                           struct NdArrayHandle **out_handle);
 
+/**
+ * Compute the outer product `out[i, j] = a[i] * b[j]`.
+ *
+ * Both inputs are flattened in C order, so the result is always 2D with shape
+ * `[a.size, b.size]`. Operands are promoted as in `ndarray_dot`.
+ */
+int32_t ndarray_outer(const struct NdArrayHandle *a,
+                      const struct ArrayMetadata *a_meta,
+                      const struct NdArrayHandle *b,
+                      const struct ArrayMetadata *b_meta,
+                      struct NdArrayHandle **out_handle,
+                      uint8_t *out_dtype_ptr,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
 /**
  * Compute the Moore-Penrose pseudo-inverse of a matrix.
  *
@@ -1271,6 +2564,42 @@ int32_t ndarray_pinv(const struct NdArrayHandle *a,
                      uintptr_t *out_shape,
                      uintptr_t max_ndim);
 
+/**
+ * Fit a polynomial of degree `deg` to the points `(x, y)` by least squares.
+ *
+ * `x` must be 1D of length n; `y` is either 1D of length n or 2D of shape
+ * (n, k) to fit k data sets at once. Coefficients are returned highest power
+ * first with shape (deg + 1,) or (deg + 1, k). Columns of the Vandermonde
+ * matrix are scaled to unit norm before solving to improve conditioning.
+ * Float32 inputs yield Float32; other real dtypes yield Float64.
+ */
+int32_t ndarray_polyfit(const struct NdArrayHandle *x,
+                        const struct ArrayMetadata *x_meta,
+                        const struct NdArrayHandle *y,
+                        const struct ArrayMetadata *y_meta,
+                        uintptr_t deg,
+                        struct NdArrayHandle **out_handle,
+                        uint8_t *out_dtype,
+                        uintptr_t *out_ndim,
+                        uintptr_t *out_shape,
+                        uintptr_t max_ndim);
+
+/**
+ * Evaluate the polynomial with coefficients `p` (highest power first) at each element of `x`.
+ *
+ * `p` must be 1D; the result has the shape of `x`. An empty `p` evaluates to
+ * zero everywhere. Float32 inputs yield Float32; other real dtypes yield Float64.
+ */
+int32_t ndarray_polyval(const struct NdArrayHandle *p,
+                        const struct ArrayMetadata *p_meta,
+                        const struct NdArrayHandle *x,
+                        const struct ArrayMetadata *x_meta,
+                        struct NdArrayHandle **out_handle,
+                        uint8_t *out_dtype,
+                        uintptr_t *out_ndim,
+                        uintptr_t *out_shape,
+                        uintptr_t max_ndim);
+
 /**
  * Compute QR decomposition: A = Q * R.
  */
@@ -1297,6 +2626,22 @@ int32_t ndarray_rank(const struct NdArrayHandle *a,
                      const double *tol,
                      int32_t *out_rank);
 
+/**
+ * Compute the roots of a polynomial via the companion-matrix eigenvalue method.
+ *
+ * `p` is a 1D array of real coefficients, highest power first. The roots are
+ * written as two 1D arrays holding their real and imaginary parts. Float32
+ * input yields Float32 outputs; other real dtypes yield Float64.
+ */
+int32_t ndarray_roots(const struct NdArrayHandle *p,
+                      const struct ArrayMetadata *p_meta,
+                      struct NdArrayHandle **out_real,
+                      struct NdArrayHandle **out_imag,
+                      uint8_t *out_dtype,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
 /**
  * Solve a linear system A * x = b.
  */
@@ -1318,6 +2663,8 @@ int32_t ndarray_solve(const struct NdArrayHandle *a,
  * * `a_meta` - Array metadata
  * * `calc_u` - If non-zero, compute U matrix
  * * `calc_vt` - If non-zero, compute V^T matrix
+ * * `full_matrices` - If non-zero, U is m x m and V^T is n x n; otherwise
+ *   they are reduced to m x k and k x n with `k = min(m, n)`
  * * `out_u` - Output U matrix handle (only written if calc_u is non-zero)
  * * `out_dtype_u` - U dtype output
  * * `out_ndim_u` - U ndim output
@@ -1339,6 +2686,7 @@ int32_t ndarray_svd(const struct NdArrayHandle *a,
                     const struct ArrayMetadata *a_meta,
                     unsigned char calc_u,
                     unsigned char calc_vt,
+                    unsigned char full_matrices,
                     struct NdArrayHandle **out_u,
                     uint8_t *out_dtype_u,
                     uintptr_t *out_ndim_u,
@@ -1353,6 +2701,26 @@ int32_t ndarray_svd(const struct NdArrayHandle *a,
                     uintptr_t *out_ndim_vt,
                     uintptr_t *out_shape_vt);
 
+/**
+ * Sum products over the given axis pairs of `a` and `b`.
+ *
+ * Axis `axes_a[i]` of `a` is contracted with axis `axes_b[i]` of `b`; negative
+ * axes count from the end. The result has the remaining axes of `a` followed
+ * by the remaining axes of `b`. Operands are promoted as in `ndarray_dot`.
+ */
+int32_t ndarray_tensordot(const struct NdArrayHandle *a,
+                          const struct ArrayMetadata *a_meta,
+                          const struct NdArrayHandle *b,
+                          const struct ArrayMetadata *b_meta,
+                          const int64_t *axes_a,
+                          const int64_t *axes_b,
+                          uintptr_t num_axes,
+                          struct NdArrayHandle **out_handle,
+                          uint8_t *out_dtype_ptr,
+                          uintptr_t *out_ndim,
+                          uintptr_t *out_shape,
+                          uintptr_t max_ndim);
+
 /**
  * Compute trace (sum of diagonal elements).
  */
@@ -1364,6 +2732,36 @@ int32_t ndarray_trace(const struct NdArrayHandle *handle,
                       uintptr_t *out_shape,
                       uintptr_t max_ndim);
 
+/**
+ * Copy of the array with elements above the k-th diagonal zeroed.
+ *
+ * Applies to the last two axes, so stacks of matrices are handled per matrix.
+ * `k = 0` keeps the main diagonal, `k > 0` also keeps that many diagonals
+ * above it and `k < 0` drops that many below it. Shape and dtype are unchanged.
+ */
+int32_t ndarray_tril(const struct NdArrayHandle *handle,
+                     const struct ArrayMetadata *meta,
+                     intptr_t k,
+                     struct NdArrayHandle **out_handle,
+                     uint8_t *out_dtype,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
+/**
+ * Copy of the array with elements below the k-th diagonal zeroed.
+ *
+ * Same conventions as `ndarray_tril`.
+ */
+int32_t ndarray_triu(const struct NdArrayHandle *handle,
+                     const struct ArrayMetadata *meta,
+                     intptr_t k,
+                     struct NdArrayHandle **out_handle,
+                     uint8_t *out_dtype,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
 /**
  * Compute the logical AND of two arrays.
  * Both arrays are converted to bool first, result is always Bool.
@@ -1769,6 +3167,100 @@ int32_t ndarray_sigmoid(const struct NdArrayHandle *a,
                         uintptr_t *out_shape,
                         uintptr_t max_ndim);
 
+/**
+ * In-place variant of [`ndarray_sigmoid`] for Float32 and Float64 arrays.
+ */
+int32_t ndarray_sigmoid_inplace(const struct NdArrayHandle *a,
+                                const struct ArrayMetadata *meta);
+
+/**
+ * Compute the rectified linear unit element-wise: max(x, 0).
+ */
+int32_t ndarray_relu(const struct NdArrayHandle *a,
+                     const struct ArrayMetadata *meta,
+                     struct NdArrayHandle **out,
+                     uint8_t *out_dtype,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
+/**
+ * In-place variant of [`ndarray_relu`] for Float32 and Float64 arrays.
+ */
+int32_t ndarray_relu_inplace(const struct NdArrayHandle *a,
+                             const struct ArrayMetadata *meta);
+
+/**
+ * Compute leaky relu element-wise: x for x >= 0, alpha * x otherwise.
+ */
+int32_t ndarray_leaky_relu(const struct NdArrayHandle *a,
+                           const struct ArrayMetadata *meta,
+                           double alpha,
+                           struct NdArrayHandle **out,
+                           uint8_t *out_dtype,
+                           uintptr_t *out_ndim,
+                           uintptr_t *out_shape,
+                           uintptr_t max_ndim);
+
+/**
+ * In-place variant of [`ndarray_leaky_relu`] for Float32 and Float64 arrays.
+ */
+int32_t ndarray_leaky_relu_inplace(const struct NdArrayHandle *a,
+                                   const struct ArrayMetadata *meta,
+                                   double alpha);
+
+/**
+ * Compute GELU element-wise using the tanh approximation:
+ * 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3))).
+ */
+int32_t ndarray_gelu(const struct NdArrayHandle *a,
+                     const struct ArrayMetadata *meta,
+                     struct NdArrayHandle **out,
+                     uint8_t *out_dtype,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
+/**
+ * In-place variant of [`ndarray_gelu`] for Float32 and Float64 arrays.
+ */
+int32_t ndarray_gelu_inplace(const struct NdArrayHandle *a,
+                             const struct ArrayMetadata *meta);
+
+/**
+ * Compute SiLU (swish) element-wise: x * sigmoid(x).
+ */
+int32_t ndarray_silu(const struct NdArrayHandle *a,
+                     const struct ArrayMetadata *meta,
+                     struct NdArrayHandle **out,
+                     uint8_t *out_dtype,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
+/**
+ * In-place variant of [`ndarray_silu`] for Float32 and Float64 arrays.
+ */
+int32_t ndarray_silu_inplace(const struct NdArrayHandle *a,
+                             const struct ArrayMetadata *meta);
+
+/**
+ * Compute softplus element-wise: ln(1 + exp(x)), without overflow.
+ */
+int32_t ndarray_softplus(const struct NdArrayHandle *a,
+                         const struct ArrayMetadata *meta,
+                         struct NdArrayHandle **out,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * In-place variant of [`ndarray_softplus`] for Float32 and Float64 arrays.
+ */
+int32_t ndarray_softplus_inplace(const struct NdArrayHandle *a,
+                                 const struct ArrayMetadata *meta);
+
 /**
  * Compute the sign number of each element.
  */
@@ -1792,6 +3284,64 @@ int32_t ndarray_softmax(const struct NdArrayHandle *handle,
                         uintptr_t *out_shape,
                         uintptr_t max_ndim);
 
+/**
+ * n-th discrete difference along `axis`.
+ *
+ * The output has the input's shape with `axis` shortened by `n` (down to zero).
+ * `n = 0` returns a copy.
+ */
+int32_t ndarray_diff(const struct NdArrayHandle *handle,
+                     const struct ArrayMetadata *meta,
+                     uintptr_t n,
+                     int32_t axis,
+                     struct NdArrayHandle **out_handle,
+                     uint8_t *out_dtype,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
+/**
+ * Gradient along `axis` with sample distance `spacing`.
+ *
+ * `edge_order` (1 or 2) selects the accuracy of the boundary differences. The
+ * axis must hold at least `edge_order + 1` samples. The output has the input's
+ * shape. Complex arrays return `ERR_DTYPE`.
+ */
+int32_t ndarray_gradient(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         int32_t axis,
+                         double spacing,
+                         uint8_t edge_order,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * Linear interpolation of the query points `x` against samples `(xp, fp)`.
+ *
+ * `xp` and `fp` must be non-empty 1D arrays of equal length, with `xp`
+ * non-decreasing. Queries outside `[xp[0], xp[-1]]` take `left`/`right` when
+ * `has_left`/`has_right` are set and `fp[0]`/`fp[-1]` otherwise. The output
+ * is Float64 with the shape of `x`. Complex operands return `ERR_DTYPE`.
+ */
+int32_t ndarray_interp(const struct NdArrayHandle *x,
+                       const struct ArrayMetadata *x_meta,
+                       const struct NdArrayHandle *xp,
+                       const struct ArrayMetadata *xp_meta,
+                       const struct NdArrayHandle *fp,
+                       const struct ArrayMetadata *fp_meta,
+                       bool has_left,
+                       double left,
+                       bool has_right,
+                       double right,
+                       struct NdArrayHandle **out_handle,
+                       uint8_t *out_dtype,
+                       uintptr_t *out_ndim,
+                       uintptr_t *out_shape,
+                       uintptr_t max_ndim);
+
 /**
  * Compute phase angle element-wise (always returns Float64).
  */
@@ -1848,6 +3398,39 @@ int32_t ndarray_isreal(const struct NdArrayHandle *a,
                        uintptr_t *out_shape,
                        uintptr_t max_ndim);
 
+/**
+ * Returns bool array: true where element is NaN.
+ */
+int32_t ndarray_isnan(const struct NdArrayHandle *a,
+                      const struct ArrayMetadata *meta,
+                      struct NdArrayHandle **out,
+                      uint8_t *out_dtype,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
+/**
+ * Returns bool array: true where element is positive or negative infinity.
+ */
+int32_t ndarray_isinf(const struct NdArrayHandle *a,
+                      const struct ArrayMetadata *meta,
+                      struct NdArrayHandle **out,
+                      uint8_t *out_dtype,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
+/**
+ * Returns bool array: true where element is neither NaN nor infinite.
+ */
+int32_t ndarray_isfinite(const struct NdArrayHandle *a,
+                         const struct ArrayMetadata *meta,
+                         struct NdArrayHandle **out,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
 /**
  * Extract real part element-wise.
  */
@@ -1867,16 +3450,64 @@ int32_t ndarray_astype(const struct NdArrayHandle *handle,
                        int32_t target_dtype,
                        struct NdArrayHandle **out);
 
+/**
+ * Cast an NDArray to a different dtype, checking that every value fits.
+ *
+ * Values the target cannot represent follow `policy` (0 = clamp, 1 = wrap,
+ * 2 = fail with `ERR_OVERFLOW`) and are counted in `out_count`. NaN and
+ * infinities are out of range for integer targets, as are finite values
+ * that overflow a narrower float. Under `Error` the message gives the
+ * number of offending elements and the first of them.
+ */
+int32_t ndarray_astype_checked(const struct NdArrayHandle *handle,
+                               const struct ArrayMetadata *meta,
+                               int32_t target_dtype,
+                               int32_t policy,
+                               struct NdArrayHandle **out,
+                               uintptr_t *out_count);
+
+/**
+ * Report which linear algebra backends are active.
+ *
+ * `out_blas` is set to 1 when matrix products (`matmul`, `dot`) dispatch to
+ * BLAS gemm, i.e. the library was built with the `blas` feature, and to 0
+ * when they run on the pure-Rust `matrixmultiply` kernels. The LAPACK
+ * provider used by the decompositions is written to `buf` as a
+ * NUL-terminated string, truncated to `len` bytes.
+ */
+int32_t ndarray_backend_info(uint8_t *out_blas, char *buf, uintptr_t len);
+
+/**
+ * Reverse the byte order of every element, returning a new array.
+ *
+ * Single-byte dtypes (Int8, Uint8, Bool) are copied unchanged.
+ */
+int32_t ndarray_byteswap(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         struct NdArrayHandle **out,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
 /**
  * Clamp array values to [min, max] range.
  *
- * Similar to NumPy's clip function.
- * Returns error if min > max.
+ * Similar to NumPy's clip function. The result keeps the array dtype and
+ * integer arrays are compared as integers, so bounds beyond 2^53 are exact.
+ * Bounds outside the dtype's range saturate to its limits.
+ * Returns error if min > max or either bound is NaN.
+ *
+ * # Arguments
+ * * `min_val` / `min_dtype` - Lower bound scalar and its dtype
+ * * `max_val` / `max_dtype` - Upper bound scalar and its dtype
  */
 int32_t ndarray_clamp(const struct NdArrayHandle *handle,
                       const struct ArrayMetadata *meta,
-                      double min_val,
-                      double max_val,
+                      const void *min_val,
+                      uint8_t min_dtype,
+                      const void *max_val,
+                      uint8_t max_dtype,
                       struct NdArrayHandle **out_handle,
                       uint8_t *out_dtype,
                       uintptr_t *out_ndim,
@@ -1889,7 +3520,103 @@ int32_t ndarray_clamp(const struct NdArrayHandle *handle,
  * Writes the message to `buf` up to `len` bytes.
  * Returns the actual length of the message.
  */
-uintptr_t ndarray_get_last_error(char *buf, uintptr_t len);
+uintptr_t ndarray_get_last_error(char *buf, uintptr_t len);
+
+/**
+ * Get the last error as a structured record.
+ *
+ * Fills `out` with the code, operation name and message of the last error
+ * on this thread. With no error recorded, `code` is `SUCCESS` and both
+ * strings are empty.
+ */
+int32_t ndarray_get_last_error_struct(struct NdArrayError *out);
+
+/**
+ * Compute a 64-bit XXH3 hash of a view's dtype, shape and element data.
+ *
+ * Elements are visited in C order, so equal views hash equally regardless
+ * of their strides or offset. Element bytes are hashed in little-endian
+ * order, so the digest is the same on every platform.
+ *
+ * # Arguments
+ * * `handle` - Array handle
+ * * `meta` - View metadata
+ * * `seed` - Hash seed
+ * * `out_hash` - Output: 64-bit digest
+ */
+int32_t ndarray_hash(const struct NdArrayHandle *handle,
+                     const struct ArrayMetadata *meta,
+                     uint64_t seed,
+                     uint64_t *out_hash);
+
+/**
+ * Quantize a real array to Int8 or Uint8 (`target_dtype`).
+ *
+ * `scale` holds one value (per-tensor) or one per index along `axis`
+ * (per-channel); `zero_point` may be null (all zeros) or an integer array of
+ * the same length. Values are divided by the scale, rounded half-to-even,
+ * shifted by the zero point and saturated to the target range. NaN maps to
+ * the zero point.
+ */
+int32_t ndarray_quantize(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         const struct NdArrayHandle *scale_handle,
+                         const struct ArrayMetadata *scale_meta,
+                         const struct NdArrayHandle *zp_handle,
+                         const struct ArrayMetadata *zp_meta,
+                         int32_t axis,
+                         uint8_t target_dtype,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * Dequantize an Int8 or Uint8 array to Float32: `(q - zero_point) * scale`.
+ *
+ * Parameters follow the same per-tensor / per-channel rules as
+ * [`ndarray_quantize`].
+ */
+int32_t ndarray_dequantize(const struct NdArrayHandle *handle,
+                           const struct ArrayMetadata *meta,
+                           const struct NdArrayHandle *scale_handle,
+                           const struct ArrayMetadata *scale_meta,
+                           const struct NdArrayHandle *zp_handle,
+                           const struct ArrayMetadata *zp_meta,
+                           int32_t axis,
+                           struct NdArrayHandle **out_handle,
+                           uint8_t *out_dtype,
+                           uintptr_t *out_ndim,
+                           uintptr_t *out_shape,
+                           uintptr_t max_ndim);
+
+/**
+ * Set the number of threads used by parallel kernels.
+ *
+ * Zero restores the default of one thread per core. The pool is rebuilt, so
+ * this may be called any number of times. Without the `parallel` feature the
+ * call succeeds and has no effect.
+ */
+int32_t ndarray_set_num_threads(uintptr_t num_threads);
+
+/**
+ * Return the number of threads used by parallel kernels (1 without the
+ * `parallel` feature).
+ */
+uintptr_t ndarray_get_num_threads(void);
+
+/**
+ * Set the element count at which kernels switch to the thread pool.
+ *
+ * Zero restores the default.
+ */
+int32_t ndarray_set_parallel_threshold(uintptr_t threshold);
+
+/**
+ * Return the element count at which kernels switch to the thread pool.
+ */
+uintptr_t ndarray_get_parallel_threshold(void);
 
 /**
  * Format an array into a string buffer.
@@ -1902,6 +3629,87 @@ uintptr_t ndarray_to_string(const struct NdArrayHandle *handle,
                             uintptr_t edgeitems,
                             uintptr_t precision);
 
+/**
+ * Divide each lane along `axis` by its L2 norm: `x / max(||x||, epsilon)`.
+ *
+ * `epsilon` keeps zero (or near-zero) lanes finite: an all-zero lane stays
+ * zero. Float32 input yields Float32; other real dtypes yield Float64.
+ */
+int32_t ndarray_l2_normalize(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             int32_t axis,
+                             double epsilon,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Scale each lane along `axis` linearly so its range maps to `[feature_min, feature_max]`.
+ *
+ * Per lane, `scale = (feature_max - feature_min) / (data_max - data_min)` and
+ * `y = (x - data_min) * scale + feature_min`. NaN values are ignored when
+ * finding the range and stay NaN. Constant lanes use a data range of 1, so
+ * they map to `feature_min`.
+ *
+ * When `out_data_min` / `out_scale` are non-null they receive the per-lane
+ * `data_min` and `scale` (input shape with `axis` removed, same dtype as the
+ * result), so `x = (y - feature_min) / scale + data_min` inverts the transform.
+ * Float32 input yields Float32; other real dtypes yield Float64.
+ */
+int32_t ndarray_minmax_scale(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             int32_t axis,
+                             double feature_min,
+                             double feature_max,
+                             struct NdArrayHandle **out_data_min,
+                             struct NdArrayHandle **out_scale,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Standardize along `axis`: `(x - mean) / (std + epsilon)`.
+ *
+ * `mean_handle` / `std_handle` may be null, in which case the statistic is
+ * computed per lane (standard deviation with `ddof` delta degrees of freedom).
+ * Supplied statistics must have the input shape with `axis` removed, or kept
+ * as length 1, or be a single value. Float32 input yields Float32; other real
+ * dtypes yield Float64.
+ */
+int32_t ndarray_standardize(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            const struct NdArrayHandle *mean_handle,
+                            const struct ArrayMetadata *mean_meta,
+                            const struct NdArrayHandle *std_handle,
+                            const struct ArrayMetadata *std_meta,
+                            uintptr_t ddof,
+                            double epsilon,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * In-place variant of [`ndarray_standardize`], overwriting the view.
+ *
+ * Only Float32 and Float64 arrays can be standardized in place.
+ */
+int32_t ndarray_standardize_inplace(const struct NdArrayHandle *handle,
+                                    const struct ArrayMetadata *meta,
+                                    int32_t axis,
+                                    const struct NdArrayHandle *mean_handle,
+                                    const struct ArrayMetadata *mean_meta,
+                                    const struct NdArrayHandle *std_handle,
+                                    const struct ArrayMetadata *std_meta,
+                                    uintptr_t ddof,
+                                    double epsilon);
+
 /**
  * Compute whether all elements are truthy (scalar).
  */
@@ -1959,7 +3767,7 @@ int32_t ndarray_argmax_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the index of the maximum element.
- * Returns Int64 index.
+ * Returns Int64 index; a zero-size array is rejected.
  */
 int32_t ndarray_argmax(const struct NdArrayHandle *handle,
                        const struct ArrayMetadata *meta,
@@ -1981,7 +3789,7 @@ int32_t ndarray_argmin_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the index of the minimum element.
- * Returns Int64 index.
+ * Returns Int64 index; a zero-size array is rejected.
  */
 int32_t ndarray_argmin(const struct NdArrayHandle *handle,
                        const struct ArrayMetadata *meta,
@@ -2000,6 +3808,29 @@ int32_t ndarray_bincount(const struct NdArrayHandle *handle,
                          uintptr_t *out_shape,
                          uintptr_t max_ndim);
 
+/**
+ * Count the non-zero elements of the array (scalar Int64).
+ *
+ * NaN counts as non-zero.
+ */
+int32_t ndarray_count_nonzero(const struct NdArrayHandle *handle,
+                              const struct ArrayMetadata *meta,
+                              void *out_value,
+                              uint8_t *out_dtype);
+
+/**
+ * Count the non-zero elements along an axis (Int64 result).
+ */
+int32_t ndarray_count_nonzero_axis(const struct NdArrayHandle *handle,
+                                   const struct ArrayMetadata *meta,
+                                   int32_t axis,
+                                   bool keepdims,
+                                   struct NdArrayHandle **out_handle,
+                                   uint8_t *out_dtype,
+                                   uintptr_t *out_ndim,
+                                   uintptr_t *out_shape,
+                                   uintptr_t max_ndim);
+
 /**
  * Cumulative product over flattened array. Returns 1D array.
  */
@@ -2046,8 +3877,153 @@ int32_t ndarray_cumsum_axis(const struct NdArrayHandle *handle,
                             uintptr_t *out_shape,
                             uintptr_t max_ndim);
 
+/**
+ * Enable or disable deterministic float reductions for the whole process.
+ *
+ * When enabled, sum/mean/var/std visit elements in logical C order with a
+ * reduction tree fixed by the element count, so repeated runs on equal data
+ * produce bit-identical results regardless of memory layout or threading.
+ */
+int32_t ndarray_set_deterministic(bool enabled);
+
+/**
+ * Return whether deterministic float reductions are enabled.
+ */
+bool ndarray_is_deterministic(void);
+
+/**
+ * Group `values` rows by `keys` and compute count, sum, mean, min and max per group.
+ *
+ * `keys` is a 1D array of any real or Bool dtype; `values` must have one row per
+ * key along axis 0. Groups are returned in ascending key order, with NaN keys
+ * last. `out_keys` has the keys' dtype and `out_counts` is Int64, both of
+ * shape `(groups,)`. The aggregates have shape `(groups, ...)` and are Float32
+ * for Float32 values, Float64 otherwise.
+ */
+int32_t ndarray_group_by(const struct NdArrayHandle *keys_handle,
+                         const struct ArrayMetadata *keys_meta,
+                         const struct NdArrayHandle *values_handle,
+                         const struct ArrayMetadata *values_meta,
+                         struct NdArrayHandle **out_keys,
+                         struct NdArrayHandle **out_counts,
+                         struct NdArrayHandle **out_sum,
+                         struct NdArrayHandle **out_mean,
+                         struct NdArrayHandle **out_min,
+                         struct NdArrayHandle **out_max,
+                         uintptr_t *out_num_groups);
+
+/**
+ * Compute the histogram of a flattened array.
+ *
+ * When `edges` is non-null its values are used as bin edges and `bins` and
+ * `range` are ignored. Otherwise `bins` equal-width bins span `range`
+ * (`[min, max]`, or the data extent when `range` is null).
+ *
+ * `weights` is optional and must have as many elements as the input; when
+ * given, `out_counts` holds Float64 weight sums instead of Int64 counts.
+ * `out_counts` has shape `[nbins]` and `out_edges` has shape `[nbins + 1]`.
+ */
+int32_t ndarray_histogram(const struct NdArrayHandle *handle,
+                          const struct ArrayMetadata *meta,
+                          uintptr_t bins,
+                          const double *range,
+                          const struct NdArrayHandle *edges,
+                          const struct ArrayMetadata *edges_meta,
+                          const struct NdArrayHandle *weights,
+                          const struct ArrayMetadata *weights_meta,
+                          struct NdArrayHandle **out_counts,
+                          struct NdArrayHandle **out_edges);
+
+/**
+ * Compute the two-dimensional histogram of paired samples `x` and `y`.
+ *
+ * `x` and `y` are flattened and must have the same number of elements.
+ * Each dimension is binned like [`ndarray_histogram`]: explicit edges when
+ * `x_edges` / `y_edges` is non-null, otherwise `bins_x` / `bins_y`
+ * equal-width bins over `range` (`[xmin, xmax, ymin, ymax]`, or the data
+ * extent when null). `out_counts` has shape `[nbins_x, nbins_y]`.
+ */
+int32_t ndarray_histogram2d(const struct NdArrayHandle *x,
+                            const struct ArrayMetadata *x_meta,
+                            const struct NdArrayHandle *y,
+                            const struct ArrayMetadata *y_meta,
+                            uintptr_t bins_x,
+                            uintptr_t bins_y,
+                            const double *range,
+                            const struct NdArrayHandle *x_edges,
+                            const struct ArrayMetadata *x_edges_meta,
+                            const struct NdArrayHandle *y_edges,
+                            const struct ArrayMetadata *y_edges_meta,
+                            const struct NdArrayHandle *weights,
+                            const struct ArrayMetadata *weights_meta,
+                            struct NdArrayHandle **out_counts,
+                            struct NdArrayHandle **out_x_edges,
+                            struct NdArrayHandle **out_y_edges);
+
+/**
+ * Compute `ln(sum(exp(x)))` over all elements without overflow.
+ */
+int32_t ndarray_logsumexp(const struct NdArrayHandle *handle,
+                          const struct ArrayMetadata *meta,
+                          void *out_value,
+                          uint8_t *out_dtype);
+
+/**
+ * Compute `ln(sum(exp(x)))` along an axis without overflow.
+ */
+int32_t ndarray_logsumexp_axis(const struct NdArrayHandle *handle,
+                               const struct ArrayMetadata *meta,
+                               int32_t axis,
+                               bool keepdims,
+                               struct NdArrayHandle **out_handle,
+                               uint8_t *out_dtype,
+                               uintptr_t *out_ndim,
+                               uintptr_t *out_shape,
+                               uintptr_t max_ndim);
+
+/**
+ * Integrate along `axis` with the trapezoidal rule.
+ *
+ * `x_handle`/`x_meta` may be null to use uniform spacing `dx`; otherwise `x`
+ * is 1D with the length of `axis` or has the shape of the input. The output
+ * drops `axis` (a 0-d array for 1D input). Complex arrays return `ERR_DTYPE`.
+ */
+int32_t ndarray_trapz(const struct NdArrayHandle *handle,
+                      const struct ArrayMetadata *meta,
+                      const struct NdArrayHandle *x_handle,
+                      const struct ArrayMetadata *x_meta,
+                      double dx,
+                      int32_t axis,
+                      struct NdArrayHandle **out_handle,
+                      uint8_t *out_dtype,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
+/**
+ * Cumulative trapezoidal integral along `axis`.
+ *
+ * Spacing is given as in [`ndarray_trapz`]. The output keeps the input's
+ * shape with `axis` shortened by one, or at full length with a leading zero
+ * when `include_initial` is set.
+ */
+int32_t ndarray_cumtrapz(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         const struct NdArrayHandle *x_handle,
+                         const struct ArrayMetadata *x_meta,
+                         double dx,
+                         int32_t axis,
+                         bool include_initial,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
 /**
  * Compute the maximum of all elements in the array.
+ *
+ * A zero-size array has no maximum and is rejected.
  */
 int32_t ndarray_max(const struct NdArrayHandle *handle,
                     const struct ArrayMetadata *meta,
@@ -2072,19 +4048,28 @@ int32_t ndarray_max_axis(const struct NdArrayHandle *handle,
  *
  * Scalar output dtype matches the computation: `Float32` / `Complex64` / `Complex128` preserve
  * native precision; integer inputs promote to `Float64` (NumPy-style); other reals use `Float64`.
+ * The mean of a zero-size array is NaN.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_mean(const struct NdArrayHandle *handle,
                      const struct ArrayMetadata *meta,
+                     int32_t mode,
                      void *out_value,
                      uint8_t *out_dtype);
 
 /**
  * Compute the mean along an axis.
+ *
+ * Lanes of length zero (reducing over an empty axis) produce NaN.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_mean_axis(const struct NdArrayHandle *handle,
                           const struct ArrayMetadata *meta,
                           int32_t axis,
                           bool keepdims,
+                          int32_t mode,
                           struct NdArrayHandle **out_handle,
                           uint8_t *out_dtype,
                           uintptr_t *out_ndim,
@@ -2093,6 +4078,8 @@ int32_t ndarray_mean_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the minimum of all elements in the array.
+ *
+ * A zero-size array has no minimum and is rejected.
  */
 int32_t ndarray_min(const struct NdArrayHandle *handle,
                     const struct ArrayMetadata *meta,
@@ -2112,6 +4099,123 @@ int32_t ndarray_min_axis(const struct NdArrayHandle *handle,
                          uintptr_t *out_shape,
                          uintptr_t max_ndim);
 
+/**
+ * Sum all elements, treating NaN as zero.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
+ */
+int32_t ndarray_nansum(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       int32_t mode,
+                       void *out_value,
+                       uint8_t *out_dtype);
+
+/**
+ * Sum along an axis, treating NaN as zero.
+ */
+int32_t ndarray_nansum_axis(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            bool keepdims,
+                            int32_t mode,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Mean of all non-NaN elements (NaN when there are none).
+ */
+int32_t ndarray_nanmean(const struct NdArrayHandle *handle,
+                        const struct ArrayMetadata *meta,
+                        int32_t mode,
+                        void *out_value,
+                        uint8_t *out_dtype);
+
+/**
+ * Mean of the non-NaN elements along an axis.
+ */
+int32_t ndarray_nanmean_axis(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             int32_t axis,
+                             bool keepdims,
+                             int32_t mode,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Minimum of all non-NaN elements (NaN when there are none).
+ */
+int32_t ndarray_nanmin(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       void *out_value,
+                       uint8_t *out_dtype);
+
+/**
+ * Minimum of the non-NaN elements along an axis.
+ */
+int32_t ndarray_nanmin_axis(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            bool keepdims,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Maximum of all non-NaN elements (NaN when there are none).
+ */
+int32_t ndarray_nanmax(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       void *out_value,
+                       uint8_t *out_dtype);
+
+/**
+ * Maximum of the non-NaN elements along an axis.
+ */
+int32_t ndarray_nanmax_axis(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            bool keepdims,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Standard deviation of all non-NaN elements.
+ *
+ * Reduces to NaN when no more than `ddof` non-NaN values remain.
+ */
+int32_t ndarray_nanstd(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       double ddof,
+                       int32_t mode,
+                       void *out_value,
+                       uint8_t *out_dtype);
+
+/**
+ * Standard deviation of the non-NaN elements along an axis.
+ */
+int32_t ndarray_nanstd_axis(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            bool keepdims,
+                            double ddof,
+                            int32_t mode,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
 /**
  * Compute the product of all elements in the array.
  */
@@ -2133,23 +4237,163 @@ int32_t ndarray_product_axis(const struct NdArrayHandle *handle,
                              uintptr_t *out_shape,
                              uintptr_t max_ndim);
 
+/**
+ * Median of all elements.
+ */
+int32_t ndarray_median(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       void *out_value,
+                       uint8_t *out_dtype);
+
+/**
+ * Median along an axis.
+ */
+int32_t ndarray_median_axis(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            bool keepdims,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Quantile `q` (in `[0, 1]`) of all elements.
+ *
+ * `method`: 0 = linear, 1 = lower, 2 = higher, 3 = nearest, 4 = midpoint.
+ */
+int32_t ndarray_quantile(const struct NdArrayHandle *handle,
+                         const struct ArrayMetadata *meta,
+                         double q,
+                         int32_t method,
+                         void *out_value,
+                         uint8_t *out_dtype);
+
+/**
+ * Quantile `q` (in `[0, 1]`) along an axis.
+ */
+int32_t ndarray_quantile_axis(const struct NdArrayHandle *handle,
+                              const struct ArrayMetadata *meta,
+                              int32_t axis,
+                              bool keepdims,
+                              double q,
+                              int32_t method,
+                              struct NdArrayHandle **out_handle,
+                              uint8_t *out_dtype,
+                              uintptr_t *out_ndim,
+                              uintptr_t *out_shape,
+                              uintptr_t max_ndim);
+
+/**
+ * Percentile `p` (in `[0, 100]`) of all elements.
+ */
+int32_t ndarray_percentile(const struct NdArrayHandle *handle,
+                           const struct ArrayMetadata *meta,
+                           double p,
+                           int32_t method,
+                           void *out_value,
+                           uint8_t *out_dtype);
+
+/**
+ * Percentile `p` (in `[0, 100]`) along an axis.
+ */
+int32_t ndarray_percentile_axis(const struct NdArrayHandle *handle,
+                                const struct ArrayMetadata *meta,
+                                int32_t axis,
+                                bool keepdims,
+                                double p,
+                                int32_t method,
+                                struct NdArrayHandle **out_handle,
+                                uint8_t *out_dtype,
+                                uintptr_t *out_ndim,
+                                uintptr_t *out_shape,
+                                uintptr_t max_ndim);
+
+/**
+ * Sum rows along axis 0 grouped by `segment_ids`.
+ *
+ * `segment_ids` is a 1D integer array with one id per row. The output has shape
+ * `(num_segments, ...)`; a negative `num_segments` uses `max(segment_ids) + 1`.
+ * Ids outside `[0, num_segments)` return ERR_INDEX. Empty segments sum to 0.
+ * Float32 input yields Float32; all other real dtypes yield Float64.
+ */
+int32_t ndarray_segment_sum(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            const struct NdArrayHandle *ids_handle,
+                            const struct ArrayMetadata *ids_meta,
+                            int64_t num_segments,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Mean of rows per segment. Empty segments are NaN. See [`ndarray_segment_sum`].
+ */
+int32_t ndarray_segment_mean(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             const struct NdArrayHandle *ids_handle,
+                             const struct ArrayMetadata *ids_meta,
+                             int64_t num_segments,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Minimum of rows per segment. Empty segments are NaN. See [`ndarray_segment_sum`].
+ */
+int32_t ndarray_segment_min(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            const struct NdArrayHandle *ids_handle,
+                            const struct ArrayMetadata *ids_meta,
+                            int64_t num_segments,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Maximum of rows per segment. Empty segments are NaN. See [`ndarray_segment_sum`].
+ */
+int32_t ndarray_segment_max(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            const struct NdArrayHandle *ids_handle,
+                            const struct ArrayMetadata *ids_meta,
+                            int64_t num_segments,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
 /**
  * Compute the standard deviation of all elements in the array.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_std(const struct NdArrayHandle *handle,
                     const struct ArrayMetadata *meta,
                     double ddof,
+                    int32_t mode,
                     void *out_value,
                     uint8_t *out_dtype);
 
 /**
  * Compute the standard deviation along an axis in the array.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_std_axis(const struct NdArrayHandle *handle,
                          const struct ArrayMetadata *meta,
                          int32_t axis,
                          bool keepdims,
                          double ddof,
+                         int32_t mode,
                          struct NdArrayHandle **out_handle,
                          uint8_t *out_dtype,
                          uintptr_t *out_ndim,
@@ -2158,19 +4402,25 @@ int32_t ndarray_std_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the sum of all elements in the array.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_sum(const struct NdArrayHandle *handle,
                     const struct ArrayMetadata *meta,
+                    int32_t mode,
                     void *out_value,
                     uint8_t *out_dtype);
 
 /**
  * Compute the sum along an axis.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_sum_axis(const struct NdArrayHandle *handle,
                          const struct ArrayMetadata *meta,
                          int32_t axis,
                          bool keepdims,
+                         int32_t mode,
                          struct NdArrayHandle **out_handle,
                          uint8_t *out_dtype,
                          uintptr_t *out_ndim,
@@ -2179,27 +4429,139 @@ int32_t ndarray_sum_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the variance of all elements in the array.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_var(const struct NdArrayHandle *handle,
                     const struct ArrayMetadata *meta,
                     double ddof,
+                    int32_t mode,
                     void *out_value,
                     uint8_t *out_dtype);
 
 /**
  * Compute the variance along an axis in the array.
+ *
+ * `mode` selects the float summation strategy (see [`SumMode`]).
  */
 int32_t ndarray_var_axis(const struct NdArrayHandle *handle,
                          const struct ArrayMetadata *meta,
                          int32_t axis,
                          bool keepdims,
                          double ddof,
+                         int32_t mode,
                          struct NdArrayHandle **out_handle,
                          uint8_t *out_dtype,
                          uintptr_t *out_ndim,
                          uintptr_t *out_shape,
                          uintptr_t max_ndim);
 
+/**
+ * Find the sorted unique values of the flattened array.
+ *
+ * `out_values` keeps the input dtype and has shape `[out_len]`. When
+ * non-null, `out_inverse` receives Int64 indices into the unique values with
+ * the input's shape (so `values[inverse]` rebuilds the input), and
+ * `out_counts` receives the Int64 number of occurrences of each unique
+ * value. NaN sorts last and all NaNs collapse into one entry. Complex arrays
+ * are not supported.
+ */
+int32_t ndarray_unique(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       struct NdArrayHandle **out_values,
+                       struct NdArrayHandle **out_inverse,
+                       struct NdArrayHandle **out_counts,
+                       uintptr_t *out_len);
+
+/**
+ * Find the unique slices of the array along `axis`.
+ *
+ * Slices are compared element by element in C order and returned sorted.
+ * `out_values` keeps the input dtype and shape except that `axis` has length
+ * `out_len`. The optional outputs are Int64: `out_index` holds the position
+ * of each unique slice's first occurrence, `out_inverse` maps every position
+ * along `axis` to its unique slice, and `out_counts` holds occurrences per
+ * unique slice. NaN sorts last and NaNs compare equal, so slices with NaN in
+ * the same positions are merged. Complex arrays are not supported.
+ */
+int32_t ndarray_unique_axis(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            struct NdArrayHandle **out_values,
+                            struct NdArrayHandle **out_index,
+                            struct NdArrayHandle **out_inverse,
+                            struct NdArrayHandle **out_counts,
+                            uintptr_t *out_len);
+
+/**
+ * Sorted unique values present in both flattened arrays.
+ *
+ * Operands are promoted to a common dtype. With `assume_unique` both inputs
+ * are taken to be free of duplicates, which skips deduplication.
+ */
+int32_t ndarray_intersect1d(const struct NdArrayHandle *a,
+                            const struct ArrayMetadata *a_meta,
+                            const struct NdArrayHandle *b,
+                            const struct ArrayMetadata *b_meta,
+                            bool assume_unique,
+                            struct NdArrayHandle **out,
+                            uint8_t *out_dtype_ptr,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Sorted unique values present in either flattened array.
+ *
+ * Operands are promoted to a common dtype.
+ */
+int32_t ndarray_union1d(const struct NdArrayHandle *a,
+                        const struct ArrayMetadata *a_meta,
+                        const struct NdArrayHandle *b,
+                        const struct ArrayMetadata *b_meta,
+                        struct NdArrayHandle **out,
+                        uint8_t *out_dtype_ptr,
+                        uintptr_t *out_ndim,
+                        uintptr_t *out_shape,
+                        uintptr_t max_ndim);
+
+/**
+ * Unique values of flattened `a` that are not in `b`.
+ *
+ * Operands are promoted to a common dtype. The result is sorted unless
+ * `assume_unique` is set, in which case `a` is taken to be free of
+ * duplicates and its order is kept.
+ */
+int32_t ndarray_setdiff1d(const struct NdArrayHandle *a,
+                          const struct ArrayMetadata *a_meta,
+                          const struct NdArrayHandle *b,
+                          const struct ArrayMetadata *b_meta,
+                          bool assume_unique,
+                          struct NdArrayHandle **out,
+                          uint8_t *out_dtype_ptr,
+                          uintptr_t *out_ndim,
+                          uintptr_t *out_shape,
+                          uintptr_t max_ndim);
+
+/**
+ * Test whether each element of `a` occurs in `b`.
+ *
+ * Returns a Bool array with `a`'s shape; `b` is flattened. Operands are
+ * compared after promotion to a common dtype, and NaN never matches.
+ * `assume_unique` skips deduplicating `b`, and `invert` negates the result.
+ */
+int32_t ndarray_isin(const struct NdArrayHandle *a,
+                     const struct ArrayMetadata *a_meta,
+                     const struct NdArrayHandle *b,
+                     const struct ArrayMetadata *b_meta,
+                     bool assume_unique,
+                     bool invert,
+                     struct NdArrayHandle **out,
+                     uint8_t *out_dtype_ptr,
+                     uintptr_t *out_ndim,
+                     uintptr_t *out_shape,
+                     uintptr_t max_ndim);
+
 /**
  * Flatten array to 1D.
  */
@@ -2261,12 +4623,12 @@ int32_t ndarray_pad(const struct NdArrayHandle *handle,
 /**
  * Permute axes of the array and return in standard layout.
  *
- * The axes array specifies the new order of axes.
- * Panics if axes are invalid (out of bounds, missing, or duplicated).
+ * The axes array specifies the new order of axes; negative axes count
+ * from the end. Returns ERR_SHAPE if axes are out of bounds, missing, or duplicated.
  */
 int32_t ndarray_permute(const struct NdArrayHandle *handle,
                         const struct ArrayMetadata *meta,
-                        const uintptr_t *axes,
+                        const int64_t *axes,
                         uintptr_t num_axes,
                         struct NdArrayHandle **out_handle,
                         uint8_t *out_dtype,
@@ -2276,12 +4638,16 @@ int32_t ndarray_permute(const struct NdArrayHandle *handle,
 
 /**
  * Repeat elements of an array.
+ *
+ * When `has_axis` is false the input is flattened first; otherwise `axis`
+ * may be negative (-1 = last axis).
  */
 int32_t ndarray_repeat(const struct NdArrayHandle *handle,
                        const struct ArrayMetadata *meta,
                        const uintptr_t *repeats,
                        uintptr_t repeats_len,
                        int32_t axis,
+                       bool has_axis,
                        struct NdArrayHandle **out_handle,
                        uint8_t *out_dtype,
                        uintptr_t *out_ndim,
@@ -2290,6 +4656,9 @@ int32_t ndarray_repeat(const struct NdArrayHandle *handle,
 
 /**
  * Reshape array to new shape.
+ *
+ * `order` is 0 for C (row-major) or 1 for Fortran (column-major). The new
+ * array is stored in that order: C-contiguous for 0, F-contiguous for 1.
  */
 int32_t ndarray_reshape(const struct NdArrayHandle *handle,
                         const struct ArrayMetadata *meta,
@@ -2325,13 +4694,160 @@ int32_t ndarray_transpose(const struct NdArrayHandle *handle,
                           uintptr_t *out_shape,
                           uintptr_t max_ndim);
 
+/**
+ * Discrete linear convolution of two 1D arrays.
+ *
+ * `mode`: 0 = full (length `n + m - 1`), 1 = same (length `max(n, m)`),
+ * 2 = valid (length `max(n, m) - min(n, m) + 1`), as in NumPy.
+ */
+int32_t ndarray_convolve(const struct NdArrayHandle *a,
+                         const struct ArrayMetadata *a_meta,
+                         const struct NdArrayHandle *v,
+                         const struct ArrayMetadata *v_meta,
+                         int32_t mode,
+                         struct NdArrayHandle **out_handle,
+                         uint8_t *out_dtype,
+                         uintptr_t *out_ndim,
+                         uintptr_t *out_shape,
+                         uintptr_t max_ndim);
+
+/**
+ * 2D convolution of `a` with `kernel` (the kernel is flipped on both axes).
+ *
+ * The input is zero-padded by `pad_h` rows and `pad_w` columns on each side
+ * on top of the padding implied by `mode` (0 = full, 1 = same, 2 = valid),
+ * then every `stride_h`-th row and `stride_w`-th column of the output is kept.
+ */
+int32_t ndarray_convolve2d(const struct NdArrayHandle *a,
+                           const struct ArrayMetadata *a_meta,
+                           const struct NdArrayHandle *kernel,
+                           const struct ArrayMetadata *kernel_meta,
+                           int32_t mode,
+                           uintptr_t stride_h,
+                           uintptr_t stride_w,
+                           uintptr_t pad_h,
+                           uintptr_t pad_w,
+                           struct NdArrayHandle **out_handle,
+                           uint8_t *out_dtype,
+                           uintptr_t *out_ndim,
+                           uintptr_t *out_shape,
+                           uintptr_t max_ndim);
+
+/**
+ * 2D cross-correlation of `a` with `kernel` (no kernel flip).
+ *
+ * Mode, stride and padding behave as in [`ndarray_convolve2d`]; valid mode
+ * with padding and stride is the usual CNN convolution layer.
+ */
+int32_t ndarray_correlate2d(const struct NdArrayHandle *a,
+                            const struct ArrayMetadata *a_meta,
+                            const struct NdArrayHandle *kernel,
+                            const struct ArrayMetadata *kernel_meta,
+                            int32_t mode,
+                            uintptr_t stride_h,
+                            uintptr_t stride_w,
+                            uintptr_t pad_h,
+                            uintptr_t pad_w,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Rolling sum over `window` elements along `axis`.
+ *
+ * Positions whose window holds fewer than `min_periods` non-NaN values are NaN.
+ * With `center`, each window is centred on its output position instead of ending there.
+ * Float32 input yields Float32; all other real dtypes yield Float64.
+ */
+int32_t ndarray_rolling_sum(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            uintptr_t window,
+                            int32_t axis,
+                            uintptr_t min_periods,
+                            bool center,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Rolling mean over `window` elements along `axis`. See [`ndarray_rolling_sum`].
+ */
+int32_t ndarray_rolling_mean(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             uintptr_t window,
+                             int32_t axis,
+                             uintptr_t min_periods,
+                             bool center,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Rolling minimum over `window` elements along `axis`. See [`ndarray_rolling_sum`].
+ */
+int32_t ndarray_rolling_min(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            uintptr_t window,
+                            int32_t axis,
+                            uintptr_t min_periods,
+                            bool center,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Rolling maximum over `window` elements along `axis`. See [`ndarray_rolling_sum`].
+ */
+int32_t ndarray_rolling_max(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            uintptr_t window,
+                            int32_t axis,
+                            uintptr_t min_periods,
+                            bool center,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Rolling standard deviation with `ddof` delta degrees of freedom. See [`ndarray_rolling_sum`].
+ *
+ * Windows with no more than `ddof` valid values are NaN.
+ */
+int32_t ndarray_rolling_std(const struct NdArrayHandle *handle,
+                            const struct ArrayMetadata *meta,
+                            uintptr_t window,
+                            int32_t axis,
+                            uintptr_t min_periods,
+                            bool center,
+                            uintptr_t ddof,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
 /**
  * Compute the argsort along an axis in the array.
+ *
+ * `descending` reverses the order; `nan_first` moves NaN to the front
+ * instead of the end, independently of direction.
  */
 int32_t ndarray_argsort_axis(const struct NdArrayHandle *handle,
                              const struct ArrayMetadata *meta,
                              int32_t axis,
                              int32_t kind,
+                             bool descending,
+                             bool nan_first,
                              struct NdArrayHandle **out_handle,
                              uint8_t *out_dtype,
                              uintptr_t *out_ndim,
@@ -2340,10 +4856,15 @@ int32_t ndarray_argsort_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the argsort of the flattened array.
+ *
+ * `descending` reverses the order; `nan_first` moves NaN to the front
+ * instead of the end, independently of direction.
  */
 int32_t ndarray_argsort_flat(const struct NdArrayHandle *handle,
                              const struct ArrayMetadata *meta,
                              int32_t kind,
+                             bool descending,
+                             bool nan_first,
                              struct NdArrayHandle **out_handle,
                              uint8_t *out_dtype,
                              uintptr_t *out_ndim,
@@ -2352,11 +4873,16 @@ int32_t ndarray_argsort_flat(const struct NdArrayHandle *handle,
 
 /**
  * Compute the sort along an axis in the array.
+ *
+ * `descending` reverses the order; `nan_first` moves NaN to the front
+ * instead of the end, independently of direction.
  */
 int32_t ndarray_sort_axis(const struct NdArrayHandle *handle,
                           const struct ArrayMetadata *meta,
                           int32_t axis,
                           int32_t kind,
+                          bool descending,
+                          bool nan_first,
                           struct NdArrayHandle **out_handle,
                           uint8_t *out_dtype,
                           uintptr_t *out_ndim,
@@ -2365,10 +4891,15 @@ int32_t ndarray_sort_axis(const struct NdArrayHandle *handle,
 
 /**
  * Compute the sort of the flattened array.
+ *
+ * `descending` reverses the order; `nan_first` moves NaN to the front
+ * instead of the end, independently of direction.
  */
 int32_t ndarray_sort_flat(const struct NdArrayHandle *handle,
                           const struct ArrayMetadata *meta,
                           int32_t kind,
+                          bool descending,
+                          bool nan_first,
                           struct NdArrayHandle **out_handle,
                           uint8_t *out_dtype,
                           uintptr_t *out_ndim,
@@ -2403,6 +4934,62 @@ int32_t ndarray_topk_flat(const struct NdArrayHandle *handle,
                           struct NdArrayHandle **out_indices,
                           uintptr_t *out_shape);
 
+/**
+ * Partition along an axis so each `kth` position holds its sorted value.
+ *
+ * Elements before a kth position compare less than or equal to it and
+ * elements after compare greater than or equal; order within each side is
+ * unspecified. NaN sorts last. Negative entries in `kth` count from the end.
+ */
+int32_t ndarray_partition(const struct NdArrayHandle *handle,
+                          const struct ArrayMetadata *meta,
+                          const int64_t *kth,
+                          uintptr_t num_kth,
+                          int32_t axis,
+                          struct NdArrayHandle **out_handle,
+                          uint8_t *out_dtype,
+                          uintptr_t *out_ndim,
+                          uintptr_t *out_shape,
+                          uintptr_t max_ndim);
+
+/**
+ * Indices that would partition the array along an axis.
+ *
+ * Same contract as `ndarray_partition`; the output is Int64 positions into
+ * each lane.
+ */
+int32_t ndarray_argpartition(const struct NdArrayHandle *handle,
+                             const struct ArrayMetadata *meta,
+                             const int64_t *kth,
+                             uintptr_t num_kth,
+                             int32_t axis,
+                             struct NdArrayHandle **out_handle,
+                             uint8_t *out_dtype,
+                             uintptr_t *out_ndim,
+                             uintptr_t *out_shape,
+                             uintptr_t max_ndim);
+
+/**
+ * Assemble N arrays laid out as nested lists, like `numpy.block`.
+ *
+ * `layout` holds the number of entries of every list in depth-first order and
+ * every array sits `depth` lists deep; e.g. `[[a, b], [c]]` is `layout = [2, 2, 1]`,
+ * `depth = 2`. Arrays with fewer than `max(depth, ndim)` dimensions get leading
+ * axes of length 1. The innermost lists join along the last axis, the next level
+ * along the one before it, and so on.
+ */
+int32_t ndarray_block(const struct NdArrayHandle *const *handles,
+                      const struct ArrayMetadata *const *metas,
+                      uintptr_t num_arrays,
+                      const uintptr_t *layout,
+                      uintptr_t layout_len,
+                      uintptr_t depth,
+                      struct NdArrayHandle **out_handle,
+                      uint8_t *out_dtype,
+                      uintptr_t *out_ndim,
+                      uintptr_t *out_shape,
+                      uintptr_t max_ndim);
+
 /**
  * Concatenate N arrays along the given axis.
  */
@@ -2411,6 +4998,7 @@ int32_t ndarray_concatenate(const struct NdArrayHandle *const *handles,
                             uintptr_t num_arrays,
                             int32_t axis,
                             struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
                             uintptr_t *out_ndim,
                             uintptr_t *out_shape,
                             uintptr_t max_ndim);
@@ -2433,6 +5021,39 @@ int32_t ndarray_split(const struct NdArrayHandle *_handle,
                       uintptr_t *out_shapes,
                       uintptr_t *out_strides);
 
+/**
+ * Split array along axis into `sections` parts of nearly equal length.
+ *
+ * The first `len % sections` parts get one extra element, like `numpy.array_split`.
+ * Writes view metadata like `ndarray_split`: out_offsets (size sections), out_shapes and
+ * out_strides (size sections*ndim each).
+ */
+int32_t ndarray_array_split(const struct NdArrayHandle *_handle,
+                            const struct ArrayMetadata *meta,
+                            int32_t axis,
+                            uintptr_t sections,
+                            uintptr_t *out_offsets,
+                            uintptr_t *out_shapes,
+                            uintptr_t *out_strides);
+
+/**
+ * Split array along axis at the given boundaries.
+ *
+ * Produces num_indices + 1 parts `[b(i-1)..b(i))` with `b(-1) = 0` and `b(n) = len`.
+ * Negative boundaries count from the end and all boundaries are clamped to the axis, so a
+ * boundary smaller than its predecessor yields an empty part. Writes view metadata like
+ * `ndarray_split`: out_offsets (size num_indices+1), out_shapes and out_strides (size
+ * (num_indices+1)*ndim each).
+ */
+int32_t ndarray_split_at(const struct NdArrayHandle *_handle,
+                         const struct ArrayMetadata *meta,
+                         int32_t axis,
+                         const int64_t *indices,
+                         uintptr_t num_indices,
+                         uintptr_t *out_offsets,
+                         uintptr_t *out_shapes,
+                         uintptr_t *out_strides);
+
 /**
  * Stack N arrays along a new axis.
  */
@@ -2441,10 +5062,99 @@ int32_t ndarray_stack(const struct NdArrayHandle *const *handles,
                       uintptr_t num_arrays,
                       int32_t axis,
                       struct NdArrayHandle **out_handle,
+                      uint8_t *out_dtype,
                       uintptr_t *out_ndim,
                       uintptr_t *out_shape,
                       uintptr_t max_ndim);
 
+/**
+ * Register `kernel` under `name` with a core `signature` such as `"(m,n),(n)->(m)"`.
+ *
+ * Dimension names are identifiers; `()` denotes a scalar operand. Exactly one
+ * output is supported and each of its dimensions must appear in an input.
+ * The id is written to `out_id`. Registering an existing name replaces its
+ * signature and kernel and keeps its id, so hosts whose callbacks do not
+ * outlive a request can re-register at the start of each one.
+ *
+ * The registry keeps `kernel` until [`ndarray_gufunc_unregister`] is called,
+ * so hosts must unregister before the callback is freed.
+ */
+int32_t ndarray_gufunc_register(const char *name,
+                                const char *signature,
+                                NdGufuncKernel kernel,
+                                uint32_t *out_id);
+
+/**
+ * Look up the id of a registered gufunc by name.
+ */
+int32_t ndarray_gufunc_find(const char *name, uint32_t *out_id);
+
+/**
+ * Remove gufunc `id` from the registry so its kernel is never called again.
+ *
+ * Later calls with this id fail and the id is not reused. Unregistering an
+ * unknown or already removed id is an error.
+ */
+int32_t ndarray_gufunc_unregister(uint32_t id);
+
+/**
+ * Invoke registered gufunc `id` on `num_inputs` arrays.
+ *
+ * Each input's trailing dimensions are matched against its core signature;
+ * the remaining leading (loop) dimensions are broadcast together. The result
+ * is Float64 with shape `loop_shape + output_core_shape`. Complex inputs are
+ * not supported.
+ */
+int32_t ndarray_gufunc_call(uint32_t id,
+                            const struct NdArrayHandle *const *handles,
+                            const struct ArrayMetadata *const *metas,
+                            uintptr_t num_inputs,
+                            struct NdArrayHandle **out_handle,
+                            uint8_t *out_dtype,
+                            uintptr_t *out_ndim,
+                            uintptr_t *out_shape,
+                            uintptr_t max_ndim);
+
+/**
+ * Apply `callback` to every element and assemble the results into a new array.
+ *
+ * Elements are visited in C order and passed to the callback as f64 in
+ * batches of at most `batch_size` values (0 selects a default). The results
+ * are cast to `target_dtype`, which must be a real dtype; the output has the
+ * shape of the input. Complex inputs are not supported.
+ */
+int32_t ndarray_map(const struct NdArrayHandle *handle,
+                    const struct ArrayMetadata *meta,
+                    NdMapCallback callback,
+                    uint8_t target_dtype,
+                    uintptr_t batch_size,
+                    struct NdArrayHandle **out_handle,
+                    uint8_t *out_dtype,
+                    uintptr_t *out_ndim,
+                    uintptr_t *out_shape,
+                    uintptr_t max_ndim);
+
+/**
+ * Reduce each lane along `axis` with `callback`, starting from `init`.
+ *
+ * Lane values are passed as f64 in batches of at most `batch_size` values
+ * (0 selects a default); empty lanes reduce to `init`. The result is Float64
+ * with `axis` removed, or kept with length 1 when `keepdims` is set. Complex
+ * inputs are not supported.
+ */
+int32_t ndarray_reduce(const struct NdArrayHandle *handle,
+                       const struct ArrayMetadata *meta,
+                       int32_t axis,
+                       bool keepdims,
+                       double init,
+                       NdReduceCallback callback,
+                       uintptr_t batch_size,
+                       struct NdArrayHandle **out_handle,
+                       uint8_t *out_dtype,
+                       uintptr_t *out_ndim,
+                       uintptr_t *out_shape,
+                       uintptr_t max_ndim);
+
 /**
  * Generate a Bartlett window (Float64).
  */
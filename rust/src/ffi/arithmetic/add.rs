@@ -1,26 +1,31 @@
 //! Addition operation.
 
+use super::overflow_ops::{int_op_arrays, int_op_scalar, IntOp};
 use crate::binary_op_arithmetic;
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::scalar_op_arithmetic;
 use crate::types::dtype::DType;
-use crate::types::{ArrayMetadata, NdArrayHandle};
+use crate::types::{ArrayMetadata, NdArrayHandle, OverflowMode};
 use std::ffi::c_void;
 use std::ops::Add;
 
 #[inline(always)]
-fn add<T: Copy + Add<Output = T>>(a: &T, b: &T) -> T {
+pub(crate) fn add<T: Copy + Add<Output = T>>(a: &T, b: &T) -> T {
     *a + *b
 }
 
 /// Add two arrays.
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_add(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     b: *const NdArrayHandle,
     b_meta: *const ArrayMetadata,
+    overflow: i32,
     out: *mut *mut NdArrayHandle,
     out_dtype_ptr: *mut u8,
     out_ndim: *mut usize,
@@ -39,14 +44,32 @@ pub unsafe extern "C" fn ndarray_add(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
+        }
+    };
+
+    crate::ffi_guard!("add", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
         let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
 
-        let result_wrapper = binary_op_arithmetic!(a_wrapper, a_meta, b_wrapper, b_meta, add);
+        let result_wrapper = if DType::promote(a_wrapper.dtype, b_wrapper.dtype).is_integer() {
+            match int_op_arrays(IntOp::Add, overflow, a_wrapper, a_meta, b_wrapper, b_meta) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            binary_op_arithmetic!(a_wrapper, a_meta, b_wrapper, b_meta, add)
+        };
 
         if let Err(e) = write_output_metadata(
             &result_wrapper,
@@ -65,12 +88,16 @@ pub unsafe extern "C" fn ndarray_add(
 }
 
 /// Add a scalar to an array.
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_add_scalar(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     scalar: *const c_void,
     scalar_dtype: u8,
+    overflow: i32,
     out: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -92,15 +119,41 @@ pub unsafe extern "C" fn ndarray_add_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
+        }
+    };
+
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("add_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
-        let result_wrapper = scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, +);
+        let result_wrapper = if DType::promote_scalar(a_wrapper.dtype, scalar_dtype).is_integer() {
+            match int_op_scalar(
+                IntOp::Add,
+                overflow,
+                a_wrapper,
+                a_meta,
+                scalar,
+                scalar_dtype,
+                false,
+            ) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, +)
+        };
 
         if let Err(e) =
             write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
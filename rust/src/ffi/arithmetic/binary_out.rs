@@ -0,0 +1,189 @@
+//! Binary arithmetic into a caller-supplied destination (NumPy `out=`).
+//!
+//! The result is written straight into the destination view instead of a
+//! fresh allocation, so long-running callers can reuse one buffer across
+//! iterations. Operands that already have the destination dtype and do not
+//! share its storage are read through zero-copy views; anything else is
+//! converted (or, when it aliases the destination, snapshotted) first.
+
+use ndarray::{ArrayViewD, ArrayViewMutD, CowArray, IxDyn, Zip};
+
+use super::{add, div, maximum, minimum, mul, rem, sub};
+use crate::helpers::broadcast_shape;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::parallel;
+use crate::helpers::view::strided_view_mut;
+use crate::helpers::{
+    extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
+    extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+    extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+    extract_view_c128, extract_view_c64, extract_view_f32, extract_view_f64, extract_view_i16,
+    extract_view_i32, extract_view_i64, extract_view_i8, extract_view_u16, extract_view_u32,
+    extract_view_u64, extract_view_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, BinaryOp, NdArrayHandle};
+
+/// Fill `out` with `f(a, b)`; both operands already have `out`'s shape.
+fn zip_into<T, F>(mut out: ArrayViewMutD<'_, T>, a: ArrayViewD<'_, T>, b: ArrayViewD<'_, T>, f: F)
+where
+    T: Copy + Send + Sync,
+    F: Fn(&T, &T) -> T + Send + Sync,
+{
+    let parallel = parallel::should_parallelize(out.len());
+    let zip = Zip::from(&mut out).and(&a).and(&b);
+    #[cfg(feature = "parallel")]
+    if parallel {
+        parallel::install(|| zip.par_for_each(|o, a, b| *o = f(a, b)));
+        return;
+    }
+    let _ = parallel;
+    zip.for_each(|o, a, b| *o = f(a, b));
+}
+
+/// Apply a binary arithmetic operation, writing into an existing array.
+///
+/// `out` must already have the broadcast shape of `a` and `b` and their
+/// promoted dtype; it may be a strided view and may alias either operand.
+/// Float16, BFloat16 and Bool destinations are not supported.
+///
+/// # Arguments
+/// * `op` - Operation (0 = add, 1 = sub, 2 = mul, 3 = div, 4 = rem, 5 = maximum, 6 = minimum)
+/// * `a` / `a_meta` - Left operand and view
+/// * `b` / `b_meta` - Right operand and view
+/// * `out` / `out_meta` - Destination array and view
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_binary_op_out(
+    op: i32,
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    out: *const NdArrayHandle,
+    out_meta: *const ArrayMetadata,
+) -> i32 {
+    if a.is_null()
+        || a_meta.is_null()
+        || b.is_null()
+        || b_meta.is_null()
+        || out.is_null()
+        || out_meta.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("binary_op_out", {
+        let a_meta = &*a_meta;
+        let b_meta = &*b_meta;
+        let out_meta = &*out_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+        let out_wrapper = NdArrayHandle::as_wrapper(out as *mut _);
+
+        let op = match BinaryOp::from_i32(op) {
+            Ok(op) => op,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+        if out_wrapper.dtype != dtype {
+            set_last_error(format!(
+                "out has dtype {} but the result of {} and {} is {}",
+                out_wrapper.dtype, a_wrapper.dtype, b_wrapper.dtype, dtype
+            ));
+            return ERR_DTYPE;
+        }
+
+        let out_shape = out_meta.shape_slice();
+        match broadcast_shape(a_meta.shape_slice(), b_meta.shape_slice()) {
+            Some(shape) if shape == out_shape => {}
+            Some(shape) => {
+                set_last_error(format!(
+                    "out has shape {:?} but the result has shape {:?}",
+                    out_shape, shape
+                ));
+                return ERR_SHAPE;
+            }
+            None => {
+                set_last_error(format!(
+                    "Cannot broadcast shapes {:?} and {:?}",
+                    a_meta.shape_slice(),
+                    b_meta.shape_slice()
+                ));
+                return ERR_SHAPE;
+            }
+        }
+
+        // Borrow operands that already match and do not alias `out`; copy the rest.
+        macro_rules! operand {
+            ($wrapper:expr, $meta:expr, $view_fn:ident, $as_fn:ident) => {{
+                let view = if $wrapper.dtype == dtype && !$wrapper.is_same_array(out_wrapper) {
+                    $view_fn($wrapper, $meta)
+                } else {
+                    None
+                };
+                match view {
+                    Some(v) => CowArray::from(v),
+                    None => match $as_fn($wrapper, $meta) {
+                        Some(arr) => CowArray::from(arr),
+                        None => {
+                            set_last_error(format!("Failed to extract operand as {}", dtype));
+                            return ERR_DTYPE;
+                        }
+                    },
+                }
+            }};
+        }
+
+        macro_rules! apply_as {
+            ($lock:expr, $view_fn:ident, $as_fn:ident) => {{
+                let a_arr = operand!(a_wrapper, a_meta, $view_fn, $as_fn);
+                let b_arr = operand!(b_wrapper, b_meta, $view_fn, $as_fn);
+                let shape = IxDyn(out_shape);
+                let (Some(a_bc), Some(b_bc)) =
+                    (a_arr.broadcast(shape.clone()), b_arr.broadcast(shape))
+                else {
+                    set_last_error("Failed to broadcast operands".to_string());
+                    return ERR_SHAPE;
+                };
+                let mut guard = $lock.write();
+                let view = strided_view_mut(guard.as_mut_ptr(), out_meta);
+                match op {
+                    BinaryOp::Add => zip_into(view, a_bc, b_bc, add::add),
+                    BinaryOp::Sub => zip_into(view, a_bc, b_bc, sub::sub),
+                    BinaryOp::Mul => zip_into(view, a_bc, b_bc, mul::mul),
+                    BinaryOp::Div => zip_into(view, a_bc, b_bc, div::div),
+                    BinaryOp::Rem => zip_into(view, a_bc, b_bc, rem::rem),
+                    BinaryOp::Maximum => zip_into(view, a_bc, b_bc, maximum::maximum),
+                    BinaryOp::Minimum => zip_into(view, a_bc, b_bc, minimum::minimum),
+                }
+            }};
+        }
+
+        match &out_wrapper.data {
+            ArrayData::Int8(a) => apply_as!(a, extract_view_i8, extract_array_as_i8),
+            ArrayData::Int16(a) => apply_as!(a, extract_view_i16, extract_array_as_i16),
+            ArrayData::Int32(a) => apply_as!(a, extract_view_i32, extract_array_as_i32),
+            ArrayData::Int64(a) => apply_as!(a, extract_view_i64, extract_array_as_i64),
+            ArrayData::Uint8(a) => apply_as!(a, extract_view_u8, extract_array_as_u8),
+            ArrayData::Uint16(a) => apply_as!(a, extract_view_u16, extract_array_as_u16),
+            ArrayData::Uint32(a) => apply_as!(a, extract_view_u32, extract_array_as_u32),
+            ArrayData::Uint64(a) => apply_as!(a, extract_view_u64, extract_array_as_u64),
+            ArrayData::Float32(a) => apply_as!(a, extract_view_f32, extract_array_as_f32),
+            ArrayData::Float64(a) => apply_as!(a, extract_view_f64, extract_array_as_f64),
+            ArrayData::Complex64(a) => apply_as!(a, extract_view_c64, extract_array_as_c64),
+            ArrayData::Complex128(a) => apply_as!(a, extract_view_c128, extract_array_as_c128),
+            ArrayData::Bool(_) | ArrayData::Float16(_) | ArrayData::BFloat16(_) => {
+                set_last_error(format!("out= is not supported for {} arrays", dtype));
+                return ERR_DTYPE;
+            }
+        }
+
+        SUCCESS
+    })
+}
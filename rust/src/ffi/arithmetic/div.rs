@@ -1,6 +1,6 @@
 //! Division operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::types::dtype::DType;
 use crate::types::{ArrayMetadata, NdArrayHandle};
@@ -9,7 +9,7 @@ use std::ffi::c_void;
 use std::ops::Div;
 
 #[inline(always)]
-fn div<T: Copy + Div<Output = T>>(a: &T, b: &T) -> T {
+pub(crate) fn div<T: Copy + Div<Output = T>>(a: &T, b: &T) -> T {
     *a / *b
 }
 
@@ -38,7 +38,7 @@ pub unsafe extern "C" fn ndarray_div(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("div", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
@@ -91,11 +91,11 @@ pub unsafe extern "C" fn ndarray_div_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("div_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -111,3 +111,52 @@ pub unsafe extern "C" fn ndarray_div_scalar(
         SUCCESS
     })
 }
+
+/// Divide a scalar by an array (`scalar / a`).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rdiv_scalar(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || scalar.is_null()
+        || out.is_null()
+        || a_meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    let scalar_dtype = match DType::from_u8(scalar_dtype) {
+        Some(d) => d,
+        None => {
+            set_last_error("Invalid scalar dtype".to_string());
+            return ERR_DTYPE;
+        }
+    };
+
+    crate::ffi_guard!("rdiv_scalar", {
+        let a_meta = &*a_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+
+        let result_wrapper = scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, rev /);
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
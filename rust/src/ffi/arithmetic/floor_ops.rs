@@ -0,0 +1,241 @@
+//! Shared kernels for floor division, modulo and fmod.
+//!
+//! The three operations only differ in the scalar function applied, so the
+//! dtype dispatch and broadcasting live here and the FFI entry points in
+//! `floordiv`, `modulo` and `fmod` just pick a [`FloorOp`].
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, IxDyn, Zip};
+use parking_lot::RwLock;
+
+use crate::helpers::broadcast_shape;
+use crate::helpers::error::{ERR_DTYPE, ERR_MATH, ERR_SHAPE};
+use crate::helpers::floor_arith::FloorArith;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_as_f64, extract_array_as_i16, extract_array_as_i32,
+    extract_array_as_i64, extract_array_as_i8, extract_array_as_u16, extract_array_as_u32,
+    extract_array_as_u64, extract_array_as_u8, get_scalar_as_f32, get_scalar_as_f64,
+    get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8, get_scalar_as_u16,
+    get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum FloorOp {
+    /// Quotient rounded toward negative infinity.
+    FloorDiv,
+    /// Remainder with the sign of the divisor (Python `%`).
+    Mod,
+    /// Remainder with the sign of the dividend (C `fmod`).
+    FMod,
+}
+
+impl FloorOp {
+    fn name(self) -> &'static str {
+        match self {
+            FloorOp::FloorDiv => "floor_divide",
+            FloorOp::Mod => "mod",
+            FloorOp::FMod => "fmod",
+        }
+    }
+
+    #[inline(always)]
+    fn apply<T: FloorArith>(self, a: T, b: T) -> Option<T> {
+        match self {
+            FloorOp::FloorDiv => T::floor_div(a, b),
+            FloorOp::Mod => T::py_mod(a, b),
+            FloorOp::FMod => T::fmod(a, b),
+        }
+    }
+}
+
+/// Apply `op` over the broadcast of `a` and `b`.
+///
+/// Integer division by zero (or `MIN / -1` for floor division) fails the
+/// whole operation with `ERR_MATH` instead of panicking.
+fn apply_broadcast<T: FloorArith>(
+    op: FloorOp,
+    a: &ArrayD<T>,
+    b: &ArrayD<T>,
+) -> Result<ArrayD<T>, (i32, String)> {
+    let shape = broadcast_shape(a.shape(), b.shape()).ok_or_else(|| {
+        (
+            ERR_SHAPE,
+            format!(
+                "Cannot broadcast shapes {:?} and {:?}",
+                a.shape(),
+                b.shape()
+            ),
+        )
+    })?;
+    let (Some(a_bc), Some(b_bc)) = (a.broadcast(IxDyn(&shape)), b.broadcast(IxDyn(&shape))) else {
+        return Err((ERR_SHAPE, "Failed to broadcast operands".to_string()));
+    };
+
+    let mut failed = false;
+    let result = Zip::from(&a_bc)
+        .and(&b_bc)
+        .map_collect(|&x, &y| match op.apply(x, y) {
+            Some(v) => v,
+            None => {
+                failed = true;
+                x
+            }
+        });
+    if failed {
+        return Err((
+            ERR_MATH,
+            format!("{}: integer division by zero or overflow", op.name()),
+        ));
+    }
+    Ok(result)
+}
+
+/// Evaluate `op` between two arrays after dtype promotion.
+///
+/// Float16/BFloat16 are computed in f32; Bool and complex operands are
+/// rejected. Errors carry the FFI status code to return.
+pub(crate) unsafe fn floor_op_arrays(
+    op: FloorOp,
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    b_wrapper: &NDArrayWrapper,
+    b_meta: &ArrayMetadata,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let out_dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+
+    macro_rules! apply_with {
+        ($extract_fn:ident) => {{
+            let (Some(a_arr), Some(b_arr)) = (
+                $extract_fn(a_wrapper, a_meta),
+                $extract_fn(b_wrapper, b_meta),
+            ) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to prepare {} operands for {}", out_dtype, op.name()),
+                ));
+            };
+            apply_broadcast(op, &a_arr, &b_arr)?
+        }};
+    }
+
+    macro_rules! apply_as {
+        ($variant:ident, $extract_fn:ident) => {
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(apply_with!($extract_fn)))),
+                dtype: DType::$variant,
+            }
+        };
+    }
+
+    Ok(match out_dtype {
+        DType::Int8 => apply_as!(Int8, extract_array_as_i8),
+        DType::Int16 => apply_as!(Int16, extract_array_as_i16),
+        DType::Int32 => apply_as!(Int32, extract_array_as_i32),
+        DType::Int64 => apply_as!(Int64, extract_array_as_i64),
+        DType::Uint8 => apply_as!(Uint8, extract_array_as_u8),
+        DType::Uint16 => apply_as!(Uint16, extract_array_as_u16),
+        DType::Uint32 => apply_as!(Uint32, extract_array_as_u32),
+        DType::Uint64 => apply_as!(Uint64, extract_array_as_u64),
+        DType::Float32 => apply_as!(Float32, extract_array_as_f32),
+        DType::Float64 => apply_as!(Float64, extract_array_as_f64),
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_f32_as_half(apply_with!(extract_array_as_f32), out_dtype)
+        }
+        DType::Complex64 | DType::Complex128 | DType::Bool => {
+            return Err(unsupported(op, out_dtype));
+        }
+    })
+}
+
+/// Evaluate `op` between an array and a scalar after dtype promotion.
+///
+/// The scalar is treated as a 0-d array, so semantics match
+/// [`floor_op_arrays`].
+pub(crate) unsafe fn floor_op_scalar(
+    op: FloorOp,
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: DType,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let out_dtype = DType::promote_scalar(a_wrapper.dtype, scalar_dtype);
+
+    macro_rules! apply_with {
+        ($extract_fn:ident, $scalar_fn:ident) => {{
+            let Some(a_arr) = $extract_fn(a_wrapper, a_meta) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to prepare {} operand for {}", out_dtype, op.name()),
+                ));
+            };
+            let b_arr = ArrayD::from_elem(IxDyn(&[]), $scalar_fn(scalar, scalar_dtype));
+            apply_broadcast(op, &a_arr, &b_arr)?
+        }};
+    }
+
+    macro_rules! apply_as {
+        ($variant:ident, $extract_fn:ident, $scalar_fn:ident) => {
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(apply_with!(
+                    $extract_fn,
+                    $scalar_fn
+                )))),
+                dtype: DType::$variant,
+            }
+        };
+    }
+
+    Ok(match out_dtype {
+        DType::Int8 => apply_as!(Int8, extract_array_as_i8, get_scalar_as_i8),
+        DType::Int16 => apply_as!(Int16, extract_array_as_i16, get_scalar_as_i16),
+        DType::Int32 => apply_as!(Int32, extract_array_as_i32, get_scalar_as_i32),
+        DType::Int64 => apply_as!(Int64, extract_array_as_i64, get_scalar_as_i64),
+        DType::Uint8 => apply_as!(Uint8, extract_array_as_u8, get_scalar_as_u8),
+        DType::Uint16 => apply_as!(Uint16, extract_array_as_u16, get_scalar_as_u16),
+        DType::Uint32 => apply_as!(Uint32, extract_array_as_u32, get_scalar_as_u32),
+        DType::Uint64 => apply_as!(Uint64, extract_array_as_u64, get_scalar_as_u64),
+        DType::Float32 => apply_as!(Float32, extract_array_as_f32, get_scalar_as_f32),
+        DType::Float64 => apply_as!(Float64, extract_array_as_f64, get_scalar_as_f64),
+        DType::Float16 | DType::BFloat16 => NDArrayWrapper::from_f32_as_half(
+            apply_with!(extract_array_as_f32, get_scalar_as_f32),
+            out_dtype,
+        ),
+        DType::Complex64 | DType::Complex128 | DType::Bool => {
+            return Err(unsupported(op, out_dtype));
+        }
+    })
+}
+
+fn unsupported(op: FloorOp, dtype: DType) -> (i32, String) {
+    (
+        ERR_DTYPE,
+        format!("{} is not supported for {} arrays", op.name(), dtype),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalar_divisor_broadcasts() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[4]), vec![-7i64, -1, 1, 7]).unwrap();
+        let b = ArrayD::from_elem(IxDyn(&[]), 3i64);
+        let r = apply_broadcast(FloorOp::Mod, &a, &b).unwrap();
+        assert_eq!(r.iter().copied().collect::<Vec<_>>(), vec![2, 2, 1, 1]);
+        let q = apply_broadcast(FloorOp::FloorDiv, &a, &b).unwrap();
+        assert_eq!(q.iter().copied().collect::<Vec<_>>(), vec![-3, -1, 0, 2]);
+    }
+
+    #[test]
+    fn integer_zero_divisor_is_an_error() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[2]), vec![1i32, 2]).unwrap();
+        let b = ArrayD::from_shape_vec(IxDyn(&[2]), vec![1i32, 0]).unwrap();
+        let err = apply_broadcast(FloorOp::FMod, &a, &b).unwrap_err();
+        assert_eq!(err.0, ERR_MATH);
+    }
+}
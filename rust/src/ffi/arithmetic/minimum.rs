@@ -4,11 +4,12 @@
 
 use crate::binary_op_arithmetic;
 use crate::helpers::elementwise_minmax::ElementwiseMinimum;
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f32, extract_array_f64, extract_array_i16,
+    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
+    extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -16,7 +17,7 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 #[inline(always)]
-fn minimum<T: ElementwiseMinimum>(a: &T, b: &T) -> T {
+pub(crate) fn minimum<T: ElementwiseMinimum>(a: &T, b: &T) -> T {
     T::elementwise_min(*a, *b)
 }
 
@@ -45,7 +46,7 @@ pub unsafe extern "C" fn ndarray_minimum(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("minimum", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
@@ -92,7 +93,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("minimum_scalar", {
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
         let meta = &*meta;
 
@@ -100,7 +101,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.mapv(|x| x.min(scalar));
                 NDArrayWrapper {
@@ -111,7 +112,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.mapv(|x| x.min(scalar as f32));
                 NDArrayWrapper {
@@ -119,10 +120,18 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
+                    set_last_error("Failed to extract f32 array".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.mapv(|x| x.min(scalar as f32));
+                NDArrayWrapper::from_f32_as_half(result, a_wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(a_wrapper, meta) else {
                     set_last_error("Failed to extract i64 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = scalar as i64;
                 let result = arr.mapv(|x| x.min(s));
@@ -134,7 +143,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(a_wrapper, meta) else {
                     set_last_error("Failed to extract i32 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = scalar as i32;
                 let result = arr.mapv(|x| x.min(s));
@@ -146,7 +155,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(a_wrapper, meta) else {
                     set_last_error("Failed to extract i16 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = scalar as i16;
                 let result = arr.mapv(|x| x.min(s));
@@ -158,7 +167,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(a_wrapper, meta) else {
                     set_last_error("Failed to extract i8 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = scalar as i8;
                 let result = arr.mapv(|x| x.min(s));
@@ -170,7 +179,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(a_wrapper, meta) else {
                     set_last_error("Failed to extract u64 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = (scalar.max(0.0)) as u64;
                 let result = arr.mapv(|x| x.min(s));
@@ -182,7 +191,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(a_wrapper, meta) else {
                     set_last_error("Failed to extract u32 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = (scalar.max(0.0)) as u32;
                 let result = arr.mapv(|x| x.min(s));
@@ -194,7 +203,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(a_wrapper, meta) else {
                     set_last_error("Failed to extract u16 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = (scalar.max(0.0)) as u16;
                 let result = arr.mapv(|x| x.min(s));
@@ -206,7 +215,7 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(a_wrapper, meta) else {
                     set_last_error("Failed to extract u8 array".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = (scalar.max(0.0)) as u8;
                 let result = arr.mapv(|x| x.min(s));
@@ -217,11 +226,11 @@ pub unsafe extern "C" fn ndarray_minimum_scalar(
             }
             DType::Bool => {
                 set_last_error("minimum_scalar() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("minimum_scalar() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -4,18 +4,30 @@
 //! for both array-array and array-scalar operations.
 
 pub mod add;
+pub mod binary_out;
 pub mod div;
+pub mod floor_ops;
+pub mod floordiv;
+pub mod fmod;
 pub mod maximum;
 pub mod minimum;
+pub mod modulo;
 pub mod mul;
+pub mod overflow_ops;
 pub mod rem;
+pub mod rpow;
 pub mod sub;
 
 // Re-export all FFI functions
 pub use add::*;
+pub use binary_out::*;
 pub use div::*;
+pub use floordiv::*;
+pub use fmod::*;
 pub use maximum::*;
 pub use minimum::*;
+pub use modulo::*;
 pub use mul::*;
 pub use rem::*;
+pub use rpow::*;
 pub use sub::*;
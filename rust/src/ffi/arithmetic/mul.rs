@@ -1,25 +1,30 @@
 //! Multiplication operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use super::overflow_ops::{int_op_arrays, int_op_scalar, IntOp};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::types::dtype::DType;
-use crate::types::{ArrayMetadata, NdArrayHandle};
+use crate::types::{ArrayMetadata, NdArrayHandle, OverflowMode};
 use crate::{binary_op_arithmetic, scalar_op_arithmetic};
 use std::ffi::c_void;
 use std::ops::Mul;
 
 #[inline(always)]
-fn mul<T: Copy + Mul<Output = T>>(a: &T, b: &T) -> T {
+pub(crate) fn mul<T: Copy + Mul<Output = T>>(a: &T, b: &T) -> T {
     *a * *b
 }
 
 /// Multiply two arrays.
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_mul(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     b: *const NdArrayHandle,
     b_meta: *const ArrayMetadata,
+    overflow: i32,
     out: *mut *mut NdArrayHandle,
     out_dtype_ptr: *mut u8,
     out_ndim: *mut usize,
@@ -38,14 +43,32 @@ pub unsafe extern "C" fn ndarray_mul(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
+        }
+    };
+
+    crate::ffi_guard!("mul", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
         let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
 
-        let result_wrapper = binary_op_arithmetic!(a_wrapper, a_meta, b_wrapper, b_meta, mul);
+        let result_wrapper = if DType::promote(a_wrapper.dtype, b_wrapper.dtype).is_integer() {
+            match int_op_arrays(IntOp::Mul, overflow, a_wrapper, a_meta, b_wrapper, b_meta) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            binary_op_arithmetic!(a_wrapper, a_meta, b_wrapper, b_meta, mul)
+        };
 
         if let Err(e) = write_output_metadata(
             &result_wrapper,
@@ -64,12 +87,16 @@ pub unsafe extern "C" fn ndarray_mul(
 }
 
 /// Multiply an array by a scalar.
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_mul_scalar(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     scalar: *const c_void,
     scalar_dtype: u8,
+    overflow: i32,
     out: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -91,15 +118,41 @@ pub unsafe extern "C" fn ndarray_mul_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
+        }
+    };
+
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("mul_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
-        let result_wrapper = scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, *);
+        let result_wrapper = if DType::promote_scalar(a_wrapper.dtype, scalar_dtype).is_integer() {
+            match int_op_scalar(
+                IntOp::Mul,
+                overflow,
+                a_wrapper,
+                a_meta,
+                scalar,
+                scalar_dtype,
+                false,
+            ) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, *)
+        };
 
         if let Err(e) =
             write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
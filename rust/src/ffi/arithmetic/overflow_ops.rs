@@ -0,0 +1,332 @@
+//! Shared kernels for integer add, subtract and multiply.
+//!
+//! Integer results of `ndarray_add`, `ndarray_sub`, `ndarray_mul` and their
+//! scalar variants are computed here so the caller's [`OverflowMode`]
+//! applies; float and complex results keep using the arithmetic macros.
+//! Scalars are read exactly: one outside the array dtype's range gives the
+//! exact result under `Saturate` and `Error` instead of being truncated
+//! first.
+
+use std::ffi::c_void;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, ArrayViewD, CowArray, IxDyn, Zip};
+use parking_lot::RwLock;
+
+use crate::helpers::broadcast_shape;
+use crate::helpers::error::{ERR_DTYPE, ERR_OVERFLOW, ERR_SHAPE};
+use crate::helpers::overflow_arith::{narrow, OverflowArith};
+#[cfg(feature = "parallel")]
+use crate::helpers::parallel;
+use crate::helpers::{
+    extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+    extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+    extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8, extract_view_u16,
+    extract_view_u32, extract_view_u64, extract_view_u8, get_scalar_as_i64, get_scalar_as_u64,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, OverflowMode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IntOp {
+    Add,
+    Sub,
+    Mul,
+}
+
+impl IntOp {
+    fn name(self) -> &'static str {
+        match self {
+            IntOp::Add => "add",
+            IntOp::Sub => "subtract",
+            IntOp::Mul => "multiply",
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            IntOp::Add => "+",
+            IntOp::Sub => "-",
+            IntOp::Mul => "*",
+        }
+    }
+
+    #[inline(always)]
+    fn apply<T: OverflowArith>(self, mode: OverflowMode, a: T, b: T) -> Option<T> {
+        match self {
+            IntOp::Add => T::add_with(a, b, mode),
+            IntOp::Sub => T::sub_with(a, b, mode),
+            IntOp::Mul => T::mul_with(a, b, mode),
+        }
+    }
+
+    /// Exact result for operands read from 64-bit integers.
+    ///
+    /// Both operands are below 2^64 in magnitude, so sums, differences and
+    /// products all fit in an `i128`.
+    #[inline(always)]
+    fn apply_wide(self, a: i128, b: i128) -> i128 {
+        match self {
+            IntOp::Add => a + b,
+            IntOp::Sub => a - b,
+            IntOp::Mul => a * b,
+        }
+    }
+
+    fn overflow(self, dtype: DType, a: impl Display, b: impl Display) -> (i32, String) {
+        (
+            ERR_OVERFLOW,
+            format!(
+                "Integer overflow in {}: {} {} {} does not fit in {}",
+                self.name(),
+                a,
+                self.symbol(),
+                b,
+                dtype
+            ),
+        )
+    }
+}
+
+/// Apply `op` over the broadcast of `a` and `b`.
+///
+/// In `Error` mode the first overflowing pair fails the whole operation with
+/// `ERR_OVERFLOW`; the other modes cannot fail and run on the thread pool
+/// for large outputs.
+fn apply_broadcast<T: OverflowArith + Send + Sync>(
+    op: IntOp,
+    mode: OverflowMode,
+    dtype: DType,
+    a: ArrayViewD<'_, T>,
+    b: ArrayViewD<'_, T>,
+) -> Result<ArrayD<T>, (i32, String)> {
+    let shape = broadcast_shape(a.shape(), b.shape()).ok_or_else(|| {
+        (
+            ERR_SHAPE,
+            format!(
+                "Cannot broadcast shapes {:?} and {:?}",
+                a.shape(),
+                b.shape()
+            ),
+        )
+    })?;
+    let (Some(a_bc), Some(b_bc)) = (a.broadcast(IxDyn(&shape)), b.broadcast(IxDyn(&shape))) else {
+        return Err((ERR_SHAPE, "Failed to broadcast operands".to_string()));
+    };
+    let zip = Zip::from(&a_bc).and(&b_bc);
+
+    if mode != OverflowMode::Error {
+        #[cfg(feature = "parallel")]
+        if parallel::should_parallelize(a_bc.len()) {
+            return Ok(parallel::install(|| {
+                zip.par_map_collect(|&x, &y| op.apply(mode, x, y).unwrap_or(x))
+            }));
+        }
+        return Ok(zip.map_collect(|&x, &y| op.apply(mode, x, y).unwrap_or(x)));
+    }
+
+    let mut failed = None;
+    let result = zip.map_collect(|&x, &y| {
+        op.apply(mode, x, y).unwrap_or_else(|| {
+            failed.get_or_insert((x, y));
+            x
+        })
+    });
+    match failed {
+        Some((x, y)) => Err(op.overflow(dtype, x, y)),
+        None => Ok(result),
+    }
+}
+
+/// Apply `op` between `a` and an exact scalar (`s op a` when `reversed`).
+fn apply_scalar<T: OverflowArith + Send + Sync>(
+    op: IntOp,
+    mode: OverflowMode,
+    dtype: DType,
+    a: ArrayViewD<'_, T>,
+    s: i128,
+    reversed: bool,
+) -> Result<ArrayD<T>, (i32, String)> {
+    if let Ok(b) = T::try_from(s) {
+        let b = ArrayD::from_elem(IxDyn(&[]), b);
+        return if reversed {
+            apply_broadcast(op, mode, dtype, b.view(), a)
+        } else {
+            apply_broadcast(op, mode, dtype, a, b.view())
+        };
+    }
+
+    // The scalar does not fit the dtype: compute exactly, then narrow.
+    let mut failed = None;
+    let result = a.map(|&x| {
+        let (l, r) = if reversed {
+            (s, x.into())
+        } else {
+            (x.into(), s)
+        };
+        narrow(op.apply_wide(l, r), mode).unwrap_or_else(|| {
+            failed.get_or_insert((l, r));
+            x
+        })
+    });
+    match failed {
+        Some((l, r)) => Err(op.overflow(dtype, l, r)),
+        None => Ok(result),
+    }
+}
+
+/// Read an integer or bool scalar without loss.
+unsafe fn scalar_as_i128(scalar: *const c_void, dtype: DType) -> i128 {
+    if dtype.is_unsigned() {
+        get_scalar_as_u64(scalar, dtype) as i128
+    } else {
+        get_scalar_as_i64(scalar, dtype) as i128
+    }
+}
+
+/// Borrow operands that already have the result dtype; convert the rest.
+macro_rules! operand {
+    ($wrapper:expr, $meta:expr, $dtype:expr, $view_fn:ident, $as_fn:ident) => {{
+        let arr = if $wrapper.dtype == $dtype {
+            $view_fn($wrapper, $meta).map(CowArray::from)
+        } else {
+            $as_fn($wrapper, $meta).map(CowArray::from)
+        };
+        arr.ok_or_else(|| {
+            (
+                ERR_DTYPE,
+                format!("Failed to extract operand as {}", $dtype),
+            )
+        })?
+    }};
+}
+
+/// Dispatch an integer result dtype to its element type.
+macro_rules! with_int_dtype {
+    ($dtype:expr, $apply:ident) => {
+        match $dtype {
+            DType::Int8 => $apply!(Int8, extract_view_i8, extract_array_as_i8),
+            DType::Int16 => $apply!(Int16, extract_view_i16, extract_array_as_i16),
+            DType::Int32 => $apply!(Int32, extract_view_i32, extract_array_as_i32),
+            DType::Int64 => $apply!(Int64, extract_view_i64, extract_array_as_i64),
+            DType::Uint8 => $apply!(Uint8, extract_view_u8, extract_array_as_u8),
+            DType::Uint16 => $apply!(Uint16, extract_view_u16, extract_array_as_u16),
+            DType::Uint32 => $apply!(Uint32, extract_view_u32, extract_array_as_u32),
+            DType::Uint64 => $apply!(Uint64, extract_view_u64, extract_array_as_u64),
+            _ => return Err((ERR_DTYPE, format!("{} is not an integer dtype", $dtype))),
+        }
+    };
+}
+
+/// Evaluate `op` between two arrays whose promoted dtype is an integer.
+pub(crate) unsafe fn int_op_arrays(
+    op: IntOp,
+    mode: OverflowMode,
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    b_wrapper: &NDArrayWrapper,
+    b_meta: &ArrayMetadata,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let out_dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+
+    macro_rules! apply_as {
+        ($variant:ident, $view_fn:ident, $as_fn:ident) => {{
+            let a_arr = operand!(a_wrapper, a_meta, out_dtype, $view_fn, $as_fn);
+            let b_arr = operand!(b_wrapper, b_meta, out_dtype, $view_fn, $as_fn);
+            let result = apply_broadcast(op, mode, out_dtype, a_arr.view(), b_arr.view())?;
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(result))),
+                dtype: DType::$variant,
+            }
+        }};
+    }
+
+    Ok(with_int_dtype!(out_dtype, apply_as))
+}
+
+/// Evaluate `op` between an array and an integer scalar whose promoted
+/// dtype is an integer (`scalar op a` when `reversed`).
+pub(crate) unsafe fn int_op_scalar(
+    op: IntOp,
+    mode: OverflowMode,
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: DType,
+    reversed: bool,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let out_dtype = DType::promote_scalar(a_wrapper.dtype, scalar_dtype);
+    let s = scalar_as_i128(scalar, scalar_dtype);
+
+    macro_rules! apply_as {
+        ($variant:ident, $view_fn:ident, $as_fn:ident) => {{
+            let a_arr = operand!(a_wrapper, a_meta, out_dtype, $view_fn, $as_fn);
+            let result = apply_scalar(op, mode, out_dtype, a_arr.view(), s, reversed)?;
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(result))),
+                dtype: DType::$variant,
+            }
+        }};
+    }
+
+    Ok(with_int_dtype!(out_dtype, apply_as))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_mode_reports_first_overflow() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[3]), vec![1i8, 100, 120]).unwrap();
+        let b = ArrayD::from_elem(IxDyn(&[]), 30i8);
+        let err = apply_broadcast(
+            IntOp::Add,
+            OverflowMode::Error,
+            DType::Int8,
+            a.view(),
+            b.view(),
+        )
+        .unwrap_err();
+        assert_eq!(err.0, ERR_OVERFLOW);
+        assert!(err.1.contains("100 + 30"), "{}", err.1);
+    }
+
+    #[test]
+    fn out_of_range_scalar_is_exact() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[3]), vec![0u8, 10, 200]).unwrap();
+        let sat = apply_scalar(
+            IntOp::Sub,
+            OverflowMode::Saturate,
+            DType::Uint8,
+            a.view(),
+            300,
+            true,
+        )
+        .unwrap();
+        assert_eq!(sat.iter().copied().collect::<Vec<_>>(), vec![255, 255, 100]);
+
+        let wrap = apply_scalar(
+            IntOp::Add,
+            OverflowMode::Wrap,
+            DType::Uint8,
+            a.view(),
+            300,
+            false,
+        )
+        .unwrap();
+        assert_eq!(wrap.iter().copied().collect::<Vec<_>>(), vec![44, 54, 244]);
+
+        let err = apply_scalar(
+            IntOp::Mul,
+            OverflowMode::Error,
+            DType::Uint8,
+            a.view(),
+            -1,
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(err.0, ERR_OVERFLOW);
+    }
+}
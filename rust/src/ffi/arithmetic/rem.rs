@@ -1,6 +1,6 @@
 //! Remainder (modulo) operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::types::dtype::DType;
 use crate::types::{ArrayMetadata, NdArrayHandle};
@@ -9,7 +9,7 @@ use std::ffi::c_void;
 use std::ops::Rem;
 
 #[inline(always)]
-fn rem<T: Copy + Rem<Output = T>>(a: &T, b: &T) -> T {
+pub(crate) fn rem<T: Copy + Rem<Output = T>>(a: &T, b: &T) -> T {
     *a % *b
 }
 
@@ -39,7 +39,7 @@ pub unsafe extern "C" fn ndarray_rem(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("rem", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
@@ -93,11 +93,11 @@ pub unsafe extern "C" fn ndarray_rem_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("rem_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
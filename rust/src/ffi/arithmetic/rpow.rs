@@ -0,0 +1,144 @@
+//! Scalar raised to array powers.
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_MATH, SUCCESS};
+use crate::helpers::parallel::mapv_into;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
+    extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+    extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+    get_scalar_as_c128, get_scalar_as_c64, get_scalar_as_f32, get_scalar_as_f64, get_scalar_as_i16,
+    get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8, get_scalar_as_u16, get_scalar_as_u32,
+    get_scalar_as_u64, get_scalar_as_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use num_traits::ToPrimitive;
+use parking_lot::RwLock;
+use std::ffi::c_void;
+use std::sync::Arc;
+
+/// Raise a scalar to the power of each array element (`scalar ** a`).
+///
+/// Dtypes are promoted as in `ndarray_mul_scalar`. Integer results wrap on
+/// overflow; negative integer exponents return `ERR_MATH`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rpow_scalar(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || scalar.is_null()
+        || out.is_null()
+        || a_meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        set_last_error("Invalid input parameters".to_string());
+        return ERR_GENERIC;
+    }
+
+    let scalar_dtype = match DType::from_u8(scalar_dtype) {
+        Some(d) => d,
+        None => {
+            set_last_error("Invalid scalar dtype".to_string());
+            return ERR_DTYPE;
+        }
+    };
+
+    crate::ffi_guard!("rpow_scalar", {
+        let a_meta = &*a_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let result_dtype = DType::promote_scalar(a_wrapper.dtype, scalar_dtype);
+
+        macro_rules! rpow_with {
+            ($extract_fn:ident, $scalar_fn:ident, $pow:ident) => {{
+                let Some(arr) = $extract_fn(a_wrapper, a_meta) else {
+                    set_last_error(format!("Failed to extract array as {}", result_dtype));
+                    return ERR_DTYPE;
+                };
+                let s = $scalar_fn(scalar, scalar_dtype);
+                mapv_into(arr, |x| s.$pow(x))
+            }};
+        }
+
+        macro_rules! rpow_float {
+            ($variant:ident, $extract_fn:ident, $scalar_fn:ident, $pow:ident) => {
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(rpow_with!(
+                        $extract_fn,
+                        $scalar_fn,
+                        $pow
+                    )))),
+                    dtype: DType::$variant,
+                }
+            };
+        }
+
+        macro_rules! rpow_int {
+            ($variant:ident, $extract_fn:ident, $scalar_fn:ident) => {{
+                let Some(arr) = $extract_fn(a_wrapper, a_meta) else {
+                    set_last_error(format!("Failed to extract array as {}", result_dtype));
+                    return ERR_DTYPE;
+                };
+                if !arr.iter().all(|x| x.to_u32().is_some()) {
+                    set_last_error(
+                        "rpow: integer exponents must be non-negative and fit in u32".to_string(),
+                    );
+                    return ERR_MATH;
+                }
+                let s = $scalar_fn(scalar, scalar_dtype);
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(mapv_into(arr, |x| {
+                        s.wrapping_pow(x as u32)
+                    })))),
+                    dtype: DType::$variant,
+                }
+            }};
+        }
+
+        let result_wrapper = match result_dtype {
+            DType::Float64 => rpow_float!(Float64, extract_array_as_f64, get_scalar_as_f64, powf),
+            DType::Float32 => rpow_float!(Float32, extract_array_as_f32, get_scalar_as_f32, powf),
+            DType::Complex64 => {
+                rpow_float!(Complex64, extract_array_as_c64, get_scalar_as_c64, powc)
+            }
+            DType::Complex128 => {
+                rpow_float!(Complex128, extract_array_as_c128, get_scalar_as_c128, powc)
+            }
+            DType::Float16 | DType::BFloat16 => NDArrayWrapper::from_f32_as_half(
+                rpow_with!(extract_array_as_f32, get_scalar_as_f32, powf),
+                result_dtype,
+            ),
+            DType::Int8 => rpow_int!(Int8, extract_array_as_i8, get_scalar_as_i8),
+            DType::Int16 => rpow_int!(Int16, extract_array_as_i16, get_scalar_as_i16),
+            DType::Int32 => rpow_int!(Int32, extract_array_as_i32, get_scalar_as_i32),
+            DType::Int64 => rpow_int!(Int64, extract_array_as_i64, get_scalar_as_i64),
+            DType::Uint8 => rpow_int!(Uint8, extract_array_as_u8, get_scalar_as_u8),
+            DType::Uint16 => rpow_int!(Uint16, extract_array_as_u16, get_scalar_as_u16),
+            DType::Uint32 => rpow_int!(Uint32, extract_array_as_u32, get_scalar_as_u32),
+            DType::Uint64 => rpow_int!(Uint64, extract_array_as_u64, get_scalar_as_u64),
+            DType::Bool => {
+                set_last_error("rpow is not supported for Bool arrays".to_string());
+                return ERR_DTYPE;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
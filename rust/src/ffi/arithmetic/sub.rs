@@ -1,25 +1,30 @@
 //! Subtraction operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use super::overflow_ops::{int_op_arrays, int_op_scalar, IntOp};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::types::dtype::DType;
-use crate::types::{ArrayMetadata, NdArrayHandle};
+use crate::types::{ArrayMetadata, NdArrayHandle, OverflowMode};
 use crate::{binary_op_arithmetic, scalar_op_arithmetic};
 use std::ffi::c_void;
 use std::ops::Sub;
 
 #[inline(always)]
-fn sub<T: Copy + Sub<Output = T>>(a: &T, b: &T) -> T {
+pub(crate) fn sub<T: Copy + Sub<Output = T>>(a: &T, b: &T) -> T {
     *a - *b
 }
 
 /// Subtract two arrays.
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_sub(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     b: *const NdArrayHandle,
     b_meta: *const ArrayMetadata,
+    overflow: i32,
     out: *mut *mut NdArrayHandle,
     out_dtype_ptr: *mut u8,
     out_ndim: *mut usize,
@@ -39,14 +44,32 @@ pub unsafe extern "C" fn ndarray_sub(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
+        }
+    };
+
+    crate::ffi_guard!("sub", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
         let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
 
-        let result_wrapper = binary_op_arithmetic!(a_wrapper, a_meta, b_wrapper, b_meta, sub);
+        let result_wrapper = if DType::promote(a_wrapper.dtype, b_wrapper.dtype).is_integer() {
+            match int_op_arrays(IntOp::Sub, overflow, a_wrapper, a_meta, b_wrapper, b_meta) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            binary_op_arithmetic!(a_wrapper, a_meta, b_wrapper, b_meta, sub)
+        };
 
         if let Err(e) = write_output_metadata(
             &result_wrapper,
@@ -65,12 +88,16 @@ pub unsafe extern "C" fn ndarray_sub(
 }
 
 /// Subtract a scalar from an array.
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_sub_scalar(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     scalar: *const c_void,
     scalar_dtype: u8,
+    overflow: i32,
     out: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -93,15 +120,121 @@ pub unsafe extern "C" fn ndarray_sub_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
+            return ERR_DTYPE;
+        }
+    };
+
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
+        }
+    };
+
+    crate::ffi_guard!("sub_scalar", {
+        let a_meta = &*a_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+
+        let result_wrapper = if DType::promote_scalar(a_wrapper.dtype, scalar_dtype).is_integer() {
+            match int_op_scalar(
+                IntOp::Sub,
+                overflow,
+                a_wrapper,
+                a_meta,
+                scalar,
+                scalar_dtype,
+                false,
+            ) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, -)
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
             return ERR_GENERIC;
         }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Subtract an array from a scalar (`scalar - a`).
+///
+/// Integer results follow `overflow` (0 = wrap, 1 = saturate, 2 = fail with
+/// `ERR_OVERFLOW`); float and complex results ignore it.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rsub_scalar(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+    overflow: i32,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || scalar.is_null()
+        || out.is_null()
+        || a_meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        set_last_error("Invalid input parameters".to_string());
+        return ERR_GENERIC;
+    }
+
+    let scalar_dtype = match DType::from_u8(scalar_dtype) {
+        Some(d) => d,
+        None => {
+            set_last_error("Invalid scalar dtype".to_string());
+            return ERR_DTYPE;
+        }
+    };
+
+    let overflow = match OverflowMode::from_i32(overflow) {
+        Ok(m) => m,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
+        }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("rsub_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
-        let result_wrapper = scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, -);
+        let result_wrapper = if DType::promote_scalar(a_wrapper.dtype, scalar_dtype).is_integer() {
+            match int_op_scalar(
+                IntOp::Sub,
+                overflow,
+                a_wrapper,
+                a_meta,
+                scalar,
+                scalar_dtype,
+                true,
+            ) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            }
+        } else {
+            scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, rev -)
+        };
 
         if let Err(e) =
             write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
@@ -2,11 +2,14 @@
 
 use std::ffi::c_void;
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayMetadata, NdArrayHandle};
@@ -36,14 +39,14 @@ pub unsafe extern "C" fn ndarray_as_scalar(
         return ERR_SHAPE;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("as_scalar", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let result = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut f64) = *v;
@@ -52,7 +55,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut f32) = *v;
@@ -61,7 +64,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut i64) = *v;
@@ -70,7 +73,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut i32) = *v;
@@ -79,7 +82,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut i16) = *v;
@@ -88,7 +91,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut i8) = *v;
@@ -97,7 +100,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut u64) = *v;
@@ -106,7 +109,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut u32) = *v;
@@ -115,7 +118,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut u16) = *v;
@@ -124,7 +127,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut u8) = *v;
@@ -133,7 +136,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut u8) = *v;
@@ -142,7 +145,7 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut Complex32) = *v;
@@ -151,19 +154,28 @@ pub unsafe extern "C" fn ndarray_as_scalar(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.first().map(|v| {
                     *(out_value as *mut Complex64) = *v;
                 })
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                arr.first().map(|v| {
+                    *(out_value as *mut u16) = *v;
+                })
+            }
         };
 
         match result {
             Some(()) => SUCCESS,
             None => {
                 set_last_error("0-dimensional array has no element".to_string());
-                ERR_GENERIC
+                ERR_VALUE
             }
         }
     })
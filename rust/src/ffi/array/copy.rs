@@ -2,8 +2,9 @@
 
 use crate::helpers::view::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -21,7 +22,7 @@ pub unsafe extern "C" fn ndarray_copy(
         return crate::helpers::error::ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("copy", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -117,6 +118,10 @@ pub unsafe extern "C" fn ndarray_copy(
                     dtype: DType::Complex128,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let arr = extract_array_half_bits(wrapper, meta).expect("Type mismatch");
+                NDArrayWrapper::from_half_bits(arr, wrapper.dtype)
+            }
         };
 
         *out_handle = NdArrayHandle::from_wrapper(Box::new(new_wrapper));
@@ -3,11 +3,15 @@
 use std::ffi::c_void;
 use std::slice;
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
 use crate::types::dtype::DType;
-use crate::types::{NDArrayWrapper, NdArrayHandle};
+use crate::types::{MemoryOrder, NDArrayWrapper, NdArrayHandle};
 
 /// Create an NDArray from raw data with specified dtype.
+///
+/// `data` holds the elements in the memory order selected by `order`:
+/// 0 for C (row-major), 1 for Fortran (column-major). Fortran data is kept
+/// as-is in column-major storage, so no reordering copy is made.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_create(
     data: *const c_void,
@@ -15,6 +19,7 @@ pub unsafe extern "C" fn ndarray_create(
     shape: *const usize,
     ndim: usize,
     dtype: i32,
+    order: i32,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if data.is_null() || shape.is_null() || out_handle.is_null() {
@@ -23,11 +28,25 @@ pub unsafe extern "C" fn ndarray_create(
 
     let dtype = match DType::from_u8(dtype as u8) {
         Some(d) => d,
-        None => return ERR_GENERIC,
+        None => return ERR_DTYPE,
     };
 
-    crate::ffi_guard!({
-        let shape_slice = slice::from_raw_parts(shape, ndim);
+    crate::ffi_guard!("create", {
+        let order = match MemoryOrder::from_i32(order) {
+            Ok(o) => o,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        // Fortran data is C-order data of the reversed shape; build that and
+        // reverse the axes afterwards.
+        let mut dims = slice::from_raw_parts(shape, ndim).to_vec();
+        if order == MemoryOrder::F {
+            dims.reverse();
+        }
+        let shape_slice = dims.as_slice();
 
         let result = match dtype {
             DType::Int8 => {
@@ -82,10 +101,21 @@ pub unsafe extern "C" fn ndarray_create(
                 let data_slice = slice::from_raw_parts(data as *const f64, len);
                 NDArrayWrapper::from_slice_complex128(data_slice, shape_slice)
             }
+            DType::Float16 => {
+                let data_slice = slice::from_raw_parts(data as *const u16, len);
+                NDArrayWrapper::from_slice_f16(data_slice, shape_slice)
+            }
+            DType::BFloat16 => {
+                let data_slice = slice::from_raw_parts(data as *const u16, len);
+                NDArrayWrapper::from_slice_bf16(data_slice, shape_slice)
+            }
         };
 
         match result {
             Ok(wrapper) => {
+                if order == MemoryOrder::F {
+                    wrapper.reverse_axes();
+                }
                 *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
                 SUCCESS
             }
@@ -0,0 +1,106 @@
+//! Layout flags of an array view.
+
+use crate::helpers::error::{ERR_GENERIC, SUCCESS};
+use crate::match_array_data;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// True when the view is contiguous in C (`fortran == false`) or Fortran order.
+///
+/// Follows NumPy: strides of length-1 axes are ignored, and zero-size arrays
+/// are contiguous in both orders. Negative strides are never contiguous.
+fn is_contiguous_in(shape: &[usize], strides: &[usize], fortran: bool) -> bool {
+    if shape.contains(&0) {
+        return true;
+    }
+
+    let mut expected = 1isize;
+    let mut check = |axis: usize| {
+        if shape[axis] == 1 {
+            return true;
+        }
+        let ok = strides[axis] as isize == expected;
+        expected *= shape[axis] as isize;
+        ok
+    };
+
+    if fortran {
+        (0..shape.len()).all(&mut check)
+    } else {
+        (0..shape.len()).rev().all(&mut check)
+    }
+}
+
+/// Query layout flags of a view.
+///
+/// # Arguments
+/// * `handle` - Array handle
+/// * `meta` - View metadata
+/// * `out_itemsize` - Output: bytes per element
+/// * `out_nbytes` - Output: bytes spanned by the view's elements (`size * itemsize`)
+/// * `out_c_contiguous` - Output: elements are laid out in C (row-major) order
+/// * `out_f_contiguous` - Output: elements are laid out in Fortran (column-major) order
+/// * `out_owns_data` - Output: the view covers the whole underlying buffer with its
+///   native offset, shape and strides, i.e. it is not a view of a larger array
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_flags(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_itemsize: *mut usize,
+    out_nbytes: *mut usize,
+    out_c_contiguous: *mut bool,
+    out_f_contiguous: *mut bool,
+    out_owns_data: *mut bool,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_itemsize.is_null()
+        || out_nbytes.is_null()
+        || out_c_contiguous.is_null()
+        || out_f_contiguous.is_null()
+        || out_owns_data.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("flags", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+        let shape = meta.shape_slice();
+        let strides = meta.strides_slice();
+
+        let owns_data = match_array_data!(wrapper.data, arr => {
+            let guard = arr.read();
+            meta.offset == 0
+                && guard.shape() == shape
+                && guard
+                    .strides()
+                    .iter()
+                    .zip(strides)
+                    .all(|(&native, &view)| native == view as isize)
+        });
+
+        let itemsize = wrapper.dtype.item_size();
+        *out_itemsize = itemsize;
+        *out_nbytes = shape.iter().product::<usize>() * itemsize;
+        *out_c_contiguous = is_contiguous_in(shape, strides, false);
+        *out_f_contiguous = is_contiguous_in(shape, strides, true);
+        *out_owns_data = owns_data;
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_contiguous_in;
+
+    #[test]
+    fn length_one_axes_do_not_affect_contiguity() {
+        assert!(is_contiguous_in(&[2, 3], &[3, 1], false));
+        assert!(!is_contiguous_in(&[2, 3], &[3, 1], true));
+        assert!(is_contiguous_in(&[2, 3], &[1, 2], true));
+        assert!(is_contiguous_in(&[1, 3], &[99, 1], false));
+        assert!(is_contiguous_in(&[1, 3], &[99, 1], true));
+        assert!(is_contiguous_in(&[0, 3], &[7, 7], false));
+        assert!(!is_contiguous_in(&[3], &[usize::MAX], false));
+    }
+}
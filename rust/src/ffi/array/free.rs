@@ -6,7 +6,7 @@ use crate::types::NdArrayHandle;
 /// Destroy an NDArray and free its memory.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_free(handle: *mut NdArrayHandle) -> i32 {
-    crate::ffi_guard!({
+    crate::ffi_guard!("free", {
         if !handle.is_null() {
             let _ = NdArrayHandle::into_wrapper(handle);
         }
@@ -7,12 +7,7 @@ use std::slice;
 use parking_lot::RwLock;
 
 use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, SUCCESS};
-use crate::helpers::{
-    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
-};
-use crate::helpers::is_c_contiguous;
+use crate::helpers::{extract_array_half_bits, is_c_contiguous, CastView};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NdArrayHandle};
 use ndarray::ArrayD;
@@ -82,7 +77,7 @@ pub unsafe extern "C" fn ndarray_get_data(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("get_data", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -155,142 +150,62 @@ pub unsafe extern "C" fn ndarray_get_data(
                     out_data as *mut num_complex::Complex64,
                     extractor!(Complex128, num_complex::Complex64),
                 ),
+                DType::Float16 => copy_contiguous(
+                    &wrapper.data, meta.offset, start, copy_len,
+                    out_data as *mut u16, extractor!(Float16, u16),
+                ),
+                DType::BFloat16 => copy_contiguous(
+                    &wrapper.data, meta.offset, start, copy_len,
+                    out_data as *mut u16, extractor!(BFloat16, u16),
+                ),
             };
             if ok {
                 return SUCCESS;
             }
         }
 
-        // ── Slow path: non-contiguous views → materialise, then copy ──
-        let result = match wrapper.dtype {
-            DType::Int8 => {
-                let Some(arr) = extract_array_i8(wrapper, meta) else {
-                    error::set_last_error("Failed to extract i8 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut i8)
-            }
-            DType::Int16 => {
-                let Some(arr) = extract_array_i16(wrapper, meta) else {
-                    error::set_last_error("Failed to extract i16 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut i16)
-            }
-            DType::Int32 => {
-                let Some(arr) = extract_array_i32(wrapper, meta) else {
-                    error::set_last_error("Failed to extract i32 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut i32)
-            }
-            DType::Int64 => {
-                let Some(arr) = extract_array_i64(wrapper, meta) else {
-                    error::set_last_error("Failed to extract i64 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut i64)
-            }
-            DType::Uint8 => {
-                let Some(arr) = extract_array_u8(wrapper, meta) else {
-                    error::set_last_error("Failed to extract u8 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut u8)
-            }
-            DType::Uint16 => {
-                let Some(arr) = extract_array_u16(wrapper, meta) else {
-                    error::set_last_error("Failed to extract u16 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut u16)
-            }
-            DType::Uint32 => {
-                let Some(arr) = extract_array_u32(wrapper, meta) else {
-                    error::set_last_error("Failed to extract u32 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut u32)
-            }
-            DType::Uint64 => {
-                let Some(arr) = extract_array_u64(wrapper, meta) else {
-                    error::set_last_error("Failed to extract u64 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut u64)
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
-                    error::set_last_error("Failed to extract f32 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut f32)
-            }
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(wrapper, meta) else {
-                    error::set_last_error("Failed to extract f64 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut f64)
-            }
-            DType::Bool => {
-                let Some(arr) = extract_array_bool(wrapper, meta) else {
-                    error::set_last_error("Failed to extract bool view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut u8)
-            }
-            DType::Complex64 => {
-                let Some(arr) = extract_array_c64(wrapper, meta) else {
-                    error::set_last_error("Failed to extract Complex64 view");
-                    return ERR_DTYPE;
-                };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut num_complex::Complex32)
-            }
-            DType::Complex128 => {
-                let Some(arr) = extract_array_c128(wrapper, meta) else {
-                    error::set_last_error("Failed to extract Complex128 view");
+        // ── Slow path: strided views → read just the requested range in place ──
+        let view = CastView::new(wrapper, meta);
+
+        macro_rules! copy_range {
+            ($t:ty) => {{
+                let out = slice::from_raw_parts_mut(out_data as *mut $t, copy_len);
+                let mut i = 0;
+                view.for_each_in(start, copy_len, |v: $t| {
+                    out[i] = v;
+                    i += 1;
+                });
+            }};
+        }
+
+        match wrapper.dtype {
+            DType::Int8 => copy_range!(i8),
+            DType::Int16 => copy_range!(i16),
+            DType::Int32 => copy_range!(i32),
+            DType::Int64 => copy_range!(i64),
+            DType::Uint8 => copy_range!(u8),
+            DType::Uint16 => copy_range!(u16),
+            DType::Uint32 => copy_range!(u32),
+            DType::Uint64 => copy_range!(u64),
+            DType::Float32 => copy_range!(f32),
+            DType::Float64 => copy_range!(f64),
+            DType::Bool => copy_range!(u8),
+            DType::Complex64 => copy_range!(num_complex::Complex32),
+            DType::Complex128 => copy_range!(num_complex::Complex64),
+            DType::Float16 | DType::BFloat16 => {
+                // Half-precision data is handed out as raw bit patterns, which
+                // the casting view would convert numerically.
+                let Some(bits) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view");
                     return ERR_DTYPE;
                 };
-                copy_array_to_buffer(&arr, start, copy_len, out_data as *mut num_complex::Complex64)
+                let out = slice::from_raw_parts_mut(out_data as *mut u16, copy_len);
+                for (o, v) in out.iter_mut().zip(bits.iter().skip(start)) {
+                    *o = *v;
+                }
             }
-        };
-
-        result
-    })
-}
-
-// ---------------------------------------------------------------------------
-// Slow-path copy
-// ---------------------------------------------------------------------------
-
-/// Copy a range of elements from an extracted `ArrayD` into a flat buffer.
-///
-/// Uses `copy_from_slice` (memcpy) when the array is contiguous, or
-/// stride-based element iteration otherwise.
-unsafe fn copy_array_to_buffer<T: Copy>(
-    arr: &ArrayD<T>,
-    start: usize,
-    len: usize,
-    out: *mut T,
-) -> i32 {
-    let total = arr.len();
-
-    if start >= total {
-        error::set_last_error("Start offset is out of bounds");
-        return ERR_INDEX;
-    }
-
-    let copy_len = len.min(total - start);
-    let out_slice = slice::from_raw_parts_mut(out, copy_len);
-
-    if let Some(data) = arr.as_slice() {
-        out_slice.copy_from_slice(&data[start..start + copy_len]);
-    } else {
-        for (dst, src) in out_slice.iter_mut().zip(arr.iter().skip(start).take(copy_len)) {
-            *dst = *src;
         }
-    }
 
-    SUCCESS
+        SUCCESS
+    })
 }
@@ -5,12 +5,16 @@
 pub mod as_scalar;
 pub mod copy;
 pub mod create;
+pub mod flags;
 pub mod free;
 pub mod get_data;
+pub mod nditer;
 
 // Re-export all public functions
 pub use as_scalar::*;
 pub use copy::*;
 pub use create::*;
+pub use flags::*;
 pub use free::*;
 pub use get_data::*;
+pub use nditer::*;
@@ -0,0 +1,233 @@
+//! Block iterator over array views.
+//!
+//! An iterator handle walks a view (offset/shape/strides) in C order and hands
+//! back small batches of values together with their multi-indices, so callers
+//! can stream large arrays without materialising them.
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ndarray::ArrayD;
+use parking_lot::RwLock;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NdArrayHandle};
+
+/// Opaque pointer type for iterator handles.
+///
+/// PHP holds this pointer and passes it back to Rust for `ndarray_iter_*` calls.
+#[repr(C)]
+pub struct NdIterHandle {
+    _private: [u8; 0],
+}
+
+/// Iterator state behind an [`NdIterHandle`].
+///
+/// Holds its own reference to the array storage, so the iterator stays valid
+/// even if the source array is freed first.
+struct NdIterState {
+    data: ArrayData,
+    dtype: DType,
+    offset: usize,
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    /// Multi-index of the next element to yield.
+    index: Vec<usize>,
+    /// Elements not yet yielded.
+    remaining: usize,
+}
+
+impl NdIterState {
+    /// Storage offset (in elements) of the element at the current index.
+    fn current_offset(&self) -> usize {
+        let mut pos = self.offset as isize;
+        for (&i, &s) in self.index.iter().zip(self.strides.iter()) {
+            pos += i as isize * s as isize;
+        }
+        pos as usize
+    }
+
+    /// Advance the multi-index by one element in C order.
+    fn advance(&mut self) {
+        self.remaining -= 1;
+        for axis in (0..self.shape.len()).rev() {
+            self.index[axis] += 1;
+            if self.index[axis] < self.shape[axis] {
+                return;
+            }
+            self.index[axis] = 0;
+        }
+    }
+
+    /// Copy up to `max_count` elements (and optionally their indices) into the output buffers.
+    unsafe fn fill<T: Copy>(
+        &mut self,
+        arr: &Arc<RwLock<ArrayD<T>>>,
+        max_count: usize,
+        out_values: *mut T,
+        out_indices: *mut usize,
+    ) -> usize {
+        let count = max_count.min(self.remaining);
+        let ndim = self.shape.len();
+        let guard = arr.read();
+        let base = guard.as_ptr();
+
+        for n in 0..count {
+            *out_values.add(n) = *base.add(self.current_offset());
+            if !out_indices.is_null() {
+                std::ptr::copy_nonoverlapping(self.index.as_ptr(), out_indices.add(n * ndim), ndim);
+            }
+            self.advance();
+        }
+
+        count
+    }
+}
+
+/// Create an iterator over an array view.
+///
+/// # Arguments
+/// * `handle` - Array handle
+/// * `meta` - View metadata
+/// * `out_iter` - Output iterator handle, released with `ndarray_iter_free`
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_iter_new(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_iter: *mut *mut NdIterHandle,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_iter.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("iter_new", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+        let shape = meta.shape_slice().to_vec();
+
+        let state = NdIterState {
+            data: wrapper.data.clone(),
+            dtype: wrapper.dtype,
+            offset: meta.offset,
+            strides: meta.strides_slice().to_vec(),
+            index: vec![0; shape.len()],
+            remaining: shape.iter().product(),
+            shape,
+        };
+
+        *out_iter = Box::into_raw(Box::new(state)) as *mut NdIterHandle;
+        SUCCESS
+    })
+}
+
+/// Read the next block of elements from an iterator.
+///
+/// # Arguments
+/// * `iter` - Iterator handle
+/// * `max_count` - Maximum number of elements to read
+/// * `out_values` - Output buffer for at least `max_count` values (type must match array dtype;
+///   Float16 and BFloat16 are written as 16-bit patterns)
+/// * `out_indices` - Optional output buffer for `max_count * ndim` indices (row per element); may be null
+/// * `out_count` - Output: number of elements written (0 once exhausted)
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_iter_next_block(
+    iter: *mut NdIterHandle,
+    max_count: usize,
+    out_values: *mut c_void,
+    out_indices: *mut usize,
+    out_count: *mut usize,
+) -> i32 {
+    if iter.is_null() || out_values.is_null() || out_count.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("iter_next_block", {
+        let state = &mut *(iter as *mut NdIterState);
+        let data = state.data.clone();
+
+        let count = match (&data, state.dtype) {
+            (ArrayData::Int8(a), DType::Int8) => {
+                state.fill(a, max_count, out_values as *mut i8, out_indices)
+            }
+            (ArrayData::Int16(a), DType::Int16) => {
+                state.fill(a, max_count, out_values as *mut i16, out_indices)
+            }
+            (ArrayData::Int32(a), DType::Int32) => {
+                state.fill(a, max_count, out_values as *mut i32, out_indices)
+            }
+            (ArrayData::Int64(a), DType::Int64) => {
+                state.fill(a, max_count, out_values as *mut i64, out_indices)
+            }
+            (ArrayData::Uint8(a), DType::Uint8) => {
+                state.fill(a, max_count, out_values as *mut u8, out_indices)
+            }
+            (ArrayData::Uint16(a), DType::Uint16) => {
+                state.fill(a, max_count, out_values as *mut u16, out_indices)
+            }
+            (ArrayData::Uint32(a), DType::Uint32) => {
+                state.fill(a, max_count, out_values as *mut u32, out_indices)
+            }
+            (ArrayData::Uint64(a), DType::Uint64) => {
+                state.fill(a, max_count, out_values as *mut u64, out_indices)
+            }
+            (ArrayData::Float32(a), DType::Float32) => {
+                state.fill(a, max_count, out_values as *mut f32, out_indices)
+            }
+            (ArrayData::Float64(a), DType::Float64) => {
+                state.fill(a, max_count, out_values as *mut f64, out_indices)
+            }
+            (ArrayData::Bool(a), DType::Bool) => {
+                state.fill(a, max_count, out_values as *mut u8, out_indices)
+            }
+            (ArrayData::Float16(a), DType::Float16) | (ArrayData::BFloat16(a), DType::BFloat16) => {
+                state.fill(a, max_count, out_values as *mut u16, out_indices)
+            }
+            (ArrayData::Complex64(a), DType::Complex64) => state.fill(
+                a,
+                max_count,
+                out_values as *mut num_complex::Complex32,
+                out_indices,
+            ),
+            (ArrayData::Complex128(a), DType::Complex128) => state.fill(
+                a,
+                max_count,
+                out_values as *mut num_complex::Complex64,
+                out_indices,
+            ),
+            _ => {
+                set_last_error("Iterator data does not match its dtype".to_string());
+                return ERR_DTYPE;
+            }
+        };
+
+        *out_count = count;
+        SUCCESS
+    })
+}
+
+/// Rewind an iterator to the first element.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_iter_reset(iter: *mut NdIterHandle) -> i32 {
+    if iter.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("iter_reset", {
+        let state = &mut *(iter as *mut NdIterState);
+        state.index.fill(0);
+        state.remaining = state.shape.iter().product();
+        SUCCESS
+    })
+}
+
+/// Destroy an iterator and release its reference to the array storage.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_iter_free(iter: *mut NdIterHandle) -> i32 {
+    crate::ffi_guard!("iter_free", {
+        if !iter.is_null() {
+            drop(Box::from_raw(iter as *mut NdIterState));
+        }
+        SUCCESS
+    })
+}
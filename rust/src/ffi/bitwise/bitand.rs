@@ -3,7 +3,7 @@
 //! Works with all integer types (signed and unsigned) and Bool.
 
 use crate::binary_op_bitwise;
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::scalar_op_bitwise;
 use crate::types::{ArrayMetadata, DType, NdArrayHandle};
@@ -41,7 +41,7 @@ pub unsafe extern "C" fn ndarray_bitand(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("bitand", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
@@ -94,11 +94,11 @@ pub unsafe extern "C" fn ndarray_bitand_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("bitand_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
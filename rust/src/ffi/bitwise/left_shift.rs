@@ -4,7 +4,7 @@
 //! Bool and float types are not supported.
 
 use crate::binary_op_bitwise;
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::scalar_op_bitwise;
 use crate::types::{ArrayMetadata, DType, NdArrayHandle};
@@ -42,7 +42,7 @@ pub unsafe extern "C" fn ndarray_left_shift(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("left_shift", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
@@ -95,11 +95,11 @@ pub unsafe extern "C" fn ndarray_left_shift_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("left_shift_scalar", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
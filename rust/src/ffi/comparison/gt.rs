@@ -1,7 +1,7 @@
 //! Element-wise greater-than (>) comparison with broadcasting.
 
 use crate::binary_op_comparison;
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::scalar_op_comparison;
 use crate::types::dtype::DType;
@@ -40,7 +40,7 @@ pub unsafe extern "C" fn ndarray_gt(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("gt", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
@@ -94,11 +94,11 @@ pub unsafe extern "C" fn ndarray_gt_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("gt_scalar", {
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
         let result_wrapper =
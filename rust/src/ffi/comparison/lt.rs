@@ -1,7 +1,7 @@
 //! Element-wise less-than (<) comparison with broadcasting.
 
 use crate::binary_op_comparison;
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::scalar_op_comparison;
 use crate::types::dtype::DType;
@@ -40,7 +40,7 @@ pub unsafe extern "C" fn ndarray_lt(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("lt", {
         let a_meta = &*a_meta;
         let b_meta = &*b_meta;
 
@@ -94,11 +94,11 @@ pub unsafe extern "C" fn ndarray_lt_scalar(
         Some(d) => d,
         None => {
             set_last_error("Invalid scalar dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("lt_scalar", {
         let a_meta = &*a_meta;
 
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
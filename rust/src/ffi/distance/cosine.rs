@@ -0,0 +1,105 @@
+//! Cosine similarity matrix between two sets of row vectors.
+
+use ndarray::{Array2, Axis, LinalgScalar};
+use num_traits::Float;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+use super::{check_row_matrices, pair_dtype, rows_f32, rows_f64};
+
+/// Scale every row to unit L2 norm in place; zero rows stay zero.
+fn normalize_rows<F: Float>(m: &mut Array2<F>) {
+    for mut row in m.axis_iter_mut(Axis(0)) {
+        let norm = row.iter().fold(F::zero(), |acc, &x| acc + x * x).sqrt();
+        if norm > F::zero() {
+            row.mapv_inplace(|x| x / norm);
+        }
+    }
+}
+
+/// Normalize both operands and multiply, clamping rounding error to `[-1, 1]`.
+fn cosine_matrix<F: Float + LinalgScalar>(mut a: Array2<F>, mut b: Array2<F>) -> Array2<F> {
+    normalize_rows(&mut a);
+    normalize_rows(&mut b);
+    let mut sim = a.dot(&b.t());
+    sim.mapv_inplace(|x| x.max(-F::one()).min(F::one()));
+    sim
+}
+
+/// Cosine similarity between every row of `a` `(m, d)` and every row of `b` `(n, d)`.
+///
+/// Rows are normalized to unit length and multiplied with a BLAS matmul,
+/// giving an `(m, n)` matrix. Similarities involving a zero vector are 0.
+/// Float32 inputs yield Float32; any other real dtype yields Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_cosine_similarity(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || a_meta.is_null()
+        || b.is_null()
+        || b_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("cosine_similarity", {
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+        let (a_meta, b_meta) = (&*a_meta, &*b_meta);
+
+        if let Err((code, e)) = check_row_matrices(a_wrapper, a_meta, b_wrapper, b_meta) {
+            set_last_error(e);
+            return code;
+        }
+
+        let result = if pair_dtype(a_wrapper.dtype, b_wrapper.dtype) == DType::Float32 {
+            rows_f32(a_wrapper, a_meta)
+                .and_then(|a| Ok(cosine_matrix(a, rows_f32(b_wrapper, b_meta)?)))
+                .map(|sim| NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(sim.into_dyn()))),
+                    dtype: DType::Float32,
+                })
+        } else {
+            rows_f64(a_wrapper, a_meta)
+                .and_then(|a| Ok(cosine_matrix(a, rows_f64(b_wrapper, b_meta)?)))
+                .map(|sim| NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(sim.into_dyn()))),
+                    dtype: DType::Float64,
+                })
+        };
+        let result_wrapper = match result {
+            Ok(w) => w,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_DTYPE;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
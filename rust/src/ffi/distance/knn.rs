@@ -0,0 +1,224 @@
+//! Exact k-nearest-neighbour search without a full distance matrix.
+//!
+//! Each query row scans the database once and keeps its `k` best candidates
+//! in a bounded max-heap, so memory is `O(m * k)` instead of `O(m * n)`.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use ndarray::{Array2, ArrayView1, Axis};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, DistanceMetric, NDArrayWrapper, NdArrayHandle};
+
+use super::{check_row_matrices, pair_dtype, rows_f64};
+
+/// Heap entry ordered by distance, then database index (NaN sorts last).
+#[derive(Clone, Copy)]
+struct Candidate {
+    dist: f64,
+    index: usize,
+}
+
+impl Candidate {
+    fn key(&self) -> f64 {
+        if self.dist.is_nan() {
+            f64::INFINITY
+        } else {
+            self.dist
+        }
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key()
+            .total_cmp(&other.key())
+            .then(self.dist.is_nan().cmp(&other.dist.is_nan()))
+            .then(self.index.cmp(&other.index))
+    }
+}
+
+fn l2_norm(v: ArrayView1<f64>) -> f64 {
+    v.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+fn distance(
+    metric: DistanceMetric,
+    q: ArrayView1<f64>,
+    d: ArrayView1<f64>,
+    q_norm: f64,
+    d_norm: f64,
+) -> f64 {
+    match metric {
+        DistanceMetric::Euclidean => q
+            .iter()
+            .zip(d)
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt(),
+        DistanceMetric::SquaredEuclidean => q.iter().zip(d).map(|(a, b)| (a - b) * (a - b)).sum(),
+        DistanceMetric::Manhattan => q.iter().zip(d).map(|(a, b)| (a - b).abs()).sum(),
+        DistanceMetric::Cosine => {
+            if q_norm == 0.0 || d_norm == 0.0 {
+                // A zero vector has no direction: treat it as orthogonal to everything.
+                1.0
+            } else {
+                1.0 - (q.dot(&d) / (q_norm * d_norm)).clamp(-1.0, 1.0)
+            }
+        }
+    }
+}
+
+/// Indices and distances of the `k` nearest database rows for every query row.
+fn k_nearest(
+    queries: &Array2<f64>,
+    database: &Array2<f64>,
+    metric: DistanceMetric,
+    k: usize,
+) -> (Array2<i64>, Array2<f64>) {
+    let m = queries.nrows();
+    let mut indices = Array2::<i64>::zeros((m, k));
+    let mut distances = Array2::<f64>::zeros((m, k));
+    let norms: Vec<f64> = if metric == DistanceMetric::Cosine {
+        database.axis_iter(Axis(0)).map(l2_norm).collect()
+    } else {
+        vec![0.0; database.nrows()]
+    };
+
+    for (qi, q) in queries.axis_iter(Axis(0)).enumerate() {
+        let q_norm = if metric == DistanceMetric::Cosine {
+            l2_norm(q)
+        } else {
+            0.0
+        };
+        let mut heap: BinaryHeap<Candidate> = BinaryHeap::with_capacity(k + 1);
+        for (index, row) in database.axis_iter(Axis(0)).enumerate() {
+            let candidate = Candidate {
+                dist: distance(metric, q, row, q_norm, norms[index]),
+                index,
+            };
+            if heap.len() < k {
+                heap.push(candidate);
+            } else if heap.peek().is_some_and(|worst| candidate < *worst) {
+                heap.pop();
+                heap.push(candidate);
+            }
+        }
+        for (j, c) in heap.into_sorted_vec().into_iter().enumerate() {
+            indices[[qi, j]] = c.index as i64;
+            distances[[qi, j]] = c.dist;
+        }
+    }
+    (indices, distances)
+}
+
+/// Find the `k` nearest rows of `database` `(n, d)` for each row of `query` `(m, d)`.
+///
+/// `metric`: 0 Euclidean, 1 squared Euclidean, 2 Manhattan, 3 cosine distance
+/// (`1 - cosine similarity`, with zero vectors at distance 1). `k` is clamped
+/// to `n`. Writes Int64 indices and distances, both `(m, min(k, n))`, sorted by
+/// ascending distance with ties broken by lower index. Distances are Float32
+/// when both inputs are Float32, otherwise Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_argmin_distance(
+    query: *const NdArrayHandle,
+    query_meta: *const ArrayMetadata,
+    database: *const NdArrayHandle,
+    database_meta: *const ArrayMetadata,
+    metric: i32,
+    k: usize,
+    out_indices: *mut *mut NdArrayHandle,
+    out_distances: *mut *mut NdArrayHandle,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if query.is_null()
+        || query_meta.is_null()
+        || database.is_null()
+        || database_meta.is_null()
+        || out_indices.is_null()
+        || out_distances.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("argmin_distance", {
+        let q_wrapper = NdArrayHandle::as_wrapper(query as *mut _);
+        let db_wrapper = NdArrayHandle::as_wrapper(database as *mut _);
+        let (q_meta, db_meta) = (&*query_meta, &*database_meta);
+
+        let metric = match DistanceMetric::from_i32(metric) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        if let Err((code, e)) = check_row_matrices(q_wrapper, q_meta, db_wrapper, db_meta) {
+            set_last_error(e);
+            return code;
+        }
+        if max_ndim < 2 {
+            set_last_error("max_ndim too small for output shape".to_string());
+            return ERR_SHAPE;
+        }
+
+        let (queries, db) = match rows_f64(q_wrapper, q_meta)
+            .and_then(|q| Ok((q, rows_f64(db_wrapper, db_meta)?)))
+        {
+            Ok(pair) => pair,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_DTYPE;
+            }
+        };
+
+        let k = k.min(db.nrows());
+        let (indices, distances) = k_nearest(&queries, &db, metric, k);
+
+        let dist_wrapper = if pair_dtype(q_wrapper.dtype, db_wrapper.dtype) == DType::Float32 {
+            NDArrayWrapper {
+                data: ArrayData::Float32(Arc::new(RwLock::new(
+                    distances.mapv(|x| x as f32).into_dyn(),
+                ))),
+                dtype: DType::Float32,
+            }
+        } else {
+            NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(distances.into_dyn()))),
+                dtype: DType::Float64,
+            }
+        };
+        let idx_wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(indices.into_dyn()))),
+            dtype: DType::Int64,
+        };
+
+        *out_shape = queries.nrows();
+        *out_shape.add(1) = k;
+        *out_indices = NdArrayHandle::from_wrapper(Box::new(idx_wrapper));
+        *out_distances = NdArrayHandle::from_wrapper(Box::new(dist_wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,79 @@
+//! Similarity and distance kernels between the rows of two matrices.
+//!
+//! Both operands are 2D `(rows, features)` arrays with the same feature
+//! count. Computation runs in f32 when both inputs are Float32 and in f64
+//! otherwise.
+
+mod cosine;
+mod knn;
+
+pub use cosine::*;
+pub use knn::*;
+
+use ndarray::{Array2, Ix2};
+
+use crate::helpers::error::{ERR_DTYPE, ERR_SHAPE};
+use crate::helpers::{extract_array_as_f32, extract_array_as_f64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NDArrayWrapper};
+
+/// Computation dtype for a pair of operands: Float32 only when both are Float32.
+pub(crate) fn pair_dtype(a: DType, b: DType) -> DType {
+    if a == DType::Float32 && b == DType::Float32 {
+        DType::Float32
+    } else {
+        DType::Float64
+    }
+}
+
+/// Validate that both operands are real 2D matrices with matching feature counts.
+pub(crate) unsafe fn check_row_matrices(
+    a: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    b: &NDArrayWrapper,
+    b_meta: &ArrayMetadata,
+) -> Result<(), (i32, String)> {
+    if a.dtype.is_complex() || b.dtype.is_complex() {
+        return Err((ERR_DTYPE, "Complex arrays are not supported".to_string()));
+    }
+    let (a_shape, b_shape) = (a_meta.shape_slice(), b_meta.shape_slice());
+    if a_shape.len() != 2 || b_shape.len() != 2 {
+        return Err((
+            ERR_SHAPE,
+            format!(
+                "Expected 2D (rows, features) matrices, got {}D and {}D",
+                a_shape.len(),
+                b_shape.len()
+            ),
+        ));
+    }
+    if a_shape[1] != b_shape[1] {
+        return Err((
+            ERR_SHAPE,
+            format!("Feature counts differ: {} and {}", a_shape[1], b_shape[1]),
+        ));
+    }
+    Ok(())
+}
+
+/// Load a row matrix as an owned f64 `Array2`.
+pub(crate) unsafe fn rows_f64(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Result<Array2<f64>, String> {
+    extract_array_as_f64(wrapper, meta)
+        .ok_or_else(|| "Failed to extract array as f64".to_string())?
+        .into_dimensionality::<Ix2>()
+        .map_err(|e| e.to_string())
+}
+
+/// Load a row matrix as an owned f32 `Array2`.
+pub(crate) unsafe fn rows_f32(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Result<Array2<f32>, String> {
+    extract_array_as_f32(wrapper, meta)
+        .ok_or_else(|| "Failed to extract array as f32".to_string())?
+        .into_dimensionality::<Ix2>()
+        .map_err(|e| e.to_string())
+}
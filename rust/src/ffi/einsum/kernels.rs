@@ -1,140 +1,159 @@
-//! Optimized einsum contraction kernels with deterministic accumulation order.
+//! Einsum contraction kernel with deterministic accumulation order.
+//!
+//! Every output element is accumulated over the summed labels in C order of
+//! their first appearance, so equal inputs always give bit-identical results.
 
-use ndarray::{ArrayD, Axis, IxDyn, ScalarOperand};
+use ndarray::{ArrayD, IxDyn};
 use num_traits::Zero;
 use std::ops::{Add, Mul};
 
-/// Deterministic matrix multiplication `ij,jk->ik`.
-pub fn gemm<T>(a: &ArrayD<T>, b: &ArrayD<T>) -> ArrayD<T>
-where
-    T: Copy + ScalarOperand + Zero + Mul<Output = T> + Add<Output = T>,
-{
-    let m = a.shape()[0];
-    let n = a.shape()[1];
-    let p = b.shape()[1];
-    let a_flat = a.as_slice().unwrap();
-    let b_flat = b.as_slice().unwrap();
-    let mut c = ArrayD::zeros(IxDyn(&[m, p]));
-    let c_flat = c.as_slice_mut().unwrap();
-    for i in 0..m {
-        for k in 0..p {
-            let row = i * n;
-            let mut s = T::zero();
-            for j in 0..n {
-                s = s + a_flat[row + j] * b_flat[j * p + k];
-            }
-            c_flat[i * p + k] = s;
-        }
-    }
-    c
+/// A C-contiguous operand described by its per-axis labels.
+pub struct Operand<'a, T> {
+    pub data: &'a [T],
+    pub shape: &'a [usize],
+    pub labels: &'a [char],
 }
 
-pub fn gemm_transposed<T>(a: &ArrayD<T>, b: &ArrayD<T>) -> ArrayD<T>
-where
-    T: Copy + ScalarOperand + Zero + Mul<Output = T> + Add<Output = T>,
-{
-    let m = a.shape()[0];
-    let n = a.shape()[1];
-    let p = b.shape()[0];
-    let asl = a.as_slice().unwrap();
-    let bsl = b.as_slice().unwrap();
-    let mut c = ArrayD::zeros(IxDyn(&[m, p]));
-    let csl = c.as_slice_mut().unwrap();
-    for i in 0..m {
-        for k in 0..p {
-            let ar = i * n;
-            let br = k * n;
-            let mut s = T::zero();
-            for j in 0..n {
-                s = s + asl[ar + j] * bsl[br + j];
+impl<T> Operand<'_, T> {
+    /// Element stride of `label`: the sum of the strides of every axis that
+    /// carries it (so a repeated label walks the diagonal), or 0 if absent.
+    fn label_stride(&self, label: char) -> usize {
+        let mut stride = 1;
+        let mut total = 0;
+        for (axis, &dim) in self.shape.iter().enumerate().rev() {
+            if self.labels[axis] == label {
+                total += stride;
             }
-            csl[i * p + k] = s;
+            stride *= dim;
         }
+        total
     }
-    c
 }
 
-pub fn dot<T>(a: &ArrayD<T>, b: &ArrayD<T>) -> T
-where
-    T: Copy + ScalarOperand + Zero + Mul<Output = T> + Add<Output = T>,
-{
-    let n = a.len();
-    let asl = a.as_slice().unwrap();
-    let bsl = b.as_slice().unwrap();
-    let mut s = T::zero();
-    for i in 0..n {
-        s = s + asl[i] * bsl[i];
+/// Visit every index of `shape` in C order, passing each operand's offset.
+///
+/// `strides[k][d]` is operand `k`'s stride along dimension `d`; offsets start
+/// at `base`.
+fn walk(shape: &[usize], strides: &[Vec<usize>], base: &[usize], mut f: impl FnMut(&[usize])) {
+    if shape.contains(&0) {
+        return;
     }
-    s
-}
-
-pub fn outer<T>(a: &ArrayD<T>, b: &ArrayD<T>) -> ArrayD<T>
-where
-    T: Copy + ScalarOperand + Zero + Mul<Output = T> + Add<Output = T>,
-{
-    let m = a.len();
-    let n = b.len();
-    let asl = a.as_slice().unwrap();
-    let bsl = b.as_slice().unwrap();
-    let mut c = ArrayD::zeros(IxDyn(&[m, n]));
-    let cf = c.as_slice_mut().unwrap();
-    for i in 0..m {
-        let ai = asl[i];
-        let rs = i * n;
-        for j in 0..n {
-            cf[rs + j] = ai * bsl[j];
+    let mut idx = vec![0usize; shape.len()];
+    let mut off = base.to_vec();
+    loop {
+        f(&off);
+        let mut d = shape.len();
+        loop {
+            if d == 0 {
+                return;
+            }
+            d -= 1;
+            idx[d] += 1;
+            for (o, s) in off.iter_mut().zip(strides) {
+                *o += s[d];
+            }
+            if idx[d] < shape[d] {
+                break;
+            }
+            for (o, s) in off.iter_mut().zip(strides) {
+                *o -= s[d] * shape[d];
+            }
+            idx[d] = 0;
         }
     }
-    c
 }
 
-pub fn hadamard<T>(a: &ArrayD<T>, b: &ArrayD<T>) -> ArrayD<T>
+/// Multiply `inputs` element-wise over all their labels and sum out every
+/// label missing from `out_labels`, returning a C-order array labelled
+/// `out_labels`.
+pub fn contract<T>(
+    inputs: &[Operand<'_, T>],
+    out_labels: &[char],
+    size_of: impl Fn(char) -> usize,
+) -> ArrayD<T>
 where
-    T: Copy + ScalarOperand + Zero + Mul<Output = T> + Add<Output = T>,
+    T: Copy + Zero + Mul<Output = T> + Add<Output = T>,
 {
-    let n = a.len();
-    let asl = a.as_slice().unwrap();
-    let bsl = b.as_slice().unwrap();
-    let mut d = Vec::with_capacity(n);
-    for i in 0..n {
-        d.push(asl[i] * bsl[i]);
+    let mut sum_labels: Vec<char> = Vec::new();
+    for op in inputs {
+        for &l in op.labels {
+            if !out_labels.contains(&l) && !sum_labels.contains(&l) {
+                sum_labels.push(l);
+            }
+        }
     }
-    ArrayD::from_shape_vec(IxDyn(a.shape()), d).unwrap()
-}
 
-pub fn trace<T>(a: &ArrayD<T>) -> T
-where
-    T: Copy + ScalarOperand + Zero + Add<Output = T>,
-{
-    a.diag().iter().copied().fold(T::zero(), |acc, x| acc + x)
-}
+    let out_shape: Vec<usize> = out_labels.iter().map(|&l| size_of(l)).collect();
+    let sum_shape: Vec<usize> = sum_labels.iter().map(|&l| size_of(l)).collect();
+    let out_strides: Vec<Vec<usize>> = inputs
+        .iter()
+        .map(|op| out_labels.iter().map(|&l| op.label_stride(l)).collect())
+        .collect();
+    let sum_strides: Vec<Vec<usize>> = inputs
+        .iter()
+        .map(|op| sum_labels.iter().map(|&l| op.label_stride(l)).collect())
+        .collect();
 
-pub fn diagonal<T>(a: &ArrayD<T>) -> ArrayD<T>
-where
-    T: Copy + ScalarOperand + Zero,
-{
-    a.diag().to_owned().into_dyn()
-}
+    let total: usize = out_shape.iter().product();
+    let mut data = Vec::with_capacity(total);
+    let zero = vec![0usize; inputs.len()];
+    walk(&out_shape, &out_strides, &zero, |base| {
+        let mut acc = T::zero();
+        walk(&sum_shape, &sum_strides, base, |off| {
+            let mut p = inputs[0].data[off[0]];
+            for (op, &o) in inputs.iter().zip(off).skip(1) {
+                p = p * op.data[o];
+            }
+            acc = acc + p;
+        });
+        data.push(acc);
+    });
 
-pub fn transpose<T>(a: &ArrayD<T>, perm: &[usize]) -> ArrayD<T>
-where
-    T: Copy + ScalarOperand + Zero,
-{
-    let view = a.view().permuted_axes(perm);
-    let data: Vec<T> = view.iter().copied().collect();
-    ArrayD::from_shape_vec(IxDyn(view.shape()), data).unwrap()
+    ArrayD::from_shape_vec(IxDyn(&out_shape), data).expect("output size matches its shape")
 }
 
-pub fn sum_over_axis<T>(a: &ArrayD<T>, axis: usize) -> ArrayD<T>
-where
-    T: Copy + ScalarOperand + Zero,
-{
-    a.sum_axis(Axis(axis))
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub fn sum_all<T>(a: &ArrayD<T>) -> T
-where
-    T: Copy + ScalarOperand + Zero,
-{
-    a.sum()
+    #[test]
+    fn contract_matmul_and_diagonal() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let b = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let size = |l: char| match l {
+            'i' => 2,
+            'j' => 3,
+            _ => 2,
+        };
+        let out = contract(
+            &[
+                Operand {
+                    data: &a,
+                    shape: &[2, 3],
+                    labels: &['i', 'j'],
+                },
+                Operand {
+                    data: &b,
+                    shape: &[3, 2],
+                    labels: &['j', 'k'],
+                },
+            ],
+            &['i', 'k'],
+            size,
+        );
+        assert_eq!(out.as_slice().unwrap(), &[22.0, 28.0, 49.0, 64.0]);
+
+        let m = [1.0, 2.0, 3.0, 4.0];
+        let trace = contract(
+            &[Operand {
+                data: &m,
+                shape: &[2, 2],
+                labels: &['i', 'i'],
+            }],
+            &[],
+            |_| 2,
+        );
+        assert_eq!(trace.ndim(), 0);
+        assert_eq!(trace[IxDyn(&[])], 5.0);
+    }
 }
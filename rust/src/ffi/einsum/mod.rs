@@ -7,137 +7,91 @@ use std::ffi::CStr;
 use std::ops::{Add, Mul};
 use std::sync::Arc;
 
-use ndarray::{ArrayD, IxDyn, ScalarOperand};
+use ndarray::{ArrayD, CowArray, IxDyn};
 use num_traits::Zero;
 use parking_lot::RwLock;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::helpers::{extract_array_as_f32, extract_array_as_f64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 
+use self::kernels::Operand;
 use self::parser::EinsumSpec;
 
-fn dispatch<T>(a: &ArrayD<T>, b: &ArrayD<T>, spec: &EinsumSpec) -> Result<ArrayD<T>, String>
+/// Evaluate `spec` over `arrays`.
+///
+/// Operands are contracted left to right: each step multiplies the running
+/// result by the next operand and sums out every label that is neither in the
+/// output nor used by a later operand. This keeps intermediates small for
+/// chains such as `ij,jk,kl->il`, and the fixed order keeps results
+/// reproducible.
+fn evaluate<T>(arrays: &[ArrayD<T>], spec: &EinsumSpec) -> ArrayD<T>
 where
-    T: Copy + ScalarOperand + Zero + Mul<Output = T> + Add<Output = T>,
-{
-    if spec.is_elementwise {
-        return Ok(kernels::hadamard(a, b));
-    }
-    if spec.contracted_axes.len() == 1 {
-        let (la, ra) = spec.contracted_axes[0];
-        if spec.out_shape.len() == 2 && a.ndim() == 2 && b.ndim() == 2 && la == 1 && ra == 0 {
-            return Ok(kernels::gemm(a, b));
-        }
-        if spec.out_shape.len() == 2 && a.ndim() == 2 && b.ndim() == 2 && la == 1 && ra == 1 {
-            return Ok(kernels::gemm_transposed(a, b));
-        }
-        if spec.out_shape.is_empty() && a.ndim() == 1 && b.ndim() == 1 {
-            let s = kernels::dot(a, b);
-            return Ok(ArrayD::from_shape_vec(IxDyn(&[]), vec![s]).unwrap());
-        }
-    }
-    if spec.contracted_axes.is_empty()
-        && a.ndim() == 1
-        && b.ndim() == 1
-        && spec.out_shape.len() == 2
-    {
-        return Ok(kernels::outer(a, b));
-    }
-    generic_contract(a, b, spec)
-}
-
-fn dispatch_single<T>(a: &ArrayD<T>, spec: &EinsumSpec) -> Result<ArrayD<T>, String>
-where
-    T: Copy + ScalarOperand + Zero,
+    T: Copy + Zero + Mul<Output = T> + Add<Output = T>,
 {
-    if let Some(ref perm) = spec.permute_order {
-        return Ok(kernels::transpose(a, perm));
-    }
-    if !spec.reduce_axes.is_empty() {
-        if spec.reduce_axes.len() == 1 {
-            return Ok(kernels::sum_over_axis(a, spec.reduce_axes[0]));
-        }
-        if spec.reduce_axes.len() == 2 && spec.reduce_axes[0] == spec.reduce_axes[1] {
-            let s = kernels::trace(a);
-            return Ok(ArrayD::from_shape_vec(IxDyn(&[]), vec![s]).unwrap());
-        }
-        let s = kernels::sum_all(a);
-        return Ok(ArrayD::from_shape_vec(IxDyn(&[]), vec![s]).unwrap());
-    }
-    if spec.out_shape.len() == 1
-        && spec.lhs_shape.len() == 2
-        && spec.lhs_shape[0] == spec.lhs_shape[1]
-    {
-        return Ok(kernels::diagonal(a));
+    let size_of = |l: char| spec.size_of(l);
+    let contiguous: Vec<CowArray<'_, T, IxDyn>> =
+        arrays.iter().map(|a| a.as_standard_layout()).collect();
+    let operand = |k: usize| Operand {
+        data: contiguous[k]
+            .as_slice()
+            .expect("standard layout is contiguous"),
+        shape: contiguous[k].shape(),
+        labels: &spec.operands[k],
+    };
+
+    let last = arrays.len() - 1;
+    if last == 0 {
+        return kernels::contract(&[operand(0)], &spec.output, size_of);
     }
-    Err(format!(
-        "unsupported: shape={:?} out={:?}",
-        spec.lhs_shape, spec.out_shape
-    ))
-}
 
-fn generic_contract<T>(a: &ArrayD<T>, b: &ArrayD<T>, spec: &EinsumSpec) -> Result<ArrayD<T>, String>
-where
-    T: Copy + Zero + Mul<Output = T> + Add<Output = T>,
-{
-    let total: usize = spec.out_shape.iter().product();
-    let mut data = vec![T::zero(); total];
-    for out_idx in 0..total {
-        let mut rem = out_idx;
-        let mut oc = vec![0usize; spec.out_shape.len()];
-        for dim in (0..spec.out_shape.len()).rev() {
-            oc[dim] = rem % spec.out_shape[dim];
-            rem /= spec.out_shape[dim];
-        }
-        let mut ac = vec![0usize; a.ndim()];
-        let mut bc = vec![0usize; b.ndim()];
-        for (op, (src, ax)) in spec.output_map.iter().enumerate() {
-            if *src {
-                ac[*ax] = oc[op];
-            } else {
-                bc[*ax] = oc[op];
+    let mut acc = operand(0).data.to_vec();
+    let mut acc_shape = operand(0).shape.to_vec();
+    let mut acc_labels = spec.operands[0].clone();
+    for k in 1..last {
+        let mut keep = Vec::new();
+        for &l in acc_labels.iter().chain(&spec.operands[k]) {
+            let needed = spec.output.contains(&l)
+                || spec.operands[k + 1..]
+                    .iter()
+                    .any(|labels| labels.contains(&l));
+            if needed && !keep.contains(&l) {
+                keep.push(l);
             }
         }
-        if spec.contracted_axes.is_empty() {
-            data[out_idx] = a.as_slice().unwrap()[flat_index(&ac, a.shape())]
-                * b.as_slice().unwrap()[flat_index(&bc, b.shape())];
-        } else {
-            let (la, ra) = spec.contracted_axes[0];
-            let cs = spec.lhs_shape[la];
-            let mut sum = T::zero();
-            for k in 0..cs {
-                let mut ac2 = ac.clone();
-                let mut bc2 = bc.clone();
-                ac2[la] = k;
-                bc2[ra] = k;
-                sum = sum
-                    + a.as_slice().unwrap()[flat_index(&ac2, a.shape())]
-                        * b.as_slice().unwrap()[flat_index(&bc2, b.shape())];
-            }
-            data[out_idx] = sum;
-        }
-    }
-    Ok(ArrayD::from_shape_vec(IxDyn(&spec.out_shape), data).unwrap())
-}
-
-fn flat_index(coords: &[usize], shape: &[usize]) -> usize {
-    let mut idx = 0;
-    for (i, &c) in coords.iter().enumerate().rev() {
-        idx = idx * shape[i] + c;
+        let lhs = Operand {
+            data: &acc,
+            shape: &acc_shape,
+            labels: &acc_labels,
+        };
+        let result = kernels::contract(&[lhs, operand(k)], &keep, size_of);
+        acc_shape = result.shape().to_vec();
+        acc = result.into_raw_vec_and_offset().0;
+        acc_labels = keep;
     }
-    idx
+    let lhs = Operand {
+        data: &acc,
+        shape: &acc_shape,
+        labels: &acc_labels,
+    };
+    kernels::contract(&[lhs, operand(last)], &spec.output, size_of)
 }
 
-/// FFI entry point for einsum (1 or 2 operands, null b for single-op).
+/// Einstein summation over `num_operands` arrays.
+///
+/// `subscripts` uses NumPy notation, e.g. `ij,jk->ik` (matrix product),
+/// `bqd,bkd->bqk` (batched contraction), `ij->ji` (transpose), `ii->`
+/// (trace) or `ij,jk,kl->il` (chained contraction). Operands are promoted to
+/// a common float dtype; the result is Float32 or Float64.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_einsum(
-    a: *const NdArrayHandle,
-    a_meta: *const ArrayMetadata,
-    b: *const NdArrayHandle,
-    b_meta: *const ArrayMetadata,
+    handles: *const *const NdArrayHandle,
+    metas: *const *const ArrayMetadata,
+    num_operands: usize,
     subscripts: *const std::os::raw::c_char,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
@@ -145,36 +99,40 @@ pub unsafe extern "C" fn ndarray_einsum(
     out_shape_ptr: *mut usize,
     max_ndim: usize,
 ) -> i32 {
-    if a.is_null()
+    if handles.is_null()
+        || metas.is_null()
         || subscripts.is_null()
         || out_handle.is_null()
         || out_dtype.is_null()
         || out_ndim.is_null()
         || out_shape_ptr.is_null()
-        || a_meta.is_null()
+        || num_operands == 0
     {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
-        let a_meta = &*a_meta;
-        let a_shape = a_meta.shape_slice();
-        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
-        let has_b = !b.is_null() && !b_meta.is_null();
-        let b_shape = if has_b {
-            Some((&*b_meta).shape_slice())
-        } else {
-            None
-        };
+    crate::ffi_guard!("einsum", {
+        let handles = std::slice::from_raw_parts(handles, num_operands);
+        let metas = std::slice::from_raw_parts(metas, num_operands);
+        if handles.iter().any(|h| h.is_null()) || metas.iter().any(|m| m.is_null()) {
+            set_last_error("einsum received a null operand".to_string());
+            return ERR_GENERIC;
+        }
+        let wrappers: Vec<&NDArrayWrapper> = handles
+            .iter()
+            .map(|&h| NdArrayHandle::as_wrapper(h as *mut _))
+            .collect();
+        let metas: Vec<&ArrayMetadata> = metas.iter().map(|&m| &*m).collect();
+        let shapes: Vec<&[usize]> = metas.iter().map(|m| m.shape_slice()).collect();
 
         let s = match CStr::from_ptr(subscripts).to_str() {
             Ok(s) => s,
             Err(e) => {
                 set_last_error(format!("Invalid subscripts: {}", e));
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
-        let spec = match parser::parse(s, a_shape, b_shape) {
+        let spec = match parser::parse(s, &shapes) {
             Ok(s) => s,
             Err(e) => {
                 set_last_error(e);
@@ -182,47 +140,31 @@ pub unsafe extern "C" fn ndarray_einsum(
             }
         };
 
-        let out_dtype_val = if has_b {
-            DType::promote(
-                a_wrapper.dtype,
-                NdArrayHandle::as_wrapper(b as *mut _).dtype,
-            )
-        } else {
-            a_wrapper.dtype
-        };
-
-        macro_rules! extract_and_dispatch {
-            ($dtype:ident, $array_data:ident, $extract_fn:ident) => {{
-                let Some(arr) = $extract_fn(a_wrapper, a_meta) else {
-                    set_last_error("Failed to extract array A".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = if has_b {
-                    let Some(arr_b) = $extract_fn(NdArrayHandle::as_wrapper(b as *mut _), &*b_meta)
-                    else {
-                        set_last_error("Failed to extract array B".to_string());
-                        return ERR_GENERIC;
+        let out_dtype_val = wrappers
+            .iter()
+            .skip(1)
+            .fold(wrappers[0].dtype, |acc, w| DType::promote(acc, w.dtype));
+
+        macro_rules! extract_and_evaluate {
+            ($dtype:ident, $extract_fn:ident) => {{
+                let mut arrays = Vec::with_capacity(num_operands);
+                for (i, (w, m)) in wrappers.iter().zip(&metas).enumerate() {
+                    let Some(arr) = $extract_fn(w, m) else {
+                        set_last_error(format!("Failed to extract einsum operand {}", i + 1));
+                        return ERR_DTYPE;
                     };
-                    dispatch(&arr, &arr_b, &spec)
-                } else {
-                    dispatch_single(&arr, &spec)
-                };
-                match result {
-                    Ok(r) => NDArrayWrapper {
-                        data: ArrayData::$array_data(Arc::new(RwLock::new(r))),
-                        dtype: DType::$dtype,
-                    },
-                    Err(e) => {
-                        set_last_error(e);
-                        return ERR_SHAPE;
-                    }
+                    arrays.push(arr);
+                }
+                NDArrayWrapper {
+                    data: ArrayData::$dtype(Arc::new(RwLock::new(evaluate(&arrays, &spec)))),
+                    dtype: DType::$dtype,
                 }
             }};
         }
 
         let result_wrapper = match out_dtype_val {
-            DType::Float64 => extract_and_dispatch!(Float64, Float64, extract_array_f64),
-            DType::Float32 => extract_and_dispatch!(Float32, Float32, extract_array_f32),
+            DType::Float64 => extract_and_evaluate!(Float64, extract_array_as_f64),
+            DType::Float32 => extract_and_evaluate!(Float32, extract_array_as_f32),
             other => {
                 set_last_error(format!(
                     "einsum only supports Float32/Float64, got {:?}",
@@ -117,7 +117,7 @@ pub unsafe extern "C" fn ndarray_fft(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("fft", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -145,7 +145,7 @@ pub unsafe extern "C" fn ndarray_fft(
                     v
                 } else {
                     error::set_last_error("fft: failed to read Complex128 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_complex128(&arr, axis_n, target_n);
                 let out = {
@@ -168,7 +168,7 @@ pub unsafe extern "C" fn ndarray_fft(
                     v
                 } else {
                     error::set_last_error("fft: failed to read Complex64 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_complex64(&arr, axis_n, target_n);
                 let out = {
@@ -186,6 +186,8 @@ pub unsafe extern "C" fn ndarray_fft(
             }
             DType::Float64
             | DType::Float32
+            | DType::Float16
+            | DType::BFloat16
             | DType::Int8
             | DType::Int16
             | DType::Int32
@@ -194,12 +196,15 @@ pub unsafe extern "C" fn ndarray_fft(
             | DType::Uint16
             | DType::Uint32
             | DType::Uint64 => {
-                if matches!(wrapper.dtype, DType::Float32) {
+                if matches!(
+                    wrapper.dtype,
+                    DType::Float32 | DType::Float16 | DType::BFloat16
+                ) {
                     let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                         v
                     } else {
                         error::set_last_error("fft: failed to read Float32 input".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let arr = resize_along_axis_f32(&arr, axis_n, target_n);
                     let c: ArrayD<C64> = arr.mapv(|x| Complex::new(x, 0.0));
@@ -219,7 +224,7 @@ pub unsafe extern "C" fn ndarray_fft(
                         error::set_last_error(
                             "fft: failed to read real input as float64".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let arr = resize_along_axis_f64(&arr, axis_n, target_n);
                     let c: ArrayD<C128> = arr.mapv(|x| Complex::new(x, 0.0));
@@ -279,7 +284,7 @@ pub unsafe extern "C" fn ndarray_ifft(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("ifft", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -307,7 +312,7 @@ pub unsafe extern "C" fn ndarray_ifft(
                     v
                 } else {
                     error::set_last_error("ifft: failed to read Complex128 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_complex128(&arr, axis_n, target_n);
                 let n_ax = arr.shape()[axis_n];
@@ -327,7 +332,7 @@ pub unsafe extern "C" fn ndarray_ifft(
                     v
                 } else {
                     error::set_last_error("ifft: failed to read Complex64 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_complex64(&arr, axis_n, target_n);
                 let n_ax = arr.shape()[axis_n];
@@ -385,7 +390,7 @@ pub unsafe extern "C" fn ndarray_fftn(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("fftn", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -410,7 +415,7 @@ pub unsafe extern "C" fn ndarray_fftn(
                     v
                 } else {
                     error::set_last_error("fftn: failed to read Complex128 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = fftn_c2c_f64(arr, &axes_v, norm);
                 NDArrayWrapper {
@@ -425,7 +430,7 @@ pub unsafe extern "C" fn ndarray_fftn(
                     v
                 } else {
                     error::set_last_error("fftn: failed to read Complex64 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = fftn_c2c_f32(arr, &axes_v, norm);
                 NDArrayWrapper {
@@ -446,7 +451,7 @@ pub unsafe extern "C" fn ndarray_fftn(
                     v
                 } else {
                     error::set_last_error("fftn: failed to read real input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let c: ArrayD<C128> = arr.mapv(|x| Complex::new(x, 0.0));
                 let out = fftn_c2c_f64(c, &axes_v, norm);
@@ -455,12 +460,12 @@ pub unsafe extern "C" fn ndarray_fftn(
                     dtype: DType::Complex128,
                 }
             }
-            DType::Float32 => {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
                 let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                     v
                 } else {
                     error::set_last_error("fftn: failed to read Float32 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let c: ArrayD<C64> = arr.mapv(|x| Complex::new(x, 0.0));
                 let out = fftn_c2c_f32(c, &axes_v, norm);
@@ -514,7 +519,7 @@ pub unsafe extern "C" fn ndarray_ifftn(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("ifftn", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -539,7 +544,7 @@ pub unsafe extern "C" fn ndarray_ifftn(
                     v
                 } else {
                     error::set_last_error("ifftn: failed to read Complex128 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = ifftn_c2c_f64(arr, &axes_v, norm);
                 NDArrayWrapper {
@@ -554,7 +559,7 @@ pub unsafe extern "C" fn ndarray_ifftn(
                     v
                 } else {
                     error::set_last_error("ifftn: failed to read Complex64 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = ifftn_c2c_f32(arr, &axes_v, norm);
                 NDArrayWrapper {
@@ -498,7 +498,7 @@ pub unsafe extern "C" fn ndarray_dct(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("dct", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -537,7 +537,7 @@ pub unsafe extern "C" fn ndarray_dct(
                     v
                 } else {
                     error::set_last_error("dct: failed to read real input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_f64(&arr, axis_n, target_n);
                 let out = match run_dct_f64(&arr, axis_n, dct_type, norm) {
@@ -552,12 +552,12 @@ pub unsafe extern "C" fn ndarray_dct(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
                 let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                     v
                 } else {
                     error::set_last_error("dct: failed to read Float32 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_f32(&arr, axis_n, target_n);
                 let out = match run_dct_f32(&arr, axis_n, dct_type, norm) {
@@ -622,7 +622,7 @@ pub unsafe extern "C" fn ndarray_idct(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("idct", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -661,7 +661,7 @@ pub unsafe extern "C" fn ndarray_idct(
                     v
                 } else {
                     error::set_last_error("idct: failed to read real input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_f64(&arr, axis_n, target_n);
                 let out = match run_idct_f64(&arr, axis_n, dct_type, norm) {
@@ -676,12 +676,12 @@ pub unsafe extern "C" fn ndarray_idct(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
                 let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                     v
                 } else {
                     error::set_last_error("idct: failed to read Float32 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_f32(&arr, axis_n, target_n);
                 let out = match run_idct_f32(&arr, axis_n, dct_type, norm) {
@@ -746,7 +746,7 @@ pub unsafe extern "C" fn ndarray_dctn(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("dctn", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -782,7 +782,7 @@ pub unsafe extern "C" fn ndarray_dctn(
                     v
                 } else {
                     error::set_last_error("dctn: failed to read real input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match dctn_f64(arr, &axes_v, dct_type, norm) {
                     Ok(o) => o,
@@ -796,12 +796,12 @@ pub unsafe extern "C" fn ndarray_dctn(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
                 let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                     v
                 } else {
                     error::set_last_error("dctn: failed to read Float32 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match dctn_f32(arr, &axes_v, dct_type, norm) {
                     Ok(o) => o,
@@ -865,7 +865,7 @@ pub unsafe extern "C" fn ndarray_idctn(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("idctn", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -901,7 +901,7 @@ pub unsafe extern "C" fn ndarray_idctn(
                     v
                 } else {
                     error::set_last_error("idctn: failed to read real input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match idctn_f64(arr, &axes_v, dct_type, norm) {
                     Ok(o) => o,
@@ -915,12 +915,12 @@ pub unsafe extern "C" fn ndarray_idctn(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
                 let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                     v
                 } else {
                     error::set_last_error("idctn: failed to read Float32 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match idctn_f32(arr, &axes_v, dct_type, norm) {
                     Ok(o) => o,
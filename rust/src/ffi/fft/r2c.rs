@@ -45,7 +45,7 @@ pub unsafe extern "C" fn ndarray_rfft(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("rfft", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -66,12 +66,12 @@ pub unsafe extern "C" fn ndarray_rfft(
         let target_n = if n == 0 { len_along } else { n };
 
         let result_wrapper = match wrapper.dtype {
-            DType::Float32 => {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
                 let arr = if let Some(v) = extract_array_as_f32(wrapper, meta_ref) {
                     v
                 } else {
                     error::set_last_error("rfft: failed to read Float32 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_f32(&arr, axis_n, target_n);
                 let n_ax = arr.shape()[axis_n];
@@ -99,7 +99,7 @@ pub unsafe extern "C" fn ndarray_rfft(
                     v
                 } else {
                     error::set_last_error("rfft: failed to read real input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = resize_along_axis_f64(&arr, axis_n, target_n);
                 let n_ax = arr.shape()[axis_n];
@@ -163,7 +163,7 @@ pub unsafe extern "C" fn ndarray_irfft(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("irfft", {
         let meta_ref = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape = wrapper.shape();
@@ -189,7 +189,7 @@ pub unsafe extern "C" fn ndarray_irfft(
                     v
                 } else {
                     error::set_last_error("irfft: failed to read Complex128 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let n_real = match infer_irfft_real_len(m, n) {
                     Ok(n) => n,
@@ -218,7 +218,7 @@ pub unsafe extern "C" fn ndarray_irfft(
                     v
                 } else {
                     error::set_last_error("irfft: failed to read Complex64 input".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let n_real = match infer_irfft_real_len(m, n) {
                     Ok(n) => n,
@@ -5,7 +5,9 @@ use num_complex::Complex;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_OVERFLOW, ERR_VALUE, SUCCESS,
+};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 
@@ -25,7 +27,7 @@ pub unsafe extern "C" fn ndarray_arange(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("arange", {
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
@@ -121,6 +123,9 @@ pub unsafe extern "C" fn ndarray_arange(
                     ))),
                     dtype: DType::Complex128,
                 },
+                DType::Float16 | DType::BFloat16 => {
+                    NDArrayWrapper::from_half_bits(ArrayD::<u16>::zeros(IxDyn(&[0])), dtype_enum)
+                }
             };
             *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
             return SUCCESS;
@@ -130,7 +135,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Int8 => {
                 let s = step as i8;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<i8> = (0..n).map(|i| (start as i8) + (i as i8) * s).collect();
                 let arr = ArrayD::<i8>::from_shape_vec(IxDyn(&[n]), data)
@@ -143,7 +149,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Int16 => {
                 let s = step as i16;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<i16> = (0..n).map(|i| (start as i16) + (i as i16) * s).collect();
                 let arr = ArrayD::<i16>::from_shape_vec(IxDyn(&[n]), data)
@@ -156,7 +163,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Int32 => {
                 let s = step as i32;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<i32> = (0..n).map(|i| (start as i32) + (i as i32) * s).collect();
                 let arr = ArrayD::<i32>::from_shape_vec(IxDyn(&[n]), data)
@@ -169,7 +177,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Int64 => {
                 let s = step as i64;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<i64> = (0..n).map(|i| (start as i64) + (i as i64) * s).collect();
                 let arr = ArrayD::<i64>::from_shape_vec(IxDyn(&[n]), data)
@@ -182,7 +191,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Uint8 => {
                 let s = step as u8;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<u8> = (0..n).map(|i| (start as u8) + (i as u8) * s).collect();
                 let arr = ArrayD::<u8>::from_shape_vec(IxDyn(&[n]), data)
@@ -195,7 +205,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Uint16 => {
                 let s = step as u16;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<u16> = (0..n).map(|i| (start as u16) + (i as u16) * s).collect();
                 let arr = ArrayD::<u16>::from_shape_vec(IxDyn(&[n]), data)
@@ -208,7 +219,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Uint32 => {
                 let s = step as u32;
                 if s == 0 {
-                    return crate::helpers::error::ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<u32> = (0..n).map(|i| (start as u32) + (i as u32) * s).collect();
                 let arr = ArrayD::<u32>::from_shape_vec(IxDyn(&[n]), data)
@@ -221,7 +233,8 @@ pub unsafe extern "C" fn ndarray_arange(
             DType::Uint64 => {
                 let s = step as u64;
                 if s == 0 {
-                    return ERR_GENERIC;
+                    set_last_error("arange() step cannot be zero".to_string());
+                    return ERR_VALUE;
                 }
                 let data: Vec<u64> = (0..n).map(|i| (start as u64) + (i as u64) * s).collect();
                 let arr = ArrayD::<u64>::from_shape_vec(IxDyn(&[n]), data)
@@ -241,6 +254,13 @@ pub unsafe extern "C" fn ndarray_arange(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let s = step as f32;
+                let data: Vec<f32> = (0..n).map(|i| (start as f32) + (i as f32) * s).collect();
+                let arr = ArrayD::<f32>::from_shape_vec(IxDyn(&[n]), data)
+                    .expect("Shape mismatch should not happen");
+                NDArrayWrapper::from_f32_as_half(arr, dtype_enum)
+            }
             DType::Float64 => {
                 let s = step;
                 let data: Vec<f64> = (0..n).map(|i| start + (i as f64) * s).collect();
@@ -284,3 +304,184 @@ pub unsafe extern "C" fn ndarray_arange(
         SUCCESS
     })
 }
+
+/// Exact integer range `start, start + step, ...` up to but excluding `stop`.
+///
+/// Bounds are widened to i128 so the element count and every value are
+/// computed without rounding or overflow; values that do not fit `T` and
+/// counts too large to allocate are errors.
+fn integer_arange<T: TryFrom<i128>>(
+    start: i128,
+    stop: i128,
+    step: i128,
+) -> Result<ArrayD<T>, String> {
+    let n = if step > 0 && start < stop {
+        (stop - start + step - 1) / step
+    } else if step < 0 && start > stop {
+        (start - stop - step - 1) / -step
+    } else {
+        0
+    };
+
+    let n = usize::try_from(n)
+        .ok()
+        .filter(|&n| {
+            n.checked_mul(std::mem::size_of::<T>())
+                .is_some_and(|bytes| bytes <= isize::MAX as usize)
+        })
+        .ok_or_else(|| {
+            format!(
+                "arange() would produce {} elements, too many to allocate",
+                n
+            )
+        })?;
+
+    let data = (0..n)
+        .map(|i| {
+            let value = start + i as i128 * step;
+            T::try_from(value).map_err(|_| {
+                format!(
+                    "arange() value {} is out of range for the requested dtype",
+                    value
+                )
+            })
+        })
+        .collect::<Result<Vec<T>, String>>()?;
+
+    Ok(ArrayD::from_shape_vec(IxDyn(&[n]), data).expect("Shape mismatch should not happen"))
+}
+
+/// Build an integer-dtype arange wrapper, writing its length to `out_len`.
+unsafe fn integer_arange_ffi(
+    start: i128,
+    stop: i128,
+    step: i128,
+    dtype: u8,
+    out_handle: *mut *mut NdArrayHandle,
+    out_len: *mut usize,
+) -> i32 {
+    if step == 0 {
+        set_last_error("arange() step cannot be zero".to_string());
+        return ERR_VALUE;
+    }
+
+    let dtype = match DType::from_u8(dtype) {
+        Some(d) if d.is_signed() || d.is_unsigned() => d,
+        _ => {
+            set_last_error("Exact arange() requires an integer dtype".to_string());
+            return ERR_DTYPE;
+        }
+    };
+
+    macro_rules! int_arange {
+        ($t:ty, $variant:ident) => {
+            match integer_arange::<$t>(start, stop, step) {
+                Ok(arr) => ArrayData::$variant(Arc::new(RwLock::new(arr))),
+                Err(e) => {
+                    set_last_error(e);
+                    return ERR_OVERFLOW;
+                }
+            }
+        };
+    }
+
+    let data = match dtype {
+        DType::Int8 => int_arange!(i8, Int8),
+        DType::Int16 => int_arange!(i16, Int16),
+        DType::Int32 => int_arange!(i32, Int32),
+        DType::Int64 => int_arange!(i64, Int64),
+        DType::Uint8 => int_arange!(u8, Uint8),
+        DType::Uint16 => int_arange!(u16, Uint16),
+        DType::Uint32 => int_arange!(u32, Uint32),
+        _ => int_arange!(u64, Uint64),
+    };
+
+    let wrapper = NDArrayWrapper { data, dtype };
+    *out_len = wrapper.len();
+    *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+    SUCCESS
+}
+
+/// Create an integer range from exact 64-bit signed bounds.
+///
+/// Unlike [`ndarray_arange`], no value passes through f64, so ranges beyond
+/// 2^53 are exact. `dtype` must be an integer type; any element that does not
+/// fit it is an error. The number of elements is written to `out_len`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_arange_i64(
+    start: i64,
+    stop: i64,
+    step: i64,
+    dtype: u8,
+    out_handle: *mut *mut NdArrayHandle,
+    out_len: *mut usize,
+) -> i32 {
+    if out_handle.is_null() || out_len.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("arange_i64", {
+        integer_arange_ffi(
+            start as i128,
+            stop as i128,
+            step as i128,
+            dtype,
+            out_handle,
+            out_len,
+        )
+    })
+}
+
+/// Create an integer range from exact 64-bit unsigned bounds.
+///
+/// Same as [`ndarray_arange_i64`] for bounds above `i64::MAX`; `step` stays
+/// signed so descending ranges are possible.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_arange_u64(
+    start: u64,
+    stop: u64,
+    step: i64,
+    dtype: u8,
+    out_handle: *mut *mut NdArrayHandle,
+    out_len: *mut usize,
+) -> i32 {
+    if out_handle.is_null() || out_len.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("arange_u64", {
+        integer_arange_ffi(
+            start as i128,
+            stop as i128,
+            step as i128,
+            dtype,
+            out_handle,
+            out_len,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::integer_arange;
+
+    #[test]
+    fn integer_ranges_are_exact_beyond_f64_precision() {
+        let big = (1i64 << 53) + 1;
+        let a = integer_arange::<i64>(big as i128, big as i128 + 3, 1).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[big, big + 1, big + 2]);
+
+        let a = integer_arange::<u64>(u64::MAX as i128, u64::MAX as i128 - 4, -2).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[u64::MAX, u64::MAX - 2]);
+
+        let a = integer_arange::<i32>(0, 10, 3).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[0, 3, 6, 9]);
+        assert_eq!(integer_arange::<i32>(5, 0, 1).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn out_of_range_values_and_huge_counts_are_errors() {
+        assert!(integer_arange::<u8>(250, 260, 1).is_err());
+        assert!(integer_arange::<i64>(i64::MIN as i128, i64::MAX as i128, 1).is_err());
+    }
+}
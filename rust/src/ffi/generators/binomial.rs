@@ -0,0 +1,77 @@
+//! Create arrays with binomial random values.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand_distr::{Binomial, Distribution};
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use std::slice;
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Create an Int64 array of binomial samples: the number of successes in `n`
+/// trials with success probability `p`.
+///
+/// Requires `0 <= p <= 1`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_binomial(
+    n: u64,
+    p: f64,
+    shape: *const usize,
+    ndim: usize,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+    if !(0.0..=1.0).contains(&p) {
+        set_last_error(format!("binomial requires 0 <= p <= 1, got {}", p));
+        return ERR_VALUE;
+    }
+
+    crate::ffi_guard!("binomial", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let len = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let dist = match Binomial::new(n, p) {
+            Ok(d) => d,
+            Err(e) => {
+                set_last_error(format!("Invalid binomial params: {}", e));
+                return ERR_VALUE;
+            }
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+
+        let data: Vec<i64> = (0..len)
+            .map(|_| i64::try_from(dist.sample(&mut rng)).unwrap_or(i64::MAX))
+            .collect();
+        let arr = ArrayD::<i64>::from_shape_vec(IxDyn(shape_slice), data)
+            .expect("Shape mismatch should not happen");
+        let wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Int64,
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,109 @@
+//! Create arrays with chi-square random values.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand_distr::{ChiSquared, Distribution};
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use std::slice;
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Create an array of random values from the chi-square distribution with
+/// `df` degrees of freedom.
+///
+/// Supports Float32 and Float64 only. Requires `df > 0`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_chisquare(
+    df: f64,
+    shape: *const usize,
+    ndim: usize,
+    dtype: u8,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+    if !(df > 0.0) {
+        set_last_error(format!("chisquare requires df > 0, got {}", df));
+        return ERR_VALUE;
+    }
+
+    crate::ffi_guard!("chisquare", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let len = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let dtype_enum = match DType::from_u8(dtype) {
+            Some(d) => d,
+            None => return ERR_DTYPE,
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+
+        let wrapper = match dtype_enum {
+            DType::Float32 => {
+                let dist = match ChiSquared::<f32>::new(df as f32) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        set_last_error(format!("Invalid chisquare params: {}", e));
+                        return ERR_VALUE;
+                    }
+                };
+                let data: Vec<f32> = (0..len).map(|_| dist.sample(&mut rng)).collect();
+                let arr = ArrayD::<f32>::from_shape_vec(IxDyn(shape_slice), data)
+                    .expect("Shape mismatch should not happen");
+                NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(arr))),
+                    dtype: DType::Float32,
+                }
+            }
+            DType::Float64 => {
+                let dist = match ChiSquared::<f64>::new(df) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        set_last_error(format!("Invalid chisquare params: {}", e));
+                        return ERR_VALUE;
+                    }
+                };
+                let data: Vec<f64> = (0..len).map(|_| dist.sample(&mut rng)).collect();
+                let arr = ArrayD::<f64>::from_shape_vec(IxDyn(shape_slice), data)
+                    .expect("Shape mismatch should not happen");
+                NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
+                    dtype: DType::Float64,
+                }
+            }
+            DType::Complex64 | DType::Complex128 => {
+                set_last_error("chisquare() not supported for complex dtype".to_string());
+                return ERR_DTYPE;
+            }
+            _ => {
+                set_last_error("chisquare() requires float type (Float64 or Float32)".to_string());
+                return ERR_DTYPE;
+            }
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
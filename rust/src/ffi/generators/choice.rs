@@ -0,0 +1,225 @@
+//! Random sampling of indices from `0..n`, optionally weighted.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand::distr::weighted::WeightedIndex;
+use rand::distr::Distribution;
+use rand::seq::index;
+use rand::{Rng, RngExt};
+use std::slice;
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::extract_array_as_f64;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Check that `weights` are usable probabilities for a population of `n`.
+///
+/// Weights need not sum to one; they are normalized by their total.
+fn validate_weights(weights: &[f64], n: usize) -> Result<(), (i32, String)> {
+    if weights.len() != n {
+        return Err((
+            ERR_SHAPE,
+            format!("choice: p must have {} entries, got {}", n, weights.len()),
+        ));
+    }
+    if weights.iter().any(|w| !w.is_finite() || *w < 0.0) {
+        return Err((
+            ERR_VALUE,
+            "choice: probabilities must be finite and non-negative".to_string(),
+        ));
+    }
+    if weights.iter().sum::<f64>() <= 0.0 {
+        return Err((
+            ERR_VALUE,
+            "choice: probabilities must not all be zero".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Draw `size` indices from `0..n`.
+///
+/// Without replacement, weighted draws use Efraimidis-Spirakis keys: sorting
+/// by `ln(u) / w` gives the same order as drawing one item at a time and
+/// removing it from the pool.
+fn choice_indices<R: Rng + ?Sized>(
+    n: usize,
+    size: usize,
+    replace: bool,
+    weights: Option<&[f64]>,
+    rng: &mut R,
+) -> Result<Vec<usize>, (i32, String)> {
+    if size > 0 && n == 0 {
+        return Err((
+            ERR_VALUE,
+            "choice: cannot take a non-empty sample from an empty population".to_string(),
+        ));
+    }
+
+    match (weights, replace) {
+        (None, true) => Ok((0..size).map(|_| rng.random_range(0..n)).collect()),
+        (None, false) => {
+            if size > n {
+                return Err((
+                    ERR_VALUE,
+                    format!(
+                        "choice: cannot take {} samples without replacement from {}",
+                        size, n
+                    ),
+                ));
+            }
+            Ok(index::sample(rng, n, size).into_vec())
+        }
+        (Some(w), true) => {
+            validate_weights(w, n)?;
+            let dist = WeightedIndex::<f64>::new(w)
+                .map_err(|e| (ERR_VALUE, format!("choice: invalid probabilities: {}", e)))?;
+            Ok((0..size).map(|_| dist.sample(&mut *rng)).collect())
+        }
+        (Some(w), false) => {
+            validate_weights(w, n)?;
+            let mut keyed: Vec<(f64, usize)> = w
+                .iter()
+                .enumerate()
+                .filter(|(_, &wi)| wi > 0.0)
+                .map(|(i, &wi)| (rng.random::<f64>().ln() / wi, i))
+                .collect();
+            if size > keyed.len() {
+                return Err((
+                    ERR_VALUE,
+                    format!(
+                        "choice: only {} entries of p are non-zero, cannot take {} samples without replacement",
+                        keyed.len(),
+                        size
+                    ),
+                ));
+            }
+            keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+            Ok(keyed.into_iter().take(size).map(|(_, i)| i).collect())
+        }
+    }
+}
+
+/// Sample Int64 indices from `0..n` into an array of the given shape.
+///
+/// With `replace == false` every index appears at most once. `p` (nullable,
+/// with `p_meta`) holds one non-negative weight per index; weights are
+/// normalized by their sum. Callers gather values from a 1-D array with
+/// `ndarray_take`.
+///
+/// # Arguments
+/// * `n` - Population size
+/// * `shape` / `ndim` - Output shape
+/// * `replace` - Whether an index may be drawn more than once
+/// * `p` / `p_meta` - Optional 1-D weights of length `n`; both null for uniform
+/// * `has_seed` / `seed` - Optional seed, ignored when `rng` is non-null
+/// * `rng` - Optional generator handle
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_choice(
+    n: usize,
+    shape: *const usize,
+    ndim: usize,
+    replace: bool,
+    p: *const NdArrayHandle,
+    p_meta: *const ArrayMetadata,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() || p.is_null() != p_meta.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("choice", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let size = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let weights = if p.is_null() {
+            None
+        } else {
+            let p_meta = &*p_meta;
+            if p_meta.ndim != 1 {
+                set_last_error(format!(
+                    "choice: p must be 1-dimensional, got shape {:?}",
+                    p_meta.shape_slice()
+                ));
+                return ERR_SHAPE;
+            }
+            let p_wrapper = NdArrayHandle::as_wrapper(p as *mut _);
+            let Some(arr) = extract_array_as_f64(p_wrapper, p_meta) else {
+                set_last_error("Failed to extract p as f64".to_string());
+                return ERR_DTYPE;
+            };
+            Some(arr.iter().copied().collect::<Vec<f64>>())
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+        let indices = match choice_indices(n, size, replace, weights.as_deref(), &mut *rng) {
+            Ok(v) => v,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+
+        let data: Vec<i64> = indices.into_iter().map(|i| i as i64).collect();
+        let arr = ArrayD::<i64>::from_shape_vec(IxDyn(shape_slice), data)
+            .expect("Shape mismatch should not happen");
+        let wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Int64,
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn without_replacement_is_distinct() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let weights = [0.1, 0.0, 0.4, 0.2, 0.3];
+        let mut idx = choice_indices(5, 4, false, Some(&weights[..]), &mut rng).unwrap();
+        idx.sort_unstable();
+        assert_eq!(idx, vec![0, 2, 3, 4]);
+
+        let mut idx = choice_indices(6, 6, false, None, &mut rng).unwrap();
+        idx.sort_unstable();
+        assert_eq!(idx, (0..6).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn zero_weights_are_never_drawn() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let idx = choice_indices(3, 500, true, Some(&[0.0, 2.0, 0.0][..]), &mut rng).unwrap();
+        assert!(idx.iter().all(|&i| i == 1));
+
+        let err = choice_indices(3, 2, false, Some(&[0.0, 2.0, 0.0][..]), &mut rng).unwrap_err();
+        assert_eq!(err.0, ERR_VALUE);
+    }
+}
@@ -22,7 +22,7 @@ pub unsafe extern "C" fn ndarray_eye(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("eye", {
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
@@ -127,6 +127,11 @@ pub unsafe extern "C" fn ndarray_eye(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let arr = Array2::<f32>::zeros((n, m));
+                let arr = fill_eye(arr, k, 1.0);
+                NDArrayWrapper::from_f32_as_half(arr.into_dyn(), dtype_enum)
+            }
             DType::Float64 => {
                 let arr = Array2::<f64>::zeros((n, m));
                 let arr = fill_eye(arr, k, 1.0);
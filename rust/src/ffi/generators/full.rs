@@ -1,40 +1,50 @@
 //! Create an array filled with a specific value.
 
-use ndarray::{ArrayD, IxDyn};
+use ndarray::ArrayD;
 use num_complex::Complex;
 use parking_lot::RwLock;
 use std::os::raw::c_void;
 use std::sync::Arc;
 
-use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use crate::types::{ArrayData, MemoryOrder, NDArrayWrapper, NdArrayHandle};
 use std::slice;
 
 /// Create an array filled with a specific value.
+///
+/// `order` selects C (0) or Fortran (1) storage layout.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_full(
     shape: *const usize,
     ndim: usize,
     value: *const c_void,
     dtype: u8,
+    order: i32,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if shape.is_null() || value.is_null() || out_handle.is_null() {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("full", {
         let shape_slice = slice::from_raw_parts(shape, ndim);
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
         };
+        let order = match MemoryOrder::from_i32(order) {
+            Ok(o) => o,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
 
         let wrapper = match dtype_enum {
             DType::Int8 => {
                 let val = *(value as *const i8);
-                let arr = ArrayD::<i8>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<i8>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Int8(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int8,
@@ -42,7 +52,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Int16 => {
                 let val = *(value as *const i16);
-                let arr = ArrayD::<i16>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<i16>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Int16(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int16,
@@ -50,7 +60,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Int32 => {
                 let val = *(value as *const i32);
-                let arr = ArrayD::<i32>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<i32>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Int32(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int32,
@@ -58,7 +68,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Int64 => {
                 let val = *(value as *const i64);
-                let arr = ArrayD::<i64>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<i64>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int64,
@@ -66,7 +76,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Uint8 => {
                 let val = *(value as *const u8);
-                let arr = ArrayD::<u8>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<u8>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Uint8(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint8,
@@ -74,7 +84,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Uint16 => {
                 let val = *(value as *const u16);
-                let arr = ArrayD::<u16>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<u16>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Uint16(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint16,
@@ -82,7 +92,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Uint32 => {
                 let val = *(value as *const u32);
-                let arr = ArrayD::<u32>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<u32>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Uint32(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint32,
@@ -90,7 +100,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Uint64 => {
                 let val = *(value as *const u64);
-                let arr = ArrayD::<u64>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<u64>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Uint64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint64,
@@ -98,15 +108,20 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Float32 => {
                 let val = *(value as *const f32);
-                let arr = ArrayD::<f32>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<f32>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(arr))),
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let val = *(value as *const u16);
+                let arr = ArrayD::<u16>::from_elem(order.shape(shape_slice), val);
+                NDArrayWrapper::from_half_bits(arr, dtype_enum)
+            }
             DType::Float64 => {
                 let val = *(value as *const f64);
-                let arr = ArrayD::<f64>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<f64>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Float64,
@@ -114,7 +129,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Bool => {
                 let val = *(value as *const u8);
-                let arr = ArrayD::<u8>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<u8>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(arr))),
                     dtype: DType::Bool,
@@ -122,7 +137,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Complex64 => {
                 let val = *(value as *const Complex<f32>);
-                let arr = ArrayD::<Complex<f32>>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<Complex<f32>>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Complex64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Complex64,
@@ -130,7 +145,7 @@ pub unsafe extern "C" fn ndarray_full(
             }
             DType::Complex128 => {
                 let val = *(value as *const Complex<f64>);
-                let arr = ArrayD::<Complex<f64>>::from_elem(IxDyn(shape_slice), val);
+                let arr = ArrayD::<Complex<f64>>::from_elem(order.shape(shape_slice), val);
                 NDArrayWrapper {
                     data: ArrayData::Complex128(Arc::new(RwLock::new(arr))),
                     dtype: DType::Complex128,
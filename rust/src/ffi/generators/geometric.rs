@@ -0,0 +1,77 @@
+//! Create arrays with geometric random values.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand_distr::{Distribution, Geometric};
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use std::slice;
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Create an Int64 array of geometric samples with success probability `p`.
+///
+/// Like NumPy, each value is the number of trials up to and including the
+/// first success, so samples are >= 1. Requires `0 < p <= 1`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_geometric(
+    p: f64,
+    shape: *const usize,
+    ndim: usize,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+    if !(p > 0.0 && p <= 1.0) {
+        set_last_error(format!("geometric requires 0 < p <= 1, got {}", p));
+        return ERR_VALUE;
+    }
+
+    crate::ffi_guard!("geometric", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let len = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let dist = match Geometric::new(p) {
+            Ok(d) => d,
+            Err(e) => {
+                set_last_error(format!("Invalid geometric params: {}", e));
+                return ERR_VALUE;
+            }
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+
+        // `Geometric` counts failures before the first success.
+        let data: Vec<i64> = (0..len)
+            .map(|_| i64::try_from(dist.sample(&mut rng).saturating_add(1)).unwrap_or(i64::MAX))
+            .collect();
+        let arr = ArrayD::<i64>::from_shape_vec(IxDyn(shape_slice), data)
+            .expect("Shape mismatch should not happen");
+        let wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Int64,
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
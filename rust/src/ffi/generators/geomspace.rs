@@ -4,7 +4,7 @@ use ndarray::{Array, ArrayD};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 
@@ -24,7 +24,7 @@ pub unsafe extern "C" fn ndarray_geomspace(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("geomspace", {
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
@@ -36,7 +36,7 @@ pub unsafe extern "C" fn ndarray_geomspace(
                     Some(arr1) => arr1.into_dyn(),
                     None => {
                         set_last_error("geomspace requires start and stop to have the same sign and be non-zero".to_string());
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 NDArrayWrapper {
@@ -49,7 +49,7 @@ pub unsafe extern "C" fn ndarray_geomspace(
                     Some(arr1) => arr1.into_dyn(),
                     None => {
                         set_last_error("geomspace requires start and stop to have the same sign and be non-zero".to_string());
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 NDArrayWrapper {
@@ -59,7 +59,7 @@ pub unsafe extern "C" fn ndarray_geomspace(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("geomspace() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
                 set_last_error("geomspace() requires float type (Float64 or Float32)".to_string());
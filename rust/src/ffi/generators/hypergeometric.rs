@@ -0,0 +1,89 @@
+//! Create arrays with hypergeometric random values.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand_distr::{Distribution, Hypergeometric};
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_GENERIC, ERR_OVERFLOW, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use std::slice;
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Create an Int64 array of hypergeometric samples.
+///
+/// Each value is the number of "good" items among `nsample` items drawn
+/// without replacement from `ngood` good and `nbad` bad items. Requires
+/// `nsample <= ngood + nbad`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_hypergeometric(
+    ngood: u64,
+    nbad: u64,
+    nsample: u64,
+    shape: *const usize,
+    ndim: usize,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let Some(total) = ngood.checked_add(nbad) else {
+        set_last_error("hypergeometric population size overflows u64".to_string());
+        return ERR_OVERFLOW;
+    };
+    if nsample > total {
+        set_last_error(format!(
+            "hypergeometric requires nsample <= ngood + nbad, got {} > {}",
+            nsample, total
+        ));
+        return ERR_VALUE;
+    }
+
+    crate::ffi_guard!("hypergeometric", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let len = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let dist = match Hypergeometric::new(total, ngood, nsample) {
+            Ok(d) => d,
+            Err(e) => {
+                set_last_error(format!("Invalid hypergeometric params: {}", e));
+                return ERR_VALUE;
+            }
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+
+        let data: Vec<i64> = (0..len)
+            .map(|_| i64::try_from(dist.sample(&mut rng)).unwrap_or(i64::MAX))
+            .collect();
+        let arr = ArrayD::<i64>::from_shape_vec(IxDyn(shape_slice), data)
+            .expect("Shape mismatch should not happen");
+        let wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Int64,
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
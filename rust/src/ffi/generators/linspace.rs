@@ -1,16 +1,53 @@
 //! Create evenly spaced numbers over a specified interval.
 
 use ndarray::{Array, ArrayD};
+use num_traits::NumCast;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_OVERFLOW, SUCCESS};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 
+/// Evenly spaced integers, computed in f64 and rounded toward negative
+/// infinity (NumPy's rule for integer `linspace`).
+///
+/// Sample `i` is `start + i * step`; with `endpoint` the last sample is
+/// exactly `stop`. Integer steps are exact, so grids such as
+/// `linspace(0, 10, 6)` come out as `[0, 2, 4, 6, 8, 10]`.
+fn integer_linspace<T: NumCast>(
+    start: f64,
+    stop: f64,
+    num: usize,
+    endpoint: bool,
+) -> Result<ArrayD<T>, String> {
+    let div = if endpoint && num > 1 { num - 1 } else { num };
+    let step = (stop - start) / div as f64;
+
+    let values = (0..num)
+        .map(|i| {
+            let y = if endpoint && num > 1 && i == num - 1 {
+                stop
+            } else {
+                start + i as f64 * step
+            };
+            T::from(y.floor()).ok_or_else(|| {
+                format!(
+                    "linspace() value {} is out of range for the requested dtype",
+                    y.floor()
+                )
+            })
+        })
+        .collect::<Result<Vec<T>, String>>()?;
+
+    Ok(Array::from_vec(values).into_dyn())
+}
+
 /// Create evenly spaced numbers over a specified interval.
 ///
-/// Only supports Float32 and Float64 dtypes.
+/// Float dtypes hold the exact samples. Integer dtypes round each sample
+/// toward negative infinity, as NumPy does; a sample outside the dtype's
+/// range is an error. Bool and complex dtypes are not supported.
 /// The endpoint parameter controls whether stop is included.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_linspace(
@@ -25,7 +62,7 @@ pub unsafe extern "C" fn ndarray_linspace(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("linspace", {
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
@@ -47,6 +84,18 @@ pub unsafe extern "C" fn ndarray_linspace(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let adjusted_stop = if endpoint {
+                    stop as f32
+                } else {
+                    let step = ((stop - start) / (num as f64)) as f32;
+                    (start as f32) + step * ((num - 1) as f32)
+                };
+
+                let arr: ArrayD<f32> = Array::linspace(start as f32, adjusted_stop, num).into_dyn();
+
+                NDArrayWrapper::from_f32_as_half(arr, dtype_enum)
+            }
             DType::Float64 => {
                 let adjusted_stop = if endpoint {
                     stop
@@ -62,12 +111,48 @@ pub unsafe extern "C" fn ndarray_linspace(
                     dtype: DType::Float64,
                 }
             }
+            DType::Int8
+            | DType::Int16
+            | DType::Int32
+            | DType::Int64
+            | DType::Uint8
+            | DType::Uint16
+            | DType::Uint32
+            | DType::Uint64 => {
+                macro_rules! int_linspace {
+                    ($t:ty, $variant:ident) => {
+                        match integer_linspace::<$t>(start, stop, num, endpoint) {
+                            Ok(arr) => ArrayData::$variant(Arc::new(RwLock::new(arr))),
+                            Err(e) => {
+                                set_last_error(e);
+                                return ERR_OVERFLOW;
+                            }
+                        }
+                    };
+                }
+
+                let data = match dtype_enum {
+                    DType::Int8 => int_linspace!(i8, Int8),
+                    DType::Int16 => int_linspace!(i16, Int16),
+                    DType::Int32 => int_linspace!(i32, Int32),
+                    DType::Int64 => int_linspace!(i64, Int64),
+                    DType::Uint8 => int_linspace!(u8, Uint8),
+                    DType::Uint16 => int_linspace!(u16, Uint16),
+                    DType::Uint32 => int_linspace!(u32, Uint32),
+                    _ => int_linspace!(u64, Uint64),
+                };
+
+                NDArrayWrapper {
+                    data,
+                    dtype: dtype_enum,
+                }
+            }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("linspace() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
-            _ => {
-                set_last_error("linspace() requires float type (Float64 or Float32)".to_string());
+            DType::Bool => {
+                set_last_error("linspace() requires a numeric dtype, got Bool".to_string());
                 return ERR_DTYPE;
             }
         };
@@ -76,3 +161,26 @@ pub unsafe extern "C" fn ndarray_linspace(
         SUCCESS
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::integer_linspace;
+
+    #[test]
+    fn integer_samples_round_toward_negative_infinity() {
+        let a = integer_linspace::<i64>(0.0, 10.0, 6, true).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[0, 2, 4, 6, 8, 10]);
+
+        let a = integer_linspace::<i32>(0.0, 1.0, 4, true).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[0, 0, 0, 1]);
+
+        let a = integer_linspace::<i32>(-1.0, 0.0, 3, true).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[-1, -1, 0]);
+
+        let a = integer_linspace::<u8>(0.0, 10.0, 4, false).unwrap();
+        assert_eq!(a.as_slice().unwrap(), &[0, 2, 5, 7]);
+
+        assert!(integer_linspace::<u8>(-1.0, 1.0, 3, true).is_err());
+        assert!(integer_linspace::<i8>(0.0, 300.0, 2, true).is_err());
+    }
+}
@@ -25,7 +25,7 @@ pub unsafe extern "C" fn ndarray_logspace(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("logspace", {
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
@@ -49,7 +49,7 @@ pub unsafe extern "C" fn ndarray_logspace(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("logspace() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
                 set_last_error("logspace() requires float type (Float64 or Float32)".to_string());
@@ -1,29 +1,61 @@
 //! Array generation FFI functions.
 
 pub mod arange;
+pub mod beta;
+pub mod binomial;
+pub mod chisquare;
+pub mod choice;
+pub mod exponential;
 pub mod eye;
 pub mod full;
+pub mod gamma;
+pub mod geometric;
 pub mod geomspace;
+pub mod hypergeometric;
 pub mod linspace;
 pub mod logspace;
+pub mod multivariate_normal;
 pub mod normal;
 pub mod ones;
+pub mod permutation;
+pub mod poisson;
 pub mod randn;
 pub mod random;
 pub mod random_int;
+pub mod rng;
+pub mod shuffle;
+pub mod standard_cauchy;
+pub mod standard_t;
+pub mod tri;
 pub mod uniform;
 pub mod zeros;
 
 pub use arange::ndarray_arange;
+pub use beta::ndarray_beta;
+pub use binomial::ndarray_binomial;
+pub use chisquare::ndarray_chisquare;
+pub use choice::ndarray_choice;
+pub use exponential::ndarray_exponential;
 pub use eye::ndarray_eye;
 pub use full::ndarray_full;
+pub use gamma::ndarray_gamma;
+pub use geometric::ndarray_geometric;
 pub use geomspace::ndarray_geomspace;
+pub use hypergeometric::ndarray_hypergeometric;
 pub use linspace::ndarray_linspace;
 pub use logspace::ndarray_logspace;
+pub use multivariate_normal::ndarray_multivariate_normal;
 pub use normal::ndarray_normal;
 pub use ones::ndarray_ones;
+pub use permutation::ndarray_permutation;
+pub use poisson::ndarray_poisson;
 pub use randn::ndarray_randn;
 pub use random::ndarray_random;
 pub use random_int::ndarray_random_int;
+pub use rng::{ndarray_rng_free, ndarray_rng_from_entropy, ndarray_rng_new, RngHandle};
+pub use shuffle::ndarray_shuffle;
+pub use standard_cauchy::ndarray_standard_cauchy;
+pub use standard_t::ndarray_standard_t;
+pub use tri::ndarray_tri;
 pub use uniform::ndarray_uniform;
 pub use zeros::ndarray_zeros;
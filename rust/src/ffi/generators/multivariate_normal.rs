@@ -0,0 +1,209 @@
+//! Create arrays of multivariate normal samples.
+
+use ndarray::{Array1, Array2, ArrayD, Ix1, Ix2, IxDyn};
+use ndarray_linalg::{cholesky::UPLO, Cholesky};
+use parking_lot::RwLock;
+use rand::Rng;
+use rand_distr::{Distribution, StandardNormal};
+use std::slice;
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_MATH, ERR_SHAPE, SUCCESS};
+use crate::helpers::extract_array_as_f64;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Draw `size` samples as the rows of a `[size, d]` matrix.
+///
+/// Each row is `mean + L z` where `L L^T = cov` is the Cholesky factor and
+/// `z` is standard normal, so `cov` must be symmetric positive-definite.
+fn sample_rows<R: Rng + ?Sized>(
+    mean: &Array1<f64>,
+    cov: &Array2<f64>,
+    size: usize,
+    rng: &mut R,
+) -> Result<Array2<f64>, (i32, String)> {
+    let d = mean.len();
+    if cov.dim() != (d, d) {
+        return Err((
+            ERR_SHAPE,
+            format!(
+                "multivariate_normal: cov must have shape [{}, {}], got {:?}",
+                d,
+                d,
+                cov.shape()
+            ),
+        ));
+    }
+
+    let scale = cov.iter().fold(0.0f64, |m, v| m.max(v.abs()));
+    let asymmetric =
+        (0..d).any(|i| (0..i).any(|j| (cov[[i, j]] - cov[[j, i]]).abs() > 1e-8 * scale));
+    if asymmetric {
+        return Err((
+            ERR_MATH,
+            "multivariate_normal: cov must be symmetric".to_string(),
+        ));
+    }
+
+    let l = cov.cholesky(UPLO::Lower).map_err(|e| {
+        (
+            ERR_MATH,
+            format!("multivariate_normal: cov must be positive-definite ({})", e),
+        )
+    })?;
+
+    let z: Array2<f64> = Array2::from_shape_fn((size, d), |_| StandardNormal.sample(&mut *rng));
+    Ok(z.dot(&l.t()) + mean)
+}
+
+/// Create an array of samples from a multivariate normal distribution.
+///
+/// The output has shape `shape + [d]`, where `d` is the length of `mean`;
+/// `cov` is the `[d, d]` covariance matrix and must be symmetric
+/// positive-definite. Sampling goes through its Cholesky factor.
+///
+/// Supports Float32 and Float64 output; inputs of any real dtype are read as f64.
+///
+/// # Arguments
+/// * `mean` / `mean_meta` - 1-D mean vector
+/// * `cov` / `cov_meta` - 2-D covariance matrix
+/// * `shape` / `ndim` - Number of samples, as a shape
+/// * `dtype` - Output dtype
+/// * `has_seed` / `seed` - Optional seed, ignored when `rng` is non-null
+/// * `rng` - Optional generator handle
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_multivariate_normal(
+    mean: *const NdArrayHandle,
+    mean_meta: *const ArrayMetadata,
+    cov: *const NdArrayHandle,
+    cov_meta: *const ArrayMetadata,
+    shape: *const usize,
+    ndim: usize,
+    dtype: u8,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if mean.is_null()
+        || mean_meta.is_null()
+        || cov.is_null()
+        || cov_meta.is_null()
+        || shape.is_null()
+        || out_handle.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("multivariate_normal", {
+        let dtype_enum = match DType::from_u8(dtype) {
+            Some(d @ (DType::Float32 | DType::Float64)) => d,
+            Some(_) => {
+                set_last_error(
+                    "multivariate_normal() requires float type (Float64 or Float32)".to_string(),
+                );
+                return ERR_DTYPE;
+            }
+            None => return ERR_DTYPE,
+        };
+
+        let mean_wrapper = NdArrayHandle::as_wrapper(mean as *mut _);
+        let cov_wrapper = NdArrayHandle::as_wrapper(cov as *mut _);
+        if mean_wrapper.dtype.is_complex() || cov_wrapper.dtype.is_complex() {
+            set_last_error("multivariate_normal() does not support complex inputs".to_string());
+            return ERR_DTYPE;
+        }
+
+        let (Some(mean_arr), Some(cov_arr)) = (
+            extract_array_as_f64(mean_wrapper, &*mean_meta),
+            extract_array_as_f64(cov_wrapper, &*cov_meta),
+        ) else {
+            set_last_error("Failed to extract multivariate_normal inputs as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let Ok(mean_arr) = mean_arr.into_dimensionality::<Ix1>() else {
+            set_last_error("multivariate_normal: mean must be 1-dimensional".to_string());
+            return ERR_SHAPE;
+        };
+        let Ok(cov_arr) = cov_arr.into_dimensionality::<Ix2>() else {
+            set_last_error("multivariate_normal: cov must be 2-dimensional".to_string());
+            return ERR_SHAPE;
+        };
+
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let size = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+        let mut out_shape = shape_slice.to_vec();
+        out_shape.push(mean_arr.len());
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+        let samples = match sample_rows(&mean_arr, &cov_arr, size, &mut *rng) {
+            Ok(s) => s,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+        let samples: ArrayD<f64> = samples
+            .into_shape_with_order(IxDyn(&out_shape))
+            .expect("Shape mismatch should not happen");
+
+        let wrapper = match dtype_enum {
+            DType::Float32 => NDArrayWrapper {
+                data: ArrayData::Float32(Arc::new(RwLock::new(samples.mapv(|v| v as f32)))),
+                dtype: DType::Float32,
+            },
+            _ => NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(samples))),
+                dtype: DType::Float64,
+            },
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{arr1, arr2, Axis};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn sample_moments_match_parameters() {
+        let mean = arr1(&[1.0, -2.0]);
+        let cov = arr2(&[[2.0, 0.6], [0.6, 1.0]]);
+        let x = sample_rows(&mean, &cov, 20000, &mut StdRng::seed_from_u64(5)).unwrap();
+
+        let m = x.mean_axis(Axis(0)).unwrap();
+        assert!((m[0] - 1.0).abs() < 0.05 && (m[1] + 2.0).abs() < 0.05);
+        let c = (&x - &m).t().dot(&(&x - &m)) / 20000.0;
+        assert!((c[[0, 0]] - 2.0).abs() < 0.1);
+        assert!((c[[0, 1]] - 0.6).abs() < 0.05);
+        assert!((c[[1, 1]] - 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn rejects_indefinite_covariance() {
+        let mean = arr1(&[0.0, 0.0]);
+        let cov = arr2(&[[1.0, 2.0], [2.0, 1.0]]);
+        let err = sample_rows(&mean, &cov, 1, &mut StdRng::seed_from_u64(0)).unwrap_err();
+        assert_eq!(err.0, ERR_MATH);
+    }
+}
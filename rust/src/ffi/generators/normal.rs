@@ -2,12 +2,13 @@
 
 use ndarray::{ArrayD, IxDyn};
 use parking_lot::RwLock;
-use rand::rngs::StdRng;
-use rand::SeedableRng;
 use rand_distr::{Distribution, Normal};
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 use std::slice;
@@ -19,14 +20,6 @@ fn shape_len(shape: &[usize]) -> Result<usize, String> {
         .ok_or_else(|| "Shape product overflow".to_string())
 }
 
-fn build_rng(has_seed: bool, seed: u64) -> StdRng {
-    if has_seed {
-        StdRng::seed_from_u64(seed)
-    } else {
-        StdRng::seed_from_u64(rand::random::<u64>())
-    }
-}
-
 /// Create an array of random values sampled from N(mean, std).
 ///
 /// Supports Float32 and Float64 only.
@@ -39,6 +32,7 @@ pub unsafe extern "C" fn ndarray_normal(
     dtype: u8,
     has_seed: bool,
     seed: u64,
+    rng: *const RngHandle,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if shape.is_null() || out_handle.is_null() {
@@ -46,16 +40,16 @@ pub unsafe extern "C" fn ndarray_normal(
     }
     if !(std > 0.0) {
         set_last_error(format!("normal requires std > 0, got {}", std));
-        return ERR_GENERIC;
+        return ERR_VALUE;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("normal", {
         let shape_slice = slice::from_raw_parts(shape, ndim);
         let len = match shape_len(shape_slice) {
             Ok(v) => v,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_SHAPE;
             }
         };
 
@@ -64,7 +58,7 @@ pub unsafe extern "C" fn ndarray_normal(
             None => return ERR_DTYPE,
         };
 
-        let mut rng = build_rng(has_seed, seed);
+        let mut rng = generator_rng(rng, has_seed, seed);
 
         let wrapper = match dtype_enum {
             DType::Float32 => {
@@ -72,7 +66,7 @@ pub unsafe extern "C" fn ndarray_normal(
                     Ok(d) => d,
                     Err(e) => {
                         set_last_error(format!("Invalid normal params: {}", e));
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<f32> = (0..len).map(|_| dist.sample(&mut rng)).collect();
@@ -88,7 +82,7 @@ pub unsafe extern "C" fn ndarray_normal(
                     Ok(d) => d,
                     Err(e) => {
                         set_last_error(format!("Invalid normal params: {}", e));
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<f64> = (0..len).map(|_| dist.sample(&mut rng)).collect();
@@ -101,7 +95,7 @@ pub unsafe extern "C" fn ndarray_normal(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("normal() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
                 set_last_error("normal() requires float type (Float64 or Float32)".to_string());
@@ -0,0 +1,50 @@
+//! Create a random permutation of `0..n`.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use super::shuffle::fisher_yates_swaps;
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_OVERFLOW, SUCCESS};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+
+/// Create an Int64 array holding a random permutation of `0..n`.
+///
+/// Uses the same swap sequence as `ndarray_shuffle`, so the result matches
+/// shuffling `arange(n)` with the same seed or generator state.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_permutation(
+    n: usize,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+    if i64::try_from(n).is_err() {
+        set_last_error(format!("permutation length {} does not fit in Int64", n));
+        return ERR_OVERFLOW;
+    }
+
+    crate::ffi_guard!("permutation", {
+        let mut rng = generator_rng(rng, has_seed, seed);
+        let mut data: Vec<i64> = (0..n as i64).collect();
+        for (i, j) in fisher_yates_swaps(n, &mut *rng) {
+            data.swap(i, j);
+        }
+
+        let arr = ArrayD::<i64>::from_shape_vec(IxDyn(&[n]), data)
+            .expect("Shape mismatch should not happen");
+        let wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Int64,
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,81 @@
+//! Create arrays with Poisson random values.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand_distr::{Distribution, Poisson};
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use std::slice;
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Create an Int64 array of Poisson samples with expected count `lam`.
+///
+/// Requires `0 <= lam < Poisson::MAX_LAMBDA`; `lam == 0` yields all zeros.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_poisson(
+    lam: f64,
+    shape: *const usize,
+    ndim: usize,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+    if !(0.0..Poisson::<f64>::MAX_LAMBDA).contains(&lam) {
+        set_last_error(format!(
+            "poisson requires 0 <= lam < {}, got {}",
+            Poisson::<f64>::MAX_LAMBDA,
+            lam
+        ));
+        return ERR_VALUE;
+    }
+
+    crate::ffi_guard!("poisson", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let len = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let data: Vec<i64> = if lam == 0.0 {
+            // `Poisson` rejects lam == 0, whose samples are all zero anyway.
+            vec![0; len]
+        } else {
+            let dist = match Poisson::new(lam) {
+                Ok(d) => d,
+                Err(e) => {
+                    set_last_error(format!("Invalid poisson params: {}", e));
+                    return ERR_VALUE;
+                }
+            };
+            let mut rng = generator_rng(rng, has_seed, seed);
+            // Samples are whole-valued floats; `as` saturates on overflow.
+            (0..len).map(|_| dist.sample(&mut rng) as i64).collect()
+        };
+        let arr = ArrayD::<i64>::from_shape_vec(IxDyn(shape_slice), data)
+            .expect("Shape mismatch should not happen");
+        let wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Int64,
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
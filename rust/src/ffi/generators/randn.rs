@@ -2,12 +2,13 @@
 
 use ndarray::{ArrayD, IxDyn};
 use parking_lot::RwLock;
-use rand::rngs::StdRng;
-use rand::SeedableRng;
 use rand_distr::{Distribution, Normal};
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 use std::slice;
@@ -19,14 +20,6 @@ fn shape_len(shape: &[usize]) -> Result<usize, String> {
         .ok_or_else(|| "Shape product overflow".to_string())
 }
 
-fn build_rng(has_seed: bool, seed: u64) -> StdRng {
-    if has_seed {
-        StdRng::seed_from_u64(seed)
-    } else {
-        StdRng::seed_from_u64(rand::random::<u64>())
-    }
-}
-
 /// Create an array of random values sampled from N(0, 1).
 ///
 /// Supports Float32 and Float64 only.
@@ -37,19 +30,20 @@ pub unsafe extern "C" fn ndarray_randn(
     dtype: u8,
     has_seed: bool,
     seed: u64,
+    rng: *const RngHandle,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if shape.is_null() || out_handle.is_null() {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("randn", {
         let shape_slice = slice::from_raw_parts(shape, ndim);
         let len = match shape_len(shape_slice) {
             Ok(v) => v,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_SHAPE;
             }
         };
 
@@ -58,14 +52,14 @@ pub unsafe extern "C" fn ndarray_randn(
             None => return ERR_DTYPE,
         };
 
-        let mut rng = build_rng(has_seed, seed);
+        let mut rng = generator_rng(rng, has_seed, seed);
         let wrapper = match dtype_enum {
             DType::Float32 => {
                 let dist = match Normal::<f32>::new(0.0, 1.0) {
                     Ok(d) => d,
                     Err(e) => {
                         set_last_error(format!("Invalid randn params: {}", e));
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<f32> = (0..len).map(|_| dist.sample(&mut rng)).collect();
@@ -81,7 +75,7 @@ pub unsafe extern "C" fn ndarray_randn(
                     Ok(d) => d,
                     Err(e) => {
                         set_last_error(format!("Invalid randn params: {}", e));
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<f64> = (0..len).map(|_| dist.sample(&mut rng)).collect();
@@ -94,7 +88,7 @@ pub unsafe extern "C" fn ndarray_randn(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("randn() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
                 set_last_error("randn() requires float type (Float64 or Float32)".to_string());
@@ -2,11 +2,11 @@
 
 use ndarray::{ArrayD, IxDyn};
 use parking_lot::RwLock;
-use rand::rngs::StdRng;
-use rand::{RngExt, SeedableRng};
+use rand::RngExt;
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 use std::slice;
@@ -18,14 +18,6 @@ fn shape_len(shape: &[usize]) -> Result<usize, String> {
         .ok_or_else(|| "Shape product overflow".to_string())
 }
 
-fn build_rng(has_seed: bool, seed: u64) -> StdRng {
-    if has_seed {
-        StdRng::seed_from_u64(seed)
-    } else {
-        StdRng::seed_from_u64(rand::random::<u64>())
-    }
-}
-
 /// Create an array of random values sampled uniformly from [0, 1).
 ///
 /// Supports Float32 and Float64 only.
@@ -36,19 +28,20 @@ pub unsafe extern "C" fn ndarray_random(
     dtype: u8,
     has_seed: bool,
     seed: u64,
+    rng: *const RngHandle,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if shape.is_null() || out_handle.is_null() {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("random", {
         let shape_slice = slice::from_raw_parts(shape, ndim);
         let len = match shape_len(shape_slice) {
             Ok(v) => v,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_SHAPE;
             }
         };
 
@@ -57,7 +50,7 @@ pub unsafe extern "C" fn ndarray_random(
             None => return ERR_DTYPE,
         };
 
-        let mut rng = build_rng(has_seed, seed);
+        let mut rng = generator_rng(rng, has_seed, seed);
 
         let wrapper = match dtype_enum {
             DType::Float32 => {
@@ -80,7 +73,7 @@ pub unsafe extern "C" fn ndarray_random(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("random() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
                 set_last_error("random() requires float type (Float64 or Float32)".to_string());
@@ -2,11 +2,13 @@
 
 use ndarray::{ArrayD, IxDyn};
 use parking_lot::RwLock;
-use rand::rngs::StdRng;
-use rand::{RngExt, SeedableRng};
+use rand::RngExt;
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 use std::slice;
@@ -18,14 +20,6 @@ fn shape_len(shape: &[usize]) -> Result<usize, String> {
         .ok_or_else(|| "Shape product overflow".to_string())
 }
 
-fn build_rng(has_seed: bool, seed: u64) -> StdRng {
-    if has_seed {
-        StdRng::seed_from_u64(seed)
-    } else {
-        StdRng::seed_from_u64(rand::random::<u64>())
-    }
-}
-
 fn bounds_i8(low: i64, high: i64) -> Result<(i8, i8), String> {
     let lo = i8::try_from(low).map_err(|_| "low is out of range for Int8".to_string())?;
     let hi = i8::try_from(high).map_err(|_| "high is out of range for Int8".to_string())?;
@@ -120,19 +114,20 @@ pub unsafe extern "C" fn ndarray_random_int(
     dtype: u8,
     has_seed: bool,
     seed: u64,
+    rng: *const RngHandle,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if shape.is_null() || out_handle.is_null() {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("random_int", {
         let shape_slice = slice::from_raw_parts(shape, ndim);
         let len = match shape_len(shape_slice) {
             Ok(v) => v,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_SHAPE;
             }
         };
 
@@ -141,7 +136,7 @@ pub unsafe extern "C" fn ndarray_random_int(
             None => return ERR_DTYPE,
         };
 
-        let mut rng = build_rng(has_seed, seed);
+        let mut rng = generator_rng(rng, has_seed, seed);
 
         let wrapper = match dtype_enum {
             DType::Int8 => {
@@ -149,7 +144,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<i8> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -165,7 +160,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<i16> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -181,7 +176,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<i32> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -197,7 +192,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<i64> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -213,7 +208,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<u8> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -229,7 +224,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<u16> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -245,7 +240,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<u32> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -261,7 +256,7 @@ pub unsafe extern "C" fn ndarray_random_int(
                     Ok(v) => v,
                     Err(e) => {
                         set_last_error(e);
-                        return ERR_GENERIC;
+                        return ERR_VALUE;
                     }
                 };
                 let data: Vec<u64> = (0..len).map(|_| rng.random_range(lo..hi)).collect();
@@ -274,7 +269,7 @@ pub unsafe extern "C" fn ndarray_random_int(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("randomInt() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
                 set_last_error("randomInt() requires integer type (Int8, Int16, Int32, Int64, Uint8, Uint16, Uint32, Uint64)".to_string());
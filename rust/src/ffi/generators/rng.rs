@@ -0,0 +1,153 @@
+//! Seedable random generator handles.
+//!
+//! A generator handle owns a `StdRng` that PHP keeps alive between calls, so a
+//! sequence of draws is reproducible from a single seed. Every random
+//! generator takes an optional handle; when it is null, the call seeds its own
+//! generator from `seed` (or from entropy).
+
+use std::ops::{Deref, DerefMut};
+
+use parking_lot::{Mutex, MutexGuard};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::helpers::error::{ERR_GENERIC, SUCCESS};
+
+/// Opaque pointer type for random generator handles.
+///
+/// PHP holds this pointer and passes it to random generators; it is released
+/// with `ndarray_rng_free`.
+#[repr(C)]
+pub struct RngHandle {
+    _private: [u8; 0],
+}
+
+/// Generator state behind an [`RngHandle`].
+struct RngState {
+    rng: Mutex<StdRng>,
+}
+
+/// Random source for one generator call: a shared handle or a call-local generator.
+pub(crate) enum GeneratorRng<'a> {
+    Shared(MutexGuard<'a, StdRng>),
+    Local(StdRng),
+}
+
+impl Deref for GeneratorRng<'_> {
+    type Target = StdRng;
+
+    fn deref(&self) -> &StdRng {
+        match self {
+            GeneratorRng::Shared(guard) => guard,
+            GeneratorRng::Local(rng) => rng,
+        }
+    }
+}
+
+impl DerefMut for GeneratorRng<'_> {
+    fn deref_mut(&mut self) -> &mut StdRng {
+        match self {
+            GeneratorRng::Shared(guard) => guard,
+            GeneratorRng::Local(rng) => rng,
+        }
+    }
+}
+
+/// Resolve the random source of a generator call.
+///
+/// A non-null `rng` handle takes precedence over `has_seed`/`seed`.
+///
+/// # Safety
+/// `rng` must be null or a live handle from `ndarray_rng_new` /
+/// `ndarray_rng_from_entropy`.
+pub(crate) unsafe fn generator_rng<'a>(
+    rng: *const RngHandle,
+    has_seed: bool,
+    seed: u64,
+) -> GeneratorRng<'a> {
+    if !rng.is_null() {
+        let state = &*(rng as *const RngState);
+        return GeneratorRng::Shared(state.rng.lock());
+    }
+    GeneratorRng::Local(if has_seed {
+        StdRng::seed_from_u64(seed)
+    } else {
+        StdRng::seed_from_u64(rand::random::<u64>())
+    })
+}
+
+fn into_handle(rng: StdRng) -> *mut RngHandle {
+    Box::into_raw(Box::new(RngState {
+        rng: Mutex::new(rng),
+    })) as *mut RngHandle
+}
+
+/// Create a generator handle seeded with `seed`.
+///
+/// Equal seeds produce equal sequences of draws.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rng_new(seed: u64, out_rng: *mut *mut RngHandle) -> i32 {
+    if out_rng.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("rng_new", {
+        *out_rng = into_handle(StdRng::seed_from_u64(seed));
+        SUCCESS
+    })
+}
+
+/// Create a generator handle seeded from operating-system entropy.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rng_from_entropy(out_rng: *mut *mut RngHandle) -> i32 {
+    if out_rng.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("rng_from_entropy", {
+        *out_rng = into_handle(StdRng::seed_from_u64(rand::random::<u64>()));
+        SUCCESS
+    })
+}
+
+/// Release a generator handle. Null is a no-op.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rng_free(rng: *mut RngHandle) -> i32 {
+    crate::ffi_guard!("rng_free", {
+        if !rng.is_null() {
+            drop(Box::from_raw(rng as *mut RngState));
+        }
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::RngExt;
+
+    #[test]
+    fn shared_handle_advances_between_calls() {
+        let mut handle = std::ptr::null_mut();
+        unsafe {
+            assert_eq!(ndarray_rng_new(7, &mut handle), SUCCESS);
+            let first: u64 = generator_rng(handle, false, 0).random();
+            let second: u64 = generator_rng(handle, false, 0).random();
+            assert_ne!(first, second);
+
+            let mut reference = StdRng::seed_from_u64(7);
+            assert_eq!(first, reference.random::<u64>());
+            assert_eq!(second, reference.random::<u64>());
+            assert_eq!(ndarray_rng_free(handle), SUCCESS);
+        }
+    }
+
+    #[test]
+    fn null_handle_uses_seed() {
+        unsafe {
+            let a: u64 = generator_rng(std::ptr::null(), true, 42).random();
+            let b: u64 = generator_rng(std::ptr::null(), true, 42).random();
+            assert_eq!(a, b);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! In-place shuffling of an array along one axis.
+
+use ndarray::{ArrayViewMutD, Axis};
+use rand::{Rng, RngExt};
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::normalize_axis;
+use crate::helpers::view::{
+    extract_view_mut_bool, extract_view_mut_c128, extract_view_mut_c64, extract_view_mut_f32,
+    extract_view_mut_f64, extract_view_mut_half_bits, extract_view_mut_i16, extract_view_mut_i32,
+    extract_view_mut_i64, extract_view_mut_i8, extract_view_mut_u16, extract_view_mut_u32,
+    extract_view_mut_u64, extract_view_mut_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Fisher-Yates swap sequence for `n` items.
+///
+/// Applying the swaps in order to `0..n` yields a uniformly random
+/// permutation; `ndarray_permutation` uses the same sequence, so shuffling
+/// `arange(n)` and `permutation(n)` agree for the same seed.
+pub(crate) fn fisher_yates_swaps<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Vec<(usize, usize)> {
+    (1..n).rev().map(|i| (i, rng.random_range(0..=i))).collect()
+}
+
+/// Apply the swap sequence to every lane along `axis`, which moves whole
+/// sub-arrays (e.g. rows for axis 0) together.
+fn shuffle_view<T>(view: &mut ArrayViewMutD<'_, T>, axis: usize, swaps: &[(usize, usize)]) {
+    for mut lane in view.lanes_mut(Axis(axis)) {
+        for &(i, j) in swaps {
+            lane.swap(i, j);
+        }
+    }
+}
+
+/// Shuffle an array in place along `axis`.
+///
+/// Sub-arrays along the axis are reordered together, so shuffling a
+/// `[samples, features]` matrix along axis 0 permutes its rows. Works for
+/// every dtype and for strided views.
+///
+/// # Arguments
+/// * `handle` / `meta` - Array and view to shuffle
+/// * `axis` - Axis to shuffle along (negative counts from the end)
+/// * `has_seed` / `seed` - Optional seed, ignored when `rng` is non-null
+/// * `rng` - Optional generator handle
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_shuffle(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+) -> i32 {
+    if handle.is_null() || meta.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("shuffle", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+        let swaps = fisher_yates_swaps(meta.shape_slice()[axis], &mut *rng);
+
+        macro_rules! shuffle_as {
+            ($extract:ident) => {{
+                let Some(mut view) = $extract(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype));
+                    return ERR_DTYPE;
+                };
+                shuffle_view(&mut view, axis, &swaps);
+            }};
+        }
+
+        match wrapper.dtype {
+            DType::Int8 => shuffle_as!(extract_view_mut_i8),
+            DType::Int16 => shuffle_as!(extract_view_mut_i16),
+            DType::Int32 => shuffle_as!(extract_view_mut_i32),
+            DType::Int64 => shuffle_as!(extract_view_mut_i64),
+            DType::Uint8 => shuffle_as!(extract_view_mut_u8),
+            DType::Uint16 => shuffle_as!(extract_view_mut_u16),
+            DType::Uint32 => shuffle_as!(extract_view_mut_u32),
+            DType::Uint64 => shuffle_as!(extract_view_mut_u64),
+            DType::Float32 => shuffle_as!(extract_view_mut_f32),
+            DType::Float64 => shuffle_as!(extract_view_mut_f64),
+            DType::Complex64 => shuffle_as!(extract_view_mut_c64),
+            DType::Complex128 => shuffle_as!(extract_view_mut_c128),
+            DType::Bool => shuffle_as!(extract_view_mut_bool),
+            DType::Float16 | DType::BFloat16 => shuffle_as!(extract_view_mut_half_bits),
+        }
+
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{ArrayD, IxDyn};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn rows_move_together() {
+        let mut arr = ArrayD::from_shape_vec(IxDyn(&[4, 2]), vec![0, 0, 1, 1, 2, 2, 3, 3]).unwrap();
+        let swaps = fisher_yates_swaps(4, &mut StdRng::seed_from_u64(3));
+        shuffle_view(&mut arr.view_mut(), 0, &swaps);
+
+        let mut firsts: Vec<i32> = arr.rows().into_iter().map(|r| r[0]).collect();
+        assert!(arr.rows().into_iter().all(|r| r[0] == r[1]));
+        firsts.sort_unstable();
+        assert_eq!(firsts, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,106 @@
+//! Create arrays with standard Cauchy random values.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use rand_distr::{Cauchy, Distribution};
+use std::sync::Arc;
+
+use super::rng::{generator_rng, RngHandle};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use std::slice;
+
+fn shape_len(shape: &[usize]) -> Result<usize, String> {
+    shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| "Shape product overflow".to_string())
+}
+
+/// Create an array of random values from the standard Cauchy distribution
+/// (median 0, scale 1).
+///
+/// Supports Float32 and Float64 only.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_standard_cauchy(
+    shape: *const usize,
+    ndim: usize,
+    dtype: u8,
+    has_seed: bool,
+    seed: u64,
+    rng: *const RngHandle,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if shape.is_null() || out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("standard_cauchy", {
+        let shape_slice = slice::from_raw_parts(shape, ndim);
+        let len = match shape_len(shape_slice) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let dtype_enum = match DType::from_u8(dtype) {
+            Some(d) => d,
+            None => return ERR_DTYPE,
+        };
+
+        let mut rng = generator_rng(rng, has_seed, seed);
+
+        let wrapper = match dtype_enum {
+            DType::Float32 => {
+                let dist = match Cauchy::<f32>::new(0.0, 1.0) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        set_last_error(format!("Invalid standard_cauchy params: {}", e));
+                        return ERR_VALUE;
+                    }
+                };
+                let data: Vec<f32> = (0..len).map(|_| dist.sample(&mut rng)).collect();
+                let arr = ArrayD::<f32>::from_shape_vec(IxDyn(shape_slice), data)
+                    .expect("Shape mismatch should not happen");
+                NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(arr))),
+                    dtype: DType::Float32,
+                }
+            }
+            DType::Float64 => {
+                let dist = match Cauchy::<f64>::new(0.0, 1.0) {
+                    Ok(d) => d,
+                    Err(e) => {
+                        set_last_error(format!("Invalid standard_cauchy params: {}", e));
+                        return ERR_VALUE;
+                    }
+                };
+                let data: Vec<f64> = (0..len).map(|_| dist.sample(&mut rng)).collect();
+                let arr = ArrayD::<f64>::from_shape_vec(IxDyn(shape_slice), data)
+                    .expect("Shape mismatch should not happen");
+                NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
+                    dtype: DType::Float64,
+                }
+            }
+            DType::Complex64 | DType::Complex128 => {
+                set_last_error("standard_cauchy() not supported for complex dtype".to_string());
+                return ERR_DTYPE;
+            }
+            _ => {
+                set_last_error(
+                    "standard_cauchy() requires float type (Float64 or Float32)".to_string(),
+                );
+                return ERR_DTYPE;
+            }
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,80 @@
+//! Create a lower-triangular matrix of ones.
+
+use ndarray::Array2;
+use num_complex::Complex;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+
+/// Create an n x m matrix with ones at and below the k-th diagonal.
+///
+/// Element `(i, j)` is one when `j <= i + k` and zero otherwise, so `k > 0`
+/// extends the ones above the main diagonal and `k < 0` lowers their edge.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_tri(
+    n: usize,
+    m: usize,
+    k: isize,
+    dtype: u8,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if out_handle.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("tri", {
+        let dtype_enum = match DType::from_u8(dtype) {
+            Some(d) => d,
+            None => return ERR_DTYPE,
+        };
+
+        fn fill_tri<T: Clone>(n: usize, m: usize, k: isize, one: T, zero: T) -> Array2<T> {
+            Array2::from_shape_fn((n, m), |(i, j)| {
+                if j as isize <= (i as isize).saturating_add(k) {
+                    one.clone()
+                } else {
+                    zero.clone()
+                }
+            })
+        }
+
+        macro_rules! tri_as {
+            ($variant:ident, $one:expr, $zero:expr) => {
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(
+                        fill_tri(n, m, k, $one, $zero).into_dyn(),
+                    ))),
+                    dtype: DType::$variant,
+                }
+            };
+        }
+
+        let wrapper = match dtype_enum {
+            DType::Int8 => tri_as!(Int8, 1, 0),
+            DType::Int16 => tri_as!(Int16, 1, 0),
+            DType::Int32 => tri_as!(Int32, 1, 0),
+            DType::Int64 => tri_as!(Int64, 1, 0),
+            DType::Uint8 => tri_as!(Uint8, 1, 0),
+            DType::Uint16 => tri_as!(Uint16, 1, 0),
+            DType::Uint32 => tri_as!(Uint32, 1, 0),
+            DType::Uint64 => tri_as!(Uint64, 1, 0),
+            DType::Float32 => tri_as!(Float32, 1.0, 0.0),
+            DType::Float64 => tri_as!(Float64, 1.0, 0.0),
+            DType::Float16 | DType::BFloat16 => {
+                let arr = fill_tri(n, m, k, 1.0f32, 0.0);
+                NDArrayWrapper::from_f32_as_half(arr.into_dyn(), dtype_enum)
+            }
+            DType::Bool => tri_as!(Bool, 1, 0),
+            DType::Complex64 => tri_as!(Complex64, Complex::new(1.0, 0.0), Complex::new(0.0, 0.0)),
+            DType::Complex128 => {
+                tri_as!(Complex128, Complex::new(1.0, 0.0), Complex::new(0.0, 0.0))
+            }
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(wrapper));
+        SUCCESS
+    })
+}
@@ -1,100 +1,115 @@
 //! Create an array filled with zeros.
 
-use ndarray::{ArrayD, IxDyn};
+use ndarray::ArrayD;
 use num_complex::Complex;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
+use crate::types::{ArrayData, MemoryOrder, NDArrayWrapper, NdArrayHandle};
 use std::slice;
 
 /// Create an array filled with zeros.
+///
+/// `order` selects C (0) or Fortran (1) storage layout.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_zeros(
     shape: *const usize,
     ndim: usize,
     dtype: u8,
+    order: i32,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if shape.is_null() || out_handle.is_null() {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("zeros", {
         let shape_slice = slice::from_raw_parts(shape, ndim);
         let dtype_enum = match DType::from_u8(dtype) {
             Some(d) => d,
             None => return ERR_DTYPE,
         };
+        let order = match MemoryOrder::from_i32(order) {
+            Ok(o) => o,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
 
         let wrapper = match dtype_enum {
             DType::Int8 => {
-                let arr = ArrayD::<i8>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<i8>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Int8(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int8,
                 }
             }
             DType::Int16 => {
-                let arr = ArrayD::<i16>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<i16>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Int16(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int16,
                 }
             }
             DType::Int32 => {
-                let arr = ArrayD::<i32>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<i32>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Int32(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int32,
                 }
             }
             DType::Int64 => {
-                let arr = ArrayD::<i64>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<i64>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Int64,
                 }
             }
             DType::Uint8 => {
-                let arr = ArrayD::<u8>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<u8>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Uint8(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint8,
                 }
             }
             DType::Uint16 => {
-                let arr = ArrayD::<u16>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<u16>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Uint16(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint16,
                 }
             }
             DType::Uint32 => {
-                let arr = ArrayD::<u32>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<u32>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Uint32(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint32,
                 }
             }
             DType::Uint64 => {
-                let arr = ArrayD::<u64>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<u64>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Uint64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Uint64,
                 }
             }
             DType::Float32 => {
-                let arr = ArrayD::<f32>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<f32>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(arr))),
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                // All-zero bits are +0.0 in both half formats.
+                let arr = ArrayD::<u16>::zeros(order.shape(shape_slice));
+                NDArrayWrapper::from_half_bits(arr, dtype_enum)
+            }
             DType::Float64 => {
-                let arr = ArrayD::<f64>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<f64>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Float64,
@@ -102,21 +117,21 @@ pub unsafe extern "C" fn ndarray_zeros(
             }
             DType::Bool => {
                 // bool zeros = false
-                let arr = ArrayD::<u8>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<u8>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(arr))),
                     dtype: DType::Bool,
                 }
             }
             DType::Complex64 => {
-                let arr = ArrayD::<Complex<f32>>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<Complex<f32>>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Complex64(Arc::new(RwLock::new(arr))),
                     dtype: DType::Complex64,
                 }
             }
             DType::Complex128 => {
-                let arr = ArrayD::<Complex<f64>>::zeros(IxDyn(shape_slice));
+                let arr = ArrayD::<Complex<f64>>::zeros(order.shape(shape_slice));
                 NDArrayWrapper {
                     data: ArrayData::Complex128(Arc::new(RwLock::new(arr))),
                     dtype: DType::Complex128,
@@ -0,0 +1,107 @@
+//! JPEG codec: decoding via `zune-jpeg`, encoding via `jpeg-encoder`.
+
+use jpeg_encoder::{ColorType, Encoder, SamplingFactor};
+use zune_jpeg::zune_core::colorspace::ColorSpace;
+use zune_jpeg::zune_core::options::DecoderOptions;
+use zune_jpeg::JpegDecoder;
+
+use super::{decoded_len, DecodedImage};
+
+/// Decode a JPEG file to 8-bit gray (1 channel) or RGB (3 channels).
+pub(super) fn decode(bytes: &[u8]) -> Result<DecodedImage, String> {
+    let mut probe = JpegDecoder::new(bytes);
+    probe
+        .decode_headers()
+        .map_err(|e| format!("Failed to decode JPEG: {:?}", e))?;
+    let out_space = match probe.get_input_colorspace() {
+        Some(ColorSpace::Luma) => ColorSpace::Luma,
+        _ => ColorSpace::RGB,
+    };
+    let (width, height) = probe
+        .dimensions()
+        .ok_or_else(|| "Failed to read JPEG dimensions".to_string())?;
+    decoded_len(height, width, out_space.num_components())?;
+
+    let options = DecoderOptions::default().jpeg_set_out_colorspace(out_space);
+    let mut decoder = JpegDecoder::new_with_options(bytes, options);
+    let pixels = decoder
+        .decode()
+        .map_err(|e| format!("Failed to decode JPEG: {:?}", e))?;
+    let info = decoder
+        .info()
+        .ok_or_else(|| "Failed to read JPEG dimensions".to_string())?;
+
+    Ok(DecodedImage {
+        pixels,
+        height: info.height as usize,
+        width: info.width as usize,
+        channels: out_space.num_components(),
+    })
+}
+
+/// Encode 8-bit gray (1 channel) or RGB (3 channels) samples as a baseline JPEG.
+///
+/// Colour images are written without chroma subsampling.
+pub(super) fn encode(
+    pixels: &[u8],
+    height: usize,
+    width: usize,
+    channels: usize,
+    quality: u8,
+) -> Result<Vec<u8>, String> {
+    let color = match channels {
+        1 => ColorType::Luma,
+        3 => ColorType::Rgb,
+        _ => {
+            return Err(format!(
+                "JPEG encoding supports 1 or 3 channels, got {}",
+                channels
+            ))
+        }
+    };
+    let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err("Image is too large for JPEG (max 65535x65535)".to_string());
+    };
+
+    let mut out = Vec::new();
+    let mut encoder = Encoder::new(&mut out, quality);
+    encoder.set_sampling_factor(SamplingFactor::R_4_4_4);
+    encoder
+        .encode(pixels, width, height, color)
+        .map_err(|e| format!("Failed to encode JPEG: {}", e))?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+
+    fn max_error(a: &[u8], b: &[u8]) -> u8 {
+        a.iter().zip(b).map(|(x, y)| x.abs_diff(*y)).max().unwrap()
+    }
+
+    #[test]
+    fn round_trips_rgb_within_tolerance() {
+        let (h, w) = (19, 13);
+        let pixels: Vec<u8> = (0..h * w)
+            .flat_map(|i| {
+                let (y, x) = (i / w, i % w);
+                [(x * 16) as u8, (y * 12) as u8, 128]
+            })
+            .collect();
+        let jpeg = encode(&pixels, h, w, 3, 95).unwrap();
+        let img = decode(&jpeg).unwrap();
+        assert_eq!((img.height, img.width, img.channels), (h, w, 3));
+        assert!(max_error(&img.pixels, &pixels) <= 12);
+    }
+
+    #[test]
+    fn round_trips_grayscale_as_single_channel() {
+        let (h, w) = (8, 16);
+        let pixels: Vec<u8> = (0..h * w).map(|i| (i % w * 15) as u8).collect();
+        let jpeg = encode(&pixels, h, w, 1, 90).unwrap();
+        let img = decode(&jpeg).unwrap();
+        assert_eq!((img.height, img.width, img.channels), (h, w, 1));
+        assert!(max_error(&img.pixels, &pixels) <= 12);
+    }
+}
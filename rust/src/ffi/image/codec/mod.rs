@@ -0,0 +1,268 @@
+//! PNG and JPEG decoding/encoding for image tensors.
+//!
+//! Codecs are only compiled with the `image` cargo feature. The FFI entry
+//! points are always exported so the C header stays stable; without the
+//! feature they fail with a descriptive error.
+
+#[cfg(feature = "image")]
+mod jpeg;
+#[cfg(feature = "image")]
+mod png;
+
+use std::slice;
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{extract_array_u8, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Encoded image container format. Integer values match PHP `ImageFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageFormat {
+    Png = 0,
+    Jpeg = 1,
+}
+
+impl ImageFormat {
+    pub(crate) fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(ImageFormat::Png),
+            1 => Ok(ImageFormat::Jpeg),
+            _ => Err(format!("Invalid image format: {}", value)),
+        }
+    }
+}
+
+/// Largest decoded image accepted, in bytes of 8-bit samples (1 GiB).
+///
+/// Image dimensions come from the untrusted file header, so the pixel buffer
+/// size is checked against this limit before anything is allocated.
+#[cfg(feature = "image")]
+pub(crate) const MAX_DECODED_BYTES: usize = 1 << 30;
+
+/// Byte size of a decoded `(H, W, C)` image, or an error when the product
+/// overflows or exceeds [`MAX_DECODED_BYTES`].
+#[cfg(feature = "image")]
+fn decoded_len(height: usize, width: usize, channels: usize) -> Result<usize, String> {
+    height
+        .checked_mul(width)
+        .and_then(|n| n.checked_mul(channels))
+        .filter(|&n| n <= MAX_DECODED_BYTES)
+        .ok_or_else(|| {
+            format!(
+                "Decoded image of {}x{}x{} exceeds the {} byte limit",
+                height, width, channels, MAX_DECODED_BYTES
+            )
+        })
+}
+
+/// Interleaved 8-bit samples in row-major `(H, W, C)` order.
+#[cfg(feature = "image")]
+pub(crate) struct DecodedImage {
+    pub(crate) pixels: Vec<u8>,
+    pub(crate) height: usize,
+    pub(crate) width: usize,
+    pub(crate) channels: usize,
+}
+
+#[cfg(feature = "image")]
+fn decode_bytes(bytes: &[u8]) -> Result<(Vec<u8>, [usize; 3]), String> {
+    let image = if bytes.starts_with(&png::SIGNATURE) {
+        png::decode(bytes)?
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        jpeg::decode(bytes)?
+    } else {
+        return Err("Unrecognised image format (expected PNG or JPEG data)".to_string());
+    };
+    Ok((image.pixels, [image.height, image.width, image.channels]))
+}
+
+#[cfg(feature = "image")]
+fn encode_pixels(
+    pixels: &[u8],
+    shape: [usize; 3],
+    format: ImageFormat,
+    quality: u8,
+) -> Result<Vec<u8>, String> {
+    let [height, width, channels] = shape;
+    match format {
+        ImageFormat::Png => png::encode(pixels, height, width, channels),
+        ImageFormat::Jpeg => jpeg::encode(pixels, height, width, channels, quality),
+    }
+}
+
+#[cfg(not(feature = "image"))]
+const FEATURE_DISABLED: &str =
+    "Image codecs are unavailable: the library was built without the `image` feature";
+
+#[cfg(not(feature = "image"))]
+fn decode_bytes(_bytes: &[u8]) -> Result<(Vec<u8>, [usize; 3]), String> {
+    Err(FEATURE_DISABLED.to_string())
+}
+
+#[cfg(not(feature = "image"))]
+fn encode_pixels(
+    _pixels: &[u8],
+    _shape: [usize; 3],
+    _format: ImageFormat,
+    _quality: u8,
+) -> Result<Vec<u8>, String> {
+    Err(FEATURE_DISABLED.to_string())
+}
+
+fn uint8_wrapper(data: Vec<u8>, shape: &[usize]) -> Result<NDArrayWrapper, String> {
+    let arr = ArrayD::from_shape_vec(IxDyn(shape), data)
+        .map_err(|e| format!("Invalid decoded image shape: {}", e))?;
+    Ok(NDArrayWrapper {
+        data: ArrayData::Uint8(Arc::new(RwLock::new(arr))),
+        dtype: DType::Uint8,
+    })
+}
+
+/// Decode a PNG or JPEG file into a Uint8 `(H, W, C)` array.
+///
+/// The format is detected from the file signature. PNG keeps its channel
+/// count (gray, gray+alpha, RGB, RGBA; palettes expand to RGB/RGBA and 16-bit
+/// samples are reduced to 8 bits). JPEG decodes to 1 channel for grayscale
+/// files and RGB otherwise. Images whose decoded size would exceed 1 GiB are
+/// rejected before any pixel buffer is allocated. Requires the `image` feature.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_decode(
+    bytes: *const u8,
+    len: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if bytes.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("image_decode", {
+        let bytes = slice::from_raw_parts(bytes, len);
+        let result_wrapper =
+            match decode_bytes(bytes).and_then(|(pixels, shape)| uint8_wrapper(pixels, &shape)) {
+                Ok(w) => w,
+                Err(e) => {
+                    set_last_error(e);
+                    return ERR_GENERIC;
+                }
+            };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Encode a Uint8 `(H, W)` or `(H, W, C)` image as PNG or JPEG bytes.
+///
+/// `format` is 0 for PNG (1-4 channels, lossless) or 1 for JPEG (1 or 3
+/// channels, baseline, `quality` 1-100). The encoded file is returned as a
+/// 1D Uint8 array. Requires the `image` feature.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_encode(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    format: i32,
+    quality: u8,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("image_encode", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let format = match ImageFormat::from_i32(format) {
+            Ok(f) => f,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        if !(1..=100).contains(&quality) {
+            set_last_error(format!("Image quality must be in 1..=100, got {}", quality));
+            return ERR_VALUE;
+        }
+        if wrapper.dtype != DType::Uint8 {
+            set_last_error(format!(
+                "Image encoding requires a uint8 array, got {}",
+                wrapper.dtype.name()
+            ));
+            return ERR_DTYPE;
+        }
+        let shape = meta.shape_slice();
+        let shape = match *shape {
+            [h, w] => [h, w, 1],
+            [h, w, c] => [h, w, c],
+            _ => {
+                set_last_error(format!(
+                    "Image encoding requires a 2D (H, W) or 3D (H, W, C) array, got {} dimensions",
+                    meta.ndim
+                ));
+                return ERR_SHAPE;
+            }
+        };
+        if shape.contains(&0) {
+            set_last_error("Cannot encode an empty image".to_string());
+            return ERR_SHAPE;
+        }
+
+        let Some(arr) = extract_array_u8(wrapper, meta) else {
+            set_last_error("Failed to extract Uint8 view".to_string());
+            return ERR_DTYPE;
+        };
+        let pixels: Vec<u8> = arr.iter().copied().collect();
+
+        let result_wrapper = match encode_pixels(&pixels, shape, format, quality).and_then(|data| {
+            let len = data.len();
+            uint8_wrapper(data, &[len])
+        }) {
+            Ok(w) => w,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_GENERIC;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
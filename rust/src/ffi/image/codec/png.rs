@@ -0,0 +1,214 @@
+//! PNG codec on top of the `png` crate.
+//!
+//! Decodes every standard colour type and bit depth, including palettes and
+//! Adam7 interlacing, to 8-bit samples. Encodes 8-bit gray, gray+alpha, RGB
+//! and RGBA with adaptive per-row filtering.
+
+use png::{AdaptiveFilterType, BitDepth, ColorType, Decoder, Encoder, Limits, Transformations};
+
+use super::{decoded_len, DecodedImage, MAX_DECODED_BYTES};
+
+pub(super) const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+/// Palette index of pixel `x` in a packed row of `depth`-bit indices.
+fn palette_index(row: &[u8], x: usize, depth: u8) -> usize {
+    if depth == 8 {
+        return row[x] as usize;
+    }
+    let bit = x * depth as usize;
+    let shift = 8 - depth as usize - bit % 8;
+    ((row[bit / 8] >> shift) & ((1u8 << depth) - 1)) as usize
+}
+
+/// Decode a PNG file to 8-bit interleaved samples.
+///
+/// Palette images expand to RGB, or RGBA when a `tRNS` chunk is present; an
+/// index past the end of the palette is an error. Other colour types keep
+/// their channels (plus alpha from `tRNS`), with 16-bit samples reduced to
+/// 8 bits and lower depths scaled up.
+pub(super) fn decode(bytes: &[u8]) -> Result<DecodedImage, String> {
+    let decode_error = |e: png::DecodingError| format!("Failed to decode PNG: {}", e);
+
+    let mut decoder = Decoder::new_with_limits(
+        bytes,
+        Limits {
+            bytes: MAX_DECODED_BYTES,
+        },
+    );
+    let indexed =
+        decoder.read_header_info().map_err(decode_error)?.color_type == ColorType::Indexed;
+    // The crate maps out-of-range palette indices to black, so palettes are
+    // decoded as raw indices and expanded below.
+    decoder.set_transformations(if indexed {
+        Transformations::IDENTITY
+    } else {
+        Transformations::EXPAND | Transformations::STRIP_16
+    });
+    let mut reader = decoder.read_info().map_err(decode_error)?;
+
+    let info = reader.info();
+    let (width, height) = (info.width as usize, info.height as usize);
+    let depth = info.bit_depth as u8;
+    let palette = info.palette.as_deref().map(<[u8]>::to_vec);
+    let trns = info.trns.as_deref().map(<[u8]>::to_vec);
+    let channels = match (indexed, &trns) {
+        (true, Some(_)) => 4,
+        (true, None) => 3,
+        (false, _) => reader.output_color_type().0.samples(),
+    };
+    let len = decoded_len(height, width, channels)?;
+
+    let mut raw = vec![0u8; reader.output_buffer_size()];
+    let frame = reader.next_frame(&mut raw).map_err(decode_error)?;
+    if !indexed {
+        raw.truncate(len);
+        return Ok(DecodedImage {
+            pixels: raw,
+            height,
+            width,
+            channels,
+        });
+    }
+
+    let palette = palette.ok_or_else(|| "Palette PNG is missing its PLTE chunk".to_string())?;
+    let entries = palette.len() / 3;
+    let mut pixels = Vec::with_capacity(len);
+    for row in raw.chunks_exact(frame.line_size).take(height) {
+        for x in 0..width {
+            let index = palette_index(row, x, depth);
+            if index >= entries {
+                return Err(format!(
+                    "PNG palette index {} is out of range for a palette of {} entries",
+                    index, entries
+                ));
+            }
+            pixels.extend_from_slice(&palette[3 * index..3 * index + 3]);
+            if let Some(alpha) = &trns {
+                pixels.push(alpha.get(index).copied().unwrap_or(255));
+            }
+        }
+    }
+
+    Ok(DecodedImage {
+        pixels,
+        height,
+        width,
+        channels,
+    })
+}
+
+/// Encode 8-bit interleaved samples with 1-4 channels as a PNG file.
+pub(super) fn encode(
+    pixels: &[u8],
+    height: usize,
+    width: usize,
+    channels: usize,
+) -> Result<Vec<u8>, String> {
+    let color = match channels {
+        1 => ColorType::Grayscale,
+        2 => ColorType::GrayscaleAlpha,
+        3 => ColorType::Rgb,
+        4 => ColorType::Rgba,
+        _ => {
+            return Err(format!(
+                "PNG encoding supports 1-4 channels, got {}",
+                channels
+            ))
+        }
+    };
+    let (Ok(width), Ok(height)) = (u32::try_from(width), u32::try_from(height)) else {
+        return Err("Image is too large for PNG".to_string());
+    };
+    let encode_error = |e: png::EncodingError| format!("Failed to encode PNG: {}", e);
+
+    let mut out = Vec::new();
+    let mut encoder = Encoder::new(&mut out, width, height);
+    encoder.set_color(color);
+    encoder.set_depth(BitDepth::Eight);
+    encoder.set_adaptive_filter(AdaptiveFilterType::Adaptive);
+    let mut writer = encoder.write_header().map_err(encode_error)?;
+    writer.write_image_data(pixels).map_err(encode_error)?;
+    writer.finish().map_err(encode_error)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode};
+    use flate2::Crc;
+
+    fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        out.extend_from_slice(kind);
+        out.extend_from_slice(data);
+        let mut crc = Crc::new();
+        crc.update(kind);
+        crc.update(data);
+        out.extend_from_slice(&crc.sum().to_be_bytes());
+    }
+
+    #[test]
+    fn round_trips_every_channel_count() {
+        for channels in 1..=4 {
+            let (h, w) = (5, 7);
+            let pixels: Vec<u8> = (0..h * w * channels)
+                .map(|i| (i * 37 % 256) as u8)
+                .collect();
+            let png = encode(&pixels, h, w, channels).unwrap();
+            let img = decode(&png).unwrap();
+            assert_eq!((img.height, img.width, img.channels), (h, w, channels));
+            assert_eq!(img.pixels, pixels);
+        }
+    }
+
+    #[test]
+    fn rejects_corrupt_crc() {
+        let mut png = encode(&[1, 2, 3, 4], 2, 2, 1).unwrap();
+        png[20] ^= 0xFF;
+        assert!(decode(&png).is_err());
+    }
+
+    #[test]
+    fn expands_palettes_and_rejects_out_of_range_indices() {
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, 3, 1);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(vec![10, 20, 30, 40, 50, 60]);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[1, 0, 1]).unwrap();
+        writer.finish().unwrap();
+        let img = decode(&out).unwrap();
+        assert_eq!(img.channels, 3);
+        assert_eq!(img.pixels, vec![40, 50, 60, 10, 20, 30, 40, 50, 60]);
+
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, 2, 1);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(vec![10, 20, 30]);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[0, 7]).unwrap();
+        writer.finish().unwrap();
+        let err = decode(&out).err().unwrap();
+        assert!(err.contains("palette index 7"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_oversized_dimensions_before_allocating() {
+        let mut png = super::SIGNATURE.to_vec();
+        let mut ihdr = Vec::new();
+        ihdr.extend_from_slice(&100_000u32.to_be_bytes());
+        ihdr.extend_from_slice(&100_000u32.to_be_bytes());
+        ihdr.extend_from_slice(&[8, 6, 0, 0, 0]);
+        chunk(&mut png, b"IHDR", &ihdr);
+        chunk(
+            &mut png,
+            b"IDAT",
+            &[0x78, 0x9C, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01],
+        );
+        chunk(&mut png, b"IEND", &[]);
+        let err = decode(&png).err().unwrap();
+        assert!(err.contains("byte limit"), "{}", err);
+    }
+}
@@ -0,0 +1,218 @@
+//! Colour conversions: RGB <-> grayscale and RGB <-> BGR channel order.
+
+use ndarray::{concatenate, s, Array3, Axis};
+
+use super::{check_image_dtype, from_planes, to_planes, ImageLayout};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// ITU-R BT.601 luma weights, as used by PIL's `convert("L")` and OpenCV.
+const LUMA_WEIGHTS: [f64; 3] = [0.299, 0.587, 0.114];
+
+#[derive(Clone, Copy)]
+enum ColorOp {
+    RgbToGray { keep_channel_axis: bool },
+    GrayToRgb,
+    SwapRedBlue,
+}
+
+fn apply_color_op(
+    planes: Array3<f64>,
+    op: ColorOp,
+    layout: ImageLayout,
+    dtype: DType,
+    was_2d: bool,
+) -> Result<NDArrayWrapper, String> {
+    let channels = planes.len_of(Axis(0));
+    match op {
+        ColorOp::RgbToGray { keep_channel_axis } => {
+            if was_2d || (channels != 3 && channels != 4) {
+                return Err(format!(
+                    "RGB to grayscale requires 3 or 4 channels, got {}",
+                    if was_2d { 1 } else { channels }
+                ));
+            }
+            let gray = &planes.index_axis(Axis(0), 0) * LUMA_WEIGHTS[0]
+                + &planes.index_axis(Axis(0), 1) * LUMA_WEIGHTS[1]
+                + &planes.index_axis(Axis(0), 2) * LUMA_WEIGHTS[2];
+            Ok(from_planes(
+                gray.insert_axis(Axis(0)),
+                layout,
+                dtype,
+                !keep_channel_axis,
+            ))
+        }
+        ColorOp::GrayToRgb => {
+            if channels != 1 {
+                return Err(format!(
+                    "Grayscale to RGB requires a single channel, got {}",
+                    channels
+                ));
+            }
+            let view = planes.view();
+            let rgb = concatenate(Axis(0), &[view, view, view])
+                .map_err(|e| format!("Failed to build RGB image: {}", e))?;
+            Ok(from_planes(rgb, layout, dtype, false))
+        }
+        ColorOp::SwapRedBlue => {
+            if was_2d || (channels != 3 && channels != 4) {
+                return Err(format!(
+                    "RGB/BGR conversion requires 3 or 4 channels, got {}",
+                    if was_2d { 1 } else { channels }
+                ));
+            }
+            let mut swapped = planes;
+            let (mut red, mut blue) = swapped.multi_slice_mut((s![0, .., ..], s![2, .., ..]));
+            ndarray::Zip::from(&mut red)
+                .and(&mut blue)
+                .for_each(std::mem::swap);
+            Ok(from_planes(swapped, layout, dtype, false))
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn color_op(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    layout: i32,
+    op: ColorOp,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if let Err(e) = check_image_dtype(wrapper.dtype) {
+            set_last_error(e);
+            return ERR_DTYPE;
+        }
+        let layout = match ImageLayout::from_i32(layout) {
+            Ok(l) => l,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let result_wrapper = match to_planes(wrapper, meta, layout)
+            .and_then(|planes| apply_color_op(planes, op, layout, wrapper.dtype, meta.ndim == 2))
+        {
+            Ok(w) => w,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Convert an RGB (or RGBA, alpha ignored) image to grayscale with BT.601 weights.
+///
+/// The result is `(H, W)`, or keeps a single-channel axis in `layout` when
+/// `keep_channel_axis` is set. Uint8 output is rounded.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_rgb_to_gray(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    layout: i32,
+    keep_channel_axis: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    color_op(
+        "image_rgb_to_gray",
+        handle,
+        meta,
+        layout,
+        ColorOp::RgbToGray { keep_channel_axis },
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Replicate a grayscale image (2D, or 3D with one channel) into three RGB channels.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_gray_to_rgb(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    layout: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    color_op(
+        "image_gray_to_rgb",
+        handle,
+        meta,
+        layout,
+        ColorOp::GrayToRgb,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Swap the first and third channels, converting RGB <-> BGR (alpha is kept).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_swap_rb(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    layout: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    color_op(
+        "image_swap_rb",
+        handle,
+        meta,
+        layout,
+        ColorOp::SwapRedBlue,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
@@ -0,0 +1,150 @@
+//! HWC <-> CHW channel-layout conversion with contiguous output.
+//!
+//! The transposition is done in one copy in the native dtype, so the result can
+//! be handed straight to model runtimes that expect a dense buffer.
+
+use ndarray::ArrayD;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use super::check_image_dtype;
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::{
+    extract_array_f32, extract_array_f64, extract_array_u8, write_output_metadata,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Axis permutation moving channels last-to-first (or first-to-last) on the
+/// trailing three axes, leaving an optional leading batch axis in place.
+fn layout_permutation(ndim: usize, to_chw: bool) -> Vec<usize> {
+    let base = ndim - 3;
+    let mut axes: Vec<usize> = (0..base).collect();
+    if to_chw {
+        axes.extend([base + 2, base, base + 1]);
+    } else {
+        axes.extend([base + 1, base + 2, base]);
+    }
+    axes
+}
+
+fn permute_contiguous<T: Clone>(arr: ArrayD<T>, axes: Vec<usize>) -> ArrayD<T> {
+    arr.permuted_axes(axes).as_standard_layout().into_owned()
+}
+
+macro_rules! permute_arm {
+    ($wrapper:expr, $meta:expr, $extract:ident, $variant:ident, $axes:expr) => {{
+        let Some(arr) = $extract($wrapper, $meta) else {
+            set_last_error(format!("Failed to extract {} image", stringify!($variant)));
+            return ERR_DTYPE;
+        };
+        NDArrayWrapper {
+            data: ArrayData::$variant(Arc::new(RwLock::new(permute_contiguous(arr, $axes)))),
+            dtype: DType::$variant,
+        }
+    }};
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn convert_layout(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    to_chw: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if let Err(e) = check_image_dtype(wrapper.dtype) {
+            set_last_error(e);
+            return ERR_DTYPE;
+        }
+        if meta.ndim != 3 && meta.ndim != 4 {
+            set_last_error(format!(
+                "Layout conversion requires a 3D image or 4D batch, got {} dimensions",
+                meta.ndim
+            ));
+            return ERR_SHAPE;
+        }
+
+        let axes = layout_permutation(meta.ndim, to_chw);
+        let result_wrapper = match wrapper.dtype {
+            DType::Uint8 => permute_arm!(wrapper, meta, extract_array_u8, Uint8, axes),
+            DType::Float32 => permute_arm!(wrapper, meta, extract_array_f32, Float32, axes),
+            _ => permute_arm!(wrapper, meta, extract_array_f64, Float64, axes),
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Convert an `(H, W, C)` image or `(N, H, W, C)` batch to contiguous CHW / NCHW.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_hwc_to_chw(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    convert_layout(
+        "image_hwc_to_chw",
+        handle,
+        meta,
+        true,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Convert a `(C, H, W)` image or `(N, C, H, W)` batch to contiguous HWC / NHWC.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_chw_to_hwc(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    convert_layout(
+        "image_chw_to_hwc",
+        handle,
+        meta,
+        false,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
@@ -0,0 +1,112 @@
+//! Image tensor operations for vision preprocessing.
+//!
+//! Images are 2D `(H, W)` grayscale arrays or 3D arrays in HWC or CHW layout,
+//! stored as Uint8, Float32 or Float64. Kernels work on CHW planes of f64 and
+//! convert back to the input dtype, rounding and clamping for Uint8.
+
+mod codec;
+mod color;
+mod layout;
+mod resize;
+
+pub use codec::*;
+pub use color::*;
+pub use layout::*;
+pub use resize::*;
+
+use ndarray::{Array3, ArrayD, Axis, Ix3};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::extract_array_as_f64;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper};
+
+/// Channel layout of a 3D image tensor. Integer values match PHP `ImageLayout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageLayout {
+    Hwc = 0,
+    Chw = 1,
+}
+
+impl ImageLayout {
+    pub(crate) fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(ImageLayout::Hwc),
+            1 => Ok(ImageLayout::Chw),
+            _ => Err(format!("Invalid image layout: {}", value)),
+        }
+    }
+}
+
+/// Validate an image dtype: only Uint8, Float32 and Float64 are supported.
+pub(crate) fn check_image_dtype(dtype: DType) -> Result<(), String> {
+    match dtype {
+        DType::Uint8 | DType::Float32 | DType::Float64 => Ok(()),
+        other => Err(format!(
+            "Image operations require uint8, float32 or float64 arrays, got {}",
+            other.name()
+        )),
+    }
+}
+
+/// Load an image as `(C, H, W)` f64 planes. 2D arrays become a single channel.
+pub(crate) fn to_planes(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    layout: ImageLayout,
+) -> Result<Array3<f64>, String> {
+    if meta.ndim != 2 && meta.ndim != 3 {
+        return Err(format!(
+            "Images must be 2D (H, W) or 3D (HWC/CHW), got {} dimensions",
+            meta.ndim
+        ));
+    }
+    let arr = extract_array_as_f64(wrapper, meta)
+        .ok_or_else(|| "Failed to extract image as f64".to_string())?;
+    let arr = if meta.ndim == 2 {
+        arr.insert_axis(Axis(0))
+    } else if layout == ImageLayout::Hwc {
+        arr.permuted_axes(vec![2, 0, 1])
+    } else {
+        arr
+    };
+    arr.into_dimensionality::<Ix3>()
+        .map_err(|e| format!("Invalid image shape: {}", e))
+}
+
+/// Convert `(C, H, W)` planes back to an image of `dtype` in `layout`.
+///
+/// With `squeeze`, a single channel is returned as a 2D `(H, W)` array.
+pub(crate) fn from_planes(
+    planes: Array3<f64>,
+    layout: ImageLayout,
+    dtype: DType,
+    squeeze: bool,
+) -> NDArrayWrapper {
+    let arr: ArrayD<f64> = if squeeze && planes.len_of(Axis(0)) == 1 {
+        planes.index_axis_move(Axis(0), 0).into_dyn()
+    } else if layout == ImageLayout::Hwc {
+        planes.permuted_axes([1, 2, 0]).into_dyn()
+    } else {
+        planes.into_dyn()
+    };
+    let arr = arr.as_standard_layout().into_owned();
+
+    match dtype {
+        DType::Uint8 => NDArrayWrapper {
+            data: ArrayData::Uint8(Arc::new(RwLock::new(
+                arr.mapv(|x| x.round().clamp(0.0, 255.0) as u8),
+            ))),
+            dtype: DType::Uint8,
+        },
+        DType::Float32 => NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(arr.mapv(|x| x as f32)))),
+            dtype: DType::Float32,
+        },
+        _ => NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Float64,
+        },
+    }
+}
@@ -0,0 +1,183 @@
+//! Image resizing with nearest and bilinear interpolation.
+//!
+//! Pixel centres are aligned (half-pixel convention, as in PIL and
+//! `align_corners=False`). Bilinear resampling is separable: rows are
+//! resampled first, then columns. With antialiasing, the triangle filter is
+//! widened by the downscale factor so every input pixel contributes.
+
+use ndarray::{Array3, ArrayView1, ArrayViewMut1, Axis, Zip};
+
+use super::{check_image_dtype, from_planes, to_planes, ImageLayout};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Resampling filter. Integer values match PHP `Interpolation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Interpolation {
+    Nearest = 0,
+    Bilinear = 1,
+}
+
+impl Interpolation {
+    fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(Interpolation::Nearest),
+            1 => Ok(Interpolation::Bilinear),
+            _ => Err(format!("Invalid interpolation: {}", value)),
+        }
+    }
+}
+
+/// Input taps for one output sample: first input index and normalised weights.
+struct Taps {
+    start: usize,
+    weights: Vec<f64>,
+}
+
+/// Precompute the taps mapping `in_len` samples onto `out_len`.
+fn compute_taps(in_len: usize, out_len: usize, mode: Interpolation, antialias: bool) -> Vec<Taps> {
+    let scale = in_len as f64 / out_len as f64;
+
+    if mode == Interpolation::Nearest {
+        return (0..out_len)
+            .map(|i| Taps {
+                start: (((i as f64 + 0.5) * scale) as usize).min(in_len - 1),
+                weights: vec![1.0],
+            })
+            .collect();
+    }
+
+    let filter_scale = if antialias && scale > 1.0 { scale } else { 1.0 };
+    let support = filter_scale;
+    (0..out_len)
+        .map(|i| {
+            let center = (i as f64 + 0.5) * scale;
+            let lo = ((center - support).floor().max(0.0)) as usize;
+            let hi = ((center + support).ceil() as usize).min(in_len);
+            let mut weights: Vec<f64> = (lo..hi)
+                .map(|j| {
+                    let x = ((j as f64 + 0.5 - center) / filter_scale).abs();
+                    (1.0 - x).max(0.0)
+                })
+                .collect();
+            let total: f64 = weights.iter().sum();
+            if total > 0.0 {
+                weights.iter_mut().for_each(|w| *w /= total);
+            }
+            Taps { start: lo, weights }
+        })
+        .collect()
+}
+
+fn resample_lane(input: ArrayView1<f64>, mut out: ArrayViewMut1<f64>, taps: &[Taps]) {
+    for (o, tap) in out.iter_mut().zip(taps) {
+        *o = tap
+            .weights
+            .iter()
+            .enumerate()
+            .map(|(k, w)| w * input[tap.start + k])
+            .sum();
+    }
+}
+
+/// Resample `planes` (C, H, W) along `axis` to `len` samples.
+fn resample_axis(
+    planes: &Array3<f64>,
+    axis: usize,
+    len: usize,
+    mode: Interpolation,
+    antialias: bool,
+) -> Array3<f64> {
+    let mut shape = planes.raw_dim();
+    let taps = compute_taps(shape[axis], len, mode, antialias);
+    shape[axis] = len;
+    let mut out = Array3::<f64>::zeros(shape);
+    Zip::from(planes.lanes(Axis(axis)))
+        .and(out.lanes_mut(Axis(axis)))
+        .for_each(|lane, out_lane| resample_lane(lane, out_lane, &taps));
+    out
+}
+
+/// Resize an image to `out_height` x `out_width`.
+///
+/// Accepts 2D `(H, W)` arrays or 3D arrays in the given `layout` (0 = HWC, 1 = CHW)
+/// of dtype Uint8, Float32 or Float64; the output keeps the dtype, layout and
+/// channel count. `interpolation` is 0 for nearest, 1 for bilinear. `antialias`
+/// widens the bilinear filter when downscaling; it has no effect on nearest.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_image_resize(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_height: usize,
+    out_width: usize,
+    interpolation: i32,
+    antialias: bool,
+    layout: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("image_resize", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if let Err(e) = check_image_dtype(wrapper.dtype) {
+            set_last_error(e);
+            return ERR_DTYPE;
+        }
+        let (mode, layout) = match (
+            Interpolation::from_i32(interpolation),
+            ImageLayout::from_i32(layout),
+        ) {
+            (Ok(m), Ok(l)) => (m, l),
+            (Err(e), _) | (_, Err(e)) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        if out_height == 0 || out_width == 0 {
+            set_last_error("Resize target height and width must be at least 1".to_string());
+            return ERR_VALUE;
+        }
+
+        let planes = match to_planes(wrapper, meta, layout) {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+        if planes.len_of(Axis(1)) == 0 || planes.len_of(Axis(2)) == 0 {
+            set_last_error("Cannot resize an empty image".to_string());
+            return ERR_SHAPE;
+        }
+
+        let rows = resample_axis(&planes, 1, out_height, mode, antialias);
+        let resized = resample_axis(&rows, 2, out_width, mode, antialias);
+        let result_wrapper = from_planes(resized, layout, wrapper.dtype, meta.ndim == 2);
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
@@ -1,22 +1,49 @@
 //! Slice assign operations.
 //!
-//! Provides assign operations between strided array views.
+//! Provides assign operations between strided array views. The source is
+//! broadcast to the destination view's shape and written while the
+//! destination's write lock is held.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::view::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
-    extract_view_mut_bool, extract_view_mut_c128, extract_view_mut_c64, extract_view_mut_f32,
-    extract_view_mut_f64, extract_view_mut_i16, extract_view_mut_i32, extract_view_mut_i64,
-    extract_view_mut_i8, extract_view_mut_u16, extract_view_mut_u32, extract_view_mut_u64,
-    extract_view_mut_u8, rhs_broadcasts_to_lhs,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8, rhs_broadcasts_to_lhs, strided_view_mut,
 };
-use crate::types::dtype::DType;
-use crate::types::{ArrayMetadata, NdArrayHandle};
+use crate::types::{ArrayData, ArrayMetadata, NdArrayHandle};
+use ndarray::ArrayD;
+use parking_lot::RwLock;
+
+/// Source metadata with leading length-1 axes beyond the destination's rank
+/// dropped, as NumPy does when assigning e.g. a `(1, n)` row into an `(n,)` view.
+pub(crate) unsafe fn squeeze_leading(src: &ArrayMetadata, dst_ndim: usize) -> ArrayMetadata {
+    let extra = src.ndim.saturating_sub(dst_ndim);
+    if src.shape_slice()[..extra].iter().any(|&d| d != 1) {
+        return ArrayMetadata::new(src.offset, src.shape, src.strides, src.ndim);
+    }
+    ArrayMetadata::new(
+        src.offset,
+        src.shape.add(extra),
+        src.strides.add(extra),
+        src.ndim - extra,
+    )
+}
+
+/// Write `src` into the destination view, broadcasting as needed.
+///
+/// The source has already been copied out of its own lock, so assigning an
+/// array into an overlapping view of itself reads the original values.
+unsafe fn assign_locked<T: Clone>(dst: &RwLock<ArrayD<T>>, meta: &ArrayMetadata, src: &ArrayD<T>) {
+    let mut guard = dst.write();
+    strided_view_mut(guard.as_mut_ptr(), meta).assign(src);
+}
 
 /// Assign values from source view to destination view.
 ///
+/// The source shape must broadcast to the destination shape; leading
+/// length-1 source axes beyond the destination's rank are ignored.
+///
 /// # Arguments
 /// * `dst` - Destination array handle
 /// * `dst_meta` - Destination view metadata
@@ -33,12 +60,13 @@ pub unsafe extern "C" fn ndarray_assign(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("assign", {
         let dst_wrapper = NdArrayHandle::as_wrapper(dst as *mut _);
         let src_wrapper = NdArrayHandle::as_wrapper(src as *mut _);
 
         let dst_meta = &*dst_meta;
         let src_meta = &*src_meta;
+        let squeezed = squeeze_leading(src_meta, dst_meta.ndim);
 
         // Validate dtypes match
         if dst_wrapper.dtype != src_wrapper.dtype {
@@ -46,98 +74,91 @@ pub unsafe extern "C" fn ndarray_assign(
                 "DType mismatch in assign: dst={:?}, src={:?}",
                 dst_wrapper.dtype, src_wrapper.dtype
             ));
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
 
-        let dst_shape = unsafe { dst_meta.shape_slice() };
-        let src_shape = unsafe { src_meta.shape_slice() };
+        let dst_shape = dst_meta.shape_slice();
+        let src_shape = squeezed.shape_slice();
         if !rhs_broadcasts_to_lhs(dst_shape, src_shape) {
             set_last_error(format!(
                 "Cannot assign: source shape {:?} cannot broadcast to destination shape {:?}",
-                src_shape, dst_shape
+                src_meta.shape_slice(),
+                dst_shape
             ));
             return ERR_SHAPE;
         }
 
-        match dst_wrapper.dtype {
-            DType::Int8 => {
-                let src_arr = extract_array_i8(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_i8(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Int16 => {
-                let src_arr = extract_array_i16(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_i16(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Int32 => {
-                let src_arr = extract_array_i32(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_i32(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Int64 => {
-                let src_arr = extract_array_i64(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_i64(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Uint8 => {
-                let src_arr = extract_array_u8(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_u8(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Uint16 => {
-                let src_arr = extract_array_u16(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_u16(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Uint32 => {
-                let src_arr = extract_array_u32(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_u32(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Uint64 => {
-                let src_arr = extract_array_u64(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_u64(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Float32 => {
-                let src_arr = extract_array_f32(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_f32(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Float64 => {
-                let src_arr = extract_array_f64(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_f64(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Complex64 => {
-                let src_arr = extract_array_c64(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_c64(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Complex128 => {
-                let src_arr = extract_array_c128(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_c128(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
-            DType::Bool => {
-                let src_arr = extract_array_bool(src_wrapper, src_meta).expect("Type mismatch");
-                let mut dst_arr =
-                    extract_view_mut_bool(dst_wrapper, dst_meta).expect("Type mismatch");
-                dst_arr.assign(&src_arr);
-            }
+        match &dst_wrapper.data {
+            ArrayData::Int8(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_i8(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Int16(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_i16(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Int32(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_i32(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Int64(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_i64(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Uint8(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_u8(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Uint16(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_u16(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Uint32(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_u32(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Uint64(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_u64(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Float32(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_f32(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Float64(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_f64(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Complex64(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_c64(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Complex128(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_c128(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Bool(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_bool(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
+            ArrayData::Float16(a) | ArrayData::BFloat16(a) => assign_locked(
+                a,
+                dst_meta,
+                &extract_array_half_bits(src_wrapper, &squeezed).expect("Type mismatch"),
+            ),
         }
 
         SUCCESS
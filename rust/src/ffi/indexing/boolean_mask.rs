@@ -0,0 +1,382 @@
+//! Boolean mask indexing: `a[mask]` reads and `a[mask] = values` writes.
+//!
+//! A mask covers the leading axes of the array and must match their shape
+//! exactly. Selecting yields `[count, ...trailing]`, where `count` is the
+//! number of true entries, so a mask with the array's full shape gives a 1D
+//! result. Elements and sub-arrays are visited in C order.
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, ArrayViewD, IxDyn};
+use parking_lot::RwLock;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::view::strided_view_mut;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_bf16, extract_array_as_bool, extract_array_as_c128, extract_array_as_c64,
+    extract_array_as_f16, extract_array_as_f32, extract_array_as_f64, extract_array_as_i16,
+    extract_array_as_i32, extract_array_as_i64, extract_array_as_i8, extract_array_as_u16,
+    extract_array_as_u32, extract_array_as_u64, extract_array_as_u8, extract_array_bool,
+    extract_view_bool, extract_view_c128, extract_view_c64, extract_view_f32, extract_view_f64,
+    extract_view_half_bits, extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8,
+    extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8, get_scalar_as_bf16,
+    get_scalar_as_bool, get_scalar_as_c128, get_scalar_as_c64, get_scalar_as_f16,
+    get_scalar_as_f32, get_scalar_as_f64, get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64,
+    get_scalar_as_i8, get_scalar_as_u16, get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// A mask flattened in C order, checked against the array it indexes.
+struct Mask {
+    flags: Vec<bool>,
+    count: usize,
+    /// Elements covered by each mask entry (product of the trailing axes).
+    block: usize,
+    /// Shape of the selection: `[count, ...trailing]`.
+    selection: Vec<usize>,
+}
+
+impl Mask {
+    fn new(mask: &ArrayD<u8>, shape: &[usize]) -> Result<Self, String> {
+        let lead = mask.ndim();
+        if lead > shape.len() || mask.shape() != &shape[..lead] {
+            return Err(format!(
+                "Boolean mask shape {:?} does not match the leading axes of array shape {:?}",
+                mask.shape(),
+                shape
+            ));
+        }
+        let flags: Vec<bool> = mask.iter().map(|&m| m != 0).collect();
+        let count = flags.iter().filter(|&&f| f).count();
+        let trailing = &shape[lead..];
+        let mut selection = Vec::with_capacity(1 + trailing.len());
+        selection.push(count);
+        selection.extend_from_slice(trailing);
+        Ok(Self {
+            flags,
+            count,
+            block: trailing.iter().product(),
+            selection,
+        })
+    }
+
+    /// Whether the element at logical C-order index `i` is selected.
+    #[inline]
+    fn selects(&self, i: usize) -> bool {
+        self.flags[i / self.block]
+    }
+}
+
+fn select_impl<T: Copy>(arr: ArrayViewD<'_, T>, mask: &Mask) -> Result<ArrayD<T>, String> {
+    let mut out = Vec::with_capacity(mask.count * mask.block);
+    if mask.block > 0 {
+        for (i, &v) in arr.iter().enumerate() {
+            if mask.selects(i) {
+                out.push(v);
+            }
+        }
+    }
+    ArrayD::from_shape_vec(IxDyn(&mask.selection), out)
+        .map_err(|e| format!("Failed to create boolean selection: {}", e))
+}
+
+/// Values written by a masked assignment, already in the array's dtype.
+enum Values<T> {
+    Array(ArrayD<T>),
+    Scalar(T),
+}
+
+/// Write `values` into the selected elements of the view described by `meta`.
+///
+/// Array values are an owned copy taken before the write lock, so assigning
+/// from the array itself reads its original contents.
+unsafe fn assign_impl<T: Copy>(
+    dst: &RwLock<ArrayD<T>>,
+    meta: &ArrayMetadata,
+    mask: &Mask,
+    values: Values<T>,
+) -> Result<(), String> {
+    let values = match values {
+        Values::Array(arr) => {
+            let Some(view) = arr.broadcast(IxDyn(&mask.selection)) else {
+                return Err(format!(
+                    "Cannot assign values of shape {:?} to a boolean selection of shape {:?}",
+                    arr.shape(),
+                    mask.selection
+                ));
+            };
+            view.iter().copied().collect::<Vec<T>>()
+        }
+        Values::Scalar(s) => vec![s; mask.count * mask.block],
+    };
+
+    let mut guard = dst.write();
+    let mut view = strided_view_mut(guard.as_mut_ptr(), meta);
+    if mask.block == 0 {
+        return Ok(());
+    }
+    let mut src = values.into_iter();
+    for (i, d) in view.iter_mut().enumerate() {
+        if mask.selects(i) {
+            if let Some(v) = src.next() {
+                *d = v;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read a Bool mask and check it against `shape`.
+unsafe fn read_mask(
+    mask: *const NdArrayHandle,
+    mask_meta: *const ArrayMetadata,
+    shape: &[usize],
+) -> Result<Mask, (i32, String)> {
+    let mask_wrapper = NdArrayHandle::as_wrapper(mask as *mut _);
+    if mask_wrapper.dtype != DType::Bool {
+        return Err((
+            ERR_DTYPE,
+            format!(
+                "Boolean index must have Bool dtype, got {}",
+                mask_wrapper.dtype
+            ),
+        ));
+    }
+    let Some(arr) = extract_array_bool(mask_wrapper, &*mask_meta) else {
+        return Err((ERR_DTYPE, "Failed to extract boolean mask".to_string()));
+    };
+    Mask::new(&arr, shape).map_err(|e| (ERR_SHAPE, e))
+}
+
+/// Select the elements (or trailing sub-arrays) where `mask` is true.
+///
+/// The mask must be Bool and match the leading axes of the array. The result
+/// is a new C-contiguous array of shape `[count, ...trailing]`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_boolean_select(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    mask: *const NdArrayHandle,
+    mask_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || mask.is_null()
+        || mask_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("boolean_select", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let mask = match read_mask(mask, mask_meta, meta.shape_slice()) {
+            Ok(m) => m,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        macro_rules! select_as {
+            ($view_fn:ident, $variant:ident) => {{
+                let Some(view) = $view_fn(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype));
+                    return ERR_DTYPE;
+                };
+                match select_impl(view, &mask) {
+                    Ok(out) => NDArrayWrapper {
+                        data: ArrayData::$variant(Arc::new(RwLock::new(out))),
+                        dtype: DType::$variant,
+                    },
+                    Err(e) => {
+                        set_last_error(e);
+                        return ERR_GENERIC;
+                    }
+                }
+            }};
+        }
+
+        let result_wrapper = match wrapper.dtype {
+            DType::Float64 => select_as!(extract_view_f64, Float64),
+            DType::Float32 => select_as!(extract_view_f32, Float32),
+            DType::Int64 => select_as!(extract_view_i64, Int64),
+            DType::Int32 => select_as!(extract_view_i32, Int32),
+            DType::Int16 => select_as!(extract_view_i16, Int16),
+            DType::Int8 => select_as!(extract_view_i8, Int8),
+            DType::Uint64 => select_as!(extract_view_u64, Uint64),
+            DType::Uint32 => select_as!(extract_view_u32, Uint32),
+            DType::Uint16 => select_as!(extract_view_u16, Uint16),
+            DType::Uint8 => select_as!(extract_view_u8, Uint8),
+            DType::Bool => select_as!(extract_view_bool, Bool),
+            DType::Complex64 => select_as!(extract_view_c64, Complex64),
+            DType::Complex128 => select_as!(extract_view_c128, Complex128),
+            DType::Float16 | DType::BFloat16 => {
+                let Some(view) = extract_view_half_bits(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype));
+                    return ERR_DTYPE;
+                };
+                match select_impl(view, &mask) {
+                    Ok(bits) => NDArrayWrapper::from_half_bits(bits, wrapper.dtype),
+                    Err(e) => {
+                        set_last_error(e);
+                        return ERR_GENERIC;
+                    }
+                }
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Assign to the elements (or trailing sub-arrays) where `mask` is true, in place.
+///
+/// Pass either `values` / `values_meta`, whose shape must broadcast to the
+/// selection shape `[count, ...trailing]`, or null for both and a `scalar`
+/// of `scalar_dtype` to write to every selected element. Values are
+/// converted to the array's dtype; complex values require a complex array.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_boolean_assign(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    mask: *const NdArrayHandle,
+    mask_meta: *const ArrayMetadata,
+    values: *const NdArrayHandle,
+    values_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || mask.is_null()
+        || mask_meta.is_null()
+        || values.is_null() != values_meta.is_null()
+        || (values.is_null() && scalar.is_null())
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("boolean_assign", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let mask = match read_mask(mask, mask_meta, meta.shape_slice()) {
+            Ok(m) => m,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        let source = if values.is_null() {
+            let Some(dtype) = DType::from_u8(scalar_dtype) else {
+                set_last_error("Invalid scalar dtype".to_string());
+                return ERR_DTYPE;
+            };
+            Err(dtype)
+        } else {
+            Ok((NdArrayHandle::as_wrapper(values as *mut _), &*values_meta))
+        };
+        let source_dtype = match source {
+            Ok((w, _)) => w.dtype,
+            Err(dtype) => dtype,
+        };
+        if source_dtype.is_complex() && !wrapper.dtype.is_complex() {
+            set_last_error(format!(
+                "Cannot assign {} values to a {} array",
+                source_dtype, wrapper.dtype
+            ));
+            return ERR_DTYPE;
+        }
+
+        macro_rules! assign_as {
+            ($a:expr, $as_fn:ident, $scalar_fn:ident) => {{
+                let values = match source {
+                    Ok((w, m)) => match $as_fn(w, m) {
+                        Some(arr) => Values::Array(arr),
+                        None => {
+                            set_last_error("Failed to extract assigned values".to_string());
+                            return ERR_DTYPE;
+                        }
+                    },
+                    Err(dtype) => Values::Scalar($scalar_fn(scalar, dtype)),
+                };
+                if let Err(e) = assign_impl($a, meta, &mask, values) {
+                    set_last_error(e);
+                    return ERR_SHAPE;
+                }
+            }};
+        }
+
+        match &wrapper.data {
+            ArrayData::Float64(a) => assign_as!(a, extract_array_as_f64, get_scalar_as_f64),
+            ArrayData::Float32(a) => assign_as!(a, extract_array_as_f32, get_scalar_as_f32),
+            ArrayData::Int64(a) => assign_as!(a, extract_array_as_i64, get_scalar_as_i64),
+            ArrayData::Int32(a) => assign_as!(a, extract_array_as_i32, get_scalar_as_i32),
+            ArrayData::Int16(a) => assign_as!(a, extract_array_as_i16, get_scalar_as_i16),
+            ArrayData::Int8(a) => assign_as!(a, extract_array_as_i8, get_scalar_as_i8),
+            ArrayData::Uint64(a) => assign_as!(a, extract_array_as_u64, get_scalar_as_u64),
+            ArrayData::Uint32(a) => assign_as!(a, extract_array_as_u32, get_scalar_as_u32),
+            ArrayData::Uint16(a) => assign_as!(a, extract_array_as_u16, get_scalar_as_u16),
+            ArrayData::Uint8(a) => assign_as!(a, extract_array_as_u8, get_scalar_as_u8),
+            ArrayData::Bool(a) => assign_as!(a, extract_array_as_bool, get_scalar_as_bool),
+            ArrayData::Complex64(a) => assign_as!(a, extract_array_as_c64, get_scalar_as_c64),
+            ArrayData::Complex128(a) => assign_as!(a, extract_array_as_c128, get_scalar_as_c128),
+            ArrayData::Float16(a) => assign_as!(a, extract_array_as_f16, get_scalar_as_f16),
+            ArrayData::BFloat16(a) => assign_as!(a, extract_array_as_bf16, get_scalar_as_bf16),
+        }
+
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mask(shape: &[usize], flags: &[u8]) -> ArrayD<u8> {
+        ArrayD::from_shape_vec(IxDyn(shape), flags.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn leading_axis_mask_selects_rows() {
+        let arr = ArrayD::from_shape_vec(IxDyn(&[3, 2]), vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let m = Mask::new(&mask(&[3], &[1, 0, 1]), arr.shape()).unwrap();
+        let out = select_impl(arr.view(), &m).unwrap();
+        assert_eq!(out.shape(), &[2, 2]);
+        assert_eq!(out.iter().copied().collect::<Vec<_>>(), vec![1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn full_mask_selects_flat_and_rejects_mismatch() {
+        let arr = ArrayD::from_shape_vec(IxDyn(&[2, 2]), vec![1.0, -2.0, 3.0, -4.0]).unwrap();
+        let m = Mask::new(&mask(&[2, 2], &[0, 1, 0, 1]), arr.shape()).unwrap();
+        let out = select_impl(arr.view(), &m).unwrap();
+        assert_eq!(out.shape(), &[2]);
+        assert_eq!(out.iter().copied().collect::<Vec<_>>(), vec![-2.0, -4.0]);
+
+        assert!(Mask::new(&mask(&[3], &[1, 0, 1]), arr.shape()).is_err());
+        assert!(Mask::new(&mask(&[2, 2, 1], &[1, 0, 1, 0]), arr.shape()).is_err());
+    }
+}
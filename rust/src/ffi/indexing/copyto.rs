@@ -0,0 +1,176 @@
+//! Masked, casting copy into an existing array (NumPy `copyto`).
+//!
+//! The source (and mask) are broadcast to the destination view and written
+//! in place, so updating part of a large array does not allocate a result
+//! the way `where()` does.
+
+use ndarray::{ArrayD, Zip};
+use parking_lot::RwLock;
+
+use super::assign::squeeze_leading;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::view::{
+    extract_array_as_bf16, extract_array_as_bool, extract_array_as_c128, extract_array_as_c64,
+    extract_array_as_f16, extract_array_as_f32, extract_array_as_f64, extract_array_as_i16,
+    extract_array_as_i32, extract_array_as_i64, extract_array_as_i8, extract_array_as_u16,
+    extract_array_as_u32, extract_array_as_u64, extract_array_as_u8, extract_array_bool,
+    rhs_broadcasts_to_lhs, strided_view_mut,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, Casting, NdArrayHandle};
+
+/// Write `src` into the destination view where `mask` is non-zero.
+///
+/// Both `src` and `mask` are owned copies taken before the write lock, so
+/// copying an array into an overlapping view of itself reads the original
+/// values. Callers have checked that both broadcast to the view's shape.
+unsafe fn copyto_locked<T: Clone>(
+    dst: &RwLock<ArrayD<T>>,
+    meta: &ArrayMetadata,
+    src: &ArrayD<T>,
+    mask: Option<&ArrayD<u8>>,
+) {
+    let mut guard = dst.write();
+    let mut view = strided_view_mut(guard.as_mut_ptr(), meta);
+    let Some(mask) = mask else {
+        view.assign(src);
+        return;
+    };
+    let shape = view.raw_dim();
+    let src = src
+        .broadcast(shape.clone())
+        .expect("source broadcast checked");
+    let mask = mask.broadcast(shape).expect("mask broadcast checked");
+    Zip::from(&mut view)
+        .and(&src)
+        .and(&mask)
+        .for_each(|d, s, &m| {
+            if m != 0 {
+                *d = s.clone();
+            }
+        });
+}
+
+/// Copy `src` into the `dst` view in place, optionally only where `mask` is true.
+///
+/// The source and mask shapes must broadcast to the destination shape;
+/// leading length-1 axes beyond the destination's rank are ignored. The
+/// source is converted to the destination dtype when `casting` allows it.
+///
+/// # Arguments
+/// * `dst` / `dst_meta` - Destination array and view
+/// * `src` / `src_meta` - Source array and view
+/// * `mask` / `mask_meta` - Bool mask; both null to copy every element
+/// * `casting` - Casting rule (0 = no, 1 = equiv, 2 = safe, 3 = same_kind, 4 = unsafe)
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_copyto(
+    dst: *const NdArrayHandle,
+    dst_meta: *const ArrayMetadata,
+    src: *const NdArrayHandle,
+    src_meta: *const ArrayMetadata,
+    mask: *const NdArrayHandle,
+    mask_meta: *const ArrayMetadata,
+    casting: i32,
+) -> i32 {
+    if dst.is_null()
+        || dst_meta.is_null()
+        || src.is_null()
+        || src_meta.is_null()
+        || mask.is_null() != mask_meta.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("copyto", {
+        let dst_wrapper = NdArrayHandle::as_wrapper(dst as *mut _);
+        let src_wrapper = NdArrayHandle::as_wrapper(src as *mut _);
+        let dst_meta = &*dst_meta;
+        let dst_shape = dst_meta.shape_slice();
+
+        let casting = match Casting::from_i32(casting) {
+            Ok(c) => c,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        if !casting.allows(src_wrapper.dtype, dst_wrapper.dtype) {
+            set_last_error(format!(
+                "Cannot cast array data from {} to {} according to the rule '{}'",
+                src_wrapper.dtype,
+                dst_wrapper.dtype,
+                casting.name()
+            ));
+            return ERR_DTYPE;
+        }
+
+        let src_meta = squeeze_leading(&*src_meta, dst_meta.ndim);
+        if !rhs_broadcasts_to_lhs(dst_shape, src_meta.shape_slice()) {
+            set_last_error(format!(
+                "copyto: source shape {:?} cannot broadcast to destination shape {:?}",
+                src_meta.shape_slice(),
+                dst_shape
+            ));
+            return ERR_SHAPE;
+        }
+
+        let mask = if mask.is_null() {
+            None
+        } else {
+            let mask_wrapper = NdArrayHandle::as_wrapper(mask as *mut _);
+            if mask_wrapper.dtype != DType::Bool {
+                set_last_error(format!(
+                    "copyto: where mask must be bool, got {}",
+                    mask_wrapper.dtype
+                ));
+                return ERR_DTYPE;
+            }
+            let mask_meta = squeeze_leading(&*mask_meta, dst_meta.ndim);
+            if !rhs_broadcasts_to_lhs(dst_shape, mask_meta.shape_slice()) {
+                set_last_error(format!(
+                    "copyto: where mask shape {:?} cannot broadcast to destination shape {:?}",
+                    mask_meta.shape_slice(),
+                    dst_shape
+                ));
+                return ERR_SHAPE;
+            }
+            let Some(m) = extract_array_bool(mask_wrapper, &mask_meta) else {
+                set_last_error("Failed to extract where mask".to_string());
+                return ERR_DTYPE;
+            };
+            Some(m)
+        };
+
+        macro_rules! copy_as {
+            ($a:expr, $extract:ident) => {{
+                let Some(values) = $extract(src_wrapper, &src_meta) else {
+                    set_last_error("Failed to extract copyto source".to_string());
+                    return ERR_DTYPE;
+                };
+                copyto_locked($a, dst_meta, &values, mask.as_ref());
+            }};
+        }
+
+        match &dst_wrapper.data {
+            ArrayData::Int8(a) => copy_as!(a, extract_array_as_i8),
+            ArrayData::Int16(a) => copy_as!(a, extract_array_as_i16),
+            ArrayData::Int32(a) => copy_as!(a, extract_array_as_i32),
+            ArrayData::Int64(a) => copy_as!(a, extract_array_as_i64),
+            ArrayData::Uint8(a) => copy_as!(a, extract_array_as_u8),
+            ArrayData::Uint16(a) => copy_as!(a, extract_array_as_u16),
+            ArrayData::Uint32(a) => copy_as!(a, extract_array_as_u32),
+            ArrayData::Uint64(a) => copy_as!(a, extract_array_as_u64),
+            ArrayData::Float32(a) => copy_as!(a, extract_array_as_f32),
+            ArrayData::Float64(a) => copy_as!(a, extract_array_as_f64),
+            ArrayData::Complex64(a) => copy_as!(a, extract_array_as_c64),
+            ArrayData::Complex128(a) => copy_as!(a, extract_array_as_c128),
+            ArrayData::Bool(a) => copy_as!(a, extract_array_as_bool),
+            ArrayData::Float16(a) => copy_as!(a, extract_array_as_f16),
+            ArrayData::BFloat16(a) => copy_as!(a, extract_array_as_bf16),
+        }
+
+        SUCCESS
+    })
+}
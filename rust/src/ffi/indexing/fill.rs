@@ -6,9 +6,9 @@ use std::ffi::c_void;
 
 use crate::helpers::view::{
     extract_view_mut_bool, extract_view_mut_c128, extract_view_mut_c64, extract_view_mut_f32,
-    extract_view_mut_f64, extract_view_mut_i16, extract_view_mut_i32, extract_view_mut_i64,
-    extract_view_mut_i8, extract_view_mut_u16, extract_view_mut_u32, extract_view_mut_u64,
-    extract_view_mut_u8,
+    extract_view_mut_f64, extract_view_mut_half_bits, extract_view_mut_i16, extract_view_mut_i32,
+    extract_view_mut_i64, extract_view_mut_i8, extract_view_mut_u16, extract_view_mut_u32,
+    extract_view_mut_u64, extract_view_mut_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayMetadata, NdArrayHandle};
@@ -30,7 +30,7 @@ pub unsafe extern "C" fn ndarray_fill(
         return crate::helpers::error::ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("fill", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -113,6 +113,12 @@ pub unsafe extern "C" fn ndarray_fill(
                     .expect("Type mismatch")
                     .fill(v);
             }
+            DType::Float16 | DType::BFloat16 => {
+                let v = *(value as *const u16);
+                extract_view_mut_half_bits(wrapper, meta)
+                    .expect("Type mismatch")
+                    .fill(v);
+            }
         }
 
         crate::helpers::error::SUCCESS
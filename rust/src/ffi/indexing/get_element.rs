@@ -27,7 +27,7 @@ pub unsafe extern "C" fn ndarray_get_element(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("get_element", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let result = match wrapper.dtype {
@@ -115,6 +115,20 @@ pub unsafe extern "C" fn ndarray_get_element(
                 }
                 Err(e) => return handle_get_error(e),
             },
+            DType::Float16 => match wrapper.get_element_f16(flat_index) {
+                Ok(v) => {
+                    *(out_value as *mut u16) = v;
+                    SUCCESS
+                }
+                Err(e) => return handle_get_error(e),
+            },
+            DType::BFloat16 => match wrapper.get_element_bf16(flat_index) {
+                Ok(v) => {
+                    *(out_value as *mut u16) = v;
+                    SUCCESS
+                }
+                Err(e) => return handle_get_error(e),
+            },
             DType::Bool => match wrapper.get_element_bool(flat_index) {
                 Ok(v) => {
                     *(out_value as *mut u8) = v;
@@ -0,0 +1,121 @@
+//! Batched element get operations.
+//!
+//! Reads many elements in a single FFI call so PHP loops over thousands of
+//! coordinates do not pay one call and one lock acquisition per element.
+
+use std::ffi::c_void;
+
+use crate::helpers::error::{self, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS};
+use crate::helpers::resolve_storage_indices;
+use crate::types::{ArrayData, ArrayMetadata, NdArrayHandle};
+use ndarray::ArrayD;
+use parking_lot::RwLock;
+
+fn gather<T: Copy>(arr: &RwLock<ArrayD<T>>, storage: &[usize], out: *mut T) -> Result<(), String> {
+    let guard = arr.read();
+    let owned;
+    let slice = match guard.as_slice_memory_order() {
+        Some(s) => s,
+        None => {
+            owned = guard.iter().copied().collect::<Vec<T>>();
+            &owned[..]
+        }
+    };
+
+    let out = unsafe { std::slice::from_raw_parts_mut(out, storage.len()) };
+    for (dst, &i) in out.iter_mut().zip(storage) {
+        *dst = *slice.get(i).ok_or_else(|| {
+            format!(
+                "Index {} out of bounds for array with {} elements",
+                i,
+                slice.len()
+            )
+        })?;
+    }
+    Ok(())
+}
+
+/// Get many elements of an array or view in one call.
+///
+/// # Arguments
+/// * `handle` - Array handle
+/// * `meta` - View metadata (offset, shape, strides)
+/// * `indices` - `n * index_ndim` indices stored back to back
+/// * `n` - Number of elements to read
+/// * `index_ndim` - 1 for logical C-order flat indices, or `ndim` for coordinate tuples
+/// * `out_values` - Buffer of `n` elements in the array's dtype (complex as re/im pairs, bool as u8)
+///
+/// Negative indices count from the end. All indices are validated before any
+/// value is written; an out-of-range index returns ERR_INDEX.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_get_many(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    indices: *const i64,
+    n: usize,
+    index_ndim: usize,
+    out_values: *mut c_void,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || (n > 0 && (indices.is_null() || out_values.is_null()))
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("get_many", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta_ref = &*meta;
+
+        if index_ndim == 0 || (index_ndim != 1 && index_ndim != meta_ref.ndim) {
+            error::set_last_error(format!(
+                "get_many expects 1 (flat) or {} (coordinate) components per index, got {}",
+                meta_ref.ndim, index_ndim
+            ));
+            return ERR_SHAPE;
+        }
+        if n == 0 {
+            return SUCCESS;
+        }
+
+        let indices = std::slice::from_raw_parts(indices, n * index_ndim);
+        let storage = match resolve_storage_indices(
+            meta_ref.shape_slice(),
+            meta_ref.strides_slice(),
+            meta_ref.offset,
+            indices,
+            index_ndim,
+        ) {
+            Ok(s) => s,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_INDEX;
+            }
+        };
+
+        let result = match &wrapper.data {
+            ArrayData::Int8(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Int16(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Int32(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Int64(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Uint8(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Uint16(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Uint32(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Uint64(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Float32(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Float64(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Bool(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Complex64(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Complex128(a) => gather(a, &storage, out_values as *mut _),
+            ArrayData::Float16(a) | ArrayData::BFloat16(a) => {
+                gather(a, &storage, out_values as *mut _)
+            }
+        };
+
+        match result {
+            Ok(()) => SUCCESS,
+            Err(e) => {
+                error::set_last_error(e);
+                ERR_INDEX
+            }
+        }
+    })
+}
@@ -4,24 +4,36 @@
 
 // Public modules
 pub mod assign;
+pub mod boolean_mask;
+pub mod copyto;
 pub mod fill;
 pub mod get_element;
+pub mod get_many;
 pub mod put;
 pub mod put_along_axis;
+pub mod scatter;
 pub mod scatter_add;
 pub mod set_element;
+pub mod set_many;
 pub mod take;
 pub mod take_along_axis;
+pub mod take_rows;
 pub mod where_op;
 
 // Re-export all public functions for convenient access
 pub use assign::*;
+pub use boolean_mask::*;
+pub use copyto::*;
 pub use fill::*;
 pub use get_element::*;
+pub use get_many::*;
 pub use put::*;
 pub use put_along_axis::*;
+pub use scatter::*;
 pub use scatter_add::*;
 pub use set_element::*;
+pub use set_many::*;
 pub use take::*;
 pub use take_along_axis::*;
+pub use take_rows::*;
 pub use where_op::*;
@@ -1,434 +1,456 @@
-//! Put values by flattened logical indices.
+//! Put values by flattened logical indices or along an axis.
+//!
+//! Both operations return an updated copy. Values are either an array,
+//! converted to the input dtype and broadcast against the selection, or a
+//! single scalar written to every selected position.
 
-use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, SUCCESS};
-use crate::helpers::normalize_index;
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, ArrayViewD, IxDyn};
+use parking_lot::RwLock;
+
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS};
 use crate::helpers::{
-    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_bf16, extract_array_as_bool, extract_array_as_c128, extract_array_as_c64,
+    extract_array_as_f16, extract_array_as_f32, extract_array_as_f64, extract_array_as_i16,
+    extract_array_as_i32, extract_array_as_i64, extract_array_as_i8, extract_array_as_u16,
+    extract_array_as_u32, extract_array_as_u64, extract_array_as_u8, extract_view_bf16,
+    extract_view_bool, extract_view_c128, extract_view_c64, extract_view_f16, extract_view_f32,
+    extract_view_f64, extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8,
+    extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8, get_scalar_as_bf16,
+    get_scalar_as_bool, get_scalar_as_c128, get_scalar_as_c64, get_scalar_as_f16,
+    get_scalar_as_f32, get_scalar_as_f64, get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64,
+    get_scalar_as_i8, get_scalar_as_u16, get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8,
 };
+use crate::helpers::{normalize_axis, normalize_index};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
-use ndarray::ArrayD;
-use num_complex::Complex;
-use parking_lot::RwLock;
-use std::ffi::c_void;
-use std::sync::Arc;
 
+/// The values operand as passed over FFI.
+pub(super) enum Source<'a> {
+    Array(&'a NDArrayWrapper, &'a ArrayMetadata),
+    Scalar(*const c_void, DType),
+}
+
+/// Values converted to the element type of the array being updated.
+pub(super) enum Values<T> {
+    Array(ArrayD<T>),
+    Scalar(T),
+}
+
+impl<T: Copy> Values<T> {
+    /// One value per index, in the C order of an index array of `shape`.
+    ///
+    /// Array values broadcast against `shape`. Values that do not broadcast
+    /// are repeated in C order until every index has one, as in NumPy's
+    /// `put`.
+    pub(super) fn per_index(&self, shape: &[usize]) -> Result<Vec<T>, (i32, String)> {
+        let len = shape.iter().product();
+        match self {
+            Values::Scalar(s) => Ok(vec![*s; len]),
+            Values::Array(arr) => match arr.broadcast(IxDyn(shape)) {
+                Some(view) => Ok(view.iter().copied().collect()),
+                None if arr.is_empty() => Err((
+                    ERR_SHAPE,
+                    "Values must be a scalar or a non-empty array".to_string(),
+                )),
+                None => Ok(arr.iter().copied().cycle().take(len).collect()),
+            },
+        }
+    }
+}
+
+/// Where the indices point.
+#[derive(Clone, Copy)]
+enum Target {
+    /// Flat positions in the logical C order of the array.
+    Flat,
+    /// Positions along one axis, selecting whole sub-arrays like `take_axis`.
+    Axis(usize),
+}
+
+pub(super) unsafe fn read_values<T>(
+    source: &Source,
+    convert: unsafe fn(&NDArrayWrapper, &ArrayMetadata) -> Option<ArrayD<T>>,
+    scalar: unsafe fn(*const c_void, DType) -> T,
+) -> Result<Values<T>, (i32, String)> {
+    match *source {
+        Source::Array(wrapper, meta) => convert(wrapper, meta)
+            .map(Values::Array)
+            .ok_or_else(|| (ERR_DTYPE, "Failed to extract put values".to_string())),
+        Source::Scalar(ptr, dtype) => Ok(Values::Scalar(scalar(ptr, dtype))),
+    }
+}
+
+/// Write `values` at flat `indices` of `data`.
+fn put_flat<T: Copy>(
+    data: &mut [T],
+    indices: ArrayViewD<'_, i64>,
+    values: &Values<T>,
+) -> Result<(), (i32, String)> {
+    let vals = values.per_index(indices.shape())?;
+    for (&idx, v) in indices.iter().zip(vals) {
+        let i = normalize_index(idx, data.len()).map_err(|e| (ERR_INDEX, e))?;
+        data[i] = v;
+    }
+    Ok(())
+}
+
+/// Write `values` into the sub-arrays at `indices` along `axis` of `data`.
+///
+/// The selection has the shape `take_axis` would return,
+/// `shape[..axis] + indices.shape + shape[axis + 1..]`, and array values must
+/// broadcast to it. Repeated indices keep the last write.
+fn put_axis<T: Copy>(
+    data: &mut [T],
+    shape: &[usize],
+    indices: ArrayViewD<'_, i64>,
+    axis: usize,
+    values: &Values<T>,
+) -> Result<(), (i32, String)> {
+    let axis_len = shape[axis];
+    let outer: usize = shape[..axis].iter().product();
+    let inner: usize = shape[axis + 1..].iter().product();
+    let idx = indices
+        .iter()
+        .map(|&i| normalize_index(i, axis_len))
+        .collect::<Result<Vec<usize>, String>>()
+        .map_err(|e| (ERR_INDEX, e))?;
+
+    let vals: Vec<T> = match values {
+        Values::Scalar(s) => {
+            for o in 0..outer {
+                for &i in &idx {
+                    let start = (o * axis_len + i) * inner;
+                    data[start..start + inner].fill(*s);
+                }
+            }
+            return Ok(());
+        }
+        Values::Array(arr) => {
+            let mut selection = shape[..axis].to_vec();
+            selection.extend_from_slice(indices.shape());
+            selection.extend_from_slice(&shape[axis + 1..]);
+            let Some(view) = arr.broadcast(IxDyn(&selection)) else {
+                return Err((
+                    ERR_SHAPE,
+                    format!(
+                        "Cannot broadcast values of shape {:?} to put_axis selection shape {:?}",
+                        arr.shape(),
+                        selection
+                    ),
+                ));
+            };
+            view.iter().copied().collect()
+        }
+    };
+
+    for o in 0..outer {
+        for (k, &i) in idx.iter().enumerate() {
+            let dst = (o * axis_len + i) * inner;
+            let src = (o * idx.len() + k) * inner;
+            data[dst..dst + inner].copy_from_slice(&vals[src..src + inner]);
+        }
+    }
+    Ok(())
+}
+
+/// Copy `arr` in C order, apply the put and reshape.
 fn put_impl<T: Copy>(
-    arr: &ndarray::ArrayD<T>,
-    indices: &[i64],
-    values: &[T],
-    scalar: Option<T>,
-) -> Result<ArrayD<T>, String> {
-    let mut flat: Vec<T> = arr.iter().copied().collect();
-    if values.is_empty() && scalar.is_none() {
-        return Err("put() requires scalar or non-empty values".to_string());
+    arr: ArrayViewD<'_, T>,
+    indices: ArrayViewD<'_, i64>,
+    target: Target,
+    values: Values<T>,
+) -> Result<ArrayD<T>, (i32, String)> {
+    let shape = arr.shape().to_vec();
+    let mut data: Vec<T> = arr.iter().copied().collect();
+    match target {
+        Target::Flat => put_flat(&mut data, indices, &values)?,
+        Target::Axis(axis) => put_axis(&mut data, &shape, indices, axis, &values)?,
     }
+    ArrayD::from_shape_vec(IxDyn(&shape), data)
+        .map_err(|e| (ERR_GENERIC, format!("Failed to create put output: {}", e)))
+}
 
-    for (k, &idx) in indices.iter().enumerate() {
-        let i = normalize_index(idx, flat.len())?;
-        let v = match scalar {
-            Some(s) => s,
-            None => values[k % values.len()],
-        };
-        flat[i] = v;
+unsafe fn put_dispatch(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    indices: ArrayViewD<'_, i64>,
+    target: Target,
+    source: Source,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let source_dtype = match source {
+        Source::Array(w, _) => w.dtype,
+        Source::Scalar(_, dtype) => dtype,
+    };
+    if source_dtype.is_complex() && !wrapper.dtype.is_complex() {
+        return Err((
+            ERR_DTYPE,
+            format!(
+                "Cannot put {} values into a {} array",
+                source_dtype, wrapper.dtype
+            ),
+        ));
+    }
+
+    macro_rules! put_as {
+        ($view_fn:ident, $as_fn:ident, $scalar_fn:ident) => {{
+            let Some(arr) = $view_fn(wrapper, meta) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to extract {} view", wrapper.dtype),
+                ));
+            };
+            let values = read_values(&source, $as_fn, $scalar_fn)?;
+            put_impl(arr, indices, target, values)?
+        }};
+        ($variant:ident, $view_fn:ident, $as_fn:ident, $scalar_fn:ident) => {{
+            let out = put_as!($view_fn, $as_fn, $scalar_fn);
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(out))),
+                dtype: DType::$variant,
+            }
+        }};
+    }
+
+    Ok(match wrapper.dtype {
+        DType::Float64 => put_as!(
+            Float64,
+            extract_view_f64,
+            extract_array_as_f64,
+            get_scalar_as_f64
+        ),
+        DType::Float32 => put_as!(
+            Float32,
+            extract_view_f32,
+            extract_array_as_f32,
+            get_scalar_as_f32
+        ),
+        DType::Int64 => put_as!(
+            Int64,
+            extract_view_i64,
+            extract_array_as_i64,
+            get_scalar_as_i64
+        ),
+        DType::Int32 => put_as!(
+            Int32,
+            extract_view_i32,
+            extract_array_as_i32,
+            get_scalar_as_i32
+        ),
+        DType::Int16 => put_as!(
+            Int16,
+            extract_view_i16,
+            extract_array_as_i16,
+            get_scalar_as_i16
+        ),
+        DType::Int8 => put_as!(Int8, extract_view_i8, extract_array_as_i8, get_scalar_as_i8),
+        DType::Uint64 => put_as!(
+            Uint64,
+            extract_view_u64,
+            extract_array_as_u64,
+            get_scalar_as_u64
+        ),
+        DType::Uint32 => put_as!(
+            Uint32,
+            extract_view_u32,
+            extract_array_as_u32,
+            get_scalar_as_u32
+        ),
+        DType::Uint16 => put_as!(
+            Uint16,
+            extract_view_u16,
+            extract_array_as_u16,
+            get_scalar_as_u16
+        ),
+        DType::Uint8 => put_as!(
+            Uint8,
+            extract_view_u8,
+            extract_array_as_u8,
+            get_scalar_as_u8
+        ),
+        DType::Bool => put_as!(
+            Bool,
+            extract_view_bool,
+            extract_array_as_bool,
+            get_scalar_as_bool
+        ),
+        DType::Complex64 => {
+            put_as!(
+                Complex64,
+                extract_view_c64,
+                extract_array_as_c64,
+                get_scalar_as_c64
+            )
+        }
+        DType::Complex128 => {
+            put_as!(
+                Complex128,
+                extract_view_c128,
+                extract_array_as_c128,
+                get_scalar_as_c128
+            )
+        }
+        DType::Float16 => NDArrayWrapper::from_half_bits(
+            put_as!(extract_view_f16, extract_array_as_f16, get_scalar_as_f16),
+            DType::Float16,
+        ),
+        DType::BFloat16 => NDArrayWrapper::from_half_bits(
+            put_as!(extract_view_bf16, extract_array_as_bf16, get_scalar_as_bf16),
+            DType::BFloat16,
+        ),
+    })
+}
+
+/// Read the Int64 indices and the values operand shared by both entry points.
+unsafe fn read_operands<'a>(
+    indices_handle: *const NdArrayHandle,
+    indices_meta: *const ArrayMetadata,
+    values_handle: *const NdArrayHandle,
+    values_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+) -> Result<(ArrayViewD<'a, i64>, Source<'a>), (i32, String)> {
+    let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
+    if indices_wrapper.dtype != DType::Int64 {
+        return Err((ERR_DTYPE, "put indices must have Int64 dtype".to_string()));
     }
+    let Some(indices) = extract_view_i64(indices_wrapper, &*indices_meta) else {
+        return Err((
+            ERR_DTYPE,
+            "Failed to extract Int64 indices view".to_string(),
+        ));
+    };
 
-    ArrayD::from_shape_vec(arr.raw_dim(), flat)
-        .map_err(|e| format!("Failed to create put output: {}", e))
+    let source = if values_handle.is_null() {
+        let Some(dtype) = DType::from_u8(scalar_dtype) else {
+            return Err((ERR_DTYPE, "Invalid scalar dtype".to_string()));
+        };
+        Source::Scalar(scalar, dtype)
+    } else {
+        Source::Array(
+            NdArrayHandle::as_wrapper(values_handle as *mut _),
+            &*values_meta,
+        )
+    };
+    Ok((indices, source))
 }
 
 /// Put values by flattened logical indices.
+///
+/// Pass either `values_handle` / `values_meta` or null for both and a
+/// `scalar` of `scalar_dtype`. Array values are converted to the input dtype
+/// and broadcast against the indices shape; values that do not broadcast are
+/// repeated in C order, as in NumPy's `put`. Returns an updated copy.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_put(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     indices_handle: *const NdArrayHandle,
     indices_meta: *const ArrayMetadata,
-    values: *const c_void,
-    values_len: usize,
-    scalar_value: f64,
-    has_scalar: bool,
+    values_handle: *const NdArrayHandle,
+    values_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
     out_handle: *mut *mut NdArrayHandle,
 ) -> i32 {
     if handle.is_null()
         || meta.is_null()
         || indices_handle.is_null()
         || indices_meta.is_null()
+        || values_handle.is_null() != values_meta.is_null()
+        || (values_handle.is_null() && scalar.is_null())
         || out_handle.is_null()
     {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("put", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
-        let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
-        let meta_ref = &*meta;
-        let indices_meta_ref = &*indices_meta;
-
-        if indices_wrapper.dtype != DType::Int64 {
-            error::set_last_error("put indices must have Int64 dtype".to_string());
-            return ERR_DTYPE;
-        }
+        let meta = &*meta;
 
-        let Some(indices_arr) = extract_array_i64(indices_wrapper, indices_meta_ref) else {
-            error::set_last_error("Failed to extract Int64 indices view".to_string());
-            return ERR_GENERIC;
-        };
-        let idx_slice = indices_arr.as_slice().unwrap_or(&[]);
-
-        let result_wrapper = match wrapper.dtype {
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[f64] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const f64, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as f64),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(out))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[f32] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const f32, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as f32),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(out))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Int64 => {
-                let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[i64] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const i64, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as i64),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Int64(Arc::new(RwLock::new(out))),
-                    dtype: DType::Int64,
-                }
+        let result = read_operands(
+            indices_handle,
+            indices_meta,
+            values_handle,
+            values_meta,
+            scalar,
+            scalar_dtype,
+        )
+        .and_then(|(indices, source)| put_dispatch(wrapper, meta, indices, Target::Flat, source));
+        let result_wrapper = match result {
+            Ok(w) => w,
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
             }
-            DType::Int32 => {
-                let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[i32] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const i32, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as i32),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Int32(Arc::new(RwLock::new(out))),
-                    dtype: DType::Int32,
-                }
-            }
-            DType::Int16 => {
-                let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[i16] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const i16, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as i16),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Int16(Arc::new(RwLock::new(out))),
-                    dtype: DType::Int16,
-                }
-            }
-            DType::Int8 => {
-                let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[i8] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const i8, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as i8),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Int8(Arc::new(RwLock::new(out))),
-                    dtype: DType::Int8,
-                }
-            }
-            DType::Uint64 => {
-                let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[u64] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const u64, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as u64),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Uint64(Arc::new(RwLock::new(out))),
-                    dtype: DType::Uint64,
-                }
-            }
-            DType::Uint32 => {
-                let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[u32] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const u32, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as u32),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Uint32(Arc::new(RwLock::new(out))),
-                    dtype: DType::Uint32,
-                }
-            }
-            DType::Uint16 => {
-                let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[u16] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const u16, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as u16),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Uint16(Arc::new(RwLock::new(out))),
-                    dtype: DType::Uint16,
-                }
-            }
-            DType::Uint8 => {
-                let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[u8] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const u8, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(scalar_value as u8),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Uint8(Arc::new(RwLock::new(out))),
-                    dtype: DType::Uint8,
-                }
-            }
-            DType::Complex64 => {
-                let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[Complex<f32>] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const Complex<f32>, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(Complex::new(scalar_value as f32, 0.0)),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Complex64(Arc::new(RwLock::new(out))),
-                    dtype: DType::Complex64,
-                }
-            }
-            DType::Complex128 => {
-                let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[Complex<f64>] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const Complex<f64>, values_len)
-                };
-                let out = match put_impl(
-                    &arr,
-                    idx_slice,
-                    vals,
-                    has_scalar.then_some(Complex::new(scalar_value, 0.0)),
-                ) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Complex128(Arc::new(RwLock::new(out))),
-                    dtype: DType::Complex128,
-                }
+        };
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Put sub-arrays along an axis, the inverse of `ndarray_take_axis`.
+///
+/// Each index selects a whole sub-array along `axis`. Array values must
+/// broadcast to the shape `take_axis` would return,
+/// `shape[..axis] + indices.shape + shape[axis + 1..]`; a scalar fills every
+/// selected sub-array. Repeated indices keep the last write. Returns an
+/// updated copy.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_put_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    indices_handle: *const NdArrayHandle,
+    indices_meta: *const ArrayMetadata,
+    axis: i32,
+    values_handle: *const NdArrayHandle,
+    values_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+    out_handle: *mut *mut NdArrayHandle,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || indices_handle.is_null()
+        || indices_meta.is_null()
+        || values_handle.is_null() != values_meta.is_null()
+        || (values_handle.is_null() && scalar.is_null())
+        || out_handle.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("put_axis", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_SHAPE;
             }
-            DType::Bool => {
-                let Some(arr) = extract_array_bool(wrapper, meta_ref) else {
-                    error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
-                };
-                let vals: &[u8] = if values.is_null() || values_len == 0 {
-                    &[]
-                } else {
-                    std::slice::from_raw_parts(values as *const u8, values_len)
-                };
-                let scalar = if has_scalar {
-                    Some(if scalar_value != 0.0 { 1u8 } else { 0u8 })
-                } else {
-                    None
-                };
-                let out = match put_impl(&arr, idx_slice, vals, scalar) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_INDEX;
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Bool(Arc::new(RwLock::new(out))),
-                    dtype: DType::Bool,
-                }
+        };
+
+        let result = read_operands(
+            indices_handle,
+            indices_meta,
+            values_handle,
+            values_meta,
+            scalar,
+            scalar_dtype,
+        )
+        .and_then(|(indices, source)| {
+            put_dispatch(wrapper, meta, indices, Target::Axis(axis), source)
+        });
+        let result_wrapper = match result {
+            Ok(w) => w,
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
             }
         };
 
@@ -436,3 +458,76 @@ pub unsafe extern "C" fn ndarray_put(
         SUCCESS
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flat_values_broadcast_then_cycle() {
+        let arr = ArrayD::from_shape_vec(IxDyn(&[6]), vec![0i32; 6]).unwrap();
+        let indices = ArrayD::from_shape_vec(IxDyn(&[2, 2]), vec![0i64, 1, 4, -1]).unwrap();
+
+        let column = ArrayD::from_shape_vec(IxDyn(&[2, 1]), vec![7, 8]).unwrap();
+        let out = put_impl(
+            arr.view(),
+            indices.view(),
+            Target::Flat,
+            Values::Array(column),
+        )
+        .unwrap();
+        assert_eq!(
+            out.iter().copied().collect::<Vec<_>>(),
+            vec![7, 7, 0, 0, 8, 8]
+        );
+
+        let short = ArrayD::from_shape_vec(IxDyn(&[3]), vec![1, 2, 3]).unwrap();
+        let out = put_impl(
+            arr.view(),
+            indices.view(),
+            Target::Flat,
+            Values::Array(short),
+        )
+        .unwrap();
+        assert_eq!(
+            out.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 0, 0, 3, 1]
+        );
+    }
+
+    #[test]
+    fn axis_put_writes_sub_arrays() {
+        let arr = ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![1, 2, 3, 4, 5, 6]).unwrap();
+        let indices = ArrayD::from_shape_vec(IxDyn(&[2]), vec![2i64, 0]).unwrap();
+
+        let cols = ArrayD::from_shape_vec(IxDyn(&[2]), vec![9, 8]).unwrap();
+        let out = put_impl(
+            arr.view(),
+            indices.view(),
+            Target::Axis(1),
+            Values::Array(cols),
+        )
+        .unwrap();
+        assert_eq!(
+            out.iter().copied().collect::<Vec<_>>(),
+            vec![8, 2, 9, 8, 5, 9]
+        );
+
+        let out = put_impl(
+            arr.view(),
+            indices.view(),
+            Target::Axis(0),
+            Values::Scalar(0),
+        );
+        assert_eq!(out.unwrap_err().0, ERR_INDEX);
+
+        let bad = ArrayD::from_shape_vec(IxDyn(&[3]), vec![1, 2, 3]).unwrap();
+        let out = put_impl(
+            arr.view(),
+            indices.view(),
+            Target::Axis(1),
+            Values::Array(bad),
+        );
+        assert_eq!(out.unwrap_err().0, ERR_SHAPE);
+    }
+}
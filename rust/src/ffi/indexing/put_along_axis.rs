@@ -5,10 +5,12 @@ use crate::helpers::normalize_axis;
 use crate::helpers::normalize_index;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
+use crate::types::half::f32_to_half;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use ndarray::{ArrayD, Dimension, IxDyn};
 use num_complex::Complex;
@@ -83,7 +85,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("put_along_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
         let meta_ref = &*meta;
@@ -102,14 +104,14 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
         };
         let Some(indices_arr) = extract_array_i64(indices_wrapper, indices_meta_ref) else {
             error::set_last_error("Failed to extract Int64 indices view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[f64] = if values.is_null() || values_len == 0 {
                     &[]
@@ -137,7 +139,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[f32] = if values.is_null() || values_len == 0 {
                     &[]
@@ -165,7 +167,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[i64] = if values.is_null() || values_len == 0 {
                     &[]
@@ -193,7 +195,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[i32] = if values.is_null() || values_len == 0 {
                     &[]
@@ -221,7 +223,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[i16] = if values.is_null() || values_len == 0 {
                     &[]
@@ -249,7 +251,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[i8] = if values.is_null() || values_len == 0 {
                     &[]
@@ -277,7 +279,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[u64] = if values.is_null() || values_len == 0 {
                     &[]
@@ -305,7 +307,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[u32] = if values.is_null() || values_len == 0 {
                     &[]
@@ -333,7 +335,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[u16] = if values.is_null() || values_len == 0 {
                     &[]
@@ -358,10 +360,35 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let vals: &[u16] = if values.is_null() || values_len == 0 {
+                    &[]
+                } else {
+                    std::slice::from_raw_parts(values as *const u16, values_len)
+                };
+                let out = match put_along_axis_impl(
+                    &arr,
+                    &indices_arr,
+                    axis_usize,
+                    vals,
+                    has_scalar.then_some(f32_to_half(wrapper.dtype, scalar_value as f32)),
+                ) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_INDEX;
+                    }
+                };
+                NDArrayWrapper::from_half_bits(out, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[u8] = if values.is_null() || values_len == 0 {
                     &[]
@@ -389,7 +416,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[Complex<f32>] = if values.is_null() || values_len == 0 {
                     &[]
@@ -417,7 +444,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[Complex<f64>] = if values.is_null() || values_len == 0 {
                     &[]
@@ -445,7 +472,7 @@ pub unsafe extern "C" fn ndarray_put_along_axis(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let vals: &[u8] = if values.is_null() || values_len == 0 {
                     &[]
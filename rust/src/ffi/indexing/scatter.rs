@@ -0,0 +1,699 @@
+//! Scatter with a reduction mode along an axis.
+//!
+//! Slice `j` of `updates` along the axis is combined into slice `indices[j]` of a
+//! copy of the input. Repeated indices reduce together, which covers segment
+//! pooling (`Add`/`Max`/`Mean` into a zeroed output) and sparse updates (`Assign`).
+
+use super::put::{read_values, Source, Values};
+use crate::helpers::error::{
+    self, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_OVERFLOW, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{
+    extract_array_as_f32, extract_array_as_f64, extract_array_as_i16, extract_array_as_i32,
+    extract_array_as_i64, extract_array_as_i8, extract_array_as_u16, extract_array_as_u32,
+    extract_array_as_u64, extract_array_as_u8, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8, get_scalar_as_f32, get_scalar_as_f64,
+    get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8, get_scalar_as_u16,
+    get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8, normalize_axis, normalize_index,
+    write_output_metadata,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle, ScatterMode};
+use ndarray::{ArrayD, Axis};
+use num_traits::{Num, NumCast};
+use parking_lot::RwLock;
+use std::ffi::c_void;
+use std::sync::Arc;
+
+/// Element types scatter can reduce into.
+///
+/// Integer sums and products are checked; floats never overflow here.
+trait ScatterElem: Copy + PartialOrd + Num + NumCast {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_scatter_elem_int {
+    ($($t:ty),*) => {$(
+        impl ScatterElem for $t {
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_scatter_elem_float {
+    ($($t:ty),*) => {$(
+        impl ScatterElem for $t {
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+        }
+    )*};
+}
+
+impl_scatter_elem_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+impl_scatter_elem_float!(f32, f64);
+
+/// Combine `value` into `acc`, failing with `ERR_OVERFLOW` when an integer
+/// sum or product does not fit the dtype.
+#[inline]
+fn combine<T: ScatterElem>(mode: ScatterMode, acc: T, value: T) -> Result<T, (i32, String)> {
+    let overflow = || {
+        let what = if mode == ScatterMode::Mul {
+            "product"
+        } else {
+            "sum"
+        };
+        (
+            ERR_OVERFLOW,
+            format!("Integer overflow in scatter {}", what),
+        )
+    };
+    Ok(match mode {
+        ScatterMode::Assign => value,
+        ScatterMode::Add | ScatterMode::Mean => acc.checked_add(value).ok_or_else(overflow)?,
+        ScatterMode::Mul => acc.checked_mul(value).ok_or_else(overflow)?,
+        ScatterMode::Min => {
+            if value < acc {
+                value
+            } else {
+                acc
+            }
+        }
+        ScatterMode::Max => {
+            if value > acc {
+                value
+            } else {
+                acc
+            }
+        }
+    })
+}
+
+/// The divisor for a `Mean` position with `n` contributions.
+fn mean_divisor<T: ScatterElem>(n: usize) -> Result<T, (i32, String)> {
+    NumCast::from(n).ok_or_else(|| {
+        (
+            ERR_OVERFLOW,
+            format!("Scatter mean count {} does not fit the dtype", n),
+        )
+    })
+}
+
+/// Reduce `updates` into `out` along `axis`.
+///
+/// Without `include_self`, the first value scattered to a position replaces the
+/// original instead of being combined with it. `Mean` divides each touched
+/// position by its number of contributions (truncating for integer dtypes).
+/// Integer overflow fails with `ERR_OVERFLOW` and a bad index with `ERR_INDEX`.
+fn scatter_axis_impl<T: ScatterElem>(
+    mut out: ArrayD<T>,
+    updates: &ArrayD<T>,
+    axis: usize,
+    indices: &[i64],
+    mode: ScatterMode,
+    include_self: bool,
+) -> Result<ArrayD<T>, (i32, String)> {
+    let len = out.len_of(Axis(axis));
+    let mut counts = vec![0usize; len];
+
+    for (j, &idx) in indices.iter().enumerate() {
+        let i = normalize_index(idx, len).map_err(|e| (ERR_INDEX, e))?;
+        let src = updates.index_axis(Axis(axis), j);
+        let mut dst = out.index_axis_mut(Axis(axis), i);
+        if mode == ScatterMode::Assign || (counts[i] == 0 && !include_self) {
+            dst.assign(&src);
+        } else {
+            for (d, &s) in dst.iter_mut().zip(src.iter()) {
+                *d = combine(mode, *d, s)?;
+            }
+        }
+        counts[i] += 1;
+    }
+
+    if mode == ScatterMode::Mean {
+        for (i, &count) in counts.iter().enumerate() {
+            let n = if include_self && count > 0 {
+                count + 1
+            } else {
+                count
+            };
+            if n > 1 {
+                let divisor: T = mean_divisor(n)?;
+                out.index_axis_mut(Axis(axis), i)
+                    .mapv_inplace(|v| v / divisor);
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+macro_rules! scatter_arm {
+    ($extract:ident, $variant:ident, $ctx:expr) => {{
+        let (wrapper, meta, updates_wrapper, updates_meta, axis, indices, mode, include_self) =
+            $ctx;
+        let (Some(arr), Some(updates)) = (
+            $extract(wrapper, meta),
+            $extract(updates_wrapper, updates_meta),
+        ) else {
+            error::set_last_error(format!("Failed to extract {} arrays", stringify!($variant)));
+            return ERR_DTYPE;
+        };
+        match scatter_axis_impl(arr, &updates, axis, indices, mode, include_self) {
+            Ok(out) => NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(out))),
+                dtype: DType::$variant,
+            },
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
+            }
+        }
+    }};
+}
+
+/// Scatter slices of `updates` into a copy of the array along `axis`, reducing by `mode`.
+///
+/// `indices` is a 1D integer array; `updates` must match the array's shape except
+/// along `axis`, where its length equals `indices.len()`. `mode` is a
+/// [`ScatterMode`] code. Updates must share the array's dtype; Bool and complex
+/// arrays are not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_scatter(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    indices_handle: *const NdArrayHandle,
+    indices_meta: *const ArrayMetadata,
+    updates_handle: *const NdArrayHandle,
+    updates_meta: *const ArrayMetadata,
+    axis: i32,
+    mode: i32,
+    include_self: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || indices_handle.is_null()
+        || indices_meta.is_null()
+        || updates_handle.is_null()
+        || updates_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("scatter", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
+        let updates_wrapper = NdArrayHandle::as_wrapper(updates_handle as *mut _);
+        let meta_ref = &*meta;
+        let indices_meta_ref = &*indices_meta;
+        let updates_meta_ref = &*updates_meta;
+
+        let mode = match ScatterMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        if !(indices_wrapper.dtype.is_signed() || indices_wrapper.dtype.is_unsigned()) {
+            error::set_last_error("scatter indices must have an integer dtype".to_string());
+            return ERR_DTYPE;
+        }
+        if updates_wrapper.dtype != wrapper.dtype {
+            error::set_last_error(format!(
+                "scatter updates dtype {} does not match array dtype {}",
+                updates_wrapper.dtype.name(),
+                wrapper.dtype.name()
+            ));
+            return ERR_DTYPE;
+        }
+        if indices_meta_ref.ndim != 1 {
+            error::set_last_error(format!(
+                "scatter indices must be 1D, got {} dimensions",
+                indices_meta_ref.ndim
+            ));
+            return ERR_SHAPE;
+        }
+
+        let shape = meta_ref.shape_slice();
+        let axis = match normalize_axis(shape, axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let num_indices = indices_meta_ref.shape_slice()[0];
+        let mut expected = shape.to_vec();
+        expected[axis] = num_indices;
+        if updates_meta_ref.shape_slice() != expected.as_slice() {
+            error::set_last_error(format!(
+                "scatter updates shape {:?} does not match expected {:?}",
+                updates_meta_ref.shape_slice(),
+                expected
+            ));
+            return ERR_SHAPE;
+        }
+
+        let Some(indices_arr) = extract_array_as_i64(indices_wrapper, indices_meta_ref) else {
+            error::set_last_error("Failed to extract indices as Int64".to_string());
+            return ERR_DTYPE;
+        };
+        let indices: Vec<i64> = indices_arr.iter().copied().collect();
+
+        let ctx = (
+            wrapper,
+            meta_ref,
+            updates_wrapper,
+            updates_meta_ref,
+            axis,
+            indices.as_slice(),
+            mode,
+            include_self,
+        );
+        let result_wrapper = match wrapper.dtype {
+            DType::Float64 => scatter_arm!(extract_array_f64, Float64, ctx),
+            DType::Float32 => scatter_arm!(extract_array_f32, Float32, ctx),
+            DType::Int64 => scatter_arm!(extract_array_i64, Int64, ctx),
+            DType::Int32 => scatter_arm!(extract_array_i32, Int32, ctx),
+            DType::Int16 => scatter_arm!(extract_array_i16, Int16, ctx),
+            DType::Int8 => scatter_arm!(extract_array_i8, Int8, ctx),
+            DType::Uint64 => scatter_arm!(extract_array_u64, Uint64, ctx),
+            DType::Uint32 => scatter_arm!(extract_array_u32, Uint32, ctx),
+            DType::Uint16 => scatter_arm!(extract_array_u16, Uint16, ctx),
+            DType::Float16 | DType::BFloat16 => {
+                // Reduce in f32 and round back once per element.
+                let (Some(arr), Some(updates), true) = (
+                    extract_array_as_f32(wrapper, meta_ref),
+                    extract_array_as_f32(updates_wrapper, updates_meta_ref),
+                    updates_wrapper.dtype == wrapper.dtype,
+                ) else {
+                    error::set_last_error(format!(
+                        "Failed to extract {} arrays",
+                        wrapper.dtype.name()
+                    ));
+                    return ERR_DTYPE;
+                };
+                match scatter_axis_impl(arr, &updates, axis, &indices, mode, include_self) {
+                    Ok(out) => NDArrayWrapper::from_f32_as_half(out, wrapper.dtype),
+                    Err((code, e)) => {
+                        error::set_last_error(e);
+                        return code;
+                    }
+                }
+            }
+            DType::Uint8 => scatter_arm!(extract_array_u8, Uint8, ctx),
+            DType::Bool | DType::Complex64 | DType::Complex128 => {
+                error::set_last_error(format!(
+                    "scatter is not supported for {} arrays",
+                    wrapper.dtype.name()
+                ));
+                return ERR_DTYPE;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Reduce one update per index into the flat logical positions of `data`.
+///
+/// Follows [`scatter_axis_impl`] element by element: without `include_self`
+/// the first update to a position replaces it, and `Mean` divides each
+/// touched position by its number of contributions once all updates are in.
+fn scatter_flat_impl<T: ScatterElem>(
+    data: &mut [T],
+    indices: &[i64],
+    updates: &[T],
+    mode: ScatterMode,
+    include_self: bool,
+) -> Result<(), (i32, String)> {
+    let mut counts = vec![0usize; data.len()];
+
+    for (&idx, &u) in indices.iter().zip(updates) {
+        let i = normalize_index(idx, data.len()).map_err(|e| (ERR_INDEX, e))?;
+        data[i] = if mode == ScatterMode::Assign || (counts[i] == 0 && !include_self) {
+            u
+        } else {
+            combine(mode, data[i], u)?
+        };
+        counts[i] += 1;
+    }
+
+    if mode == ScatterMode::Mean {
+        for (v, &count) in data.iter_mut().zip(&counts) {
+            let n = if include_self && count > 0 {
+                count + 1
+            } else {
+                count
+            };
+            if n > 1 {
+                *v = *v / mean_divisor(n)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Copy `arr` in C order, scatter `updates` into it and reshape.
+fn scatter_flat_values<T>(
+    arr: ArrayD<T>,
+    indices: &ArrayD<i64>,
+    updates: Values<T>,
+    mode: ScatterMode,
+    include_self: bool,
+) -> Result<ArrayD<T>, (i32, String)>
+where
+    T: ScatterElem,
+{
+    let updates = updates.per_index(indices.shape())?;
+    let indices: Vec<i64> = indices.iter().copied().collect();
+    let mut data: Vec<T> = arr.iter().copied().collect();
+    scatter_flat_impl(&mut data, &indices, &updates, mode, include_self)?;
+    ArrayD::from_shape_vec(arr.raw_dim(), data).map_err(|e| {
+        (
+            ERR_GENERIC,
+            format!("Failed to create scatter output: {}", e),
+        )
+    })
+}
+
+/// Scatter updates into flattened indices of a copy of the array, reducing by `mode`.
+///
+/// The flat counterpart of [`ndarray_scatter`] and a generalisation of
+/// `ndarray_scatter_add_flat`: `Min`, `Max` and `Mean` reduce repeated
+/// indices in a single pass, with `Mean` counting contributions per position.
+/// Pass either `updates_handle` / `updates_meta`, converted to the array's
+/// dtype and broadcast against the indices shape (repeated in order when
+/// they do not broadcast), or null for both and a `scalar` of `scalar_dtype`.
+/// Indices may have any integer dtype and shape. Bool and complex arrays are
+/// not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_scatter_flat(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    indices_handle: *const NdArrayHandle,
+    indices_meta: *const ArrayMetadata,
+    updates_handle: *const NdArrayHandle,
+    updates_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+    mode: i32,
+    include_self: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || indices_handle.is_null()
+        || indices_meta.is_null()
+        || updates_handle.is_null() != updates_meta.is_null()
+        || (updates_handle.is_null() && scalar.is_null())
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("scatter_flat", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
+        let meta_ref = &*meta;
+
+        let mode = match ScatterMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        if !(indices_wrapper.dtype.is_signed() || indices_wrapper.dtype.is_unsigned()) {
+            error::set_last_error("scatter indices must have an integer dtype".to_string());
+            return ERR_DTYPE;
+        }
+        let Some(indices) = extract_array_as_i64(indices_wrapper, &*indices_meta) else {
+            error::set_last_error("Failed to extract indices as Int64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let source = if updates_handle.is_null() {
+            let Some(dtype) = DType::from_u8(scalar_dtype) else {
+                error::set_last_error("Invalid scalar dtype".to_string());
+                return ERR_DTYPE;
+            };
+            Source::Scalar(scalar, dtype)
+        } else {
+            Source::Array(
+                NdArrayHandle::as_wrapper(updates_handle as *mut _),
+                &*updates_meta,
+            )
+        };
+        let source_dtype = match source {
+            Source::Array(w, _) => w.dtype,
+            Source::Scalar(_, dtype) => dtype,
+        };
+        if source_dtype.is_complex() {
+            error::set_last_error(format!(
+                "scatter updates must be real, got {}",
+                source_dtype.name()
+            ));
+            return ERR_DTYPE;
+        }
+
+        macro_rules! scatter_flat_as {
+            ($extract:ident, $as_fn:ident, $scalar_fn:ident) => {{
+                let Some(arr) = $extract(wrapper, meta_ref) else {
+                    error::set_last_error(format!(
+                        "Failed to extract {} array",
+                        wrapper.dtype.name()
+                    ));
+                    return ERR_DTYPE;
+                };
+                let result = read_values(&source, $as_fn, $scalar_fn).and_then(|updates| {
+                    scatter_flat_values(arr, &indices, updates, mode, include_self)
+                });
+                match result {
+                    Ok(out) => out,
+                    Err((code, e)) => {
+                        error::set_last_error(e);
+                        return code;
+                    }
+                }
+            }};
+            ($variant:ident, $extract:ident, $as_fn:ident, $scalar_fn:ident) => {{
+                let out = scatter_flat_as!($extract, $as_fn, $scalar_fn);
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(out))),
+                    dtype: DType::$variant,
+                }
+            }};
+        }
+
+        let result_wrapper = match wrapper.dtype {
+            DType::Float64 => {
+                scatter_flat_as!(
+                    Float64,
+                    extract_array_f64,
+                    extract_array_as_f64,
+                    get_scalar_as_f64
+                )
+            }
+            DType::Float32 => {
+                scatter_flat_as!(
+                    Float32,
+                    extract_array_f32,
+                    extract_array_as_f32,
+                    get_scalar_as_f32
+                )
+            }
+            DType::Int64 => {
+                scatter_flat_as!(
+                    Int64,
+                    extract_array_i64,
+                    extract_array_as_i64,
+                    get_scalar_as_i64
+                )
+            }
+            DType::Int32 => {
+                scatter_flat_as!(
+                    Int32,
+                    extract_array_i32,
+                    extract_array_as_i32,
+                    get_scalar_as_i32
+                )
+            }
+            DType::Int16 => {
+                scatter_flat_as!(
+                    Int16,
+                    extract_array_i16,
+                    extract_array_as_i16,
+                    get_scalar_as_i16
+                )
+            }
+            DType::Int8 => {
+                scatter_flat_as!(
+                    Int8,
+                    extract_array_i8,
+                    extract_array_as_i8,
+                    get_scalar_as_i8
+                )
+            }
+            DType::Uint64 => {
+                scatter_flat_as!(
+                    Uint64,
+                    extract_array_u64,
+                    extract_array_as_u64,
+                    get_scalar_as_u64
+                )
+            }
+            DType::Uint32 => {
+                scatter_flat_as!(
+                    Uint32,
+                    extract_array_u32,
+                    extract_array_as_u32,
+                    get_scalar_as_u32
+                )
+            }
+            DType::Uint16 => {
+                scatter_flat_as!(
+                    Uint16,
+                    extract_array_u16,
+                    extract_array_as_u16,
+                    get_scalar_as_u16
+                )
+            }
+            DType::Uint8 => {
+                scatter_flat_as!(
+                    Uint8,
+                    extract_array_u8,
+                    extract_array_as_u8,
+                    get_scalar_as_u8
+                )
+            }
+            DType::Float16 | DType::BFloat16 => {
+                // Reduce in f32 and round back once per element.
+                let out = scatter_flat_as!(
+                    extract_array_as_f32,
+                    extract_array_as_f32,
+                    get_scalar_as_f32
+                );
+                NDArrayWrapper::from_f32_as_half(out, wrapper.dtype)
+            }
+            DType::Bool | DType::Complex64 | DType::Complex128 => {
+                error::set_last_error(format!(
+                    "scatter is not supported for {} arrays",
+                    wrapper.dtype.name()
+                ));
+                return ERR_DTYPE;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::IxDyn;
+
+    #[test]
+    fn flat_reductions_with_and_without_self() {
+        let indices = [0i64, 2, 0, -3];
+        let updates = [1.0, 5.0, 3.0, 2.0];
+
+        let mut mean = vec![0.0; 3];
+        scatter_flat_impl(&mut mean, &indices, &updates, ScatterMode::Mean, false).unwrap();
+        assert_eq!(mean, vec![2.0, 0.0, 5.0]);
+
+        let mut max = vec![4.0, 9.0, 1.0];
+        scatter_flat_impl(&mut max, &indices, &updates, ScatterMode::Max, true).unwrap();
+        assert_eq!(max, vec![4.0, 9.0, 5.0]);
+
+        let mut min = vec![4i32, 9, 1];
+        scatter_flat_impl(&mut min, &indices, &[7, 8, 6, 5], ScatterMode::Min, false).unwrap();
+        assert_eq!(min, vec![5, 9, 8]);
+
+        let mut out = vec![0i32; 3];
+        assert!(scatter_flat_impl(&mut out, &[3], &[1], ScatterMode::Add, true).is_err());
+    }
+
+    #[test]
+    fn integer_overflow_is_an_error() {
+        let code = |r: Result<(), (i32, String)>| r.unwrap_err().0;
+
+        let mut sum = vec![100i8];
+        let r = scatter_flat_impl(&mut sum, &[0, 0], &[20, 20], ScatterMode::Add, true);
+        assert_eq!(code(r), ERR_OVERFLOW);
+
+        let mut mean = vec![0u8];
+        let r = scatter_flat_impl(&mut mean, &[0, 0], &[200, 100], ScatterMode::Mean, false);
+        assert_eq!(code(r), ERR_OVERFLOW);
+
+        let mut mean = vec![0u8; 1];
+        let r = scatter_flat_impl(&mut mean, &[0; 256], &[0; 256], ScatterMode::Mean, false);
+        assert_eq!(code(r), ERR_OVERFLOW);
+
+        let mut out = vec![0i32];
+        let r = scatter_flat_impl(&mut out, &[1], &[1], ScatterMode::Add, true);
+        assert_eq!(code(r), ERR_INDEX);
+
+        let out = scatter_axis_impl(
+            ArrayD::from_elem(IxDyn(&[2]), 1i16),
+            &ArrayD::from_elem(IxDyn(&[2]), i16::MAX),
+            0,
+            &[1, 1],
+            ScatterMode::Mul,
+            true,
+        );
+        assert_eq!(out.unwrap_err().0, ERR_OVERFLOW);
+    }
+}
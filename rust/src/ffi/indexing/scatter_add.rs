@@ -3,11 +3,12 @@
 use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_MATH, SUCCESS};
 use crate::helpers::normalize_index;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
+    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
+use crate::types::half::half_to_f32;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use ndarray::ArrayD;
 use num_complex::Complex;
@@ -64,7 +65,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("scatter_add_flat", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
         let meta_ref = &*meta;
@@ -77,7 +78,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
 
         let Some(indices_arr) = extract_array_i64(indices_wrapper, indices_meta_ref) else {
             error::set_last_error("Failed to extract Int64 indices view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let idx_slice = indices_arr.as_slice().unwrap_or(&[]);
 
@@ -85,7 +86,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[f64] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -112,7 +113,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[f32] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -136,10 +137,38 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                // Updates arrive as half bit patterns; accumulate in f32.
+                let upd: Vec<f32> = if updates.is_null() || updates_len == 0 {
+                    Vec::new()
+                } else {
+                    std::slice::from_raw_parts(updates as *const u16, updates_len)
+                        .iter()
+                        .map(|&b| half_to_f32(wrapper.dtype, b))
+                        .collect()
+                };
+                let out = match scatter_add_impl(
+                    &arr,
+                    idx_slice,
+                    &upd,
+                    has_scalar.then_some(scalar_update as f32),
+                ) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_INDEX;
+                    }
+                };
+                NDArrayWrapper::from_f32_as_half(out, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[i64] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -166,7 +195,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[i32] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -193,7 +222,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[i16] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -220,7 +249,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[i8] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -247,7 +276,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[u64] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -274,7 +303,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[u32] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -301,7 +330,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[u16] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -328,7 +357,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[u8] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -355,7 +384,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[Complex<f32>] = if updates.is_null() || updates_len == 0 {
                     &[]
@@ -382,7 +411,7 @@ pub unsafe extern "C" fn ndarray_scatter_add_flat(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let upd: &[Complex<f64>] = if updates.is_null() || updates_len == 0 {
                     &[]
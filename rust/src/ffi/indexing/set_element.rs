@@ -27,7 +27,7 @@ pub unsafe extern "C" fn ndarray_set_element(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("set_element", {
         let wrapper = NdArrayHandle::as_wrapper(handle);
 
         let result = match wrapper.dtype {
@@ -79,6 +79,14 @@ pub unsafe extern "C" fn ndarray_set_element(
                 let v = *(value as *const Complex<f64>);
                 wrapper.set_element_complex128(flat_index, v)
             }
+            DType::Float16 => {
+                let v = *(value as *const u16);
+                wrapper.set_element_f16(flat_index, v)
+            }
+            DType::BFloat16 => {
+                let v = *(value as *const u16);
+                wrapper.set_element_bf16(flat_index, v)
+            }
             DType::Bool => {
                 let v = *(value as *const u8);
                 wrapper.set_element_bool(flat_index, v)
@@ -0,0 +1,126 @@
+//! Batched element set operations.
+//!
+//! Writes many elements in a single FFI call under one write lock.
+
+use std::ffi::c_void;
+
+use crate::helpers::error::{self, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS};
+use crate::helpers::resolve_storage_indices;
+use crate::types::{ArrayData, ArrayMetadata, NdArrayHandle};
+use ndarray::ArrayD;
+use parking_lot::RwLock;
+
+fn scatter<T: Copy>(
+    arr: &RwLock<ArrayD<T>>,
+    storage: &[usize],
+    values: *const T,
+) -> Result<(), String> {
+    let values = unsafe { std::slice::from_raw_parts(values, storage.len()) };
+    let mut guard = arr.write();
+    let len = guard.len();
+    if let Some(&i) = storage.iter().find(|&&i| i >= len) {
+        return Err(format!(
+            "Index {} out of bounds for array with {} elements",
+            i, len
+        ));
+    }
+
+    match guard.as_slice_memory_order_mut() {
+        Some(slice) => {
+            for (&i, &v) in storage.iter().zip(values) {
+                slice[i] = v;
+            }
+        }
+        None => {
+            let mut elems: Vec<&mut T> = guard.iter_mut().collect();
+            for (&i, &v) in storage.iter().zip(values) {
+                *elems[i] = v;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Set many elements of an array or view in one call.
+///
+/// # Arguments
+/// * `handle` - Array handle
+/// * `meta` - View metadata (offset, shape, strides)
+/// * `indices` - `n * index_ndim` indices stored back to back
+/// * `n` - Number of elements to write
+/// * `index_ndim` - 1 for logical C-order flat indices, or `ndim` for coordinate tuples
+/// * `values` - Buffer of `n` elements in the array's dtype (complex as re/im pairs, bool as u8)
+///
+/// Negative indices count from the end. All indices are validated before the
+/// array is modified. When an index repeats, the last value wins.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_set_many(
+    handle: *mut NdArrayHandle,
+    meta: *const ArrayMetadata,
+    indices: *const i64,
+    n: usize,
+    index_ndim: usize,
+    values: *const c_void,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || (n > 0 && (indices.is_null() || values.is_null())) {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("set_many", {
+        let wrapper = NdArrayHandle::as_wrapper(handle);
+        let meta_ref = &*meta;
+
+        if index_ndim == 0 || (index_ndim != 1 && index_ndim != meta_ref.ndim) {
+            error::set_last_error(format!(
+                "set_many expects 1 (flat) or {} (coordinate) components per index, got {}",
+                meta_ref.ndim, index_ndim
+            ));
+            return ERR_SHAPE;
+        }
+        if n == 0 {
+            return SUCCESS;
+        }
+
+        let indices = std::slice::from_raw_parts(indices, n * index_ndim);
+        let storage = match resolve_storage_indices(
+            meta_ref.shape_slice(),
+            meta_ref.strides_slice(),
+            meta_ref.offset,
+            indices,
+            index_ndim,
+        ) {
+            Ok(s) => s,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_INDEX;
+            }
+        };
+
+        let result = match &wrapper.data {
+            ArrayData::Int8(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Int16(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Int32(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Int64(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Uint8(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Uint16(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Uint32(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Uint64(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Float32(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Float64(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Bool(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Complex64(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Complex128(a) => scatter(a, &storage, values as *const _),
+            ArrayData::Float16(a) | ArrayData::BFloat16(a) => {
+                scatter(a, &storage, values as *const _)
+            }
+        };
+
+        match result {
+            Ok(()) => SUCCESS,
+            Err(e) => {
+                error::set_last_error(e);
+                ERR_INDEX
+            }
+        }
+    })
+}
@@ -6,8 +6,9 @@ use crate::helpers::normalize_index;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -55,7 +56,7 @@ pub unsafe extern "C" fn ndarray_take(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("take", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
         let meta_ref = &*meta;
@@ -68,7 +69,7 @@ pub unsafe extern "C" fn ndarray_take(
 
         let Some(indices_arr) = extract_array_i64(indices_wrapper, indices_meta_ref) else {
             error::set_last_error("Failed to extract Int64 indices view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
 
         let idx_slice = indices_arr.as_slice().unwrap_or(&[]);
@@ -78,7 +79,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -95,7 +96,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -112,7 +113,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -129,7 +130,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -146,7 +147,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -163,7 +164,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -180,7 +181,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -197,7 +198,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -214,7 +215,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -228,10 +229,24 @@ pub unsafe extern "C" fn ndarray_take(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_INDEX;
+                    }
+                };
+                NDArrayWrapper::from_half_bits(out, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -248,7 +263,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -265,7 +280,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -282,7 +297,7 @@ pub unsafe extern "C" fn ndarray_take(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_impl(&arr, idx_slice, idx_shape_slice) {
                     Ok(v) => v,
@@ -452,7 +467,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("take_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
         let meta_ref = &*meta;
@@ -465,7 +480,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
 
         let Some(indices_arr) = extract_array_i64(indices_wrapper, indices_meta_ref) else {
             error::set_last_error("Failed to extract Int64 indices view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
 
         let idx_slice = indices_arr.as_slice().unwrap_or(&[]);
@@ -483,7 +498,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -500,7 +515,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -517,7 +532,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -534,7 +549,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -551,7 +566,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -568,7 +583,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -585,7 +600,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -602,7 +617,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -619,7 +634,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -633,10 +648,24 @@ pub unsafe extern "C" fn ndarray_take_axis(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_INDEX;
+                    }
+                };
+                NDArrayWrapper::from_half_bits(out, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -653,7 +682,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -670,7 +699,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
@@ -687,7 +716,7 @@ pub unsafe extern "C" fn ndarray_take_axis(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_axis_impl(&arr, idx_slice, idx_shape_slice, axis_usize) {
                     Ok(v) => v,
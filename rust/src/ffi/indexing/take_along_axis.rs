@@ -6,8 +6,9 @@ use crate::helpers::normalize_index;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -40,7 +41,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("take_along_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
         let meta_ref = &*meta;
@@ -61,14 +62,14 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
 
         let Some(indices_arr) = extract_array_i64(indices_wrapper, indices_meta_ref) else {
             error::set_last_error("Failed to extract Int64 indices view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -85,7 +86,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -102,7 +103,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -119,7 +120,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -136,7 +137,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -153,7 +154,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -170,7 +171,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -187,7 +188,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -204,7 +205,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -218,10 +219,24 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_INDEX;
+                    }
+                };
+                NDArrayWrapper::from_half_bits(out, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -238,7 +253,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -255,7 +270,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -272,7 +287,7 @@ pub unsafe extern "C" fn ndarray_take_along_axis(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let out = match take_along_axis_impl(&arr, &indices_arr, axis_usize) {
                     Ok(v) => v,
@@ -0,0 +1,280 @@
+//! Row gather for embedding-table lookups.
+//!
+//! Copies whole rows of a 2D table with one slice copy per index instead of
+//! going element by element through dynamic-dimension indexing.
+
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS};
+use crate::helpers::{
+    extract_array_as_i64, extract_array_bool, extract_array_c128, extract_array_c64,
+    extract_array_f32, extract_array_f64, extract_array_half_bits, extract_array_i16,
+    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
+    extract_array_u64, extract_array_u8, extract_view_bool, extract_view_c128, extract_view_c64,
+    extract_view_f32, extract_view_f64, extract_view_i16, extract_view_i32, extract_view_i64,
+    extract_view_i8, extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8,
+    is_c_contiguous, normalize_index, write_output_metadata,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+fn gather_rows<T: Copy>(
+    table: &[T],
+    vocab: usize,
+    dim: usize,
+    indices: &[i64],
+    out_shape: &[usize],
+) -> Result<ArrayD<T>, String> {
+    let mut out = Vec::with_capacity(indices.len() * dim);
+    for &idx in indices {
+        let row = normalize_index(idx, vocab)?;
+        out.extend_from_slice(&table[row * dim..(row + 1) * dim]);
+    }
+    ArrayD::from_shape_vec(IxDyn(out_shape), out)
+        .map_err(|e| format!("Failed to create take_rows output: {}", e))
+}
+
+/// Gather from a contiguous view when possible, otherwise from a C-order copy.
+macro_rules! take_rows_arm {
+    ($wrapper:expr, $meta:expr, $view_fn:ident, $array_fn:ident, $variant:ident, $ctx:expr) => {{
+        let (vocab, dim, indices, out_shape) = $ctx;
+        let gathered = if is_c_contiguous($meta.shape_slice(), $meta.strides_slice()) {
+            let Some(view) = $view_fn($wrapper, $meta) else {
+                error::set_last_error(format!("Failed to extract {} view", stringify!($variant)));
+                return ERR_DTYPE;
+            };
+            gather_rows(view.as_slice().unwrap(), vocab, dim, indices, out_shape)
+        } else {
+            let Some(arr) = $array_fn($wrapper, $meta) else {
+                error::set_last_error(format!("Failed to extract {} view", stringify!($variant)));
+                return ERR_DTYPE;
+            };
+            let arr = arr.as_standard_layout().into_owned();
+            gather_rows(arr.as_slice().unwrap(), vocab, dim, indices, out_shape)
+        };
+        match gathered {
+            Ok(out) => NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(out))),
+                dtype: DType::$variant,
+            },
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_INDEX;
+            }
+        }
+    }};
+}
+
+/// Gather rows of a 2D `(vocab, dim)` table by an integer index array of any shape.
+///
+/// The result has shape `indices.shape + (dim,)` and the table's dtype.
+/// Negative indices count from the end; out-of-range indices return ERR_INDEX.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_take_rows(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    indices_handle: *const NdArrayHandle,
+    indices_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || indices_handle.is_null()
+        || indices_meta.is_null()
+        || out_handle.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("take_rows", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let indices_wrapper = NdArrayHandle::as_wrapper(indices_handle as *mut _);
+        let meta_ref = &*meta;
+        let indices_meta_ref = &*indices_meta;
+
+        if !(indices_wrapper.dtype.is_signed() || indices_wrapper.dtype.is_unsigned()) {
+            error::set_last_error("take_rows indices must have an integer dtype".to_string());
+            return ERR_DTYPE;
+        }
+        if meta_ref.ndim != 2 {
+            error::set_last_error(format!(
+                "take_rows requires a 2D table, got {} dimensions",
+                meta_ref.ndim
+            ));
+            return ERR_SHAPE;
+        }
+
+        let Some(indices_arr) = extract_array_as_i64(indices_wrapper, indices_meta_ref) else {
+            error::set_last_error("Failed to extract indices as Int64".to_string());
+            return ERR_DTYPE;
+        };
+        let indices: Vec<i64> = indices_arr.iter().copied().collect();
+
+        let table_shape = meta_ref.shape_slice();
+        let (vocab, dim) = (table_shape[0], table_shape[1]);
+        let mut result_shape = indices_meta_ref.shape_slice().to_vec();
+        result_shape.push(dim);
+        let ctx = (vocab, dim, indices.as_slice(), result_shape.as_slice());
+
+        let result_wrapper = match wrapper.dtype {
+            DType::Float64 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_f64,
+                    extract_array_f64,
+                    Float64,
+                    ctx
+                )
+            }
+            DType::Float32 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_f32,
+                    extract_array_f32,
+                    Float32,
+                    ctx
+                )
+            }
+            DType::Int64 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_i64,
+                    extract_array_i64,
+                    Int64,
+                    ctx
+                )
+            }
+            DType::Int32 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_i32,
+                    extract_array_i32,
+                    Int32,
+                    ctx
+                )
+            }
+            DType::Int16 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_i16,
+                    extract_array_i16,
+                    Int16,
+                    ctx
+                )
+            }
+            DType::Int8 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_i8,
+                    extract_array_i8,
+                    Int8,
+                    ctx
+                )
+            }
+            DType::Uint64 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_u64,
+                    extract_array_u64,
+                    Uint64,
+                    ctx
+                )
+            }
+            DType::Uint32 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_u32,
+                    extract_array_u32,
+                    Uint32,
+                    ctx
+                )
+            }
+            DType::Uint16 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_u16,
+                    extract_array_u16,
+                    Uint16,
+                    ctx
+                )
+            }
+            DType::Float16 | DType::BFloat16 => {
+                let (vocab, dim, indices, out_shape) = ctx;
+                let Some(arr) = extract_array_half_bits(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                match gather_rows(arr.as_slice().unwrap(), vocab, dim, indices, out_shape) {
+                    Ok(out) => NDArrayWrapper::from_half_bits(out, wrapper.dtype),
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_INDEX;
+                    }
+                }
+            }
+            DType::Uint8 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_u8,
+                    extract_array_u8,
+                    Uint8,
+                    ctx
+                )
+            }
+            DType::Bool => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_bool,
+                    extract_array_bool,
+                    Bool,
+                    ctx
+                )
+            }
+            DType::Complex64 => {
+                take_rows_arm!(
+                    wrapper,
+                    meta_ref,
+                    extract_view_c64,
+                    extract_array_c64,
+                    Complex64,
+                    ctx
+                )
+            }
+            DType::Complex128 => take_rows_arm!(
+                wrapper,
+                meta_ref,
+                extract_view_c128,
+                extract_array_c128,
+                Complex128,
+                ctx
+            ),
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
@@ -1,60 +1,281 @@
 //! where(condition, x, y) operation with broadcasting.
+//!
+//! The condition and both value operands are read through strided views and
+//! broadcast without copying; only an operand whose dtype differs from the
+//! result dtype is converted first. A scalar `x` or `y` is selected directly
+//! instead of being materialised as an array.
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, ArrayViewD, CowArray, IxDyn, Zip};
+use parking_lot::RwLock;
 
 use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+#[cfg(feature = "parallel")]
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    broadcast_shape, extract_array_as_f32, extract_array_as_f64, extract_array_as_i16,
-    extract_array_as_i32, extract_array_as_i64, extract_array_as_i8, extract_array_as_u16,
-    extract_array_as_u32, extract_array_as_u64, extract_array_as_u8, extract_array_bool,
-    extract_array_c128, extract_array_c64,
+    broadcast_shape, extract_array_as_bf16, extract_array_as_bool, extract_array_as_c128,
+    extract_array_as_c64, extract_array_as_f16, extract_array_as_f32, extract_array_as_f64,
+    extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+    extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+    extract_view_bf16, extract_view_bool, extract_view_c128, extract_view_c64, extract_view_f16,
+    extract_view_f32, extract_view_f64, extract_view_i16, extract_view_i32, extract_view_i64,
+    extract_view_i8, extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8,
+    get_scalar_as_bf16, get_scalar_as_c128, get_scalar_as_c64, get_scalar_as_f16,
+    get_scalar_as_f32, get_scalar_as_f64, get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64,
+    get_scalar_as_i8, get_scalar_as_u16, get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
-use ndarray::{ArrayD, IxDyn};
-use parking_lot::RwLock;
-use std::sync::Arc;
 
-fn where_impl<T: Copy>(
-    cond: &ArrayD<u8>,
-    x: &ArrayD<T>,
-    y: &ArrayD<T>,
-) -> Result<(ArrayD<T>, Vec<usize>), String> {
-    let out_xy = broadcast_shape(x.shape(), y.shape()).ok_or_else(|| {
+/// A value operand as passed over FFI.
+enum Source<'a> {
+    Array(&'a NDArrayWrapper, &'a ArrayMetadata),
+    Scalar(*const c_void, DType),
+}
+
+impl Source<'_> {
+    fn dtype(&self) -> DType {
+        match self {
+            Source::Array(wrapper, _) => wrapper.dtype,
+            Source::Scalar(_, dtype) => *dtype,
+        }
+    }
+}
+
+/// A value operand converted to the result element type.
+enum Operand<'a, T> {
+    Array(CowArray<'a, T, IxDyn>),
+    Scalar(T),
+}
+
+impl<T> Operand<'_, T> {
+    fn shape(&self) -> &[usize] {
+        match self {
+            Operand::Array(arr) => arr.shape(),
+            Operand::Scalar(_) => &[],
+        }
+    }
+}
+
+/// Result dtype of `where()`.
+///
+/// Two arrays promote as in arithmetic; a scalar next to an array follows
+/// `DType::promote_scalar`, so `where(c, int8_array, 0)` stays Int8.
+fn result_dtype(x: &Source, y: &Source) -> DType {
+    match (x, y) {
+        (Source::Array(..), Source::Scalar(_, s)) => DType::promote_scalar(x.dtype(), *s),
+        (Source::Scalar(_, s), Source::Array(..)) => DType::promote_scalar(y.dtype(), *s),
+        _ => DType::promote(x.dtype(), y.dtype()),
+    }
+}
+
+/// Borrow an array operand that already has `dtype`, convert it otherwise.
+unsafe fn operand<'a, T>(
+    src: &Source<'a>,
+    dtype: DType,
+    view: unsafe fn(&'a NDArrayWrapper, &'a ArrayMetadata) -> Option<ArrayViewD<'a, T>>,
+    convert: unsafe fn(&NDArrayWrapper, &ArrayMetadata) -> Option<ArrayD<T>>,
+    scalar: unsafe fn(*const c_void, DType) -> T,
+) -> Result<Operand<'a, T>, (i32, String)> {
+    match *src {
+        Source::Array(wrapper, meta) => {
+            let arr = if wrapper.dtype == dtype {
+                view(wrapper, meta).map(CowArray::from)
+            } else {
+                convert(wrapper, meta).map(CowArray::from)
+            };
+            arr.map(Operand::Array).ok_or_else(|| {
+                (
+                    ERR_DTYPE,
+                    format!("Failed to extract where() operand as {}", dtype),
+                )
+            })
+        }
+        Source::Scalar(ptr, scalar_dtype) => Ok(Operand::Scalar(scalar(ptr, scalar_dtype))),
+    }
+}
+
+/// Broadcast shape of the condition and both value operands.
+fn where_shape(cond: &[usize], x: &[usize], y: &[usize]) -> Result<Vec<usize>, String> {
+    let out_xy = broadcast_shape(x, y).ok_or_else(|| {
         format!(
             "x shape {:?} and y shape {:?} are not broadcast-compatible",
-            x.shape(),
-            y.shape()
+            x, y
         )
     })?;
-    let out_shape = broadcast_shape(&out_xy, cond.shape()).ok_or_else(|| {
+    broadcast_shape(&out_xy, cond).ok_or_else(|| {
         format!(
             "condition shape {:?} is not broadcast-compatible with x/y shape {:?}",
-            cond.shape(),
-            out_xy
+            cond, out_xy
         )
-    })?;
+    })
+}
+
+fn broadcast_to<'v, T>(
+    arr: &'v CowArray<'_, T, IxDyn>,
+    shape: &[usize],
+    name: &str,
+) -> Result<ArrayViewD<'v, T>, String> {
+    arr.broadcast(IxDyn(shape))
+        .ok_or_else(|| format!("Failed to broadcast {} in where()", name))
+}
+
+#[inline(always)]
+fn pick<T>(c: u8, x: T, y: T) -> T {
+    if c != 0 {
+        x
+    } else {
+        y
+    }
+}
 
-    let out_dyn = IxDyn(&out_shape);
-    let xb = x
-        .broadcast(out_dyn.clone())
-        .ok_or_else(|| "Failed to broadcast x in where()".to_string())?;
-    let yb = y
-        .broadcast(out_dyn.clone())
-        .ok_or_else(|| "Failed to broadcast y in where()".to_string())?;
+/// Take `x` where `cond` is non-zero and `y` elsewhere.
+fn select<T: Copy + Send + Sync>(
+    cond: ArrayViewD<'_, u8>,
+    x: &Operand<'_, T>,
+    y: &Operand<'_, T>,
+) -> Result<ArrayD<T>, String> {
+    let shape = where_shape(cond.shape(), x.shape(), y.shape())?;
     let cb = cond
-        .broadcast(out_dyn.clone())
+        .broadcast(IxDyn(&shape))
         .ok_or_else(|| "Failed to broadcast condition in where()".to_string())?;
 
-    // Use ndarray::Zip for vectorized, cache-efficient element-wise selection
-    let result = ndarray::Zip::from(&cb)
-        .and(&xb)
-        .and(&yb)
-        .map_collect(|&c, &x, &y| if c != 0 { x } else { y });
+    macro_rules! collect {
+        ($zip:expr, $f:expr) => {{
+            let zip = $zip;
+            #[cfg(feature = "parallel")]
+            if parallel::should_parallelize(cb.len()) {
+                return Ok(parallel::install(|| zip.par_map_collect($f)));
+            }
+            Ok(zip.map_collect($f))
+        }};
+    }
+
+    match (x, y) {
+        (Operand::Array(x), Operand::Array(y)) => {
+            let xb = broadcast_to(x, &shape, "x")?;
+            let yb = broadcast_to(y, &shape, "y")?;
+            collect!(Zip::from(&cb).and(&xb).and(&yb), |&c, &x, &y| pick(c, x, y))
+        }
+        (Operand::Array(x), &Operand::Scalar(s)) => {
+            let xb = broadcast_to(x, &shape, "x")?;
+            collect!(Zip::from(&cb).and(&xb), move |&c, &x| pick(c, x, s))
+        }
+        (&Operand::Scalar(s), Operand::Array(y)) => {
+            let yb = broadcast_to(y, &shape, "y")?;
+            collect!(Zip::from(&cb).and(&yb), move |&c, &y| pick(c, s, y))
+        }
+        (&Operand::Scalar(a), &Operand::Scalar(b)) => {
+            collect!(Zip::from(&cb), move |&c| pick(c, a, b))
+        }
+    }
+}
+
+/// Evaluate `where()` for any combination of array and scalar operands.
+unsafe fn where_dispatch(
+    cond_wrapper: &NDArrayWrapper,
+    cond_meta: &ArrayMetadata,
+    x: Source,
+    y: Source,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    // Non-Bool conditions select where they are non-zero.
+    let cond = if cond_wrapper.dtype == DType::Bool {
+        extract_view_bool(cond_wrapper, cond_meta).map(CowArray::from)
+    } else {
+        extract_array_as_bool(cond_wrapper, cond_meta).map(CowArray::from)
+    };
+    let cond = cond.ok_or_else(|| (ERR_DTYPE, "Failed to read where() condition".to_string()))?;
+
+    let out_dtype = result_dtype(&x, &y);
+
+    macro_rules! select_as {
+        ($view_fn:ident, $as_fn:ident, $scalar_fn:ident) => {{
+            let xo = operand(&x, out_dtype, $view_fn, $as_fn, $scalar_fn)?;
+            let yo = operand(&y, out_dtype, $view_fn, $as_fn, $scalar_fn)?;
+            select(cond.view(), &xo, &yo).map_err(|e| (ERR_SHAPE, e))?
+        }};
+    }
+
+    macro_rules! wrap {
+        ($variant:ident, $arr:expr) => {
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new($arr))),
+                dtype: DType::$variant,
+            }
+        };
+    }
 
-    Ok((result, out_shape))
+    Ok(match out_dtype {
+        DType::Float64 => wrap!(
+            Float64,
+            select_as!(extract_view_f64, extract_array_as_f64, get_scalar_as_f64)
+        ),
+        DType::Float32 => wrap!(
+            Float32,
+            select_as!(extract_view_f32, extract_array_as_f32, get_scalar_as_f32)
+        ),
+        DType::Float16 => NDArrayWrapper::from_half_bits(
+            select_as!(extract_view_f16, extract_array_as_f16, get_scalar_as_f16),
+            DType::Float16,
+        ),
+        DType::BFloat16 => NDArrayWrapper::from_half_bits(
+            select_as!(extract_view_bf16, extract_array_as_bf16, get_scalar_as_bf16),
+            DType::BFloat16,
+        ),
+        DType::Int64 => wrap!(
+            Int64,
+            select_as!(extract_view_i64, extract_array_as_i64, get_scalar_as_i64)
+        ),
+        DType::Int32 => wrap!(
+            Int32,
+            select_as!(extract_view_i32, extract_array_as_i32, get_scalar_as_i32)
+        ),
+        DType::Int16 => wrap!(
+            Int16,
+            select_as!(extract_view_i16, extract_array_as_i16, get_scalar_as_i16)
+        ),
+        DType::Int8 => wrap!(
+            Int8,
+            select_as!(extract_view_i8, extract_array_as_i8, get_scalar_as_i8)
+        ),
+        DType::Uint64 => wrap!(
+            Uint64,
+            select_as!(extract_view_u64, extract_array_as_u64, get_scalar_as_u64)
+        ),
+        DType::Uint32 => wrap!(
+            Uint32,
+            select_as!(extract_view_u32, extract_array_as_u32, get_scalar_as_u32)
+        ),
+        DType::Uint16 => wrap!(
+            Uint16,
+            select_as!(extract_view_u16, extract_array_as_u16, get_scalar_as_u16)
+        ),
+        DType::Uint8 => wrap!(
+            Uint8,
+            select_as!(extract_view_u8, extract_array_as_u8, get_scalar_as_u8)
+        ),
+        DType::Complex64 => wrap!(
+            Complex64,
+            select_as!(extract_view_c64, extract_array_as_c64, get_scalar_as_c64)
+        ),
+        DType::Complex128 => wrap!(
+            Complex128,
+            select_as!(extract_view_c128, extract_array_as_c128, get_scalar_as_c128)
+        ),
+        DType::Bool => wrap!(
+            Bool,
+            select_as!(extract_view_bool, extract_array_as_bool, get_scalar_as_u8)
+        ),
+    })
 }
 
 /// Select values from x and y depending on condition.
+///
+/// All three operands broadcast together. The result dtype is the promotion
+/// of x and y; a condition that is not Bool selects x where it is non-zero.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_where(
     cond_handle: *const NdArrayHandle,
@@ -83,343 +304,93 @@ pub unsafe extern "C" fn ndarray_where(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("where", {
         let cond_wrapper = NdArrayHandle::as_wrapper(cond_handle as *mut _);
-        let x_wrapper = NdArrayHandle::as_wrapper(x_handle as *mut _);
-        let y_wrapper = NdArrayHandle::as_wrapper(y_handle as *mut _);
+        let x = Source::Array(NdArrayHandle::as_wrapper(x_handle as *mut _), &*x_meta);
+        let y = Source::Array(NdArrayHandle::as_wrapper(y_handle as *mut _), &*y_meta);
 
-        let cond_meta_ref = &*cond_meta;
-        let x_meta_ref = &*x_meta;
-        let y_meta_ref = &*y_meta;
+        let result_wrapper = match where_dispatch(cond_wrapper, &*cond_meta, x, y) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
+            }
+        };
 
-        if cond_wrapper.dtype != DType::Bool {
-            error::set_last_error("where() condition must have Bool dtype".to_string());
-            return ERR_DTYPE;
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            error::set_last_error(e);
+            return ERR_GENERIC;
         }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
 
-        let Some(cond_arr) = extract_array_bool(cond_wrapper, cond_meta_ref) else {
-            error::set_last_error("Failed to extract Bool condition view".to_string());
-            return ERR_GENERIC;
+/// Select between an array and a scalar depending on condition.
+///
+/// The scalar is `x` when `scalar_is_x` is true and `y` otherwise. The
+/// result dtype follows scalar promotion: the array's dtype is kept unless
+/// the scalar needs a float or complex result.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_where_scalar(
+    cond_handle: *const NdArrayHandle,
+    cond_meta: *const ArrayMetadata,
+    array_handle: *const NdArrayHandle,
+    array_meta: *const ArrayMetadata,
+    scalar: *const c_void,
+    scalar_dtype: u8,
+    scalar_is_x: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if cond_handle.is_null()
+        || cond_meta.is_null()
+        || array_handle.is_null()
+        || array_meta.is_null()
+        || scalar.is_null()
+        || out_handle.is_null()
+        || out_dtype_ptr.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    let Some(scalar_dtype) = DType::from_u8(scalar_dtype) else {
+        error::set_last_error("Invalid scalar dtype".to_string());
+        return ERR_DTYPE;
+    };
+
+    crate::ffi_guard!("where_scalar", {
+        let cond_wrapper = NdArrayHandle::as_wrapper(cond_handle as *mut _);
+        let array = Source::Array(
+            NdArrayHandle::as_wrapper(array_handle as *mut _),
+            &*array_meta,
+        );
+        let scalar = Source::Scalar(scalar, scalar_dtype);
+        let (x, y) = if scalar_is_x {
+            (scalar, array)
+        } else {
+            (array, scalar)
         };
 
-        let out_dtype = DType::promote(x_wrapper.dtype, y_wrapper.dtype);
-
-        let (result_wrapper, result_shape_vec) = match out_dtype {
-            DType::Float64 => {
-                let Some(xv) = extract_array_as_f64(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as f64".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_f64(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as f64".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Float64(Arc::new(RwLock::new(out))),
-                        dtype: DType::Float64,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Float32 => {
-                let Some(xv) = extract_array_as_f32(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as f32".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_f32(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as f32".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Float32(Arc::new(RwLock::new(out))),
-                        dtype: DType::Float32,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Int64 => {
-                let Some(xv) = extract_array_as_i64(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as i64".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_i64(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as i64".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Int64(Arc::new(RwLock::new(out))),
-                        dtype: DType::Int64,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Int32 => {
-                let Some(xv) = extract_array_as_i32(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as i32".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_i32(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as i32".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Int32(Arc::new(RwLock::new(out))),
-                        dtype: DType::Int32,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Int16 => {
-                let Some(xv) = extract_array_as_i16(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as i16".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_i16(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as i16".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Int16(Arc::new(RwLock::new(out))),
-                        dtype: DType::Int16,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Int8 => {
-                let Some(xv) = extract_array_as_i8(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as i8".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_i8(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as i8".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Int8(Arc::new(RwLock::new(out))),
-                        dtype: DType::Int8,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Uint64 => {
-                let Some(xv) = extract_array_as_u64(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as u64".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_u64(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as u64".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Uint64(Arc::new(RwLock::new(out))),
-                        dtype: DType::Uint64,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Uint32 => {
-                let Some(xv) = extract_array_as_u32(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as u32".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_u32(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as u32".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Uint32(Arc::new(RwLock::new(out))),
-                        dtype: DType::Uint32,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Uint16 => {
-                let Some(xv) = extract_array_as_u16(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as u16".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_u16(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as u16".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Uint16(Arc::new(RwLock::new(out))),
-                        dtype: DType::Uint16,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Uint8 => {
-                let Some(xv) = extract_array_as_u8(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as u8".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_as_u8(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as u8".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Uint8(Arc::new(RwLock::new(out))),
-                        dtype: DType::Uint8,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Complex64 => {
-                let Some(xv) = extract_array_c64(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as c64".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_c64(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as c64".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Complex64(Arc::new(RwLock::new(out))),
-                        dtype: DType::Complex64,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Complex128 => {
-                let Some(xv) = extract_array_c128(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as c128".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_c128(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as c128".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Complex128(Arc::new(RwLock::new(out))),
-                        dtype: DType::Complex128,
-                    },
-                    shape_vec,
-                )
-            }
-            DType::Bool => {
-                let Some(xv) = extract_array_bool(x_wrapper, x_meta_ref) else {
-                    error::set_last_error("Failed to extract x as bool".to_string());
-                    return ERR_GENERIC;
-                };
-                let Some(yv) = extract_array_bool(y_wrapper, y_meta_ref) else {
-                    error::set_last_error("Failed to extract y as bool".to_string());
-                    return ERR_GENERIC;
-                };
-                let (out, shape_vec) = match where_impl(&cond_arr, &xv, &yv) {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                (
-                    NDArrayWrapper {
-                        data: ArrayData::Bool(Arc::new(RwLock::new(out))),
-                        dtype: DType::Bool,
-                    },
-                    shape_vec,
-                )
+        let result_wrapper = match where_dispatch(cond_wrapper, &*cond_meta, x, y) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
             }
         };
 
-        let _ = result_shape_vec;
         if let Err(e) = write_output_metadata(
             &result_wrapper,
             out_dtype_ptr,
@@ -434,3 +405,38 @@ pub unsafe extern "C" fn ndarray_where(
         SUCCESS
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn broadcasts_condition_against_scalar_and_column() {
+        let cond = ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![1u8, 0, 1, 0, 1, 0]).unwrap();
+        let x = ArrayD::from_shape_vec(IxDyn(&[2, 1]), vec![10i32, 20]).unwrap();
+        let out = select(
+            cond.view(),
+            &Operand::Array(CowArray::from(x.view())),
+            &Operand::Scalar(-1),
+        )
+        .unwrap();
+        assert_eq!(out.shape(), &[2, 3]);
+        assert_eq!(
+            out.iter().copied().collect::<Vec<_>>(),
+            vec![10, -1, 10, -1, 20, -1]
+        );
+    }
+
+    #[test]
+    fn incompatible_shapes_are_rejected() {
+        let cond = ArrayD::from_elem(IxDyn(&[4]), 1u8);
+        let x = ArrayD::from_elem(IxDyn(&[3]), 0.0f64);
+        let err = select(
+            cond.view(),
+            &Operand::Array(CowArray::from(x.view())),
+            &Operand::Scalar(1.0),
+        )
+        .unwrap_err();
+        assert!(err.contains("condition shape [4]"), "{}", err);
+    }
+}
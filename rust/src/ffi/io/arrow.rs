@@ -0,0 +1,551 @@
+//! Apache Arrow IPC stream interop.
+//!
+//! Arrays are exchanged as a record batch stream with a single non-nullable
+//! `data` column. 0-D and 1-D arrays use a primitive column; a 0-D array is
+//! a single row marked with `ndarray:ndim = 0` field metadata so it loads
+//! back as 0-D. Higher dimensions use the `arrow.fixed_shape_tensor`
+//! canonical extension: one fixed-size list per index of the first axis,
+//! with the remaining axes in the extension metadata. Streams with several
+//! primitive columns of one type load as a 2-D `[rows, columns]` table.
+//!
+//! Both directions copy: values are serialized into the IPC stream on
+//! encode, and decoding builds a new C-contiguous array from the stream's
+//! buffers. No memory is shared with Arrow.
+//!
+//! The conversion needs the `arrow` cargo feature; the entry points are
+//! exported regardless and report the missing feature at runtime.
+
+use std::slice;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+
+#[cfg(not(feature = "arrow"))]
+use crate::helpers::error::ERR_NOT_IMPLEMENTED;
+use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+#[cfg(feature = "arrow")]
+mod ipc {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::{make_array, Array, ArrayRef, BooleanArray, RecordBatch};
+    use arrow_buffer::{ArrowNativeType, BooleanBuffer, Buffer};
+    use arrow_data::ArrayData as ArrowData;
+    use arrow_ipc::reader::StreamReader;
+    use arrow_ipc::writer::StreamWriter;
+    use arrow_schema::{ArrowError, DataType, Field, Schema};
+    use ndarray::{ArrayD, IxDyn};
+    use parking_lot::RwLock;
+
+    use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE};
+    use crate::helpers::{
+        extract_view_bool, extract_view_f32, extract_view_f64, extract_view_half_bits,
+        extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8, extract_view_u16,
+        extract_view_u32, extract_view_u64, extract_view_u8,
+    };
+    use crate::types::dtype::DType;
+    use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper};
+
+    const EXTENSION_NAME: &str = "ARROW:extension:name";
+    const EXTENSION_METADATA: &str = "ARROW:extension:metadata";
+    const TENSOR_EXTENSION: &str = "arrow.fixed_shape_tensor";
+    /// Field metadata key recording the rank of 0-D arrays.
+    const NDIM_METADATA: &str = "ndarray:ndim";
+
+    fn arrow_error(e: ArrowError) -> (i32, String) {
+        (ERR_GENERIC, format!("Arrow error: {}", e))
+    }
+
+    fn arrow_type(dtype: DType) -> Result<DataType, (i32, String)> {
+        Ok(match dtype {
+            DType::Int8 => DataType::Int8,
+            DType::Int16 => DataType::Int16,
+            DType::Int32 => DataType::Int32,
+            DType::Int64 => DataType::Int64,
+            DType::Uint8 => DataType::UInt8,
+            DType::Uint16 => DataType::UInt16,
+            DType::Uint32 => DataType::UInt32,
+            DType::Uint64 => DataType::UInt64,
+            DType::Float16 => DataType::Float16,
+            DType::Float32 => DataType::Float32,
+            DType::Float64 => DataType::Float64,
+            DType::Bool => DataType::Boolean,
+            DType::BFloat16 | DType::Complex64 | DType::Complex128 => {
+                return Err((ERR_DTYPE, format!("{} has no Arrow type", dtype.name())))
+            }
+        })
+    }
+
+    fn dtype_of(data_type: &DataType) -> Result<DType, (i32, String)> {
+        Ok(match data_type {
+            DataType::Int8 => DType::Int8,
+            DataType::Int16 => DType::Int16,
+            DataType::Int32 => DType::Int32,
+            DataType::Int64 => DType::Int64,
+            DataType::UInt8 => DType::Uint8,
+            DataType::UInt16 => DType::Uint16,
+            DataType::UInt32 => DType::Uint32,
+            DataType::UInt64 => DType::Uint64,
+            DataType::Float16 => DType::Float16,
+            DataType::Float32 => DType::Float32,
+            DataType::Float64 => DType::Float64,
+            DataType::Boolean => DType::Bool,
+            other => return Err((ERR_DTYPE, format!("Unsupported Arrow type {}", other))),
+        })
+    }
+
+    /// Element values of a view as a flat Arrow array in C order.
+    unsafe fn values_data(
+        wrapper: &NDArrayWrapper,
+        meta: &ArrayMetadata,
+        data_type: DataType,
+    ) -> Result<ArrowData, (i32, String)> {
+        macro_rules! primitive {
+            ($extract:ident) => {{
+                let Some(view) = $extract(wrapper, meta) else {
+                    return Err((
+                        ERR_DTYPE,
+                        format!("Failed to extract {} view", wrapper.dtype.name()),
+                    ));
+                };
+                let values: Vec<_> = view.iter().copied().collect();
+                ArrowData::builder(data_type)
+                    .len(values.len())
+                    .add_buffer(Buffer::from_vec(values))
+                    .build()
+                    .map_err(arrow_error)
+            }};
+        }
+
+        match wrapper.dtype {
+            DType::Int8 => primitive!(extract_view_i8),
+            DType::Int16 => primitive!(extract_view_i16),
+            DType::Int32 => primitive!(extract_view_i32),
+            DType::Int64 => primitive!(extract_view_i64),
+            DType::Uint8 => primitive!(extract_view_u8),
+            DType::Uint16 => primitive!(extract_view_u16),
+            DType::Uint32 => primitive!(extract_view_u32),
+            DType::Uint64 => primitive!(extract_view_u64),
+            DType::Float16 => primitive!(extract_view_half_bits),
+            DType::Float32 => primitive!(extract_view_f32),
+            DType::Float64 => primitive!(extract_view_f64),
+            DType::Bool => {
+                let Some(view) = extract_view_bool(wrapper, meta) else {
+                    return Err((ERR_DTYPE, "Failed to extract bool view".to_string()));
+                };
+                let bits = BooleanBuffer::from_iter(view.iter().map(|&b| b != 0));
+                Ok(BooleanArray::new(bits, None).into_data())
+            }
+            DType::BFloat16 | DType::Complex64 | DType::Complex128 => unreachable!(),
+        }
+    }
+
+    /// Encode an array or view as an Arrow IPC stream.
+    pub(super) unsafe fn encode(
+        wrapper: &NDArrayWrapper,
+        meta: &ArrayMetadata,
+    ) -> Result<Vec<u8>, (i32, String)> {
+        let data_type = arrow_type(wrapper.dtype)?;
+        let shape = meta.shape_slice();
+        let values = values_data(wrapper, meta, data_type.clone())?;
+
+        let (column, field) = if shape.is_empty() {
+            let metadata = HashMap::from([(NDIM_METADATA.to_string(), "0".to_string())]);
+            (
+                values,
+                Field::new("data", data_type, false).with_metadata(metadata),
+            )
+        } else if shape.len() == 1 {
+            (values, Field::new("data", data_type, false))
+        } else {
+            let inner = &shape[1..];
+            let size = i32::try_from(inner.iter().product::<usize>()).map_err(|_| {
+                (
+                    ERR_SHAPE,
+                    "Array rows are too large for an Arrow fixed-size list".to_string(),
+                )
+            })?;
+            let list_type =
+                DataType::FixedSizeList(Arc::new(Field::new("item", data_type, false)), size);
+            let list = ArrowData::builder(list_type.clone())
+                .len(shape[0])
+                .add_child_data(values)
+                .build()
+                .map_err(arrow_error)?;
+            let dims: Vec<String> = inner.iter().map(|d| d.to_string()).collect();
+            let metadata = HashMap::from([
+                (EXTENSION_NAME.to_string(), TENSOR_EXTENSION.to_string()),
+                (
+                    EXTENSION_METADATA.to_string(),
+                    format!("{{\"shape\":[{}]}}", dims.join(",")),
+                ),
+            ]);
+            (
+                list,
+                Field::new("data", list_type, false).with_metadata(metadata),
+            )
+        };
+
+        let schema = Arc::new(Schema::new(vec![field]));
+        let batch =
+            RecordBatch::try_new(schema.clone(), vec![make_array(column)]).map_err(arrow_error)?;
+        let mut writer = StreamWriter::try_new(Vec::new(), &schema).map_err(arrow_error)?;
+        writer.write(&batch).map_err(arrow_error)?;
+        writer.finish().map_err(arrow_error)?;
+        writer.into_inner().map_err(arrow_error)
+    }
+
+    /// Unsigned integers following `"key":` in a flat JSON object.
+    fn json_usize_list(json: &str, key: &str) -> Option<Vec<usize>> {
+        let rest = &json[json.find(&format!("\"{}\"", key))?..];
+        let open = rest.find('[')?;
+        let close = open + rest[open..].find(']')?;
+        rest[open + 1..close]
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse().ok())
+            .collect()
+    }
+
+    /// Axes stored in each list element, from the tensor extension metadata
+    /// when present.
+    fn list_dims(field: &Field, size: usize) -> Result<Vec<usize>, (i32, String)> {
+        let metadata = field.metadata();
+        if metadata.get(EXTENSION_NAME).map(String::as_str) != Some(TENSOR_EXTENSION) {
+            return Ok(vec![size]);
+        }
+
+        let invalid = || {
+            (
+                ERR_SHAPE,
+                format!(
+                    "Invalid {} metadata on column '{}'",
+                    TENSOR_EXTENSION,
+                    field.name()
+                ),
+            )
+        };
+        let json = metadata.get(EXTENSION_METADATA).ok_or_else(invalid)?;
+        let dims = json_usize_list(json, "shape").ok_or_else(invalid)?;
+        if dims.iter().product::<usize>() != size {
+            return Err(invalid());
+        }
+        if let Some(permutation) = json_usize_list(json, "permutation") {
+            if permutation.iter().enumerate().any(|(i, &p)| i != p) {
+                return Err((
+                    ERR_SHAPE,
+                    "Permuted fixed_shape_tensor columns are not supported".to_string(),
+                ));
+            }
+        }
+        Ok(dims)
+    }
+
+    /// Whether a primitive column holds a 0-D array.
+    fn is_scalar(field: &Field) -> bool {
+        field.metadata().get(NDIM_METADATA).map(String::as_str) == Some("0")
+    }
+
+    fn gather<T: ArrowNativeType>(chunks: &[ArrayRef]) -> Vec<T> {
+        let mut out = Vec::with_capacity(chunks.iter().map(|c| c.len()).sum());
+        for chunk in chunks {
+            let data = chunk.to_data();
+            let values = &data.buffers()[0].typed_data::<T>()[data.offset()..];
+            out.extend_from_slice(&values[..data.len()]);
+        }
+        out
+    }
+
+    fn gather_bool(chunks: &[ArrayRef]) -> Vec<u8> {
+        chunks
+            .iter()
+            .flat_map(|c| c.as_boolean().values().iter())
+            .map(u8::from)
+            .collect()
+    }
+
+    /// Decode an Arrow IPC stream into a C-contiguous array.
+    pub(super) fn decode(bytes: &[u8]) -> Result<NDArrayWrapper, (i32, String)> {
+        let reader = StreamReader::try_new(bytes, None).map_err(arrow_error)?;
+        let schema = reader.schema();
+        let batches = reader
+            .collect::<Result<Vec<RecordBatch>, _>>()
+            .map_err(arrow_error)?;
+        let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+
+        let fields = schema.fields();
+        let Some(first) = fields.first() else {
+            return Err((ERR_SHAPE, "Arrow stream has no columns".to_string()));
+        };
+
+        // Value chunks in storage order, the shape they fill, and whether
+        // that storage is column-major (tables).
+        let (dtype, chunks, dims, transpose) = match first.data_type() {
+            DataType::FixedSizeList(item, size) if fields.len() == 1 => {
+                let mut dims = vec![rows];
+                dims.extend(list_dims(first, *size as usize)?);
+                let children = batches
+                    .iter()
+                    .map(|b| b.column(0).as_fixed_size_list().values().clone())
+                    .collect::<Vec<_>>();
+                (dtype_of(item.data_type())?, children, dims, false)
+            }
+            data_type => {
+                let dtype = dtype_of(data_type)?;
+                if let Some(other) = fields.iter().find(|f| f.data_type() != data_type) {
+                    return Err((
+                        ERR_DTYPE,
+                        format!(
+                            "Arrow columns must share one primitive type, got {} and {}",
+                            data_type,
+                            other.data_type()
+                        ),
+                    ));
+                }
+                let columns = (0..fields.len())
+                    .flat_map(|c| batches.iter().map(move |b| b.column(c).clone()))
+                    .collect::<Vec<_>>();
+                if fields.len() == 1 && is_scalar(first) {
+                    if rows != 1 {
+                        return Err((
+                            ERR_SHAPE,
+                            format!("0-D Arrow column has {} rows, expected 1", rows),
+                        ));
+                    }
+                    (dtype, columns, vec![], false)
+                } else if fields.len() == 1 {
+                    (dtype, columns, vec![rows], false)
+                } else {
+                    (dtype, columns, vec![fields.len(), rows], true)
+                }
+            }
+        };
+
+        let parents = batches.iter().flat_map(|b| b.columns());
+        if chunks.iter().chain(parents).any(|c| c.null_count() > 0) {
+            return Err((
+                ERR_VALUE,
+                "Arrow data contains nulls, which arrays cannot represent".to_string(),
+            ));
+        }
+
+        macro_rules! build {
+            ($variant:ident, $values:expr) => {{
+                let arr = ArrayD::from_shape_vec(IxDyn(&dims), $values).map_err(|e| {
+                    (
+                        ERR_SHAPE,
+                        format!("Arrow data does not match its shape: {}", e),
+                    )
+                })?;
+                let arr = if transpose {
+                    arr.reversed_axes().as_standard_layout().into_owned()
+                } else {
+                    arr
+                };
+                ArrayData::$variant(Arc::new(RwLock::new(arr)))
+            }};
+        }
+
+        let data = match dtype {
+            DType::Int8 => build!(Int8, gather::<i8>(&chunks)),
+            DType::Int16 => build!(Int16, gather::<i16>(&chunks)),
+            DType::Int32 => build!(Int32, gather::<i32>(&chunks)),
+            DType::Int64 => build!(Int64, gather::<i64>(&chunks)),
+            DType::Uint8 => build!(Uint8, gather::<u8>(&chunks)),
+            DType::Uint16 => build!(Uint16, gather::<u16>(&chunks)),
+            DType::Uint32 => build!(Uint32, gather::<u32>(&chunks)),
+            DType::Uint64 => build!(Uint64, gather::<u64>(&chunks)),
+            DType::Float16 => build!(Float16, gather::<u16>(&chunks)),
+            DType::Float32 => build!(Float32, gather::<f32>(&chunks)),
+            DType::Float64 => build!(Float64, gather::<f64>(&chunks)),
+            DType::Bool => build!(Bool, gather_bool(&chunks)),
+            DType::BFloat16 | DType::Complex64 | DType::Complex128 => unreachable!(),
+        };
+
+        Ok(NDArrayWrapper { data, dtype })
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::helpers::extract_array_as_f64;
+
+        #[test]
+        fn tensor_round_trip() {
+            let shape = [2, 2, 3];
+            let strides = [6, 3, 1];
+            let meta = ArrayMetadata::new(0, shape.as_ptr(), strides.as_ptr(), shape.len());
+            let arr =
+                ArrayD::from_shape_vec(IxDyn(&shape), (0..12).map(f64::from).collect()).unwrap();
+            let wrapper = NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(arr.clone()))),
+                dtype: DType::Float64,
+            };
+
+            let bytes = unsafe { encode(&wrapper, &meta) }.unwrap();
+            let decoded = decode(&bytes).unwrap();
+            assert_eq!(decoded.dtype, DType::Float64);
+            let out = unsafe { extract_array_as_f64(&decoded, &meta) }.unwrap();
+            assert_eq!(out, arr);
+        }
+
+        #[test]
+        fn scalar_round_trip_keeps_rank() {
+            let meta = ArrayMetadata::new(0, [].as_ptr(), [].as_ptr(), 0);
+            let arr = ArrayD::from_elem(IxDyn(&[]), 2.5);
+            let wrapper = NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(arr.clone()))),
+                dtype: DType::Float64,
+            };
+
+            let bytes = unsafe { encode(&wrapper, &meta) }.unwrap();
+            let decoded = decode(&bytes).unwrap();
+            let out = unsafe { extract_array_as_f64(&decoded, &meta) }.unwrap();
+            assert_eq!(out, arr);
+            match &decoded.data {
+                ArrayData::Float64(a) => assert_eq!(a.read().ndim(), 0),
+                _ => panic!("expected Float64 data"),
+            }
+        }
+
+        #[test]
+        fn parses_tensor_shape_metadata() {
+            let json = r#"{"shape":[2, 3],"permutation":[0,1]}"#;
+            assert_eq!(json_usize_list(json, "shape"), Some(vec![2, 3]));
+            assert_eq!(json_usize_list(json, "permutation"), Some(vec![0, 1]));
+            assert_eq!(json_usize_list(json, "dim_names"), None);
+        }
+    }
+}
+
+#[cfg(feature = "arrow")]
+use ipc::{decode, encode};
+
+#[cfg(not(feature = "arrow"))]
+const FEATURE_DISABLED: &str =
+    "Arrow interop is unavailable: the library was built without the `arrow` feature";
+
+#[cfg(not(feature = "arrow"))]
+unsafe fn encode(
+    _wrapper: &NDArrayWrapper,
+    _meta: &ArrayMetadata,
+) -> Result<Vec<u8>, (i32, String)> {
+    Err((ERR_NOT_IMPLEMENTED, FEATURE_DISABLED.to_string()))
+}
+
+#[cfg(not(feature = "arrow"))]
+fn decode(_bytes: &[u8]) -> Result<NDArrayWrapper, (i32, String)> {
+    Err((ERR_NOT_IMPLEMENTED, FEATURE_DISABLED.to_string()))
+}
+
+/// Encode an array or view as an Arrow IPC stream.
+///
+/// The stream holds one record batch with a non-nullable `data` column:
+/// primitive for 0-D and 1-D arrays, an `arrow.fixed_shape_tensor` column
+/// with one row per index of the first axis otherwise. 0-D arrays carry
+/// `ndarray:ndim = 0` field metadata. BFloat16 and complex arrays have no
+/// Arrow type. The values are always copied into the stream, which is
+/// returned as a 1D Uint8 array. Requires the `arrow` feature.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_to_arrow_buffer(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("to_arrow_buffer", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let bytes = match encode(wrapper, &*meta) {
+            Ok(b) => b,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+
+        let len = bytes.len();
+        let result_wrapper = NDArrayWrapper {
+            data: ArrayData::Uint8(Arc::new(RwLock::new(
+                ArrayD::from_shape_vec(IxDyn(&[len]), bytes)
+                    .expect("Shape mismatch should not happen"),
+            ))),
+            dtype: DType::Uint8,
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Decode an Arrow IPC stream into an array.
+///
+/// Accepts a single primitive column (1-D result, or 0-D when marked with
+/// `ndarray:ndim = 0`), a single fixed-size list column (`[rows, list_size]`,
+/// or `[rows, ...shape]` for `arrow.fixed_shape_tensor`), or several
+/// primitive columns of one type (`[rows, columns]`). All record batches are
+/// concatenated into a new array; nulls are rejected. Requires the `arrow`
+/// feature.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_from_arrow_buffer(
+    bytes: *const u8,
+    len: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if bytes.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("from_arrow_buffer", {
+        let result_wrapper = match decode(slice::from_raw_parts(bytes, len)) {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,268 @@
+//! Self-describing binary buffers for caching arrays.
+//!
+//! A buffer is an 8-byte header — magic, format version, byte order, dtype
+//! and ndim — followed by `ndim` little-endian `u64` dimensions and the
+//! element data in C order and the writer's native byte order. The data
+//! therefore starts on an 8-byte boundary of the buffer.
+
+use std::slice;
+
+use super::{read_elements, write_elements};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+const MAGIC: &[u8; 4] = b"\x93NDA";
+
+const VERSION: u8 = 1;
+
+/// Length of the fixed part of the header.
+const FIXED_LEN: usize = 8;
+
+/// Byte order marker for this host.
+const NATIVE_ORDER: u8 = if cfg!(target_endian = "little") {
+    b'<'
+} else {
+    b'>'
+};
+
+/// Decoded buffer header.
+struct Header {
+    dtype: DType,
+    shape: Vec<usize>,
+    /// Data is stored in the opposite byte order to the host.
+    swap: bool,
+    /// Length of the header, i.e. the offset of the data.
+    len: usize,
+}
+
+fn header_len(ndim: usize) -> usize {
+    FIXED_LEN + 8 * ndim
+}
+
+fn encode_header(dtype: DType, shape: &[usize]) -> Result<Vec<u8>, String> {
+    let ndim = u8::try_from(shape.len())
+        .map_err(|_| format!("Too many dimensions to serialize: {}", shape.len()))?;
+
+    let mut out = Vec::with_capacity(header_len(shape.len()));
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[VERSION, NATIVE_ORDER, dtype as u8, ndim]);
+    for &d in shape {
+        out.extend_from_slice(&(d as u64).to_le_bytes());
+    }
+    Ok(out)
+}
+
+fn header_error(msg: &str) -> (i32, String) {
+    (ERR_VALUE, format!("Invalid array buffer: {}", msg))
+}
+
+fn parse_header(buf: &[u8]) -> Result<Header, (i32, String)> {
+    if buf.len() < FIXED_LEN || &buf[..4] != MAGIC {
+        return Err(header_error("missing header"));
+    }
+    let [version, order, dtype, ndim] = [buf[4], buf[5], buf[6], buf[7]];
+    if version != VERSION {
+        return Err(header_error(&format!("unsupported version {}", version)));
+    }
+    let swap = match order {
+        b'<' | b'>' => order != NATIVE_ORDER,
+        _ => return Err(header_error("unknown byte order")),
+    };
+    let dtype = DType::from_u8(dtype).ok_or_else(|| {
+        (
+            ERR_DTYPE,
+            format!("Invalid array buffer: unknown dtype {}", dtype),
+        )
+    })?;
+
+    let len = header_len(ndim as usize);
+    let dims = buf
+        .get(FIXED_LEN..len)
+        .ok_or_else(|| header_error("truncated shape"))?;
+    let shape = dims
+        .chunks_exact(8)
+        .map(|d| usize::try_from(u64::from_le_bytes(d.try_into().unwrap())))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| header_error("dimension too large"))?;
+
+    Ok(Header {
+        dtype,
+        shape,
+        swap: swap && dtype.item_size() > 1,
+        len,
+    })
+}
+
+/// Serialize an array or view into a self-describing byte buffer.
+///
+/// The buffer holds a small header (dtype, ndim, shape) followed by the raw
+/// native-endian elements in C order, so it can be cached as an opaque
+/// string and restored with `ndarray_from_bytes`. `out_len` receives the
+/// buffer size; when it exceeds `len` nothing is written, so callers can
+/// query the size with a null buffer first.
+///
+/// # Arguments
+/// * `handle` / `meta` - Array or view to serialize
+/// * `buf` / `len` - Output buffer (may be null when `len` is 0)
+/// * `out_len` - Output: bytes needed for the serialized array
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_to_bytes(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    buf: *mut u8,
+    len: usize,
+    out_len: *mut usize,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_len.is_null() || (buf.is_null() && len > 0) {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("to_bytes", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+        let shape = meta.shape_slice();
+
+        let header = match encode_header(wrapper.dtype, shape) {
+            Ok(h) => h,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+        let needed = shape
+            .iter()
+            .try_fold(wrapper.dtype.item_size(), |acc, &d| acc.checked_mul(d))
+            .and_then(|n| n.checked_add(header.len()));
+        let Some(needed) = needed else {
+            set_last_error("Shape product overflow".to_string());
+            return ERR_SHAPE;
+        };
+
+        *out_len = needed;
+        if needed > len {
+            return SUCCESS;
+        }
+
+        let out = slice::from_raw_parts_mut(buf, needed);
+        out[..header.len()].copy_from_slice(&header);
+        if let Err((code, msg)) = write_elements(wrapper, meta, &mut &mut out[header.len()..]) {
+            set_last_error(msg);
+            return code;
+        }
+        SUCCESS
+    })
+}
+
+/// Restore an array from a buffer written by `ndarray_to_bytes`.
+///
+/// Buffers written on a host of the other byte order are swapped on load.
+/// The buffer length must match its header exactly.
+///
+/// # Arguments
+/// * `buf` / `len` - Serialized array
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_from_bytes(
+    buf: *const u8,
+    len: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if (buf.is_null() && len > 0)
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("from_bytes", {
+        let bytes: &[u8] = if len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(buf, len)
+        };
+        let header = match parse_header(bytes) {
+            Ok(h) => h,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+
+        let mut data = &bytes[header.len..];
+        let expected = header
+            .shape
+            .iter()
+            .try_fold(header.dtype.item_size(), |acc, &d| acc.checked_mul(d));
+        if expected != Some(data.len()) {
+            set_last_error(format!(
+                "Invalid array buffer: expected {} data bytes for shape {:?} and dtype {}, got {}",
+                expected.map_or_else(|| "too many".to_string(), |n| n.to_string()),
+                header.shape,
+                header.dtype.name(),
+                data.len()
+            ));
+            return ERR_DTYPE;
+        }
+
+        let result_wrapper =
+            match read_elements(&mut data, header.dtype, &header.shape, false, header.swap) {
+                Ok(w) => w,
+                Err((code, msg)) => {
+                    set_last_error(msg);
+                    return code;
+                }
+            };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_round_trip() {
+        let header = encode_header(DType::Float32, &[2, 0, 5]).unwrap();
+        assert_eq!(header.len(), header_len(3));
+
+        let parsed = parse_header(&header).unwrap();
+        assert_eq!(parsed.dtype, DType::Float32);
+        assert_eq!(parsed.shape, vec![2, 0, 5]);
+        assert!(!parsed.swap);
+        assert_eq!(parsed.len, header.len());
+    }
+
+    #[test]
+    fn foreign_byte_order_is_swapped() {
+        let mut header = encode_header(DType::Int32, &[4]).unwrap();
+        header[5] = if NATIVE_ORDER == b'<' { b'>' } else { b'<' };
+        assert!(parse_header(&header).unwrap().swap);
+
+        header[6] = DType::Uint8 as u8;
+        assert!(!parse_header(&header).unwrap().swap);
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        let header = encode_header(DType::Int64, &[3, 4]).unwrap();
+        assert!(parse_header(&header[..12]).is_err());
+        assert!(parse_header(b"NPY").is_err());
+    }
+}
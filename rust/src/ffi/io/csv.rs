@@ -0,0 +1,605 @@
+//! Delimited text (CSV) files.
+//!
+//! Fields may be quoted with `"`, in which case they can contain the
+//! delimiter, newlines and doubled quotes. Blank lines are skipped.
+//!
+//! The reader is not a full RFC 4180 parser. A quote only opens a quoted
+//! field at the start of a field (leading whitespace is dropped), and text
+//! after the closing quote is appended to the field. A quote in the middle
+//! of an unquoted field is kept as a literal character, but it still counts
+//! towards the quote parity that decides whether a record continues on the
+//! next line, so such files can be misread.
+
+use std::fmt::Debug;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::os::raw::c_char;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, ArrayViewD, IxDyn};
+use num_traits::{Float, NumCast};
+use parking_lot::RwLock;
+
+use super::read_c_str;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{
+    extract_view_bool, extract_view_f32, extract_view_f64, extract_view_half_bits,
+    extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8, extract_view_u16,
+    extract_view_u32, extract_view_u64, extract_view_u8, write_output_metadata,
+};
+use crate::types::dtype::DType;
+use crate::types::half::{bf16_to_f32, f16_to_f32, f32_to_bf16, f32_to_f16};
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+fn check_delimiter(delimiter: u8) -> Result<(), String> {
+    if !delimiter.is_ascii() || matches!(delimiter, b'"' | b'\n' | b'\r') {
+        return Err(format!(
+            "Invalid CSV delimiter {:?}: must be an ASCII character other than a quote or newline",
+            delimiter as char
+        ));
+    }
+    Ok(())
+}
+
+/// Check that an array of `dtype` with `ndim` dimensions can be written
+/// with `delimiter`, without looking at its data.
+fn validate_csv_target(dtype: DType, ndim: usize, delimiter: u8) -> Result<(), (i32, String)> {
+    check_delimiter(delimiter).map_err(|e| (ERR_VALUE, e))?;
+    if ndim > 2 {
+        return Err((
+            ERR_SHAPE,
+            format!("CSV output requires at most 2 dimensions, got {}", ndim),
+        ));
+    }
+    if dtype.is_complex() {
+        return Err((
+            ERR_DTYPE,
+            "CSV files do not support complex dtypes".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Record-at-a-time reader over delimited text.
+struct Records<R> {
+    reader: R,
+    delimiter: char,
+    /// Line number of the last line read (1-based).
+    line: usize,
+    fields: Vec<String>,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> Records<R> {
+    fn new(reader: R, delimiter: u8) -> Self {
+        Records {
+            reader,
+            delimiter: delimiter as char,
+            line: 0,
+            fields: Vec::new(),
+            buf: Vec::new(),
+        }
+    }
+
+    /// Read the next non-blank record into `fields`, returning the line it
+    /// starts on, or `None` at end of input.
+    fn next_record(&mut self) -> Result<Option<usize>, (i32, String)> {
+        loop {
+            self.buf.clear();
+            let start = self.line + 1;
+            // A quoted field may span lines; an odd number of quotes so far
+            // means the record continues on the next line.
+            loop {
+                let n = self
+                    .reader
+                    .read_until(b'\n', &mut self.buf)
+                    .map_err(|e| (ERR_GENERIC, format!("Failed to read CSV data: {}", e)))?;
+                if n == 0 {
+                    break;
+                }
+                self.line += 1;
+                if self.buf.iter().filter(|&&b| b == b'"').count() % 2 == 0 {
+                    break;
+                }
+            }
+            if self.buf.is_empty() {
+                return Ok(None);
+            }
+
+            let text = std::str::from_utf8(&self.buf)
+                .map_err(|e| (ERR_VALUE, format!("Invalid UTF-8 on line {}: {}", start, e)))?;
+            let text = text.trim_end_matches(['\n', '\r']);
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            self.fields.clear();
+            let mut field = String::new();
+            let mut quoted = false;
+            let mut chars = text.chars().peekable();
+            while let Some(c) = chars.next() {
+                if quoted {
+                    if c != '"' {
+                        field.push(c);
+                    } else if chars.peek() == Some(&'"') {
+                        field.push('"');
+                        chars.next();
+                    } else {
+                        quoted = false;
+                    }
+                } else if c == '"' && field.trim().is_empty() {
+                    field.clear();
+                    quoted = true;
+                } else if c == self.delimiter {
+                    self.fields.push(std::mem::take(&mut field));
+                } else {
+                    field.push(c);
+                }
+            }
+            if quoted {
+                return Err((
+                    ERR_VALUE,
+                    format!("Unterminated quoted field on line {}", start),
+                ));
+            }
+            self.fields.push(field);
+            return Ok(Some(start));
+        }
+    }
+}
+
+/// Parse every remaining record into a row-major `[rows, cols]` buffer.
+///
+/// Empty fields take `fill`; when there is no fill value for the dtype they
+/// are an error.
+fn collect_rows<T, R: BufRead>(
+    records: &mut Records<R>,
+    dtype: DType,
+    parse: impl Fn(&str) -> Option<T>,
+    fill: Option<T>,
+) -> Result<(Vec<T>, [usize; 2]), (i32, String)>
+where
+    T: Copy,
+{
+    let mut data = Vec::new();
+    let mut rows = 0;
+    let mut cols = 0;
+
+    while let Some(line) = records.next_record()? {
+        if rows == 0 {
+            cols = records.fields.len();
+        } else if records.fields.len() != cols {
+            return Err((
+                ERR_SHAPE,
+                format!(
+                    "Line {} has {} columns, expected {}",
+                    line,
+                    records.fields.len(),
+                    cols
+                ),
+            ));
+        }
+
+        for (col, field) in records.fields.iter().enumerate() {
+            let field = field.trim();
+            let value = if field.is_empty() {
+                fill.ok_or_else(|| {
+                    (
+                        ERR_VALUE,
+                        format!(
+                            "Missing value on line {}, column {} and no fill value for {}",
+                            line,
+                            col + 1,
+                            dtype.name()
+                        ),
+                    )
+                })?
+            } else {
+                parse(field).ok_or_else(|| {
+                    (
+                        ERR_VALUE,
+                        format!(
+                            "Cannot parse '{}' as {} on line {}, column {}",
+                            field,
+                            dtype.name(),
+                            line,
+                            col + 1
+                        ),
+                    )
+                })?
+            };
+            data.push(value);
+        }
+        rows += 1;
+    }
+
+    Ok((data, [rows, cols]))
+}
+
+fn parse_num<T: FromStr>(s: &str) -> Option<T> {
+    s.parse().ok()
+}
+
+fn parse_bool(s: &str) -> Option<u8> {
+    match s {
+        "1" => Some(1),
+        "0" => Some(0),
+        _ if s.eq_ignore_ascii_case("true") => Some(1),
+        _ if s.eq_ignore_ascii_case("false") => Some(0),
+        _ => None,
+    }
+}
+
+/// `fill` as an integer, if it is a whole number in range.
+fn int_fill<T: NumCast>(fill: f64) -> Option<T> {
+    if fill.fract() == 0.0 {
+        <T as NumCast>::from(fill)
+    } else {
+        None
+    }
+}
+
+/// Read a delimited text table as a 2-D array of `dtype`.
+///
+/// The first `skip_rows` records (e.g. a header) are discarded. Empty
+/// fields become `fill`, which must be representable in `dtype`; NaN is
+/// only representable in float dtypes.
+pub(crate) fn read_csv<R: BufRead>(
+    reader: R,
+    delimiter: u8,
+    skip_rows: usize,
+    dtype: DType,
+    fill: f64,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    check_delimiter(delimiter).map_err(|e| (ERR_VALUE, e))?;
+    let mut records = Records::new(reader, delimiter);
+    for _ in 0..skip_rows {
+        if records.next_record()?.is_none() {
+            break;
+        }
+    }
+
+    macro_rules! read_as {
+        ($variant:ident, $parse:expr, $fill:expr) => {{
+            let (data, shape) = collect_rows(&mut records, dtype, $parse, $fill)?;
+            let arr = ArrayD::from_shape_vec(IxDyn(&shape), data)
+                .expect("Shape mismatch should not happen");
+            ArrayData::$variant(Arc::new(RwLock::new(arr)))
+        }};
+    }
+
+    let data = match dtype {
+        DType::Int8 => read_as!(Int8, parse_num::<i8>, int_fill(fill)),
+        DType::Int16 => read_as!(Int16, parse_num::<i16>, int_fill(fill)),
+        DType::Int32 => read_as!(Int32, parse_num::<i32>, int_fill(fill)),
+        DType::Int64 => read_as!(Int64, parse_num::<i64>, int_fill(fill)),
+        DType::Uint8 => read_as!(Uint8, parse_num::<u8>, int_fill(fill)),
+        DType::Uint16 => read_as!(Uint16, parse_num::<u16>, int_fill(fill)),
+        DType::Uint32 => read_as!(Uint32, parse_num::<u32>, int_fill(fill)),
+        DType::Uint64 => read_as!(Uint64, parse_num::<u64>, int_fill(fill)),
+        DType::Float32 => read_as!(Float32, parse_num::<f32>, Some(fill as f32)),
+        DType::Float64 => read_as!(Float64, parse_num::<f64>, Some(fill)),
+        DType::Float16 => read_as!(
+            Float16,
+            |s| parse_num::<f32>(s).map(f32_to_f16),
+            Some(f32_to_f16(fill as f32))
+        ),
+        DType::BFloat16 => read_as!(
+            BFloat16,
+            |s| parse_num::<f32>(s).map(f32_to_bf16),
+            Some(f32_to_bf16(fill as f32))
+        ),
+        DType::Bool => read_as!(
+            Bool,
+            parse_bool,
+            (!fill.is_nan()).then_some((fill != 0.0) as u8)
+        ),
+        DType::Complex64 | DType::Complex128 => {
+            return Err((
+                ERR_DTYPE,
+                "CSV files do not support complex dtypes".to_string(),
+            ))
+        }
+    };
+
+    Ok(NDArrayWrapper { data, dtype })
+}
+
+fn write_float<W: Write, F: Float + Debug>(w: &mut W, v: F, nan_repr: &str) -> io::Result<()> {
+    if v.is_nan() {
+        w.write_all(nan_repr.as_bytes())
+    } else {
+        write!(w, "{:?}", v)
+    }
+}
+
+fn write_rows<T: Copy, W: Write>(
+    w: &mut W,
+    view: &ArrayViewD<'_, T>,
+    cols: usize,
+    delimiter: u8,
+    mut fmt: impl FnMut(&mut W, T) -> io::Result<()>,
+) -> io::Result<()> {
+    for (i, &v) in view.iter().enumerate() {
+        if i % cols != 0 {
+            w.write_all(&[delimiter])?;
+        }
+        fmt(w, v)?;
+        if (i + 1) % cols == 0 {
+            w.write_all(b"\n")?;
+        }
+    }
+    Ok(())
+}
+
+/// Write a 0-D, 1-D or 2-D array or view as delimited text.
+///
+/// Rows of a 2-D array become lines; 1-D arrays are written one element per
+/// line, like a single column. Floats use the shortest representation that
+/// reads back to the same value, with NaN written as `nan_repr`.
+pub(crate) unsafe fn write_csv<W: Write>(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    w: &mut W,
+    delimiter: u8,
+    header: Option<&str>,
+    nan_repr: &str,
+) -> Result<(), (i32, String)> {
+    let shape = meta.shape_slice();
+    validate_csv_target(wrapper.dtype, shape.len(), delimiter)?;
+    let cols = if shape.len() == 2 { shape[1] } else { 1 };
+
+    let io_error = |e: io::Error| (ERR_GENERIC, format!("Failed to write CSV data: {}", e));
+    if let Some(header) = header {
+        writeln!(w, "{}", header).map_err(io_error)?;
+    }
+    if cols == 0 {
+        return Ok(());
+    }
+
+    macro_rules! write_as {
+        ($extract:ident, $fmt:expr) => {{
+            let Some(view) = $extract(wrapper, meta) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to extract {} view", wrapper.dtype.name()),
+                ));
+            };
+            write_rows(w, &view, cols, delimiter, $fmt)
+        }};
+    }
+
+    let result = match wrapper.dtype {
+        DType::Int8 => write_as!(extract_view_i8, |w, v| write!(w, "{}", v)),
+        DType::Int16 => write_as!(extract_view_i16, |w, v| write!(w, "{}", v)),
+        DType::Int32 => write_as!(extract_view_i32, |w, v| write!(w, "{}", v)),
+        DType::Int64 => write_as!(extract_view_i64, |w, v| write!(w, "{}", v)),
+        DType::Uint8 => write_as!(extract_view_u8, |w, v| write!(w, "{}", v)),
+        DType::Uint16 => write_as!(extract_view_u16, |w, v| write!(w, "{}", v)),
+        DType::Uint32 => write_as!(extract_view_u32, |w, v| write!(w, "{}", v)),
+        DType::Uint64 => write_as!(extract_view_u64, |w, v| write!(w, "{}", v)),
+        DType::Bool => write_as!(extract_view_bool, |w, v| write!(w, "{}", v)),
+        DType::Float32 => write_as!(extract_view_f32, |w, v| write_float(w, v, nan_repr)),
+        DType::Float64 => write_as!(extract_view_f64, |w, v| write_float(w, v, nan_repr)),
+        DType::Float16 => write_as!(extract_view_half_bits, |w, v| {
+            write_float(w, f16_to_f32(v), nan_repr)
+        }),
+        DType::BFloat16 => write_as!(extract_view_half_bits, |w, v| {
+            write_float(w, bf16_to_f32(v), nan_repr)
+        }),
+        DType::Complex64 | DType::Complex128 => unreachable!(),
+    };
+    result.map_err(io_error)
+}
+
+/// Save an array or view to a CSV file.
+///
+/// Rows of a 2-D array become lines and a 1-D array is written as one
+/// column. Real and bool dtypes are supported. An existing file is replaced.
+///
+/// # Arguments
+/// * `path` - Nul-terminated UTF-8 file path
+/// * `delimiter` - ASCII field delimiter, e.g. `b','`
+/// * `header` - Optional nul-terminated line written before the data (may be null)
+/// * `nan_repr` - Optional nul-terminated text for NaN (null writes `nan`)
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_to_csv(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    path: *const c_char,
+    delimiter: u8,
+    header: *const c_char,
+    nan_repr: *const c_char,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || path.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("to_csv", {
+        let strings = (|| {
+            let path = read_c_str(path, "path")?;
+            let header = if header.is_null() {
+                None
+            } else {
+                Some(read_c_str(header, "header")?)
+            };
+            let nan_repr = if nan_repr.is_null() {
+                "nan".to_string()
+            } else {
+                read_c_str(nan_repr, "NaN representation")?
+            };
+            Ok::<_, String>((path, header, nan_repr))
+        })();
+        let (path, header, nan_repr) = match strings {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+        // Reject unsupported arrays before the file is touched.
+        let result = validate_csv_target(wrapper.dtype, meta.ndim, delimiter).and_then(|()| {
+            let file = File::create(&path)
+                .map_err(|e| (ERR_GENERIC, format!("Failed to create '{}': {}", path, e)))?;
+            let mut w = BufWriter::new(file);
+            write_csv(
+                wrapper,
+                meta,
+                &mut w,
+                delimiter,
+                header.as_deref(),
+                &nan_repr,
+            )?;
+            w.flush()
+                .map_err(|e| (ERR_GENERIC, format!("Failed to write '{}': {}", path, e)))
+        });
+        if let Err((code, msg)) = result {
+            set_last_error(msg);
+            return code;
+        }
+
+        SUCCESS
+    })
+}
+
+/// Load a CSV file as a 2-D `[rows, columns]` array.
+///
+/// Every record must have the same number of fields. Float dtypes accept
+/// `nan` and `inf`; bools accept `true`/`false`/`1`/`0`. Complex dtypes are
+/// not supported.
+///
+/// # Arguments
+/// * `path` - Nul-terminated UTF-8 file path
+/// * `delimiter` - ASCII field delimiter, e.g. `b','`
+/// * `skip_rows` - Number of leading records to skip, e.g. 1 for a header
+/// * `dtype` - Output dtype
+/// * `fill_value` - Value for empty fields (NaN: only allowed for float dtypes)
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_from_csv(
+    path: *const c_char,
+    delimiter: u8,
+    skip_rows: usize,
+    dtype: u8,
+    fill_value: f64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if path.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("from_csv", {
+        let Some(dtype_enum) = DType::from_u8(dtype) else {
+            return ERR_DTYPE;
+        };
+        let path = match read_c_str(path, "path") {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let result = File::open(&path)
+            .map_err(|e| (ERR_GENERIC, format!("Failed to open '{}': {}", path, e)))
+            .and_then(|f| {
+                read_csv(
+                    BufReader::new(f),
+                    delimiter,
+                    skip_rows,
+                    dtype_enum,
+                    fill_value,
+                )
+            });
+        let result_wrapper = match result {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(format!("{} in '{}'", msg, path));
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(text: &str, dtype: DType, fill: f64) -> Result<NDArrayWrapper, (i32, String)> {
+        read_csv(text.as_bytes(), b',', 0, dtype, fill)
+    }
+
+    #[test]
+    fn reads_quoted_fields_and_fills_missing() {
+        let text = "\"1\",2\n\n3, \n\"4\",\"5\"\r\n";
+        let w = read(text, DType::Float64, -1.0).unwrap();
+        let ArrayData::Float64(arr) = &w.data else {
+            panic!("expected float64");
+        };
+        let arr = arr.read();
+        assert_eq!(arr.shape(), &[3, 2]);
+        assert_eq!(
+            arr.iter().copied().collect::<Vec<_>>(),
+            vec![1.0, 2.0, 3.0, -1.0, 4.0, 5.0]
+        );
+    }
+
+    #[test]
+    fn quoted_field_spans_lines() {
+        let mut records = Records::new("\"a\nb\",\"say \"\"hi\"\"\"\nc,d\n".as_bytes(), b',');
+        assert_eq!(records.next_record().unwrap(), Some(1));
+        assert_eq!(records.fields, vec!["a\nb", "say \"hi\""]);
+        assert_eq!(records.next_record().unwrap(), Some(3));
+        assert_eq!(records.fields, vec!["c", "d"]);
+        assert_eq!(records.next_record().unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_unfilled_integers() {
+        assert_eq!(
+            read("1,2\n3\n", DType::Int32, 0.0).err().unwrap().0,
+            ERR_SHAPE
+        );
+        assert_eq!(
+            read("1,\n", DType::Int32, f64::NAN).err().unwrap().0,
+            ERR_VALUE
+        );
+        assert!(read("1,\n", DType::Int32, 0.0).is_ok());
+    }
+
+    #[test]
+    fn validates_targets_before_writing() {
+        let code = |dtype, ndim, delimiter| {
+            validate_csv_target(dtype, ndim, delimiter)
+                .err()
+                .map(|e| e.0)
+        };
+        assert_eq!(code(DType::Float64, 2, b';'), None);
+        assert_eq!(code(DType::Float64, 3, b','), Some(ERR_SHAPE));
+        assert_eq!(code(DType::Complex64, 1, b','), Some(ERR_DTYPE));
+        assert_eq!(code(DType::Int32, 1, b'"'), Some(ERR_VALUE));
+    }
+}
@@ -0,0 +1,199 @@
+//! File and byte-stream serialization FFI functions.
+//!
+//! Formats share the helpers below, which move element data between arrays
+//! and raw native-endian bytes in C order.
+
+pub mod arrow;
+pub mod bytes;
+pub mod csv;
+pub mod npy;
+pub mod npz;
+
+pub use arrow::*;
+pub use bytes::*;
+pub use csv::*;
+pub use npy::*;
+pub use npz::*;
+
+use std::ffi::CStr;
+use std::io::{self, Read, Write};
+use std::mem::{size_of, size_of_val};
+use std::os::raw::c_char;
+use std::slice;
+
+use ndarray::{ArrayD, ArrayViewD, IxDyn};
+use num_complex::{Complex32, Complex64};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, ERR_SHAPE};
+use crate::helpers::{
+    extract_view_bool, extract_view_c128, extract_view_c64, extract_view_f32, extract_view_f64,
+    extract_view_half_bits, extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8,
+    extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper};
+
+/// Elements buffered before writing a strided view.
+const STAGING_BYTES: usize = 64 * 1024;
+
+/// Bytes read per step, so a corrupt shape fails on a short read instead of
+/// allocating the whole claimed size up front.
+const READ_CHUNK_BYTES: usize = 1 << 20;
+
+/// Decode a nul-terminated UTF-8 string argument such as a path or name.
+pub(crate) unsafe fn read_c_str(ptr: *const c_char, what: &str) -> Result<String, String> {
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(str::to_string)
+        .map_err(|e| format!("Invalid UTF-8 in {}: {}", what, e))
+}
+
+fn write_view<T: Copy, W: Write>(w: &mut W, view: &ArrayViewD<'_, T>) -> io::Result<()> {
+    if let Some(data) = view.as_slice() {
+        let bytes = unsafe { slice::from_raw_parts(data.as_ptr() as *const u8, size_of_val(data)) };
+        return w.write_all(bytes);
+    }
+
+    let item = size_of::<T>();
+    let mut staging = Vec::with_capacity(STAGING_BYTES);
+    for value in view.iter() {
+        let bytes = unsafe { slice::from_raw_parts(value as *const T as *const u8, item) };
+        staging.extend_from_slice(bytes);
+        if staging.len() >= STAGING_BYTES {
+            w.write_all(&staging)?;
+            staging.clear();
+        }
+    }
+    w.write_all(&staging)
+}
+
+/// Write the elements of a view to `w` as native-endian bytes in C order.
+///
+/// Half-precision arrays are written as their 16-bit patterns and bools as
+/// one byte each.
+pub(crate) unsafe fn write_elements<W: Write>(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    w: &mut W,
+) -> Result<(), (i32, String)> {
+    macro_rules! write_as {
+        ($extract:ident) => {{
+            let Some(view) = $extract(wrapper, meta) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to extract {} view", wrapper.dtype.name()),
+                ));
+            };
+            write_view(w, &view)
+        }};
+    }
+
+    let result = match wrapper.dtype {
+        DType::Int8 => write_as!(extract_view_i8),
+        DType::Int16 => write_as!(extract_view_i16),
+        DType::Int32 => write_as!(extract_view_i32),
+        DType::Int64 => write_as!(extract_view_i64),
+        DType::Uint8 => write_as!(extract_view_u8),
+        DType::Uint16 => write_as!(extract_view_u16),
+        DType::Uint32 => write_as!(extract_view_u32),
+        DType::Uint64 => write_as!(extract_view_u64),
+        DType::Float32 => write_as!(extract_view_f32),
+        DType::Float64 => write_as!(extract_view_f64),
+        DType::Float16 | DType::BFloat16 => write_as!(extract_view_half_bits),
+        DType::Bool => write_as!(extract_view_bool),
+        DType::Complex64 => write_as!(extract_view_c64),
+        DType::Complex128 => write_as!(extract_view_c128),
+    };
+    result.map_err(|e| (ERR_GENERIC, format!("Failed to write array data: {}", e)))
+}
+
+/// Read `count` elements, reversing every `swap_width`-byte group when the
+/// source byte order differs from the host (0 leaves bytes untouched).
+fn read_vec<T: Copy + Default, R: Read>(
+    r: &mut R,
+    count: usize,
+    swap_width: usize,
+) -> io::Result<Vec<T>> {
+    let item = size_of::<T>();
+    let chunk = (READ_CHUNK_BYTES / item).max(1);
+    let mut out: Vec<T> = Vec::new();
+    while out.len() < count {
+        let start = out.len();
+        out.resize(start + chunk.min(count - start), T::default());
+        let tail = &mut out[start..];
+        // All element types are plain integers or floats, so any bytes are valid.
+        let bytes =
+            unsafe { slice::from_raw_parts_mut(tail.as_mut_ptr() as *mut u8, size_of_val(tail)) };
+        r.read_exact(bytes)?;
+        if swap_width > 1 {
+            bytes
+                .chunks_exact_mut(swap_width)
+                .for_each(|group| group.reverse());
+        }
+    }
+    Ok(out)
+}
+
+/// Read an array of `dtype` and `shape` from `r`.
+///
+/// The data is laid out in C order, or in Fortran order when `fortran` is
+/// set; either way the result is a C-contiguous array. `swap` marks data in
+/// the opposite byte order to the host. Nonzero bool bytes become `1`.
+pub(crate) fn read_elements<R: Read>(
+    r: &mut R,
+    dtype: DType,
+    shape: &[usize],
+    fortran: bool,
+    swap: bool,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let count = shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| (ERR_SHAPE, "Shape product overflow".to_string()))?;
+    let dims: Vec<usize> = if fortran {
+        shape.iter().rev().copied().collect()
+    } else {
+        shape.to_vec()
+    };
+
+    macro_rules! read_as {
+        ($t:ty, $width:expr) => {{
+            let data = read_vec::<$t, R>(r, count, if swap { $width } else { 0 })
+                .map_err(|e| (ERR_GENERIC, format!("Failed to read array data: {}", e)))?;
+            let arr = ArrayD::from_shape_vec(IxDyn(&dims), data)
+                .expect("Shape mismatch should not happen");
+            let arr: ArrayD<$t> = if fortran {
+                arr.reversed_axes().as_standard_layout().into_owned()
+            } else {
+                arr
+            };
+            Arc::new(RwLock::new(arr))
+        }};
+    }
+
+    let data = match dtype {
+        DType::Int8 => ArrayData::Int8(read_as!(i8, 1)),
+        DType::Int16 => ArrayData::Int16(read_as!(i16, 2)),
+        DType::Int32 => ArrayData::Int32(read_as!(i32, 4)),
+        DType::Int64 => ArrayData::Int64(read_as!(i64, 8)),
+        DType::Uint8 => ArrayData::Uint8(read_as!(u8, 1)),
+        DType::Uint16 => ArrayData::Uint16(read_as!(u16, 2)),
+        DType::Uint32 => ArrayData::Uint32(read_as!(u32, 4)),
+        DType::Uint64 => ArrayData::Uint64(read_as!(u64, 8)),
+        DType::Float32 => ArrayData::Float32(read_as!(f32, 4)),
+        DType::Float64 => ArrayData::Float64(read_as!(f64, 8)),
+        DType::Float16 => ArrayData::Float16(read_as!(u16, 2)),
+        DType::BFloat16 => ArrayData::BFloat16(read_as!(u16, 2)),
+        DType::Complex64 => ArrayData::Complex64(read_as!(Complex32, 4)),
+        DType::Complex128 => ArrayData::Complex128(read_as!(Complex64, 8)),
+        DType::Bool => {
+            let arr = read_as!(u8, 1);
+            arr.write().mapv_inplace(|b| (b != 0) as u8);
+            ArrayData::Bool(arr)
+        }
+    };
+
+    Ok(NDArrayWrapper { data, dtype })
+}
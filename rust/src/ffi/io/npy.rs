@@ -0,0 +1,473 @@
+//! NumPy `.npy` files.
+//!
+//! A file is the magic string, a format version, a little-endian header
+//! length and a Python dict literal describing dtype, order and shape,
+//! padded so the data that follows starts on a 64-byte boundary.
+
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::os::raw::c_char;
+
+use super::{read_c_str, read_elements, write_elements};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_NOT_IMPLEMENTED, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// Alignment of the data section, counted from the start of the file.
+const HEADER_ALIGN: usize = 64;
+
+/// Byte order character NumPy uses for this host.
+const NATIVE_ORDER: char = if cfg!(target_endian = "little") {
+    '<'
+} else {
+    '>'
+};
+
+/// Decoded `.npy` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NpyHeader {
+    pub(crate) dtype: DType,
+    pub(crate) shape: Vec<usize>,
+    pub(crate) fortran_order: bool,
+    /// Data is stored in the opposite byte order to the host.
+    pub(crate) swap: bool,
+}
+
+/// NumPy type string for `dtype` in native byte order.
+fn descr(dtype: DType) -> Result<String, (i32, String)> {
+    let code = match dtype {
+        DType::Int8 => return Ok("|i1".to_string()),
+        DType::Uint8 => return Ok("|u1".to_string()),
+        DType::Bool => return Ok("|b1".to_string()),
+        DType::Int16 => "i2",
+        DType::Int32 => "i4",
+        DType::Int64 => "i8",
+        DType::Uint16 => "u2",
+        DType::Uint32 => "u4",
+        DType::Uint64 => "u8",
+        DType::Float16 => "f2",
+        DType::Float32 => "f4",
+        DType::Float64 => "f8",
+        DType::Complex64 => "c8",
+        DType::Complex128 => "c16",
+        DType::BFloat16 => {
+            return Err((
+                ERR_DTYPE,
+                "BFloat16 has no .npy type; convert to Float32 before saving".to_string(),
+            ))
+        }
+    };
+    Ok(format!("{}{}", NATIVE_ORDER, code))
+}
+
+/// Parse a NumPy type string into a dtype and whether its bytes need swapping.
+fn parse_descr(descr: &str) -> Result<(DType, bool), (i32, String)> {
+    let (order, code) = match descr.chars().next() {
+        Some(c @ ('<' | '>' | '|' | '=')) => (c, &descr[1..]),
+        _ => ('=', descr),
+    };
+    let dtype = match code {
+        "b1" | "?" => DType::Bool,
+        "i1" => DType::Int8,
+        "i2" => DType::Int16,
+        "i4" => DType::Int32,
+        "i8" => DType::Int64,
+        "u1" => DType::Uint8,
+        "u2" => DType::Uint16,
+        "u4" => DType::Uint32,
+        "u8" => DType::Uint64,
+        "f2" => DType::Float16,
+        "f4" => DType::Float32,
+        "f8" => DType::Float64,
+        "c8" => DType::Complex64,
+        "c16" => DType::Complex128,
+        _ => return Err((ERR_DTYPE, format!("Unsupported .npy dtype '{}'", descr))),
+    };
+    let swap = matches!(order, '<' | '>') && order != NATIVE_ORDER && dtype.item_size() > 1;
+    Ok((dtype, swap))
+}
+
+/// Encode the full header, magic string included, for an array of `dtype`
+/// and `shape` stored in C order.
+///
+/// Version 1.0 is used unless the header outgrows its 16-bit length field.
+pub(crate) fn encode_header(dtype: DType, shape: &[usize]) -> Result<Vec<u8>, (i32, String)> {
+    let dims = match shape {
+        [d] => format!("({},)", d),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let dict = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr(dtype)?,
+        dims
+    );
+
+    let padded_len =
+        |prefix: usize| (prefix + dict.len() + 1).div_ceil(HEADER_ALIGN) * HEADER_ALIGN - prefix;
+    let (version, prefix) = if padded_len(MAGIC.len() + 4) <= u16::MAX as usize {
+        (1u8, MAGIC.len() + 4)
+    } else {
+        (2u8, MAGIC.len() + 6)
+    };
+    let padded = padded_len(prefix);
+
+    let mut out = Vec::with_capacity(prefix + padded);
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        out.extend_from_slice(&(padded as u16).to_le_bytes());
+    } else {
+        out.extend_from_slice(&(padded as u32).to_le_bytes());
+    }
+    out.extend_from_slice(dict.as_bytes());
+    out.resize(prefix + padded - 1, b' ');
+    out.push(b'\n');
+    Ok(out)
+}
+
+fn header_error(msg: &str) -> (i32, String) {
+    (ERR_VALUE, format!("Invalid .npy header: {}", msg))
+}
+
+/// Split a quoted Python string literal off the front of `s`.
+fn take_quoted(s: &str) -> Result<(&str, &str), (i32, String)> {
+    let quote = s
+        .chars()
+        .next()
+        .filter(|&c| matches!(c, '\'' | '"'))
+        .ok_or_else(|| header_error("expected a quoted string"))?;
+    let end = s[1..]
+        .find(quote)
+        .ok_or_else(|| header_error("unterminated string"))?;
+    Ok((&s[1..end + 1], &s[end + 2..]))
+}
+
+/// Parse the header dict, e.g. `{'descr': '<f8', 'fortran_order': False, 'shape': (3,), }`.
+pub(crate) fn parse_header(text: &str) -> Result<NpyHeader, (i32, String)> {
+    let body = text
+        .trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(|| header_error("expected a dict literal"))?;
+
+    let mut descr = None;
+    let mut fortran_order = None;
+    let mut shape = None;
+
+    let mut rest = body.trim_start();
+    while !rest.is_empty() {
+        let (key, after) = take_quoted(rest)?;
+        let after = after
+            .trim_start()
+            .strip_prefix(':')
+            .ok_or_else(|| header_error("expected ':' after key"))?
+            .trim_start();
+
+        rest = match key {
+            "descr" => {
+                if after.starts_with('[') {
+                    return Err((
+                        ERR_DTYPE,
+                        "Structured .npy dtypes are not supported".to_string(),
+                    ));
+                }
+                let (value, after) = take_quoted(after)?;
+                descr = Some(value);
+                after
+            }
+            "fortran_order" => {
+                if let Some(after) = after.strip_prefix("True") {
+                    fortran_order = Some(true);
+                    after
+                } else if let Some(after) = after.strip_prefix("False") {
+                    fortran_order = Some(false);
+                    after
+                } else {
+                    return Err(header_error("fortran_order must be True or False"));
+                }
+            }
+            "shape" => {
+                let inner = after
+                    .strip_prefix('(')
+                    .ok_or_else(|| header_error("shape must be a tuple"))?;
+                let end = inner
+                    .find(')')
+                    .ok_or_else(|| header_error("unterminated shape tuple"))?;
+                let dims = inner[..end]
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|d| !d.is_empty())
+                    .map(|d| {
+                        d.trim_end_matches('L')
+                            .parse::<usize>()
+                            .map_err(|_| header_error(&format!("invalid dimension '{}'", d)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                shape = Some(dims);
+                &inner[end + 1..]
+            }
+            other => return Err(header_error(&format!("unexpected key '{}'", other))),
+        };
+
+        rest = rest.trim_start();
+        rest = rest.strip_prefix(',').unwrap_or(rest).trim_start();
+    }
+
+    let (Some(descr), Some(fortran_order), Some(shape)) = (descr, fortran_order, shape) else {
+        return Err(header_error("missing descr, fortran_order or shape"));
+    };
+    let (dtype, swap) = parse_descr(descr)?;
+    Ok(NpyHeader {
+        dtype,
+        shape,
+        fortran_order,
+        swap,
+    })
+}
+
+/// Read and parse the header of a `.npy` stream, leaving `r` at the data.
+pub(crate) fn read_header<R: Read>(r: &mut R) -> Result<NpyHeader, (i32, String)> {
+    let io_error = |e: std::io::Error| (ERR_GENERIC, format!("Failed to read .npy header: {}", e));
+
+    let mut preamble = [0u8; 8];
+    r.read_exact(&mut preamble).map_err(io_error)?;
+    if &preamble[..6] != MAGIC {
+        return Err((ERR_VALUE, "Not a .npy file (bad magic string)".to_string()));
+    }
+
+    let header_len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            r.read_exact(&mut len).map_err(io_error)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            r.read_exact(&mut len).map_err(io_error)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => {
+            return Err((
+                ERR_NOT_IMPLEMENTED,
+                format!("Unsupported .npy format version {}.{}", major, preamble[7]),
+            ))
+        }
+    };
+
+    let mut header = vec![0u8; header_len];
+    r.read_exact(&mut header).map_err(io_error)?;
+    let text = std::str::from_utf8(&header).map_err(|_| header_error("not valid text"))?;
+    parse_header(text)
+}
+
+/// Write a view as a complete `.npy` stream in C order.
+pub(crate) unsafe fn write_npy<W: Write>(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    w: &mut W,
+) -> Result<(), (i32, String)> {
+    let header = encode_header(wrapper.dtype, meta.shape_slice())?;
+    w.write_all(&header)
+        .map_err(|e| (ERR_GENERIC, format!("Failed to write .npy header: {}", e)))?;
+    write_elements(wrapper, meta, w)
+}
+
+/// Read a complete `.npy` stream into a new C-contiguous array.
+pub(crate) fn read_npy<R: Read>(r: &mut R) -> Result<NDArrayWrapper, (i32, String)> {
+    let header = read_header(r)?;
+    read_elements(
+        r,
+        header.dtype,
+        &header.shape,
+        header.fortran_order,
+        header.swap,
+    )
+}
+
+/// Save an array or view to a NumPy `.npy` file.
+///
+/// Data is written in C order and native byte order, with a version 1.0
+/// header (2.0 when the header does not fit). Every dtype except BFloat16,
+/// which NumPy has no type for, can be saved. An existing file is replaced.
+///
+/// # Arguments
+/// * `handle` / `meta` - Array and view to save
+/// * `path` - Nul-terminated UTF-8 file path
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_save_npy(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    path: *const c_char,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || path.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("save_npy", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+        let path = match read_c_str(path, "path") {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        // Reject unsupported dtypes before the file is touched.
+        if let Err((code, msg)) = encode_header(wrapper.dtype, meta.shape_slice()) {
+            set_last_error(msg);
+            return code;
+        }
+
+        let file = match File::create(&path) {
+            Ok(f) => f,
+            Err(e) => {
+                set_last_error(format!("Failed to create '{}': {}", path, e));
+                return ERR_GENERIC;
+            }
+        };
+        let mut writer = BufWriter::new(file);
+        let result = write_npy(wrapper, meta, &mut writer).and_then(|()| {
+            writer
+                .flush()
+                .map_err(|e| (ERR_GENERIC, format!("Failed to write '{}': {}", path, e)))
+        });
+        if let Err((code, msg)) = result {
+            set_last_error(msg);
+            return code;
+        }
+
+        SUCCESS
+    })
+}
+
+/// Load a NumPy `.npy` file into a new array.
+///
+/// Accepts format versions 1.0 to 3.0, either byte order and Fortran-ordered
+/// data; the result is always native-endian and C-contiguous. Structured
+/// and object dtypes are rejected.
+///
+/// # Arguments
+/// * `path` - Nul-terminated UTF-8 file path
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_load_npy(
+    path: *const c_char,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if path.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("load_npy", {
+        let path = match read_c_str(path, "path") {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let file = match File::open(&path) {
+            Ok(f) => f,
+            Err(e) => {
+                set_last_error(format!("Failed to open '{}': {}", path, e));
+                return ERR_GENERIC;
+            }
+        };
+        let result_wrapper = match read_npy(&mut BufReader::new(file)) {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(format!("{} ('{}')", msg, path));
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_is_aligned_and_round_trips() {
+        for shape in [vec![], vec![7], vec![2, 3, 4]] {
+            let bytes = encode_header(DType::Float64, &shape).unwrap();
+            assert_eq!(bytes.len() % HEADER_ALIGN, 0);
+            assert_eq!(*bytes.last().unwrap(), b'\n');
+
+            let header = read_header(&mut &bytes[..]).unwrap();
+            assert_eq!(header.dtype, DType::Float64);
+            assert_eq!(header.shape, shape);
+            assert!(!header.fortran_order && !header.swap);
+        }
+    }
+
+    #[test]
+    fn parses_numpy_headers() {
+        let header =
+            parse_header("{'descr': '>i4', 'fortran_order': True, 'shape': (3, 2), }   \n")
+                .unwrap();
+        assert_eq!(header.dtype, DType::Int32);
+        assert_eq!(header.shape, vec![3, 2]);
+        assert!(header.fortran_order);
+        assert_eq!(header.swap, cfg!(target_endian = "little"));
+
+        let header =
+            parse_header("{\"shape\": (), \"fortran_order\": False, \"descr\": \"|b1\"}").unwrap();
+        assert_eq!(header.dtype, DType::Bool);
+        assert!(header.shape.is_empty());
+
+        let err = parse_header("{'descr': [('a', '<f4')], 'fortran_order': False, 'shape': (1,)}")
+            .unwrap_err();
+        assert_eq!(err.0, ERR_DTYPE);
+    }
+
+    #[test]
+    fn fortran_order_data_is_transposed() {
+        let data: Vec<u8> = [1i32, 4, 2, 5, 3, 6]
+            .iter()
+            .flat_map(|v| v.to_ne_bytes())
+            .collect();
+        let wrapper = read_elements(&mut &data[..], DType::Int32, &[2, 3], true, false).unwrap();
+        let crate::types::ArrayData::Int32(arr) = &wrapper.data else {
+            panic!("expected Int32 data");
+        };
+        let arr = arr.read();
+        assert!(arr.is_standard_layout());
+        assert_eq!(
+            arr.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! NumPy `.npz` archives: named `.npy` arrays in a ZIP container.
+
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Seek, Write};
+use std::os::raw::c_char;
+use std::ptr;
+
+use zip::result::ZipResult;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::npy::{encode_header, read_npy, write_npy};
+use super::read_c_str;
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_INDEX, ERR_VALUE, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Array name of an archive entry, i.e. the entry name without `.npy`.
+fn array_name(entry: &str) -> &str {
+    entry.strip_suffix(".npy").unwrap_or(entry)
+}
+
+fn open_archive(path: &str) -> Result<ZipArchive<BufReader<File>>, String> {
+    let file = File::open(path).map_err(|e| format!("Failed to open '{}': {}", path, e))?;
+    ZipArchive::new(BufReader::new(file))
+        .map_err(|e| format!("Failed to read '{}' as .npz: {}", path, e))
+}
+
+/// Entry names in archive order.
+fn entry_names<R: Read + Seek>(archive: &ZipArchive<R>) -> impl Iterator<Item = &str> {
+    (0..archive.len()).filter_map(|i| archive.name_for_index(i))
+}
+
+/// Append `data` as the entry `name`, deflated when `compressed`.
+fn add_entry<W: Write + Seek>(
+    zip: &mut ZipWriter<W>,
+    name: &str,
+    data: &[u8],
+    compressed: bool,
+) -> ZipResult<()> {
+    let method = if compressed {
+        CompressionMethod::Deflated
+    } else {
+        CompressionMethod::Stored
+    };
+    let options = SimpleFileOptions::default()
+        .compression_method(method)
+        .large_file(data.len() as u64 >= u32::MAX as u64);
+    zip.start_file(name, options)?;
+    zip.write_all(data)?;
+    Ok(())
+}
+
+/// Decode entry `index` as a `.npy` array.
+///
+/// The entry is read to its end so the CRC check runs even when the array
+/// data stops short of it.
+fn read_entry<R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    index: usize,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let mut entry = archive
+        .by_index(index)
+        .map_err(|e| (ERR_GENERIC, e.to_string()))?;
+    let wrapper = read_npy(&mut entry)?;
+    io::copy(&mut entry, &mut io::sink()).map_err(|e| (ERR_GENERIC, e.to_string()))?;
+    Ok(wrapper)
+}
+
+/// Save arrays to a NumPy `.npz` archive.
+///
+/// Array `i` is stored as the entry `names[i] + ".npy"`, in the same format
+/// as `ndarray_save_npy`. With `compressed` the entries are deflated (like
+/// `numpy.savez_compressed`), otherwise they are stored uncompressed. Names
+/// must be unique. An existing file is replaced.
+///
+/// # Arguments
+/// * `path` - Nul-terminated UTF-8 file path
+/// * `names` - `num_arrays` nul-terminated UTF-8 array names
+/// * `handles` / `metas` - `num_arrays` arrays and views to save
+/// * `compressed` - Whether to deflate the entries
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_save_npz(
+    path: *const c_char,
+    names: *const *const c_char,
+    handles: *const *const NdArrayHandle,
+    metas: *const *const ArrayMetadata,
+    num_arrays: usize,
+    compressed: bool,
+) -> i32 {
+    if path.is_null()
+        || (num_arrays > 0 && (names.is_null() || handles.is_null() || metas.is_null()))
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("save_npz", {
+        let path = match read_c_str(path, "path") {
+            Ok(p) => p,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let mut arrays = Vec::with_capacity(num_arrays);
+        let mut seen = HashSet::with_capacity(num_arrays);
+        for i in 0..num_arrays {
+            let (name_ptr, handle, meta) = (*names.add(i), *handles.add(i), *metas.add(i));
+            if name_ptr.is_null() || handle.is_null() || meta.is_null() {
+                return ERR_GENERIC;
+            }
+            let name = match read_c_str(name_ptr, "array name") {
+                Ok(n) => n,
+                Err(e) => {
+                    set_last_error(e);
+                    return ERR_VALUE;
+                }
+            };
+            if !seen.insert(name.clone()) {
+                set_last_error(format!("Duplicate array name '{}' in .npz archive", name));
+                return ERR_VALUE;
+            }
+
+            let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+            let meta = &*meta;
+            // Reject unsupported dtypes before the file is touched.
+            if let Err((code, msg)) = encode_header(wrapper.dtype, meta.shape_slice()) {
+                set_last_error(format!("{} (array '{}')", msg, name));
+                return code;
+            }
+            arrays.push((name, wrapper, meta));
+        }
+
+        let file = match File::create(&path) {
+            Ok(f) => f,
+            Err(e) => {
+                set_last_error(format!("Failed to create '{}': {}", path, e));
+                return ERR_GENERIC;
+            }
+        };
+        let mut zip = ZipWriter::new(BufWriter::new(file));
+        for (name, wrapper, meta) in arrays {
+            let mut npy = Vec::new();
+            if let Err((code, msg)) = write_npy(wrapper, meta, &mut npy) {
+                set_last_error(msg);
+                return code;
+            }
+            if let Err(e) = add_entry(&mut zip, &format!("{}.npy", name), &npy, compressed) {
+                set_last_error(format!("Failed to write '{}': {}", path, e));
+                return ERR_GENERIC;
+            }
+        }
+        if let Err(e) = zip
+            .finish()
+            .map_err(io::Error::from)
+            .and_then(|mut w| w.flush())
+        {
+            set_last_error(format!("Failed to write '{}': {}", path, e));
+            return ERR_GENERIC;
+        }
+
+        SUCCESS
+    })
+}
+
+/// List the array names stored in a `.npz` archive, in archive order.
+///
+/// Names are entry names without their `.npy` suffix, each followed by a NUL
+/// byte. `out_len` receives the total length in bytes; when it exceeds
+/// `len` nothing is written, so callers can retry with a larger buffer.
+///
+/// # Arguments
+/// * `path` - Nul-terminated UTF-8 file path
+/// * `buf` / `len` - Output buffer for the names (may be null when `len` is 0)
+/// * `out_len` - Output: bytes needed for all names
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_npz_names(
+    path: *const c_char,
+    buf: *mut c_char,
+    len: usize,
+    out_len: *mut usize,
+) -> i32 {
+    if path.is_null() || out_len.is_null() || (buf.is_null() && len > 0) {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("npz_names", {
+        let archive = match read_c_str(path, "path").and_then(|p| open_archive(&p)) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let mut bytes = Vec::new();
+        for entry in entry_names(&archive) {
+            bytes.extend_from_slice(array_name(entry).as_bytes());
+            bytes.push(0);
+        }
+
+        *out_len = bytes.len();
+        if !bytes.is_empty() && bytes.len() <= len {
+            ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, buf, bytes.len());
+        }
+        SUCCESS
+    })
+}
+
+/// Load one named array from a NumPy `.npz` archive.
+///
+/// `name` is matched against entry names with their `.npy` suffix removed,
+/// as listed by `ndarray_npz_names`. The entry is decoded like
+/// `ndarray_load_npy` and its CRC is verified.
+///
+/// # Arguments
+/// * `path` - Nul-terminated UTF-8 file path
+/// * `name` - Nul-terminated UTF-8 array name
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_load_npz(
+    path: *const c_char,
+    name: *const c_char,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if path.is_null()
+        || name.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("load_npz", {
+        let (path, name) = match (read_c_str(path, "path"), read_c_str(name, "array name")) {
+            (Ok(p), Ok(n)) => (p, n),
+            (Err(e), _) | (_, Err(e)) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        let mut archive = match open_archive(&path) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_GENERIC;
+            }
+        };
+
+        let Some(index) = entry_names(&archive).position(|entry| array_name(entry) == name) else {
+            set_last_error(format!("No array named '{}' in '{}'", name, path));
+            return ERR_INDEX;
+        };
+
+        let result_wrapper = match read_entry(&mut archive, index) {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(format!("{} (array '{}' in '{}')", msg, name, path));
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::dtype::DType;
+    use std::io::Cursor;
+
+    fn int32_npy(values: &[i32]) -> Vec<u8> {
+        let mut npy = encode_header(DType::Int32, &[values.len()]).unwrap();
+        for v in values {
+            npy.extend_from_slice(&v.to_le_bytes());
+        }
+        npy
+    }
+
+    #[test]
+    fn round_trips_stored_and_deflated_entries() {
+        let npy = int32_npy(&(0..5000).map(|i| i % 7).collect::<Vec<_>>());
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        add_entry(&mut zip, "a.npy", &npy, false).unwrap();
+        add_entry(&mut zip, "b.npy", &npy, true).unwrap();
+        let bytes = zip.finish().unwrap().into_inner();
+        assert!(bytes.len() < 2 * npy.len());
+
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+        let names: Vec<&str> = entry_names(&archive).map(array_name).collect();
+        assert_eq!(names, vec!["a", "b"]);
+        for index in 0..2 {
+            let wrapper = read_entry(&mut archive, index).unwrap();
+            assert_eq!(wrapper.dtype, DType::Int32);
+            assert_eq!(wrapper.len(), 5000);
+        }
+    }
+
+    #[test]
+    fn detects_corrupt_data() {
+        let npy = int32_npy(&[1, 2, 3]);
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        add_entry(&mut zip, "x.npy", &npy, false).unwrap();
+        let mut bytes = zip.finish().unwrap().into_inner();
+        let at = bytes
+            .windows(npy.len())
+            .position(|w| w == npy.as_slice())
+            .unwrap();
+        bytes[at + npy.len() - 1] ^= 0xFF;
+
+        let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+        assert!(read_entry(&mut archive, 0).is_err());
+    }
+}
@@ -34,7 +34,7 @@ pub unsafe extern "C" fn ndarray_cholesky(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("cholesky", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -55,7 +55,7 @@ pub unsafe extern "C" fn ndarray_cholesky(
             DType::Float64 => {
                 let Some(a_arr_dyn) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for Cholesky".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -79,7 +79,7 @@ pub unsafe extern "C" fn ndarray_cholesky(
             DType::Float32 => {
                 let Some(a_arr_dyn) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for Cholesky".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -103,7 +103,7 @@ pub unsafe extern "C" fn ndarray_cholesky(
             DType::Complex64 => {
                 let Some(a_arr_dyn) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for Cholesky".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -127,7 +127,7 @@ pub unsafe extern "C" fn ndarray_cholesky(
             DType::Complex128 => {
                 let Some(a_arr_dyn) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for Cholesky".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -24,7 +24,7 @@ pub unsafe extern "C" fn ndarray_cond(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("cond", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -37,7 +37,7 @@ pub unsafe extern "C" fn ndarray_cond(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for cond".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr_2d = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -65,7 +65,7 @@ pub unsafe extern "C" fn ndarray_cond(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for cond".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr_2d = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -93,7 +93,7 @@ pub unsafe extern "C" fn ndarray_cond(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for cond".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr_2d = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -121,7 +121,7 @@ pub unsafe extern "C" fn ndarray_cond(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for cond".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr_2d = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -0,0 +1,187 @@
+//! Cross product of 3-vectors.
+
+use std::ops::{Mul, Sub};
+use std::sync::Arc;
+
+use ndarray::{ArrayD, Axis, IxDyn, Zip};
+use num_traits::Zero;
+use parking_lot::RwLock;
+
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    broadcast_shape, extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
+    extract_array_as_f64, extract_array_as_i16, extract_array_as_i32, extract_array_as_i64,
+    extract_array_as_i8, extract_array_as_u16, extract_array_as_u32, extract_array_as_u64,
+    extract_array_as_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Cross product of the 3-vectors along the last axis of `a` and `b`.
+///
+/// Both operands must have a last axis of length 3; the leading axes are
+/// broadcast against each other.
+pub(crate) fn cross_arrays<T>(a: &ArrayD<T>, b: &ArrayD<T>) -> Result<ArrayD<T>, String>
+where
+    T: Copy + Zero + Mul<Output = T> + Sub<Output = T>,
+{
+    if a.shape().last() != Some(&3) || b.shape().last() != Some(&3) {
+        return Err(format!(
+            "cross requires a last axis of length 3, got shapes {:?} and {:?}",
+            a.shape(),
+            b.shape()
+        ));
+    }
+    let shape = broadcast_shape(a.shape(), b.shape()).ok_or_else(|| {
+        format!(
+            "Cannot broadcast shapes {:?} and {:?}",
+            a.shape(),
+            b.shape()
+        )
+    })?;
+    let (Some(a_bc), Some(b_bc)) = (a.broadcast(shape.as_slice()), b.broadcast(shape.as_slice()))
+    else {
+        return Err("Failed to broadcast cross operands".to_string());
+    };
+
+    let last = Axis(shape.len() - 1);
+    let mut out = ArrayD::zeros(IxDyn(&shape));
+    Zip::from(out.lanes_mut(last))
+        .and(a_bc.lanes(last))
+        .and(b_bc.lanes(last))
+        .for_each(|mut o, u, v| {
+            o[0] = u[1] * v[2] - u[2] * v[1];
+            o[1] = u[2] * v[0] - u[0] * v[2];
+            o[2] = u[0] * v[1] - u[1] * v[0];
+        });
+    Ok(out)
+}
+
+/// Compute the cross product of 3-vectors along the last axis.
+///
+/// Both operands must have a last axis of length 3; leading axes broadcast
+/// and dtypes are promoted as in `ndarray_mul`. Bool operands are rejected.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_cross(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || b.is_null()
+        || a_meta.is_null()
+        || b_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype_ptr.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("cross", {
+        let a_meta = &*a_meta;
+        let b_meta = &*b_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+
+        let out_dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+
+        macro_rules! cross_with {
+            ($extract_fn:ident) => {{
+                let (Some(a_arr), Some(b_arr)) = (
+                    $extract_fn(a_wrapper, a_meta),
+                    $extract_fn(b_wrapper, b_meta),
+                ) else {
+                    error::set_last_error(format!(
+                        "Failed to prepare {:?} operands for cross",
+                        out_dtype
+                    ));
+                    return ERR_DTYPE;
+                };
+                match cross_arrays(&a_arr, &b_arr) {
+                    Ok(result) => result,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_SHAPE;
+                    }
+                }
+            }};
+        }
+
+        macro_rules! cross_as {
+            ($variant:ident, $extract_fn:ident) => {
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(cross_with!($extract_fn)))),
+                    dtype: DType::$variant,
+                }
+            };
+        }
+
+        let result_wrapper = match out_dtype {
+            DType::Int8 => cross_as!(Int8, extract_array_as_i8),
+            DType::Int16 => cross_as!(Int16, extract_array_as_i16),
+            DType::Int32 => cross_as!(Int32, extract_array_as_i32),
+            DType::Int64 => cross_as!(Int64, extract_array_as_i64),
+            DType::Uint8 => cross_as!(Uint8, extract_array_as_u8),
+            DType::Uint16 => cross_as!(Uint16, extract_array_as_u16),
+            DType::Uint32 => cross_as!(Uint32, extract_array_as_u32),
+            DType::Uint64 => cross_as!(Uint64, extract_array_as_u64),
+            DType::Float32 => cross_as!(Float32, extract_array_as_f32),
+            DType::Float64 => cross_as!(Float64, extract_array_as_f64),
+            DType::Complex64 => cross_as!(Complex64, extract_array_as_c64),
+            DType::Complex128 => cross_as!(Complex128, extract_array_as_c128),
+            DType::Float16 | DType::BFloat16 => {
+                NDArrayWrapper::from_f32_as_half(cross_with!(extract_array_as_f32), out_dtype)
+            }
+            DType::Bool => {
+                error::set_last_error("cross is not supported for Bool arrays".to_string());
+                return ERR_DTYPE;
+            }
+        };
+
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cross_broadcasts_leading_axes() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]).unwrap();
+        let b = ArrayD::from_shape_vec(IxDyn(&[3]), vec![0.0, 1.0, 0.0]).unwrap();
+        let c = cross_arrays(&a, &b).unwrap();
+        assert_eq!(c.shape(), &[2, 3]);
+        assert_eq!(
+            c.iter().copied().collect::<Vec<_>>(),
+            vec![0.0, 0.0, 1.0, 0.0, 0.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn cross_rejects_non_3_vectors() {
+        let a = ArrayD::<i64>::zeros(IxDyn(&[2]));
+        let b = ArrayD::<i64>::zeros(IxDyn(&[3]));
+        assert!(cross_arrays(&a, &b).is_err());
+    }
+}
@@ -1,148 +1,148 @@
 //! Matrix Determinant
 //!
-//! Compute the determinant of a square matrix.
+//! Compute the determinant of a square matrix, or of each matrix in a
+//! `[..., n, n]` stack.
 
-use std::ffi::c_void;
+use std::sync::Arc;
 
-use ndarray::Ix2;
-use ndarray_linalg::Determinant;
+use ndarray::{ArrayD, Axis, IxDyn};
+use ndarray_linalg::{Determinant, Lapack, Scalar};
+use parking_lot::RwLock;
 
+use super::inverse::{matrix_stack, square_matrix_size};
 use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_MATH, ERR_SHAPE, SUCCESS};
+use crate::helpers::write_output_metadata;
 use crate::helpers::{extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64};
-use crate::types::{ArrayMetadata, DType, NdArrayHandle};
+use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
+
+/// Determinant of every matrix of a `[..., n, n]` array, shaped like the
+/// leading batch dimensions (0-D for a single matrix).
+///
+/// A singular matrix has determinant zero; it is not an error.
+fn det_stack<A>(a: ArrayD<A>, n: usize) -> Result<ArrayD<A>, String>
+where
+    A: Scalar + Lapack,
+{
+    let batch_shape = &a.shape()[..a.ndim() - 2];
+    let stack = matrix_stack(&a, n);
+    let mut dets = Vec::with_capacity(stack.len_of(Axis(0)));
+    for m in stack.axis_iter(Axis(0)) {
+        if n == 0 {
+            dets.push(A::one());
+            continue;
+        }
+        dets.push(m.det().map_err(|e| format!("Determinant failed: {}", e))?);
+    }
+    ArrayD::from_shape_vec(IxDyn(batch_shape), dets).map_err(|e| e.to_string())
+}
 
 /// Compute the determinant of a square matrix.
+///
+/// Inputs with more than two dimensions are treated as stacks of matrices; the
+/// result holds one determinant per trailing `n x n` block and is 0-D for a
+/// single matrix.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_det(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
-    out_value: *mut c_void,
+    out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
 ) -> i32 {
-    if a.is_null() || a_meta.is_null() {
+    if a.is_null() || a_meta.is_null() || out_handle.is_null() {
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("det", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
-        if a_meta_ref.ndim != 2 {
-            error::set_last_error("Determinant requires a 2D matrix".to_string());
+        if a_meta_ref.ndim < 2 {
+            error::set_last_error(format!(
+                "Determinant requires a 2D matrix or a stack of matrices, got {}D",
+                a_meta_ref.ndim
+            ));
             return ERR_SHAPE;
         }
+        let n = match square_matrix_size(a_meta_ref.shape_slice(), "Determinant") {
+            Ok(n) => n,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_MATH;
+            }
+        };
 
-        let shape = a_meta_ref.shape_slice();
-        if shape[0] != shape[1] {
-            error::set_last_error("Determinant requires a square matrix".to_string());
-            return ERR_MATH;
-        }
-
-        match a_wrapper.dtype {
+        let result_wrapper = match a_wrapper.dtype {
             DType::Float64 => {
-                let Some(a_arr_dyn) = extract_array_f64(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for determinant".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                let det = match a_arr_2d.det() {
-                    Ok(d) => d,
+                let result = match det_stack(a_arr, n) {
+                    Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
                         return ERR_MATH;
                     }
                 };
-                if !out_value.is_null() {
-                    *(out_value as *mut f64) = det;
-                }
-                if !out_dtype.is_null() {
-                    *out_dtype = DType::Float64 as u8;
+                NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+                    dtype: DType::Float64,
                 }
             }
             DType::Float32 => {
-                let Some(a_arr_dyn) = extract_array_f32(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for determinant".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                let det = match a_arr_2d.det() {
-                    Ok(d) => d,
+                let result = match det_stack(a_arr, n) {
+                    Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
                         return ERR_MATH;
                     }
                 };
-                if !out_value.is_null() {
-                    *(out_value as *mut f32) = det;
-                }
-                if !out_dtype.is_null() {
-                    *out_dtype = DType::Float32 as u8;
+                NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
+                    dtype: DType::Float32,
                 }
             }
             DType::Complex64 => {
-                let Some(a_arr_dyn) = extract_array_c64(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for determinant".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                let det = match a_arr_2d.det() {
-                    Ok(d) => d,
+                let result = match det_stack(a_arr, n) {
+                    Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
                         return ERR_MATH;
                     }
                 };
-                if !out_value.is_null() {
-                    *(out_value as *mut num_complex::Complex<f32>) = det;
-                }
-                if !out_dtype.is_null() {
-                    *out_dtype = DType::Complex64 as u8;
+                NDArrayWrapper {
+                    data: ArrayData::Complex64(Arc::new(RwLock::new(result))),
+                    dtype: DType::Complex64,
                 }
             }
             DType::Complex128 => {
-                let Some(a_arr_dyn) = extract_array_c128(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error(
                         "Failed to extract c128 view for determinant".to_string(),
                     );
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                let det = match a_arr_2d.det() {
-                    Ok(d) => d,
+                let result = match det_stack(a_arr, n) {
+                    Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
                         return ERR_MATH;
                     }
                 };
-                if !out_value.is_null() {
-                    *(out_value as *mut num_complex::Complex<f64>) = det;
-                }
-                if !out_dtype.is_null() {
-                    *out_dtype = DType::Complex128 as u8;
+                NDArrayWrapper {
+                    data: ArrayData::Complex128(Arc::new(RwLock::new(result))),
+                    dtype: DType::Complex128,
                 }
             }
             _ => {
@@ -152,8 +152,15 @@ pub unsafe extern "C" fn ndarray_det(
                 );
                 return ERR_DTYPE;
             }
-        }
+        };
 
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
         SUCCESS
     })
 }
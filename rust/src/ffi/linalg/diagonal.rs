@@ -2,12 +2,13 @@
 
 use ndarray::s;
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -49,7 +50,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("diagonal", {
         let meta = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
@@ -63,7 +64,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -74,7 +75,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -85,7 +86,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -96,7 +97,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -107,7 +108,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -118,7 +119,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -129,7 +130,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -140,7 +141,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -151,7 +152,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -159,10 +160,18 @@ pub unsafe extern "C" fn ndarray_diagonal(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = extract_offset_diag(&arr, offset);
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -173,7 +182,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -184,7 +193,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
@@ -195,7 +204,7 @@ pub unsafe extern "C" fn ndarray_diagonal(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = extract_offset_diag(&arr, offset);
                 NDArrayWrapper {
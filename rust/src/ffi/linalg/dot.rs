@@ -41,7 +41,7 @@ pub unsafe extern "C" fn ndarray_dot(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("dot", {
         let a_meta_ref = &*a_meta;
         let b_meta_ref = &*b_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
@@ -64,13 +64,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Float64 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_f64(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Float64 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -84,13 +84,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Float64 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_f64(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Float64 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -111,13 +111,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Float32 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_f32(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Float32 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -131,13 +131,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Float32 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_f32(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Float32 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -158,13 +158,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Complex64 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_c64(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Complex64 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -178,13 +178,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Complex64 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_c64(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Complex64 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -205,13 +205,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Complex128 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_c128(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Complex128 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -225,13 +225,13 @@ pub unsafe extern "C" fn ndarray_dot(
                         error::set_last_error(
                             "Failed to prepare Complex128 operand a for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_c128(b_wrapper, b_meta_ref) else {
                         error::set_last_error(
                             "Failed to prepare Complex128 operand b for dot".to_string(),
                         );
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     match dot_dispatch(&a_arr, &b_arr) {
                         Ok(r) => r,
@@ -280,6 +280,15 @@ where
     let na = a.ndim();
     let nb = b.ndim();
     match (na, nb) {
+        // A 0-d operand acts as a scalar multiplier (NumPy semantics).
+        (0, _) => {
+            let s = a[IxDyn(&[])];
+            Ok(b.mapv(|x| s * x))
+        }
+        (_, 0) => {
+            let s = b[IxDyn(&[])];
+            Ok(a.mapv(|x| x * s))
+        }
         (1, 1) => {
             let a1 = a
                 .view()
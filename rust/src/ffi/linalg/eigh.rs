@@ -52,7 +52,7 @@ pub unsafe extern "C" fn ndarray_eigh(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("eigh", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -67,7 +67,7 @@ pub unsafe extern "C" fn ndarray_eigh(
             DType::Float64 => {
                 let Some(a_arr_dyn) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for Eigh".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -98,7 +98,7 @@ pub unsafe extern "C" fn ndarray_eigh(
             DType::Float32 => {
                 let Some(a_arr_dyn) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for Eigh".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -129,7 +129,7 @@ pub unsafe extern "C" fn ndarray_eigh(
             DType::Complex64 => {
                 let Some(a_arr_dyn) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for Eigh".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -160,7 +160,7 @@ pub unsafe extern "C" fn ndarray_eigh(
             DType::Complex128 => {
                 let Some(a_arr_dyn) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for Eigh".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -40,7 +40,7 @@ pub unsafe extern "C" fn ndarray_eigvalsh(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("eigvalsh", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -55,7 +55,7 @@ pub unsafe extern "C" fn ndarray_eigvalsh(
             DType::Float64 => {
                 let Some(a_arr_dyn) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for EigValsH".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -80,7 +80,7 @@ pub unsafe extern "C" fn ndarray_eigvalsh(
             DType::Float32 => {
                 let Some(a_arr_dyn) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for EigValsH".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -105,7 +105,7 @@ pub unsafe extern "C" fn ndarray_eigvalsh(
             DType::Complex64 => {
                 let Some(a_arr_dyn) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for EigValsH".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -130,7 +130,7 @@ pub unsafe extern "C" fn ndarray_eigvalsh(
             DType::Complex128 => {
                 let Some(a_arr_dyn) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for EigValsH".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
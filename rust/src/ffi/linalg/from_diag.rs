@@ -4,12 +4,13 @@ use ndarray::{s, Array2};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -61,7 +62,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("from_diag", {
         let meta = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
@@ -75,7 +76,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -86,7 +87,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -97,7 +98,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -108,7 +109,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -119,7 +120,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -130,7 +131,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -141,7 +142,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -152,7 +153,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -163,7 +164,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -171,10 +172,18 @@ pub unsafe extern "C" fn ndarray_from_diag(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = matrix_from_diag(&arr, offset);
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -185,7 +194,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -196,7 +205,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -207,7 +216,7 @@ pub unsafe extern "C" fn ndarray_from_diag(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = matrix_from_diag(&arr, offset);
                 NDArrayWrapper {
@@ -1,19 +1,88 @@
 //! Matrix Inverse
 //!
-//! Compute the inverse of a square matrix using LU decomposition.
+//! Compute the inverse of a square matrix, or of each matrix in a
+//! `[..., n, n]` stack, using LU decomposition.
 
 use std::sync::Arc;
 
-use ndarray::Ix2;
-use ndarray_linalg::Inverse;
+use ndarray::{Array3, ArrayD, Axis, IxDyn};
+use ndarray_linalg::{Inverse, Lapack, Scalar};
+use num_traits::Float;
 use parking_lot::RwLock;
 
-use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_MATH, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_SINGULAR_MATRIX, SUCCESS,
+};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64};
 use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
 
+/// Validate that `shape` is `[..., n, n]` and return `n`.
+pub(crate) fn square_matrix_size(shape: &[usize], op: &str) -> Result<usize, String> {
+    if shape.len() < 2 {
+        return Err(format!(
+            "{} requires a 2D matrix or a stack of matrices, got {}D",
+            op,
+            shape.len()
+        ));
+    }
+    let (rows, cols) = (shape[shape.len() - 2], shape[shape.len() - 1]);
+    if rows != cols {
+        return Err(format!(
+            "{} requires square matrices, got {}x{}",
+            op, rows, cols
+        ));
+    }
+    Ok(rows)
+}
+
+/// Copy a `[..., n, n]` array into a `(batch, n, n)` stack in logical order.
+pub(crate) fn matrix_stack<A: Clone>(a: &ArrayD<A>, n: usize) -> Array3<A> {
+    let batch = a.shape()[..a.ndim() - 2].iter().product::<usize>();
+    Array3::from_shape_vec((batch, n, n), a.iter().cloned().collect())
+        .expect("stack shape matches element count")
+}
+
+/// Invert every matrix of a `[..., n, n]` array.
+///
+/// Once the shape is validated, a LAPACK failure can only mean an exactly
+/// zero pivot. LAPACK does not flag pivots that merely underflow, so a result
+/// with non-finite entries is treated as singular as well.
+fn inv_stack<A>(a: ArrayD<A>, n: usize) -> Result<ArrayD<A>, String>
+where
+    A: Scalar + Lapack,
+{
+    if n == 0 {
+        return Ok(a);
+    }
+    let stack = matrix_stack(&a, n);
+    let batched = a.ndim() > 2;
+    let singular = |i: usize| {
+        if batched {
+            format!("Inverse: matrix {} of the stack is singular", i)
+        } else {
+            "Inverse: matrix is singular".to_string()
+        }
+    };
+
+    let mut out = Vec::with_capacity(a.len());
+    for (i, m) in stack.axis_iter(Axis(0)).enumerate() {
+        let Ok(inv) = m.inv() else {
+            return Err(singular(i));
+        };
+        if inv.iter().any(|&x| !Float::is_finite(Scalar::abs(x))) {
+            return Err(singular(i));
+        }
+        out.extend(inv.iter().cloned());
+    }
+    ArrayD::from_shape_vec(IxDyn(a.shape()), out).map_err(|e| e.to_string())
+}
+
 /// Compute the inverse of a square matrix.
+///
+/// Inputs with more than two dimensions are treated as stacks of matrices and
+/// each trailing `n x n` block is inverted. Singular matrices fail with
+/// `ERR_SINGULAR_MATRIX`.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_inv(
     a: *const NdArrayHandle,
@@ -28,115 +97,84 @@ pub unsafe extern "C" fn ndarray_inv(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("inv", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
-        if a_meta_ref.ndim != 2 {
-            error::set_last_error("Inverse requires a 2D matrix".to_string());
-            return ERR_SHAPE;
-        }
-
-        let shape = a_meta_ref.shape_slice();
-        if shape[0] != shape[1] {
-            error::set_last_error("Inverse requires a square matrix".to_string());
-            return ERR_SHAPE;
-        }
+        let n = match square_matrix_size(a_meta_ref.shape_slice(), "Inverse") {
+            Ok(n) => n,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
 
         let result_wrapper = match a_wrapper.dtype {
             DType::Float64 => {
-                let Some(a_arr_dyn) = extract_array_f64(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for inverse".to_string());
-                    return ERR_GENERIC;
-                };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
+                    return ERR_DTYPE;
                 };
-                let result = match a_arr_2d.inv() {
+                let result = match inv_stack(a_arr, n) {
                     Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                 };
                 NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result.into_dyn()))),
+                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
                 }
             }
             DType::Float32 => {
-                let Some(a_arr_dyn) = extract_array_f32(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for inverse".to_string());
-                    return ERR_GENERIC;
-                };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
+                    return ERR_DTYPE;
                 };
-                let result = match a_arr_2d.inv() {
+                let result = match inv_stack(a_arr, n) {
                     Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                 };
                 NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result.into_dyn()))),
+                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
                 }
             }
             DType::Complex64 => {
-                let Some(a_arr_dyn) = extract_array_c64(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for inverse".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
-                };
-                let result = match a_arr_2d.inv() {
+                let result = match inv_stack(a_arr, n) {
                     Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                 };
                 NDArrayWrapper {
-                    data: ArrayData::Complex64(Arc::new(RwLock::new(result.into_dyn()))),
+                    data: ArrayData::Complex64(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex64,
                 }
             }
             DType::Complex128 => {
-                let Some(a_arr_dyn) = extract_array_c128(a_wrapper, a_meta_ref) else {
+                let Some(a_arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for inverse".to_string());
-                    return ERR_GENERIC;
-                };
-                let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
-                    Ok(v) => v,
-                    Err(e) => {
-                        error::set_last_error(e);
-                        return ERR_SHAPE;
-                    }
+                    return ERR_DTYPE;
                 };
-                let result = match a_arr_2d.inv() {
+                let result = match inv_stack(a_arr, n) {
                     Ok(r) => r,
                     Err(e) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                 };
                 NDArrayWrapper {
-                    data: ArrayData::Complex128(Arc::new(RwLock::new(result.into_dyn()))),
+                    data: ArrayData::Complex128(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex128,
                 }
             }
@@ -0,0 +1,172 @@
+//! Kronecker product.
+
+use std::ops::Mul;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, Dimension, IxDyn};
+use parking_lot::RwLock;
+
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
+    extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+    extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Kronecker product of `a` and `b`.
+///
+/// The operand with fewer dimensions is padded with leading axes of length 1,
+/// so the result has `max(a.ndim, b.ndim)` axes of length `a_dim * b_dim`.
+/// Element `out[i * b_dim + j]` (per axis) is `a[i] * b[j]`.
+pub(crate) fn kron_arrays<T: Copy + Mul<Output = T>>(a: &ArrayD<T>, b: &ArrayD<T>) -> ArrayD<T> {
+    let ndim = a.ndim().max(b.ndim());
+    let pad = |shape: &[usize]| {
+        let mut padded = vec![1; ndim - shape.len()];
+        padded.extend_from_slice(shape);
+        padded
+    };
+    let a_shape = pad(a.shape());
+    let b_shape = pad(b.shape());
+    let out_shape: Vec<usize> = a_shape.iter().zip(&b_shape).map(|(x, y)| x * y).collect();
+    let mut ia = vec![0usize; ndim];
+    let mut ib = vec![0usize; ndim];
+    ArrayD::from_shape_fn(IxDyn(&out_shape), |ix| {
+        for (((i, j), &x), &n) in ia.iter_mut().zip(&mut ib).zip(ix.slice()).zip(&b_shape) {
+            *i = x / n;
+            *j = x % n;
+        }
+        a[IxDyn(&ia[ndim - a.ndim()..])] * b[IxDyn(&ib[ndim - b.ndim()..])]
+    })
+}
+
+/// Compute the Kronecker product of two arrays.
+///
+/// Operands are promoted to a common dtype (integers stay integers); the
+/// result has `max(a.ndim, b.ndim)` dimensions, each the product of the
+/// operands' lengths along it. Bool operands are rejected.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_kron(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || b.is_null()
+        || a_meta.is_null()
+        || b_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype_ptr.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("kron", {
+        let a_meta = &*a_meta;
+        let b_meta = &*b_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+
+        let out_dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+
+        macro_rules! kron_as {
+            ($variant:ident, $extract_fn:ident) => {{
+                let (Some(a_arr), Some(b_arr)) = (
+                    $extract_fn(a_wrapper, a_meta),
+                    $extract_fn(b_wrapper, b_meta),
+                ) else {
+                    error::set_last_error(format!(
+                        "Failed to prepare {} operands for kron",
+                        stringify!($variant)
+                    ));
+                    return ERR_DTYPE;
+                };
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(kron_arrays(&a_arr, &b_arr)))),
+                    dtype: DType::$variant,
+                }
+            }};
+        }
+
+        let result_wrapper = match out_dtype {
+            DType::Int8 => kron_as!(Int8, extract_array_as_i8),
+            DType::Int16 => kron_as!(Int16, extract_array_as_i16),
+            DType::Int32 => kron_as!(Int32, extract_array_as_i32),
+            DType::Int64 => kron_as!(Int64, extract_array_as_i64),
+            DType::Uint8 => kron_as!(Uint8, extract_array_as_u8),
+            DType::Uint16 => kron_as!(Uint16, extract_array_as_u16),
+            DType::Uint32 => kron_as!(Uint32, extract_array_as_u32),
+            DType::Uint64 => kron_as!(Uint64, extract_array_as_u64),
+            DType::Float32 => kron_as!(Float32, extract_array_as_f32),
+            DType::Float64 => kron_as!(Float64, extract_array_as_f64),
+            DType::Complex64 => kron_as!(Complex64, extract_array_as_c64),
+            DType::Complex128 => kron_as!(Complex128, extract_array_as_c128),
+            DType::Float16 | DType::BFloat16 => {
+                let (Some(a_arr), Some(b_arr)) = (
+                    extract_array_as_f32(a_wrapper, a_meta),
+                    extract_array_as_f32(b_wrapper, b_meta),
+                ) else {
+                    error::set_last_error("Failed to prepare operands for kron".to_string());
+                    return ERR_DTYPE;
+                };
+                NDArrayWrapper::from_f32_as_half(kron_arrays(&a_arr, &b_arr), out_dtype)
+            }
+            DType::Bool => {
+                error::set_last_error("kron is not supported for Bool arrays".to_string());
+                return ERR_DTYPE;
+            }
+        };
+
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kron_matches_block_layout() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[2, 2]), vec![1, 2, 3, 4]).unwrap();
+        let b = ArrayD::from_shape_vec(IxDyn(&[2, 2]), vec![0, 1, 1, 0]).unwrap();
+        let k = kron_arrays(&a, &b);
+        assert_eq!(k.shape(), &[4, 4]);
+        assert_eq!(
+            k.iter().copied().collect::<Vec<_>>(),
+            vec![0, 1, 0, 2, 1, 0, 2, 0, 0, 3, 0, 4, 3, 0, 4, 0]
+        );
+    }
+
+    #[test]
+    fn kron_pads_lower_rank_operand() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[2]), vec![1.0, 2.0]).unwrap();
+        let b = ArrayD::from_shape_vec(IxDyn(&[2, 1]), vec![1.0, 10.0]).unwrap();
+        let k = kron_arrays(&a, &b);
+        assert_eq!(k.shape(), &[2, 2]);
+        assert_eq!(
+            k.iter().copied().collect::<Vec<_>>(),
+            vec![1.0, 2.0, 10.0, 20.0]
+        );
+    }
+}
@@ -101,7 +101,7 @@ pub unsafe extern "C" fn ndarray_lstsq(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("lstsq", {
         let a_meta_ref = &*a_meta;
         let b_meta_ref = &*b_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
@@ -116,11 +116,11 @@ pub unsafe extern "C" fn ndarray_lstsq(
             DType::Float64 => {
                 let Some(a_arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_f64(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let (sol, res, rank, s) =
                     match lstsq_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
@@ -151,11 +151,11 @@ pub unsafe extern "C" fn ndarray_lstsq(
             DType::Float32 => {
                 let Some(a_arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_f32(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let (sol, res, rank, s) =
                     match lstsq_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
@@ -186,11 +186,11 @@ pub unsafe extern "C" fn ndarray_lstsq(
             DType::Complex64 => {
                 let Some(a_arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_c64(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let (sol, res, rank, s) =
                     match lstsq_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
@@ -221,11 +221,11 @@ pub unsafe extern "C" fn ndarray_lstsq(
             DType::Complex128 => {
                 let Some(a_arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_c128(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let (sol, res, rank, s) =
                     match lstsq_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
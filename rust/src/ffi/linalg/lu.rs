@@ -0,0 +1,252 @@
+//! LU Decomposition
+//!
+//! Factors a matrix with partial (row) pivoting so that `A[perm] = L * U`,
+//! where L is unit lower triangular and U is upper triangular.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use ndarray::{Array2, ArrayD, ArrayView2, Ix2, IxDyn};
+use num_traits::Float;
+use parking_lot::RwLock;
+
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
+
+/// Packed LU factors of an `m x n` matrix.
+pub(crate) struct LuFactors<A> {
+    /// Entries below the diagonal hold L (its unit diagonal is implied), the
+    /// rest holds U.
+    pub lu: Array2<A>,
+    /// Row `i` of `L * U` is row `perm[i]` of the input.
+    pub perm: Vec<usize>,
+}
+
+/// Doolittle elimination with partial pivoting.
+///
+/// The largest-magnitude entry of each column is swapped onto the diagonal.
+/// A zero pivot leaves its column untouched, so singular and rectangular
+/// matrices still factor; U then carries the zero on its diagonal.
+pub(crate) fn lu_factor<A: Float>(a: ArrayView2<A>) -> LuFactors<A> {
+    let (m, n) = a.dim();
+    let mut lu = a.to_owned();
+    let mut perm: Vec<usize> = (0..m).collect();
+
+    for j in 0..m.min(n) {
+        let mut p = j;
+        for i in j + 1..m {
+            if lu[[i, j]].abs() > lu[[p, j]].abs() {
+                p = i;
+            }
+        }
+        if p != j {
+            for c in 0..n {
+                lu.swap([p, c], [j, c]);
+            }
+            perm.swap(p, j);
+        }
+
+        let pivot = lu[[j, j]];
+        if pivot == A::zero() {
+            continue;
+        }
+        for i in j + 1..m {
+            let factor = lu[[i, j]] / pivot;
+            lu[[i, j]] = factor;
+            for c in j + 1..n {
+                lu[[i, c]] = lu[[i, c]] - factor * lu[[j, c]];
+            }
+        }
+    }
+
+    LuFactors { lu, perm }
+}
+
+impl<A: Float> LuFactors<A> {
+    /// Split the packed factors into L (`m x k`) and U (`k x n`), `k = min(m, n)`.
+    fn unpack(&self) -> (Array2<A>, Array2<A>) {
+        let (m, n) = self.lu.dim();
+        let k = m.min(n);
+        let l = Array2::from_shape_fn((m, k), |(i, j)| match i.cmp(&j) {
+            Ordering::Greater => self.lu[[i, j]],
+            Ordering::Equal => A::one(),
+            Ordering::Less => A::zero(),
+        });
+        let u = Array2::from_shape_fn((k, n), |(i, j)| {
+            if j >= i {
+                self.lu[[i, j]]
+            } else {
+                A::zero()
+            }
+        });
+        (l, u)
+    }
+}
+
+/// Factor a 2D matrix and return `(L, U, perm)`.
+fn lu_parts<A: Float>(a: ArrayD<A>) -> Result<(ArrayD<A>, ArrayD<A>, ArrayD<i64>), String> {
+    let a = a.into_dimensionality::<Ix2>().map_err(|e| e.to_string())?;
+    let factors = lu_factor(a.view());
+    let (l, u) = factors.unpack();
+    let perm: Vec<i64> = factors.perm.iter().map(|&p| p as i64).collect();
+    let perm = ArrayD::from_shape_vec(IxDyn(&[perm.len()]), perm).map_err(|e| e.to_string())?;
+    Ok((l.into_dyn(), u.into_dyn(), perm))
+}
+
+/// Compute the LU decomposition with partial pivoting: `A[perm] = L * U`.
+///
+/// For an `m x n` input with `k = min(m, n)`, L is `m x k` with a unit
+/// diagonal, U is `k x n`, and `perm` is an Int64 array of `m` row indices.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_lu(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    out_l: *mut *mut NdArrayHandle,
+    out_dtype_l: *mut u8,
+    out_ndim_l: *mut usize,
+    out_shape_l: *mut usize,
+    max_ndim: usize,
+    out_u: *mut *mut NdArrayHandle,
+    out_dtype_u: *mut u8,
+    out_ndim_u: *mut usize,
+    out_shape_u: *mut usize,
+    out_perm: *mut *mut NdArrayHandle,
+    out_dtype_perm: *mut u8,
+    out_ndim_perm: *mut usize,
+    out_shape_perm: *mut usize,
+) -> i32 {
+    if a.is_null() || a_meta.is_null() || out_l.is_null() || out_u.is_null() || out_perm.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("lu", {
+        let a_meta_ref = &*a_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+
+        if a_meta_ref.ndim != 2 {
+            error::set_last_error("LU requires a 2D matrix".to_string());
+            return ERR_SHAPE;
+        }
+
+        let (l_wrapper, u_wrapper, perm) = match a_wrapper.dtype {
+            DType::Float64 => {
+                let Some(a_arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
+                    error::set_last_error("Failed to extract f64 view for LU".to_string());
+                    return ERR_DTYPE;
+                };
+                let (l, u, perm) = match lu_parts(a_arr) {
+                    Ok(parts) => parts,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_SHAPE;
+                    }
+                };
+                let l_wrapper = NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(l))),
+                    dtype: DType::Float64,
+                };
+                let u_wrapper = NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(u))),
+                    dtype: DType::Float64,
+                };
+                (l_wrapper, u_wrapper, perm)
+            }
+            DType::Float32 => {
+                let Some(a_arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
+                    error::set_last_error("Failed to extract f32 view for LU".to_string());
+                    return ERR_DTYPE;
+                };
+                let (l, u, perm) = match lu_parts(a_arr) {
+                    Ok(parts) => parts,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_SHAPE;
+                    }
+                };
+                let l_wrapper = NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(l))),
+                    dtype: DType::Float32,
+                };
+                let u_wrapper = NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(u))),
+                    dtype: DType::Float32,
+                };
+                (l_wrapper, u_wrapper, perm)
+            }
+            _ => {
+                error::set_last_error("LU only supports Float32 and Float64".to_string());
+                return ERR_DTYPE;
+            }
+        };
+        let perm_wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(perm))),
+            dtype: DType::Int64,
+        };
+
+        if let Err(e) =
+            write_output_metadata(&l_wrapper, out_dtype_l, out_ndim_l, out_shape_l, max_ndim)
+        {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_l = NdArrayHandle::from_wrapper(Box::new(l_wrapper));
+
+        if let Err(e) =
+            write_output_metadata(&u_wrapper, out_dtype_u, out_ndim_u, out_shape_u, max_ndim)
+        {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_u = NdArrayHandle::from_wrapper(Box::new(u_wrapper));
+
+        if let Err(e) = write_output_metadata(
+            &perm_wrapper,
+            out_dtype_perm,
+            out_ndim_perm,
+            out_shape_perm,
+            max_ndim,
+        ) {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_perm = NdArrayHandle::from_wrapper(Box::new(perm_wrapper));
+
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn lu_reconstructs_permuted_rows() {
+        let a = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+        let factors = lu_factor(a.view());
+        assert_eq!(factors.perm, vec![2, 0, 1]);
+
+        let (l, u) = factors.unpack();
+        let product = l.dot(&u);
+        for (i, &p) in factors.perm.iter().enumerate() {
+            for j in 0..3 {
+                assert!((product[[i, j]] - a[[p, j]]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn lu_handles_rectangular_and_singular_inputs() {
+        let wide = array![[0.0f32, 1.0, 2.0], [3.0, 4.0, 5.0]];
+        let (l, u) = lu_factor(wide.view()).unpack();
+        assert_eq!(l.dim(), (2, 2));
+        assert_eq!(u.dim(), (2, 3));
+
+        let singular = array![[1.0, 2.0], [2.0, 4.0]];
+        let factors = lu_factor(singular.view());
+        assert_eq!(factors.lu[[1, 1]], 0.0);
+    }
+}
@@ -1,22 +1,28 @@
-//! Matrix multiplication (`@`), NumPy `matmul` rules for 1D/2D and dtype promotion.
+//! Matrix multiplication (`@`), NumPy `matmul` rules for 1D/2D, stacked
+//! matrices with broadcast batch dimensions, and dtype promotion.
+//!
+//! Products can be accumulated in a wider dtype than the operands (e.g. `f32`
+//! inputs summed in `f64`) and returned in an explicit result dtype, trading
+//! speed for precision on long inner dimensions.
 
 use std::sync::Arc;
 
-use ndarray::linalg::Dot;
-use ndarray::{ArrayBase, ArrayD, Data, Ix0, Ix1, Ix2, IxDyn, LinalgScalar};
+use ndarray::linalg::{general_mat_mul, Dot};
+use ndarray::{ArrayBase, ArrayD, Axis, Data, Ix0, Ix1, Ix2, IxDyn, LinalgScalar};
+use num_complex::{Complex32, Complex64};
 use parking_lot::RwLock;
 
 use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64,
-    linalg_computation_dtype,
+    broadcast_shape, extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
+    extract_array_as_f64, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_f64, linalg_computation_dtype,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 
-/// Matrix multiply following NumPy `matmul` for 1D and 2D only (no stacks).
+/// Matrix multiply following NumPy `matmul`.
 ///
 /// Works on any [`ArrayBase`] with dynamic dimensions (owned arrays or views).
 ///
@@ -24,6 +30,7 @@ use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 /// - `(m,n) @ (n,)` → `(m,)`
 /// - `(n,) @ (n,k)` → `(k,)`
 /// - `(n,) @ (n,)` → scalar (`0`-D array)
+/// - `(...,m,n) @ (...,n,k)` → `(...,m,k)`, see [`matmul_batched`]
 fn matmul_nd<A, Sa, Sb>(
     a: &ArrayBase<Sa, IxDyn>,
     b: &ArrayBase<Sb, IxDyn>,
@@ -35,12 +42,15 @@ where
 {
     let na = a.ndim();
     let nb = b.ndim();
-    if na == 0 || nb == 0 || na > 2 || nb > 2 {
+    if na == 0 || nb == 0 {
         return Err(format!(
-            "matmul supports 1D and 2D arrays only (got {}D @ {}D)",
+            "matmul does not support 0D operands (got {}D @ {}D)",
             na, nb
         ));
     }
+    if na > 2 || nb > 2 {
+        return matmul_batched(a, b);
+    }
 
     match (na, nb) {
         (1, 1) => {
@@ -121,14 +131,226 @@ where
     }
 }
 
-/// Matrix multiplication with NumPy-style 1D/2D handling and dtype promotion.
-/// When BLAS is enabled, automatically uses BLAS gemm.
+/// Stacked matrix multiply: `(..., m, k) @ (..., k, n)` → `(..., m, n)`.
+///
+/// Leading batch dimensions broadcast against each other. A 1D operand is
+/// promoted to a matrix (`(k,)` becomes `(1, k)` on the left and `(k, 1)` on
+/// the right) and the added axis is dropped from the result, as in NumPy.
+/// Each matrix product goes through `general_mat_mul`, so BLAS is used when
+/// the layout allows it.
+fn matmul_batched<A, Sa, Sb>(
+    a: &ArrayBase<Sa, IxDyn>,
+    b: &ArrayBase<Sb, IxDyn>,
+) -> Result<ArrayD<A>, String>
+where
+    A: LinalgScalar + Clone,
+    Sa: Data<Elem = A>,
+    Sb: Data<Elem = A>,
+{
+    let a_vector = a.ndim() == 1;
+    let b_vector = b.ndim() == 1;
+    let a = if a_vector {
+        a.view().insert_axis(Axis(0))
+    } else {
+        a.view()
+    };
+    let b = if b_vector {
+        b.view().insert_axis(Axis(1))
+    } else {
+        b.view()
+    };
+
+    let (na, nb) = (a.ndim(), b.ndim());
+    let (m, k) = (a.shape()[na - 2], a.shape()[na - 1]);
+    let (kb, n) = (b.shape()[nb - 2], b.shape()[nb - 1]);
+    if k != kb {
+        return Err(format!(
+            "matmul: inner dimensions mismatch ({} and {})",
+            k, kb
+        ));
+    }
+
+    let batch = broadcast_shape(&a.shape()[..na - 2], &b.shape()[..nb - 2]).ok_or_else(|| {
+        format!(
+            "matmul: batch dimensions {:?} and {:?} cannot be broadcast",
+            &a.shape()[..na - 2],
+            &b.shape()[..nb - 2]
+        )
+    })?;
+
+    let with_matrix = |rows: usize, cols: usize| {
+        let mut shape = batch.clone();
+        shape.extend([rows, cols]);
+        shape
+    };
+    let a_full = a
+        .broadcast(IxDyn(&with_matrix(m, k)))
+        .ok_or("matmul: failed to broadcast left operand")?;
+    let b_full = b
+        .broadcast(IxDyn(&with_matrix(k, n)))
+        .ok_or("matmul: failed to broadcast right operand")?;
+    let mut out = ArrayD::<A>::zeros(IxDyn(&with_matrix(m, n)));
+
+    let count: usize = batch.iter().product();
+    let mut index = vec![0usize; batch.len()];
+    for _ in 0..count {
+        let mut a_mat = a_full.view();
+        let mut b_mat = b_full.view();
+        let mut out_mat = out.view_mut();
+        for &i in &index {
+            a_mat = a_mat.index_axis_move(Axis(0), i);
+            b_mat = b_mat.index_axis_move(Axis(0), i);
+            out_mat = out_mat.index_axis_move(Axis(0), i);
+        }
+        let a_mat = a_mat
+            .into_dimensionality::<Ix2>()
+            .map_err(|e| e.to_string())?;
+        let b_mat = b_mat
+            .into_dimensionality::<Ix2>()
+            .map_err(|e| e.to_string())?;
+        let mut out_mat = out_mat
+            .into_dimensionality::<Ix2>()
+            .map_err(|e| e.to_string())?;
+        general_mat_mul(A::one(), &a_mat, &b_mat, A::zero(), &mut out_mat);
+
+        for axis in (0..index.len()).rev() {
+            index[axis] += 1;
+            if index[axis] < batch[axis] {
+                break;
+            }
+            index[axis] = 0;
+        }
+    }
+
+    if !a_vector && !b_vector {
+        return Ok(out);
+    }
+    let mut shape = batch;
+    if !a_vector {
+        shape.push(m);
+    }
+    if !b_vector {
+        shape.push(n);
+    }
+    out.into_shape_with_order(IxDyn(&shape))
+        .map_err(|e| e.to_string())
+}
+
+/// Compute `a @ b` with both operands converted to the accumulation type of
+/// `$variant`, borrowing native views when no conversion is needed.
+macro_rules! matmul_in {
+    ($a_w:expr, $a_m:expr, $b_w:expr, $b_m:expr, $native:ident, $as_fn:ident, $variant:ident) => {{
+        let native = $a_w.dtype == DType::$variant && $b_w.dtype == DType::$variant;
+        let result = if native {
+            let (Some(a_arr), Some(b_arr)) = ($native($a_w, $a_m), $native($b_w, $b_m)) else {
+                error::set_last_error(format!(
+                    "Failed to prepare {} operands for matmul",
+                    stringify!($variant)
+                ));
+                return ERR_DTYPE;
+            };
+            matmul_nd(&a_arr, &b_arr)
+        } else {
+            let (Some(a_arr), Some(b_arr)) = ($as_fn($a_w, $a_m), $as_fn($b_w, $b_m)) else {
+                error::set_last_error(format!(
+                    "Failed to prepare {} operands for matmul",
+                    stringify!($variant)
+                ));
+                return ERR_DTYPE;
+            };
+            matmul_nd(&a_arr, &b_arr)
+        };
+        match result {
+            Ok(r) => NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(r))),
+                dtype: DType::$variant,
+            },
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_SHAPE;
+            }
+        }
+    }};
+}
+
+/// Check that `acc` can accumulate a product computed in `comp`.
+///
+/// Real products accumulate in `Float32` or `Float64`, complex products in
+/// `Complex64` or `Complex128`.
+fn validate_accumulator(comp: DType, acc: DType) -> Result<(), String> {
+    let ok = if comp.is_complex() {
+        matches!(acc, DType::Complex64 | DType::Complex128)
+    } else {
+        matches!(acc, DType::Float32 | DType::Float64)
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(format!(
+            "Cannot accumulate a {} matmul in {}",
+            comp.name(),
+            acc.name()
+        ))
+    }
+}
+
+/// Convert an accumulated product to the requested result dtype.
+///
+/// Only float ↔ float and complex ↔ complex conversions are allowed, so no
+/// imaginary part is ever dropped silently.
+fn cast_product(wrapper: NDArrayWrapper, target: DType) -> Result<NDArrayWrapper, String> {
+    if wrapper.dtype == target {
+        return Ok(wrapper);
+    }
+    let data = match (&wrapper.data, target) {
+        (ArrayData::Float64(a), DType::Float32) => {
+            ArrayData::Float32(Arc::new(RwLock::new(a.read().mapv(|x| x as f32))))
+        }
+        (ArrayData::Float32(a), DType::Float64) => {
+            ArrayData::Float64(Arc::new(RwLock::new(a.read().mapv(|x| x as f64))))
+        }
+        (ArrayData::Complex128(a), DType::Complex64) => ArrayData::Complex64(Arc::new(
+            RwLock::new(a.read().mapv(|z| Complex32::new(z.re as f32, z.im as f32))),
+        )),
+        (ArrayData::Complex64(a), DType::Complex128) => ArrayData::Complex128(Arc::new(
+            RwLock::new(a.read().mapv(|z| Complex64::new(z.re as f64, z.im as f64))),
+        )),
+        _ => {
+            return Err(format!(
+                "Cannot return a {} matmul result as {}",
+                wrapper.dtype.name(),
+                target.name()
+            ))
+        }
+    };
+    Ok(NDArrayWrapper {
+        data,
+        dtype: target,
+    })
+}
+
+/// Matrix multiplication with NumPy-style 1D/2D handling, stacked matrices
+/// (`[..., M, K] @ [..., K, N]` with broadcast batch dimensions) and dtype
+/// promotion. When BLAS is enabled, automatically uses BLAS gemm.
+///
+/// # Arguments
+/// * `acc_dtype` / `has_acc_dtype` - Dtype the operands are converted to and
+///   the product is accumulated in. Defaults to the promoted dtype (integers
+///   compute in `Float64`); must be real for real inputs and complex for
+///   complex inputs.
+/// * `result_dtype` / `has_result_dtype` - Dtype of the returned array.
+///   Defaults to the promoted dtype, so widening the accumulator alone keeps
+///   the input precision of the output.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_matmul(
     a: *const NdArrayHandle,
     a_meta: *const ArrayMetadata,
     b: *const NdArrayHandle,
     b_meta: *const ArrayMetadata,
+    acc_dtype: u8,
+    has_acc_dtype: bool,
+    result_dtype: u8,
+    has_result_dtype: bool,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype_ptr: *mut u8,
     out_ndim: *mut usize,
@@ -147,7 +369,7 @@ pub unsafe extern "C" fn ndarray_matmul(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("matmul", {
         let a_meta_ref = &*a_meta;
         let b_meta_ref = &*b_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
@@ -159,203 +381,81 @@ pub unsafe extern "C" fn ndarray_matmul(
             return ERR_DTYPE;
         };
 
-        let same_native = a_wrapper.dtype == b_wrapper.dtype && a_wrapper.dtype == comp_dtype;
-
-        let result_wrapper = match comp_dtype {
-            DType::Float64 => {
-                let result = if same_native {
-                    let Some(a_arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float64 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_f64(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float64 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                } else {
-                    let Some(a_arr) = extract_array_as_f64(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float64 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_as_f64(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float64 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let result = if same_native {
-                    let Some(a_arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float32 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_f32(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float32 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                } else {
-                    let Some(a_arr) = extract_array_as_f32(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float32 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_as_f32(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Float32 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Complex64 => {
-                let result = if same_native {
-                    let Some(a_arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex64 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_c64(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex64 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                } else {
-                    let Some(a_arr) = extract_array_as_c64(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex64 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_as_c64(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex64 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Complex64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Complex64,
-                }
-            }
-            DType::Complex128 => {
-                let result = if same_native {
-                    let Some(a_arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex128 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_c128(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex128 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                } else {
-                    let Some(a_arr) = extract_array_as_c128(a_wrapper, a_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex128 operand a for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    let Some(b_arr) = extract_array_as_c128(b_wrapper, b_meta_ref) else {
-                        error::set_last_error(
-                            "Failed to prepare Complex128 operand b for matmul".to_string(),
-                        );
-                        return ERR_GENERIC;
-                    };
-                    match matmul_nd(&a_arr, &b_arr) {
-                        Ok(r) => r,
-                        Err(e) => {
-                            error::set_last_error(e);
-                            return ERR_SHAPE;
-                        }
-                    }
-                };
-                NDArrayWrapper {
-                    data: ArrayData::Complex128(Arc::new(RwLock::new(result))),
-                    dtype: DType::Complex128,
-                }
+        let acc = if has_acc_dtype {
+            let Some(acc) = DType::from_u8(acc_dtype) else {
+                error::set_last_error(format!("Invalid accumulation dtype: {}", acc_dtype));
+                return ERR_DTYPE;
+            };
+            if let Err(e) = validate_accumulator(comp_dtype, acc) {
+                error::set_last_error(e);
+                return ERR_DTYPE;
             }
+            acc
+        } else {
+            comp_dtype
+        };
+
+        let target = if has_result_dtype {
+            let Some(target) = DType::from_u8(result_dtype) else {
+                error::set_last_error(format!("Invalid result dtype: {}", result_dtype));
+                return ERR_DTYPE;
+            };
+            target
+        } else {
+            comp_dtype
+        };
+
+        let product = match acc {
+            DType::Float64 => matmul_in!(
+                a_wrapper,
+                a_meta_ref,
+                b_wrapper,
+                b_meta_ref,
+                extract_array_f64,
+                extract_array_as_f64,
+                Float64
+            ),
+            DType::Float32 => matmul_in!(
+                a_wrapper,
+                a_meta_ref,
+                b_wrapper,
+                b_meta_ref,
+                extract_array_f32,
+                extract_array_as_f32,
+                Float32
+            ),
+            DType::Complex64 => matmul_in!(
+                a_wrapper,
+                a_meta_ref,
+                b_wrapper,
+                b_meta_ref,
+                extract_array_c64,
+                extract_array_as_c64,
+                Complex64
+            ),
+            DType::Complex128 => matmul_in!(
+                a_wrapper,
+                a_meta_ref,
+                b_wrapper,
+                b_meta_ref,
+                extract_array_c128,
+                extract_array_as_c128,
+                Complex128
+            ),
             _ => {
                 error::set_last_error("Matmul internal dtype error".to_string());
                 return ERR_DTYPE;
             }
         };
 
+        let result_wrapper = match cast_product(product, target) {
+            Ok(w) => w,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_DTYPE;
+            }
+        };
+
         if let Err(e) = write_output_metadata(
             &result_wrapper,
             out_dtype_ptr,
@@ -370,3 +470,54 @@ pub unsafe extern "C" fn ndarray_matmul(
         SUCCESS
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_casts_keep_kind() {
+        let w = NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(ArrayD::from_elem(IxDyn(&[2]), 1.5)))),
+            dtype: DType::Float64,
+        };
+        assert_eq!(
+            cast_product(w, DType::Float32).unwrap().dtype,
+            DType::Float32
+        );
+
+        let w = NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(ArrayD::from_elem(IxDyn(&[2]), 1.5)))),
+            dtype: DType::Float64,
+        };
+        assert!(cast_product(w, DType::Complex128).is_err());
+        assert!(validate_accumulator(DType::Float32, DType::Float64).is_ok());
+        assert!(validate_accumulator(DType::Float32, DType::Complex128).is_err());
+        assert!(validate_accumulator(DType::Complex64, DType::Float64).is_err());
+    }
+
+    #[test]
+    fn batched_matmul_broadcasts_stacks() {
+        // Integer elements keep the test off the BLAS path.
+        let a = ArrayD::from_shape_vec(IxDyn(&[2, 2, 3]), (0..12i64).collect()).unwrap();
+        let b = ArrayD::from_shape_vec(IxDyn(&[3, 2]), (0..6i64).collect()).unwrap();
+        let out = matmul_nd(&a, &b).unwrap();
+        assert_eq!(out.shape(), &[2, 2, 2]);
+        assert_eq!(
+            out.iter().copied().collect::<Vec<_>>(),
+            vec![10, 13, 28, 40, 46, 67, 64, 94]
+        );
+
+        let v = ArrayD::from_shape_vec(IxDyn(&[3]), vec![1i64; 3]).unwrap();
+        let out = matmul_nd(&a, &v).unwrap();
+        assert_eq!(out.shape(), &[2, 2]);
+        assert_eq!(out.iter().copied().collect::<Vec<_>>(), vec![3, 12, 21, 30]);
+
+        let c = ArrayD::from_shape_vec(IxDyn(&[3, 3, 2]), (0..18i64).collect()).unwrap();
+        assert!(matmul_nd(&a, &c)
+            .unwrap_err()
+            .contains("cannot be broadcast"));
+        let d = ArrayD::from_shape_vec(IxDyn(&[4, 1, 3, 2]), (0..24i64).collect()).unwrap();
+        assert_eq!(matmul_nd(&a, &d).unwrap().shape(), &[4, 2, 2, 2]);
+    }
+}
@@ -2,6 +2,7 @@
 
 pub mod cholesky;
 pub mod cond;
+pub mod cross;
 pub mod determinant;
 pub mod diagonal;
 pub mod dot;
@@ -11,18 +12,24 @@ pub mod eigvals;
 pub mod eigvalsh;
 pub mod from_diag;
 pub mod inverse;
+pub mod kron;
 pub mod lstsq;
+pub mod lu;
 pub mod matmul;
 pub mod norm;
+pub mod outer;
 pub mod pinv;
 pub mod qr;
 pub mod rank;
 pub mod solve;
 pub mod svd;
+pub mod tensordot;
 pub mod trace;
+pub mod triangle;
 
 pub use cholesky::*;
 pub use cond::*;
+pub use cross::*;
 pub use determinant::*;
 pub use diagonal::*;
 pub use dot::*;
@@ -32,12 +39,17 @@ pub use eigvals::*;
 pub use eigvalsh::*;
 pub use from_diag::*;
 pub use inverse::*;
+pub use kron::*;
 pub use lstsq::*;
+pub use lu::*;
 pub use matmul::*;
 pub use norm::*;
+pub use outer::*;
 pub use pinv::*;
 pub use qr::*;
 pub use rank::*;
 pub use solve::*;
 pub use svd::*;
+pub use tensordot::*;
 pub use trace::*;
+pub use triangle::*;
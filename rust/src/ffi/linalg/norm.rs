@@ -8,10 +8,10 @@ use num_complex::{Complex32, Complex64};
 use num_traits::ToPrimitive;
 use parking_lot::RwLock;
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::{
-    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_as_f32, extract_array_bool, extract_array_c128, extract_array_c64,
     extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
     extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
@@ -330,7 +330,7 @@ pub unsafe extern "C" fn ndarray_norm(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("norm", {
         let meta = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
@@ -338,7 +338,7 @@ pub unsafe extern "C" fn ndarray_norm(
             Ok(o) => o,
             Err(e) => {
                 error::set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
 
@@ -348,91 +348,91 @@ pub unsafe extern "C" fn ndarray_norm(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Float64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract Float32 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int32 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int16 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int8 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint32 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint16 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint8 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract Bool view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_real(&arr, shape, ord)
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Complex64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_complex32(&arr, shape, ord)
             }
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract Complex128 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_scalar_complex64(&arr, shape, ord)
             }
@@ -470,7 +470,7 @@ pub unsafe extern "C" fn ndarray_norm_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("norm_axis", {
         let meta = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
@@ -479,7 +479,7 @@ pub unsafe extern "C" fn ndarray_norm_axis(
             Ok(o) => o,
             Err(e) => {
                 error::set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
 
@@ -502,91 +502,91 @@ pub unsafe extern "C" fn ndarray_norm_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Float64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract Float32 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int32 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int16 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract Int8 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint32 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint16 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract Uint8 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract Bool view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_real(&arr, shape, axis, keepdims, ord)
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract Complex64 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_complex32(&arr, shape, axis, keepdims, ord)
             }
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract Complex128 view for norm".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 norm_axis_complex64(&arr, shape, axis, keepdims, ord)
             }
@@ -0,0 +1,69 @@
+//! Outer product of two arrays.
+
+use crate::helpers::error::{self, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+use super::tensordot::{tensordot_wrappers, Contraction};
+
+/// Compute the outer product `out[i, j] = a[i] * b[j]`.
+///
+/// Both inputs are flattened in C order, so the result is always 2D with shape
+/// `[a.size, b.size]`. Operands are promoted as in `ndarray_dot`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_outer(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || b.is_null()
+        || a_meta.is_null()
+        || b_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype_ptr.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("outer", {
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+
+        let result_wrapper = match tensordot_wrappers(
+            a_wrapper,
+            &*a_meta,
+            b_wrapper,
+            &*b_meta,
+            Contraction::Outer,
+            "outer",
+        ) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
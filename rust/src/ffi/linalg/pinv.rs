@@ -152,7 +152,7 @@ pub unsafe extern "C" fn ndarray_pinv(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("pinv", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -165,7 +165,7 @@ pub unsafe extern "C" fn ndarray_pinv(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for pinv".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.view().into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -194,7 +194,7 @@ pub unsafe extern "C" fn ndarray_pinv(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for pinv".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.view().into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -223,7 +223,7 @@ pub unsafe extern "C" fn ndarray_pinv(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for pinv".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.view().into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -252,7 +252,7 @@ pub unsafe extern "C" fn ndarray_pinv(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for pinv".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.view().into_dimensionality::<Ix2>() {
                     Ok(v) => v,
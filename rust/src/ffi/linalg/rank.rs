@@ -24,7 +24,7 @@ pub unsafe extern "C" fn ndarray_rank(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("rank", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -37,7 +37,7 @@ pub unsafe extern "C" fn ndarray_rank(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for rank".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -67,7 +67,7 @@ pub unsafe extern "C" fn ndarray_rank(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for rank".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -97,7 +97,7 @@ pub unsafe extern "C" fn ndarray_rank(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for rank".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
@@ -127,7 +127,7 @@ pub unsafe extern "C" fn ndarray_rank(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for rank".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr = match arr.into_dimensionality::<Ix2>() {
                     Ok(v) => v,
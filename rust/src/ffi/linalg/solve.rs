@@ -8,14 +8,16 @@ use ndarray::{ArrayD, ArrayViewD, Axis, Ix1, Ix2};
 use ndarray_linalg::{Lapack, Scalar, Solve};
 use parking_lot::RwLock;
 
-use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_MATH, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_SINGULAR_MATRIX, SUCCESS,
+};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64};
 use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
 
 enum SolveErr {
     Shape(String),
-    Math(String),
+    Singular(String),
     Generic(String),
 }
 
@@ -31,15 +33,31 @@ where
     let a_2d = a
         .into_dimensionality::<Ix2>()
         .map_err(|e| SolveErr::Shape(format!("Solve: failed to convert A to 2D: {}", e)))?;
+    let n = a_2d.nrows();
+    if a_2d.ncols() != n {
+        return Err(SolveErr::Shape(format!(
+            "Solve requires a square coefficient matrix, got {}x{}",
+            n,
+            a_2d.ncols()
+        )));
+    }
+    if b.shape().first() != Some(&n) {
+        return Err(SolveErr::Shape(format!(
+            "Solve: b has {} rows but A is {}x{}",
+            b.shape().first().copied().unwrap_or(0),
+            n,
+            n
+        )));
+    }
+    // With the shapes checked, LAPACK only fails on an exactly zero pivot.
+    let singular = |_| SolveErr::Singular("Solve: coefficient matrix is singular".to_string());
 
     match b_ndim {
         1 => {
             let b_1d = b
                 .into_dimensionality::<Ix1>()
                 .map_err(|e| SolveErr::Shape(format!("Solve: failed to convert b to 1D: {}", e)))?;
-            a_2d.solve(&b_1d)
-                .map(|r| r.into_dyn())
-                .map_err(|e| SolveErr::Math(format!("Solve computation failed: {:?}", e)))
+            a_2d.solve(&b_1d).map(|r| r.into_dyn()).map_err(singular)
         }
         2 => {
             let b_2d = b
@@ -47,9 +65,7 @@ where
                 .map_err(|e| SolveErr::Shape(format!("Solve: failed to convert b to 2D: {}", e)))?;
             let mut cols: Vec<ndarray::Array1<A>> = Vec::with_capacity(b_2d.ncols());
             for col in b_2d.axis_iter(Axis(1)) {
-                let x = a_2d
-                    .solve(&col)
-                    .map_err(|e| SolveErr::Math(format!("Solve computation failed: {:?}", e)))?;
+                let x = a_2d.solve(&col).map_err(singular)?;
                 cols.push(x);
             }
             let stacked =
@@ -82,7 +98,7 @@ pub unsafe extern "C" fn ndarray_solve(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("solve", {
         let a_meta_ref = &*a_meta;
         let b_meta_ref = &*b_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
@@ -102,11 +118,11 @@ pub unsafe extern "C" fn ndarray_solve(
             DType::Float64 => {
                 let Some(a_arr) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_f64(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = match solve_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
                     Ok(r) => r,
@@ -114,9 +130,9 @@ pub unsafe extern "C" fn ndarray_solve(
                         error::set_last_error(e);
                         return ERR_SHAPE;
                     }
-                    Err(SolveErr::Math(e)) => {
+                    Err(SolveErr::Singular(e)) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                     Err(SolveErr::Generic(e)) => {
                         error::set_last_error(e);
@@ -131,11 +147,11 @@ pub unsafe extern "C" fn ndarray_solve(
             DType::Float32 => {
                 let Some(a_arr) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_f32(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = match solve_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
                     Ok(r) => r,
@@ -143,9 +159,9 @@ pub unsafe extern "C" fn ndarray_solve(
                         error::set_last_error(e);
                         return ERR_SHAPE;
                     }
-                    Err(SolveErr::Math(e)) => {
+                    Err(SolveErr::Singular(e)) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                     Err(SolveErr::Generic(e)) => {
                         error::set_last_error(e);
@@ -160,11 +176,11 @@ pub unsafe extern "C" fn ndarray_solve(
             DType::Complex64 => {
                 let Some(a_arr) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_c64(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = match solve_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
                     Ok(r) => r,
@@ -172,9 +188,9 @@ pub unsafe extern "C" fn ndarray_solve(
                         error::set_last_error(e);
                         return ERR_SHAPE;
                     }
-                    Err(SolveErr::Math(e)) => {
+                    Err(SolveErr::Singular(e)) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                     Err(SolveErr::Generic(e)) => {
                         error::set_last_error(e);
@@ -189,11 +205,11 @@ pub unsafe extern "C" fn ndarray_solve(
             DType::Complex128 => {
                 let Some(a_arr) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for A".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let Some(b_arr) = extract_array_c128(b_wrapper, b_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for b".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = match solve_dispatch(a_arr.view(), b_arr.view(), b_meta_ref.ndim) {
                     Ok(r) => r,
@@ -201,9 +217,9 @@ pub unsafe extern "C" fn ndarray_solve(
                         error::set_last_error(e);
                         return ERR_SHAPE;
                     }
-                    Err(SolveErr::Math(e)) => {
+                    Err(SolveErr::Singular(e)) => {
                         error::set_last_error(e);
-                        return ERR_MATH;
+                        return ERR_SINGULAR_MATRIX;
                     }
                     Err(SolveErr::Generic(e)) => {
                         error::set_last_error(e);
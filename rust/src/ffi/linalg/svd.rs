@@ -5,7 +5,7 @@
 use std::os::raw::c_uchar;
 use std::sync::Arc;
 
-use ndarray::Ix2;
+use ndarray::{s, Array2, Ix2};
 use ndarray_linalg::SVD;
 use parking_lot::RwLock;
 
@@ -14,6 +14,19 @@ use crate::helpers::write_output_metadata;
 use crate::helpers::{extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64};
 use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
 
+/// Drop the trailing columns of U and rows of V^T that only span the null
+/// space, leaving `k = min(m, n)` of each (NumPy's `full_matrices=False`).
+fn reduce_factors<A: Clone>(
+    u: Option<Array2<A>>,
+    vt: Option<Array2<A>>,
+    k: usize,
+) -> (Option<Array2<A>>, Option<Array2<A>>) {
+    (
+        u.map(|u| u.slice(s![.., ..k]).to_owned()),
+        vt.map(|vt| vt.slice(s![..k, ..]).to_owned()),
+    )
+}
+
 /// Compute SVD: A = U * S * V^T
 ///
 /// # Arguments
@@ -21,6 +34,8 @@ use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandl
 /// * `a_meta` - Array metadata
 /// * `calc_u` - If non-zero, compute U matrix
 /// * `calc_vt` - If non-zero, compute V^T matrix
+/// * `full_matrices` - If non-zero, U is m x m and V^T is n x n; otherwise
+///   they are reduced to m x k and k x n with `k = min(m, n)`
 /// * `out_u` - Output U matrix handle (only written if calc_u is non-zero)
 /// * `out_dtype_u` - U dtype output
 /// * `out_ndim_u` - U ndim output
@@ -43,6 +58,7 @@ pub unsafe extern "C" fn ndarray_svd(
     a_meta: *const ArrayMetadata,
     calc_u: c_uchar,
     calc_vt: c_uchar,
+    full_matrices: c_uchar,
     out_u: *mut *mut NdArrayHandle,
     out_dtype_u: *mut u8,
     out_ndim_u: *mut usize,
@@ -63,8 +79,9 @@ pub unsafe extern "C" fn ndarray_svd(
 
     let calc_u_bool = calc_u != 0;
     let calc_vt_bool = calc_vt != 0;
+    let full_matrices = full_matrices != 0;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("svd", {
         let a_meta_ref = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -72,12 +89,14 @@ pub unsafe extern "C" fn ndarray_svd(
             error::set_last_error("SVD requires 2D matrix".to_string());
             return ERR_SHAPE;
         }
+        let shape = a_meta_ref.shape_slice();
+        let k = shape[0].min(shape[1]);
 
         let (u_wrapper_opt, s_wrapper, vt_wrapper_opt) = match a_wrapper.dtype {
             DType::Float64 => {
                 let Some(a_arr_dyn) = extract_array_f64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f64 view for SVD".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(a) => a,
@@ -93,6 +112,11 @@ pub unsafe extern "C" fn ndarray_svd(
                         return ERR_MATH;
                     }
                 };
+                let (u_opt, vt_opt) = if full_matrices {
+                    (u_opt, vt_opt)
+                } else {
+                    reduce_factors(u_opt, vt_opt, k)
+                };
 
                 let u_wrapper = u_opt.map(|u| NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(u.into_dyn()))),
@@ -114,7 +138,7 @@ pub unsafe extern "C" fn ndarray_svd(
             DType::Float32 => {
                 let Some(a_arr_dyn) = extract_array_f32(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract f32 view for SVD".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(a) => a,
@@ -130,6 +154,11 @@ pub unsafe extern "C" fn ndarray_svd(
                         return ERR_MATH;
                     }
                 };
+                let (u_opt, vt_opt) = if full_matrices {
+                    (u_opt, vt_opt)
+                } else {
+                    reduce_factors(u_opt, vt_opt, k)
+                };
 
                 let u_wrapper = u_opt.map(|u| NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(u.into_dyn()))),
@@ -151,7 +180,7 @@ pub unsafe extern "C" fn ndarray_svd(
             DType::Complex64 => {
                 let Some(a_arr_dyn) = extract_array_c64(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c64 view for SVD".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(a) => a,
@@ -167,6 +196,11 @@ pub unsafe extern "C" fn ndarray_svd(
                         return ERR_MATH;
                     }
                 };
+                let (u_opt, vt_opt) = if full_matrices {
+                    (u_opt, vt_opt)
+                } else {
+                    reduce_factors(u_opt, vt_opt, k)
+                };
 
                 let u_wrapper = u_opt.map(|u| NDArrayWrapper {
                     data: ArrayData::Complex64(Arc::new(RwLock::new(u.into_dyn()))),
@@ -188,7 +222,7 @@ pub unsafe extern "C" fn ndarray_svd(
             DType::Complex128 => {
                 let Some(a_arr_dyn) = extract_array_c128(a_wrapper, a_meta_ref) else {
                     error::set_last_error("Failed to extract c128 view for SVD".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let a_arr_2d = match a_arr_dyn.into_dimensionality::<Ix2>() {
                     Ok(a) => a,
@@ -204,6 +238,11 @@ pub unsafe extern "C" fn ndarray_svd(
                         return ERR_MATH;
                     }
                 };
+                let (u_opt, vt_opt) = if full_matrices {
+                    (u_opt, vt_opt)
+                } else {
+                    reduce_factors(u_opt, vt_opt, k)
+                };
 
                 let u_wrapper = u_opt.map(|u| NDArrayWrapper {
                     data: ArrayData::Complex128(Arc::new(RwLock::new(u.into_dyn()))),
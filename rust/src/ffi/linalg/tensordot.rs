@@ -0,0 +1,288 @@
+//! Tensor dot product over arbitrary pairs of axes.
+
+use std::sync::Arc;
+
+use ndarray::{Array2, ArrayD, IxDyn, LinalgScalar};
+use parking_lot::RwLock;
+
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
+    linalg_computation_dtype, normalize_axes,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Contract `axes_a` of `a` with `axes_b` of `b`.
+///
+/// The result has the free axes of `a` followed by the free axes of `b`, each
+/// in their original order. Both operands are gathered into matrices
+/// (free x contracted and contracted x free) and multiplied with one gemm.
+pub(crate) fn tensordot_arrays<A: LinalgScalar>(
+    a: &ArrayD<A>,
+    b: &ArrayD<A>,
+    axes_a: &[usize],
+    axes_b: &[usize],
+) -> Result<ArrayD<A>, String> {
+    if axes_a.len() != axes_b.len() {
+        return Err(format!(
+            "tensordot: {} axes given for a but {} for b",
+            axes_a.len(),
+            axes_b.len()
+        ));
+    }
+    for (&x, &y) in axes_a.iter().zip(axes_b) {
+        if a.shape()[x] != b.shape()[y] {
+            return Err(format!(
+                "tensordot: axis {} of a has length {} but axis {} of b has length {}",
+                x,
+                a.shape()[x],
+                y,
+                b.shape()[y]
+            ));
+        }
+    }
+
+    let free_a: Vec<usize> = (0..a.ndim()).filter(|d| !axes_a.contains(d)).collect();
+    let free_b: Vec<usize> = (0..b.ndim()).filter(|d| !axes_b.contains(d)).collect();
+    let m: usize = free_a.iter().map(|&d| a.shape()[d]).product();
+    let n: usize = free_b.iter().map(|&d| b.shape()[d]).product();
+    let k: usize = axes_a.iter().map(|&d| a.shape()[d]).product();
+
+    let perm_a: Vec<usize> = free_a.iter().chain(axes_a).copied().collect();
+    let perm_b: Vec<usize> = axes_b.iter().chain(&free_b).copied().collect();
+    let a_mat = Array2::from_shape_vec(
+        (m, k),
+        a.view().permuted_axes(perm_a).iter().cloned().collect(),
+    )
+    .map_err(|e| e.to_string())?;
+    let b_mat = Array2::from_shape_vec(
+        (k, n),
+        b.view().permuted_axes(perm_b).iter().cloned().collect(),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let out_shape: Vec<usize> = free_a
+        .iter()
+        .map(|&d| a.shape()[d])
+        .chain(free_b.iter().map(|&d| b.shape()[d]))
+        .collect();
+    let product = a_mat.dot(&b_mat);
+    ArrayD::from_shape_vec(IxDyn(&out_shape), product.iter().cloned().collect())
+        .map_err(|e| e.to_string())
+}
+
+/// Which axes [`tensordot_wrappers`] contracts.
+pub(crate) enum Contraction<'a> {
+    /// Explicit, already normalized axis pairs.
+    Axes(&'a [usize], &'a [usize]),
+    /// Flatten both operands and contract nothing (outer product).
+    Outer,
+}
+
+/// Promote two operands to their linear algebra dtype and contract them.
+///
+/// Errors carry the FFI status code to return.
+pub(crate) unsafe fn tensordot_wrappers(
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    b_wrapper: &NDArrayWrapper,
+    b_meta: &ArrayMetadata,
+    contraction: Contraction<'_>,
+    op: &str,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let promoted = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+    let Some(comp_dtype) = linalg_computation_dtype(promoted) else {
+        return Err((
+            ERR_DTYPE,
+            format!("{} supports numeric and complex dtypes", op),
+        ));
+    };
+
+    macro_rules! contract_as {
+        ($variant:ident, $extract_fn:ident) => {{
+            let (Some(a_arr), Some(b_arr)) = (
+                $extract_fn(a_wrapper, a_meta),
+                $extract_fn(b_wrapper, b_meta),
+            ) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!(
+                        "Failed to prepare {} operands for {}",
+                        stringify!($variant),
+                        op
+                    ),
+                ));
+            };
+            let result = match contraction {
+                Contraction::Axes(axes_a, axes_b) => {
+                    tensordot_arrays(&a_arr, &b_arr, axes_a, axes_b)
+                }
+                Contraction::Outer => {
+                    let a_flat = ArrayD::from_shape_vec(
+                        IxDyn(&[a_arr.len()]),
+                        a_arr.iter().cloned().collect(),
+                    );
+                    let b_flat = ArrayD::from_shape_vec(
+                        IxDyn(&[b_arr.len()]),
+                        b_arr.iter().cloned().collect(),
+                    );
+                    match (a_flat, b_flat) {
+                        (Ok(a_flat), Ok(b_flat)) => {
+                            tensordot_arrays(&a_flat, &b_flat, &[], &[])
+                        }
+                        (Err(e), _) | (_, Err(e)) => Err(e.to_string()),
+                    }
+                }
+            }
+            .map_err(|e| (ERR_SHAPE, e))?;
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(result))),
+                dtype: DType::$variant,
+            }
+        }};
+    }
+
+    Ok(match comp_dtype {
+        DType::Float64 => contract_as!(Float64, extract_array_as_f64),
+        DType::Float32 => contract_as!(Float32, extract_array_as_f32),
+        DType::Complex64 => contract_as!(Complex64, extract_array_as_c64),
+        DType::Complex128 => contract_as!(Complex128, extract_array_as_c128),
+        other => {
+            return Err((
+                ERR_DTYPE,
+                format!("{} internal dtype error: {:?}", op, other),
+            ))
+        }
+    })
+}
+
+/// Sum products over the given axis pairs of `a` and `b`.
+///
+/// Axis `axes_a[i]` of `a` is contracted with axis `axes_b[i]` of `b`; negative
+/// axes count from the end. The result has the remaining axes of `a` followed
+/// by the remaining axes of `b`. Operands are promoted as in `ndarray_dot`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_tensordot(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    axes_a: *const i64,
+    axes_b: *const i64,
+    num_axes: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || b.is_null()
+        || a_meta.is_null()
+        || b_meta.is_null()
+        || (num_axes > 0 && (axes_a.is_null() || axes_b.is_null()))
+        || out_handle.is_null()
+        || out_dtype_ptr.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("tensordot", {
+        let a_meta_ref = &*a_meta;
+        let b_meta_ref = &*b_meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+
+        let (raw_a, raw_b): (&[i64], &[i64]) = if num_axes == 0 {
+            (&[], &[])
+        } else {
+            (
+                std::slice::from_raw_parts(axes_a, num_axes),
+                std::slice::from_raw_parts(axes_b, num_axes),
+            )
+        };
+        let mut axes = [Vec::new(), Vec::new()];
+        for (slot, (meta, raw)) in axes
+            .iter_mut()
+            .zip([(a_meta_ref, raw_a), (b_meta_ref, raw_b)])
+        {
+            *slot = match normalize_axes(meta.shape_slice(), raw) {
+                Ok(v) => v,
+                Err(e) => {
+                    error::set_last_error(format!("tensordot: {}", e));
+                    return ERR_SHAPE;
+                }
+            };
+        }
+        let [axes_a, axes_b] = axes;
+
+        let result_wrapper = match tensordot_wrappers(
+            a_wrapper,
+            a_meta_ref,
+            b_wrapper,
+            b_meta_ref,
+            Contraction::Axes(&axes_a, &axes_b),
+            "tensordot",
+        ) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                error::set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            error::set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array;
+
+    #[test]
+    fn tensordot_matches_explicit_sum() {
+        let a = Array::from_shape_fn(IxDyn(&[2, 3, 4]), |ix| {
+            (ix[0] * 12 + ix[1] * 4 + ix[2]) as f64
+        });
+        let b = Array::from_shape_fn(IxDyn(&[4, 3, 5]), |ix| {
+            (ix[0] + 2 * ix[1] + 3 * ix[2]) as f64
+        });
+        let c = tensordot_arrays(&a, &b, &[1, 2], &[1, 0]).unwrap();
+        assert_eq!(c.shape(), &[2, 5]);
+        for i in 0..2 {
+            for l in 0..5 {
+                let mut s = 0.0;
+                for j in 0..3 {
+                    for k in 0..4 {
+                        s += a[[i, j, k]] * b[[k, j, l]];
+                    }
+                }
+                assert_eq!(c[[i, l]], s);
+            }
+        }
+    }
+
+    #[test]
+    fn tensordot_rejects_mismatched_axes() {
+        let a = ArrayD::<f64>::zeros(IxDyn(&[2, 3]));
+        let b = ArrayD::<f64>::zeros(IxDyn(&[2, 3]));
+        assert!(tensordot_arrays(&a, &b, &[1], &[0]).is_err());
+        assert!(tensordot_arrays(&a, &b, &[1], &[]).is_err());
+    }
+}
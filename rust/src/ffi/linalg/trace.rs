@@ -1,9 +1,9 @@
 //! Trace computation.
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_as_f32, extract_array_bool, extract_array_c128, extract_array_c64,
     extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
     extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
@@ -31,7 +31,7 @@ pub unsafe extern "C" fn ndarray_trace(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("trace", {
         let meta = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
@@ -45,15 +45,15 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: f64 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_f64(&[trace_sum], &[]).unwrap()
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: f32 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_f32(&[trace_sum], &[]).unwrap()
@@ -61,7 +61,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract c64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: num_complex::Complex<f32> = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_complex64(&[trace_sum.re, trace_sum.im], &[]).unwrap()
@@ -69,7 +69,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract c128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: num_complex::Complex<f64> = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_complex128(&[trace_sum.re, trace_sum.im], &[]).unwrap()
@@ -77,7 +77,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: i64 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_i64(&[trace_sum], &[]).unwrap()
@@ -85,7 +85,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: i32 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_i32(&[trace_sum], &[]).unwrap()
@@ -93,7 +93,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: i16 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_i16(&[trace_sum], &[]).unwrap()
@@ -101,7 +101,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: i8 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_i8(&[trace_sum], &[]).unwrap()
@@ -109,7 +109,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: u64 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_u64(&[trace_sum], &[]).unwrap()
@@ -117,7 +117,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: u32 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_u32(&[trace_sum], &[]).unwrap()
@@ -125,7 +125,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: u16 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_u16(&[trace_sum], &[]).unwrap()
@@ -133,7 +133,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: u8 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_u8(&[trace_sum], &[]).unwrap()
@@ -141,7 +141,7 @@ pub unsafe extern "C" fn ndarray_trace(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let trace_sum: u8 = arr.diag().iter().sum();
                 NDArrayWrapper::from_slice_bool(&[trace_sum], &[]).unwrap()
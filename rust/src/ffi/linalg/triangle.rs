@@ -0,0 +1,158 @@
+//! Lower and upper triangles of (batched) matrices.
+
+use ndarray::{ArrayD, Axis};
+
+use crate::ffi::stacking::helpers::copy_view;
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Copy of the array with elements above the k-th diagonal zeroed.
+///
+/// Applies to the last two axes, so stacks of matrices are handled per matrix.
+/// `k = 0` keeps the main diagonal, `k > 0` also keeps that many diagonals
+/// above it and `k < 0` drops that many below it. Shape and dtype are unchanged.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_tril(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    k: isize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    triangle_entry(
+        "tril", handle, meta, k, true, out_handle, out_dtype, out_ndim, out_shape, max_ndim,
+    )
+}
+
+/// Copy of the array with elements below the k-th diagonal zeroed.
+///
+/// Same conventions as `ndarray_tril`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_triu(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    k: isize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    triangle_entry(
+        "triu", handle, meta, k, false, out_handle, out_dtype, out_ndim, out_shape, max_ndim,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn triangle_entry(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    k: isize,
+    lower: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if meta.ndim < 2 {
+            set_last_error(format!(
+                "{} requires at least 2 dimensions, got {}",
+                if lower { "tril" } else { "triu" },
+                meta.ndim
+            ));
+            return ERR_SHAPE;
+        }
+
+        let Some(result) = copy_view(wrapper, meta) else {
+            set_last_error(format!("Failed to extract {} view", wrapper.dtype));
+            return ERR_DTYPE;
+        };
+        crate::match_array_data!(result.data, arr => {
+            mask_triangle(&mut arr.write(), k, lower)
+        });
+
+        if let Err(e) = write_output_metadata(&result, out_dtype, out_ndim, out_shape, max_ndim) {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result));
+        SUCCESS
+    })
+}
+
+/// Zero the part of every matrix in the last two axes outside the kept triangle.
+///
+/// Row `i` of a lower triangle keeps columns `..=i + k`; of an upper triangle,
+/// columns `i + k..`. Zero is `T::default()`, which is also +0.0 for half bits.
+fn mask_triangle<T: Default>(arr: &mut ArrayD<T>, k: isize, lower: bool) {
+    let ndim = arr.ndim();
+    let rows = arr.shape()[ndim - 2];
+    let cols = arr.shape()[ndim - 1] as isize;
+    for (r, mut row) in arr.lanes_mut(Axis(ndim - 1)).into_iter().enumerate() {
+        let edge = ((r % rows) as isize).saturating_add(k);
+        let zeroed = if lower {
+            edge.saturating_add(1).clamp(0, cols) as usize..cols as usize
+        } else {
+            0..edge.clamp(0, cols) as usize
+        };
+        for j in zeroed {
+            row[j] = T::default();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, Array};
+
+    #[test]
+    fn mask_triangle_handles_offsets_and_batches() {
+        let base = Array::from_iter(1..=12)
+            .into_shape_with_order((2, 2, 3))
+            .unwrap();
+
+        let mut lower = base.clone().into_dyn();
+        mask_triangle(&mut lower, 0, true);
+        let expected = array![[[1, 0, 0], [4, 5, 0]], [[7, 0, 0], [10, 11, 0]]];
+        assert_eq!(lower, expected.into_dyn());
+
+        let mut upper = base.clone().into_dyn();
+        mask_triangle(&mut upper, 1, false);
+        let expected = array![[[0, 2, 3], [0, 0, 6]], [[0, 8, 9], [0, 0, 12]]];
+        assert_eq!(upper, expected.into_dyn());
+
+        let mut none = base.clone().into_dyn();
+        mask_triangle(&mut none, -5, true);
+        assert!(none.iter().all(|&v| v == 0));
+
+        let mut all = base.clone().into_dyn();
+        mask_triangle(&mut all, isize::MAX, true);
+        assert_eq!(all, base.clone().into_dyn());
+
+        let mut none = base.into_dyn();
+        mask_triangle(&mut none, isize::MIN, true);
+        assert!(none.iter().all(|&v| v == 0));
+    }
+}
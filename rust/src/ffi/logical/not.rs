@@ -3,7 +3,7 @@
 //! Works with all input types (converts to bool first).
 //! Always returns a Bool array.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::extract_array_as_bool;
 use crate::helpers::write_output_metadata;
 use crate::types::dtype::DType;
@@ -39,18 +39,18 @@ pub unsafe extern "C" fn ndarray_logical_not(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("logical_not", {
         let a_meta = &*a_meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
         if a_wrapper.dtype == DType::Complex64 || a_wrapper.dtype == DType::Complex128 {
             set_last_error("Logical NOT not supported for complex dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
 
         let Some(arr) = extract_array_as_bool(a_wrapper, a_meta) else {
             set_last_error("Failed to extract view as bool".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let result = arr.mapv(|x| not(&x));
         let result_wrapper = NDArrayWrapper {
@@ -1,11 +1,9 @@
 //! Absolute value operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
-};
+use crate::helpers::{extract_array_c128, extract_array_c64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 
@@ -13,6 +11,10 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute absolute value element-wise.
+///
+/// Real arrays keep their dtype; the absolute value of the most negative
+/// signed integer wraps to itself, as in NumPy. Complex arrays return their
+/// magnitude as Float32 / Float64.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_abs(
     a: *const NdArrayHandle,
@@ -33,147 +35,38 @@ pub unsafe extern "C" fn ndarray_abs(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("abs", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
         let result_wrapper = match a_wrapper.dtype {
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.abs();
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.abs();
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Int64 => {
-                let Some(arr) = extract_array_i64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.abs());
-                NDArrayWrapper {
-                    data: ArrayData::Int64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int64,
-                }
-            }
-            DType::Int32 => {
-                let Some(arr) = extract_array_i32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.abs());
-                NDArrayWrapper {
-                    data: ArrayData::Int32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int32,
-                }
-            }
-            DType::Int16 => {
-                let Some(arr) = extract_array_i16(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.abs());
-                NDArrayWrapper {
-                    data: ArrayData::Int16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int16,
-                }
-            }
-            DType::Int8 => {
-                let Some(arr) = extract_array_i8(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.abs());
-                NDArrayWrapper {
-                    data: ArrayData::Int8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int8,
-                }
-            }
-            DType::Uint64 => {
-                let Some(arr) = extract_array_u64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.to_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint64,
-                }
-            }
-            DType::Uint32 => {
-                let Some(arr) = extract_array_u32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.to_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint32,
-                }
-            }
-            DType::Uint16 => {
-                let Some(arr) = extract_array_u16(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.to_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint16,
-                }
-            }
-            DType::Uint8 => {
-                let Some(arr) = extract_array_u8(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.to_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint8,
-                }
-            }
             DType::Complex64 => {
-                let Some(arr) = crate::helpers::extract_array_c64(a_wrapper, meta) else {
+                let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.norm());
+                let result = parallel::mapv(&arr, |x| x.norm());
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
                 }
             }
             DType::Complex128 => {
-                let Some(arr) = crate::helpers::extract_array_c128(a_wrapper, meta) else {
+                let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.norm());
+                let result = parallel::mapv(&arr, |x| x.norm());
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
                 }
             }
-            DType::Bool => {
-                set_last_error("abs() not supported for Bool type".to_string());
-                return ERR_GENERIC;
-            }
+            _ => crate::unary_op_native!(a_wrapper, meta, "abs",
+                float: |arr| parallel::mapv_into(arr, |x| x.abs()),
+                signed: |arr| parallel::mapv_into(arr, |x| x.wrapping_abs()),
+                unsigned: |arr| arr,
+            ),
         };
 
         if let Err(e) =
@@ -5,10 +5,11 @@
 //! For real arrays: 0.0 for non-negative, π for negative.
 //! For complex arrays: atan2(imag, real).
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_as_f32, extract_array_bool, extract_array_c128, extract_array_c64,
     extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
     extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
@@ -46,7 +47,7 @@ pub unsafe extern "C" fn ndarray_angle(
         1.0
     };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("angle", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -54,9 +55,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = if x >= 0.0 { 0.0 } else { std::f64::consts::PI };
                     angle * scale
                 });
@@ -65,12 +66,12 @@ pub unsafe extern "C" fn ndarray_angle(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(a_wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = if x >= 0.0 { 0.0 } else { std::f64::consts::PI };
                     angle * scale
                 });
@@ -82,9 +83,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(a_wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = if x >= 0 { 0.0 } else { std::f64::consts::PI };
                     angle * scale
                 });
@@ -96,9 +97,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(a_wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = if x >= 0 { 0.0 } else { std::f64::consts::PI };
                     angle * scale
                 });
@@ -110,9 +111,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(a_wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = if x >= 0 { 0.0 } else { std::f64::consts::PI };
                     angle * scale
                 });
@@ -124,9 +125,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(a_wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = if x >= 0 { 0.0 } else { std::f64::consts::PI };
                     angle * scale
                 });
@@ -138,7 +139,7 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(a_wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = ArrayD::zeros(arr.raw_dim());
                 NDArrayWrapper {
@@ -149,7 +150,7 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(a_wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = ArrayD::zeros(arr.raw_dim());
                 NDArrayWrapper {
@@ -160,7 +161,7 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(a_wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = ArrayD::zeros(arr.raw_dim());
                 NDArrayWrapper {
@@ -171,7 +172,7 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(a_wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = ArrayD::zeros(arr.raw_dim());
                 NDArrayWrapper {
@@ -182,7 +183,7 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(a_wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = ArrayD::zeros(arr.raw_dim());
                 NDArrayWrapper {
@@ -193,9 +194,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = x.arg() as f64;
                     angle * scale
                 });
@@ -207,9 +208,9 @@ pub unsafe extern "C" fn ndarray_angle(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| {
+                let result = parallel::mapv(&arr, |x| {
                     let angle = x.arg();
                     angle * scale
                 });
@@ -1,8 +1,9 @@
 //! Arc sine operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::helpers::{extract_array_as_f32, extract_array_f32, extract_array_f64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use parking_lot::RwLock;
@@ -29,7 +30,7 @@ pub unsafe extern "C" fn ndarray_asin(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("asin", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -37,7 +38,7 @@ pub unsafe extern "C" fn ndarray_asin(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.asin();
                 NDArrayWrapper {
@@ -48,7 +49,7 @@ pub unsafe extern "C" fn ndarray_asin(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.asin();
                 NDArrayWrapper {
@@ -56,12 +57,20 @@ pub unsafe extern "C" fn ndarray_asin(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.asin();
+                NDArrayWrapper::from_f32_as_half(result, a_wrapper.dtype)
+            }
             DType::Complex64 => {
                 let Some(arr) = crate::helpers::extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.asin());
+                let result = parallel::mapv(&arr, |x| x.asin());
                 NDArrayWrapper {
                     data: ArrayData::Complex64(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex64,
@@ -70,17 +79,17 @@ pub unsafe extern "C" fn ndarray_asin(
             DType::Complex128 => {
                 let Some(arr) = crate::helpers::extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.asin());
+                let result = parallel::mapv(&arr, |x| x.asin());
                 NDArrayWrapper {
                     data: ArrayData::Complex128(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex128,
                 }
             }
             _ => {
-                set_last_error("asin() requires float type (Float64 or Float32)".to_string());
-                return ERR_GENERIC;
+                set_last_error("asin() requires a float type".to_string());
+                return ERR_DTYPE;
             }
         };
 
@@ -1,8 +1,8 @@
 //! Ceiling operation.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::helpers::{extract_array_as_f32, extract_array_f32, extract_array_f64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use parking_lot::RwLock;
@@ -29,7 +29,7 @@ pub unsafe extern "C" fn ndarray_ceil(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("ceil", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -37,7 +37,7 @@ pub unsafe extern "C" fn ndarray_ceil(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.ceil();
                 NDArrayWrapper {
@@ -48,7 +48,7 @@ pub unsafe extern "C" fn ndarray_ceil(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.ceil();
                 NDArrayWrapper {
@@ -56,13 +56,21 @@ pub unsafe extern "C" fn ndarray_ceil(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.ceil();
+                NDArrayWrapper::from_f32_as_half(result, a_wrapper.dtype)
+            }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("ceil() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
-                set_last_error("ceil() requires float type (Float64 or Float32)".to_string());
-                return ERR_GENERIC;
+                set_last_error("ceil() requires a float type".to_string());
+                return ERR_DTYPE;
             }
         };
 
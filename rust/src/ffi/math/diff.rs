@@ -0,0 +1,148 @@
+//! n-th discrete difference along an axis: `out[i] = a[i + 1] - a[i]`, applied `n` times.
+//!
+//! The dtype is preserved. Integer differences wrap on overflow, Bool arrays
+//! use `a[i + 1] != a[i]` and Float16/BFloat16 are computed in f32.
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_bool, extract_array_c128, extract_array_c64,
+    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
+    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, Axis, Slice, Zip};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Apply `sub(next, prev)` between neighbours along `axis`, `n` times.
+///
+/// Each pass shortens the axis by one; once it is empty, further passes are no-ops.
+fn diff_axis<T: Copy>(arr: ArrayD<T>, n: usize, axis: usize, sub: impl Fn(T, T) -> T) -> ArrayD<T> {
+    let mut current = arr;
+    for _ in 0..n {
+        let len = current.len_of(Axis(axis));
+        if len == 0 {
+            break;
+        }
+        current = Zip::from(current.slice_axis(Axis(axis), Slice::from(1..)))
+            .and(current.slice_axis(Axis(axis), Slice::from(..len - 1)))
+            .map_collect(|&next, &prev| sub(next, prev));
+    }
+    current
+}
+
+/// n-th discrete difference along `axis`.
+///
+/// The output has the input's shape with `axis` shortened by `n` (down to zero).
+/// `n = 0` returns a copy.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_diff(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    n: usize,
+    axis: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("diff", {
+        let meta = &*meta;
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        macro_rules! diff_with {
+            ($extract_fn:ident, $sub:expr) => {{
+                let Some(arr) = $extract_fn(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} array", wrapper.dtype));
+                    return ERR_DTYPE;
+                };
+                diff_axis(arr, n, axis, $sub)
+            }};
+        }
+
+        macro_rules! diff_as {
+            ($variant:ident, $extract_fn:ident, $sub:expr) => {
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(diff_with!($extract_fn, $sub)))),
+                    dtype: DType::$variant,
+                }
+            };
+        }
+
+        let result_wrapper = match wrapper.dtype {
+            DType::Float64 => diff_as!(Float64, extract_array_f64, |b, a| b - a),
+            DType::Float32 => diff_as!(Float32, extract_array_f32, |b, a| b - a),
+            DType::Float16 | DType::BFloat16 => NDArrayWrapper::from_f32_as_half(
+                diff_with!(extract_array_as_f32, |b: f32, a| b - a),
+                wrapper.dtype,
+            ),
+            DType::Complex64 => diff_as!(Complex64, extract_array_c64, |b, a| b - a),
+            DType::Complex128 => diff_as!(Complex128, extract_array_c128, |b, a| b - a),
+            DType::Int64 => diff_as!(Int64, extract_array_i64, i64::wrapping_sub),
+            DType::Int32 => diff_as!(Int32, extract_array_i32, i32::wrapping_sub),
+            DType::Int16 => diff_as!(Int16, extract_array_i16, i16::wrapping_sub),
+            DType::Int8 => diff_as!(Int8, extract_array_i8, i8::wrapping_sub),
+            DType::Uint64 => diff_as!(Uint64, extract_array_u64, u64::wrapping_sub),
+            DType::Uint32 => diff_as!(Uint32, extract_array_u32, u32::wrapping_sub),
+            DType::Uint16 => diff_as!(Uint16, extract_array_u16, u16::wrapping_sub),
+            DType::Uint8 => diff_as!(Uint8, extract_array_u8, u8::wrapping_sub),
+            DType::Bool => diff_as!(Bool, extract_array_bool, |b: u8, a: u8| {
+                u8::from((b != 0) != (a != 0))
+            }),
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, IxDyn};
+
+    #[test]
+    fn repeated_differences_shorten_the_axis() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[5]), vec![1i64, 2, 4, 7, 0]).unwrap();
+        let d1 = diff_axis(a.clone(), 1, 0, |b, a| b - a);
+        assert_eq!(d1.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, -7]);
+        let d2 = diff_axis(a.clone(), 2, 0, |b, a| b - a);
+        assert_eq!(d2.iter().copied().collect::<Vec<_>>(), vec![1, 1, -10]);
+        assert_eq!(diff_axis(a, 7, 0, |b, a| b - a).shape(), &[0]);
+    }
+
+    #[test]
+    fn difference_along_inner_axis() {
+        let a = array![[1.0, 3.0, 6.0], [0.0, 5.0, 5.0]].into_dyn();
+        let d = diff_axis(a, 1, 1, |b, a| b - a);
+        assert_eq!(d, array![[2.0, 3.0], [5.0, 0.0]].into_dyn());
+    }
+}
@@ -0,0 +1,180 @@
+//! Numerical gradient along an axis with uniform sample spacing.
+//!
+//! Interior points use second-order central differences; the two boundary
+//! points use first- or second-order one-sided differences (`edge_order`).
+//! Float32 input yields Float32, Float16/BFloat16 keep their dtype and every
+//! other real dtype yields Float64.
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{extract_array_as_f32, extract_array_as_f64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, Axis, Zip};
+use num_traits::Float;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Gradient of every lane along `axis`; each lane must hold `edge_order + 1` or more samples.
+fn gradient_axis<T: Float>(arr: &ArrayD<T>, axis: usize, spacing: T, edge_order: u8) -> ArrayD<T> {
+    let two = T::one() + T::one();
+    let three = two + T::one();
+    let four = two + two;
+    let mut result = ArrayD::zeros(arr.raw_dim());
+
+    Zip::from(arr.lanes(Axis(axis)))
+        .and(result.lanes_mut(Axis(axis)))
+        .for_each(|f, mut g| {
+            let n = f.len();
+            for i in 1..n - 1 {
+                g[i] = (f[i + 1] - f[i - 1]) / (two * spacing);
+            }
+            if edge_order == 1 {
+                g[0] = (f[1] - f[0]) / spacing;
+                g[n - 1] = (f[n - 1] - f[n - 2]) / spacing;
+            } else {
+                g[0] = (-three * f[0] + four * f[1] - f[2]) / (two * spacing);
+                g[n - 1] = (three * f[n - 1] - four * f[n - 2] + f[n - 3]) / (two * spacing);
+            }
+        });
+    result
+}
+
+/// Gradient along `axis` with sample distance `spacing`.
+///
+/// `edge_order` (1 or 2) selects the accuracy of the boundary differences. The
+/// axis must hold at least `edge_order + 1` samples. The output has the input's
+/// shape. Complex arrays return `ERR_DTYPE`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_gradient(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    spacing: f64,
+    edge_order: u8,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("gradient", {
+        let meta = &*meta;
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        if wrapper.dtype.is_complex() {
+            set_last_error(format!(
+                "gradient is not supported for {} arrays",
+                wrapper.dtype
+            ));
+            return ERR_DTYPE;
+        }
+        if edge_order != 1 && edge_order != 2 {
+            set_last_error(format!("edge_order must be 1 or 2, got {}", edge_order));
+            return ERR_VALUE;
+        }
+        if spacing == 0.0 || !spacing.is_finite() {
+            set_last_error("gradient spacing must be finite and non-zero".to_string());
+            return ERR_VALUE;
+        }
+
+        let shape = meta.shape_slice();
+        let axis = match normalize_axis(shape, axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+        if shape[axis] < edge_order as usize + 1 {
+            set_last_error(format!(
+                "gradient with edge_order={} needs at least {} samples along axis {}, got {}",
+                edge_order,
+                edge_order + 1,
+                axis,
+                shape[axis]
+            ));
+            return ERR_SHAPE;
+        }
+
+        let result_wrapper = match wrapper.dtype {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = gradient_axis(&arr, axis, spacing as f32, edge_order);
+                if wrapper.dtype == DType::Float32 {
+                    NDArrayWrapper {
+                        data: ArrayData::Float32(Arc::new(RwLock::new(result))),
+                        dtype: DType::Float32,
+                    }
+                } else {
+                    NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+                }
+            }
+            _ => {
+                let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+                    set_last_error("Failed to extract f64 view".to_string());
+                    return ERR_DTYPE;
+                };
+                NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(gradient_axis(
+                        &arr, axis, spacing, edge_order,
+                    )))),
+                    dtype: DType::Float64,
+                }
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, IxDyn};
+
+    #[test]
+    fn matches_numpy_edge_orders() {
+        let f = ArrayD::from_shape_vec(IxDyn(&[6]), vec![1.0, 2.0, 4.0, 7.0, 11.0, 16.0]).unwrap();
+        let g1 = gradient_axis(&f, 0, 1.0, 1);
+        assert_eq!(
+            g1.iter().copied().collect::<Vec<_>>(),
+            vec![1.0, 1.5, 2.5, 3.5, 4.5, 5.0]
+        );
+        let g2 = gradient_axis(&f, 0, 2.0, 2);
+        assert_eq!(
+            g2.iter().copied().collect::<Vec<_>>(),
+            vec![0.25, 0.75, 1.25, 1.75, 2.25, 2.75]
+        );
+    }
+
+    #[test]
+    fn gradient_along_rows() {
+        let f = array![[1.0, 2.0, 6.0], [3.0, 4.0, 5.0]].into_dyn();
+        let g = gradient_axis(&f, 0, 1.0, 1);
+        assert_eq!(g, array![[2.0, 2.0, -1.0], [2.0, 2.0, -1.0]].into_dyn());
+    }
+}
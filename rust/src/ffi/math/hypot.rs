@@ -4,9 +4,10 @@
 //! For complex inputs, uses `|a|` (i.e. `re.hypot(im)` from `num_complex::Complex::norm`)
 //! and returns `hypot(|a|, b)` as a real array (Float32 / Float64).
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::helpers::{extract_array_as_f32, extract_array_f32, extract_array_f64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use parking_lot::RwLock;
@@ -34,7 +35,7 @@ pub unsafe extern "C" fn ndarray_hypot(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("hypot", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -42,7 +43,7 @@ pub unsafe extern "C" fn ndarray_hypot(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.hypot(b);
                 NDArrayWrapper {
@@ -53,7 +54,7 @@ pub unsafe extern "C" fn ndarray_hypot(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.hypot(b as f32);
                 NDArrayWrapper {
@@ -61,13 +62,21 @@ pub unsafe extern "C" fn ndarray_hypot(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.hypot(b as f32);
+                NDArrayWrapper::from_f32_as_half(result, a_wrapper.dtype)
+            }
             DType::Complex64 => {
                 let Some(arr) = crate::helpers::extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let bb = b as f32;
-                let result = arr.mapv(|z| z.norm().hypot(bb));
+                let result = parallel::mapv(&arr, |z| z.norm().hypot(bb));
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
@@ -76,9 +85,9 @@ pub unsafe extern "C" fn ndarray_hypot(
             DType::Complex128 => {
                 let Some(arr) = crate::helpers::extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|z| z.norm().hypot(b));
+                let result = parallel::mapv(&arr, |z| z.norm().hypot(b));
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
@@ -86,7 +95,7 @@ pub unsafe extern "C" fn ndarray_hypot(
             }
             _ => {
                 set_last_error("hypot() requires float or complex type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -3,7 +3,8 @@
 //! For complex arrays, returns the imaginary component as float.
 //! For real arrays, returns zeros with the same dtype.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{extract_array_c128, extract_array_c64};
 use crate::types::dtype::DType;
@@ -33,7 +34,7 @@ pub unsafe extern "C" fn ndarray_imag(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("imag", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -54,6 +55,10 @@ pub unsafe extern "C" fn ndarray_imag(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => NDArrayWrapper::from_half_bits(
+                ArrayD::zeros(IxDyn(meta.shape_slice())),
+                a_wrapper.dtype,
+            ),
             DType::Int64 => {
                 let shape = IxDyn(meta.shape_slice());
                 let result = ArrayD::zeros(shape);
@@ -129,9 +134,9 @@ pub unsafe extern "C" fn ndarray_imag(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.im);
+                let result = parallel::mapv(&arr, |x| x.im);
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
@@ -140,9 +145,9 @@ pub unsafe extern "C" fn ndarray_imag(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.im);
+                let result = parallel::mapv(&arr, |x| x.im);
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
@@ -0,0 +1,164 @@
+//! One-dimensional piecewise-linear interpolation, as `numpy.interp`.
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::extract_array_as_f64;
+use crate::helpers::parallel::mapv;
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Interpolate `x` on the samples `(xp, fp)`; `xp` must be non-decreasing.
+///
+/// Queries below `xp[0]` return `left`, queries above the last sample return
+/// `right` and NaN queries return NaN.
+fn interp_point(x: f64, xp: &[f64], fp: &[f64], left: f64, right: f64) -> f64 {
+    let last = xp.len() - 1;
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x < xp[0] {
+        return left;
+    }
+    if x > xp[last] {
+        return right;
+    }
+    // Largest j with xp[j] <= x.
+    let j = xp.partition_point(|&v| v <= x) - 1;
+    if j == last || xp[j] == x {
+        return fp[j];
+    }
+    let t = (x - xp[j]) / (xp[j + 1] - xp[j]);
+    fp[j] + t * (fp[j + 1] - fp[j])
+}
+
+/// Linear interpolation of the query points `x` against samples `(xp, fp)`.
+///
+/// `xp` and `fp` must be non-empty 1D arrays of equal length, with `xp`
+/// non-decreasing. Queries outside `[xp[0], xp[-1]]` take `left`/`right` when
+/// `has_left`/`has_right` are set and `fp[0]`/`fp[-1]` otherwise. The output
+/// is Float64 with the shape of `x`. Complex operands return `ERR_DTYPE`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_interp(
+    x: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    xp: *const NdArrayHandle,
+    xp_meta: *const ArrayMetadata,
+    fp: *const NdArrayHandle,
+    fp_meta: *const ArrayMetadata,
+    has_left: bool,
+    left: f64,
+    has_right: bool,
+    right: f64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if x.is_null()
+        || x_meta.is_null()
+        || xp.is_null()
+        || xp_meta.is_null()
+        || fp.is_null()
+        || fp_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("interp", {
+        let (x_meta, xp_meta, fp_meta) = (&*x_meta, &*xp_meta, &*fp_meta);
+        let x_wrapper = NdArrayHandle::as_wrapper(x as *mut _);
+        let xp_wrapper = NdArrayHandle::as_wrapper(xp as *mut _);
+        let fp_wrapper = NdArrayHandle::as_wrapper(fp as *mut _);
+
+        for wrapper in [x_wrapper, xp_wrapper, fp_wrapper] {
+            if wrapper.dtype.is_complex() {
+                set_last_error(format!(
+                    "interp is not supported for {} arrays",
+                    wrapper.dtype
+                ));
+                return ERR_DTYPE;
+            }
+        }
+
+        let (xp_shape, fp_shape) = (xp_meta.shape_slice(), fp_meta.shape_slice());
+        if xp_shape.len() != 1 || xp_shape != fp_shape || xp_shape[0] == 0 {
+            set_last_error(format!(
+                "interp: xp and fp must be non-empty 1D arrays of equal length, got {:?} and {:?}",
+                xp_shape, fp_shape
+            ));
+            return ERR_SHAPE;
+        }
+
+        let (Some(queries), Some(xp_arr), Some(fp_arr)) = (
+            extract_array_as_f64(x_wrapper, x_meta),
+            extract_array_as_f64(xp_wrapper, xp_meta),
+            extract_array_as_f64(fp_wrapper, fp_meta),
+        ) else {
+            set_last_error("Failed to extract f64 views".to_string());
+            return ERR_DTYPE;
+        };
+        let xp_vals: Vec<f64> = xp_arr.iter().copied().collect();
+        let fp_vals: Vec<f64> = fp_arr.iter().copied().collect();
+        if !xp_vals.windows(2).all(|w| w[0] <= w[1]) {
+            set_last_error("interp: xp must be increasing and free of NaN".to_string());
+            return ERR_VALUE;
+        }
+
+        let left = if has_left { left } else { fp_vals[0] };
+        let right = if has_right {
+            right
+        } else {
+            fp_vals[fp_vals.len() - 1]
+        };
+        let result = mapv(&queries, |q| {
+            interp_point(q, &xp_vals, &fp_vals, left, right)
+        });
+        let result_wrapper = NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+            dtype: DType::Float64,
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_numpy_interp() {
+        let xp = [1.0, 2.0, 3.0];
+        let fp = [3.0, 2.0, 0.0];
+        let at = |x| interp_point(x, &xp, &fp, 3.0, 0.0);
+        assert_eq!(at(2.5), 1.0);
+        assert_eq!(at(0.0), 3.0);
+        assert_eq!(at(1.5), 2.5);
+        assert_eq!(at(3.0), 0.0);
+        assert_eq!(at(7.0), 0.0);
+        assert_eq!(interp_point(3.5, &xp, &fp, -99.0, 99.0), 99.0);
+        assert!(at(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn single_sample_is_constant() {
+        assert_eq!(interp_point(5.0, &[5.0], &[2.0], 0.0, 1.0), 2.0);
+        assert_eq!(interp_point(6.0, &[5.0], &[2.0], 0.0, 1.0), 1.0);
+    }
+}
@@ -3,7 +3,8 @@
 //! iscomplex: Returns true where imaginary part is non-zero.
 //! isreal:   Returns true where imaginary part is zero (or for real dtypes).
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{extract_array_c128, extract_array_c64};
 use crate::types::dtype::DType;
@@ -33,7 +34,7 @@ pub unsafe extern "C" fn ndarray_iscomplex(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("iscomplex", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -41,9 +42,9 @@ pub unsafe extern "C" fn ndarray_iscomplex(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| if x.im != 0.0 { 1u8 } else { 0u8 });
+                let result = parallel::mapv(&arr, |x| if x.im != 0.0 { 1u8 } else { 0u8 });
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(result))),
                     dtype: DType::Bool,
@@ -52,9 +53,9 @@ pub unsafe extern "C" fn ndarray_iscomplex(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| if x.im != 0.0 { 1u8 } else { 0u8 });
+                let result = parallel::mapv(&arr, |x| if x.im != 0.0 { 1u8 } else { 0u8 });
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(result))),
                     dtype: DType::Bool,
@@ -103,7 +104,7 @@ pub unsafe extern "C" fn ndarray_isreal(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("isreal", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -111,9 +112,9 @@ pub unsafe extern "C" fn ndarray_isreal(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| if x.im == 0.0 { 1u8 } else { 0u8 });
+                let result = parallel::mapv(&arr, |x| if x.im == 0.0 { 1u8 } else { 0u8 });
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(result))),
                     dtype: DType::Bool,
@@ -122,9 +123,9 @@ pub unsafe extern "C" fn ndarray_isreal(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| if x.im == 0.0 { 1u8 } else { 0u8 });
+                let result = parallel::mapv(&arr, |x| if x.im == 0.0 { 1u8 } else { 0u8 });
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(result))),
                     dtype: DType::Bool,
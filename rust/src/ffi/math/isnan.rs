@@ -0,0 +1,188 @@
+//! Floating-point classification predicates: isnan, isinf, isfinite.
+//!
+//! Complex values are NaN (infinite) if either part is, and finite only if
+//! both parts are. Integer and Bool arrays are always finite.
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f64,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FloatClass {
+    Nan,
+    Inf,
+    Finite,
+}
+
+impl FloatClass {
+    #[inline(always)]
+    fn test(self, x: f64) -> bool {
+        match self {
+            FloatClass::Nan => x.is_nan(),
+            FloatClass::Inf => x.is_infinite(),
+            FloatClass::Finite => x.is_finite(),
+        }
+    }
+
+    #[inline(always)]
+    fn test_complex(self, re: f64, im: f64) -> bool {
+        match self {
+            FloatClass::Finite => re.is_finite() && im.is_finite(),
+            _ => self.test(re) || self.test(im),
+        }
+    }
+}
+
+/// Bool mask of `class` over the array, or `None` if the view can't be extracted.
+unsafe fn classify(
+    class: FloatClass,
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Option<ArrayD<u8>> {
+    Some(match wrapper.dtype {
+        DType::Float64 => {
+            parallel::mapv(&extract_array_f64(wrapper, meta)?, |x| class.test(x) as u8)
+        }
+        DType::Float32 | DType::Float16 | DType::BFloat16 => {
+            parallel::mapv(&extract_array_as_f32(wrapper, meta)?, |x| {
+                class.test(x as f64) as u8
+            })
+        }
+        DType::Complex64 => parallel::mapv(&extract_array_c64(wrapper, meta)?, |x| {
+            class.test_complex(x.re as f64, x.im as f64) as u8
+        }),
+        DType::Complex128 => parallel::mapv(&extract_array_c128(wrapper, meta)?, |x| {
+            class.test_complex(x.re, x.im) as u8
+        }),
+        _ => ArrayD::from_elem(
+            IxDyn(meta.shape_slice()),
+            (class == FloatClass::Finite) as u8,
+        ),
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn classify_entry(
+    name: &'static str,
+    class: FloatClass,
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || meta.is_null()
+        || out.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let meta = &*meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+
+        let Some(mask) = classify(class, a_wrapper, meta) else {
+            set_last_error(format!("Failed to extract {} view", a_wrapper.dtype));
+            return ERR_DTYPE;
+        };
+        let result_wrapper = NDArrayWrapper {
+            data: ArrayData::Bool(Arc::new(RwLock::new(mask))),
+            dtype: DType::Bool,
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Returns bool array: true where element is NaN.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_isnan(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    classify_entry(
+        "isnan",
+        FloatClass::Nan,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Returns bool array: true where element is positive or negative infinity.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_isinf(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    classify_entry(
+        "isinf",
+        FloatClass::Inf,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Returns bool array: true where element is neither NaN nor infinite.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_isfinite(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    classify_entry(
+        "isfinite",
+        FloatClass::Finite,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
@@ -1,8 +1,8 @@
 //! Natural logarithm of 1+x operation (ln_1p).
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::helpers::{extract_array_as_f32, extract_array_f32, extract_array_f64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use parking_lot::RwLock;
@@ -31,7 +31,7 @@ pub unsafe extern "C" fn ndarray_ln_1p(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("ln_1p", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -39,7 +39,7 @@ pub unsafe extern "C" fn ndarray_ln_1p(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.ln_1p();
                 NDArrayWrapper {
@@ -50,7 +50,7 @@ pub unsafe extern "C" fn ndarray_ln_1p(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.ln_1p();
                 NDArrayWrapper {
@@ -58,13 +58,21 @@ pub unsafe extern "C" fn ndarray_ln_1p(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.ln_1p();
+                NDArrayWrapper::from_f32_as_half(result, a_wrapper.dtype)
+            }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("ln_1p() not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
-                set_last_error("ln_1p() requires float type (Float64 or Float32)".to_string());
-                return ERR_GENERIC;
+                set_last_error("ln_1p() requires a float type".to_string());
+                return ERR_DTYPE;
             }
         };
 
@@ -42,10 +42,17 @@ pub mod round;
 // Absolute value and sign
 pub mod abs;
 pub mod neg;
-pub mod sigmoid;
 pub mod signum;
 pub mod softmax;
 
+// Discrete differences and interpolation
+pub mod diff;
+pub mod gradient;
+pub mod interp;
+
+// Floating-point classification
+pub mod isnan;
+
 // Complex number operations
 pub mod angle;
 pub mod conjugate;
@@ -64,12 +71,16 @@ pub use ceil::*;
 pub use conjugate::*;
 pub use cos::*;
 pub use cosh::*;
+pub use diff::*;
 pub use exp::*;
 pub use exp2::*;
 pub use floor::*;
+pub use gradient::*;
 pub use hypot::*;
 pub use imag::*;
+pub use interp::*;
 pub use iscomplex::*;
+pub use isnan::*;
 pub use ln::*;
 pub use ln_1p::*;
 pub use log::*;
@@ -82,7 +93,6 @@ pub use powi::*;
 pub use real::*;
 pub use recip::*;
 pub use round::*;
-pub use sigmoid::*;
 pub use signum::*;
 pub use sin::*;
 pub use sinh::*;
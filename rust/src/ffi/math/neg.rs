@@ -2,14 +2,10 @@
 //!
 //! Not supported for unsigned integers (negating unsigned is undefined).
 
-use std::ops::Neg;
-
 use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8,
-};
+use crate::helpers::{extract_array_c128, extract_array_c64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use parking_lot::RwLock;
@@ -17,6 +13,8 @@ use std::sync::Arc;
 
 /// Compute negation element-wise.
 /// Not supported for unsigned integers or bool.
+///
+/// Signed integers wrap, so the most negative value negates to itself.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_neg(
     a: *const NdArrayHandle,
@@ -37,103 +35,37 @@ pub unsafe extern "C" fn ndarray_neg(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("neg", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
         let result_wrapper = match a_wrapper.dtype {
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.neg();
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.neg();
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Int64 => {
-                let Some(arr) = extract_array_i64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.neg();
-                NDArrayWrapper {
-                    data: ArrayData::Int64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int64,
-                }
-            }
-            DType::Int32 => {
-                let Some(arr) = extract_array_i32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.neg();
-                NDArrayWrapper {
-                    data: ArrayData::Int32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int32,
-                }
-            }
-            DType::Int16 => {
-                let Some(arr) = extract_array_i16(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.neg();
-                NDArrayWrapper {
-                    data: ArrayData::Int16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int16,
-                }
-            }
-            DType::Int8 => {
-                let Some(arr) = extract_array_i8(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.neg();
-                NDArrayWrapper {
-                    data: ArrayData::Int8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int8,
-                }
-            }
             DType::Complex64 => {
-                let Some(arr) = crate::helpers::extract_array_c64(a_wrapper, meta) else {
+                let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| -x);
+                let result = parallel::mapv(&arr, |x| -x);
                 NDArrayWrapper {
                     data: ArrayData::Complex64(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex64,
                 }
             }
             DType::Complex128 => {
-                let Some(arr) = crate::helpers::extract_array_c128(a_wrapper, meta) else {
+                let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| -x);
+                let result = parallel::mapv(&arr, |x| -x);
                 NDArrayWrapper {
                     data: ArrayData::Complex128(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex128,
                 }
             }
-            DType::Uint64 | DType::Uint32 | DType::Uint16 | DType::Uint8 | DType::Bool => {
-                set_last_error("Negation not supported for unsigned integers or bool".to_string());
-                return ERR_DTYPE;
-            }
+            _ => crate::unary_op_native!(a_wrapper, meta, "neg",
+                float: |arr| parallel::mapv_into(arr, |x| -x),
+                signed: |arr| parallel::mapv_into(arr, |x| x.wrapping_neg()),
+            ),
         };
 
         if let Err(e) =
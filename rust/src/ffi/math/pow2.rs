@@ -2,11 +2,13 @@
 //!
 //! Complex arrays use `num_complex::Complex::powu(2)` (same as integer power with exponent 2).
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f32, extract_array_f64, extract_array_i16,
+    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
+    extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -34,7 +36,7 @@ pub unsafe extern "C" fn ndarray_pow2(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("pow2", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -42,7 +44,7 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.pow2();
                 NDArrayWrapper {
@@ -53,7 +55,7 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.pow2();
                 NDArrayWrapper {
@@ -61,12 +63,20 @@ pub unsafe extern "C" fn ndarray_pow2(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(a_wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.pow2();
+                NDArrayWrapper::from_f32_as_half(result, a_wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(a_wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Int64(Arc::new(RwLock::new(result))),
                     dtype: DType::Int64,
@@ -75,9 +85,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(a_wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Int32(Arc::new(RwLock::new(result))),
                     dtype: DType::Int32,
@@ -86,9 +96,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(a_wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Int16(Arc::new(RwLock::new(result))),
                     dtype: DType::Int16,
@@ -97,9 +107,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(a_wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Int8(Arc::new(RwLock::new(result))),
                     dtype: DType::Int8,
@@ -108,9 +118,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(a_wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Uint64(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint64,
@@ -119,9 +129,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(a_wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Uint32(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint32,
@@ -130,9 +140,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(a_wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Uint16(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint16,
@@ -141,9 +151,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(a_wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x * x);
+                let result = parallel::mapv(&arr, |x| x * x);
                 NDArrayWrapper {
                     data: ArrayData::Uint8(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint8,
@@ -152,9 +162,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Complex64 => {
                 let Some(arr) = crate::helpers::extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.powu(2));
+                let result = parallel::mapv(&arr, |x| x.powu(2));
                 NDArrayWrapper {
                     data: ArrayData::Complex64(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex64,
@@ -163,9 +173,9 @@ pub unsafe extern "C" fn ndarray_pow2(
             DType::Complex128 => {
                 let Some(arr) = crate::helpers::extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.powu(2));
+                let result = parallel::mapv(&arr, |x| x.powu(2));
                 NDArrayWrapper {
                     data: ArrayData::Complex128(Arc::new(RwLock::new(result))),
                     dtype: DType::Complex128,
@@ -173,7 +183,7 @@ pub unsafe extern "C" fn ndarray_pow2(
             }
             DType::Bool => {
                 set_last_error("pow2() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -3,12 +3,14 @@
 //! For complex arrays, returns the real component as float.
 //! For real arrays, returns a copy with the same dtype.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -36,7 +38,7 @@ pub unsafe extern "C" fn ndarray_real(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("real", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
@@ -44,7 +46,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(a_wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -55,7 +57,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(a_wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -63,10 +65,17 @@ pub unsafe extern "C" fn ndarray_real(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(a_wrapper, meta) else {
+                    set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                NDArrayWrapper::from_half_bits(arr, a_wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(a_wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -77,7 +86,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(a_wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -88,7 +97,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(a_wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -99,7 +108,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(a_wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -110,7 +119,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(a_wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -121,7 +130,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(a_wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -132,7 +141,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(a_wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -143,7 +152,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(a_wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -154,7 +163,7 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(a_wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.to_owned();
                 NDArrayWrapper {
@@ -165,9 +174,9 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.re);
+                let result = parallel::mapv(&arr, |x| x.re);
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
@@ -176,9 +185,9 @@ pub unsafe extern "C" fn ndarray_real(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(a_wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.mapv(|x| x.re);
+                let result = parallel::mapv(&arr, |x| x.re);
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
@@ -1,78 +0,0 @@
-//! Sigmoid operation: 1 / (1 + exp(-x)).
-
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
-use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
-use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
-use parking_lot::RwLock;
-use std::sync::Arc;
-
-/// Compute sigmoid element-wise: 1 / (1 + exp(-x)).
-#[no_mangle]
-pub unsafe extern "C" fn ndarray_sigmoid(
-    a: *const NdArrayHandle,
-    meta: *const ArrayMetadata,
-    out: *mut *mut NdArrayHandle,
-    out_dtype: *mut u8,
-    out_ndim: *mut usize,
-    out_shape: *mut usize,
-    max_ndim: usize,
-) -> i32 {
-    if a.is_null()
-        || meta.is_null()
-        || out.is_null()
-        || out_dtype.is_null()
-        || out_ndim.is_null()
-        || out_shape.is_null()
-    {
-        return ERR_GENERIC;
-    }
-
-    crate::ffi_guard!({
-        let meta = &*meta;
-        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
-
-        let result_wrapper = match a_wrapper.dtype {
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x: f64| 1.0 / (1.0 + (-x).exp()));
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x: f32| 1.0_f32 / (1.0_f32 + (-x).exp()));
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Complex64 | DType::Complex128 => {
-                set_last_error("Operation sigmoid not supported for complex dtype".to_string());
-                return ERR_GENERIC;
-            }
-            _ => {
-                set_last_error("sigmoid() requires float type (Float64 or Float32)".to_string());
-                return ERR_GENERIC;
-            }
-        };
-
-        if let Err(e) =
-            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
-        {
-            set_last_error(e);
-            return ERR_GENERIC;
-        }
-        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
-        SUCCESS
-    })
-}
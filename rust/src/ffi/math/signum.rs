@@ -1,18 +1,15 @@
 //! The sign number of each element.
 
 use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
-};
-use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
-
-use parking_lot::RwLock;
-use std::sync::Arc;
+use crate::types::ArrayMetadata;
+use crate::types::NdArrayHandle;
 
 /// Compute the sign number of each element.
+///
+/// The result keeps the input dtype: -1, 0 or 1 for integers (0 or 1 for
+/// unsigned), and NaN stays NaN for floats.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_signum(
     a: *const NdArrayHandle,
@@ -33,130 +30,15 @@ pub unsafe extern "C" fn ndarray_signum(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("signum", {
         let meta = &*meta;
         let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
 
-        let result_wrapper = match a_wrapper.dtype {
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.signum();
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.signum();
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Int64 => {
-                let Some(arr) = extract_array_i64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.signum());
-                NDArrayWrapper {
-                    data: ArrayData::Int64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int64,
-                }
-            }
-            DType::Int32 => {
-                let Some(arr) = extract_array_i32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.signum());
-                NDArrayWrapper {
-                    data: ArrayData::Int32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int32,
-                }
-            }
-            DType::Int16 => {
-                let Some(arr) = extract_array_i16(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.signum());
-                NDArrayWrapper {
-                    data: ArrayData::Int16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int16,
-                }
-            }
-            DType::Int8 => {
-                let Some(arr) = extract_array_i8(a_wrapper, meta) else {
-                    set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| x.signum());
-                NDArrayWrapper {
-                    data: ArrayData::Int8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int8,
-                }
-            }
-            DType::Uint64 => {
-                let Some(arr) = extract_array_u64(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| if x == 0 { 0 } else { 1 });
-                NDArrayWrapper {
-                    data: ArrayData::Uint64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint64,
-                }
-            }
-            DType::Uint32 => {
-                let Some(arr) = extract_array_u32(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| if x == 0 { 0 } else { 1 });
-                NDArrayWrapper {
-                    data: ArrayData::Uint32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint32,
-                }
-            }
-            DType::Uint16 => {
-                let Some(arr) = extract_array_u16(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| if x == 0 { 0 } else { 1 });
-                NDArrayWrapper {
-                    data: ArrayData::Uint16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint16,
-                }
-            }
-            DType::Uint8 => {
-                let Some(arr) = extract_array_u8(a_wrapper, meta) else {
-                    set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.mapv(|x| if x == 0 { 0 } else { 1 });
-                NDArrayWrapper {
-                    data: ArrayData::Uint8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint8,
-                }
-            }
-            DType::Complex64 | DType::Complex128 => {
-                set_last_error("Operation signum not supported for complex dtype".to_string());
-                return ERR_GENERIC;
-            }
-            DType::Bool => {
-                set_last_error("signum() not supported for Bool type".to_string());
-                return ERR_GENERIC;
-            }
-        };
+        let result_wrapper = crate::unary_op_native!(a_wrapper, meta, "signum",
+            float: |arr| parallel::mapv_into(arr, |x| x.signum()),
+            signed: |arr| parallel::mapv_into(arr, |x| x.signum()),
+            unsigned: |arr| parallel::mapv_into(arr, |x| if x == 0 { 0 } else { 1 }),
+        );
 
         if let Err(e) =
             write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
@@ -2,10 +2,10 @@
 //!
 //! Numerically stable implementation. Float-only. Output shape equals input shape.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{extract_array_f32, extract_array_f64};
+use crate::helpers::{extract_array_as_f32, extract_array_f32, extract_array_f64};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use ndarray::Axis;
@@ -34,7 +34,7 @@ pub unsafe extern "C" fn ndarray_softmax(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("softmax", {
         let meta = &*meta;
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
@@ -50,7 +50,7 @@ pub unsafe extern "C" fn ndarray_softmax(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 for (in_lane, mut out_lane) in arr
@@ -79,7 +79,7 @@ pub unsafe extern "C" fn ndarray_softmax(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 for (in_lane, mut out_lane) in arr
@@ -105,13 +105,39 @@ pub unsafe extern "C" fn ndarray_softmax(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let mut result = arr.to_owned();
+                for (in_lane, mut out_lane) in arr
+                    .lanes(Axis(axis_usize))
+                    .into_iter()
+                    .zip(result.lanes_mut(Axis(axis_usize)))
+                {
+                    let max = in_lane.iter().fold(f32::NEG_INFINITY, |a, &b| a.max(b));
+                    let sum_exp: f32 = in_lane.iter().map(|&x| (x - max).exp()).sum();
+                    if sum_exp > 0.0 && sum_exp.is_finite() {
+                        for (i, &x) in in_lane.iter().enumerate() {
+                            out_lane[i] = (x - max).exp() / sum_exp;
+                        }
+                    } else {
+                        let n = in_lane.len() as f32;
+                        for i in 0..in_lane.len() {
+                            out_lane[i] = 1.0_f32 / n;
+                        }
+                    }
+                }
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("Operation softmax not supported for complex dtype".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {
-                set_last_error("softmax() requires float type (Float64 or Float32)".to_string());
-                return ERR_GENERIC;
+                set_last_error("softmax() requires a float type".to_string());
+                return ERR_DTYPE;
             }
         };
 
@@ -2,14 +2,15 @@
 //!
 //! Provides astype() functionality to copy arrays with type conversion.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::{
     extract_array_as_bool, extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
-    extract_array_as_f64, extract_array_as_i16, extract_array_as_i32, extract_array_as_i64,
-    extract_array_as_i8, extract_array_as_u16, extract_array_as_u32, extract_array_as_u64,
-    extract_array_as_u8, extract_array_bool, extract_array_c128, extract_array_c64,
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f64, extract_array_as_half, extract_array_as_i16, extract_array_as_i32,
+    extract_array_as_i64, extract_array_as_i8, extract_array_as_u16, extract_array_as_u32,
+    extract_array_as_u64, extract_array_as_u8, extract_array_bool, extract_array_c128,
+    extract_array_c64, extract_array_f32, extract_array_f64, extract_array_half_bits,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -29,7 +30,7 @@ pub unsafe extern "C" fn ndarray_astype(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("astype", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -37,7 +38,7 @@ pub unsafe extern "C" fn ndarray_astype(
             Some(dt) => dt,
             None => {
                 set_last_error(format!("Invalid target dtype: {}", target_dtype));
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -46,7 +47,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Float64 => {
                     let Some(arr) = extract_array_f64(wrapper, meta) else {
                         set_last_error("Failed to extract Float64 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Float64(Arc::new(RwLock::new(arr.to_owned()))),
@@ -56,17 +57,24 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Float32 => {
                     let Some(arr) = extract_array_f32(wrapper, meta) else {
                         set_last_error("Failed to extract Float32 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Float32(Arc::new(RwLock::new(arr.to_owned()))),
                         dtype: DType::Float32,
                     }
                 }
+                DType::Float16 | DType::BFloat16 => {
+                    let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                        set_last_error(format!("Failed to extract {} view", wrapper.dtype.name()));
+                        return ERR_DTYPE;
+                    };
+                    NDArrayWrapper::from_half_bits(arr, wrapper.dtype)
+                }
                 DType::Int64 => {
                     let Some(arr) = extract_array_i64(wrapper, meta) else {
                         set_last_error("Failed to extract Int64 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int64(Arc::new(RwLock::new(arr.to_owned()))),
@@ -76,7 +84,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Int32 => {
                     let Some(arr) = extract_array_i32(wrapper, meta) else {
                         set_last_error("Failed to extract Int32 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int32(Arc::new(RwLock::new(arr.to_owned()))),
@@ -86,7 +94,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Int16 => {
                     let Some(arr) = extract_array_i16(wrapper, meta) else {
                         set_last_error("Failed to extract Int16 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int16(Arc::new(RwLock::new(arr.to_owned()))),
@@ -96,7 +104,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Int8 => {
                     let Some(arr) = extract_array_i8(wrapper, meta) else {
                         set_last_error("Failed to extract Int8 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int8(Arc::new(RwLock::new(arr.to_owned()))),
@@ -106,7 +114,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint64 => {
                     let Some(arr) = extract_array_u64(wrapper, meta) else {
                         set_last_error("Failed to extract Uint64 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint64(Arc::new(RwLock::new(arr.to_owned()))),
@@ -116,7 +124,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint32 => {
                     let Some(arr) = extract_array_u32(wrapper, meta) else {
                         set_last_error("Failed to extract Uint32 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint32(Arc::new(RwLock::new(arr.to_owned()))),
@@ -126,7 +134,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint16 => {
                     let Some(arr) = extract_array_u16(wrapper, meta) else {
                         set_last_error("Failed to extract Uint16 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint16(Arc::new(RwLock::new(arr.to_owned()))),
@@ -136,7 +144,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint8 => {
                     let Some(arr) = extract_array_u8(wrapper, meta) else {
                         set_last_error("Failed to extract Uint8 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint8(Arc::new(RwLock::new(arr.to_owned()))),
@@ -146,7 +154,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Bool => {
                     let Some(arr) = extract_array_bool(wrapper, meta) else {
                         set_last_error("Failed to extract Bool view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Bool(Arc::new(RwLock::new(arr.to_owned()))),
@@ -156,7 +164,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Complex64 => {
                     let Some(arr) = extract_array_c64(wrapper, meta) else {
                         set_last_error("Failed to extract Complex64 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Complex64(Arc::new(RwLock::new(arr.to_owned()))),
@@ -166,7 +174,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Complex128 => {
                     let Some(arr) = extract_array_c128(wrapper, meta) else {
                         set_last_error("Failed to extract Complex128 view".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Complex128(Arc::new(RwLock::new(arr.to_owned()))),
@@ -179,7 +187,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Float64 => {
                     let Some(arr) = extract_array_as_f64(wrapper, meta) else {
                         set_last_error("Failed to cast array to Float64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
@@ -189,17 +197,24 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Float32 => {
                     let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                         set_last_error("Failed to cast array to Float32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Float32(Arc::new(RwLock::new(arr))),
                         dtype: DType::Float32,
                     }
                 }
+                DType::Float16 | DType::BFloat16 => {
+                    let Some(arr) = extract_array_as_half(wrapper, meta, target) else {
+                        set_last_error(format!("Failed to cast array to {}", target.name()));
+                        return ERR_DTYPE;
+                    };
+                    NDArrayWrapper::from_half_bits(arr, target)
+                }
                 DType::Int64 => {
                     let Some(arr) = extract_array_as_i64(wrapper, meta) else {
                         set_last_error("Failed to cast array to Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
@@ -209,7 +224,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Int32 => {
                     let Some(arr) = extract_array_as_i32(wrapper, meta) else {
                         set_last_error("Failed to cast array to Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int32(Arc::new(RwLock::new(arr))),
@@ -219,7 +234,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Int16 => {
                     let Some(arr) = extract_array_as_i16(wrapper, meta) else {
                         set_last_error("Failed to cast array to Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int16(Arc::new(RwLock::new(arr))),
@@ -229,7 +244,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Int8 => {
                     let Some(arr) = extract_array_as_i8(wrapper, meta) else {
                         set_last_error("Failed to cast array to Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Int8(Arc::new(RwLock::new(arr))),
@@ -239,7 +254,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint64 => {
                     let Some(arr) = extract_array_as_u64(wrapper, meta) else {
                         set_last_error("Failed to cast array to Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint64(Arc::new(RwLock::new(arr))),
@@ -249,7 +264,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint32 => {
                     let Some(arr) = extract_array_as_u32(wrapper, meta) else {
                         set_last_error("Failed to cast array to Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint32(Arc::new(RwLock::new(arr))),
@@ -259,7 +274,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint16 => {
                     let Some(arr) = extract_array_as_u16(wrapper, meta) else {
                         set_last_error("Failed to cast array to Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint16(Arc::new(RwLock::new(arr))),
@@ -269,7 +284,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Uint8 => {
                     let Some(arr) = extract_array_as_u8(wrapper, meta) else {
                         set_last_error("Failed to cast array to Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Uint8(Arc::new(RwLock::new(arr))),
@@ -279,7 +294,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Bool => {
                     let Some(arr) = extract_array_as_bool(wrapper, meta) else {
                         set_last_error("Failed to cast array to Bool".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Bool(Arc::new(RwLock::new(arr))),
@@ -289,7 +304,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Complex64 => {
                     let Some(arr) = extract_array_as_c64(wrapper, meta) else {
                         set_last_error("Failed to cast array to Complex64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Complex64(Arc::new(RwLock::new(arr))),
@@ -299,7 +314,7 @@ pub unsafe extern "C" fn ndarray_astype(
                 DType::Complex128 => {
                     let Some(arr) = extract_array_as_c128(wrapper, meta) else {
                         set_last_error("Failed to cast array to Complex128".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     NDArrayWrapper {
                         data: ArrayData::Complex128(Arc::new(RwLock::new(arr))),
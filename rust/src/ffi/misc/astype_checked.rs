@@ -0,0 +1,329 @@
+//! Checked dtype casts that count and handle out-of-range values.
+//!
+//! `ndarray_astype` converts with `as` semantics, so 300 becomes 44 in uint8
+//! and -1.0 becomes 0 without notice. The checked cast applies a
+//! [`CastPolicy`] to every value the target dtype cannot represent and
+//! reports how many there were. Float-to-integer casts still truncate toward
+//! zero; only the range is checked.
+
+use std::fmt::Display;
+use std::sync::Arc;
+
+use ndarray::ArrayD;
+use num_complex::{Complex32, Complex64};
+use parking_lot::RwLock;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_OVERFLOW, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::overflow_arith::OverflowArith;
+use crate::helpers::{
+    extract_array_as_bool, extract_array_as_c128, extract_array_as_f64, extract_array_as_i64,
+    extract_array_as_u64,
+};
+use crate::types::dtype::DType;
+use crate::types::half::{f32_to_half, half_to_f32};
+use crate::types::{ArrayData, ArrayMetadata, CastPolicy, NDArrayWrapper, NdArrayHandle};
+
+/// Out-of-range values seen during a cast.
+#[derive(Debug, Default)]
+struct Report {
+    count: usize,
+    /// Flat C-order index and value of the first one.
+    first: Option<(usize, String)>,
+}
+
+impl Report {
+    fn record(&mut self, index: usize, value: impl Display) {
+        if self.first.is_none() {
+            self.first = Some((index, value.to_string()));
+        }
+        self.count += 1;
+    }
+}
+
+/// Convert element by element, recording those `cast` flags as out of range.
+fn map_checked<S: Copy + Display, T>(
+    src: &ArrayD<S>,
+    report: &mut Report,
+    mut cast: impl FnMut(S) -> (T, bool),
+) -> ArrayD<T> {
+    let mut index = 0;
+    src.map(|&v| {
+        let (out, overflowed) = cast(v);
+        if overflowed {
+            report.record(index, v);
+        }
+        index += 1;
+        out
+    })
+}
+
+/// Convert an exact integer to `T`.
+fn int_to<T: OverflowArith>(v: i128, policy: CastPolicy) -> (T, bool) {
+    match T::try_from(v) {
+        Ok(x) => (x, false),
+        Err(_) if policy == CastPolicy::Wrap => (T::wrap_from(v), true),
+        Err(_) if v < 0 => (T::min_value(), true),
+        Err(_) => (T::max_value(), true),
+    }
+}
+
+/// Convert a float to `T`, truncating toward zero.
+///
+/// NaN and infinities are out of range. A float of magnitude 2^127 or more
+/// is a multiple of 2^64, so its wrapped value is exactly 0.
+fn float_to_int<T: OverflowArith>(v: f64, policy: CastPolicy) -> (T, bool) {
+    let t = v.trunc();
+    if t.abs() < 2f64.powi(127) {
+        return int_to(t as i128, policy);
+    }
+    let value = if t.is_nan() || policy == CastPolicy::Wrap {
+        T::wrap_from(0)
+    } else if t < 0.0 {
+        T::min_value()
+    } else {
+        T::max_value()
+    };
+    (value, true)
+}
+
+/// Narrow to f32; finite values beyond its range round to ±inf.
+fn to_f32(v: f64, policy: CastPolicy) -> (f32, bool) {
+    let x = v as f32;
+    if !(x.is_infinite() && v.is_finite()) {
+        return (x, false);
+    }
+    match policy {
+        CastPolicy::Wrap => (x, true),
+        _ => (f32::MAX.copysign(x), true),
+    }
+}
+
+/// Narrow to Float16 / BFloat16 bits, rounding through f32.
+fn to_half(v: f64, dtype: DType, policy: CastPolicy) -> (u16, bool) {
+    let bits = f32_to_half(dtype, v as f32);
+    if !(half_to_f32(dtype, bits).is_infinite() && v.is_finite()) {
+        return (bits, false);
+    }
+    let max: u16 = if dtype == DType::BFloat16 {
+        0x7F7F
+    } else {
+        0x7BFF
+    };
+    match policy {
+        CastPolicy::Wrap => (bits, true),
+        _ => (max | (bits & 0x8000), true),
+    }
+}
+
+fn to_c64(v: Complex64, policy: CastPolicy) -> (Complex32, bool) {
+    let (re, re_out) = to_f32(v.re, policy);
+    let (im, im_out) = to_f32(v.im, policy);
+    (Complex32::new(re, im), re_out || im_out)
+}
+
+/// Cast `wrapper` to `target`, applying `policy` to out-of-range values.
+///
+/// Complex sources cast to real targets through their real part, as in
+/// `ndarray_astype`.
+unsafe fn cast_checked(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    target: DType,
+    policy: CastPolicy,
+) -> Result<(NDArrayWrapper, Report), String> {
+    let mut report = Report::default();
+    let failed = || format!("Failed to cast array to {}", target);
+
+    macro_rules! wrap {
+        ($variant:ident, $arr:expr) => {
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new($arr))),
+                dtype: DType::$variant,
+            }
+        };
+    }
+
+    macro_rules! to_int {
+        ($variant:ident, $t:ty) => {{
+            let arr: ArrayD<$t> = if wrapper.dtype.is_unsigned() {
+                let src = extract_array_as_u64(wrapper, meta).ok_or_else(failed)?;
+                map_checked(&src, &mut report, |v| int_to(v as i128, policy))
+            } else if wrapper.dtype.is_signed() || wrapper.dtype == DType::Bool {
+                let src = extract_array_as_i64(wrapper, meta).ok_or_else(failed)?;
+                map_checked(&src, &mut report, |v| int_to(v as i128, policy))
+            } else {
+                let src = extract_array_as_f64(wrapper, meta).ok_or_else(failed)?;
+                map_checked(&src, &mut report, |v| float_to_int(v, policy))
+            };
+            wrap!($variant, arr)
+        }};
+    }
+
+    let result = match target {
+        DType::Int8 => to_int!(Int8, i8),
+        DType::Int16 => to_int!(Int16, i16),
+        DType::Int32 => to_int!(Int32, i32),
+        DType::Int64 => to_int!(Int64, i64),
+        DType::Uint8 => to_int!(Uint8, u8),
+        DType::Uint16 => to_int!(Uint16, u16),
+        DType::Uint32 => to_int!(Uint32, u32),
+        DType::Uint64 => to_int!(Uint64, u64),
+        DType::Float32 => {
+            let src = extract_array_as_f64(wrapper, meta).ok_or_else(failed)?;
+            wrap!(
+                Float32,
+                map_checked(&src, &mut report, |v| to_f32(v, policy))
+            )
+        }
+        DType::Float16 | DType::BFloat16 => {
+            let src = extract_array_as_f64(wrapper, meta).ok_or_else(failed)?;
+            let bits = map_checked(&src, &mut report, |v| to_half(v, target, policy));
+            NDArrayWrapper::from_half_bits(bits, target)
+        }
+        DType::Complex64 => {
+            let src = extract_array_as_c128(wrapper, meta).ok_or_else(failed)?;
+            wrap!(
+                Complex64,
+                map_checked(&src, &mut report, |v| to_c64(v, policy))
+            )
+        }
+        // Every value of every dtype fits these, up to rounding.
+        DType::Float64 => wrap!(
+            Float64,
+            extract_array_as_f64(wrapper, meta).ok_or_else(failed)?
+        ),
+        DType::Complex128 => wrap!(
+            Complex128,
+            extract_array_as_c128(wrapper, meta).ok_or_else(failed)?
+        ),
+        DType::Bool => wrap!(
+            Bool,
+            extract_array_as_bool(wrapper, meta).ok_or_else(failed)?
+        ),
+    };
+    Ok((result, report))
+}
+
+/// Cast an NDArray to a different dtype, checking that every value fits.
+///
+/// Values the target cannot represent follow `policy` (0 = clamp, 1 = wrap,
+/// 2 = fail with `ERR_OVERFLOW`) and are counted in `out_count`. NaN and
+/// infinities are out of range for integer targets, as are finite values
+/// that overflow a narrower float. Under `Error` the message gives the
+/// number of offending elements and the first of them.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_astype_checked(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    target_dtype: i32,
+    policy: i32,
+    out: *mut *mut NdArrayHandle,
+    out_count: *mut usize,
+) -> i32 {
+    if handle.is_null() || out.is_null() || meta.is_null() || out_count.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let policy = match CastPolicy::from_i32(policy) {
+        Ok(p) => p,
+        Err(e) => {
+            set_last_error(e);
+            return ERR_VALUE;
+        }
+    };
+
+    crate::ffi_guard!("astype_checked", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let Some(target) = DType::from_u8(target_dtype as u8) else {
+            set_last_error(format!("Invalid target dtype: {}", target_dtype));
+            return ERR_DTYPE;
+        };
+
+        let (result_wrapper, report) = match cast_checked(wrapper, meta, target, policy) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_DTYPE;
+            }
+        };
+        *out_count = report.count;
+
+        if policy == CastPolicy::Error {
+            if let Some((index, value)) = report.first {
+                set_last_error(format!(
+                    "Cannot cast to {}: {} of {} elements out of range (first: {} at index {})",
+                    target,
+                    report.count,
+                    meta.shape_slice().iter().product::<usize>(),
+                    value,
+                    index
+                ));
+                return ERR_OVERFLOW;
+            }
+        }
+
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_policies() {
+        assert_eq!(int_to::<u8>(300, CastPolicy::Clamp), (255, true));
+        assert_eq!(int_to::<u8>(300, CastPolicy::Wrap), (44, true));
+        assert_eq!(int_to::<u8>(-1, CastPolicy::Clamp), (0, true));
+        assert_eq!(int_to::<i8>(-128, CastPolicy::Error), (-128, false));
+        assert_eq!(
+            int_to::<u64>(u64::MAX as i128, CastPolicy::Clamp),
+            (u64::MAX, false)
+        );
+    }
+
+    #[test]
+    fn float_to_int_policies() {
+        assert_eq!(float_to_int::<i8>(3.9, CastPolicy::Error), (3, false));
+        assert_eq!(float_to_int::<i8>(-200.5, CastPolicy::Clamp), (-128, true));
+        assert_eq!(float_to_int::<u8>(-1.0, CastPolicy::Wrap), (255, true));
+        assert_eq!(float_to_int::<i32>(f64::NAN, CastPolicy::Clamp), (0, true));
+        assert_eq!(
+            float_to_int::<i64>(f64::INFINITY, CastPolicy::Clamp),
+            (i64::MAX, true)
+        );
+        assert_eq!(float_to_int::<u32>(1e300, CastPolicy::Wrap), (0, true));
+    }
+
+    #[test]
+    fn float_narrowing_policies() {
+        assert_eq!(to_f32(1e300, CastPolicy::Clamp), (f32::MAX, true));
+        assert_eq!(to_f32(-1e300, CastPolicy::Wrap), (f32::NEG_INFINITY, true));
+        assert!(!to_f32(f64::INFINITY, CastPolicy::Error).1);
+        assert_eq!(
+            to_half(70000.0, DType::Float16, CastPolicy::Clamp),
+            (0x7BFF, true)
+        );
+        assert_eq!(
+            to_half(-70000.0, DType::Float16, CastPolicy::Clamp),
+            (0xFBFF, true)
+        );
+        assert!(!to_half(70000.0, DType::BFloat16, CastPolicy::Error).1);
+    }
+
+    #[test]
+    fn report_keeps_first_offender() {
+        let src = ArrayD::from_shape_vec(ndarray::IxDyn(&[4]), vec![1i64, 300, -5, 2]).unwrap();
+        let mut report = Report::default();
+        let out: ArrayD<u8> =
+            map_checked(&src, &mut report, |v| int_to(v as i128, CastPolicy::Clamp));
+        assert_eq!(out.iter().copied().collect::<Vec<_>>(), vec![1, 255, 0, 2]);
+        assert_eq!(report.count, 2);
+        assert_eq!(report.first, Some((1, "300".to_string())));
+    }
+}
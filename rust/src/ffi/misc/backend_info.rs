@@ -0,0 +1,42 @@
+//! FFI function reporting the linear algebra backends compiled into the library.
+
+use std::os::raw::c_char;
+use std::ptr;
+
+use crate::helpers::error::{ERR_GENERIC, SUCCESS};
+
+/// LAPACK provider that `ndarray-linalg` links for decompositions and solvers.
+///
+/// It is linked statically regardless of the `blas` feature, so the library
+/// always carries it.
+#[cfg(windows)]
+const LAPACK_PROVIDER: &str = "mkl-static";
+#[cfg(not(windows))]
+const LAPACK_PROVIDER: &str = "openblas-static";
+
+/// Report which linear algebra backends are active.
+///
+/// `out_blas` is set to 1 when matrix products (`matmul`, `dot`) dispatch to
+/// BLAS gemm, i.e. the library was built with the `blas` feature, and to 0
+/// when they run on the pure-Rust `matrixmultiply` kernels. The LAPACK
+/// provider used by the decompositions is written to `buf` as a
+/// NUL-terminated string, truncated to `len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_backend_info(
+    out_blas: *mut u8,
+    buf: *mut c_char,
+    len: usize,
+) -> i32 {
+    if out_blas.is_null() || buf.is_null() || len == 0 {
+        return ERR_GENERIC;
+    }
+
+    *out_blas = u8::from(cfg!(feature = "blas"));
+
+    let bytes = LAPACK_PROVIDER.as_bytes();
+    let copy_len = bytes.len().min(len - 1);
+    ptr::copy_nonoverlapping(bytes.as_ptr() as *const c_char, buf, copy_len);
+    *buf.add(copy_len) = 0;
+
+    SUCCESS
+}
@@ -0,0 +1,140 @@
+//! Byte order reversal for converting arrays between little- and big-endian.
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use num_complex::{Complex32, Complex64};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Element types whose in-memory byte order can be reversed.
+trait ByteSwap: Copy {
+    fn byteswap(self) -> Self;
+}
+
+macro_rules! impl_byteswap_int {
+    ($($t:ty),*) => {
+        $(impl ByteSwap for $t {
+            fn byteswap(self) -> Self {
+                self.swap_bytes()
+            }
+        })*
+    };
+}
+
+impl_byteswap_int!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl ByteSwap for f32 {
+    fn byteswap(self) -> Self {
+        f32::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+impl ByteSwap for f64 {
+    fn byteswap(self) -> Self {
+        f64::from_bits(self.to_bits().swap_bytes())
+    }
+}
+
+/// Complex values swap the real and imaginary components independently.
+impl ByteSwap for Complex32 {
+    fn byteswap(self) -> Self {
+        Complex32::new(self.re.byteswap(), self.im.byteswap())
+    }
+}
+
+impl ByteSwap for Complex64 {
+    fn byteswap(self) -> Self {
+        Complex64::new(self.re.byteswap(), self.im.byteswap())
+    }
+}
+
+macro_rules! byteswap_arm {
+    ($wrapper:expr, $meta:expr, $extract:ident, $variant:ident) => {{
+        let Some(arr) = $extract($wrapper, $meta) else {
+            set_last_error(format!("Failed to extract {} view", stringify!($variant)));
+            return ERR_DTYPE;
+        };
+        NDArrayWrapper {
+            data: ArrayData::$variant(Arc::new(RwLock::new(arr.mapv(ByteSwap::byteswap)))),
+            dtype: DType::$variant,
+        }
+    }};
+}
+
+/// Reverse the byte order of every element, returning a new array.
+///
+/// Single-byte dtypes (Int8, Uint8, Bool) are copied unchanged.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_byteswap(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("byteswap", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let result_wrapper = match wrapper.dtype {
+            DType::Int8 => byteswap_arm!(wrapper, meta, extract_array_i8, Int8),
+            DType::Int16 => byteswap_arm!(wrapper, meta, extract_array_i16, Int16),
+            DType::Int32 => byteswap_arm!(wrapper, meta, extract_array_i32, Int32),
+            DType::Int64 => byteswap_arm!(wrapper, meta, extract_array_i64, Int64),
+            DType::Uint8 => byteswap_arm!(wrapper, meta, extract_array_u8, Uint8),
+            DType::Uint16 => byteswap_arm!(wrapper, meta, extract_array_u16, Uint16),
+            DType::Uint32 => byteswap_arm!(wrapper, meta, extract_array_u32, Uint32),
+            DType::Uint64 => byteswap_arm!(wrapper, meta, extract_array_u64, Uint64),
+            DType::Float32 => byteswap_arm!(wrapper, meta, extract_array_f32, Float32),
+            DType::Float64 => byteswap_arm!(wrapper, meta, extract_array_f64, Float64),
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype.name()));
+                    return ERR_DTYPE;
+                };
+                NDArrayWrapper::from_half_bits(arr.mapv(u16::swap_bytes), wrapper.dtype)
+            }
+            DType::Complex64 => byteswap_arm!(wrapper, meta, extract_array_c64, Complex64),
+            DType::Complex128 => byteswap_arm!(wrapper, meta, extract_array_c128, Complex128),
+            DType::Bool => {
+                let Some(arr) = extract_array_bool(wrapper, meta) else {
+                    set_last_error("Failed to extract Bool view".to_string());
+                    return ERR_DTYPE;
+                };
+                NDArrayWrapper {
+                    data: ArrayData::Bool(Arc::new(RwLock::new(arr))),
+                    dtype: DType::Bool,
+                }
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
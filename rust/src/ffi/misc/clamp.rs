@@ -1,26 +1,92 @@
 //! Clamp operation - limit values to [min, max] range.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use std::ffi::c_void;
+
+use num_traits::{Bounded, NumCast};
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_VALUE, SUCCESS};
+use crate::helpers::parallel;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
-};
+use crate::helpers::{get_scalar_as_f64, get_scalar_as_i64, get_scalar_as_u64};
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
-use parking_lot::RwLock;
-use std::sync::Arc;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// A clamp bound as given by the caller, before conversion to the array dtype.
+#[derive(Clone, Copy)]
+enum Bound {
+    Int(i128),
+    Float(f64),
+}
+
+impl Bound {
+    unsafe fn read(scalar: *const c_void, dtype: DType) -> Result<Self, (i32, String)> {
+        if dtype.is_complex() {
+            return Err((ERR_DTYPE, "Clamp bounds must be real".to_string()));
+        }
+        let bound = match dtype {
+            DType::Uint64 | DType::Uint32 | DType::Uint16 | DType::Uint8 => {
+                Bound::Int(get_scalar_as_u64(scalar, dtype) as i128)
+            }
+            DType::Int64 | DType::Int32 | DType::Int16 | DType::Int8 | DType::Bool => {
+                Bound::Int(get_scalar_as_i64(scalar, dtype) as i128)
+            }
+            _ => Bound::Float(get_scalar_as_f64(scalar, dtype)),
+        };
+        if matches!(bound, Bound::Float(v) if v.is_nan()) {
+            return Err((ERR_VALUE, "Clamp bounds must not be NaN".to_string()));
+        }
+        Ok(bound)
+    }
+
+    fn exceeds(self, other: Bound) -> bool {
+        match (self, other) {
+            (Bound::Int(a), Bound::Int(b)) => a > b,
+            (a, b) => a.as_f64() > b.as_f64(),
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            Bound::Int(v) => v as f64,
+            Bound::Float(v) => v,
+        }
+    }
+
+    /// Convert to `T`, saturating at the limits of `T`. Fractional bounds
+    /// are truncated toward zero for integer dtypes.
+    fn to<T: NumCast + Bounded>(self) -> T {
+        let (cast, negative) = match self {
+            Bound::Int(v) => (T::from(v), v < 0),
+            Bound::Float(v) => (T::from(v), v < 0.0),
+        };
+        cast.unwrap_or_else(|| {
+            if negative {
+                T::min_value()
+            } else {
+                T::max_value()
+            }
+        })
+    }
+}
 
 /// Clamp array values to [min, max] range.
 ///
-/// Similar to NumPy's clip function.
-/// Returns error if min > max.
+/// Similar to NumPy's clip function. The result keeps the array dtype and
+/// integer arrays are compared as integers, so bounds beyond 2^53 are exact.
+/// Bounds outside the dtype's range saturate to its limits.
+/// Returns error if min > max or either bound is NaN.
+///
+/// # Arguments
+/// * `min_val` / `min_dtype` - Lower bound scalar and its dtype
+/// * `max_val` / `max_dtype` - Upper bound scalar and its dtype
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_clamp(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
-    min_val: f64,
-    max_val: f64,
+    min_val: *const c_void,
+    min_dtype: u8,
+    max_val: *const c_void,
+    max_dtype: u8,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -28,6 +94,8 @@ pub unsafe extern "C" fn ndarray_clamp(
     max_ndim: usize,
 ) -> i32 {
     if handle.is_null()
+        || min_val.is_null()
+        || max_val.is_null()
         || out_handle.is_null()
         || meta.is_null()
         || out_dtype.is_null()
@@ -37,147 +105,35 @@ pub unsafe extern "C" fn ndarray_clamp(
         return ERR_GENERIC;
     }
 
-    if min_val > max_val {
-        set_last_error("Clamp failed: min > max".to_string());
-        return ERR_GENERIC;
-    }
+    let (Some(min_dtype), Some(max_dtype)) = (DType::from_u8(min_dtype), DType::from_u8(max_dtype))
+    else {
+        set_last_error("Invalid scalar dtype".to_string());
+        return ERR_DTYPE;
+    };
+
+    let bounds = Bound::read(min_val, min_dtype).and_then(|min| {
+        let max = Bound::read(max_val, max_dtype)?;
+        if min.exceeds(max) {
+            return Err((ERR_VALUE, "Clamp failed: min > max".to_string()));
+        }
+        Ok((min, max))
+    });
+    let (min, max) = match bounds {
+        Ok(b) => b,
+        Err((code, e)) => {
+            set_last_error(e);
+            return code;
+        }
+    };
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("clamp", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
-        // Match on dtype, extract view, clamp, and create result wrapper
-        let result_wrapper = match wrapper.dtype {
-            DType::Float64 => {
-                let Some(arr) = extract_array_f64(wrapper, meta) else {
-                    set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.clamp(min_val, max_val);
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
-                    set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.clamp(min_val as f32, max_val as f32);
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Int64 => {
-                let Some(arr) = extract_array_i64(wrapper, meta) else {
-                    set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.clamp(min_val as i64, max_val as i64);
-                NDArrayWrapper {
-                    data: ArrayData::Int64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int64,
-                }
-            }
-            DType::Int32 => {
-                let Some(arr) = extract_array_i32(wrapper, meta) else {
-                    set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.clamp(min_val as i32, max_val as i32);
-                NDArrayWrapper {
-                    data: ArrayData::Int32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int32,
-                }
-            }
-            DType::Int16 => {
-                let Some(arr) = extract_array_i16(wrapper, meta) else {
-                    set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.clamp(min_val as i16, max_val as i16);
-                NDArrayWrapper {
-                    data: ArrayData::Int16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int16,
-                }
-            }
-            DType::Int8 => {
-                let Some(arr) = extract_array_i8(wrapper, meta) else {
-                    set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let result = arr.clamp(min_val as i8, max_val as i8);
-                NDArrayWrapper {
-                    data: ArrayData::Int8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Int8,
-                }
-            }
-            DType::Uint64 => {
-                let Some(arr) = extract_array_u64(wrapper, meta) else {
-                    set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let min_u64 = min_val.max(0.0) as u64;
-                let max_u64 = max_val.max(0.0) as u64;
-                let result = arr.clamp(min_u64, max_u64);
-                NDArrayWrapper {
-                    data: ArrayData::Uint64(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint64,
-                }
-            }
-            DType::Uint32 => {
-                let Some(arr) = extract_array_u32(wrapper, meta) else {
-                    set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let min_u32 = min_val.max(0.0) as u32;
-                let max_u32 = max_val.max(0.0) as u32;
-                let result = arr.clamp(min_u32, max_u32);
-                NDArrayWrapper {
-                    data: ArrayData::Uint32(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint32,
-                }
-            }
-            DType::Uint16 => {
-                let Some(arr) = extract_array_u16(wrapper, meta) else {
-                    set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let min_u16 = min_val.max(0.0) as u16;
-                let max_u16 = max_val.max(0.0) as u16;
-                let result = arr.clamp(min_u16, max_u16);
-                NDArrayWrapper {
-                    data: ArrayData::Uint16(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint16,
-                }
-            }
-            DType::Uint8 => {
-                let Some(arr) = extract_array_u8(wrapper, meta) else {
-                    set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
-                };
-                let min_u8 = min_val.max(0.0) as u8;
-                let max_u8 = max_val.max(0.0) as u8;
-                let result = arr.clamp(min_u8, max_u8);
-                NDArrayWrapper {
-                    data: ArrayData::Uint8(Arc::new(RwLock::new(result))),
-                    dtype: DType::Uint8,
-                }
-            }
-            DType::Bool => {
-                set_last_error("clamp() not supported for Bool type".to_string());
-                return ERR_GENERIC;
-            }
-            DType::Complex64 | DType::Complex128 => {
-                set_last_error(
-                    "clamp() not supported for complex dtypes (use real/imag or magnitude)"
-                        .to_string(),
-                );
-                return ERR_GENERIC;
-            }
-        };
+        let result_wrapper = crate::unary_op_native!(wrapper, meta, "clamp", |arr| {
+            let (lo, hi) = (min.to(), max.to());
+            parallel::mapv_into(arr, |x| num_traits::clamp(x, lo, hi))
+        });
 
         if let Err(e) =
             write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
@@ -189,3 +145,25 @@ pub unsafe extern "C" fn ndarray_clamp(
         SUCCESS
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_bounds_are_exact_beyond_f64_precision() {
+        let lo = Bound::Int(i64::MAX as i128 - 1);
+        assert_eq!(lo.to::<i64>(), i64::MAX - 1);
+        assert_eq!(Bound::Int(u64::MAX as i128).to::<u64>(), u64::MAX);
+        assert!(Bound::Int(u64::MAX as i128).exceeds(Bound::Int(u64::MAX as i128 - 1)));
+    }
+
+    #[test]
+    fn out_of_range_bounds_saturate() {
+        assert_eq!(Bound::Int(-5).to::<u8>(), 0);
+        assert_eq!(Bound::Int(300).to::<u8>(), 255);
+        assert_eq!(Bound::Float(-1e30).to::<i32>(), i32::MIN);
+        assert_eq!(Bound::Float(2.7).to::<i16>(), 2);
+        assert_eq!(Bound::Float(-1e30).to::<u64>(), 0);
+    }
+}
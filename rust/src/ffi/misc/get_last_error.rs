@@ -1,10 +1,36 @@
-//! FFI function to retrieve the last error message.
+//! FFI functions to retrieve the last error.
 
 use std::ffi::CString;
 use std::os::raw::c_char;
 use std::ptr;
 
-use crate::helpers::error::get_last_error_message;
+use crate::helpers::error::{get_last_error_detail, get_last_error_message, ERR_GENERIC, SUCCESS};
+
+/// The last error with its code and the operation that raised it.
+///
+/// Strings are NUL-terminated and truncated to fit.
+#[repr(C)]
+pub struct NdArrayError {
+    /// Error code returned by the failing call, or `SUCCESS` if none.
+    pub code: i32,
+    /// Failing FFI function without its `ndarray_` prefix, e.g. `"matmul"`.
+    /// Empty when the call failed before its arguments were validated.
+    pub operation: [c_char; 64],
+    pub message: [c_char; 1024],
+}
+
+/// Copy `s` into `buf` as a NUL-terminated string, truncating at a UTF-8
+/// character boundary.
+fn write_c_str(buf: &mut [c_char], s: &str) {
+    let mut len = s.len().min(buf.len() - 1);
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+    for (dst, &src) in buf.iter_mut().zip(&s.as_bytes()[..len]) {
+        *dst = src as c_char;
+    }
+    buf[len] = 0;
+}
 
 /// Get the last error message.
 ///
@@ -36,3 +62,43 @@ pub unsafe extern "C" fn ndarray_get_last_error(buf: *mut c_char, len: usize) ->
         0
     }
 }
+
+/// Get the last error as a structured record.
+///
+/// Fills `out` with the code, operation name and message of the last error
+/// on this thread. With no error recorded, `code` is `SUCCESS` and both
+/// strings are empty.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_get_last_error_struct(out: *mut NdArrayError) -> i32 {
+    if out.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let out = &mut *out;
+    let detail = get_last_error_detail();
+    out.code = detail.as_ref().map_or(SUCCESS, |d| d.code);
+    write_c_str(
+        &mut out.operation,
+        detail.as_ref().and_then(|d| d.operation).unwrap_or(""),
+    );
+    write_c_str(
+        &mut out.message,
+        detail.as_ref().map_or("", |d| d.message.as_str()),
+    );
+    SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_c_str_truncates_on_char_boundary() {
+        let mut buf = [1 as c_char; 5];
+        write_c_str(&mut buf, "abé");
+        assert_eq!(&buf[..5], &[97, 98, -61i8 as c_char, -87i8 as c_char, 0]);
+
+        write_c_str(&mut buf, "abcé");
+        assert_eq!(&buf[..4], &[97, 98, 99, 0]);
+    }
+}
@@ -0,0 +1,170 @@
+//! Content hashing of array views.
+
+use std::mem::size_of;
+use std::slice;
+
+use ndarray::ArrayViewD;
+use num_complex::{Complex32, Complex64};
+use xxhash_rust::xxh3::Xxh3;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::{
+    extract_view_bool, extract_view_c128, extract_view_c64, extract_view_f32, extract_view_f64,
+    extract_view_half_bits, extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8,
+    extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Elements buffered before feeding a strided view into the hasher.
+const STAGING_BYTES: usize = 64 * 1024;
+
+/// Element types that can append their little-endian bytes to a buffer.
+trait LeBytes: Copy {
+    fn extend_le(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_le_bytes {
+    ($($t:ty),*) => {
+        $(impl LeBytes for $t {
+            #[inline]
+            fn extend_le(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+impl_le_bytes!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+
+impl LeBytes for Complex32 {
+    #[inline]
+    fn extend_le(self, out: &mut Vec<u8>) {
+        self.re.extend_le(out);
+        self.im.extend_le(out);
+    }
+}
+
+impl LeBytes for Complex64 {
+    #[inline]
+    fn extend_le(self, out: &mut Vec<u8>) {
+        self.re.extend_le(out);
+        self.im.extend_le(out);
+    }
+}
+
+/// Feed the little-endian bytes of every element of `view` (in C order)
+/// into `hasher`.
+fn hash_elements<T: LeBytes>(hasher: &mut Xxh3, view: &ArrayViewD<'_, T>) {
+    if cfg!(target_endian = "little") {
+        if let Some(data) = view.as_slice() {
+            let bytes = unsafe {
+                slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data))
+            };
+            hasher.update(bytes);
+            return;
+        }
+    }
+
+    let mut staging = Vec::with_capacity(STAGING_BYTES + size_of::<T>());
+    for &value in view.iter() {
+        value.extend_le(&mut staging);
+        if staging.len() >= STAGING_BYTES {
+            hasher.update(&staging);
+            staging.clear();
+        }
+    }
+    hasher.update(&staging);
+}
+
+/// Compute a 64-bit XXH3 hash of a view's dtype, shape and element data.
+///
+/// Elements are visited in C order, so equal views hash equally regardless
+/// of their strides or offset. Element bytes are hashed in little-endian
+/// order, so the digest is the same on every platform.
+///
+/// # Arguments
+/// * `handle` - Array handle
+/// * `meta` - View metadata
+/// * `seed` - Hash seed
+/// * `out_hash` - Output: 64-bit digest
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_hash(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    seed: u64,
+    out_hash: *mut u64,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_hash.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("hash", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+        let shape = meta.shape_slice();
+
+        let mut hasher = Xxh3::with_seed(seed);
+        hasher.update(&[wrapper.dtype as u8]);
+        hasher.update(&(shape.len() as u64).to_le_bytes());
+        for &dim in shape {
+            hasher.update(&(dim as u64).to_le_bytes());
+        }
+
+        macro_rules! hash_arm {
+            ($extract:ident) => {{
+                let Some(view) = $extract(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype.name()));
+                    return ERR_DTYPE;
+                };
+                hash_elements(&mut hasher, &view);
+            }};
+        }
+
+        match wrapper.dtype {
+            DType::Int8 => hash_arm!(extract_view_i8),
+            DType::Int16 => hash_arm!(extract_view_i16),
+            DType::Int32 => hash_arm!(extract_view_i32),
+            DType::Int64 => hash_arm!(extract_view_i64),
+            DType::Uint8 => hash_arm!(extract_view_u8),
+            DType::Uint16 => hash_arm!(extract_view_u16),
+            DType::Uint32 => hash_arm!(extract_view_u32),
+            DType::Uint64 => hash_arm!(extract_view_u64),
+            DType::Float32 => hash_arm!(extract_view_f32),
+            DType::Float64 => hash_arm!(extract_view_f64),
+            DType::Float16 | DType::BFloat16 => hash_arm!(extract_view_half_bits),
+            DType::Bool => hash_arm!(extract_view_bool),
+            DType::Complex64 => hash_arm!(extract_view_c64),
+            DType::Complex128 => hash_arm!(extract_view_c128),
+        }
+
+        *out_hash = hasher.digest();
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hash_elements;
+    use ndarray::{arr1, arr2};
+    use xxhash_rust::xxh3::{xxh3_64, Xxh3};
+
+    #[test]
+    fn hashes_elements_as_little_endian_bytes() {
+        let a = arr1(&[0x0102_0304u32, 0x0506_0708]).into_dyn();
+        let mut hasher = Xxh3::new();
+        hash_elements(&mut hasher, &a.view());
+        assert_eq!(hasher.digest(), xxh3_64(&[4, 3, 2, 1, 8, 7, 6, 5]));
+    }
+
+    #[test]
+    fn strided_views_hash_like_contiguous_ones() {
+        let a = arr2(&[[1.5f64, -2.0], [3.25, 4.0]]).into_dyn();
+        let t = a.t();
+        let copy = t.to_owned();
+        let (mut strided, mut contiguous) = (Xxh3::new(), Xxh3::new());
+        hash_elements(&mut strided, &t);
+        hash_elements(&mut contiguous, &copy.view());
+        assert_eq!(strided.digest(), contiguous.digest());
+    }
+}
@@ -1,12 +1,24 @@
 //! Miscellaneous array operations that don't fit into other categories.
 
 pub mod astype;
+pub mod astype_checked;
+pub mod backend_info;
+pub mod byteswap;
 pub mod clamp;
 pub mod get_last_error;
+pub mod hash;
+pub mod quantize;
+pub mod threads;
 pub mod to_string;
 
 // Re-export all FFI functions
 pub use astype::*;
+pub use astype_checked::*;
+pub use backend_info::*;
+pub use byteswap::*;
 pub use clamp::*;
 pub use get_last_error::*;
+pub use hash::*;
+pub use quantize::*;
+pub use threads::*;
 pub use to_string::*;
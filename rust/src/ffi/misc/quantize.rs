@@ -0,0 +1,366 @@
+//! Affine int8/uint8 quantization: `q = clamp(round(x / scale) + zero_point)`.
+//!
+//! Parameters are per-tensor (one scale and zero point) or per-channel (one
+//! per index along `axis`). Rounding is half-to-even, matching PyTorch and
+//! ONNX `QuantizeLinear`.
+
+use ndarray::{ArrayD, Axis, Zip};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{
+    extract_array_as_f64, extract_array_as_i64, normalize_axis, write_output_metadata,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Resolved quantization parameters: one entry per channel, or a single entry.
+struct QuantParams {
+    scales: Vec<f64>,
+    zero_points: Vec<i64>,
+    /// Channel axis when the parameters are per-channel.
+    axis: Option<usize>,
+}
+
+impl QuantParams {
+    /// Parameters for channel `c` (ignored for per-tensor parameters).
+    fn get(&self, c: usize) -> (f64, i64) {
+        if self.axis.is_some() {
+            (self.scales[c], self.zero_points[c])
+        } else {
+            (self.scales[0], self.zero_points[0])
+        }
+    }
+}
+
+fn quant_range(dtype: DType) -> Option<(i64, i64)> {
+    match dtype {
+        DType::Int8 => Some((i8::MIN as i64, i8::MAX as i64)),
+        DType::Uint8 => Some((0, u8::MAX as i64)),
+        _ => None,
+    }
+}
+
+/// Load and validate scale / zero-point arrays against `shape`.
+#[allow(clippy::too_many_arguments)]
+unsafe fn load_params(
+    shape: &[usize],
+    axis: i32,
+    scale_handle: *const NdArrayHandle,
+    scale_meta: *const ArrayMetadata,
+    zp_handle: *const NdArrayHandle,
+    zp_meta: *const ArrayMetadata,
+    range: (i64, i64),
+) -> Result<QuantParams, (i32, String)> {
+    let scale_wrapper = NdArrayHandle::as_wrapper(scale_handle as *mut _);
+    let scales: Vec<f64> = extract_array_as_f64(scale_wrapper, &*scale_meta)
+        .ok_or_else(|| (ERR_DTYPE, "scale must be a real array".to_string()))?
+        .iter()
+        .copied()
+        .collect();
+    let zero_points: Vec<i64> = if zp_handle.is_null() || zp_meta.is_null() {
+        vec![0; scales.len()]
+    } else {
+        let zp_wrapper = NdArrayHandle::as_wrapper(zp_handle as *mut _);
+        if zp_wrapper.dtype.is_complex()
+            || matches!(zp_wrapper.dtype, DType::Float32 | DType::Float64)
+        {
+            return Err((ERR_DTYPE, "zero_point must be an integer array".to_string()));
+        }
+        extract_array_as_i64(zp_wrapper, &*zp_meta)
+            .ok_or_else(|| (ERR_DTYPE, "zero_point must be an integer array".to_string()))?
+            .iter()
+            .copied()
+            .collect()
+    };
+
+    if scales.is_empty() || scales.len() != zero_points.len() {
+        return Err((
+            ERR_SHAPE,
+            format!(
+                "scale and zero_point must have the same non-zero length (got {} and {})",
+                scales.len(),
+                zero_points.len()
+            ),
+        ));
+    }
+    if let Some(s) = scales.iter().find(|s| !(s.is_finite() && **s > 0.0)) {
+        return Err((
+            ERR_VALUE,
+            format!("scale must be positive and finite, got {}", s),
+        ));
+    }
+    if let Some(z) = zero_points.iter().find(|z| **z < range.0 || **z > range.1) {
+        return Err((
+            ERR_VALUE,
+            format!(
+                "zero_point {} is outside the quantized range [{}, {}]",
+                z, range.0, range.1
+            ),
+        ));
+    }
+
+    let axis = if scales.len() == 1 {
+        None
+    } else {
+        let axis = normalize_axis(shape, axis, false).map_err(|e| (ERR_SHAPE, e))?;
+        if shape[axis] != scales.len() {
+            return Err((
+                ERR_SHAPE,
+                format!(
+                    "Per-channel parameters have length {}, but axis {} has size {}",
+                    scales.len(),
+                    axis,
+                    shape[axis]
+                ),
+            ));
+        }
+        Some(axis)
+    };
+
+    Ok(QuantParams {
+        scales,
+        zero_points,
+        axis,
+    })
+}
+
+/// Apply `f(value, scale, zero_point)` per element, using the parameters of its channel.
+fn map_channels<A: Copy, B: Default + Clone>(
+    input: &ArrayD<A>,
+    params: &QuantParams,
+    f: impl Fn(A, f64, i64) -> B,
+) -> ArrayD<B> {
+    let mut out = ArrayD::<B>::default(input.raw_dim());
+    match params.axis {
+        None => {
+            let (scale, zp) = params.get(0);
+            Zip::from(&mut out)
+                .and(input)
+                .for_each(|o, &x| *o = f(x, scale, zp));
+        }
+        Some(axis) => {
+            for (c, (mut out_c, in_c)) in out
+                .axis_iter_mut(Axis(axis))
+                .zip(input.axis_iter(Axis(axis)))
+                .enumerate()
+            {
+                let (scale, zp) = params.get(c);
+                Zip::from(&mut out_c)
+                    .and(&in_c)
+                    .for_each(|o, &x| *o = f(x, scale, zp));
+            }
+        }
+    }
+    out
+}
+
+unsafe fn finish(
+    result_wrapper: NDArrayWrapper,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if let Err(e) = write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+    {
+        set_last_error(e);
+        return ERR_GENERIC;
+    }
+    *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+    SUCCESS
+}
+
+/// Quantize a real array to Int8 or Uint8 (`target_dtype`).
+///
+/// `scale` holds one value (per-tensor) or one per index along `axis`
+/// (per-channel); `zero_point` may be null (all zeros) or an integer array of
+/// the same length. Values are divided by the scale, rounded half-to-even,
+/// shifted by the zero point and saturated to the target range. NaN maps to
+/// the zero point.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_quantize(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    scale_handle: *const NdArrayHandle,
+    scale_meta: *const ArrayMetadata,
+    zp_handle: *const NdArrayHandle,
+    zp_meta: *const ArrayMetadata,
+    axis: i32,
+    target_dtype: u8,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || scale_handle.is_null()
+        || scale_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("quantize", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let Some(target) = DType::from_u8(target_dtype) else {
+            set_last_error(format!("Invalid target dtype: {}", target_dtype));
+            return ERR_DTYPE;
+        };
+        let Some(range) = quant_range(target) else {
+            set_last_error(format!(
+                "Quantization target must be int8 or uint8, got {}",
+                target.name()
+            ));
+            return ERR_DTYPE;
+        };
+        if wrapper.dtype.is_complex() {
+            set_last_error("Complex arrays cannot be quantized".to_string());
+            return ERR_DTYPE;
+        }
+
+        let params = match load_params(
+            meta.shape_slice(),
+            axis,
+            scale_handle,
+            scale_meta,
+            zp_handle,
+            zp_meta,
+            range,
+        ) {
+            Ok(p) => p,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let (lo, hi) = range;
+        let q = map_channels(&arr, &params, |x, scale, zp| {
+            if x.is_nan() {
+                zp
+            } else {
+                let r = (x / scale).round_ties_even() + zp as f64;
+                r.clamp(lo as f64, hi as f64) as i64
+            }
+        });
+
+        let result_wrapper = if target == DType::Int8 {
+            NDArrayWrapper {
+                data: ArrayData::Int8(Arc::new(RwLock::new(q.mapv(|v| v as i8)))),
+                dtype: DType::Int8,
+            }
+        } else {
+            NDArrayWrapper {
+                data: ArrayData::Uint8(Arc::new(RwLock::new(q.mapv(|v| v as u8)))),
+                dtype: DType::Uint8,
+            }
+        };
+        finish(
+            result_wrapper,
+            out_handle,
+            out_dtype,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        )
+    })
+}
+
+/// Dequantize an Int8 or Uint8 array to Float32: `(q - zero_point) * scale`.
+///
+/// Parameters follow the same per-tensor / per-channel rules as
+/// [`ndarray_quantize`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_dequantize(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    scale_handle: *const NdArrayHandle,
+    scale_meta: *const ArrayMetadata,
+    zp_handle: *const NdArrayHandle,
+    zp_meta: *const ArrayMetadata,
+    axis: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || scale_handle.is_null()
+        || scale_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("dequantize", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let Some(range) = quant_range(wrapper.dtype) else {
+            set_last_error(format!(
+                "Dequantization requires an int8 or uint8 array, got {}",
+                wrapper.dtype.name()
+            ));
+            return ERR_DTYPE;
+        };
+
+        let params = match load_params(
+            meta.shape_slice(),
+            axis,
+            scale_handle,
+            scale_meta,
+            zp_handle,
+            zp_meta,
+            range,
+        ) {
+            Ok(p) => p,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        let Some(arr) = extract_array_as_i64(wrapper, meta) else {
+            set_last_error("Failed to extract array as i64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let x = map_channels(&arr, &params, |q, scale, zp| {
+            ((q - zp) as f64 * scale) as f32
+        });
+
+        finish(
+            NDArrayWrapper {
+                data: ArrayData::Float32(Arc::new(RwLock::new(x))),
+                dtype: DType::Float32,
+            },
+            out_handle,
+            out_dtype,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        )
+    })
+}
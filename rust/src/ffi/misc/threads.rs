@@ -0,0 +1,42 @@
+//! Thread pool controls for the `parallel` feature.
+
+use crate::helpers::error::{set_last_error, ERR_VALUE, SUCCESS};
+use crate::helpers::parallel;
+
+/// Set the number of threads used by parallel kernels.
+///
+/// Zero restores the default of one thread per core. The pool is rebuilt, so
+/// this may be called any number of times. Without the `parallel` feature the
+/// call succeeds and has no effect.
+#[no_mangle]
+pub extern "C" fn ndarray_set_num_threads(num_threads: usize) -> i32 {
+    match parallel::set_num_threads(num_threads) {
+        Ok(()) => SUCCESS,
+        Err(e) => {
+            set_last_error(e);
+            ERR_VALUE
+        }
+    }
+}
+
+/// Return the number of threads used by parallel kernels (1 without the
+/// `parallel` feature).
+#[no_mangle]
+pub extern "C" fn ndarray_get_num_threads() -> usize {
+    parallel::num_threads()
+}
+
+/// Set the element count at which kernels switch to the thread pool.
+///
+/// Zero restores the default.
+#[no_mangle]
+pub extern "C" fn ndarray_set_parallel_threshold(threshold: usize) -> i32 {
+    parallel::set_parallel_threshold(threshold);
+    SUCCESS
+}
+
+/// Return the element count at which kernels switch to the thread pool.
+#[no_mangle]
+pub extern "C" fn ndarray_get_parallel_threshold() -> usize {
+    parallel::parallel_threshold()
+}
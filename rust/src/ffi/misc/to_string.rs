@@ -3,14 +3,10 @@
 //! Provides human-readable string representation of arrays with proper
 //! formatting, truncation for large arrays, and support for all dimensions.
 
-use crate::helpers::{
-    extract_array_as_bool, extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
-    extract_array_as_f64, extract_array_as_i16, extract_array_as_i32, extract_array_as_i64,
-    extract_array_as_i8, extract_array_as_u16, extract_array_as_u32, extract_array_as_u64,
-    extract_array_as_u8,
-};
+use crate::helpers::CastView;
 use crate::types::{ArrayMetadata, NdArrayHandle};
 use crate::DType;
+use num_complex::{Complex32, Complex64};
 use std::io::Write;
 
 /// Format an array into a string buffer.
@@ -100,44 +96,63 @@ fn write_scalar<T: std::fmt::Display>(
     write!(buf, "{}", val)
 }
 
-fn format_0d(
+/// Write elements `[start, end)` of the view (logical C order), space separated.
+///
+/// Reads only the requested elements; the rest of the array is never copied.
+fn write_range(
     wrapper: &crate::types::NDArrayWrapper,
     meta: &ArrayMetadata,
     buf: &mut Vec<u8>,
+    start: usize,
+    end: usize,
     precision: usize,
 ) -> std::io::Result<()> {
-    macro_rules! format_scalar {
-        ($extract_fn:ident) => {{
-            if let Some(arr) = $extract_fn(wrapper, meta) {
-                if let Some(val) = arr.iter().next() {
-                    write_scalar(buf, val, precision)?;
+    let view = unsafe { CastView::new(wrapper, meta) };
+    let len = end.saturating_sub(start);
+    let mut first = true;
+
+    macro_rules! write_elems {
+        ($t:ty) => {
+            view.try_for_each_in(start, len, |val: $t| {
+                if !first {
+                    write!(buf, " ")?;
                 }
-            }
-        }};
+                first = false;
+                write_scalar(buf, &val, precision)
+            })
+        };
     }
 
     match wrapper.dtype {
-        DType::Float64 => format_scalar!(extract_array_as_f64),
-        DType::Float32 => format_scalar!(extract_array_as_f32),
-        DType::Int64 => format_scalar!(extract_array_as_i64),
-        DType::Int32 => format_scalar!(extract_array_as_i32),
-        DType::Int16 => format_scalar!(extract_array_as_i16),
-        DType::Int8 => format_scalar!(extract_array_as_i8),
-        DType::Uint64 => format_scalar!(extract_array_as_u64),
-        DType::Uint32 => format_scalar!(extract_array_as_u32),
-        DType::Uint16 => format_scalar!(extract_array_as_u16),
-        DType::Uint8 => format_scalar!(extract_array_as_u8),
-        DType::Complex64 => format_scalar!(extract_array_as_c64),
-        DType::Complex128 => format_scalar!(extract_array_as_c128),
-        DType::Bool => {
-            if let Some(arr) = extract_array_as_bool(wrapper, meta) {
-                if let Some(val) = arr.iter().next() {
-                    write!(buf, "{}", if *val != 0 { "true" } else { "false" })?;
-                }
+        DType::Float64 => write_elems!(f64),
+        DType::Float32 | DType::Float16 | DType::BFloat16 => write_elems!(f32),
+        DType::Int64 => write_elems!(i64),
+        DType::Int32 => write_elems!(i32),
+        DType::Int16 => write_elems!(i16),
+        DType::Int8 => write_elems!(i8),
+        DType::Uint64 => write_elems!(u64),
+        DType::Uint32 => write_elems!(u32),
+        DType::Uint16 => write_elems!(u16),
+        DType::Uint8 => write_elems!(u8),
+        DType::Complex64 => write_elems!(Complex32),
+        DType::Complex128 => write_elems!(Complex64),
+        DType::Bool => view.try_for_each_in(start, len, |val: u8| {
+            if !first {
+                write!(buf, " ")?;
             }
-        }
+            first = false;
+            write!(buf, "{}", if val != 0 { "true" } else { "false" })
+        }),
     }
-    Ok(())
+}
+
+fn format_0d(
+    wrapper: &crate::types::NDArrayWrapper,
+    meta: &ArrayMetadata,
+    buf: &mut Vec<u8>,
+    precision: usize,
+) -> std::io::Result<()> {
+    write_range(wrapper, meta, buf, 0, 1, precision)
 }
 
 fn format_1d(
@@ -172,44 +187,7 @@ fn format_1d_elements(
     end: usize,
     precision: usize,
 ) -> std::io::Result<()> {
-    macro_rules! format_elements {
-        ($extract_fn:ident) => {{
-            if let Some(arr) = $extract_fn(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate().skip(start).take(end - start) {
-                    if i > start {
-                        write!(buf, " ")?;
-                    }
-                    write_scalar(buf, val, precision)?;
-                }
-            }
-        }};
-    }
-
-    match wrapper.dtype {
-        DType::Float64 => format_elements!(extract_array_as_f64),
-        DType::Float32 => format_elements!(extract_array_as_f32),
-        DType::Int64 => format_elements!(extract_array_as_i64),
-        DType::Int32 => format_elements!(extract_array_as_i32),
-        DType::Int16 => format_elements!(extract_array_as_i16),
-        DType::Int8 => format_elements!(extract_array_as_i8),
-        DType::Uint64 => format_elements!(extract_array_as_u64),
-        DType::Uint32 => format_elements!(extract_array_as_u32),
-        DType::Uint16 => format_elements!(extract_array_as_u16),
-        DType::Uint8 => format_elements!(extract_array_as_u8),
-        DType::Complex64 => format_elements!(extract_array_as_c64),
-        DType::Complex128 => format_elements!(extract_array_as_c128),
-        DType::Bool => {
-            if let Some(arr) = extract_array_as_bool(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate().skip(start).take(end - start) {
-                    if i > start {
-                        write!(buf, " ")?;
-                    }
-                    write!(buf, "{}", if *val != 0 { "true" } else { "false" })?;
-                }
-            }
-        }
-    }
-    Ok(())
+    write_range(wrapper, meta, buf, start, end, precision)
 }
 
 fn format_2d(
@@ -262,45 +240,7 @@ fn format_2d_row(
     precision: usize,
 ) -> std::io::Result<()> {
     let start_idx = row * cols;
-
-    macro_rules! format_cols {
-        ($extract_fn:ident) => {{
-            if let Some(arr) = $extract_fn(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate().skip(start_idx).take(cols) {
-                    if i > start_idx {
-                        write!(buf, " ")?;
-                    }
-                    write_scalar(buf, val, precision)?;
-                }
-            }
-        }};
-    }
-
-    match wrapper.dtype {
-        DType::Float64 => format_cols!(extract_array_as_f64),
-        DType::Float32 => format_cols!(extract_array_as_f32),
-        DType::Int64 => format_cols!(extract_array_as_i64),
-        DType::Int32 => format_cols!(extract_array_as_i32),
-        DType::Int16 => format_cols!(extract_array_as_i16),
-        DType::Int8 => format_cols!(extract_array_as_i8),
-        DType::Uint64 => format_cols!(extract_array_as_u64),
-        DType::Uint32 => format_cols!(extract_array_as_u32),
-        DType::Uint16 => format_cols!(extract_array_as_u16),
-        DType::Uint8 => format_cols!(extract_array_as_u8),
-        DType::Complex64 => format_cols!(extract_array_as_c64),
-        DType::Complex128 => format_cols!(extract_array_as_c128),
-        DType::Bool => {
-            if let Some(arr) = extract_array_as_bool(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate().skip(start_idx).take(cols) {
-                    if i > start_idx {
-                        write!(buf, " ")?;
-                    }
-                    write!(buf, "{}", if *val != 0 { "true" } else { "false" })?;
-                }
-            }
-        }
-    }
-    Ok(())
+    write_range(wrapper, meta, buf, start_idx, start_idx + cols, precision)
 }
 
 fn format_3d(
@@ -374,44 +314,7 @@ fn format_3d_row(
     cols: usize,
     precision: usize,
 ) -> std::io::Result<()> {
-    macro_rules! format_cols {
-        ($extract_fn:ident) => {{
-            if let Some(arr) = $extract_fn(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate().skip(start_idx).take(cols) {
-                    if i > start_idx {
-                        write!(buf, " ")?;
-                    }
-                    write_scalar(buf, val, precision)?;
-                }
-            }
-        }};
-    }
-
-    match wrapper.dtype {
-        DType::Float64 => format_cols!(extract_array_as_f64),
-        DType::Float32 => format_cols!(extract_array_as_f32),
-        DType::Int64 => format_cols!(extract_array_as_i64),
-        DType::Int32 => format_cols!(extract_array_as_i32),
-        DType::Int16 => format_cols!(extract_array_as_i16),
-        DType::Int8 => format_cols!(extract_array_as_i8),
-        DType::Uint64 => format_cols!(extract_array_as_u64),
-        DType::Uint32 => format_cols!(extract_array_as_u32),
-        DType::Uint16 => format_cols!(extract_array_as_u16),
-        DType::Uint8 => format_cols!(extract_array_as_u8),
-        DType::Complex64 => format_cols!(extract_array_as_c64),
-        DType::Complex128 => format_cols!(extract_array_as_c128),
-        DType::Bool => {
-            if let Some(arr) = extract_array_as_bool(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate().skip(start_idx).take(cols) {
-                    if i > start_idx {
-                        write!(buf, " ")?;
-                    }
-                    write!(buf, "{}", if *val != 0 { "true" } else { "false" })?;
-                }
-            }
-        }
-    }
-    Ok(())
+    write_range(wrapper, meta, buf, start_idx, start_idx + cols, precision)
 }
 
 fn format_nd(
@@ -630,46 +533,13 @@ fn format_element_at_offset(
     buf: &mut Vec<u8>,
     precision: usize,
 ) -> std::io::Result<()> {
-    macro_rules! format_elem {
-        ($extract_fn:ident) => {{
-            if let Some(arr) = $extract_fn(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate() {
-                    if i == offset {
-                        write_scalar(buf, val, precision)?;
-                        break;
-                    }
-                }
-            }
-        }};
-    }
-
-    match wrapper.dtype {
-        DType::Float64 => format_elem!(extract_array_as_f64),
-        DType::Float32 => format_elem!(extract_array_as_f32),
-        DType::Int64 => format_elem!(extract_array_as_i64),
-        DType::Int32 => format_elem!(extract_array_as_i32),
-        DType::Int16 => format_elem!(extract_array_as_i16),
-        DType::Int8 => format_elem!(extract_array_as_i8),
-        DType::Uint64 => format_elem!(extract_array_as_u64),
-        DType::Uint32 => format_elem!(extract_array_as_u32),
-        DType::Uint16 => format_elem!(extract_array_as_u16),
-        DType::Uint8 => format_elem!(extract_array_as_u8),
-        DType::Complex64 => format_elem!(extract_array_as_c64),
-        DType::Complex128 => format_elem!(extract_array_as_c128),
-        DType::Bool => {
-            if let Some(arr) = extract_array_as_bool(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate() {
-                    if i == offset {
-                        write!(buf, "{}", if *val != 0 { "true" } else { "false" })?;
-                        break;
-                    }
-                }
-            }
-        }
-    }
-    Ok(())
+    write_range(wrapper, meta, buf, offset, offset + 1, precision)
 }
 
+/// Write elements `start..end` of an innermost row beginning at logical `base_offset`.
+///
+/// `stride` is the row's logical C-order stride, which is always 1 for the
+/// innermost dimension.
 fn format_elements_at_offset(
     wrapper: &crate::types::NDArrayWrapper,
     meta: &ArrayMetadata,
@@ -680,52 +550,13 @@ fn format_elements_at_offset(
     buf: &mut Vec<u8>,
     precision: usize,
 ) -> std::io::Result<()> {
-    macro_rules! format_elems {
-        ($extract_fn:ident) => {{
-            if let Some(arr) = $extract_fn(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate() {
-                    if i >= base_offset + start * stride && i < base_offset + end * stride {
-                        let idx = (i - base_offset) / stride;
-                        if idx as usize >= start && (idx as usize) < end {
-                            if idx as usize > start {
-                                write!(buf, " ")?;
-                            }
-                            write_scalar(buf, val, precision)?;
-                        }
-                    }
-                }
-            }
-        }};
-    }
-
-    match wrapper.dtype {
-        DType::Float64 => format_elems!(extract_array_as_f64),
-        DType::Float32 => format_elems!(extract_array_as_f32),
-        DType::Int64 => format_elems!(extract_array_as_i64),
-        DType::Int32 => format_elems!(extract_array_as_i32),
-        DType::Int16 => format_elems!(extract_array_as_i16),
-        DType::Int8 => format_elems!(extract_array_as_i8),
-        DType::Uint64 => format_elems!(extract_array_as_u64),
-        DType::Uint32 => format_elems!(extract_array_as_u32),
-        DType::Uint16 => format_elems!(extract_array_as_u16),
-        DType::Uint8 => format_elems!(extract_array_as_u8),
-        DType::Complex64 => format_elems!(extract_array_as_c64),
-        DType::Complex128 => format_elems!(extract_array_as_c128),
-        DType::Bool => {
-            if let Some(arr) = extract_array_as_bool(wrapper, meta) {
-                for (i, val) in arr.iter().enumerate() {
-                    if i >= base_offset + start * stride && i < base_offset + end * stride {
-                        let idx = (i - base_offset) / stride;
-                        if idx as usize >= start && (idx as usize) < end {
-                            if idx as usize > start {
-                                write!(buf, " ")?;
-                            }
-                            write!(buf, "{}", if *val != 0 { "true" } else { "false" })?;
-                        }
-                    }
-                }
-            }
-        }
-    }
-    Ok(())
+    debug_assert_eq!(stride, 1);
+    write_range(
+        wrapper,
+        meta,
+        buf,
+        base_offset + start,
+        base_offset + end,
+        precision,
+    )
 }
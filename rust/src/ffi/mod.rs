@@ -6,33 +6,51 @@ pub mod arithmetic;
 pub mod array;
 pub mod bitwise;
 pub mod comparison;
+pub mod distance;
 pub mod einsum;
 pub mod fft;
 pub mod generators;
+pub mod image;
 pub mod indexing;
+pub mod io;
 pub mod linalg;
 pub mod logical;
 pub mod math;
 pub mod misc;
+pub mod nn;
+pub mod poly;
+pub mod preprocessing;
 pub mod reductions;
+pub mod set_ops;
 pub mod shape_ops;
+pub mod signal;
 pub mod sorting;
 pub mod stacking;
+pub mod ufunc;
 pub mod windows;
 
 pub use arithmetic::*;
 pub use array::*;
 pub use bitwise::*;
 pub use comparison::*;
+pub use distance::*;
 pub use fft::*;
 pub use generators::*;
+pub use image::*;
 pub use indexing::*;
+pub use io::*;
 pub use linalg::*;
 pub use logical::*;
 pub use math::*;
 pub use misc::*;
+pub use nn::*;
+pub use poly::*;
+pub use preprocessing::*;
 pub use reductions::*;
+pub use set_ops::*;
 pub use shape_ops::*;
+pub use signal::*;
 pub use sorting::*;
 pub use stacking::*;
+pub use ufunc::*;
 pub use windows::*;
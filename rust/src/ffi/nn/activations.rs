@@ -0,0 +1,476 @@
+//! Activation functions: sigmoid, relu, leaky_relu, gelu, silu, softplus.
+//!
+//! Float32 input yields Float32, Float16/BFloat16 are computed in f32 and
+//! keep their dtype, and every other real dtype yields Float64. The
+//! `_inplace` variants overwrite a Float32 or Float64 view.
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::parallel;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_as_f64, extract_view_mut_f32, extract_view_mut_f64,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use num_traits::Float;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Activation {
+    /// `1 / (1 + exp(-x))`.
+    Sigmoid,
+    /// `max(x, 0)`.
+    Relu,
+    /// `x` for positive inputs, `alpha * x` otherwise.
+    LeakyRelu(f64),
+    /// Gaussian error linear unit, tanh approximation.
+    Gelu,
+    /// `x * sigmoid(x)`, also known as swish.
+    Silu,
+    /// `ln(1 + exp(x))`.
+    Softplus,
+}
+
+impl Activation {
+    fn name(self) -> &'static str {
+        match self {
+            Activation::Sigmoid => "sigmoid",
+            Activation::Relu => "relu",
+            Activation::LeakyRelu(_) => "leaky_relu",
+            Activation::Gelu => "gelu",
+            Activation::Silu => "silu",
+            Activation::Softplus => "softplus",
+        }
+    }
+
+    #[inline(always)]
+    fn apply<T: Float>(self, x: T) -> T {
+        let zero = T::zero();
+        let one = T::one();
+        match self {
+            Activation::Sigmoid => sigmoid(x),
+            // Written so that NaN falls through unchanged.
+            Activation::Relu => {
+                if x < zero {
+                    zero
+                } else {
+                    x
+                }
+            }
+            Activation::LeakyRelu(alpha) => {
+                if x < zero {
+                    x * T::from(alpha).unwrap()
+                } else {
+                    x
+                }
+            }
+            Activation::Gelu => {
+                let c = T::from(0.797_884_560_802_865_4).unwrap(); // sqrt(2 / pi)
+                let k = T::from(0.044_715).unwrap();
+                let half = T::from(0.5).unwrap();
+                half * x * (one + (c * (x + k * x * x * x)).tanh())
+            }
+            Activation::Silu => x * sigmoid(x),
+            // max(x, 0) + ln(1 + exp(-|x|)) never exponentiates a large value.
+            Activation::Softplus => x.max(zero) + (-x.abs()).exp().ln_1p(),
+        }
+    }
+}
+
+/// Logistic sigmoid that avoids `exp` overflow for large negative inputs.
+#[inline(always)]
+fn sigmoid<T: Float>(x: T) -> T {
+    if x >= T::zero() {
+        T::one() / (T::one() + (-x).exp())
+    } else {
+        let e = x.exp();
+        e / (T::one() + e)
+    }
+}
+
+unsafe fn apply_activation(
+    act: Activation,
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let extract_err = || {
+        (
+            ERR_DTYPE,
+            format!("Failed to extract {} view", wrapper.dtype),
+        )
+    };
+
+    Ok(match wrapper.dtype {
+        DType::Complex64 | DType::Complex128 => {
+            return Err((
+                ERR_DTYPE,
+                format!(
+                    "{} is not supported for {} arrays",
+                    act.name(),
+                    wrapper.dtype
+                ),
+            ));
+        }
+        DType::Float32 => {
+            let arr = extract_array_as_f32(wrapper, meta).ok_or_else(extract_err)?;
+            NDArrayWrapper {
+                data: ArrayData::Float32(Arc::new(RwLock::new(parallel::mapv_into(arr, |x| {
+                    act.apply(x)
+                })))),
+                dtype: DType::Float32,
+            }
+        }
+        DType::Float16 | DType::BFloat16 => {
+            let arr = extract_array_as_f32(wrapper, meta).ok_or_else(extract_err)?;
+            NDArrayWrapper::from_f32_as_half(
+                parallel::mapv_into(arr, |x| act.apply(x)),
+                wrapper.dtype,
+            )
+        }
+        _ => {
+            let arr = extract_array_as_f64(wrapper, meta).ok_or_else(extract_err)?;
+            NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(parallel::mapv_into(arr, |x| {
+                    act.apply(x)
+                })))),
+                dtype: DType::Float64,
+            }
+        }
+    })
+}
+
+unsafe fn apply_activation_inplace(
+    act: Activation,
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Result<(), (i32, String)> {
+    match wrapper.dtype {
+        DType::Float64 => {
+            let mut view = extract_view_mut_f64(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f64 view".to_string()))?;
+            view.mapv_inplace(|x| act.apply(x));
+        }
+        DType::Float32 => {
+            let mut view = extract_view_mut_f32(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f32 view".to_string()))?;
+            view.mapv_inplace(|x| act.apply(x));
+        }
+        other => {
+            return Err((
+                ERR_DTYPE,
+                format!(
+                    "In-place {} requires a float32 or float64 array, got {}",
+                    act.name(),
+                    other.name()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Shared body of the allocating FFI entry points.
+#[allow(clippy::too_many_arguments)]
+unsafe fn activation_entry(
+    name: &'static str,
+    act: Activation,
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || meta.is_null()
+        || out.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let meta = &*meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+
+        let result_wrapper = match apply_activation(act, a_wrapper, meta) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Shared body of the in-place FFI entry points.
+unsafe fn activation_inplace_entry(
+    name: &'static str,
+    act: Activation,
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+) -> i32 {
+    if a.is_null() || meta.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let meta = &*meta;
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+
+        if let Err((code, e)) = apply_activation_inplace(act, a_wrapper, meta) {
+            set_last_error(e);
+            return code;
+        }
+        SUCCESS
+    })
+}
+
+/// Compute sigmoid element-wise: 1 / (1 + exp(-x)).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_sigmoid(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    activation_entry(
+        "sigmoid",
+        Activation::Sigmoid,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// In-place variant of [`ndarray_sigmoid`] for Float32 and Float64 arrays.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_sigmoid_inplace(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+) -> i32 {
+    activation_inplace_entry("sigmoid_inplace", Activation::Sigmoid, a, meta)
+}
+
+/// Compute the rectified linear unit element-wise: max(x, 0).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_relu(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    activation_entry(
+        "relu",
+        Activation::Relu,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// In-place variant of [`ndarray_relu`] for Float32 and Float64 arrays.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_relu_inplace(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+) -> i32 {
+    activation_inplace_entry("relu_inplace", Activation::Relu, a, meta)
+}
+
+/// Compute leaky relu element-wise: x for x >= 0, alpha * x otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_leaky_relu(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    alpha: f64,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    activation_entry(
+        "leaky_relu",
+        Activation::LeakyRelu(alpha),
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// In-place variant of [`ndarray_leaky_relu`] for Float32 and Float64 arrays.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_leaky_relu_inplace(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    alpha: f64,
+) -> i32 {
+    activation_inplace_entry("leaky_relu_inplace", Activation::LeakyRelu(alpha), a, meta)
+}
+
+/// Compute GELU element-wise using the tanh approximation:
+/// 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3))).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_gelu(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    activation_entry(
+        "gelu",
+        Activation::Gelu,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// In-place variant of [`ndarray_gelu`] for Float32 and Float64 arrays.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_gelu_inplace(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+) -> i32 {
+    activation_inplace_entry("gelu_inplace", Activation::Gelu, a, meta)
+}
+
+/// Compute SiLU (swish) element-wise: x * sigmoid(x).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_silu(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    activation_entry(
+        "silu",
+        Activation::Silu,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// In-place variant of [`ndarray_silu`] for Float32 and Float64 arrays.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_silu_inplace(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+) -> i32 {
+    activation_inplace_entry("silu_inplace", Activation::Silu, a, meta)
+}
+
+/// Compute softplus element-wise: ln(1 + exp(x)), without overflow.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_softplus(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    activation_entry(
+        "softplus",
+        Activation::Softplus,
+        a,
+        meta,
+        out,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// In-place variant of [`ndarray_softplus`] for Float32 and Float64 arrays.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_softplus_inplace(
+    a: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+) -> i32 {
+    activation_inplace_entry("softplus_inplace", Activation::Softplus, a, meta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn activations_match_reference_values() {
+        let x = [-2.0f64, 0.0, 3.0];
+        let run = |act: Activation| x.iter().map(|&v| act.apply(v)).collect::<Vec<_>>();
+        assert_eq!(run(Activation::Relu), vec![0.0, 0.0, 3.0]);
+        assert_eq!(run(Activation::LeakyRelu(0.1)), vec![-0.2, 0.0, 3.0]);
+
+        let close = |got: Vec<f64>, want: [f64; 3]| {
+            for (g, w) in got.iter().zip(want) {
+                assert!((g - w).abs() < 1e-6, "{} != {}", g, w);
+            }
+        };
+        close(run(Activation::Sigmoid), [0.119_202_9, 0.5, 0.952_574_1]);
+        close(run(Activation::Silu), [-0.238_405_8, 0.0, 2.857_722_4]);
+        close(
+            run(Activation::Softplus),
+            [0.126_928_0, 0.693_147_2, 3.048_587_4],
+        );
+        close(run(Activation::Gelu), [-0.045_402_3, 0.0, 2.996_362_8]);
+    }
+
+    #[test]
+    fn extreme_inputs_stay_finite() {
+        assert_eq!(Activation::Sigmoid.apply(-1000.0f64), 0.0);
+        assert_eq!(Activation::Sigmoid.apply(1000.0f32), 1.0);
+        assert_eq!(Activation::Softplus.apply(1000.0f64), 1000.0);
+        assert_eq!(Activation::Softplus.apply(-1000.0f64), 0.0);
+        assert!(Activation::Relu.apply(f64::NAN).is_nan());
+    }
+}
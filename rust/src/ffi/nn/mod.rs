@@ -0,0 +1,8 @@
+//! Neural-network building blocks.
+//!
+//! Fused element-wise kernels for common activations, replacing chains such
+//! as `1 / (1 + exp(-x))` built from separate array operations.
+
+mod activations;
+
+pub use activations::*;
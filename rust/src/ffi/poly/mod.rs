@@ -0,0 +1,9 @@
+//! Polynomial evaluation, fitting and root finding.
+
+pub mod polyfit;
+pub mod polyval;
+pub mod roots;
+
+pub use polyfit::*;
+pub use polyval::*;
+pub use roots::*;
@@ -0,0 +1,165 @@
+//! Polynomial Fitting
+//!
+//! Least-squares fit of a degree-n polynomial via the Vandermonde matrix.
+
+use std::sync::Arc;
+
+use ndarray::{Array2, ArrayD, Ix1, Ix2};
+use ndarray_linalg::LeastSquaresSvd;
+use parking_lot::RwLock;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_MATH, ERR_SHAPE, SUCCESS};
+use crate::helpers::{extract_array_as_f64, write_output_metadata};
+use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
+
+/// Vandermonde matrix with columns `x^deg, ..., x^1, x^0`.
+fn vandermonde(x: &[f64], deg: usize) -> Array2<f64> {
+    let mut v = Array2::<f64>::zeros((x.len(), deg + 1));
+    for (i, &xi) in x.iter().enumerate() {
+        let mut p = 1.0;
+        for j in (0..=deg).rev() {
+            v[[i, j]] = p;
+            p *= xi;
+        }
+    }
+    v
+}
+
+/// Fit a polynomial of degree `deg` to the points `(x, y)` by least squares.
+///
+/// `x` must be 1D of length n; `y` is either 1D of length n or 2D of shape
+/// (n, k) to fit k data sets at once. Coefficients are returned highest power
+/// first with shape (deg + 1,) or (deg + 1, k). Columns of the Vandermonde
+/// matrix are scaled to unit norm before solving to improve conditioning.
+/// Float32 inputs yield Float32; other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_polyfit(
+    x: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    y: *const NdArrayHandle,
+    y_meta: *const ArrayMetadata,
+    deg: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if x.is_null()
+        || x_meta.is_null()
+        || y.is_null()
+        || y_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("polyfit", {
+        let x_wrapper = NdArrayHandle::as_wrapper(x as *mut _);
+        let y_wrapper = NdArrayHandle::as_wrapper(y as *mut _);
+        let x_meta = &*x_meta;
+        let y_meta = &*y_meta;
+
+        if x_wrapper.dtype.is_complex() || y_wrapper.dtype.is_complex() {
+            set_last_error("Polyfit does not support complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if x_meta.ndim != 1 {
+            set_last_error("Polyfit requires x to be 1D".to_string());
+            return ERR_SHAPE;
+        }
+        if y_meta.ndim != 1 && y_meta.ndim != 2 {
+            set_last_error("Polyfit requires y to be 1D or 2D".to_string());
+            return ERR_SHAPE;
+        }
+        let n = x_meta.shape_slice()[0];
+        if y_meta.shape_slice()[0] != n {
+            set_last_error(format!(
+                "Polyfit: x and y must have the same length along the first axis ({} vs {})",
+                n,
+                y_meta.shape_slice()[0]
+            ));
+            return ERR_SHAPE;
+        }
+        if n == 0 {
+            set_last_error("Polyfit requires at least one data point".to_string());
+            return ERR_SHAPE;
+        }
+
+        let (Some(x_arr), Some(y_arr)) = (
+            extract_array_as_f64(x_wrapper, x_meta),
+            extract_array_as_f64(y_wrapper, y_meta),
+        ) else {
+            set_last_error("Failed to extract arrays as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let x_vec: Vec<f64> = x_arr.iter().copied().collect();
+
+        let mut lhs = vandermonde(&x_vec, deg);
+        let scale: Vec<f64> = lhs
+            .columns()
+            .into_iter()
+            .map(|c| {
+                let norm = c.dot(&c).sqrt();
+                if norm > 0.0 {
+                    norm
+                } else {
+                    1.0
+                }
+            })
+            .collect();
+        for (mut col, &s) in lhs.columns_mut().into_iter().zip(&scale) {
+            col /= s;
+        }
+
+        let solved: Result<ArrayD<f64>, _> = if y_meta.ndim == 1 {
+            let y_1d = y_arr.into_dimensionality::<Ix1>().unwrap();
+            lhs.least_squares(&y_1d).map(|r| {
+                let mut c = r.solution;
+                c.iter_mut().zip(&scale).for_each(|(v, s)| *v /= s);
+                c.into_dyn()
+            })
+        } else {
+            let y_2d = y_arr.into_dimensionality::<Ix2>().unwrap();
+            lhs.least_squares(&y_2d).map(|r| {
+                let mut c = r.solution;
+                for (mut row, &s) in c.rows_mut().into_iter().zip(&scale) {
+                    row /= s;
+                }
+                c.into_dyn()
+            })
+        };
+        let coeffs = match solved {
+            Ok(c) => c,
+            Err(e) => {
+                set_last_error(format!("Polyfit computation failed: {:?}", e));
+                return ERR_MATH;
+            }
+        };
+
+        let result_wrapper =
+            if x_wrapper.dtype == DType::Float32 && y_wrapper.dtype == DType::Float32 {
+                NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(coeffs.mapv(|v| v as f32)))),
+                    dtype: DType::Float32,
+                }
+            } else {
+                NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(coeffs))),
+                    dtype: DType::Float64,
+                }
+            };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
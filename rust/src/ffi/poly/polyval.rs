@@ -0,0 +1,88 @@
+//! Polynomial Evaluation
+//!
+//! Evaluate a polynomial at every element of an array using Horner's scheme.
+
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::{extract_array_as_f64, write_output_metadata};
+use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
+
+/// Evaluate the polynomial with coefficients `p` (highest power first) at each element of `x`.
+///
+/// `p` must be 1D; the result has the shape of `x`. An empty `p` evaluates to
+/// zero everywhere. Float32 inputs yield Float32; other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_polyval(
+    p: *const NdArrayHandle,
+    p_meta: *const ArrayMetadata,
+    x: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if p.is_null()
+        || p_meta.is_null()
+        || x.is_null()
+        || x_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("polyval", {
+        let p_wrapper = NdArrayHandle::as_wrapper(p as *mut _);
+        let x_wrapper = NdArrayHandle::as_wrapper(x as *mut _);
+        let p_meta = &*p_meta;
+        let x_meta = &*x_meta;
+
+        if p_wrapper.dtype.is_complex() || x_wrapper.dtype.is_complex() {
+            set_last_error("Polyval does not support complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if p_meta.ndim != 1 {
+            set_last_error("Polyval requires the coefficients to be 1D".to_string());
+            return ERR_SHAPE;
+        }
+
+        let (Some(p_arr), Some(x_arr)) = (
+            extract_array_as_f64(p_wrapper, p_meta),
+            extract_array_as_f64(x_wrapper, x_meta),
+        ) else {
+            set_last_error("Failed to extract arrays as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let coeffs: Vec<f64> = p_arr.iter().copied().collect();
+        let result = x_arr.mapv(|xi| coeffs.iter().fold(0.0, |acc, &c| acc * xi + c));
+
+        let result_wrapper =
+            if p_wrapper.dtype == DType::Float32 && x_wrapper.dtype == DType::Float32 {
+                NDArrayWrapper {
+                    data: ArrayData::Float32(Arc::new(RwLock::new(result.mapv(|v| v as f32)))),
+                    dtype: DType::Float32,
+                }
+            } else {
+                NDArrayWrapper {
+                    data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+                    dtype: DType::Float64,
+                }
+            };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
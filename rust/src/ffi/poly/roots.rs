@@ -0,0 +1,131 @@
+//! Polynomial Roots
+//!
+//! Roots of a polynomial as the eigenvalues of its companion matrix.
+
+use std::sync::Arc;
+
+use ndarray::{Array1, Array2};
+use ndarray_linalg::EigVals;
+use num_complex::Complex64;
+use parking_lot::RwLock;
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_MATH, ERR_SHAPE, SUCCESS};
+use crate::helpers::{extract_array_as_f64, write_output_metadata};
+use crate::types::{ArrayData, ArrayMetadata, DType, NDArrayWrapper, NdArrayHandle};
+
+/// Roots of the polynomial with coefficients `coeffs` (highest power first).
+///
+/// Leading zeros are ignored and trailing zeros contribute roots at zero, so
+/// the companion matrix is built from the remaining non-degenerate part.
+fn polynomial_roots(coeffs: &[f64]) -> Result<Vec<Complex64>, String> {
+    let Some(first) = coeffs.iter().position(|&c| c != 0.0) else {
+        return Ok(Vec::new());
+    };
+    let last = coeffs.iter().rposition(|&c| c != 0.0).unwrap_or(first);
+    let trimmed = &coeffs[first..=last];
+    let zero_roots = coeffs.len() - 1 - last;
+
+    let degree = trimmed.len() - 1;
+    let mut roots = Vec::with_capacity(degree + zero_roots);
+    if degree > 0 {
+        let mut companion = Array2::<f64>::zeros((degree, degree));
+        for j in 0..degree {
+            companion[[0, j]] = -trimmed[j + 1] / trimmed[0];
+        }
+        for i in 1..degree {
+            companion[[i, i - 1]] = 1.0;
+        }
+        let eigvals = companion
+            .eigvals()
+            .map_err(|e| format!("Roots computation failed: {:?}", e))?;
+        roots.extend(eigvals.iter().copied());
+    }
+    roots.extend(std::iter::repeat_n(Complex64::new(0.0, 0.0), zero_roots));
+    Ok(roots)
+}
+
+fn real_wrapper(values: Array1<f64>, as_f32: bool) -> NDArrayWrapper {
+    if as_f32 {
+        NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(values.mapv(|v| v as f32).into_dyn()))),
+            dtype: DType::Float32,
+        }
+    } else {
+        NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(values.into_dyn()))),
+            dtype: DType::Float64,
+        }
+    }
+}
+
+/// Compute the roots of a polynomial via the companion-matrix eigenvalue method.
+///
+/// `p` is a 1D array of real coefficients, highest power first. The roots are
+/// written as two 1D arrays holding their real and imaginary parts. Float32
+/// input yields Float32 outputs; other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_roots(
+    p: *const NdArrayHandle,
+    p_meta: *const ArrayMetadata,
+    out_real: *mut *mut NdArrayHandle,
+    out_imag: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if p.is_null()
+        || p_meta.is_null()
+        || out_real.is_null()
+        || out_imag.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("roots", {
+        let wrapper = NdArrayHandle::as_wrapper(p as *mut _);
+        let meta = &*p_meta;
+
+        if wrapper.dtype.is_complex() {
+            set_last_error("Roots does not support complex coefficients".to_string());
+            return ERR_DTYPE;
+        }
+        if meta.ndim != 1 {
+            set_last_error("Roots requires a 1D array of coefficients".to_string());
+            return ERR_SHAPE;
+        }
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let coeffs: Vec<f64> = arr.iter().copied().collect();
+        if coeffs.iter().any(|c| !c.is_finite()) {
+            set_last_error("Roots requires finite coefficients".to_string());
+            return ERR_MATH;
+        }
+
+        let roots = match polynomial_roots(&coeffs) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_MATH;
+            }
+        };
+
+        let as_f32 = wrapper.dtype == DType::Float32;
+        let real = real_wrapper(roots.iter().map(|z| z.re).collect(), as_f32);
+        let imag = real_wrapper(roots.iter().map(|z| z.im).collect(), as_f32);
+
+        if let Err(e) = write_output_metadata(&real, out_dtype, out_ndim, out_shape, max_ndim) {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_real = NdArrayHandle::from_wrapper(Box::new(real));
+        *out_imag = NdArrayHandle::from_wrapper(Box::new(imag));
+        SUCCESS
+    })
+}
@@ -0,0 +1,93 @@
+//! L2 normalization of each lane to unit Euclidean norm.
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{extract_array_as_f64, normalize_axis, write_output_metadata};
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+use super::{float_result, map_lanes};
+
+/// Divide each lane along `axis` by its L2 norm: `x / max(||x||, epsilon)`.
+///
+/// `epsilon` keeps zero (or near-zero) lanes finite: an all-zero lane stays
+/// zero. Float32 input yields Float32; other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_l2_normalize(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    epsilon: f64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("l2_normalize", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if wrapper.dtype.is_complex() {
+            set_last_error("l2_normalize() is not supported for complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if epsilon.is_nan() || epsilon < 0.0 {
+            set_last_error("epsilon must be >= 0".to_string());
+            return ERR_VALUE;
+        }
+        if meta.ndim == 0 {
+            set_last_error("l2_normalize() requires at least one dimension".to_string());
+            return ERR_SHAPE;
+        }
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let result = map_lanes(&arr, axis, |_, lane, mut out| {
+            // Scale by the largest magnitude first so squaring cannot overflow.
+            let peak = lane.iter().fold(0.0f64, |m, &x| m.max(x.abs()));
+            let norm = if peak > 0.0 && peak.is_finite() {
+                peak * lane.iter().map(|&x| (x / peak).powi(2)).sum::<f64>().sqrt()
+            } else {
+                peak
+            };
+            if norm.is_nan() {
+                out.fill(f64::NAN);
+            } else if norm.max(epsilon) > 0.0 {
+                let denom = norm.max(epsilon);
+                out.zip_mut_with(&lane, |o, &x| *o = x / denom);
+            }
+        });
+
+        let result_wrapper = float_result(wrapper.dtype, result);
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,132 @@
+//! Min-max scaling of each lane to a target range.
+
+use ndarray::{ArrayD, IxDyn};
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{extract_array_as_f64, normalize_axis, write_output_metadata};
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+use super::{float_result, map_lanes};
+
+/// Scale each lane along `axis` linearly so its range maps to `[feature_min, feature_max]`.
+///
+/// Per lane, `scale = (feature_max - feature_min) / (data_max - data_min)` and
+/// `y = (x - data_min) * scale + feature_min`. NaN values are ignored when
+/// finding the range and stay NaN. Constant lanes use a data range of 1, so
+/// they map to `feature_min`.
+///
+/// When `out_data_min` / `out_scale` are non-null they receive the per-lane
+/// `data_min` and `scale` (input shape with `axis` removed, same dtype as the
+/// result), so `x = (y - feature_min) / scale + data_min` inverts the transform.
+/// Float32 input yields Float32; other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_minmax_scale(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    feature_min: f64,
+    feature_max: f64,
+    out_data_min: *mut *mut NdArrayHandle,
+    out_scale: *mut *mut NdArrayHandle,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("minmax_scale", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if wrapper.dtype.is_complex() {
+            set_last_error("minmax_scale() is not supported for complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if feature_min.is_nan() || feature_max.is_nan() || feature_min >= feature_max {
+            set_last_error(format!(
+                "Feature range minimum ({}) must be less than maximum ({})",
+                feature_min, feature_max
+            ));
+            return ERR_VALUE;
+        }
+        if meta.ndim == 0 {
+            set_last_error("minmax_scale() requires at least one dimension".to_string());
+            return ERR_SHAPE;
+        }
+
+        let shape = meta.shape_slice();
+        let axis = match normalize_axis(shape, axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let target = feature_max - feature_min;
+        let mut data_mins = Vec::new();
+        let mut scales = Vec::new();
+        let result = map_lanes(&arr, axis, |_, lane, mut out| {
+            let (lo, hi) = lane
+                .iter()
+                .filter(|x| !x.is_nan())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| {
+                    (lo.min(x), hi.max(x))
+                });
+            let (data_min, scale) = if lo > hi {
+                // Empty or all-NaN lane.
+                (f64::NAN, f64::NAN)
+            } else if hi == lo {
+                (lo, target)
+            } else {
+                (lo, target / (hi - lo))
+            };
+            out.zip_mut_with(&lane, |o, &x| *o = (x - data_min) * scale + feature_min);
+            data_mins.push(data_min);
+            scales.push(scale);
+        });
+
+        let mut reduced = shape.to_vec();
+        reduced.remove(axis);
+        for (out, values) in [(out_data_min, data_mins), (out_scale, scales)] {
+            if out.is_null() {
+                continue;
+            }
+            let params = match ArrayD::from_shape_vec(IxDyn(&reduced), values) {
+                Ok(p) => p,
+                Err(e) => {
+                    set_last_error(format!("Failed to build scaling parameters: {}", e));
+                    return ERR_GENERIC;
+                }
+            };
+            *out = NdArrayHandle::from_wrapper(Box::new(float_result(wrapper.dtype, params)));
+        }
+
+        let result_wrapper = float_result(wrapper.dtype, result);
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
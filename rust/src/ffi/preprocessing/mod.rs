@@ -0,0 +1,127 @@
+//! Feature preprocessing for ML pipelines.
+//!
+//! Fused kernels that replace chains of reductions and broadcast arithmetic
+//! (standardize, scale, normalize) with a single pass per lane. Computation
+//! happens in f64; Float32 input yields Float32 and every other real dtype
+//! yields Float64.
+
+mod l2_normalize;
+mod minmax_scale;
+mod standardize;
+
+pub use l2_normalize::*;
+pub use minmax_scale::*;
+pub use standardize::*;
+
+use ndarray::{ArrayD, Axis};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::{extract_array_as_f64, extract_view_mut_f32, extract_view_mut_f64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Load optional per-lane parameters (one value per lane along `axis`).
+///
+/// Accepted shapes are the input shape with `axis` removed, the same with
+/// `axis` kept as length 1, or a single element broadcast to every lane.
+/// Returns `Ok(None)` when `handle` is null.
+pub(crate) unsafe fn lane_params(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    shape: &[usize],
+    axis: usize,
+    name: &str,
+) -> Result<Option<Vec<f64>>, String> {
+    if handle.is_null() || meta.is_null() {
+        return Ok(None);
+    }
+    let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+    let meta = &*meta;
+    if wrapper.dtype.is_complex() {
+        return Err(format!("{} must be a real array", name));
+    }
+
+    let mut reduced = shape.to_vec();
+    reduced.remove(axis);
+    let mut kept = shape.to_vec();
+    kept[axis] = 1;
+    let lanes: usize = reduced.iter().product();
+
+    let given = meta.shape_slice();
+    let size: usize = given.iter().product();
+    if given != reduced.as_slice() && given != kept.as_slice() && size != 1 {
+        return Err(format!(
+            "{} has shape {:?}, expected {:?} or {:?}",
+            name, given, reduced, kept
+        ));
+    }
+
+    let arr = extract_array_as_f64(wrapper, meta)
+        .ok_or_else(|| format!("Failed to extract {} as f64", name))?;
+    let values: Vec<f64> = arr.iter().copied().collect();
+    Ok(Some(if size == 1 {
+        vec![values[0]; lanes]
+    } else {
+        values
+    }))
+}
+
+/// Apply `f(lane_index, input_lane, output_lane)` to every lane along `axis`.
+pub(crate) fn map_lanes<F>(input: &ArrayD<f64>, axis: usize, mut f: F) -> ArrayD<f64>
+where
+    F: FnMut(usize, ndarray::ArrayView1<f64>, ndarray::ArrayViewMut1<f64>),
+{
+    let mut result = ArrayD::<f64>::zeros(input.raw_dim());
+    for (i, (lane, out)) in input
+        .lanes(Axis(axis))
+        .into_iter()
+        .zip(result.lanes_mut(Axis(axis)))
+        .enumerate()
+    {
+        f(i, lane, out);
+    }
+    result
+}
+
+/// Wrap an f64 result, narrowing to Float32 when the input was Float32.
+pub(crate) fn float_result(input_dtype: DType, result: ArrayD<f64>) -> NDArrayWrapper {
+    if input_dtype == DType::Float32 {
+        NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(result.mapv(|x| x as f32)))),
+            dtype: DType::Float32,
+        }
+    } else {
+        NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+            dtype: DType::Float64,
+        }
+    }
+}
+
+/// Write `result` back into a Float32 or Float64 view.
+pub(crate) unsafe fn write_back(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    result: &ArrayD<f64>,
+) -> Result<(), String> {
+    match wrapper.dtype {
+        DType::Float64 => {
+            let mut view = extract_view_mut_f64(wrapper, meta)
+                .ok_or_else(|| "Failed to extract f64 view".to_string())?;
+            view.assign(result);
+        }
+        DType::Float32 => {
+            let mut view = extract_view_mut_f32(wrapper, meta)
+                .ok_or_else(|| "Failed to extract f32 view".to_string())?;
+            view.zip_mut_with(result, |dst, &src| *dst = src as f32);
+        }
+        other => {
+            return Err(format!(
+                "In-place preprocessing requires a float32 or float64 array, got {}",
+                other.name()
+            ))
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,202 @@
+//! Z-score standardization: `(x - mean) / (std + epsilon)` along an axis.
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{extract_array_as_f64, normalize_axis, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::ArrayD;
+
+use super::{float_result, lane_params, map_lanes, write_back};
+
+/// Compute the standardized array, returning an error code and message on failure.
+#[allow(clippy::too_many_arguments)]
+unsafe fn standardize_impl(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    axis: i32,
+    mean_handle: *const NdArrayHandle,
+    mean_meta: *const ArrayMetadata,
+    std_handle: *const NdArrayHandle,
+    std_meta: *const ArrayMetadata,
+    ddof: usize,
+    epsilon: f64,
+) -> Result<ArrayD<f64>, (i32, String)> {
+    if wrapper.dtype.is_complex() {
+        return Err((
+            ERR_DTYPE,
+            "standardize() is not supported for complex arrays".to_string(),
+        ));
+    }
+    if meta.ndim == 0 {
+        return Err((
+            ERR_SHAPE,
+            "standardize() requires at least one dimension".to_string(),
+        ));
+    }
+    if epsilon.is_nan() || epsilon < 0.0 {
+        return Err((ERR_VALUE, "epsilon must be >= 0".to_string()));
+    }
+
+    let shape = meta.shape_slice();
+    let axis = normalize_axis(shape, axis, false).map_err(|e| (ERR_SHAPE, e))?;
+    let means =
+        lane_params(mean_handle, mean_meta, shape, axis, "mean").map_err(|e| (ERR_SHAPE, e))?;
+    let stds = lane_params(std_handle, std_meta, shape, axis, "std").map_err(|e| (ERR_SHAPE, e))?;
+
+    let arr = extract_array_as_f64(wrapper, meta)
+        .ok_or_else(|| (ERR_DTYPE, "Failed to extract array as f64".to_string()))?;
+
+    Ok(map_lanes(&arr, axis, |i, lane, mut out| {
+        let (mean, std) = match (&means, &stds) {
+            (Some(m), Some(s)) => (m[i], s[i]),
+            _ => {
+                // Welford's update gives mean and variance in a single pass.
+                let (mut count, mut mean, mut m2) = (0usize, 0.0f64, 0.0f64);
+                for &x in lane.iter() {
+                    count += 1;
+                    let delta = x - mean;
+                    mean += delta / count as f64;
+                    m2 += delta * (x - mean);
+                }
+                let std = if count > ddof {
+                    (m2 / (count - ddof) as f64).sqrt()
+                } else {
+                    f64::NAN
+                };
+                (
+                    means.as_ref().map_or(mean, |m| m[i]),
+                    stds.as_ref().map_or(std, |s| s[i]),
+                )
+            }
+        };
+        let denom = std + epsilon;
+        out.zip_mut_with(&lane, |o, &x| *o = (x - mean) / denom);
+    }))
+}
+
+/// Standardize along `axis`: `(x - mean) / (std + epsilon)`.
+///
+/// `mean_handle` / `std_handle` may be null, in which case the statistic is
+/// computed per lane (standard deviation with `ddof` delta degrees of freedom).
+/// Supplied statistics must have the input shape with `axis` removed, or kept
+/// as length 1, or be a single value. Float32 input yields Float32; other real
+/// dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_standardize(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    mean_handle: *const NdArrayHandle,
+    mean_meta: *const ArrayMetadata,
+    std_handle: *const NdArrayHandle,
+    std_meta: *const ArrayMetadata,
+    ddof: usize,
+    epsilon: f64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("standardize", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let result = match standardize_impl(
+            wrapper,
+            meta,
+            axis,
+            mean_handle,
+            mean_meta,
+            std_handle,
+            std_meta,
+            ddof,
+            epsilon,
+        ) {
+            Ok(r) => r,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        let result_wrapper = float_result(wrapper.dtype, result);
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// In-place variant of [`ndarray_standardize`], overwriting the view.
+///
+/// Only Float32 and Float64 arrays can be standardized in place.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_standardize_inplace(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    mean_handle: *const NdArrayHandle,
+    mean_meta: *const ArrayMetadata,
+    std_handle: *const NdArrayHandle,
+    std_meta: *const ArrayMetadata,
+    ddof: usize,
+    epsilon: f64,
+) -> i32 {
+    if handle.is_null() || meta.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("standardize_inplace", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if !matches!(wrapper.dtype, DType::Float32 | DType::Float64) {
+            set_last_error(format!(
+                "In-place standardize requires a float32 or float64 array, got {}",
+                wrapper.dtype.name()
+            ));
+            return ERR_DTYPE;
+        }
+
+        let result = match standardize_impl(
+            wrapper,
+            meta,
+            axis,
+            mean_handle,
+            mean_meta,
+            std_handle,
+            std_meta,
+            ddof,
+            epsilon,
+        ) {
+            Ok(r) => r,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) = write_back(wrapper, meta, &result) {
+            set_last_error(e);
+            return ERR_DTYPE;
+        }
+        SUCCESS
+    })
+}
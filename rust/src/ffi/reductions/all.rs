@@ -7,7 +7,7 @@ use ndarray::Axis;
 use parking_lot::RwLock;
 
 use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::extract_array_as_bool;
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
@@ -28,12 +28,12 @@ pub unsafe extern "C" fn ndarray_all(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("all", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let Some(arr) = extract_array_as_bool(wrapper, meta) else {
             set_last_error("Failed to extract view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
 
         let result: u8 = arr.iter().all(|&x| x != 0) as u8;
@@ -67,7 +67,7 @@ pub unsafe extern "C" fn ndarray_all_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("all_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -81,7 +81,7 @@ pub unsafe extern "C" fn ndarray_all_axis(
 
         let Some(arr) = extract_array_as_bool(wrapper, meta) else {
             set_last_error("Failed to extract view".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
 
         let result = arr.fold_axis(Axis(axis_usize), 1u8, |&acc, &x| acc & x);
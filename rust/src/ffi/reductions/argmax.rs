@@ -3,12 +3,15 @@
 use crate::ffi::reductions::helpers::{
     compute_axis_output_shape, write_reduction_scalar, ReductionScalar,
 };
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f64, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -41,7 +44,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("argmax_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -58,14 +61,14 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
 
         if axis_len == 0 {
             set_last_error("Cannot compute argmax along empty axis".to_string());
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let result_arr: ArrayD<i64> = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -75,10 +78,10 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
                         .unwrap_or(0)
                 })
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -91,7 +94,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -104,7 +107,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -117,7 +120,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -130,7 +133,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -143,7 +146,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -156,7 +159,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -169,7 +172,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -182,7 +185,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -194,11 +197,11 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("argmax_axis() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("argmax_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -229,7 +232,7 @@ pub unsafe extern "C" fn ndarray_argmax_axis(
 }
 
 /// Compute the index of the maximum element.
-/// Returns Int64 index.
+/// Returns Int64 index; a zero-size array is rejected.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_argmax(
     handle: *const NdArrayHandle,
@@ -241,16 +244,21 @@ pub unsafe extern "C" fn ndarray_argmax(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("argmax", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let meta = &*meta;
 
+        if meta.shape_slice().contains(&0) {
+            set_last_error("Cannot compute argmax of an empty array".to_string());
+            return ERR_VALUE;
+        }
+
         let argmax_result = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -258,10 +266,10 @@ pub unsafe extern "C" fn ndarray_argmax(
                     .map(|(idx, _)| idx as i64)
                     .unwrap_or(-1)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -272,7 +280,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -283,7 +291,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -294,7 +302,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -305,7 +313,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -316,7 +324,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -327,7 +335,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -338,7 +346,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -349,7 +357,7 @@ pub unsafe extern "C" fn ndarray_argmax(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -359,11 +367,11 @@ pub unsafe extern "C" fn ndarray_argmax(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("argmax() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("argmax() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
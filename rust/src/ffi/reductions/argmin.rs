@@ -3,12 +3,15 @@
 use crate::ffi::reductions::helpers::{
     compute_axis_output_shape, write_reduction_scalar, ReductionScalar,
 };
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f64, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -41,7 +44,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("argmin_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -58,7 +61,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
 
         if axis_len == 0 {
             set_last_error("Cannot compute argmin along empty axis".to_string());
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         // Match on dtype, extract view, compute argmin along axis, and create result wrapper
@@ -66,7 +69,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -76,10 +79,10 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
                         .unwrap_or(0)
                 })
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -92,7 +95,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -105,7 +108,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -118,7 +121,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -131,7 +134,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -144,7 +147,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -157,7 +160,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -170,7 +173,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -183,7 +186,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.map_axis(ndarray::Axis(axis_usize), |lane| {
                     lane.iter()
@@ -195,11 +198,11 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("argmin_axis() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("argmin_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -228,7 +231,7 @@ pub unsafe extern "C" fn ndarray_argmin_axis(
 }
 
 /// Compute the index of the minimum element.
-/// Returns Int64 index.
+/// Returns Int64 index; a zero-size array is rejected.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_argmin(
     handle: *const NdArrayHandle,
@@ -240,16 +243,21 @@ pub unsafe extern "C" fn ndarray_argmin(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("argmin", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let meta = &*meta;
 
+        if meta.shape_slice().contains(&0) {
+            set_last_error("Cannot compute argmin of an empty array".to_string());
+            return ERR_VALUE;
+        }
+
         let argmin_result = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -257,10 +265,10 @@ pub unsafe extern "C" fn ndarray_argmin(
                     .map(|(idx, _)| idx as i64)
                     .unwrap_or(-1)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -271,7 +279,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -282,7 +290,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -293,7 +301,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -304,7 +312,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -315,7 +323,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -326,7 +334,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -337,7 +345,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -348,7 +356,7 @@ pub unsafe extern "C" fn ndarray_argmin(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 arr.iter()
                     .enumerate()
@@ -358,11 +366,11 @@ pub unsafe extern "C" fn ndarray_argmin(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("argmin() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("argmin() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -65,7 +65,7 @@ pub unsafe extern "C" fn ndarray_bincount(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("bincount", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let meta = &*meta;
@@ -74,7 +74,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().copied(), minlength) {
                     Ok(v) => v,
@@ -87,7 +87,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -100,7 +100,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -113,7 +113,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -126,7 +126,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -139,7 +139,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -152,7 +152,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -165,7 +165,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -178,7 +178,7 @@ pub unsafe extern "C" fn ndarray_bincount(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 match bincount_from_iter(arr.iter().map(|&x| x as i64), minlength) {
                     Ok(v) => v,
@@ -188,7 +188,12 @@ pub unsafe extern "C" fn ndarray_bincount(
                     }
                 }
             }
-            DType::Float32 | DType::Float64 | DType::Complex64 | DType::Complex128 => {
+            DType::Float16
+            | DType::BFloat16
+            | DType::Float32
+            | DType::Float64
+            | DType::Complex64
+            | DType::Complex128 => {
                 error::set_last_error("bincount requires integer or bool dtype".to_string());
                 return ERR_DTYPE;
             }
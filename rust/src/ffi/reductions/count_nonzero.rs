@@ -0,0 +1,122 @@
+//! Count of non-zero elements.
+
+use std::ffi::c_void;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, Axis};
+use parking_lot::RwLock;
+
+use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{extract_array_as_bool, extract_array_c128, extract_array_c64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Non-zero mask of the array; complex values count if either part is non-zero.
+unsafe fn nonzero_mask(wrapper: &NDArrayWrapper, meta: &ArrayMetadata) -> Option<ArrayD<u8>> {
+    match wrapper.dtype {
+        DType::Complex64 => {
+            extract_array_c64(wrapper, meta).map(|a| a.mapv(|x| (x.re != 0.0 || x.im != 0.0) as u8))
+        }
+        DType::Complex128 => extract_array_c128(wrapper, meta)
+            .map(|a| a.mapv(|x| (x.re != 0.0 || x.im != 0.0) as u8)),
+        _ => extract_array_as_bool(wrapper, meta),
+    }
+}
+
+/// Count the non-zero elements of the array (scalar Int64).
+///
+/// NaN counts as non-zero.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_count_nonzero(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_value.is_null() || out_dtype.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!("count_nonzero", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        let Some(mask) = nonzero_mask(wrapper, meta) else {
+            set_last_error("Failed to extract view".to_string());
+            return ERR_DTYPE;
+        };
+
+        let count = mask.iter().filter(|&&x| x != 0).count() as i64;
+        write_reduction_scalar(out_value, out_dtype, ReductionScalar::I64(count));
+        SUCCESS
+    })
+}
+
+/// Count the non-zero elements along an axis (Int64 result).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_count_nonzero_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || out_handle.is_null()
+        || meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!("count_nonzero_axis", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let shape_slice = meta.shape_slice();
+
+        let axis_usize = match normalize_axis(shape_slice, axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let Some(mask) = nonzero_mask(wrapper, meta) else {
+            set_last_error("Failed to extract view".to_string());
+            return ERR_DTYPE;
+        };
+
+        let result = mask.fold_axis(Axis(axis_usize), 0i64, |&acc, &x| acc + (x != 0) as i64);
+        let final_arr: ArrayD<i64> = if keepdims {
+            result.insert_axis(Axis(axis_usize)).into_dyn()
+        } else {
+            result.into_dyn()
+        };
+
+        let result_wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(final_arr))),
+            dtype: DType::Int64,
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
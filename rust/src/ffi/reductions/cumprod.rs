@@ -1,12 +1,12 @@
 //! Cumulative product reduction.
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
+    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -36,14 +36,14 @@ pub unsafe extern "C" fn ndarray_cumprod(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("cumprod", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned();
                 let result = flat.cumprod(Axis(0)).into_dyn();
@@ -55,7 +55,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -63,10 +63,18 @@ pub unsafe extern "C" fn ndarray_cumprod(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -77,7 +85,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -88,7 +96,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -99,7 +107,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -110,7 +118,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -121,7 +129,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -132,7 +140,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -143,7 +151,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -154,7 +162,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -165,7 +173,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.flatten().into_owned().cumprod(Axis(0)).into_dyn();
                 NDArrayWrapper {
@@ -175,7 +183,7 @@ pub unsafe extern "C" fn ndarray_cumprod(
             }
             DType::Bool => {
                 set_last_error("cumprod() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -213,7 +221,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("cumprod_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -229,7 +237,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -240,7 +248,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -248,10 +256,18 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = arr.cumprod(Axis(axis_usize));
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -262,7 +278,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -273,7 +289,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -284,7 +300,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -295,7 +311,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -306,7 +322,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -317,7 +333,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -328,7 +344,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -339,7 +355,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -350,7 +366,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.cumprod(Axis(axis_usize));
                 NDArrayWrapper {
@@ -360,7 +376,7 @@ pub unsafe extern "C" fn ndarray_cumprod_axis(
             }
             DType::Bool => {
                 set_last_error("cumprod_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
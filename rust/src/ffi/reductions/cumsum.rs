@@ -2,13 +2,13 @@
 
 use std::ops::AddAssign;
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f32,
+    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
+    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -38,14 +38,14 @@ pub unsafe extern "C" fn ndarray_cumsum(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("cumsum", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -60,7 +60,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -72,10 +72,22 @@ pub unsafe extern "C" fn ndarray_cumsum(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let mut flat = arr.flatten().into_owned();
+                flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
+                    curr.add_assign(*prev);
+                });
+                let result = flat.into_dyn();
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -90,7 +102,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -105,7 +117,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -120,7 +132,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -135,7 +147,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -150,7 +162,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -165,7 +177,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -180,7 +192,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -195,7 +207,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -210,7 +222,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flat = arr.flatten().into_owned();
                 flat.accumulate_axis_inplace(Axis(0), |prev, curr| {
@@ -224,7 +236,7 @@ pub unsafe extern "C" fn ndarray_cumsum(
             }
             DType::Bool => {
                 set_last_error("cumsum() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -262,7 +274,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("cumsum_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -278,7 +290,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -292,7 +304,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -303,10 +315,21 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let mut result = arr.to_owned();
+                result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
+                    curr.add_assign(*prev);
+                });
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -320,7 +343,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -334,7 +357,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -348,7 +371,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -362,7 +385,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -376,7 +399,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -390,7 +413,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -404,7 +427,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -418,7 +441,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -432,7 +455,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut result = arr.to_owned();
                 result.accumulate_axis_inplace(Axis(axis_usize), |prev, curr| {
@@ -445,7 +468,7 @@ pub unsafe extern "C" fn ndarray_cumsum_axis(
             }
             DType::Bool => {
                 set_last_error("cumsum_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
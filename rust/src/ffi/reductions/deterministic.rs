@@ -0,0 +1,21 @@
+//! Deterministic-reduction mode.
+
+use crate::ffi::reductions::summation;
+use crate::helpers::error::SUCCESS;
+
+/// Enable or disable deterministic float reductions for the whole process.
+///
+/// When enabled, sum/mean/var/std visit elements in logical C order with a
+/// reduction tree fixed by the element count, so repeated runs on equal data
+/// produce bit-identical results regardless of memory layout or threading.
+#[no_mangle]
+pub extern "C" fn ndarray_set_deterministic(enabled: bool) -> i32 {
+    summation::set_deterministic(enabled);
+    SUCCESS
+}
+
+/// Return whether deterministic float reductions are enabled.
+#[no_mangle]
+pub extern "C" fn ndarray_is_deterministic() -> bool {
+    summation::is_deterministic()
+}
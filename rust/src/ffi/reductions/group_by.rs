@@ -0,0 +1,194 @@
+//! Group-by aggregation of a value column by a key column.
+//!
+//! Keys are sorted once to find the unique groups, then every aggregate is a
+//! segment reduction over the resulting group ids.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use super::segment::{segment_reduce, SegmentOp};
+use crate::ffi::set_ops::helpers::unique_inverse;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS,
+};
+use crate::helpers::{
+    extract_array_as_f64, extract_array_bool, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+macro_rules! group_keys_arm {
+    ($wrapper:expr, $meta:expr, $extract:ident, $variant:ident) => {{
+        let Some(arr) = $extract($wrapper, $meta) else {
+            set_last_error(format!("Failed to extract {} keys", stringify!($variant)));
+            return ERR_DTYPE;
+        };
+        let keys: Vec<_> = arr.iter().copied().collect();
+        let (unique, ids) = unique_inverse(&keys);
+        let n = unique.len();
+        let unique = ArrayD::from_shape_vec(IxDyn(&[n]), unique).unwrap();
+        (
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(unique))),
+                dtype: DType::$variant,
+            },
+            ids,
+        )
+    }};
+}
+
+/// Wrap a flat f64 aggregate as Float32 or Float64 with the given shape.
+fn aggregate_wrapper(values: Vec<f64>, shape: &[usize], as_f32: bool) -> NDArrayWrapper {
+    let arr = ArrayD::from_shape_vec(IxDyn(shape), values).unwrap();
+    if as_f32 {
+        NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(arr.mapv(|x| x as f32)))),
+            dtype: DType::Float32,
+        }
+    } else {
+        NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
+            dtype: DType::Float64,
+        }
+    }
+}
+
+/// Group `values` rows by `keys` and compute count, sum, mean, min and max per group.
+///
+/// `keys` is a 1D array of any real or Bool dtype; `values` must have one row per
+/// key along axis 0. Groups are returned in ascending key order, with NaN keys
+/// last. `out_keys` has the keys' dtype and `out_counts` is Int64, both of
+/// shape `(groups,)`. The aggregates have shape `(groups, ...)` and are Float32
+/// for Float32 values, Float64 otherwise.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_group_by(
+    keys_handle: *const NdArrayHandle,
+    keys_meta: *const ArrayMetadata,
+    values_handle: *const NdArrayHandle,
+    values_meta: *const ArrayMetadata,
+    out_keys: *mut *mut NdArrayHandle,
+    out_counts: *mut *mut NdArrayHandle,
+    out_sum: *mut *mut NdArrayHandle,
+    out_mean: *mut *mut NdArrayHandle,
+    out_min: *mut *mut NdArrayHandle,
+    out_max: *mut *mut NdArrayHandle,
+    out_num_groups: *mut usize,
+) -> i32 {
+    if keys_handle.is_null()
+        || keys_meta.is_null()
+        || values_handle.is_null()
+        || values_meta.is_null()
+        || out_keys.is_null()
+        || out_counts.is_null()
+        || out_sum.is_null()
+        || out_mean.is_null()
+        || out_min.is_null()
+        || out_max.is_null()
+        || out_num_groups.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("group_by", {
+        let keys_wrapper = NdArrayHandle::as_wrapper(keys_handle as *mut _);
+        let values_wrapper = NdArrayHandle::as_wrapper(values_handle as *mut _);
+        let keys_meta = &*keys_meta;
+        let values_meta = &*values_meta;
+
+        if keys_wrapper.dtype.is_complex() || values_wrapper.dtype.is_complex() {
+            set_last_error("group_by does not support complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if keys_meta.ndim != 1 {
+            set_last_error(format!(
+                "group_by keys must be 1D, got {} dimensions",
+                keys_meta.ndim
+            ));
+            return ERR_SHAPE;
+        }
+        let values_shape = values_meta.shape_slice();
+        if values_shape.first() != Some(&keys_meta.shape_slice()[0]) {
+            set_last_error(format!(
+                "group_by values shape {:?} must have {} rows to match the keys",
+                values_shape,
+                keys_meta.shape_slice()[0]
+            ));
+            return ERR_SHAPE;
+        }
+
+        let (keys_out, ids) = match keys_wrapper.dtype {
+            DType::Int64 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_i64, Int64),
+            DType::Int32 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_i32, Int32),
+            DType::Int16 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_i16, Int16),
+            DType::Int8 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_i8, Int8),
+            DType::Uint64 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_u64, Uint64),
+            DType::Uint32 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_u32, Uint32),
+            DType::Uint16 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_u16, Uint16),
+            DType::Uint8 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_u8, Uint8),
+            DType::Bool => group_keys_arm!(keys_wrapper, keys_meta, extract_array_bool, Bool),
+            DType::Float64 => group_keys_arm!(keys_wrapper, keys_meta, extract_array_f64, Float64),
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                group_keys_arm!(keys_wrapper, keys_meta, extract_array_f32, Float32)
+            }
+            DType::Complex64 | DType::Complex128 => unreachable!(),
+        };
+        let num_groups = keys_out.len();
+
+        let Some(values) = extract_array_as_f64(values_wrapper, values_meta) else {
+            set_last_error("Failed to extract values as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let values = values.as_standard_layout();
+        let rows = values.as_slice().unwrap();
+        let inner: usize = values_shape[1..].iter().product();
+
+        let reduce = |op| segment_reduce(rows, inner, &ids, num_groups, op);
+        let (sum, min, max) = match (
+            reduce(SegmentOp::Sum),
+            reduce(SegmentOp::Min),
+            reduce(SegmentOp::Max),
+        ) {
+            (Ok(s), Ok(mn), Ok(mx)) => (s, mn, mx),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+                set_last_error(e);
+                return ERR_INDEX;
+            }
+        };
+
+        let mut counts = vec![0i64; num_groups];
+        for &id in &ids {
+            counts[id as usize] += 1;
+        }
+        let mean: Vec<f64> = sum
+            .iter()
+            .enumerate()
+            .map(|(i, &s)| s / counts[i / inner.max(1)] as f64)
+            .collect();
+
+        let mut agg_shape = values_shape.to_vec();
+        agg_shape[0] = num_groups;
+        let as_f32 = values_wrapper.dtype == DType::Float32;
+        let counts_wrapper = NDArrayWrapper {
+            data: ArrayData::Int64(Arc::new(RwLock::new(
+                ArrayD::from_shape_vec(IxDyn(&[num_groups]), counts).unwrap(),
+            ))),
+            dtype: DType::Int64,
+        };
+
+        *out_keys = NdArrayHandle::from_wrapper(Box::new(keys_out));
+        *out_counts = NdArrayHandle::from_wrapper(Box::new(counts_wrapper));
+        *out_sum =
+            NdArrayHandle::from_wrapper(Box::new(aggregate_wrapper(sum, &agg_shape, as_f32)));
+        *out_mean =
+            NdArrayHandle::from_wrapper(Box::new(aggregate_wrapper(mean, &agg_shape, as_f32)));
+        *out_min =
+            NdArrayHandle::from_wrapper(Box::new(aggregate_wrapper(min, &agg_shape, as_f32)));
+        *out_max =
+            NdArrayHandle::from_wrapper(Box::new(aggregate_wrapper(max, &agg_shape, as_f32)));
+        *out_num_groups = num_groups;
+        SUCCESS
+    })
+}
@@ -0,0 +1,482 @@
+//! Histograms over flattened data (histogram, histogram2d).
+//!
+//! Bins are half-open `[e[i], e[i + 1])` except the last, which also
+//! includes its right edge. Values outside the edges and NaN are not
+//! counted. Unweighted counts are Int64; weighted counts are Float64 sums of
+//! the weights. Edges are always returned as Float64.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::extract_array_as_f64;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Bin edges along one dimension.
+struct Bins {
+    edges: Vec<f64>,
+    /// Edges are evenly spaced, so a value's bin can be computed directly.
+    uniform: bool,
+}
+
+impl Bins {
+    /// `count` equal-width bins over `[lo, hi]`. An empty range is widened
+    /// by 0.5 on each side, as in NumPy.
+    fn uniform(count: usize, lo: f64, hi: f64) -> Result<Self, String> {
+        if count == 0 {
+            return Err("histogram: number of bins must be at least 1".to_string());
+        }
+        if !lo.is_finite() || !hi.is_finite() {
+            return Err(format!("histogram: range [{}, {}] is not finite", lo, hi));
+        }
+        if lo > hi {
+            return Err(format!(
+                "histogram: range min {} is greater than max {}",
+                lo, hi
+            ));
+        }
+        let (lo, hi) = if lo == hi {
+            (lo - 0.5, hi + 0.5)
+        } else {
+            (lo, hi)
+        };
+        let step = (hi - lo) / count as f64;
+        let mut edges: Vec<f64> = (0..count).map(|i| lo + step * i as f64).collect();
+        edges.push(hi);
+        Ok(Bins {
+            edges,
+            uniform: true,
+        })
+    }
+
+    fn from_edges(edges: Vec<f64>) -> Result<Self, String> {
+        if edges.len() < 2 {
+            return Err("histogram: at least two bin edges are required".to_string());
+        }
+        if edges.iter().any(|e| e.is_nan()) || edges.windows(2).any(|w| w[0] > w[1]) {
+            return Err("histogram: bin edges must increase monotonically".to_string());
+        }
+        Ok(Bins {
+            edges,
+            uniform: false,
+        })
+    }
+
+    fn len(&self) -> usize {
+        self.edges.len() - 1
+    }
+
+    /// Bin containing `x`, or `None` when it is outside the edges or NaN.
+    fn index(&self, x: f64) -> Option<usize> {
+        let n = self.len();
+        let (lo, hi) = (self.edges[0], self.edges[n]);
+        if x.is_nan() || x < lo || x > hi {
+            return None;
+        }
+        if x == hi {
+            return Some(n - 1);
+        }
+        if !self.uniform {
+            return Some(self.edges.partition_point(|&e| e <= x) - 1);
+        }
+        // The direct formula can be off by one from the stored edges due
+        // to rounding; nudge it back so both agree.
+        let mut i = (((x - lo) / (hi - lo)) * n as f64) as usize;
+        i = i.min(n - 1);
+        if x < self.edges[i] {
+            i -= 1;
+        } else if i + 1 < n && x >= self.edges[i + 1] {
+            i += 1;
+        }
+        Some(i)
+    }
+}
+
+/// Min and max of `values`, used when no range is given. NaN or infinite
+/// values make the range unusable and are rejected, as in NumPy.
+fn data_range(values: &[f64]) -> Result<(f64, f64), String> {
+    if values.is_empty() {
+        return Ok((0.0, 1.0));
+    }
+    let (lo, hi) = if values.iter().any(|x| x.is_nan()) {
+        (f64::NAN, f64::NAN)
+    } else {
+        values
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &x| {
+                (lo.min(x), hi.max(x))
+            })
+    };
+    if !lo.is_finite() || !hi.is_finite() {
+        return Err(format!(
+            "histogram: autodetected range [{}, {}] is not finite",
+            lo, hi
+        ));
+    }
+    Ok((lo, hi))
+}
+
+/// Resolve the bins for one dimension from explicit edges, or from a bin
+/// count and either `range` or the data extent.
+unsafe fn resolve_bins(
+    values: &[f64],
+    count: usize,
+    range: Option<(f64, f64)>,
+    edges: *const NdArrayHandle,
+    edges_meta: *const ArrayMetadata,
+) -> Result<Bins, (i32, String)> {
+    if !edges.is_null() {
+        if edges_meta.is_null() {
+            return Err((ERR_GENERIC, "Invalid input parameters".to_string()));
+        }
+        let edges = flatten_f64(edges, &*edges_meta, "bin edges")?;
+        return Bins::from_edges(edges).map_err(|e| (ERR_VALUE, e));
+    }
+    let (lo, hi) = match range {
+        Some(r) => r,
+        None => data_range(values).map_err(|e| (ERR_VALUE, e))?,
+    };
+    Bins::uniform(count, lo, hi).map_err(|e| (ERR_VALUE, e))
+}
+
+/// Read any real array as a flat f64 vector in logical order.
+unsafe fn flatten_f64(
+    handle: *const NdArrayHandle,
+    meta: &ArrayMetadata,
+    what: &str,
+) -> Result<Vec<f64>, (i32, String)> {
+    let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+    if matches!(wrapper.dtype, DType::Complex64 | DType::Complex128) {
+        return Err((
+            ERR_DTYPE,
+            format!("histogram: {} must be real, got {}", what, wrapper.dtype),
+        ));
+    }
+    let arr = extract_array_as_f64(wrapper, meta)
+        .ok_or_else(|| (ERR_DTYPE, format!("Failed to extract {} as f64", what)))?;
+    Ok(arr.iter().copied().collect())
+}
+
+/// Read optional weights, which must have `len` elements.
+unsafe fn read_weights(
+    weights: *const NdArrayHandle,
+    weights_meta: *const ArrayMetadata,
+    len: usize,
+) -> Result<Option<Vec<f64>>, (i32, String)> {
+    if weights.is_null() {
+        return Ok(None);
+    }
+    if weights_meta.is_null() {
+        return Err((ERR_GENERIC, "Invalid input parameters".to_string()));
+    }
+    let w = flatten_f64(weights, &*weights_meta, "weights")?;
+    if w.len() != len {
+        return Err((
+            ERR_SHAPE,
+            format!(
+                "histogram: weights have {} elements but the data has {}",
+                w.len(),
+                len
+            ),
+        ));
+    }
+    Ok(Some(w))
+}
+
+/// Read an optional `[min, max, ...]` range with `dims` pairs.
+unsafe fn read_range(range: *const f64, dims: usize) -> Vec<Option<(f64, f64)>> {
+    (0..dims)
+        .map(|d| {
+            if range.is_null() {
+                None
+            } else {
+                Some((*range.add(2 * d), *range.add(2 * d + 1)))
+            }
+        })
+        .collect()
+}
+
+/// Accumulate counts for each `(flat bin, weight index)` pair.
+fn accumulate(
+    shape: &[usize],
+    bins: impl Iterator<Item = (usize, usize)>,
+    weights: Option<&[f64]>,
+) -> NDArrayWrapper {
+    let len = shape.iter().product();
+    match weights {
+        None => {
+            let mut counts = vec![0i64; len];
+            for (b, _) in bins {
+                counts[b] += 1;
+            }
+            NDArrayWrapper {
+                data: ArrayData::Int64(Arc::new(RwLock::new(
+                    ArrayD::from_shape_vec(IxDyn(shape), counts).unwrap(),
+                ))),
+                dtype: DType::Int64,
+            }
+        }
+        Some(w) => {
+            let mut sums = vec![0f64; len];
+            for (b, i) in bins {
+                sums[b] += w[i];
+            }
+            NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(
+                    ArrayD::from_shape_vec(IxDyn(shape), sums).unwrap(),
+                ))),
+                dtype: DType::Float64,
+            }
+        }
+    }
+}
+
+fn edges_wrapper(bins: Bins) -> NDArrayWrapper {
+    let n = bins.edges.len();
+    NDArrayWrapper {
+        data: ArrayData::Float64(Arc::new(RwLock::new(
+            ArrayD::from_shape_vec(IxDyn(&[n]), bins.edges).unwrap(),
+        ))),
+        dtype: DType::Float64,
+    }
+}
+
+/// One histogram dimension as passed over FFI.
+struct DimArgs<'a> {
+    data: *const NdArrayHandle,
+    meta: &'a ArrayMetadata,
+    bins: usize,
+    range: Option<(f64, f64)>,
+    edges: *const NdArrayHandle,
+    edges_meta: *const ArrayMetadata,
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn histogram(
+    handle: *const NdArrayHandle,
+    meta: &ArrayMetadata,
+    bins: usize,
+    range: Option<(f64, f64)>,
+    edges: *const NdArrayHandle,
+    edges_meta: *const ArrayMetadata,
+    weights: *const NdArrayHandle,
+    weights_meta: *const ArrayMetadata,
+) -> Result<(NDArrayWrapper, NDArrayWrapper), (i32, String)> {
+    let values = flatten_f64(handle, meta, "input")?;
+    let weights = read_weights(weights, weights_meta, values.len())?;
+    let bins = resolve_bins(&values, bins, range, edges, edges_meta)?;
+
+    let counts = accumulate(
+        &[bins.len()],
+        values
+            .iter()
+            .enumerate()
+            .filter_map(|(i, &x)| bins.index(x).map(|b| (b, i))),
+        weights.as_deref(),
+    );
+    Ok((counts, edges_wrapper(bins)))
+}
+
+unsafe fn histogram2d(
+    x: DimArgs<'_>,
+    y: DimArgs<'_>,
+    weights: *const NdArrayHandle,
+    weights_meta: *const ArrayMetadata,
+) -> Result<(NDArrayWrapper, NDArrayWrapper, NDArrayWrapper), (i32, String)> {
+    let xs = flatten_f64(x.data, x.meta, "x")?;
+    let ys = flatten_f64(y.data, y.meta, "y")?;
+    if xs.len() != ys.len() {
+        return Err((
+            ERR_SHAPE,
+            format!(
+                "histogram2d: x has {} elements but y has {}",
+                xs.len(),
+                ys.len()
+            ),
+        ));
+    }
+    let weights = read_weights(weights, weights_meta, xs.len())?;
+    let bx = resolve_bins(&xs, x.bins, x.range, x.edges, x.edges_meta)?;
+    let by = resolve_bins(&ys, y.bins, y.range, y.edges, y.edges_meta)?;
+
+    let ny = by.len();
+    let counts = accumulate(
+        &[bx.len(), ny],
+        xs.iter()
+            .zip(&ys)
+            .enumerate()
+            .filter_map(|(i, (&xv, &yv))| Some((bx.index(xv)? * ny + by.index(yv)?, i))),
+        weights.as_deref(),
+    );
+    Ok((counts, edges_wrapper(bx), edges_wrapper(by)))
+}
+
+/// Compute the histogram of a flattened array.
+///
+/// When `edges` is non-null its values are used as bin edges and `bins` and
+/// `range` are ignored. Otherwise `bins` equal-width bins span `range`
+/// (`[min, max]`, or the data extent when `range` is null).
+///
+/// `weights` is optional and must have as many elements as the input; when
+/// given, `out_counts` holds Float64 weight sums instead of Int64 counts.
+/// `out_counts` has shape `[nbins]` and `out_edges` has shape `[nbins + 1]`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_histogram(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    bins: usize,
+    range: *const f64,
+    edges: *const NdArrayHandle,
+    edges_meta: *const ArrayMetadata,
+    weights: *const NdArrayHandle,
+    weights_meta: *const ArrayMetadata,
+    out_counts: *mut *mut NdArrayHandle,
+    out_edges: *mut *mut NdArrayHandle,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_counts.is_null() || out_edges.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("histogram", {
+        let range = read_range(range, 1)[0];
+        match histogram(
+            handle,
+            &*meta,
+            bins,
+            range,
+            edges,
+            edges_meta,
+            weights,
+            weights_meta,
+        ) {
+            Ok((counts, edges)) => {
+                *out_counts = NdArrayHandle::from_wrapper(Box::new(counts));
+                *out_edges = NdArrayHandle::from_wrapper(Box::new(edges));
+                SUCCESS
+            }
+            Err((code, e)) => {
+                set_last_error(e);
+                code
+            }
+        }
+    })
+}
+
+/// Compute the two-dimensional histogram of paired samples `x` and `y`.
+///
+/// `x` and `y` are flattened and must have the same number of elements.
+/// Each dimension is binned like [`ndarray_histogram`]: explicit edges when
+/// `x_edges` / `y_edges` is non-null, otherwise `bins_x` / `bins_y`
+/// equal-width bins over `range` (`[xmin, xmax, ymin, ymax]`, or the data
+/// extent when null). `out_counts` has shape `[nbins_x, nbins_y]`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_histogram2d(
+    x: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    y: *const NdArrayHandle,
+    y_meta: *const ArrayMetadata,
+    bins_x: usize,
+    bins_y: usize,
+    range: *const f64,
+    x_edges: *const NdArrayHandle,
+    x_edges_meta: *const ArrayMetadata,
+    y_edges: *const NdArrayHandle,
+    y_edges_meta: *const ArrayMetadata,
+    weights: *const NdArrayHandle,
+    weights_meta: *const ArrayMetadata,
+    out_counts: *mut *mut NdArrayHandle,
+    out_x_edges: *mut *mut NdArrayHandle,
+    out_y_edges: *mut *mut NdArrayHandle,
+) -> i32 {
+    if x.is_null()
+        || x_meta.is_null()
+        || y.is_null()
+        || y_meta.is_null()
+        || out_counts.is_null()
+        || out_x_edges.is_null()
+        || out_y_edges.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("histogram2d", {
+        let range = read_range(range, 2);
+        match histogram2d(
+            DimArgs {
+                data: x,
+                meta: &*x_meta,
+                bins: bins_x,
+                range: range[0],
+                edges: x_edges,
+                edges_meta: x_edges_meta,
+            },
+            DimArgs {
+                data: y,
+                meta: &*y_meta,
+                bins: bins_y,
+                range: range[1],
+                edges: y_edges,
+                edges_meta: y_edges_meta,
+            },
+            weights,
+            weights_meta,
+        ) {
+            Ok((counts, x_edges, y_edges)) => {
+                *out_counts = NdArrayHandle::from_wrapper(Box::new(counts));
+                *out_x_edges = NdArrayHandle::from_wrapper(Box::new(x_edges));
+                *out_y_edges = NdArrayHandle::from_wrapper(Box::new(y_edges));
+                SUCCESS
+            }
+            Err((code, e)) => {
+                set_last_error(e);
+                code
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_bins_include_the_right_edge() {
+        let bins = Bins::uniform(4, 0.0, 1.0).unwrap();
+        assert_eq!(bins.edges, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(bins.index(0.0), Some(0));
+        assert_eq!(bins.index(0.25), Some(1));
+        assert_eq!(bins.index(0.99), Some(3));
+        assert_eq!(bins.index(1.0), Some(3));
+        assert_eq!(bins.index(1.5), None);
+        assert_eq!(bins.index(f64::NAN), None);
+    }
+
+    #[test]
+    fn explicit_edges_and_degenerate_range() {
+        let bins = Bins::from_edges(vec![0.0, 1.0, 10.0]).unwrap();
+        assert_eq!(bins.index(0.5), Some(0));
+        assert_eq!(bins.index(1.0), Some(1));
+        assert_eq!(bins.index(10.0), Some(1));
+        assert!(Bins::from_edges(vec![1.0, 0.0]).is_err());
+
+        let bins = Bins::uniform(1, 3.0, 3.0).unwrap();
+        assert_eq!(bins.edges, vec![2.5, 3.5]);
+    }
+
+    #[test]
+    fn autodetected_range_rejects_nan_and_inf() {
+        assert_eq!(data_range(&[2.0, -1.0, 3.0]), Ok((-1.0, 3.0)));
+        assert_eq!(
+            data_range(&[1.0, f64::NAN, 2.0]),
+            Err("histogram: autodetected range [NaN, NaN] is not finite".to_string())
+        );
+        assert_eq!(
+            data_range(&[1.0, f64::INFINITY]),
+            Err("histogram: autodetected range [1, inf] is not finite".to_string())
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Log-sum-exp reduction: `ln(sum(exp(x)))`.
+//!
+//! The lane maximum is factored out before exponentiating, so large inputs
+//! do not overflow. Integer and Bool inputs produce Float64, Float32 stays
+//! Float32 and Float16/BFloat16 are computed in f32.
+
+use std::ffi::c_void;
+
+use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{extract_array_as_f32, extract_array_as_f64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::Axis;
+use num_traits::Float;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Stable `ln(sum(exp(x)))` over `values`.
+///
+/// An empty lane or one of only `-inf` gives `-inf`; NaN propagates.
+fn lane_logsumexp<'a, T: Float + 'a>(values: impl Iterator<Item = &'a T> + Clone) -> T {
+    let mut max = T::neg_infinity();
+    for &x in values.clone() {
+        if x.is_nan() {
+            return x;
+        }
+        max = max.max(x);
+    }
+    if max.is_infinite() {
+        return max;
+    }
+    let sum = values.fold(T::zero(), |acc, &x| acc + (x - max).exp());
+    max + sum.ln()
+}
+
+unsafe fn reduce_all(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Result<ReductionScalar, (i32, String)> {
+    match wrapper.dtype {
+        DType::Complex64 | DType::Complex128 => Err(unsupported(wrapper.dtype)),
+        DType::Float32 | DType::Float16 | DType::BFloat16 => {
+            let arr = extract_array_as_f32(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f32 view".to_string()))?;
+            Ok(ReductionScalar::F32(lane_logsumexp(arr.iter())))
+        }
+        _ => {
+            let arr = extract_array_as_f64(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f64 view".to_string()))?;
+            Ok(ReductionScalar::F64(lane_logsumexp(arr.iter())))
+        }
+    }
+}
+
+unsafe fn reduce_axis(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let axis = Axis(normalize_axis(meta.shape_slice(), axis, false).map_err(|e| (ERR_SHAPE, e))?);
+
+    macro_rules! reduce_with {
+        ($extract_fn:ident) => {{
+            let arr = $extract_fn(wrapper, meta).ok_or_else(|| {
+                (
+                    ERR_DTYPE,
+                    format!("Failed to extract array as {}", wrapper.dtype),
+                )
+            })?;
+            let result = arr.map_axis(axis, |lane| lane_logsumexp(lane.iter()));
+            if keepdims {
+                result.insert_axis(axis)
+            } else {
+                result
+            }
+        }};
+    }
+
+    Ok(match wrapper.dtype {
+        DType::Complex64 | DType::Complex128 => return Err(unsupported(wrapper.dtype)),
+        DType::Float32 => NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(reduce_with!(extract_array_as_f32)))),
+            dtype: DType::Float32,
+        },
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_f32_as_half(reduce_with!(extract_array_as_f32), wrapper.dtype)
+        }
+        _ => NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(reduce_with!(extract_array_as_f64)))),
+            dtype: DType::Float64,
+        },
+    })
+}
+
+fn unsupported(dtype: DType) -> (i32, String) {
+    (
+        ERR_DTYPE,
+        format!("logsumexp is not supported for {} arrays", dtype),
+    )
+}
+
+/// Compute `ln(sum(exp(x)))` over all elements without overflow.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_logsumexp(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_value.is_null() || out_dtype.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!("logsumexp", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        match reduce_all(wrapper, meta) {
+            Ok(scalar) => write_reduction_scalar(out_value, out_dtype, scalar),
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        }
+        SUCCESS
+    })
+}
+
+/// Compute `ln(sum(exp(x)))` along an axis without overflow.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_logsumexp_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || out_handle.is_null()
+        || meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!("logsumexp_axis", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        let result_wrapper = match reduce_axis(wrapper, meta, axis, keepdims) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_inputs_do_not_overflow() {
+        let v = [1000.0f64, 1000.0];
+        assert!((lane_logsumexp(v.iter()) - (1000.0 + 2f64.ln())).abs() < 1e-12);
+        let v = [1.0f32, 2.0, 3.0];
+        assert!((lane_logsumexp(v.iter()) - 3.407_606).abs() < 1e-5);
+    }
+
+    #[test]
+    fn infinite_and_nan_lanes() {
+        assert_eq!(lane_logsumexp::<f64>([].iter()), f64::NEG_INFINITY);
+        assert_eq!(
+            lane_logsumexp([f64::NEG_INFINITY, f64::NEG_INFINITY].iter()),
+            f64::NEG_INFINITY
+        );
+        assert_eq!(lane_logsumexp([1.0, f64::INFINITY].iter()), f64::INFINITY);
+        assert!(lane_logsumexp([f64::INFINITY, f64::NAN].iter()).is_nan());
+    }
+}
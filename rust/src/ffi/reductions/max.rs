@@ -3,12 +3,15 @@
 use std::ffi::c_void;
 
 use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f64, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -17,6 +20,8 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute the maximum of all elements in the array.
+///
+/// A zero-size array has no maximum and is rejected.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_max(
     handle: *const NdArrayHandle,
@@ -30,14 +35,19 @@ pub unsafe extern "C" fn ndarray_max(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("max", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
+        if meta.shape_slice().contains(&0) {
+            set_last_error("Cannot compute max of an empty array".to_string());
+            return ERR_VALUE;
+        }
+
         let scalar = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::F64(arr.iter().cloned().fold(f64::NAN, |max, x| {
                     if max.is_nan() || x > max {
@@ -47,10 +57,10 @@ pub unsafe extern "C" fn ndarray_max(
                     }
                 }))
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::F32(arr.iter().cloned().fold(f32::NAN, |max, x| {
                     if max.is_nan() || x > max {
@@ -63,7 +73,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I64(arr.iter().cloned().fold(i64::MIN, |max, x| {
                     if x > max {
@@ -76,7 +86,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I32(arr.iter().cloned().fold(i32::MIN, |max, x| {
                     if x > max {
@@ -89,7 +99,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I16(arr.iter().cloned().fold(i16::MIN, |max, x| {
                     if x > max {
@@ -102,7 +112,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I8(arr.iter().cloned().fold(i8::MIN, |max, x| {
                     if x > max {
@@ -115,7 +125,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U64(arr.iter().cloned().fold(u64::MIN, |max, x| {
                     if x > max {
@@ -128,7 +138,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U32(arr.iter().cloned().fold(u32::MIN, |max, x| {
                     if x > max {
@@ -141,7 +151,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U16(arr.iter().cloned().fold(u16::MIN, |max, x| {
                     if x > max {
@@ -154,7 +164,7 @@ pub unsafe extern "C" fn ndarray_max(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U8(arr.iter().cloned().fold(u8::MIN, |max, x| {
                     if x > max {
@@ -166,11 +176,11 @@ pub unsafe extern "C" fn ndarray_max(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("max() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("max() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -203,7 +213,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("max_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -220,14 +230,14 @@ pub unsafe extern "C" fn ndarray_max_axis(
 
         if axis_len == 0 {
             set_last_error("Cannot compute max along empty axis".to_string());
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), f64::NEG_INFINITY, |acc, &x| acc.max(x));
@@ -241,10 +251,10 @@ pub unsafe extern "C" fn ndarray_max_axis(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), f32::NEG_INFINITY, |acc, &x| acc.max(x));
@@ -261,7 +271,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), i64::MIN as i64, |&acc, &x| acc.max(x));
@@ -278,7 +288,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), i32::MIN as i32, |&acc, &x| acc.max(x));
@@ -295,7 +305,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), i16::MIN as i16, |&acc, &x| acc.max(x));
@@ -312,7 +322,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.fold_axis(Axis(axis_usize), i8::MIN as i8, |&acc, &x| acc.max(x));
                 let final_arr = if keepdims {
@@ -328,7 +338,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), u64::MIN as u64, |&acc, &x| acc.max(x));
@@ -345,7 +355,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), u32::MIN as u32, |&acc, &x| acc.max(x));
@@ -362,7 +372,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), u16::MIN as u16, |&acc, &x| acc.max(x));
@@ -379,7 +389,7 @@ pub unsafe extern "C" fn ndarray_max_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.fold_axis(Axis(axis_usize), u8::MIN as u8, |&acc, &x| acc.max(x));
                 let final_arr = if keepdims {
@@ -394,11 +404,11 @@ pub unsafe extern "C" fn ndarray_max_axis(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("max_axis() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("max_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -5,16 +5,19 @@ use std::ffi::c_void;
 use crate::ffi::reductions::helpers::{
     compute_axis_output_shape, write_reduction_scalar, ReductionScalar,
 };
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::ffi::reductions::summation;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle, SumMode};
 use ndarray::Axis;
 use ndarray::{ArrayD, IxDyn};
 use num_complex::{Complex32, Complex64};
@@ -25,10 +28,14 @@ use std::sync::Arc;
 ///
 /// Scalar output dtype matches the computation: `Float32` / `Complex64` / `Complex128` preserve
 /// native precision; integer inputs promote to `Float64` (NumPy-style); other reals use `Float64`.
+/// The mean of a zero-size array is NaN.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_mean(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
+    mode: i32,
     out_value: *mut c_void,
     out_dtype: *mut u8,
 ) -> i32 {
@@ -38,84 +45,91 @@ pub unsafe extern "C" fn ndarray_mean(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("mean", {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let scalar = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().unwrap_or(0.0))
+                ReductionScalar::F64(summation::mean(&arr.view(), mode).unwrap_or(f64::NAN))
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F32(arr.mean().unwrap_or(0.0))
+                ReductionScalar::F32(summation::mean(&arr.view(), mode).unwrap_or(f32::NAN))
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(0.0))
+                ReductionScalar::F64(arr.mean().map(|x| x as f64).unwrap_or(f64::NAN))
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let n = arr.len() as f32;
                 let sum = arr.sum();
@@ -125,7 +139,7 @@ pub unsafe extern "C" fn ndarray_mean(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let n = arr.len() as f64;
                 let sum = arr.sum();
@@ -134,7 +148,7 @@ pub unsafe extern "C" fn ndarray_mean(
             }
             DType::Bool => {
                 set_last_error("mean() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -144,12 +158,17 @@ pub unsafe extern "C" fn ndarray_mean(
 }
 
 /// Compute the mean along an axis.
+///
+/// Lanes of length zero (reducing over an empty axis) produce NaN.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_mean_axis(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     axis: i32,
     keepdims: bool,
+    mode: i32,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -167,7 +186,14 @@ pub unsafe extern "C" fn ndarray_mean_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("mean_axis", {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -186,125 +212,116 @@ pub unsafe extern "C" fn ndarray_mean_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mean_axis(Axis(axis_usize)).unwrap_or_else(|| {
+                summation::mean_axis(&arr.view(), Axis(axis_usize), mode).unwrap_or_else(|| {
                     let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                    ArrayD::zeros(IxDyn(&out_shape))
+                    ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                 })
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64)
-                    .mean_axis(Axis(axis_usize))
+                summation::mean_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), mode)
                     .unwrap_or_else(|| {
                         let out_shape = compute_axis_output_shape(shape_slice, axis_usize, false);
-                        ArrayD::zeros(IxDyn(&out_shape))
+                        ArrayD::from_elem(IxDyn(&out_shape), f64::NAN)
                     })
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let count = shape_slice[axis_usize] as f32;
                 let sum = arr.sum_axis(Axis(axis_usize));
@@ -330,7 +347,7 @@ pub unsafe extern "C" fn ndarray_mean_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let count = shape_slice[axis_usize] as f64;
                 let sum = arr.sum_axis(Axis(axis_usize));
@@ -355,7 +372,7 @@ pub unsafe extern "C" fn ndarray_mean_axis(
             }
             DType::Bool => {
                 set_last_error("mean_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
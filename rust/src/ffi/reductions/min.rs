@@ -3,12 +3,15 @@
 use std::ffi::c_void;
 
 use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f64, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -17,6 +20,8 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute the minimum of all elements in the array.
+///
+/// A zero-size array has no minimum and is rejected.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_min(
     handle: *const NdArrayHandle,
@@ -30,14 +35,19 @@ pub unsafe extern "C" fn ndarray_min(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("min", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
+        if meta.shape_slice().contains(&0) {
+            set_last_error("Cannot compute min of an empty array".to_string());
+            return ERR_VALUE;
+        }
+
         let scalar = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::F64(arr.iter().cloned().fold(f64::NAN, |min, x| {
                     if min.is_nan() || x < min {
@@ -47,10 +57,10 @@ pub unsafe extern "C" fn ndarray_min(
                     }
                 }))
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::F32(arr.iter().cloned().fold(f32::NAN, |min, x| {
                     if min.is_nan() || x < min {
@@ -63,7 +73,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I64(arr.iter().cloned().fold(i64::MAX, |min, x| {
                     if x < min {
@@ -76,7 +86,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I32(arr.iter().cloned().fold(i32::MAX, |min, x| {
                     if x < min {
@@ -89,7 +99,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I16(arr.iter().cloned().fold(i16::MAX, |min, x| {
                     if x < min {
@@ -102,7 +112,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I8(arr.iter().cloned().fold(i8::MAX, |min, x| {
                     if x < min {
@@ -115,7 +125,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U64(arr.iter().cloned().fold(u64::MAX, |min, x| {
                     if x < min {
@@ -128,7 +138,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U32(arr.iter().cloned().fold(u32::MAX, |min, x| {
                     if x < min {
@@ -141,7 +151,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U16(arr.iter().cloned().fold(u16::MAX, |min, x| {
                     if x < min {
@@ -154,7 +164,7 @@ pub unsafe extern "C" fn ndarray_min(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U8(arr.iter().cloned().fold(u8::MAX, |min, x| {
                     if x < min {
@@ -166,11 +176,11 @@ pub unsafe extern "C" fn ndarray_min(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("min() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("min() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -202,7 +212,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
     }
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("min_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -219,14 +229,14 @@ pub unsafe extern "C" fn ndarray_min_axis(
 
         if axis_len == 0 {
             set_last_error("Cannot compute min along empty axis".to_string());
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.fold_axis(Axis(axis_usize), f64::INFINITY, |acc, &x| acc.min(x));
                 let final_arr = if keepdims {
@@ -239,10 +249,10 @@ pub unsafe extern "C" fn ndarray_min_axis(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.fold_axis(Axis(axis_usize), f32::INFINITY, |acc, &x| acc.min(x));
                 let final_arr = if keepdims {
@@ -258,7 +268,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), i64::MAX as i64, |&acc, &x| acc.min(x));
@@ -275,7 +285,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), i32::MAX as i32, |&acc, &x| acc.min(x));
@@ -292,7 +302,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), i16::MAX as i16, |&acc, &x| acc.min(x));
@@ -309,7 +319,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.fold_axis(Axis(axis_usize), i8::MAX as i8, |&acc, &x| acc.min(x));
                 let final_arr = if keepdims {
@@ -325,7 +335,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), u64::MAX as u64, |&acc, &x| acc.min(x));
@@ -342,7 +352,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), u32::MAX as u32, |&acc, &x| acc.min(x));
@@ -359,7 +369,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result =
                     arr.fold_axis(Axis(axis_usize), u16::MAX as u16, |&acc, &x| acc.min(x));
@@ -376,7 +386,7 @@ pub unsafe extern "C" fn ndarray_min_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.fold_axis(Axis(axis_usize), u8::MAX as u8, |&acc, &x| acc.min(x));
                 let final_arr = if keepdims {
@@ -391,11 +401,11 @@ pub unsafe extern "C" fn ndarray_min_axis(
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("min_axis() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("min_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
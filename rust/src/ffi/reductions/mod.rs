@@ -4,20 +4,30 @@
 //! axis reductions with keepdims support.
 
 pub(crate) mod helpers;
+pub(crate) mod summation;
 
 pub mod all;
 pub mod any;
 pub mod argmax;
 pub mod argmin;
 pub mod bincount;
+pub mod count_nonzero;
 pub mod cumprod;
 pub mod cumsum;
+pub mod deterministic;
+pub mod group_by;
+pub mod histogram;
+pub mod logsumexp;
 pub mod max;
 pub mod mean;
 pub mod min;
+pub mod nan;
 pub mod product;
+pub mod quantile;
+pub mod segment;
 pub mod std;
 pub mod sum;
+pub mod trapz;
 pub mod var;
 
 // Re-export all FFI functions
@@ -26,12 +36,21 @@ pub use any::*;
 pub use argmax::*;
 pub use argmin::*;
 pub use bincount::*;
+pub use count_nonzero::*;
 pub use cumprod::*;
 pub use cumsum::*;
+pub use deterministic::*;
+pub use group_by::*;
+pub use histogram::*;
+pub use logsumexp::*;
 pub use max::*;
 pub use mean::*;
 pub use min::*;
+pub use nan::*;
 pub use product::*;
+pub use quantile::*;
+pub use segment::*;
 pub use std::*;
 pub use sum::*;
+pub use trapz::*;
 pub use var::*;
@@ -0,0 +1,522 @@
+//! NaN-skipping reductions (nansum, nanmean, nanmin, nanmax, nanstd).
+//!
+//! These accept floating-point arrays only; integer arrays cannot hold NaN,
+//! so callers use the plain reductions for them. Float16/BFloat16 inputs are
+//! reduced in f32. An all-NaN input sums to zero and reduces to NaN for
+//! every other operation, as in NumPy.
+
+use std::ffi::c_void;
+
+use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
+use crate::ffi::reductions::summation;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{extract_array_as_f32, extract_array_f64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle, SumMode};
+use ndarray::{ArrayD, ArrayViewD, Axis};
+use num_traits::Float;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+#[derive(Clone, Copy)]
+enum NanOp {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Std(f64),
+}
+
+impl NanOp {
+    fn name(self) -> &'static str {
+        match self {
+            NanOp::Sum => "nansum",
+            NanOp::Mean => "nanmean",
+            NanOp::Min => "nanmin",
+            NanOp::Max => "nanmax",
+            NanOp::Std(_) => "nanstd",
+        }
+    }
+
+    /// Fold step for nanmin/nanmax: keep `acc` unless `x` is a better non-NaN value.
+    #[inline(always)]
+    fn pick<T: Float>(self, acc: T, x: T) -> T {
+        let better = match self {
+            NanOp::Min => x < acc,
+            _ => x > acc,
+        };
+        if !x.is_nan() && (acc.is_nan() || better) {
+            x
+        } else {
+            acc
+        }
+    }
+
+    fn reduce<T: Float + Send + Sync>(self, view: &ArrayViewD<T>, mode: SumMode) -> T {
+        match self {
+            NanOp::Sum => summation::nansum(view, mode),
+            NanOp::Mean => summation::nanmean(view, mode),
+            NanOp::Min | NanOp::Max => view.iter().fold(T::nan(), |acc, &x| self.pick(acc, x)),
+            NanOp::Std(ddof) => summation::nanvar(view, T::from(ddof).unwrap(), mode).sqrt(),
+        }
+    }
+
+    fn reduce_axis<T: Float + Send + Sync>(
+        self,
+        view: &ArrayViewD<T>,
+        axis: Axis,
+        mode: SumMode,
+    ) -> ArrayD<T> {
+        match self {
+            NanOp::Sum => summation::nansum_axis(view, axis, mode),
+            NanOp::Mean => summation::nanmean_axis(view, axis, mode),
+            NanOp::Min | NanOp::Max => view.fold_axis(axis, T::nan(), |&acc, &x| self.pick(acc, x)),
+            NanOp::Std(ddof) => {
+                summation::nanvar_axis(view, axis, T::from(ddof).unwrap(), mode).mapv(T::sqrt)
+            }
+        }
+    }
+}
+
+/// Reduce every element of a float array, skipping NaN.
+unsafe fn nan_reduce(
+    op: NanOp,
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    mode: SumMode,
+) -> Result<ReductionScalar, (i32, String)> {
+    match wrapper.dtype {
+        DType::Float64 => {
+            let arr = extract_array_f64(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f64 view".to_string()))?;
+            Ok(ReductionScalar::F64(op.reduce(&arr.view(), mode)))
+        }
+        DType::Float32 | DType::Float16 | DType::BFloat16 => {
+            let arr = extract_array_as_f32(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f32 view".to_string()))?;
+            Ok(ReductionScalar::F32(op.reduce(&arr.view(), mode)))
+        }
+        other => Err((
+            ERR_DTYPE,
+            format!("{}() requires a float array, got {}", op.name(), other),
+        )),
+    }
+}
+
+/// Reduce a float array along `axis`, skipping NaN. The result keeps the
+/// input dtype.
+unsafe fn nan_reduce_axis(
+    op: NanOp,
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    mode: SumMode,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let axis = Axis(normalize_axis(meta.shape_slice(), axis, false).map_err(|e| (ERR_SHAPE, e))?);
+
+    macro_rules! reduce_with {
+        ($extract_fn:ident) => {{
+            let arr = $extract_fn(wrapper, meta).ok_or_else(|| {
+                (
+                    ERR_DTYPE,
+                    format!("Failed to extract array as {}", wrapper.dtype),
+                )
+            })?;
+            let result = op.reduce_axis(&arr.view(), axis, mode);
+            if keepdims {
+                result.insert_axis(axis)
+            } else {
+                result
+            }
+        }};
+    }
+
+    Ok(match wrapper.dtype {
+        DType::Float64 => NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(reduce_with!(extract_array_f64)))),
+            dtype: DType::Float64,
+        },
+        DType::Float32 => NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(reduce_with!(extract_array_as_f32)))),
+            dtype: DType::Float32,
+        },
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_f32_as_half(reduce_with!(extract_array_as_f32), wrapper.dtype)
+        }
+        other => {
+            return Err((
+                ERR_DTYPE,
+                format!("{}() requires a float array, got {}", op.name(), other),
+            ))
+        }
+    })
+}
+
+/// Shared body of the full-array FFI entry points.
+unsafe fn scalar_entry(
+    name: &'static str,
+    op: NanOp,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    mode: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_value.is_null() || out_dtype.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!(name, {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        match nan_reduce(op, wrapper, meta, mode) {
+            Ok(scalar) => write_reduction_scalar(out_value, out_dtype, scalar),
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        }
+        SUCCESS
+    })
+}
+
+/// Shared body of the axis FFI entry points.
+#[allow(clippy::too_many_arguments)]
+unsafe fn axis_entry(
+    name: &'static str,
+    op: NanOp,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    mode: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || out_handle.is_null()
+        || meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!(name, {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        let result_wrapper = match nan_reduce_axis(op, wrapper, meta, axis, keepdims, mode) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Sum all elements, treating NaN as zero.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nansum(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    mode: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry(
+        "nansum",
+        NanOp::Sum,
+        handle,
+        meta,
+        mode,
+        out_value,
+        out_dtype,
+    )
+}
+
+/// Sum along an axis, treating NaN as zero.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nansum_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    mode: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "nansum_axis",
+        NanOp::Sum,
+        handle,
+        meta,
+        axis,
+        keepdims,
+        mode,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Mean of all non-NaN elements (NaN when there are none).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanmean(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    mode: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry(
+        "nanmean",
+        NanOp::Mean,
+        handle,
+        meta,
+        mode,
+        out_value,
+        out_dtype,
+    )
+}
+
+/// Mean of the non-NaN elements along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanmean_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    mode: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "nanmean_axis",
+        NanOp::Mean,
+        handle,
+        meta,
+        axis,
+        keepdims,
+        mode,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Minimum of all non-NaN elements (NaN when there are none).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanmin(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry(
+        "nanmin",
+        NanOp::Min,
+        handle,
+        meta,
+        SumMode::Pairwise as i32,
+        out_value,
+        out_dtype,
+    )
+}
+
+/// Minimum of the non-NaN elements along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanmin_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "nanmin_axis",
+        NanOp::Min,
+        handle,
+        meta,
+        axis,
+        keepdims,
+        SumMode::Pairwise as i32,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Maximum of all non-NaN elements (NaN when there are none).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanmax(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry(
+        "nanmax",
+        NanOp::Max,
+        handle,
+        meta,
+        SumMode::Pairwise as i32,
+        out_value,
+        out_dtype,
+    )
+}
+
+/// Maximum of the non-NaN elements along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanmax_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "nanmax_axis",
+        NanOp::Max,
+        handle,
+        meta,
+        axis,
+        keepdims,
+        SumMode::Pairwise as i32,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Standard deviation of all non-NaN elements.
+///
+/// Reduces to NaN when no more than `ddof` non-NaN values remain.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanstd(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    ddof: f64,
+    mode: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry(
+        "nanstd",
+        NanOp::Std(ddof),
+        handle,
+        meta,
+        mode,
+        out_value,
+        out_dtype,
+    )
+}
+
+/// Standard deviation of the non-NaN elements along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_nanstd_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    ddof: f64,
+    mode: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "nanstd_axis",
+        NanOp::Std(ddof),
+        handle,
+        meta,
+        axis,
+        keepdims,
+        mode,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::IxDyn;
+
+    #[test]
+    fn nanmin_nanmax_skip_nan() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[2, 2]), vec![f64::NAN, 2.0, f64::NAN, f64::NAN])
+            .unwrap();
+        assert_eq!(NanOp::Min.reduce(&a.view(), SumMode::Pairwise), 2.0);
+        assert_eq!(NanOp::Max.reduce(&a.view(), SumMode::Pairwise), 2.0);
+
+        let rows = NanOp::Max.reduce_axis(&a.view(), Axis(1), SumMode::Pairwise);
+        assert_eq!(rows[[0]], 2.0);
+        assert!(rows[[1]].is_nan());
+    }
+}
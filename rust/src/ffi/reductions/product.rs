@@ -3,13 +3,13 @@
 use std::ffi::c_void;
 
 use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -31,97 +31,97 @@ pub unsafe extern "C" fn ndarray_product(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("product", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let scalar = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::F64(arr.product())
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::F32(arr.product())
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I64(arr.product())
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I32(arr.product())
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I16(arr.product())
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I8(arr.product())
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U64(arr.product())
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U32(arr.product())
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U16(arr.product())
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U8(arr.product())
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::C64(arr.product())
             }
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::C128(arr.product())
             }
             DType::Bool => {
                 set_last_error("product() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -155,7 +155,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("product_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -173,7 +173,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -186,10 +186,10 @@ pub unsafe extern "C" fn ndarray_product_axis(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -205,7 +205,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -221,7 +221,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -237,7 +237,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -253,7 +253,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -269,7 +269,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -285,7 +285,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -301,7 +301,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -317,7 +317,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -333,7 +333,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -349,7 +349,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.product_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -364,7 +364,7 @@ pub unsafe extern "C" fn ndarray_product_axis(
             }
             DType::Bool => {
                 set_last_error("product_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
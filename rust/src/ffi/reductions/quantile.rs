@@ -0,0 +1,455 @@
+//! Order-statistic reductions (median, quantile, percentile).
+//!
+//! Each lane is copied and partially sorted with `select_nth_unstable`, so a
+//! reduction costs O(n) per lane rather than a full sort. Integer and Bool
+//! inputs produce Float64, Float32 stays Float32 and Float16/BFloat16 are
+//! computed in f32. A lane containing NaN reduces to NaN, as in NumPy.
+
+use std::ffi::c_void;
+
+use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::helpers::{extract_array_as_f32, extract_array_as_f64};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, ArrayViewD, Axis};
+use num_traits::Float;
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// How a quantile falling between two data points is resolved. Integer
+/// values match PHP `QuantileMethod`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuantileMethod {
+    /// Linear interpolation between the neighbouring values.
+    Linear,
+    /// The smaller neighbour.
+    Lower,
+    /// The larger neighbour.
+    Higher,
+    /// The closer neighbour; ties go to the even index.
+    Nearest,
+    /// The mean of both neighbours.
+    Midpoint,
+}
+
+impl QuantileMethod {
+    fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(QuantileMethod::Linear),
+            1 => Ok(QuantileMethod::Lower),
+            2 => Ok(QuantileMethod::Higher),
+            3 => Ok(QuantileMethod::Nearest),
+            4 => Ok(QuantileMethod::Midpoint),
+            _ => Err(format!("Invalid quantile method: {}", value)),
+        }
+    }
+}
+
+/// Quantile `q` (in `[0, 1]`) of `values`, which is reordered in place.
+fn lane_quantile<T: Float>(values: &mut [T], q: f64, method: QuantileMethod) -> T {
+    let n = values.len();
+    if n == 0 || values.iter().any(|x| x.is_nan()) {
+        return T::nan();
+    }
+
+    let pos = q * (n - 1) as f64;
+    let lo = pos.floor() as usize;
+    let hi = pos.ceil() as usize;
+    let frac = pos - lo as f64;
+
+    let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap();
+    let (_, &mut v_lo, rest) = values.select_nth_unstable_by(lo, cmp);
+    let v_hi = if hi > lo {
+        rest.iter().copied().fold(T::infinity(), T::min)
+    } else {
+        v_lo
+    };
+
+    match method {
+        QuantileMethod::Linear => v_lo + (v_hi - v_lo) * T::from(frac).unwrap(),
+        QuantileMethod::Lower => v_lo,
+        QuantileMethod::Higher => v_hi,
+        QuantileMethod::Nearest => {
+            if frac < 0.5 || (frac == 0.5 && lo % 2 == 0) {
+                v_lo
+            } else {
+                v_hi
+            }
+        }
+        QuantileMethod::Midpoint => (v_lo + v_hi) / T::from(2.0).unwrap(),
+    }
+}
+
+fn quantile_all<T: Float>(view: &ArrayViewD<T>, q: f64, method: QuantileMethod) -> T {
+    let mut values: Vec<T> = view.iter().copied().collect();
+    lane_quantile(&mut values, q, method)
+}
+
+fn quantile_axis<T: Float>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    q: f64,
+    method: QuantileMethod,
+) -> ArrayD<T> {
+    let mut buf = Vec::with_capacity(view.len_of(axis));
+    view.map_axis(axis, |lane| {
+        buf.clear();
+        buf.extend(lane.iter().copied());
+        lane_quantile(&mut buf, q, method)
+    })
+}
+
+fn check_q(q: f64) -> Result<(), (i32, String)> {
+    if (0.0..=1.0).contains(&q) {
+        Ok(())
+    } else {
+        Err((
+            ERR_VALUE,
+            format!("Quantile must be in the range [0, 1], got {}", q),
+        ))
+    }
+}
+
+unsafe fn reduce_all(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    q: f64,
+    method: QuantileMethod,
+) -> Result<ReductionScalar, (i32, String)> {
+    check_q(q)?;
+    match wrapper.dtype {
+        DType::Complex64 | DType::Complex128 => Err(unsupported(wrapper.dtype)),
+        DType::Float32 | DType::Float16 | DType::BFloat16 => {
+            let arr = extract_array_as_f32(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f32 view".to_string()))?;
+            Ok(ReductionScalar::F32(quantile_all(&arr.view(), q, method)))
+        }
+        _ => {
+            let arr = extract_array_as_f64(wrapper, meta)
+                .ok_or_else(|| (ERR_DTYPE, "Failed to extract f64 view".to_string()))?;
+            Ok(ReductionScalar::F64(quantile_all(&arr.view(), q, method)))
+        }
+    }
+}
+
+unsafe fn reduce_axis(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    q: f64,
+    method: QuantileMethod,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    check_q(q)?;
+    let axis = Axis(normalize_axis(meta.shape_slice(), axis, false).map_err(|e| (ERR_SHAPE, e))?);
+
+    macro_rules! reduce_with {
+        ($extract_fn:ident) => {{
+            let arr = $extract_fn(wrapper, meta).ok_or_else(|| {
+                (
+                    ERR_DTYPE,
+                    format!("Failed to extract array as {}", wrapper.dtype),
+                )
+            })?;
+            let result = quantile_axis(&arr.view(), axis, q, method);
+            if keepdims {
+                result.insert_axis(axis)
+            } else {
+                result
+            }
+        }};
+    }
+
+    Ok(match wrapper.dtype {
+        DType::Complex64 | DType::Complex128 => return Err(unsupported(wrapper.dtype)),
+        DType::Float32 => NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(reduce_with!(extract_array_as_f32)))),
+            dtype: DType::Float32,
+        },
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_f32_as_half(reduce_with!(extract_array_as_f32), wrapper.dtype)
+        }
+        _ => NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(reduce_with!(extract_array_as_f64)))),
+            dtype: DType::Float64,
+        },
+    })
+}
+
+fn unsupported(dtype: DType) -> (i32, String) {
+    (
+        ERR_DTYPE,
+        format!("Quantiles are not supported for {} arrays", dtype),
+    )
+}
+
+/// Shared body of the full-array FFI entry points.
+unsafe fn scalar_entry(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    q: f64,
+    method: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_value.is_null() || out_dtype.is_null() {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!(name, {
+        let method = match QuantileMethod::from_i32(method) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        match reduce_all(wrapper, meta, q, method) {
+            Ok(scalar) => write_reduction_scalar(out_value, out_dtype, scalar),
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        }
+        SUCCESS
+    })
+}
+
+/// Shared body of the axis FFI entry points.
+#[allow(clippy::too_many_arguments)]
+unsafe fn axis_entry(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    q: f64,
+    method: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || out_handle.is_null()
+        || meta.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    let meta = &*meta;
+
+    crate::ffi_guard!(name, {
+        let method = match QuantileMethod::from_i32(method) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+
+        let result_wrapper = match reduce_axis(wrapper, meta, axis, keepdims, q, method) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Median of all elements.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_median(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry("median", handle, meta, 0.5, 0, out_value, out_dtype)
+}
+
+/// Median along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_median_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "median_axis",
+        handle,
+        meta,
+        axis,
+        keepdims,
+        0.5,
+        0,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Quantile `q` (in `[0, 1]`) of all elements.
+///
+/// `method`: 0 = linear, 1 = lower, 2 = higher, 3 = nearest, 4 = midpoint.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_quantile(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    q: f64,
+    method: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry("quantile", handle, meta, q, method, out_value, out_dtype)
+}
+
+/// Quantile `q` (in `[0, 1]`) along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_quantile_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    q: f64,
+    method: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "quantile_axis",
+        handle,
+        meta,
+        axis,
+        keepdims,
+        q,
+        method,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Percentile `p` (in `[0, 100]`) of all elements.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_percentile(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    p: f64,
+    method: i32,
+    out_value: *mut c_void,
+    out_dtype: *mut u8,
+) -> i32 {
+    scalar_entry(
+        "percentile",
+        handle,
+        meta,
+        p / 100.0,
+        method,
+        out_value,
+        out_dtype,
+    )
+}
+
+/// Percentile `p` (in `[0, 100]`) along an axis.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_percentile_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    p: f64,
+    method: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    axis_entry(
+        "percentile_axis",
+        handle,
+        meta,
+        axis,
+        keepdims,
+        p / 100.0,
+        method,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::IxDyn;
+
+    fn q(values: &[f64], q: f64, method: QuantileMethod) -> f64 {
+        lane_quantile(&mut values.to_vec(), q, method)
+    }
+
+    #[test]
+    fn methods_match_numpy() {
+        let v = [4.0, 1.0, 3.0, 2.0];
+        assert_eq!(q(&v, 0.5, QuantileMethod::Linear), 2.5);
+        assert_eq!(q(&v, 0.5, QuantileMethod::Lower), 2.0);
+        assert_eq!(q(&v, 0.5, QuantileMethod::Higher), 3.0);
+        assert_eq!(q(&v, 0.5, QuantileMethod::Midpoint), 2.5);
+        // pos = 1.5 ties to the even index (2), pos = 0.75 rounds up.
+        assert_eq!(q(&v, 0.5, QuantileMethod::Nearest), 3.0);
+        assert_eq!(q(&v, 0.25, QuantileMethod::Nearest), 2.0);
+        assert!((q(&v, 0.4, QuantileMethod::Linear) - 2.2).abs() < 1e-12);
+        assert_eq!(q(&v, 1.0, QuantileMethod::Linear), 4.0);
+    }
+
+    #[test]
+    fn nan_and_empty_lanes_reduce_to_nan() {
+        assert!(q(&[1.0, f64::NAN], 0.5, QuantileMethod::Linear).is_nan());
+        assert!(q(&[], 0.5, QuantileMethod::Linear).is_nan());
+
+        let a = ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![3.0, 1.0, 2.0, 6.0, 5.0, 4.0]).unwrap();
+        let rows = quantile_axis(&a.view(), Axis(1), 0.5, QuantileMethod::Linear);
+        assert_eq!(rows.iter().copied().collect::<Vec<_>>(), vec![2.0, 5.0]);
+    }
+}
@@ -0,0 +1,325 @@
+//! Segment reductions: aggregate rows along axis 0 by a segment-id array.
+//!
+//! Row `i` of the data is folded into output row `segment_ids[i]` in a single
+//! pass over the input, so any number of groups costs one FFI call.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS,
+};
+use crate::helpers::{extract_array_as_f64, extract_array_as_i64, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Aggregate computed per segment.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SegmentOp {
+    Sum,
+    Mean,
+    Min,
+    Max,
+}
+
+/// Reduce `rows` (row-major, `inner` values per row) into `num_segments` output rows.
+///
+/// Empty segments are 0 for `Sum` and NaN otherwise. NaN inputs propagate.
+pub(crate) fn segment_reduce(
+    rows: &[f64],
+    inner: usize,
+    ids: &[i64],
+    num_segments: usize,
+    op: SegmentOp,
+) -> Result<Vec<f64>, String> {
+    let init = match op {
+        SegmentOp::Sum | SegmentOp::Mean => 0.0,
+        SegmentOp::Min => f64::INFINITY,
+        SegmentOp::Max => f64::NEG_INFINITY,
+    };
+    let mut out = vec![init; num_segments * inner];
+    let mut counts = vec![0usize; num_segments];
+
+    for (i, &id) in ids.iter().enumerate() {
+        if id < 0 || id as usize >= num_segments {
+            return Err(format!(
+                "Segment id {} is out of bounds for {} segments",
+                id, num_segments
+            ));
+        }
+        let seg = id as usize;
+        counts[seg] += 1;
+        let src = &rows[i * inner..(i + 1) * inner];
+        let dst = &mut out[seg * inner..(seg + 1) * inner];
+        for (d, &v) in dst.iter_mut().zip(src) {
+            match op {
+                SegmentOp::Sum | SegmentOp::Mean => *d += v,
+                SegmentOp::Min => {
+                    if v < *d || v.is_nan() {
+                        *d = v;
+                    }
+                }
+                SegmentOp::Max => {
+                    if v > *d || v.is_nan() {
+                        *d = v;
+                    }
+                }
+            }
+        }
+    }
+
+    if op != SegmentOp::Sum {
+        for (seg, &count) in counts.iter().enumerate() {
+            let dst = &mut out[seg * inner..(seg + 1) * inner];
+            if count == 0 {
+                dst.fill(f64::NAN);
+            } else if op == SegmentOp::Mean {
+                dst.iter_mut().for_each(|d| *d /= count as f64);
+            }
+        }
+    }
+
+    Ok(out)
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn segment_op(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    ids_handle: *const NdArrayHandle,
+    ids_meta: *const ArrayMetadata,
+    num_segments: i64,
+    op: SegmentOp,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || ids_handle.is_null()
+        || ids_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let ids_wrapper = NdArrayHandle::as_wrapper(ids_handle as *mut _);
+        let meta = &*meta;
+        let ids_meta = &*ids_meta;
+
+        if wrapper.dtype.is_complex() {
+            set_last_error("Segment reductions are not supported for complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if !(ids_wrapper.dtype.is_signed() || ids_wrapper.dtype.is_unsigned()) {
+            set_last_error("Segment ids must have an integer dtype".to_string());
+            return ERR_DTYPE;
+        }
+
+        let shape = meta.shape_slice();
+        if shape.is_empty() {
+            set_last_error("Segment reductions require at least one dimension".to_string());
+            return ERR_SHAPE;
+        }
+        if ids_meta.shape_slice() != [shape[0]] {
+            set_last_error(format!(
+                "Segment ids shape {:?} must be ({},) to match the data's first axis",
+                ids_meta.shape_slice(),
+                shape[0]
+            ));
+            return ERR_SHAPE;
+        }
+
+        let Some(ids_arr) = extract_array_as_i64(ids_wrapper, ids_meta) else {
+            set_last_error("Failed to extract segment ids as Int64".to_string());
+            return ERR_DTYPE;
+        };
+        let ids: Vec<i64> = ids_arr.iter().copied().collect();
+
+        let num_segments = if num_segments >= 0 {
+            num_segments as usize
+        } else {
+            ids.iter().max().map_or(0, |&m| (m.max(-1) + 1) as usize)
+        };
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let arr = arr.as_standard_layout();
+        let inner: usize = shape[1..].iter().product();
+
+        let values = match segment_reduce(arr.as_slice().unwrap(), inner, &ids, num_segments, op) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_INDEX;
+            }
+        };
+
+        let mut result_shape = shape.to_vec();
+        result_shape[0] = num_segments;
+        let result = match ArrayD::from_shape_vec(IxDyn(&result_shape), values) {
+            Ok(r) => r,
+            Err(e) => {
+                set_last_error(format!("Failed to create segment output: {}", e));
+                return ERR_GENERIC;
+            }
+        };
+
+        let result_wrapper = if wrapper.dtype == DType::Float32 {
+            NDArrayWrapper {
+                data: ArrayData::Float32(Arc::new(RwLock::new(result.mapv(|x| x as f32)))),
+                dtype: DType::Float32,
+            }
+        } else {
+            NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+                dtype: DType::Float64,
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Sum rows along axis 0 grouped by `segment_ids`.
+///
+/// `segment_ids` is a 1D integer array with one id per row. The output has shape
+/// `(num_segments, ...)`; a negative `num_segments` uses `max(segment_ids) + 1`.
+/// Ids outside `[0, num_segments)` return ERR_INDEX. Empty segments sum to 0.
+/// Float32 input yields Float32; all other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_segment_sum(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    ids_handle: *const NdArrayHandle,
+    ids_meta: *const ArrayMetadata,
+    num_segments: i64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    segment_op(
+        "segment_sum",
+        handle,
+        meta,
+        ids_handle,
+        ids_meta,
+        num_segments,
+        SegmentOp::Sum,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Mean of rows per segment. Empty segments are NaN. See [`ndarray_segment_sum`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_segment_mean(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    ids_handle: *const NdArrayHandle,
+    ids_meta: *const ArrayMetadata,
+    num_segments: i64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    segment_op(
+        "segment_mean",
+        handle,
+        meta,
+        ids_handle,
+        ids_meta,
+        num_segments,
+        SegmentOp::Mean,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Minimum of rows per segment. Empty segments are NaN. See [`ndarray_segment_sum`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_segment_min(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    ids_handle: *const NdArrayHandle,
+    ids_meta: *const ArrayMetadata,
+    num_segments: i64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    segment_op(
+        "segment_min",
+        handle,
+        meta,
+        ids_handle,
+        ids_meta,
+        num_segments,
+        SegmentOp::Min,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Maximum of rows per segment. Empty segments are NaN. See [`ndarray_segment_sum`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_segment_max(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    ids_handle: *const NdArrayHandle,
+    ids_meta: *const ArrayMetadata,
+    num_segments: i64,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    segment_op(
+        "segment_max",
+        handle,
+        meta,
+        ids_handle,
+        ids_meta,
+        num_segments,
+        SegmentOp::Max,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
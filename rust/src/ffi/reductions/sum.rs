@@ -3,25 +3,31 @@
 use std::ffi::c_void;
 
 use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::ffi::reductions::summation;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_c128, extract_array_c64, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle, SumMode};
 use ndarray::Axis;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute the sum of all elements in the array.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_sum(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
+    mode: i32,
     out_value: *mut c_void,
     out_dtype: *mut u8,
 ) -> i32 {
@@ -31,97 +37,104 @@ pub unsafe extern "C" fn ndarray_sum(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("sum", {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
 
         let scalar = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.sum())
+                ReductionScalar::F64(summation::sum(&arr.view(), mode))
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F32(arr.sum())
+                ReductionScalar::F32(summation::sum(&arr.view(), mode))
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I64(arr.sum())
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I32(arr.sum())
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I16(arr.sum())
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::I8(arr.sum())
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U64(arr.sum())
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U32(arr.sum())
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U16(arr.sum())
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::U8(arr.sum())
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::C64(arr.sum())
             }
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 ReductionScalar::C128(arr.sum())
             }
             DType::Bool => {
                 set_last_error("sum() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -131,12 +144,15 @@ pub unsafe extern "C" fn ndarray_sum(
 }
 
 /// Compute the sum along an axis.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_sum_axis(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     axis: i32,
     keepdims: bool,
+    mode: i32,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -155,7 +171,14 @@ pub unsafe extern "C" fn ndarray_sum_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("sum_axis", {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -173,9 +196,9 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.sum_axis(Axis(axis_usize));
+                let result = summation::sum_axis(&arr.view(), Axis(axis_usize), mode);
                 let final_arr = if keepdims {
                     result.insert_axis(Axis(axis_usize))
                 } else {
@@ -186,12 +209,12 @@ pub unsafe extern "C" fn ndarray_sum_axis(
                     dtype: DType::Float64,
                 }
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = arr.sum_axis(Axis(axis_usize));
+                let result = summation::sum_axis(&arr.view(), Axis(axis_usize), mode);
                 let final_arr = if keepdims {
                     result.insert_axis(Axis(axis_usize))
                 } else {
@@ -205,7 +228,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -221,7 +244,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -237,7 +260,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -253,7 +276,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -269,7 +292,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -285,7 +308,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -301,7 +324,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -317,7 +340,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -333,7 +356,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract Complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -349,7 +372,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract Complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let result = arr.sum_axis(Axis(axis_usize));
                 let final_arr = if keepdims {
@@ -364,7 +387,7 @@ pub unsafe extern "C" fn ndarray_sum_axis(
             }
             DType::Bool => {
                 set_last_error("sum_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -0,0 +1,413 @@
+//! Accurate floating-point summation kernels shared by sum, mean, var and std
+//! (and their NaN-skipping variants).
+//!
+//! Naive left-to-right accumulation loses roughly `n * eps` relative accuracy,
+//! which is visible for long `f32` arrays. Pairwise summation (the default)
+//! reduces this to `O(log n * eps)` at no measurable cost; compensated
+//! summation keeps the error independent of `n` for about twice the work.
+//!
+//! The reduction tree depends only on the element count. In deterministic
+//! mode ([`set_deterministic`]) elements are also always visited in logical
+//! C order, so results are bit-identical regardless of memory layout.
+//! With the `parallel` feature, large pairwise sums evaluate the two halves of
+//! each split on the thread pool; the tree itself is unchanged, so parallel
+//! results are bit-identical to serial ones.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ndarray::{ArrayD, ArrayViewD, Axis, IxDyn};
+use num_traits::Float;
+
+use crate::helpers::parallel;
+use crate::types::SumMode;
+
+/// Process-wide deterministic-reduction flag.
+static DETERMINISTIC: AtomicBool = AtomicBool::new(false);
+
+/// Enable or disable deterministic reductions.
+pub(crate) fn set_deterministic(enabled: bool) {
+    DETERMINISTIC.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether reductions must use a fixed, layout-independent order.
+pub(crate) fn is_deterministic() -> bool {
+    DETERMINISTIC.load(Ordering::Relaxed)
+}
+
+/// Length at or below which pairwise summation switches to an unrolled loop.
+const PAIRWISE_BLOCK: usize = 128;
+
+/// Pairwise sum of `f(x)` over `xs`.
+///
+/// Splits of at least `threshold` elements evaluate their halves on the
+/// thread pool when the `parallel` feature is enabled.
+fn pairwise_sum<T: Float + Send + Sync>(
+    xs: &[T],
+    threshold: usize,
+    f: &(impl Fn(T) -> T + Sync),
+) -> T {
+    if xs.len() <= PAIRWISE_BLOCK {
+        let mut acc = [T::zero(); 8];
+        let chunks = xs.chunks_exact(8);
+        let rest = chunks.remainder();
+        for chunk in chunks {
+            for (a, &x) in acc.iter_mut().zip(chunk) {
+                *a = *a + f(x);
+            }
+        }
+        let mut sum =
+            ((acc[0] + acc[1]) + (acc[2] + acc[3])) + ((acc[4] + acc[5]) + (acc[6] + acc[7]));
+        for &x in rest {
+            sum = sum + f(x);
+        }
+        sum
+    } else {
+        // Split on a multiple of 8 so the leaves stay fully unrolled.
+        let mid = xs.len() / 2 / 8 * 8;
+        let (left, right) = xs.split_at(mid);
+        if cfg!(feature = "parallel") && xs.len() >= threshold {
+            let (l, r) = parallel::join(
+                || pairwise_sum(left, threshold, f),
+                || pairwise_sum(right, threshold, f),
+            );
+            l + r
+        } else {
+            pairwise_sum(left, threshold, f) + pairwise_sum(right, threshold, f)
+        }
+    }
+}
+
+/// Compensated (Kahan) sum of `f(x)` over `xs`.
+fn compensated_sum<T: Float>(xs: &[T], f: &impl Fn(T) -> T) -> T {
+    let mut sum = T::zero();
+    let mut comp = T::zero();
+    for &x in xs {
+        let y = f(x) - comp;
+        let t = sum + y;
+        // After overflow or NaN the correction would itself be NaN; drop it so
+        // infinities propagate exactly as they do in a plain sum.
+        comp = if t.is_finite() {
+            (t - sum) - y
+        } else {
+            T::zero()
+        };
+        sum = t;
+    }
+    sum
+}
+
+/// Sum `f(x)` over `xs` using `mode`.
+pub(crate) fn sum_by<T: Float + Send + Sync>(
+    xs: &[T],
+    mode: SumMode,
+    f: impl Fn(T) -> T + Sync,
+) -> T {
+    match mode {
+        SumMode::Pairwise => pairwise_sum(xs, parallel::parallel_threshold(), &f),
+        SumMode::Kahan => compensated_sum(xs, &f),
+    }
+}
+
+/// Sample variance of `xs` with `ddof` delta degrees of freedom.
+///
+/// Two-pass with the corrected-sum term, so cancellation in `x - mean` does
+/// not inflate the result. Callers ensure `xs.len() > ddof` unless `xs` is
+/// empty, in which case the variance is NaN.
+fn slice_var<T: Float + Send + Sync>(xs: &[T], ddof: T, mode: SumMode) -> T {
+    if xs.is_empty() {
+        return T::nan();
+    }
+    let n = T::from(xs.len()).unwrap();
+    let mean = sum_by(xs, mode, |x| x) / n;
+    let dev_sq = sum_by(xs, mode, |x| (x - mean) * (x - mean));
+    let dev = sum_by(xs, mode, |x| x - mean);
+    ((dev_sq - dev * dev / n) / (n - ddof)).max(T::zero())
+}
+
+/// Run `g` on the elements of `view` as a slice, copying only when needed.
+///
+/// By default any contiguous memory order is used as-is to avoid a copy, so
+/// a float result can differ in the last bits between layouts of the same
+/// logical array (e.g. an F-order array and its C-order copy), since rounding
+/// depends on summation order. In deterministic mode only C-order data is
+/// borrowed; other layouts are copied in logical order, so the result depends
+/// only on the logical contents.
+fn with_slice<T: Float, R>(view: &ArrayViewD<T>, g: impl FnOnce(&[T]) -> R) -> R {
+    with_slice_in(view, is_deterministic(), g)
+}
+
+/// [`with_slice`] with the deterministic flag given explicitly.
+fn with_slice_in<T: Float, R>(view: &ArrayViewD<T>, logical: bool, g: impl FnOnce(&[T]) -> R) -> R {
+    let xs = if logical {
+        view.as_slice()
+    } else {
+        view.as_slice_memory_order()
+    };
+    match xs {
+        Some(xs) => g(xs),
+        None => g(&view.iter().copied().collect::<Vec<_>>()),
+    }
+}
+
+/// Reduce each lane along `axis` with `g`, reusing one buffer for strided lanes.
+fn reduce_lanes<T: Float>(view: &ArrayViewD<T>, axis: Axis, g: impl Fn(&[T]) -> T) -> ArrayD<T> {
+    let mut out_shape = view.shape().to_vec();
+    out_shape.remove(axis.index());
+
+    let mut buf = Vec::with_capacity(view.len_of(axis));
+    let values: Vec<T> = view
+        .lanes(axis)
+        .into_iter()
+        .map(|lane| match lane.as_slice() {
+            Some(xs) => g(xs),
+            None => {
+                buf.clear();
+                buf.extend(lane.iter().copied());
+                g(&buf)
+            }
+        })
+        .collect();
+
+    ArrayD::from_shape_vec(IxDyn(&out_shape), values).expect("lane count matches the reduced shape")
+}
+
+/// Sum of all elements.
+pub(crate) fn sum<T: Float + Send + Sync>(view: &ArrayViewD<T>, mode: SumMode) -> T {
+    with_slice(view, |xs| sum_by(xs, mode, |x| x))
+}
+
+/// Sum along `axis` (the axis is removed).
+pub(crate) fn sum_axis<T: Float + Send + Sync>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    mode: SumMode,
+) -> ArrayD<T> {
+    reduce_lanes(view, axis, |xs| sum_by(xs, mode, |x| x))
+}
+
+/// Mean of all elements, or `None` for an empty array.
+pub(crate) fn mean<T: Float + Send + Sync>(view: &ArrayViewD<T>, mode: SumMode) -> Option<T> {
+    if view.is_empty() {
+        return None;
+    }
+    Some(sum(view, mode) / T::from(view.len()).unwrap())
+}
+
+/// Mean along `axis`, or `None` when the axis is empty.
+pub(crate) fn mean_axis<T: Float + Send + Sync>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    mode: SumMode,
+) -> Option<ArrayD<T>> {
+    let n = view.len_of(axis);
+    if n == 0 {
+        return None;
+    }
+    let n = T::from(n).unwrap();
+    Some(reduce_lanes(view, axis, |xs| sum_by(xs, mode, |x| x) / n))
+}
+
+/// Variance of all elements (NaN when empty). Callers ensure a non-empty
+/// element count exceeds `ddof`.
+pub(crate) fn var<T: Float + Send + Sync>(view: &ArrayViewD<T>, ddof: T, mode: SumMode) -> T {
+    with_slice(view, |xs| slice_var(xs, ddof, mode))
+}
+
+/// Variance along `axis` (NaN lanes when the axis is empty). Callers ensure a
+/// non-empty axis length exceeds `ddof`.
+pub(crate) fn var_axis<T: Float + Send + Sync>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    ddof: T,
+    mode: SumMode,
+) -> ArrayD<T> {
+    reduce_lanes(view, axis, |xs| slice_var(xs, ddof, mode))
+}
+
+/// `x`, or zero when it is NaN, so NaN-skipping sums can reuse [`sum_by`].
+#[inline(always)]
+fn skip_nan<T: Float>(x: T) -> T {
+    if x.is_nan() {
+        T::zero()
+    } else {
+        x
+    }
+}
+
+/// Mean of the non-NaN values of `xs`, or NaN when there are none.
+fn slice_nanmean<T: Float + Send + Sync>(xs: &[T], mode: SumMode) -> T {
+    let n = xs.iter().filter(|x| !x.is_nan()).count();
+    if n == 0 {
+        return T::nan();
+    }
+    sum_by(xs, mode, skip_nan) / T::from(n).unwrap()
+}
+
+/// [`slice_var`] over the non-NaN values of `xs`; NaN when no more than
+/// `ddof` values remain.
+fn slice_nanvar<T: Float + Send + Sync>(xs: &[T], ddof: T, mode: SumMode) -> T {
+    let n = T::from(xs.iter().filter(|x| !x.is_nan()).count()).unwrap();
+    if n == T::zero() || n <= ddof {
+        return T::nan();
+    }
+    let mean = sum_by(xs, mode, skip_nan) / n;
+    let dev_sq = sum_by(xs, mode, |x| skip_nan((x - mean) * (x - mean)));
+    let dev = sum_by(xs, mode, |x| skip_nan(x - mean));
+    ((dev_sq - dev * dev / n) / (n - ddof)).max(T::zero())
+}
+
+/// Sum of all non-NaN elements (zero when every element is NaN).
+pub(crate) fn nansum<T: Float + Send + Sync>(view: &ArrayViewD<T>, mode: SumMode) -> T {
+    with_slice(view, |xs| sum_by(xs, mode, skip_nan))
+}
+
+/// Sum of the non-NaN elements along `axis` (the axis is removed).
+pub(crate) fn nansum_axis<T: Float + Send + Sync>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    mode: SumMode,
+) -> ArrayD<T> {
+    reduce_lanes(view, axis, |xs| sum_by(xs, mode, skip_nan))
+}
+
+/// Mean of all non-NaN elements (NaN when there are none).
+pub(crate) fn nanmean<T: Float + Send + Sync>(view: &ArrayViewD<T>, mode: SumMode) -> T {
+    with_slice(view, |xs| slice_nanmean(xs, mode))
+}
+
+/// Mean of the non-NaN elements along `axis` (NaN for all-NaN lanes).
+pub(crate) fn nanmean_axis<T: Float + Send + Sync>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    mode: SumMode,
+) -> ArrayD<T> {
+    reduce_lanes(view, axis, |xs| slice_nanmean(xs, mode))
+}
+
+/// Variance of all non-NaN elements (NaN when no more than `ddof` remain).
+pub(crate) fn nanvar<T: Float + Send + Sync>(view: &ArrayViewD<T>, ddof: T, mode: SumMode) -> T {
+    with_slice(view, |xs| slice_nanvar(xs, ddof, mode))
+}
+
+/// Variance of the non-NaN elements along `axis`.
+pub(crate) fn nanvar_axis<T: Float + Send + Sync>(
+    view: &ArrayViewD<T>,
+    axis: Axis,
+    ddof: T,
+    mode: SumMode,
+) -> ArrayD<T> {
+    reduce_lanes(view, axis, |xs| slice_nanvar(xs, ddof, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array2;
+
+    #[test]
+    fn pairwise_and_kahan_beat_naive_f32() {
+        // 1e6 copies of 0.1f32: naive accumulation drifts far from 100000.
+        let xs = vec![0.1f32; 1_000_000];
+        let exact = 0.1f32 as f64 * 1e6;
+        let naive = xs.iter().fold(0.0f32, |a, &x| a + x) as f64;
+        let pairwise = sum_by(&xs, SumMode::Pairwise, |x| x) as f64;
+        let kahan = sum_by(&xs, SumMode::Kahan, |x| x) as f64;
+
+        assert!((naive - exact).abs() > 100.0);
+        assert!((pairwise - exact).abs() < 1.0);
+        assert!((kahan - exact).abs() < 0.01);
+    }
+
+    #[test]
+    fn kahan_keeps_small_terms_next_to_large_ones() {
+        let mut xs = vec![1e8f32];
+        xs.extend(std::iter::repeat_n(1.0f32, 1000));
+        assert_eq!(sum_by(&xs, SumMode::Kahan, |x| x), 1e8 + 1000.0);
+    }
+
+    #[test]
+    fn infinities_propagate() {
+        let xs = [1.0f64, f64::INFINITY, 2.0];
+        assert_eq!(sum_by(&xs, SumMode::Kahan, |x| x), f64::INFINITY);
+        assert_eq!(sum_by(&xs, SumMode::Pairwise, |x| x), f64::INFINITY);
+
+        let xs = [f64::INFINITY, f64::NEG_INFINITY];
+        assert!(sum_by(&xs, SumMode::Kahan, |x| x).is_nan());
+    }
+
+    #[test]
+    fn deterministic_mode_sums_in_logical_order() {
+        // Values whose f32 sum depends on the visiting order.
+        let vals: Vec<f32> = (0..300)
+            .map(|i| 1.0 + (i as f32) * 1e-3 * ((i % 7) as f32))
+            .collect();
+        let c = ndarray::Array2::from_shape_vec((20, 15), vals).unwrap();
+        let f = c.t().as_standard_layout().t().to_owned().into_dyn();
+        let c = c.into_dyn();
+
+        let total = |view: &ArrayViewD<f32>| {
+            with_slice_in(view, true, |xs| sum_by(xs, SumMode::Pairwise, |x| x))
+        };
+        assert_eq!(total(&c.view()).to_bits(), total(&f.view()).to_bits());
+
+        // The F-order copy is read in logical order, and in memory order
+        // without the flag.
+        let logical = with_slice_in(&f.view(), true, <[f32]>::to_vec);
+        assert_eq!(logical, c.iter().copied().collect::<Vec<_>>());
+        let memory = with_slice_in(&f.view(), false, <[f32]>::to_vec);
+        assert_eq!(memory, f.as_slice_memory_order().unwrap());
+    }
+
+    #[test]
+    fn axis_reductions_handle_strided_lanes() {
+        let a = Array2::from_shape_fn((3, 200), |(i, j)| (i * 200 + j) as f64).into_dyn();
+        let cols = sum_axis(&a.view(), Axis(0), SumMode::Pairwise);
+        assert_eq!(cols.shape(), &[200]);
+        assert_eq!(cols[[5]], 5.0 + 205.0 + 405.0);
+
+        let rows = mean_axis(&a.view(), Axis(1), SumMode::Kahan).unwrap();
+        assert_eq!(rows[[1]], 200.0 + 99.5);
+
+        let v = var_axis(&a.view(), Axis(0), 1.0, SumMode::Pairwise);
+        assert_eq!(v[[0]], 40_000.0);
+    }
+
+    #[test]
+    fn empty_inputs_reduce_to_identity_or_nan() {
+        let e = Array2::<f64>::zeros((0, 3)).into_dyn();
+        assert_eq!(sum(&e.view(), SumMode::Pairwise), 0.0);
+        assert!(mean(&e.view(), SumMode::Pairwise).is_none());
+        assert!(var(&e.view(), 0.0, SumMode::Pairwise).is_nan());
+
+        let v = var_axis(&e.view(), Axis(0), 1.0, SumMode::Kahan);
+        assert_eq!(v.shape(), &[3]);
+        assert!(v.iter().all(|x| x.is_nan()));
+    }
+
+    #[test]
+    fn split_pairwise_sum_matches_serial_bits() {
+        let xs: Vec<f64> = (0..10_000).map(|i| (i as f64).sin()).collect();
+        let serial = pairwise_sum(&xs, usize::MAX, &|x| x);
+        let split = pairwise_sum(&xs, 256, &|x| x);
+        assert_eq!(serial.to_bits(), split.to_bits());
+    }
+
+    #[test]
+    fn nan_variants_skip_missing_values() {
+        let a = Array2::from_shape_vec(
+            (2, 3),
+            vec![1.0, f64::NAN, 3.0, f64::NAN, f64::NAN, f64::NAN],
+        )
+        .unwrap()
+        .into_dyn();
+        assert_eq!(nansum(&a.view(), SumMode::Pairwise), 4.0);
+        assert_eq!(nanmean(&a.view(), SumMode::Kahan), 2.0);
+        assert_eq!(nanvar(&a.view(), 0.0, SumMode::Pairwise), 1.0);
+        assert!(nanvar(&a.view(), 2.0, SumMode::Pairwise).is_nan());
+
+        let rows = nanmean_axis(&a.view(), Axis(1), SumMode::Pairwise);
+        assert_eq!(rows[[0]], 2.0);
+        assert!(rows[[1]].is_nan());
+        assert_eq!(nansum_axis(&a.view(), Axis(1), SumMode::Pairwise)[[1]], 0.0);
+    }
+}
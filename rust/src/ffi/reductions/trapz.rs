@@ -0,0 +1,320 @@
+//! Trapezoidal integration along an axis.
+//!
+//! Samples are spaced either uniformly by `dx` or by an explicit `x` array,
+//! given as 1D coordinates along the axis or with the shape of `y`. Float32
+//! input yields Float32, Float16/BFloat16 keep their dtype and every other
+//! real dtype yields Float64.
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::extract_array_as_f64;
+use crate::helpers::normalize_axis;
+use crate::helpers::write_output_metadata;
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, ArrayView1, ArrayViewMut1, Axis, IxDyn, Zip};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Width of the `i`-th interval of a lane.
+#[inline]
+fn width(x: Option<&ArrayView1<f64>>, dx: f64, i: usize) -> f64 {
+    x.map_or(dx, |x| x[i + 1] - x[i])
+}
+
+fn trapz_lane(y: ArrayView1<f64>, x: Option<ArrayView1<f64>>, dx: f64) -> f64 {
+    (0..y.len().saturating_sub(1))
+        .map(|i| width(x.as_ref(), dx, i) * (y[i] + y[i + 1]) / 2.0)
+        .sum()
+}
+
+fn cumtrapz_lane(
+    y: ArrayView1<f64>,
+    x: Option<ArrayView1<f64>>,
+    dx: f64,
+    mut out: ArrayViewMut1<f64>,
+    initial: bool,
+) {
+    let offset = usize::from(initial);
+    let mut acc = 0.0;
+    for i in 0..y.len().saturating_sub(1) {
+        acc += width(x.as_ref(), dx, i) * (y[i] + y[i + 1]) / 2.0;
+        out[i + offset] = acc;
+    }
+}
+
+/// Operands of an integration: `y` as f64, the output dtype and the
+/// coordinates (if any) broadcast to `y`'s shape.
+struct Integrand {
+    y: ArrayD<f64>,
+    x: Option<ArrayD<f64>>,
+    axis: usize,
+    dtype: DType,
+}
+
+unsafe fn load_integrand(
+    handle: *const NdArrayHandle,
+    meta: &ArrayMetadata,
+    x_handle: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    dx: f64,
+    axis: i32,
+    name: &str,
+) -> Result<Integrand, (i32, String)> {
+    let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+    let shape = meta.shape_slice();
+    let axis = normalize_axis(shape, axis, false).map_err(|e| (ERR_SHAPE, e))?;
+
+    let mut dtype = wrapper.dtype;
+    let x = if x_handle.is_null() || x_meta.is_null() {
+        if !dx.is_finite() {
+            return Err((ERR_VALUE, format!("{}: dx must be finite", name)));
+        }
+        None
+    } else {
+        let x_wrapper = NdArrayHandle::as_wrapper(x_handle as *mut _);
+        let x_meta = &*x_meta;
+        dtype = DType::promote(dtype, x_wrapper.dtype);
+        let x_shape = x_meta.shape_slice();
+        let kept: Vec<usize> = if x_shape == shape {
+            shape.to_vec()
+        } else if x_shape.len() == 1 && x_shape[0] == shape[axis] {
+            (0..shape.len())
+                .map(|d| if d == axis { shape[axis] } else { 1 })
+                .collect()
+        } else {
+            return Err((
+                ERR_SHAPE,
+                format!(
+                    "{}: x has shape {:?}, expected [{}] or {:?}",
+                    name, x_shape, shape[axis], shape
+                ),
+            ));
+        };
+        let x = extract_array_as_f64(x_wrapper, x_meta)
+            .ok_or_else(|| (ERR_DTYPE, format!("{}: failed to extract x", name)))?
+            .into_shape_with_order(IxDyn(&kept))
+            .map_err(|e| (ERR_SHAPE, e.to_string()))?;
+        let x = x
+            .broadcast(IxDyn(shape))
+            .ok_or_else(|| (ERR_SHAPE, format!("{}: cannot broadcast x", name)))?
+            .to_owned();
+        Some(x)
+    };
+
+    if dtype.is_complex() {
+        return Err((
+            ERR_DTYPE,
+            format!("{} is not supported for {} arrays", name, dtype),
+        ));
+    }
+    let y = extract_array_as_f64(wrapper, meta)
+        .ok_or_else(|| (ERR_DTYPE, "Failed to extract f64 view".to_string()))?;
+    Ok(Integrand { y, x, axis, dtype })
+}
+
+/// Wrap an f64 result in the float dtype of the integrand.
+fn float_result(dtype: DType, result: ArrayD<f64>) -> NDArrayWrapper {
+    match dtype {
+        DType::Float32 => NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(result.mapv(|v| v as f32)))),
+            dtype: DType::Float32,
+        },
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_f32_as_half(result.mapv(|v| v as f32), dtype)
+        }
+        _ => NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+            dtype: DType::Float64,
+        },
+    }
+}
+
+fn trapz(integrand: &Integrand, dx: f64) -> ArrayD<f64> {
+    let Integrand { y, x, axis, .. } = integrand;
+    let mut out_shape = y.shape().to_vec();
+    out_shape.remove(*axis);
+    let mut out = ArrayD::zeros(IxDyn(&out_shape));
+
+    match x {
+        Some(x) => Zip::from(&mut out)
+            .and(y.lanes(Axis(*axis)))
+            .and(x.lanes(Axis(*axis)))
+            .for_each(|o, y, x| *o = trapz_lane(y, Some(x), dx)),
+        None => Zip::from(&mut out)
+            .and(y.lanes(Axis(*axis)))
+            .for_each(|o, y| *o = trapz_lane(y, None, dx)),
+    }
+    out
+}
+
+fn cumtrapz(integrand: &Integrand, dx: f64, initial: bool) -> ArrayD<f64> {
+    let Integrand { y, x, axis, .. } = integrand;
+    let n = y.len_of(Axis(*axis));
+    let mut out_shape = y.shape().to_vec();
+    out_shape[*axis] = if initial { n } else { n.saturating_sub(1) };
+    let mut out = ArrayD::zeros(IxDyn(&out_shape));
+
+    match x {
+        Some(x) => Zip::from(out.lanes_mut(Axis(*axis)))
+            .and(y.lanes(Axis(*axis)))
+            .and(x.lanes(Axis(*axis)))
+            .for_each(|o, y, x| cumtrapz_lane(y, Some(x), dx, o, initial)),
+        None => Zip::from(out.lanes_mut(Axis(*axis)))
+            .and(y.lanes(Axis(*axis)))
+            .for_each(|o, y| cumtrapz_lane(y, None, dx, o, initial)),
+    }
+    out
+}
+
+/// Integrate along `axis` with the trapezoidal rule.
+///
+/// `x_handle`/`x_meta` may be null to use uniform spacing `dx`; otherwise `x`
+/// is 1D with the length of `axis` or has the shape of the input. The output
+/// drops `axis` (a 0-d array for 1D input). Complex arrays return `ERR_DTYPE`.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_trapz(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    x_handle: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    dx: f64,
+    axis: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("trapz", {
+        let integrand = match load_integrand(handle, &*meta, x_handle, x_meta, dx, axis, "trapz") {
+            Ok(i) => i,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+        let result_wrapper = float_result(integrand.dtype, trapz(&integrand, dx));
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Cumulative trapezoidal integral along `axis`.
+///
+/// Spacing is given as in [`ndarray_trapz`]. The output keeps the input's
+/// shape with `axis` shortened by one, or at full length with a leading zero
+/// when `include_initial` is set.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_cumtrapz(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    x_handle: *const NdArrayHandle,
+    x_meta: *const ArrayMetadata,
+    dx: f64,
+    axis: i32,
+    include_initial: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("cumtrapz", {
+        let integrand = match load_integrand(handle, &*meta, x_handle, x_meta, dx, axis, "cumtrapz")
+        {
+            Ok(i) => i,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+        let result_wrapper =
+            float_result(integrand.dtype, cumtrapz(&integrand, dx, include_initial));
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn integrand(y: ArrayD<f64>, x: Option<ArrayD<f64>>, axis: usize) -> Integrand {
+        Integrand {
+            y,
+            x,
+            axis,
+            dtype: DType::Float64,
+        }
+    }
+
+    #[test]
+    fn trapz_with_dx_and_coordinates() {
+        let y = array![1.0, 2.0, 3.0].into_dyn();
+        assert_eq!(
+            trapz(&integrand(y.clone(), None, 0), 1.0).first(),
+            Some(&4.0)
+        );
+        assert_eq!(
+            trapz(&integrand(y.clone(), None, 0), 2.0).first(),
+            Some(&8.0)
+        );
+        let x = array![4.0, 6.0, 8.0].into_dyn();
+        assert_eq!(trapz(&integrand(y, Some(x), 0), 1.0).first(), Some(&8.0));
+    }
+
+    #[test]
+    fn trapz_along_rows() {
+        let y = array![[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]].into_dyn();
+        let out = trapz(&integrand(y, None, 0), 1.0);
+        assert_eq!(out, array![1.5, 2.5, 3.5].into_dyn());
+    }
+
+    #[test]
+    fn cumtrapz_with_and_without_initial() {
+        let y = array![1.0, 2.0, 3.0, 4.0].into_dyn();
+        let i = integrand(y, None, 0);
+        assert_eq!(cumtrapz(&i, 1.0, false), array![1.5, 4.0, 7.5].into_dyn());
+        assert_eq!(
+            cumtrapz(&i, 1.0, true),
+            array![0.0, 1.5, 4.0, 7.5].into_dyn()
+        );
+    }
+}
@@ -3,25 +3,32 @@
 use std::ffi::c_void;
 
 use crate::ffi::reductions::helpers::{write_reduction_scalar, ReductionScalar};
-use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::ffi::reductions::summation;
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64,
-    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_as_f32, extract_array_f64, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle, SumMode};
 use ndarray::Axis;
 use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute the variance of all elements in the array.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_var(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     ddof: f64,
+    mode: i32,
     out_value: *mut c_void,
     out_dtype: *mut u8,
 ) -> i32 {
@@ -31,98 +38,106 @@ pub unsafe extern "C" fn ndarray_var(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("var", {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
         let n = shape_slice.iter().map(|&x| x as f64).product::<f64>();
 
-        if n <= ddof {
+        // Zero-size input reduces to NaN, as in NumPy.
+        if n > 0.0 && n <= ddof {
             set_last_error(format!(
                 "ddof ({}) must be less than number of elements ({})",
                 ddof, n as usize
             ));
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let scalar = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.var(ddof))
+                ReductionScalar::F64(summation::var(&arr.view(), ddof, mode))
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F32(arr.var(ddof as f32))
+                ReductionScalar::F32(summation::var(&arr.view(), ddof as f32, mode))
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                ReductionScalar::F64(arr.mapv(|x| x as f64).var(ddof))
+                ReductionScalar::F64(summation::var(&arr.mapv(|x| x as f64).view(), ddof, mode))
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("var() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("var() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -132,6 +147,8 @@ pub unsafe extern "C" fn ndarray_var(
 }
 
 /// Compute the variance along an axis in the array.
+///
+/// `mode` selects the float summation strategy (see [`SumMode`]).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_var_axis(
     handle: *const NdArrayHandle,
@@ -139,6 +156,7 @@ pub unsafe extern "C" fn ndarray_var_axis(
     axis: i32,
     keepdims: bool,
     ddof: f64,
+    mode: i32,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -156,7 +174,14 @@ pub unsafe extern "C" fn ndarray_var_axis(
 
     let meta = &*meta;
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("var_axis", {
+        let mode = match SumMode::from_i32(mode) {
+            Ok(m) => m,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let shape_slice = meta.shape_slice();
 
@@ -171,12 +196,12 @@ pub unsafe extern "C" fn ndarray_var_axis(
 
         let axis_len = shape_slice[axis_usize];
 
-        if (axis_len as f64) <= ddof {
+        if axis_len > 0 && (axis_len as f64) <= ddof {
             set_last_error(format!(
                 "ddof ({}) must be less than axis length ({})",
                 ddof, axis_len
             ));
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         // Match on dtype, extract view, compute variance along axis, and create result wrapper
@@ -184,80 +209,80 @@ pub unsafe extern "C" fn ndarray_var_axis(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.view(), Axis(axis_usize), ddof, mode)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                arr.mapv(|x| x as f64).var_axis(Axis(axis_usize), ddof)
+                summation::var_axis(&arr.mapv(|x| x as f64).view(), Axis(axis_usize), ddof, mode)
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("var_axis() not supported for complex dtypes".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Bool => {
                 set_last_error("var_axis() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -0,0 +1,347 @@
+//! Shared helpers for set operations.
+
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use ndarray::{ArrayD, Axis, IxDyn};
+use parking_lot::RwLock;
+
+use crate::ffi::sorting::helpers::{cmp_f32_asc_nan_last, cmp_f64_asc_nan_last};
+use crate::helpers::error::ERR_DTYPE;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_as_f64, extract_array_as_i16, extract_array_as_i32,
+    extract_array_as_i64, extract_array_as_i8, extract_array_as_u16, extract_array_as_u32,
+    extract_array_as_u64, extract_array_as_u8, extract_array_bool,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper};
+
+/// Total order used to deduplicate values; NaN sorts last and all NaNs
+/// compare equal, so they collapse into a single entry.
+pub(crate) trait SetKey: Copy {
+    fn cmp_key(&self, other: &Self) -> Ordering;
+
+    /// NaN never matches anything in membership tests, as in NumPy.
+    fn is_nan_key(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! impl_set_key_ord {
+    ($($t:ty),*) => {
+        $(impl SetKey for $t {
+            fn cmp_key(&self, other: &Self) -> Ordering {
+                self.cmp(other)
+            }
+        })*
+    };
+}
+
+impl_set_key_ord!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl SetKey for f32 {
+    fn cmp_key(&self, other: &Self) -> Ordering {
+        cmp_f32_asc_nan_last(self, other)
+    }
+
+    fn is_nan_key(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl SetKey for f64 {
+    fn cmp_key(&self, other: &Self) -> Ordering {
+        cmp_f64_asc_nan_last(self, other)
+    }
+
+    fn is_nan_key(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+/// Sorted unique values and, for every input position, the index of its
+/// value in the unique list.
+pub(crate) fn unique_inverse<T: SetKey>(values: &[T]) -> (Vec<T>, Vec<i64>) {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|&a, &b| values[a].cmp_key(&values[b]));
+
+    let mut unique: Vec<T> = Vec::new();
+    let mut inverse = vec![0i64; values.len()];
+    for &i in &order {
+        if unique
+            .last()
+            .is_none_or(|last| last.cmp_key(&values[i]) != Ordering::Equal)
+        {
+            unique.push(values[i]);
+        }
+        inverse[i] = unique.len() as i64 - 1;
+    }
+    (unique, inverse)
+}
+
+/// Unique slices of `arr` along `axis`, compared lexicographically in C order.
+///
+/// Returns the position of the first occurrence of each unique slice, in
+/// sorted slice order, and for every position along `axis` the index of its
+/// slice in that list.
+pub(crate) fn unique_slices<T: SetKey>(arr: &ArrayD<T>, axis: usize) -> (Vec<usize>, Vec<i64>) {
+    let slices: Vec<Vec<T>> = arr
+        .axis_iter(Axis(axis))
+        .map(|slice| slice.iter().copied().collect())
+        .collect();
+    let cmp = |a: &[T], b: &[T]| {
+        a.iter()
+            .zip(b)
+            .map(|(x, y)| x.cmp_key(y))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(Ordering::Equal)
+    };
+
+    // Stable, so the first slice of every run is its first occurrence.
+    let mut order: Vec<usize> = (0..slices.len()).collect();
+    order.sort_by(|&a, &b| cmp(&slices[a], &slices[b]));
+
+    let mut first: Vec<usize> = Vec::new();
+    let mut inverse = vec![0i64; slices.len()];
+    for &i in &order {
+        if first
+            .last()
+            .is_none_or(|&f| cmp(&slices[f], &slices[i]) != Ordering::Equal)
+        {
+            first.push(i);
+        }
+        inverse[i] = first.len() as i64 - 1;
+    }
+    (first, inverse)
+}
+
+/// Sort `values`, dropping duplicates unless the caller promises there are none.
+fn sorted_set<T: SetKey>(mut values: Vec<T>, assume_unique: bool) -> Vec<T> {
+    values.sort_by(T::cmp_key);
+    if !assume_unique {
+        values.dedup_by(|a, b| a.cmp_key(b) == Ordering::Equal);
+    }
+    values
+}
+
+/// Whether `x` occurs in the sorted slice `set`.
+fn contains<T: SetKey>(set: &[T], x: &T) -> bool {
+    !x.is_nan_key() && set.binary_search_by(|p| p.cmp_key(x)).is_ok()
+}
+
+/// Output of a [`SetOp`]: values for the 1D operations, a mask for `IsIn`.
+#[derive(Debug, PartialEq)]
+enum SetResult<T> {
+    Values(Vec<T>),
+    Mask(Vec<u8>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SetOp {
+    /// Sorted unique values present in both inputs.
+    Intersect { assume_unique: bool },
+    /// Sorted unique values present in either input.
+    Union,
+    /// Unique values of the first input missing from the second. With
+    /// `assume_unique` the first input's order is kept instead of sorting.
+    SetDiff { assume_unique: bool },
+    /// Per-element membership of the first input in the second.
+    IsIn { assume_unique: bool, invert: bool },
+}
+
+impl SetOp {
+    fn name(self) -> &'static str {
+        match self {
+            SetOp::Intersect { .. } => "intersect1d",
+            SetOp::Union => "union1d",
+            SetOp::SetDiff { .. } => "setdiff1d",
+            SetOp::IsIn { .. } => "isin",
+        }
+    }
+
+    fn apply<T: SetKey>(self, a: Vec<T>, b: Vec<T>) -> SetResult<T> {
+        match self {
+            SetOp::Intersect { assume_unique } => {
+                let b = sorted_set(b, assume_unique);
+                let a = sorted_set(a, assume_unique);
+                SetResult::Values(a.into_iter().filter(|x| contains(&b, x)).collect())
+            }
+            SetOp::Union => {
+                let mut all = a;
+                all.extend(b);
+                SetResult::Values(sorted_set(all, false))
+            }
+            SetOp::SetDiff { assume_unique } => {
+                let b = sorted_set(b, assume_unique);
+                let a = if assume_unique {
+                    a
+                } else {
+                    sorted_set(a, false)
+                };
+                SetResult::Values(a.into_iter().filter(|x| !contains(&b, x)).collect())
+            }
+            SetOp::IsIn {
+                assume_unique,
+                invert,
+            } => {
+                let b = sorted_set(b, assume_unique);
+                SetResult::Mask(
+                    a.iter()
+                        .map(|x| (contains(&b, x) != invert) as u8)
+                        .collect(),
+                )
+            }
+        }
+    }
+}
+
+/// Evaluate `op` on two flattened arrays after dtype promotion.
+///
+/// The 1D operations return the promoted dtype (Float16/BFloat16 are compared
+/// in f32); `IsIn` returns a Bool array with `a`'s shape. Complex operands are
+/// rejected. Errors carry the FFI status code to return.
+pub(crate) unsafe fn set_op_arrays(
+    op: SetOp,
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    b_wrapper: &NDArrayWrapper,
+    b_meta: &ArrayMetadata,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+    let a_shape = a_meta.shape_slice();
+
+    let mask_wrapper = |mask: Vec<u8>| NDArrayWrapper {
+        data: ArrayData::Bool(Arc::new(RwLock::new(
+            ArrayD::from_shape_vec(IxDyn(a_shape), mask).unwrap(),
+        ))),
+        dtype: DType::Bool,
+    };
+
+    macro_rules! apply_with {
+        ($extract_fn:ident) => {{
+            let (Some(a), Some(b)) = (
+                $extract_fn(a_wrapper, a_meta),
+                $extract_fn(b_wrapper, b_meta),
+            ) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to prepare {} operands for {}", dtype, op.name()),
+                ));
+            };
+            op.apply(a.iter().copied().collect(), b.iter().copied().collect())
+        }};
+    }
+
+    macro_rules! apply_as {
+        ($variant:ident, $extract_fn:ident) => {
+            match apply_with!($extract_fn) {
+                SetResult::Values(values) => NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(
+                        ArrayD::from_shape_vec(IxDyn(&[values.len()]), values).unwrap(),
+                    ))),
+                    dtype: DType::$variant,
+                },
+                SetResult::Mask(mask) => mask_wrapper(mask),
+            }
+        };
+    }
+
+    Ok(match dtype {
+        DType::Int8 => apply_as!(Int8, extract_array_as_i8),
+        DType::Int16 => apply_as!(Int16, extract_array_as_i16),
+        DType::Int32 => apply_as!(Int32, extract_array_as_i32),
+        DType::Int64 => apply_as!(Int64, extract_array_as_i64),
+        DType::Uint8 => apply_as!(Uint8, extract_array_as_u8),
+        DType::Uint16 => apply_as!(Uint16, extract_array_as_u16),
+        DType::Uint32 => apply_as!(Uint32, extract_array_as_u32),
+        DType::Uint64 => apply_as!(Uint64, extract_array_as_u64),
+        DType::Float32 => apply_as!(Float32, extract_array_as_f32),
+        DType::Float64 => apply_as!(Float64, extract_array_as_f64),
+        DType::Bool => apply_as!(Bool, extract_array_bool),
+        DType::Float16 | DType::BFloat16 => match apply_with!(extract_array_as_f32) {
+            SetResult::Values(values) => NDArrayWrapper::from_f32_as_half(
+                ArrayD::from_shape_vec(IxDyn(&[values.len()]), values).unwrap(),
+                dtype,
+            ),
+            SetResult::Mask(mask) => mask_wrapper(mask),
+        },
+        DType::Complex64 | DType::Complex128 => {
+            return Err((
+                ERR_DTYPE,
+                format!("{} is not supported for {} arrays", op.name(), dtype),
+            ));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_inverse_collapses_nan() {
+        let (unique, inverse) = unique_inverse(&[2.0, f64::NAN, 1.0, 2.0, f64::NAN]);
+        assert_eq!(unique.len(), 3);
+        assert_eq!(&unique[..2], &[1.0, 2.0]);
+        assert!(unique[2].is_nan());
+        assert_eq!(inverse, vec![1, 2, 0, 1, 2]);
+    }
+
+    #[test]
+    fn unique_slices_keeps_first_occurrence() {
+        let rows = ArrayD::from_shape_vec(IxDyn(&[4, 2]), vec![3, 1, 1, 2, 3, 1, 1, 0]).unwrap();
+        let (first, inverse) = unique_slices(&rows, 0);
+        assert_eq!(first, vec![3, 1, 0]);
+        assert_eq!(inverse, vec![2, 1, 2, 0]);
+
+        let (first, inverse) = unique_slices(&rows, 1);
+        assert_eq!(first, vec![1, 0]);
+        assert_eq!(inverse, vec![1, 0]);
+    }
+
+    #[test]
+    fn set_ops_match_numpy() {
+        let a = vec![5, 1, 3, 3, 7];
+        let b = vec![3, 5, 8];
+        let run = |op: SetOp| op.apply(a.clone(), b.clone());
+        assert_eq!(
+            run(SetOp::Intersect {
+                assume_unique: false
+            }),
+            SetResult::Values(vec![3, 5])
+        );
+        assert_eq!(run(SetOp::Union), SetResult::Values(vec![1, 3, 5, 7, 8]));
+        assert_eq!(
+            run(SetOp::SetDiff {
+                assume_unique: false
+            }),
+            SetResult::Values(vec![1, 7])
+        );
+        assert_eq!(
+            SetOp::SetDiff {
+                assume_unique: true
+            }
+            .apply(vec![7, 5, 1], b.clone()),
+            SetResult::Values(vec![7, 1])
+        );
+        assert_eq!(
+            run(SetOp::IsIn {
+                assume_unique: false,
+                invert: false
+            }),
+            SetResult::Mask(vec![1, 0, 1, 1, 0])
+        );
+    }
+
+    #[test]
+    fn nan_never_matches() {
+        let isin = SetOp::IsIn {
+            assume_unique: false,
+            invert: false,
+        };
+        assert_eq!(
+            isin.apply(vec![f64::NAN, 1.0], vec![f64::NAN, 1.0]),
+            SetResult::Mask(vec![0, 1])
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Intersection of two arrays.
+
+use super::helpers::{set_op_arrays, SetOp};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Sorted unique values present in both flattened arrays.
+///
+/// Operands are promoted to a common dtype. With `assume_unique` both inputs
+/// are taken to be free of duplicates, which skips deduplication.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_intersect1d(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    assume_unique: bool,
+    out: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || b.is_null()
+        || out.is_null()
+        || out_dtype_ptr.is_null()
+        || out_shape.is_null()
+        || out_ndim.is_null()
+        || a_meta.is_null()
+        || b_meta.is_null()
+    {
+        set_last_error("Invalid input parameters".to_string());
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("intersect1d", {
+        let a_meta = &*a_meta;
+        let b_meta = &*b_meta;
+
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+
+        let op = SetOp::Intersect { assume_unique };
+        let result_wrapper = match set_op_arrays(op, a_wrapper, a_meta, b_wrapper, b_meta) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,72 @@
+//! Element-wise membership test.
+
+use super::helpers::{set_op_arrays, SetOp};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Test whether each element of `a` occurs in `b`.
+///
+/// Returns a Bool array with `a`'s shape; `b` is flattened. Operands are
+/// compared after promotion to a common dtype, and NaN never matches.
+/// `assume_unique` skips deduplicating `b`, and `invert` negates the result.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_isin(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    b: *const NdArrayHandle,
+    b_meta: *const ArrayMetadata,
+    assume_unique: bool,
+    invert: bool,
+    out: *mut *mut NdArrayHandle,
+    out_dtype_ptr: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || b.is_null()
+        || out.is_null()
+        || out_dtype_ptr.is_null()
+        || out_shape.is_null()
+        || out_ndim.is_null()
+        || a_meta.is_null()
+        || b_meta.is_null()
+    {
+        set_last_error("Invalid input parameters".to_string());
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("isin", {
+        let a_meta = &*a_meta;
+        let b_meta = &*b_meta;
+
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let b_wrapper = NdArrayHandle::as_wrapper(b as *mut _);
+
+        let op = SetOp::IsIn {
+            assume_unique,
+            invert,
+        };
+        let result_wrapper = match set_op_arrays(op, a_wrapper, a_meta, b_wrapper, b_meta) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) = write_output_metadata(
+            &result_wrapper,
+            out_dtype_ptr,
+            out_ndim,
+            out_shape,
+            max_ndim,
+        ) {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
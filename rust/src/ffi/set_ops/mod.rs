@@ -0,0 +1,19 @@
+//! Set operations on flattened arrays, plus unique slices along an axis.
+//!
+//! Results are sorted ascending with NaN last; all NaNs count as one value.
+
+pub(crate) mod helpers;
+
+pub mod intersect1d;
+pub mod isin;
+pub mod setdiff1d;
+pub mod union1d;
+pub mod unique;
+pub mod unique_axis;
+
+pub use intersect1d::*;
+pub use isin::*;
+pub use setdiff1d::*;
+pub use union1d::*;
+pub use unique::*;
+pub use unique_axis::*;
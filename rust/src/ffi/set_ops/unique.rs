@@ -0,0 +1,111 @@
+//! Sorted unique values with optional inverse indices and counts.
+
+use ndarray::{ArrayD, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use super::helpers::unique_inverse;
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::{
+    extract_array_as_f32, extract_array_bool, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Find the sorted unique values of the flattened array.
+///
+/// `out_values` keeps the input dtype and has shape `[out_len]`. When
+/// non-null, `out_inverse` receives Int64 indices into the unique values with
+/// the input's shape (so `values[inverse]` rebuilds the input), and
+/// `out_counts` receives the Int64 number of occurrences of each unique
+/// value. NaN sorts last and all NaNs collapse into one entry. Complex arrays
+/// are not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_unique(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    out_values: *mut *mut NdArrayHandle,
+    out_inverse: *mut *mut NdArrayHandle,
+    out_counts: *mut *mut NdArrayHandle,
+    out_len: *mut usize,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_values.is_null() || out_len.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("unique", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        macro_rules! unique_arm {
+            ($extract:ident, |$u:ident| $wrap:expr) => {{
+                let Some(arr) = $extract(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} values", wrapper.dtype));
+                    return ERR_DTYPE;
+                };
+                let values: Vec<_> = arr.iter().copied().collect();
+                let (unique, inverse) = unique_inverse(&values);
+                let n = unique.len();
+                let $u = ArrayD::from_shape_vec(IxDyn(&[n]), unique).unwrap();
+                ($wrap, inverse)
+            }};
+            ($extract:ident, $variant:ident) => {
+                unique_arm!($extract, |u| NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(u))),
+                    dtype: DType::$variant,
+                })
+            };
+        }
+
+        let (values, inverse) = match wrapper.dtype {
+            DType::Int64 => unique_arm!(extract_array_i64, Int64),
+            DType::Int32 => unique_arm!(extract_array_i32, Int32),
+            DType::Int16 => unique_arm!(extract_array_i16, Int16),
+            DType::Int8 => unique_arm!(extract_array_i8, Int8),
+            DType::Uint64 => unique_arm!(extract_array_u64, Uint64),
+            DType::Uint32 => unique_arm!(extract_array_u32, Uint32),
+            DType::Uint16 => unique_arm!(extract_array_u16, Uint16),
+            DType::Uint8 => unique_arm!(extract_array_u8, Uint8),
+            DType::Bool => unique_arm!(extract_array_bool, Bool),
+            DType::Float64 => unique_arm!(extract_array_f64, Float64),
+            DType::Float32 => unique_arm!(extract_array_f32, Float32),
+            DType::Float16 | DType::BFloat16 => unique_arm!(extract_array_as_f32, |u| {
+                NDArrayWrapper::from_f32_as_half(u, wrapper.dtype)
+            }),
+            DType::Complex64 | DType::Complex128 => {
+                set_last_error(format!(
+                    "unique is not supported for {} arrays",
+                    wrapper.dtype
+                ));
+                return ERR_DTYPE;
+            }
+        };
+        let len = values.len();
+
+        if !out_counts.is_null() {
+            let mut counts = vec![0i64; len];
+            for &i in &inverse {
+                counts[i as usize] += 1;
+            }
+            *out_counts = NdArrayHandle::from_wrapper(Box::new(NDArrayWrapper {
+                data: ArrayData::Int64(Arc::new(RwLock::new(
+                    ArrayD::from_shape_vec(IxDyn(&[len]), counts).unwrap(),
+                ))),
+                dtype: DType::Int64,
+            }));
+        }
+        if !out_inverse.is_null() {
+            *out_inverse = NdArrayHandle::from_wrapper(Box::new(NDArrayWrapper {
+                data: ArrayData::Int64(Arc::new(RwLock::new(
+                    ArrayD::from_shape_vec(IxDyn(meta.shape_slice()), inverse).unwrap(),
+                ))),
+                dtype: DType::Int64,
+            }));
+        }
+        *out_values = NdArrayHandle::from_wrapper(Box::new(values));
+        *out_len = len;
+        SUCCESS
+    })
+}
@@ -0,0 +1,124 @@
+//! Unique slices along an axis, like `numpy.unique(axis=...)`.
+
+use ndarray::{ArrayD, Axis, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use super::helpers::unique_slices;
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::normalize_axis;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_bool, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+fn int64_handle(values: Vec<i64>) -> *mut NdArrayHandle {
+    let len = values.len();
+    NdArrayHandle::from_wrapper(Box::new(NDArrayWrapper {
+        data: ArrayData::Int64(Arc::new(RwLock::new(
+            ArrayD::from_shape_vec(IxDyn(&[len]), values).unwrap(),
+        ))),
+        dtype: DType::Int64,
+    }))
+}
+
+/// Find the unique slices of the array along `axis`.
+///
+/// Slices are compared element by element in C order and returned sorted.
+/// `out_values` keeps the input dtype and shape except that `axis` has length
+/// `out_len`. The optional outputs are Int64: `out_index` holds the position
+/// of each unique slice's first occurrence, `out_inverse` maps every position
+/// along `axis` to its unique slice, and `out_counts` holds occurrences per
+/// unique slice. NaN sorts last and NaNs compare equal, so slices with NaN in
+/// the same positions are merged. Complex arrays are not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_unique_axis(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    out_values: *mut *mut NdArrayHandle,
+    out_index: *mut *mut NdArrayHandle,
+    out_inverse: *mut *mut NdArrayHandle,
+    out_counts: *mut *mut NdArrayHandle,
+    out_len: *mut usize,
+) -> i32 {
+    if handle.is_null() || meta.is_null() || out_values.is_null() || out_len.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("unique_axis", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        macro_rules! unique_arm {
+            ($extract:ident, |$u:ident| $wrap:expr) => {{
+                let Some(arr) = $extract(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} values", wrapper.dtype));
+                    return ERR_DTYPE;
+                };
+                let (first, inverse) = unique_slices(&arr, axis);
+                let $u = arr.select(Axis(axis), &first);
+                ($wrap, first, inverse)
+            }};
+            ($extract:ident, $variant:ident) => {
+                unique_arm!($extract, |u| NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(u))),
+                    dtype: DType::$variant,
+                })
+            };
+        }
+
+        let (values, first, inverse) = match wrapper.dtype {
+            DType::Int64 => unique_arm!(extract_array_i64, Int64),
+            DType::Int32 => unique_arm!(extract_array_i32, Int32),
+            DType::Int16 => unique_arm!(extract_array_i16, Int16),
+            DType::Int8 => unique_arm!(extract_array_i8, Int8),
+            DType::Uint64 => unique_arm!(extract_array_u64, Uint64),
+            DType::Uint32 => unique_arm!(extract_array_u32, Uint32),
+            DType::Uint16 => unique_arm!(extract_array_u16, Uint16),
+            DType::Uint8 => unique_arm!(extract_array_u8, Uint8),
+            DType::Bool => unique_arm!(extract_array_bool, Bool),
+            DType::Float64 => unique_arm!(extract_array_f64, Float64),
+            DType::Float32 => unique_arm!(extract_array_f32, Float32),
+            DType::Float16 | DType::BFloat16 => unique_arm!(extract_array_as_f32, |u| {
+                NDArrayWrapper::from_f32_as_half(u, wrapper.dtype)
+            }),
+            DType::Complex64 | DType::Complex128 => {
+                set_last_error(format!(
+                    "unique is not supported for {} arrays",
+                    wrapper.dtype
+                ));
+                return ERR_DTYPE;
+            }
+        };
+        let len = first.len();
+
+        if !out_counts.is_null() {
+            let mut counts = vec![0i64; len];
+            for &i in &inverse {
+                counts[i as usize] += 1;
+            }
+            *out_counts = int64_handle(counts);
+        }
+        if !out_inverse.is_null() {
+            *out_inverse = int64_handle(inverse);
+        }
+        if !out_index.is_null() {
+            *out_index = int64_handle(first.into_iter().map(|i| i as i64).collect());
+        }
+        *out_values = NdArrayHandle::from_wrapper(Box::new(values));
+        *out_len = len;
+        SUCCESS
+    })
+}
@@ -1,11 +1,12 @@
 //! Flatten and ravel operations.
 
-use crate::helpers::error::{self, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -34,7 +35,7 @@ pub unsafe extern "C" fn ndarray_flatten(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("flatten", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -42,7 +43,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -53,7 +54,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -64,7 +65,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -75,7 +76,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -86,7 +87,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -97,7 +98,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -108,7 +109,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -119,7 +120,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -130,7 +131,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -138,10 +139,18 @@ pub unsafe extern "C" fn ndarray_flatten(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let flat = arr.flatten().into_owned().into_dyn();
+                NDArrayWrapper::from_half_bits(flat, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -152,7 +161,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -163,7 +172,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -174,7 +183,7 @@ pub unsafe extern "C" fn ndarray_flatten(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten().into_owned().into_dyn();
                 NDArrayWrapper {
@@ -221,7 +230,7 @@ pub unsafe extern "C" fn ndarray_ravel(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("ravel", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta_ref = &*meta;
 
@@ -236,7 +245,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -247,7 +256,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -258,7 +267,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -269,7 +278,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -280,7 +289,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -291,7 +300,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -302,7 +311,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -313,7 +322,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -324,7 +333,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -332,10 +341,18 @@ pub unsafe extern "C" fn ndarray_ravel(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta_ref) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let flat = arr.flatten_with_order(order).into_owned().into_dyn();
+                NDArrayWrapper::from_half_bits(flat, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -346,7 +363,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -357,7 +374,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -368,7 +385,7 @@ pub unsafe extern "C" fn ndarray_ravel(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta_ref) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let flat = arr.flatten_with_order(order).into_owned().into_dyn();
                 NDArrayWrapper {
@@ -1,12 +1,13 @@
 //! Flip operations - reverse elements along axis/axes.
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
-use crate::helpers::write_output_metadata;
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
+use crate::helpers::{normalize_axes, write_output_metadata};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use ndarray::Axis;
@@ -40,45 +41,31 @@ pub unsafe extern "C" fn ndarray_flip(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("flip", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let ndim = meta.ndim;
 
-        // Parse axes
+        // Parse axes (negative axes count from the end)
         let axes_to_flip: Vec<usize> = if num_axes == 0 {
             // Flip all axes
             (0..ndim).collect()
         } else {
             let axes_slice = std::slice::from_raw_parts(axes, num_axes);
-            axes_slice
-                .iter()
-                .map(|&ax| {
-                    if ax < 0 {
-                        (ndim as i64 + ax) as usize
-                    } else {
-                        ax as usize
-                    }
-                })
-                .collect()
-        };
-
-        // Validate all axes
-        for &axis in &axes_to_flip {
-            if axis >= ndim {
-                error::set_last_error(format!(
-                    "Axis {} out of bounds for array with {} dimensions",
-                    axis, ndim
-                ));
-                return ERR_SHAPE;
+            match normalize_axes(meta.shape_slice(), axes_slice) {
+                Ok(a) => a,
+                Err(e) => {
+                    error::set_last_error(e);
+                    return ERR_SHAPE;
+                }
             }
-        }
+        };
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -95,7 +82,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -112,7 +99,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -129,7 +116,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -146,7 +133,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -163,7 +150,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -180,7 +167,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -197,7 +184,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -214,7 +201,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -228,10 +215,24 @@ pub unsafe extern "C" fn ndarray_flip(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let mut flipped = arr.to_owned();
+                for &axis in &axes_to_flip {
+                    flipped.invert_axis(Axis(axis));
+                }
+                let data: Vec<u16> = flipped.iter().cloned().collect();
+                let result = ndarray::ArrayD::from_shape_vec(flipped.raw_dim(), data)
+                    .expect("Failed to create flipped array");
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -248,7 +249,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -265,7 +266,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -282,7 +283,7 @@ pub unsafe extern "C" fn ndarray_flip(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let mut flipped = arr.to_owned();
                 for &axis in &axes_to_flip {
@@ -1,13 +1,15 @@
 //! Pad operations.
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
+use crate::types::half::f32_to_half;
 use crate::types::PadMode;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use ndarray::{ArrayD, IxDyn};
@@ -179,7 +181,7 @@ pub unsafe extern "C" fn ndarray_pad(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("pad", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let ndim = meta.ndim;
@@ -194,7 +196,7 @@ pub unsafe extern "C" fn ndarray_pad(
             Ok(m) => m,
             Err(e) => {
                 error::set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
 
@@ -212,7 +214,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -230,7 +232,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as f32);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -248,7 +250,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as i64);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -266,7 +268,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as i32);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -284,7 +286,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as i16);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -302,7 +304,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as i8);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -320,7 +322,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as u64);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -338,7 +340,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as u32);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -356,7 +358,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as u16);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -371,10 +373,28 @@ pub unsafe extern "C" fn ndarray_pad(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let consts: Vec<(u16, u16)> = parse_constants(constant_slice, ndim, |x| x as f32)
+                    .into_iter()
+                    .map(|(b, a)| (f32_to_half(wrapper.dtype, b), f32_to_half(wrapper.dtype, a)))
+                    .collect();
+                let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        error::set_last_error(e);
+                        return ERR_SHAPE;
+                    }
+                };
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| x as u8);
                 let result = match pad_view(&arr, &pad_pairs, pad_mode, &consts) {
@@ -392,7 +412,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     error::set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts =
                     parse_constants(constant_slice, ndim, |x| if x != 0.0 { 1u8 } else { 0u8 });
@@ -411,7 +431,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts = parse_constants(constant_slice, ndim, |x| {
                     num_complex::Complex::new(x as f32, 0.0)
@@ -431,7 +451,7 @@ pub unsafe extern "C" fn ndarray_pad(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let consts =
                     parse_constants(constant_slice, ndim, |x| num_complex::Complex::new(x, 0.0));
@@ -1,12 +1,12 @@
 //! Permute axes operations.
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
-use crate::helpers::write_output_metadata;
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64,
+    extract_array_half_bits, extract_array_i16, extract_array_i32, extract_array_i64,
+    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
+use crate::helpers::{normalize_axes, write_output_metadata};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 use parking_lot::RwLock;
@@ -14,13 +14,13 @@ use std::sync::Arc;
 
 /// Permute axes of the array and return in standard layout.
 ///
-/// The axes array specifies the new order of axes.
-/// Panics if axes are invalid (out of bounds, missing, or duplicated).
+/// The axes array specifies the new order of axes; negative axes count
+/// from the end. Returns ERR_SHAPE if axes are out of bounds, missing, or duplicated.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_permute(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
-    axes: *const usize,
+    axes: *const i64,
     num_axes: usize,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
@@ -39,11 +39,11 @@ pub unsafe extern "C" fn ndarray_permute(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("permute", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let ndim = meta.ndim;
-        let axes_slice = std::slice::from_raw_parts(axes, num_axes);
+        let axes_raw = std::slice::from_raw_parts(axes, num_axes);
 
         // Validate axes
         if num_axes != ndim {
@@ -54,28 +54,21 @@ pub unsafe extern "C" fn ndarray_permute(
             return ERR_SHAPE;
         }
 
-        // Check for valid axis indices and duplicates
-        let mut seen = vec![false; ndim];
-        for &axis in axes_slice {
-            if axis >= ndim {
-                error::set_last_error(format!(
-                    "Axis {} out of bounds for array with {} dimensions",
-                    axis, ndim
-                ));
+        // Resolve negative axes and reject out-of-bounds or duplicated ones
+        let axes_vec = match normalize_axes(meta.shape_slice(), axes_raw) {
+            Ok(a) => a,
+            Err(e) => {
+                error::set_last_error(e);
                 return ERR_SHAPE;
             }
-            if seen[axis] {
-                error::set_last_error(format!("Axis {} appears more than once", axis));
-                return ERR_SHAPE;
-            }
-            seen[axis] = true;
-        }
+        };
+        let axes_slice = axes_vec.as_slice();
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<f64> = permuted.iter().cloned().collect();
@@ -89,7 +82,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<f32> = permuted.iter().cloned().collect();
@@ -103,7 +96,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<i64> = permuted.iter().cloned().collect();
@@ -117,7 +110,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<i32> = permuted.iter().cloned().collect();
@@ -131,7 +124,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<i16> = permuted.iter().cloned().collect();
@@ -145,7 +138,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<i8> = permuted.iter().cloned().collect();
@@ -159,7 +152,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<u64> = permuted.iter().cloned().collect();
@@ -173,7 +166,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<u32> = permuted.iter().cloned().collect();
@@ -187,7 +180,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<u16> = permuted.iter().cloned().collect();
@@ -198,10 +191,21 @@ pub unsafe extern "C" fn ndarray_permute(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let permuted = arr.permuted_axes(axes_slice);
+                let data: Vec<u16> = permuted.iter().cloned().collect();
+                let result = ndarray::ArrayD::from_shape_vec(permuted.raw_dim(), data)
+                    .expect("Failed to create permuted array");
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<u8> = permuted.iter().cloned().collect();
@@ -214,12 +218,12 @@ pub unsafe extern "C" fn ndarray_permute(
             }
             DType::Bool => {
                 error::set_last_error("permute_axes() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<num_complex::Complex<f32>> = permuted.iter().cloned().collect();
@@ -233,7 +237,7 @@ pub unsafe extern "C" fn ndarray_permute(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let permuted = arr.permuted_axes(axes_slice);
                 let data: Vec<num_complex::Complex<f64>> = permuted.iter().cloned().collect();
@@ -4,20 +4,24 @@
 
 use std::sync::Arc;
 
-use ndarray::{ArrayD, Axis, IxDyn};
+use ndarray::{ArrayD, Axis, IxDyn, Slice};
 use parking_lot::RwLock;
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
-use crate::helpers::write_output_metadata;
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
+use crate::helpers::{normalize_axis, write_output_metadata};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
 
 /// Repeat elements of an array.
+///
+/// When `has_axis` is false the input is flattened first; otherwise `axis`
+/// may be negative (-1 = last axis).
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_repeat(
     handle: *const NdArrayHandle,
@@ -25,6 +29,7 @@ pub unsafe extern "C" fn ndarray_repeat(
     repeats: *const usize,
     repeats_len: usize,
     axis: i32,
+    has_axis: bool,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -42,16 +47,27 @@ pub unsafe extern "C" fn ndarray_repeat(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("repeat", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let repeats_slice = std::slice::from_raw_parts(repeats, repeats_len);
+        let axis = if has_axis {
+            match normalize_axis(meta.shape_slice(), axis, false) {
+                Ok(a) => Some(a),
+                Err(e) => {
+                    set_last_error(e);
+                    return ERR_SHAPE;
+                }
+            }
+        } else {
+            None
+        };
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -63,7 +79,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -75,7 +91,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -87,7 +103,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -99,7 +115,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -111,7 +127,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -123,7 +139,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -135,7 +151,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -147,7 +163,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -156,10 +172,19 @@ pub unsafe extern "C" fn ndarray_repeat(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let arr = arr.to_owned();
+                let result = repeat_array(arr, repeats_slice, axis);
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -171,7 +196,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -183,7 +208,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -195,7 +220,7 @@ pub unsafe extern "C" fn ndarray_repeat(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = repeat_array(arr, repeats_slice, axis);
@@ -217,10 +242,10 @@ pub unsafe extern "C" fn ndarray_repeat(
     })
 }
 
-fn repeat_array<T: Copy>(arr: ArrayD<T>, repeats: &[usize], axis: i32) -> ArrayD<T> {
+fn repeat_array<T: Copy>(arr: ArrayD<T>, repeats: &[usize], axis: Option<usize>) -> ArrayD<T> {
     let is_scalar_repeat = repeats.len() == 1;
 
-    if axis < 0 {
+    let Some(axis_usize) = axis else {
         // Flatten and repeat each element
         let flat: Vec<T> = arr.iter().copied().collect();
         let mut result = Vec::new();
@@ -245,78 +270,79 @@ fn repeat_array<T: Copy>(arr: ArrayD<T>, repeats: &[usize], axis: i32) -> ArrayD
             }
         }
 
-        ArrayD::from_shape_vec(IxDyn(&[result.len()]), result).unwrap()
-    } else {
-        let axis_usize = axis as usize;
-        assert!(axis_usize < arr.ndim(), "axis out of bounds");
+        return ArrayD::from_shape_vec(IxDyn(&[result.len()]), result).unwrap();
+    };
 
-        let axis_len = arr.shape()[axis_usize];
+    let axis_len = arr.shape()[axis_usize];
 
-        if is_scalar_repeat {
-            // Repeat each element along axis the same number of times
-            let rep = repeats[0];
-            if rep <= 1 {
-                return arr;
-            }
+    if is_scalar_repeat {
+        // Repeat each element along axis the same number of times
+        let rep = repeats[0];
+        if rep == 1 {
+            return arr;
+        }
 
-            // Collect all owned subviews, re-insert axis, then create views
-            let mut expanded_subviews: Vec<_> = Vec::new();
-            for subview in arr.axis_iter(Axis(axis_usize)) {
-                let owned = subview.to_owned();
-                // Insert the axis back so we can concatenate along it
-                let with_axis = owned.insert_axis(Axis(axis_usize));
-                expanded_subviews.push(with_axis);
-            }
+        // Collect all owned subviews, re-insert axis, then create views
+        let mut expanded_subviews: Vec<_> = Vec::new();
+        for subview in arr.axis_iter(Axis(axis_usize)) {
+            let owned = subview.to_owned();
+            // Insert the axis back so we can concatenate along it
+            let with_axis = owned.insert_axis(Axis(axis_usize));
+            expanded_subviews.push(with_axis);
+        }
 
-            // Now create views from the owned data
-            let mut result_slices: Vec<_> = Vec::new();
-            for expanded in &expanded_subviews {
-                for _ in 0..rep {
-                    result_slices.push(expanded.view());
-                }
+        // Now create views from the owned data
+        let mut result_slices: Vec<_> = Vec::new();
+        for expanded in &expanded_subviews {
+            for _ in 0..rep {
+                result_slices.push(expanded.view());
             }
+        }
 
-            if result_slices.is_empty() {
-                arr
-            } else {
-                ndarray::concatenate(Axis(axis_usize), &result_slices)
-                    .expect("concatenate should succeed")
-                    .as_standard_layout()
-                    .into_owned()
-            }
+        if result_slices.is_empty() {
+            // Zero repeats leave the axis with length zero.
+            arr.slice_axis(Axis(axis_usize), Slice::from(0..0))
+                .to_owned()
         } else {
-            // Different repeat count for each element along axis
-            assert_eq!(
-                repeats.len(),
-                axis_len,
-                "repeats length must match axis length"
-            );
+            ndarray::concatenate(Axis(axis_usize), &result_slices)
+                .expect("concatenate should succeed")
+                .as_standard_layout()
+                .into_owned()
+        }
+    } else {
+        // Different repeat count for each element along axis
+        assert_eq!(
+            repeats.len(),
+            axis_len,
+            "repeats length must match axis length"
+        );
 
-            // Collect all owned subviews, re-insert axis, then create views
-            let mut expanded_subviews: Vec<_> = Vec::new();
-            for subview in arr.axis_iter(Axis(axis_usize)) {
-                let owned = subview.to_owned();
-                // Insert the axis back so we can concatenate along it
-                let with_axis = owned.insert_axis(Axis(axis_usize));
-                expanded_subviews.push(with_axis);
-            }
+        // Collect all owned subviews, re-insert axis, then create views
+        let mut expanded_subviews: Vec<_> = Vec::new();
+        for subview in arr.axis_iter(Axis(axis_usize)) {
+            let owned = subview.to_owned();
+            // Insert the axis back so we can concatenate along it
+            let with_axis = owned.insert_axis(Axis(axis_usize));
+            expanded_subviews.push(with_axis);
+        }
 
-            // Now create views from the owned data
-            let mut result_slices: Vec<_> = Vec::new();
-            for (i, expanded) in expanded_subviews.iter().enumerate() {
-                for _ in 0..repeats[i] {
-                    result_slices.push(expanded.view());
-                }
+        // Now create views from the owned data
+        let mut result_slices: Vec<_> = Vec::new();
+        for (i, expanded) in expanded_subviews.iter().enumerate() {
+            for _ in 0..repeats[i] {
+                result_slices.push(expanded.view());
             }
+        }
 
-            if result_slices.is_empty() {
-                arr
-            } else {
-                ndarray::concatenate(Axis(axis_usize), &result_slices)
-                    .expect("concatenate should succeed")
-                    .as_standard_layout()
-                    .into_owned()
-            }
+        if result_slices.is_empty() {
+            // Zero repeats leave the axis with length zero.
+            arr.slice_axis(Axis(axis_usize), Slice::from(0..0))
+                .to_owned()
+        } else {
+            ndarray::concatenate(Axis(axis_usize), &result_slices)
+                .expect("concatenate should succeed")
+                .as_standard_layout()
+                .into_owned()
         }
     }
 }
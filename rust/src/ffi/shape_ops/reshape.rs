@@ -1,18 +1,46 @@
 //! Reshape operations.
 
-use crate::helpers::error::{self, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS};
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64,
+    extract_array_half_bits, extract_array_i16, extract_array_i32, extract_array_i64,
+    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
-use ndarray::IxDyn;
+use crate::types::{ArrayData, ArrayMetadata, MemoryOrder, NDArrayWrapper, NdArrayHandle};
+use ndarray::{ArrayD, ArrayViewD, IxDyn, Order, ShapeError};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
+/// Reshape `arr`, reading and storing elements in `order`.
+///
+/// The result's storage is laid out in `order` as well, so callers can
+/// describe it with C or Fortran strides respectively.
+fn reshape_in_order<T: Clone>(
+    arr: ArrayViewD<'_, T>,
+    shape: &[usize],
+    order: MemoryOrder,
+) -> Result<ArrayD<T>, ShapeError> {
+    match order {
+        MemoryOrder::C => Ok(arr
+            .to_shape((IxDyn(shape), Order::RowMajor))?
+            .as_standard_layout()
+            .into_owned()),
+        MemoryOrder::F => {
+            let reshaped = arr.to_shape((IxDyn(shape), Order::ColumnMajor))?;
+            Ok(reshaped
+                .t()
+                .as_standard_layout()
+                .into_owned()
+                .reversed_axes())
+        }
+    }
+}
+
 /// Reshape array to new shape.
+///
+/// `order` is 0 for C (row-major) or 1 for Fortran (column-major). The new
+/// array is stored in that order: C-contiguous for 0, F-contiguous for 1.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_reshape(
     handle: *const NdArrayHandle,
@@ -26,7 +54,7 @@ pub unsafe extern "C" fn ndarray_reshape(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("reshape", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let shape_slice = meta.shape_slice();
@@ -44,15 +72,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             return ERR_SHAPE;
         }
 
-        let order_enum = match order {
-            0 => ndarray::Order::RowMajor,
-            1 => ndarray::Order::ColumnMajor,
-            _ => {
-                error::set_last_error(format!(
-                    "Invalid order: {}. Use 0 for RowMajor, 1 for ColumnMajor",
-                    order
-                ));
-                return ERR_GENERIC;
+        let order = match MemoryOrder::from_i32(order) {
+            Ok(o) => o,
+            Err(e) => {
+                error::set_last_error(e);
+                return ERR_VALUE;
             }
         };
 
@@ -60,12 +84,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Float64(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Float64(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Float64,
                     },
                     Err(e) => {
@@ -77,12 +100,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Float32(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Float32(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Float32,
                     },
                     Err(e) => {
@@ -94,12 +116,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Int64(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Int64(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Int64,
                     },
                     Err(e) => {
@@ -111,12 +132,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Int32(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Int32(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Int32,
                     },
                     Err(e) => {
@@ -128,12 +148,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Int16(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Int16(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Int16,
                     },
                     Err(e) => {
@@ -145,12 +164,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Int8(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Int8(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Int8,
                     },
                     Err(e) => {
@@ -162,12 +180,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Uint64(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Uint64(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Uint64,
                     },
                     Err(e) => {
@@ -179,12 +196,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Uint32(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Uint32(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Uint32,
                     },
                     Err(e) => {
@@ -196,12 +212,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Uint16(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Uint16(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Uint16,
                     },
                     Err(e) => {
@@ -210,15 +225,27 @@ pub unsafe extern "C" fn ndarray_reshape(
                     }
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
+                    Ok(reshaped) => NDArrayWrapper::from_half_bits(reshaped, wrapper.dtype),
+                    Err(e) => {
+                        error::set_last_error(format!("Reshape failed: {}", e));
+                        return ERR_SHAPE;
+                    }
+                }
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Uint8(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Uint8(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Uint8,
                     },
                     Err(e) => {
@@ -229,17 +256,16 @@ pub unsafe extern "C" fn ndarray_reshape(
             }
             DType::Bool => {
                 error::set_last_error("reshape() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Complex64(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Complex64(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Complex64,
                     },
                     Err(e) => {
@@ -251,12 +277,11 @@ pub unsafe extern "C" fn ndarray_reshape(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let new_ixdyn = IxDyn(new_shape_slice);
-                match arr.to_shape((new_ixdyn, order_enum)) {
+                match reshape_in_order(arr.view(), new_shape_slice, order) {
                     Ok(reshaped) => NDArrayWrapper {
-                        data: ArrayData::Complex128(Arc::new(RwLock::new(reshaped.to_owned()))),
+                        data: ArrayData::Complex128(Arc::new(RwLock::new(reshaped))),
                         dtype: DType::Complex128,
                     },
                     Err(e) => {
@@ -271,3 +296,24 @@ pub unsafe extern "C" fn ndarray_reshape(
         SUCCESS
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn result_storage_follows_order() {
+        let a = ArrayD::from_shape_vec(IxDyn(&[2, 3]), vec![1, 2, 3, 4, 5, 6]).unwrap();
+
+        let c = reshape_in_order(a.view(), &[3, 2], MemoryOrder::C).unwrap();
+        assert!(c.is_standard_layout());
+        assert_eq!(c.as_slice().unwrap(), &[1, 2, 3, 4, 5, 6]);
+
+        // NumPy: a.reshape(3, 2, order='F') == [[1, 5], [4, 3], [2, 6]]
+        let f = reshape_in_order(a.view(), &[3, 2], MemoryOrder::F).unwrap();
+        assert!(f.t().is_standard_layout());
+        assert_eq!(f.as_slice_memory_order().unwrap(), &[1, 4, 2, 5, 3, 6]);
+        assert_eq!(f[[0, 1]], 5);
+        assert_eq!(f[[2, 0]], 2);
+    }
+}
@@ -7,12 +7,13 @@ use std::sync::Arc;
 use ndarray::{concatenate, Axis};
 use parking_lot::RwLock;
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
     extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
+    extract_array_f64, extract_array_half_bits, extract_array_i16, extract_array_i32,
+    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
+    extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -41,7 +42,7 @@ pub unsafe extern "C" fn ndarray_tile(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("tile", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let reps_slice = std::slice::from_raw_parts(reps, reps_len);
@@ -50,7 +51,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -62,7 +63,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -74,7 +75,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -86,7 +87,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -98,7 +99,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -110,7 +111,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -122,7 +123,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -134,7 +135,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -146,7 +147,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -155,10 +156,19 @@ pub unsafe extern "C" fn ndarray_tile(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let arr = arr.to_owned();
+                let result = tile_array(arr, reps_slice);
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -170,7 +180,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -182,7 +192,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -194,7 +204,7 @@ pub unsafe extern "C" fn ndarray_tile(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let arr = arr.to_owned();
                 let result = tile_array(arr, reps_slice);
@@ -1,11 +1,11 @@
 //! Transpose operations.
 
-use crate::helpers::error::{self, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{self, ERR_DTYPE, ERR_GENERIC, SUCCESS};
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
-    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-    extract_array_u64, extract_array_u8,
+    extract_array_c128, extract_array_c64, extract_array_f32, extract_array_f64,
+    extract_array_half_bits, extract_array_i16, extract_array_i32, extract_array_i64,
+    extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
@@ -36,7 +36,7 @@ pub unsafe extern "C" fn ndarray_transpose(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("transpose", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -44,7 +44,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     error::set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<f64> = transposed_view.iter().cloned().collect();
@@ -58,7 +58,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     error::set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<f32> = transposed_view.iter().cloned().collect();
@@ -72,7 +72,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     error::set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<i64> = transposed_view.iter().cloned().collect();
@@ -86,7 +86,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     error::set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<i32> = transposed_view.iter().cloned().collect();
@@ -100,7 +100,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     error::set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<i16> = transposed_view.iter().cloned().collect();
@@ -114,7 +114,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     error::set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<i8> = transposed_view.iter().cloned().collect();
@@ -128,7 +128,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     error::set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<u64> = transposed_view.iter().cloned().collect();
@@ -142,7 +142,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     error::set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<u32> = transposed_view.iter().cloned().collect();
@@ -156,7 +156,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     error::set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<u16> = transposed_view.iter().cloned().collect();
@@ -167,10 +167,21 @@ pub unsafe extern "C" fn ndarray_transpose(
                     dtype: DType::Uint16,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_half_bits(wrapper, meta) else {
+                    error::set_last_error("Failed to extract half-precision view".to_string());
+                    return ERR_DTYPE;
+                };
+                let transposed_view = arr.t();
+                let data: Vec<u16> = transposed_view.iter().cloned().collect();
+                let result = ndarray::ArrayD::from_shape_vec(transposed_view.raw_dim(), data)
+                    .expect("Failed to create transposed array");
+                NDArrayWrapper::from_half_bits(result, wrapper.dtype)
+            }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     error::set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<u8> = transposed_view.iter().cloned().collect();
@@ -183,12 +194,12 @@ pub unsafe extern "C" fn ndarray_transpose(
             }
             DType::Bool => {
                 error::set_last_error("transpose() not supported for Bool type".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             DType::Complex64 => {
                 let Some(arr) = extract_array_c64(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<num_complex::Complex<f32>> =
@@ -203,7 +214,7 @@ pub unsafe extern "C" fn ndarray_transpose(
             DType::Complex128 => {
                 let Some(arr) = extract_array_c128(wrapper, meta) else {
                     error::set_last_error("Failed to extract complex128 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let transposed_view = arr.t();
                 let data: Vec<num_complex::Complex<f64>> =
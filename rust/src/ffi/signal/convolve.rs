@@ -0,0 +1,461 @@
+//! 1D convolution and 2D convolution / cross-correlation.
+//!
+//! Computation happens in f64. Float32 and half-precision operands yield the
+//! promoted float dtype, every other real dtype yields Float64. Complex
+//! operands are not supported.
+
+use ndarray::{Array2, ArrayD, ArrayView2, Ix2, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{extract_array_as_f64, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Output region of a convolution. Integer values match PHP `ConvolveMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConvolveMode {
+    /// Every position where the kernel overlaps the input.
+    Full,
+    /// Same size as the input, centred on the full output.
+    Same,
+    /// Only positions where the kernel lies entirely inside the input.
+    Valid,
+}
+
+impl ConvolveMode {
+    fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(ConvolveMode::Full),
+            1 => Ok(ConvolveMode::Same),
+            2 => Ok(ConvolveMode::Valid),
+            _ => Err(format!("Invalid convolve mode: {}", value)),
+        }
+    }
+
+    /// Implicit zero padding `(before, after)` for a kernel of length `k`.
+    fn padding(self, k: usize) -> (usize, usize) {
+        match self {
+            ConvolveMode::Full => (k - 1, k - 1),
+            ConvolveMode::Same => (k - 1 - (k - 1) / 2, (k - 1) / 2),
+            ConvolveMode::Valid => (0, 0),
+        }
+    }
+}
+
+/// Cross-correlate `input` with `kernel` over a zero-padded input.
+///
+/// `pad` is added to both sides of each axis on top of the padding implied by
+/// `mode`, then every `stride`-th output position is kept. Convolution is
+/// correlation with the kernel flipped on both axes.
+fn correlate_2d(
+    input: ArrayView2<f64>,
+    kernel: ArrayView2<f64>,
+    mode: ConvolveMode,
+    stride: (usize, usize),
+    pad: (usize, usize),
+) -> Result<Array2<f64>, String> {
+    let (h, w) = input.dim();
+    let (kh, kw) = kernel.dim();
+    let (top, bottom) = mode.padding(kh);
+    let (left, right) = mode.padding(kw);
+    let (top, bottom) = (top + pad.0, bottom + pad.0);
+    let (left, right) = (left + pad.1, right + pad.1);
+
+    let (padded_h, padded_w) = (h + top + bottom, w + left + right);
+    if padded_h < kh || padded_w < kw {
+        return Err(format!(
+            "Kernel of shape [{}, {}] does not fit the padded input of shape [{}, {}]",
+            kh, kw, padded_h, padded_w
+        ));
+    }
+    let out_h = (padded_h - kh) / stride.0 + 1;
+    let out_w = (padded_w - kw) / stride.1 + 1;
+
+    Ok(Array2::from_shape_fn((out_h, out_w), |(i, j)| {
+        let (y0, x0) = (i * stride.0, j * stride.1);
+        let mut acc = 0.0;
+        for ki in 0..kh {
+            let Some(y) = (y0 + ki).checked_sub(top).filter(|&y| y < h) else {
+                continue;
+            };
+            for kj in 0..kw {
+                if let Some(x) = (x0 + kj).checked_sub(left).filter(|&x| x < w) {
+                    acc += input[[y, x]] * kernel[[ki, kj]];
+                }
+            }
+        }
+        acc
+    }))
+}
+
+fn flipped(kernel: ArrayView2<f64>) -> Array2<f64> {
+    let (kh, kw) = kernel.dim();
+    Array2::from_shape_fn((kh, kw), |(i, j)| kernel[[kh - 1 - i, kw - 1 - j]])
+}
+
+/// Wrap an f64 result in the float dtype promoted from both operands.
+fn float_result(dtype: DType, result: ArrayD<f64>) -> NDArrayWrapper {
+    match dtype {
+        DType::Float32 => NDArrayWrapper {
+            data: ArrayData::Float32(Arc::new(RwLock::new(result.mapv(|x| x as f32)))),
+            dtype: DType::Float32,
+        },
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_f32_as_half(result.mapv(|x| x as f32), dtype)
+        }
+        _ => NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+            dtype: DType::Float64,
+        },
+    }
+}
+
+/// Load both operands as f64 with their expected dimensionality.
+unsafe fn load_operands(
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    b_wrapper: &NDArrayWrapper,
+    b_meta: &ArrayMetadata,
+    ndim: usize,
+    name: &str,
+) -> Result<(ArrayD<f64>, ArrayD<f64>, DType), (i32, String)> {
+    let dtype = DType::promote(a_wrapper.dtype, b_wrapper.dtype);
+    if dtype.is_complex() {
+        return Err((
+            ERR_DTYPE,
+            format!("{} is not supported for {} arrays", name, dtype),
+        ));
+    }
+    for meta in [a_meta, b_meta] {
+        let shape = meta.shape_slice();
+        if shape.len() != ndim || shape.contains(&0) {
+            return Err((
+                ERR_SHAPE,
+                format!(
+                    "{} requires non-empty {}D operands, got shape {:?}",
+                    name, ndim, shape
+                ),
+            ));
+        }
+    }
+    let (Some(a), Some(b)) = (
+        extract_array_as_f64(a_wrapper, a_meta),
+        extract_array_as_f64(b_wrapper, b_meta),
+    ) else {
+        return Err((
+            ERR_DTYPE,
+            format!("Failed to prepare operands for {}", name),
+        ));
+    };
+    Ok((a, b, dtype))
+}
+
+unsafe fn convolve_1d(
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    v_wrapper: &NDArrayWrapper,
+    v_meta: &ArrayMetadata,
+    mode: i32,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let mode = ConvolveMode::from_i32(mode).map_err(|e| (ERR_VALUE, e))?;
+    let (a, v, dtype) = load_operands(a_wrapper, a_meta, v_wrapper, v_meta, 1, "convolve")?;
+
+    // Convolution is commutative; sliding the shorter operand matches NumPy
+    // for every mode.
+    let (a, v) = if v.len() > a.len() { (v, a) } else { (a, v) };
+    fn as_row(x: &ArrayD<f64>) -> Result<ArrayView2<'_, f64>, (i32, String)> {
+        x.view()
+            .into_shape_with_order((1, x.len()))
+            .map_err(|e| (ERR_SHAPE, e.to_string()))
+    }
+    let result = correlate_2d(
+        as_row(&a)?,
+        flipped(as_row(&v)?).view(),
+        mode,
+        (1, 1),
+        (0, 0),
+    )
+    .map_err(|e| (ERR_SHAPE, e))?;
+    let len = result.len();
+    let result = result
+        .into_shape_with_order(IxDyn(&[len]))
+        .map_err(|e| (ERR_SHAPE, e.to_string()))?;
+    Ok(float_result(dtype, result))
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn filter_2d(
+    a_wrapper: &NDArrayWrapper,
+    a_meta: &ArrayMetadata,
+    k_wrapper: &NDArrayWrapper,
+    k_meta: &ArrayMetadata,
+    mode: i32,
+    stride: (usize, usize),
+    pad: (usize, usize),
+    flip: bool,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let name = if flip { "convolve2d" } else { "correlate2d" };
+    let mode = ConvolveMode::from_i32(mode).map_err(|e| (ERR_VALUE, e))?;
+    if stride.0 == 0 || stride.1 == 0 {
+        return Err((ERR_VALUE, format!("{}: stride must be positive", name)));
+    }
+    let (a, k, dtype) = load_operands(a_wrapper, a_meta, k_wrapper, k_meta, 2, name)?;
+    let to_2d = |x: ArrayD<f64>| {
+        x.into_dimensionality::<Ix2>()
+            .map_err(|e| (ERR_SHAPE, e.to_string()))
+    };
+    let (a, k) = (to_2d(a)?, to_2d(k)?);
+    let k = if flip { flipped(k.view()) } else { k };
+
+    let result = correlate_2d(a.view(), k.view(), mode, stride, pad).map_err(|e| (ERR_SHAPE, e))?;
+    Ok(float_result(dtype, result.into_dyn()))
+}
+
+/// Discrete linear convolution of two 1D arrays.
+///
+/// `mode`: 0 = full (length `n + m - 1`), 1 = same (length `max(n, m)`),
+/// 2 = valid (length `max(n, m) - min(n, m) + 1`), as in NumPy.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_convolve(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    v: *const NdArrayHandle,
+    v_meta: *const ArrayMetadata,
+    mode: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || a_meta.is_null()
+        || v.is_null()
+        || v_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("convolve", {
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let v_wrapper = NdArrayHandle::as_wrapper(v as *mut _);
+
+        let result_wrapper = match convolve_1d(a_wrapper, &*a_meta, v_wrapper, &*v_meta, mode) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Shared body of the 2D FFI entry points.
+#[allow(clippy::too_many_arguments)]
+unsafe fn filter_2d_entry(
+    name: &'static str,
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    kernel: *const NdArrayHandle,
+    kernel_meta: *const ArrayMetadata,
+    mode: i32,
+    stride: (usize, usize),
+    pad: (usize, usize),
+    flip: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if a.is_null()
+        || a_meta.is_null()
+        || kernel.is_null()
+        || kernel_meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let a_wrapper = NdArrayHandle::as_wrapper(a as *mut _);
+        let k_wrapper = NdArrayHandle::as_wrapper(kernel as *mut _);
+
+        let result_wrapper = match filter_2d(
+            a_wrapper,
+            &*a_meta,
+            k_wrapper,
+            &*kernel_meta,
+            mode,
+            stride,
+            pad,
+            flip,
+        ) {
+            Ok(w) => w,
+            Err((code, e)) => {
+                set_last_error(e);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// 2D convolution of `a` with `kernel` (the kernel is flipped on both axes).
+///
+/// The input is zero-padded by `pad_h` rows and `pad_w` columns on each side
+/// on top of the padding implied by `mode` (0 = full, 1 = same, 2 = valid),
+/// then every `stride_h`-th row and `stride_w`-th column of the output is kept.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_convolve2d(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    kernel: *const NdArrayHandle,
+    kernel_meta: *const ArrayMetadata,
+    mode: i32,
+    stride_h: usize,
+    stride_w: usize,
+    pad_h: usize,
+    pad_w: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    filter_2d_entry(
+        "convolve2d",
+        a,
+        a_meta,
+        kernel,
+        kernel_meta,
+        mode,
+        (stride_h, stride_w),
+        (pad_h, pad_w),
+        true,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// 2D cross-correlation of `a` with `kernel` (no kernel flip).
+///
+/// Mode, stride and padding behave as in [`ndarray_convolve2d`]; valid mode
+/// with padding and stride is the usual CNN convolution layer.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_correlate2d(
+    a: *const NdArrayHandle,
+    a_meta: *const ArrayMetadata,
+    kernel: *const NdArrayHandle,
+    kernel_meta: *const ArrayMetadata,
+    mode: i32,
+    stride_h: usize,
+    stride_w: usize,
+    pad_h: usize,
+    pad_w: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    filter_2d_entry(
+        "correlate2d",
+        a,
+        a_meta,
+        kernel,
+        kernel_meta,
+        mode,
+        (stride_h, stride_w),
+        (pad_h, pad_w),
+        false,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    fn convolve(a: &[f64], v: &[f64], mode: ConvolveMode) -> Vec<f64> {
+        let (a, v) = if v.len() > a.len() { (v, a) } else { (a, v) };
+        let a = ArrayView2::from_shape((1, a.len()), a).unwrap();
+        let v = ArrayView2::from_shape((1, v.len()), v).unwrap();
+        correlate_2d(a, flipped(v).view(), mode, (1, 1), (0, 0))
+            .unwrap()
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn convolve_modes_match_numpy() {
+        let a = [1.0, 2.0, 3.0];
+        let v = [0.0, 1.0, 0.5];
+        assert_eq!(
+            convolve(&a, &v, ConvolveMode::Full),
+            vec![0.0, 1.0, 2.5, 4.0, 1.5]
+        );
+        assert_eq!(convolve(&a, &v, ConvolveMode::Same), vec![1.0, 2.5, 4.0]);
+        assert_eq!(convolve(&a, &v, ConvolveMode::Valid), vec![2.5]);
+        assert_eq!(
+            convolve(&[1.0, 2.0, 3.0, 4.0, 5.0], &[1.0, 1.0], ConvolveMode::Same),
+            vec![1.0, 3.0, 5.0, 7.0, 9.0]
+        );
+        assert_eq!(
+            convolve(&[1.0, 2.0], &[1.0, 2.0, 3.0], ConvolveMode::Same),
+            vec![1.0, 4.0, 7.0]
+        );
+    }
+
+    #[test]
+    fn correlate_2d_with_stride_and_padding() {
+        let x = array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        let k = array![[1.0, 0.0], [0.0, -1.0]];
+
+        let valid = correlate_2d(x.view(), k.view(), ConvolveMode::Valid, (1, 1), (0, 0));
+        assert_eq!(valid.unwrap(), array![[-4.0, -4.0], [-4.0, -4.0]]);
+
+        let strided = correlate_2d(x.view(), k.view(), ConvolveMode::Valid, (2, 2), (1, 1));
+        assert_eq!(strided.unwrap(), array![[-1.0, -3.0], [-7.0, -4.0]]);
+
+        let same = correlate_2d(x.view(), k.view(), ConvolveMode::Same, (1, 1), (0, 0));
+        assert_eq!(same.unwrap().dim(), (3, 3));
+        assert!(correlate_2d(k.view(), x.view(), ConvolveMode::Valid, (1, 1), (0, 0)).is_err());
+    }
+}
@@ -0,0 +1,9 @@
+//! Signal processing module.
+//!
+//! Provides convolution and sliding-window operations over array axes.
+
+pub mod convolve;
+pub mod rolling;
+
+pub use convolve::*;
+pub use rolling::*;
@@ -0,0 +1,519 @@
+//! Rolling-window statistics along an axis.
+//!
+//! Each lane is processed in a single pass: sums and moments are updated as
+//! elements enter and leave the window, and min/max use a monotonic deque, so
+//! the cost is O(n) regardless of window size. NaN inputs are skipped and do
+//! not count towards `min_periods`.
+
+use std::collections::VecDeque;
+
+use ndarray::{ArrayView1, ArrayViewMut1, Axis, Zip};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{extract_array_as_f64, normalize_axis, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Statistic computed over each window.
+#[derive(Clone, Copy)]
+enum RollingStat {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    Std { ddof: usize },
+}
+
+/// Window geometry shared by every lane.
+#[derive(Clone, Copy)]
+struct RollingWindow {
+    size: usize,
+    min_periods: usize,
+    center: bool,
+}
+
+impl RollingWindow {
+    /// Half-open input range `[start, end)` covered by the window for output `i`.
+    ///
+    /// Trailing windows end at `i`; centred windows put `i` at the middle,
+    /// biased right for even sizes (matching pandas).
+    fn bounds(&self, i: usize, len: usize) -> (usize, usize) {
+        let shift = if self.center { (self.size - 1) / 2 } else { 0 };
+        let end = (i + 1 + shift).min(len);
+        let start = (i + 1 + shift).saturating_sub(self.size);
+        (start, end)
+    }
+}
+
+/// Running sum / mean / variance over the valid values in the window (Welford updates).
+///
+/// Infinities are counted rather than folded into the running state, which
+/// they would turn into NaN for good once they leave the window.
+#[derive(Default)]
+struct Moments {
+    count: usize,
+    sum: f64,
+    mean: f64,
+    m2: f64,
+    pos_inf: usize,
+    neg_inf: usize,
+}
+
+impl Moments {
+    /// Number of valid values in the window, infinities included.
+    fn len(&self) -> usize {
+        self.count + self.pos_inf + self.neg_inf
+    }
+
+    /// Sum and mean when the window holds infinities: ±inf, or NaN for both signs.
+    fn infinite(&self) -> Option<f64> {
+        match (self.pos_inf > 0, self.neg_inf > 0) {
+            (false, false) => None,
+            (true, false) => Some(f64::INFINITY),
+            (false, true) => Some(f64::NEG_INFINITY),
+            (true, true) => Some(f64::NAN),
+        }
+    }
+
+    fn push(&mut self, x: f64) {
+        if x.is_infinite() {
+            if x > 0.0 {
+                self.pos_inf += 1;
+            } else {
+                self.neg_inf += 1;
+            }
+            return;
+        }
+        self.count += 1;
+        self.sum += x;
+        let delta = x - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (x - self.mean);
+    }
+
+    fn pop(&mut self, x: f64) {
+        if x.is_infinite() {
+            if x > 0.0 {
+                self.pos_inf -= 1;
+            } else {
+                self.neg_inf -= 1;
+            }
+            return;
+        }
+        if self.count == 1 {
+            *self = Self {
+                pos_inf: self.pos_inf,
+                neg_inf: self.neg_inf,
+                ..Self::default()
+            };
+            return;
+        }
+        self.count -= 1;
+        self.sum -= x;
+        let delta = x - self.mean;
+        self.mean -= delta / self.count as f64;
+        self.m2 -= delta * (x - self.mean);
+    }
+}
+
+fn rolling_moments(
+    input: ArrayView1<f64>,
+    mut out: ArrayViewMut1<f64>,
+    win: RollingWindow,
+    stat: RollingStat,
+) {
+    let len = input.len();
+    let mut acc = Moments::default();
+    let (mut lo, mut hi) = (0, 0);
+
+    for i in 0..len {
+        let (start, end) = win.bounds(i, len);
+        while hi < end {
+            if !input[hi].is_nan() {
+                acc.push(input[hi]);
+            }
+            hi += 1;
+        }
+        while lo < start {
+            if !input[lo].is_nan() {
+                acc.pop(input[lo]);
+            }
+            lo += 1;
+        }
+
+        let n = acc.len();
+        out[i] = if n < win.min_periods {
+            f64::NAN
+        } else {
+            match (stat, acc.infinite()) {
+                (RollingStat::Sum, inf) => inf.unwrap_or(acc.sum),
+                (RollingStat::Mean, inf) if n > 0 => inf.unwrap_or(acc.mean),
+                (RollingStat::Std { ddof }, None) if n > ddof => {
+                    (acc.m2.max(0.0) / (n - ddof) as f64).sqrt()
+                }
+                _ => f64::NAN,
+            }
+        };
+    }
+}
+
+fn rolling_extreme(
+    input: ArrayView1<f64>,
+    mut out: ArrayViewMut1<f64>,
+    win: RollingWindow,
+    want_max: bool,
+) {
+    let len = input.len();
+    // Indices of valid values; their values are monotonic from front to back.
+    let mut deque: VecDeque<usize> = VecDeque::new();
+    let (mut lo, mut hi, mut count) = (0, 0, 0usize);
+    let dominated = |kept: f64, incoming: f64| {
+        if want_max {
+            kept <= incoming
+        } else {
+            kept >= incoming
+        }
+    };
+
+    for i in 0..len {
+        let (start, end) = win.bounds(i, len);
+        while hi < end {
+            let x = input[hi];
+            if !x.is_nan() {
+                while deque.back().is_some_and(|&b| dominated(input[b], x)) {
+                    deque.pop_back();
+                }
+                deque.push_back(hi);
+                count += 1;
+            }
+            hi += 1;
+        }
+        while lo < start {
+            if !input[lo].is_nan() {
+                count -= 1;
+            }
+            lo += 1;
+        }
+        while deque.front().is_some_and(|&f| f < start) {
+            deque.pop_front();
+        }
+
+        out[i] = match deque.front() {
+            Some(&f) if count >= win.min_periods => input[f],
+            _ => f64::NAN,
+        };
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn rolling_op(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    window: usize,
+    axis: i32,
+    min_periods: usize,
+    center: bool,
+    stat: RollingStat,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if wrapper.dtype.is_complex() {
+            set_last_error("Rolling statistics are not supported for complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+        if window == 0 {
+            set_last_error("Rolling window size must be at least 1".to_string());
+            return ERR_VALUE;
+        }
+        if min_periods > window {
+            set_last_error(format!(
+                "min_periods ({}) must not exceed the window size ({})",
+                min_periods, window
+            ));
+            return ERR_VALUE;
+        }
+
+        if meta.ndim == 0 {
+            set_last_error("Rolling statistics require at least one dimension".to_string());
+            return ERR_SHAPE;
+        }
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let win = RollingWindow {
+            size: window,
+            min_periods,
+            center,
+        };
+        let mut result = ndarray::ArrayD::<f64>::zeros(arr.raw_dim());
+        Zip::from(arr.lanes(Axis(axis)))
+            .and(result.lanes_mut(Axis(axis)))
+            .for_each(|lane, out| match stat {
+                RollingStat::Min => rolling_extreme(lane, out, win, false),
+                RollingStat::Max => rolling_extreme(lane, out, win, true),
+                _ => rolling_moments(lane, out, win, stat),
+            });
+
+        let result_wrapper = if wrapper.dtype == DType::Float32 {
+            NDArrayWrapper {
+                data: ArrayData::Float32(Arc::new(RwLock::new(result.mapv(|x| x as f32)))),
+                dtype: DType::Float32,
+            }
+        } else {
+            NDArrayWrapper {
+                data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+                dtype: DType::Float64,
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Rolling sum over `window` elements along `axis`.
+///
+/// Positions whose window holds fewer than `min_periods` non-NaN values are NaN.
+/// With `center`, each window is centred on its output position instead of ending there.
+/// Float32 input yields Float32; all other real dtypes yield Float64.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rolling_sum(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    window: usize,
+    axis: i32,
+    min_periods: usize,
+    center: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    rolling_op(
+        "rolling_sum",
+        handle,
+        meta,
+        window,
+        axis,
+        min_periods,
+        center,
+        RollingStat::Sum,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Rolling mean over `window` elements along `axis`. See [`ndarray_rolling_sum`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rolling_mean(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    window: usize,
+    axis: i32,
+    min_periods: usize,
+    center: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    rolling_op(
+        "rolling_mean",
+        handle,
+        meta,
+        window,
+        axis,
+        min_periods,
+        center,
+        RollingStat::Mean,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Rolling minimum over `window` elements along `axis`. See [`ndarray_rolling_sum`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rolling_min(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    window: usize,
+    axis: i32,
+    min_periods: usize,
+    center: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    rolling_op(
+        "rolling_min",
+        handle,
+        meta,
+        window,
+        axis,
+        min_periods,
+        center,
+        RollingStat::Min,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Rolling maximum over `window` elements along `axis`. See [`ndarray_rolling_sum`].
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rolling_max(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    window: usize,
+    axis: i32,
+    min_periods: usize,
+    center: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    rolling_op(
+        "rolling_max",
+        handle,
+        meta,
+        window,
+        axis,
+        min_periods,
+        center,
+        RollingStat::Max,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Rolling standard deviation with `ddof` delta degrees of freedom. See [`ndarray_rolling_sum`].
+///
+/// Windows with no more than `ddof` valid values are NaN.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_rolling_std(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    window: usize,
+    axis: i32,
+    min_periods: usize,
+    center: bool,
+    ddof: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    rolling_op(
+        "rolling_std",
+        handle,
+        meta,
+        window,
+        axis,
+        min_periods,
+        center,
+        RollingStat::Std { ddof },
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::Array1;
+
+    fn trailing(input: &[f64], size: usize, stat: RollingStat) -> Vec<f64> {
+        let input = Array1::from_vec(input.to_vec());
+        let mut out = Array1::zeros(input.len());
+        let win = RollingWindow {
+            size,
+            min_periods: 1,
+            center: false,
+        };
+        rolling_moments(input.view(), out.view_mut(), win, stat);
+        out.to_vec()
+    }
+
+    #[test]
+    fn infinities_only_affect_windows_that_hold_them() {
+        let inf = f64::INFINITY;
+        let input = [1.0, 2.0, inf, 4.0, 5.0, -inf, inf, 6.0, 7.0];
+
+        let sum = trailing(&input, 2, RollingStat::Sum);
+        assert_eq!(&sum[..5], &[1.0, 3.0, inf, inf, 9.0]);
+        assert_eq!(sum[5], -inf);
+        assert!(sum[6].is_nan());
+        assert_eq!(&sum[7..], &[inf, 13.0]);
+
+        let mean = trailing(&input, 2, RollingStat::Mean);
+        assert_eq!(&mean[..5], &[1.0, 1.5, inf, inf, 4.5]);
+        assert_eq!(mean[8], 6.5);
+
+        let std = trailing(&input, 2, RollingStat::Std { ddof: 1 });
+        let half_sqrt2 = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(std[0].is_nan() && std[2].is_nan() && std[3].is_nan());
+        assert!((std[1] - half_sqrt2).abs() < 1e-12);
+        assert!((std[4] - half_sqrt2).abs() < 1e-12);
+        assert!((std[8] - half_sqrt2).abs() < 1e-12);
+    }
+}
@@ -2,14 +2,17 @@
 
 use crate::ffi::sorting::helpers::{
     argsort_axis_generic, argsort_flat_generic, cmp_f32_asc_nan_last, cmp_f64_asc_nan_last,
+    SortOrder,
+};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
 };
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_bool, extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32,
-    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
-    extract_array_u8,
+    extract_array_as_f32, extract_array_bool, extract_array_f64, extract_array_i16,
+    extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
+    extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::SortKind;
@@ -18,12 +21,17 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute the argsort along an axis in the array.
+///
+/// `descending` reverses the order; `nan_first` moves NaN to the front
+/// instead of the end, independently of direction.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_argsort_axis(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     axis: i32,
     kind: i32,
+    descending: bool,
+    nan_first: bool,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -40,7 +48,7 @@ pub unsafe extern "C" fn ndarray_argsort_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("argsort_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -56,80 +64,81 @@ pub unsafe extern "C" fn ndarray_argsort_axis(
             Ok(k) => k,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
+        let order = SortOrder::new(descending, nan_first);
 
         let result = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, cmp_f64_asc_nan_last)
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, cmp_f64_asc_nan_last)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, cmp_f32_asc_nan_last)
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, cmp_f32_asc_nan_last)
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("Argsort is not supported for complex dtypes".to_string());
@@ -138,9 +147,9 @@ pub unsafe extern "C" fn ndarray_argsort_axis(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b))
+                argsort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b))
             }
         };
 
@@ -161,11 +170,16 @@ pub unsafe extern "C" fn ndarray_argsort_axis(
 }
 
 /// Compute the argsort of the flattened array.
+///
+/// `descending` reverses the order; `nan_first` moves NaN to the front
+/// instead of the end, independently of direction.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_argsort_flat(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     kind: i32,
+    descending: bool,
+    nan_first: bool,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -182,7 +196,7 @@ pub unsafe extern "C" fn ndarray_argsort_flat(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("argsort_flat", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -190,80 +204,81 @@ pub unsafe extern "C" fn ndarray_argsort_flat(
             Ok(k) => k,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
+        let order = SortOrder::new(descending, nan_first);
 
         let result = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, cmp_f64_asc_nan_last)
+                argsort_flat_generic(&arr, sort_kind, order, cmp_f64_asc_nan_last)
             }
-            DType::Float32 => {
-                let Some(arr) = extract_array_f32(wrapper, meta) else {
+            DType::Float32 | DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, cmp_f32_asc_nan_last)
+                argsort_flat_generic(&arr, sort_kind, order, cmp_f32_asc_nan_last)
             }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
             DType::Complex64 | DType::Complex128 => {
                 set_last_error("Argsort is not supported for complex dtypes".to_string());
@@ -272,9 +287,9 @@ pub unsafe extern "C" fn ndarray_argsort_flat(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                argsort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b))
+                argsort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b))
             }
         };
 
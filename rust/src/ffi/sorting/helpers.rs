@@ -24,6 +24,184 @@ pub fn cmp_f32_asc_nan_last(a: &f32, b: &f32) -> Ordering {
     }
 }
 
+/// Direction and NaN placement for sort and argsort.
+///
+/// NaN stays at the requested end whichever way the rest is ordered, and
+/// equal values keep their input order under the stable kinds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SortOrder {
+    pub descending: bool,
+    pub nan_first: bool,
+}
+
+impl SortOrder {
+    pub fn new(descending: bool, nan_first: bool) -> Self {
+        Self {
+            descending,
+            nan_first,
+        }
+    }
+
+    /// Adapt an ascending comparator to this order.
+    pub fn apply<T, F>(self, cmp_asc: F) -> impl Fn(&T, &T) -> Ordering + Copy
+    where
+        T: SortValue,
+        F: Fn(&T, &T) -> Ordering + Copy,
+    {
+        move |a, b| match (a.is_unordered(), b.is_unordered()) {
+            (true, true) => Ordering::Equal,
+            (true, false) if self.nan_first => Ordering::Less,
+            (true, false) => Ordering::Greater,
+            (false, true) if self.nan_first => Ordering::Greater,
+            (false, true) => Ordering::Less,
+            (false, false) if self.descending => cmp_asc(b, a),
+            (false, false) => cmp_asc(a, b),
+        }
+    }
+
+    /// Radix key of `value` in this direction.
+    fn radix_key<T: SortValue>(self, value: &T) -> u64 {
+        let key = value.radix_key();
+        if self.descending {
+            !key & (u64::MAX >> (64 - 8 * T::RADIX_BYTES))
+        } else {
+            key
+        }
+    }
+}
+
+/// Element types handled by sort and argsort. Only floats have values
+/// that sit outside the ordering.
+///
+/// Integer types also expose an order-preserving unsigned key, which lets
+/// sort and argsort skip comparisons entirely. The key must agree with the
+/// comparator the FFI layer passes for that type.
+pub trait SortValue: Copy {
+    /// Bytes of `radix_key` that vary, or 0 for comparison-only types.
+    const RADIX_BYTES: usize = 0;
+
+    fn is_unordered(&self) -> bool {
+        false
+    }
+
+    fn radix_key(&self) -> u64 {
+        0
+    }
+}
+
+impl SortValue for f64 {
+    fn is_unordered(&self) -> bool {
+        f64::is_nan(*self)
+    }
+}
+
+impl SortValue for f32 {
+    fn is_unordered(&self) -> bool {
+        f32::is_nan(*self)
+    }
+}
+
+macro_rules! impl_radix_unsigned {
+    ($($t:ty),*) => {$(
+        impl SortValue for $t {
+            const RADIX_BYTES: usize = std::mem::size_of::<$t>();
+
+            fn radix_key(&self) -> u64 {
+                *self as u64
+            }
+        }
+    )*};
+}
+
+// Flipping the sign bit maps two's complement onto unsigned order.
+macro_rules! impl_radix_signed {
+    ($($t:ty => $u:ty),*) => {$(
+        impl SortValue for $t {
+            const RADIX_BYTES: usize = std::mem::size_of::<$t>();
+
+            fn radix_key(&self) -> u64 {
+                ((*self as $u) ^ (1 << (<$u>::BITS - 1))) as u64
+            }
+        }
+    )*};
+}
+
+impl_radix_unsigned!(u64, u32, u16, u8);
+impl_radix_signed!(i64 => u64, i32 => u32, i16 => u16, i8 => u8);
+
+/// Below this length a comparison sort beats the radix passes.
+const RADIX_MIN_LEN: usize = 256;
+
+/// Stable LSD radix sort of `(key, item)` pairs, one byte per pass.
+///
+/// A pass where every key has the same byte is skipped, so a narrow value
+/// range in a wide dtype costs little more than a counting sort. For
+/// one-byte types the single pass is a counting sort.
+fn radix_sort_pairs<I: Copy>(entries: &mut Vec<(u64, I)>, bytes: usize) {
+    let mut buf = entries.clone();
+    let mut counts = [0usize; 256];
+
+    for pass in 0..bytes {
+        let shift = pass * 8;
+        counts.fill(0);
+        for &(key, _) in entries.iter() {
+            counts[((key >> shift) & 0xff) as usize] += 1;
+        }
+        if counts.iter().any(|&c| c == entries.len()) {
+            continue;
+        }
+
+        let mut offset = 0;
+        for count in counts.iter_mut() {
+            let n = *count;
+            *count = offset;
+            offset += n;
+        }
+        for &entry in entries.iter() {
+            let bucket = ((entry.0 >> shift) & 0xff) as usize;
+            buf[counts[bucket]] = entry;
+            counts[bucket] += 1;
+        }
+        std::mem::swap(entries, &mut buf);
+    }
+}
+
+/// Radix-sort `values` when `T` has a key and the slice is long enough to
+/// pay for it. Returns false when the caller should compare instead.
+///
+/// The result is stable, which satisfies every `SortKind`.
+fn try_radix_sort<T: SortValue>(values: &mut [T], order: SortOrder) -> bool {
+    if T::RADIX_BYTES == 0 || values.len() < RADIX_MIN_LEN {
+        return false;
+    }
+
+    let mut entries: Vec<(u64, T)> = values.iter().map(|v| (order.radix_key(v), *v)).collect();
+    radix_sort_pairs(&mut entries, T::RADIX_BYTES);
+    for (dst, (_, value)) in values.iter_mut().zip(entries) {
+        *dst = value;
+    }
+    true
+}
+
+/// Argsort counterpart of `try_radix_sort`: fills `indices` with the
+/// positions `0..len` ordered by `get(position)`.
+fn try_radix_argsort<T, G>(len: usize, order: SortOrder, get: G, indices: &mut [usize]) -> bool
+where
+    T: SortValue,
+    G: Fn(usize) -> T,
+{
+    if T::RADIX_BYTES == 0 || len < RADIX_MIN_LEN {
+        return false;
+    }
+
+    let mut entries: Vec<(u64, usize)> = (0..len).map(|i| (order.radix_key(&get(i)), i)).collect();
+    radix_sort_pairs(&mut entries, T::RADIX_BYTES);
+    for (dst, (_, idx)) in indices.iter_mut().zip(entries) {
+        *dst = idx;
+    }
+    true
+}
+
 fn sift_down_by<T, F>(values: &mut [T], start: usize, end: usize, cmp: &mut F)
 where
     F: FnMut(&T, &T) -> Ordering,
@@ -87,18 +265,27 @@ where
     }
 }
 
-pub fn sort_axis_generic<T, F>(view: &ArrayD<T>, axis: usize, kind: SortKind, cmp: F) -> ArrayD<T>
+pub fn sort_axis_generic<T, F>(
+    view: &ArrayD<T>,
+    axis: usize,
+    kind: SortKind,
+    order: SortOrder,
+    cmp: F,
+) -> ArrayD<T>
 where
-    T: Copy,
+    T: SortValue,
     F: Fn(&T, &T) -> Ordering + Copy,
 {
+    let cmp = order.apply(cmp);
     let mut result = view.to_owned();
     let mut scratch: Vec<T> = Vec::new();
 
     for mut lane in result.lanes_mut(Axis(axis)) {
         scratch.clear();
         scratch.extend(lane.iter().copied());
-        sort_by_kind(&mut scratch, kind, |a, b| cmp(a, b));
+        if !try_radix_sort(&mut scratch, order) {
+            sort_by_kind(&mut scratch, kind, |a, b| cmp(a, b));
+        }
         for (dst, src) in lane.iter_mut().zip(scratch.iter().copied()) {
             *dst = src;
         }
@@ -107,12 +294,45 @@ where
     result
 }
 
-pub fn sort_flat_generic<T, F>(view: &ArrayD<T>, kind: SortKind, cmp: F) -> ArrayD<T>
+/// Sort `values` on the thread pool. Heapsort has no parallel form and
+/// stays serial.
+#[cfg(feature = "parallel")]
+fn par_sort_by_kind<T, F>(values: &mut [T], kind: SortKind, cmp: F)
 where
-    T: Copy,
-    F: Fn(&T, &T) -> Ordering + Copy,
+    T: Send,
+    F: Fn(&T, &T) -> Ordering + Send + Sync,
+{
+    use rayon::slice::ParallelSliceMut;
+
+    crate::helpers::parallel::install(|| match kind {
+        SortKind::QuickSort => values.par_sort_unstable_by(|a, b| cmp(a, b)),
+        SortKind::MergeSort | SortKind::Stable => values.par_sort_by(|a, b| cmp(a, b)),
+        SortKind::HeapSort => heapsort_by(values, |a, b| cmp(a, b)),
+    })
+}
+
+pub fn sort_flat_generic<T, F>(
+    view: &ArrayD<T>,
+    kind: SortKind,
+    order: SortOrder,
+    cmp: F,
+) -> ArrayD<T>
+where
+    T: SortValue + Send,
+    F: Fn(&T, &T) -> Ordering + Copy + Send + Sync,
 {
+    let cmp = order.apply(cmp);
     let mut flat: Vec<T> = view.iter().copied().collect();
+    if try_radix_sort(&mut flat, order) {
+        return ArrayD::from_shape_vec(IxDyn(&[flat.len()]), flat)
+            .expect("Failed to build flat sorted output");
+    }
+    #[cfg(feature = "parallel")]
+    if crate::helpers::parallel::should_parallelize(flat.len()) {
+        par_sort_by_kind(&mut flat, kind, cmp);
+        return ArrayD::from_shape_vec(IxDyn(&[flat.len()]), flat)
+            .expect("Failed to build flat sorted output");
+    }
     sort_by_kind(&mut flat, kind, |a, b| cmp(a, b));
     ArrayD::from_shape_vec(IxDyn(&[flat.len()]), flat)
         .expect("Failed to build flat sorted output")
@@ -122,12 +342,14 @@ pub fn argsort_axis_generic<T, F>(
     view: &ArrayD<T>,
     axis: usize,
     kind: SortKind,
+    order: SortOrder,
     cmp: F,
 ) -> ArrayD<i64>
 where
-    T: Copy,
+    T: SortValue,
     F: Fn(&T, &T) -> Ordering + Copy,
 {
+    let cmp = order.apply(cmp);
     let mut result = ArrayD::<i64>::zeros(IxDyn(view.shape()));
     let mut idx_scratch: Vec<usize> = Vec::new();
 
@@ -138,9 +360,11 @@ where
     {
         idx_scratch.clear();
         idx_scratch.extend(0..lane_in.len());
-        sort_by_kind(&mut idx_scratch, kind, |a, b| {
-            cmp(&lane_in[*a], &lane_in[*b])
-        });
+        if !try_radix_argsort(lane_in.len(), order, |i| lane_in[i], &mut idx_scratch) {
+            sort_by_kind(&mut idx_scratch, kind, |a, b| {
+                cmp(&lane_in[*a], &lane_in[*b])
+            });
+        }
         for (dst, src) in lane_out.iter_mut().zip(idx_scratch.iter().copied()) {
             *dst = src as i64;
         }
@@ -149,19 +373,101 @@ where
     result
 }
 
-pub fn argsort_flat_generic<T, F>(view: &ArrayD<T>, kind: SortKind, cmp: F) -> ArrayD<i64>
+pub fn argsort_flat_generic<T, F>(
+    view: &ArrayD<T>,
+    kind: SortKind,
+    order: SortOrder,
+    cmp: F,
+) -> ArrayD<i64>
 where
-    T: Copy,
+    T: SortValue,
     F: Fn(&T, &T) -> Ordering + Copy,
 {
+    let cmp = order.apply(cmp);
     let values: Vec<T> = view.iter().copied().collect();
     let mut indices: Vec<usize> = (0..values.len()).collect();
-    sort_by_kind(&mut indices, kind, |a, b| cmp(&values[*a], &values[*b]));
+    if !try_radix_argsort(values.len(), order, |i| values[i], &mut indices) {
+        sort_by_kind(&mut indices, kind, |a, b| cmp(&values[*a], &values[*b]));
+    }
     let out: Vec<i64> = indices.into_iter().map(|i| i as i64).collect();
     ArrayD::from_shape_vec(IxDyn(&[out.len()]), out)
         .expect("Failed to build flat argsort output")
 }
 
+// ---------------------------------------------------------------------------
+// partition
+// ---------------------------------------------------------------------------
+
+/// Place every position in `kth` where a full sort would put it, with
+/// smaller values before and larger values after. `kth` must be ascending
+/// and free of duplicates.
+fn select_kth_by<T, F>(values: &mut [T], kth: &[usize], mut cmp: F)
+where
+    F: FnMut(&T, &T) -> Ordering,
+{
+    let mut start = 0usize;
+    for &k in kth {
+        values[start..].select_nth_unstable_by(k - start, &mut cmp);
+        start = k + 1;
+    }
+}
+
+pub fn partition_axis_generic<T, F>(
+    view: &ArrayD<T>,
+    axis: usize,
+    kth: &[usize],
+    cmp: F,
+) -> ArrayD<T>
+where
+    T: Copy,
+    F: Fn(&T, &T) -> Ordering + Copy,
+{
+    let mut result = view.to_owned();
+    let mut scratch: Vec<T> = Vec::new();
+
+    for mut lane in result.lanes_mut(Axis(axis)) {
+        scratch.clear();
+        scratch.extend(lane.iter().copied());
+        select_kth_by(&mut scratch, kth, cmp);
+        for (dst, src) in lane.iter_mut().zip(scratch.iter().copied()) {
+            *dst = src;
+        }
+    }
+
+    result
+}
+
+pub fn argpartition_axis_generic<T, F>(
+    view: &ArrayD<T>,
+    axis: usize,
+    kth: &[usize],
+    cmp: F,
+) -> ArrayD<i64>
+where
+    T: Copy,
+    F: Fn(&T, &T) -> Ordering + Copy,
+{
+    let mut result = ArrayD::<i64>::zeros(IxDyn(view.shape()));
+    let mut idx_scratch: Vec<usize> = Vec::new();
+
+    for (lane_in, mut lane_out) in view
+        .lanes(Axis(axis))
+        .into_iter()
+        .zip(result.lanes_mut(Axis(axis)))
+    {
+        idx_scratch.clear();
+        idx_scratch.extend(0..lane_in.len());
+        select_kth_by(&mut idx_scratch, kth, |a, b| {
+            cmp(&lane_in[*a], &lane_in[*b]).then(a.cmp(b))
+        });
+        for (dst, src) in lane_out.iter_mut().zip(idx_scratch.iter().copied()) {
+            *dst = src as i64;
+        }
+    }
+
+    result
+}
+
 // ---------------------------------------------------------------------------
 // heap helpers
 // ---------------------------------------------------------------------------
@@ -254,6 +560,33 @@ where
     heap
 }
 
+/// Indices of the `k` largest (or smallest) values, in ascending index order.
+///
+/// Uses O(n) selection rather than sorting, which is what makes unsorted
+/// top-k cheap. Ties go to the lower index.
+fn select_topk_indices<T, F>(data: &[T], k: usize, largest: bool, cmp_asc: F) -> Vec<usize>
+where
+    F: Fn(&T, &T) -> Ordering,
+{
+    let mut indices: Vec<usize> = (0..data.len()).collect();
+    if k == 0 {
+        return Vec::new();
+    }
+    if k < indices.len() {
+        indices.select_nth_unstable_by(k - 1, |&a, &b| {
+            let ord = if largest {
+                cmp_asc(&data[b], &data[a])
+            } else {
+                cmp_asc(&data[a], &data[b])
+            };
+            ord.then(a.cmp(&b))
+        });
+        indices.truncate(k);
+        indices.sort_unstable();
+    }
+    indices
+}
+
 // ---------------------------------------------------------------------------
 // topk
 // ---------------------------------------------------------------------------
@@ -311,27 +644,32 @@ where
             continue;
         }
 
+        let tmp: Vec<T>;
+        let slice = match lane_in.as_slice() {
+            Some(slice) => slice,
+            None => {
+                tmp = lane_in.iter().copied().collect();
+                &tmp
+            }
+        };
+
+        if !sorted {
+            for (i, idx) in select_topk_indices(slice, k, largest, cmp_asc)
+                .into_iter()
+                .enumerate()
+            {
+                lane_vals[i] = slice[idx];
+                lane_idxs[i] = idx as i64;
+            }
+            continue;
+        }
+
         if k * 4 < n {
             let mut cmp = cmp_asc;
-            let top_items: Vec<(T, usize)> = if let Some(slice) = lane_in.as_slice() {
-                heap_topk(slice, k, largest, &mut cmp)
-            } else {
-                let tmp: Vec<T> = lane_in.iter().copied().collect();
-                heap_topk(&tmp, k, largest, &mut cmp)
-            };
-
-            if !sorted {
-                let mut items = top_items;
-                items.sort_unstable_by_key(|&(_, idx)| idx);
-                for (i, &(val, idx)) in items.iter().enumerate() {
-                    lane_vals[i] = val;
-                    lane_idxs[i] = idx as i64;
-                }
-            } else {
-                for (i, &(val, idx)) in top_items.iter().enumerate() {
-                    lane_vals[i] = val;
-                    lane_idxs[i] = idx as i64;
-                }
+            let top_items = heap_topk(slice, k, largest, &mut cmp);
+            for (i, &(val, idx)) in top_items.iter().enumerate() {
+                lane_vals[i] = val;
+                lane_idxs[i] = idx as i64;
             }
             continue;
         }
@@ -343,21 +681,17 @@ where
 
         if largest {
             sort_by_kind(&mut idx_scratch, kind, |a, b| {
-                cmp_asc(&lane_in[*b], &lane_in[*a])
+                cmp_asc(&slice[*b], &slice[*a])
             });
         } else {
             sort_by_kind(&mut idx_scratch, kind, |a, b| {
-                cmp_asc(&lane_in[*a], &lane_in[*b])
+                cmp_asc(&slice[*a], &slice[*b])
             });
         }
 
-        if !sorted {
-            idx_scratch[..k].sort_unstable();
-        }
-
         for i in 0..k {
             let src_idx = idx_scratch[i];
-            lane_vals[i] = lane_in[src_idx];
+            lane_vals[i] = slice[src_idx];
             lane_idxs[i] = src_idx as i64;
         }
     }
@@ -392,19 +726,16 @@ where
     let mut out_vals: Vec<T> = Vec::with_capacity(out_k);
     let mut out_idxs: Vec<i64> = Vec::with_capacity(out_k);
 
-    if k * 4 < n {
+    if !sorted {
+        for idx in select_topk_indices(&flat, out_k, largest, cmp_asc) {
+            out_vals.push(flat[idx]);
+            out_idxs.push(idx as i64);
+        }
+    } else if k * 4 < n {
         let mut cmp = cmp_asc;
         let top_items = heap_topk(&flat, k, largest, &mut cmp);
 
-        let items: Vec<_> = if sorted {
-            top_items
-        } else {
-            let mut items = top_items;
-            items.sort_unstable_by_key(|&(_, idx)| idx);
-            items
-        };
-
-        for &(val, idx) in items.iter().take(out_k) {
+        for &(val, idx) in top_items.iter().take(out_k) {
             out_vals.push(val);
             out_idxs.push(idx as i64);
         }
@@ -417,10 +748,6 @@ where
             sort_by_kind(&mut indices, kind, |a, b| cmp_asc(&flat[*a], &flat[*b]));
         }
 
-        if !sorted {
-            indices[..out_k].sort_unstable();
-        }
-
         for &idx in indices.iter().take(out_k) {
             out_vals.push(flat[idx]);
             out_idxs.push(idx as i64);
@@ -432,3 +759,121 @@ where
         ArrayD::from_shape_vec(IxDyn(&[out_k]), out_idxs).unwrap(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsorted_topk_matches_sorted_selection() {
+        let data = ArrayD::from_shape_vec(IxDyn(&[12]), vec![5, 3, 9, 1, 9, 0, 7, 2, 8, 6, 4, 9])
+            .unwrap();
+        for largest in [true, false] {
+            for k in [0, 1, 2, 3, 5, 12] {
+                let (sv, si) =
+                    topk_flat_generic(&data, k, largest, true, SortKind::Stable, |a, b| a.cmp(b));
+                let (uv, ui) =
+                    topk_flat_generic(&data, k, largest, false, SortKind::Stable, |a, b| a.cmp(b));
+
+                let mut sorted_idx = si.iter().copied().collect::<Vec<_>>();
+                sorted_idx.sort_unstable();
+                assert_eq!(ui.iter().copied().collect::<Vec<_>>(), sorted_idx);
+                let (mut a, mut b) = (sv.into_raw_vec_and_offset().0, uv.into_raw_vec_and_offset().0);
+                a.sort_unstable();
+                b.sort_unstable();
+                assert_eq!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn sort_order_keeps_nan_placement_independent_of_direction() {
+        let data = ArrayD::from_shape_vec(IxDyn(&[5]), vec![2.0, f64::NAN, 3.0, 1.0, 2.0]).unwrap();
+        let render = |arr: ArrayD<f64>| arr.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+        for (descending, nan_first, expected) in [
+            (false, false, ["1", "2", "2", "3", "NaN"]),
+            (true, false, ["3", "2", "2", "1", "NaN"]),
+            (true, true, ["NaN", "3", "2", "2", "1"]),
+            (false, true, ["NaN", "1", "2", "2", "3"]),
+        ] {
+            let order = SortOrder::new(descending, nan_first);
+            let sorted = sort_flat_generic(&data, SortKind::QuickSort, order, cmp_f64_asc_nan_last);
+            assert_eq!(render(sorted), expected);
+        }
+
+        let idx = argsort_flat_generic(
+            &data,
+            SortKind::Stable,
+            SortOrder::new(true, false),
+            cmp_f64_asc_nan_last,
+        );
+        assert_eq!(idx.as_slice().unwrap(), &[2, 0, 4, 3, 1]);
+    }
+
+    #[test]
+    fn radix_path_matches_comparison_sort() {
+        fn check<T: SortValue + Ord + Send + Sync + std::fmt::Debug>(values: Vec<T>) {
+            let n = values.len();
+            let data = ArrayD::from_shape_vec(IxDyn(&[n]), values.clone()).unwrap();
+            for descending in [false, true] {
+                let order = SortOrder::new(descending, false);
+                let cmp = order.apply(|a: &T, b: &T| a.cmp(b));
+                let mut expected = values.clone();
+                expected.sort_by(cmp);
+                let mut expected_idx: Vec<usize> = (0..n).collect();
+                expected_idx.sort_by(|&a, &b| cmp(&values[a], &values[b]));
+
+                let sorted = sort_flat_generic(&data, SortKind::QuickSort, order, |a, b| a.cmp(b));
+                let idx = argsort_flat_generic(&data, SortKind::QuickSort, order, |a, b| a.cmp(b));
+                assert_eq!(sorted.into_raw_vec_and_offset().0, expected);
+                let idx: Vec<usize> = idx.iter().map(|&i| i as usize).collect();
+                assert_eq!(idx, expected_idx);
+            }
+        }
+
+        let seq: Vec<i64> = (0..600).map(|i| (i * 7919 + 13) % 1009 - 504).collect();
+        check(seq.iter().map(|v| v * 1_000_000_007).collect::<Vec<i64>>());
+        check(seq.iter().map(|&v| v as i32).collect::<Vec<i32>>());
+        check(seq.iter().map(|&v| v as i8).collect::<Vec<i8>>());
+        check(seq.iter().map(|&v| v as u8).collect::<Vec<u8>>());
+        check(seq.iter().map(|&v| (v as u64) << 20).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn partition_places_every_kth_in_sorted_position() {
+        let values = vec![5., f64::NAN, 1., 4., 2., 3., 0., 9., 7., 8., 6., 5.];
+        let data = ArrayD::from_shape_vec(IxDyn(&[2, 6]), values).unwrap();
+        let kth = [1, 4];
+        let parted = partition_axis_generic(&data, 1, &kth, cmp_f64_asc_nan_last);
+        let idxs = argpartition_axis_generic(&data, 1, &kth, cmp_f64_asc_nan_last);
+
+        for row in 0..2 {
+            let lane = parted.index_axis(Axis(0), row);
+            let mut sorted: Vec<f64> = data.index_axis(Axis(0), row).iter().copied().collect();
+            sorted.sort_by(cmp_f64_asc_nan_last);
+            for &k in &kth {
+                assert_eq!(lane[k].to_bits(), sorted[k].to_bits());
+                assert!(lane.iter().take(k).all(|v| *v <= lane[k]));
+                assert!(lane.iter().skip(k + 1).all(|v| v.is_nan() || *v >= lane[k]));
+                assert_eq!(
+                    data[[row, idxs[[row, k]] as usize]].to_bits(),
+                    sorted[k].to_bits()
+                );
+            }
+        }
+        assert!(parted[[0, 5]].is_nan());
+    }
+
+    #[test]
+    fn unsorted_axis_topk_handles_nan_and_ties() {
+        let data =
+            ArrayD::from_shape_vec(IxDyn(&[2, 4]), vec![1.0, f64::NAN, 3.0, 3.0, 2.0, 2.0, 2.0, 0.0])
+                .unwrap();
+        let (vals, idxs) =
+            topk_axis_generic(&data, 1, 2, true, false, SortKind::QuickSort, cmp_f64_asc_nan_last);
+        assert_eq!(idxs.as_slice().unwrap(), &[1, 2, 0, 1]);
+        assert!(vals[[0, 0]].is_nan());
+        assert_eq!(vals[[1, 1]], 2.0);
+    }
+}
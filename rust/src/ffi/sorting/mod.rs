@@ -2,9 +2,11 @@
 
 pub mod argsort;
 pub mod helpers;
+pub mod partition;
 pub mod sort;
 pub mod topk;
 
 pub use argsort::*;
+pub use partition::*;
 pub use sort::*;
 pub use topk::*;
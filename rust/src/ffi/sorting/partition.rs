@@ -0,0 +1,253 @@
+//! Partial sort (kth-element partition) along an axis.
+
+use crate::ffi::sorting::helpers::{
+    argpartition_axis_generic, cmp_f32_asc_nan_last, cmp_f64_asc_nan_last, partition_axis_generic,
+};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS,
+};
+use crate::helpers::write_output_metadata;
+use crate::helpers::{
+    extract_array_as_f32, extract_array_bool, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
+};
+use crate::helpers::{normalize_axis, normalize_index};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Partition along an axis so each `kth` position holds its sorted value.
+///
+/// Elements before a kth position compare less than or equal to it and
+/// elements after compare greater than or equal; order within each side is
+/// unspecified. NaN sorts last. Negative entries in `kth` count from the end.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_partition(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    kth: *const i64,
+    num_kth: usize,
+    axis: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    partition_entry(
+        "partition",
+        handle,
+        meta,
+        kth,
+        num_kth,
+        axis,
+        false,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+/// Indices that would partition the array along an axis.
+///
+/// Same contract as `ndarray_partition`; the output is Int64 positions into
+/// each lane.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_argpartition(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    kth: *const i64,
+    num_kth: usize,
+    axis: i32,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    partition_entry(
+        "argpartition",
+        handle,
+        meta,
+        kth,
+        num_kth,
+        axis,
+        true,
+        out_handle,
+        out_dtype,
+        out_ndim,
+        out_shape,
+        max_ndim,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe fn partition_entry(
+    name: &'static str,
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    kth: *const i64,
+    num_kth: usize,
+    axis: i32,
+    indices: bool,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || (kth.is_null() && num_kth > 0)
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!(name, {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let axis_usize = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let kth_raw = if num_kth == 0 {
+            &[][..]
+        } else {
+            std::slice::from_raw_parts(kth, num_kth)
+        };
+        let kth = match normalize_kth(kth_raw, meta.shape_slice()[axis_usize]) {
+            Ok(k) => k,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_INDEX;
+            }
+        };
+
+        let result_wrapper = match partition_dispatch(wrapper, meta, axis_usize, &kth, indices) {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+/// Resolve negative kth values and return them ascending without duplicates.
+fn normalize_kth(kth: &[i64], len: usize) -> Result<Vec<usize>, String> {
+    let mut out = kth
+        .iter()
+        .map(|&k| {
+            normalize_index(k, len)
+                .map_err(|_| format!("kth {} is out of bounds for axis with size {}", k, len))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    out.sort_unstable();
+    out.dedup();
+    Ok(out)
+}
+
+unsafe fn partition_dispatch(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+    axis: usize,
+    kth: &[usize],
+    indices: bool,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    let int64 = |result| NDArrayWrapper {
+        data: ArrayData::Int64(Arc::new(RwLock::new(result))),
+        dtype: DType::Int64,
+    };
+
+    macro_rules! partition_as {
+        ($variant:ident, $extract:ident, $cmp:expr) => {{
+            let Some(arr) = $extract(wrapper, meta) else {
+                return Err((
+                    ERR_DTYPE,
+                    format!("Failed to extract {} view", wrapper.dtype),
+                ));
+            };
+            if indices {
+                int64(argpartition_axis_generic(&arr, axis, kth, $cmp))
+            } else {
+                NDArrayWrapper {
+                    data: ArrayData::$variant(Arc::new(RwLock::new(partition_axis_generic(
+                        &arr, axis, kth, $cmp,
+                    )))),
+                    dtype: DType::$variant,
+                }
+            }
+        }};
+    }
+
+    Ok(match wrapper.dtype {
+        DType::Float64 => partition_as!(Float64, extract_array_f64, cmp_f64_asc_nan_last),
+        DType::Float32 => partition_as!(Float32, extract_array_f32, cmp_f32_asc_nan_last),
+        DType::Float16 | DType::BFloat16 => {
+            let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                return Err((ERR_DTYPE, "Failed to extract f32 view".to_string()));
+            };
+            if indices {
+                int64(argpartition_axis_generic(
+                    &arr,
+                    axis,
+                    kth,
+                    cmp_f32_asc_nan_last,
+                ))
+            } else {
+                let result = partition_axis_generic(&arr, axis, kth, cmp_f32_asc_nan_last);
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
+        }
+        DType::Int64 => partition_as!(Int64, extract_array_i64, |a, b| a.cmp(b)),
+        DType::Int32 => partition_as!(Int32, extract_array_i32, |a, b| a.cmp(b)),
+        DType::Int16 => partition_as!(Int16, extract_array_i16, |a, b| a.cmp(b)),
+        DType::Int8 => partition_as!(Int8, extract_array_i8, |a, b| a.cmp(b)),
+        DType::Uint64 => partition_as!(Uint64, extract_array_u64, |a, b| a.cmp(b)),
+        DType::Uint32 => partition_as!(Uint32, extract_array_u32, |a, b| a.cmp(b)),
+        DType::Uint16 => partition_as!(Uint16, extract_array_u16, |a, b| a.cmp(b)),
+        DType::Uint8 => partition_as!(Uint8, extract_array_u8, |a, b| a.cmp(b)),
+        DType::Bool => partition_as!(Bool, extract_array_bool, |a, b| a.cmp(b)),
+        DType::Complex64 | DType::Complex128 => {
+            return Err((
+                ERR_DTYPE,
+                "Partition is not supported for complex dtypes".to_string(),
+            ));
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_kth_resolves_negatives_and_rejects_out_of_range() {
+        assert_eq!(normalize_kth(&[-1, 0, 4, 0], 5).unwrap(), vec![0, 4]);
+        assert!(normalize_kth(&[5], 5).is_err());
+        assert!(normalize_kth(&[-6], 5).is_err());
+    }
+}
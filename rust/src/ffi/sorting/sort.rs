@@ -1,15 +1,17 @@
 //! Sort along an axis.
 
 use crate::ffi::sorting::helpers::{
-    cmp_f32_asc_nan_last, cmp_f64_asc_nan_last, sort_axis_generic, sort_flat_generic,
+    cmp_f32_asc_nan_last, cmp_f64_asc_nan_last, sort_axis_generic, sort_flat_generic, SortOrder,
+};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
 };
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
 use crate::helpers::{
-    extract_array_bool, extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32,
-    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
-    extract_array_u8,
+    extract_array_as_f32, extract_array_bool, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::SortKind;
@@ -18,12 +20,17 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 /// Compute the sort along an axis in the array.
+///
+/// `descending` reverses the order; `nan_first` moves NaN to the front
+/// instead of the end, independently of direction.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_sort_axis(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     axis: i32,
     kind: i32,
+    descending: bool,
+    nan_first: bool,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -40,7 +47,7 @@ pub unsafe extern "C" fn ndarray_sort_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("sort_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -56,17 +63,19 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             Ok(k) => k,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
+        let order = SortOrder::new(descending, nan_first);
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, cmp_f64_asc_nan_last);
+                let result =
+                    sort_axis_generic(&arr, axis_usize, sort_kind, order, cmp_f64_asc_nan_last);
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
@@ -75,20 +84,30 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, cmp_f32_asc_nan_last);
+                let result =
+                    sort_axis_generic(&arr, axis_usize, sort_kind, order, cmp_f32_asc_nan_last);
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result =
+                    sort_axis_generic(&arr, axis_usize, sort_kind, order, cmp_f32_asc_nan_last);
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int64(Arc::new(RwLock::new(result))),
                     dtype: DType::Int64,
@@ -97,9 +116,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int32(Arc::new(RwLock::new(result))),
                     dtype: DType::Int32,
@@ -108,9 +127,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int16(Arc::new(RwLock::new(result))),
                     dtype: DType::Int16,
@@ -119,9 +138,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int8(Arc::new(RwLock::new(result))),
                     dtype: DType::Int8,
@@ -130,9 +149,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint64(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint64,
@@ -141,9 +160,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint32(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint32,
@@ -152,9 +171,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint16(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint16,
@@ -163,9 +182,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint8(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint8,
@@ -178,9 +197,9 @@ pub unsafe extern "C" fn ndarray_sort_axis(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_axis_generic(&arr, axis_usize, sort_kind, |a, b| a.cmp(b));
+                let result = sort_axis_generic(&arr, axis_usize, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(result))),
                     dtype: DType::Bool,
@@ -200,11 +219,16 @@ pub unsafe extern "C" fn ndarray_sort_axis(
 }
 
 /// Compute the sort of the flattened array.
+///
+/// `descending` reverses the order; `nan_first` moves NaN to the front
+/// instead of the end, independently of direction.
 #[no_mangle]
 pub unsafe extern "C" fn ndarray_sort_flat(
     handle: *const NdArrayHandle,
     meta: *const ArrayMetadata,
     kind: i32,
+    descending: bool,
+    nan_first: bool,
     out_handle: *mut *mut NdArrayHandle,
     out_dtype: *mut u8,
     out_ndim: *mut usize,
@@ -221,7 +245,7 @@ pub unsafe extern "C" fn ndarray_sort_flat(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("sort_flat", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
 
@@ -229,17 +253,18 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             Ok(k) => k,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
+        let order = SortOrder::new(descending, nan_first);
 
         let result_wrapper = match wrapper.dtype {
             DType::Float64 => {
                 let Some(arr) = extract_array_f64(wrapper, meta) else {
                     set_last_error("Failed to extract f64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, cmp_f64_asc_nan_last);
+                let result = sort_flat_generic(&arr, sort_kind, order, cmp_f64_asc_nan_last);
                 NDArrayWrapper {
                     data: ArrayData::Float64(Arc::new(RwLock::new(result))),
                     dtype: DType::Float64,
@@ -248,20 +273,28 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Float32 => {
                 let Some(arr) = extract_array_f32(wrapper, meta) else {
                     set_last_error("Failed to extract f32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, cmp_f32_asc_nan_last);
+                let result = sort_flat_generic(&arr, sort_kind, order, cmp_f32_asc_nan_last);
                 NDArrayWrapper {
                     data: ArrayData::Float32(Arc::new(RwLock::new(result))),
                     dtype: DType::Float32,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error("Failed to extract f32 view".to_string());
+                    return ERR_DTYPE;
+                };
+                let result = sort_flat_generic(&arr, sort_kind, order, cmp_f32_asc_nan_last);
+                NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_i64(wrapper, meta) else {
                     set_last_error("Failed to extract i64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int64(Arc::new(RwLock::new(result))),
                     dtype: DType::Int64,
@@ -270,9 +303,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Int32 => {
                 let Some(arr) = extract_array_i32(wrapper, meta) else {
                     set_last_error("Failed to extract i32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int32(Arc::new(RwLock::new(result))),
                     dtype: DType::Int32,
@@ -281,9 +314,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Int16 => {
                 let Some(arr) = extract_array_i16(wrapper, meta) else {
                     set_last_error("Failed to extract i16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int16(Arc::new(RwLock::new(result))),
                     dtype: DType::Int16,
@@ -292,9 +325,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Int8 => {
                 let Some(arr) = extract_array_i8(wrapper, meta) else {
                     set_last_error("Failed to extract i8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Int8(Arc::new(RwLock::new(result))),
                     dtype: DType::Int8,
@@ -303,9 +336,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Uint64 => {
                 let Some(arr) = extract_array_u64(wrapper, meta) else {
                     set_last_error("Failed to extract u64 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint64(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint64,
@@ -314,9 +347,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Uint32 => {
                 let Some(arr) = extract_array_u32(wrapper, meta) else {
                     set_last_error("Failed to extract u32 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint32(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint32,
@@ -325,9 +358,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Uint16 => {
                 let Some(arr) = extract_array_u16(wrapper, meta) else {
                     set_last_error("Failed to extract u16 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint16(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint16,
@@ -336,9 +369,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Uint8 => {
                 let Some(arr) = extract_array_u8(wrapper, meta) else {
                     set_last_error("Failed to extract u8 view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Uint8(Arc::new(RwLock::new(result))),
                     dtype: DType::Uint8,
@@ -351,9 +384,9 @@ pub unsafe extern "C" fn ndarray_sort_flat(
             DType::Bool => {
                 let Some(arr) = extract_array_bool(wrapper, meta) else {
                     set_last_error("Failed to extract bool view".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
-                let result = sort_flat_generic(&arr, sort_kind, |a, b| a.cmp(b));
+                let result = sort_flat_generic(&arr, sort_kind, order, |a, b| a.cmp(b));
                 NDArrayWrapper {
                     data: ArrayData::Bool(Arc::new(RwLock::new(result))),
                     dtype: DType::Bool,
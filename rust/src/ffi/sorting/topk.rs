@@ -7,15 +7,17 @@ use parking_lot::RwLock;
 use crate::ffi::sorting::helpers::{
     cmp_f32_asc_nan_last, cmp_f64_asc_nan_last, topk_axis_generic, topk_flat_generic,
 };
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
 use crate::helpers::is_c_contiguous;
 use crate::helpers::normalize_axis;
 use crate::helpers::{
-    extract_array_bool, extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32,
-    extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
-    extract_array_u8, extract_view_bool, extract_view_f32, extract_view_f64, extract_view_i16,
-    extract_view_i32, extract_view_i64, extract_view_i8, extract_view_u16, extract_view_u32,
-    extract_view_u64, extract_view_u8,
+    extract_array_as_f32, extract_array_bool, extract_array_f32, extract_array_f64,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8, extract_view_bool, extract_view_f32,
+    extract_view_f64, extract_view_i16, extract_view_i32, extract_view_i64, extract_view_i8,
+    extract_view_u16, extract_view_u32, extract_view_u64, extract_view_u8,
 };
 use crate::types::dtype::DType;
 use crate::types::SortKind;
@@ -119,7 +121,7 @@ pub unsafe extern "C" fn ndarray_topk_axis(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("topk_axis", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let shape_slice = meta.shape_slice();
@@ -138,14 +140,14 @@ pub unsafe extern "C" fn ndarray_topk_axis(
                 "k={} is larger than selected axis size {}",
                 k, shape_slice[axis_usize]
             ));
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let sort_kind = match SortKind::from_i32(kind) {
             Ok(k) => k,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
 
@@ -164,6 +166,20 @@ pub unsafe extern "C" fn ndarray_topk_axis(
                 cmp_f32_asc_nan_last, Float32, Float32,
                 axis_usize, k, largest, sorted, sort_kind
             ),
+            DType::Float16 | DType::BFloat16 => {
+                // Select in f32; the chosen values convert back to half exactly.
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype.name()));
+                    return ERR_DTYPE;
+                };
+                let (vals, idxs) = topk_axis_generic(
+                    &arr, axis_usize, k, largest, sorted, sort_kind, cmp_f32_asc_nan_last,
+                );
+                (
+                    NDArrayWrapper::from_f32_as_half(vals, wrapper.dtype),
+                    NDArrayWrapper { data: ArrayData::Int64(Arc::new(RwLock::new(idxs))), dtype: DType::Int64 },
+                )
+            }
             DType::Int64 => topk_axis_arm!(
                 contig, wrapper, meta,
                 extract_view_i64, extract_array_i64,
@@ -233,7 +249,7 @@ pub unsafe extern "C" fn ndarray_topk_axis(
                 "output ndim {} exceeds max_ndim {}",
                 out_ndim, max_ndim
             ));
-            return ERR_GENERIC;
+            return ERR_SHAPE;
         }
         for (i, &dim) in result_shape.iter().enumerate() {
             *out_shape.add(i) = dim;
@@ -271,7 +287,7 @@ pub unsafe extern "C" fn ndarray_topk_flat(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("topk_flat", {
         let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
         let meta = &*meta;
         let shape_slice = meta.shape_slice();
@@ -279,14 +295,14 @@ pub unsafe extern "C" fn ndarray_topk_flat(
 
         if k > total {
             set_last_error(format!("k={} is larger than flattened size {}", k, total));
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let sort_kind = match SortKind::from_i32(kind) {
             Ok(k) => k,
             Err(e) => {
                 set_last_error(e);
-                return ERR_GENERIC;
+                return ERR_VALUE;
             }
         };
 
@@ -305,6 +321,20 @@ pub unsafe extern "C" fn ndarray_topk_flat(
                 cmp_f32_asc_nan_last, Float32, Float32,
                 k, largest, sorted, sort_kind
             ),
+            DType::Float16 | DType::BFloat16 => {
+                // Select in f32; the chosen values convert back to half exactly.
+                let Some(arr) = extract_array_as_f32(wrapper, meta) else {
+                    set_last_error(format!("Failed to extract {} view", wrapper.dtype.name()));
+                    return ERR_DTYPE;
+                };
+                let (vals, idxs) = topk_flat_generic(
+                    &arr, k, largest, sorted, sort_kind, cmp_f32_asc_nan_last,
+                );
+                (
+                    NDArrayWrapper::from_f32_as_half(vals, wrapper.dtype),
+                    NDArrayWrapper { data: ArrayData::Int64(Arc::new(RwLock::new(idxs))), dtype: DType::Int64 },
+                )
+            }
             DType::Int64 => topk_flat_arm!(
                 contig, wrapper, meta,
                 extract_view_i64, extract_array_i64,
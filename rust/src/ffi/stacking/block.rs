@@ -0,0 +1,90 @@
+//! Assemble an array from a nested grid of blocks.
+//!
+//! Inputs are promoted to a common dtype; block edges must line up.
+
+use super::helpers::{join_arrays, promoted_dtype, BlockLayout, Join};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::write_output_metadata;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+/// Assemble N arrays laid out as nested lists, like `numpy.block`.
+///
+/// `layout` holds the number of entries of every list in depth-first order and
+/// every array sits `depth` lists deep; e.g. `[[a, b], [c]]` is `layout = [2, 2, 1]`,
+/// `depth = 2`. Arrays with fewer than `max(depth, ndim)` dimensions get leading
+/// axes of length 1. The innermost lists join along the last axis, the next level
+/// along the one before it, and so on.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_block(
+    handles: *const *const NdArrayHandle,
+    metas: *const *const ArrayMetadata,
+    num_arrays: usize,
+    layout: *const usize,
+    layout_len: usize,
+    depth: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handles.is_null()
+        || metas.is_null()
+        || (layout.is_null() && layout_len > 0)
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_shape.is_null()
+        || out_ndim.is_null()
+        || num_arrays == 0
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("block", {
+        let handles_slice = std::slice::from_raw_parts(handles, num_arrays);
+        let metas_slice = std::slice::from_raw_parts(metas, num_arrays);
+        let layout_slice = if layout_len == 0 {
+            &[][..]
+        } else {
+            std::slice::from_raw_parts(layout, layout_len)
+        };
+
+        let block_layout = match BlockLayout::parse(layout_slice, depth, num_arrays) {
+            Ok(l) => l,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+        let ndim = metas_slice
+            .iter()
+            .map(|&m| (*m).ndim)
+            .max()
+            .unwrap_or(0)
+            .max(depth);
+
+        let dtype = promoted_dtype(handles_slice);
+        let result_wrapper = match join_arrays(
+            handles_slice,
+            metas_slice,
+            dtype,
+            Join::Block(&block_layout, ndim),
+        ) {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
+            }
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
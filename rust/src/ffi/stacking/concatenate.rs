@@ -1,21 +1,12 @@
 //! Concatenate N arrays along an axis.
 //!
-//! All arrays must have same dtype and matching shapes except along axis.
+//! Inputs are promoted to a common dtype; shapes must match except along axis.
 
-use ndarray::{concatenate, Axis};
-use parking_lot::RwLock;
-use std::sync::Arc;
-
-use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use super::helpers::{join_arrays, promoted_dtype, Join};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
 use crate::helpers::write_output_metadata;
-use crate::helpers::{
-    extract_array_bool, extract_array_c128, extract_array_c64, extract_array_f32,
-    extract_array_f64, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
-    extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8,
-};
-use crate::types::dtype::DType;
-use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+use crate::types::{ArrayMetadata, NdArrayHandle};
 
 /// Concatenate N arrays along the given axis.
 #[no_mangle]
@@ -25,6 +16,7 @@ pub unsafe extern "C" fn ndarray_concatenate(
     num_arrays: usize,
     axis: i32,
     out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
     out_ndim: *mut usize,
     out_shape: *mut usize,
     max_ndim: usize,
@@ -32,6 +24,7 @@ pub unsafe extern "C" fn ndarray_concatenate(
     if handles.is_null()
         || metas.is_null()
         || out_handle.is_null()
+        || out_dtype.is_null()
         || out_shape.is_null()
         || out_ndim.is_null()
         || num_arrays == 0
@@ -39,31 +32,14 @@ pub unsafe extern "C" fn ndarray_concatenate(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("concatenate", {
         let handles_slice = std::slice::from_raw_parts(handles, num_arrays);
         let metas_slice = std::slice::from_raw_parts(metas, num_arrays);
 
-        let first_handle = match handles_slice.get(0) {
-            Some(&h) => h,
-            None => {
-                set_last_error("concatenate requires at least one array".to_string());
-                return ERR_GENERIC;
-            }
-        };
-        let wrapper_0 = NdArrayHandle::as_wrapper(first_handle as *mut _);
-        let dtype = wrapper_0.dtype;
-
-        for &h in handles_slice.iter().skip(1) {
-            let w = NdArrayHandle::as_wrapper(h as *mut _);
-            if w.dtype != dtype {
-                set_last_error("concatenate requires arrays with same dtype".to_string());
-                return ERR_DTYPE;
-            }
-        }
+        let dtype = promoted_dtype(handles_slice);
 
-        let meta_0 = &**metas_slice.get(0).unwrap();
-        let shape_0 = std::slice::from_raw_parts(meta_0.shape, meta_0.ndim);
-        let axis_usize = match normalize_axis(shape_0, axis, false) {
+        let meta_0 = &**metas_slice.first().unwrap();
+        let axis_usize = match normalize_axis(meta_0.shape_slice(), axis, false) {
             Ok(a) => a,
             Err(e) => {
                 set_last_error(e);
@@ -71,380 +47,22 @@ pub unsafe extern "C" fn ndarray_concatenate(
             }
         };
 
-        let result_wrapper = match dtype {
-            DType::Float64 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_f64(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract f64 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Float64(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Float64,
-                }
-            }
-            DType::Float32 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_f32(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract f32 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Float32(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Float32,
-                }
-            }
-            DType::Int64 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_i64(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract i64 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Int64(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Int64,
-                }
-            }
-            DType::Int32 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_i32(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract i32 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Int32(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Int32,
-                }
-            }
-            DType::Int16 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_i16(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract i16 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Int16(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Int16,
-                }
-            }
-            DType::Int8 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_i8(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract i8 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Int8(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Int8,
-                }
-            }
-            DType::Uint64 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_u64(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract u64 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint64(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Uint64,
-                }
-            }
-            DType::Uint32 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_u32(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract u32 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint32(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Uint32,
-                }
-            }
-            DType::Uint16 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_u16(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract u16 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint16(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Uint16,
-                }
-            }
-            DType::Uint8 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_u8(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract u8 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Uint8(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Uint8,
-                }
-            }
-            DType::Bool => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_bool(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract bool view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Bool(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Bool,
-                }
-            }
-            DType::Complex64 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_c64(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract complex64 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Complex64(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Complex64,
-                }
-            }
-            DType::Complex128 => {
-                let mut views = Vec::with_capacity(num_arrays);
-                for i in 0..num_arrays {
-                    let meta = &**metas_slice.get(i).unwrap();
-                    let w = NdArrayHandle::as_wrapper(*handles_slice.get(i).unwrap() as *mut _);
-                    let v = match extract_array_c128(w, meta) {
-                        Some(v) => v,
-                        None => {
-                            set_last_error("Failed to extract complex128 view".to_string());
-                            return ERR_GENERIC;
-                        }
-                    };
-                    views.push(v);
-                }
-                let views_refs: Vec<_> = views.iter().map(|v| v.view()).collect();
-                let arr = match concatenate(Axis(axis_usize), &views_refs) {
-                    Ok(a) => a.into_dyn(),
-                    Err(e) => {
-                        set_last_error(e.to_string());
-                        return ERR_SHAPE;
-                    }
-                };
-                let arr = arr.as_standard_layout().into_owned();
-                NDArrayWrapper {
-                    data: ArrayData::Complex128(Arc::new(RwLock::new(arr))),
-                    dtype: DType::Complex128,
-                }
+        let result_wrapper = match join_arrays(
+            handles_slice,
+            metas_slice,
+            dtype,
+            Join::Concatenate(axis_usize),
+        ) {
+            Ok(w) => w,
+            Err((code, msg)) => {
+                set_last_error(msg);
+                return code;
             }
         };
 
-        if let Err(e) = write_output_metadata(
-            &result_wrapper,
-            std::ptr::null_mut(),
-            out_ndim,
-            out_shape,
-            max_ndim,
-        ) {
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
             set_last_error(e);
             return ERR_GENERIC;
         }
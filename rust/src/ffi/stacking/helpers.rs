@@ -0,0 +1,284 @@
+//! Shared helpers for joining and splitting.
+
+use ndarray::{concatenate, stack, ArrayD, ArrayViewD, Axis};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{ERR_DTYPE, ERR_SHAPE};
+use crate::helpers::{
+    extract_array_as_bool, extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
+    extract_array_as_f64, extract_array_as_half, extract_array_as_i16, extract_array_as_i32,
+    extract_array_as_i64, extract_array_as_i8, extract_array_as_u16, extract_array_as_u32,
+    extract_array_as_u64, extract_array_as_u8, extract_array_bool, extract_array_c128,
+    extract_array_c64, extract_array_f32, extract_array_f64, extract_array_half_bits,
+    extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
+    extract_array_u32, extract_array_u64, extract_array_u8,
+};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// How the inputs are joined.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Join<'a> {
+    /// Along an existing axis.
+    Concatenate(usize),
+    /// Along a new axis inserted at this position.
+    Stack(usize),
+    /// As a nested grid of blocks, producing an array of this many dimensions.
+    Block(&'a BlockLayout, usize),
+}
+
+impl Join<'_> {
+    fn name(self) -> &'static str {
+        match self {
+            Join::Concatenate(_) => "concatenate",
+            Join::Stack(_) => "stack",
+            Join::Block(..) => "block",
+        }
+    }
+
+    fn apply<T: Clone>(self, views: &[ArrayViewD<'_, T>]) -> Result<ArrayD<T>, String> {
+        let joined = match self {
+            Join::Concatenate(axis) => concatenate(Axis(axis), views),
+            Join::Stack(axis) => stack(Axis(axis), views),
+            Join::Block(layout, ndim) => {
+                return assemble(layout, views, ndim, ndim - layout.depth());
+            }
+        };
+        joined
+            .map(|arr| arr.as_standard_layout().into_owned())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Nesting of the inputs to `block`, like the nested lists passed to `numpy.block`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum BlockLayout {
+    /// Index of an input array.
+    Leaf(usize),
+    /// Blocks joined along one axis; the outermost list uses the first blocked axis.
+    Nest(Vec<BlockLayout>),
+}
+
+impl BlockLayout {
+    /// Rebuild the nesting from the child count of every list in depth-first order.
+    ///
+    /// Every input must sit exactly `depth` lists deep and be used once, in order.
+    pub(crate) fn parse(counts: &[usize], depth: usize, num_leaves: usize) -> Result<Self, String> {
+        fn node(
+            counts: &mut std::slice::Iter<'_, usize>,
+            remaining: usize,
+            next_leaf: &mut usize,
+        ) -> Result<BlockLayout, String> {
+            if remaining == 0 {
+                *next_leaf += 1;
+                return Ok(BlockLayout::Leaf(*next_leaf - 1));
+            }
+            match counts.next() {
+                None => Err("block layout ends before all lists are described".to_string()),
+                Some(0) => Err("block lists must not be empty".to_string()),
+                Some(&n) => (0..n)
+                    .map(|_| node(counts, remaining - 1, next_leaf))
+                    .collect::<Result<_, _>>()
+                    .map(BlockLayout::Nest),
+            }
+        }
+
+        let mut iter = counts.iter();
+        let mut next_leaf = 0;
+        let layout = node(&mut iter, depth, &mut next_leaf)?;
+        if iter.next().is_some() {
+            return Err("block layout has unused list counts".to_string());
+        }
+        if next_leaf != num_leaves {
+            return Err(format!(
+                "block layout references {} arrays but {} were given",
+                next_leaf, num_leaves
+            ));
+        }
+        Ok(layout)
+    }
+
+    /// Number of list levels above the leaves.
+    pub(crate) fn depth(&self) -> usize {
+        match self {
+            BlockLayout::Leaf(_) => 0,
+            BlockLayout::Nest(children) => 1 + children[0].depth(),
+        }
+    }
+}
+
+/// Join the blocks under `layout`, the current list level joining along `axis`.
+///
+/// Leaves get leading axes of length 1 until they have `ndim` dimensions.
+fn assemble<T: Clone>(
+    layout: &BlockLayout,
+    views: &[ArrayViewD<'_, T>],
+    ndim: usize,
+    axis: usize,
+) -> Result<ArrayD<T>, String> {
+    let children = match layout {
+        BlockLayout::Leaf(i) => {
+            let mut view = views[*i].clone();
+            while view.ndim() < ndim {
+                view = view.insert_axis(Axis(0));
+            }
+            return Ok(view.as_standard_layout().into_owned());
+        }
+        BlockLayout::Nest(children) => children,
+    };
+
+    let parts = children
+        .iter()
+        .map(|child| assemble(child, views, ndim, axis + 1))
+        .collect::<Result<Vec<_>, _>>()?;
+    let first = parts[0].shape();
+    for part in &parts[1..] {
+        let mismatch = (0..ndim).any(|d| d != axis && part.shape()[d] != first[d]);
+        if mismatch {
+            return Err(format!(
+                "block shapes {:?} and {:?} do not match outside axis {}",
+                first,
+                part.shape(),
+                axis
+            ));
+        }
+    }
+    let part_views: Vec<_> = parts.iter().map(|p| p.view()).collect();
+    concatenate(Axis(axis), &part_views).map_err(|e| e.to_string())
+}
+
+/// Result dtype of joining arrays of these dtypes, folded pairwise with
+/// `DType::promote`.
+///
+/// # Safety
+/// Every handle must be valid; `handles` must not be empty.
+pub(crate) unsafe fn promoted_dtype(handles: &[*const NdArrayHandle]) -> DType {
+    handles
+        .iter()
+        .map(|&h| NdArrayHandle::as_wrapper(h as *mut _).dtype)
+        .reduce(DType::promote)
+        .expect("join requires at least one array")
+}
+
+/// Convert every input to `dtype` and join them.
+///
+/// Errors carry the FFI status code to return.
+///
+/// # Safety
+/// `handles` and `metas` must pair up valid arrays and their layouts.
+pub(crate) unsafe fn join_arrays(
+    handles: &[*const NdArrayHandle],
+    metas: &[*const ArrayMetadata],
+    dtype: DType,
+    join: Join<'_>,
+) -> Result<NDArrayWrapper, (i32, String)> {
+    macro_rules! join_with {
+        ($extract:expr) => {{
+            let mut arrays = Vec::with_capacity(handles.len());
+            for (&h, &m) in handles.iter().zip(metas) {
+                let w = NdArrayHandle::as_wrapper(h as *mut _);
+                let Some(arr) = $extract(w, &*m) else {
+                    return Err((
+                        ERR_DTYPE,
+                        format!("Failed to convert {} input to {}", join.name(), dtype),
+                    ));
+                };
+                arrays.push(arr);
+            }
+            let views: Vec<_> = arrays.iter().map(|a| a.view()).collect();
+            join.apply(&views).map_err(|e| (ERR_SHAPE, e))?
+        }};
+    }
+
+    macro_rules! join_as {
+        ($variant:ident, $extract:ident) => {
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new(join_with!($extract)))),
+                dtype: DType::$variant,
+            }
+        };
+    }
+
+    Ok(match dtype {
+        DType::Float64 => join_as!(Float64, extract_array_as_f64),
+        DType::Float32 => join_as!(Float32, extract_array_as_f32),
+        DType::Int64 => join_as!(Int64, extract_array_as_i64),
+        DType::Int32 => join_as!(Int32, extract_array_as_i32),
+        DType::Int16 => join_as!(Int16, extract_array_as_i16),
+        DType::Int8 => join_as!(Int8, extract_array_as_i8),
+        DType::Uint64 => join_as!(Uint64, extract_array_as_u64),
+        DType::Uint32 => join_as!(Uint32, extract_array_as_u32),
+        DType::Uint16 => join_as!(Uint16, extract_array_as_u16),
+        DType::Uint8 => join_as!(Uint8, extract_array_as_u8),
+        DType::Bool => join_as!(Bool, extract_array_as_bool),
+        DType::Complex64 => join_as!(Complex64, extract_array_as_c64),
+        DType::Complex128 => join_as!(Complex128, extract_array_as_c128),
+        DType::Float16 | DType::BFloat16 => {
+            let bits = join_with!(|w, m| extract_array_as_half(w, m, dtype));
+            NDArrayWrapper::from_half_bits(bits, dtype)
+        }
+    })
+}
+
+/// Copy a view into a new array of the same dtype.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub(crate) unsafe fn copy_view(
+    wrapper: &NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Option<NDArrayWrapper> {
+    macro_rules! copy_as {
+        ($variant:ident, $extract:ident) => {
+            NDArrayWrapper {
+                data: ArrayData::$variant(Arc::new(RwLock::new($extract(wrapper, meta)?))),
+                dtype: DType::$variant,
+            }
+        };
+    }
+
+    Some(match wrapper.dtype {
+        DType::Float64 => copy_as!(Float64, extract_array_f64),
+        DType::Float32 => copy_as!(Float32, extract_array_f32),
+        DType::Int64 => copy_as!(Int64, extract_array_i64),
+        DType::Int32 => copy_as!(Int32, extract_array_i32),
+        DType::Int16 => copy_as!(Int16, extract_array_i16),
+        DType::Int8 => copy_as!(Int8, extract_array_i8),
+        DType::Uint64 => copy_as!(Uint64, extract_array_u64),
+        DType::Uint32 => copy_as!(Uint32, extract_array_u32),
+        DType::Uint16 => copy_as!(Uint16, extract_array_u16),
+        DType::Uint8 => copy_as!(Uint8, extract_array_u8),
+        DType::Bool => copy_as!(Bool, extract_array_bool),
+        DType::Complex64 => copy_as!(Complex64, extract_array_c64),
+        DType::Complex128 => copy_as!(Complex128, extract_array_c128),
+        DType::Float16 | DType::BFloat16 => {
+            NDArrayWrapper::from_half_bits(extract_array_half_bits(wrapper, meta)?, wrapper.dtype)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn block_assembles_nested_grid() {
+        // [[a, b], [c]] with a: 2x2, b: 2x1, c: 1x3
+        let layout = BlockLayout::parse(&[2, 2, 1], 2, 3).unwrap();
+        let a = array![[1, 2], [4, 5]].into_dyn();
+        let b = array![[3], [6]].into_dyn();
+        let c = array![7, 8, 9].into_dyn();
+        let views = [a.view(), b.view(), c.view()];
+
+        let out = Join::Block(&layout, 2).apply(&views).unwrap();
+        assert_eq!(out, array![[1, 2, 3], [4, 5, 6], [7, 8, 9]].into_dyn());
+
+        let short = array![7, 8].into_dyn();
+        let views = [a.view(), b.view(), short.view()];
+        assert!(Join::Block(&layout, 2).apply(&views).is_err());
+        assert!(BlockLayout::parse(&[2, 0], 2, 0).is_err());
+        assert!(BlockLayout::parse(&[2, 2, 1], 2, 2).is_err());
+    }
+}
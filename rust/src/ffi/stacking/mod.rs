@@ -1,11 +1,14 @@
 //! Joining and splitting operations.
 //!
-//! Provides concatenate, stack, and split using ndarray's stacking and split_at.
+//! Provides concatenate, stack, block, and split using ndarray's stacking and split_at.
 
+pub mod block;
 pub mod concatenate;
+pub(crate) mod helpers;
 pub mod split;
 pub mod stack;
 
+pub use block::*;
 pub use concatenate::*;
 pub use split::*;
 pub use stack::*;
@@ -1,7 +1,10 @@
 //! Split array along axis at given indices.
 //!
-//! Returns view metadata (offset, shape, strides) for each part. No new allocations -
-//! parts are views into the original. PHP creates NDArray objects with same handle.
+//! `ndarray_split` returns view metadata (offset, shape, strides) for each part. No new
+//! allocations - parts are views into the original. PHP creates NDArray objects with same handle.
+//!
+//! `ndarray_array_split` and `ndarray_split_at` follow `numpy.array_split` instead: sections may
+//! be uneven and boundaries are clamped rather than rejected. Their parts are views as well.
 
 use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_INDEX, ERR_SHAPE, SUCCESS};
 use crate::helpers::normalize_axis;
@@ -34,10 +37,9 @@ pub unsafe extern "C" fn ndarray_split(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("split", {
         let meta = &*meta;
         let shape_slice = meta.shape_slice();
-        let strides_slice = meta.strides_slice();
         let indices_slice = std::slice::from_raw_parts(indices, num_indices);
 
         let axis_usize = match normalize_axis(shape_slice, axis, false) {
@@ -49,7 +51,6 @@ pub unsafe extern "C" fn ndarray_split(
         };
 
         let axis_len = shape_slice[axis_usize];
-        let axis_stride = strides_slice[axis_usize];
 
         // Validate indices: 0 <= i0 < i1 < ... < i_{n-1} <= axis_len
         let mut prev = 0usize;
@@ -64,37 +65,196 @@ pub unsafe extern "C" fn ndarray_split(
             prev = idx;
         }
 
-        let num_parts = num_indices + 1;
-
-        let mut part_starts = Vec::with_capacity(num_parts + 1);
-        part_starts.push(0);
-        part_starts.extend_from_slice(indices_slice);
-        part_starts.push(axis_len);
-
-        for (i, &start) in part_starts.iter().enumerate().take(num_parts) {
-            let end = part_starts[i + 1];
-            let part_len = end - start;
-
-            // Offset for this part: base_offset + start * axis_stride
-            let part_offset = meta.offset + start * axis_stride;
-            *out_offsets.add(i) = part_offset;
-
-            // Shape: same as input but axis dimension = part_len
-            for d in 0..meta.ndim {
-                let val = if d == axis_usize {
-                    part_len
-                } else {
-                    shape_slice[d]
-                };
-                *out_shapes.add(i * meta.ndim + d) = val;
+        let mut points = Vec::with_capacity(num_indices + 2);
+        points.push(0);
+        points.extend_from_slice(indices_slice);
+        points.push(axis_len);
+        let bounds: Vec<(usize, usize)> = points.windows(2).map(|w| (w[0], w[1])).collect();
+        write_parts(
+            meta,
+            axis_usize,
+            &bounds,
+            out_offsets,
+            out_shapes,
+            out_strides,
+        );
+
+        SUCCESS
+    })
+}
+
+/// Split array along axis into `sections` parts of nearly equal length.
+///
+/// The first `len % sections` parts get one extra element, like `numpy.array_split`.
+/// Writes view metadata like `ndarray_split`: out_offsets (size sections), out_shapes and
+/// out_strides (size sections*ndim each).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_array_split(
+    _handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    sections: usize,
+    out_offsets: *mut usize,
+    out_shapes: *mut usize,
+    out_strides: *mut usize,
+) -> i32 {
+    if meta.is_null() || out_offsets.is_null() || out_shapes.is_null() || out_strides.is_null() {
+        return ERR_GENERIC;
+    }
+    if sections == 0 {
+        set_last_error("Number of sections must be >= 1".to_string());
+        return ERR_SHAPE;
+    }
+
+    crate::ffi_guard!("array_split", {
+        let meta = &*meta;
+        let axis_usize = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
             }
+        };
+
+        let bounds = section_bounds(meta.shape_slice()[axis_usize], sections);
+        write_parts(
+            meta,
+            axis_usize,
+            &bounds,
+            out_offsets,
+            out_shapes,
+            out_strides,
+        );
+        SUCCESS
+    })
+}
+
+/// Split array along axis at the given boundaries.
+///
+/// Produces num_indices + 1 parts `[b(i-1)..b(i))` with `b(-1) = 0` and `b(n) = len`.
+/// Negative boundaries count from the end and all boundaries are clamped to the axis, so a
+/// boundary smaller than its predecessor yields an empty part. Writes view metadata like
+/// `ndarray_split`: out_offsets (size num_indices+1), out_shapes and out_strides (size
+/// (num_indices+1)*ndim each).
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_split_at(
+    _handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    indices: *const i64,
+    num_indices: usize,
+    out_offsets: *mut usize,
+    out_shapes: *mut usize,
+    out_strides: *mut usize,
+) -> i32 {
+    if meta.is_null()
+        || (indices.is_null() && num_indices > 0)
+        || out_offsets.is_null()
+        || out_shapes.is_null()
+        || out_strides.is_null()
+    {
+        return ERR_GENERIC;
+    }
 
-            // Strides: same as input (views share strides)
-            for d in 0..meta.ndim {
-                *out_strides.add(i * meta.ndim + d) = strides_slice[d];
+    crate::ffi_guard!("split_at", {
+        let meta = &*meta;
+        let axis_usize = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
             }
-        }
+        };
 
+        let indices_slice = if num_indices == 0 {
+            &[][..]
+        } else {
+            std::slice::from_raw_parts(indices, num_indices)
+        };
+        let bounds = boundary_bounds(meta.shape_slice()[axis_usize], indices_slice);
+        write_parts(
+            meta,
+            axis_usize,
+            &bounds,
+            out_offsets,
+            out_shapes,
+            out_strides,
+        );
         SUCCESS
     })
 }
+
+/// Part ranges for an uneven split of `len` into `sections`.
+fn section_bounds(len: usize, sections: usize) -> Vec<(usize, usize)> {
+    let (base, extra) = (len / sections, len % sections);
+    let mut start = 0;
+    (0..sections)
+        .map(|i| {
+            let end = start + base + usize::from(i < extra);
+            let part = (start, end);
+            start = end;
+            part
+        })
+        .collect()
+}
+
+/// Part ranges between consecutive boundaries, with slice-style clamping.
+fn boundary_bounds(len: usize, indices: &[i64]) -> Vec<(usize, usize)> {
+    let clamp = |i: i64| {
+        if i < 0 {
+            len.saturating_sub(i.unsigned_abs() as usize)
+        } else {
+            (i as usize).min(len)
+        }
+    };
+    let mut points = Vec::with_capacity(indices.len() + 2);
+    points.push(0);
+    points.extend(indices.iter().map(|&i| clamp(i)));
+    points.push(len);
+    points.windows(2).map(|w| (w[0], w[1].max(w[0]))).collect()
+}
+
+/// Write the view metadata of each `[start..end)` range along `axis`.
+///
+/// Parts share the input's strides; only the offset and the axis length change.
+unsafe fn write_parts(
+    meta: &ArrayMetadata,
+    axis: usize,
+    bounds: &[(usize, usize)],
+    out_offsets: *mut usize,
+    out_shapes: *mut usize,
+    out_strides: *mut usize,
+) {
+    let ndim = meta.ndim;
+    let shape = meta.shape_slice();
+    let strides = meta.strides_slice();
+    let axis_stride = strides[axis] as isize;
+
+    for (i, &(start, end)) in bounds.iter().enumerate() {
+        // Empty parts keep the base offset so it never points past the data.
+        *out_offsets.add(i) = if end > start {
+            (meta.offset as isize + start as isize * axis_stride) as usize
+        } else {
+            meta.offset
+        };
+        for d in 0..ndim {
+            *out_shapes.add(i * ndim + d) = if d == axis { end - start } else { shape[d] };
+            *out_strides.add(i * ndim + d) = strides[d];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_follow_numpy_array_split() {
+        assert_eq!(section_bounds(7, 3), vec![(0, 3), (3, 5), (5, 7)]);
+        assert_eq!(section_bounds(2, 3), vec![(0, 1), (1, 2), (2, 2)]);
+        assert_eq!(
+            boundary_bounds(6, &[2, -1, 10, 3]),
+            vec![(0, 2), (2, 5), (5, 6), (6, 6), (3, 6)]
+        );
+    }
+}
@@ -0,0 +1,492 @@
+//! Generalized ufunc registry.
+//!
+//! A kernel is registered once under a name together with a core signature
+//! such as `"(m,n),(n)->(m)"`. Invoking it by id broadcasts the leading loop
+//! dimensions of every input and calls the kernel once per loop position with
+//! contiguous C-order f64 blocks holding each operand's core dimensions.
+
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use ndarray::{ArrayD, Axis, Dimension, IxDyn};
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+use crate::helpers::error::{
+    set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, ERR_VALUE, SUCCESS,
+};
+use crate::helpers::{broadcast_shape, extract_array_as_f64, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+/// Gufunc kernel, called once per loop position.
+///
+/// `inputs` holds one pointer per operand to its core block, `output` points
+/// to the core block to fill, and `dims` lists the size bound to each core
+/// dimension name in order of first appearance in the signature. Returns 0
+/// on success; any other value aborts the call.
+pub type NdGufuncKernel = Option<
+    unsafe extern "C" fn(inputs: *const *const f64, output: *mut f64, dims: *const usize) -> i32,
+>;
+
+type KernelFn =
+    unsafe extern "C" fn(inputs: *const *const f64, output: *mut f64, dims: *const usize) -> i32;
+
+/// Parsed core signature; dimensions are indices into `dim_names`.
+#[derive(Clone, Debug, PartialEq)]
+struct Signature {
+    inputs: Vec<Vec<usize>>,
+    output: Vec<usize>,
+    dim_names: Vec<String>,
+}
+
+impl Signature {
+    fn parse(text: &str) -> Result<Self, String> {
+        let compact: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+        let Some((lhs, rhs)) = compact.split_once("->") else {
+            return Err(format!("Gufunc signature '{}' is missing '->'", text));
+        };
+
+        let mut dim_names = Vec::new();
+        let inputs = Self::parse_operands(lhs, &mut dim_names)?;
+        let mut outputs = Self::parse_operands(rhs, &mut dim_names)?;
+        if inputs.is_empty() {
+            return Err(format!("Gufunc signature '{}' has no inputs", text));
+        }
+        if outputs.len() != 1 {
+            return Err(format!(
+                "Gufunc signature '{}' must have exactly one output",
+                text
+            ));
+        }
+        let output = outputs.pop().unwrap();
+        for &d in &output {
+            if !inputs.iter().any(|op| op.contains(&d)) {
+                return Err(format!(
+                    "Output dimension '{}' does not appear in any input",
+                    dim_names[d]
+                ));
+            }
+        }
+
+        Ok(Self {
+            inputs,
+            output,
+            dim_names,
+        })
+    }
+
+    fn parse_operands(text: &str, dim_names: &mut Vec<String>) -> Result<Vec<Vec<usize>>, String> {
+        let mut operands = Vec::new();
+        let mut rest = text;
+        while !rest.is_empty() {
+            let Some(body) = rest.strip_prefix('(') else {
+                return Err(format!("Expected '(' in gufunc signature near '{}'", rest));
+            };
+            let Some(close) = body.find(')') else {
+                return Err(format!("Unclosed '(' in gufunc signature near '{}'", rest));
+            };
+
+            let mut dims = Vec::new();
+            if close > 0 {
+                for name in body[..close].split(',') {
+                    let valid = name
+                        .chars()
+                        .next()
+                        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+                    if !valid {
+                        return Err(format!("Invalid core dimension name '{}'", name));
+                    }
+                    let idx = match dim_names.iter().position(|n| n == name) {
+                        Some(i) => i,
+                        None => {
+                            dim_names.push(name.to_string());
+                            dim_names.len() - 1
+                        }
+                    };
+                    dims.push(idx);
+                }
+            }
+            operands.push(dims);
+
+            rest = &body[close + 1..];
+            if let Some(next) = rest.strip_prefix(',') {
+                if next.is_empty() {
+                    return Err("Trailing ',' in gufunc signature".to_string());
+                }
+                rest = next;
+            } else if !rest.is_empty() {
+                return Err(format!("Expected ',' in gufunc signature near '{}'", rest));
+            }
+        }
+        Ok(operands)
+    }
+}
+
+struct Gufunc {
+    name: String,
+    signature: Signature,
+    kernel: KernelFn,
+}
+
+/// Registered gufuncs indexed by id. Unregistered slots stay `None` so ids
+/// are never reused for a different kernel.
+static REGISTRY: RwLock<Vec<Option<Gufunc>>> = RwLock::new(Vec::new());
+
+unsafe fn read_name(ptr: *const c_char, what: &str) -> Result<String, String> {
+    CStr::from_ptr(ptr)
+        .to_str()
+        .map(str::to_string)
+        .map_err(|e| format!("Invalid UTF-8 in gufunc {}: {}", what, e))
+}
+
+/// Register `kernel` under `name` with a core `signature` such as `"(m,n),(n)->(m)"`.
+///
+/// Dimension names are identifiers; `()` denotes a scalar operand. Exactly one
+/// output is supported and each of its dimensions must appear in an input.
+/// The id is written to `out_id`. Registering an existing name replaces its
+/// signature and kernel and keeps its id, so hosts whose callbacks do not
+/// outlive a request can re-register at the start of each one.
+///
+/// The registry keeps `kernel` until [`ndarray_gufunc_unregister`] is called,
+/// so hosts must unregister before the callback is freed.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_gufunc_register(
+    name: *const c_char,
+    signature: *const c_char,
+    kernel: NdGufuncKernel,
+    out_id: *mut u32,
+) -> i32 {
+    if name.is_null() || signature.is_null() || out_id.is_null() {
+        return ERR_GENERIC;
+    }
+    let Some(kernel) = kernel else {
+        set_last_error("Gufunc kernel must not be null".to_string());
+        return ERR_GENERIC;
+    };
+
+    crate::ffi_guard!("gufunc_register", {
+        let (name, signature) = match (read_name(name, "name"), read_name(signature, "signature")) {
+            (Ok(n), Ok(s)) => (n, s),
+            (Err(e), _) | (_, Err(e)) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        let signature = match Signature::parse(&signature) {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+
+        let mut registry = REGISTRY.write();
+        let existing = registry
+            .iter_mut()
+            .enumerate()
+            .find_map(|(id, slot)| slot.as_mut().filter(|g| g.name == name).map(|g| (id, g)));
+        if let Some((id, g)) = existing {
+            g.signature = signature;
+            g.kernel = kernel;
+            *out_id = id as u32;
+            return SUCCESS;
+        }
+        registry.push(Some(Gufunc {
+            name,
+            signature,
+            kernel,
+        }));
+        *out_id = (registry.len() - 1) as u32;
+        SUCCESS
+    })
+}
+
+/// Look up the id of a registered gufunc by name.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_gufunc_find(name: *const c_char, out_id: *mut u32) -> i32 {
+    if name.is_null() || out_id.is_null() {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("gufunc_find", {
+        let name = match read_name(name, "name") {
+            Ok(n) => n,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_VALUE;
+            }
+        };
+        match REGISTRY
+            .read()
+            .iter()
+            .position(|slot| slot.as_ref().is_some_and(|g| g.name == name))
+        {
+            Some(id) => {
+                *out_id = id as u32;
+                SUCCESS
+            }
+            None => {
+                set_last_error(format!("No gufunc registered as '{}'", name));
+                ERR_VALUE
+            }
+        }
+    })
+}
+
+/// Remove gufunc `id` from the registry so its kernel is never called again.
+///
+/// Later calls with this id fail and the id is not reused. Unregistering an
+/// unknown or already removed id is an error.
+#[no_mangle]
+pub extern "C" fn ndarray_gufunc_unregister(id: u32) -> i32 {
+    crate::ffi_guard!("gufunc_unregister", {
+        let mut registry = REGISTRY.write();
+        match registry.get_mut(id as usize).and_then(Option::take) {
+            Some(_) => SUCCESS,
+            None => {
+                set_last_error(format!("No gufunc registered with id {}", id));
+                ERR_VALUE
+            }
+        }
+    })
+}
+
+/// Invoke registered gufunc `id` on `num_inputs` arrays.
+///
+/// Each input's trailing dimensions are matched against its core signature;
+/// the remaining leading (loop) dimensions are broadcast together. The result
+/// is Float64 with shape `loop_shape + output_core_shape`. Complex inputs are
+/// not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_gufunc_call(
+    id: u32,
+    handles: *const *const NdArrayHandle,
+    metas: *const *const ArrayMetadata,
+    num_inputs: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handles.is_null()
+        || metas.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+
+    crate::ffi_guard!("gufunc_call", {
+        let (name, signature, kernel) = {
+            let registry = REGISTRY.read();
+            let Some(g) = registry.get(id as usize).and_then(Option::as_ref) else {
+                set_last_error(format!("No gufunc registered with id {}", id));
+                return ERR_VALUE;
+            };
+            (g.name.clone(), g.signature.clone(), g.kernel)
+        };
+        if num_inputs != signature.inputs.len() {
+            set_last_error(format!(
+                "Gufunc '{}' expects {} inputs, got {}",
+                name,
+                signature.inputs.len(),
+                num_inputs
+            ));
+            return ERR_VALUE;
+        }
+
+        let handles = std::slice::from_raw_parts(handles, num_inputs);
+        let metas = std::slice::from_raw_parts(metas, num_inputs);
+
+        let mut dims: Vec<Option<usize>> = vec![None; signature.dim_names.len()];
+        let mut loop_shape: Vec<usize> = Vec::new();
+        let mut arrays = Vec::with_capacity(num_inputs);
+        for (i, core) in signature.inputs.iter().enumerate() {
+            if handles[i].is_null() || metas[i].is_null() {
+                return ERR_GENERIC;
+            }
+            let wrapper = NdArrayHandle::as_wrapper(handles[i] as *mut _);
+            let meta = &*metas[i];
+            if wrapper.dtype.is_complex() {
+                set_last_error("Gufuncs do not support complex arrays".to_string());
+                return ERR_DTYPE;
+            }
+
+            let shape = meta.shape_slice();
+            if shape.len() < core.len() {
+                set_last_error(format!(
+                    "Gufunc '{}' input {} needs at least {} dimensions, got {}",
+                    name,
+                    i,
+                    core.len(),
+                    shape.len()
+                ));
+                return ERR_SHAPE;
+            }
+            let loop_nd = shape.len() - core.len();
+            for (&d, &size) in core.iter().zip(&shape[loop_nd..]) {
+                match dims[d] {
+                    Some(bound) if bound != size => {
+                        set_last_error(format!(
+                            "Gufunc '{}' core dimension '{}' mismatch: {} vs {}",
+                            name, signature.dim_names[d], bound, size
+                        ));
+                        return ERR_SHAPE;
+                    }
+                    _ => dims[d] = Some(size),
+                }
+            }
+            loop_shape = match broadcast_shape(&loop_shape, &shape[..loop_nd]) {
+                Some(s) => s,
+                None => {
+                    set_last_error(format!(
+                        "Gufunc '{}' loop dimensions of input {} cannot be broadcast",
+                        name, i
+                    ));
+                    return ERR_SHAPE;
+                }
+            };
+
+            let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+                set_last_error("Failed to extract array as f64".to_string());
+                return ERR_DTYPE;
+            };
+            arrays.push(arr);
+        }
+
+        // Every name is bound: outputs only use dimensions that appear in an input.
+        let dims: Vec<usize> = dims.into_iter().map(|d| d.unwrap_or(0)).collect();
+        let core_shape = |core: &[usize]| core.iter().map(|&d| dims[d]).collect::<Vec<_>>();
+
+        let mut views = Vec::with_capacity(num_inputs);
+        for (arr, core) in arrays.iter().zip(&signature.inputs) {
+            let full: Vec<usize> = loop_shape.iter().copied().chain(core_shape(core)).collect();
+            match arr.broadcast(IxDyn(&full)) {
+                Some(v) => views.push(v),
+                None => {
+                    set_last_error(format!("Gufunc '{}' failed to broadcast inputs", name));
+                    return ERR_SHAPE;
+                }
+            }
+        }
+
+        let out_core = core_shape(&signature.output);
+        let out_block: usize = out_core.iter().product();
+        let out_full: Vec<usize> = loop_shape.iter().copied().chain(out_core).collect();
+        let mut result = ArrayD::<f64>::zeros(IxDyn(&out_full));
+        let out_data = result.as_slice_mut().expect("fresh array is contiguous");
+
+        let mut blocks: Vec<Vec<f64>> = vec![Vec::new(); num_inputs];
+        let mut block_ptrs: Vec<*const f64> = vec![std::ptr::null(); num_inputs];
+        for (k, idx) in ndarray::indices(IxDyn(&loop_shape)).into_iter().enumerate() {
+            for ((view, block), ptr) in views.iter().zip(&mut blocks).zip(&mut block_ptrs) {
+                let mut sub = view.view();
+                for &i in idx.slice() {
+                    sub = sub.index_axis_move(Axis(0), i);
+                }
+                block.clear();
+                block.extend(sub.iter().copied());
+                *ptr = block.as_ptr();
+            }
+
+            let out_ptr = out_data[k * out_block..].as_mut_ptr();
+            let status = kernel(block_ptrs.as_ptr(), out_ptr, dims.as_ptr());
+            if status != 0 {
+                set_last_error(format!(
+                    "Gufunc '{}' kernel failed with status {}",
+                    name, status
+                ));
+                return ERR_GENERIC;
+            }
+        }
+
+        let result_wrapper = NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+            dtype: DType::Float64,
+        };
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    unsafe extern "C" fn noop_kernel(
+        _inputs: *const *const f64,
+        _output: *mut f64,
+        _dims: *const usize,
+    ) -> i32 {
+        0
+    }
+
+    #[test]
+    fn unregister_releases_the_id() {
+        let name = CString::new("test_unregister_releases").unwrap();
+        let signature = CString::new("(n)->()").unwrap();
+        unsafe {
+            let mut id = 0u32;
+            let status = ndarray_gufunc_register(
+                name.as_ptr(),
+                signature.as_ptr(),
+                Some(noop_kernel),
+                &mut id,
+            );
+            assert_eq!(status, SUCCESS);
+
+            assert_eq!(ndarray_gufunc_unregister(id), SUCCESS);
+            assert_eq!(ndarray_gufunc_unregister(id), ERR_VALUE);
+            let mut found = 0u32;
+            assert_eq!(ndarray_gufunc_find(name.as_ptr(), &mut found), ERR_VALUE);
+
+            let mut new_id = 0u32;
+            let status = ndarray_gufunc_register(
+                name.as_ptr(),
+                signature.as_ptr(),
+                Some(noop_kernel),
+                &mut new_id,
+            );
+            assert_eq!(status, SUCCESS);
+            assert_ne!(new_id, id);
+            assert_eq!(ndarray_gufunc_unregister(new_id), SUCCESS);
+        }
+    }
+
+    #[test]
+    fn parses_matrix_vector_signature() {
+        let sig = Signature::parse("(m, n),(n)->(m)").unwrap();
+        assert_eq!(sig.inputs, vec![vec![0, 1], vec![1]]);
+        assert_eq!(sig.output, vec![0]);
+        assert_eq!(sig.dim_names, vec!["m".to_string(), "n".to_string()]);
+    }
+
+    #[test]
+    fn parses_scalar_operands() {
+        let sig = Signature::parse("(n),()->()").unwrap();
+        assert_eq!(sig.inputs, vec![vec![0], vec![]]);
+        assert!(sig.output.is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_signatures() {
+        assert!(Signature::parse("(n)(n)->()").is_err());
+        assert!(Signature::parse("(n),->()").is_err());
+        assert!(Signature::parse("(n)->(k)").is_err());
+        assert!(Signature::parse("(n)->(n),(n)").is_err());
+        assert!(Signature::parse("(1n)->()").is_err());
+        assert!(Signature::parse("(n)").is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! Element-wise transform through a caller-supplied callback.
+//!
+//! Values are handed to the callback in contiguous batches rather than one at
+//! a time, so the per-call FFI overhead is paid once per batch.
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, SUCCESS};
+use crate::helpers::{extract_array_as_f64, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayMetadata, NdArrayHandle};
+
+use super::{wrap_f64_as, DEFAULT_CALLBACK_BATCH};
+
+/// Map callback: read `count` values from `input`, write `count` results to `output`.
+///
+/// Returns 0 on success; any other value aborts the map.
+pub type NdMapCallback =
+    Option<unsafe extern "C" fn(input: *const f64, output: *mut f64, count: usize) -> i32>;
+
+/// Apply `callback` to every element and assemble the results into a new array.
+///
+/// Elements are visited in C order and passed to the callback as f64 in
+/// batches of at most `batch_size` values (0 selects a default). The results
+/// are cast to `target_dtype`, which must be a real dtype; the output has the
+/// shape of the input. Complex inputs are not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_map(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    callback: NdMapCallback,
+    target_dtype: u8,
+    batch_size: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+    let Some(callback) = callback else {
+        set_last_error("Map callback must not be null".to_string());
+        return ERR_GENERIC;
+    };
+
+    crate::ffi_guard!("map", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        let Some(target) = DType::from_u8(target_dtype) else {
+            set_last_error(format!("Invalid target dtype: {}", target_dtype));
+            return ERR_DTYPE;
+        };
+        if wrapper.dtype.is_complex() || target.is_complex() {
+            set_last_error("Map callbacks do not support complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+        let input: Vec<f64> = arr.iter().copied().collect();
+        let mut output = vec![0.0f64; input.len()];
+
+        let batch = if batch_size == 0 {
+            DEFAULT_CALLBACK_BATCH
+        } else {
+            batch_size
+        };
+        for (src, dst) in input.chunks(batch).zip(output.chunks_mut(batch)) {
+            let status = callback(src.as_ptr(), dst.as_mut_ptr(), src.len());
+            if status != 0 {
+                set_last_error(format!("Map callback failed with status {}", status));
+                return ERR_GENERIC;
+            }
+        }
+
+        let values = match ndarray::ArrayD::from_shape_vec(arr.raw_dim(), output) {
+            Ok(v) => v,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_GENERIC;
+            }
+        };
+        let Some(result_wrapper) = wrap_f64_as(values, target) else {
+            set_last_error("Map callbacks do not support complex arrays".to_string());
+            return ERR_DTYPE;
+        };
+
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
@@ -0,0 +1,56 @@
+//! User-defined kernels module.
+//!
+//! Runs caller-supplied callbacks over array data for transforms and
+//! aggregations not covered by the built-in operations.
+
+pub mod gufunc;
+pub mod map;
+pub mod reduce;
+
+pub use gufunc::*;
+pub use map::*;
+pub use reduce::*;
+
+use std::sync::Arc;
+
+use ndarray::ArrayD;
+use parking_lot::RwLock;
+
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, NDArrayWrapper};
+
+/// Batch size used when the caller passes 0.
+pub(crate) const DEFAULT_CALLBACK_BATCH: usize = 4096;
+
+/// Wrap f64 callback results as an array of `dtype`, casting each value.
+///
+/// Returns `None` for complex targets, which callbacks cannot produce.
+pub(crate) fn wrap_f64_as(values: ArrayD<f64>, dtype: DType) -> Option<NDArrayWrapper> {
+    macro_rules! cast {
+        ($variant:ident, $t:ty) => {
+            ArrayData::$variant(Arc::new(RwLock::new(values.mapv(|v| v as $t))))
+        };
+    }
+
+    let data = match dtype {
+        DType::Int8 => cast!(Int8, i8),
+        DType::Int16 => cast!(Int16, i16),
+        DType::Int32 => cast!(Int32, i32),
+        DType::Int64 => cast!(Int64, i64),
+        DType::Uint8 => cast!(Uint8, u8),
+        DType::Uint16 => cast!(Uint16, u16),
+        DType::Uint32 => cast!(Uint32, u32),
+        DType::Uint64 => cast!(Uint64, u64),
+        DType::Float32 => cast!(Float32, f32),
+        DType::Float64 => ArrayData::Float64(Arc::new(RwLock::new(values))),
+        DType::Float16 | DType::BFloat16 => {
+            return Some(NDArrayWrapper::from_f32_as_half(
+                values.mapv(|v| v as f32),
+                dtype,
+            ))
+        }
+        DType::Bool => ArrayData::Bool(Arc::new(RwLock::new(values.mapv(|v| (v != 0.0) as u8)))),
+        DType::Complex64 | DType::Complex128 => return None,
+    };
+    Some(NDArrayWrapper { data, dtype })
+}
@@ -0,0 +1,124 @@
+//! Lane-wise reduction through a caller-supplied combine callback.
+//!
+//! Each lane along the reduced axis is folded into an accumulator starting at
+//! `init`. Lane values are handed over in contiguous batches so the callback
+//! can apply its binary combine step without one FFI round trip per element.
+
+use ndarray::{ArrayD, Axis, IxDyn};
+
+use crate::helpers::error::{set_last_error, ERR_DTYPE, ERR_GENERIC, ERR_SHAPE, SUCCESS};
+use crate::helpers::{extract_array_as_f64, normalize_axis, write_output_metadata};
+use crate::types::dtype::DType;
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper, NdArrayHandle};
+
+use super::DEFAULT_CALLBACK_BATCH;
+
+use parking_lot::RwLock;
+use std::sync::Arc;
+
+/// Reduce callback: fold `count` values from `values` into `*acc`, in order.
+///
+/// Returns 0 on success; any other value aborts the reduction.
+pub type NdReduceCallback =
+    Option<unsafe extern "C" fn(acc: *mut f64, values: *const f64, count: usize) -> i32>;
+
+/// Reduce each lane along `axis` with `callback`, starting from `init`.
+///
+/// Lane values are passed as f64 in batches of at most `batch_size` values
+/// (0 selects a default); empty lanes reduce to `init`. The result is Float64
+/// with `axis` removed, or kept with length 1 when `keepdims` is set. Complex
+/// inputs are not supported.
+#[no_mangle]
+pub unsafe extern "C" fn ndarray_reduce(
+    handle: *const NdArrayHandle,
+    meta: *const ArrayMetadata,
+    axis: i32,
+    keepdims: bool,
+    init: f64,
+    callback: NdReduceCallback,
+    batch_size: usize,
+    out_handle: *mut *mut NdArrayHandle,
+    out_dtype: *mut u8,
+    out_ndim: *mut usize,
+    out_shape: *mut usize,
+    max_ndim: usize,
+) -> i32 {
+    if handle.is_null()
+        || meta.is_null()
+        || out_handle.is_null()
+        || out_dtype.is_null()
+        || out_ndim.is_null()
+        || out_shape.is_null()
+    {
+        return ERR_GENERIC;
+    }
+    let Some(callback) = callback else {
+        set_last_error("Reduce callback must not be null".to_string());
+        return ERR_GENERIC;
+    };
+
+    crate::ffi_guard!("reduce", {
+        let wrapper = NdArrayHandle::as_wrapper(handle as *mut _);
+        let meta = &*meta;
+
+        if wrapper.dtype.is_complex() {
+            set_last_error("Reduce callbacks do not support complex arrays".to_string());
+            return ERR_DTYPE;
+        }
+
+        let axis = match normalize_axis(meta.shape_slice(), axis, false) {
+            Ok(a) => a,
+            Err(e) => {
+                set_last_error(e);
+                return ERR_SHAPE;
+            }
+        };
+
+        let Some(arr) = extract_array_as_f64(wrapper, meta) else {
+            set_last_error("Failed to extract array as f64".to_string());
+            return ERR_DTYPE;
+        };
+
+        let batch = if batch_size == 0 {
+            DEFAULT_CALLBACK_BATCH
+        } else {
+            batch_size
+        };
+        let mut out_dims = arr.shape().to_vec();
+        out_dims.remove(axis);
+        let mut result = ArrayD::<f64>::zeros(IxDyn(&out_dims));
+        let mut buffer = Vec::with_capacity(arr.len_of(Axis(axis)));
+
+        for (lane, out) in arr.lanes(Axis(axis)).into_iter().zip(result.iter_mut()) {
+            buffer.clear();
+            buffer.extend(lane.iter().copied());
+
+            let mut acc = init;
+            for chunk in buffer.chunks(batch) {
+                let status = callback(&mut acc, chunk.as_ptr(), chunk.len());
+                if status != 0 {
+                    set_last_error(format!("Reduce callback failed with status {}", status));
+                    return ERR_GENERIC;
+                }
+            }
+            *out = acc;
+        }
+
+        if keepdims {
+            result = result.insert_axis(Axis(axis));
+        }
+
+        let result_wrapper = NDArrayWrapper {
+            data: ArrayData::Float64(Arc::new(RwLock::new(result))),
+            dtype: DType::Float64,
+        };
+        if let Err(e) =
+            write_output_metadata(&result_wrapper, out_dtype, out_ndim, out_shape, max_ndim)
+        {
+            set_last_error(e);
+            return ERR_GENERIC;
+        }
+        *out_handle = NdArrayHandle::from_wrapper(Box::new(result_wrapper));
+        SUCCESS
+    })
+}
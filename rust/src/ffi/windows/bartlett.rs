@@ -47,7 +47,7 @@ pub unsafe extern "C" fn ndarray_bartlett(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("bartlett", {
         let data = bartlett_window(m, periodic);
         let arr: ArrayD<f64> = match ArrayD::from_shape_vec(IxDyn(&[m]), data) {
             Ok(a) => a,
@@ -49,7 +49,7 @@ pub unsafe extern "C" fn ndarray_bohman(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("bohman", {
         let data = bohman_window(m, periodic);
         let arr: ArrayD<f64> = match ArrayD::from_shape_vec(IxDyn(&[m]), data) {
             Ok(a) => a,
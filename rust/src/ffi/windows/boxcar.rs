@@ -36,7 +36,7 @@ pub unsafe extern "C" fn ndarray_boxcar(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("boxcar", {
         let data = boxcar_window(m, periodic);
         let arr: ArrayD<f64> = match ArrayD::from_shape_vec(IxDyn(&[m]), data) {
             Ok(a) => a,
@@ -47,7 +47,7 @@ pub unsafe extern "C" fn ndarray_hanning(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("hanning", {
         let data = hanning_window(m, periodic);
         let arr: ArrayD<f64> = match ArrayD::from_shape_vec(IxDyn(&[m]), data) {
             Ok(a) => a,
@@ -6,7 +6,7 @@ use ndarray::{ArrayD, IxDyn};
 use parking_lot::RwLock;
 use std::sync::Arc;
 
-use crate::helpers::error::{set_last_error, ERR_GENERIC, SUCCESS};
+use crate::helpers::error::{set_last_error, ERR_GENERIC, ERR_VALUE, SUCCESS};
 use crate::types::dtype::DType;
 use crate::types::{ArrayData, NDArrayWrapper, NdArrayHandle};
 
@@ -74,10 +74,10 @@ pub unsafe extern "C" fn ndarray_kaiser(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("kaiser", {
         if !beta.is_finite() {
             set_last_error("kaiser(): beta must be finite".to_string());
-            return ERR_GENERIC;
+            return ERR_VALUE;
         }
 
         let data = kaiser_window(m, beta, periodic);
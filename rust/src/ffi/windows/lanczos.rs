@@ -58,7 +58,7 @@ pub unsafe extern "C" fn ndarray_lanczos(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("lanczos", {
         let data = lanczos_window(m, periodic);
         let arr: ArrayD<f64> = match ArrayD::from_shape_vec(IxDyn(&[m]), data) {
             Ok(a) => a,
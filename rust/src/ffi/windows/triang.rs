@@ -50,7 +50,7 @@ pub unsafe extern "C" fn ndarray_triang(
         return ERR_GENERIC;
     }
 
-    crate::ffi_guard!({
+    crate::ffi_guard!("triang", {
         let data = triang_window(m, periodic);
         let arr: ArrayD<f64> = match ArrayD::from_shape_vec(IxDyn(&[m]), data) {
             Ok(a) => a,
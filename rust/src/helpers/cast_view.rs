@@ -0,0 +1,372 @@
+//! Lazy, per-element casting reads over strided array views.
+//!
+//! The `extract_array_as_*` helpers materialise the whole view as a new
+//! `ArrayD<T>`, which is wasteful when the consumer only needs a few elements
+//! (printing edge items, reading a range). [`CastView`] instead walks the
+//! source buffer with the view's offset and strides and converts each element
+//! to the requested type on demand. Conversions match `extract_array_as_*`:
+//! `as` casts between reals, bools read as 0/1, complex → real takes `re`.
+
+use num_complex::{Complex32, Complex64};
+
+use crate::types::half::{bf16_to_f32, f16_to_f32};
+use crate::types::{ArrayData, ArrayMetadata, NDArrayWrapper};
+
+/// Element type that any dtype can be lazily converted into.
+pub trait CastTarget: Copy {
+    fn from_f64(v: f64) -> Self;
+    fn from_f32(v: f32) -> Self;
+    fn from_i64(v: i64) -> Self;
+    fn from_i32(v: i32) -> Self;
+    fn from_i16(v: i16) -> Self;
+    fn from_i8(v: i8) -> Self;
+    fn from_u64(v: u64) -> Self;
+    fn from_u32(v: u32) -> Self;
+    fn from_u16(v: u16) -> Self;
+    fn from_u8(v: u8) -> Self;
+    fn from_bool(v: u8) -> Self;
+    fn from_c64(v: Complex32) -> Self;
+    fn from_c128(v: Complex64) -> Self;
+}
+
+macro_rules! impl_real_cast_target {
+    ($($t:ty),+) => {$(
+        impl CastTarget for $t {
+            #[inline] fn from_f64(v: f64) -> Self { v as $t }
+            #[inline] fn from_f32(v: f32) -> Self { v as $t }
+            #[inline] fn from_i64(v: i64) -> Self { v as $t }
+            #[inline] fn from_i32(v: i32) -> Self { v as $t }
+            #[inline] fn from_i16(v: i16) -> Self { v as $t }
+            #[inline] fn from_i8(v: i8) -> Self { v as $t }
+            #[inline] fn from_u64(v: u64) -> Self { v as $t }
+            #[inline] fn from_u32(v: u32) -> Self { v as $t }
+            #[inline] fn from_u16(v: u16) -> Self { v as $t }
+            #[inline] fn from_u8(v: u8) -> Self { v as $t }
+            #[inline] fn from_bool(v: u8) -> Self { (v != 0) as u8 as $t }
+            #[inline] fn from_c64(v: Complex32) -> Self { v.re as $t }
+            #[inline] fn from_c128(v: Complex64) -> Self { v.re as $t }
+        }
+    )+};
+}
+
+impl_real_cast_target!(f64, f32, i64, i32, i16, i8, u64, u32, u16, u8);
+
+macro_rules! impl_complex_cast_target {
+    ($t:ty, $f:ty) => {
+        impl CastTarget for $t {
+            #[inline]
+            fn from_f64(v: f64) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_f32(v: f32) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_i64(v: i64) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_i32(v: i32) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_i16(v: i16) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_i8(v: i8) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_u64(v: u64) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_u32(v: u32) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_u16(v: u16) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_u8(v: u8) -> Self {
+                <$t>::new(v as $f, 0.0)
+            }
+            #[inline]
+            fn from_bool(v: u8) -> Self {
+                <$t>::new((v != 0) as u8 as $f, 0.0)
+            }
+            #[inline]
+            fn from_c64(v: Complex32) -> Self {
+                <$t>::new(v.re as $f, v.im as $f)
+            }
+            #[inline]
+            fn from_c128(v: Complex64) -> Self {
+                <$t>::new(v.re as $f, v.im as $f)
+            }
+        }
+    };
+}
+
+impl_complex_cast_target!(Complex32, f32);
+impl_complex_cast_target!(Complex64, f64);
+
+/// Read-only view over an array that converts elements to `T` as they are read.
+///
+/// Elements are addressed in logical C order. Each read call takes the array's
+/// read lock for its duration only; nothing is copied beyond the requested
+/// elements.
+pub struct CastView<'a> {
+    wrapper: &'a NDArrayWrapper,
+    offset: usize,
+    shape: &'a [usize],
+    strides: &'a [usize],
+}
+
+/// Dispatch on the storage variant, binding the typed base pointer and the
+/// matching `CastTarget` conversion.
+macro_rules! with_source {
+    ($view:expr, $t:ty, |$base:ident, $conv:ident| $body:expr) => {
+        match &$view.wrapper.data {
+            ArrayData::Float64(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_f64;
+                $body
+            }
+            ArrayData::Float32(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_f32;
+                $body
+            }
+            ArrayData::Int64(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_i64;
+                $body
+            }
+            ArrayData::Int32(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_i32;
+                $body
+            }
+            ArrayData::Int16(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_i16;
+                $body
+            }
+            ArrayData::Int8(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_i8;
+                $body
+            }
+            ArrayData::Uint64(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_u64;
+                $body
+            }
+            ArrayData::Uint32(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_u32;
+                $body
+            }
+            ArrayData::Uint16(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_u16;
+                $body
+            }
+            ArrayData::Uint8(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_u8;
+                $body
+            }
+            ArrayData::Bool(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_bool;
+                $body
+            }
+            ArrayData::Complex64(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_c64;
+                $body
+            }
+            ArrayData::Complex128(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = <$t>::from_c128;
+                $body
+            }
+            ArrayData::Float16(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = |v: u16| <$t>::from_f32(f16_to_f32(v));
+                $body
+            }
+            ArrayData::BFloat16(a) => {
+                let g = a.read();
+                let $base = g.as_ptr();
+                let $conv = |v: u16| <$t>::from_f32(bf16_to_f32(v));
+                $body
+            }
+        }
+    };
+}
+
+impl<'a> CastView<'a> {
+    /// Create a casting view over `wrapper` described by `meta`.
+    ///
+    /// # Safety
+    /// `meta` must describe a valid view into `wrapper`'s buffer.
+    pub unsafe fn new(wrapper: &'a NDArrayWrapper, meta: &'a ArrayMetadata) -> Self {
+        Self {
+            wrapper,
+            offset: meta.offset,
+            shape: meta.shape_slice(),
+            strides: meta.strides_slice(),
+        }
+    }
+
+    /// Number of elements in the view.
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Whether the view has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Read the element at logical C-order index `index`, converted to `T`.
+    pub fn get<T: CastTarget>(&self, index: usize) -> Option<T> {
+        let mut out = None;
+        self.for_each_in(index, 1, |v: T| out = Some(v));
+        out
+    }
+
+    /// Visit `len` elements starting at logical C-order index `start`,
+    /// converted to `T`. The range is clamped to the view.
+    pub fn for_each_in<T: CastTarget>(&self, start: usize, len: usize, mut f: impl FnMut(T)) {
+        let _ = self.try_for_each_in(start, len, |v| {
+            f(v);
+            Ok::<(), ()>(())
+        });
+    }
+
+    /// Like [`for_each_in`](Self::for_each_in), stopping at the first error.
+    pub fn try_for_each_in<T: CastTarget, E>(
+        &self,
+        start: usize,
+        len: usize,
+        mut f: impl FnMut(T) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let total = self.len();
+        if start >= total {
+            return Ok(());
+        }
+        let len = len.min(total - start);
+        with_source!(self, T, |base, conv| unsafe {
+            self.walk(start, len, |off| f(conv(*base.offset(off))))
+        })
+    }
+
+    /// Call `f` with the buffer offset of each element in `[start, start + len)`.
+    fn walk<E>(
+        &self,
+        start: usize,
+        len: usize,
+        mut f: impl FnMut(isize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let ndim = self.shape.len();
+        // Strides are stored as usize but may encode negative steps.
+        let strides: Vec<isize> = self.strides.iter().map(|&s| s as isize).collect();
+
+        // Unravel `start` into a multi-index and its buffer offset.
+        let mut index = vec![0usize; ndim];
+        let mut rem = start;
+        let mut off = self.offset as isize;
+        for d in (0..ndim).rev() {
+            index[d] = rem % self.shape[d];
+            rem /= self.shape[d];
+            off += index[d] as isize * strides[d];
+        }
+
+        for n in 0..len {
+            f(off)?;
+            if n + 1 == len {
+                break;
+            }
+            // Odometer increment in C order.
+            for d in (0..ndim).rev() {
+                index[d] += 1;
+                off += strides[d];
+                if index[d] < self.shape[d] {
+                    break;
+                }
+                off -= self.shape[d] as isize * strides[d];
+                index[d] = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::ArrayD;
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    use crate::types::DType;
+
+    fn wrapper_i32(data: Vec<i32>, shape: &[usize]) -> NDArrayWrapper {
+        NDArrayWrapper {
+            data: ArrayData::Int32(Arc::new(RwLock::new(
+                ArrayD::from_shape_vec(shape.to_vec(), data).unwrap(),
+            ))),
+            dtype: DType::Int32,
+        }
+    }
+
+    #[test]
+    fn reads_transposed_range_with_conversion() {
+        // 2x3 buffer [[0,1,2],[3,4,5]] viewed as its 3x2 transpose.
+        let w = wrapper_i32((0..6).collect(), &[2, 3]);
+        let shape = [3usize, 2];
+        let strides = [1usize, 3];
+        let meta = ArrayMetadata::new(0, shape.as_ptr(), strides.as_ptr(), 2);
+        let view = unsafe { CastView::new(&w, &meta) };
+
+        let mut got = Vec::new();
+        view.for_each_in(1, 4, |v: f64| got.push(v));
+        assert_eq!(got, vec![3.0, 1.0, 4.0, 2.0]);
+        assert_eq!(view.get::<f32>(5), Some(5.0));
+        assert_eq!(view.get::<f32>(6), None);
+    }
+
+    #[test]
+    fn handles_offsets_and_negative_strides() {
+        // Reverse of the second row of [[0,1,2],[3,4,5]].
+        let w = wrapper_i32((0..6).collect(), &[2, 3]);
+        let shape = [3usize];
+        let strides = [-1isize as usize];
+        let meta = ArrayMetadata::new(5, shape.as_ptr(), strides.as_ptr(), 1);
+        let view = unsafe { CastView::new(&w, &meta) };
+
+        let mut got = Vec::new();
+        view.for_each_in(0, 10, |v: Complex64| got.push(v.re));
+        assert_eq!(got, vec![5.0, 4.0, 3.0]);
+    }
+}
@@ -1,9 +1,20 @@
 use std::cell::RefCell;
 use std::fmt::Display;
 
-// Thread-local storage for the last error message
+/// The last error raised on the current thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorDetail {
+    /// Error code returned by the failing call.
+    pub code: i32,
+    /// Failing FFI function without its `ndarray_` prefix, e.g. `"matmul"`.
+    /// `None` until the call returns through `ffi_guard!`.
+    pub operation: Option<&'static str>,
+    pub message: String,
+}
+
+// Thread-local storage for the last error
 thread_local! {
-    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+    static LAST_ERROR: RefCell<Option<ErrorDetail>> = const { RefCell::new(None) };
 }
 
 // Error codes
@@ -15,15 +26,79 @@ pub const ERR_ALLOC: i32 = 4;
 pub const ERR_PANIC: i32 = 5;
 pub const ERR_INDEX: i32 = 6;
 pub const ERR_MATH: i32 = 7;
+pub const ERR_OVERFLOW: i32 = 8;
+pub const ERR_NOT_IMPLEMENTED: i32 = 9;
+pub const ERR_SINGULAR_MATRIX: i32 = 10;
+pub const ERR_VALUE: i32 = 11;
 
 /// Set the last error message.
+///
+/// The code and operation are filled in by [`record_failure`] when the
+/// failing call returns; until then the code reads as `ERR_GENERIC`.
 pub fn set_last_error<E: Display>(err: E) {
     LAST_ERROR.with(|e| {
-        *e.borrow_mut() = Some(err.to_string());
+        *e.borrow_mut() = Some(ErrorDetail {
+            code: ERR_GENERIC,
+            operation: None,
+            message: err.to_string(),
+        });
+    });
+}
+
+/// Attribute the pending error to `operation`, which returned `code`.
+///
+/// A message already attributed to an earlier call is not reused: the
+/// failure is then recorded without a message rather than with a stale one.
+pub fn record_failure(code: i32, operation: &'static str) {
+    LAST_ERROR.with(|e| {
+        let mut e = e.borrow_mut();
+        match e.as_mut() {
+            Some(detail) if detail.operation.is_none() => {
+                detail.code = code;
+                detail.operation = Some(operation);
+            }
+            _ => {
+                *e = Some(ErrorDetail {
+                    code,
+                    operation: Some(operation),
+                    message: String::new(),
+                })
+            }
+        }
     });
 }
 
 /// Get the last error message, if any.
 pub fn get_last_error_message() -> Option<String> {
+    LAST_ERROR.with(|e| {
+        e.borrow()
+            .as_ref()
+            .filter(|d| !d.message.is_empty())
+            .map(|d| d.message.clone())
+    })
+}
+
+/// Get the last error with its code and operation, if any.
+pub fn get_last_error_detail() -> Option<ErrorDetail> {
     LAST_ERROR.with(|e| e.borrow().clone())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_attribute_only_fresh_messages() {
+        set_last_error("Cannot broadcast shapes [2] and [3]");
+        record_failure(ERR_SHAPE, "add");
+        let detail = get_last_error_detail().unwrap();
+        assert_eq!(detail.code, ERR_SHAPE);
+        assert_eq!(detail.operation, Some("add"));
+        assert_eq!(detail.message, "Cannot broadcast shapes [2] and [3]");
+
+        record_failure(ERR_GENERIC, "matmul");
+        let detail = get_last_error_detail().unwrap();
+        assert_eq!(detail.operation, Some("matmul"));
+        assert_eq!(get_last_error_message(), None);
+    }
+}
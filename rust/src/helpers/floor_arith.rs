@@ -0,0 +1,157 @@
+//! Floor division and modulo for real scalar types.
+//!
+//! `floor_div` and `py_mod` follow Python (and NumPy `floor_divide` /
+//! `remainder`): the quotient rounds toward negative infinity and the
+//! remainder takes the sign of the divisor, so `a == floor_div(a, b) * b +
+//! py_mod(a, b)`. `fmod` is the C remainder, which takes the sign of the
+//! dividend. Integer operations return `None` on division by zero (and on
+//! `MIN / -1` overflow); float operations follow IEEE 754 instead.
+
+use num_traits::Float;
+
+pub trait FloorArith: Copy {
+    fn floor_div(a: Self, b: Self) -> Option<Self>;
+    fn py_mod(a: Self, b: Self) -> Option<Self>;
+    fn fmod(a: Self, b: Self) -> Option<Self>;
+}
+
+macro_rules! impl_floor_signed {
+    ($($t:ty),*) => {$(
+        impl FloorArith for $t {
+            #[inline(always)]
+            fn floor_div(a: Self, b: Self) -> Option<Self> {
+                let q = a.checked_div(b)?;
+                if a % b != 0 && ((a < 0) != (b < 0)) {
+                    Some(q - 1)
+                } else {
+                    Some(q)
+                }
+            }
+
+            #[inline(always)]
+            fn py_mod(a: Self, b: Self) -> Option<Self> {
+                if b == 0 {
+                    return None;
+                }
+                let r = a.wrapping_rem(b);
+                if r != 0 && ((r < 0) != (b < 0)) {
+                    Some(r + b)
+                } else {
+                    Some(r)
+                }
+            }
+
+            #[inline(always)]
+            fn fmod(a: Self, b: Self) -> Option<Self> {
+                if b == 0 {
+                    return None;
+                }
+                Some(a.wrapping_rem(b))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_floor_unsigned {
+    ($($t:ty),*) => {$(
+        impl FloorArith for $t {
+            #[inline(always)]
+            fn floor_div(a: Self, b: Self) -> Option<Self> {
+                a.checked_div(b)
+            }
+
+            #[inline(always)]
+            fn py_mod(a: Self, b: Self) -> Option<Self> {
+                a.checked_rem(b)
+            }
+
+            #[inline(always)]
+            fn fmod(a: Self, b: Self) -> Option<Self> {
+                a.checked_rem(b)
+            }
+        }
+    )*};
+}
+
+/// NumPy's `npy_divmod`: floor quotient and sign-of-divisor remainder.
+#[inline(always)]
+fn float_divmod<F: Float>(a: F, b: F) -> (F, F) {
+    let mut m = a % b;
+    if b == F::zero() {
+        return (a / b, m);
+    }
+    let mut div = (a - m) / b;
+    if m != F::zero() {
+        if (b < F::zero()) != (m < F::zero()) {
+            m = m + b;
+            div = div - F::one();
+        }
+    } else {
+        m = F::zero().copysign(b);
+    }
+    let floordiv = if div != F::zero() {
+        let f = div.floor();
+        if div - f > F::one() / (F::one() + F::one()) {
+            f + F::one()
+        } else {
+            f
+        }
+    } else {
+        F::zero().copysign(a / b)
+    };
+    (floordiv, m)
+}
+
+macro_rules! impl_floor_float {
+    ($($t:ty),*) => {$(
+        impl FloorArith for $t {
+            #[inline(always)]
+            fn floor_div(a: Self, b: Self) -> Option<Self> {
+                Some(float_divmod(a, b).0)
+            }
+
+            #[inline(always)]
+            fn py_mod(a: Self, b: Self) -> Option<Self> {
+                Some(float_divmod(a, b).1)
+            }
+
+            #[inline(always)]
+            fn fmod(a: Self, b: Self) -> Option<Self> {
+                Some(a % b)
+            }
+        }
+    )*};
+}
+
+impl_floor_signed!(i8, i16, i32, i64);
+impl_floor_unsigned!(u8, u16, u32, u64);
+impl_floor_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_semantics_match_python() {
+        assert_eq!(i32::floor_div(7, -2), Some(-4));
+        assert_eq!(i32::floor_div(-7, 2), Some(-4));
+        assert_eq!(i32::floor_div(-8, 2), Some(-4));
+        assert_eq!(i32::py_mod(-7, 2), Some(1));
+        assert_eq!(i32::py_mod(7, -2), Some(-1));
+        assert_eq!(i32::fmod(-7, 2), Some(-1));
+        assert_eq!(i8::py_mod(i8::MIN, -1), Some(0));
+        assert_eq!(i8::floor_div(i8::MIN, -1), None);
+        assert_eq!(u8::floor_div(7, 0), None);
+        assert_eq!(i64::py_mod(7, 0), None);
+    }
+
+    #[test]
+    fn float_semantics_match_numpy() {
+        assert_eq!(f64::floor_div(7.5, -2.0), Some(-4.0));
+        assert_eq!(f64::py_mod(-7.5, 2.0), Some(0.5));
+        assert_eq!(f64::fmod(-7.5, 2.0), Some(-1.5));
+        assert_eq!(f64::py_mod(-0.0, 2.0), Some(0.0));
+        assert_eq!(f64::floor_div(1.0, 0.0), Some(f64::INFINITY));
+        assert!(f64::py_mod(1.0, 0.0).unwrap().is_nan());
+    }
+}
@@ -24,29 +24,65 @@
 /// normalize_axis(&[2, 3, 4], -1, false);  // Ok(2) - last axis
 /// normalize_axis(&[2, 3, 4], 3, false);   // Err - axis 3 out of bounds
 /// normalize_axis(&[2, 3, 4], 3, true);    // Ok(3) - valid for insert operations
+/// normalize_axis(&[], 0, false);          // Err - a 0-d array has no axes
 /// ```
 pub fn normalize_axis(shape: &[usize], axis: i32, allow_insert: bool) -> Result<usize, String> {
     let ndim = shape.len();
-    let max_axis = if allow_insert {
-        ndim
-    } else {
-        ndim.saturating_sub(1)
-    };
+    // Number of valid axis positions; a 0-d array has none unless inserting.
+    let bound = if allow_insert { ndim + 1 } else { ndim };
 
-    let axis_usize = if axis < 0 {
-        (ndim as i32 + axis) as usize
+    let resolved = if axis < 0 {
+        ndim as i64 + axis as i64
     } else {
-        axis as usize
+        axis as i64
     };
 
-    if axis_usize > max_axis {
+    if bound == 0 {
+        return Err(format!(
+            "Axis {} is out of bounds for array with 0 dimensions",
+            axis
+        ));
+    }
+
+    if resolved < 0 || resolved as usize >= bound {
         return Err(format!(
             "Axis {} is out of bounds for array with {} dimensions (valid range: 0 to {})",
-            axis, ndim, max_axis
+            axis,
+            ndim,
+            bound - 1
         ));
     }
 
-    Ok(axis_usize)
+    Ok(resolved as usize)
+}
+
+/// Validate and normalize a list of distinct axes.
+///
+/// Each axis is resolved with [`normalize_axis`]; an axis that appears twice
+/// (after resolving negatives) is rejected.
+///
+/// # Examples
+/// ```
+/// normalize_axes(&[2, 3, 4], &[0, -1]);  // Ok(vec![0, 2])
+/// normalize_axes(&[2, 3, 4], &[1, -2]);  // Err - axis 1 repeated
+/// ```
+pub fn normalize_axes(shape: &[usize], axes: &[i64]) -> Result<Vec<usize>, String> {
+    let mut out = Vec::with_capacity(axes.len());
+    for &axis in axes {
+        let axis = i32::try_from(axis).map_err(|_| {
+            format!(
+                "Axis {} is out of bounds for array with {} dimensions",
+                axis,
+                shape.len()
+            )
+        })?;
+        let resolved = normalize_axis(shape, axis, false)?;
+        if out.contains(&resolved) {
+            return Err(format!("Axis {} is repeated", resolved));
+        }
+        out.push(resolved);
+    }
+    Ok(out)
 }
 
 /// Normalize an element index within a specific axis/dimension.
@@ -82,3 +118,72 @@ pub fn normalize_index(idx: i64, len: usize) -> Result<usize, String> {
     }
     Ok(i as usize)
 }
+
+/// Resolve a batch of view-relative indices to storage flat indices.
+///
+/// Each entry of `indices` is either a logical C-order flat index
+/// (`index_ndim == 1`) or a full coordinate tuple (`index_ndim == shape.len()`),
+/// stored back to back. Negative components count from the end. Strides are
+/// element strides as stored in `ArrayMetadata` and may encode negative values.
+///
+/// # Returns
+/// * `Ok(Vec<usize>)` - One storage flat index per entry
+/// * `Err(String)` - Error message for the first out-of-bounds entry
+pub fn resolve_storage_indices(
+    shape: &[usize],
+    strides: &[usize],
+    offset: usize,
+    indices: &[i64],
+    index_ndim: usize,
+) -> Result<Vec<usize>, String> {
+    let ndim = shape.len();
+    let size: usize = shape.iter().product();
+    let mut out = Vec::with_capacity(indices.len() / index_ndim.max(1));
+
+    for entry in indices.chunks(index_ndim.max(1)) {
+        let mut storage = offset as isize;
+        if index_ndim == ndim && ndim != 1 {
+            for (axis, &idx) in entry.iter().enumerate() {
+                let i = normalize_index(idx, shape[axis])?;
+                storage += i as isize * strides[axis] as isize;
+            }
+        } else {
+            let mut remaining = normalize_index(entry[0], size)?;
+            for axis in (0..ndim).rev() {
+                storage += (remaining % shape[axis]) as isize * strides[axis] as isize;
+                remaining /= shape[axis];
+            }
+        }
+        out.push(storage as usize);
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_axes, normalize_axis};
+
+    #[test]
+    fn negative_axes_count_from_the_end() {
+        assert_eq!(normalize_axis(&[2, 3, 4], -1, false), Ok(2));
+        assert_eq!(normalize_axis(&[2, 3, 4], -3, false), Ok(0));
+        assert_eq!(normalize_axis(&[2, 3, 4], -1, true), Ok(2));
+        assert!(normalize_axis(&[2, 3, 4], -4, false).is_err());
+        assert!(normalize_axis(&[2, 3, 4], i32::MIN, false).is_err());
+    }
+
+    #[test]
+    fn zero_dim_arrays_have_no_axes() {
+        assert!(normalize_axis(&[], 0, false).is_err());
+        assert!(normalize_axis(&[], -1, false).is_err());
+        assert_eq!(normalize_axis(&[], 0, true), Ok(0));
+    }
+
+    #[test]
+    fn axis_lists_reject_repeats_after_normalization() {
+        assert_eq!(normalize_axes(&[2, 3, 4], &[0, -1]), Ok(vec![0, 2]));
+        assert!(normalize_axes(&[2, 3, 4], &[1, -2]).is_err());
+        assert!(normalize_axes(&[2, 3, 4], &[i64::MAX]).is_err());
+    }
+}
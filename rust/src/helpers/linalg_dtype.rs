@@ -4,8 +4,8 @@ use crate::types::dtype::DType;
 
 /// Dtype used for `matmul` / `dot` computation after [`DType::promote`].
 ///
-/// Integer dtypes are computed in `Float64` (values promoted via `extract_array_as_f64`).
-/// [`DType::Bool`] is unsupported.
+/// Integer dtypes are computed in `Float64` (values promoted via `extract_array_as_f64`)
+/// and half-precision dtypes in `Float32`. [`DType::Bool`] is unsupported.
 pub fn linalg_computation_dtype(promoted: DType) -> Option<DType> {
     match promoted {
         DType::Bool => None,
@@ -17,6 +17,7 @@ pub fn linalg_computation_dtype(promoted: DType) -> Option<DType> {
         | DType::Uint16
         | DType::Uint32
         | DType::Uint64 => Some(DType::Float64),
+        DType::Float16 | DType::BFloat16 => Some(DType::Float32),
         DType::Float32 | DType::Float64 | DType::Complex64 | DType::Complex128 => Some(promoted),
     }
 }
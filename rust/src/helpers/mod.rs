@@ -2,21 +2,26 @@
 //!
 //! This module provides view extraction helpers and output metadata utilities.
 
+pub mod cast_view;
 pub mod elementwise_minmax;
 pub mod error;
 pub mod fft_utils;
+pub mod floor_arith;
 pub mod indexing;
 pub mod linalg_dtype;
 pub mod output;
+pub mod overflow_arith;
+pub mod parallel;
 pub mod scalar;
 pub mod validation;
 pub mod view;
 
-pub use indexing::{normalize_axis, normalize_index};
+pub use indexing::{normalize_axes, normalize_axis, normalize_index, resolve_storage_indices};
 
+pub use cast_view::{CastTarget, CastView};
 pub use error::{
-    set_last_error, ERR_ALLOC, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_MATH, ERR_PANIC, ERR_SHAPE,
-    SUCCESS,
+    set_last_error, ERR_ALLOC, ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_MATH, ERR_NOT_IMPLEMENTED,
+    ERR_OVERFLOW, ERR_PANIC, ERR_SHAPE, ERR_SINGULAR_MATRIX, ERR_VALUE, SUCCESS,
 };
 pub use fft_utils::*;
 pub use linalg_dtype::linalg_computation_dtype;
@@ -0,0 +1,110 @@
+//! Integer add, subtract and multiply under an [`OverflowMode`].
+//!
+//! Each operation returns `None` only in `Error` mode, when the exact result
+//! does not fit the type. [`narrow`] applies the same policy to an exact
+//! `i128` result, for operands that do not fit the type themselves.
+
+use std::fmt::Display;
+
+use num_traits::Bounded;
+
+use crate::types::OverflowMode;
+
+pub trait OverflowArith: Copy + Display + Bounded + Into<i128> + TryFrom<i128> {
+    fn add_with(a: Self, b: Self, mode: OverflowMode) -> Option<Self>;
+    fn sub_with(a: Self, b: Self, mode: OverflowMode) -> Option<Self>;
+    fn mul_with(a: Self, b: Self, mode: OverflowMode) -> Option<Self>;
+    /// Truncate to `Self`, keeping the low bits (two's-complement wraparound).
+    fn wrap_from(v: i128) -> Self;
+}
+
+macro_rules! impl_overflow_arith {
+    ($($t:ty),*) => {$(
+        impl OverflowArith for $t {
+            #[inline(always)]
+            fn add_with(a: Self, b: Self, mode: OverflowMode) -> Option<Self> {
+                match mode {
+                    OverflowMode::Wrap => Some(a.wrapping_add(b)),
+                    OverflowMode::Saturate => Some(a.saturating_add(b)),
+                    OverflowMode::Error => a.checked_add(b),
+                }
+            }
+
+            #[inline(always)]
+            fn sub_with(a: Self, b: Self, mode: OverflowMode) -> Option<Self> {
+                match mode {
+                    OverflowMode::Wrap => Some(a.wrapping_sub(b)),
+                    OverflowMode::Saturate => Some(a.saturating_sub(b)),
+                    OverflowMode::Error => a.checked_sub(b),
+                }
+            }
+
+            #[inline(always)]
+            fn mul_with(a: Self, b: Self, mode: OverflowMode) -> Option<Self> {
+                match mode {
+                    OverflowMode::Wrap => Some(a.wrapping_mul(b)),
+                    OverflowMode::Saturate => Some(a.saturating_mul(b)),
+                    OverflowMode::Error => a.checked_mul(b),
+                }
+            }
+
+            #[inline(always)]
+            fn wrap_from(v: i128) -> Self {
+                v as $t
+            }
+        }
+    )*};
+}
+
+impl_overflow_arith!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Convert an exact result to `T` under `mode`.
+#[inline(always)]
+pub fn narrow<T: OverflowArith>(v: i128, mode: OverflowMode) -> Option<T> {
+    match mode {
+        OverflowMode::Wrap => Some(T::wrap_from(v)),
+        OverflowMode::Saturate => Some(T::try_from(v).unwrap_or_else(|_| {
+            if v < 0 {
+                T::min_value()
+            } else {
+                T::max_value()
+            }
+        })),
+        OverflowMode::Error => T::try_from(v).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modes_differ_only_on_overflow() {
+        for mode in [
+            OverflowMode::Wrap,
+            OverflowMode::Saturate,
+            OverflowMode::Error,
+        ] {
+            assert_eq!(i8::add_with(100, 20, mode), Some(120));
+        }
+        assert_eq!(i8::add_with(100, 100, OverflowMode::Wrap), Some(-56));
+        assert_eq!(i8::add_with(100, 100, OverflowMode::Saturate), Some(127));
+        assert_eq!(i8::add_with(100, 100, OverflowMode::Error), None);
+        assert_eq!(u8::sub_with(3, 5, OverflowMode::Saturate), Some(0));
+        assert_eq!(
+            i64::mul_with(i64::MIN, -1, OverflowMode::Saturate),
+            Some(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn narrow_matches_native_ops() {
+        assert_eq!(narrow::<u8>(258, OverflowMode::Wrap), Some(2));
+        assert_eq!(
+            narrow::<i16>(-40_000, OverflowMode::Saturate),
+            Some(i16::MIN)
+        );
+        assert_eq!(narrow::<u32>(-1, OverflowMode::Error), None);
+        assert_eq!(narrow::<i64>(42, OverflowMode::Error), Some(42));
+    }
+}
@@ -0,0 +1,157 @@
+//! Multi-threaded execution for large arrays.
+//!
+//! With the `parallel` cargo feature, element-wise kernels, float sums and
+//! flat sorts switch to rayon once an array holds at least
+//! [`parallel_threshold`] elements. Smaller arrays stay on the calling thread,
+//! where the cost of splitting work would outweigh the gain. Without the
+//! feature every helper here runs serially and the thread count is always 1.
+//!
+//! Work runs on a crate-owned pool rather than rayon's global one, so the
+//! thread count can be changed more than once per process.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ndarray::{ArrayBase, ArrayD, Data, IxDyn};
+
+/// Element count at which kernels start splitting work across threads.
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1 << 20;
+
+static THRESHOLD: AtomicUsize = AtomicUsize::new(DEFAULT_PARALLEL_THRESHOLD);
+
+/// Set the element count at which kernels go parallel (0 restores the default).
+pub fn set_parallel_threshold(threshold: usize) {
+    let threshold = if threshold == 0 {
+        DEFAULT_PARALLEL_THRESHOLD
+    } else {
+        threshold
+    };
+    THRESHOLD.store(threshold, Ordering::Relaxed);
+}
+
+/// Current element count at which kernels go parallel.
+pub fn parallel_threshold() -> usize {
+    THRESHOLD.load(Ordering::Relaxed)
+}
+
+/// Whether an operation over `len` elements should use the thread pool.
+#[inline]
+pub fn should_parallelize(len: usize) -> bool {
+    cfg!(feature = "parallel") && len >= parallel_threshold()
+}
+
+#[cfg(feature = "parallel")]
+mod pool {
+    use std::sync::Arc;
+
+    use parking_lot::RwLock;
+    use rayon::{ThreadPool, ThreadPoolBuilder};
+
+    static POOL: RwLock<Option<Arc<ThreadPool>>> = RwLock::new(None);
+
+    fn build(num_threads: usize) -> Result<ThreadPool, String> {
+        ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .thread_name(|i| format!("ndarray-php-{}", i))
+            .build()
+            .map_err(|e| format!("Failed to build thread pool: {}", e))
+    }
+
+    /// The shared pool, built with rayon's default size on first use.
+    pub fn get() -> Arc<ThreadPool> {
+        if let Some(pool) = POOL.read().as_ref() {
+            return Arc::clone(pool);
+        }
+        let mut slot = POOL.write();
+        if slot.is_none() {
+            // Zero lets rayon pick (RAYON_NUM_THREADS or one per core).
+            let pool = build(0).expect("default rayon thread pool");
+            *slot = Some(Arc::new(pool));
+        }
+        Arc::clone(slot.as_ref().unwrap())
+    }
+
+    /// Replace the shared pool. Work already running keeps its old pool.
+    pub fn replace(num_threads: usize) -> Result<(), String> {
+        let pool = build(num_threads)?;
+        *POOL.write() = Some(Arc::new(pool));
+        Ok(())
+    }
+}
+
+/// Resize the thread pool used by parallel kernels (0 = one thread per core).
+///
+/// Without the `parallel` feature this is a no-op.
+pub fn set_num_threads(num_threads: usize) -> Result<(), String> {
+    #[cfg(feature = "parallel")]
+    {
+        pool::replace(num_threads)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        let _ = num_threads;
+        Ok(())
+    }
+}
+
+/// Number of threads parallel kernels use (1 without the `parallel` feature).
+pub fn num_threads() -> usize {
+    #[cfg(feature = "parallel")]
+    {
+        pool::get().current_num_threads()
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        1
+    }
+}
+
+/// Run `op` inside the thread pool.
+#[cfg(feature = "parallel")]
+pub fn install<R: Send>(op: impl FnOnce() -> R + Send) -> R {
+    pool::get().install(op)
+}
+
+/// Run two closures, potentially in parallel, and return both results.
+pub fn join<RA: Send, RB: Send>(
+    a: impl FnOnce() -> RA + Send,
+    b: impl FnOnce() -> RB + Send,
+) -> (RA, RB) {
+    #[cfg(feature = "parallel")]
+    {
+        install(|| rayon::join(a, b))
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        (a(), b())
+    }
+}
+
+/// `arr.mapv(f)`, split across threads for large arrays.
+pub fn mapv<A, B, S, F>(arr: &ArrayBase<S, IxDyn>, f: F) -> ArrayD<B>
+where
+    A: Copy + Send + Sync,
+    S: Data<Elem = A> + Sync,
+    B: Send,
+    F: Fn(A) -> B + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if should_parallelize(arr.len()) {
+        return install(|| ndarray::Zip::from(arr).par_map_collect(|&x| f(x)));
+    }
+    arr.mapv(f)
+}
+
+/// `arr.mapv_into(f)`, split across threads for large arrays.
+pub fn mapv_into<A, F>(mut arr: ArrayD<A>, f: F) -> ArrayD<A>
+where
+    A: Copy + Send + Sync,
+    F: Fn(A) -> A + Send + Sync,
+{
+    #[cfg(feature = "parallel")]
+    if should_parallelize(arr.len()) {
+        install(|| arr.par_mapv_inplace(f));
+        return arr;
+    }
+    arr.mapv_inplace(f);
+    arr
+}
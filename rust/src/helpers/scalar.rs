@@ -4,6 +4,7 @@
 //! given its `DType`, and cast it to the target type.
 
 use crate::types::dtype::DType;
+use crate::types::half::{f32_to_half, half_to_f32};
 
 /// Read a scalar from a void pointer as f64.
 pub unsafe fn get_scalar_as_f64(scalar: *const std::ffi::c_void, scalar_dtype: DType) -> f64 {
@@ -19,6 +20,9 @@ pub unsafe fn get_scalar_as_f64(scalar: *const std::ffi::c_void, scalar_dtype: D
         DType::Uint16 => *(scalar as *const u16) as f64,
         DType::Uint8 => *(scalar as *const u8) as f64,
         DType::Bool => (*(scalar as *const u8) != 0) as i32 as f64,
+        DType::Float16 | DType::BFloat16 => {
+            half_to_f32(scalar_dtype, *(scalar as *const u16)) as f64
+        }
         _ => panic!("Invalid scalar dtype for f64 output: {:?}", scalar_dtype),
     }
 }
@@ -37,6 +41,7 @@ pub unsafe fn get_scalar_as_f32(scalar: *const std::ffi::c_void, scalar_dtype: D
         DType::Uint16 => *(scalar as *const u16) as f32,
         DType::Uint8 => *(scalar as *const u8) as f32,
         DType::Bool => (*(scalar as *const u8) != 0) as i32 as f32,
+        DType::Float16 | DType::BFloat16 => half_to_f32(scalar_dtype, *(scalar as *const u16)),
         _ => panic!("Invalid scalar dtype for f32 output: {:?}", scalar_dtype),
     }
 }
@@ -206,6 +211,9 @@ pub unsafe fn get_scalar_as_c64(
         DType::Bool => {
             num_complex::Complex32::new((*(scalar as *const u8) != 0) as i32 as f32, 0.0)
         }
+        DType::Float16 | DType::BFloat16 => {
+            num_complex::Complex32::new(half_to_f32(scalar_dtype, *(scalar as *const u16)), 0.0)
+        }
     }
 }
 
@@ -233,5 +241,33 @@ pub unsafe fn get_scalar_as_c128(
         DType::Bool => {
             num_complex::Complex64::new((*(scalar as *const u8) != 0) as i32 as f64, 0.0)
         }
+        DType::Float16 | DType::BFloat16 => num_complex::Complex64::new(
+            half_to_f32(scalar_dtype, *(scalar as *const u16)) as f64,
+            0.0,
+        ),
+    }
+}
+
+/// Read a scalar from a void pointer as Float16 bits.
+pub unsafe fn get_scalar_as_f16(scalar: *const std::ffi::c_void, scalar_dtype: DType) -> u16 {
+    match scalar_dtype {
+        DType::Float16 => *(scalar as *const u16),
+        _ => f32_to_half(DType::Float16, get_scalar_as_f32(scalar, scalar_dtype)),
+    }
+}
+
+/// Read a scalar from a void pointer as BFloat16 bits.
+pub unsafe fn get_scalar_as_bf16(scalar: *const std::ffi::c_void, scalar_dtype: DType) -> u16 {
+    match scalar_dtype {
+        DType::BFloat16 => *(scalar as *const u16),
+        _ => f32_to_half(DType::BFloat16, get_scalar_as_f32(scalar, scalar_dtype)),
+    }
+}
+
+/// Read a scalar from a void pointer as a Bool element (non-zero is true).
+pub unsafe fn get_scalar_as_bool(scalar: *const std::ffi::c_void, scalar_dtype: DType) -> u8 {
+    match scalar_dtype {
+        DType::Bool => (*(scalar as *const u8) != 0) as u8,
+        _ => (get_scalar_as_f64(scalar, scalar_dtype) != 0.0) as u8,
     }
 }
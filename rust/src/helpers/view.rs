@@ -7,8 +7,10 @@ use crate::define_extract_array;
 use crate::define_extract_array_as;
 use crate::define_extract_view;
 use crate::define_extract_view_mut;
-use crate::types::ArrayData;
-use ndarray::ShapeBuilder;
+use crate::types::dtype::DType;
+use crate::types::half::{bf16_to_f32, f16_to_f32, f32_to_bf16, f32_to_f16};
+use crate::types::{ArrayData, ArrayMetadata};
+use ndarray::{ArrayViewD, ArrayViewMutD, Axis, IxDyn, ShapeBuilder};
 
 // Generate `extract_array` functions for all types (immutable)
 define_extract_array!(extract_array_f64, ArrayData::Float64, f64);
@@ -22,6 +24,8 @@ define_extract_array!(extract_array_u32, ArrayData::Uint32, u32);
 define_extract_array!(extract_array_u16, ArrayData::Uint16, u16);
 define_extract_array!(extract_array_u8, ArrayData::Uint8, u8);
 define_extract_array!(extract_array_bool, ArrayData::Bool, u8);
+define_extract_array!(extract_array_f16, ArrayData::Float16, u16);
+define_extract_array!(extract_array_bf16, ArrayData::BFloat16, u16);
 define_extract_array!(
     extract_array_c64,
     ArrayData::Complex64,
@@ -46,6 +50,8 @@ define_extract_view!(extract_view_u32, ArrayData::Uint32, u32);
 define_extract_view!(extract_view_u16, ArrayData::Uint16, u16);
 define_extract_view!(extract_view_u8, ArrayData::Uint8, u8);
 define_extract_view!(extract_view_bool, ArrayData::Bool, u8);
+define_extract_view!(extract_view_f16, ArrayData::Float16, u16);
+define_extract_view!(extract_view_bf16, ArrayData::BFloat16, u16);
 define_extract_view!(
     extract_view_c64,
     ArrayData::Complex64,
@@ -69,6 +75,8 @@ define_extract_view_mut!(extract_view_mut_u32, ArrayData::Uint32, u32);
 define_extract_view_mut!(extract_view_mut_u16, ArrayData::Uint16, u16);
 define_extract_view_mut!(extract_view_mut_u8, ArrayData::Uint8, u8);
 define_extract_view_mut!(extract_view_mut_bool, ArrayData::Bool, u8);
+define_extract_view_mut!(extract_view_mut_f16, ArrayData::Float16, u16);
+define_extract_view_mut!(extract_view_mut_bf16, ArrayData::BFloat16, u16);
 define_extract_view_mut!(
     extract_view_mut_c64,
     ArrayData::Complex64,
@@ -80,6 +88,42 @@ define_extract_view_mut!(
     num_complex::Complex64
 );
 
+/// Raw bit patterns of a Float16 or BFloat16 array (owned, C order).
+///
+/// Data-movement operations (copies, reshapes, gathers) work on the bits
+/// directly; only arithmetic needs to widen through `f32`.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub unsafe fn extract_array_half_bits(
+    wrapper: &crate::types::NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Option<ndarray::ArrayD<u16>> {
+    extract_array_f16(wrapper, meta).or_else(|| extract_array_bf16(wrapper, meta))
+}
+
+/// Zero-copy view of the bit patterns of a Float16 or BFloat16 array.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub unsafe fn extract_view_half_bits<'a>(
+    wrapper: &'a crate::types::NDArrayWrapper,
+    meta: &'a ArrayMetadata,
+) -> Option<ArrayViewD<'a, u16>> {
+    extract_view_f16(wrapper, meta).or_else(|| extract_view_bf16(wrapper, meta))
+}
+
+/// Mutable view of the bit patterns of a Float16 or BFloat16 array.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub unsafe fn extract_view_mut_half_bits<'a>(
+    wrapper: &'a crate::types::NDArrayWrapper,
+    meta: &'a ArrayMetadata,
+) -> Option<ArrayViewMutD<'a, u16>> {
+    extract_view_mut_f16(wrapper, meta).or_else(|| extract_view_mut_bf16(wrapper, meta))
+}
+
 // Generate `extract_array_as` functions for all types
 define_extract_array_as!(
     extract_array_as_f64,
@@ -98,6 +142,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as i32 as f64),
         (extract_array_c128, |x: num_complex::Complex64| x.re),
         (extract_array_c64, |x: num_complex::Complex32| x.re as f64),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as f64),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as f64),
     ]
 );
 
@@ -118,6 +164,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as i32 as f32),
         (extract_array_c128, |x: num_complex::Complex64| x.re as f32),
         (extract_array_c64, |x: num_complex::Complex32| x.re),
+        (extract_array_f16, f16_to_f32),
+        (extract_array_bf16, bf16_to_f32),
     ]
 );
 
@@ -138,6 +186,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as i64),
         (extract_array_c128, |x: num_complex::Complex64| x.re as i64),
         (extract_array_c64, |x: num_complex::Complex32| x.re as i64),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as i64),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as i64),
     ]
 );
 
@@ -158,6 +208,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as i32),
         (extract_array_c128, |x: num_complex::Complex64| x.re as i32),
         (extract_array_c64, |x: num_complex::Complex32| x.re as i32),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as i32),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as i32),
     ]
 );
 
@@ -178,6 +230,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as i16),
         (extract_array_c128, |x: num_complex::Complex64| x.re as i16),
         (extract_array_c64, |x: num_complex::Complex32| x.re as i16),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as i16),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as i16),
     ]
 );
 
@@ -198,6 +252,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as i8),
         (extract_array_c128, |x: num_complex::Complex64| x.re as i8),
         (extract_array_c64, |x: num_complex::Complex32| x.re as i8),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as i8),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as i8),
     ]
 );
 
@@ -218,6 +274,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as u64),
         (extract_array_c128, |x: num_complex::Complex64| x.re as u64),
         (extract_array_c64, |x: num_complex::Complex32| x.re as u64),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as u64),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as u64),
     ]
 );
 
@@ -238,6 +296,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as u32),
         (extract_array_c128, |x: num_complex::Complex64| x.re as u32),
         (extract_array_c64, |x: num_complex::Complex32| x.re as u32),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as u32),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as u32),
     ]
 );
 
@@ -258,6 +318,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| (x != 0) as u16),
         (extract_array_c128, |x: num_complex::Complex64| x.re as u16),
         (extract_array_c64, |x: num_complex::Complex32| x.re as u16),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as u16),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as u16),
     ]
 );
 
@@ -278,6 +340,8 @@ define_extract_array_as!(
         (extract_array_bool, |x: u8| x),
         (extract_array_c128, |x: num_complex::Complex64| x.re as u8),
         (extract_array_c64, |x: num_complex::Complex32| x.re as u8),
+        (extract_array_f16, |x: u16| f16_to_f32(x) as u8),
+        (extract_array_bf16, |x: u16| bf16_to_f32(x) as u8),
     ]
 );
 
@@ -310,6 +374,16 @@ define_extract_array_as!(
                 0
             }
         }),
+        (extract_array_f16, |x: u16| if f16_to_f32(x) != 0.0 {
+            1
+        } else {
+            0
+        }),
+        (extract_array_bf16, |x: u16| if bf16_to_f32(x) != 0.0 {
+            1
+        } else {
+            0
+        }),
     ]
 );
 
@@ -355,6 +429,14 @@ define_extract_array_as!(
             (x != 0) as i32 as f32,
             0.0
         )),
+        (extract_array_f16, |x: u16| num_complex::Complex32::new(
+            f16_to_f32(x),
+            0.0
+        )),
+        (extract_array_bf16, |x: u16| num_complex::Complex32::new(
+            bf16_to_f32(x),
+            0.0
+        )),
     ]
 );
 
@@ -400,9 +482,116 @@ define_extract_array_as!(
             (x != 0) as i32 as f64,
             0.0
         )),
+        (extract_array_f16, |x: u16| num_complex::Complex64::new(
+            f16_to_f32(x) as f64,
+            0.0
+        )),
+        (extract_array_bf16, |x: u16| num_complex::Complex64::new(
+            bf16_to_f32(x) as f64,
+            0.0
+        )),
     ]
 );
 
+/// Convert any array to Float16 bit patterns, rounding through `f32`.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub unsafe fn extract_array_as_f16(
+    wrapper: &crate::types::NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Option<ndarray::ArrayD<u16>> {
+    if let Some(bits) = extract_array_f16(wrapper, meta) {
+        return Some(bits);
+    }
+    extract_array_as_f32(wrapper, meta).map(|arr| arr.mapv(f32_to_f16))
+}
+
+/// Convert any array to BFloat16 bit patterns, rounding through `f32`.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub unsafe fn extract_array_as_bf16(
+    wrapper: &crate::types::NDArrayWrapper,
+    meta: &ArrayMetadata,
+) -> Option<ndarray::ArrayD<u16>> {
+    if let Some(bits) = extract_array_bf16(wrapper, meta) {
+        return Some(bits);
+    }
+    extract_array_as_f32(wrapper, meta).map(|arr| arr.mapv(f32_to_bf16))
+}
+
+/// Convert any array to bit patterns of the half-precision `dtype`.
+///
+/// # Safety
+/// `meta` must describe a valid layout within `wrapper`'s storage.
+pub unsafe fn extract_array_as_half(
+    wrapper: &crate::types::NDArrayWrapper,
+    meta: &ArrayMetadata,
+    dtype: DType,
+) -> Option<ndarray::ArrayD<u16>> {
+    if dtype == DType::BFloat16 {
+        extract_array_as_bf16(wrapper, meta)
+    } else {
+        extract_array_as_f16(wrapper, meta)
+    }
+}
+
+/// Rebase a possibly negatively strided layout onto its lowest address.
+///
+/// Returns the element offset of the lowest address, the absolute strides and
+/// the axes that must be inverted to restore the logical order.
+unsafe fn positive_layout(meta: &ArrayMetadata) -> (isize, Vec<usize>, Vec<usize>) {
+    let shape = meta.shape_slice();
+    let mut offset = meta.offset as isize;
+    let mut strides = Vec::with_capacity(shape.len());
+    let mut inverted = Vec::new();
+    let empty = shape.contains(&0);
+    for (axis, (&dim, &stride)) in shape.iter().zip(meta.strides_slice()).enumerate() {
+        let stride = stride as isize;
+        if stride < 0 && !empty {
+            offset += (dim as isize - 1) * stride;
+            inverted.push(axis);
+        }
+        strides.push(stride.unsigned_abs());
+    }
+    (offset, strides, inverted)
+}
+
+/// Build a view over `base` described by `meta`, including negative strides.
+///
+/// # Safety
+/// `base` must point to storage that covers every element addressed by `meta`
+/// and must stay valid (and unaliased by writers) for `'a`.
+pub unsafe fn strided_view<'a, T>(base: *const T, meta: &ArrayMetadata) -> ArrayViewD<'a, T> {
+    let (offset, strides, inverted) = positive_layout(meta);
+    let mut view = ArrayViewD::from_shape_ptr(
+        IxDyn(meta.shape_slice()).strides(IxDyn(&strides)),
+        base.offset(offset),
+    );
+    for axis in inverted {
+        view.invert_axis(Axis(axis));
+    }
+    view
+}
+
+/// Mutable counterpart of [`strided_view`].
+///
+/// # Safety
+/// Same as [`strided_view`]; additionally no other reference to the addressed
+/// elements may exist for `'a`.
+pub unsafe fn strided_view_mut<'a, T>(base: *mut T, meta: &ArrayMetadata) -> ArrayViewMutD<'a, T> {
+    let (offset, strides, inverted) = positive_layout(meta);
+    let mut view = ArrayViewMutD::from_shape_ptr(
+        IxDyn(meta.shape_slice()).strides(IxDyn(&strides)),
+        base.offset(offset),
+    );
+    for axis in inverted {
+        view.invert_axis(Axis(axis));
+    }
+    view
+}
+
 /// Compute the broadcast shape for two arrays using NumPy-compatible rules.
 ///
 /// Compares shapes from the right; dimensions are compatible if equal or one is 1.
@@ -485,3 +674,32 @@ mod broadcast_tests {
         assert_eq!(broadcast_shape(&[3], &[2]), None);
     }
 }
+
+#[cfg(test)]
+mod strided_view_tests {
+    use super::{strided_view, strided_view_mut};
+    use crate::types::ArrayMetadata;
+
+    #[test]
+    fn negative_strides_walk_backwards() {
+        let data = [0, 1, 2, 3, 4, 5];
+        // data.reshape(2, 3)[::-1, ::-1]
+        let shape = [2usize, 3];
+        let strides = [-3isize as usize, -1isize as usize];
+        let meta = ArrayMetadata::new(5, shape.as_ptr(), strides.as_ptr(), 2);
+        let view = unsafe { strided_view(data.as_ptr(), &meta) };
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), [5, 4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn negative_strides_write_in_logical_order() {
+        let mut data = [0, 0, 0, 0, 0];
+        // data[::-2]
+        let shape = [3usize];
+        let strides = [-2isize as usize];
+        let meta = ArrayMetadata::new(4, shape.as_ptr(), strides.as_ptr(), 1);
+        let mut view = unsafe { strided_view_mut(data.as_mut_ptr(), &meta) };
+        view.assign(&ndarray::arr1(&[7, 8, 9]).into_dyn());
+        assert_eq!(data, [9, 0, 8, 0, 7]);
+    }
+}
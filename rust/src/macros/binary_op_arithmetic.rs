@@ -1,8 +1,10 @@
 //! Binary operation macro for arithmetic operations on NDArrayWrapper instances.
 //!
 //! This module provides the `binary_op_arithmetic` macro which:
-//! - Extracts views for native type pairs (zero-copy for matching types)
+//! - Reads matching dtypes through zero-copy strided views, so same-dtype
+//!   operations never leave their native representation or copy their inputs
 //! - Promotes mixed types and converts before operation
+//! - Computes half-precision results in f32 and rounds them back
 //! - Performs element-wise operations with broadcasting
 //! - Only works with numeric types and NOT Bool
 
@@ -17,12 +19,12 @@
 macro_rules! binary_op_arithmetic {
     ($a_wrapper:expr, $a_meta:expr, $b_wrapper:expr, $b_meta:expr, $fn:path) => {{
         use crate::helpers::{
-            extract_array_as_f32, extract_array_as_f64, extract_array_as_i16, extract_array_as_i32,
-            extract_array_as_i64, extract_array_as_i8, extract_array_as_u16, extract_array_as_u32,
-            extract_array_as_u64, extract_array_as_u8, extract_array_c128, extract_array_c64,
-            extract_array_f32, extract_array_f64, extract_array_i16, extract_array_i32,
-            extract_array_i64, extract_array_i8, extract_array_u16, extract_array_u32,
-            extract_array_u64, extract_array_u8, set_last_error, ERR_GENERIC,
+            extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
+            extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+            extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+            extract_view_c128, extract_view_c64, extract_view_f32, extract_view_f64, extract_view_i16,
+            extract_view_i32, extract_view_i64, extract_view_i8, extract_view_u16, extract_view_u32,
+            extract_view_u64, extract_view_u8, set_last_error, ERR_DTYPE,
         };
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
@@ -32,13 +34,13 @@ macro_rules! binary_op_arithmetic {
         if $a_wrapper.dtype == $b_wrapper.dtype {
             match out_dtype {
                 DType::Float64 => {
-                    let Some(a_arr) = extract_array_f64($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_f64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Float64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_f64($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_f64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Float64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -49,13 +51,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Float32 => {
-                    let Some(a_arr) = extract_array_f32($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_f32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Float32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_f32($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_f32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Float32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -66,13 +68,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Complex64 => {
-                    let Some(a_arr) = extract_array_c64($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_c64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Complex64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_c64($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_c64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Complex64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -83,13 +85,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Complex128 => {
-                    let Some(a_arr) = extract_array_c128($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_c128($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Complex128 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_c128($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_c128($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Complex128 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -100,13 +102,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Int64 => {
-                    let Some(a_arr) = extract_array_i64($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_i64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_i64($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_i64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -117,13 +119,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Int32 => {
-                    let Some(a_arr) = extract_array_i32($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_i32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_i32($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_i32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -134,13 +136,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Int16 => {
-                    let Some(a_arr) = extract_array_i16($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_i16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int16 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_i16($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_i16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int16 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -151,13 +153,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Int8 => {
-                    let Some(a_arr) = extract_array_i8($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_i8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int8 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_i8($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_i8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int8 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -168,13 +170,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Uint64 => {
-                    let Some(a_arr) = extract_array_u64($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_u64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_u64($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_u64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -185,13 +187,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Uint32 => {
-                    let Some(a_arr) = extract_array_u32($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_u32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_u32($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_u32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -202,13 +204,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Uint16 => {
-                    let Some(a_arr) = extract_array_u16($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_u16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint16 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_u16($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_u16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint16 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -219,13 +221,13 @@ macro_rules! binary_op_arithmetic {
                     }
                 }
                 DType::Uint8 => {
-                    let Some(a_arr) = extract_array_u8($a_wrapper, $a_meta) else {
+                    let Some(a_arr) = extract_view_u8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint8 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
-                    let Some(b_arr) = extract_array_u8($b_wrapper, $b_meta) else {
+                    let Some(b_arr) = extract_view_u8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint8 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -235,9 +237,21 @@ macro_rules! binary_op_arithmetic {
                         dtype: DType::Uint8,
                     }
                 }
+                DType::Float16 | DType::BFloat16 => {
+                    let Some(a_arr) = extract_array_as_f32($a_wrapper, $a_meta) else {
+                        set_last_error("Failed to extract operand a as Float32".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let Some(b_arr) = extract_array_as_f32($b_wrapper, $b_meta) else {
+                        set_last_error("Failed to extract operand b as Float32".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
+                    NDArrayWrapper::from_f32_as_half(result, out_dtype)
+                }
                 DType::Bool => {
                     set_last_error("Arithmetic operations not supported for Bool type".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 }
             }
         } else {
@@ -245,11 +259,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Float64 => {
                     let Some(a_arr) = extract_array_as_f64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Float64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_f64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Float64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -262,11 +276,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Float32 => {
                     let Some(a_arr) = extract_array_as_f32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Float32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_f32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Float32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -276,18 +290,48 @@ macro_rules! binary_op_arithmetic {
                         dtype: DType::Float32,
                     }
                 }
-                DType::Complex64 | DType::Complex128 => {
-                    set_last_error("Mixed-type complex arithmetic not yet supported".to_string());
-                    return ERR_GENERIC;
+                DType::Complex64 => {
+                    let Some(a_arr) = extract_array_as_c64($a_wrapper, $a_meta) else {
+                        set_last_error("Failed to extract operand a as Complex64".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let Some(b_arr) = extract_array_as_c64($b_wrapper, $b_meta) else {
+                        set_last_error("Failed to extract operand b as Complex64".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
+                    NDArrayWrapper {
+                        data: ArrayData::Complex64(::std::sync::Arc::new(
+                            ::parking_lot::RwLock::new(result),
+                        )),
+                        dtype: DType::Complex64,
+                    }
+                }
+                DType::Complex128 => {
+                    let Some(a_arr) = extract_array_as_c128($a_wrapper, $a_meta) else {
+                        set_last_error("Failed to extract operand a as Complex128".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let Some(b_arr) = extract_array_as_c128($b_wrapper, $b_meta) else {
+                        set_last_error("Failed to extract operand b as Complex128".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
+                    NDArrayWrapper {
+                        data: ArrayData::Complex128(::std::sync::Arc::new(
+                            ::parking_lot::RwLock::new(result),
+                        )),
+                        dtype: DType::Complex128,
+                    }
                 }
                 DType::Int64 => {
                     let Some(a_arr) = extract_array_as_i64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -300,11 +344,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Int32 => {
                     let Some(a_arr) = extract_array_as_i32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -317,11 +361,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Int16 => {
                     let Some(a_arr) = extract_array_as_i16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -334,11 +378,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Int8 => {
                     let Some(a_arr) = extract_array_as_i8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -351,11 +395,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Uint64 => {
                     let Some(a_arr) = extract_array_as_u64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -368,11 +412,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Uint32 => {
                     let Some(a_arr) = extract_array_as_u32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -385,11 +429,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Uint16 => {
                     let Some(a_arr) = extract_array_as_u16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -402,11 +446,11 @@ macro_rules! binary_op_arithmetic {
                 DType::Uint8 => {
                     let Some(a_arr) = extract_array_as_u8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -416,9 +460,21 @@ macro_rules! binary_op_arithmetic {
                         dtype: DType::Uint8,
                     }
                 }
+                DType::Float16 | DType::BFloat16 => {
+                    let Some(a_arr) = extract_array_as_f32($a_wrapper, $a_meta) else {
+                        set_last_error("Failed to extract operand a as Float32".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let Some(b_arr) = extract_array_as_f32($b_wrapper, $b_meta) else {
+                        set_last_error("Failed to extract operand b as Float32".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
+                    NDArrayWrapper::from_f32_as_half(result, out_dtype)
+                }
                 DType::Bool => {
                     set_last_error("Arithmetic operations not supported for Bool type".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 }
             }
         }
@@ -21,9 +21,9 @@ macro_rules! binary_op_bitwise {
         use crate::helpers::{
             extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
             extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
-            extract_array_bool, extract_array_i16, extract_array_i32, extract_array_i64,
-            extract_array_i8, extract_array_u16, extract_array_u32, extract_array_u64,
-            extract_array_u8, set_last_error, ERR_GENERIC,
+            extract_array_bool, extract_array_i16, extract_array_i32, extract_array_i64, extract_array_i8,
+            extract_array_u16, extract_array_u32, extract_array_u64, extract_array_u8, set_last_error,
+            ERR_DTYPE,
         };
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
@@ -31,11 +31,16 @@ macro_rules! binary_op_bitwise {
         let out_dtype = DType::promote($a_wrapper.dtype, $b_wrapper.dtype);
 
         match out_dtype {
-            DType::Float64 | DType::Float32 | DType::Complex64 | DType::Complex128 => {
+            DType::Float64
+            | DType::Float32
+            | DType::Float16
+            | DType::BFloat16
+            | DType::Complex64
+            | DType::Complex128 => {
                 set_last_error(
                     "Bitwise operations not supported for float or complex types".to_string(),
                 );
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
             _ => {}
         }
@@ -45,11 +50,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int64 => {
                     let Some(a_arr) = extract_array_i64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -62,11 +67,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int32 => {
                     let Some(a_arr) = extract_array_i32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -79,11 +84,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int16 => {
                     let Some(a_arr) = extract_array_i16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int16 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int16 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -96,11 +101,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int8 => {
                     let Some(a_arr) = extract_array_i8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int8 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int8 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -113,11 +118,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint64 => {
                     let Some(a_arr) = extract_array_u64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -130,11 +135,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint32 => {
                     let Some(a_arr) = extract_array_u32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -147,11 +152,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint16 => {
                     let Some(a_arr) = extract_array_u16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint16 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint16 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -164,11 +169,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint8 => {
                     let Some(a_arr) = extract_array_u8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint8 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint8 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -181,11 +186,11 @@ macro_rules! binary_op_bitwise {
                 DType::Bool => {
                     let Some(a_arr) = extract_array_bool($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Bool operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_bool($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Bool operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -199,9 +204,9 @@ macro_rules! binary_op_bitwise {
                     set_last_error(
                         "Bitwise operations not supported for complex dtype".to_string(),
                     );
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 }
-                DType::Float64 | DType::Float32 => {
+                DType::Float64 | DType::Float32 | DType::Float16 | DType::BFloat16 => {
                     unreachable!("Float types already rejected");
                 }
             }
@@ -210,11 +215,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int64 => {
                     let Some(a_arr) = extract_array_as_i64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -227,11 +232,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int32 => {
                     let Some(a_arr) = extract_array_as_i32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -244,11 +249,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int16 => {
                     let Some(a_arr) = extract_array_as_i16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -261,11 +266,11 @@ macro_rules! binary_op_bitwise {
                 DType::Int8 => {
                     let Some(a_arr) = extract_array_as_i8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -278,11 +283,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint64 => {
                     let Some(a_arr) = extract_array_as_u64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -295,11 +300,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint32 => {
                     let Some(a_arr) = extract_array_as_u32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -312,11 +317,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint16 => {
                     let Some(a_arr) = extract_array_as_u16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -329,11 +334,11 @@ macro_rules! binary_op_bitwise {
                 DType::Uint8 => {
                     let Some(a_arr) = extract_array_as_u8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $fn);
                     NDArrayWrapper {
@@ -348,15 +353,15 @@ macro_rules! binary_op_bitwise {
                         "Bitwise operations on Bool arrays should be handled separately"
                             .to_string(),
                     );
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 }
                 DType::Complex64 | DType::Complex128 => {
                     set_last_error(
                         "Bitwise operations not supported for complex dtype".to_string(),
                     );
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 }
-                DType::Float64 | DType::Float32 => {
+                DType::Float64 | DType::Float32 | DType::Float16 | DType::BFloat16 => {
                     unreachable!("Float types already rejected");
                 }
             }
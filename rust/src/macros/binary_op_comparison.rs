@@ -17,11 +17,11 @@ macro_rules! binary_op_comparison_complex_same {
     (equality, $cmp_op:ident, $a:expr, $a_meta:expr, $b:expr, $b_meta:expr, C64) => {{
         let Some(a_arr) = extract_array_c64($a, $a_meta) else {
             set_last_error("Failed to extract Complex64 operand a".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let Some(b_arr) = extract_array_c64($b, $b_meta) else {
             set_last_error("Failed to extract Complex64 operand b".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
         NDArrayWrapper {
@@ -32,11 +32,11 @@ macro_rules! binary_op_comparison_complex_same {
     (equality, $cmp_op:ident, $a:expr, $a_meta:expr, $b:expr, $b_meta:expr, C128) => {{
         let Some(a_arr) = extract_array_c128($a, $a_meta) else {
             set_last_error("Failed to extract Complex128 operand a".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let Some(b_arr) = extract_array_c128($b, $b_meta) else {
             set_last_error("Failed to extract Complex128 operand b".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
         NDArrayWrapper {
@@ -48,13 +48,13 @@ macro_rules! binary_op_comparison_complex_same {
         set_last_error(
             "ordering comparison (<, <=, >, >=) is not defined for complex dtypes".to_string(),
         );
-        return ERR_GENERIC;
+        return ERR_DTYPE;
     }};
     (ordering, $cmp_op:ident, $a:expr, $a_meta:expr, $b:expr, $b_meta:expr, C128) => {{
         set_last_error(
             "ordering comparison (<, <=, >, >=) is not defined for complex dtypes".to_string(),
         );
-        return ERR_GENERIC;
+        return ERR_DTYPE;
     }};
 }
 
@@ -65,11 +65,11 @@ macro_rules! binary_op_comparison_complex_mixed {
     (equality, $cmp_op:ident, $a:expr, $a_meta:expr, $b:expr, $b_meta:expr, C64) => {{
         let Some(a_arr) = extract_array_as_c64($a, $a_meta) else {
             set_last_error("Failed to extract operand a as Complex64".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let Some(b_arr) = extract_array_as_c64($b, $b_meta) else {
             set_last_error("Failed to extract operand b as Complex64".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
         NDArrayWrapper {
@@ -80,11 +80,11 @@ macro_rules! binary_op_comparison_complex_mixed {
     (equality, $cmp_op:ident, $a:expr, $a_meta:expr, $b:expr, $b_meta:expr, C128) => {{
         let Some(a_arr) = extract_array_as_c128($a, $a_meta) else {
             set_last_error("Failed to extract operand a as Complex128".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let Some(b_arr) = extract_array_as_c128($b, $b_meta) else {
             set_last_error("Failed to extract operand b as Complex128".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         };
         let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
         NDArrayWrapper {
@@ -96,13 +96,13 @@ macro_rules! binary_op_comparison_complex_mixed {
         set_last_error(
             "ordering comparison (<, <=, >, >=) is not defined for complex dtypes".to_string(),
         );
-        return ERR_GENERIC;
+        return ERR_DTYPE;
     }};
     (ordering, $cmp_op:ident, $a:expr, $a_meta:expr, $b:expr, $b_meta:expr, C128) => {{
         set_last_error(
             "ordering comparison (<, <=, >, >=) is not defined for complex dtypes".to_string(),
         );
-        return ERR_GENERIC;
+        return ERR_DTYPE;
     }};
 }
 
@@ -124,7 +124,7 @@ macro_rules! binary_op_comparison {
             extract_array_as_u64, extract_array_as_u8, extract_array_bool, extract_array_c128,
             extract_array_c64, extract_array_f32, extract_array_f64, extract_array_i16,
             extract_array_i32, extract_array_i64, extract_array_i8, extract_array_u16,
-            extract_array_u32, extract_array_u64, extract_array_u8, set_last_error, ERR_GENERIC,
+            extract_array_u32, extract_array_u64, extract_array_u8, set_last_error, ERR_DTYPE, ERR_GENERIC,
         };
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
@@ -137,11 +137,11 @@ macro_rules! binary_op_comparison {
                 DType::Float64 => {
                     let Some(a_arr) = extract_array_f64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Float64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_f64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Float64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -154,11 +154,11 @@ macro_rules! binary_op_comparison {
                 DType::Float32 => {
                     let Some(a_arr) = extract_array_f32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Float32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_f32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Float32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -171,11 +171,11 @@ macro_rules! binary_op_comparison {
                 DType::Int64 => {
                     let Some(a_arr) = extract_array_i64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -188,11 +188,11 @@ macro_rules! binary_op_comparison {
                 DType::Int32 => {
                     let Some(a_arr) = extract_array_i32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -205,11 +205,11 @@ macro_rules! binary_op_comparison {
                 DType::Int16 => {
                     let Some(a_arr) = extract_array_i16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int16 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int16 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -222,11 +222,11 @@ macro_rules! binary_op_comparison {
                 DType::Int8 => {
                     let Some(a_arr) = extract_array_i8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Int8 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_i8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Int8 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -239,11 +239,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint64 => {
                     let Some(a_arr) = extract_array_u64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint64 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint64 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -256,11 +256,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint32 => {
                     let Some(a_arr) = extract_array_u32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint32 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint32 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -273,11 +273,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint16 => {
                     let Some(a_arr) = extract_array_u16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint16 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint16 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -290,11 +290,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint8 => {
                     let Some(a_arr) = extract_array_u8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Uint8 operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_u8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Uint8 operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -307,11 +307,28 @@ macro_rules! binary_op_comparison {
                 DType::Bool => {
                     let Some(a_arr) = extract_array_bool($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract Bool operand a".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_bool($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract Bool operand b".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
+                    };
+                    let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
+                    NDArrayWrapper {
+                        data: ArrayData::Bool(::std::sync::Arc::new(::parking_lot::RwLock::new(
+                            result,
+                        ))),
+                        dtype: DType::Bool,
+                    }
+                }
+                DType::Float16 | DType::BFloat16 => {
+                    let Some(a_arr) = extract_array_as_f32($a_wrapper, $a_meta) else {
+                        set_last_error("Failed to extract operand a as Float32".to_string());
+                        return ERR_DTYPE;
+                    };
+                    let Some(b_arr) = extract_array_as_f32($b_wrapper, $b_meta) else {
+                        set_last_error("Failed to extract operand b as Float32".to_string());
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -338,11 +355,11 @@ macro_rules! binary_op_comparison {
                 DType::Float64 => {
                     let Some(a_arr) = extract_array_as_f64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Float64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_f64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Float64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -352,14 +369,14 @@ macro_rules! binary_op_comparison {
                         dtype: DType::Bool,
                     }
                 }
-                DType::Float32 => {
+                DType::Float32 | DType::Float16 | DType::BFloat16 => {
                     let Some(a_arr) = extract_array_as_f32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Float32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_f32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Float32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -372,11 +389,11 @@ macro_rules! binary_op_comparison {
                 DType::Int64 => {
                     let Some(a_arr) = extract_array_as_i64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -389,11 +406,11 @@ macro_rules! binary_op_comparison {
                 DType::Int32 => {
                     let Some(a_arr) = extract_array_as_i32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -406,11 +423,11 @@ macro_rules! binary_op_comparison {
                 DType::Int16 => {
                     let Some(a_arr) = extract_array_as_i16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -423,11 +440,11 @@ macro_rules! binary_op_comparison {
                 DType::Int8 => {
                     let Some(a_arr) = extract_array_as_i8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_i8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Int8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -440,11 +457,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint64 => {
                     let Some(a_arr) = extract_array_as_u64($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u64($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint64".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -457,11 +474,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint32 => {
                     let Some(a_arr) = extract_array_as_u32($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u32($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint32".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -474,11 +491,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint16 => {
                     let Some(a_arr) = extract_array_as_u16($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u16($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint16".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -491,11 +508,11 @@ macro_rules! binary_op_comparison {
                 DType::Uint8 => {
                     let Some(a_arr) = extract_array_as_u8($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_u8($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Uint8".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -508,11 +525,11 @@ macro_rules! binary_op_comparison {
                 DType::Bool => {
                     let Some(a_arr) = extract_array_as_bool($a_wrapper, $a_meta) else {
                         set_last_error("Failed to extract operand a as Bool".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let Some(b_arr) = extract_array_as_bool($b_wrapper, $b_meta) else {
                         set_last_error("Failed to extract operand b as Bool".to_string());
-                        return ERR_GENERIC;
+                        return ERR_DTYPE;
                     };
                     let result = crate::broadcast_binary!(a_arr, b_arr, $cmp_op);
                     NDArrayWrapper {
@@ -17,9 +17,7 @@
 #[macro_export]
 macro_rules! binary_op_logical {
     ($a_wrapper:expr, $a_meta:expr, $b_wrapper:expr, $b_meta:expr, $logical_op:ident) => {{
-        use crate::helpers::{
-            extract_array_as_bool, extract_array_bool, set_last_error, ERR_GENERIC,
-        };
+        use crate::helpers::{extract_array_as_bool, extract_array_bool, set_last_error, ERR_DTYPE};
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
 
@@ -29,17 +27,17 @@ macro_rules! binary_op_logical {
             || $b_wrapper.dtype == DType::Complex128
         {
             set_last_error("Logical operations not supported for complex dtype".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
 
         if $a_wrapper.dtype == DType::Bool && $b_wrapper.dtype == DType::Bool {
             let Some(a_arr) = extract_array_bool($a_wrapper, $a_meta) else {
                 set_last_error("Failed to extract Bool operand a".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             };
             let Some(b_arr) = extract_array_bool($b_wrapper, $b_meta) else {
                 set_last_error("Failed to extract Bool operand b".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             };
             let result = crate::broadcast_binary!(a_arr, b_arr, $logical_op);
             NDArrayWrapper {
@@ -49,11 +47,11 @@ macro_rules! binary_op_logical {
         } else {
             let Some(a_arr) = extract_array_as_bool($a_wrapper, $a_meta) else {
                 set_last_error("Failed to extract operand a as Bool".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             };
             let Some(b_arr) = extract_array_as_bool($b_wrapper, $b_meta) else {
                 set_last_error("Failed to extract operand b as Bool".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             };
             let result = crate::broadcast_binary!(a_arr, b_arr, $logical_op);
             NDArrayWrapper {
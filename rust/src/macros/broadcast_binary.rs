@@ -1,4 +1,8 @@
 /// Broadcasts two ArrayBase instances and performs element-wise operation.
+///
+/// The result is always in standard (C) layout, whatever the operands'
+/// strides; callers describe it with default C-order metadata. With the
+/// `parallel` feature, large outputs are filled on the thread pool.
 #[macro_export]
 macro_rules! broadcast_binary {
     ($a:expr, $b:expr, $fn:path) => {{
@@ -33,6 +37,29 @@ macro_rules! broadcast_binary {
             }
         };
 
-        Zip::from(&a_bc).and(&b_bc).map_collect($fn)
+        let mut out = ndarray::ArrayD::uninit(ndarray::IxDyn(&broadcast_shape));
+        let parallel = crate::helpers::parallel::should_parallelize(out.len());
+        let zip = Zip::from(&mut out).and(&a_bc).and(&b_bc);
+        #[cfg(feature = "parallel")]
+        if parallel {
+            crate::helpers::parallel::install(|| {
+                zip.par_for_each(|o, a, b| {
+                    o.write($fn(a, b));
+                })
+            });
+        } else {
+            zip.for_each(|o, a, b| {
+                o.write($fn(a, b));
+            });
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            let _ = parallel;
+            zip.for_each(|o, a, b| {
+                o.write($fn(a, b));
+            });
+        }
+        // SAFETY: every element was written by the zip above.
+        unsafe { out.assume_init() }
     }};
 }
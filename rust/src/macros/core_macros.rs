@@ -20,6 +20,8 @@ macro_rules! match_array_data {
             $crate::types::ArrayData::Bool($arr) => $body,
             $crate::types::ArrayData::Complex64($arr) => $body,
             $crate::types::ArrayData::Complex128($arr) => $body,
+            $crate::types::ArrayData::Float16($arr) => $body,
+            $crate::types::ArrayData::BFloat16($arr) => $body,
         }
     };
 }
@@ -3,9 +3,13 @@
 //! Catches panics (e.g. from ndarray's unwrap on shape/index errors) and
 //! classifies them into the appropriate error code so PHP can throw
 //! the correct exception type. Suppresses the default panic output to stderr
-//! since we convert panics to error codes.
+//! since we convert panics to error codes. Failures are attributed to the
+//! operation named by the caller (the FFI function without its `ndarray_`
+//! prefix) so `ndarray_get_last_error_struct` can report it.
 
-use crate::helpers::error::{ERR_DTYPE, ERR_GENERIC, ERR_INDEX, ERR_MATH, ERR_PANIC, ERR_SHAPE};
+use crate::helpers::error::{
+    ERR_DTYPE, ERR_INDEX, ERR_MATH, ERR_NOT_IMPLEMENTED, ERR_PANIC, ERR_SHAPE,
+};
 
 /// Extract a string from a panic payload (Box<dyn Any + Send>).
 pub fn panic_payload_to_string(payload: Box<dyn std::any::Any + Send>) -> String {
@@ -74,7 +78,7 @@ pub fn classify_panic_message(msg: &str) -> (i32, String) {
 
     // Unsupported (ndarray ErrorKind::Unsupported)
     if msg_lower.contains("unsupported") {
-        return (ERR_GENERIC, "unsupported operation".to_string());
+        return (ERR_NOT_IMPLEMENTED, "unsupported operation".to_string());
     }
 
     // Fallback: generic panic
@@ -83,13 +87,13 @@ pub fn classify_panic_message(msg: &str) -> (i32, String) {
 
 #[macro_export]
 macro_rules! ffi_guard {
-    ($body:block) => {{
+    ($operation:expr, $body:block) => {{
         let prev_hook = std::panic::take_hook();
         std::panic::set_hook(Box::new(|_| {}));
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| $body));
         let _ = std::panic::take_hook();
         std::panic::set_hook(prev_hook);
-        match result {
+        let code = match result {
             Ok(res) => res,
             Err(payload) => {
                 let msg = $crate::macros::ffi_guard::panic_payload_to_string(payload);
@@ -97,6 +101,27 @@ macro_rules! ffi_guard {
                 $crate::helpers::error::set_last_error(display_msg);
                 code
             }
+        };
+        if code != $crate::helpers::error::SUCCESS {
+            $crate::helpers::error::record_failure(code, $operation);
         }
+        code
     }};
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::helpers::error::{get_last_error_detail, set_last_error, ERR_VALUE};
+
+    #[test]
+    fn failures_are_recorded_under_the_given_operation() {
+        let code = crate::ffi_guard!("choice", {
+            set_last_error("choice: cannot take 5 samples without replacement from 3");
+            ERR_VALUE
+        });
+        assert_eq!(code, ERR_VALUE);
+        let detail = get_last_error_detail().unwrap();
+        assert_eq!(detail.code, ERR_VALUE);
+        assert_eq!(detail.operation, Some("choice"));
+    }
+}
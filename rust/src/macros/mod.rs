@@ -1,7 +1,8 @@
 //! Macros for array operations.
 //!
 //! This module contains all macros used for binary operations,
-//! scalar operations, comparisons, logical operations, and view extraction.
+//! scalar operations, comparisons, logical operations, unary dispatch and
+//! view extraction.
 //!
 //! Note: All macros are exported at the crate root via `#[macro_export]`,
 //! so they can be used directly as `crate::macro_name!` without importing.
@@ -16,4 +17,5 @@ pub mod ffi_guard;
 pub mod scalar_op_arithmetic;
 pub mod scalar_op_bitwise;
 pub mod scalar_op_comparison;
+pub mod unary_op_native;
 pub mod view;
@@ -2,30 +2,39 @@
 //!
 //! This module provides the `scalar_op_arithmetic` macro which:
 //! - Computes the promoted dtype from array and scalar dtypes (NumPy-style type promotion)
-//! - Extracts the array as the promoted dtype using extract_view_as_* helpers
+//! - Extracts the array as the promoted dtype using extract_array_as_* helpers
 //! - Reads and casts the scalar value using get_scalar_as_* helpers
-//! - Performs element-wise scalar operation
+//! - Performs the element-wise scalar operation in place on that owned copy
+//!   (half-precision arrays compute in f32 and round back), splitting large
+//!   arrays across threads with the `parallel` feature
 //! - Only works with numeric types and NOT Bool
 //!
+//! Prefix the operator with `rev` to put the scalar on the left (`s - x`).
+//!
 //! Usage:
 //! ```rust
 //! let result = scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, +);
+//! let result = scalar_op_arithmetic!(a_wrapper, a_meta, scalar, scalar_dtype, rev -);
 //! ```
 
 #[macro_export]
 macro_rules! scalar_op_arithmetic {
-    ($wrapper:expr, $meta:expr, $scalar:expr, $scalar_dtype:expr, $op:tt) => {{
+    (@apply fwd $x:ident $op:tt $s:ident) => {
+        $x $op $s
+    };
+    (@apply rev $x:ident $op:tt $s:ident) => {
+        $s $op $x
+    };
+    (@impl $dir:ident, $wrapper:expr, $meta:expr, $scalar:expr, $scalar_dtype:expr, $op:tt) => {{
         use crate::helpers::{
-            extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
-            extract_array_as_f64, extract_array_as_i16, extract_array_as_i32,
-            extract_array_as_i64, extract_array_as_i8, extract_array_as_u16,
-            extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
-            get_scalar_as_f64, get_scalar_as_f32, get_scalar_as_i64,
-            get_scalar_as_i32, get_scalar_as_i16, get_scalar_as_i8,
-            get_scalar_as_u64, get_scalar_as_u32, get_scalar_as_u16,
-            get_scalar_as_u8, get_scalar_as_c64, get_scalar_as_c128,
-            set_last_error, ERR_GENERIC,
+            extract_array_as_c128, extract_array_as_c64, extract_array_as_f32, extract_array_as_f64,
+            extract_array_as_i16, extract_array_as_i32, extract_array_as_i64, extract_array_as_i8,
+            extract_array_as_u16, extract_array_as_u32, extract_array_as_u64, extract_array_as_u8,
+            get_scalar_as_c128, get_scalar_as_c64, get_scalar_as_f32, get_scalar_as_f64, get_scalar_as_i16,
+            get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8, get_scalar_as_u16, get_scalar_as_u32,
+            get_scalar_as_u64, get_scalar_as_u8, set_last_error, ERR_DTYPE,
         };
+        use crate::helpers::parallel::mapv_into;
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
 
@@ -36,10 +45,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Float64 => {
                 let Some(arr) = extract_array_as_f64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Float64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_f64($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Float64(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -50,10 +59,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Float32 => {
                 let Some(arr) = extract_array_as_f32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Float32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_f32($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Float32(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -64,10 +73,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Int64 => {
                 let Some(arr) = extract_array_as_i64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i64($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Int64(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -78,10 +87,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Int32 => {
                 let Some(arr) = extract_array_as_i32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i32($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Int32(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -92,10 +101,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Int16 => {
                 let Some(arr) = extract_array_as_i16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i16($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Int16(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -106,10 +115,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Int8 => {
                 let Some(arr) = extract_array_as_i8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i8($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Int8(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -120,10 +129,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Uint64 => {
                 let Some(arr) = extract_array_as_u64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u64($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Uint64(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -134,10 +143,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Uint32 => {
                 let Some(arr) = extract_array_as_u32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u32($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Uint32(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -148,10 +157,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Uint16 => {
                 let Some(arr) = extract_array_as_u16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u16($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Uint16(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -162,10 +171,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Uint8 => {
                 let Some(arr) = extract_array_as_u8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Uint8(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -176,10 +185,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Complex64 => {
                 let Some(arr) = extract_array_as_c64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Complex64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_c64($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Complex64(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -190,10 +199,10 @@ macro_rules! scalar_op_arithmetic {
             DType::Complex128 => {
                 let Some(arr) = extract_array_as_c128($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Complex128".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_c128($scalar, $scalar_dtype) };
-                let result = arr.mapv(|x| x $op s);
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
                 NDArrayWrapper {
                     data: ArrayData::Complex128(::std::sync::Arc::new(::parking_lot::RwLock::new(
                         result,
@@ -201,12 +210,27 @@ macro_rules! scalar_op_arithmetic {
                     dtype: DType::Complex128,
                 }
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32($wrapper, $meta) else {
+                    set_last_error("Failed to extract array as Float32".to_string());
+                    return ERR_DTYPE;
+                };
+                let s = unsafe { get_scalar_as_f32($scalar, $scalar_dtype) };
+                let result = mapv_into(arr, |x| $crate::scalar_op_arithmetic!(@apply $dir x $op s));
+                NDArrayWrapper::from_f32_as_half(result, out_dtype)
+            }
             DType::Bool => {
                 set_last_error(
                     "Arithmetic operations not supported for Bool type".to_string(),
                 );
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         }
     }};
+    ($wrapper:expr, $meta:expr, $scalar:expr, $scalar_dtype:expr, rev $op:tt) => {
+        $crate::scalar_op_arithmetic!(@impl rev, $wrapper, $meta, $scalar, $scalar_dtype, $op)
+    };
+    ($wrapper:expr, $meta:expr, $scalar:expr, $scalar_dtype:expr, $op:tt) => {
+        $crate::scalar_op_arithmetic!(@impl fwd, $wrapper, $meta, $scalar, $scalar_dtype, $op)
+    };
 }
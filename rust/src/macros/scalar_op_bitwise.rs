@@ -37,9 +37,9 @@ macro_rules! scalar_op_bitwise_impl {
         use crate::helpers::{
             extract_array_as_bool, extract_array_as_i16, extract_array_as_i32, extract_array_as_i64,
             extract_array_as_i8, extract_array_as_u16, extract_array_as_u32, extract_array_as_u64,
-            extract_array_as_u8, get_scalar_as_i64, get_scalar_as_i32,
-            get_scalar_as_i16, get_scalar_as_i8, get_scalar_as_u64, get_scalar_as_u32,
-            get_scalar_as_u16, get_scalar_as_u8, set_last_error, ERR_GENERIC,
+            extract_array_as_u8, get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8,
+            get_scalar_as_u16, get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8, set_last_error,
+            ERR_DTYPE,
         };
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
@@ -48,19 +48,19 @@ macro_rules! scalar_op_bitwise_impl {
 
         if matches!(out_dtype, DType::Float32 | DType::Float64 | DType::Complex64 | DType::Complex128) {
             set_last_error("Bitwise operations not supported for float or complex types".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
 
         if out_dtype == DType::Bool && !$allow_bool {
             set_last_error("Shift operations only supported for integer types".to_string());
-            return ERR_GENERIC;
+            return ERR_DTYPE;
         }
 
         match out_dtype {
             DType::Bool => {
                 let Some(arr) = extract_array_as_bool($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Bool".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -72,7 +72,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Int64 => {
                 let Some(arr) = extract_array_as_i64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i64($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -84,7 +84,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Int32 => {
                 let Some(arr) = extract_array_as_i32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i32($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -96,7 +96,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Int16 => {
                 let Some(arr) = extract_array_as_i16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i16($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -108,7 +108,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Int8 => {
                 let Some(arr) = extract_array_as_i8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i8($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -120,7 +120,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Uint64 => {
                 let Some(arr) = extract_array_as_u64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u64($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -132,7 +132,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Uint32 => {
                 let Some(arr) = extract_array_as_u32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u32($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -144,7 +144,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Uint16 => {
                 let Some(arr) = extract_array_as_u16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u16($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -156,7 +156,7 @@ macro_rules! scalar_op_bitwise_impl {
             DType::Uint8 => {
                 let Some(arr) = extract_array_as_u8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
                 let result = arr.mapv(|x| x $op s);
@@ -167,7 +167,7 @@ macro_rules! scalar_op_bitwise_impl {
             }
             _ => {
                 set_last_error("Bitwise operations not supported for this dtype combination".to_string());
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         }
     }};
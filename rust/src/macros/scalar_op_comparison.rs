@@ -21,10 +21,9 @@ macro_rules! scalar_op_comparison {
             extract_array_as_bool, extract_array_as_c128, extract_array_as_c64, extract_array_as_f32,
             extract_array_as_f64, extract_array_as_i16, extract_array_as_i32, extract_array_as_i64,
             extract_array_as_i8, extract_array_as_u16, extract_array_as_u32, extract_array_as_u64,
-            extract_array_as_u8, get_scalar_as_f64, get_scalar_as_f32, get_scalar_as_i64,
-            get_scalar_as_i32, get_scalar_as_i16, get_scalar_as_i8, get_scalar_as_u64,
-            get_scalar_as_u32, get_scalar_as_u16, get_scalar_as_u8, get_scalar_as_c64,
-            get_scalar_as_c128, set_last_error, ERR_GENERIC,
+            extract_array_as_u8, get_scalar_as_c128, get_scalar_as_c64, get_scalar_as_f32,
+            get_scalar_as_f64, get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8,
+            get_scalar_as_u16, get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8, set_last_error,
         };
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
@@ -35,7 +34,7 @@ macro_rules! scalar_op_comparison {
             DType::Float64 => {
                 let Some(arr) = extract_array_as_f64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Float64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_f64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -43,15 +42,28 @@ macro_rules! scalar_op_comparison {
             DType::Float32 => {
                 let Some(arr) = extract_array_as_f32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Float32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_f32($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32($wrapper, $meta) else {
+                    set_last_error("Failed to extract array as Float32".to_string());
+                    return ERR_DTYPE;
+                };
+                // Round the scalar to the array's precision first, as NumPy does.
+                let s = unsafe { get_scalar_as_f32($scalar, $scalar_dtype) };
+                let s = $crate::types::half::half_to_f32(
+                    out_dtype,
+                    $crate::types::half::f32_to_half(out_dtype, s),
+                );
+                arr.mapv(|x| (x $cmp_op s) as u8)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_as_i64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -59,7 +71,7 @@ macro_rules! scalar_op_comparison {
             DType::Int32 => {
                 let Some(arr) = extract_array_as_i32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i32($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -67,7 +79,7 @@ macro_rules! scalar_op_comparison {
             DType::Int16 => {
                 let Some(arr) = extract_array_as_i16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i16($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -75,7 +87,7 @@ macro_rules! scalar_op_comparison {
             DType::Int8 => {
                 let Some(arr) = extract_array_as_i8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i8($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -83,7 +95,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint64 => {
                 let Some(arr) = extract_array_as_u64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -91,7 +103,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint32 => {
                 let Some(arr) = extract_array_as_u32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u32($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -99,7 +111,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint16 => {
                 let Some(arr) = extract_array_as_u16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u16($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -107,7 +119,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint8 => {
                 let Some(arr) = extract_array_as_u8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -115,7 +127,7 @@ macro_rules! scalar_op_comparison {
             DType::Bool => {
                 let Some(arr) = extract_array_as_bool($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Bool".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -123,7 +135,7 @@ macro_rules! scalar_op_comparison {
             DType::Complex64 => {
                 let Some(arr) = extract_array_as_c64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Complex64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_c64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -131,7 +143,7 @@ macro_rules! scalar_op_comparison {
             DType::Complex128 => {
                 let Some(arr) = extract_array_as_c128($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Complex128".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_c128($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -147,10 +159,10 @@ macro_rules! scalar_op_comparison {
         use crate::helpers::{
             extract_array_as_bool, extract_array_as_f32, extract_array_as_f64, extract_array_as_i16,
             extract_array_as_i32, extract_array_as_i64, extract_array_as_i8, extract_array_as_u16,
-            extract_array_as_u32, extract_array_as_u64, extract_array_as_u8, get_scalar_as_f64,
-            get_scalar_as_f32, get_scalar_as_i64, get_scalar_as_i32, get_scalar_as_i16,
-            get_scalar_as_i8, get_scalar_as_u64, get_scalar_as_u32, get_scalar_as_u16,
-            get_scalar_as_u8, set_last_error, ERR_GENERIC,
+            extract_array_as_u32, extract_array_as_u64, extract_array_as_u8, get_scalar_as_f32,
+            get_scalar_as_f64, get_scalar_as_i16, get_scalar_as_i32, get_scalar_as_i64, get_scalar_as_i8,
+            get_scalar_as_u16, get_scalar_as_u32, get_scalar_as_u64, get_scalar_as_u8, set_last_error,
+            ERR_DTYPE,
         };
         use crate::types::dtype::DType;
         use crate::types::{ArrayData, NDArrayWrapper};
@@ -161,7 +173,7 @@ macro_rules! scalar_op_comparison {
             DType::Float64 => {
                 let Some(arr) = extract_array_as_f64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Float64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_f64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -169,15 +181,28 @@ macro_rules! scalar_op_comparison {
             DType::Float32 => {
                 let Some(arr) = extract_array_as_f32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Float32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_f32($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
             }
+            DType::Float16 | DType::BFloat16 => {
+                let Some(arr) = extract_array_as_f32($wrapper, $meta) else {
+                    set_last_error("Failed to extract array as Float32".to_string());
+                    return ERR_DTYPE;
+                };
+                // Round the scalar to the array's precision first, as NumPy does.
+                let s = unsafe { get_scalar_as_f32($scalar, $scalar_dtype) };
+                let s = $crate::types::half::half_to_f32(
+                    out_dtype,
+                    $crate::types::half::f32_to_half(out_dtype, s),
+                );
+                arr.mapv(|x| (x $cmp_op s) as u8)
+            }
             DType::Int64 => {
                 let Some(arr) = extract_array_as_i64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -185,7 +210,7 @@ macro_rules! scalar_op_comparison {
             DType::Int32 => {
                 let Some(arr) = extract_array_as_i32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i32($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -193,7 +218,7 @@ macro_rules! scalar_op_comparison {
             DType::Int16 => {
                 let Some(arr) = extract_array_as_i16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i16($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -201,7 +226,7 @@ macro_rules! scalar_op_comparison {
             DType::Int8 => {
                 let Some(arr) = extract_array_as_i8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Int8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_i8($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -209,7 +234,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint64 => {
                 let Some(arr) = extract_array_as_u64($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint64".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u64($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -217,7 +242,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint32 => {
                 let Some(arr) = extract_array_as_u32($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint32".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u32($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -225,7 +250,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint16 => {
                 let Some(arr) = extract_array_as_u16($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint16".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u16($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -233,7 +258,7 @@ macro_rules! scalar_op_comparison {
             DType::Uint8 => {
                 let Some(arr) = extract_array_as_u8($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Uint8".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -241,7 +266,7 @@ macro_rules! scalar_op_comparison {
             DType::Bool => {
                 let Some(arr) = extract_array_as_bool($wrapper, $meta) else {
                     set_last_error("Failed to extract array as Bool".to_string());
-                    return ERR_GENERIC;
+                    return ERR_DTYPE;
                 };
                 let s = unsafe { get_scalar_as_u8($scalar, $scalar_dtype) };
                 arr.mapv(|x| (x $cmp_op s) as u8)
@@ -251,7 +276,7 @@ macro_rules! scalar_op_comparison {
                     "ordering comparison (<, <=, >, >=) is not defined for complex dtypes"
                         .to_string(),
                 );
-                return ERR_GENERIC;
+                return ERR_DTYPE;
             }
         };
 
@@ -0,0 +1,123 @@
+//! Dtype-preserving dispatch for element-wise unary operations.
+//!
+//! This module provides the `unary_op_native` macro which:
+//! - Extracts the array in its own dtype using the extract_array_* helpers
+//! - Evaluates the body with `arr` bound to that owned `ArrayD<T>`, so integer
+//!   arrays are processed as integers and never round-trip through f64
+//! - Computes half-precision arrays in f32 and rounds back to the input dtype
+//! - Wraps the result in an `NDArrayWrapper` of the same dtype
+//! - Fails with ERR_DTYPE for Bool and complex arrays (handle those before the
+//!   macro when the operation supports them), and for unsigned integers when
+//!   no `unsigned` body is given
+//!
+//! A single body is used for every real dtype; otherwise give separate bodies
+//! for floats, signed and (optionally) unsigned integers.
+//!
+//! Usage:
+//! ```rust
+//! let result = unary_op_native!(a_wrapper, meta, "clamp", |arr| arr.mapv(|x| x.clamp(lo, hi)));
+//! let result = unary_op_native!(a_wrapper, meta, "abs",
+//!     float: |arr| mapv_into(arr, |x| x.abs()),
+//!     signed: |arr| mapv_into(arr, |x| x.wrapping_abs()),
+//!     unsigned: |arr| arr,
+//! );
+//! ```
+
+#[macro_export]
+macro_rules! unary_op_native {
+    (@arm $wrapper:ident, $meta:ident, $extract:ident, $variant:ident, |$arr:ident| $body:expr) => {{
+        let Some($arr) = $crate::helpers::$extract($wrapper, $meta) else {
+            $crate::helpers::set_last_error(
+                concat!("Failed to extract ", stringify!($variant), " view").to_string(),
+            );
+            return $crate::helpers::ERR_DTYPE;
+        };
+        let result = $body;
+        $crate::types::NDArrayWrapper {
+            data: $crate::types::ArrayData::$variant(::std::sync::Arc::new(
+                ::parking_lot::RwLock::new(result),
+            )),
+            dtype: $crate::types::dtype::DType::$variant,
+        }
+    }};
+    (@unsigned $wrapper:ident, $meta:ident, $name:expr, $extract:ident, $variant:ident, |$arr:ident| $body:expr) => {
+        $crate::unary_op_native!(@arm $wrapper, $meta, $extract, $variant, |$arr| $body)
+    };
+    (@unsigned $wrapper:ident, $meta:ident, $name:expr, $extract:ident, $variant:ident) => {{
+        $crate::helpers::set_last_error(format!(
+            "{}() not supported for unsigned integer dtype {}",
+            $name,
+            $wrapper.dtype.name()
+        ));
+        return $crate::helpers::ERR_DTYPE;
+    }};
+    (
+        $wrapper:expr, $meta:expr, $name:expr,
+        float: |$farr:ident| $fbody:expr,
+        signed: |$sarr:ident| $sbody:expr
+        $(, unsigned: |$uarr:ident| $ubody:expr)?
+        $(,)?
+    ) => {{
+        use $crate::types::dtype::DType;
+
+        let wrapper: &$crate::types::NDArrayWrapper = $wrapper;
+        let meta: &$crate::types::ArrayMetadata = $meta;
+
+        match wrapper.dtype {
+            DType::Float64 => $crate::unary_op_native!(
+                @arm wrapper, meta, extract_array_f64, Float64, |$farr| $fbody
+            ),
+            DType::Float32 => $crate::unary_op_native!(
+                @arm wrapper, meta, extract_array_f32, Float32, |$farr| $fbody
+            ),
+            DType::Float16 | DType::BFloat16 => {
+                let Some($farr) = $crate::helpers::extract_array_as_f32(wrapper, meta) else {
+                    $crate::helpers::set_last_error("Failed to extract f32 view".to_string());
+                    return $crate::helpers::ERR_DTYPE;
+                };
+                let result = $fbody;
+                $crate::types::NDArrayWrapper::from_f32_as_half(result, wrapper.dtype)
+            }
+            DType::Int64 => $crate::unary_op_native!(
+                @arm wrapper, meta, extract_array_i64, Int64, |$sarr| $sbody
+            ),
+            DType::Int32 => $crate::unary_op_native!(
+                @arm wrapper, meta, extract_array_i32, Int32, |$sarr| $sbody
+            ),
+            DType::Int16 => $crate::unary_op_native!(
+                @arm wrapper, meta, extract_array_i16, Int16, |$sarr| $sbody
+            ),
+            DType::Int8 => $crate::unary_op_native!(
+                @arm wrapper, meta, extract_array_i8, Int8, |$sarr| $sbody
+            ),
+            DType::Uint64 => $crate::unary_op_native!(
+                @unsigned wrapper, meta, $name, extract_array_u64, Uint64 $(, |$uarr| $ubody)?
+            ),
+            DType::Uint32 => $crate::unary_op_native!(
+                @unsigned wrapper, meta, $name, extract_array_u32, Uint32 $(, |$uarr| $ubody)?
+            ),
+            DType::Uint16 => $crate::unary_op_native!(
+                @unsigned wrapper, meta, $name, extract_array_u16, Uint16 $(, |$uarr| $ubody)?
+            ),
+            DType::Uint8 => $crate::unary_op_native!(
+                @unsigned wrapper, meta, $name, extract_array_u8, Uint8 $(, |$uarr| $ubody)?
+            ),
+            DType::Bool | DType::Complex64 | DType::Complex128 => {
+                $crate::helpers::set_last_error(format!(
+                    "{}() not supported for {} dtype",
+                    $name,
+                    wrapper.dtype.name()
+                ));
+                return $crate::helpers::ERR_DTYPE;
+            }
+        }
+    }};
+    ($wrapper:expr, $meta:expr, $name:expr, |$arr:ident| $body:expr $(,)?) => {
+        $crate::unary_op_native!(
+            $wrapper, $meta, $name,
+            float: |$arr| $body,
+            signed: |$arr| $body,
+            unsigned: |$arr| $body,
+        )
+    };
+}
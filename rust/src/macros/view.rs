@@ -30,11 +30,7 @@ macro_rules! define_extract_array {
                     }
 
                     // Custom strides — stride-based iteration via temporary view
-                    let strides_ix = ndarray::IxDyn(strides);
-                    let view = ndarray::ArrayViewD::<$type>::from_shape_ptr(
-                        shape_ix.strides(strides_ix),
-                        ptr,
-                    );
+                    let view = $crate::helpers::view::strided_view::<$type>(base, meta);
                     let data: Vec<$type> = view.iter().copied().collect();
                     ndarray::ArrayD::from_shape_vec(ndarray::IxDyn(shape), data).ok()
                 }
@@ -55,20 +51,10 @@ macro_rules! define_extract_view {
             wrapper: &'a crate::types::NDArrayWrapper,
             meta: &'a crate::types::ArrayMetadata,
         ) -> Option<ndarray::ArrayViewD<'a, $type>> {
-            let offset = meta.offset;
-            let shape = meta.shape_slice();
-            let strides = meta.strides_slice();
             match &wrapper.data {
                 $variant(arr) => {
                     let guard = arr.read();
-                    let ptr = guard.as_ptr();
-                    let view_ptr = ptr.add(offset);
-                    let strides_ix = ndarray::IxDyn(strides);
-                    ndarray::ArrayViewD::<$type>::from_shape_ptr(
-                        ndarray::IxDyn(shape).strides(strides_ix),
-                        view_ptr,
-                    )
-                    .into()
+                    Some($crate::helpers::view::strided_view::<$type>(guard.as_ptr(), meta))
                 }
                 _ => None,
             }
@@ -84,20 +70,13 @@ macro_rules! define_extract_view_mut {
             wrapper: &'a crate::types::NDArrayWrapper,
             meta: &'a crate::types::ArrayMetadata,
         ) -> Option<ndarray::ArrayViewMutD<'a, $type>> {
-            let offset = meta.offset;
-            let shape = meta.shape_slice();
-            let strides = meta.strides_slice();
             match &wrapper.data {
                 $variant(arr) => {
                     let mut guard = arr.write();
-                    let ptr = guard.as_mut_ptr();
-                    let view_ptr = ptr.add(offset);
-                    let strides_ix = ndarray::IxDyn(strides);
-                    ndarray::ArrayViewMutD::<$type>::from_shape_ptr(
-                        ndarray::IxDyn(shape).strides(strides_ix),
-                        view_ptr,
-                    )
-                    .into()
+                    Some($crate::helpers::view::strided_view_mut::<$type>(
+                        guard.as_mut_ptr(),
+                        meta,
+                    ))
                 }
                 _ => None,
             }
@@ -28,4 +28,6 @@ pub enum ArrayData {
     Bool(Arc<RwLock<ArrayD<u8>>>), // Store bool as u8 for FFI compatibility
     Complex64(Arc<RwLock<ArrayD<Complex32>>>),
     Complex128(Arc<RwLock<ArrayD<Complex64>>>),
+    Float16(Arc<RwLock<ArrayD<u16>>>), // IEEE 754 binary16 bit patterns
+    BFloat16(Arc<RwLock<ArrayD<u16>>>), // Upper 16 bits of an f32
 }
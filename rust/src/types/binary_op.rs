@@ -0,0 +1,30 @@
+//! Element-wise binary operation selector for the `out=` entry point.
+
+/// Which arithmetic operation `ndarray_binary_op_out` applies.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add = 0,
+    Sub = 1,
+    Mul = 2,
+    Div = 3,
+    Rem = 4,
+    Maximum = 5,
+    Minimum = 6,
+}
+
+impl BinaryOp {
+    /// Parse BinaryOp from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(BinaryOp::Add),
+            1 => Ok(BinaryOp::Sub),
+            2 => Ok(BinaryOp::Mul),
+            3 => Ok(BinaryOp::Div),
+            4 => Ok(BinaryOp::Rem),
+            5 => Ok(BinaryOp::Maximum),
+            6 => Ok(BinaryOp::Minimum),
+            _ => Err(format!("Invalid binary op: {}", value)),
+        }
+    }
+}
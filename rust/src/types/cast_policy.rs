@@ -0,0 +1,26 @@
+//! Out-of-range policy for checked dtype casts.
+
+/// What a checked cast does with a value the target dtype cannot represent.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CastPolicy {
+    /// Clamp to the nearest representable value (NaN becomes 0 for integers).
+    Clamp = 0,
+    /// Keep the low bits for integers; floats keep the IEEE result (±inf).
+    Wrap = 1,
+    /// Fail the whole cast with `ERR_OVERFLOW`.
+    #[default]
+    Error = 2,
+}
+
+impl CastPolicy {
+    /// Parse CastPolicy from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(CastPolicy::Clamp),
+            1 => Ok(CastPolicy::Wrap),
+            2 => Ok(CastPolicy::Error),
+            _ => Err(format!("Invalid cast policy: {}", value)),
+        }
+    }
+}
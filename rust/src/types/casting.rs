@@ -0,0 +1,119 @@
+//! Casting rules for writing one dtype into another (NumPy `casting=`).
+
+use super::DType;
+
+/// Which dtype conversions an in-place copy may perform.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Casting {
+    /// Only identical dtypes.
+    No = 0,
+    /// Identical dtypes (no byte-order variants exist here, so same as `No`).
+    Equiv = 1,
+    /// Only casts that preserve every value, e.g. int32 -> int64 or float32 -> float64.
+    Safe = 2,
+    /// Safe casts plus casts within a kind, e.g. float64 -> float32.
+    #[default]
+    SameKind = 3,
+    /// Any conversion.
+    Unsafe = 4,
+}
+
+/// Kind rank: bool < unsigned < signed < float < complex.
+fn kind(dtype: DType) -> u8 {
+    match dtype {
+        DType::Bool => 0,
+        DType::Uint8 | DType::Uint16 | DType::Uint32 | DType::Uint64 => 1,
+        DType::Int8 | DType::Int16 | DType::Int32 | DType::Int64 => 2,
+        DType::Float16 | DType::BFloat16 | DType::Float32 | DType::Float64 => 3,
+        DType::Complex64 | DType::Complex128 => 4,
+    }
+}
+
+/// Whether every value of `from` is representable in `to`.
+///
+/// Follows NumPy: 64-bit integers cast safely to float64 even though the
+/// largest values round. Float16 and BFloat16 trade range for precision, so
+/// neither casts safely to the other.
+fn is_safe(from: DType, to: DType) -> bool {
+    if from == to || from == DType::Bool {
+        return true;
+    }
+    let (fs, ts) = (from.item_size(), to.item_size());
+    match (kind(from), kind(to)) {
+        (3, 3) if from.is_half() && to.is_half() => false,
+        (1, 1) | (2, 2) | (3, 3) | (4, 4) => ts >= fs,
+        (1, 2) => ts > fs,
+        (1 | 2, 3) if to.is_half() => fs == 1,
+        (1 | 2, 3) => fs <= 2 || to == DType::Float64,
+        (1 | 2, 4) => fs <= 2 || to == DType::Complex128,
+        (3, 4) => ts >= 2 * fs,
+        _ => false,
+    }
+}
+
+impl Casting {
+    /// Parse Casting from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(Casting::No),
+            1 => Ok(Casting::Equiv),
+            2 => Ok(Casting::Safe),
+            3 => Ok(Casting::SameKind),
+            4 => Ok(Casting::Unsafe),
+            _ => Err(format!("Invalid casting mode: {}", value)),
+        }
+    }
+
+    /// NumPy name of the rule.
+    pub fn name(self) -> &'static str {
+        match self {
+            Casting::No => "no",
+            Casting::Equiv => "equiv",
+            Casting::Safe => "safe",
+            Casting::SameKind => "same_kind",
+            Casting::Unsafe => "unsafe",
+        }
+    }
+
+    /// Whether this rule allows converting `from` to `to`.
+    pub fn allows(self, from: DType, to: DType) -> bool {
+        match self {
+            Casting::No | Casting::Equiv => from == to,
+            Casting::Safe => is_safe(from, to),
+            Casting::SameKind => is_safe(from, to) || kind(from) <= kind(to),
+            Casting::Unsafe => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn casting_rules_match_numpy() {
+        assert!(Casting::Safe.allows(DType::Int32, DType::Int64));
+        assert!(Casting::Safe.allows(DType::Uint8, DType::Int16));
+        assert!(!Casting::Safe.allows(DType::Uint32, DType::Int32));
+        assert!(Casting::Safe.allows(DType::Int16, DType::Float32));
+        assert!(!Casting::Safe.allows(DType::Int32, DType::Float32));
+        assert!(Casting::Safe.allows(DType::Int64, DType::Float64));
+        assert!(Casting::Safe.allows(DType::Float32, DType::Complex64));
+        assert!(!Casting::Safe.allows(DType::Float64, DType::Complex64));
+        assert!(!Casting::Safe.allows(DType::Float64, DType::Float32));
+        assert!(Casting::Safe.allows(DType::Float16, DType::Float32));
+        assert!(Casting::Safe.allows(DType::Uint8, DType::Float16));
+        assert!(!Casting::Safe.allows(DType::Int16, DType::BFloat16));
+        assert!(!Casting::Safe.allows(DType::Float16, DType::BFloat16));
+
+        assert!(Casting::SameKind.allows(DType::Float64, DType::Float32));
+        assert!(Casting::SameKind.allows(DType::Uint64, DType::Int8));
+        assert!(!Casting::SameKind.allows(DType::Int8, DType::Uint64));
+        assert!(!Casting::SameKind.allows(DType::Float32, DType::Int64));
+        assert!(Casting::SameKind.allows(DType::Float32, DType::BFloat16));
+
+        assert!(!Casting::No.allows(DType::Int32, DType::Int64));
+        assert!(Casting::Unsafe.allows(DType::Complex128, DType::Bool));
+    }
+}
@@ -0,0 +1,24 @@
+//! Distance metric for nearest-neighbour queries.
+
+/// How the distance between two feature vectors is measured.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    Euclidean = 0,
+    SquaredEuclidean = 1,
+    Manhattan = 2,
+    Cosine = 3,
+}
+
+impl DistanceMetric {
+    /// Parse DistanceMetric from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(DistanceMetric::Euclidean),
+            1 => Ok(DistanceMetric::SquaredEuclidean),
+            2 => Ok(DistanceMetric::Manhattan),
+            3 => Ok(DistanceMetric::Cosine),
+            _ => Err(format!("Invalid distance metric: {}", value)),
+        }
+    }
+}
@@ -34,6 +34,10 @@ pub enum DType {
     // Complex numbers
     Complex64 = 11,
     Complex128 = 12,
+
+    // Half-precision floating-point (stored as raw 16-bit patterns)
+    Float16 = 13,
+    BFloat16 = 14,
 }
 
 impl DType {
@@ -54,6 +58,8 @@ impl DType {
             10 => Some(DType::Bool),
             11 => Some(DType::Complex64),
             12 => Some(DType::Complex128),
+            13 => Some(DType::Float16),
+            14 => Some(DType::BFloat16),
             _ => None,
         }
     }
@@ -69,7 +75,7 @@ impl DType {
     pub const fn item_size(self) -> usize {
         match self {
             DType::Int8 | DType::Uint8 | DType::Bool => 1,
-            DType::Int16 | DType::Uint16 => 2,
+            DType::Int16 | DType::Uint16 | DType::Float16 | DType::BFloat16 => 2,
             DType::Int32 | DType::Uint32 | DType::Float32 => 4,
             DType::Int64 | DType::Uint64 | DType::Float64 => 8,
             DType::Complex64 => 8,
@@ -95,6 +101,30 @@ impl DType {
         )
     }
 
+    /// Check if this is a signed or unsigned integer type.
+    #[inline]
+    pub const fn is_integer(self) -> bool {
+        self.is_signed() || self.is_unsigned()
+    }
+
+    /// Check if this is a real floating-point type, including half precision.
+    #[inline]
+    pub const fn is_float(self) -> bool {
+        matches!(
+            self,
+            DType::Float16 | DType::BFloat16 | DType::Float32 | DType::Float64
+        )
+    }
+
+    /// Check if this is a 16-bit floating-point type (Float16 or BFloat16).
+    ///
+    /// Half-precision arrays are stored as raw bit patterns and computed on
+    /// through Float32.
+    #[inline]
+    pub const fn is_half(self) -> bool {
+        matches!(self, DType::Float16 | DType::BFloat16)
+    }
+
     /// Check if this is a complex number type.
     #[inline]
     pub const fn is_complex(self) -> bool {
@@ -118,6 +148,8 @@ impl DType {
             DType::Bool => "bool",
             DType::Complex64 => "complex64",
             DType::Complex128 => "complex128",
+            DType::Float16 => "float16",
+            DType::BFloat16 => "bfloat16",
         }
     }
 
@@ -142,6 +174,20 @@ impl DType {
             return DType::Complex64;
         }
 
+        // Half precision absorbs 8-bit integers and bools; anything wider
+        // widens to the smallest float that holds both (NumPy's float16
+        // rules, applied to bfloat16 as well). Mixing the two half formats
+        // goes to float32 since neither contains the other.
+        if a.is_half() || b.is_half() {
+            let (half, other) = if a.is_half() { (a, b) } else { (b, a) };
+            return match other {
+                DType::Int8 | DType::Uint8 | DType::Bool => half,
+                DType::Float16 | DType::BFloat16 => DType::Float32,
+                DType::Int16 | DType::Uint16 | DType::Float32 => DType::Float32,
+                _ => DType::Float64,
+            };
+        }
+
         if a == DType::Float64 || b == DType::Float64 {
             return DType::Float64;
         }
@@ -194,8 +240,8 @@ impl DType {
             return Self::promote(array_dtype, scalar_dtype);
         }
 
-        let array_is_float = matches!(array_dtype, DType::Float32 | DType::Float64);
-        let scalar_is_float = matches!(scalar_dtype, DType::Float32 | DType::Float64);
+        let array_is_float = array_dtype.is_float();
+        let scalar_is_float = scalar_dtype.is_float();
 
         // Float array + any scalar → keep array's float width
         if array_is_float {
@@ -282,7 +328,9 @@ mod tests {
         assert_eq!(DType::from_u8(10), Some(DType::Bool));
         assert_eq!(DType::from_u8(11), Some(DType::Complex64));
         assert_eq!(DType::from_u8(12), Some(DType::Complex128));
-        assert_eq!(DType::from_u8(13), None);
+        assert_eq!(DType::from_u8(13), Some(DType::Float16));
+        assert_eq!(DType::from_u8(14), Some(DType::BFloat16));
+        assert_eq!(DType::from_u8(15), None);
         assert_eq!(DType::from_u8(255), None);
     }
 
@@ -306,6 +354,8 @@ mod tests {
         assert_eq!(DType::Bool.item_size(), 1);
         assert_eq!(DType::Complex64.item_size(), 8);
         assert_eq!(DType::Complex128.item_size(), 16);
+        assert_eq!(DType::Float16.item_size(), 2);
+        assert_eq!(DType::BFloat16.item_size(), 2);
     }
 
     #[test]
@@ -331,5 +381,27 @@ mod tests {
             DType::promote(DType::Float64, DType::Complex64),
             DType::Complex128
         );
+        assert_eq!(DType::promote(DType::Float16, DType::Uint8), DType::Float16);
+        assert_eq!(
+            DType::promote(DType::BFloat16, DType::Bool),
+            DType::BFloat16
+        );
+        assert_eq!(DType::promote(DType::Float16, DType::Int16), DType::Float32);
+        assert_eq!(
+            DType::promote(DType::Float16, DType::BFloat16),
+            DType::Float32
+        );
+        assert_eq!(
+            DType::promote(DType::BFloat16, DType::Int32),
+            DType::Float64
+        );
+        assert_eq!(
+            DType::promote(DType::Float16, DType::Complex64),
+            DType::Complex64
+        );
+        assert_eq!(
+            DType::promote_scalar(DType::Float16, DType::Float64),
+            DType::Float16
+        );
     }
 }
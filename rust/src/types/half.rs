@@ -0,0 +1,108 @@
+//! Half-precision float conversions.
+//!
+//! Float16 (IEEE 754 binary16) and BFloat16 arrays are stored as raw `u16`
+//! bit patterns, the same way Bool is stored as `u8`. Every computation
+//! widens elements to `f32` with these functions and narrows results back,
+//! rounding to nearest with ties to even. The conversions themselves come
+//! from the `half` crate.
+
+use half::{bf16, f16};
+
+use super::DType;
+
+/// Widen an IEEE 754 binary16 bit pattern to `f32` (exact).
+#[inline]
+pub fn f16_to_f32(bits: u16) -> f32 {
+    f16::from_bits(bits).to_f32()
+}
+
+/// Narrow an `f32` to an IEEE 754 binary16 bit pattern.
+///
+/// Values beyond the float16 range become infinity, values below the smallest
+/// subnormal become signed zero, and NaN stays NaN.
+#[inline]
+pub fn f32_to_f16(value: f32) -> u16 {
+    f16::from_f32(value).to_bits()
+}
+
+/// Widen a bfloat16 bit pattern to `f32` (exact).
+#[inline]
+pub fn bf16_to_f32(bits: u16) -> f32 {
+    bf16::from_bits(bits).to_f32()
+}
+
+/// Narrow an `f32` to a bfloat16 bit pattern.
+#[inline]
+pub fn f32_to_bf16(value: f32) -> u16 {
+    bf16::from_f32(value).to_bits()
+}
+
+/// Widen a half-precision element of `dtype` to `f32`.
+///
+/// `dtype` must be Float16 or BFloat16.
+#[inline]
+pub fn half_to_f32(dtype: DType, bits: u16) -> f32 {
+    if dtype == DType::BFloat16 {
+        bf16_to_f32(bits)
+    } else {
+        f16_to_f32(bits)
+    }
+}
+
+/// Narrow an `f32` to a half-precision element of `dtype`.
+///
+/// `dtype` must be Float16 or BFloat16.
+#[inline]
+pub fn f32_to_half(dtype: DType, value: f32) -> u16 {
+    if dtype == DType::BFloat16 {
+        f32_to_bf16(value)
+    } else {
+        f32_to_f16(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f16_round_trips_representable_values() {
+        for v in [
+            0.0f32,
+            -0.0,
+            1.0,
+            -2.5,
+            0.099975586,
+            65504.0,
+            6.1035156e-5,
+            5.9604645e-8,
+        ] {
+            assert_eq!(f16_to_f32(f32_to_f16(v)).to_bits(), v.to_bits(), "{v}");
+        }
+        assert_eq!(f32_to_f16(1.0), 0x3c00);
+        assert_eq!(f32_to_f16(65504.0), 0x7bff);
+        assert_eq!(f32_to_f16(5.9604645e-8), 0x0001);
+    }
+
+    #[test]
+    fn f16_rounds_to_nearest_even_and_saturates() {
+        // 1 + 2^-11 is halfway between 1 and the next float16; ties go to even.
+        assert_eq!(f32_to_f16(1.0 + f32::powi(2.0, -11)), 0x3c00);
+        assert_eq!(f32_to_f16(1.0 + 3.0 * f32::powi(2.0, -11)), 0x3c02);
+        assert_eq!(f32_to_f16(65520.0), 0x7c00);
+        assert_eq!(f32_to_f16(f32::NEG_INFINITY), 0xfc00);
+        assert_eq!(f32_to_f16(1e-9), 0);
+        assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+    }
+
+    #[test]
+    fn bf16_keeps_f32_range() {
+        assert_eq!(f32_to_bf16(1.0), 0x3f80);
+        assert_eq!(f32_to_bf16(3.0e38), 0x7f62);
+        assert_eq!(bf16_to_f32(0x7f62), f32::from_bits(0x7f62_0000));
+        // 1 + 2^-8 is halfway between 1 and the next bfloat16.
+        assert_eq!(f32_to_bf16(1.0 + f32::powi(2.0, -8)), 0x3f80);
+        assert_eq!(f32_to_bf16(1.0 + 3.0 * f32::powi(2.0, -8)), 0x3f82);
+        assert!(bf16_to_f32(f32_to_bf16(f32::NAN)).is_nan());
+    }
+}
@@ -0,0 +1,33 @@
+//! Memory layout order of array storage.
+
+use ndarray::{IxDyn, Shape, ShapeBuilder};
+
+/// Element order of newly allocated storage.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MemoryOrder {
+    /// Row-major (C) order: the last axis varies fastest.
+    #[default]
+    C = 0,
+    /// Column-major (Fortran) order: the first axis varies fastest.
+    F = 1,
+}
+
+impl MemoryOrder {
+    /// Parse MemoryOrder from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(MemoryOrder::C),
+            1 => Ok(MemoryOrder::F),
+            _ => Err(format!(
+                "Invalid order: {}. Use 0 for C (row-major), 1 for F (column-major)",
+                value
+            )),
+        }
+    }
+
+    /// Shape with this order's layout, for `ArrayD::zeros` / `from_elem` / `from_shape_vec`.
+    pub fn shape(self, dims: &[usize]) -> Shape<IxDyn> {
+        IxDyn(dims).set_f(self == MemoryOrder::F)
+    }
+}
@@ -3,17 +3,34 @@
 //! This module contains the type definitions used throughout the library.
 
 mod array_data;
+mod binary_op;
+mod cast_policy;
+mod casting;
+mod distance_metric;
 pub mod dtype;
 mod handle;
+pub mod half;
+mod memory_order;
 mod metadata;
+mod overflow_mode;
 mod pad_mode;
+mod scatter_mode;
 mod sort_kind;
+mod sum_mode;
 mod wrapper;
 
 pub use array_data::ArrayData;
+pub use binary_op::BinaryOp;
+pub use cast_policy::CastPolicy;
+pub use casting::Casting;
+pub use distance_metric::DistanceMetric;
 pub use dtype::{DType, DTypeError};
 pub use handle::NdArrayHandle;
+pub use memory_order::MemoryOrder;
 pub use metadata::ArrayMetadata;
+pub use overflow_mode::OverflowMode;
 pub use pad_mode::PadMode;
+pub use scatter_mode::ScatterMode;
 pub use sort_kind::SortKind;
+pub use sum_mode::SumMode;
 pub use wrapper::NDArrayWrapper;
@@ -0,0 +1,26 @@
+//! Integer overflow policy for add, subtract and multiply.
+
+/// What integer arithmetic does when a result does not fit its dtype.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowMode {
+    /// Two's-complement wraparound, as in NumPy.
+    #[default]
+    Wrap = 0,
+    /// Clamp to the smallest or largest value of the dtype.
+    Saturate = 1,
+    /// Fail the whole operation with `ERR_OVERFLOW`.
+    Error = 2,
+}
+
+impl OverflowMode {
+    /// Parse OverflowMode from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(OverflowMode::Wrap),
+            1 => Ok(OverflowMode::Saturate),
+            2 => Ok(OverflowMode::Error),
+            _ => Err(format!("Invalid overflow mode: {}", value)),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Reduction mode for axis scatter operations.
+
+/// How scattered values combine with each other and with the existing values.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterMode {
+    Assign = 0,
+    Add = 1,
+    Mul = 2,
+    Min = 3,
+    Max = 4,
+    Mean = 5,
+}
+
+impl ScatterMode {
+    /// Parse ScatterMode from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(ScatterMode::Assign),
+            1 => Ok(ScatterMode::Add),
+            2 => Ok(ScatterMode::Mul),
+            3 => Ok(ScatterMode::Min),
+            4 => Ok(ScatterMode::Max),
+            5 => Ok(ScatterMode::Mean),
+            _ => Err(format!("Invalid scatter mode: {}", value)),
+        }
+    }
+}
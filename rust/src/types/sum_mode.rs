@@ -0,0 +1,23 @@
+//! Floating-point summation strategy for sum, mean, var and std.
+
+/// How float reductions accumulate their terms.
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SumMode {
+    /// Blocked pairwise summation; error grows as O(log n).
+    #[default]
+    Pairwise = 0,
+    /// Compensated (Kahan) summation; error is essentially independent of n.
+    Kahan = 1,
+}
+
+impl SumMode {
+    /// Parse SumMode from FFI integer value.
+    pub fn from_i32(value: i32) -> Result<Self, String> {
+        match value {
+            0 => Ok(SumMode::Pairwise),
+            1 => Ok(SumMode::Kahan),
+            _ => Err(format!("Invalid sum mode: {}", value)),
+        }
+    }
+}
@@ -36,6 +36,20 @@ impl NDArrayWrapper {
         self.len() == 0
     }
 
+    /// Reverse the axis order of the storage in place.
+    ///
+    /// C-order storage of shape `[d_n, ..., d_1]` becomes F-order storage of
+    /// shape `[d_1, ..., d_n]` without moving any elements.
+    pub fn reverse_axes(&self) {
+        match_array_data!(self.data, arr => {
+            let mut guard = arr.write();
+            let ndim = guard.ndim();
+            for axis in 0..ndim / 2 {
+                guard.swap_axes(axis, ndim - 1 - axis);
+            }
+        })
+    }
+
     /// Check if this wrapper points to the same underlying array data as another.
     pub fn is_same_array(&self, other: &Self) -> bool {
         use crate::types::ArrayData::*;
@@ -55,9 +69,36 @@ impl NDArrayWrapper {
             (Bool(a), Bool(b)) => Arc::ptr_eq(a, b),
             (Complex64(a), Complex64(b)) => Arc::ptr_eq(a, b),
             (Complex128(a), Complex128(b)) => Arc::ptr_eq(a, b),
+            (Float16(a), Float16(b)) => Arc::ptr_eq(a, b),
+            (BFloat16(a), BFloat16(b)) => Arc::ptr_eq(a, b),
             _ => false,
         }
     }
+
+    /// Wrap raw half-precision bit patterns as a Float16 or BFloat16 array.
+    ///
+    /// `dtype` must be one of the half-precision types.
+    pub fn from_half_bits(bits: ndarray::ArrayD<u16>, dtype: DType) -> Self {
+        let lock = std::sync::Arc::new(parking_lot::RwLock::new(bits));
+        Self {
+            data: if dtype == DType::BFloat16 {
+                ArrayData::BFloat16(lock)
+            } else {
+                ArrayData::Float16(lock)
+            },
+            dtype,
+        }
+    }
+
+    /// Narrow an f32 result into a Float16 or BFloat16 array.
+    ///
+    /// `dtype` must be one of the half-precision types.
+    pub fn from_f32_as_half(arr: ndarray::ArrayD<f32>, dtype: DType) -> Self {
+        Self::from_half_bits(
+            arr.mapv(|v| crate::types::half::f32_to_half(dtype, v)),
+            dtype,
+        )
+    }
 }
 
 // Generate all from_slice_* methods using the macro
@@ -72,7 +113,9 @@ crate::impl_from_slice!(
     from_slice_u64, u64, Uint64, Uint64;
     from_slice_f32, f32, Float32, Float32;
     from_slice_f64, f64, Float64, Float64;
-    from_slice_bool, u8, Bool, Bool
+    from_slice_bool, u8, Bool, Bool;
+    from_slice_f16, u16, Float16, Float16;
+    from_slice_bf16, u16, BFloat16, BFloat16
 );
 
 impl NDArrayWrapper {
@@ -158,7 +201,9 @@ crate::impl_get_element!(
     get_element_f64, f64, Float64;
     get_element_bool, u8, Bool;
     get_element_complex64, num_complex::Complex<f32>, Complex64;
-    get_element_complex128, num_complex::Complex<f64>, Complex128
+    get_element_complex128, num_complex::Complex<f64>, Complex128;
+    get_element_f16, u16, Float16;
+    get_element_bf16, u16, BFloat16
 );
 
 // Generate set_element_* methods for single-element mutation
@@ -175,7 +220,9 @@ crate::impl_set_element!(
     set_element_f64, f64, Float64;
     set_element_bool, u8, Bool;
     set_element_complex64, num_complex::Complex<f32>, Complex64;
-    set_element_complex128, num_complex::Complex<f64>, Complex128
+    set_element_complex128, num_complex::Complex<f64>, Complex128;
+    set_element_f16, u16, Float16;
+    set_element_bf16, u16, BFloat16
 );
 
 #[cfg(test)]
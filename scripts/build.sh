@@ -32,15 +32,21 @@ echo "Detected platform: $PLATFORM"
 
 # Parse arguments
 BUILD_MODE="release"
+FEATURES="ffi"
 
 for arg in "$@"; do
     case $arg in
         debug)
             BUILD_MODE="debug"
             ;;
+        blas|parallel)
+            FEATURES="$FEATURES $arg"
+            ;;
     esac
 done
 
+echo "Cargo features: $FEATURES"
+
 # Setup environment for BLAS build on macOS
 if [[ "$OSTYPE" == "darwin"* ]]; then
     if [ -d "/opt/homebrew/Cellar/gcc" ]; then
@@ -73,14 +79,14 @@ fi
 if [ "$BUILD_MODE" = "debug" ]; then
     echo "Building Rust library (debug mode)..."
     cd rust
-    cargo build --features ffi
+    cargo build --features "$FEATURES"
     cd ..
 
     SOURCE_DIR="rust/target/debug"
 else
     echo "Building Rust library (release mode)..."
     cd rust
-    cargo build --release --features ffi
+    cargo build --release --features "$FEATURES"
     cd ..
 
     SOURCE_DIR="rust/target/release"
@@ -133,5 +139,8 @@ echo "Library location: lib/$PLATFORM/"
 echo "Header location: include/ndarray_php.h"
 echo ""
 echo "Usage:"
-echo "  ./scripts/build.sh        # Release build (default)"
-echo "  ./scripts/build.sh debug  # Debug build (faster)"
+echo "  ./scripts/build.sh           # Release build (default)"
+echo "  ./scripts/build.sh debug     # Debug build (faster)"
+echo "  ./scripts/build.sh blas      # Route matmul/dot through BLAS gemm"
+echo "  ./scripts/build.sh parallel  # Enable rayon-parallel kernels"
+echo "  Flags combine, e.g. ./scripts/build.sh debug blas parallel"
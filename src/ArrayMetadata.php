@@ -73,23 +73,54 @@ final class ArrayMetadata
     }
 
     /**
-     * Compute row-major strides from shape.
+     * Compute contiguous strides from shape.
      *
      * @param array<int> $shape
+     * @param string     $order 'C' for row-major, 'F' for column-major
      *
      * @return array<int>
      */
-    public static function computeStrides(array $shape): array
+    public static function computeStrides(array $shape, string $order = 'C'): array
     {
         $ndim = \count($shape);
         if (0 === $ndim) {
             return [];
         }
         $strides = array_fill(0, $ndim, 1);
-        for ($i = $ndim - 2; $i >= 0; --$i) {
-            $strides[$i] = $strides[$i + 1] * $shape[$i + 1];
+        if ('F' === self::validateOrder($order)) {
+            for ($i = 1; $i < $ndim; ++$i) {
+                $strides[$i] = $strides[$i - 1] * $shape[$i - 1];
+            }
+        } else {
+            for ($i = $ndim - 2; $i >= 0; --$i) {
+                $strides[$i] = $strides[$i + 1] * $shape[$i + 1];
+            }
         }
 
         return $strides;
     }
+
+    /**
+     * Validate a memory order and return it.
+     *
+     * @return 'C'|'F'
+     *
+     * @throws \InvalidArgumentException If the order is not 'C' or 'F'
+     */
+    public static function validateOrder(string $order): string
+    {
+        if ('C' !== $order && 'F' !== $order) {
+            throw new \InvalidArgumentException("Order must be 'C' or 'F', got '{$order}'");
+        }
+
+        return $order;
+    }
+
+    /**
+     * FFI code of a memory order: 0 for C, 1 for F.
+     */
+    public static function orderCode(string $order): int
+    {
+        return 'F' === self::validateOrder($order) ? 1 : 0;
+    }
 }
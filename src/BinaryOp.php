@@ -0,0 +1,23 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Element-wise operation selector for the `$out` variants of arithmetic methods.
+ *
+ * Integer values must stay in sync with Rust BinaryOp.
+ *
+ * @internal
+ */
+enum BinaryOp: int
+{
+    case Add = 0;
+    case Sub = 1;
+    case Mul = 2;
+    case Div = 3;
+    case Rem = 4;
+    case Maximum = 5;
+    case Minimum = 6;
+}
@@ -0,0 +1,31 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Byte order of raw binary data exchanged with {@see NDArray::fromBytes()} and {@see NDArray::toBytes()}.
+ *
+ * Values follow the NumPy dtype prefix characters.
+ */
+enum ByteOrder: string
+{
+    case Little = '<';
+    case Big = '>';
+
+    /** Byte order of the host machine. */
+    case Native = '=';
+
+    /**
+     * Whether data in this byte order can be used without swapping on the host machine.
+     */
+    public function isNative(): bool
+    {
+        return match ($this) {
+            self::Native => true,
+            self::Little => "\x01\x00" === pack('S', 1),
+            self::Big => "\x00\x01" === pack('S', 1),
+        };
+    }
+}
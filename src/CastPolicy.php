@@ -0,0 +1,22 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * What astypeChecked() does with values the target dtype cannot represent.
+ *
+ * Integer values must stay in sync with Rust CastPolicy.
+ */
+enum CastPolicy: int
+{
+    /** Clamp to the nearest representable value (NaN becomes 0 for integers). */
+    case Clamp = 0;
+
+    /** Keep the low bits for integers; floats overflow to infinity. */
+    case Wrap = 1;
+
+    /** Throw an OverflowException (default). */
+    case Error = 2;
+}
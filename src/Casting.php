@@ -0,0 +1,28 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Dtype conversions allowed when writing into an existing array (NumPy `casting=`).
+ *
+ * Integer values must stay in sync with Rust Casting.
+ */
+enum Casting: int
+{
+    /** Only identical dtypes. */
+    case No = 0;
+
+    /** Identical dtypes (no byte-order variants exist, so same as No). */
+    case Equiv = 1;
+
+    /** Only casts that preserve every value, e.g. Int32 to Int64. */
+    case Safe = 2;
+
+    /** Safe casts plus casts within a kind, e.g. Float64 to Float32 (default). */
+    case SameKind = 3;
+
+    /** Any conversion. */
+    case Unsafe = 4;
+}
@@ -0,0 +1,22 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Output size of NDArray::convolve(), convolve2d() and correlate2d().
+ *
+ * Integer values must stay in sync with Rust ConvolveMode in ffi/signal/convolve.rs.
+ */
+enum ConvolveMode: int
+{
+    /** Every position where the kernel overlaps the input. */
+    case Full = 0;
+
+    /** Same size as the input, centred on the full output. */
+    case Same = 1;
+
+    /** Only positions where the kernel lies entirely inside the input. */
+    case Valid = 2;
+}
@@ -37,6 +37,10 @@ enum DType: int
     case Complex64 = 11;
     case Complex128 = 12;
 
+    // Half-precision floating-point (stored as raw 16-bit patterns)
+    case Float16 = 13;
+    case BFloat16 = 14;
+
     /**
      * Get the C FFI type string for this dtype.
      */
@@ -56,6 +60,7 @@ enum DType: int
             self::Bool => 'uint8_t',
             self::Complex64 => 'float',
             self::Complex128 => 'double',
+            self::Float16, self::BFloat16 => 'uint16_t',
         };
     }
 
@@ -66,7 +71,7 @@ enum DType: int
     {
         return match ($this) {
             self::Int8, self::UInt8, self::Bool => 1,
-            self::Int16, self::UInt16 => 2,
+            self::Int16, self::UInt16, self::Float16, self::BFloat16 => 2,
             self::Int32, self::UInt32, self::Float32 => 4,
             self::Int64, self::UInt64, self::Float64 => 8,
             self::Complex64 => 8,
@@ -110,11 +115,21 @@ enum DType: int
     public function isFloat(): bool
     {
         return match ($this) {
-            self::Float32, self::Float64 => true,
+            self::Float16, self::BFloat16, self::Float32, self::Float64 => true,
             default => false,
         };
     }
 
+    /**
+     * Check if this dtype is a half-precision float (Float16 or BFloat16).
+     *
+     * Half-precision values cross the FFI boundary as 16-bit patterns.
+     */
+    public function isHalf(): bool
+    {
+        return self::Float16 === $this || self::BFloat16 === $this;
+    }
+
     /**
      * Check if this dtype is a complex number type.
      */
@@ -235,6 +250,8 @@ enum DType: int
             self::Int32 => -2147483648,
             self::Int64 => \PHP_INT_MIN,
             self::UInt8, self::UInt16, self::UInt32, self::UInt64 => 0,
+            self::Float16 => -65504.0,
+            self::BFloat16 => -3.3895314e+38,
             self::Float32 => -3.4028235e+38,
             self::Float64 => -\PHP_FLOAT_MAX,
             self::Bool => 0,
@@ -257,6 +274,8 @@ enum DType: int
             self::UInt16 => 65535,
             self::UInt32 => 4294967295,
             self::UInt64 => \PHP_INT_MAX,
+            self::Float16 => 65504.0,
+            self::BFloat16 => 3.3895314e+38,
             self::Float32 => 3.4028235e+38,
             self::Float64 => \PHP_FLOAT_MAX,
             self::Bool => 1,
@@ -293,7 +312,11 @@ enum DType: int
         $cValue = $lib->new($this->ffiType());
 
         if (null !== $value) {
-            $cValue->cdata = $this->isBool() ? ($value ? 1 : 0) : $value;
+            $cValue->cdata = match (true) {
+                $this->isBool() => $value ? 1 : 0,
+                $this->isHalf() => $this->toHalfBits((float) $value),
+                default => $value,
+            };
         }
 
         return $cValue;
@@ -343,6 +366,7 @@ enum DType: int
     {
         return match ($this) {
             self::Float64, self::Float32 => (float) $cValue,
+            self::Float16, self::BFloat16 => $this->fromHalfBits((int) $cValue),
             self::Bool => (bool) $cValue,
             self::Complex64 => new Complex((float) $cValue[0], (float) $cValue[1]),
             self::Complex128 => new Complex((float) $cValue[0], (float) $cValue[1]),
@@ -351,14 +375,15 @@ enum DType: int
     }
 
     /**
-     * Prepare array values for FFI by converting bools to 1/0 and complex to flat pairs.
+     * Prepare array values for FFI by converting bools to 1/0, complex to flat pairs
+     * and half-precision floats to their 16-bit patterns.
      *
      * This is necessary because FFI bools are represented as uint8_t,
      * so boolean values must be converted to integers.
      *
      * @param array<bool|Complex|float|int> $values Array of values to prepare
      *
-     * @return array<float|int> Prepared values with bools converted and complex flattened
+     * @return array<float|int> Prepared values with bools converted, complex flattened and halves encoded
      */
     public function prepareArrayValues(array $values): array
     {
@@ -373,10 +398,89 @@ enum DType: int
             return $result;
         }
 
+        if ($this->isHalf()) {
+            return array_map(fn ($v) => $this->toHalfBits((float) $v), $values);
+        }
+
         if (!$this->isBool()) {
             return $values;
         }
 
         return array_map(static fn ($v) => $v ? 1 : 0, $values);
     }
+
+    /**
+     * Encode a float as the 16-bit pattern of this half-precision dtype.
+     *
+     * Rounds to nearest, ties to even, matching the native conversion.
+     */
+    public function toHalfBits(float $value): int
+    {
+        $bits = unpack('V', pack('g', $value))[1];
+
+        if (self::BFloat16 === $this) {
+            if (is_nan($value)) {
+                return ($bits >> 16) | 0x0040;
+            }
+
+            return (($bits + 0x7FFF + (($bits >> 16) & 1)) >> 16) & 0xFFFF;
+        }
+
+        $sign = ($bits >> 16) & 0x8000;
+        $exp = ($bits >> 23) & 0xFF;
+        $mant = $bits & 0x7FFFFF;
+
+        if (0xFF === $exp) {
+            return $sign | 0x7C00 | (0 !== $mant ? 0x0200 : 0);
+        }
+
+        $halfExp = $exp - 127 + 15;
+        if ($halfExp >= 0x1F) {
+            return $sign | 0x7C00;
+        }
+
+        if ($halfExp <= 0) {
+            if ($halfExp < -10) {
+                return $sign;
+            }
+            $mant |= 0x800000;
+            $shift = 14 - $halfExp;
+            $half = $mant >> $shift;
+            $rem = $mant & ((1 << $shift) - 1);
+            $halfway = 1 << ($shift - 1);
+            if ($rem > $halfway || ($rem === $halfway && ($half & 1))) {
+                ++$half;
+            }
+
+            return $sign | $half;
+        }
+
+        $half = $sign | ($halfExp << 10) | ($mant >> 13);
+        $rem = $mant & 0x1FFF;
+        if ($rem > 0x1000 || (0x1000 === $rem && ($half & 1))) {
+            ++$half;
+        }
+
+        return $half;
+    }
+
+    /**
+     * Decode a 16-bit pattern of this half-precision dtype to a float.
+     */
+    public function fromHalfBits(int $bits): float
+    {
+        if (self::BFloat16 === $this) {
+            return unpack('g', pack('V', ($bits & 0xFFFF) << 16))[1];
+        }
+
+        $sign = ($bits & 0x8000) ? -1.0 : 1.0;
+        $exp = ($bits >> 10) & 0x1F;
+        $mant = $bits & 0x3FF;
+
+        return match ($exp) {
+            0 => $sign * $mant * 2 ** -24,
+            0x1F => 0 === $mant ? $sign * \INF : \NAN,
+            default => $sign * (1 + $mant / 1024) * 2 ** ($exp - 15),
+        };
+    }
 }
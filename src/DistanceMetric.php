@@ -0,0 +1,20 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Distance metric for NDArray::nearestNeighbors().
+ *
+ * Integer values must stay in sync with Rust DistanceMetric.
+ */
+enum DistanceMetric: int
+{
+    case Euclidean = 0;
+    case SquaredEuclidean = 1;
+    case Manhattan = 2;
+
+    /** `1 - cosine similarity`; zero vectors are at distance 1 from everything. */
+    case Cosine = 3;
+}
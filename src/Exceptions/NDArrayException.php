@@ -8,5 +8,25 @@ use Exception;
 
 /**
  * Base exception for all NDArray errors.
+ *
+ * The exception code is the status code returned by the native library.
  */
-class NDArrayException extends \Exception {}
+class NDArrayException extends \Exception
+{
+    public function __construct(
+        string $message = '',
+        int $code = 0,
+        ?\Throwable $previous = null,
+        private readonly ?string $operation = null,
+    ) {
+        parent::__construct($message, $code, $previous);
+    }
+
+    /**
+     * Name of the native operation that failed (e.g. "inv"), if known.
+     */
+    public function getOperation(): ?string
+    {
+        return $this->operation;
+    }
+}
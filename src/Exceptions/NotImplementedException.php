@@ -0,0 +1,10 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Exceptions;
+
+/**
+ * Exception for operations or formats the library does not support yet.
+ */
+class NotImplementedException extends NDArrayException {}
@@ -0,0 +1,10 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Exceptions;
+
+/**
+ * Exception for integer results that do not fit their dtype (OverflowMode::Error).
+ */
+class OverflowException extends MathException {}
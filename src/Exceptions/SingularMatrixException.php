@@ -0,0 +1,10 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Exceptions;
+
+/**
+ * Exception for inverting or solving with a singular matrix.
+ */
+class SingularMatrixException extends MathException {}
@@ -0,0 +1,10 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Exceptions;
+
+/**
+ * Exception for invalid argument values (unknown modes, out-of-range parameters).
+ */
+class ValueException extends NDArrayException {}
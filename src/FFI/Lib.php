@@ -11,8 +11,12 @@ use PhpMlKit\NDArray\Exceptions\DTypeException;
 use PhpMlKit\NDArray\Exceptions\IndexException;
 use PhpMlKit\NDArray\Exceptions\MathException;
 use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\Exceptions\NotImplementedException;
+use PhpMlKit\NDArray\Exceptions\OverflowException;
 use PhpMlKit\NDArray\Exceptions\PanicException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\Exceptions\SingularMatrixException;
+use PhpMlKit\NDArray\Exceptions\ValueException;
 
 /**
  * Singleton wrapper for the Rust NDArray FFI library.
@@ -21,42 +25,113 @@ use PhpMlKit\NDArray\Exceptions\ShapeException;
  * and forwarded to the underlying \FFI instance through __call().
  *
  * @method int   ndarray_get_last_error(CData $buf, int $len)
+ * @method int   ndarray_get_last_error_struct(CData $out)
+ * @method int   ndarray_backend_info(CData $out_blas, CData $buf, int $len)
+ * @method int   ndarray_set_num_threads(int $num_threads)
+ * @method int   ndarray_get_num_threads()
+ * @method int   ndarray_set_parallel_threshold(int $threshold)
+ * @method int   ndarray_get_parallel_threshold()
  * @method int   ndarray_to_string(CData $handle, CData $meta, CData $buf, int $buf_size, int $threshold, int $edgeitems, int $precision)
- * @method int   ndarray_create(CData $data, int $len, CData $shape, int $ndim, int $dtype, CData $out_handle)
+ * @method int   ndarray_create(CData $data, int $len, CData $shape, int $ndim, int $dtype, int $order, CData $out_handle)
  * @method int   ndarray_copy(CData $handle, CData $meta, CData $out_handle)
  * @method int   ndarray_free(CData $handle)
- * @method int   ndarray_zeros(CData $shape, int $ndim, int $dtype, CData $out_handle)
- * @method int   ndarray_ones(CData $shape, int $ndim, int $dtype, CData $out_handle)
- * @method int   ndarray_full(CData $shape, int $ndim, CData $value, int $dtype, CData $out_handle)
+ * @method int   ndarray_zeros(CData $shape, int $ndim, int $dtype, int $order, CData $out_handle)
+ * @method int   ndarray_ones(CData $shape, int $ndim, int $dtype, int $order, CData $out_handle)
+ * @method int   ndarray_full(CData $shape, int $ndim, CData $value, int $dtype, int $order, CData $out_handle)
  * @method int   ndarray_eye(int $n, int $m, int $k, int $dtype, CData $out_handle)
+ * @method int   ndarray_tri(int $n, int $m, int $k, int $dtype, CData $out_handle)
  * @method int   ndarray_arange(float $start, float $stop, float $step, int $dtype, CData $out_handle)
+ * @method int   ndarray_arange_i64(int $start, int $stop, int $step, int $dtype, CData $out_handle, CData $out_len)
+ * @method int   ndarray_arange_u64(int $start, int $stop, int $step, int $dtype, CData $out_handle, CData $out_len)
  * @method int   ndarray_linspace(float $start, float $stop, int $num, bool $endpoint, int $dtype, CData $out_handle)
  * @method int   ndarray_logspace(float $start, float $stop, int $num, float $base, int $dtype, CData $out_handle)
  * @method int   ndarray_geomspace(float $start, float $stop, int $num, int $dtype, CData $out_handle)
- * @method int   ndarray_random(CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, CData $out_handle)
- * @method int   ndarray_random_int(int $low, int $high, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, CData $out_handle)
- * @method int   ndarray_randn(CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, CData $out_handle)
- * @method int   ndarray_normal(float $mean, float $std, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, CData $out_handle)
- * @method int   ndarray_uniform(float $low, float $high, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, CData $out_handle)
+ * @method int   ndarray_random(CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_random_int(int $low, int $high, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_randn(CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_normal(float $mean, float $std, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_uniform(float $low, float $high, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_standard_cauchy(CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_standard_t(float $df, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_geometric(float $p, CData $shape, int $ndim, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_hypergeometric(int $ngood, int $nbad, int $nsample, CData $shape, int $ndim, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_binomial(int $n, float $p, CData $shape, int $ndim, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_poisson(float $lam, CData $shape, int $ndim, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_exponential(float $scale, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_gamma(float $k, float $theta, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_beta(float $a, float $b, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_chisquare(float $df, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_multivariate_normal(CData $mean, CData $mean_meta, CData $cov, CData $cov_meta, CData $shape, int $ndim, int $dtype, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_permutation(int $n, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_shuffle(CData $handle, CData $meta, int $axis, bool $has_seed, int $seed, ?CData $rng)
+ * @method int   ndarray_choice(int $n, CData $shape, int $ndim, bool $replace, ?CData $p, ?CData $p_meta, bool $has_seed, int $seed, ?CData $rng, CData $out_handle)
+ * @method int   ndarray_rng_new(int $seed, CData $out_rng)
+ * @method int   ndarray_rng_from_entropy(CData $out_rng)
+ * @method int   ndarray_rng_free(CData $rng)
  * @method int   ndarray_get_element(CData $handle, int $flat_index, CData $out_value)
  * @method int   ndarray_set_element(CData $handle, int $flat_index, CData $value)
+ * @method int   ndarray_get_many(CData $handle, CData $meta, CData $indices, int $n, int $index_ndim, CData $out_values)
+ * @method int   ndarray_set_many(CData $handle, CData $meta, CData $indices, int $n, int $index_ndim, CData $values)
  * @method int   ndarray_as_scalar(CData $handle, CData $meta, CData $out_value)
  * @method int   ndarray_get_data(CData $handle, CData $meta, int $start, int $len, CData $out_data, CData $out_len)
+ * @method int   ndarray_iter_new(CData $handle, CData $meta, CData $out_iter)
+ * @method int   ndarray_iter_next_block(CData $iter, int $max_count, CData $out_values, ?CData $out_indices, CData $out_count)
+ * @method int   ndarray_iter_reset(CData $iter)
+ * @method int   ndarray_iter_free(CData $iter)
+ * @method int   ndarray_byteswap(CData $handle, CData $meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_quantize(CData $handle, CData $meta, CData $scale_handle, CData $scale_meta, CData $zp_handle, CData $zp_meta, int $axis, int $target_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_dequantize(CData $handle, CData $meta, CData $scale_handle, CData $scale_meta, CData $zp_handle, CData $zp_meta, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_hash(CData $handle, CData $meta, int $seed, CData $out_hash)
+ * @method int   ndarray_flags(CData $handle, CData $meta, CData $out_itemsize, CData $out_nbytes, CData $out_c_contiguous, CData $out_f_contiguous, CData $out_owns_data)
+ * @method int   ndarray_convolve(CData $a, CData $a_meta, CData $v, CData $v_meta, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_convolve2d(CData $a, CData $a_meta, CData $kernel, CData $kernel_meta, int $mode, int $stride_h, int $stride_w, int $pad_h, int $pad_w, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_correlate2d(CData $a, CData $a_meta, CData $kernel, CData $kernel_meta, int $mode, int $stride_h, int $stride_w, int $pad_h, int $pad_w, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rolling_sum(CData $handle, CData $meta, int $window, int $axis, int $min_periods, bool $center, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rolling_mean(CData $handle, CData $meta, int $window, int $axis, int $min_periods, bool $center, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rolling_min(CData $handle, CData $meta, int $window, int $axis, int $min_periods, bool $center, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rolling_max(CData $handle, CData $meta, int $window, int $axis, int $min_periods, bool $center, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rolling_std(CData $handle, CData $meta, int $window, int $axis, int $min_periods, bool $center, int $ddof, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_map(CData $handle, CData $meta, \Closure|CData $callback, int $target_dtype, int $batch_size, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_reduce(CData $handle, CData $meta, int $axis, bool $keepdims, float $init, \Closure|CData $callback, int $batch_size, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_gufunc_register(CData $name, CData $signature, \Closure|CData $kernel, CData $out_id)
+ * @method int   ndarray_gufunc_find(CData $name, CData $out_id)
+ * @method int   ndarray_gufunc_unregister(int $id)
+ * @method int   ndarray_gufunc_call(int $id, CData $handles, CData $metas, int $num_inputs, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_take(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_take_axis(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_take_along_axis(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_put(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, CData $values, int $values_len, float $scalar_value, bool $has_scalar, CData $out_handle)
+ * @method int   ndarray_take_rows(CData $a, CData $a_meta, CData $indices, CData $indices_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_put(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, ?CData $values_handle, ?CData $values_meta, ?CData $scalar, int $scalar_dtype, CData $out_handle)
+ * @method int   ndarray_put_axis(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, int $axis, ?CData $values_handle, ?CData $values_meta, ?CData $scalar, int $scalar_dtype, CData $out_handle)
  * @method int   ndarray_put_along_axis(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, int $axis, CData $values, int $values_len, float $scalar_value, bool $has_scalar, CData $out_handle)
- * @method int   ndarray_where(CData $cond_handle, CData $cond_meta, CData $x_handle, CData $x_meta, CData $y_handle, CData $y_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_where(CData $cond_handle, CData $cond_meta, ?CData $x_handle, ?CData $x_meta, CData $y_handle, CData $y_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_where_scalar(CData $cond_handle, CData $cond_meta, CData $array_handle, CData $array_meta, CData $scalar, int $scalar_dtype, bool $scalar_is_x, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_boolean_select(CData $handle, CData $meta, CData $mask, CData $mask_meta, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_boolean_assign(CData $handle, CData $meta, CData $mask, CData $mask_meta, ?CData $values, ?CData $values_meta, ?CData $scalar, int $scalar_dtype)
  * @method int   ndarray_scatter_add_flat(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, CData $updates, int $updates_len, float $scalar_update, bool $has_scalar, CData $out_handle)
+ * @method int   ndarray_scatter(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, CData $updates_handle, CData $updates_meta, int $axis, int $mode, bool $include_self, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_scatter_flat(CData $handle, CData $meta, CData $indices_handle, CData $indices_meta, ?CData $updates_handle, ?CData $updates_meta, ?CData $scalar, int $scalar_dtype, int $mode, bool $include_self, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_fill(CData $handle, CData $meta, CData $value)
  * @method int   ndarray_assign(CData $dst, CData $dst_meta, CData $src, CData $src_meta)
- * @method int   ndarray_add(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_add_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_sub_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_mul_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_copyto(CData $dst, CData $dst_meta, CData $src, CData $src_meta, ?CData $mask, ?CData $mask_meta, int $casting)
+ * @method int   ndarray_add(CData $a, CData $a_meta, CData $b, CData $b_meta, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_sub(CData $a, CData $a_meta, CData $b, CData $b_meta, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_mul(CData $a, CData $a_meta, CData $b, CData $b_meta, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_binary_op_out(int $op, CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out, CData $out_meta)
+ * @method int   ndarray_add_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_sub_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_mul_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_div_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_rem_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rsub_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, int $overflow, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rdiv_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_rpow_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_floordiv(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_floordiv_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_mod(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_mod_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_fmod(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_fmod_scalar(CData $a, CData $a_meta, CData $scalar, int $scalar_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_hypot(CData $a, CData $a_meta, float $b, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_minimum(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_maximum(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
@@ -113,15 +188,36 @@ use PhpMlKit\NDArray\Exceptions\ShapeException;
  * @method int   ndarray_angle(CData $a, CData $a_meta, int $deg, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_iscomplex(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_isreal(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_isnan(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_isinf(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_isfinite(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_powi(CData $a, CData $a_meta, int $exp, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_powf(CData $a, CData $a_meta, float $exp, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_clamp(CData $a, CData $a_meta, float $min_val, float $max_val, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_clamp(CData $a, CData $a_meta, CData $min_val, int $min_dtype, CData $max_val, int $max_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_sigmoid(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_sigmoid_inplace(CData $a, CData $a_meta)
+ * @method int   ndarray_relu(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_relu_inplace(CData $a, CData $a_meta)
+ * @method int   ndarray_leaky_relu(CData $a, CData $a_meta, float $alpha, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_leaky_relu_inplace(CData $a, CData $a_meta, float $alpha)
+ * @method int   ndarray_gelu(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_gelu_inplace(CData $a, CData $a_meta)
+ * @method int   ndarray_silu(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_silu_inplace(CData $a, CData $a_meta)
+ * @method int   ndarray_softplus(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_softplus_inplace(CData $a, CData $a_meta)
  * @method int   ndarray_softmax(CData $handle, CData $meta, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_sum(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
- * @method int   ndarray_sum_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_mean(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
- * @method int   ndarray_mean_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_diff(CData $handle, CData $meta, int $n, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_gradient(CData $handle, CData $meta, int $axis, float $spacing, int $edge_order, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_interp(CData $x, CData $x_meta, CData $xp, CData $xp_meta, CData $fp, CData $fp_meta, bool $has_left, float $left, bool $has_right, float $right, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_standardize(CData $handle, CData $meta, int $axis, ?CData $mean_handle, ?CData $mean_meta, ?CData $std_handle, ?CData $std_meta, int $ddof, float $epsilon, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_standardize_inplace(CData $handle, CData $meta, int $axis, ?CData $mean_handle, ?CData $mean_meta, ?CData $std_handle, ?CData $std_meta, int $ddof, float $epsilon)
+ * @method int   ndarray_minmax_scale(CData $handle, CData $meta, int $axis, float $feature_min, float $feature_max, ?CData $out_data_min, ?CData $out_scale, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_l2_normalize(CData $handle, CData $meta, int $axis, float $epsilon, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_sum(CData $handle, CData $meta, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_sum_axis(CData $handle, CData $meta, int $axis, bool $keepdims, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_mean(CData $handle, CData $meta, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_mean_axis(CData $handle, CData $meta, int $axis, bool $keepdims, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_min(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
  * @method int   ndarray_min_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_max(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
@@ -136,22 +232,62 @@ use PhpMlKit\NDArray\Exceptions\ShapeException;
  * @method int   ndarray_cumsum_axis(CData $handle, CData $meta, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_cumprod(CData $handle, CData $meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_cumprod_axis(CData $handle, CData $meta, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_var(CData $handle, CData $meta, float $ddof, CData $out_value, CData $out_dtype_ptr)
- * @method int   ndarray_var_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $ddof, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_std(CData $handle, CData $meta, float $ddof, CData $out_value, CData $out_dtype_ptr)
- * @method int   ndarray_std_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $ddof, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_var(CData $handle, CData $meta, float $ddof, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_var_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $ddof, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_std(CData $handle, CData $meta, float $ddof, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_std_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $ddof, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_nansum(CData $handle, CData $meta, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_nansum_axis(CData $handle, CData $meta, int $axis, bool $keepdims, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_nanmean(CData $handle, CData $meta, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_nanmean_axis(CData $handle, CData $meta, int $axis, bool $keepdims, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_nanmin(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_nanmin_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_nanmax(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_nanmax_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_nanstd(CData $handle, CData $meta, float $ddof, int $mode, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_nanstd_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $ddof, int $mode, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_median(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_median_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_quantile(CData $handle, CData $meta, float $q, int $method, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_quantile_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $q, int $method, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_percentile(CData $handle, CData $meta, float $p, int $method, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_percentile_axis(CData $handle, CData $meta, int $axis, bool $keepdims, float $p, int $method, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_set_deterministic(bool $enabled)
+ * @method bool  ndarray_is_deterministic()
  * @method int   ndarray_any(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
  * @method int   ndarray_any_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_all(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
  * @method int   ndarray_all_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_bincount(CData $handle, CData $meta, int $minlength, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_sort_axis(CData $handle, CData $meta, int $axis, int $kind, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_sort_flat(CData $handle, CData $meta, int $kind, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_argsort_axis(CData $handle, CData $meta, int $axis, int $kind, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_argsort_flat(CData $handle, CData $meta, int $kind, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_count_nonzero(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_count_nonzero_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_segment_sum(CData $handle, CData $meta, CData $ids_handle, CData $ids_meta, int $num_segments, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_segment_mean(CData $handle, CData $meta, CData $ids_handle, CData $ids_meta, int $num_segments, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_segment_min(CData $handle, CData $meta, CData $ids_handle, CData $ids_meta, int $num_segments, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_segment_max(CData $handle, CData $meta, CData $ids_handle, CData $ids_meta, int $num_segments, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_group_by(CData $keys_handle, CData $keys_meta, CData $values_handle, CData $values_meta, CData $out_keys, CData $out_counts, CData $out_sum, CData $out_mean, CData $out_min, CData $out_max, CData $out_num_groups)
+ * @method int   ndarray_histogram(CData $handle, CData $meta, int $bins, ?CData $range, ?CData $edges, ?CData $edges_meta, ?CData $weights, ?CData $weights_meta, CData $out_counts, CData $out_edges)
+ * @method int   ndarray_histogram2d(CData $x, CData $x_meta, CData $y, CData $y_meta, int $bins_x, int $bins_y, ?CData $range, ?CData $x_edges, ?CData $x_edges_meta, ?CData $y_edges, ?CData $y_edges_meta, ?CData $weights, ?CData $weights_meta, CData $out_counts, CData $out_x_edges, CData $out_y_edges)
+ * @method int   ndarray_logsumexp(CData $handle, CData $meta, CData $out_value, CData $out_dtype_ptr)
+ * @method int   ndarray_logsumexp_axis(CData $handle, CData $meta, int $axis, bool $keepdims, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_trapz(CData $handle, CData $meta, ?CData $x_handle, ?CData $x_meta, float $dx, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_cumtrapz(CData $handle, CData $meta, ?CData $x_handle, ?CData $x_meta, float $dx, int $axis, bool $include_initial, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_sort_axis(CData $handle, CData $meta, int $axis, int $kind, bool $descending, bool $nan_first, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_sort_flat(CData $handle, CData $meta, int $kind, bool $descending, bool $nan_first, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_argsort_axis(CData $handle, CData $meta, int $axis, int $kind, bool $descending, bool $nan_first, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_argsort_flat(CData $handle, CData $meta, int $kind, bool $descending, bool $nan_first, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_topk_axis(CData $handle, CData $meta, int $axis, int $k, bool $largest, bool $sorted, int $kind, CData $out_values, CData $out_indices, CData $out_shape, int $max_ndim)
  * @method int   ndarray_topk_flat(CData $handle, CData $meta, int $k, bool $largest, bool $sorted, int $kind, CData $out_values, CData $out_indices, CData $out_shape)
+ * @method int   ndarray_partition(CData $handle, CData $meta, CData $kth, int $num_kth, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_argpartition(CData $handle, CData $meta, CData $kth, int $num_kth, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_unique(CData $handle, CData $meta, CData $out_values, ?CData $out_inverse, ?CData $out_counts, CData $out_len)
+ * @method int   ndarray_unique_axis(CData $handle, CData $meta, int $axis, CData $out_values, ?CData $out_index, ?CData $out_inverse, ?CData $out_counts, CData $out_len)
+ * @method int   ndarray_intersect1d(CData $a, CData $a_meta, CData $b, CData $b_meta, bool $assume_unique, CData $out, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_union1d(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_setdiff1d(CData $a, CData $a_meta, CData $b, CData $b_meta, bool $assume_unique, CData $out, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_isin(CData $a, CData $a_meta, CData $b, CData $b_meta, bool $assume_unique, bool $invert, CData $out, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_astype(CData $handle, CData $meta, int $target_dtype, CData $out_handle)
+ * @method int   ndarray_astype_checked(CData $handle, CData $meta, int $target_dtype, int $policy, CData $out, CData $out_count)
  * @method int   ndarray_reshape(CData $handle, CData $meta, CData $new_shape, int $new_ndim, int $order, CData $out_handle)
  * @method int   ndarray_transpose(CData $handle, CData $meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_flip(CData $handle, CData $meta, CData $axes, int $num_axes, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
@@ -160,17 +296,26 @@ use PhpMlKit\NDArray\Exceptions\ShapeException;
  * @method int   ndarray_ravel(CData $handle, CData $meta, int $order, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_pad(CData $handle, CData $meta, CData $pad_width, int $mode, CData $constant_values, int $constant_values_len, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_dot(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_matmul(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_matmul(CData $a, CData $a_meta, CData $b, CData $b_meta, int $acc_dtype, bool $has_acc_dtype, int $result_dtype, bool $has_result_dtype, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_tensordot(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $axes_a, CData $axes_b, int $num_axes, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_outer(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_kron(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_cross(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_cosine_similarity(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_argmin_distance(CData $query, CData $query_meta, CData $database, CData $database_meta, int $metric, int $k, CData $out_indices, CData $out_distances, CData $out_shape, int $max_ndim)
  * @method int   ndarray_diagonal(CData $handle, CData $meta, int $offset, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_from_diag(CData $handle, CData $meta, int $offset, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_trace(CData $handle, CData $meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_tril(CData $handle, CData $meta, int $k, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_triu(CData $handle, CData $meta, int $k, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_norm(CData $handle, CData $meta, int $ord, CData $out_value, CData $out_dtype_ptr)
  * @method int   ndarray_norm_axis(CData $handle, CData $meta, int $axis, bool $keepdims, int $ord, CData $out_handle)
  * @method int   ndarray_solve(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_inv(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_det(CData $a, CData $a_meta, CData $out_value, CData $out_dtype_ptr)
- * @method int   ndarray_svd(CData $a, CData $a_meta, int $calc_u, int $calc_vt, CData $out_u, CData $out_dtype_u, CData $out_ndim_u, CData $out_shape_u, int $max_ndim, CData $out_s, CData $out_dtype_s, CData $out_ndim_s, CData $out_shape_s, CData $out_vt, CData $out_dtype_vt, CData $out_ndim_vt, CData $out_shape_vt)
+ * @method int   ndarray_det(CData $a, CData $a_meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_svd(CData $a, CData $a_meta, int $calc_u, int $calc_vt, int $full_matrices, CData $out_u, CData $out_dtype_u, CData $out_ndim_u, CData $out_shape_u, int $max_ndim, CData $out_s, CData $out_dtype_s, CData $out_ndim_s, CData $out_shape_s, CData $out_vt, CData $out_dtype_vt, CData $out_ndim_vt, CData $out_shape_vt)
  * @method int   ndarray_qr(CData $a, CData $a_meta, CData $out_q, CData $out_dtype_q, CData $out_ndim_q, CData $out_shape_q, int $max_ndim, CData $out_r, CData $out_dtype_r, CData $out_ndim_r, CData $out_shape_r)
+ * @method int   ndarray_lu(CData $a, CData $a_meta, CData $out_l, CData $out_dtype_l, CData $out_ndim_l, CData $out_shape_l, int $max_ndim, CData $out_u, CData $out_dtype_u, CData $out_ndim_u, CData $out_shape_u, CData $out_perm, CData $out_dtype_perm, CData $out_ndim_perm, CData $out_shape_perm)
  * @method int   ndarray_eig(CData $a, CData $a_meta, CData $out_eigvals, CData $out_dtype_eigvals, CData $out_ndim_eigvals, CData $out_shape_eigvals, int $max_ndim, CData $out_eigvecs, CData $out_dtype_eigvecs, CData $out_ndim_eigvecs, CData $out_shape_eigvecs)
  * @method int   ndarray_eigvals(CData $a, CData $a_meta, CData $out_eigvals, CData $out_dtype_eigvals, CData $out_ndim_eigvals, CData $out_shape_eigvals, int $max_ndim)
  * @method int   ndarray_eigh(CData $a, CData $a_meta, int $uplo, CData $out_eigvals, CData $out_dtype_eigvals, CData $out_ndim_eigvals, CData $out_shape_eigvals, int $max_ndim, CData $out_eigvecs, CData $out_dtype_eigvecs, CData $out_ndim_eigvecs, CData $out_shape_eigvecs)
@@ -178,15 +323,37 @@ use PhpMlKit\NDArray\Exceptions\ShapeException;
  * @method int   ndarray_cholesky(CData $a, CData $a_meta, int $upper, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_lstsq(CData $a, CData $a_meta, CData $b, CData $b_meta, CData $out_solution, CData $out_residuals, CData $out_rank, CData $out_s, CData $out_dtype_sol, CData $out_ndim_sol, CData $out_shape_sol, CData $out_dtype_res, CData $out_ndim_res, CData $out_shape_res, CData $out_dtype_s, CData $out_ndim_s, CData $out_shape_s, int $max_ndim)
  * @method int   ndarray_pinv(CData $a, CData $a_meta, CData $rcond, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_polyfit(CData $x, CData $x_meta, CData $y, CData $y_meta, int $deg, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_polyval(CData $p, CData $p_meta, CData $x, CData $x_meta, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_roots(CData $p, CData $p_meta, CData $out_real, CData $out_imag, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_cond(CData $a, CData $a_meta, CData $out_value, CData $out_dtype_ptr)
  * @method int   ndarray_rank(CData $a, CData $a_meta, CData $tol, CData $out_rank)
- * @method int   ndarray_einsum(CData $a, CData $a_meta, ?CData $b, ?CData $b_meta, CData $subscripts, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_einsum(CData $handles, CData $metas, int $num_operands, CData $subscripts, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_fft(CData $handle, CData $meta, int $axis, int $n, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_ifft(CData $handle, CData $meta, int $axis, int $n, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_fftn(CData $handle, CData $meta, ?CData $axes, int $n_axes, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_ifftn(CData $handle, CData $meta, ?CData $axes, int $n_axes, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_rfft(CData $handle, CData $meta, int $axis, int $n, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_irfft(CData $handle, CData $meta, int $axis, int $n, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_resize(CData $handle, CData $meta, int $out_height, int $out_width, int $interpolation, bool $antialias, int $layout, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_hwc_to_chw(CData $handle, CData $meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_chw_to_hwc(CData $handle, CData $meta, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_rgb_to_gray(CData $handle, CData $meta, int $layout, bool $keep_channel_axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_gray_to_rgb(CData $handle, CData $meta, int $layout, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_swap_rb(CData $handle, CData $meta, int $layout, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_decode(CData $bytes, int $len, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_image_encode(CData $handle, CData $meta, int $format, int $quality, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_save_npy(CData $handle, CData $meta, CData $path)
+ * @method int   ndarray_load_npy(CData $path, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_save_npz(CData $path, ?CData $names, ?CData $handles, ?CData $metas, int $num_arrays, bool $compressed)
+ * @method int   ndarray_npz_names(CData $path, ?CData $buf, int $len, CData $out_len)
+ * @method int   ndarray_load_npz(CData $path, CData $name, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_to_bytes(CData $handle, CData $meta, ?CData $buf, int $len, CData $out_len)
+ * @method int   ndarray_from_bytes(CData $buf, int $len, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_to_csv(CData $handle, CData $meta, CData $path, int $delimiter, ?CData $header, ?CData $nan_repr)
+ * @method int   ndarray_from_csv(CData $path, int $delimiter, int $skip_rows, int $dtype, float $fill_value, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_to_arrow_buffer(CData $handle, CData $meta, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_from_arrow_buffer(CData $bytes, int $len, CData $out_handle, CData $out_dtype, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_dct(CData $handle, CData $meta, int $axis, int $n, int $dct_type, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_idct(CData $handle, CData $meta, int $axis, int $n, int $dct_type, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_dctn(CData $handle, CData $meta, ?CData $axes, int $n_axes, int $dct_type, int $norm, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
@@ -200,11 +367,14 @@ use PhpMlKit\NDArray\Exceptions\ShapeException;
  * @method int   ndarray_kaiser(int $m, float $beta, bool $periodic, CData $out_handle)
  * @method int   ndarray_lanczos(int $m, bool $periodic, CData $out_handle)
  * @method int   ndarray_triang(int $m, bool $periodic, CData $out_handle)
- * @method int   ndarray_concatenate(CData $handles, CData $handles_meta, int $num_arrays, int $axis, CData $out_handle, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_stack(CData $handles, CData $handles_meta, int $num_arrays, int $axis, CData $out_handle, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_block(CData $handles, CData $handles_meta, int $num_arrays, ?CData $layout, int $layout_len, int $depth, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_concatenate(CData $handles, CData $handles_meta, int $num_arrays, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_stack(CData $handles, CData $handles_meta, int $num_arrays, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method int   ndarray_split(CData $handle, CData $meta, int $axis, CData $indices, int $num_indices, CData $out_offsets, CData $out_shapes, CData $out_strides)
+ * @method int   ndarray_array_split(CData $handle, CData $meta, int $axis, int $sections, CData $out_offsets, CData $out_shapes, CData $out_strides)
+ * @method int   ndarray_split_at(CData $handle, CData $meta, int $axis, ?CData $indices, int $num_indices, CData $out_offsets, CData $out_shapes, CData $out_strides)
  * @method int   ndarray_tile(CData $handle, CData $meta, CData $reps, int $reps_len, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
- * @method int   ndarray_repeat(CData $handle, CData $meta, CData $repeats, int $repeats_len, int $axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
+ * @method int   ndarray_repeat(CData $handle, CData $meta, CData $repeats, int $repeats_len, int $axis, bool $has_axis, CData $out_handle, CData $out_dtype_ptr, CData $out_ndim, CData $out_shape, int $max_ndim)
  * @method CData new(string $type, bool $owned = true)
  * @method CData cast(string $type, CData $ptr)
  */
@@ -329,6 +499,27 @@ final class Lib
         return \FFI::string($buffer, $len);
     }
 
+    /**
+     * Get the last error recorded by Rust with its code and operation.
+     *
+     * The operation is the failing function without its "ndarray_" prefix
+     * (e.g. "inv"), or an empty string when the error was raised before the
+     * operation started.
+     *
+     * @return array{code: int, operation: string, message: string}
+     */
+    public function getLastErrorDetail(): array
+    {
+        $detail = $this->new('struct NdArrayError');
+        $this->ndarray_get_last_error_struct(self::addr($detail));
+
+        return [
+            'code' => (int) $detail->code,
+            'operation' => \FFI::string($detail->operation),
+            'message' => \FFI::string($detail->message),
+        ];
+    }
+
     /**
      * Check the status code returned by a C function.
      *
@@ -340,17 +531,22 @@ final class Lib
             return;
         }
 
-        $message = $this->getLastError();
+        $detail = $this->getLastErrorDetail();
+        $message = '' !== $detail['message'] ? $detail['message'] : 'Unknown error';
+        $operation = '' !== $detail['operation'] ? $detail['operation'] : null;
 
         match ($code) {
-            1 => throw new NDArrayException($message),
-            2 => throw new ShapeException($message),
-            3 => throw new DTypeException($message),
-            4 => throw new AllocationException($message),
-            5 => throw new PanicException($message),
-            6 => throw new IndexException($message),
-            7 => throw new MathException($message),
-            default => throw new NDArrayException($message),
+            2 => throw new ShapeException($message, $code, operation: $operation),
+            3 => throw new DTypeException($message, $code, operation: $operation),
+            4 => throw new AllocationException($message, $code, operation: $operation),
+            5 => throw new PanicException($message, $code, operation: $operation),
+            6 => throw new IndexException($message, $code, operation: $operation),
+            7 => throw new MathException($message, $code, operation: $operation),
+            8 => throw new OverflowException($message, $code, operation: $operation),
+            9 => throw new NotImplementedException($message, $code, operation: $operation),
+            10 => throw new SingularMatrixException($message, $code, operation: $operation),
+            11 => throw new ValueException($message, $code, operation: $operation),
+            default => throw new NDArrayException($message, $code, operation: $operation),
         };
     }
 
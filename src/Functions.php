@@ -20,10 +20,11 @@ namespace PhpMlKit\NDArray {
      * @param array<mixed>    $data  Nested PHP array
      * @param null|array<int> $shape Optional shape. If null, inferred from data.
      * @param null|DType      $dtype Data type (auto-inferred if null)
+     * @param string          $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    function nd_array(array $data, ?array $shape = null, ?DType $dtype = null): NDArray
+    function nd_array(array $data, ?array $shape = null, ?DType $dtype = null, string $order = 'C'): NDArray
     {
-        return NDArray::fromArray($data, $shape, $dtype);
+        return NDArray::fromArray($data, $shape, $dtype, $order);
     }
 
     /**
@@ -31,10 +32,11 @@ namespace PhpMlKit\NDArray {
      *
      * @param array<int> $shape Array shape
      * @param DType      $dtype Data type (default: Float64)
+     * @param string     $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    function zeros(array $shape, DType $dtype = DType::Float64): NDArray
+    function zeros(array $shape, DType $dtype = DType::Float64, string $order = 'C'): NDArray
     {
-        return NDArray::zeros($shape, $dtype);
+        return NDArray::zeros($shape, $dtype, $order);
     }
 
     /**
@@ -42,10 +44,11 @@ namespace PhpMlKit\NDArray {
      *
      * @param array<int> $shape Array shape
      * @param DType      $dtype Data type (default: Float64)
+     * @param string     $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    function ones(array $shape, DType $dtype = DType::Float64): NDArray
+    function ones(array $shape, DType $dtype = DType::Float64, string $order = 'C'): NDArray
     {
-        return NDArray::ones($shape, $dtype);
+        return NDArray::ones($shape, $dtype, $order);
     }
 
     /**
@@ -54,10 +57,11 @@ namespace PhpMlKit\NDArray {
      * @param bool|Complex|float|int $value Value to fill array with
      * @param array<int>             $shape Array shape
      * @param null|DType             $dtype Data type (default: inferred from value)
+     * @param string                 $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    function full(bool|Complex|float|int $value, array $shape, ?DType $dtype = null): NDArray
+    function full(bool|Complex|float|int $value, array $shape, ?DType $dtype = null, string $order = 'C'): NDArray
     {
-        return NDArray::full($value, $shape, $dtype);
+        return NDArray::full($value, $shape, $dtype, $order);
     }
 
     /**
@@ -90,18 +94,20 @@ namespace PhpMlKit\NDArray {
     /**
      * Create an array from a binary string.
      *
-     * This method interprets a PHP binary string as raw array data in little-endian format.
-     * The bytes are copied into a new NDArray with the specified shape and dtype.
+     * This method interprets a PHP binary string as raw array data in the given byte order
+     * (little-endian by default). The bytes are copied into a new NDArray with the specified
+     * shape and dtype, swapping to native order when needed.
      *
-     * @param string     $bytes Binary string containing raw array data (little-endian)
-     * @param array<int> $shape Array shape
-     * @param DType      $dtype Data type of the buffer
+     * @param string     $bytes     Binary string containing raw array data
+     * @param array<int> $shape     Array shape
+     * @param DType      $dtype     Data type of the buffer
+     * @param ByteOrder  $byteOrder Byte order of `$bytes` (default: little-endian)
      *
      * @throws ShapeException If buffer size doesn't match shape
      */
-    function from_bytes(string $bytes, array $shape, DType $dtype): NDArray
+    function from_bytes(string $bytes, array $shape, DType $dtype, ByteOrder $byteOrder = ByteOrder::Little): NDArray
     {
-        return NDArray::fromBytes($bytes, $shape, $dtype);
+        return NDArray::fromBytes($bytes, $shape, $dtype, $byteOrder);
     }
 
     /**
@@ -165,6 +171,19 @@ namespace PhpMlKit\NDArray {
         return NDArray::eye($N, $M, $k, $dtype);
     }
 
+    /**
+     * Create a matrix with ones at and below the k-th diagonal.
+     *
+     * @param int      $N     Number of rows
+     * @param null|int $M     Number of columns (default: N)
+     * @param int      $k     Diagonal at and below which the array is filled
+     * @param DType    $dtype Data type (default: Float64)
+     */
+    function tri(int $N, ?int $M = null, int $k = 0, DType $dtype = DType::Float64): NDArray
+    {
+        return NDArray::tri($N, $M, $k, $dtype);
+    }
+
     /**
      * Create evenly spaced values within a given interval.
      *
@@ -185,7 +204,7 @@ namespace PhpMlKit\NDArray {
      * @param float $stop     The end value of the sequence
      * @param int   $num      Number of samples to generate
      * @param bool  $endpoint If true, stop is the last sample
-     * @param DType $dtype    Data type (default: Float64)
+     * @param DType $dtype    Data type: float or integer (default: Float64)
      */
     function linspace(
         float $start,
@@ -239,10 +258,11 @@ namespace PhpMlKit\NDArray {
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    function random(array $shape, ?DType $dtype = null, ?int $seed = null): NDArray
+    function random(array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
     {
-        return NDArray::random($shape, $dtype, $seed);
+        return NDArray::random($shape, $dtype, $seed, $rng);
     }
 
     /**
@@ -253,10 +273,11 @@ namespace PhpMlKit\NDArray {
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Integer dtype (default: Int64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    function random_int(int $low, int $high, array $shape, ?DType $dtype = null, ?int $seed = null): NDArray
+    function random_int(int $low, int $high, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
     {
-        return NDArray::randomInt($low, $high, $shape, $dtype, $seed);
+        return NDArray::randomInt($low, $high, $shape, $dtype, $seed, $rng);
     }
 
     /**
@@ -265,10 +286,11 @@ namespace PhpMlKit\NDArray {
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    function randn(array $shape, ?DType $dtype = null, ?int $seed = null): NDArray
+    function randn(array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
     {
-        return NDArray::randn($shape, $dtype, $seed);
+        return NDArray::randn($shape, $dtype, $seed, $rng);
     }
 
     /**
@@ -279,10 +301,11 @@ namespace PhpMlKit\NDArray {
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    function normal(float $mean, float $std, array $shape, ?DType $dtype = null, ?int $seed = null): NDArray
+    function normal(float $mean, float $std, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
     {
-        return NDArray::normal($mean, $std, $shape, $dtype, $seed);
+        return NDArray::normal($mean, $std, $shape, $dtype, $seed, $rng);
     }
 
     /**
@@ -293,10 +316,205 @@ namespace PhpMlKit\NDArray {
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function uniform(float $low, float $high, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::uniform($low, $high, $shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from the standard Cauchy distribution (median 0, scale 1).
+     *
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function standard_cauchy(array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::standardCauchy($shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from Student's t distribution.
+     *
+     * @param float      $df    Degrees of freedom (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function standard_t(float $df, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::standardT($df, $shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a geometric distribution (trials until first success).
+     *
+     * @param float      $p     Success probability of each trial, in (0, 1]
+     * @param array<int> $shape Output shape
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function geometric(float $p, array $shape, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::geometric($p, $shape, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a hypergeometric distribution.
+     *
+     * @param int        $ngood   Number of good items (>= 0)
+     * @param int        $nbad    Number of bad items (>= 0)
+     * @param int        $nsample Number of items drawn, at most `$ngood + $nbad`
+     * @param array<int> $shape   Output shape
+     * @param null|int   $seed    Optional seed for deterministic output
+     * @param null|Rng   $rng     Generator to draw from; overrides `$seed`
+     */
+    function hypergeometric(int $ngood, int $nbad, int $nsample, array $shape, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::hypergeometric($ngood, $nbad, $nsample, $shape, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a binomial distribution.
+     *
+     * @param int        $n     Number of trials (>= 0)
+     * @param float      $p     Success probability of each trial, in [0, 1]
+     * @param array<int> $shape Output shape
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function binomial(int $n, float $p, array $shape, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::binomial($n, $p, $shape, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a Poisson distribution.
+     *
+     * @param float      $lam   Expected number of events (>= 0)
+     * @param array<int> $shape Output shape
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function poisson(float $lam, array $shape, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::poisson($lam, $shape, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from an exponential distribution.
+     *
+     * @param float      $scale Mean of the distribution (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function exponential(float $scale, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::exponential($scale, $shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a gamma distribution.
+     *
+     * @param float      $k     Shape parameter (must be > 0)
+     * @param float      $theta Scale parameter (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function gamma(float $k, float $theta, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::gamma($k, $theta, $shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples in [0, 1] from a beta distribution.
+     *
+     * @param float      $a     First shape parameter (must be > 0)
+     * @param float      $b     Second shape parameter (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function beta(float $a, float $b, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::beta($a, $b, $shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a chi-square distribution.
+     *
+     * @param float      $df    Degrees of freedom (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    function chisquare(float $df, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): NDArray
+    {
+        return NDArray::chisquare($df, $shape, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create random samples from a multivariate normal distribution.
+     *
+     * @param array<float>|NDArray        $mean  Mean vector of length d
+     * @param array<array<float>>|NDArray $cov   Covariance matrix of shape [d, d]
+     * @param array<int>|int              $size  Number of samples, or their shape; [] draws one vector
+     * @param null|DType                  $dtype Float dtype (default: Float64)
+     * @param null|int                    $seed  Optional seed for deterministic output
+     * @param null|Rng                    $rng   Generator to draw from; overrides `$seed`
+     */
+    function multivariate_normal(
+        array|NDArray $mean,
+        array|NDArray $cov,
+        array|int $size = [],
+        ?DType $dtype = null,
+        ?int $seed = null,
+        ?Rng $rng = null
+    ): NDArray {
+        return NDArray::multivariateNormal($mean, $cov, $size, $dtype, $seed, $rng);
+    }
+
+    /**
+     * Create a random permutation of the integers `0..n-1`.
+     *
+     * @param int      $n    Number of elements (>= 0)
+     * @param null|int $seed Optional seed for deterministic output
+     * @param null|Rng $rng  Generator to draw from; overrides `$seed`
      */
-    function uniform(float $low, float $high, array $shape, ?DType $dtype = null, ?int $seed = null): NDArray
+    function permutation(int $n, ?int $seed = null, ?Rng $rng = null): NDArray
     {
-        return NDArray::uniform($low, $high, $shape, $dtype, $seed);
+        return NDArray::permutation($n, $seed, $rng);
+    }
+
+    /**
+     * Draw a random sample from a 1-D array, or from the integers `0..n-1`.
+     *
+     * @param array<mixed>|int|NDArray  $a       1-D population, or n to sample from `0..n-1`
+     * @param null|array<int>|int       $size    Output shape; null draws a single value
+     * @param bool                      $replace Whether an entry may be drawn more than once
+     * @param null|array<float>|NDArray $p       Optional non-negative weight per entry of `$a`
+     * @param null|int                  $seed    Optional seed for deterministic output
+     * @param null|Rng                  $rng     Generator to draw from; overrides `$seed`
+     */
+    function choice(
+        array|int|NDArray $a,
+        array|int|null $size = null,
+        bool $replace = true,
+        array|NDArray|null $p = null,
+        ?int $seed = null,
+        ?Rng $rng = null
+    ): bool|Complex|float|int|NDArray {
+        return NDArray::choice($a, $size, $replace, $p, $seed, $rng);
     }
 
     /**
@@ -369,81 +587,163 @@ namespace PhpMlKit\NDArray {
     }
 
     // =============================================================================
-    // HasMath — element-wise arithmetic, ufuncs, bitwise, clamp, min/max, softmax
+    // HasMath — element-wise arithmetic, ufuncs, bitwise, clamp, min/max, softmax, diff/gradient, interp
     // =============================================================================
 
     /**
      * Add another array or scalar to this array.
      *
-     * @param Complex|float|int|NDArray $other Array or scalar to add
+     * @param Complex|float|int|NDArray $other    Array or scalar to add
+     * @param null|NDArray              $out      Existing array to write the result into
+     * @param OverflowMode              $overflow What integer results do when they overflow
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    function add(NDArray $a, Complex|float|int|NDArray $other): NDArray
-    {
-        return $a->add($other);
+    function add(
+        NDArray $a,
+        Complex|float|int|NDArray $other,
+        ?NDArray $out = null,
+        OverflowMode $overflow = OverflowMode::Wrap,
+    ): NDArray {
+        return $a->add($other, $out, $overflow);
     }
 
     /**
      * Subtract another array or scalar from this array.
      *
-     * @param Complex|float|int|NDArray $other Array or scalar to subtract
+     * @param Complex|float|int|NDArray $other    Array or scalar to subtract
+     * @param null|NDArray              $out      Existing array to write the result into
+     * @param OverflowMode              $overflow What integer results do when they overflow
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    function subtract(NDArray $a, Complex|float|int|NDArray $other): NDArray
-    {
-        return $a->subtract($other);
+    function subtract(
+        NDArray $a,
+        Complex|float|int|NDArray $other,
+        ?NDArray $out = null,
+        OverflowMode $overflow = OverflowMode::Wrap,
+    ): NDArray {
+        return $a->subtract($other, $out, $overflow);
     }
 
     /**
      * Multiply this array by another array or scalar.
      *
-     * @param Complex|float|int|NDArray $other Array or scalar to multiply by
+     * @param Complex|float|int|NDArray $other    Array or scalar to multiply by
+     * @param null|NDArray              $out      Existing array to write the result into
+     * @param OverflowMode              $overflow What integer results do when they overflow
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    function multiply(NDArray $a, Complex|float|int|NDArray $other): NDArray
-    {
-        return $a->multiply($other);
+    function multiply(
+        NDArray $a,
+        Complex|float|int|NDArray $other,
+        ?NDArray $out = null,
+        OverflowMode $overflow = OverflowMode::Wrap,
+    ): NDArray {
+        return $a->multiply($other, $out, $overflow);
     }
 
     /**
      * Divide this array by another array or scalar.
      *
      * @param Complex|float|int|NDArray $other Array or scalar to divide by
+     * @param null|NDArray              $out   Existing array to write the result into
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    function divide(NDArray $a, Complex|float|int|NDArray $other): NDArray
+    function divide(NDArray $a, Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
     {
-        return $a->divide($other);
+        return $a->divide($other, $out);
     }
 
     /**
      * Compute remainder (modulo) with another array or scalar.
      *
      * @param Complex|float|int|NDArray $other Array or scalar
+     * @param null|NDArray              $out   Existing array to write the result into
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    function rem(NDArray $a, Complex|float|int|NDArray $other): NDArray
+    function rem(NDArray $a, Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
     {
-        return $a->rem($other);
+        return $a->rem($other, $out);
     }
 
     /**
      * Compute modulo with another array or scalar.
      *
-     * Alias for rem().
+     * The result takes the sign of the divisor (Python `%`).
      *
      * @param Complex|float|int|NDArray $other Array or scalar
+     * @param null|NDArray              $out   Existing array to copy the result into
+     *
+     * @return NDArray New array with result, or `$out` when given
+     */
+    function mod(NDArray $a, Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
+    {
+        return $a->mod($other, $out);
+    }
+
+    /**
+     * Compute floor division with another array or scalar.
+     *
+     * @param float|int|NDArray $other Array or scalar
+     *
+     * @return NDArray New array with result
+     */
+    function floor_divide(NDArray $a, float|int|NDArray $other): NDArray
+    {
+        return $a->floorDivide($other);
+    }
+
+    /**
+     * Compute the C-style remainder with another array or scalar.
+     *
+     * @param float|int|NDArray $other Array or scalar
+     *
+     * @return NDArray New array with result
+     */
+    function fmod(NDArray $a, float|int|NDArray $other): NDArray
+    {
+        return $a->fmod($other);
+    }
+
+    /**
+     * Subtract an array from a scalar ($scalar - $a).
+     *
+     * @param Complex|float|int $scalar   Left-hand scalar
+     * @param OverflowMode      $overflow What integer results do when they overflow
+     *
+     * @return NDArray New array with result
+     */
+    function rsub(NDArray $a, Complex|float|int $scalar, OverflowMode $overflow = OverflowMode::Wrap): NDArray
+    {
+        return $a->rsub($scalar, $overflow);
+    }
+
+    /**
+     * Divide a scalar by an array ($scalar / $a).
+     *
+     * @param Complex|float|int $scalar Dividend
+     *
+     * @return NDArray New array with result
+     */
+    function rdiv(NDArray $a, Complex|float|int $scalar): NDArray
+    {
+        return $a->rdiv($scalar);
+    }
+
+    /**
+     * Raise a scalar to the power of each element ($scalar ** $a).
+     *
+     * @param Complex|float|int $scalar Base
      *
      * @return NDArray New array with result
      */
-    function mod(NDArray $a, Complex|float|int|NDArray $other): NDArray
+    function rpow(NDArray $a, Complex|float|int $scalar): NDArray
     {
-        return $a->mod($other);
+        return $a->rpow($scalar);
     }
 
     /**
@@ -526,6 +826,30 @@ namespace PhpMlKit\NDArray {
         return $a->isreal();
     }
 
+    /**
+     * Returns bool array: true where element is NaN.
+     */
+    function isnan(NDArray $a): NDArray
+    {
+        return $a->isnan();
+    }
+
+    /**
+     * Returns bool array: true where element is positive or negative infinity.
+     */
+    function isinf(NDArray $a): NDArray
+    {
+        return $a->isinf();
+    }
+
+    /**
+     * Returns bool array: true where element is neither NaN nor infinite.
+     */
+    function isfinite(NDArray $a): NDArray
+    {
+        return $a->isfinite();
+    }
+
     /**
      * Compute phase angle element-wise.
      *
@@ -880,13 +1204,14 @@ namespace PhpMlKit\NDArray {
      * Compares two arrays element-wise and returns a new array containing
      * the smaller value at each position. Supports broadcasting.
      *
-     * @param NDArray $other The array to compare with
+     * @param NDArray      $other The array to compare with
+     * @param null|NDArray $out   Existing array to write the result into
      *
-     * @return NDArray New array with element-wise minimum values
+     * @return NDArray New array with element-wise minimum values, or `$out` when given
      */
-    function minimum(NDArray $a, NDArray $other): NDArray
+    function minimum(NDArray $a, NDArray $other, ?NDArray $out = null): NDArray
     {
-        return $a->minimum($other);
+        return $a->minimum($other, $out);
     }
 
     /**
@@ -895,21 +1220,14 @@ namespace PhpMlKit\NDArray {
      * Compares two arrays element-wise and returns a new array containing
      * the larger value at each position. Supports broadcasting.
      *
-     * @param NDArray $other The array to compare with
+     * @param NDArray      $other The array to compare with
+     * @param null|NDArray $out   Existing array to write the result into
      *
-     * @return NDArray New array with element-wise maximum values
-     */
-    function maximum(NDArray $a, NDArray $other): NDArray
-    {
-        return $a->maximum($other);
-    }
-
-    /**
-     * Compute sigmoid element-wise: 1 / (1 + exp(-x)).
+     * @return NDArray New array with element-wise maximum values, or `$out` when given
      */
-    function sigmoid(NDArray $a): NDArray
+    function maximum(NDArray $a, NDArray $other, ?NDArray $out = null): NDArray
     {
-        return $a->sigmoid();
+        return $a->maximum($other, $out);
     }
 
     /**
@@ -924,6 +1242,33 @@ namespace PhpMlKit\NDArray {
         return $a->softmax($axis);
     }
 
+    /**
+     * n-th discrete difference along an axis.
+     */
+    function diff(NDArray $a, int $n = 1, int $axis = -1): NDArray
+    {
+        return $a->diff($n, $axis);
+    }
+
+    /**
+     * Numerical gradient along an axis using central differences.
+     */
+    function gradient(NDArray $a, int $axis = -1, float $spacing = 1.0, int $edgeOrder = 1): NDArray
+    {
+        return $a->gradient($axis, $spacing, $edgeOrder);
+    }
+
+    /**
+     * One-dimensional linear interpolation of `$x` on the samples `(xp, fp)`, as numpy.interp.
+     *
+     * @param array<mixed>|NDArray $xp
+     * @param array<mixed>|NDArray $fp
+     */
+    function interp(NDArray $x, array|NDArray $xp, array|NDArray $fp, ?float $left = null, ?float $right = null): NDArray
+    {
+        return $x->interp($xp, $fp, $left, $right);
+    }
+
     // =============================================================================
     // HasComparison — element-wise comparisons (Bool result)
     // =============================================================================
@@ -1033,7 +1378,7 @@ namespace PhpMlKit\NDArray {
     }
 
     // =============================================================================
-    // HasReductions — reductions, sort, topk, cumulative, bincount
+    // HasReductions — reductions, sort, topk, cumulative, bincount, rolling
     // =============================================================================
 
     /**
@@ -1041,12 +1386,13 @@ namespace PhpMlKit\NDArray {
      *
      * @param null|int $axis     Axis along which to sum. If null, sum over all elements.
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return Complex|float|int|NDArray scalar if axis is null, otherwise an NDArray
      */
-    function sum(NDArray $a, ?int $axis = null, bool $keepdims = false): Complex|float|int|NDArray
+    function sum(NDArray $a, ?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|int|NDArray
     {
-        return $a->sum($axis, $keepdims);
+        return $a->sum($axis, $keepdims, $mode);
     }
 
     /**
@@ -1054,12 +1400,13 @@ namespace PhpMlKit\NDArray {
      *
      * @param null|int $axis     Axis along which to compute mean. If null, compute mean of all elements.
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return Complex|float|NDArray scalar if axis is null, otherwise an NDArray
      */
-    function mean(NDArray $a, ?int $axis = null, bool $keepdims = false): Complex|float|NDArray
+    function mean(NDArray $a, ?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|NDArray
     {
-        return $a->mean($axis, $keepdims);
+        return $a->mean($axis, $keepdims, $mode);
     }
 
     /**
@@ -1121,25 +1468,39 @@ namespace PhpMlKit\NDArray {
     /**
      * Return a sorted copy of the array.
      *
-     * @param null|int $axis Axis along which to sort. If null, sort flattened data.
-     * @param SortKind $kind sorting algorithm
+     * @param null|int $axis       Axis along which to sort. If null, sort flattened data.
+     * @param SortKind $kind       sorting algorithm
+     * @param bool     $descending If true, sort largest first
+     * @param bool     $nanFirst   If true, place NaN at the start instead of the end
      */
-    function sort(NDArray $a, ?int $axis = -1, SortKind $kind = SortKind::QuickSort): NDArray
-    {
-        return $a->sort($axis, $kind);
+    function sort(
+        NDArray $a,
+        ?int $axis = -1,
+        SortKind $kind = SortKind::QuickSort,
+        bool $descending = false,
+        bool $nanFirst = false,
+    ): NDArray {
+        return $a->sort($axis, $kind, $descending, $nanFirst);
     }
 
     /**
      * Return indices that would sort the array.
      *
-     * @param null|int $axis Axis along which to argsort. If null, argsort flattened data.
-     * @param SortKind $kind sorting algorithm
+     * @param null|int $axis       Axis along which to argsort. If null, argsort flattened data.
+     * @param SortKind $kind       sorting algorithm
+     * @param bool     $descending If true, order indices largest value first
+     * @param bool     $nanFirst   If true, place NaN at the start instead of the end
      *
      * @return NDArray int64 indices array
      */
-    function argsort(NDArray $a, ?int $axis = -1, SortKind $kind = SortKind::QuickSort): NDArray
-    {
-        return $a->argsort($axis, $kind);
+    function argsort(
+        NDArray $a,
+        ?int $axis = -1,
+        SortKind $kind = SortKind::QuickSort,
+        bool $descending = false,
+        bool $nanFirst = false,
+    ): NDArray {
+        return $a->argsort($axis, $kind, $descending, $nanFirst);
     }
 
     /**
@@ -1148,8 +1509,9 @@ namespace PhpMlKit\NDArray {
      * @param int      $k       Number of elements to select
      * @param null|int $axis    Axis along which to select. If null, flatten first.
      * @param bool     $largest If true, select largest values; otherwise smallest values
-     * @param bool     $sorted  If true, keep selected values sorted by rank
-     * @param SortKind $kind    Sorting algorithm
+     * @param bool     $sorted  If true, order results by rank; if false, keep them in original
+     *                          index order using O(n) selection, which is faster for large inputs
+     * @param SortKind $kind    Sorting algorithm (used only when $sorted is true)
      *
      * @return array{0: NDArray, 1: NDArray} [values, indices]
      */
@@ -1164,6 +1526,30 @@ namespace PhpMlKit\NDArray {
         return $a->topk($k, $axis, $largest, $sorted, $kind);
     }
 
+    /**
+     * Return a partitioned copy of the array.
+     *
+     * @param array<int>|int $kth  Position or positions to place; negative values count from the end
+     * @param null|int       $axis Axis along which to partition. If null, partition flattened data.
+     */
+    function partition(NDArray $a, array|int $kth, ?int $axis = -1): NDArray
+    {
+        return $a->partition($kth, $axis);
+    }
+
+    /**
+     * Return indices that would partition the array.
+     *
+     * @param array<int>|int $kth  Position or positions to place; negative values count from the end
+     * @param null|int       $axis Axis along which to partition. If null, partition flattened data.
+     *
+     * @return NDArray int64 indices array
+     */
+    function argpartition(NDArray $a, array|int $kth, ?int $axis = -1): NDArray
+    {
+        return $a->argpartition($kth, $axis);
+    }
+
     /**
      * Product of array elements over a given axis.
      *
@@ -1203,12 +1589,13 @@ namespace PhpMlKit\NDArray {
      * @param null|int $axis     Axis along which to compute variance. If null, compute variance of all elements.
      * @param int      $ddof     delta degrees of freedom (0 for population, 1 for sample)
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return float|NDArray scalar if axis is null, otherwise an NDArray
      */
-    function variance(NDArray $a, ?int $axis = null, int $ddof = 0, bool $keepdims = false): float|NDArray
+    function variance(NDArray $a, ?int $axis = null, int $ddof = 0, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): float|NDArray
     {
-        return $a->var($axis, $ddof, $keepdims);
+        return $a->var($axis, $ddof, $keepdims, $mode);
     }
 
     /**
@@ -1217,52 +1604,422 @@ namespace PhpMlKit\NDArray {
      * @param null|int $axis     Axis along which to compute std. If null, compute std of all elements.
      * @param int      $ddof     delta degrees of freedom (0 for population, 1 for sample)
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return float|NDArray scalar if axis is null, otherwise an NDArray
      */
-    function std(NDArray $a, ?int $axis = null, int $ddof = 0, bool $keepdims = false): float|NDArray
+    function std(NDArray $a, ?int $axis = null, int $ddof = 0, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): float|NDArray
     {
-        return $a->std($axis, $ddof, $keepdims);
+        return $a->std($axis, $ddof, $keepdims, $mode);
     }
 
     /**
-     * Count occurrences of non-negative integer values in flattened input.
+     * Sum of array elements over a given axis, treating NaN as zero.
      *
-     * @param null|int $minlength Minimum output length
+     * @param null|int $axis     Axis along which to sum. If null, sum over all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
-     * @return NDArray Int64 counts array
+     * @return Complex|float|int|NDArray scalar if axis is null, otherwise an NDArray
      */
-    function bincount(NDArray $a, ?int $minlength = null): NDArray
+    function nansum(NDArray $a, ?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|int|NDArray
     {
-        return $a->bincount($minlength);
+        return $a->nansum($axis, $keepdims, $mode);
     }
 
-    // =============================================================================
-    // HasShapeOps — shape, views, pad, tile, repeat
-    // =============================================================================
-
     /**
-     * Pad an array.
+     * Mean of the non-NaN elements over a given axis.
      *
-     * @param array<array{int,int}|int>|array{int,int}|int $padWidth       Number of elements to pad on each side of each axis.
-     *                                                                     - int: pad same amount on all sides of every axis
-     *                                                                     - array{int,int}: pad [before, after] for all axes
-     *                                                                     - array<int|array{int,int}>: per-axis pad (int or [before, after] per axis)
-     * @param PadMode                                      $mode           padding mode
-     * @param array<bool|float|int>|bool|float|int         $constantValues constant value to pad with (used for PadMode::Constant)
+     * @param null|int $axis     Axis along which to compute mean. If null, compute mean of all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
-     * @return NDArray padded array
+     * @return Complex|float|NDArray scalar if axis is null, otherwise an NDArray
      */
-    function pad(NDArray $a, array|int $padWidth, PadMode $mode = PadMode::Constant, array|bool|float|int $constantValues = 0): NDArray
+    function nanmean(NDArray $a, ?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|NDArray
     {
-        return $a->pad($padWidth, $mode, $constantValues);
+        return $a->nanmean($axis, $keepdims, $mode);
     }
 
     /**
-     * Reshape the array to a new shape.
-     *
-     * Returns a new array with the specified shape.
-     * Supports both C-order (row-major, order='C') and F-order (column-major, order='F').
+     * Minimum of the non-NaN elements over a given axis.
+     *
+     * @param null|int $axis     Axis along which to find minimum. If null, find minimum of all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return Complex|float|int|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function nanmin(NDArray $a, ?int $axis = null, bool $keepdims = false): Complex|float|int|NDArray
+    {
+        return $a->nanmin($axis, $keepdims);
+    }
+
+    /**
+     * Maximum of the non-NaN elements over a given axis.
+     *
+     * @param null|int $axis     Axis along which to find maximum. If null, find maximum of all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return Complex|float|int|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function nanmax(NDArray $a, ?int $axis = null, bool $keepdims = false): Complex|float|int|NDArray
+    {
+        return $a->nanmax($axis, $keepdims);
+    }
+
+    /**
+     * Standard deviation of the non-NaN elements over a given axis.
+     *
+     * @param null|int $axis     Axis along which to compute std. If null, compute std of all elements.
+     * @param int      $ddof     delta degrees of freedom (0 for population, 1 for sample)
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
+     *
+     * @return float|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function nanstd(NDArray $a, ?int $axis = null, int $ddof = 0, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): float|NDArray
+    {
+        return $a->nanstd($axis, $ddof, $keepdims, $mode);
+    }
+
+    /**
+     * Median of array elements over a given axis.
+     *
+     * @param null|int $axis     Axis along which to compute the median. If null, use all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return float|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function median(NDArray $a, ?int $axis = null, bool $keepdims = false): float|NDArray
+    {
+        return $a->median($axis, $keepdims);
+    }
+
+    /**
+     * Quantile of array elements over a given axis.
+     *
+     * @param float          $q        quantile to compute, in [0, 1]
+     * @param null|int       $axis     Axis along which to compute the quantile. If null, use all elements.
+     * @param bool           $keepdims if true, the reduced axis is retained with size 1
+     * @param QuantileMethod $method   how to resolve a position between two data points
+     *
+     * @return float|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function quantile(NDArray $a, float $q, ?int $axis = null, bool $keepdims = false, QuantileMethod $method = QuantileMethod::Linear): float|NDArray
+    {
+        return $a->quantile($q, $axis, $keepdims, $method);
+    }
+
+    /**
+     * Percentile of array elements over a given axis.
+     *
+     * @param float          $p        percentile to compute, in [0, 100]
+     * @param null|int       $axis     Axis along which to compute the percentile. If null, use all elements.
+     * @param bool           $keepdims if true, the reduced axis is retained with size 1
+     * @param QuantileMethod $method   how to resolve a position between two data points
+     *
+     * @return float|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function percentile(NDArray $a, float $p, ?int $axis = null, bool $keepdims = false, QuantileMethod $method = QuantileMethod::Linear): float|NDArray
+    {
+        return $a->percentile($p, $axis, $keepdims, $method);
+    }
+
+    /**
+     * Log of the sum of exponentials over a given axis, computed without overflow.
+     *
+     * @return float|NDArray scalar if axis is null, otherwise an NDArray
+     */
+    function logsumexp(NDArray $a, ?int $axis = null, bool $keepdims = false): float|NDArray
+    {
+        return $a->logsumexp($axis, $keepdims);
+    }
+
+    /**
+     * Integrate along an axis with the trapezoidal rule.
+     *
+     * @param null|array<mixed>|NDArray $x
+     *
+     * @return float|NDArray scalar for 1D input, otherwise an NDArray
+     */
+    function trapz(NDArray $y, array|NDArray|null $x = null, float $dx = 1.0, int $axis = -1): float|NDArray
+    {
+        return $y->trapz($x, $dx, $axis);
+    }
+
+    /**
+     * Cumulative trapezoidal integral along an axis.
+     *
+     * @param null|array<mixed>|NDArray $x
+     */
+    function cumtrapz(
+        NDArray $y,
+        array|NDArray|null $x = null,
+        float $dx = 1.0,
+        int $axis = -1,
+        bool $includeInitial = false,
+    ): NDArray {
+        return $y->cumtrapz($x, $dx, $axis, $includeInitial);
+    }
+
+    /**
+     * Count occurrences of non-negative integer values in flattened input.
+     *
+     * @param null|int $minlength Minimum output length
+     *
+     * @return NDArray Int64 counts array
+     */
+    function bincount(NDArray $a, ?int $minlength = null): NDArray
+    {
+        return $a->bincount($minlength);
+    }
+
+    /**
+     * Count the non-zero elements over a given axis.
+     *
+     * @return ($axis is null ? int : NDArray)
+     */
+    function count_nonzero(NDArray $a, ?int $axis = null, bool $keepdims = false): int|NDArray
+    {
+        return $a->countNonzero($axis, $keepdims);
+    }
+
+    /**
+     * Compute the histogram of the flattened array.
+     *
+     * @param array<float|int>|int|NDArray         $bins    number of equal-width bins, or monotonically increasing bin edges
+     * @param null|array{0: float|int, 1: float|int} $range   [min, max] spanned by equal-width bins
+     * @param null|array<float|int>|NDArray        $weights one weight per element
+     *
+     * @return array{0: NDArray, 1: NDArray} [counts, edges]
+     */
+    function histogram(NDArray $a, array|int|NDArray $bins = 10, ?array $range = null, null|array|NDArray $weights = null): array
+    {
+        return $a->histogram($bins, $range, $weights);
+    }
+
+    /**
+     * Compute the two-dimensional histogram of paired samples.
+     *
+     * @param array<float|int>|NDArray                                            $y       y coordinates, one per element of $x
+     * @param array{0: array<float|int>|int|NDArray, 1: array<float|int>|int|NDArray}|int $bins    bin count for both dimensions, or a [x, y] pair of bin counts or edges
+     * @param null|array{0: array{0: float|int, 1: float|int}, 1: array{0: float|int, 1: float|int}} $range   [[xmin, xmax], [ymin, ymax]]
+     * @param null|array<float|int>|NDArray                                       $weights one weight per sample
+     *
+     * @return array{0: NDArray, 1: NDArray, 2: NDArray} [counts, xEdges, yEdges]
+     */
+    function histogram2d(NDArray $x, array|NDArray $y, array|int $bins = 10, ?array $range = null, null|array|NDArray $weights = null): array
+    {
+        return $x->histogram2d($y, $bins, $range, $weights);
+    }
+
+    /**
+     * Rolling sum over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     */
+    function rolling_sum(NDArray $a, int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $a->rollingSum($window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling mean over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     */
+    function rolling_mean(NDArray $a, int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $a->rollingMean($window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling minimum over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     */
+    function rolling_min(NDArray $a, int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $a->rollingMin($window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling maximum over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     */
+    function rolling_max(NDArray $a, int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $a->rollingMax($window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling standard deviation over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     * @param int      $ddof       delta degrees of freedom (0 for population, 1 for sample)
+     */
+    function rolling_std(NDArray $a, int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false, int $ddof = 0): NDArray
+    {
+        return $a->rollingStd($window, $axis, $minPeriods, $center, $ddof);
+    }
+
+    /**
+     * Sum of rows along axis 0 grouped by segment id. Empty segments are 0.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     */
+    function segment_sum(NDArray $a, array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $a->segmentSum($segmentIds, $numSegments);
+    }
+
+    /**
+     * Mean of rows along axis 0 grouped by segment id. Empty segments are NaN.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     */
+    function segment_mean(NDArray $a, array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $a->segmentMean($segmentIds, $numSegments);
+    }
+
+    /**
+     * Minimum of rows along axis 0 grouped by segment id. Empty segments are NaN.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     */
+    function segment_min(NDArray $a, array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $a->segmentMin($segmentIds, $numSegments);
+    }
+
+    /**
+     * Maximum of rows along axis 0 grouped by segment id. Empty segments are NaN.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     */
+    function segment_max(NDArray $a, array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $a->segmentMax($segmentIds, $numSegments);
+    }
+
+    /**
+     * Group rows of values by a key column and compute count/sum/mean/min/max per key.
+     *
+     * @param array<bool|float|int>|NDArray $keys
+     *
+     * @return array{keys: NDArray, count: NDArray, sum: NDArray, mean: NDArray, min: NDArray, max: NDArray}
+     */
+    function group_by(array|NDArray $keys, NDArray $values): array
+    {
+        return NDArray::groupBy($keys, $values);
+    }
+
+    // =============================================================================
+    // HasSetOps — unique values and set operations on flattened arrays
+    // =============================================================================
+
+    /**
+     * Sorted unique values of the flattened array, or unique slices along an axis.
+     *
+     * @return array{values: NDArray, index?: NDArray, inverse?: NDArray, counts?: NDArray}|NDArray
+     */
+    function unique(
+        NDArray $a,
+        bool $returnInverse = false,
+        bool $returnCounts = false,
+        ?int $axis = null,
+        bool $returnIndex = false,
+    ): array|NDArray {
+        return $a->unique($returnInverse, $returnCounts, $axis, $returnIndex);
+    }
+
+    /**
+     * Sorted unique values present in both arrays.
+     *
+     * @param array<mixed>|NDArray $b
+     */
+    function intersect1d(NDArray $a, array|NDArray $b, bool $assumeUnique = false): NDArray
+    {
+        return $a->intersect1d($b, $assumeUnique);
+    }
+
+    /**
+     * Sorted unique values present in either array.
+     *
+     * @param array<mixed>|NDArray $b
+     */
+    function union1d(NDArray $a, array|NDArray $b): NDArray
+    {
+        return $a->union1d($b);
+    }
+
+    /**
+     * Unique values of `$a` that are not in `$b`.
+     *
+     * @param array<mixed>|NDArray $b
+     */
+    function setdiff1d(NDArray $a, array|NDArray $b, bool $assumeUnique = false): NDArray
+    {
+        return $a->setdiff1d($b, $assumeUnique);
+    }
+
+    /**
+     * Element-wise membership mask of `$element` in `$testElements`.
+     *
+     * @param array<mixed>|NDArray $testElements
+     */
+    function isin(NDArray $element, array|NDArray $testElements, bool $assumeUnique = false, bool $invert = false): NDArray
+    {
+        return $element->isin($testElements, $assumeUnique, $invert);
+    }
+
+    // =============================================================================
+    // HasShapeOps — shape, views, pad, tile, repeat
+    // =============================================================================
+
+    /**
+     * Pad an array.
+     *
+     * @param array<array{int,int}|int>|array{int,int}|int $padWidth       Number of elements to pad on each side of each axis.
+     *                                                                     - int: pad same amount on all sides of every axis
+     *                                                                     - array{int,int}: pad [before, after] for all axes
+     *                                                                     - array<int|array{int,int}>: per-axis pad (int or [before, after] per axis)
+     * @param PadMode                                      $mode           padding mode
+     * @param array<bool|float|int>|bool|float|int         $constantValues constant value to pad with (used for PadMode::Constant)
+     *
+     * @return NDArray padded array
+     */
+    function pad(NDArray $a, array|int $padWidth, PadMode $mode = PadMode::Constant, array|bool|float|int $constantValues = 0): NDArray
+    {
+        return $a->pad($padWidth, $mode, $constantValues);
+    }
+
+    /**
+     * Reshape the array to a new shape.
+     *
+     * Returns a new array with the specified shape.
+     * Supports both C-order (row-major, order='C') and F-order (column-major, order='F').
      *
      * For contiguous arrays, this returns a zero-copy view with updated metadata.
      * For non-contiguous arrays, data is copied to make it contiguous first.
@@ -1361,11 +2118,13 @@ namespace PhpMlKit\NDArray {
     /**
      * Flatten the array to 1D.
      *
-     * Always returns a copy in C-order (row-major).
+     * Always returns a copy, reading elements in the given order.
+     *
+     * @param string $order 'C' for row-major, 'F' for column-major
      */
-    function flatten(NDArray $a): NDArray
+    function flatten(NDArray $a, string $order = 'C'): NDArray
     {
-        return $a->flatten();
+        return $a->flatten($order);
     }
 
     /**
@@ -1458,236 +2217,752 @@ namespace PhpMlKit\NDArray {
      */
     function stack(array $arrays, int $axis = 0): NDArray
     {
-        return NDArray::stack($arrays, $axis);
+        return NDArray::stack($arrays, $axis);
+    }
+
+    /**
+     * Stack arrays vertically (along axis 0).
+     *
+     * 1-D arrays of shape (N,) are treated as rows of shape (1, N).
+     *
+     * @param array<NDArray> $arrays Arrays to stack
+     */
+    function vstack(array $arrays): NDArray
+    {
+        return NDArray::vstack($arrays);
+    }
+
+    /**
+     * Stack arrays horizontally.
+     *
+     * 1-D arrays are joined along axis 0, everything else along axis 1.
+     *
+     * @param array<NDArray> $arrays Arrays to stack
+     */
+    function hstack(array $arrays): NDArray
+    {
+        return NDArray::hstack($arrays);
+    }
+
+    /**
+     * Stack arrays depth-wise (along axis 2).
+     *
+     * @param array<NDArray> $arrays Arrays to stack
+     */
+    function dstack(array $arrays): NDArray
+    {
+        return NDArray::dstack($arrays);
+    }
+
+    /**
+     * Stack 1-D arrays as columns of a 2-D array.
+     *
+     * @param array<NDArray> $arrays Arrays to stack
+     */
+    function column_stack(array $arrays): NDArray
+    {
+        return NDArray::columnStack($arrays);
+    }
+
+    /**
+     * Assemble an array from a nested grid of blocks, like numpy.block.
+     *
+     * @param array<mixed>|NDArray $blocks Nested lists of arrays
+     */
+    function block(array|NDArray $blocks): NDArray
+    {
+        return NDArray::block($blocks);
+    }
+
+    /**
+     * Split array along axis.
+     *
+     * If $indicesOrSections is an integer N, split into N equal parts (axis length must be divisible by N).
+     * If it is an array of indices, split at those positions.
+     *
+     * @param array<int>|int $indicesOrSections Number of equal parts, or array of split indices
+     * @param int            $axis              Axis along which to split
+     *
+     * @return array<NDArray> List of sub-arrays (views)
+     */
+    function split(NDArray $a, array|int $indicesOrSections, int $axis = 0): array
+    {
+        return $a->split($indicesOrSections, $axis);
+    }
+
+    /**
+     * Split array vertically (along axis 0).
+     *
+     * @param array<int>|int $indicesOrSections Number of equal parts or split indices
+     *
+     * @return array<NDArray>
+     */
+    function vsplit(NDArray $a, array|int $indicesOrSections): array
+    {
+        return $a->vsplit($indicesOrSections);
+    }
+
+    /**
+     * Split array horizontally (along axis 1).
+     *
+     * @param array<int>|int $indicesOrSections Number of equal parts or split indices
+     *
+     * @return array<NDArray>
+     */
+    function hsplit(NDArray $a, array|int $indicesOrSections): array
+    {
+        return $a->hsplit($indicesOrSections);
+    }
+
+    /**
+     * Split array along axis, allowing uneven parts (numpy.array_split).
+     *
+     * @param array<int>|int $indicesOrSections Number of parts, or array of split boundaries
+     * @param int            $axis              Axis along which to split
+     *
+     * @return array<NDArray>
+     */
+    function array_split(NDArray $a, array|int $indicesOrSections, int $axis = 0): array
+    {
+        return $a->arraySplit($indicesOrSections, $axis);
+    }
+
+    // =============================================================================
+    // HasIndexing — get/set, take/put, scatter, where
+    // =============================================================================
+
+    /**
+     * Access elements by index.
+     *
+     * Full indices (count === ndim) return a scalar via FFI read.
+     * Partial indices (count < ndim) return a view (pure PHP, zero FFI).
+     *
+     * Supports negative indices: -1 refers to the last element, -2 to second-to-last, etc.
+     *
+     * @param int ...$indices One or more dimension indices
+     *
+     * @return bool|float|int|NDArray Scalar for full indexing, view for partial
+     */
+    function get(NDArray $a, int ...$indices): bool|Complex|float|int|NDArray
+    {
+        return $a->get(...$indices);
+    }
+
+    /**
+     * Set a scalar value at the given indices.
+     *
+     * Requires full indexing (count === ndim).
+     *
+     * Supports negative indices: -1 refers to the last element, -2 to second-to-last, etc.
+     *
+     * @param array<int>             $indices Indices for each dimension
+     * @param bool|Complex|float|int $value   Value to set
+     */
+    function set(NDArray $a, array $indices, bool|Complex|float|int $value): void
+    {
+        $a->set($indices, $value);
+    }
+
+    /**
+     * Set a scalar value using a logical flat index (C-order) for this array/view.
+     *
+     * Supports negative indices: -1 refers to the last logical element.
+     *
+     * @param int            $flatIndex Logical flat index into this array/view
+     * @param bool|float|int $value     Value to set
+     */
+    function set_at(NDArray $a, int $flatIndex, bool|Complex|float|int $value): void
+    {
+        $a->setAt($flatIndex, $value);
+    }
+
+    /**
+     * Get a scalar value using a logical flat index (C-order) for this array/view.
+     *
+     * Supports negative indices: -1 refers to the last logical element.
+     *
+     * @param int $flatIndex Logical flat index into this array/view
+     *
+     * @return bool|float|int The value at the specified flat index
+     */
+    function get_at(NDArray $a, int $flatIndex): bool|Complex|float|int
+    {
+        return $a->getAt($flatIndex);
+    }
+
+    /**
+     * Get many scalar values in a single FFI call.
+     *
+     * @param array<array<int>|int> $indices Flat indices or coordinate lists
+     *
+     * @return array<bool|Complex|float|int>
+     */
+    function get_many(NDArray $a, array $indices): array
+    {
+        return $a->getMany($indices);
+    }
+
+    /**
+     * Set many scalar values in a single FFI call.
+     *
+     * @param array<array<int>|int>         $indices Flat indices or coordinate lists
+     * @param array<bool|Complex|float|int> $values  One value per index
+     */
+    function set_many(NDArray $a, array $indices, array $values): void
+    {
+        $a->setMany($indices, $values);
+    }
+
+    /**
+     * Select the elements where a Bool mask is true (NumPy `a[mask]`).
+     */
+    function boolean_select(NDArray $a, NDArray $mask): NDArray
+    {
+        return $a->booleanSelect($mask);
+    }
+
+    /**
+     * Assign a scalar or broadcast values where a Bool mask is true, in place.
+     */
+    function boolean_assign(NDArray $a, NDArray $mask, bool|Complex|float|int|NDArray $values): void
+    {
+        $a->booleanAssign($mask, $values);
+    }
+
+    /**
+     * Gather values by indices.
+     *
+     * If axis is null, gathers from logical flattened view (C-order).
+     * If axis is provided, delegates to takeAlongAxis semantics.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function take(NDArray $a, array|NDArray $indices, ?int $axis = null): NDArray
+    {
+        return $a->take($indices, $axis);
+    }
+
+    /**
+     * Gather whole rows of a 2D table (embedding lookup); result shape is indices.shape + [dim].
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function take_rows(NDArray $a, array|NDArray $indices): NDArray
+    {
+        return $a->takeRows($indices);
+    }
+
+    /**
+     * Gather values along an axis using per-position indices.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function take_along_axis(NDArray $a, array|NDArray $indices, int $axis): NDArray
+    {
+        return $a->takeAlongAxis($indices, $axis);
+    }
+
+    /**
+     * Scatter values by indices and return a mutated copy.
+     *
+     * If axis is provided, each index replaces a whole sub-array along that axis.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     * @param string                        $mode    Currently supports only 'raise'
+     */
+    function put(NDArray $a, array|NDArray $indices, bool|Complex|float|int|NDArray $values, string $mode = 'raise', ?int $axis = null): NDArray
+    {
+        return $a->put($indices, $values, $mode, $axis);
+    }
+
+    /**
+     * Scatter values along an axis and return a mutated copy.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function put_along_axis(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $values, int $axis): NDArray
+    {
+        return $a->putAlongAxis($indices, $values, $axis);
+    }
+
+    /**
+     * Add updates by flattened indices and return a mutated copy.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function scatter_add(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $updates): NDArray
+    {
+        return $a->scatterAdd($indices, $updates);
+    }
+
+    /**
+     * Take the minimum of updates by flattened indices and return a mutated copy.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function scatter_min(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $updates, bool $includeSelf = true): NDArray
+    {
+        return $a->scatterMin($indices, $updates, $includeSelf);
+    }
+
+    /**
+     * Take the maximum of updates by flattened indices and return a mutated copy.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function scatter_max(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $updates, bool $includeSelf = true): NDArray
+    {
+        return $a->scatterMax($indices, $updates, $includeSelf);
+    }
+
+    /**
+     * Average updates by flattened indices and return a mutated copy.
+     *
+     * @param array<array<int>|int>|NDArray $indices
+     */
+    function scatter_mean(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $updates, bool $includeSelf = true): NDArray
+    {
+        return $a->scatterMean($indices, $updates, $includeSelf);
+    }
+
+    /**
+     * Scatter slices of updates along an axis, reducing repeated indices with the given mode.
+     *
+     * @param array<int>|NDArray $indices
+     */
+    function scatter(
+        NDArray $a,
+        array|NDArray $indices,
+        NDArray $updates,
+        int $axis = 0,
+        ScatterMode $mode = ScatterMode::Assign,
+        bool $includeSelf = true,
+    ): NDArray {
+        return $a->scatter($indices, $updates, $axis, $mode, $includeSelf);
+    }
+
+    /**
+     * Select values from x and y based on a condition.
+     *
+     * @param bool|float|int|NDArray         $condition Condition array or scalar
+     * @param bool|Complex|float|int|NDArray $x         Values where condition is true
+     * @param bool|Complex|float|int|NDArray $y         Values where condition is false
+     */
+    function where(bool|float|int|NDArray $condition, bool|Complex|float|int|NDArray $x, bool|Complex|float|int|NDArray $y): NDArray
+    {
+        return NDArray::where($condition, $x, $y);
+    }
+
+    // =============================================================================
+    // HasSlicing — slice views, assign
+    // =============================================================================
+
+    /**
+     * Create a view of the array using slice syntax.
+     *
+     * Selectors can be integers (to reduce dimensions) or slice strings
+     * (e.g., "0:5", ":", "::2") to subset dimensions.
+     *
+     * @param array<int|string> $selection List of selectors for each dimension
+     *
+     * @return NDArray A new view sharing the same data
+     */
+    function slice(NDArray $a, array $selection): NDArray
+    {
+        return $a->slice($selection);
+    }
+
+    /**
+     * Assign values to the current array/view.
+     *
+     * Supports scalar assignment (fill) or NDArray assignment (rhs is broadcast to this view’s shape when compatible).
+     *
+     * @param bool|Complex|float|int|NDArray $value Scalar value or NDArray
+     */
+    function assign(NDArray $a, bool|Complex|float|int|NDArray $value): void
+    {
+        $a->assign($value);
+    }
+
+    /**
+     * Copy values into an existing array in place, optionally only where a mask is true.
+     *
+     * @param NDArray                $dst     Destination array or view
+     * @param bool|float|int|NDArray $src     Values to copy, broadcast to the destination
+     * @param Casting                $casting Allowed dtype conversion from source to destination
+     * @param null|NDArray           $where   Bool mask; null copies every element
+     */
+    function copyto(NDArray $dst, bool|float|int|NDArray $src, Casting $casting = Casting::SameKind, ?NDArray $where = null): void
+    {
+        $dst->copyFrom($src, $where, $casting);
+    }
+
+    /**
+     * Shuffle an array or view in place along an axis.
+     *
+     * @param NDArray  $a    Array to shuffle
+     * @param int      $axis Axis to shuffle along (default: 0)
+     * @param null|int $seed Optional seed for deterministic output
+     * @param null|Rng $rng  Generator to draw from; overrides `$seed`
+     */
+    function shuffle(NDArray $a, int $axis = 0, ?int $seed = null, ?Rng $rng = null): void
+    {
+        $a->shuffle($axis, $seed, $rng);
+    }
+
+    // =============================================================================
+    // HasCallbacks — user-supplied PHP callbacks
+    // =============================================================================
+
+    /**
+     * Apply a PHP callback element-wise, passing values in batches.
+     *
+     * @param callable(list<float>): array<bool|float|int> $fn batch transform
+     */
+    function map(NDArray $a, callable $fn, DType $dtype = DType::Float64, int $batchSize = 4096): NDArray
+    {
+        return $a->map($fn, $dtype, $batchSize);
+    }
+
+    /**
+     * Reduce with a PHP combine function `$fn($acc, $x)`, starting from `$initial`.
+     *
+     * @param callable(float, float): (float|int) $fn binary combine step
+     */
+    function reduce(
+        NDArray $a,
+        callable $fn,
+        float|int $initial = 0.0,
+        ?int $axis = null,
+        bool $keepdims = false,
+        int $batchSize = 4096,
+    ): float|NDArray {
+        return $a->reduce($fn, $initial, $axis, $keepdims, $batchSize);
+    }
+
+    /**
+     * Register a generalized ufunc kernel under a core signature such as "(m,n),(n)->(m)".
+     *
+     * @param callable(list<list<float>>, array<string, int>): array<bool|float|int> $kernel
+     */
+    function register_gufunc(string $name, string $signature, callable $kernel): int
+    {
+        return NDArray::registerGufunc($name, $signature, $kernel);
+    }
+
+    /**
+     * Invoke a registered gufunc by id or name, broadcasting over loop dimensions.
+     */
+    function gufunc(int|string $gufunc, NDArray ...$inputs): NDArray
+    {
+        return NDArray::gufunc($gufunc, ...$inputs);
+    }
+
+    /**
+     * Remove a registered gufunc by id or name and release its kernel.
+     */
+    function unregister_gufunc(int|string $gufunc): void
+    {
+        NDArray::unregisterGufunc($gufunc);
+    }
+
+    // =============================================================================
+    // HasConversion — byte order
+    // =============================================================================
+
+    /**
+     * Reverse the byte order of every element.
+     *
+     * Complex values swap the real and imaginary parts independently; single-byte
+     * dtypes are returned unchanged.
+     */
+    function byteswap(NDArray $a): NDArray
+    {
+        return $a->byteswap();
+    }
+
+    // =============================================================================
+    // HasConversion — quantization
+    // =============================================================================
+
+    /**
+     * Quantize to Int8 or UInt8 with an affine scale and zero point.
+     *
+     * Scalar parameters are per-tensor; array parameters are per-channel along $axis.
+     */
+    function quantize(
+        NDArray $a,
+        float|NDArray $scale,
+        int|NDArray $zeroPoint = 0,
+        DType $dtype = DType::Int8,
+        ?int $axis = null,
+    ): NDArray {
+        return $a->quantize($scale, $zeroPoint, $dtype, $axis);
+    }
+
+    /**
+     * Dequantize an Int8 or UInt8 array to Float32.
+     */
+    function dequantize(NDArray $a, float|NDArray $scale, int|NDArray $zeroPoint = 0, ?int $axis = null): NDArray
+    {
+        return $a->dequantize($scale, $zeroPoint, $axis);
+    }
+
+    // =============================================================================
+    // HasConversion — NumPy files
+    // =============================================================================
+
+    /**
+     * Save an array to a NumPy `.npy` file — delegates to {@see NDArray::save()}.
+     */
+    function save(string $path, NDArray $a): void
+    {
+        $a->save($path);
     }
 
     /**
-     * Stack arrays vertically (along axis 0).
-     *
-     * Equivalent to concatenate(arrays, axis=0).
-     *
-     * @param array<NDArray> $arrays Arrays to stack
+     * Load an array from a NumPy `.npy` file — delegates to {@see NDArray::load()}.
      */
-    function vstack(array $arrays): NDArray
+    function load(string $path): NDArray
     {
-        return NDArray::vstack($arrays);
+        return NDArray::load($path);
     }
 
     /**
-     * Stack arrays horizontally (along axis 1).
-     *
-     * Equivalent to concatenate(arrays, axis=1).
+     * Save named arrays to a NumPy `.npz` archive — delegates to {@see NDArray::savez()}.
      *
-     * @param array<NDArray> $arrays Arrays to stack
+     * @param array<int|string, NDArray> $arrays
      */
-    function hstack(array $arrays): NDArray
+    function savez(string $path, array $arrays, bool $compressed = false): void
     {
-        return NDArray::hstack($arrays);
+        NDArray::savez($path, $arrays, $compressed);
     }
 
     /**
-     * Split array along axis.
+     * Load arrays from a NumPy `.npz` archive — delegates to {@see NDArray::loadz()}.
      *
-     * If $indicesOrSections is an integer N, split into N equal parts (axis length must be divisible by N).
-     * If it is an array of indices, split at those positions.
-     *
-     * @param array<int>|int $indicesOrSections Number of equal parts, or array of split indices
-     * @param int            $axis              Axis along which to split
+     * @param null|array<string> $names
      *
-     * @return array<NDArray> List of sub-arrays (views)
+     * @return array<string, NDArray>
      */
-    function split(NDArray $a, array|int $indicesOrSections, int $axis = 0): array
+    function loadz(string $path, ?array $names = null): array
     {
-        return $a->split($indicesOrSections, $axis);
+        return NDArray::loadz($path, $names);
     }
 
+    // =============================================================================
+    // HasConversion — CSV files
+    // =============================================================================
+
     /**
-     * Split array vertically (along axis 0).
-     *
-     * @param array<int>|int $indicesOrSections Number of equal parts or split indices
-     *
-     * @return array<NDArray>
+     * Save a 0-D, 1-D or 2-D array as a CSV file — delegates to {@see NDArray::toCsv()}.
      */
-    function vsplit(NDArray $a, array|int $indicesOrSections): array
+    function to_csv(string $path, NDArray $a, string $delimiter = ',', ?string $header = null, string $nanRepr = 'nan'): void
     {
-        return $a->vsplit($indicesOrSections);
+        $a->toCsv($path, $delimiter, $header, $nanRepr);
     }
 
     /**
-     * Split array horizontally (along axis 1).
-     *
-     * @param array<int>|int $indicesOrSections Number of equal parts or split indices
-     *
-     * @return array<NDArray>
+     * Load a CSV file as a 2-D array — delegates to {@see NDArray::fromCsv()}.
      */
-    function hsplit(NDArray $a, array|int $indicesOrSections): array
-    {
-        return $a->hsplit($indicesOrSections);
+    function from_csv(
+        string $path,
+        string $delimiter = ',',
+        int $skipRows = 0,
+        DType $dtype = DType::Float64,
+        float $fillValue = NAN,
+    ): NDArray {
+        return NDArray::fromCsv($path, $delimiter, $skipRows, $dtype, $fillValue);
     }
 
     // =============================================================================
-    // HasIndexing — get/set, take/put, scatter, where
+    // HasPreprocessing — standardize, scale, normalize
     // =============================================================================
 
     /**
-     * Access elements by index.
-     *
-     * Full indices (count === ndim) return a scalar via FFI read.
-     * Partial indices (count < ndim) return a view (pure PHP, zero FFI).
-     *
-     * Supports negative indices: -1 refers to the last element, -2 to second-to-last, etc.
+     * Standardize along an axis: (x - mean) / (std + epsilon).
      *
-     * @param int ...$indices One or more dimension indices
+     * @param NDArray      $a       Input array
+     * @param int          $axis    Axis along which statistics are taken
+     * @param null|NDArray $mean    Precomputed means, or null to compute them
+     * @param null|NDArray $std     Precomputed standard deviations, or null to compute them
+     * @param int          $ddof    Delta degrees of freedom for computed standard deviations
+     * @param float        $epsilon Added to the standard deviation
+     */
+    function standardize(
+        NDArray $a,
+        int $axis = 0,
+        ?NDArray $mean = null,
+        ?NDArray $std = null,
+        int $ddof = 0,
+        float $epsilon = 0.0,
+    ): NDArray {
+        return $a->standardize($axis, $mean, $std, $ddof, $epsilon);
+    }
+
+    /**
+     * Scale each lane along an axis linearly to the range [min, max].
      *
-     * @return bool|float|int|NDArray Scalar for full indexing, view for partial
+     * @param NDArray $a    Input array
+     * @param int     $axis Axis along which ranges are taken
+     * @param float   $min  Lower bound of the target range
+     * @param float   $max  Upper bound of the target range
      */
-    function get(NDArray $a, int ...$indices): bool|Complex|float|int|NDArray
+    function minmax_scale(NDArray $a, int $axis = 0, float $min = 0.0, float $max = 1.0): NDArray
     {
-        return $a->get(...$indices);
+        return $a->minMaxScale($axis, $min, $max);
     }
 
     /**
-     * Set a scalar value at the given indices.
-     *
-     * Requires full indexing (count === ndim).
+     * Divide each lane along an axis by its L2 norm.
      *
-     * Supports negative indices: -1 refers to the last element, -2 to second-to-last, etc.
-     *
-     * @param array<int>             $indices Indices for each dimension
-     * @param bool|Complex|float|int $value   Value to set
+     * @param NDArray $a       Input array
+     * @param int     $axis    Axis along which norms are taken
+     * @param float   $epsilon Lower bound on the norm
      */
-    function set(NDArray $a, array $indices, bool|Complex|float|int $value): void
+    function l2_normalize(NDArray $a, int $axis = -1, float $epsilon = 1e-12): NDArray
     {
-        $a->set($indices, $value);
+        return $a->l2Normalize($axis, $epsilon);
     }
 
+    // =============================================================================
+    // HasActivations — neural-network activation functions
+    // =============================================================================
+
     /**
-     * Set a scalar value using a logical flat index (C-order) for this array/view.
-     *
-     * Supports negative indices: -1 refers to the last logical element.
-     *
-     * @param int            $flatIndex Logical flat index into this array/view
-     * @param bool|float|int $value     Value to set
+     * Compute sigmoid element-wise: 1 / (1 + exp(-x)).
      */
-    function set_at(NDArray $a, int $flatIndex, bool|Complex|float|int $value): void
+    function sigmoid(NDArray $a): NDArray
     {
-        $a->setAt($flatIndex, $value);
+        return $a->sigmoid();
     }
 
     /**
-     * Get a scalar value using a logical flat index (C-order) for this array/view.
-     *
-     * Supports negative indices: -1 refers to the last logical element.
-     *
-     * @param int $flatIndex Logical flat index into this array/view
-     *
-     * @return bool|float|int The value at the specified flat index
+     * Compute the rectified linear unit element-wise: max(x, 0).
      */
-    function get_at(NDArray $a, int $flatIndex): bool|Complex|float|int
+    function relu(NDArray $a): NDArray
     {
-        return $a->getAt($flatIndex);
+        return $a->relu();
     }
 
     /**
-     * Gather values by indices.
-     *
-     * If axis is null, gathers from logical flattened view (C-order).
-     * If axis is provided, delegates to takeAlongAxis semantics.
-     *
-     * @param array<array<int>|int>|NDArray $indices
+     * Compute leaky relu element-wise: x for x >= 0, alpha * x otherwise.
      */
-    function take(NDArray $a, array|NDArray $indices, ?int $axis = null): NDArray
+    function leaky_relu(NDArray $a, float $alpha = 0.01): NDArray
     {
-        return $a->take($indices, $axis);
+        return $a->leakyRelu($alpha);
     }
 
     /**
-     * Gather values along an axis using per-position indices.
-     *
-     * @param array<array<int>|int>|NDArray $indices
+     * Compute GELU element-wise, using the tanh approximation.
      */
-    function take_along_axis(NDArray $a, array|NDArray $indices, int $axis): NDArray
+    function gelu(NDArray $a): NDArray
     {
-        return $a->takeAlongAxis($indices, $axis);
+        return $a->gelu();
     }
 
     /**
-     * Scatter values by flattened indices and return a mutated copy.
-     *
-     * @param array<array<int>|int>|NDArray $indices
-     * @param string                        $mode    Currently supports only 'raise'
+     * Compute SiLU (swish) element-wise: x * sigmoid(x).
      */
-    function put(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $values, string $mode = 'raise'): NDArray
+    function silu(NDArray $a): NDArray
     {
-        return $a->put($indices, $values, $mode);
+        return $a->silu();
     }
 
     /**
-     * Scatter values along an axis and return a mutated copy.
-     *
-     * @param array<array<int>|int>|NDArray $indices
+     * Compute softplus element-wise: log(1 + exp(x)).
      */
-    function put_along_axis(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $values, int $axis): NDArray
+    function softplus(NDArray $a): NDArray
     {
-        return $a->putAlongAxis($indices, $values, $axis);
+        return $a->softplus();
     }
 
+    // =============================================================================
+    // HasConvolution — 1D convolution, 2D convolution and cross-correlation
+    // =============================================================================
+
     /**
-     * Add updates by flattened indices and return a mutated copy.
+     * Discrete linear convolution of two 1D arrays.
      *
-     * @param array<array<int>|int>|NDArray $indices
+     * @param array<mixed>|NDArray $v
      */
-    function scatter_add(NDArray $a, array|NDArray $indices, bool|float|int|NDArray $updates): NDArray
+    function convolve(NDArray $a, array|NDArray $v, ConvolveMode $mode = ConvolveMode::Full): NDArray
     {
-        return $a->scatterAdd($indices, $updates);
+        return $a->convolve($v, $mode);
     }
 
     /**
-     * Select values from x and y based on a boolean condition.
+     * 2D convolution with zero padding and stride.
      *
-     * @param bool|float|int|NDArray $condition Bool NDArray or scalar condition
-     * @param bool|float|int|NDArray $x         Values where condition is true
-     * @param bool|float|int|NDArray $y         Values where condition is false
+     * @param array<mixed>|NDArray $kernel
+     * @param array{int, int}|int  $stride
+     * @param array{int, int}|int  $padding
      */
-    function where(bool|float|int|NDArray $condition, bool|float|int|NDArray $x, bool|float|int|NDArray $y): NDArray
-    {
-        return NDArray::where($condition, $x, $y);
+    function convolve2d(
+        NDArray $a,
+        array|NDArray $kernel,
+        ConvolveMode $mode = ConvolveMode::Full,
+        array|int $stride = 1,
+        array|int $padding = 0,
+    ): NDArray {
+        return $a->convolve2d($kernel, $mode, $stride, $padding);
+    }
+
+    /**
+     * 2D cross-correlation with zero padding and stride.
+     *
+     * @param array<mixed>|NDArray $kernel
+     * @param array{int, int}|int  $stride
+     * @param array{int, int}|int  $padding
+     */
+    function correlate2d(
+        NDArray $a,
+        array|NDArray $kernel,
+        ConvolveMode $mode = ConvolveMode::Full,
+        array|int $stride = 1,
+        array|int $padding = 0,
+    ): NDArray {
+        return $a->correlate2d($kernel, $mode, $stride, $padding);
     }
 
     // =============================================================================
-    // HasSlicing — slice views, assign
+    // HasDistance — similarity and nearest-neighbour search between row matrices
     // =============================================================================
 
     /**
-     * Create a view of the array using slice syntax.
-     *
-     * Selectors can be integers (to reduce dimensions) or slice strings
-     * (e.g., "0:5", ":", "::2") to subset dimensions.
-     *
-     * @param array<int|string> $selection List of selectors for each dimension
+     * Cosine similarity between every row of $a and every row of $b.
      *
-     * @return NDArray A new view sharing the same data
+     * @param NDArray      $a (m, d) matrix
+     * @param null|NDArray $b (n, d) matrix (default: $a)
      */
-    function slice(NDArray $a, array $selection): NDArray
+    function cosine_similarity(NDArray $a, ?NDArray $b = null): NDArray
     {
-        return $a->slice($selection);
+        return $a->cosineSimilarity($b);
     }
 
     /**
-     * Assign values to the current array/view.
+     * Exact k-nearest-neighbour search of $database rows for each row of $queries.
      *
-     * Supports scalar assignment (fill) or NDArray assignment (rhs is broadcast to this view’s shape when compatible).
+     * @param NDArray        $queries  (m, d) query rows
+     * @param NDArray        $database (n, d) rows to search
+     * @param int            $k        Number of neighbours per query
+     * @param DistanceMetric $metric   Distance metric
      *
-     * @param bool|Complex|float|int|NDArray $value Scalar value or NDArray
+     * @return array{0: NDArray, 1: NDArray} [indices, distances]
      */
-    function assign(NDArray $a, bool|Complex|float|int|NDArray $value): void
-    {
-        $a->assign($value);
+    function nearest_neighbors(
+        NDArray $queries,
+        NDArray $database,
+        int $k = 1,
+        DistanceMetric $metric = DistanceMetric::Euclidean,
+    ): array {
+        return $queries->nearestNeighbors($database, $k, $metric);
     }
 }
 
 namespace PhpMlKit\NDArray\Linalg {
     use PhpMlKit\NDArray\Complex;
+    use PhpMlKit\NDArray\DType;
     use PhpMlKit\NDArray\NDArray;
 
     /**
@@ -1727,37 +3002,88 @@ namespace PhpMlKit\NDArray\Linalg {
     }
 
     /**
-     * Matrix multiplication (`@`) for 1D and 2D operands.
+     * Matrix multiplication (`@`) following NumPy `matmul` rules.
      *
      * Operand dtypes are promoted to a common type before the operation. Supported shapes:
      * - **2D × 2D**: matrix × matrix → 2D array
      * - **2D × 1D** or **1D × 2D**: matrix × vector → 1D array
      * - **1D × 1D**: inner product → scalar (0-D result unpacked to a PHP scalar or `Complex`)
+     * - **ND × ND**: stacks of matrices `[..., M, K] × [..., K, N]` → `[..., M, N]`,
+     *   with the leading batch dimensions broadcast against each other
      *
-     * Operands with more than two dimensions are not supported.
+     * By default products are accumulated in the promoted dtype. Pass `$accumulate`
+     * to accumulate in a wider dtype (e.g. Float64 for Float32 inputs) for better
+     * precision on long inner dimensions, and `$dtype` to choose the result dtype.
      *
-     * @param NDArray $other The other array
+     * @param NDArray    $other      The other array
+     * @param null|DType $accumulate Accumulation dtype (real for real inputs, complex for complex inputs)
+     * @param null|DType $dtype      Result dtype; defaults to the promoted dtype
      *
      * @return Complex|float|int|NDArray scalar when the result is 0-D, otherwise an NDArray
      */
-    function matmul(NDArray $a, NDArray $other): Complex|float|int|NDArray
+    function matmul(NDArray $a, NDArray $other, ?DType $accumulate = null, ?DType $dtype = null): Complex|float|int|NDArray
+    {
+        return $a->matmul($other, $accumulate, $dtype);
+    }
+
+    /**
+     * Tensor dot product: sum products over pairs of axes of `$a` and `$b`.
+     *
+     * @param array{0: array<int>|int, 1: array<int>|int}|int $axes Number of axes or explicit axis pairs
+     *
+     * @return Complex|float|int|NDArray scalar when every axis is contracted, otherwise an NDArray
+     *
+     * @see NDArray::tensordot()
+     */
+    function tensordot(NDArray $a, NDArray $b, array|int $axes = 2): Complex|float|int|NDArray
+    {
+        return $a->tensordot($b, $axes);
+    }
+
+    /**
+     * Outer product of two arrays, flattened first; the result has shape `[a.size, b.size]`.
+     *
+     * @see NDArray::outer()
+     */
+    function outer(NDArray $a, NDArray $b): NDArray
     {
-        return $a->matmul($other);
+        return $a->outer($b);
+    }
+
+    /**
+     * Kronecker product of two arrays.
+     *
+     * @see NDArray::kron()
+     */
+    function kron(NDArray $a, NDArray $b): NDArray
+    {
+        return $a->kron($b);
+    }
+
+    /**
+     * Cross product of 3-vectors along the last axis, broadcasting leading axes.
+     *
+     * @see NDArray::cross()
+     */
+    function cross(NDArray $a, NDArray $b): NDArray
+    {
+        return $a->cross($b);
     }
 
     /**
      * Einstein summation with deterministic accumulation order.
      *
-     * @param string       $subscripts Einstein summation subscript
-     * @param null|NDArray $b          Second operand (null for single-operand)
+     * @param string  $subscripts  Einstein summation subscript
+     * @param NDArray $a           First operand
+     * @param NDArray ...$operands Remaining operands (none for single-operand patterns)
      *
      * @return NDArray Result of the contraction
      *
      * @see NDArray::einsum()
      */
-    function einsum(string $subscripts, NDArray $a, ?NDArray $b = null): NDArray
+    function einsum(string $subscripts, NDArray $a, NDArray ...$operands): NDArray
     {
-        return $a->einsum($subscripts, $b);
+        return $a->einsum($subscripts, ...$operands);
     }
 
     /**
@@ -1785,6 +3111,26 @@ namespace PhpMlKit\NDArray\Linalg {
         return $a->diag($offset);
     }
 
+    /**
+     * Lower triangle of a matrix or stack of matrices.
+     *
+     * @param int $k Diagonal above which to zero elements
+     */
+    function tril(NDArray $a, int $k = 0): NDArray
+    {
+        return $a->tril($k);
+    }
+
+    /**
+     * Upper triangle of a matrix or stack of matrices.
+     *
+     * @param int $k Diagonal below which to zero elements
+     */
+    function triu(NDArray $a, int $k = 0): NDArray
+    {
+        return $a->triu($k);
+    }
+
     /**
      * Compute trace (sum of diagonal elements).
      *
@@ -1810,7 +3156,8 @@ namespace PhpMlKit\NDArray\Linalg {
     /**
      * Compute the inverse of a square matrix.
      *
-     * Requires a 2D square matrix.
+     * Requires a 2D square matrix or a stack of them (`[..., N, N]`), in which case
+     * each matrix is inverted independently. Singular matrices throw a MathException.
      */
     function inv(NDArray $a): NDArray
     {
@@ -1820,9 +3167,12 @@ namespace PhpMlKit\NDArray\Linalg {
     /**
      * Compute the determinant of a square matrix.
      *
-     * Requires a 2D square matrix.
+     * Requires a 2D square matrix or a stack of them (`[..., N, N]`).
+     *
+     * @return Complex|float|int|NDArray scalar for a single matrix, otherwise an array of
+     *                                   determinants shaped like the batch dimensions
      */
-    function det(NDArray $a): Complex|float|int
+    function det(NDArray $a): Complex|float|int|NDArray
     {
         return $a->det();
     }
@@ -1835,13 +3185,15 @@ namespace PhpMlKit\NDArray\Linalg {
      * - S is a diagonal matrix of singular values (returned as 1D array)
      * - V^T is an orthogonal matrix (right singular vectors transposed)
      *
-     * @param bool $computeUv If true, compute U and V^T matrices. If false, only compute singular values.
+     * @param bool $computeUv    If true, compute U and V^T matrices. If false, only compute singular values.
+     * @param bool $fullMatrices If true, U is M×M and V^T is N×N. If false, they are reduced to M×K and
+     *                           K×N with K = min(M, N), which is all a low-rank reconstruction needs.
      *
      * @return ($computeUv is true ? array{0: NDArray, 1: NDArray, 2: NDArray} : NDArray)
      */
-    function svd(NDArray $a, bool $computeUv = true): array|NDArray
+    function svd(NDArray $a, bool $computeUv = true, bool $fullMatrices = true): array|NDArray
     {
-        return $a->svd($computeUv);
+        return $a->svd($computeUv, $fullMatrices);
     }
 
     /**
@@ -1856,6 +3208,19 @@ namespace PhpMlKit\NDArray\Linalg {
         return $a->qr();
     }
 
+    /**
+     * Compute LU decomposition with partial pivoting.
+     *
+     * Factors an M×N matrix so that `A[perm] = L * U`, where L is unit lower
+     * triangular and U is upper triangular.
+     *
+     * @return array{0: NDArray, 1: NDArray, 2: NDArray} [L, U, perm] with perm as an Int64 array
+     */
+    function lu(NDArray $a): array
+    {
+        return $a->lu();
+    }
+
     /**
      * Compute eigenvalue decomposition.
      *
@@ -1957,6 +3322,32 @@ namespace PhpMlKit\NDArray\Linalg {
         return $a->pinv($rcond);
     }
 
+    /**
+     * Least-squares fit of a polynomial of degree `$deg`; coefficients are highest power first.
+     */
+    function polyfit(NDArray $x, NDArray $y, int $deg): NDArray
+    {
+        return NDArray::polyfit($x, $y, $deg);
+    }
+
+    /**
+     * Evaluate the polynomial with coefficients `$p` (highest power first) at each element of `$x`.
+     */
+    function polyval(NDArray $p, NDArray $x): NDArray
+    {
+        return NDArray::polyval($p, $x);
+    }
+
+    /**
+     * Roots of a polynomial (highest power first) as [real, imag] arrays.
+     *
+     * @return array{0: NDArray, 1: NDArray}
+     */
+    function roots(NDArray $p): array
+    {
+        return NDArray::roots($p);
+    }
+
     /**
      * Compute the 2-norm condition number of a matrix.
      */
@@ -2262,3 +3653,88 @@ namespace PhpMlKit\NDArray\Windows {
         return NDArray::triang($m, $periodic);
     }
 }
+
+namespace PhpMlKit\NDArray\Image {
+    use PhpMlKit\NDArray\ImageFormat;
+    use PhpMlKit\NDArray\ImageLayout;
+    use PhpMlKit\NDArray\Interpolation;
+    use PhpMlKit\NDArray\NDArray;
+
+    /**
+     * Resize an image to `$height` x `$width` — delegates to {@see NDArray::resizeImage()}.
+     */
+    function resize(
+        NDArray $image,
+        int $height,
+        int $width,
+        Interpolation $interpolation = Interpolation::Bilinear,
+        bool $antialias = false,
+        ImageLayout $layout = ImageLayout::HWC,
+    ): NDArray {
+        return $image->resizeImage($height, $width, $interpolation, $antialias, $layout);
+    }
+
+    /**
+     * Convert an HWC image (or NHWC batch) to contiguous CHW — delegates to {@see NDArray::hwcToChw()}.
+     */
+    function hwc_to_chw(NDArray $image): NDArray
+    {
+        return $image->hwcToChw();
+    }
+
+    /**
+     * Convert a CHW image (or NCHW batch) to contiguous HWC — delegates to {@see NDArray::chwToHwc()}.
+     */
+    function chw_to_hwc(NDArray $image): NDArray
+    {
+        return $image->chwToHwc();
+    }
+
+    /**
+     * RGB to grayscale with BT.601 weights — delegates to {@see NDArray::rgbToGray()}.
+     */
+    function rgb_to_gray(NDArray $image, ImageLayout $layout = ImageLayout::HWC, bool $keepChannelAxis = false): NDArray
+    {
+        return $image->rgbToGray($layout, $keepChannelAxis);
+    }
+
+    /**
+     * Grayscale to three-channel RGB — delegates to {@see NDArray::grayToRgb()}.
+     */
+    function gray_to_rgb(NDArray $image, ImageLayout $layout = ImageLayout::HWC): NDArray
+    {
+        return $image->grayToRgb($layout);
+    }
+
+    /**
+     * RGB to BGR channel order — delegates to {@see NDArray::rgbToBgr()}.
+     */
+    function rgb_to_bgr(NDArray $image, ImageLayout $layout = ImageLayout::HWC): NDArray
+    {
+        return $image->rgbToBgr($layout);
+    }
+
+    /**
+     * BGR to RGB channel order — delegates to {@see NDArray::bgrToRgb()}.
+     */
+    function bgr_to_rgb(NDArray $image, ImageLayout $layout = ImageLayout::HWC): NDArray
+    {
+        return $image->bgrToRgb($layout);
+    }
+
+    /**
+     * Decode PNG or JPEG file contents to a uint8 HWC array — delegates to {@see NDArray::decodeImage()}.
+     */
+    function decode(string $bytes): NDArray
+    {
+        return NDArray::decodeImage($bytes);
+    }
+
+    /**
+     * Encode a uint8 image as PNG or JPEG file contents — delegates to {@see NDArray::encodeImage()}.
+     */
+    function encode(NDArray $image, ImageFormat $format = ImageFormat::Png, int $quality = 90): string
+    {
+        return $image->encodeImage($format, $quality);
+    }
+}
@@ -0,0 +1,29 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+use PhpMlKit\NDArray\FFI\Lib;
+
+/**
+ * A gufunc kernel registered with the native library.
+ *
+ * The native registry only stores a pointer to the PHP callback, so this object keeps the
+ * callback alive while it is registered and removes the registration before the callback is
+ * freed.
+ *
+ * @internal
+ */
+final class GufuncRegistration
+{
+    public function __construct(
+        public readonly int $id,
+        public \Closure $callback,
+    ) {}
+
+    public function __destruct()
+    {
+        Lib::get()->ndarray_gufunc_unregister($this->id);
+    }
+}
@@ -0,0 +1,19 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Encoded image file format.
+ *
+ * Integer values must stay in sync with Rust ImageFormat in ffi/image/codec.
+ */
+enum ImageFormat: int
+{
+    /** Lossless PNG with 1-4 channels. */
+    case Png = 0;
+
+    /** Baseline JPEG with 1 or 3 channels. */
+    case Jpeg = 1;
+}
@@ -0,0 +1,19 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Channel layout of a 3D image tensor.
+ *
+ * Integer values must stay in sync with Rust ImageLayout in ffi/image.
+ */
+enum ImageLayout: int
+{
+    /** Height, width, channels (PHP/GD and most image decoders). */
+    case HWC = 0;
+
+    /** Channels, height, width (most vision model inputs). */
+    case CHW = 1;
+}
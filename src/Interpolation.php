@@ -0,0 +1,16 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Resampling filter for NDArray::resizeImage().
+ *
+ * Integer values must stay in sync with Rust Interpolation in ffi/image.
+ */
+enum Interpolation: int
+{
+    case Nearest = 0;
+    case Bilinear = 1;
+}
@@ -6,19 +6,27 @@ namespace PhpMlKit\NDArray;
 
 use FFI\CData;
 use PhpMlKit\NDArray\Exceptions\IndexException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\Traits\CanBePrinted;
 use PhpMlKit\NDArray\Traits\CreatesArrays;
+use PhpMlKit\NDArray\Traits\HasActivations;
 use PhpMlKit\NDArray\Traits\HasArrayAccess;
+use PhpMlKit\NDArray\Traits\HasCallbacks;
 use PhpMlKit\NDArray\Traits\HasComparison;
 use PhpMlKit\NDArray\Traits\HasConversion;
+use PhpMlKit\NDArray\Traits\HasConvolution;
+use PhpMlKit\NDArray\Traits\HasDistance;
 use PhpMlKit\NDArray\Traits\HasFourier;
+use PhpMlKit\NDArray\Traits\HasImageOps;
 use PhpMlKit\NDArray\Traits\HasIndexing;
 use PhpMlKit\NDArray\Traits\HasLinearAlgebra;
 use PhpMlKit\NDArray\Traits\HasLogical;
 use PhpMlKit\NDArray\Traits\HasMath;
 use PhpMlKit\NDArray\Traits\HasOps;
+use PhpMlKit\NDArray\Traits\HasPreprocessing;
 use PhpMlKit\NDArray\Traits\HasReductions;
+use PhpMlKit\NDArray\Traits\HasSetOps;
 use PhpMlKit\NDArray\Traits\HasShapeOps;
 use PhpMlKit\NDArray\Traits\HasSlicing;
 use PhpMlKit\NDArray\Traits\HasStacking;
@@ -38,16 +46,23 @@ class NDArray implements \ArrayAccess, \Stringable, \IteratorAggregate
 {
     use CanBePrinted;
     use CreatesArrays;
+    use HasActivations;
     use HasArrayAccess;
+    use HasCallbacks;
     use HasComparison;
     use HasConversion;
+    use HasConvolution;
+    use HasDistance;
     use HasFourier;
+    use HasImageOps;
     use HasIndexing;
     use HasLinearAlgebra;
     use HasLogical;
     use HasMath;
     use HasOps;
+    use HasPreprocessing;
     use HasReductions;
+    use HasSetOps;
     use HasShapeOps;
     use HasSlicing;
     use HasStacking;
@@ -211,16 +226,172 @@ class NDArray implements \ArrayAccess, \Stringable, \IteratorAggregate
         return $this->meta->strides === $expected;
     }
 
+    /**
+     * Check if the array is Fortran-contiguous (column-major).
+     */
+    public function isFortranContiguous(): bool
+    {
+        $expected = ArrayMetadata::computeStrides($this->meta->shape, 'F');
+
+        return $this->meta->strides === $expected;
+    }
+
+    /**
+     * Get layout flags of this array as seen by the native storage.
+     *
+     * - `c_contiguous` / `f_contiguous`: elements are laid out in C (row-major) or
+     *   Fortran (column-major) order. Length-1 axes are ignored and zero-size arrays
+     *   are contiguous in both orders.
+     * - `owndata`: the array covers its whole underlying buffer rather than being
+     *   a window into a larger one.
+     * - `itemsize` / `nbytes`: bytes per element and bytes spanned by all elements.
+     *
+     * @return array{c_contiguous: bool, f_contiguous: bool, owndata: bool, itemsize: int, nbytes: int}
+     */
+    public function flags(): array
+    {
+        $lib = Lib::get();
+        $meta = $this->meta->toCData();
+        $itemsize = $lib->new('size_t');
+        $nbytes = $lib->new('size_t');
+        $cContiguous = $lib->new('bool');
+        $fContiguous = $lib->new('bool');
+        $ownsData = $lib->new('bool');
+
+        $status = $lib->ndarray_flags(
+            $this->handle,
+            Lib::addr($meta),
+            Lib::addr($itemsize),
+            Lib::addr($nbytes),
+            Lib::addr($cContiguous),
+            Lib::addr($fContiguous),
+            Lib::addr($ownsData),
+        );
+        $lib->checkStatus($status);
+
+        return [
+            'c_contiguous' => $cContiguous->cdata,
+            'f_contiguous' => $fContiguous->cdata,
+            'owndata' => $ownsData->cdata,
+            'itemsize' => $itemsize->cdata,
+            'nbytes' => $nbytes->cdata,
+        ];
+    }
+
+    /**
+     * Report the linear algebra backends compiled into the native library.
+     *
+     * - `blas`: whether `matmul()` and `dot()` dispatch to BLAS gemm. The native
+     *   library only does so when built with the `blas` Cargo feature; otherwise
+     *   they run on pure-Rust kernels.
+     * - `lapack`: the LAPACK provider used by decompositions and solvers
+     *   (`openblas-static` or `mkl-static`). It is always statically linked,
+     *   independent of the `blas` feature.
+     *
+     * @return array{blas: bool, lapack: string}
+     */
+    public static function backendInfo(): array
+    {
+        $lib = Lib::get();
+        $outBlas = $lib->new('uint8_t');
+        $buffer = $lib->new('char[32]');
+
+        $status = $lib->ndarray_backend_info(Lib::addr($outBlas), $buffer, 32);
+        $lib->checkStatus($status);
+
+        return [
+            'blas' => 1 === $outBlas->cdata,
+            'lapack' => \FFI::string($buffer),
+        ];
+    }
+
+    /**
+     * Set the number of threads used for large arrays.
+     *
+     * Element-wise arithmetic and math, float sums and flat sorts split arrays of
+     * at least getParallelThreshold() elements across a thread pool. Pass 0 for
+     * one thread per core. Has no effect unless the native library was built with
+     * the `parallel` Cargo feature.
+     */
+    public static function setNumThreads(int $numThreads): void
+    {
+        if ($numThreads < 0) {
+            throw new \InvalidArgumentException('Thread count must be non-negative');
+        }
+        $lib = Lib::get();
+        $lib->checkStatus($lib->ndarray_set_num_threads($numThreads));
+    }
+
+    /**
+     * Number of threads used for large arrays (1 without the `parallel` feature).
+     */
+    public static function getNumThreads(): int
+    {
+        return Lib::get()->ndarray_get_num_threads();
+    }
+
+    /**
+     * Set the element count at which operations switch to the thread pool.
+     *
+     * Pass 0 to restore the default (1,048,576 elements).
+     */
+    public static function setParallelThreshold(int $threshold): void
+    {
+        if ($threshold < 0) {
+            throw new \InvalidArgumentException('Parallel threshold must be non-negative');
+        }
+        $lib = Lib::get();
+        $lib->checkStatus($lib->ndarray_set_parallel_threshold($threshold));
+    }
+
+    /**
+     * Element count at which operations switch to the thread pool.
+     */
+    public static function getParallelThreshold(): int
+    {
+        return Lib::get()->ndarray_get_parallel_threshold();
+    }
+
+    /**
+     * Compute a stable 64-bit content hash of the array.
+     *
+     * Hashes the dtype, shape and element data (XXH3, C order) without exporting
+     * the data to PHP. Equal arrays hash equally regardless of whether they are
+     * views, copies or differently strided. Useful for cache keys and change detection.
+     *
+     * @param int $seed Hash seed
+     *
+     * @return string 16-character lowercase hex digest
+     */
+    public function contentHash(int $seed = 0): string
+    {
+        $lib = Lib::get();
+        $outHash = $lib->new('uint64_t');
+        $meta = $this->meta->toCData();
+
+        $status = $lib->ndarray_hash($this->handle, Lib::addr($meta), $seed, Lib::addr($outHash));
+        $lib->checkStatus($status);
+
+        return \sprintf('%016x', $outHash->cdata);
+    }
+
     /**
      * Get iterator for foreach loops.
      *
      * 1D arrays: yields scalar values
      * 2D+ arrays: yields row views (along first axis)
+     * 0D arrays cannot be iterated (NumPy behavior); use toScalar() instead.
      *
      * @return \Generator<int, bool|float|int|self>
+     *
+     * @throws ShapeException If the array is 0-dimensional
      */
     public function getIterator(): \Generator
     {
+        if (0 === $this->ndim()) {
+            throw new ShapeException('Iteration over a 0-d array');
+        }
+
         if (1 === $this->ndim()) {
             foreach ($this->flat() as $value) {
                 yield $value;
@@ -247,4 +418,20 @@ class NDArray implements \ArrayAccess, \Stringable, \IteratorAggregate
     {
         return new FlatIterator($this);
     }
+
+    /**
+     * Get an N-dimensional iterator yielding `multi-index => value` pairs.
+     *
+     * Elements are visited in C-contiguous (row-major) order and fetched from
+     * Rust in blocks of `$blockSize`, so large arrays and strided views can be
+     * walked without a full nested-array conversion.
+     *
+     * @param null|int $blockSize Elements per block. Default: {@see NDIterator::$blockSize}
+     *
+     * @return NDIterator Iterator keyed by index tuples
+     */
+    public function ndenumerate(?int $blockSize = null): NDIterator
+    {
+        return new NDIterator($this, $blockSize);
+    }
 }
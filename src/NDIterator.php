@@ -0,0 +1,105 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+use PhpMlKit\NDArray\FFI\Lib;
+
+/**
+ * N-dimensional iterator over an NDArray.
+ *
+ * Walks the array in C-contiguous (row-major) order and yields each element
+ * keyed by its multi-index. Elements are pulled from Rust in fixed-size
+ * blocks, so iterating a large array (or a strided view of one) never
+ * materialises the whole array in PHP.
+ *
+ * Note: The iterator reads live data - writes to the array made between
+ * blocks are visible to elements not yet fetched.
+ *
+ * @implements \IteratorAggregate<list<int>, bool|Complex|float|int>
+ */
+class NDIterator implements \IteratorAggregate, \Countable
+{
+    /**
+     * Default number of elements fetched per FFI call.
+     */
+    public static int $blockSize = 1024;
+
+    private readonly int $size;
+
+    private readonly int $blockLength;
+
+    /**
+     * @param NDArray  $array     Array (or view) to iterate
+     * @param null|int $blockSize Elements per block. Default: {@see NDIterator::$blockSize}
+     */
+    public function __construct(public readonly NDArray $array, ?int $blockSize = null)
+    {
+        $this->size = $array->size();
+        $this->blockLength = max(1, $blockSize ?? self::$blockSize);
+    }
+
+    /**
+     * Yield `multi-index => value` pairs in row-major order.
+     *
+     * @return \Generator<list<int>, bool|Complex|float|int>
+     */
+    public function getIterator(): \Generator
+    {
+        if (0 === $this->size) {
+            return;
+        }
+
+        $lib = Lib::get();
+        $dtype = $this->array->dtype();
+        $ndim = $this->array->ndim();
+
+        $iter = $lib->new('struct NdIterHandle*');
+        $meta = $this->array->meta()->toCData();
+        $status = $lib->ndarray_iter_new($this->array->handle(), Lib::addr($meta), Lib::addr($iter));
+        $lib->checkStatus($status);
+
+        try {
+            $values = $dtype->createCArray($this->blockLength);
+            $indices = $lib->new(\sprintf('size_t[%d]', max(1, $this->blockLength * $ndim)));
+            $count = $lib->new('size_t');
+
+            while (true) {
+                $status = $lib->ndarray_iter_next_block(
+                    $iter,
+                    $this->blockLength,
+                    $values,
+                    $indices,
+                    Lib::addr($count),
+                );
+                $lib->checkStatus($status);
+
+                $n = (int) $count->cdata;
+                if (0 === $n) {
+                    break;
+                }
+
+                for ($i = 0; $i < $n; ++$i) {
+                    $index = [];
+                    for ($d = 0; $d < $ndim; ++$d) {
+                        $index[] = (int) $indices[$i * $ndim + $d];
+                    }
+
+                    $value = $dtype->isComplex()
+                        ? new Complex((float) $values[$i * 2], (float) $values[$i * 2 + 1])
+                        : $dtype->castFromCValue($values[$i]);
+
+                    yield $index => $value;
+                }
+            }
+        } finally {
+            $lib->ndarray_iter_free($iter);
+        }
+    }
+
+    public function count(): int
+    {
+        return $this->size;
+    }
+}
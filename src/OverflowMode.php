@@ -0,0 +1,22 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * What integer add(), subtract() and multiply() do when a result does not fit its dtype.
+ *
+ * Integer values must stay in sync with Rust OverflowMode.
+ */
+enum OverflowMode: int
+{
+    /** Two's-complement wraparound (default), as in NumPy. */
+    case Wrap = 0;
+
+    /** Clamp to the smallest or largest value of the dtype. */
+    case Saturate = 1;
+
+    /** Throw an OverflowException. */
+    case Error = 2;
+}
@@ -0,0 +1,29 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * How median(), quantile() and percentile() resolve a position that falls
+ * between two sorted data points.
+ *
+ * Integer values must stay in sync with Rust QuantileMethod in ffi/reductions.
+ */
+enum QuantileMethod: int
+{
+    /** Linear interpolation between the two neighbours (default). */
+    case Linear = 0;
+
+    /** The smaller neighbour. */
+    case Lower = 1;
+
+    /** The larger neighbour. */
+    case Higher = 2;
+
+    /** The closer neighbour; exact ties go to the even index. */
+    case Nearest = 3;
+
+    /** The mean of the two neighbours. */
+    case Midpoint = 4;
+}
@@ -0,0 +1,64 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+use FFI\CData;
+use PhpMlKit\NDArray\FFI\Lib;
+
+/**
+ * Reusable random generator for the NDArray random factories.
+ *
+ * Passing the same generator to several calls continues one stream of draws, so a
+ * whole sequence of samples is reproducible from a single seed:
+ *
+ * ```php
+ * $rng = Rng::seeded(42);
+ * $weights = NDArray::randn([784, 128], rng: $rng);
+ * $dropout = NDArray::random([128], rng: $rng);
+ * ```
+ */
+final class Rng
+{
+    private function __construct(private readonly CData $handle) {}
+
+    public function __destruct()
+    {
+        Lib::get()->ndarray_rng_free($this->handle);
+    }
+
+    /**
+     * Create a generator with a fixed seed. Equal seeds give equal sequences of draws.
+     */
+    public static function seeded(int $seed): self
+    {
+        $lib = Lib::get();
+        $out = $lib->new('struct RngHandle*');
+        $lib->checkStatus($lib->ndarray_rng_new($seed, Lib::addr($out)));
+
+        return new self($out);
+    }
+
+    /**
+     * Create a generator seeded from operating-system entropy.
+     */
+    public static function fromEntropy(): self
+    {
+        $lib = Lib::get();
+        $out = $lib->new('struct RngHandle*');
+        $lib->checkStatus($lib->ndarray_rng_from_entropy(Lib::addr($out)));
+
+        return new self($out);
+    }
+
+    /**
+     * Raw generator handle for FFI calls.
+     *
+     * @internal
+     */
+    public function handle(): CData
+    {
+        return $this->handle;
+    }
+}
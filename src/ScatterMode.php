@@ -0,0 +1,20 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Reduction mode for NDArray::scatter().
+ *
+ * Integer values must stay in sync with Rust ScatterMode.
+ */
+enum ScatterMode: int
+{
+    case Assign = 0;
+    case Add = 1;
+    case Mul = 2;
+    case Min = 3;
+    case Max = 4;
+    case Mean = 5;
+}
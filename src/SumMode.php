@@ -0,0 +1,19 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray;
+
+/**
+ * Floating-point summation strategy for sum(), mean(), var() and std().
+ *
+ * Integer values must stay in sync with Rust SumMode.
+ */
+enum SumMode: int
+{
+    /** Blocked pairwise summation (default); error grows as O(log n). */
+    case Pairwise = 0;
+
+    /** Compensated (Kahan) summation; error is essentially independent of n. */
+    case Kahan = 1;
+}
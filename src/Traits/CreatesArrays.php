@@ -6,16 +6,20 @@ namespace PhpMlKit\NDArray\Traits;
 
 use FFI\CData;
 use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\ByteOrder;
+use PhpMlKit\NDArray\CastPolicy;
 use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\OverflowException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\Rng;
 
 /**
  * Static factory methods for creating NDArray instances.
  *
- * Provides array(), empty(), zeros(), ones(), full(), and eye().
+ * Provides array(), empty(), zeros(), ones(), full(), eye(), and tri().
  */
 trait CreatesArrays
 {
@@ -24,8 +28,9 @@ trait CreatesArrays
      *
      * @param array<mixed> $data  Nested PHP array
      * @param null|DType   $dtype Data type (auto-inferred if null)
+     * @param string       $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    public static function array(array $data, ?DType $dtype = null): self
+    public static function array(array $data, ?DType $dtype = null, string $order = 'C'): self
     {
         $shape = self::inferShape($data);
         $flatData = self::flattenArray($data);
@@ -39,9 +44,9 @@ trait CreatesArrays
         $lib = Lib::get();
         $len = \count($flatData);
 
-        $handle = self::createTyped($lib, $dtype, $flatData, $shape, $len);
+        $handle = self::createTyped($lib, $dtype, $flatData, $shape, $len, $order);
 
-        return new self($handle, new ArrayMetadata($shape), $dtype);
+        return new self($handle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
     }
 
     /**
@@ -49,8 +54,9 @@ trait CreatesArrays
      *
      * @param array<int> $shape Array shape
      * @param DType      $dtype Data type (default: Float64)
+     * @param string     $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    public static function zeros(array $shape, DType $dtype = DType::Float64): self
+    public static function zeros(array $shape, DType $dtype = DType::Float64, string $order = 'C'): self
     {
         $lib = Lib::get();
         $cShape = $lib->createCArray('size_t', $shape);
@@ -60,12 +66,13 @@ trait CreatesArrays
             $cShape,
             \count($shape),
             $dtype->value,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
         $lib->checkStatus($status);
 
-        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+        return new self($outHandle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
     }
 
     /**
@@ -73,8 +80,9 @@ trait CreatesArrays
      *
      * @param array<int> $shape Array shape
      * @param DType      $dtype Data type (default: Float64)
+     * @param string     $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    public static function ones(array $shape, DType $dtype = DType::Float64): self
+    public static function ones(array $shape, DType $dtype = DType::Float64, string $order = 'C'): self
     {
         $lib = Lib::get();
         $cShape = $lib->createCArray('size_t', $shape);
@@ -84,12 +92,13 @@ trait CreatesArrays
             $cShape,
             \count($shape),
             $dtype->value,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
         $lib->checkStatus($status);
 
-        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+        return new self($outHandle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
     }
 
     /**
@@ -101,14 +110,15 @@ trait CreatesArrays
      *
      * @param array<int> $shape Array shape
      * @param DType      $dtype Data type (default: Float64)
+     * @param string     $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    public static function empty(array $shape, DType $dtype = DType::Float64): self
+    public static function empty(array $shape, DType $dtype = DType::Float64, string $order = 'C'): self
     {
         if (!\in_array(0, $shape, true)) {
             throw new ShapeException('empty() requires a zero-size shape (at least one dimension must be 0)');
         }
 
-        return self::zeros($shape, $dtype);
+        return self::zeros($shape, $dtype, $order);
     }
 
     /**
@@ -117,8 +127,9 @@ trait CreatesArrays
      * @param bool|Complex|float|int $value Value to fill array with
      * @param array<int>             $shape Array shape
      * @param null|DType             $dtype Data type (default: inferred from value)
+     * @param string                 $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    public static function full(bool|Complex|float|int $value, array $shape, ?DType $dtype = null): self
+    public static function full(bool|Complex|float|int $value, array $shape, ?DType $dtype = null, string $order = 'C'): self
     {
         if (null === $dtype) {
             if ($value instanceof Complex) {
@@ -146,12 +157,13 @@ trait CreatesArrays
             \count($shape),
             Lib::addr($cValue),
             $dtype->value,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
         $lib->checkStatus($status);
 
-        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+        return new self($outHandle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
     }
 
     /**
@@ -180,8 +192,9 @@ trait CreatesArrays
      * @param array<mixed>    $data  Nested PHP array
      * @param null|array<int> $shape Optional shape. If null, inferred from data.
      * @param null|DType      $dtype Data type (auto-inferred if null)
+     * @param string          $order Memory layout: 'C' for row-major, 'F' for column-major
      */
-    public static function fromArray(array $data, ?array $shape = null, ?DType $dtype = null): self
+    public static function fromArray(array $data, ?array $shape = null, ?DType $dtype = null, string $order = 'C'): self
     {
         $shape ??= self::inferShape($data);
         $flatData = self::flattenArray($data);
@@ -195,9 +208,9 @@ trait CreatesArrays
         $lib = Lib::get();
         $len = \count($flatData);
 
-        $handle = self::createTyped($lib, $dtype, $flatData, $shape, $len);
+        $handle = self::createTyped($lib, $dtype, $flatData, $shape, $len, $order);
 
-        return new self($handle, new ArrayMetadata($shape), $dtype);
+        return new self($handle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
     }
 
     /**
@@ -206,13 +219,17 @@ trait CreatesArrays
      * This method copies data from an external FFI buffer into a new NDArray. The source buffer
      * remains owned by the caller - this method creates an independent copy.
      *
+     * With `$order = 'F'` the buffer is read as column-major data (as produced by BLAS/LAPACK
+     * and Fortran code) and kept in that layout, so no transpose is needed.
+     *
      * @param CData      $buffer Pointer to raw C data (e.g., float*, int16_t*)
      * @param array<int> $shape  Array shape
      * @param DType      $dtype  Data type of the buffer
+     * @param string     $order  Memory layout of the buffer: 'C' for row-major, 'F' for column-major
      *
      * @throws ShapeException If buffer size doesn't match shape
      */
-    public static function fromBuffer(CData $buffer, array $shape, DType $dtype): self
+    public static function fromBuffer(CData $buffer, array $shape, DType $dtype, string $order = 'C'): self
     {
         $expectedSize = (int) array_product($shape);
 
@@ -230,27 +247,31 @@ trait CreatesArrays
             $cShape,
             \count($shape),
             $dtype->value,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
         $lib->checkStatus($status);
 
-        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+        return new self($outHandle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
     }
 
     /**
      * Create an array from a binary string.
      *
-     * This method interprets a PHP binary string as raw array data in little-endian format.
-     * The bytes are copied into a new NDArray with the specified shape and dtype.
+     * This method interprets a PHP binary string as raw array data in the given byte order
+     * (little-endian by default). The bytes are copied into a new NDArray with the specified
+     * shape and dtype, swapping to native order when needed.
      *
-     * @param string     $bytes Binary string containing raw array data (little-endian)
-     * @param array<int> $shape Array shape
-     * @param DType      $dtype Data type of the buffer
+     * @param string     $bytes     Binary string containing raw array data
+     * @param array<int> $shape     Array shape
+     * @param DType      $dtype     Data type of the buffer
+     * @param ByteOrder  $byteOrder Byte order of `$bytes` (default: little-endian)
+     * @param string     $order     Memory layout of `$bytes`: 'C' for row-major, 'F' for column-major
      *
      * @throws ShapeException If buffer size doesn't match shape
      */
-    public static function fromBytes(string $bytes, array $shape, DType $dtype): self
+    public static function fromBytes(string $bytes, array $shape, DType $dtype, ByteOrder $byteOrder = ByteOrder::Little, string $order = 'C'): self
     {
         $expectedSize = (int) array_product($shape);
 
@@ -280,12 +301,256 @@ trait CreatesArrays
             $cShape,
             \count($shape),
             $dtype->value,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
         $lib->checkStatus($status);
 
-        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+        $array = new self($outHandle, new ArrayMetadata($shape, ArrayMetadata::computeStrides($shape, $order)), $dtype);
+
+        return $byteOrder->isNative() ? $array : $array->byteswap();
+    }
+
+    /**
+     * Restore an array from a string produced by {@see NDArray::serialize()}.
+     *
+     * Strings written on a host with the other byte order are swapped on load.
+     *
+     * @param string $bytes Serialized array
+     */
+    public static function unserialize(string $bytes): self
+    {
+        $lib = Lib::get();
+        $length = \strlen($bytes);
+        $buffer = $lib->new('uint8_t['.max(1, $length).']');
+        \FFI::memcpy($buffer, $bytes, $length);
+
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_from_bytes(
+            $buffer,
+            $length,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShapeBuf, (int) $outNdimBuf->cdata);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtypeBuf->cdata));
+    }
+
+    /**
+     * Decode an Apache Arrow IPC stream into an array.
+     *
+     * A single primitive column becomes a 1-D array (0-D when written by toArrow() from a 0-D
+     * array) and a single fixed-size list column a `[rows, listSize]` array, or the full tensor
+     * shape for `arrow.fixed_shape_tensor` columns. Several primitive columns of one type load as
+     * a `[rows, columns]` table. Record batches are concatenated into a new array; nulls are not
+     * supported. Requires the native library to be built with the `arrow` cargo feature.
+     *
+     * @param string $bytes Arrow IPC stream
+     */
+    public static function fromArrow(string $bytes): self
+    {
+        $length = \strlen($bytes);
+        if (0 === $length) {
+            throw new \InvalidArgumentException('Cannot decode an empty Arrow stream');
+        }
+
+        $lib = Lib::get();
+        $buffer = $lib->new("uint8_t[{$length}]");
+        \FFI::memcpy($buffer, $bytes, $length);
+
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_from_arrow_buffer(
+            $buffer,
+            $length,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShapeBuf, (int) $outNdimBuf->cdata);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtypeBuf->cdata));
+    }
+
+    /**
+     * Load an array from a NumPy `.npy` file.
+     *
+     * Reads files written by `numpy.save()` or {@see NDArray::save()}: any byte order and
+     * either memory order, returned as a native-endian C-contiguous array. Structured and
+     * object dtypes are not supported.
+     *
+     * @param string $path Path of the `.npy` file
+     */
+    public static function load(string $path): self
+    {
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_load_npy(
+            self::cString($path),
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShapeBuf, (int) $outNdimBuf->cdata);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtypeBuf->cdata));
+    }
+
+    /**
+     * Load arrays from a NumPy `.npz` archive.
+     *
+     * Reads archives written by `numpy.savez()`, `numpy.savez_compressed()` or
+     * {@see NDArray::savez()}. Pass `$names` to load only some of the arrays; the other
+     * entries are not decoded.
+     *
+     * @param string             $path  Path of the `.npz` file
+     * @param null|array<string> $names Names of the arrays to load (default: all, in archive order)
+     *
+     * @return array<string, self> Arrays keyed by name
+     */
+    public static function loadz(string $path, ?array $names = null): array
+    {
+        $lib = Lib::get();
+        $cPath = self::cString($path);
+        $names ??= self::npzNames($cPath);
+
+        $arrays = [];
+        foreach ($names as $name) {
+            $outHandle = $lib->new('struct NdArrayHandle*');
+            $outDtypeBuf = $lib->new('uint8_t');
+            $outNdimBuf = $lib->new('size_t');
+            $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+            $status = $lib->ndarray_load_npz(
+                $cPath,
+                self::cString($name),
+                Lib::addr($outHandle),
+                Lib::addr($outDtypeBuf),
+                Lib::addr($outNdimBuf),
+                $outShapeBuf,
+                Lib::MAX_NDIM
+            );
+
+            $lib->checkStatus($status);
+
+            $shape = $lib->readSizeTArray($outShapeBuf, (int) $outNdimBuf->cdata);
+            $arrays[$name] = new self($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtypeBuf->cdata));
+        }
+
+        return $arrays;
+    }
+
+    /**
+     * List the array names in a `.npz` archive.
+     *
+     * @param CData $cPath NUL-terminated path
+     *
+     * @return array<string>
+     */
+    private static function npzNames(CData $cPath): array
+    {
+        $lib = Lib::get();
+        $outLen = $lib->new('size_t');
+        $capacity = 4096;
+
+        while (true) {
+            $buffer = $lib->new("char[{$capacity}]");
+            $status = $lib->ndarray_npz_names($cPath, $buffer, $capacity, Lib::addr($outLen));
+            $lib->checkStatus($status);
+
+            $length = (int) $outLen->cdata;
+            if ($length <= $capacity) {
+                break;
+            }
+            $capacity = $length;
+        }
+
+        if (0 === $length) {
+            return [];
+        }
+
+        return explode("\0", \FFI::string($buffer, $length - 1));
+    }
+
+    /**
+     * Load a CSV file as a 2-D `[rows, columns]` array.
+     *
+     * Fields may be quoted and blank lines are skipped. Every row must have the same number of
+     * fields. Float dtypes accept `nan` and `inf`; Bool accepts `true`, `false`, `1` and `0`.
+     * Empty fields are replaced by `$fillValue`, so integer and bool dtypes need a finite one.
+     *
+     * @param string $path      Path of the CSV file
+     * @param string $delimiter Single-character field delimiter
+     * @param int    $skipRows  Number of leading rows to skip, e.g. 1 for a header line
+     * @param DType  $dtype     Data type of the result
+     * @param float  $fillValue Value for empty fields
+     *
+     * @throws ShapeException If rows have different numbers of fields
+     */
+    public static function fromCsv(
+        string $path,
+        string $delimiter = ',',
+        int $skipRows = 0,
+        DType $dtype = DType::Float64,
+        float $fillValue = NAN,
+    ): self {
+        if (1 !== \strlen($delimiter)) {
+            throw new \InvalidArgumentException('CSV delimiter must be a single character');
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_from_csv(
+            self::cString($path),
+            \ord($delimiter),
+            max(0, $skipRows),
+            $dtype->value,
+            $fillValue,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShapeBuf, (int) $outNdimBuf->cdata);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtypeBuf->cdata));
     }
 
     /**
@@ -437,9 +702,40 @@ trait CreatesArrays
         return new self($outHandle, new ArrayMetadata([$N, $M]), $dtype);
     }
 
+    /**
+     * Create a matrix with ones at and below the k-th diagonal and zeros elsewhere.
+     *
+     * @param int      $N     Number of rows
+     * @param null|int $M     Number of columns (default: N)
+     * @param int      $k     Diagonal at and below which the array is filled (0: main, >0: upper, <0: lower)
+     * @param DType    $dtype Data type (default: Float64)
+     */
+    public static function tri(int $N, ?int $M = null, int $k = 0, DType $dtype = DType::Float64): self
+    {
+        $M ??= $N;
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+
+        $status = $lib->ndarray_tri(
+            $N,
+            $M,
+            $k,
+            $dtype->value,
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata([$N, $M]), $dtype);
+    }
+
     /**
      * Create evenly spaced values within a given interval.
      *
+     * With integer bounds and an integer dtype the range is computed exactly in
+     * integer arithmetic, so 64-bit values beyond 2^53 are not rounded. Values
+     * that do not fit the dtype throw.
+     *
      * @param float|int      $start Start of interval (inclusive)
      * @param null|float|int $stop  End of interval (exclusive)
      * @param float|int      $step  Spacing between values
@@ -465,6 +761,23 @@ trait CreatesArrays
         $lib = Lib::get();
         $outHandle = $lib->new('struct NdArrayHandle*');
 
+        if ($dtype->isInteger() && \is_int($start) && \is_int($stop) && \is_int($step)) {
+            // Integer bounds skip the f64 path so values beyond 2^53 stay exact.
+            $outLen = $lib->new('size_t');
+            $status = $lib->ndarray_arange_i64(
+                $start,
+                $stop,
+                $step,
+                $dtype->value,
+                Lib::addr($outHandle),
+                Lib::addr($outLen)
+            );
+
+            $lib->checkStatus($status);
+
+            return new self($outHandle, new ArrayMetadata([(int) $outLen->cdata]), $dtype);
+        }
+
         $status = $lib->ndarray_arange(
             (float) $start,
             (float) $stop,
@@ -489,11 +802,14 @@ trait CreatesArrays
     /**
      * Create evenly spaced numbers over a specified interval.
      *
+     * Integer dtypes round each sample toward negative infinity, as NumPy does,
+     * so `linspace(0, 10, 6, dtype: DType::Int64)` is `[0, 2, 4, 6, 8, 10]`.
+     *
      * @param float $start    The starting value of the sequence
      * @param float $stop     The end value of the sequence
      * @param int   $num      Number of samples to generate
      * @param bool  $endpoint If true, stop is the last sample
-     * @param DType $dtype    Data type (default: Float64)
+     * @param DType $dtype    Data type: float or integer (default: Float64)
      */
     public static function linspace(
         float $start,
@@ -506,8 +822,8 @@ trait CreatesArrays
             throw new ShapeException("Number of samples, {$num}, must be positive");
         }
 
-        if (DType::Float32 !== $dtype && DType::Float64 !== $dtype) {
-            throw new \InvalidArgumentException('linspace only supports Float32 and Float64 dtypes');
+        if (!$dtype->isFloat() && !$dtype->isInteger()) {
+            throw new \InvalidArgumentException('linspace only supports float and integer dtypes');
         }
 
         $lib = Lib::get();
@@ -620,8 +936,9 @@ trait CreatesArrays
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    public static function random(array $shape, ?DType $dtype = null, ?int $seed = null): self
+    public static function random(array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
     {
         $dtype ??= DType::Float64;
         self::assertFloatDtype($dtype, 'random');
@@ -634,6 +951,7 @@ trait CreatesArrays
             $dtype->value,
             null !== $seed,
             $seed ?? 0,
+            $rng?->handle(),
             Lib::addr($outHandle)
         );
 
@@ -650,8 +968,9 @@ trait CreatesArrays
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Integer dtype (default: Int64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    public static function randomInt(int $low, int $high, array $shape, ?DType $dtype = null, ?int $seed = null): self
+    public static function randomInt(int $low, int $high, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
     {
         $dtype ??= DType::Int64;
         if (!$dtype->isInteger()) {
@@ -671,6 +990,7 @@ trait CreatesArrays
             $dtype->value,
             null !== $seed,
             $seed ?? 0,
+            $rng?->handle(),
             Lib::addr($outHandle)
         );
 
@@ -685,8 +1005,9 @@ trait CreatesArrays
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    public static function randn(array $shape, ?DType $dtype = null, ?int $seed = null): self
+    public static function randn(array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
     {
         $dtype ??= DType::Float64;
         self::assertFloatDtype($dtype, 'randn');
@@ -699,6 +1020,7 @@ trait CreatesArrays
             $dtype->value,
             null !== $seed,
             $seed ?? 0,
+            $rng?->handle(),
             Lib::addr($outHandle)
         );
 
@@ -715,8 +1037,9 @@ trait CreatesArrays
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    public static function normal(float $mean, float $std, array $shape, ?DType $dtype = null, ?int $seed = null): self
+    public static function normal(float $mean, float $std, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
     {
         $dtype ??= DType::Float64;
         self::assertFloatDtype($dtype, 'normal');
@@ -734,6 +1057,7 @@ trait CreatesArrays
             $dtype->value,
             null !== $seed,
             $seed ?? 0,
+            $rng?->handle(),
             Lib::addr($outHandle)
         );
 
@@ -750,8 +1074,9 @@ trait CreatesArrays
      * @param array<int> $shape Output shape
      * @param null|DType $dtype Float dtype (default: Float64)
      * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
      */
-    public static function uniform(float $low, float $high, array $shape, ?DType $dtype = null, ?int $seed = null): self
+    public static function uniform(float $low, float $high, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
     {
         $dtype ??= DType::Float64;
         self::assertFloatDtype($dtype, 'uniform');
@@ -769,6 +1094,368 @@ trait CreatesArrays
             $dtype->value,
             null !== $seed,
             $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+    }
+
+    /**
+     * Create random samples from the standard Cauchy distribution (median 0, scale 1).
+     *
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function standardCauchy(array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
+    {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'standardCauchy');
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_standard_cauchy(
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+    }
+
+    /**
+     * Create random samples from Student's t distribution.
+     *
+     * @param float      $df    Degrees of freedom (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function standardT(float $df, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
+    {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'standardT');
+        if (!($df > 0.0)) {
+            throw new \InvalidArgumentException("standardT requires df > 0, got {$df}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_standard_t(
+            $df,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+    }
+
+    /**
+     * Create random samples from a geometric distribution.
+     *
+     * Each value is the number of Bernoulli trials up to and including the first
+     * success, so samples are >= 1.
+     *
+     * @param float      $p     Success probability of each trial, in (0, 1]
+     * @param array<int> $shape Output shape
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     *
+     * @return self Int64 array
+     */
+    public static function geometric(float $p, array $shape, ?int $seed = null, ?Rng $rng = null): self
+    {
+        if (!($p > 0.0 && $p <= 1.0)) {
+            throw new \InvalidArgumentException("geometric requires 0 < p <= 1, got {$p}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_geometric(
+            $p,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::Int64);
+    }
+
+    /**
+     * Create random samples from a hypergeometric distribution.
+     *
+     * Each value is the number of good items among `$nsample` items drawn without
+     * replacement from an urn of `$ngood` good and `$nbad` bad items.
+     *
+     * @param int        $ngood   Number of good items (>= 0)
+     * @param int        $nbad    Number of bad items (>= 0)
+     * @param int        $nsample Number of items drawn, at most `$ngood + $nbad`
+     * @param array<int> $shape   Output shape
+     * @param null|int   $seed    Optional seed for deterministic output
+     * @param null|Rng   $rng     Generator to draw from; overrides `$seed`
+     *
+     * @return self Int64 array
+     */
+    public static function hypergeometric(int $ngood, int $nbad, int $nsample, array $shape, ?int $seed = null, ?Rng $rng = null): self
+    {
+        if ($ngood < 0 || $nbad < 0 || $nsample < 0) {
+            throw new \InvalidArgumentException('hypergeometric requires ngood, nbad and nsample >= 0');
+        }
+        if ($nsample > $ngood + $nbad) {
+            throw new \InvalidArgumentException("hypergeometric requires nsample <= ngood + nbad, got {$nsample}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_hypergeometric(
+            $ngood,
+            $nbad,
+            $nsample,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::Int64);
+    }
+
+    /**
+     * Create random samples from a binomial distribution.
+     *
+     * Each value is the number of successes in `$n` independent trials.
+     *
+     * @param int        $n     Number of trials (>= 0)
+     * @param float      $p     Success probability of each trial, in [0, 1]
+     * @param array<int> $shape Output shape
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     *
+     * @return self Int64 array
+     */
+    public static function binomial(int $n, float $p, array $shape, ?int $seed = null, ?Rng $rng = null): self
+    {
+        if ($n < 0) {
+            throw new \InvalidArgumentException("binomial requires n >= 0, got {$n}");
+        }
+        if (!($p >= 0.0 && $p <= 1.0)) {
+            throw new \InvalidArgumentException("binomial requires 0 <= p <= 1, got {$p}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_binomial(
+            $n,
+            $p,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::Int64);
+    }
+
+    /**
+     * Create random samples from a Poisson distribution.
+     *
+     * @param float      $lam   Expected number of events (>= 0)
+     * @param array<int> $shape Output shape
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     *
+     * @return self Int64 array
+     */
+    public static function poisson(float $lam, array $shape, ?int $seed = null, ?Rng $rng = null): self
+    {
+        if (!($lam >= 0.0)) {
+            throw new \InvalidArgumentException("poisson requires lam >= 0, got {$lam}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_poisson(
+            $lam,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), DType::Int64);
+    }
+
+    /**
+     * Create random samples from an exponential distribution.
+     *
+     * @param float      $scale Mean of the distribution, `1 / lambda` (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function exponential(float $scale, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
+    {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'exponential');
+        if (!($scale > 0.0)) {
+            throw new \InvalidArgumentException("exponential requires scale > 0, got {$scale}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_exponential(
+            $scale,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+    }
+
+    /**
+     * Create random samples from a gamma distribution.
+     *
+     * @param float      $k     Shape parameter (must be > 0)
+     * @param float      $theta Scale parameter (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function gamma(float $k, float $theta, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
+    {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'gamma');
+        if (!($k > 0.0 && $theta > 0.0)) {
+            throw new \InvalidArgumentException("gamma requires k > 0 and theta > 0, got k={$k}, theta={$theta}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_gamma(
+            $k,
+            $theta,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+    }
+
+    /**
+     * Create random samples in [0, 1] from a beta distribution.
+     *
+     * @param float      $a     First shape parameter, alpha (must be > 0)
+     * @param float      $b     Second shape parameter, beta (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function beta(float $a, float $b, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
+    {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'beta');
+        if (!($a > 0.0 && $b > 0.0)) {
+            throw new \InvalidArgumentException("beta requires a > 0 and b > 0, got a={$a}, b={$b}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_beta(
+            $a,
+            $b,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($shape), $dtype);
+    }
+
+    /**
+     * Create random samples from a chi-square distribution.
+     *
+     * @param float      $df    Degrees of freedom (must be > 0)
+     * @param array<int> $shape Output shape
+     * @param null|DType $dtype Float dtype (default: Float64)
+     * @param null|int   $seed  Optional seed for deterministic output
+     * @param null|Rng   $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function chisquare(float $df, array $shape, ?DType $dtype = null, ?int $seed = null, ?Rng $rng = null): self
+    {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'chisquare');
+        if (!($df > 0.0)) {
+            throw new \InvalidArgumentException("chisquare requires df > 0, got {$df}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_chisquare(
+            $df,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
             Lib::addr($outHandle)
         );
 
@@ -777,6 +1464,157 @@ trait CreatesArrays
         return new self($outHandle, new ArrayMetadata($shape), $dtype);
     }
 
+    /**
+     * Create random samples from a multivariate normal distribution.
+     *
+     * The result has shape `[...$size, d]` for a mean of length d. The covariance
+     * must be symmetric positive-definite; samples are drawn through its Cholesky
+     * factor.
+     *
+     * @param array<float>|self        $mean  Mean vector of length d
+     * @param array<array<float>>|self $cov   Covariance matrix of shape [d, d]
+     * @param array<int>|int           $size  Number of samples, or their shape; [] draws one vector
+     * @param null|DType               $dtype Float dtype (default: Float64)
+     * @param null|int                 $seed  Optional seed for deterministic output
+     * @param null|Rng                 $rng   Generator to draw from; overrides `$seed`
+     */
+    public static function multivariateNormal(
+        array|self $mean,
+        array|self $cov,
+        array|int $size = [],
+        ?DType $dtype = null,
+        ?int $seed = null,
+        ?Rng $rng = null
+    ): self {
+        $dtype ??= DType::Float64;
+        self::assertFloatDtype($dtype, 'multivariateNormal');
+
+        $mean = $mean instanceof self ? $mean : self::array($mean, DType::Float64);
+        $cov = $cov instanceof self ? $cov : self::array($cov, DType::Float64);
+        if (1 !== $mean->ndim()) {
+            throw new ShapeException('multivariateNormal requires a 1-dimensional mean');
+        }
+
+        $shape = \is_int($size) ? [$size] : $size;
+        $meanMeta = $mean->meta()->toCData();
+        $covMeta = $cov->meta()->toCData();
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_multivariate_normal(
+            $mean->handle(),
+            Lib::addr($meanMeta),
+            $cov->handle(),
+            Lib::addr($covMeta),
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $dtype->value,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata([...$shape, $mean->shape()[0]]), $dtype);
+    }
+
+    /**
+     * Create a random permutation of the integers `0..n-1`.
+     *
+     * Matches shuffling `arange($n)` with the same seed, so it can be used to
+     * index several arrays with one shared ordering.
+     *
+     * @param int      $n    Number of elements (>= 0)
+     * @param null|int $seed Optional seed for deterministic output
+     * @param null|Rng $rng  Generator to draw from; overrides `$seed`
+     *
+     * @return self Int64 array of shape [n]
+     */
+    public static function permutation(int $n, ?int $seed = null, ?Rng $rng = null): self
+    {
+        if ($n < 0) {
+            throw new \InvalidArgumentException("permutation requires n >= 0, got {$n}");
+        }
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_permutation(
+            $n,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata([$n]), DType::Int64);
+    }
+
+    /**
+     * Draw a random sample from a 1-D array, or from the integers `0..n-1`.
+     *
+     * An integer `$a` samples Int64 indices; an array samples its values and keeps
+     * its dtype. Weights in `$p` need not sum to 1, they are normalized by their total.
+     *
+     * @param array<mixed>|int|self  $a       1-D population, or n to sample from `0..n-1`
+     * @param null|array<int>|int    $size    Output shape; null draws a single value
+     * @param bool                   $replace Whether an entry may be drawn more than once
+     * @param null|array<float>|self $p       Optional non-negative weight per entry of `$a`
+     * @param null|int               $seed    Optional seed for deterministic output
+     * @param null|Rng               $rng     Generator to draw from; overrides `$seed`
+     */
+    public static function choice(
+        array|int|self $a,
+        array|int|null $size = null,
+        bool $replace = true,
+        array|self|null $p = null,
+        ?int $seed = null,
+        ?Rng $rng = null
+    ): bool|Complex|float|int|self {
+        if (\is_int($a)) {
+            if ($a < 0) {
+                throw new \InvalidArgumentException("choice requires n >= 0, got {$a}");
+            }
+            $values = null;
+            $n = $a;
+        } else {
+            $values = $a instanceof self ? $a : self::array($a);
+            if (1 !== $values->ndim()) {
+                throw new ShapeException('choice requires a 1-dimensional population');
+            }
+            $n = $values->shape()[0];
+        }
+
+        $shape = null === $size ? [] : (\is_int($size) ? [$size] : $size);
+        $p = null === $p || $p instanceof self ? $p : self::array($p, DType::Float64);
+        $pMeta = $p?->meta()->toCData();
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $status = $lib->ndarray_choice(
+            $n,
+            $lib->createCArray('size_t', $shape),
+            \count($shape),
+            $replace,
+            $p?->handle(),
+            null !== $pMeta ? Lib::addr($pMeta) : null,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+            Lib::addr($outHandle)
+        );
+
+        $lib->checkStatus($status);
+
+        $indices = new self($outHandle, new ArrayMetadata($shape), DType::Int64);
+        $result = null === $values ? $indices : $values->take($indices);
+
+        return null === $size ? $result->toScalar() : $result;
+    }
+
     /**
      * Tile an array by repeating it along each axis.
      *
@@ -871,6 +1709,47 @@ trait CreatesArrays
         return new self($outHandle, new ArrayMetadata($this->shape()), $dtype);
     }
 
+    /**
+     * Cast array to a different data type, checking that every value fits.
+     *
+     * Unlike astype(), which converts out-of-range values silently (300 becomes
+     * 44 in UInt8), values the target cannot represent follow $policy and are
+     * counted in $outOfRange. Float-to-integer casts truncate toward zero;
+     * NaN and infinities are out of range for integer targets.
+     *
+     * @param DType      $dtype      Target data type
+     * @param CastPolicy $policy     What to do with out-of-range values
+     * @param null|int   $outOfRange Set to the number of out-of-range values
+     *
+     * @return self New array with converted data
+     *
+     * @throws OverflowException If $policy is Error and any value is out of range
+     */
+    public function astypeChecked(
+        DType $dtype,
+        CastPolicy $policy = CastPolicy::Error,
+        ?int &$outOfRange = null,
+    ): self {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $count = $lib->new('size_t');
+
+        $status = $lib->ndarray_astype_checked(
+            $this->handle,
+            Lib::addr($meta),
+            $dtype->value,
+            $policy->value,
+            Lib::addr($outHandle),
+            Lib::addr($count)
+        );
+
+        $lib->checkStatus($status);
+        $outOfRange = (int) $count->cdata;
+
+        return new self($outHandle, new ArrayMetadata($this->shape()), $dtype);
+    }
+
     /**
      * Cast the array to a different dtype, without copy when already the target type.
      *
@@ -942,11 +1821,16 @@ trait CreatesArrays
      * @param array<mixed> $data  Flat array of values
      * @param array<int>   $shape Array shape
      * @param int          $len   Number of elements
+     * @param string       $order Memory layout of the new array: 'C' or 'F'
      *
      * @return CData Opaque handle
      */
-    private static function createTyped(Lib $lib, DType $dtype, array $data, array $shape, int $len): CData
+    private static function createTyped(Lib $lib, DType $dtype, array $data, array $shape, int $len, string $order = 'C'): CData
     {
+        if ('F' === ArrayMetadata::validateOrder($order)) {
+            $data = self::toFortranOrder($data, $shape);
+        }
+
         $data = $dtype->prepareArrayValues($data);
 
         $cData = $dtype->createCArray(\count($data), $data);
@@ -960,6 +1844,7 @@ trait CreatesArrays
             $cShape,
             \count($shape),
             $dtype->value,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
@@ -968,6 +1853,44 @@ trait CreatesArrays
         return $outHandle;
     }
 
+    /**
+     * Reorder row-major flat values into column-major memory order.
+     *
+     * @param array<mixed> $data  Flat values in C order
+     * @param array<int>   $shape Array shape
+     *
+     * @return array<mixed>
+     */
+    private static function toFortranOrder(array $data, array $shape): array
+    {
+        $ndim = \count($shape);
+        if ($ndim < 2) {
+            return $data;
+        }
+
+        $fStrides = ArrayMetadata::computeStrides($shape, 'F');
+        $index = array_fill(0, $ndim, 0);
+        $result = [];
+
+        foreach ($data as $value) {
+            $offset = 0;
+            for ($k = 0; $k < $ndim; ++$k) {
+                $offset += $index[$k] * $fStrides[$k];
+            }
+            $result[$offset] = $value;
+
+            for ($k = $ndim - 1; $k >= 0; --$k) {
+                if (++$index[$k] < $shape[$k]) {
+                    break;
+                }
+                $index[$k] = 0;
+            }
+        }
+        ksort($result);
+
+        return $result;
+    }
+
     /**
      * Ensure method only accepts floating-point dtypes.
      */
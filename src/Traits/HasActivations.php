@@ -0,0 +1,94 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * Neural-network activation functions as fused element-wise kernels.
+ *
+ * Float32 input yields Float32, Float16/BFloat16 keep their dtype and other real dtypes
+ * yield Float64. With `$inPlace` a Float32 or Float64 array is overwritten and returned.
+ */
+trait HasActivations
+{
+    /**
+     * Compute sigmoid element-wise: `1 / (1 + exp(-x))`.
+     *
+     * @param bool $inPlace Overwrite this float array instead of returning a new one
+     */
+    public function sigmoid(bool $inPlace = false): NDArray
+    {
+        return $this->activationOp('ndarray_sigmoid', $inPlace);
+    }
+
+    /**
+     * Compute the rectified linear unit element-wise: `max(x, 0)`.
+     *
+     * @param bool $inPlace Overwrite this float array instead of returning a new one
+     */
+    public function relu(bool $inPlace = false): NDArray
+    {
+        return $this->activationOp('ndarray_relu', $inPlace);
+    }
+
+    /**
+     * Compute leaky relu element-wise: `x` for `x >= 0`, `alpha * x` otherwise.
+     *
+     * @param float $alpha   Slope for negative inputs
+     * @param bool  $inPlace Overwrite this float array instead of returning a new one
+     */
+    public function leakyRelu(float $alpha = 0.01, bool $inPlace = false): NDArray
+    {
+        return $this->activationOp('ndarray_leaky_relu', $inPlace, $alpha);
+    }
+
+    /**
+     * Compute GELU element-wise, using the tanh approximation.
+     *
+     * @param bool $inPlace Overwrite this float array instead of returning a new one
+     */
+    public function gelu(bool $inPlace = false): NDArray
+    {
+        return $this->activationOp('ndarray_gelu', $inPlace);
+    }
+
+    /**
+     * Compute SiLU (swish) element-wise: `x * sigmoid(x)`.
+     *
+     * @param bool $inPlace Overwrite this float array instead of returning a new one
+     */
+    public function silu(bool $inPlace = false): NDArray
+    {
+        return $this->activationOp('ndarray_silu', $inPlace);
+    }
+
+    /**
+     * Compute softplus element-wise: `log(1 + exp(x))`, without overflow for large inputs.
+     *
+     * @param bool $inPlace Overwrite this float array instead of returning a new one
+     */
+    public function softplus(bool $inPlace = false): NDArray
+    {
+        return $this->activationOp('ndarray_softplus', $inPlace);
+    }
+
+    /**
+     * Run an activation kernel, or its `_inplace` variant on this array.
+     */
+    private function activationOp(string $funcName, bool $inPlace, float ...$params): NDArray
+    {
+        if ($inPlace) {
+            $lib = Lib::get();
+            $meta = $this->meta()->toCData();
+            $lib->checkStatus($lib->{$funcName.'_inplace'}($this->handle, Lib::addr($meta), ...$params));
+
+            return $this;
+        }
+
+        return $this->unaryOp($funcName, ...$params);
+    }
+}
@@ -15,6 +15,7 @@ use PhpMlKit\NDArray\NDArray;
  *   $arr[0]     — integer index (first dimension)
  *   $arr['1,2'] — comma-separated multi-index
  *   $arr['0:2'] — reserved for future slice syntax (throws)
+ *   $arr[$mask] — boolean mask selection and assignment
  */
 trait HasArrayAccess
 {
@@ -57,10 +58,16 @@ trait HasArrayAccess
     /**
      * Get element or sub-array at offset.
      *
-     * @param int|string $offset
+     * A Bool NDArray offset selects the masked elements (see booleanSelect()).
+     *
+     * @param int|NDArray|string $offset
      */
     public function offsetGet(mixed $offset): bool|Complex|float|int|self
     {
+        if ($offset instanceof NDArray) {
+            return $this->booleanSelect($offset);
+        }
+
         if ($this->isSlice($offset)) {
             return $this->slice($this->parseSelectors($offset));
         }
@@ -77,8 +84,9 @@ trait HasArrayAccess
      * - Scalar assignment: $arr[0,0] = 5
      * - Slice assignment: $arr['0:2'] = $other
      * - Partial assignment: $arr[0] = $row
+     * - Masked assignment: $arr[$mask] = 0
      *
-     * @param int|string $offset
+     * @param int|NDArray|string $offset
      */
     public function offsetSet(mixed $offset, mixed $value): void
     {
@@ -86,6 +94,12 @@ trait HasArrayAccess
             $value = NDArray::array($value, $this->dtype);
         }
 
+        if ($offset instanceof NDArray) {
+            $this->booleanAssign($offset, $value);
+
+            return;
+        }
+
         if ($this->isSlice($offset)) {
             $view = $this->slice($this->parseSelectors($offset));
             $view->assign($value);
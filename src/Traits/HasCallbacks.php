@@ -0,0 +1,358 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use FFI\CData;
+use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\GufuncRegistration;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * User-supplied PHP callbacks run over array data for transforms the built-in ops don't cover.
+ *
+ * Values cross the FFI boundary in batches, so the callback overhead is paid once per batch
+ * rather than once per element. Exceptions thrown by a callback abort the operation and are
+ * rethrown to the caller unchanged.
+ */
+trait HasCallbacks
+{
+    /**
+     * Gufuncs registered from PHP in this request, keyed by name.
+     *
+     * Each registration keeps its callback alive for as long as Rust may call it and
+     * unregisters it when destroyed.
+     *
+     * @var array<string, GufuncRegistration>
+     */
+    private static array $gufuncs = [];
+
+    private static ?\Throwable $gufuncError = null;
+
+    /**
+     * Apply a PHP callback element-wise and collect the results into a new array.
+     *
+     * The callback receives a batch of values (in C order, as floats) and must return the same
+     * number of results. Results are cast to `$dtype`, which must be a real dtype.
+     *
+     * @param callable(list<float>): array<bool|float|int> $fn        batch transform
+     * @param DType                                        $dtype     result dtype (default Float64)
+     * @param int                                          $batchSize maximum number of values per callback call
+     */
+    public function map(callable $fn, DType $dtype = DType::Float64, int $batchSize = 4096): NDArray
+    {
+        if ($batchSize < 1) {
+            throw new \InvalidArgumentException("Batch size must be at least 1, got {$batchSize}");
+        }
+
+        $error = null;
+        $callback = static function (CData $input, CData $output, int $count) use ($fn, &$error): int {
+            try {
+                $values = [];
+                for ($i = 0; $i < $count; ++$i) {
+                    $values[] = $input[$i];
+                }
+
+                $results = $fn($values);
+                if (!\is_array($results) || \count($results) !== $count) {
+                    throw new \UnexpectedValueException("Map callback must return an array of {$count} values");
+                }
+
+                $i = 0;
+                foreach ($results as $value) {
+                    $output[$i++] = (float) $value;
+                }
+
+                return 0;
+            } catch (\Throwable $e) {
+                $error = $e;
+
+                return 1;
+            }
+        };
+
+        return $this->callbackOp('ndarray_map', $error, $callback, $dtype->value, $batchSize);
+    }
+
+    /**
+     * Reduce with a PHP combine function, folding each lane into an accumulator.
+     *
+     * `$fn($acc, $x)` is applied left to right starting from `$initial`; lane values are passed
+     * across the FFI boundary in batches of up to `$batchSize`. Empty lanes reduce to `$initial`.
+     * The result is Float64.
+     *
+     * @param callable(float, float): (float|int) $fn       binary combine step
+     * @param float|int                           $initial  starting accumulator for every lane
+     * @param null|int                            $axis     axis to reduce; null reduces all elements
+     * @param bool                                $keepdims if true, reduced axes are retained with size 1
+     *
+     * @return ($axis is null ? ($keepdims is true ? NDArray : float) : NDArray)
+     */
+    public function reduce(
+        callable $fn,
+        float|int $initial = 0.0,
+        ?int $axis = null,
+        bool $keepdims = false,
+        int $batchSize = 4096,
+    ): float|NDArray {
+        if ($batchSize < 1) {
+            throw new \InvalidArgumentException("Batch size must be at least 1, got {$batchSize}");
+        }
+
+        if (null === $axis) {
+            $result = $this->flatten()->reduce($fn, $initial, 0, false, $batchSize);
+
+            return $keepdims ? $result->reshape(array_fill(0, $this->ndim(), 1)) : (float) $result->toScalar();
+        }
+
+        $error = null;
+        $callback = static function (CData $acc, CData $values, int $count) use ($fn, &$error): int {
+            try {
+                $value = $acc[0];
+                for ($i = 0; $i < $count; ++$i) {
+                    $value = (float) $fn($value, $values[$i]);
+                }
+                $acc[0] = $value;
+
+                return 0;
+            } catch (\Throwable $e) {
+                $error = $e;
+
+                return 1;
+            }
+        };
+
+        return $this->callbackOp('ndarray_reduce', $error, $axis, $keepdims, (float) $initial, $callback, $batchSize);
+    }
+
+    /**
+     * Register a generalized ufunc: a kernel over core dimensions, broadcast over loop dimensions.
+     *
+     * The signature names each operand's core dimensions, e.g. `"(m,n),(n)->(m)"` for a
+     * matrix-vector product or `"(n),(n)->()"` for a dot product; exactly one output is
+     * supported. The kernel receives one flat C-order float list per input plus the size bound
+     * to each dimension name, and returns the flat output block. Registering an existing name
+     * replaces its kernel.
+     *
+     * @param callable(list<list<float>>, array<string, int>): array<bool|float|int> $kernel
+     *
+     * @return int id to pass to gufunc()
+     */
+    public static function registerGufunc(string $name, string $signature, callable $kernel): int
+    {
+        [$inputDims, $outputDims] = self::parseGufuncSignature($signature);
+        $names = array_values(array_unique(array_merge(...$inputDims, ...[$outputDims])));
+
+        $callback = static function (CData $inputs, CData $output, CData $dims) use ($kernel, $inputDims, $outputDims, $names): int {
+            try {
+                $sizes = [];
+                foreach ($names as $i => $dimName) {
+                    $sizes[$dimName] = (int) $dims[$i];
+                }
+                $blockSize = static fn (array $core): int => (int) array_product(array_map(static fn (string $d) => $sizes[$d], $core));
+
+                $blocks = [];
+                foreach ($inputDims as $k => $core) {
+                    $block = [];
+                    for ($i = 0, $n = $blockSize($core); $i < $n; ++$i) {
+                        $block[] = $inputs[$k][$i];
+                    }
+                    $blocks[] = $block;
+                }
+
+                $result = $kernel($blocks, $sizes);
+                $expected = $blockSize($outputDims);
+                if (!\is_array($result) || \count($result) !== $expected) {
+                    throw new \UnexpectedValueException("Gufunc kernel must return an array of {$expected} values");
+                }
+
+                $i = 0;
+                foreach ($result as $value) {
+                    $output[$i++] = (float) $value;
+                }
+
+                return 0;
+            } catch (\Throwable $e) {
+                self::$gufuncError = $e;
+
+                return 1;
+            }
+        };
+
+        $lib = Lib::get();
+        $outId = $lib->new('uint32_t');
+        $status = $lib->ndarray_gufunc_register(
+            self::cString($name),
+            self::cString($signature),
+            $callback,
+            Lib::addr($outId),
+        );
+        $lib->checkStatus($status);
+
+        $id = (int) $outId->cdata;
+        if (isset(self::$gufuncs[$name])) {
+            // Re-registering a name keeps its id; only the callback changes.
+            self::$gufuncs[$name]->callback = $callback;
+        } else {
+            self::$gufuncs[$name] = new GufuncRegistration($id, $callback);
+        }
+
+        return $id;
+    }
+
+    /**
+     * Remove a gufunc registered with registerGufunc(), by id or name.
+     *
+     * The kernel is released and its id can no longer be called. The name may be registered
+     * again afterwards, which assigns a new id.
+     */
+    public static function unregisterGufunc(int|string $gufunc): void
+    {
+        unset(self::$gufuncs[self::gufuncName($gufunc)]);
+    }
+
+    /**
+     * Invoke a gufunc registered with registerGufunc(), by id or name.
+     *
+     * Loop (leading) dimensions of the inputs are broadcast together; the result is Float64 with
+     * shape `loop_shape + output_core_shape`.
+     */
+    public static function gufunc(int|string $gufunc, NDArray ...$inputs): NDArray
+    {
+        $id = self::$gufuncs[self::gufuncName($gufunc)]->id;
+
+        $lib = Lib::get();
+        $numInputs = \count($inputs);
+        $metas = array_map(static fn (NDArray $a) => $a->meta()->toCData(), $inputs);
+        $cHandles = $lib->new('struct NdArrayHandle*['.max(1, $numInputs).']');
+        $cMetas = $lib->new('struct ArrayMetadata*['.max(1, $numInputs).']');
+        foreach ($inputs as $i => $input) {
+            $cHandles[$i] = $input->handle;
+            $cMetas[$i] = Lib::addr($metas[$i]);
+        }
+
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtype = $lib->new('uint8_t');
+        $outNdim = $lib->new('size_t');
+        $outShape = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        self::$gufuncError = null;
+        $status = $lib->ndarray_gufunc_call(
+            $id,
+            $cHandles,
+            $cMetas,
+            $numInputs,
+            Lib::addr($outHandle),
+            Lib::addr($outDtype),
+            Lib::addr($outNdim),
+            $outShape,
+            Lib::MAX_NDIM,
+        );
+
+        if (null !== self::$gufuncError) {
+            $error = self::$gufuncError;
+            self::$gufuncError = null;
+
+            throw $error;
+        }
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShape, (int) $outNdim->cdata);
+
+        return new NDArray($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtype->cdata));
+    }
+
+    /**
+     * Run a callback-driven FFI op and rethrow any exception captured by the callback.
+     *
+     * FFI: `(handle, metadata, ...$args, out_handle, out_dtype, out_ndim, out_shape, max_ndim)`.
+     */
+    private function callbackOp(string $funcName, ?\Throwable &$error, mixed ...$args): NDArray
+    {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtype = $lib->new('uint8_t');
+        $outNdim = $lib->new('size_t');
+        $outShape = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->{$funcName}(
+            $this->handle,
+            Lib::addr($meta),
+            ...$args,
+            ...[
+                Lib::addr($outHandle),
+                Lib::addr($outDtype),
+                Lib::addr($outNdim),
+                $outShape,
+                Lib::MAX_NDIM,
+            ],
+        );
+
+        if (null !== $error) {
+            throw $error;
+        }
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShape, (int) $outNdim->cdata);
+
+        return new NDArray($outHandle, new ArrayMetadata($shape), DType::from((int) $outDtype->cdata));
+    }
+
+    /**
+     * Resolve a gufunc id or name to the name it is registered under.
+     */
+    private static function gufuncName(int|string $gufunc): string
+    {
+        if (\is_string($gufunc)) {
+            if (isset(self::$gufuncs[$gufunc])) {
+                return $gufunc;
+            }
+        } else {
+            foreach (self::$gufuncs as $name => $registration) {
+                if ($registration->id === $gufunc) {
+                    return (string) $name;
+                }
+            }
+        }
+
+        throw new \InvalidArgumentException("Gufunc '{$gufunc}' has not been registered");
+    }
+
+    /**
+     * Split a gufunc signature into input core dimension names and output core dimension names.
+     *
+     * Only the shape of the signature is read here; Rust validates it on registration.
+     *
+     * @return array{0: list<list<string>>, 1: list<string>}
+     */
+    private static function parseGufuncSignature(string $signature): array
+    {
+        $parts = explode('->', preg_replace('/\s+/', '', $signature) ?? '', 2);
+        $operands = [];
+        foreach ($parts as $side => $text) {
+            preg_match_all('/\(([^)]*)\)/', $text, $matches);
+            $operands[$side] = array_map(
+                static fn (string $dims): array => '' === $dims ? [] : explode(',', $dims),
+                $matches[1],
+            );
+        }
+
+        return [$operands[0] ?? [], $operands[1][0] ?? []];
+    }
+
+    /**
+     * Copy a PHP string into a NUL-terminated C buffer.
+     */
+    private static function cString(string $value): CData
+    {
+        $bytes = $value."\0";
+        $buffer = Lib::get()->new('char['.\strlen($bytes).']');
+        \FFI::memcpy($buffer, $bytes, \strlen($bytes));
+
+        return $buffer;
+    }
+}
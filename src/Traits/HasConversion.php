@@ -5,9 +5,12 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Traits;
 
 use FFI\CData;
+use PhpMlKit\NDArray\ByteOrder;
 use PhpMlKit\NDArray\Complex;
+use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\NDArray;
 
 /**
  * Conversion methods for transforming NDArray data into PHP types.
@@ -152,23 +155,240 @@ trait HasConversion
         return (int) $outLen->cdata;
     }
 
+    /**
+     * Reverse the byte order of every element.
+     *
+     * Returns a new array whose elements have their bytes swapped, e.g. to convert
+     * data read from a big-endian source. Complex values swap the real and imaginary
+     * parts independently; single-byte dtypes are returned unchanged.
+     */
+    public function byteswap(): NDArray
+    {
+        return $this->unaryOp('ndarray_byteswap');
+    }
+
+    /**
+     * Quantize to Int8 or UInt8 using an affine scale and zero point.
+     *
+     * Computes `clamp(round(x / scale) + zeroPoint)` with round-half-to-even and
+     * saturation to the target range; NaN maps to the zero point. Scalar parameters
+     * quantize the whole tensor. Array parameters with one entry per index along
+     * `$axis` quantize per channel.
+     *
+     * @param float|NDArray $scale     Positive scale (scalar or 1-D per-channel array)
+     * @param int|NDArray   $zeroPoint Zero point within the target range (scalar or per-channel)
+     * @param DType         $dtype     Target dtype, Int8 or UInt8
+     * @param null|int      $axis      Channel axis for per-channel parameters
+     */
+    public function quantize(
+        float|NDArray $scale,
+        int|NDArray $zeroPoint = 0,
+        DType $dtype = DType::Int8,
+        ?int $axis = null,
+    ): NDArray {
+        if (DType::Int8 !== $dtype && DType::UInt8 !== $dtype) {
+            throw new \InvalidArgumentException('Quantization target dtype must be Int8 or UInt8');
+        }
+
+        [$scale, $zeroPoint] = self::quantizationParams($scale, $zeroPoint, $axis);
+        $scaleMeta = $scale->meta()->toCData();
+        $zeroPointMeta = $zeroPoint->meta()->toCData();
+
+        return $this->unaryOp(
+            'ndarray_quantize',
+            $scale->handle(),
+            Lib::addr($scaleMeta),
+            $zeroPoint->handle(),
+            Lib::addr($zeroPointMeta),
+            $axis ?? 0,
+            $dtype,
+        );
+    }
+
+    /**
+     * Dequantize an Int8 or UInt8 array to Float32: `(q - zeroPoint) * scale`.
+     *
+     * Parameters follow the same per-tensor / per-channel rules as quantize().
+     *
+     * @param float|NDArray $scale     Positive scale (scalar or 1-D per-channel array)
+     * @param int|NDArray   $zeroPoint Zero point (scalar or per-channel)
+     * @param null|int      $axis      Channel axis for per-channel parameters
+     */
+    public function dequantize(float|NDArray $scale, int|NDArray $zeroPoint = 0, ?int $axis = null): NDArray
+    {
+        [$scale, $zeroPoint] = self::quantizationParams($scale, $zeroPoint, $axis);
+        $scaleMeta = $scale->meta()->toCData();
+        $zeroPointMeta = $zeroPoint->meta()->toCData();
+
+        return $this->unaryOp(
+            'ndarray_dequantize',
+            $scale->handle(),
+            Lib::addr($scaleMeta),
+            $zeroPoint->handle(),
+            Lib::addr($zeroPointMeta),
+            $axis ?? 0,
+        );
+    }
+
     /**
      * Return raw bytes of the array/view in C-order.
      *
-     * The returned string contains the raw binary data in little-endian format.
+     * The returned string contains the raw binary data in the requested byte order
+     * (little-endian by default).
+     *
+     * @param ByteOrder $byteOrder Byte order of the returned data (default: little-endian)
      */
-    public function toBytes(): string
+    public function toBytes(ByteOrder $byteOrder = ByteOrder::Little): string
     {
         $nbytes = $this->nbytes();
         if (0 === $nbytes) {
             return '';
         }
 
+        if (!$byteOrder->isNative()) {
+            return $this->byteswap()->toBytes(ByteOrder::Native);
+        }
+
         $buffer = $this->toBuffer();
 
         return \FFI::string($buffer, $nbytes);
     }
 
+    /**
+     * Serialize the array or view into a self-describing binary string.
+     *
+     * Unlike toBytes(), the string carries the dtype and shape along with the native-endian data,
+     * so it can be cached as-is (e.g. in Redis or APCu) and restored with {@see NDArray::unserialize()}
+     * without any per-element conversion.
+     */
+    public function serialize(): string
+    {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+        $outLen = $lib->new('size_t');
+
+        $status = $lib->ndarray_to_bytes($this->handle, Lib::addr($meta), null, 0, Lib::addr($outLen));
+        $lib->checkStatus($status);
+
+        $length = (int) $outLen->cdata;
+        $buffer = $lib->new("uint8_t[{$length}]");
+
+        $status = $lib->ndarray_to_bytes($this->handle, Lib::addr($meta), $buffer, $length, Lib::addr($outLen));
+        $lib->checkStatus($status);
+
+        return \FFI::string($buffer, $length);
+    }
+
+    /**
+     * Encode the array or view as an Apache Arrow IPC stream.
+     *
+     * The stream holds a single non-nullable `data` column: a primitive column for 0-D and 1-D
+     * arrays, and an `arrow.fixed_shape_tensor` column with one row per index of the first axis
+     * otherwise, so it can be handed to DuckDB, Polars or other Arrow consumers. 0-D arrays are
+     * marked so fromArrow() restores their rank. The values are always copied into the stream.
+     * BFloat16 and complex arrays have no Arrow type. Requires the native library to be built
+     * with the `arrow` cargo feature.
+     */
+    public function toArrow(): string
+    {
+        return $this->unaryOp('ndarray_to_arrow_buffer')->toBytes();
+    }
+
+    /**
+     * Save the array or view to a NumPy `.npy` file readable by `numpy.load()`.
+     *
+     * Data is written in C order and native byte order. BFloat16 has no NumPy type and must
+     * be converted (e.g. to Float32) first. An existing file is overwritten.
+     *
+     * @param string $path Destination path, conventionally ending in `.npy`
+     */
+    public function save(string $path): void
+    {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+
+        $status = $lib->ndarray_save_npy($this->handle, Lib::addr($meta), self::cString($path));
+
+        $lib->checkStatus($status);
+    }
+
+    /**
+     * Save several arrays to a NumPy `.npz` archive readable by `numpy.load()`.
+     *
+     * String keys become the array names; integer keys are named `arr_0`, `arr_1`, … like the
+     * positional arrays of `numpy.savez()`. Each array is stored as by {@see save()}. An
+     * existing file is overwritten.
+     *
+     * @param string                  $path       Destination path, conventionally ending in `.npz`
+     * @param array<int|string, self> $arrays     Arrays to save, keyed by name
+     * @param bool                    $compressed Deflate the entries, like `numpy.savez_compressed()`
+     */
+    public static function savez(string $path, array $arrays, bool $compressed = false): void
+    {
+        $lib = Lib::get();
+        $count = \count($arrays);
+        $cNames = $cHandles = $cMetas = null;
+        $nameBuffers = $metaBuffers = [];
+
+        if ($count > 0) {
+            $cNames = $lib->new("char*[{$count}]");
+            $cHandles = $lib->new("struct NdArrayHandle*[{$count}]");
+            $cMetas = $lib->new("struct ArrayMetadata*[{$count}]");
+
+            $i = 0;
+            foreach ($arrays as $key => $array) {
+                if (!$array instanceof NDArray) {
+                    throw new \InvalidArgumentException(
+                        'savez expects NDArray values, got '.get_debug_type($array)." for '{$key}'"
+                    );
+                }
+                $nameBuffers[$i] = self::cString(\is_int($key) ? "arr_{$key}" : $key);
+                $metaBuffers[$i] = $array->meta()->toCData();
+                $cNames[$i] = $lib->cast('char*', $nameBuffers[$i]);
+                $cHandles[$i] = $array->handle;
+                $cMetas[$i] = Lib::addr($metaBuffers[$i]);
+                ++$i;
+            }
+        }
+
+        $status = $lib->ndarray_save_npz(self::cString($path), $cNames, $cHandles, $cMetas, $count, $compressed);
+
+        $lib->checkStatus($status);
+    }
+
+    /**
+     * Save a 0-D, 1-D or 2-D array or view as a CSV file.
+     *
+     * Rows of a 2-D array become lines and a 1-D array is written as a single column. Floats
+     * use the shortest text that reads back to the same value. Complex arrays are not supported.
+     * An existing file is overwritten.
+     *
+     * @param string      $path      Destination path
+     * @param string      $delimiter Single-character field delimiter
+     * @param null|string $header    Line written before the data, e.g. column names
+     * @param string      $nanRepr   Text written for NaN values
+     */
+    public function toCsv(string $path, string $delimiter = ',', ?string $header = null, string $nanRepr = 'nan'): void
+    {
+        if (1 !== \strlen($delimiter)) {
+            throw new \InvalidArgumentException('CSV delimiter must be a single character');
+        }
+
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+
+        $status = $lib->ndarray_to_csv(
+            $this->handle,
+            Lib::addr($meta),
+            self::cString($path),
+            \ord($delimiter),
+            null === $header ? null : self::cString($header),
+            self::cString($nanRepr),
+        );
+
+        $lib->checkStatus($status);
+    }
+
     /**
      * Fetch a range of flattened view data from Rust.
      *
@@ -200,6 +420,28 @@ trait HasConversion
         return $out;
     }
 
+    /**
+     * Normalize scalar quantization parameters to 1-element arrays.
+     *
+     * @return array{0: NDArray, 1: NDArray}
+     */
+    private static function quantizationParams(float|NDArray $scale, int|NDArray $zeroPoint, ?int $axis): array
+    {
+        if (null === $axis && ($scale instanceof NDArray && $scale->size() > 1
+            || $zeroPoint instanceof NDArray && $zeroPoint->size() > 1)) {
+            throw new \InvalidArgumentException('Per-channel quantization parameters require an axis');
+        }
+
+        if (!$scale instanceof NDArray) {
+            $scale = NDArray::array([$scale], DType::Float64);
+        }
+        if (!$zeroPoint instanceof NDArray) {
+            $zeroPoint = NDArray::array([$zeroPoint], DType::Int64);
+        }
+
+        return [$scale, $zeroPoint];
+    }
+
     /**
      * Build nested arrays from a flat C-order vector using iterative chunking.
      *
@@ -216,7 +458,7 @@ trait HasConversion
         for ($dim = $ndim - 1; $dim >= 1; --$dim) {
             $chunkSize = $shape[$dim];
             if ($chunkSize <= 0) {
-                return [];
+                return self::nestEmpty($shape);
             }
 
             $next = [];
@@ -234,4 +476,21 @@ trait HasConversion
 
         return $level;
     }
+
+    /**
+     * Build the nested empty arrays for a zero-size shape, e.g. [2, 0] → [[], []].
+     *
+     * @param array<int> $shape
+     *
+     * @return array<mixed>
+     */
+    private static function nestEmpty(array $shape): array
+    {
+        $level = [];
+        for ($dim = \count($shape) - 1; $dim >= 1; --$dim) {
+            $level = 0 === $shape[$dim] ? [] : array_fill(0, $shape[$dim], $level);
+        }
+
+        return 0 === $shape[0] ? [] : array_fill(0, $shape[0], $level);
+    }
 }
@@ -0,0 +1,117 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use PhpMlKit\NDArray\ConvolveMode;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * Convolution and cross-correlation with zero padding.
+ *
+ * Float32 operands yield Float32, Float16/BFloat16 keep their dtype and other real
+ * dtypes yield Float64. Complex arrays are not supported.
+ */
+trait HasConvolution
+{
+    /**
+     * Discrete linear convolution of two 1D arrays.
+     *
+     * Like NumPy, the operands may be given in either order.
+     *
+     * @param array<mixed>|NDArray $v    Second 1D operand
+     * @param ConvolveMode         $mode Output size: full `n + m - 1`, same `max(n, m)`, or valid `max(n, m) - min(n, m) + 1`
+     */
+    public function convolve(array|NDArray $v, ConvolveMode $mode = ConvolveMode::Full): NDArray
+    {
+        return $this->binaryOp('ndarray_convolve', self::convolveOperand($v), $mode->value);
+    }
+
+    /**
+     * 2D convolution of this array with `$kernel` (the kernel is flipped on both axes).
+     *
+     * The input is zero-padded by `$padding` on each side on top of the padding implied
+     * by `$mode`, then every `$stride`-th output row and column is kept.
+     *
+     * @param array<mixed>|NDArray $kernel  2D filter kernel
+     * @param ConvolveMode         $mode    Output size before padding and stride are applied
+     * @param array{int, int}|int  $stride  Step between output positions, as `[rows, cols]` or one value for both
+     * @param array{int, int}|int  $padding Extra zero rows/columns on each side, as `[rows, cols]` or one value for both
+     */
+    public function convolve2d(
+        array|NDArray $kernel,
+        ConvolveMode $mode = ConvolveMode::Full,
+        array|int $stride = 1,
+        array|int $padding = 0,
+    ): NDArray {
+        return $this->filter2dOp('ndarray_convolve2d', $kernel, $mode, $stride, $padding);
+    }
+
+    /**
+     * 2D cross-correlation of this array with `$kernel` (no kernel flip).
+     *
+     * Mode, stride and padding behave as in convolve2d(). Valid mode with padding and
+     * stride matches a CNN convolution layer.
+     *
+     * @param array<mixed>|NDArray $kernel  2D filter kernel
+     * @param ConvolveMode         $mode    Output size before padding and stride are applied
+     * @param array{int, int}|int  $stride  Step between output positions, as `[rows, cols]` or one value for both
+     * @param array{int, int}|int  $padding Extra zero rows/columns on each side, as `[rows, cols]` or one value for both
+     */
+    public function correlate2d(
+        array|NDArray $kernel,
+        ConvolveMode $mode = ConvolveMode::Full,
+        array|int $stride = 1,
+        array|int $padding = 0,
+    ): NDArray {
+        return $this->filter2dOp('ndarray_correlate2d', $kernel, $mode, $stride, $padding);
+    }
+
+    /**
+     * @param array{int, int}|int $stride
+     * @param array{int, int}|int $padding
+     */
+    private function filter2dOp(
+        string $funcName,
+        array|NDArray $kernel,
+        ConvolveMode $mode,
+        array|int $stride,
+        array|int $padding,
+    ): NDArray {
+        [$strideH, $strideW] = self::convolvePair($stride, 'stride', 1);
+        [$padH, $padW] = self::convolvePair($padding, 'padding', 0);
+
+        return $this->binaryOp(
+            $funcName,
+            self::convolveOperand($kernel),
+            $mode->value,
+            $strideH,
+            $strideW,
+            $padH,
+            $padW
+        );
+    }
+
+    private static function convolveOperand(array|NDArray $values): NDArray
+    {
+        return $values instanceof NDArray ? $values : NDArray::array($values);
+    }
+
+    /**
+     * @param array{int, int}|int $value
+     *
+     * @return array{int, int}
+     */
+    private static function convolvePair(array|int $value, string $name, int $min): array
+    {
+        $pair = \is_int($value) ? [$value, $value] : array_values($value);
+        if (2 !== \count($pair) || !\is_int($pair[0]) || !\is_int($pair[1]) || $pair[0] < $min || $pair[1] < $min) {
+            throw new \InvalidArgumentException(
+                \sprintf('%s must be an integer >= %d or a pair of them', $name, $min)
+            );
+        }
+
+        return $pair;
+    }
+}
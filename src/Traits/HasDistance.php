@@ -0,0 +1,88 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\DistanceMetric;
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * Similarity and distance between the rows of two matrices, for embedding search.
+ *
+ * Operands are 2D `(rows, features)` arrays with the same feature count. Float32 inputs
+ * yield Float32 results; any other real dtype yields Float64.
+ */
+trait HasDistance
+{
+    /**
+     * Cosine similarity between every row of this matrix and every row of `$other`.
+     *
+     * Rows are normalized and multiplied in one native call, giving an `(m, n)` matrix for
+     * `(m, d)` and `(n, d)` inputs. Similarities involving a zero vector are 0.
+     *
+     * @param null|NDArray $other Second set of rows (default: this matrix, giving pairwise self-similarity)
+     */
+    public function cosineSimilarity(?NDArray $other = null): NDArray
+    {
+        return $this->binaryOp('ndarray_cosine_similarity', $other ?? $this);
+    }
+
+    /**
+     * Exact k-nearest-neighbour search: the `$k` closest `$database` rows for each row of this matrix.
+     *
+     * Each query scans the database once while keeping only its best `$k` candidates, so memory
+     * stays bounded by `(m, k)` instead of the full `(m, n)` distance matrix. `$k` is clamped to
+     * the number of database rows. Results are sorted by ascending distance, ties by lower index.
+     *
+     * @param NDArray        $database Rows to search, shape `(n, d)`
+     * @param int            $k        Number of neighbours per query (>= 1)
+     * @param DistanceMetric $metric   Distance metric
+     *
+     * @return array{0: NDArray, 1: NDArray} [indices (Int64), distances], both `(m, min(k, n))`
+     */
+    public function nearestNeighbors(
+        NDArray $database,
+        int $k = 1,
+        DistanceMetric $metric = DistanceMetric::Euclidean,
+    ): array {
+        if ($k < 1) {
+            throw new \InvalidArgumentException('k must be >= 1');
+        }
+
+        $lib = Lib::get();
+        $outIndicesHandle = $lib->new('struct NdArrayHandle*');
+        $outDistancesHandle = $lib->new('struct NdArrayHandle*');
+        $outShapeBuf = $lib->createCArray('size_t', [0, 0]);
+
+        $queryMeta = $this->meta()->toCData();
+        $databaseMeta = $database->meta()->toCData();
+        $status = $lib->ndarray_argmin_distance(
+            $this->handle,
+            Lib::addr($queryMeta),
+            $database->handle(),
+            Lib::addr($databaseMeta),
+            $metric->value,
+            $k,
+            Lib::addr($outIndicesHandle),
+            Lib::addr($outDistancesHandle),
+            $outShapeBuf,
+            2
+        );
+
+        $lib->checkStatus($status);
+
+        $outShape = $lib->readSizeTArray($outShapeBuf, 2);
+        $distanceDtype = DType::Float32 === $this->dtype && DType::Float32 === $database->dtype()
+            ? DType::Float32
+            : DType::Float64;
+
+        return [
+            new NDArray($outIndicesHandle, new ArrayMetadata($outShape), DType::Int64),
+            new NDArray($outDistancesHandle, new ArrayMetadata($outShape), $distanceDtype),
+        ];
+    }
+}
@@ -0,0 +1,169 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\ImageFormat;
+use PhpMlKit\NDArray\ImageLayout;
+use PhpMlKit\NDArray\Interpolation;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * Image tensor preprocessing: resizing, layout and colour conversion, PNG/JPEG codecs.
+ *
+ * Images are 2D `(H, W)` grayscale arrays or 3D arrays in HWC or CHW layout, with
+ * dtype Uint8, Float32 or Float64. Results keep the input dtype; Uint8 output is
+ * rounded and clamped to `[0, 255]`.
+ */
+trait HasImageOps
+{
+    /**
+     * Resize an image to `$height` x `$width`.
+     *
+     * Uses half-pixel centres (PIL / `align_corners=False`). With `$antialias`, the bilinear
+     * filter is widened when downscaling so every source pixel contributes, which avoids
+     * aliasing on large reductions.
+     *
+     * @param int           $height        Output height (>= 1)
+     * @param int           $width         Output width (>= 1)
+     * @param Interpolation $interpolation Resampling filter
+     * @param bool          $antialias     Widen the bilinear filter when downscaling (ignored for nearest)
+     * @param ImageLayout   $layout        Layout of 3D inputs; the output uses the same layout
+     */
+    public function resizeImage(
+        int $height,
+        int $width,
+        Interpolation $interpolation = Interpolation::Bilinear,
+        bool $antialias = false,
+        ImageLayout $layout = ImageLayout::HWC,
+    ): NDArray {
+        if ($height < 1 || $width < 1) {
+            throw new \InvalidArgumentException('height and width must be >= 1');
+        }
+
+        return $this->unaryOp('ndarray_image_resize', $height, $width, $interpolation, $antialias, $layout);
+    }
+
+    /**
+     * Convert an `(H, W, C)` image or `(N, H, W, C)` batch to contiguous CHW / NCHW.
+     */
+    public function hwcToChw(): NDArray
+    {
+        return $this->unaryOp('ndarray_image_hwc_to_chw');
+    }
+
+    /**
+     * Convert a `(C, H, W)` image or `(N, C, H, W)` batch to contiguous HWC / NHWC.
+     */
+    public function chwToHwc(): NDArray
+    {
+        return $this->unaryOp('ndarray_image_chw_to_hwc');
+    }
+
+    /**
+     * Convert an RGB (or RGBA, alpha ignored) image to grayscale using BT.601 luma weights.
+     *
+     * @param ImageLayout $layout          Layout of the input
+     * @param bool        $keepChannelAxis Return `(H, W, 1)` / `(1, H, W)` instead of `(H, W)`
+     */
+    public function rgbToGray(ImageLayout $layout = ImageLayout::HWC, bool $keepChannelAxis = false): NDArray
+    {
+        return $this->unaryOp('ndarray_image_rgb_to_gray', $layout, $keepChannelAxis);
+    }
+
+    /**
+     * Replicate a grayscale image (2D, or 3D with one channel) into three RGB channels.
+     *
+     * @param ImageLayout $layout Layout of the input and output
+     */
+    public function grayToRgb(ImageLayout $layout = ImageLayout::HWC): NDArray
+    {
+        return $this->unaryOp('ndarray_image_gray_to_rgb', $layout);
+    }
+
+    /**
+     * Reorder RGB channels to BGR (alpha, if present, is kept).
+     *
+     * @param ImageLayout $layout Layout of the input and output
+     */
+    public function rgbToBgr(ImageLayout $layout = ImageLayout::HWC): NDArray
+    {
+        return $this->unaryOp('ndarray_image_swap_rb', $layout);
+    }
+
+    /**
+     * Reorder BGR channels to RGB (alpha, if present, is kept).
+     *
+     * @param ImageLayout $layout Layout of the input and output
+     */
+    public function bgrToRgb(ImageLayout $layout = ImageLayout::HWC): NDArray
+    {
+        return $this->unaryOp('ndarray_image_swap_rb', $layout);
+    }
+
+    /**
+     * Decode PNG or JPEG file contents into a Uint8 `(H, W, C)` array.
+     *
+     * The format is detected from the data. PNG keeps its channel count (palettes expand to
+     * RGB or RGBA, 16-bit samples are reduced to 8 bits); JPEG decodes to 1 channel for
+     * grayscale files and RGB otherwise. Requires the native library to be built with the
+     * `image` cargo feature.
+     *
+     * @param string $bytes Encoded file contents
+     */
+    public static function decodeImage(string $bytes): NDArray
+    {
+        $length = \strlen($bytes);
+        if (0 === $length) {
+            throw new \InvalidArgumentException('Cannot decode an empty string');
+        }
+
+        $lib = Lib::get();
+        $buffer = $lib->new("uint8_t[{$length}]");
+        \FFI::memcpy($buffer, $bytes, $length);
+
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_image_decode(
+            $buffer,
+            $length,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShapeBuf, (int) $outNdimBuf->cdata);
+
+        return new NDArray($outHandle, new ArrayMetadata($shape), DType::Uint8);
+    }
+
+    /**
+     * Encode a Uint8 `(H, W)` or `(H, W, C)` image as PNG or JPEG file contents.
+     *
+     * PNG accepts 1-4 channels and is lossless. JPEG accepts 1 or 3 channels and writes a
+     * baseline file without chroma subsampling. Requires the native library to be built with
+     * the `image` cargo feature.
+     *
+     * @param ImageFormat $format  Output file format
+     * @param int         $quality JPEG quality from 1 (smallest) to 100 (best); ignored for PNG
+     */
+    public function encodeImage(ImageFormat $format = ImageFormat::Png, int $quality = 90): string
+    {
+        if ($quality < 1 || $quality > 100) {
+            throw new \InvalidArgumentException('quality must be between 1 and 100');
+        }
+
+        return $this->unaryOp('ndarray_image_encode', $format, $quality)->toBytes();
+    }
+}
@@ -12,6 +12,7 @@ use PhpMlKit\NDArray\Exceptions\IndexException;
 use PhpMlKit\NDArray\Exceptions\NDArrayException;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\ScatterMode;
 
 /**
  * Element indexing: get(), set(), and view creation.
@@ -128,6 +129,76 @@ trait HasIndexing
         return $this->getElement($storageFlatIndex);
     }
 
+    /**
+     * Get many scalar values in a single FFI call.
+     *
+     * Each index is either a logical flat index (C-order) or a full coordinate
+     * list with one entry per dimension. Negative indices count from the end.
+     *
+     * @param array<array<int>|int> $indices Flat indices or coordinate lists
+     *
+     * @return array<bool|Complex|float|int> Values in the same order as $indices
+     */
+    public function getMany(array $indices): array
+    {
+        $count = \count($indices);
+        if (0 === $count) {
+            return [];
+        }
+
+        [$cIndices, $indexNdim] = $this->prepareManyIndices($indices);
+
+        $lib = Lib::get();
+        $buffer = $this->dtype->createCArray($count);
+        $meta = $this->meta()->toCData();
+        $status = $lib->ndarray_get_many($this->handle, Lib::addr($meta), $cIndices, $count, $indexNdim, $buffer);
+        $lib->checkStatus($status);
+
+        $out = [];
+        if ($this->dtype->isComplex()) {
+            for ($i = 0; $i < $count; ++$i) {
+                $out[] = new Complex((float) $buffer[$i * 2], (float) $buffer[$i * 2 + 1]);
+            }
+        } else {
+            for ($i = 0; $i < $count; ++$i) {
+                $out[] = $this->dtype->castFromCValue($buffer[$i]);
+            }
+        }
+
+        return $out;
+    }
+
+    /**
+     * Set many scalar values in a single FFI call.
+     *
+     * Indices follow the same rules as getMany(). All indices are validated
+     * before anything is written; when an index repeats, the last value wins.
+     *
+     * @param array<array<int>|int>         $indices Flat indices or coordinate lists
+     * @param array<bool|Complex|float|int> $values  One value per index
+     */
+    public function setMany(array $indices, array $values): void
+    {
+        $count = \count($indices);
+        if ($count !== \count($values)) {
+            throw new \InvalidArgumentException(
+                "setMany() requires one value per index, got {$count} indices and ".\count($values).' values'
+            );
+        }
+        if (0 === $count) {
+            return;
+        }
+
+        [$cIndices, $indexNdim] = $this->prepareManyIndices($indices);
+
+        $lib = Lib::get();
+        $prepared = $this->dtype->prepareArrayValues(array_values($values));
+        $buffer = $this->dtype->createCArray($count, $prepared);
+        $meta = $this->meta()->toCData();
+        $status = $lib->ndarray_set_many($this->handle, Lib::addr($meta), $cIndices, $count, $indexNdim, $buffer);
+        $lib->checkStatus($status);
+    }
+
     /**
      * Gather values by indices.
      *
@@ -189,6 +260,47 @@ trait HasIndexing
         return new self($outHandle, new ArrayMetadata($outShape), $dtype);
     }
 
+    /**
+     * Gather whole rows of a 2D table, e.g. an embedding lookup.
+     *
+     * For a `(vocab, dim)` table and integer indices of any shape, returns an array of shape
+     * `indices.shape + [dim]` holding the selected rows. Each row is copied in one block, so
+     * this is much faster than `take($indices, axis: 0)` for large tables.
+     *
+     * @param array<array<int>|int>|self $indices integer row indices; negative values count from the end
+     */
+    public function takeRows(array|self $indices): self
+    {
+        $indices = $indices instanceof self ? $indices : NDArray::array($indices, DType::Int64);
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $meta = $this->meta()->toCData();
+        $indicesMeta = $indices->meta()->toCData();
+        $status = $lib->ndarray_take_rows(
+            $this->handle,
+            Lib::addr($meta),
+            $indices->handle(),
+            Lib::addr($indicesMeta),
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $ndim = (int) $outNdimBuf->cdata;
+        $outShape = $lib->readSizeTArray($outShapeBuf, $ndim);
+
+        return new self($outHandle, new ArrayMetadata($outShape), $this->dtype);
+    }
+
     /**
      * Gather values along an axis using per-position indices.
      *
@@ -233,12 +345,22 @@ trait HasIndexing
     }
 
     /**
-     * Scatter values by flattened indices and return a mutated copy.
+     * Scatter values by indices and return a mutated copy.
      *
-     * @param array<array<int>|int>|self $indices
-     * @param string                     $mode    Currently supports only 'raise'
+     * If axis is null, indices are positions in the logical flattened view (C-order).
+     * Array values broadcast against the indices shape; values that do not broadcast
+     * are repeated in order, as in NumPy `put`.
+     *
+     * If axis is provided, each index replaces a whole sub-array along that axis, the
+     * inverse of take() with an axis. Values must broadcast to the shape take() would
+     * return.
+     *
+     * @param array<array<int>|int>|self     $indices
+     * @param bool|Complex|float|int|NDArray $values  Scalar or values converted to this array's dtype
+     * @param string                         $mode    Currently supports only 'raise'
+     * @param null|int                       $axis    Axis whose sub-arrays are replaced; null for flat indices
      */
-    public function put(array|self $indices, bool|float|int|self $values, string $mode = 'raise'): self
+    public function put(array|self $indices, bool|Complex|float|int|self $values, string $mode = 'raise', ?int $axis = null): self
     {
         if ('raise' !== $mode) {
             throw new \InvalidArgumentException("put mode '{$mode}' is not supported yet. Use 'raise'.");
@@ -246,24 +368,51 @@ trait HasIndexing
 
         $indices = $indices instanceof self ? $indices : NDArray::array($indices, DType::Int64);
 
-        [$valuesBuffer, $valuesLen, $scalarValue, $hasScalar] = $this->prepareValuesBuffer($values);
-
         $lib = Lib::get();
         $outHandle = $lib->new('struct NdArrayHandle*');
 
         $meta = $this->meta()->toCData();
         $indicesMeta = $indices->meta()->toCData();
-        $status = $lib->ndarray_put(
-            $this->handle,
-            Lib::addr($meta),
-            $indices->handle(),
-            Lib::addr($indicesMeta),
-            $valuesBuffer,
-            $valuesLen,
-            $scalarValue,
-            $hasScalar,
-            Lib::addr($outHandle)
-        );
+
+        // Either an array operand or a scalar buffer is passed; the other is null.
+        $valuesMeta = null;
+        $scalar = null;
+        $scalarDtype = 0;
+        if ($values instanceof self) {
+            $valuesMeta = $values->meta()->toCData();
+        } else {
+            [$scalar, $dtype] = $this->scalarToBuffer($values);
+            $scalarDtype = $dtype->value;
+        }
+        $valuesHandle = $values instanceof self ? $values->handle : null;
+        $valuesMetaPtr = null !== $valuesMeta ? Lib::addr($valuesMeta) : null;
+
+        if (null !== $axis) {
+            $status = $lib->ndarray_put_axis(
+                $this->handle,
+                Lib::addr($meta),
+                $indices->handle(),
+                Lib::addr($indicesMeta),
+                $axis,
+                $valuesHandle,
+                $valuesMetaPtr,
+                $scalar,
+                $scalarDtype,
+                Lib::addr($outHandle)
+            );
+        } else {
+            $status = $lib->ndarray_put(
+                $this->handle,
+                Lib::addr($meta),
+                $indices->handle(),
+                Lib::addr($indicesMeta),
+                $valuesHandle,
+                $valuesMetaPtr,
+                $scalar,
+                $scalarDtype,
+                Lib::addr($outHandle)
+            );
+        }
 
         $lib->checkStatus($status);
 
@@ -337,17 +486,192 @@ trait HasIndexing
     }
 
     /**
-     * Select values from x and y based on a boolean condition.
+     * Take the minimum of updates by flattened indices and return a mutated copy.
+     *
+     * Repeated indices reduce together in one pass. Updates broadcast against the
+     * indices shape and are converted to this array's dtype.
+     *
+     * @param array<array<int>|int>|self $indices     flat indices; negative values count from the end
+     * @param bool|float|int|self        $updates     scalar or one update per index
+     * @param bool                       $includeSelf when false, the first update to a position replaces the original
+     */
+    public function scatterMin(array|self $indices, bool|float|int|self $updates, bool $includeSelf = true): self
+    {
+        return $this->scatterFlat($indices, $updates, ScatterMode::Min, $includeSelf);
+    }
+
+    /**
+     * Take the maximum of updates by flattened indices and return a mutated copy.
+     *
+     * Repeated indices reduce together in one pass. Updates broadcast against the
+     * indices shape and are converted to this array's dtype.
+     *
+     * @param array<array<int>|int>|self $indices     flat indices; negative values count from the end
+     * @param bool|float|int|self        $updates     scalar or one update per index
+     * @param bool                       $includeSelf when false, the first update to a position replaces the original
+     */
+    public function scatterMax(array|self $indices, bool|float|int|self $updates, bool $includeSelf = true): self
+    {
+        return $this->scatterFlat($indices, $updates, ScatterMode::Max, $includeSelf);
+    }
+
+    /**
+     * Average updates by flattened indices and return a mutated copy.
+     *
+     * Each touched position becomes the mean of its updates, plus its original value
+     * when `$includeSelf` is true; untouched positions keep their value. Sums and
+     * counts are tracked in one pass, so scattering into zeros with `includeSelf: false`
+     * gives per-segment means. Integer dtypes truncate the division.
+     *
+     * @param array<array<int>|int>|self $indices     flat indices; negative values count from the end
+     * @param bool|float|int|self        $updates     scalar or one update per index
+     * @param bool                       $includeSelf whether the original value counts towards the mean
+     */
+    public function scatterMean(array|self $indices, bool|float|int|self $updates, bool $includeSelf = true): self
+    {
+        return $this->scatterFlat($indices, $updates, ScatterMode::Mean, $includeSelf);
+    }
+
+    /**
+     * Scatter slices of $updates along an axis, reducing with the given mode.
+     *
+     * Slice `j` of `$updates` along `$axis` is combined into slice `$indices[j]` of a copy
+     * of this array. Repeated indices reduce together, so scattering into zeros with
+     * Add/Max/Mean gives segment pooling, and Assign gives sparse row updates.
+     *
+     * @param array<int>|self $indices     1D integer indices along $axis; negative values count from the end
+     * @param self            $updates     same shape as this array except `count($indices)` along $axis
+     * @param bool            $includeSelf when false, the first value scattered to a position replaces the original
+     */
+    public function scatter(
+        array|self $indices,
+        self $updates,
+        int $axis = 0,
+        ScatterMode $mode = ScatterMode::Assign,
+        bool $includeSelf = true,
+    ): self {
+        $indices = $indices instanceof self ? $indices : NDArray::array($indices, DType::Int64);
+        $updates = $updates->dtype === $this->dtype ? $updates : $updates->astype($this->dtype);
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $meta = $this->meta()->toCData();
+        $indicesMeta = $indices->meta()->toCData();
+        $updatesMeta = $updates->meta()->toCData();
+        $status = $lib->ndarray_scatter(
+            $this->handle,
+            Lib::addr($meta),
+            $indices->handle(),
+            Lib::addr($indicesMeta),
+            $updates->handle(),
+            Lib::addr($updatesMeta),
+            $axis,
+            $mode->value,
+            $includeSelf,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        return new self($outHandle, new ArrayMetadata($this->shape()), $this->dtype);
+    }
+
+    /**
+     * Select the elements where a Bool mask is true, like NumPy `$a[$mask]`.
      *
-     * @param bool|float|int|NDArray $condition Bool NDArray or scalar condition
-     * @param bool|float|int|NDArray $x         Values where condition is true
-     * @param bool|float|int|NDArray $y         Values where condition is false
+     * The mask must match this array's shape or its leading axes. The result is a
+     * new array of shape `[count, ...remaining axes]`, so a full-shape mask gives a
+     * 1D array and a mask over axis 0 selects whole rows. Elements are taken in
+     * C order.
+     *
+     * @param NDArray $mask Bool array matching the leading axes of this array
      */
-    public static function where(bool|float|int|NDArray $condition, bool|float|int|NDArray $x, bool|float|int|NDArray $y): NDArray
+    public function booleanSelect(NDArray $mask): NDArray
     {
-        $condArray = self::coerceWhereOperand($condition, DType::Bool);
-        $xArray = self::coerceWhereOperand($x, null);
-        $yArray = self::coerceWhereOperand($y, null);
+        $maskMeta = $mask->meta()->toCData();
+
+        return $this->unaryOp('ndarray_boolean_select', $mask->handle, Lib::addr($maskMeta));
+    }
+
+    /**
+     * Assign to the elements where a Bool mask is true, in place.
+     *
+     * Like NumPy `$a[$mask] = $values`. A scalar is written to every selected
+     * element; an array must broadcast to the shape booleanSelect() would return.
+     * Values are converted to this array's dtype. Writes through a view reach its
+     * base array.
+     *
+     * @param NDArray                        $mask   Bool array matching the leading axes of this array
+     * @param bool|Complex|float|int|NDArray $values Scalar or values for the selected elements
+     */
+    public function booleanAssign(NDArray $mask, bool|Complex|float|int|NDArray $values): void
+    {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+        $maskMeta = $mask->meta()->toCData();
+
+        if ($values instanceof NDArray) {
+            $valuesMeta = $values->meta()->toCData();
+            $status = $lib->ndarray_boolean_assign(
+                $this->handle,
+                Lib::addr($meta),
+                $mask->handle,
+                Lib::addr($maskMeta),
+                $values->handle,
+                Lib::addr($valuesMeta),
+                null,
+                0
+            );
+        } else {
+            [$buffer, $dtype] = $this->scalarToBuffer($values);
+            $status = $lib->ndarray_boolean_assign(
+                $this->handle,
+                Lib::addr($meta),
+                $mask->handle,
+                Lib::addr($maskMeta),
+                null,
+                null,
+                $buffer,
+                $dtype->value
+            );
+        }
+
+        $lib->checkStatus($status);
+    }
+
+    /**
+     * Select values from x and y based on a condition.
+     *
+     * The condition, x and y broadcast together. A non-Bool condition selects x
+     * where it is non-zero. Two arrays promote as in arithmetic; a scalar next to
+     * an array keeps the array's dtype unless it needs a float or complex result,
+     * so `where($mask, $int8Array, 0)` stays Int8.
+     *
+     * @param bool|float|int|NDArray         $condition Condition array or scalar
+     * @param bool|Complex|float|int|NDArray $x         Values where condition is true
+     * @param bool|Complex|float|int|NDArray $y         Values where condition is false
+     */
+    public static function where(bool|float|int|NDArray $condition, bool|Complex|float|int|NDArray $x, bool|Complex|float|int|NDArray $y): NDArray
+    {
+        $condArray = $condition instanceof NDArray ? $condition : NDArray::fromScalar((bool) $condition);
+
+        if ($x instanceof NDArray && !$y instanceof NDArray) {
+            return $condArray->whereScalar($x, $y, false);
+        }
+        if ($y instanceof NDArray && !$x instanceof NDArray) {
+            return $condArray->whereScalar($y, $x, true);
+        }
+
+        $xArray = $x instanceof NDArray ? $x : NDArray::fromScalar($x);
+        $yArray = $y instanceof NDArray ? $y : NDArray::fromScalar($y);
 
         $lib = Lib::get();
         $outHandle = $lib->new('struct NdArrayHandle*');
@@ -506,19 +830,90 @@ trait HasIndexing
         return [$buffer, \count($flat), 0.0, false];
     }
 
-    private static function coerceWhereOperand(bool|float|int|NDArray $value, ?DType $forceDtype): NDArray
+    /**
+     * Reduce updates into flattened indices of a copy of this array.
+     *
+     * @param array<array<int>|int>|self $indices
+     */
+    private function scatterFlat(array|self $indices, bool|float|int|self $updates, ScatterMode $mode, bool $includeSelf): self
     {
-        if ($value instanceof NDArray) {
-            if (null !== $forceDtype && $value->dtype !== $forceDtype) {
-                return $value->astype($forceDtype);
-            }
+        $indices = $indices instanceof self ? $indices : NDArray::array($indices, DType::Int64);
+        $indicesMeta = $indices->meta()->toCData();
 
-            return $value;
+        if ($updates instanceof self) {
+            $updatesMeta = $updates->meta()->toCData();
+
+            return $this->unaryOp(
+                'ndarray_scatter_flat',
+                $indices->handle,
+                Lib::addr($indicesMeta),
+                $updates->handle,
+                Lib::addr($updatesMeta),
+                null,
+                0,
+                $mode,
+                $includeSelf
+            );
         }
 
-        $dtype = $forceDtype ?? DType::fromValue($value);
+        [$buffer, $dtype] = $this->scalarToBuffer($updates);
+
+        return $this->unaryOp(
+            'ndarray_scatter_flat',
+            $indices->handle,
+            Lib::addr($indicesMeta),
+            null,
+            null,
+            $buffer,
+            $dtype,
+            $mode,
+            $includeSelf
+        );
+    }
+
+    /**
+     * where() with this array as condition and one scalar operand.
+     *
+     * The scalar is passed by value, so it is never materialised as an array.
+     */
+    private function whereScalar(NDArray $array, bool|Complex|float|int $scalar, bool $scalarIsX): NDArray
+    {
+        $arrayMeta = $array->meta()->toCData();
+        [$buffer, $dtype] = $this->scalarToBuffer($scalar);
+
+        return $this->unaryOp('ndarray_where_scalar', $array->handle, Lib::addr($arrayMeta), $buffer, $dtype, $scalarIsX);
+    }
+
+    /**
+     * Pack flat indices or coordinate lists into a contiguous int64 buffer.
+     *
+     * @param array<array<int>|int> $indices
+     *
+     * @return array{0: CData, 1: int} Buffer and number of components per index
+     */
+    private function prepareManyIndices(array $indices): array
+    {
+        $coordinates = \is_array(reset($indices));
+
+        $flat = [];
+        foreach ($indices as $index) {
+            if (\is_array($index) !== $coordinates) {
+                throw new \InvalidArgumentException('Cannot mix flat indices and coordinate lists');
+            }
+            if (!$coordinates) {
+                $flat[] = $index;
+
+                continue;
+            }
+            if (\count($index) !== $this->ndim()) {
+                throw new IndexException(
+                    "Coordinate indices require exactly {$this->ndim()} entries, got ".\count($index)
+                );
+            }
+            array_push($flat, ...array_values($index));
+        }
 
-        return NDArray::array([$value], $dtype);
+        return [Lib::get()->createCArray('int64_t', $flat), $coordinates ? $this->ndim() : 1];
     }
 
     /**
@@ -7,7 +7,9 @@ namespace PhpMlKit\NDArray\Traits;
 use PhpMlKit\NDArray\ArrayMetadata;
 use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\SingularMatrixException;
 use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
 
@@ -89,6 +91,7 @@ trait HasLinearAlgebra
      * - **1D × 1D**: inner product → scalar
      * - **2D × 2D**: matrix product → 2D array
      * - **1D × 2D** or **2D × 1D**: vector–matrix product → 1D array
+     * - **0D × any** or **any × 0D**: scaling by the 0D value, same shape as the other operand
      *
      * @param NDArray $other The other array
      *
@@ -102,22 +105,124 @@ trait HasLinearAlgebra
     }
 
     /**
-     * Matrix multiplication (`@`) for 1D and 2D operands.
+     * Tensor dot product: sum products over pairs of axes of this array and `$other`.
+     *
+     * With an int `N`, the last `N` axes of this array are contracted with the
+     * first `N` axes of `$other` (`2` for matrices gives the Frobenius inner
+     * product, `1` the matrix product, `0` the outer product). With
+     * `[[a0, a1, ...], [b0, b1, ...]]`, axis `a0` is contracted with `b0` and so
+     * on; negative axes count from the end. The result has the remaining axes of
+     * this array followed by the remaining axes of `$other`.
+     *
+     * @param array{0: array<int>|int, 1: array<int>|int}|int $axes Number of axes or explicit axis pairs
+     *
+     * @return Complex|float|int|NDArray scalar when every axis is contracted, otherwise an NDArray
+     */
+    public function tensordot(NDArray $other, array|int $axes = 2): Complex|float|int|NDArray
+    {
+        if (\is_int($axes)) {
+            if ($axes < 0 || $axes > $this->ndim() || $axes > $other->ndim()) {
+                throw new ShapeException("tensordot cannot contract {$axes} axes of {$this->ndim()}D and {$other->ndim()}D arrays");
+            }
+            $axesA = 0 === $axes ? [] : range($this->ndim() - $axes, $this->ndim() - 1);
+            $axesB = 0 === $axes ? [] : range(0, $axes - 1);
+        } else {
+            if (2 !== \count($axes) || !isset($axes[0], $axes[1])) {
+                throw new ShapeException('tensordot axes must be an int or a pair of axis lists');
+            }
+            $axesA = array_values((array) $axes[0]);
+            $axesB = array_values((array) $axes[1]);
+            if (\count($axesA) !== \count($axesB)) {
+                throw new ShapeException('tensordot axis lists must have the same length');
+            }
+        }
+
+        $lib = Lib::get();
+        $result = $this->binaryOp(
+            'ndarray_tensordot',
+            $other,
+            $lib->createCArray('int64_t', $axesA),
+            $lib->createCArray('int64_t', $axesB),
+            \count($axesA),
+        );
+
+        return 0 === $result->ndim() ? $result->toScalar() : $result;
+    }
+
+    /**
+     * Outer product of two arrays.
+     *
+     * Both arrays are flattened first, so the result is always 2D with shape
+     * `[$this->size(), $other->size()]`. Operand dtypes are promoted as in dot().
+     *
+     * @param NDArray $other The other array
+     */
+    public function outer(NDArray $other): NDArray
+    {
+        return $this->binaryOp('ndarray_outer', $other);
+    }
+
+    /**
+     * Kronecker product of this array and `$other`.
+     *
+     * The array with fewer dimensions is padded with leading axes of length 1;
+     * each result axis has length `a_dim * b_dim` and holds the blocks
+     * `a[i] * $other`. Dtypes are promoted as in multiply(), so integer inputs
+     * give an integer result.
+     *
+     * @param NDArray $other The other array
+     */
+    public function kron(NDArray $other): NDArray
+    {
+        return $this->binaryOp('ndarray_kron', $other);
+    }
+
+    /**
+     * Cross product of 3-vectors along the last axis.
+     *
+     * Both arrays must have a last axis of length 3; the leading axes are
+     * broadcast against each other, so a stack of vectors can be crossed with a
+     * single vector. Dtypes are promoted as in multiply().
+     *
+     * @param NDArray $other The other array
+     *
+     * @throws ShapeException If either last axis is not of length 3 or the leading axes do not broadcast
+     */
+    public function cross(NDArray $other): NDArray
+    {
+        return $this->binaryOp('ndarray_cross', $other);
+    }
+
+    /**
+     * Matrix multiplication (`@`) following NumPy `matmul` rules.
      *
      * Operand dtypes are promoted to a common type before the operation. Supported shapes:
      * - **2D × 2D**: matrix × matrix → 2D array
      * - **2D × 1D** or **1D × 2D**: matrix × vector → 1D array
      * - **1D × 1D**: inner product → scalar (0-D result unpacked to a PHP scalar or `Complex`)
+     * - **ND × ND**: stacks of matrices `[..., M, K] × [..., K, N]` → `[..., M, N]`,
+     *   with the leading batch dimensions broadcast against each other
      *
-     * Operands with more than two dimensions are not supported.
+     * By default products are accumulated in the promoted dtype. Pass `$accumulate`
+     * to accumulate in a wider dtype (e.g. Float64 for Float32 inputs) for better
+     * precision on long inner dimensions, and `$dtype` to choose the result dtype.
      *
-     * @param NDArray $other The other array
+     * @param NDArray    $other      The other array
+     * @param null|DType $accumulate Accumulation dtype (real for real inputs, complex for complex inputs)
+     * @param null|DType $dtype      Result dtype; defaults to the promoted dtype
      *
      * @return Complex|float|int|NDArray scalar when the result is 0-D, otherwise an NDArray
      */
-    public function matmul(NDArray $other): Complex|float|int|NDArray
+    public function matmul(NDArray $other, ?DType $accumulate = null, ?DType $dtype = null): Complex|float|int|NDArray
     {
-        $result = $this->binaryOp('ndarray_matmul', $other);
+        $result = $this->binaryOp(
+            'ndarray_matmul',
+            $other,
+            $accumulate?->value ?? 0,
+            null !== $accumulate,
+            $dtype?->value ?? 0,
+            null !== $dtype,
+        );
 
         return 0 === $result->ndim() ? $result->toScalar() : $result;
     }
@@ -129,20 +234,31 @@ trait HasLinearAlgebra
      * Unlike matmul() which delegates to BLAS (tiled, non-deterministic accumulation),
      * einsum uses plain nested loops producing identical results on every call.
      *
-     * Supports both two-operand patterns (`ij,jk->ik`, `i,i->`) and
-     * single-operand patterns (`ii->` for trace, `ij->ji` for transpose,
-     * `ij->i` for sum, etc.). When `->` is omitted, output labels are
-     * inferred from labels appearing exactly once across all operands.
+     * Takes any number of operands: this array first, then $others in subscript
+     * order. Supports contractions (`ij,jk->ik`), batched contractions
+     * (`bqd,bkd->bqk`), transposes (`ij->ji`), traces and diagonals (`ii->`,
+     * `ii->i`), reductions (`ij->i`) and chains (`ij,jk,kl->il`, contracted left
+     * to right). When `->` is omitted, the output holds the labels appearing
+     * exactly once across all operands, in alphabetical order.
      *
-     * @param string       $subscripts Einstein summation subscript
-     * @param null|NDArray $other      The second operand (null for single-operand patterns)
+     * @param string  $subscripts Einstein summation subscript
+     * @param NDArray ...$others  Remaining operands (none for single-operand patterns)
      *
      * @return NDArray Result of the contraction
      */
-    public function einsum(string $subscripts, ?NDArray $other = null): NDArray
+    public function einsum(string $subscripts, NDArray ...$others): NDArray
     {
         $lib = Lib::get();
-        $aMeta = $this->meta()->toCData();
+        $operands = [$this, ...array_values($others)];
+        $numOperands = \count($operands);
+
+        $metaWrappers = array_map(static fn (NDArray $a) => $a->meta()->toCData(), $operands);
+        $cHandles = $lib->new("struct NdArrayHandle*[{$numOperands}]");
+        $cMetas = $lib->new("struct ArrayMetadata*[{$numOperands}]");
+        for ($i = 0; $i < $numOperands; ++$i) {
+            $cHandles[$i] = $operands[$i]->handle;
+            $cMetas[$i] = Lib::addr($metaWrappers[$i]);
+        }
 
         $outHandle = $lib->new('struct NdArrayHandle*');
         $outDtypeBuf = $lib->new('uint8_t');
@@ -153,13 +269,10 @@ trait HasLinearAlgebra
         $subscriptsPtr = $lib->new('char['.\strlen($subscriptsBytes).']');
         \FFI::memcpy($subscriptsPtr, $subscriptsBytes, \strlen($subscriptsBytes));
 
-        $bMetaCData = null !== $other ? $other->meta()->toCData() : null;
-
         $status = $lib->ndarray_einsum(
-            $this->handle,
-            Lib::addr($aMeta),
-            null !== $other ? $other->handle : null,
-            null !== $bMetaCData ? Lib::addr($bMetaCData) : null,
+            $cHandles,
+            $cMetas,
+            $numOperands,
             $subscriptsPtr,
             Lib::addr($outHandle),
             Lib::addr($outDtypeBuf),
@@ -208,6 +321,30 @@ trait HasLinearAlgebra
             : $this->unaryOp('ndarray_diagonal', $offset);
     }
 
+    /**
+     * Lower triangle of a matrix or stack of matrices.
+     *
+     * Elements above the k-th diagonal of the last two axes are set to zero.
+     *
+     * @param int $k Diagonal above which to zero elements. 0 = main diagonal, positive = upper diagonal, negative = lower diagonal
+     */
+    public function tril(int $k = 0): NDArray
+    {
+        return $this->unaryOp('ndarray_tril', $k);
+    }
+
+    /**
+     * Upper triangle of a matrix or stack of matrices.
+     *
+     * Elements below the k-th diagonal of the last two axes are set to zero.
+     *
+     * @param int $k Diagonal below which to zero elements. 0 = main diagonal, positive = upper diagonal, negative = lower diagonal
+     */
+    public function triu(int $k = 0): NDArray
+    {
+        return $this->unaryOp('ndarray_triu', $k);
+    }
+
     /**
      * Compute trace (sum of diagonal elements).
      *
@@ -224,6 +361,8 @@ trait HasLinearAlgebra
      * A must be a 2D square matrix. b can be 1D or 2D.
      *
      * @param NDArray $b Right-hand side array
+     *
+     * @throws SingularMatrixException If A is singular
      */
     public function solve(NDArray $b): NDArray
     {
@@ -233,7 +372,10 @@ trait HasLinearAlgebra
     /**
      * Compute the inverse of a square matrix.
      *
-     * Requires a 2D square matrix.
+     * Requires a 2D square matrix or a stack of them (`[..., N, N]`), in which case
+     * each matrix is inverted independently.
+     *
+     * @throws SingularMatrixException If a matrix is singular
      */
     public function inv(): NDArray
     {
@@ -243,11 +385,17 @@ trait HasLinearAlgebra
     /**
      * Compute the determinant of a square matrix.
      *
-     * Requires a 2D square matrix.
+     * Requires a 2D square matrix or a stack of them (`[..., N, N]`). A singular
+     * matrix has determinant zero.
+     *
+     * @return Complex|float|int|NDArray scalar for a single matrix, otherwise an array of
+     *                                   determinants shaped like the batch dimensions
      */
-    public function det(): Complex|float|int
+    public function det(): Complex|float|int|NDArray
     {
-        return $this->scalarReductionOp('ndarray_det');
+        $result = $this->unaryOp('ndarray_det');
+
+        return 0 === $result->ndim() ? $result->toScalar() : $result;
     }
 
     /**
@@ -258,11 +406,13 @@ trait HasLinearAlgebra
      * - S is a diagonal matrix of singular values (returned as 1D array)
      * - V^T is an orthogonal matrix (right singular vectors transposed)
      *
-     * @param bool $computeUv If true, compute U and V^T matrices. If false, only compute singular values.
+     * @param bool $computeUv    If true, compute U and V^T matrices. If false, only compute singular values.
+     * @param bool $fullMatrices If true, U is M×M and V^T is N×N. If false, they are reduced to M×K and
+     *                           K×N with K = min(M, N), which is all a low-rank reconstruction needs.
      *
      * @return ($computeUv is true ? array{0: NDArray, 1: NDArray, 2: NDArray} : NDArray)
      */
-    public function svd(bool $computeUv = true): array|NDArray
+    public function svd(bool $computeUv = true, bool $fullMatrices = true): array|NDArray
     {
         $lib = Lib::get();
         $meta = $this->meta()->toCData();
@@ -289,6 +439,7 @@ trait HasLinearAlgebra
             Lib::addr($meta),
             $calcUv,
             $calcUv,
+            $fullMatrices ? 1 : 0,
             $computeUv ? Lib::addr($outHandleU) : null,
             $computeUv ? Lib::addr($outDtypeU) : null,
             $computeUv ? Lib::addr($outNdimU) : null,
@@ -377,6 +528,69 @@ trait HasLinearAlgebra
         return [$q, $r];
     }
 
+    /**
+     * Compute LU decomposition with partial pivoting.
+     *
+     * Factors an M×N matrix so that `A[perm] = L * U`, where L is M×K unit lower
+     * triangular, U is K×N upper triangular and K = min(M, N). Row `i` of `L * U`
+     * is row `perm[i]` of A. Singular matrices still factor, with a zero on U's diagonal.
+     *
+     * Only Float32 and Float64 matrices are supported.
+     *
+     * @return array{0: NDArray, 1: NDArray, 2: NDArray} [L, U, perm] with perm as an Int64 array
+     */
+    public function lu(): array
+    {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+        $maxNdim = 8;
+
+        $outHandleL = $lib->new('struct NdArrayHandle*');
+        $outDtypeL = $lib->new('uint8_t');
+        $outNdimL = $lib->new('size_t');
+        $outShapeL = $lib->new("size_t[{$maxNdim}]");
+
+        $outHandleU = $lib->new('struct NdArrayHandle*');
+        $outDtypeU = $lib->new('uint8_t');
+        $outNdimU = $lib->new('size_t');
+        $outShapeU = $lib->new("size_t[{$maxNdim}]");
+
+        $outHandleP = $lib->new('struct NdArrayHandle*');
+        $outDtypeP = $lib->new('uint8_t');
+        $outNdimP = $lib->new('size_t');
+        $outShapeP = $lib->new("size_t[{$maxNdim}]");
+
+        $status = $lib->ndarray_lu(
+            $this->handle,
+            Lib::addr($meta),
+            Lib::addr($outHandleL),
+            Lib::addr($outDtypeL),
+            Lib::addr($outNdimL),
+            $outShapeL,
+            $maxNdim,
+            Lib::addr($outHandleU),
+            Lib::addr($outDtypeU),
+            Lib::addr($outNdimU),
+            $outShapeU,
+            Lib::addr($outHandleP),
+            Lib::addr($outDtypeP),
+            Lib::addr($outNdimP),
+            $outShapeP,
+        );
+
+        $lib->checkStatus($status);
+
+        $lShape = $lib->readSizeTArray($outShapeL, $outNdimL->cdata);
+        $uShape = $lib->readSizeTArray($outShapeU, $outNdimU->cdata);
+        $pShape = $lib->readSizeTArray($outShapeP, $outNdimP->cdata);
+
+        $l = new NDArray($outHandleL, new ArrayMetadata($lShape), DType::from($outDtypeL->cdata));
+        $u = new NDArray($outHandleU, new ArrayMetadata($uShape), DType::from($outDtypeU->cdata));
+        $perm = new NDArray($outHandleP, new ArrayMetadata($pShape), DType::from($outDtypeP->cdata));
+
+        return [$l, $u, $perm];
+    }
+
     /**
      * Compute eigenvalue decomposition.
      *
@@ -698,6 +912,107 @@ trait HasLinearAlgebra
         return new NDArray($outHandle, new ArrayMetadata($shape), $dtype);
     }
 
+    /**
+     * Least-squares fit of a polynomial of degree `$deg` to the points (x, y).
+     *
+     * Coefficients are returned highest power first, so they can be passed straight to
+     * polyval(). A 2D `$y` of shape (n, k) fits k data sets at once and yields coefficients
+     * of shape (deg + 1, k). Float32 inputs yield Float32; other real dtypes yield Float64.
+     *
+     * @param NDArray $x   1D sample positions of length n
+     * @param NDArray $y   sample values, shape (n,) or (n, k)
+     * @param int     $deg degree of the fitting polynomial
+     */
+    public static function polyfit(NDArray $x, NDArray $y, int $deg): NDArray
+    {
+        if ($deg < 0) {
+            throw new \InvalidArgumentException("Polynomial degree must be non-negative, got {$deg}");
+        }
+
+        $lib = Lib::get();
+        $xMeta = $x->meta()->toCData();
+        $yMeta = $y->meta()->toCData();
+
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtype = $lib->new('uint8_t');
+        $outNdim = $lib->new('size_t');
+        $outShape = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_polyfit(
+            $x->handle,
+            Lib::addr($xMeta),
+            $y->handle,
+            Lib::addr($yMeta),
+            $deg,
+            Lib::addr($outHandle),
+            Lib::addr($outDtype),
+            Lib::addr($outNdim),
+            $outShape,
+            Lib::MAX_NDIM,
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShape, $outNdim->cdata);
+
+        return new NDArray($outHandle, new ArrayMetadata($shape), DType::from($outDtype->cdata));
+    }
+
+    /**
+     * Evaluate a polynomial at every element of `$x` using Horner's scheme.
+     *
+     * @param NDArray $p 1D coefficients, highest power first (as returned by polyfit())
+     * @param NDArray $x points to evaluate at; the result has the same shape
+     */
+    public static function polyval(NDArray $p, NDArray $x): NDArray
+    {
+        return $p->binaryOp('ndarray_polyval', $x);
+    }
+
+    /**
+     * Roots of a polynomial, computed as the eigenvalues of its companion matrix.
+     *
+     * Leading zero coefficients are ignored; trailing zeros yield roots at zero.
+     * Returns [real, imag] where the i-th root is real[i] + imag[i]·i. Float32 input
+     * yields Float32 parts; other real dtypes yield Float64.
+     *
+     * @param NDArray $p 1D real coefficients, highest power first
+     *
+     * @return array{0: NDArray, 1: NDArray}
+     */
+    public static function roots(NDArray $p): array
+    {
+        $lib = Lib::get();
+        $meta = $p->meta()->toCData();
+
+        $outReal = $lib->new('struct NdArrayHandle*');
+        $outImag = $lib->new('struct NdArrayHandle*');
+        $outDtype = $lib->new('uint8_t');
+        $outNdim = $lib->new('size_t');
+        $outShape = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $status = $lib->ndarray_roots(
+            $p->handle,
+            Lib::addr($meta),
+            Lib::addr($outReal),
+            Lib::addr($outImag),
+            Lib::addr($outDtype),
+            Lib::addr($outNdim),
+            $outShape,
+            Lib::MAX_NDIM,
+        );
+
+        $lib->checkStatus($status);
+
+        $shape = $lib->readSizeTArray($outShape, $outNdim->cdata);
+        $dtype = DType::from($outDtype->cdata);
+
+        return [
+            new NDArray($outReal, new ArrayMetadata($shape), $dtype),
+            new NDArray($outImag, new ArrayMetadata($shape), $dtype),
+        ];
+    }
+
     /**
      * Compute the 2-norm condition number of a matrix.
      */
@@ -793,7 +1108,7 @@ trait HasLinearAlgebra
     {
         $shape = $this->shape();
         $ndim = \count($shape);
-        $axisNorm = $axis < 0 ? $ndim + $axis : $axis;
+        $axisNorm = self::normalizeAxis($axis, $ndim);
 
         if ($keepdims) {
             $shape[$axisNorm] = 1;
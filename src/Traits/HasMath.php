@@ -4,8 +4,11 @@ declare(strict_types=1);
 
 namespace PhpMlKit\NDArray\Traits;
 
+use PhpMlKit\NDArray\BinaryOp;
 use PhpMlKit\NDArray\Complex;
+use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\OverflowMode;
 
 /**
  * Mathematical operations trait for NDArray.
@@ -18,60 +21,110 @@ trait HasMath
     /**
      * Add another array or scalar to this array.
      *
-     * @param Complex|float|int|NDArray $other Array or scalar to add
+     * @param Complex|float|int|NDArray $other    Array or scalar to add
+     * @param null|NDArray              $out      Existing array to write the result into
+     * @param OverflowMode              $overflow What integer results do when they overflow
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    public function add(Complex|float|int|NDArray $other): NDArray
-    {
+    public function add(
+        Complex|float|int|NDArray $other,
+        ?NDArray $out = null,
+        OverflowMode $overflow = OverflowMode::Wrap,
+    ): NDArray {
+        if (null !== $out) {
+            if (OverflowMode::Wrap !== $overflow) {
+                throw new \InvalidArgumentException('OverflowMode::Wrap is the only overflow mode supported with $out');
+            }
+
+            return $this->binaryOpInto(BinaryOp::Add, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
-            return $this->binaryOp('ndarray_add', $other);
+            return $this->binaryOp('ndarray_add', $other, $overflow);
         }
 
-        return $this->unaryOp('ndarray_add_scalar', ...$this->scalarToBuffer($other));
+        [$scalar, $dtype] = $this->scalarToBuffer($other);
+
+        return $this->unaryOp('ndarray_add_scalar', $scalar, $dtype, $overflow);
     }
 
     /**
      * Subtract another array or scalar from this array.
      *
-     * @param Complex|float|int|NDArray $other Array or scalar to subtract
+     * @param Complex|float|int|NDArray $other    Array or scalar to subtract
+     * @param null|NDArray              $out      Existing array to write the result into
+     * @param OverflowMode              $overflow What integer results do when they overflow
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    public function subtract(Complex|float|int|NDArray $other): NDArray
-    {
+    public function subtract(
+        Complex|float|int|NDArray $other,
+        ?NDArray $out = null,
+        OverflowMode $overflow = OverflowMode::Wrap,
+    ): NDArray {
+        if (null !== $out) {
+            if (OverflowMode::Wrap !== $overflow) {
+                throw new \InvalidArgumentException('OverflowMode::Wrap is the only overflow mode supported with $out');
+            }
+
+            return $this->binaryOpInto(BinaryOp::Sub, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
-            return $this->binaryOp('ndarray_sub', $other);
+            return $this->binaryOp('ndarray_sub', $other, $overflow);
         }
 
-        return $this->unaryOp('ndarray_sub_scalar', ...$this->scalarToBuffer($other));
+        [$scalar, $dtype] = $this->scalarToBuffer($other);
+
+        return $this->unaryOp('ndarray_sub_scalar', $scalar, $dtype, $overflow);
     }
 
     /**
      * Multiply this array by another array or scalar.
      *
-     * @param Complex|float|int|NDArray $other Array or scalar to multiply by
+     * @param Complex|float|int|NDArray $other    Array or scalar to multiply by
+     * @param null|NDArray              $out      Existing array to write the result into
+     * @param OverflowMode              $overflow What integer results do when they overflow
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    public function multiply(Complex|float|int|NDArray $other): NDArray
-    {
+    public function multiply(
+        Complex|float|int|NDArray $other,
+        ?NDArray $out = null,
+        OverflowMode $overflow = OverflowMode::Wrap,
+    ): NDArray {
+        if (null !== $out) {
+            if (OverflowMode::Wrap !== $overflow) {
+                throw new \InvalidArgumentException('OverflowMode::Wrap is the only overflow mode supported with $out');
+            }
+
+            return $this->binaryOpInto(BinaryOp::Mul, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
-            return $this->binaryOp('ndarray_mul', $other);
+            return $this->binaryOp('ndarray_mul', $other, $overflow);
         }
 
-        return $this->unaryOp('ndarray_mul_scalar', ...$this->scalarToBuffer($other));
+        [$scalar, $dtype] = $this->scalarToBuffer($other);
+
+        return $this->unaryOp('ndarray_mul_scalar', $scalar, $dtype, $overflow);
     }
 
     /**
      * Divide this array by another array or scalar.
      *
      * @param Complex|float|int|NDArray $other Array or scalar to divide by
+     * @param null|NDArray              $out   Existing array to write the result into
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    public function divide(Complex|float|int|NDArray $other): NDArray
+    public function divide(Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
     {
+        if (null !== $out) {
+            return $this->binaryOpInto(BinaryOp::Div, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
             return $this->binaryOp('ndarray_div', $other);
         }
@@ -83,11 +136,16 @@ trait HasMath
      * Compute remainder (modulo) with another array or scalar.
      *
      * @param Complex|float|int|NDArray $other Array or scalar
+     * @param null|NDArray              $out   Existing array to write the result into
      *
-     * @return NDArray New array with result
+     * @return NDArray New array with result, or `$out` when given
      */
-    public function rem(Complex|float|int|NDArray $other): NDArray
+    public function rem(Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
     {
+        if (null !== $out) {
+            return $this->binaryOpInto(BinaryOp::Rem, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
             return $this->binaryOp('ndarray_rem', $other);
         }
@@ -98,19 +156,107 @@ trait HasMath
     /**
      * Compute modulo with another array or scalar.
      *
-     * Alias for rem().
+     * Unlike rem(), the result takes the sign of the divisor (Python `%`),
+     * so `mod(-7, 3)` is 2 rather than -1. Integer division by zero throws.
      *
      * @param Complex|float|int|NDArray $other Array or scalar
+     * @param null|NDArray              $out   Existing array to copy the result into
+     *
+     * @return NDArray New array with result, or `$out` when given
+     */
+    public function mod(Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
+    {
+        $result = $other instanceof NDArray
+            ? $this->binaryOp('ndarray_mod', $other)
+            : $this->unaryOp('ndarray_mod_scalar', ...$this->scalarToBuffer($other));
+
+        if (null !== $out) {
+            $out->assign($result);
+
+            return $out;
+        }
+
+        return $result;
+    }
+
+    /**
+     * Compute floor division with another array or scalar.
+     *
+     * The quotient is rounded toward negative infinity (Python `//`), so
+     * `floorDivide(-7, 2)` is -4. Integer inputs keep their dtype; integer
+     * division by zero throws.
+     *
+     * @param float|int|NDArray $other Array or scalar
      *
      * @return NDArray New array with result
      */
-    public function mod(Complex|float|int|NDArray $other): NDArray
+    public function floorDivide(float|int|NDArray $other): NDArray
     {
         if ($other instanceof NDArray) {
-            return $this->binaryOp('ndarray_rem', $other);
+            return $this->binaryOp('ndarray_floordiv', $other);
         }
 
-        return $this->unaryOp('ndarray_rem_scalar', ...$this->scalarToBuffer($other));
+        return $this->unaryOp('ndarray_floordiv_scalar', ...$this->scalarToBuffer($other));
+    }
+
+    /**
+     * Compute the C-style remainder with another array or scalar.
+     *
+     * The result takes the sign of the dividend, so `fmod(-7, 3)` is -1.
+     * Integer division by zero throws.
+     *
+     * @param float|int|NDArray $other Array or scalar
+     *
+     * @return NDArray New array with result
+     */
+    public function fmod(float|int|NDArray $other): NDArray
+    {
+        if ($other instanceof NDArray) {
+            return $this->binaryOp('ndarray_fmod', $other);
+        }
+
+        return $this->unaryOp('ndarray_fmod_scalar', ...$this->scalarToBuffer($other));
+    }
+
+    /**
+     * Subtract this array from a scalar ($scalar - $this).
+     *
+     * @param Complex|float|int $scalar   Left-hand scalar
+     * @param OverflowMode      $overflow What integer results do when they overflow
+     *
+     * @return NDArray New array with result
+     */
+    public function rsub(Complex|float|int $scalar, OverflowMode $overflow = OverflowMode::Wrap): NDArray
+    {
+        [$buffer, $dtype] = $this->scalarToBuffer($scalar);
+
+        return $this->unaryOp('ndarray_rsub_scalar', $buffer, $dtype, $overflow);
+    }
+
+    /**
+     * Divide a scalar by this array ($scalar / $this).
+     *
+     * @param Complex|float|int $scalar Dividend
+     *
+     * @return NDArray New array with result
+     */
+    public function rdiv(Complex|float|int $scalar): NDArray
+    {
+        return $this->unaryOp('ndarray_rdiv_scalar', ...$this->scalarToBuffer($scalar));
+    }
+
+    /**
+     * Raise a scalar to the power of each element ($scalar ** $this).
+     *
+     * Integer results wrap on overflow; negative integer exponents throw.
+     *
+     * @param Complex|float|int $scalar Base
+     *
+     * @return NDArray New array with result
+     */
+    public function rpow(Complex|float|int $scalar): NDArray
+    {
+        return $this->unaryOp('ndarray_rpow_scalar', ...$this->scalarToBuffer($scalar));
     }
 
     /**
@@ -193,6 +339,36 @@ trait HasMath
         return $this->unaryOp('ndarray_isreal');
     }
 
+    /**
+     * Returns bool array: true where element is NaN.
+     *
+     * Complex elements are NaN if either part is. Integer and bool arrays are never NaN.
+     */
+    public function isnan(): NDArray
+    {
+        return $this->unaryOp('ndarray_isnan');
+    }
+
+    /**
+     * Returns bool array: true where element is positive or negative infinity.
+     *
+     * Complex elements are infinite if either part is. Integer and bool arrays are never infinite.
+     */
+    public function isinf(): NDArray
+    {
+        return $this->unaryOp('ndarray_isinf');
+    }
+
+    /**
+     * Returns bool array: true where element is neither NaN nor infinite.
+     *
+     * Complex elements are finite if both parts are. Integer and bool arrays are always finite.
+     */
+    public function isfinite(): NDArray
+    {
+        return $this->unaryOp('ndarray_isfinite');
+    }
+
     /**
      * Compute phase angle element-wise.
      *
@@ -534,10 +710,11 @@ trait HasMath
      * Clamp (clip) array values to a specified range.
      *
      * Similar to NumPy's clip function. Values outside [min, max] are set
-     * to the nearest boundary.
+     * to the nearest boundary. The result keeps this array's dtype; integer
+     * arrays are clamped as integers, so large int bounds stay exact.
      *
-     * @param float $min Minimum value
-     * @param float $max Maximum value
+     * @param float|int $min Minimum value
+     * @param float|int $max Maximum value
      *
      * @throws \InvalidArgumentException If min > max
      */
@@ -547,7 +724,11 @@ trait HasMath
             throw new \InvalidArgumentException('Clamp requires min <= max');
         }
 
-        return $this->unaryOp('ndarray_clamp', $min, $max);
+        return $this->unaryOp(
+            'ndarray_clamp',
+            ...$this->scalarToBuffer($min),
+            ...$this->scalarToBuffer($max),
+        );
     }
 
     /**
@@ -572,11 +753,16 @@ trait HasMath
      * the smaller value at each position. Supports broadcasting.
      *
      * @param Complex|float|int|NDArray $other Array or scalar to compare with
+     * @param null|NDArray              $out   Existing array to write the result into
      *
-     * @return NDArray New array with element-wise minimum values
+     * @return NDArray New array with element-wise minimum values, or `$out` when given
      */
-    public function minimum(Complex|float|int|NDArray $other): NDArray
+    public function minimum(Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
     {
+        if (null !== $out) {
+            return $this->binaryOpInto(BinaryOp::Minimum, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
             return $this->binaryOp('ndarray_minimum', $other);
         }
@@ -592,11 +778,16 @@ trait HasMath
      * is provided, each element is compared against that value.
      *
      * @param Complex|float|int|NDArray $other Array or scalar to compare with
+     * @param null|NDArray              $out   Existing array to write the result into
      *
-     * @return NDArray New array with element-wise maximum values
+     * @return NDArray New array with element-wise maximum values, or `$out` when given
      */
-    public function maximum(Complex|float|int|NDArray $other): NDArray
+    public function maximum(Complex|float|int|NDArray $other, ?NDArray $out = null): NDArray
     {
+        if (null !== $out) {
+            return $this->binaryOpInto(BinaryOp::Maximum, $other, $out);
+        }
+
         if ($other instanceof NDArray) {
             return $this->binaryOp('ndarray_maximum', $other);
         }
@@ -604,14 +795,6 @@ trait HasMath
         return $this->unaryOp('ndarray_maximum_scalar', (float) $other);
     }
 
-    /**
-     * Compute sigmoid element-wise: 1 / (1 + exp(-x)).
-     */
-    public function sigmoid(): NDArray
-    {
-        return $this->unaryOp('ndarray_sigmoid');
-    }
-
     /**
      * Compute softmax along axis: exp(x - max) / sum(exp(x - max)).
      *
@@ -623,4 +806,74 @@ trait HasMath
     {
         return $this->unaryOp('ndarray_softmax', $axis);
     }
+
+    /**
+     * Compute the n-th discrete difference along an axis: `a[i + 1] - a[i]`, applied `$n` times.
+     *
+     * The dtype is preserved (integer differences wrap on overflow; Bool arrays use
+     * `a[i + 1] != a[i]`). The axis shrinks by `$n`, down to zero.
+     *
+     * @param int $n    Number of times to take the difference (>= 0)
+     * @param int $axis Axis along which to take the difference
+     */
+    public function diff(int $n = 1, int $axis = -1): NDArray
+    {
+        if ($n < 0) {
+            throw new \InvalidArgumentException("diff order must be non-negative, got {$n}");
+        }
+
+        return $this->unaryOp('ndarray_diff', $n, $axis);
+    }
+
+    /**
+     * Compute the numerical gradient along an axis with uniform sample spacing.
+     *
+     * Interior points use central differences `(a[i + 1] - a[i - 1]) / (2 * spacing)`;
+     * the boundaries use one-sided differences of order `$edgeOrder`. Float32 input
+     * yields Float32, Float16/BFloat16 keep their dtype and other real dtypes yield Float64.
+     *
+     * @param int   $axis      Axis along which to differentiate
+     * @param float $spacing   Distance between neighbouring samples (non-zero)
+     * @param int   $edgeOrder Accuracy of the boundary differences: 1 or 2
+     */
+    public function gradient(int $axis = -1, float $spacing = 1.0, int $edgeOrder = 1): NDArray
+    {
+        if (1 !== $edgeOrder && 2 !== $edgeOrder) {
+            throw new \InvalidArgumentException("edgeOrder must be 1 or 2, got {$edgeOrder}");
+        }
+
+        return $this->unaryOp('ndarray_gradient', $axis, $spacing, $edgeOrder);
+    }
+
+    /**
+     * Linearly interpolate this array's values as query points on the samples `(xp, fp)`.
+     *
+     * Matches numpy.interp: `$xp` must be increasing, queries below `xp[0]` take `$left`
+     * (default `fp[0]`) and queries above `xp[-1]` take `$right` (default `fp[-1]`).
+     * Returns a Float64 array with this array's shape.
+     *
+     * @param array<mixed>|NDArray $xp    Sample x-coordinates (1D, increasing)
+     * @param array<mixed>|NDArray $fp    Sample values (1D, same length as `$xp`)
+     * @param null|float           $left  Value for queries below `xp[0]`
+     * @param null|float           $right Value for queries above `xp[-1]`
+     */
+    public function interp(array|NDArray $xp, array|NDArray $fp, ?float $left = null, ?float $right = null): NDArray
+    {
+        $xp = $xp instanceof NDArray ? $xp : NDArray::array($xp);
+        $fp = $fp instanceof NDArray ? $fp : NDArray::array($fp);
+        $xpMeta = $xp->meta()->toCData();
+        $fpMeta = $fp->meta()->toCData();
+
+        return $this->unaryOp(
+            'ndarray_interp',
+            $xp->handle(),
+            Lib::addr($xpMeta),
+            $fp->handle(),
+            Lib::addr($fpMeta),
+            null !== $left,
+            $left ?? 0.0,
+            null !== $right,
+            $right ?? 0.0,
+        );
+    }
 }
@@ -6,6 +6,7 @@ namespace PhpMlKit\NDArray\Traits;
 
 use FFI\CData;
 use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\BinaryOp;
 use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\NDArrayException;
@@ -74,11 +75,16 @@ trait HasOps
     /**
      * Call a binary FFI function with this array as LHS and another {@see NDArray} as RHS.
      *
-     * Signature: `(a_handle, a_meta, b_handle, b_meta, out_handle, out_dtype, out_ndim, out_shape, max_ndim)`.
+     * Signature: `(a_handle, a_meta, b_handle, b_meta, ...$extraArgs, out_handle, out_dtype, out_ndim, out_shape, max_ndim)`.
+     *
+     * @param string  $funcName     FFI symbol
+     * @param NDArray $other        Right-hand operand
+     * @param mixed   ...$extraArgs Inserted after the operand metadata
      */
     protected function binaryOp(
         string $funcName,
         NDArray $other,
+        mixed ...$extraArgs,
     ): NDArray {
         $lib = Lib::get();
         $outHandle = $lib->new('struct NdArrayHandle*');
@@ -93,6 +99,7 @@ trait HasOps
             Lib::addr($aMeta),
             $other->handle(),
             Lib::addr($bMeta),
+            ...$extraArgs,
             Lib::addr($outHandle),
             Lib::addr($outDtypeBuf),
             Lib::addr($outNdimBuf),
@@ -113,6 +120,40 @@ trait HasOps
         return new NDArray($outHandle, new ArrayMetadata($shape), $dtype);
     }
 
+    /**
+     * Run an element-wise binary operation into an existing array (NumPy `out=`).
+     *
+     * `$out` must have the broadcast shape of this array and `$other` and their promoted
+     * dtype; it may be a view and may alias either operand. Scalars are converted to
+     * `$out`'s dtype (complex scalars keep their own). No result array is allocated.
+     *
+     * @return NDArray `$out`
+     */
+    protected function binaryOpInto(BinaryOp $op, Complex|float|int|NDArray $other, NDArray $out): NDArray
+    {
+        $lib = Lib::get();
+        if (!$other instanceof NDArray) {
+            $other = NDArray::full($other, [], $other instanceof Complex ? DType::fromValue($other) : $out->dtype());
+        }
+
+        $aMeta = $this->meta()->toCData();
+        $bMeta = $other->meta()->toCData();
+        $outMeta = $out->meta()->toCData();
+        $status = $lib->ndarray_binary_op_out(
+            $op->value,
+            $this->handle,
+            Lib::addr($aMeta),
+            $other->handle(),
+            Lib::addr($bMeta),
+            $out->handle(),
+            Lib::addr($outMeta),
+        );
+
+        $lib->checkStatus($status);
+
+        return $out;
+    }
+
     /**
      * Run a reduction that returns one element (sum, mean, argmax, …) and decode it in PHP.
      *
@@ -158,7 +199,7 @@ trait HasOps
      *
      * @return array{0: CData, 1: DType}
      */
-    protected function scalarToBuffer(Complex|float|int $value): array
+    protected function scalarToBuffer(bool|Complex|float|int $value): array
     {
         $dtype = DType::fromValue($value);
         $lib = Lib::get();
@@ -194,6 +235,7 @@ trait HasOps
         return match ($dtype) {
             DType::Float64 => $lib->cast('double*', $base)[0],
             DType::Float32 => $lib->cast('float*', $base)[0],
+            DType::Float16, DType::BFloat16 => $dtype->fromHalfBits($lib->cast('uint16_t*', $base)[0]),
             DType::Int64, DType::Int32, DType::Int16, DType::Int8 => $lib->cast('int64_t*', $base)[0],
             DType::UInt64, DType::UInt32, DType::UInt16, DType::UInt8 => $lib->cast('uint64_t*', $base)[0],
             DType::Bool => (bool) $lib->cast('uint8_t*', $base)[0],
@@ -0,0 +1,159 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use FFI\CData;
+use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * Feature preprocessing: fused standardization, scaling and normalization.
+ *
+ * Each operation runs in a single native pass per lane instead of chaining reductions and
+ * broadcast arithmetic. Float32 input yields Float32; other real dtypes yield Float64.
+ */
+trait HasPreprocessing
+{
+    /**
+     * Standardize along an axis: `(x - mean) / (std + epsilon)`.
+     *
+     * Statistics that are not supplied are computed per lane in one pass. Supplied `$mean` /
+     * `$std` arrays (for example, from a training set) must have this array's shape with `$axis`
+     * removed or kept as length 1, or hold a single value.
+     *
+     * @param int          $axis    Axis along which statistics are taken (default 0: per column)
+     * @param null|NDArray $mean    Precomputed means, or null to compute them
+     * @param null|NDArray $std     Precomputed standard deviations, or null to compute them
+     * @param int          $ddof    Delta degrees of freedom for computed standard deviations
+     * @param float        $epsilon Added to the standard deviation to keep constant lanes finite
+     * @param bool         $inPlace Overwrite this float array instead of returning a new one
+     *
+     * @return NDArray The standardized array ($this when `$inPlace` is true)
+     */
+    public function standardize(
+        int $axis = 0,
+        ?NDArray $mean = null,
+        ?NDArray $std = null,
+        int $ddof = 0,
+        float $epsilon = 0.0,
+        bool $inPlace = false,
+    ): NDArray {
+        if ($ddof < 0) {
+            throw new \InvalidArgumentException('ddof must be >= 0');
+        }
+        if ($epsilon < 0.0) {
+            throw new \InvalidArgumentException('epsilon must be >= 0');
+        }
+
+        $meanMeta = $mean?->meta()->toCData();
+        $stdMeta = $std?->meta()->toCData();
+        $params = [
+            $axis,
+            $mean?->handle(),
+            self::optionalAddr($meanMeta),
+            $std?->handle(),
+            self::optionalAddr($stdMeta),
+            $ddof,
+            $epsilon,
+        ];
+
+        if ($inPlace) {
+            $lib = Lib::get();
+            $meta = $this->meta()->toCData();
+            $lib->checkStatus($lib->ndarray_standardize_inplace($this->handle, Lib::addr($meta), ...$params));
+
+            return $this;
+        }
+
+        return $this->unaryOp('ndarray_standardize', ...$params);
+    }
+
+    /**
+     * Scale each lane along an axis linearly so its range maps to `[$min, $max]`.
+     *
+     * NaN values are ignored when finding the range and stay NaN. Constant lanes map to `$min`.
+     *
+     * @param int   $axis Axis along which ranges are taken (default 0: per column)
+     * @param float $min  Lower bound of the target range
+     * @param float $max  Upper bound of the target range
+     */
+    public function minMaxScale(int $axis = 0, float $min = 0.0, float $max = 1.0): NDArray
+    {
+        if ($min >= $max) {
+            throw new \InvalidArgumentException('min must be less than max');
+        }
+
+        return $this->unaryOp('ndarray_minmax_scale', $axis, $min, $max, null, null);
+    }
+
+    /**
+     * Min-max scale and also return the fitted parameters for inverse-transforming.
+     *
+     * `$dataMin` and `$scale` have this array's shape with `$axis` removed. The original values
+     * are recovered with `($scaled - $min) / $scale + $dataMin` (expand the parameters along
+     * `$axis` for broadcasting when `$axis` is not the first axis).
+     *
+     * @param int   $axis Axis along which ranges are taken (default 0: per column)
+     * @param float $min  Lower bound of the target range
+     * @param float $max  Upper bound of the target range
+     *
+     * @return array{0: NDArray, 1: NDArray, 2: NDArray} [scaled, dataMin, scale]
+     */
+    public function minMaxScaleWithParams(int $axis = 0, float $min = 0.0, float $max = 1.0): array
+    {
+        if ($min >= $max) {
+            throw new \InvalidArgumentException('min must be less than max');
+        }
+
+        $lib = Lib::get();
+        $dataMinHandle = $lib->new('struct NdArrayHandle*');
+        $scaleHandle = $lib->new('struct NdArrayHandle*');
+
+        $scaled = $this->unaryOp(
+            'ndarray_minmax_scale',
+            $axis,
+            $min,
+            $max,
+            Lib::addr($dataMinHandle),
+            Lib::addr($scaleHandle)
+        );
+
+        $paramShape = $this->shape();
+        array_splice($paramShape, self::normalizeAxis($axis, \count($paramShape)), 1);
+
+        return [
+            $scaled,
+            new NDArray($dataMinHandle, new ArrayMetadata($paramShape), $scaled->dtype()),
+            new NDArray($scaleHandle, new ArrayMetadata($paramShape), $scaled->dtype()),
+        ];
+    }
+
+    /**
+     * Divide each lane along an axis by its L2 norm: `x / max(||x||, epsilon)`.
+     *
+     * All-zero lanes stay zero. Use `axis: -1` (the default) to normalize the rows of an
+     * embedding matrix before cosine-similarity search.
+     *
+     * @param int   $axis    Axis along which norms are taken (default -1: per row)
+     * @param float $epsilon Lower bound on the norm to avoid division by zero
+     */
+    public function l2Normalize(int $axis = -1, float $epsilon = 1e-12): NDArray
+    {
+        if ($epsilon < 0.0) {
+            throw new \InvalidArgumentException('epsilon must be >= 0');
+        }
+
+        return $this->unaryOp('ndarray_l2_normalize', $axis, $epsilon);
+    }
+
+    /**
+     * Address of an optional metadata struct, or null when absent.
+     */
+    private static function optionalAddr(?CData $meta): ?CData
+    {
+        return null !== $meta ? Lib::addr($meta) : null;
+    }
+}
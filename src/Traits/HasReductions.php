@@ -9,7 +9,9 @@ use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\QuantileMethod;
 use PhpMlKit\NDArray\SortKind;
+use PhpMlKit\NDArray\SumMode;
 
 /**
  * Reduction and aggregation operations trait for NDArray.
@@ -24,16 +26,17 @@ trait HasReductions
      *
      * @param null|int $axis     Axis along which to sum. If null, sum over all elements.
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return ($axis is null ? Complex|float|int : NDArray)
      */
-    public function sum(?int $axis = null, bool $keepdims = false): Complex|float|int|NDArray
+    public function sum(?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|int|NDArray
     {
         if (null === $axis) {
-            return $this->scalarReductionOp('ndarray_sum');
+            return $this->scalarReductionOp('ndarray_sum', $mode->value);
         }
 
-        return $this->unaryOp('ndarray_sum_axis', $axis, $keepdims);
+        return $this->unaryOp('ndarray_sum_axis', $axis, $keepdims, $mode);
     }
 
     /**
@@ -41,16 +44,17 @@ trait HasReductions
      *
      * @param null|int $axis     Axis along which to compute mean. If null, compute mean of all elements.
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return ($axis is null ? Complex|float : NDArray)
      */
-    public function mean(?int $axis = null, bool $keepdims = false): Complex|float|NDArray
+    public function mean(?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|NDArray
     {
         if (null === $axis) {
-            return $this->scalarReductionOp('ndarray_mean');
+            return $this->scalarReductionOp('ndarray_mean', $mode->value);
         }
 
-        return $this->unaryOp('ndarray_mean_axis', $axis, $keepdims);
+        return $this->unaryOp('ndarray_mean_axis', $axis, $keepdims, $mode);
     }
 
     /**
@@ -124,33 +128,45 @@ trait HasReductions
     /**
      * Return a sorted copy of the array.
      *
-     * @param null|int $axis Axis along which to sort. If null, sort flattened data.
-     * @param SortKind $kind sorting algorithm
+     * @param null|int $axis       Axis along which to sort. If null, sort flattened data.
+     * @param SortKind $kind       sorting algorithm
+     * @param bool     $descending If true, sort largest first
+     * @param bool     $nanFirst   If true, place NaN at the start instead of the end
      */
-    public function sort(?int $axis = -1, SortKind $kind = SortKind::QuickSort): NDArray
-    {
+    public function sort(
+        ?int $axis = -1,
+        SortKind $kind = SortKind::QuickSort,
+        bool $descending = false,
+        bool $nanFirst = false
+    ): NDArray {
         if (null === $axis) {
-            return $this->unaryOp('ndarray_sort_flat', $kind);
+            return $this->unaryOp('ndarray_sort_flat', $kind, $descending, $nanFirst);
         }
 
-        return $this->unaryOp('ndarray_sort_axis', $axis, $kind);
+        return $this->unaryOp('ndarray_sort_axis', $axis, $kind, $descending, $nanFirst);
     }
 
     /**
      * Return indices that would sort the array.
      *
-     * @param null|int $axis Axis along which to argsort. If null, argsort flattened data.
-     * @param SortKind $kind sorting algorithm
+     * @param null|int $axis       Axis along which to argsort. If null, argsort flattened data.
+     * @param SortKind $kind       sorting algorithm
+     * @param bool     $descending If true, order indices largest value first
+     * @param bool     $nanFirst   If true, place NaN at the start instead of the end
      *
      * @return NDArray int64 indices array
      */
-    public function argsort(?int $axis = -1, SortKind $kind = SortKind::QuickSort): NDArray
-    {
+    public function argsort(
+        ?int $axis = -1,
+        SortKind $kind = SortKind::QuickSort,
+        bool $descending = false,
+        bool $nanFirst = false
+    ): NDArray {
         if (null === $axis) {
-            return $this->unaryOp('ndarray_argsort_flat', $kind);
+            return $this->unaryOp('ndarray_argsort_flat', $kind, $descending, $nanFirst);
         }
 
-        return $this->unaryOp('ndarray_argsort_axis', $axis, $kind);
+        return $this->unaryOp('ndarray_argsort_axis', $axis, $kind, $descending, $nanFirst);
     }
 
     /**
@@ -159,8 +175,9 @@ trait HasReductions
      * @param int      $k       Number of elements to select
      * @param null|int $axis    Axis along which to select. If null, flatten first.
      * @param bool     $largest If true, select largest values; otherwise smallest values
-     * @param bool     $sorted  If true, keep selected values sorted by rank
-     * @param SortKind $kind    Sorting algorithm
+     * @param bool     $sorted  If true, order results by rank; if false, keep them in original
+     *                          index order using O(n) selection, which is faster for large inputs
+     * @param SortKind $kind    Sorting algorithm (used only when $sorted is true)
      *
      * @return array{0: NDArray, 1: NDArray} [values, indices]
      */
@@ -182,6 +199,33 @@ trait HasReductions
         return $this->topkAxisOp($k, $axis, $largest, $sorted, $kind);
     }
 
+    /**
+     * Return a partitioned copy of the array.
+     *
+     * Each kth element ends up where a full sort would put it, with smaller
+     * elements before it and larger elements after, in unspecified order.
+     *
+     * @param array<int>|int $kth  Position or positions to place; negative values count from the end
+     * @param null|int       $axis Axis along which to partition. If null, partition flattened data.
+     */
+    public function partition(array|int $kth, ?int $axis = -1): NDArray
+    {
+        return $this->partitionOp('ndarray_partition', $kth, $axis);
+    }
+
+    /**
+     * Return indices that would partition the array.
+     *
+     * @param array<int>|int $kth  Position or positions to place; negative values count from the end
+     * @param null|int       $axis Axis along which to partition. If null, partition flattened data.
+     *
+     * @return NDArray int64 indices array
+     */
+    public function argpartition(array|int $kth, ?int $axis = -1): NDArray
+    {
+        return $this->partitionOp('ndarray_argpartition', $kth, $axis);
+    }
+
     /**
      * Product of array elements over a given axis.
      *
@@ -233,16 +277,17 @@ trait HasReductions
      * @param null|int $axis     Axis along which to compute variance. If null, compute variance of all elements.
      * @param int      $ddof     delta degrees of freedom (0 for population, 1 for sample)
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
      *
      * @return ($axis is null ? float : NDArray)
      */
-    public function var(?int $axis = null, int $ddof = 0, bool $keepdims = false): float|NDArray
+    public function var(?int $axis = null, int $ddof = 0, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): float|NDArray
     {
         if (null === $axis) {
-            return $this->scalarReductionOp('ndarray_var', $ddof);
+            return $this->scalarReductionOp('ndarray_var', $ddof, $mode->value);
         }
 
-        return $this->unaryOp('ndarray_var_axis', $axis, $keepdims, $ddof);
+        return $this->unaryOp('ndarray_var_axis', $axis, $keepdims, $ddof, $mode);
     }
 
     /**
@@ -251,16 +296,306 @@ trait HasReductions
      * @param null|int $axis     Axis along which to compute std. If null, compute std of all elements.
      * @param int      $ddof     delta degrees of freedom (0 for population, 1 for sample)
      * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
+     *
+     * @return ($axis is null ? float : NDArray)
+     */
+    public function std(?int $axis = null, int $ddof = 0, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): float|NDArray
+    {
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_std', $ddof, $mode->value);
+        }
+
+        return $this->unaryOp('ndarray_std_axis', $axis, $keepdims, $ddof, $mode);
+    }
+
+    /**
+     * Sum of array elements over a given axis, treating NaN as zero.
+     *
+     * Non-float arrays cannot hold NaN and fall back to sum().
+     *
+     * @param null|int $axis     Axis along which to sum. If null, sum over all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
+     *
+     * @return ($axis is null ? Complex|float|int : NDArray)
+     */
+    public function nansum(?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|int|NDArray
+    {
+        if (!$this->dtype->isFloat()) {
+            return $this->sum($axis, $keepdims, $mode);
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_nansum', $mode->value);
+        }
+
+        return $this->unaryOp('ndarray_nansum_axis', $axis, $keepdims, $mode);
+    }
+
+    /**
+     * Mean of the non-NaN elements over a given axis.
+     *
+     * All-NaN inputs (or lanes) reduce to NaN. Non-float arrays fall back to mean().
+     *
+     * @param null|int $axis     Axis along which to compute mean. If null, compute mean of all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
+     *
+     * @return ($axis is null ? Complex|float : NDArray)
+     */
+    public function nanmean(?int $axis = null, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): Complex|float|NDArray
+    {
+        if (!$this->dtype->isFloat()) {
+            return $this->mean($axis, $keepdims, $mode);
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_nanmean', $mode->value);
+        }
+
+        return $this->unaryOp('ndarray_nanmean_axis', $axis, $keepdims, $mode);
+    }
+
+    /**
+     * Minimum of the non-NaN elements over a given axis.
+     *
+     * All-NaN inputs (or lanes) reduce to NaN. Non-float arrays fall back to min().
+     *
+     * @param null|int $axis     Axis along which to find minimum. If null, find minimum of all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return ($axis is null ? Complex|float|int : NDArray)
+     */
+    public function nanmin(?int $axis = null, bool $keepdims = false): Complex|float|int|NDArray
+    {
+        if (!$this->dtype->isFloat()) {
+            return $this->min($axis, $keepdims);
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_nanmin');
+        }
+
+        return $this->unaryOp('ndarray_nanmin_axis', $axis, $keepdims);
+    }
+
+    /**
+     * Maximum of the non-NaN elements over a given axis.
+     *
+     * All-NaN inputs (or lanes) reduce to NaN. Non-float arrays fall back to max().
+     *
+     * @param null|int $axis     Axis along which to find maximum. If null, find maximum of all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return ($axis is null ? Complex|float|int : NDArray)
+     */
+    public function nanmax(?int $axis = null, bool $keepdims = false): Complex|float|int|NDArray
+    {
+        if (!$this->dtype->isFloat()) {
+            return $this->max($axis, $keepdims);
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_nanmax');
+        }
+
+        return $this->unaryOp('ndarray_nanmax_axis', $axis, $keepdims);
+    }
+
+    /**
+     * Standard deviation of the non-NaN elements over a given axis.
+     *
+     * Reduces to NaN when no more than `$ddof` non-NaN values remain. Non-float
+     * arrays fall back to std().
+     *
+     * @param null|int $axis     Axis along which to compute std. If null, compute std of all elements.
+     * @param int      $ddof     delta degrees of freedom (0 for population, 1 for sample)
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     * @param SumMode  $mode     float summation strategy (pairwise by default, or Kahan)
+     *
+     * @return ($axis is null ? float : NDArray)
+     */
+    public function nanstd(?int $axis = null, int $ddof = 0, bool $keepdims = false, SumMode $mode = SumMode::Pairwise): float|NDArray
+    {
+        if (!$this->dtype->isFloat()) {
+            return $this->std($axis, $ddof, $keepdims, $mode);
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_nanstd', $ddof, $mode->value);
+        }
+
+        return $this->unaryOp('ndarray_nanstd_axis', $axis, $keepdims, $ddof, $mode);
+    }
+
+    /**
+     * Median of array elements over a given axis.
+     *
+     * Integer and bool arrays produce Float64; float arrays keep their dtype. A lane
+     * containing NaN reduces to NaN.
+     *
+     * @param null|int $axis     Axis along which to compute the median. If null, use all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
      *
      * @return ($axis is null ? float : NDArray)
      */
-    public function std(?int $axis = null, int $ddof = 0, bool $keepdims = false): float|NDArray
+    public function median(?int $axis = null, bool $keepdims = false): float|NDArray
     {
         if (null === $axis) {
-            return $this->scalarReductionOp('ndarray_std', $ddof);
+            return $this->scalarReductionOp('ndarray_median');
+        }
+
+        return $this->unaryOp('ndarray_median_axis', $axis, $keepdims);
+    }
+
+    /**
+     * Quantile of array elements over a given axis.
+     *
+     * @param float          $q        quantile to compute, in [0, 1]
+     * @param null|int       $axis     Axis along which to compute the quantile. If null, use all elements.
+     * @param bool           $keepdims if true, the reduced axis is retained with size 1
+     * @param QuantileMethod $method   how to resolve a position between two data points
+     *
+     * @return ($axis is null ? float : NDArray)
+     */
+    public function quantile(
+        float $q,
+        ?int $axis = null,
+        bool $keepdims = false,
+        QuantileMethod $method = QuantileMethod::Linear,
+    ): float|NDArray {
+        if ($q < 0.0 || $q > 1.0) {
+            throw new \InvalidArgumentException('q must be in the range [0, 1]');
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_quantile', $q, $method->value);
+        }
+
+        return $this->unaryOp('ndarray_quantile_axis', $axis, $keepdims, $q, $method);
+    }
+
+    /**
+     * Percentile of array elements over a given axis.
+     *
+     * Same as quantile() with $p / 100.
+     *
+     * @param float          $p        percentile to compute, in [0, 100]
+     * @param null|int       $axis     Axis along which to compute the percentile. If null, use all elements.
+     * @param bool           $keepdims if true, the reduced axis is retained with size 1
+     * @param QuantileMethod $method   how to resolve a position between two data points
+     *
+     * @return ($axis is null ? float : NDArray)
+     */
+    public function percentile(
+        float $p,
+        ?int $axis = null,
+        bool $keepdims = false,
+        QuantileMethod $method = QuantileMethod::Linear,
+    ): float|NDArray {
+        if ($p < 0.0 || $p > 100.0) {
+            throw new \InvalidArgumentException('p must be in the range [0, 100]');
+        }
+
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_percentile', $p, $method->value);
         }
 
-        return $this->unaryOp('ndarray_std_axis', $axis, $keepdims, $ddof);
+        return $this->unaryOp('ndarray_percentile_axis', $axis, $keepdims, $p, $method);
+    }
+
+    /**
+     * Log of the sum of exponentials over a given axis, `log(sum(exp(x)))`.
+     *
+     * The maximum is factored out before exponentiating, so large values do not overflow.
+     * Integer and bool arrays produce Float64; float arrays keep their dtype.
+     *
+     * @param null|int $axis     Axis along which to reduce. If null, use all elements.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return ($axis is null ? float : NDArray)
+     */
+    public function logsumexp(?int $axis = null, bool $keepdims = false): float|NDArray
+    {
+        if (null === $axis) {
+            return $this->scalarReductionOp('ndarray_logsumexp');
+        }
+
+        return $this->unaryOp('ndarray_logsumexp_axis', $axis, $keepdims);
+    }
+
+    /**
+     * Integrate along an axis with the trapezoidal rule.
+     *
+     * Samples are spaced by `$dx`, or by the coordinates `$x` when given (1D with the
+     * length of `$axis`, or with this array's shape). Float32 input yields Float32 and
+     * integer input yields Float64.
+     *
+     * @param null|array<mixed>|NDArray $x    Sample coordinates; overrides `$dx`
+     * @param float                     $dx   Uniform spacing when `$x` is null
+     * @param int                       $axis Axis along which to integrate
+     *
+     * @return float|NDArray Scalar for 1D input, otherwise the array with `$axis` removed
+     */
+    public function trapz(array|NDArray|null $x = null, float $dx = 1.0, int $axis = -1): float|NDArray
+    {
+        $result = null === $x
+            ? $this->unaryOp('ndarray_trapz', null, null, $dx, $axis)
+            : $this->binaryOp('ndarray_trapz', $x instanceof NDArray ? $x : NDArray::array($x), $dx, $axis);
+
+        return 0 === $result->ndim() ? (float) $result->toScalar() : $result;
+    }
+
+    /**
+     * Cumulative trapezoidal integral along an axis.
+     *
+     * Spacing works as in trapz(). The result keeps this array's shape with `$axis`
+     * shortened by one, or at full length starting with 0 when `$includeInitial` is set.
+     *
+     * @param null|array<mixed>|NDArray $x              Sample coordinates; overrides `$dx`
+     * @param float                     $dx             Uniform spacing when `$x` is null
+     * @param int                       $axis           Axis along which to integrate
+     * @param bool                      $includeInitial Prepend a 0 so the output matches the input length
+     */
+    public function cumtrapz(
+        array|NDArray|null $x = null,
+        float $dx = 1.0,
+        int $axis = -1,
+        bool $includeInitial = false,
+    ): NDArray {
+        if (null === $x) {
+            return $this->unaryOp('ndarray_cumtrapz', null, null, $dx, $axis, $includeInitial);
+        }
+
+        return $this->binaryOp(
+            'ndarray_cumtrapz',
+            $x instanceof NDArray ? $x : NDArray::array($x),
+            $dx,
+            $axis,
+            $includeInitial
+        );
+    }
+
+    /**
+     * Enable or disable deterministic float reductions for the whole process.
+     *
+     * When enabled, sum/mean/var/std visit elements in logical C order with a reduction
+     * tree fixed by the element count, so repeated runs on equal data are bit-identical
+     * regardless of memory layout or thread count.
+     */
+    public static function setDeterministic(bool $enabled = true): void
+    {
+        $lib = Lib::get();
+        $lib->checkStatus($lib->ndarray_set_deterministic($enabled));
+    }
+
+    /**
+     * Whether deterministic float reductions are enabled.
+     */
+    public static function isDeterministic(): bool
+    {
+        return Lib::get()->ndarray_is_deterministic();
     }
 
     /**
@@ -303,6 +638,25 @@ trait HasReductions
         return $this->unaryOp('ndarray_all_axis', $axis, $keepdims);
     }
 
+    /**
+     * Count the non-zero elements over a given axis.
+     *
+     * NaN counts as non-zero; complex numbers count if either part is non-zero.
+     *
+     * @param null|int $axis     Axis along which to count. If null, counts the entire array.
+     * @param bool     $keepdims if true, the reduced axis is retained with size 1
+     *
+     * @return ($axis is null ? int : NDArray)
+     */
+    public function countNonzero(?int $axis = null, bool $keepdims = false): int|NDArray
+    {
+        if (null === $axis) {
+            return (int) $this->scalarReductionOp('ndarray_count_nonzero');
+        }
+
+        return $this->unaryOp('ndarray_count_nonzero_axis', $axis, $keepdims);
+    }
+
     /**
      * Count occurrences of non-negative integer values in flattened input.
      *
@@ -320,6 +674,436 @@ trait HasReductions
         return $this->unaryOp('ndarray_bincount', $minlength);
     }
 
+    /**
+     * Compute the histogram of the flattened array.
+     *
+     * Bins are half-open `[e[i], e[i+1])` except the last, which includes its right edge.
+     * Values outside the edges and NaN are not counted.
+     *
+     * @param array<float|int>|int|NDArray         $bins    number of equal-width bins, or monotonically increasing bin edges
+     * @param null|array{0: float|int, 1: float|int} $range   [min, max] spanned by equal-width bins; defaults to the data extent
+     * @param null|array<float|int>|NDArray        $weights one weight per element; counts become Float64 weight sums
+     *
+     * @return array{0: NDArray, 1: NDArray} [counts, edges] with Int64 (or Float64 when weighted) counts and Float64 edges
+     */
+    public function histogram(array|int|NDArray $bins = 10, ?array $range = null, null|array|NDArray $weights = null): array
+    {
+        [$count, $edges] = self::histogramBins($bins);
+        $weights = $this->histogramWeights($weights);
+
+        $lib = Lib::get();
+        $outCounts = $lib->new('struct NdArrayHandle*');
+        $outEdges = $lib->new('struct NdArrayHandle*');
+
+        $meta = $this->meta()->toCData();
+        $edgesMeta = $edges?->meta()->toCData();
+        $weightsMeta = $weights?->meta()->toCData();
+        $status = $lib->ndarray_histogram(
+            $this->handle,
+            Lib::addr($meta),
+            $count,
+            null !== $range ? $lib->createCArray('double', [(float) $range[0], (float) $range[1]]) : null,
+            $edges?->handle(),
+            null !== $edgesMeta ? Lib::addr($edgesMeta) : null,
+            $weights?->handle(),
+            null !== $weightsMeta ? Lib::addr($weightsMeta) : null,
+            Lib::addr($outCounts),
+            Lib::addr($outEdges)
+        );
+
+        $lib->checkStatus($status);
+
+        $countsDtype = null !== $weights ? DType::Float64 : DType::Int64;
+
+        return [
+            new NDArray($outCounts, new ArrayMetadata([$count]), $countsDtype),
+            new NDArray($outEdges, new ArrayMetadata([$count + 1]), DType::Float64),
+        ];
+    }
+
+    /**
+     * Compute the two-dimensional histogram of this array (x) against $y.
+     *
+     * Both arrays are flattened and must have the same number of elements. Each
+     * dimension is binned as in histogram().
+     *
+     * @param array<float|int>|NDArray                                            $y       y coordinates, one per element of this array
+     * @param array{0: array<float|int>|int|NDArray, 1: array<float|int>|int|NDArray}|int $bins    bin count for both dimensions, or a [x, y] pair of bin counts or edges
+     * @param null|array{0: array{0: float|int, 1: float|int}, 1: array{0: float|int, 1: float|int}} $range   [[xmin, xmax], [ymin, ymax]]; defaults to the data extent
+     * @param null|array<float|int>|NDArray                                       $weights one weight per sample; counts become Float64 weight sums
+     *
+     * @return array{0: NDArray, 1: NDArray, 2: NDArray} [counts, xEdges, yEdges] with counts of shape [nx, ny]
+     */
+    public function histogram2d(
+        array|NDArray $y,
+        array|int $bins = 10,
+        ?array $range = null,
+        null|array|NDArray $weights = null,
+    ): array {
+        $y = $y instanceof NDArray ? $y : NDArray::array($y);
+        [$xBins, $yBins] = \is_int($bins) ? [$bins, $bins] : $bins;
+        [$xCount, $xEdges] = self::histogramBins($xBins);
+        [$yCount, $yEdges] = self::histogramBins($yBins);
+        $weights = $this->histogramWeights($weights);
+
+        $lib = Lib::get();
+        $outCounts = $lib->new('struct NdArrayHandle*');
+        $outXEdges = $lib->new('struct NdArrayHandle*');
+        $outYEdges = $lib->new('struct NdArrayHandle*');
+
+        $meta = $this->meta()->toCData();
+        $yMeta = $y->meta()->toCData();
+        $xEdgesMeta = $xEdges?->meta()->toCData();
+        $yEdgesMeta = $yEdges?->meta()->toCData();
+        $weightsMeta = $weights?->meta()->toCData();
+        $rangeBuf = null !== $range
+            ? $lib->createCArray('double', [(float) $range[0][0], (float) $range[0][1], (float) $range[1][0], (float) $range[1][1]])
+            : null;
+        $status = $lib->ndarray_histogram2d(
+            $this->handle,
+            Lib::addr($meta),
+            $y->handle(),
+            Lib::addr($yMeta),
+            $xCount,
+            $yCount,
+            $rangeBuf,
+            $xEdges?->handle(),
+            null !== $xEdgesMeta ? Lib::addr($xEdgesMeta) : null,
+            $yEdges?->handle(),
+            null !== $yEdgesMeta ? Lib::addr($yEdgesMeta) : null,
+            $weights?->handle(),
+            null !== $weightsMeta ? Lib::addr($weightsMeta) : null,
+            Lib::addr($outCounts),
+            Lib::addr($outXEdges),
+            Lib::addr($outYEdges)
+        );
+
+        $lib->checkStatus($status);
+
+        $countsDtype = null !== $weights ? DType::Float64 : DType::Int64;
+
+        return [
+            new NDArray($outCounts, new ArrayMetadata([$xCount, $yCount]), $countsDtype),
+            new NDArray($outXEdges, new ArrayMetadata([$xCount + 1]), DType::Float64),
+            new NDArray($outYEdges, new ArrayMetadata([$yCount + 1]), DType::Float64),
+        ];
+    }
+
+    /**
+     * Rolling sum over a sliding window along an axis.
+     *
+     * Output has the input's shape. Each position aggregates the `$window` elements ending at it
+     * (or centred on it with `$center`); NaN values are skipped. Positions with fewer than
+     * `$minPeriods` valid values are NaN.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function rollingSum(int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $this->rollingOp('ndarray_rolling_sum', $window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling mean over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function rollingMean(int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $this->rollingOp('ndarray_rolling_mean', $window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling minimum over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function rollingMin(int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $this->rollingOp('ndarray_rolling_min', $window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling maximum over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function rollingMax(int $window, int $axis = -1, ?int $minPeriods = null, bool $center = false): NDArray
+    {
+        return $this->rollingOp('ndarray_rolling_max', $window, $axis, $minPeriods, $center);
+    }
+
+    /**
+     * Rolling standard deviation over a sliding window along an axis.
+     *
+     * @param int      $window     Window size (>= 1)
+     * @param int      $axis       Axis to roll along
+     * @param null|int $minPeriods Minimum number of valid values per window. Default: `$window`.
+     * @param bool     $center     if true, centre each window on its output position
+     * @param int      $ddof       delta degrees of freedom (0 for population, 1 for sample)
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function rollingStd(
+        int $window,
+        int $axis = -1,
+        ?int $minPeriods = null,
+        bool $center = false,
+        int $ddof = 0,
+    ): NDArray {
+        if ($ddof < 0) {
+            throw new \InvalidArgumentException('ddof must be >= 0');
+        }
+
+        return $this->rollingOp('ndarray_rolling_std', $window, $axis, $minPeriods, $center, $ddof);
+    }
+
+    /**
+     * Sum rows along axis 0 grouped by segment id.
+     *
+     * Row `i` is added into output row `$segmentIds[i]`, so the result has shape
+     * `[numSegments, ...rest]`. Empty segments are 0.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function segmentSum(array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $this->segmentOp('ndarray_segment_sum', $segmentIds, $numSegments);
+    }
+
+    /**
+     * Mean of rows along axis 0 grouped by segment id. Empty segments are NaN.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function segmentMean(array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $this->segmentOp('ndarray_segment_mean', $segmentIds, $numSegments);
+    }
+
+    /**
+     * Minimum of rows along axis 0 grouped by segment id. Empty segments are NaN.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function segmentMin(array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $this->segmentOp('ndarray_segment_min', $segmentIds, $numSegments);
+    }
+
+    /**
+     * Maximum of rows along axis 0 grouped by segment id. Empty segments are NaN.
+     *
+     * @param array<int>|NDArray $segmentIds  1D integer ids, one per row
+     * @param null|int           $numSegments Number of output rows. Default: `max($segmentIds) + 1`.
+     *
+     * @return NDArray Float64 (Float32 for Float32 input)
+     */
+    public function segmentMax(array|NDArray $segmentIds, ?int $numSegments = null): NDArray
+    {
+        return $this->segmentOp('ndarray_segment_max', $segmentIds, $numSegments);
+    }
+
+    /**
+     * Group rows of $values by $keys and aggregate each group in a single pass.
+     *
+     * Groups come back in ascending key order (NaN keys last). `keys` keeps the key dtype,
+     * `count` is Int64, and the aggregates have shape `[groups, ...rest]` as Float64
+     * (Float32 for Float32 values).
+     *
+     * @param array<bool|float|int>|NDArray $keys   1D key column
+     * @param NDArray                       $values values with one row per key
+     *
+     * @return array{keys: NDArray, count: NDArray, sum: NDArray, mean: NDArray, min: NDArray, max: NDArray}
+     */
+    public static function groupBy(array|NDArray $keys, NDArray $values): array
+    {
+        $keys = $keys instanceof NDArray ? $keys : NDArray::array($keys);
+
+        $lib = Lib::get();
+        $handles = [];
+        foreach (['keys', 'count', 'sum', 'mean', 'min', 'max'] as $name) {
+            $handles[$name] = $lib->new('struct NdArrayHandle*');
+        }
+        $numGroups = $lib->new('size_t');
+
+        $keysMeta = $keys->meta()->toCData();
+        $valuesMeta = $values->meta()->toCData();
+        $status = $lib->ndarray_group_by(
+            $keys->handle(),
+            Lib::addr($keysMeta),
+            $values->handle(),
+            Lib::addr($valuesMeta),
+            Lib::addr($handles['keys']),
+            Lib::addr($handles['count']),
+            Lib::addr($handles['sum']),
+            Lib::addr($handles['mean']),
+            Lib::addr($handles['min']),
+            Lib::addr($handles['max']),
+            Lib::addr($numGroups)
+        );
+
+        $lib->checkStatus($status);
+
+        $groups = (int) $numGroups->cdata;
+        $aggShape = $values->shape();
+        $aggShape[0] = $groups;
+        $aggDtype = DType::Float32 === $values->dtype() ? DType::Float32 : DType::Float64;
+
+        $result = [
+            'keys' => new NDArray($handles['keys'], new ArrayMetadata([$groups]), $keys->dtype()),
+            'count' => new NDArray($handles['count'], new ArrayMetadata([$groups]), DType::Int64),
+        ];
+        foreach (['sum', 'mean', 'min', 'max'] as $name) {
+            $result[$name] = new NDArray($handles[$name], new ArrayMetadata($aggShape), $aggDtype);
+        }
+
+        return $result;
+    }
+
+    /**
+     * Split a histogram bins argument into a bin count and optional explicit edges.
+     *
+     * @param array<float|int>|int|NDArray $bins
+     *
+     * @return array{0: int, 1: null|NDArray}
+     */
+    private static function histogramBins(array|int|NDArray $bins): array
+    {
+        if (\is_int($bins)) {
+            if ($bins < 1) {
+                throw new \InvalidArgumentException('bins must be >= 1');
+            }
+
+            return [$bins, null];
+        }
+
+        $edges = $bins instanceof NDArray ? $bins : NDArray::array($bins, DType::Float64);
+        if ($edges->size() < 2) {
+            throw new \InvalidArgumentException('At least two bin edges are required');
+        }
+
+        return [$edges->size() - 1, $edges];
+    }
+
+    /**
+     * @param null|array<float|int>|NDArray $weights
+     */
+    private function histogramWeights(null|array|NDArray $weights): ?NDArray
+    {
+        if (null === $weights || $weights instanceof NDArray) {
+            return $weights;
+        }
+
+        return NDArray::array($weights, DType::Float64);
+    }
+
+    /**
+     * Shared call path for the segment reduction FFI functions.
+     *
+     * @param array<int>|NDArray $segmentIds
+     */
+    private function segmentOp(string $funcName, array|NDArray $segmentIds, ?int $numSegments): NDArray
+    {
+        if (null !== $numSegments && $numSegments < 0) {
+            throw new \InvalidArgumentException('numSegments must be >= 0');
+        }
+        $segmentIds = $segmentIds instanceof NDArray ? $segmentIds : NDArray::array($segmentIds, DType::Int64);
+
+        $lib = Lib::get();
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->new(\sprintf('size_t[%d]', Lib::MAX_NDIM));
+
+        $meta = $this->meta()->toCData();
+        $idsMeta = $segmentIds->meta()->toCData();
+        $status = $lib->{$funcName}(
+            $this->handle,
+            Lib::addr($meta),
+            $segmentIds->handle(),
+            Lib::addr($idsMeta),
+            $numSegments ?? -1,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM
+        );
+
+        $lib->checkStatus($status);
+
+        $dtype = DType::from((int) $outDtypeBuf->cdata);
+        $ndim = (int) $outNdimBuf->cdata;
+        $outShape = $lib->readSizeTArray($outShapeBuf, $ndim);
+
+        return new NDArray($outHandle, new ArrayMetadata($outShape), $dtype);
+    }
+
+    /**
+     * Shared argument validation for the rolling-window FFI functions.
+     */
+    private function rollingOp(string $funcName, int $window, int $axis, ?int $minPeriods, bool $center, mixed ...$extra): NDArray
+    {
+        if ($window < 1) {
+            throw new \InvalidArgumentException('window must be >= 1');
+        }
+
+        $minPeriods ??= $window;
+        if ($minPeriods < 0 || $minPeriods > $window) {
+            throw new \InvalidArgumentException('minPeriods must be between 0 and window');
+        }
+
+        return $this->unaryOp($funcName, $window, $axis, $minPeriods, $center, ...$extra);
+    }
+
+    /**
+     * Shared body of partition() and argpartition().
+     *
+     * @param array<int>|int $kth
+     */
+    private function partitionOp(string $funcName, array|int $kth, ?int $axis): NDArray
+    {
+        $kth = \is_int($kth) ? [$kth] : array_values($kth);
+        $source = null === $axis ? $this->flatten() : $this;
+
+        return $source->unaryOp(
+            $funcName,
+            Lib::get()->createCArray('int64_t', $kth),
+            \count($kth),
+            $axis ?? 0
+        );
+    }
+
     /**
      * Perform topk along axis.
      *
@@ -0,0 +1,207 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Traits;
+
+use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\FFI\Lib;
+use PhpMlKit\NDArray\NDArray;
+
+/**
+ * Set operations on flattened arrays, plus unique slices along an axis.
+ *
+ * Results are sorted ascending with NaN last, and all NaNs count as one value.
+ * Complex arrays are not supported.
+ */
+trait HasSetOps
+{
+    /**
+     * Sorted unique values of the flattened array, or unique slices along an axis.
+     *
+     * With no flag set, returns the unique values as a 1D array of the input dtype; with
+     * `$axis`, returns the unique slices along that axis (rows for axis 0 of a matrix),
+     * sorted lexicographically, keeping the other dimensions. Otherwise returns an array
+     * with `values` plus the requested `index` (Int64 position of each unique value's first
+     * occurrence), `inverse` (Int64 indices into `values`, so `values->take(inverse, $axis)`
+     * rebuilds the input; it has the input's shape when `$axis` is null) and `counts`
+     * (Int64 occurrences of each unique value).
+     *
+     * @param bool     $returnInverse Also return the inverse indices
+     * @param bool     $returnCounts  Also return the counts
+     * @param null|int $axis          Axis along which to find unique slices. If null, flatten first.
+     * @param bool     $returnIndex   Also return first-occurrence indices
+     *
+     * @return NDArray|array{values: NDArray, index?: NDArray, inverse?: NDArray, counts?: NDArray}
+     */
+    public function unique(
+        bool $returnInverse = false,
+        bool $returnCounts = false,
+        ?int $axis = null,
+        bool $returnIndex = false
+    ): array|NDArray {
+        if (null !== $axis) {
+            return $this->uniqueAlongAxis($axis, $returnIndex, $returnInverse, $returnCounts);
+        }
+        if ($returnIndex) {
+            $result = $this->flatten()->uniqueAlongAxis(0, true, $returnInverse, $returnCounts);
+            if (isset($result['inverse'])) {
+                $result['inverse'] = $result['inverse']->reshape($this->shape());
+            }
+
+            return $result;
+        }
+
+        $lib = Lib::get();
+        $outValues = $lib->new('struct NdArrayHandle*');
+        $outInverse = $returnInverse ? $lib->new('struct NdArrayHandle*') : null;
+        $outCounts = $returnCounts ? $lib->new('struct NdArrayHandle*') : null;
+        $outLen = $lib->new('size_t');
+
+        $meta = $this->meta()->toCData();
+        $status = $lib->ndarray_unique(
+            $this->handle,
+            Lib::addr($meta),
+            Lib::addr($outValues),
+            null !== $outInverse ? Lib::addr($outInverse) : null,
+            null !== $outCounts ? Lib::addr($outCounts) : null,
+            Lib::addr($outLen)
+        );
+
+        $lib->checkStatus($status);
+
+        $len = (int) $outLen->cdata;
+        $values = new NDArray($outValues, new ArrayMetadata([$len]), $this->dtype);
+        if (null === $outInverse && null === $outCounts) {
+            return $values;
+        }
+
+        $result = ['values' => $values];
+        if (null !== $outInverse) {
+            $result['inverse'] = new NDArray($outInverse, new ArrayMetadata($this->shape()), DType::Int64);
+        }
+        if (null !== $outCounts) {
+            $result['counts'] = new NDArray($outCounts, new ArrayMetadata([$len]), DType::Int64);
+        }
+
+        return $result;
+    }
+
+    /**
+     * Sorted unique values present in both this array and `$other`.
+     *
+     * Both inputs are flattened and promoted to a common dtype.
+     *
+     * @param array<mixed>|NDArray $other        Second operand
+     * @param bool                 $assumeUnique Skip deduplication when both inputs are known to be unique
+     */
+    public function intersect1d(array|NDArray $other, bool $assumeUnique = false): NDArray
+    {
+        return $this->binaryOp('ndarray_intersect1d', self::setOperand($other), $assumeUnique);
+    }
+
+    /**
+     * Sorted unique values present in this array, `$other`, or both.
+     *
+     * Both inputs are flattened and promoted to a common dtype.
+     *
+     * @param array<mixed>|NDArray $other Second operand
+     */
+    public function union1d(array|NDArray $other): NDArray
+    {
+        return $this->binaryOp('ndarray_union1d', self::setOperand($other));
+    }
+
+    /**
+     * Unique values of this array that are not in `$other`.
+     *
+     * Both inputs are flattened and promoted to a common dtype. The result is sorted,
+     * unless `$assumeUnique` is set: this array is then taken to hold no duplicates and
+     * its order is kept.
+     *
+     * @param array<mixed>|NDArray $other        Values to remove
+     * @param bool                 $assumeUnique Skip deduplication when both inputs are known to be unique
+     */
+    public function setdiff1d(array|NDArray $other, bool $assumeUnique = false): NDArray
+    {
+        return $this->binaryOp('ndarray_setdiff1d', self::setOperand($other), $assumeUnique);
+    }
+
+    /**
+     * Test whether each element occurs in `$testElements`.
+     *
+     * Returns a Bool array with this array's shape. `$testElements` is flattened, values
+     * are compared after dtype promotion, and NaN never matches.
+     *
+     * @param array<mixed>|NDArray $testElements Values to test against
+     * @param bool                 $assumeUnique Skip deduplication when `$testElements` is known to be unique
+     * @param bool                 $invert       Return true for elements that are not in `$testElements`
+     */
+    public function isin(array|NDArray $testElements, bool $assumeUnique = false, bool $invert = false): NDArray
+    {
+        return $this->binaryOp('ndarray_isin', self::setOperand($testElements), $assumeUnique, $invert);
+    }
+
+    /**
+     * Unique slices along an axis, with the optional outputs of unique().
+     *
+     * @return NDArray|array{values: NDArray, index?: NDArray, inverse?: NDArray, counts?: NDArray}
+     */
+    private function uniqueAlongAxis(
+        int $axis,
+        bool $returnIndex,
+        bool $returnInverse,
+        bool $returnCounts
+    ): array|NDArray {
+        $axis = self::normalizeAxis($axis, $this->ndim());
+
+        $lib = Lib::get();
+        $outValues = $lib->new('struct NdArrayHandle*');
+        $outIndex = $returnIndex ? $lib->new('struct NdArrayHandle*') : null;
+        $outInverse = $returnInverse ? $lib->new('struct NdArrayHandle*') : null;
+        $outCounts = $returnCounts ? $lib->new('struct NdArrayHandle*') : null;
+        $outLen = $lib->new('size_t');
+
+        $meta = $this->meta()->toCData();
+        $status = $lib->ndarray_unique_axis(
+            $this->handle,
+            Lib::addr($meta),
+            $axis,
+            Lib::addr($outValues),
+            null !== $outIndex ? Lib::addr($outIndex) : null,
+            null !== $outInverse ? Lib::addr($outInverse) : null,
+            null !== $outCounts ? Lib::addr($outCounts) : null,
+            Lib::addr($outLen)
+        );
+
+        $lib->checkStatus($status);
+
+        $len = (int) $outLen->cdata;
+        $shape = $this->shape();
+        $axisLen = $shape[$axis];
+        $shape[$axis] = $len;
+        $values = new NDArray($outValues, new ArrayMetadata($shape), $this->dtype);
+        if (null === $outIndex && null === $outInverse && null === $outCounts) {
+            return $values;
+        }
+
+        $result = ['values' => $values];
+        if (null !== $outIndex) {
+            $result['index'] = new NDArray($outIndex, new ArrayMetadata([$len]), DType::Int64);
+        }
+        if (null !== $outInverse) {
+            $result['inverse'] = new NDArray($outInverse, new ArrayMetadata([$axisLen]), DType::Int64);
+        }
+        if (null !== $outCounts) {
+            $result['counts'] = new NDArray($outCounts, new ArrayMetadata([$len]), DType::Int64);
+        }
+
+        return $result;
+    }
+
+    private static function setOperand(array|NDArray $values): NDArray
+    {
+        return $values instanceof NDArray ? $values : NDArray::array($values);
+    }
+}
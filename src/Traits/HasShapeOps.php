@@ -56,8 +56,9 @@ trait HasShapeOps
      * Returns a new array with the specified shape.
      * Supports both C-order (row-major, order='C') and F-order (column-major, order='F').
      *
-     * For contiguous arrays, this returns a zero-copy view with updated metadata.
-     * For non-contiguous arrays, data is copied to make it contiguous first.
+     * When the array is contiguous in the requested order, this returns a zero-copy
+     * view with updated metadata. Otherwise the data is copied into a new array laid
+     * out in that order (C- or Fortran-contiguous).
      *
      * @param array<int> $newShape New shape. One dimension may be -1 to infer it from the array size.
      * @param string     $order    Memory layout: 'C' for row-major, 'F' for column-major
@@ -85,18 +86,10 @@ trait HasShapeOps
             );
         }
 
-        if ($this->isContiguous()) {
-            if ('F' === $order) {
-                $newStrides = [];
-                $stride = 1;
-                foreach ($newShape as $dimSize) {
-                    $newStrides[] = $stride;
-                    $stride *= $dimSize;
-                }
-            } else {
-                $newStrides = ArrayMetadata::computeStrides($newShape);
-            }
+        $newStrides = ArrayMetadata::computeStrides($newShape, $order);
+        $contiguous = 'F' === $order ? $this->isFortranContiguous() : $this->isContiguous();
 
+        if ($contiguous) {
             $root = $this->base ?? $this;
 
             return new self(
@@ -111,21 +104,19 @@ trait HasShapeOps
         $outHandle = $lib->new('struct NdArrayHandle*');
 
         $cShape = $lib->createCArray('size_t', $newShape);
-        $orderCode = 'F' === $order ? 1 : 0; // 0=C (RowMajor), 1=F (ColumnMajor)
-
         $meta = $this->meta()->toCData();
         $status = $lib->ndarray_reshape(
             $this->handle,
             Lib::addr($meta),
             $cShape,
             \count($newShape),
-            $orderCode,
+            ArrayMetadata::orderCode($order),
             Lib::addr($outHandle)
         );
 
         $lib->checkStatus($status);
 
-        return new NDArray($outHandle, new ArrayMetadata($newShape), $this->dtype);
+        return new NDArray($outHandle, new ArrayMetadata($newShape, $newStrides), $this->dtype);
     }
 
     /**
@@ -172,21 +163,8 @@ trait HasShapeOps
     {
         $ndim = $this->ndim();
 
-        // Normalize negative indices
-        if ($axis1 < 0) {
-            $axis1 += $ndim;
-        }
-        if ($axis2 < 0) {
-            $axis2 += $ndim;
-        }
-
-        // Validate indices
-        if ($axis1 < 0 || $axis1 >= $ndim) {
-            throw new ShapeException("Axis {$axis1} is out of bounds for array with {$ndim} dimensions");
-        }
-        if ($axis2 < 0 || $axis2 >= $ndim) {
-            throw new ShapeException("Axis {$axis2} is out of bounds for array with {$ndim} dimensions");
-        }
+        $axis1 = self::normalizeAxis($axis1, $ndim);
+        $axis2 = self::normalizeAxis($axis2, $ndim);
 
         // No change needed if axes are the same
         if ($axis1 === $axis2) {
@@ -232,22 +210,8 @@ trait HasShapeOps
             throw new ShapeException("permute requires {$this->ndim()} axes, got ".\count($axes));
         }
 
-        $normalizedAxes = [];
-        foreach ($axes as $axis) {
-            if ($axis < 0) {
-                $axis = $this->ndim() + $axis;
-            }
-            if ($axis < 0 || $axis >= $this->ndim()) {
-                throw new ShapeException("Axis {$axis} is out of bounds for array with {$this->ndim()} dimensions");
-            }
-            $normalizedAxes[] = $axis;
-        }
-
-        if (\count(array_unique($normalizedAxes)) !== \count($normalizedAxes)) {
-            throw new ShapeException('Duplicate axes in permutation');
-        }
-
-        $normalizedAxesC = $lib->createCArray('size_t', $normalizedAxes);
+        $normalizedAxes = self::normalizeAxes($axes, $this->ndim());
+        $normalizedAxesC = $lib->createCArray('int64_t', $normalizedAxes);
 
         return $this->unaryOp('ndarray_permute', $normalizedAxesC, \count($normalizedAxes));
     }
@@ -270,19 +234,8 @@ trait HasShapeOps
     {
         $ndim = $this->ndim();
 
-        if ($take < 0) {
-            $take += $ndim;
-        }
-        if ($into < 0) {
-            $into += $ndim;
-        }
-
-        if ($take < 0 || $take >= $ndim) {
-            throw new ShapeException("Axis {$take} is out of bounds for array with {$ndim} dimensions");
-        }
-        if ($into < 0 || $into >= $ndim) {
-            throw new ShapeException("Axis {$into} is out of bounds for array with {$ndim} dimensions");
-        }
+        $take = self::normalizeAxis($take, $ndim);
+        $into = self::normalizeAxis($into, $ndim);
 
         if ($take === $into) {
             throw new ShapeException('Cannot merge axis into itself');
@@ -327,27 +280,8 @@ trait HasShapeOps
         if (null === $axes) {
             $axesArray = [];
             $numAxes = 0;
-        } elseif (\is_int($axes)) {
-            $axis = $axes;
-            if ($axis < 0) {
-                $axis = $this->ndim() + $axis;
-            }
-            if ($axis < 0 || $axis >= $this->ndim()) {
-                throw new ShapeException("Axis {$axes} is out of bounds for array with {$this->ndim()} dimensions");
-            }
-            $axesArray = [$axis];
-            $numAxes = 1;
         } else {
-            $axesArray = [];
-            foreach ($axes as $axis) {
-                if ($axis < 0) {
-                    $axis = $this->ndim() + $axis;
-                }
-                if ($axis < 0 || $axis >= $this->ndim()) {
-                    throw new ShapeException("Axis {$axis} is out of bounds for array with {$this->ndim()} dimensions");
-                }
-                $axesArray[] = $axis;
-            }
+            $axesArray = self::normalizeAxes(\is_int($axes) ? [$axes] : $axes, $this->ndim());
             $numAxes = \count($axesArray);
         }
 
@@ -364,13 +298,7 @@ trait HasShapeOps
      */
     public function insertaxis(int $axis): NDArray
     {
-        if ($axis < 0) {
-            $axis = $this->ndim() + $axis + 1;
-        }
-
-        if ($axis < 0 || $axis > $this->ndim()) {
-            throw new ShapeException("Axis {$axis} is out of bounds for array with {$this->ndim()} dimensions");
-        }
+        $axis = self::normalizeAxis($axis, $this->ndim(), true);
 
         $shape = $this->shape();
         $strides = $this->strides();
@@ -400,24 +328,32 @@ trait HasShapeOps
     /**
      * Flatten the array to 1D.
      *
-     * Always returns a copy in C-order (row-major).
+     * Always returns a copy, reading elements in the given order.
+     *
+     * @param string $order 'C' for row-major, 'F' for column-major
      */
-    public function flatten(): NDArray
+    public function flatten(string $order = 'C'): NDArray
     {
+        if ('F' === ArrayMetadata::validateOrder($order)) {
+            return $this->unaryOp('ndarray_ravel', 1);
+        }
+
         return $this->unaryOp('ndarray_flatten');
     }
 
     /**
      * Ravel the array to 1D.
      *
-     * Similar to flatten() but returns a view if the array is contiguous.
-     * For non-contiguous arrays, data is copied to make it contiguous.
+     * Similar to flatten() but returns a view if the array is contiguous in the
+     * requested order. Otherwise, data is copied.
      *
      * @param string $order Memory layout: 'C' for row-major, 'F' for column-major
      */
     public function ravel(string $order = 'C'): NDArray
     {
-        if ($this->isContiguous()) {
+        $contiguous = 'F' === ArrayMetadata::validateOrder($order) ? $this->isFortranContiguous() : $this->isContiguous();
+
+        if ($contiguous) {
             $n = $this->size();
 
             $root = $this->base ?? $this;
@@ -430,15 +366,14 @@ trait HasShapeOps
             );
         }
 
-        $orderCode = 'F' === $order ? 1 : 0;
-
-        return $this->unaryOp('ndarray_ravel', $orderCode);
+        return $this->unaryOp('ndarray_ravel', ArrayMetadata::orderCode($order));
     }
 
     /**
      * Remove axes of length 1 from the array.
      *
      * If no axes are specified, removes all length-1 axes (NumPy behavior).
+     * Squeezing every axis yields a 0-D view.
      * This is a zero-copy operation that returns a view with updated metadata.
      *
      * @param null|array<int> $axes Specific axes to squeeze (null for all)
@@ -457,14 +392,7 @@ trait HasShapeOps
             }
         } else {
             $axesToRemove = [];
-            foreach ($axes as $axis) {
-                $normalizedAxis = $axis;
-                if ($normalizedAxis < 0) {
-                    $normalizedAxis = $this->ndim() + $normalizedAxis;
-                }
-                if ($normalizedAxis < 0 || $normalizedAxis >= $this->ndim()) {
-                    throw new ShapeException("Axis {$axis} is out of bounds for array with {$this->ndim()} dimensions");
-                }
+            foreach (self::normalizeAxes($axes, $this->ndim()) as $normalizedAxis) {
                 if (1 !== $shape[$normalizedAxis]) {
                     throw new ShapeException("Cannot squeeze axis {$normalizedAxis} with size {$shape[$normalizedAxis]}");
                 }
@@ -481,12 +409,6 @@ trait HasShapeOps
             array_splice($newStrides, $axis, 1);
         }
 
-        if (empty($newShape)) {
-            $lastSqueezedAxis = $axesToRemove[\count($axesToRemove) - 1];
-            $newShape = [1];
-            $newStrides = [$strides[$lastSqueezedAxis]];
-        }
-
         $root = $this->base ?? $this;
 
         return new self(
@@ -557,9 +479,13 @@ trait HasShapeOps
             $repeatsArray = $repeats;
         }
 
-        $axisValue = $axis ?? -1;
-
-        return $this->unaryOp('ndarray_repeat', $lib->createCArray('size_t', $repeatsArray), \count($repeatsArray), $axisValue);
+        return $this->unaryOp(
+            'ndarray_repeat',
+            $lib->createCArray('size_t', $repeatsArray),
+            \count($repeatsArray),
+            $axis ?? 0,
+            null !== $axis
+        );
     }
 
     /**
@@ -727,4 +653,48 @@ trait HasShapeOps
 
         return $flat;
     }
+
+    /**
+     * Resolve a possibly negative axis against an array rank.
+     *
+     * Negative axes count from the end (-1 is the last axis). With $allowInsert,
+     * axis === ndim is also valid and -1 resolves to ndim, for operations that
+     * insert a new axis.
+     *
+     * @throws ShapeException If the axis is out of bounds
+     */
+    private static function normalizeAxis(int $axis, int $ndim, bool $allowInsert = false): int
+    {
+        $upper = $allowInsert ? $ndim + 1 : $ndim;
+        $resolved = $axis < 0 ? $upper + $axis : $axis;
+
+        if ($resolved < 0 || $resolved >= $upper) {
+            throw new ShapeException("Axis {$axis} out of bounds for array with {$ndim} dimensions");
+        }
+
+        return $resolved;
+    }
+
+    /**
+     * Resolve a list of distinct, possibly negative axes.
+     *
+     * @param array<int> $axes
+     *
+     * @return array<int>
+     *
+     * @throws ShapeException If an axis is out of bounds or repeated
+     */
+    private static function normalizeAxes(array $axes, int $ndim): array
+    {
+        $resolved = [];
+        foreach ($axes as $axis) {
+            $axis = self::normalizeAxis($axis, $ndim);
+            if (\in_array($axis, $resolved, true)) {
+                throw new ShapeException("Axis {$axis} is repeated");
+            }
+            $resolved[] = $axis;
+        }
+
+        return $resolved;
+    }
 }
@@ -5,14 +5,17 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Traits;
 
 use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\Casting;
 use PhpMlKit\NDArray\Complex;
+use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\IndexException;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\Rng;
 use PhpMlKit\NDArray\Slice;
 
 /**
- * Slicing operations: slice() and assign().
+ * Slicing operations: slice(), assign(), copyFrom() and shuffle().
  *
  * Slices return views sharing the same underlying Rust handle.
  */
@@ -144,4 +147,65 @@ trait HasSlicing
 
         $lib->checkStatus($status);
     }
+
+    /**
+     * Copy values into this array or view in place, optionally only where a mask is true.
+     *
+     * Mirrors NumPy `copyto(dst, src, casting, where)`. The source and mask are broadcast
+     * to this view's shape, and the source is converted to this array's dtype when
+     * `$casting` allows it. Unlike where(), no result array is allocated.
+     *
+     * @param bool|float|int|NDArray $src     Values to copy
+     * @param null|NDArray           $where   Bool mask; null copies every element
+     * @param Casting                $casting Allowed dtype conversion from source to this array
+     */
+    public function copyFrom(bool|float|int|NDArray $src, ?NDArray $where = null, Casting $casting = Casting::SameKind): void
+    {
+        $lib = Lib::get();
+        $src = $src instanceof NDArray ? $src : NDArray::array([$src], DType::fromValue($src));
+
+        $dstMeta = $this->meta()->toCData();
+        $srcMeta = $src->meta()->toCData();
+        $maskMeta = $where?->meta()->toCData();
+
+        $status = $lib->ndarray_copyto(
+            $this->handle,
+            Lib::addr($dstMeta),
+            $src->handle(),
+            Lib::addr($srcMeta),
+            $where?->handle(),
+            null !== $maskMeta ? Lib::addr($maskMeta) : null,
+            $casting->value,
+        );
+
+        $lib->checkStatus($status);
+    }
+
+    /**
+     * Shuffle this array or view in place along an axis.
+     *
+     * Sub-arrays along the axis move together, so shuffling a `[samples, features]`
+     * matrix along axis 0 reorders its rows. To keep features and labels aligned,
+     * index both with one {@see NDArray::permutation()} instead.
+     *
+     * @param int      $axis Axis to shuffle along (default: 0)
+     * @param null|int $seed Optional seed for deterministic output
+     * @param null|Rng $rng  Generator to draw from; overrides `$seed`
+     */
+    public function shuffle(int $axis = 0, ?int $seed = null, ?Rng $rng = null): void
+    {
+        $lib = Lib::get();
+        $meta = $this->meta()->toCData();
+
+        $status = $lib->ndarray_shuffle(
+            $this->handle,
+            Lib::addr($meta),
+            $axis,
+            null !== $seed,
+            $seed ?? 0,
+            $rng?->handle(),
+        );
+
+        $lib->checkStatus($status);
+    }
 }
@@ -5,6 +5,7 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Traits;
 
 use PhpMlKit\NDArray\ArrayMetadata;
+use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
@@ -12,7 +13,8 @@ use PhpMlKit\NDArray\NDArray;
 /**
  * Joining and splitting operations.
  *
- * Provides concatenate, stack, vstack, hstack, split, vsplit, hsplit.
+ * Provides concatenate, stack, vstack, hstack, dstack, column_stack, split,
+ * vsplit, hsplit, array_split, block.
  */
 trait HasStacking
 {
@@ -20,6 +22,7 @@ trait HasStacking
      * Join arrays along an existing axis.
      *
      * All arrays must have the same shape except for the dimension along axis.
+     * Inputs of different dtypes are promoted to a common dtype.
      *
      * @param array<NDArray> $arrays Arrays to concatenate
      * @param int            $axis   Axis along which to join (default 0)
@@ -30,58 +33,16 @@ trait HasStacking
             throw new ShapeException('concatenate requires at least one array');
         }
 
-        $numArrays = \count($arrays);
-        $ndim = $arrays[0]->ndim();
-
-        $axisResolved = $axis < 0 ? $ndim + $axis : $axis;
-        if ($axisResolved < 0 || $axisResolved >= $ndim) {
-            throw new ShapeException("Axis {$axis} out of bounds for array with {$ndim} dimensions");
-        }
-
-        foreach ($arrays as $i => $arr) {
-            if ($arr->ndim() !== $ndim) {
-                throw new ShapeException(
-                    "concatenate requires all arrays to have the same number of dimensions (array {$i} has {$arr->ndim()}, expected {$ndim})"
-                );
-            }
-        }
-
-        $lib = Lib::get();
-        $metaWrappers = array_map(static fn (NDArray $a) => $a->meta()->toCData(), $arrays);
-        $cHandles = $lib->new("struct NdArrayHandle*[{$numArrays}]");
-        $cMetas = $lib->new("struct ArrayMetadata*[{$numArrays}]");
-        for ($i = 0; $i < $numArrays; ++$i) {
-            $cHandles[$i] = $arrays[$i]->handle;
-            $cMetas[$i] = Lib::addr($metaWrappers[$i]);
-        }
-
-        $outHandle = $lib->new('struct NdArrayHandle*');
-        $outNdimBuf = $lib->new('size_t');
-        $outShapeBuf = $lib->createCArray('size_t', array_fill(0, Lib::MAX_NDIM, 0));
+        $ndim = self::commonNdim('concatenate', $arrays);
 
-        $status = $lib->ndarray_concatenate(
-            $cHandles,
-            $cMetas,
-            $numArrays,
-            $axisResolved,
-            Lib::addr($outHandle),
-            Lib::addr($outNdimBuf),
-            $outShapeBuf,
-            Lib::MAX_NDIM
-        );
-
-        $lib->checkStatus($status);
-
-        $outNdim = (int) $outNdimBuf->cdata;
-        $outShape = $lib->readSizeTArray($outShapeBuf, $outNdim);
-
-        return new self($outHandle, new ArrayMetadata($outShape), $arrays[0]->dtype);
+        return self::joinArrays('concatenate', $arrays, self::normalizeAxis($axis, $ndim));
     }
 
     /**
      * Stack arrays along a new axis.
      *
-     * All arrays must have identical shapes.
+     * All arrays must have identical shapes. Inputs of different dtypes are
+     * promoted to a common dtype.
      *
      * @param array<NDArray> $arrays Arrays to stack
      * @param int            $axis   Axis in the result at which the arrays are stacked
@@ -92,76 +53,111 @@ trait HasStacking
             throw new ShapeException('stack requires at least one array');
         }
 
-        $numArrays = \count($arrays);
-        $ndim = $arrays[0]->ndim();
-
-        $axisResolved = $axis < 0 ? $ndim + $axis + 1 : $axis;
-        if ($axisResolved < 0 || $axisResolved > $ndim) {
-            throw new ShapeException("Axis {$axis} out of bounds for stack");
-        }
-
-        foreach ($arrays as $i => $arr) {
-            if ($arr->ndim() !== $ndim) {
-                throw new ShapeException(
-                    "stack requires all arrays to have the same number of dimensions (array {$i} has {$arr->ndim()}, expected {$ndim})"
-                );
-            }
-        }
-
-        $lib = Lib::get();
-        $metaWrappers = array_map(static fn (NDArray $a) => $a->meta()->toCData(), $arrays);
-        $cHandles = $lib->new("struct NdArrayHandle*[{$numArrays}]");
-        $cMetas = $lib->new("struct ArrayMetadata*[{$numArrays}]");
-        for ($i = 0; $i < $numArrays; ++$i) {
-            $cHandles[$i] = $arrays[$i]->handle;
-            $cMetas[$i] = Lib::addr($metaWrappers[$i]);
-        }
+        $ndim = self::commonNdim('stack', $arrays);
 
-        $outHandle = $lib->new('struct NdArrayHandle*');
-        $outNdimBuf = $lib->new('size_t');
-        $outShapeBuf = $lib->createCArray('size_t', array_fill(0, Lib::MAX_NDIM, 0));
+        return self::joinArrays('stack', $arrays, self::normalizeAxis($axis, $ndim, true));
+    }
 
-        $status = $lib->ndarray_stack(
-            $cHandles,
-            $cMetas,
-            $numArrays,
-            $axisResolved,
-            Lib::addr($outHandle),
-            Lib::addr($outNdimBuf),
-            $outShapeBuf,
-            Lib::MAX_NDIM
+    /**
+     * Stack arrays vertically (along axis 0).
+     *
+     * 1-D arrays of shape (N,) are treated as rows of shape (1, N).
+     *
+     * @param array<NDArray> $arrays Arrays to stack
+     */
+    public static function vstack(array $arrays): NDArray
+    {
+        $rows = array_map(
+            static fn (NDArray $a) => $a->ndim() < 2 ? $a->reshape([1, $a->size()]) : $a,
+            $arrays
         );
 
-        $lib->checkStatus($status);
+        return self::concatenate($rows, 0);
+    }
 
-        $outNdim = (int) $outNdimBuf->cdata;
-        $outShape = $lib->readSizeTArray($outShapeBuf, $outNdim);
+    /**
+     * Stack arrays horizontally.
+     *
+     * 1-D arrays are joined along axis 0, everything else along axis 1.
+     *
+     * @param array<NDArray> $arrays Arrays to stack
+     */
+    public static function hstack(array $arrays): NDArray
+    {
+        if (empty($arrays)) {
+            throw new ShapeException('hstack requires at least one array');
+        }
 
-        return new self($outHandle, new ArrayMetadata($outShape), $arrays[0]->dtype);
+        return self::concatenate($arrays, 1 === $arrays[0]->ndim() ? 0 : 1);
     }
 
     /**
-     * Stack arrays vertically (along axis 0).
+     * Stack arrays depth-wise (along axis 2).
      *
-     * Equivalent to concatenate(arrays, axis=0).
+     * 1-D arrays of shape (N,) become (1, N, 1) and 2-D arrays of shape
+     * (M, N) become (M, N, 1) before joining.
      *
      * @param array<NDArray> $arrays Arrays to stack
      */
-    public static function vstack(array $arrays): NDArray
+    public static function dstack(array $arrays): NDArray
     {
-        return self::concatenate($arrays, 0);
+        $planes = array_map(static function (NDArray $a): NDArray {
+            $shape = $a->shape();
+
+            return match (\count($shape)) {
+                0 => $a->reshape([1, 1, 1]),
+                1 => $a->reshape([1, $shape[0], 1]),
+                2 => $a->reshape([$shape[0], $shape[1], 1]),
+                default => $a,
+            };
+        }, $arrays);
+
+        return self::concatenate($planes, 2);
     }
 
     /**
-     * Stack arrays horizontally (along axis 1).
+     * Stack 1-D arrays as columns of a 2-D array.
      *
-     * Equivalent to concatenate(arrays, axis=1).
+     * 1-D arrays of shape (N,) become columns of shape (N, 1); 2-D arrays are
+     * joined along axis 1 as they are.
      *
      * @param array<NDArray> $arrays Arrays to stack
      */
-    public static function hstack(array $arrays): NDArray
+    public static function columnStack(array $arrays): NDArray
     {
-        return self::concatenate($arrays, 1);
+        $columns = array_map(
+            static fn (NDArray $a) => $a->ndim() < 2 ? $a->reshape([$a->size(), 1]) : $a,
+            $arrays
+        );
+
+        return self::concatenate($columns, 1);
+    }
+
+    /**
+     * Assemble an array from a nested grid of blocks, like numpy.block.
+     *
+     * The innermost lists are joined along the last axis, the lists containing
+     * them along the axis before it, and so on. All arrays must be nested equally
+     * deep, and arrays with fewer dimensions than the result get leading axes of
+     * length 1. Inputs of different dtypes are promoted to a common dtype.
+     *
+     * @param array<mixed>|NDArray $blocks Nested lists of arrays
+     */
+    public static function block(array|NDArray $blocks): NDArray
+    {
+        $arrays = [];
+        $layout = [];
+        $depth = self::blockLayout($blocks, [], $arrays, $layout);
+
+        $lib = Lib::get();
+
+        return self::joinArrays(
+            'block',
+            $arrays,
+            [] === $layout ? null : $lib->createCArray('size_t', $layout),
+            \count($layout),
+            $depth
+        );
     }
 
     /**
@@ -180,10 +176,7 @@ trait HasStacking
         $lib = Lib::get();
         $ndim = $this->ndim();
 
-        $axisResolved = $axis < 0 ? $ndim + $axis : $axis;
-        if ($axisResolved < 0 || $axisResolved >= $ndim) {
-            throw new ShapeException("Axis {$axis} out of bounds for array with {$ndim} dimensions");
-        }
+        $axisResolved = self::normalizeAxis($axis, $ndim);
 
         $axisLen = $this->shape()[$axisResolved];
 
@@ -260,6 +253,191 @@ trait HasStacking
         return $this->split($indicesOrSections, 1);
     }
 
+    /**
+     * Split array along axis, allowing uneven parts.
+     *
+     * Like numpy.array_split: an integer N gives N parts where the first
+     * `len % N` parts have one extra element. An array of boundaries splits at
+     * those positions; negative boundaries count from the end, boundaries past
+     * the end are clamped and a boundary smaller than its predecessor yields an
+     * empty part.
+     *
+     * @param array<int>|int $indicesOrSections Number of parts, or array of split boundaries
+     * @param int            $axis              Axis along which to split
+     *
+     * @return array<NDArray> List of sub-arrays (views)
+     */
+    public function arraySplit(array|int $indicesOrSections, int $axis = 0): array
+    {
+        $lib = Lib::get();
+        $ndim = $this->ndim();
+        $axisResolved = self::normalizeAxis($axis, $ndim);
+
+        if (\is_int($indicesOrSections) && $indicesOrSections < 1) {
+            throw new ShapeException('Number of sections must be >= 1');
+        }
+
+        $numParts = \is_int($indicesOrSections) ? $indicesOrSections : \count($indicesOrSections) + 1;
+        $cOutOffsets = $lib->new("size_t[{$numParts}]");
+        $cOutShapes = $lib->new('size_t['.max(1, $numParts * $ndim).']');
+        $cOutStrides = $lib->new('size_t['.max(1, $numParts * $ndim).']');
+
+        $meta = $this->meta()->toCData();
+        if (\is_int($indicesOrSections)) {
+            $status = $lib->ndarray_array_split(
+                $this->handle,
+                Lib::addr($meta),
+                $axisResolved,
+                $indicesOrSections,
+                $cOutOffsets,
+                $cOutShapes,
+                $cOutStrides
+            );
+        } else {
+            $indices = array_values($indicesOrSections);
+            $status = $lib->ndarray_split_at(
+                $this->handle,
+                Lib::addr($meta),
+                $axisResolved,
+                [] === $indices ? null : $lib->createCArray('int64_t', $indices),
+                \count($indices),
+                $cOutOffsets,
+                $cOutShapes,
+                $cOutStrides
+            );
+        }
+
+        $lib->checkStatus($status);
+
+        $base = $this->base ?? $this;
+        $result = [];
+        for ($i = 0; $i < $numParts; ++$i) {
+            $partShape = [];
+            $partStrides = [];
+            for ($d = 0; $d < $ndim; ++$d) {
+                $partShape[] = (int) $cOutShapes[$i * $ndim + $d];
+                $partStrides[] = (int) $cOutStrides[$i * $ndim + $d];
+            }
+            $partOffset = (int) $cOutOffsets[$i];
+            $result[] = new self(
+                $this->handle,
+                new ArrayMetadata($partShape, $partStrides, $partOffset),
+                $this->dtype,
+                $base
+            );
+        }
+
+        return $result;
+    }
+
+    /**
+     * Number of dimensions shared by all arrays.
+     *
+     * @param array<NDArray> $arrays
+     */
+    private static function commonNdim(string $op, array $arrays): int
+    {
+        $ndim = $arrays[0]->ndim();
+
+        foreach ($arrays as $i => $arr) {
+            if ($arr->ndim() !== $ndim) {
+                throw new ShapeException(
+                    "{$op} requires all arrays to have the same number of dimensions (array {$i} has {$arr->ndim()}, expected {$ndim})"
+                );
+            }
+        }
+
+        return $ndim;
+    }
+
+    /**
+     * Run ndarray_concatenate, ndarray_stack or ndarray_block over any number of arrays.
+     *
+     * @param array<NDArray> $arrays
+     * @param mixed          ...$params Arguments between the array count and the outputs
+     */
+    private static function joinArrays(string $op, array $arrays, mixed ...$params): NDArray
+    {
+        $numArrays = \count($arrays);
+
+        $lib = Lib::get();
+        $metaWrappers = array_map(static fn (NDArray $a) => $a->meta()->toCData(), $arrays);
+        $cHandles = $lib->new("struct NdArrayHandle*[{$numArrays}]");
+        $cMetas = $lib->new("struct ArrayMetadata*[{$numArrays}]");
+        for ($i = 0; $i < $numArrays; ++$i) {
+            $cHandles[$i] = $arrays[$i]->handle;
+            $cMetas[$i] = Lib::addr($metaWrappers[$i]);
+        }
+
+        $outHandle = $lib->new('struct NdArrayHandle*');
+        $outDtypeBuf = $lib->new('uint8_t');
+        $outNdimBuf = $lib->new('size_t');
+        $outShapeBuf = $lib->createCArray('size_t', array_fill(0, Lib::MAX_NDIM, 0));
+
+        $status = $lib->{"ndarray_{$op}"}(...[
+            $cHandles,
+            $cMetas,
+            $numArrays,
+            ...$params,
+            Lib::addr($outHandle),
+            Lib::addr($outDtypeBuf),
+            Lib::addr($outNdimBuf),
+            $outShapeBuf,
+            Lib::MAX_NDIM,
+        ]);
+
+        $lib->checkStatus($status);
+
+        $dtype = DType::from((int) $outDtypeBuf->cdata);
+        $outNdim = (int) $outNdimBuf->cdata;
+        $outShape = $lib->readSizeTArray($outShapeBuf, $outNdim);
+
+        return new self($outHandle, new ArrayMetadata($outShape), $dtype);
+    }
+
+    /**
+     * Flatten nested block lists into arrays and per-list entry counts.
+     *
+     * @param array<mixed>|NDArray $blocks
+     * @param array<int>           $path   Indices leading to $blocks, for error messages
+     * @param array<NDArray>       $arrays Collected arrays, in order
+     * @param array<int>           $layout Entry count of every list, depth first
+     *
+     * @return int Nesting depth of $blocks
+     */
+    private static function blockLayout(array|NDArray $blocks, array $path, array &$arrays, array &$layout): int
+    {
+        if ($blocks instanceof NDArray) {
+            $arrays[] = $blocks;
+
+            return 0;
+        }
+
+        $where = 'arrays'.implode('', array_map(static fn (int $i) => "[{$i}]", $path));
+        if ([] === $blocks) {
+            throw new ShapeException("block lists must not be empty ({$where} is empty)");
+        }
+
+        $layout[] = \count($blocks);
+        $depth = null;
+        foreach (array_values($blocks) as $i => $block) {
+            if (!$block instanceof NDArray && !\is_array($block)) {
+                throw new \InvalidArgumentException(
+                    'block expects NDArray values, got '.get_debug_type($block)." at {$where}[{$i}]"
+                );
+            }
+            $childDepth = self::blockLayout($block, [...$path, $i], $arrays, $layout);
+            if (null !== $depth && $childDepth !== $depth) {
+                throw new ShapeException(
+                    "block list depths are mismatched: {$where}[0] has depth {$depth} but {$where}[{$i}] has depth {$childDepth}"
+                );
+            }
+            $depth = $childDepth;
+        }
+
+        return $depth + 1;
+    }
+
     /**
      * Compute split indices for equal N-way split.
      *
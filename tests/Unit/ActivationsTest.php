@@ -0,0 +1,65 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for neural-network activation functions.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class ActivationsTest extends TestCase
+{
+    public function testActivationValues(): void
+    {
+        $x = NDArray::array([-2.0, 0.0, 3.0]);
+
+        $this->assertSame([0.0, 0.0, 3.0], $x->relu()->toArray());
+        $this->assertEqualsWithDelta([-0.2, 0.0, 3.0], $x->leakyRelu(0.1)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([-0.045402, 0.0, 2.996363], $x->gelu()->toArray(), 1e-6);
+        $this->assertEqualsWithDelta([-0.238406, 0.0, 2.857722], $x->silu()->toArray(), 1e-6);
+        $this->assertEqualsWithDelta([0.126928, log(2.0), 3.048587], $x->softplus()->toArray(), 1e-6);
+    }
+
+    public function testActivationsStayFiniteForLargeInputs(): void
+    {
+        $x = NDArray::array([-1000.0, 1000.0]);
+
+        $this->assertSame([0.0, 1.0], $x->sigmoid()->toArray());
+        $this->assertSame([0.0, 1000.0], $x->softplus()->toArray());
+    }
+
+    public function testActivationDtypes(): void
+    {
+        $this->assertSame(DType::Float32, NDArray::array([1.0, -1.0], DType::Float32)->relu()->dtype());
+
+        $ints = NDArray::array([-1, 2], DType::Int32)->relu();
+        $this->assertSame(DType::Float64, $ints->dtype());
+        $this->assertSame([0.0, 2.0], $ints->toArray());
+    }
+
+    public function testActivationInPlaceOnView(): void
+    {
+        $x = NDArray::array([[-1.0, -2.0], [3.0, -4.0]], DType::Float32);
+        $col = $x->slice([':', 1]);
+
+        $result = $col->relu(inPlace: true);
+
+        $this->assertSame($col, $result);
+        $this->assertSame([[-1.0, 0.0], [3.0, 0.0]], $x->toArray());
+    }
+
+    public function testActivationInPlaceRejectsIntegers(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1, 2, 3])->sigmoid(inPlace: true);
+    }
+}
@@ -6,7 +6,11 @@ namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\MathException;
+use PhpMlKit\NDArray\Exceptions\OverflowException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\OverflowMode;
 use PHPUnit\Framework\TestCase;
 
 /**
@@ -29,6 +33,67 @@ final class ArithmeticTest extends TestCase
         $this->assertEqualsWithDelta([[6, 8], [10, 12]], $result->toArray(), 0.0001);
     }
 
+    public function testSameDtypeIntegerAddKeepsFullPrecision(): void
+    {
+        // 2**53 + 1 is not representable as a float64.
+        $a = NDArray::array([9007199254740993, 4611686018427387904], DType::Int64);
+        $b = NDArray::array([1, 3], DType::Int64);
+
+        $this->assertSame([9007199254740994, 4611686018427387907], $a->add($b)->toArray());
+
+        $u = NDArray::array([9007199254740993], DType::Uint64);
+        $this->assertSame([9007199254740995], $u->add(NDArray::array([2], DType::Uint64))->toArray());
+    }
+
+    public function testSameDtypeOpsOnTransposedViewsAreRowMajor(): void
+    {
+        $m = NDArray::array([[0, 1, 2], [3, 4, 5]], DType::Int32);
+        $t = $m->transpose();
+
+        $this->assertSame([[0, 9], [1, 16], [4, 25]], $t->multiply($t)->toArray());
+        $this->assertSame([[0, 6], [2, 8], [4, 10]], $t->add($t)->toArray());
+    }
+
+    public function testIntegerOverflowWrapsByDefault(): void
+    {
+        $a = NDArray::array([100, 120], DType::Int8);
+
+        $this->assertSame([-56, -16], $a->add($a)->toArray());
+        $this->assertSame([-106, -86], $a->add(50)->toArray());
+        $this->assertSame(DType::Int8, $a->multiply(3)->dtype());
+    }
+
+    public function testIntegerOverflowSaturates(): void
+    {
+        $a = NDArray::array([100, -100, 5], DType::Int8);
+        $b = NDArray::array([100, 100, 5], DType::Int8);
+
+        $this->assertSame([127, 0, 10], $a->add($b, overflow: OverflowMode::Saturate)->toArray());
+        $this->assertSame([0, -128, 0], $a->subtract($b, overflow: OverflowMode::Saturate)->toArray());
+        $this->assertSame([127, -128, 25], $a->multiply($b, overflow: OverflowMode::Saturate)->toArray());
+
+        $u = NDArray::array([0, 10, 200], DType::UInt8);
+        $this->assertSame([255, 255, 100], $u->rsub(300, OverflowMode::Saturate)->toArray());
+    }
+
+    public function testIntegerOverflowErrorNamesOperands(): void
+    {
+        $a = NDArray::array([1, 200], DType::UInt8);
+
+        $this->assertSame([2, 201], $a->add(1, overflow: OverflowMode::Error)->toArray());
+
+        $this->expectException(OverflowException::class);
+        $this->expectExceptionMessage('200 * 2 does not fit in uint8');
+        $a->multiply(2, overflow: OverflowMode::Error);
+    }
+
+    public function testOverflowModeDoesNotAffectFloats(): void
+    {
+        $a = NDArray::array([1e308], DType::Float64);
+
+        $this->assertSame([\INF], $a->multiply(10, overflow: OverflowMode::Error)->toArray());
+    }
+
     public function testAddScalar(): void
     {
         $a = NDArray::array([[1, 2], [3, 4]], DType::Float64);
@@ -405,4 +470,111 @@ final class ArithmeticTest extends TestCase
         $this->assertSame([3], $result->shape());
         $this->assertSame([0, 0, 4], $result->toArray());
     }
+
+    public function testModTakesSignOfDivisor(): void
+    {
+        $a = NDArray::array([-7, 7, -7, 7], DType::Int32);
+        $b = NDArray::array([3, -3, -3, 3], DType::Int32);
+
+        $this->assertSame([2, -2, -1, 1], $a->mod($b)->toArray());
+        $this->assertSame([-1, 1, -1, 1], $a->fmod($b)->toArray());
+        $this->assertSame(DType::Int32, $a->mod($b)->dtype());
+    }
+
+    public function testFloorDivideRoundsTowardNegativeInfinity(): void
+    {
+        $a = NDArray::array([-7, 7, -8], DType::Int64);
+
+        $this->assertSame([-4, 3, -4], $a->floorDivide(2)->toArray());
+        $this->assertSame([3, -4, 4], $a->floorDivide(-2)->toArray());
+
+        $f = NDArray::array([-7.5, 7.5], DType::Float64);
+        $this->assertEqualsWithDelta([-4.0, 3.0], $f->floorDivide(2)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([0.5, 1.5], $f->mod(2)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([-1.5, 1.5], $f->fmod(2)->toArray(), 1e-12);
+    }
+
+    public function testFloorDivideIdentityWithMod(): void
+    {
+        $a = NDArray::array([[-9, -4, 0], [5, 11, -1]], DType::Int32);
+        $b = NDArray::array([4, -3, 5], DType::Int32);
+
+        $rebuilt = $a->floorDivide($b)->multiply($b)->add($a->mod($b));
+
+        $this->assertSame($a->toArray(), $rebuilt->toArray());
+    }
+
+    public function testIntegerFloorDivideByZeroThrows(): void
+    {
+        $this->expectException(MathException::class);
+
+        NDArray::array([1, 2, 3], DType::Int32)->floorDivide(NDArray::array([1, 0, 1], DType::Int32));
+    }
+
+    public function testReverseScalarOps(): void
+    {
+        $a = NDArray::array([1, 2, 4], DType::Int32);
+
+        $this->assertSame([9, 8, 6], $a->rsub(10)->toArray());
+        $this->assertSame([8, 4, 2], $a->rdiv(8)->toArray());
+        $this->assertSame([3, 9, 81], $a->rpow(3)->toArray());
+        $this->assertSame(DType::Int32, $a->rsub(10)->dtype());
+
+        $f = NDArray::array([1.0, 2.0, 4.0], DType::Float32);
+        $this->assertEqualsWithDelta([1.0, 0.5, 0.25], $f->rdiv(1.0)->toArray(), 1e-6);
+        $this->assertEqualsWithDelta([2.0, 4.0, 16.0], $f->rpow(2)->toArray(), 1e-5);
+    }
+
+    public function testRpowNegativeIntegerExponentThrows(): void
+    {
+        $this->expectException(MathException::class);
+
+        NDArray::array([1, -1], DType::Int32)->rpow(2);
+    }
+
+    public function testAddIntoOutWritesInPlace(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Float64);
+        $b = NDArray::array([10, 20], DType::Float64);
+        $out = NDArray::zeros([2, 2]);
+
+        $result = $a->add($b, out: $out);
+
+        $this->assertSame($out, $result);
+        $this->assertEqualsWithDelta([[11, 22], [13, 24]], $out->toArray(), 1e-12);
+    }
+
+    public function testOutMayAliasAnOperand(): void
+    {
+        $acc = NDArray::array([1, 2, 3], DType::Int32);
+
+        $acc->multiply($acc, out: $acc);
+        $acc->subtract(1, out: $acc);
+
+        $this->assertSame([0, 3, 8], $acc->toArray());
+    }
+
+    public function testOutWritesIntoView(): void
+    {
+        $buffer = NDArray::zeros([2, 3], DType::Int64);
+        $row = $buffer->slice([1]);
+
+        NDArray::array([1, 2, 3])->maximum(NDArray::array([3, 2, 1]), out: $row);
+
+        $this->assertSame([[0, 0, 0], [3, 2, 3]], $buffer->toArray());
+    }
+
+    public function testOutRejectsMismatchedDtypeAndShape(): void
+    {
+        $a = NDArray::array([1.0, 2.0], DType::Float64);
+
+        try {
+            $a->add($a, out: NDArray::zeros([2], DType::Float32));
+            $this->fail('Expected DTypeException');
+        } catch (DTypeException) {
+        }
+
+        $this->expectException(ShapeException::class);
+        $a->add($a, out: NDArray::zeros([3]));
+    }
 }
@@ -4,7 +4,9 @@ declare(strict_types=1);
 
 namespace PhpMlKit\NDArray\Tests\Unit;
 
+use PhpMlKit\NDArray\CastPolicy;
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\OverflowException;
 use PhpMlKit\NDArray\NDArray;
 use PHPUnit\Framework\TestCase;
 
@@ -289,4 +291,56 @@ final class AstypeTest extends TestCase
             $this->assertSame($shape, $casted->shape(), 'Failed for shape '.json_encode($shape));
         }
     }
+
+    public function testAstypeCheckedClampsAndCounts(): void
+    {
+        $arr = NDArray::array([-5.0, 3.9, 300.0, \NAN], DType::Float64);
+
+        $out = $arr->astypeChecked(DType::UInt8, CastPolicy::Clamp, $outOfRange);
+
+        $this->assertSame(DType::UInt8, $out->dtype());
+        $this->assertSame([0, 3, 255, 0], $out->toArray());
+        $this->assertSame(3, $outOfRange);
+    }
+
+    public function testAstypeCheckedWraps(): void
+    {
+        $arr = NDArray::array([-1, 127, 300], DType::Int64);
+
+        $out = $arr->astypeChecked(DType::UInt8, CastPolicy::Wrap, $outOfRange);
+
+        $this->assertSame([255, 127, 44], $out->toArray());
+        $this->assertSame(2, $outOfRange);
+    }
+
+    public function testAstypeCheckedErrorReportsFirstOffender(): void
+    {
+        $arr = NDArray::array([1, 2, 40000, 70000], DType::Int32);
+
+        $this->expectException(OverflowException::class);
+        $this->expectExceptionMessage('2 of 4 elements out of range (first: 40000 at index 2)');
+        $arr->astypeChecked(DType::Int16);
+    }
+
+    public function testAstypeCheckedInRangeMatchesAstype(): void
+    {
+        $arr = NDArray::array([[1.5, -2.5], [100.0, 0.0]], DType::Float64);
+
+        $out = $arr->astypeChecked(DType::Int8, outOfRange: $outOfRange);
+
+        $this->assertSame($arr->astype(DType::Int8)->toArray(), $out->toArray());
+        $this->assertSame(0, $outOfRange);
+    }
+
+    public function testAstypeCheckedFloatNarrowing(): void
+    {
+        $arr = NDArray::array([1e300, -1e300, 1.0], DType::Float64);
+
+        $clamped = $arr->astypeChecked(DType::Float32, CastPolicy::Clamp, $outOfRange);
+        $this->assertSame(2, $outOfRange);
+        $this->assertEqualsWithDelta(3.4028234663852886e38, $clamped->toArray()[0], 1e31);
+
+        $wrapped = $arr->astypeChecked(DType::Float32, CastPolicy::Wrap);
+        $this->assertSame([\INF, -\INF, 1.0], $wrapped->toArray());
+    }
 }
@@ -231,6 +231,30 @@ class BroadcastingTest extends TestCase
         $result = $transposed->add($vec);
 
         $this->assertEquals([3, 2], $result->shape());
+        $this->assertEquals([[11, 24], [12, 25], [13, 26]], $result->toArray());
+    }
+
+    public function testBroadcastTransposedWithStridedColumnView(): void
+    {
+        $matrix = NDArray::array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        $transposed = $matrix->transpose();  // Shape [3, 2], column-major strides
+        $column = NDArray::array([[6.0, 0.0], [5.0, 0.0], [4.0, 0.0]])->slice([':', '0:1']);  // Shape [3, 1], row stride 2
+
+        $result = $transposed->subtract($column);
+
+        $this->assertEquals([[-5, -2], [-3, 0], [-1, 2]], $result->toArray());
+    }
+
+    public function testBroadcastSteppedViewAgainst3DWithPromotion(): void
+    {
+        $base = NDArray::arange(8, dtype: DType::Int32);
+        $stepped = $base->slice(['::2']);  // [0, 2, 4, 6], stride 2
+        $cube = NDArray::ones([2, 1, 4], DType::Float64);
+
+        $result = $cube->multiply($stepped);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEquals([[[0, 2, 4, 6]], [[0, 2, 4, 6]]], $result->toArray());
     }
 
     public function testBroadcastInComparison(): void
@@ -5,6 +5,7 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Tests\Unit;
 
 use FFI\CData;
+use PhpMlKit\NDArray\ByteOrder;
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
@@ -558,4 +559,80 @@ final class BufferInteropTest extends TestCase
             [3.0, 4.0],
         ], $arr->toArray(), 0.0001);
     }
+
+    // =========================================================================
+    // Byte order Tests
+    // =========================================================================
+
+    public function testByteswapUInt16(): void
+    {
+        $arr = NDArray::array([1, 256, 0x1234], DType::UInt16);
+
+        $swapped = $arr->byteswap();
+
+        $this->assertSame(DType::UInt16, $swapped->dtype());
+        $this->assertSame([256, 1, 0x3412], $swapped->toArray());
+    }
+
+    public function testByteswapIsInvolution(): void
+    {
+        $arr = NDArray::array([[1.5, -2.25], [3.0, 1e-300]], DType::Float64);
+
+        $this->assertSame($arr->toArray(), $arr->byteswap()->byteswap()->toArray());
+    }
+
+    public function testByteswapSingleByteUnchanged(): void
+    {
+        $arr = NDArray::array([1, -2, 3], DType::Int8);
+
+        $this->assertSame([1, -2, 3], $arr->byteswap()->toArray());
+    }
+
+    public function testByteswapOnStridedView(): void
+    {
+        $arr = NDArray::array([[1, 2], [3, 4]], DType::Int32);
+
+        $swapped = $arr->transpose()->byteswap();
+
+        $this->assertSame(
+            pack('N*', 1, 3, 2, 4),
+            $swapped->toBytes(ByteOrder::Native)
+        );
+    }
+
+    public function testFromBytesBigEndian(): void
+    {
+        $bytes = pack('N*', 1, 2, 0xDEADBEEF);
+
+        $arr = NDArray::fromBytes($bytes, [3], DType::UInt32, ByteOrder::Big);
+
+        $this->assertSame([1, 2, 0xDEADBEEF], $arr->toArray());
+    }
+
+    public function testFromBytesBigEndianFloat64(): void
+    {
+        $bytes = pack('E*', 1.5, -2.0);
+
+        $arr = NDArray::fromBytes($bytes, [2], DType::Float64, ByteOrder::Big);
+
+        $this->assertEqualsWithDelta([1.5, -2.0], $arr->toArray(), 1e-12);
+    }
+
+    public function testToBytesBigEndian(): void
+    {
+        $arr = NDArray::array([1, 2], DType::UInt16);
+
+        $this->assertSame(pack('n*', 1, 2), $arr->toBytes(ByteOrder::Big));
+        $this->assertSame(pack('v*', 1, 2), $arr->toBytes(ByteOrder::Little));
+    }
+
+    public function testBigEndianRoundTrip(): void
+    {
+        $original = NDArray::array([100, -200, 300], DType::Int64);
+
+        $bytes = $original->toBytes(ByteOrder::Big);
+        $reconstructed = NDArray::fromBytes($bytes, [3], DType::Int64, ByteOrder::Big);
+
+        $this->assertSame($original->toArray(), $reconstructed->toArray());
+    }
 }
@@ -0,0 +1,228 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for user-supplied PHP callbacks.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class CallbackTest extends TestCase
+{
+    public function testMapAppliesCallback(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+
+        $result = $a->map(static fn (array $batch) => array_map(static fn ($x) => $x * $x + 0.5, $batch));
+
+        $this->assertSame([2, 2], $result->shape());
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([[1.5, 4.5], [9.5, 16.5]], $result->toArray(), 1e-12);
+    }
+
+    public function testMapCastsToRequestedDtype(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 3.0]);
+
+        $result = $a->map(static fn (array $batch) => array_map(static fn ($x) => $x > 1.5, $batch), DType::Bool);
+
+        $this->assertSame(DType::Bool, $result->dtype());
+        $this->assertSame([false, true, true], $result->toArray());
+    }
+
+    public function testMapPassesBatches(): void
+    {
+        $a = NDArray::arange(10, dtype: DType::Float64);
+        $sizes = [];
+
+        $result = $a->map(static function (array $batch) use (&$sizes) {
+            $sizes[] = \count($batch);
+
+            return $batch;
+        }, batchSize: 4);
+
+        $this->assertSame([4, 4, 2], $sizes);
+        $this->assertEqualsWithDelta(range(0, 9), $result->toArray(), 1e-12);
+    }
+
+    public function testMapOnStridedView(): void
+    {
+        $a = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Float64);
+
+        $result = $a->transpose()->map(static fn (array $batch) => $batch);
+
+        $this->assertSame([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]], $result->toArray());
+    }
+
+    public function testMapRethrowsCallbackException(): void
+    {
+        $a = NDArray::array([1.0, 2.0]);
+
+        $this->expectException(\RuntimeException::class);
+        $this->expectExceptionMessage('boom');
+        $a->map(static fn (array $batch) => throw new \RuntimeException('boom'));
+    }
+
+    public function testMapRejectsWrongResultCount(): void
+    {
+        $a = NDArray::array([1.0, 2.0]);
+
+        $this->expectException(\UnexpectedValueException::class);
+        $a->map(static fn (array $batch) => [1.0]);
+    }
+
+    public function testMapComplexThrows(): void
+    {
+        $a = NDArray::array([1.0, 2.0], DType::Complex128);
+
+        $this->expectException(DTypeException::class);
+        $a->map(static fn (array $batch) => $batch);
+    }
+
+    public function testReduceAllElements(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int32);
+
+        $this->assertEqualsWithDelta(24.0, $a->reduce(static fn ($acc, $x) => $acc * $x, 1), 1e-12);
+    }
+
+    public function testReduceAlongAxis(): void
+    {
+        $a = NDArray::array([[1.0, 5.0, 3.0], [4.0, 2.0, 6.0]]);
+
+        $rows = $a->reduce(static fn ($acc, $x) => max($acc, $x), -INF, axis: 1);
+        $cols = $a->reduce(static fn ($acc, $x) => $acc + $x, axis: 0, keepdims: true);
+
+        $this->assertSame([2], $rows->shape());
+        $this->assertEqualsWithDelta([5.0, 6.0], $rows->toArray(), 1e-12);
+        $this->assertSame([1, 3], $cols->shape());
+        $this->assertEqualsWithDelta([[5.0, 7.0, 9.0]], $cols->toArray(), 1e-12);
+    }
+
+    public function testReduceIsOrderedAcrossBatches(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        $digits = $a->reduce(static fn ($acc, $x) => $acc * 10 + $x, batchSize: 2);
+
+        $this->assertEqualsWithDelta(12345.0, $digits, 1e-9);
+    }
+
+    public function testReduceKeepdimsWithoutAxis(): void
+    {
+        $a = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+
+        $result = $a->reduce(static fn ($acc, $x) => $acc + $x, keepdims: true);
+
+        $this->assertSame([1, 1], $result->shape());
+        $this->assertEqualsWithDelta([[10.0]], $result->toArray(), 1e-12);
+    }
+
+    public function testReduceRethrowsCallbackException(): void
+    {
+        $this->expectException(\LogicException::class);
+        NDArray::array([1.0])->reduce(static fn ($acc, $x) => throw new \LogicException('stop'));
+    }
+
+    public function testGufuncMatrixVectorWithBroadcasting(): void
+    {
+        NDArray::registerGufunc('test_matvec', '(m,n),(n)->(m)', static function (array $in, array $dims): array {
+            [$a, $x] = $in;
+            $out = [];
+            for ($i = 0; $i < $dims['m']; ++$i) {
+                $sum = 0.0;
+                for ($j = 0; $j < $dims['n']; ++$j) {
+                    $sum += $a[$i * $dims['n'] + $j] * $x[$j];
+                }
+                $out[] = $sum;
+            }
+
+            return $out;
+        });
+
+        $a = NDArray::array([[[1, 2], [3, 4]], [[0, 1], [1, 0]]], DType::Float64);
+        $x = NDArray::array([[[1, 1]], [[2, 0]], [[0, 3]]], DType::Float64);
+
+        $result = NDArray::gufunc('test_matvec', $a, $x);
+
+        $this->assertSame([3, 2, 2], $result->shape());
+        $this->assertEqualsWithDelta(
+            [[[3, 7], [1, 1]], [[2, 6], [0, 2]], [[6, 12], [3, 0]]],
+            $result->toArray(),
+            1e-12
+        );
+    }
+
+    public function testGufuncScalarOutputById(): void
+    {
+        $id = NDArray::registerGufunc('test_dot', '(n),(n)->()', static fn (array $in, array $dims) => [
+            array_sum(array_map(static fn ($a, $b) => $a * $b, $in[0], $in[1])),
+        ]);
+
+        $result = NDArray::gufunc($id, NDArray::array([[1, 2, 3], [4, 5, 6]]), NDArray::array([1, 0, 1]));
+
+        $this->assertSame([2], $result->shape());
+        $this->assertEqualsWithDelta([4.0, 10.0], $result->toArray(), 1e-12);
+    }
+
+    public function testGufuncCoreDimensionMismatchThrows(): void
+    {
+        NDArray::registerGufunc('test_dot_mismatch', '(n),(n)->()', static fn (array $in) => [0.0]);
+
+        $this->expectException(ShapeException::class);
+        NDArray::gufunc('test_dot_mismatch', NDArray::array([1.0, 2.0]), NDArray::array([1.0, 2.0, 3.0]));
+    }
+
+    public function testGufuncInvalidSignatureThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        NDArray::registerGufunc('test_bad', '(n)->(k)', static fn (array $in) => []);
+    }
+
+    public function testGufuncUnknownNameThrows(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::gufunc('test_missing', NDArray::array([1.0]));
+    }
+
+    public function testUnregisterGufuncReleasesId(): void
+    {
+        $id = NDArray::registerGufunc('test_unregister', '(n)->()', static fn (array $in) => [array_sum($in[0])]);
+        $this->assertSame([3.0], NDArray::gufunc($id, NDArray::array([[1.0, 2.0]]))->toArray());
+
+        NDArray::unregisterGufunc('test_unregister');
+
+        try {
+            NDArray::gufunc($id, NDArray::array([[1.0, 2.0]]));
+            $this->fail('Unregistered gufunc id should not be callable');
+        } catch (\InvalidArgumentException) {
+        }
+
+        $newId = NDArray::registerGufunc('test_unregister', '(n)->()', static fn (array $in) => [max($in[0])]);
+        $this->assertNotSame($id, $newId);
+        $this->assertSame([2.0], NDArray::gufunc('test_unregister', NDArray::array([[1.0, 2.0]]))->toArray());
+
+        NDArray::unregisterGufunc($newId);
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::unregisterGufunc($newId);
+    }
+
+    public function testGufuncRethrowsKernelException(): void
+    {
+        NDArray::registerGufunc('test_throws', '(n)->()', static fn (array $in) => throw new \DomainException('bad row'));
+
+        $this->expectException(\DomainException::class);
+        NDArray::gufunc('test_throws', NDArray::array([[1.0], [2.0]]));
+    }
+}
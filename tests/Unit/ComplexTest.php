@@ -220,6 +220,34 @@ final class ComplexTest extends TestCase
         $this->assertEqualsWithDelta(12.0, $result[0][1]->imag, 0.0001);
     }
 
+    public function testComplexArithmeticWithRealArray(): void
+    {
+        $z = NDArray::array([new Complex(1, 2), new Complex(3, -1)], DType::Complex64);
+        $x = NDArray::array([10.0, 20.0], DType::Float64);
+
+        $sum = $z->add($x);
+        $this->assertSame(DType::Complex128, $sum->dtype());
+        $this->assertEqualsWithDelta(11.0, $sum->toArray()[0]->real, 1e-6);
+        $this->assertEqualsWithDelta(2.0, $sum->toArray()[0]->imag, 1e-6);
+
+        $product = $x->multiply($z);
+        $this->assertEqualsWithDelta(60.0, $product->toArray()[1]->real, 1e-6);
+        $this->assertEqualsWithDelta(-20.0, $product->toArray()[1]->imag, 1e-6);
+    }
+
+    public function testComplex64TimesComplex128Promotes(): void
+    {
+        $a = NDArray::array([new Complex(0, 2)], DType::Complex128);
+        $b = NDArray::array([[new Complex(1, 2)], [new Complex(3, -1)]], DType::Complex64);
+
+        $result = $a->multiply($b);
+
+        $this->assertSame(DType::Complex128, $result->dtype());
+        $this->assertSame([2, 1], $result->shape());
+        $this->assertEqualsWithDelta(-4.0, $result->toArray()[0][0]->real, 1e-6);
+        $this->assertEqualsWithDelta(6.0, $result->toArray()[1][0]->imag, 1e-6);
+    }
+
     public function testComplexAbs(): void
     {
         $arr = NDArray::array([
@@ -5,11 +5,19 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\IndexException;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\FFI\Lib;
 use PhpMlKit\NDArray\NDArray;
 use PHPUnit\Framework\TestCase;
 
+use function PhpMlKit\NDArray\from_csv;
+use function PhpMlKit\NDArray\loadz;
+use function PhpMlKit\NDArray\savez;
+use function PhpMlKit\NDArray\to_csv;
+
 /**
  * Tests for conversion operations (toArray, toScalar, copy, astype).
  *
@@ -285,4 +293,294 @@ final class ConversionTest extends TestCase
         $this->expectExceptionMessage('toScalar requires a 0-dimensional array');
         $a->toScalar();
     }
+
+    public function testSaveLoadNpyRoundTrip(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'npy');
+
+        try {
+            foreach ([DType::Int16, DType::UInt64, DType::Float32, DType::Float64, DType::Bool, DType::Complex128] as $dtype) {
+                $a = NDArray::array([[1, 0, 3], [4, 5, 0]], $dtype);
+                $view = $a->slice([':', '1:3']);
+                $view->save($path);
+
+                $loaded = NDArray::load($path);
+                $this->assertSame($dtype, $loaded->dtype(), "Failed for {$dtype->name}");
+                $this->assertSame([2, 2], $loaded->shape());
+                $this->assertEquals($view->toArray(), $loaded->toArray(), "Failed for {$dtype->name}");
+            }
+
+            NDArray::full(2.5, [])->save($path);
+            $this->assertSame(2.5, NDArray::load($path)->toScalar());
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testSaveWritesNpyHeader(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'npy');
+
+        try {
+            NDArray::array([1, 2, 3], DType::Int32)->save($path);
+            $contents = file_get_contents($path);
+
+            $this->assertStringStartsWith("\x93NUMPY\x01\x00", $contents);
+            $this->assertStringContainsString("'descr': '<i4', 'fortran_order': False, 'shape': (3,)", $contents);
+            $this->assertSame(0, (\strlen($contents) - 12) % 64);
+            $this->assertSame(pack('V*', 1, 2, 3), substr($contents, -12));
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testLoadNpyBigEndianFortranOrder(): void
+    {
+        $dict = "{'descr': '>u2', 'fortran_order': True, 'shape': (2, 3), }";
+        $header = str_pad($dict, 128 - 10 - 1)."\n";
+        $path = tempnam(sys_get_temp_dir(), 'npy');
+        file_put_contents($path, "\x93NUMPY\x01\x00".pack('v', \strlen($header)).$header.pack('n*', 1, 4, 2, 5, 3, 6));
+
+        try {
+            $a = NDArray::load($path);
+
+            $this->assertSame(DType::UInt16, $a->dtype());
+            $this->assertSame([[1, 2, 3], [4, 5, 6]], $a->toArray());
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testSaveNpyRejectsBFloat16(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1.0, 2.0], DType::BFloat16)->save(sys_get_temp_dir().'/bf16.npy');
+    }
+
+    public function testLoadNpyMissingFileThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        $this->expectExceptionMessage('Failed to open');
+        NDArray::load(sys_get_temp_dir().'/does-not-exist-'.uniqid().'.npy');
+    }
+
+    public function testSavezLoadzRoundTrip(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'npz');
+
+        try {
+            foreach ([false, true] as $compressed) {
+                $w = NDArray::array([[1.5, 2.5], [3.5, 4.5]], DType::Float32);
+                $b = NDArray::array([1, 2], DType::Int64);
+                NDArray::savez($path, ['W1' => $w, 'b1' => $b, 'mask' => NDArray::array([true, false])], $compressed);
+
+                $loaded = NDArray::loadz($path);
+                $this->assertSame(['W1', 'b1', 'mask'], array_keys($loaded));
+                $this->assertSame(DType::Float32, $loaded['W1']->dtype());
+                $this->assertSame([[1.5, 2.5], [3.5, 4.5]], $loaded['W1']->toArray());
+                $this->assertSame([1, 2], $loaded['b1']->toArray());
+                $this->assertSame([true, false], $loaded['mask']->toArray());
+            }
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testSavezNamesPositionalArrays(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'npz');
+
+        try {
+            savez($path, [NDArray::array([1, 2]), NDArray::array([3])]);
+
+            $this->assertSame(['arr_0', 'arr_1'], array_keys(loadz($path)));
+            $this->assertStringStartsWith("PK\x03\x04", file_get_contents($path));
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testLoadzSelectedNames(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'npz');
+
+        try {
+            NDArray::savez($path, ['a' => NDArray::array([1]), 'b' => NDArray::array([2])], compressed: true);
+            $loaded = NDArray::loadz($path, ['b']);
+
+            $this->assertSame(['b'], array_keys($loaded));
+            $this->assertSame([2], $loaded['b']->toArray());
+
+            $this->expectException(IndexException::class);
+            NDArray::loadz($path, ['c']);
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testSavezRejectsBFloat16(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::savez(sys_get_temp_dir().'/bf16.npz', ['x' => NDArray::array([1.0], DType::BFloat16)]);
+    }
+
+    public function testSerializeRoundTrip(): void
+    {
+        foreach ([DType::Int8, DType::UInt32, DType::Float64, DType::BFloat16, DType::Bool, DType::Complex64] as $dtype) {
+            $a = NDArray::array([[1, 0, 3], [4, 5, 0]], $dtype);
+            $view = $a->slice([':', '::2']);
+
+            $restored = NDArray::unserialize($view->serialize());
+            $this->assertSame($dtype, $restored->dtype(), "Failed for {$dtype->name}");
+            $this->assertSame([2, 2], $restored->shape());
+            $this->assertEquals($view->toArray(), $restored->toArray(), "Failed for {$dtype->name}");
+        }
+
+        $this->assertSame(2.5, NDArray::unserialize(NDArray::full(2.5, [])->serialize())->toScalar());
+    }
+
+    public function testSerializeLayout(): void
+    {
+        $bytes = NDArray::array([1, 2, 3], DType::Int32)->serialize();
+
+        $this->assertSame(8 + 8 + 12, \strlen($bytes));
+        $this->assertStringStartsWith("\x93NDA\x01", $bytes);
+        $this->assertSame(DType::Int32->value, \ord($bytes[6]));
+        $this->assertSame(1, \ord($bytes[7]));
+        $this->assertSame(pack('P', 3), substr($bytes, 8, 8));
+    }
+
+    public function testUnserializeBigEndian(): void
+    {
+        $bytes = "\x93NDA\x01>".\chr(DType::UInt16->value)."\x01".pack('P', 3).pack('n*', 1, 2, 3);
+
+        $a = NDArray::unserialize($bytes);
+        $this->assertSame(DType::UInt16, $a->dtype());
+        $this->assertSame([1, 2, 3], $a->toArray());
+    }
+
+    public function testUnserializeTruncatedThrows(): void
+    {
+        $bytes = NDArray::array([1.0, 2.0], DType::Float64)->serialize();
+
+        $this->expectException(NDArrayException::class);
+        $this->expectExceptionMessage('Invalid array buffer');
+        NDArray::unserialize(substr($bytes, 0, -1));
+    }
+
+    public function testCsvRoundTrip(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'csv');
+
+        try {
+            $a = NDArray::array([[1.5, -2.0, NAN], [0.1, 1e-7, 3.0]], DType::Float64);
+            $a->toCsv($path, header: 'a,b,c');
+
+            $this->assertSame("a,b,c\n1.5,-2.0,nan\n0.1,1e-7,3.0\n", file_get_contents($path));
+
+            $loaded = NDArray::fromCsv($path, skipRows: 1);
+            $this->assertSame([2, 3], $loaded->shape());
+            $this->assertSame([1.5, -2.0], $loaded->slice([0, '0:2'])->toArray());
+            $this->assertNan($loaded->get(0, 2));
+            $this->assertSame([0.1, 1e-7, 3.0], $loaded->slice([1])->toArray());
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testFromCsvQuotedFieldsAndFill(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'csv');
+        file_put_contents($path, "x\ty\n\"1\"\t2\n\n3\t\n");
+
+        try {
+            $a = NDArray::fromCsv($path, "\t", 1, DType::Int32, -1);
+
+            $this->assertSame(DType::Int32, $a->dtype());
+            $this->assertSame([[1, 2], [3, -1]], $a->toArray());
+
+            $this->expectException(NDArrayException::class);
+            $this->expectExceptionMessage('Missing value on line 4');
+            NDArray::fromCsv($path, "\t", 1, DType::Int32);
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testFromCsvRaggedRowsThrows(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'csv');
+        file_put_contents($path, "1,2\n3\n");
+
+        try {
+            $this->expectException(ShapeException::class);
+            NDArray::fromCsv($path);
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testToCsvOneDimensionalIsColumn(): void
+    {
+        $path = tempnam(sys_get_temp_dir(), 'csv');
+
+        try {
+            to_csv($path, NDArray::array([true, false, true]));
+
+            $this->assertSame("1\n0\n1\n", file_get_contents($path));
+            $this->assertSame([[true], [false], [true]], from_csv($path, dtype: DType::Bool)->toArray());
+        } finally {
+            unlink($path);
+        }
+    }
+
+    public function testArrowRoundTrip(): void
+    {
+        foreach ([DType::Int16, DType::UInt8, DType::Float32, DType::Float64, DType::Bool] as $dtype) {
+            $a = NDArray::array([[[1, 0], [3, 4]], [[0, 6], [7, 1]]], $dtype);
+            $view = $a->transpose();
+
+            $restored = NDArray::fromArrow($this->arrowOrSkip($view));
+            $this->assertSame($dtype, $restored->dtype(), "Failed for {$dtype->name}");
+            $this->assertSame([2, 2, 2], $restored->shape());
+            $this->assertEquals($view->toArray(), $restored->toArray(), "Failed for {$dtype->name}");
+        }
+
+        $vector = NDArray::array([1.5, 2.5, 3.5]);
+        $this->assertSame([1.5, 2.5, 3.5], NDArray::fromArrow($this->arrowOrSkip($vector))->toArray());
+
+        $scalar = NDArray::fromArrow($this->arrowOrSkip(NDArray::full(2.5, [])));
+        $this->assertSame([], $scalar->shape());
+        $this->assertSame(2.5, $scalar->toArray());
+    }
+
+    public function testToArrowRejectsComplex(): void
+    {
+        $this->expectException(DTypeException::class);
+        $this->arrowOrSkip(NDArray::array([1.0, 2.0], DType::Complex64));
+    }
+
+    public function testFromArrowRejectsInvalidStream(): void
+    {
+        $this->arrowOrSkip(NDArray::array([1]));
+
+        $this->expectException(NDArrayException::class);
+        NDArray::fromArrow('not an arrow stream');
+    }
+
+    /**
+     * Encode an array as Arrow IPC, skipping the test when the library was built without Arrow.
+     */
+    private function arrowOrSkip(NDArray $array): string
+    {
+        try {
+            return $array->toArrow();
+        } catch (NDArrayException $e) {
+            if (str_contains($e->getMessage(), '`arrow` feature')) {
+                $this->markTestSkipped('Native library built without the arrow feature');
+            }
+
+            throw $e;
+        }
+    }
 }
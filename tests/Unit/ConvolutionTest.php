@@ -0,0 +1,87 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\ConvolveMode;
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for 1D convolution and 2D convolution / cross-correlation.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class ConvolutionTest extends TestCase
+{
+    public function testConvolveModes(): void
+    {
+        $x = NDArray::array([1, 2, 3]);
+
+        $full = $x->convolve([0, 1, 0.5]);
+        $this->assertSame(DType::Float64, $full->dtype());
+        $this->assertSame([0.0, 1.0, 2.5, 4.0, 1.5], $full->toArray());
+        $this->assertSame([1.0, 2.5, 4.0], $x->convolve([0, 1, 0.5], ConvolveMode::Same)->toArray());
+        $this->assertSame([2.5], $x->convolve([0, 1, 0.5], ConvolveMode::Valid)->toArray());
+
+        // Operand order does not matter, as in NumPy.
+        $short = NDArray::array([1.0, 1.0], DType::Float32);
+        $same = $short->convolve([1, 2, 3, 4, 5], ConvolveMode::Same);
+        $this->assertSame(DType::Float64, $same->dtype());
+        $this->assertSame([1.0, 3.0, 5.0, 7.0, 9.0], $same->toArray());
+    }
+
+    public function testConvolveRejectsNon1D(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([[1.0, 2.0]])->convolve([1.0]);
+    }
+
+    public function testConvolveRejectsComplex(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1.0, 2.0], DType::Complex128)->convolve([1.0]);
+    }
+
+    public function testConvolve2dAndCorrelate2d(): void
+    {
+        $x = NDArray::array([[1, 2, 3], [4, 5, 6], [7, 8, 9]], DType::Float32);
+        $k = [[1, 0], [0, -1]];
+
+        $corr = $x->correlate2d($k, ConvolveMode::Valid);
+        $this->assertSame(DType::Float32, $corr->dtype());
+        $this->assertSame([[-4.0, -4.0], [-4.0, -4.0]], $corr->toArray());
+        $this->assertSame([[4.0, 4.0], [4.0, 4.0]], $x->convolve2d($k, ConvolveMode::Valid)->toArray());
+        $this->assertSame([4, 4], $x->convolve2d($k)->shape());
+        $this->assertSame([3, 3], $x->convolve2d($k, ConvolveMode::Same)->shape());
+    }
+
+    public function testCorrelate2dWithStrideAndPadding(): void
+    {
+        $x = NDArray::array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        $k = NDArray::array([[1.0, 0.0], [0.0, -1.0]]);
+
+        $out = $x->correlate2d($k, ConvolveMode::Valid, stride: 2, padding: 1);
+        $this->assertSame([[-1.0, -3.0], [-7.0, -4.0]], $out->toArray());
+        $this->assertSame([4, 1], $x->correlate2d($k, ConvolveMode::Valid, [1, 2], [1, 0])->shape());
+    }
+
+    public function testCorrelate2dRejectsOversizedKernel(): void
+    {
+        $x = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+        $this->expectException(ShapeException::class);
+        $x->correlate2d(NDArray::ones([3, 3]), ConvolveMode::Valid);
+    }
+
+    public function testConvolve2dRejectsZeroStride(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::array([[1.0]])->convolve2d([[1.0]], stride: 0);
+    }
+}
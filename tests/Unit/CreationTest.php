@@ -7,8 +7,11 @@ namespace PhpMlKit\NDArray\Tests\Unit;
 use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\MathException;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\Rng;
 use PHPUnit\Framework\TestCase;
 
 use function PhpMlKit\NDArray\from_scalar;
@@ -279,6 +282,19 @@ final class CreationTest extends TestCase
         NDArray::empty([2, 3], DType::Float64);
     }
 
+    public function testTri(): void
+    {
+        $arr = NDArray::tri(3, 4, 1, DType::Int64);
+
+        $this->assertSame(DType::Int64, $arr->dtype());
+        $this->assertSame([
+            [1, 1, 0, 0],
+            [1, 1, 1, 0],
+            [1, 1, 1, 1],
+        ], $arr->toArray());
+        $this->assertSame([[false, false], [true, false]], NDArray::tri(2, k: -1, dtype: DType::Bool)->toArray());
+    }
+
     public function testEyeRectangle(): void
     {
         $arr = NDArray::eye(2, 3);
@@ -391,6 +407,25 @@ final class CreationTest extends TestCase
         NDArray::arange(0, 5, 1, DType::Bool);
     }
 
+    public function testArangeInt64IsExactBeyondFloatPrecision(): void
+    {
+        $start = (1 << 53) + 1;
+        $arr = NDArray::arange($start, $start + 3, 1, DType::Int64);
+
+        $this->assertSame([$start, $start + 1, $start + 2], $arr->toArray());
+        $this->assertSame(
+            [PHP_INT_MAX - 1, PHP_INT_MAX - 3],
+            NDArray::arange(PHP_INT_MAX - 1, PHP_INT_MAX - 5, -2, DType::Int64)->toArray()
+        );
+    }
+
+    public function testArangeIntegerOutOfRangeThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        $this->expectExceptionMessage('out of range');
+        NDArray::arange(250, 260, 1, DType::UInt8);
+    }
+
     public function testLinspaceDefault(): void
     {
         $arr = NDArray::linspace(0.0, 1.0);
@@ -442,10 +477,31 @@ final class CreationTest extends TestCase
         NDArray::linspace(0.0, 1.0, 0);
     }
 
-    public function testLinspaceIntThrows(): void
+    public function testLinspaceInteger(): void
+    {
+        $arr = NDArray::linspace(0, 10, 6, true, DType::Int64);
+
+        $this->assertSame(DType::Int64, $arr->dtype());
+        $this->assertSame([0, 2, 4, 6, 8, 10], $arr->toArray());
+    }
+
+    public function testLinspaceIntegerRoundsDown(): void
+    {
+        $this->assertSame([0, 0, 0, 1], NDArray::linspace(0.0, 1.0, 4, true, DType::Int32)->toArray());
+        $this->assertSame([-1, -1, 0], NDArray::linspace(-1.0, 0.0, 3, true, DType::Int32)->toArray());
+        $this->assertSame([0, 2, 5, 7], NDArray::linspace(0, 10, 4, false, DType::UInt8)->toArray());
+    }
+
+    public function testLinspaceIntegerOutOfRangeThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        NDArray::linspace(-1.0, 1.0, 3, true, DType::UInt8);
+    }
+
+    public function testLinspaceBoolThrows(): void
     {
         $this->expectException(\InvalidArgumentException::class);
-        NDArray::linspace(0.0, 1.0, 5, true, DType::Int32);
+        NDArray::linspace(0.0, 1.0, 5, true, DType::Bool);
     }
 
     public function testLogspaceDefault(): void
@@ -690,6 +746,279 @@ final class CreationTest extends TestCase
         NDArray::uniform(1.0, 1.0, [4]);
     }
 
+    public function testStandardCauchyCentralMassAndSeed(): void
+    {
+        $arr = NDArray::standardCauchy([20000], DType::Float64, seed: 3);
+
+        $this->assertSame([20000], $arr->shape());
+        // Half of the mass lies in (-1, 1).
+        $inside = \count(array_filter($arr->toArray(), static fn (float $v) => abs($v) < 1.0));
+        $this->assertEqualsWithDelta(0.5, $inside / 20000, 0.02);
+        $this->assertSame($arr->toArray(), NDArray::standardCauchy([20000], DType::Float64, seed: 3)->toArray());
+        $this->assertSame(DType::Float32, NDArray::standardCauchy([4], DType::Float32)->dtype());
+    }
+
+    public function testStandardTStatisticalSanity(): void
+    {
+        // Var(t) = df / (df - 2)
+        $arr = NDArray::standardT(10.0, [20000], DType::Float64, seed: 21);
+
+        $this->assertEqualsWithDelta(0.0, $arr->mean(), 0.05);
+        $this->assertEqualsWithDelta(1.25, $arr->var(), 0.1);
+    }
+
+    public function testStandardTRequiresPositiveDf(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::standardT(0.0, [4]);
+    }
+
+    public function testGeometricCountsTrials(): void
+    {
+        $arr = NDArray::geometric(0.25, [20000], seed: 5);
+
+        $this->assertSame(DType::Int64, $arr->dtype());
+        $this->assertSame(1, $arr->min());
+        $this->assertEqualsWithDelta(4.0, $arr->mean(), 0.1);
+        $this->assertSame([1, 1, 1], NDArray::geometric(1.0, [3])->toArray());
+    }
+
+    public function testGeometricRequiresValidProbability(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::geometric(0.0, [4]);
+    }
+
+    public function testHypergeometricRangeAndMean(): void
+    {
+        $arr = NDArray::hypergeometric(30, 70, 10, [20000], seed: 9);
+
+        $this->assertSame(DType::Int64, $arr->dtype());
+        $this->assertGreaterThanOrEqual(0, $arr->min());
+        $this->assertLessThanOrEqual(10, $arr->max());
+        $this->assertEqualsWithDelta(3.0, $arr->mean(), 0.05);
+        $this->assertSame($arr->toArray(), NDArray::hypergeometric(30, 70, 10, [20000], seed: 9)->toArray());
+    }
+
+    public function testHypergeometricRejectsOversizedSample(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::hypergeometric(2, 3, 6, [4]);
+    }
+
+    public function testBinomialRangeAndMean(): void
+    {
+        $arr = NDArray::binomial(10, 0.3, [20000], seed: 13);
+
+        $this->assertSame(DType::Int64, $arr->dtype());
+        $this->assertGreaterThanOrEqual(0, $arr->min());
+        $this->assertLessThanOrEqual(10, $arr->max());
+        $this->assertEqualsWithDelta(3.0, $arr->mean(), 0.05);
+        $this->assertSame($arr->toArray(), NDArray::binomial(10, 0.3, [20000], seed: 13)->toArray());
+    }
+
+    public function testPoissonMeanAndZeroRate(): void
+    {
+        $arr = NDArray::poisson(4.0, [20000], seed: 17);
+
+        $this->assertSame(DType::Int64, $arr->dtype());
+        $this->assertGreaterThanOrEqual(0, $arr->min());
+        $this->assertEqualsWithDelta(4.0, $arr->mean(), 0.08);
+        $this->assertSame([0, 0, 0], NDArray::poisson(0.0, [3])->toArray());
+    }
+
+    public function testExponentialMean(): void
+    {
+        $arr = NDArray::exponential(2.5, [20000], DType::Float32, seed: 19);
+
+        $this->assertSame(DType::Float32, $arr->dtype());
+        $this->assertGreaterThanOrEqual(0.0, $arr->min());
+        $this->assertEqualsWithDelta(2.5, $arr->mean(), 0.08);
+    }
+
+    public function testGammaMeanAndVariance(): void
+    {
+        $arr = NDArray::gamma(2.0, 1.5, [20000], seed: 23);
+
+        $this->assertSame(DType::Float64, $arr->dtype());
+        $this->assertGreaterThan(0.0, $arr->min());
+        $this->assertEqualsWithDelta(3.0, $arr->mean(), 0.08);
+        $this->assertEqualsWithDelta(4.5, $arr->var(), 0.3);
+    }
+
+    public function testBetaRangeAndMean(): void
+    {
+        $arr = NDArray::beta(2.0, 6.0, [20000], seed: 29);
+
+        $this->assertGreaterThanOrEqual(0.0, $arr->min());
+        $this->assertLessThanOrEqual(1.0, $arr->max());
+        $this->assertEqualsWithDelta(0.25, $arr->mean(), 0.01);
+    }
+
+    public function testChisquareMean(): void
+    {
+        $arr = NDArray::chisquare(3.0, [20000], seed: 31);
+
+        $this->assertGreaterThanOrEqual(0.0, $arr->min());
+        $this->assertEqualsWithDelta(3.0, $arr->mean(), 0.1);
+        $this->assertSame($arr->toArray(), NDArray::chisquare(3.0, [20000], seed: 31)->toArray());
+    }
+
+    public function testDistributionsRejectInvalidParameters(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::gamma(0.0, 1.0, [4]);
+    }
+
+    public function testDistributionsRejectIntegerDtype(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::beta(1.0, 1.0, [4], DType::Int32);
+    }
+
+    public function testMultivariateNormalMoments(): void
+    {
+        $x = NDArray::multivariateNormal([1.0, -2.0], [[2.0, 0.6], [0.6, 1.0]], 20000, seed: 37);
+
+        $this->assertSame([20000, 2], $x->shape());
+        $this->assertSame(DType::Float64, $x->dtype());
+        $mean = $x->mean(axis: 0)->toArray();
+        $this->assertEqualsWithDelta(1.0, $mean[0], 0.05);
+        $this->assertEqualsWithDelta(-2.0, $mean[1], 0.05);
+        $var = $x->var(axis: 0)->toArray();
+        $this->assertEqualsWithDelta(2.0, $var[0], 0.1);
+        $this->assertEqualsWithDelta(1.0, $var[1], 0.05);
+    }
+
+    public function testMultivariateNormalSingleVector(): void
+    {
+        $x = NDArray::multivariateNormal([0.0, 0.0, 0.0], NDArray::eye(3), dtype: DType::Float32, seed: 1);
+
+        $this->assertSame([3], $x->shape());
+        $this->assertSame(DType::Float32, $x->dtype());
+    }
+
+    public function testMultivariateNormalRejectsIndefiniteCovariance(): void
+    {
+        $this->expectException(MathException::class);
+        NDArray::multivariateNormal([0.0, 0.0], [[1.0, 2.0], [2.0, 1.0]], 4);
+    }
+
+    public function testPermutationContainsEveryIndexOnce(): void
+    {
+        $perm = NDArray::permutation(50, seed: 4);
+
+        $this->assertSame(DType::Int64, $perm->dtype());
+        $this->assertSame([50], $perm->shape());
+        $values = $perm->toArray();
+        sort($values);
+        $this->assertSame(range(0, 49), $values);
+        $this->assertSame($perm->toArray(), NDArray::permutation(50, seed: 4)->toArray());
+        $this->assertSame([], NDArray::permutation(0)->toArray());
+    }
+
+    public function testShuffleMatchesPermutation(): void
+    {
+        $arr = NDArray::arange(20, dtype: DType::Int64);
+        $arr->shuffle(seed: 8);
+
+        $this->assertSame(NDArray::permutation(20, seed: 8)->toArray(), $arr->toArray());
+    }
+
+    public function testShuffleKeepsRowsIntact(): void
+    {
+        $arr = NDArray::arange(12, dtype: DType::Float32)->reshape([6, 2]);
+        $arr->shuffle(rng: Rng::seeded(3));
+
+        $rows = $arr->toArray();
+        foreach ($rows as $row) {
+            $this->assertSame($row[0] + 1.0, $row[1]);
+        }
+        sort($rows);
+        $this->assertSame([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0], [6.0, 7.0], [8.0, 9.0], [10.0, 11.0]], $rows);
+    }
+
+    public function testShuffleAlongLastAxisOfView(): void
+    {
+        $arr = NDArray::arange(8, dtype: DType::Int32)->reshape([2, 4]);
+        $arr->slice([1])->shuffle(axis: -1, seed: 2);
+
+        $this->assertSame([0, 1, 2, 3], $arr->toArray()[0]);
+        $row = $arr->toArray()[1];
+        sort($row);
+        $this->assertSame([4, 5, 6, 7], $row);
+    }
+
+    public function testChoiceFromRangeWithoutReplacement(): void
+    {
+        $idx = NDArray::choice(10, 10, replace: false, seed: 6);
+
+        $this->assertSame(DType::Int64, $idx->dtype());
+        $values = $idx->toArray();
+        sort($values);
+        $this->assertSame(range(0, 9), $values);
+    }
+
+    public function testChoiceSamplesValuesWithWeights(): void
+    {
+        $population = NDArray::array([1.5, 2.5, 3.5], DType::Float32);
+        $sample = NDArray::choice($population, [4000], p: [0.0, 0.75, 0.25], seed: 12);
+
+        $this->assertSame(DType::Float32, $sample->dtype());
+        $this->assertSame([4000], $sample->shape());
+        $this->assertGreaterThanOrEqual(2.5, $sample->min());
+        $this->assertEqualsWithDelta(2.75, $sample->mean(), 0.03);
+    }
+
+    public function testChoiceWithoutSizeReturnsScalar(): void
+    {
+        $pick = NDArray::choice([10, 20, 30], seed: 1);
+
+        $this->assertContains($pick, [10, 20, 30]);
+    }
+
+    public function testChoiceRejectsOversizedSampleWithoutReplacement(): void
+    {
+        $this->expectException(NDArrayException::class);
+        NDArray::choice(3, 4, replace: false);
+    }
+
+    public function testRngStreamIsReproducible(): void
+    {
+        $rng = Rng::seeded(42);
+        $first = NDArray::randn([16], rng: $rng)->toArray();
+        $second = NDArray::randn([16], rng: $rng)->toArray();
+
+        $this->assertNotSame($first, $second);
+
+        $replay = Rng::seeded(42);
+        $this->assertSame($first, NDArray::randn([16], rng: $replay)->toArray());
+        $this->assertSame($second, NDArray::randn([16], rng: $replay)->toArray());
+    }
+
+    public function testRngIsSharedAcrossGenerators(): void
+    {
+        $a = Rng::seeded(5);
+        $b = Rng::seeded(5);
+
+        $this->assertSame(
+            NDArray::uniform(0.0, 1.0, [8], rng: $a)->toArray(),
+            NDArray::uniform(0.0, 1.0, [8], rng: $b)->toArray(),
+        );
+        $this->assertSame(
+            NDArray::randomInt(0, 100, [8], rng: $a)->toArray(),
+            NDArray::randomInt(0, 100, [8], rng: $b)->toArray(),
+        );
+    }
+
+    public function testRngOverridesSeed(): void
+    {
+        $withRng = NDArray::random([8], seed: 1, rng: Rng::seeded(2))->toArray();
+
+        $this->assertSame(NDArray::random([8], rng: Rng::seeded(2))->toArray(), $withRng);
+        $this->assertNotSame(NDArray::random([8], seed: 1)->toArray(), $withRng);
+    }
+
     // =========================================================================
     // fromBuffer Tests
     // =========================================================================
@@ -0,0 +1,106 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DistanceMetric;
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for row-matrix similarity and distance kernels.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class DistanceTest extends TestCase
+{
+    public function testCosineSimilarityMatrix(): void
+    {
+        $a = NDArray::array([[1.0, 0.0], [3.0, 4.0]]);
+        $b = NDArray::array([[1.0, 1.0], [0.0, 2.0], [0.0, 0.0]]);
+
+        $this->assertEqualsWithDelta(
+            [[sqrt(0.5), 0.0, 0.0], [0.7 * sqrt(2.0), 0.8, 0.0]],
+            $a->cosineSimilarity($b)->toArray(),
+            1e-12
+        );
+    }
+
+    public function testCosineSimilarityWithSelf(): void
+    {
+        $a = NDArray::array([[1, 2], [-2, -4]], DType::Float32);
+        $sim = $a->cosineSimilarity();
+
+        $this->assertSame(DType::Float32, $sim->dtype());
+        $this->assertEqualsWithDelta([[1.0, -1.0], [-1.0, 1.0]], $sim->toArray(), 1e-6);
+    }
+
+    public function testCosineSimilarityIntegerInputReturnsFloat64(): void
+    {
+        $a = NDArray::array([[1, 0]], DType::Int32);
+
+        $this->assertSame(DType::Float64, $a->cosineSimilarity()->dtype());
+    }
+
+    public function testCosineSimilarityFeatureMismatchThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::zeros([2, 3])->cosineSimilarity(NDArray::zeros([2, 2]));
+    }
+
+    public function testCosineSimilarityRequires2D(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::zeros([3])->cosineSimilarity();
+    }
+
+    public function testNearestNeighborsEuclidean(): void
+    {
+        $db = NDArray::array([[1, 0], [4, 4], [0, 2], [10, 10]], DType::Float64);
+        $queries = NDArray::array([[0, 0], [5, 5]], DType::Float64);
+
+        [$idx, $dist] = $queries->nearestNeighbors($db, k: 2);
+
+        $this->assertSame(DType::Int64, $idx->dtype());
+        $this->assertSame([[0, 2], [1, 0]], $idx->toArray());
+        $this->assertEqualsWithDelta([[1.0, 2.0], [sqrt(2.0), sqrt(34.0)]], $dist->toArray(), 1e-12);
+    }
+
+    public function testNearestNeighborsOtherMetrics(): void
+    {
+        $db = NDArray::array([[1.0, 0.0], [0.0, 3.0], [-2.0, 0.0]]);
+        $query = NDArray::array([[0.0, 1.0]]);
+
+        [$idx, $dist] = $query->nearestNeighbors($db, k: 3, metric: DistanceMetric::Cosine);
+        $this->assertSame([[1, 0, 2]], $idx->toArray());
+        $this->assertEqualsWithDelta([[0.0, 1.0, 1.0]], $dist->toArray(), 1e-12);
+
+        [, $dist] = $query->nearestNeighbors($db, metric: DistanceMetric::Manhattan);
+        $this->assertEqualsWithDelta([[2.0]], $dist->toArray(), 1e-12);
+
+        [, $dist] = $query->nearestNeighbors($db, metric: DistanceMetric::SquaredEuclidean);
+        $this->assertEqualsWithDelta([[2.0]], $dist->toArray(), 1e-12);
+    }
+
+    public function testNearestNeighborsClampsKAndKeepsFloat32(): void
+    {
+        $db = NDArray::array([[0.0], [1.0]], DType::Float32);
+
+        [$idx, $dist] = NDArray::array([[0.9]], DType::Float32)->nearestNeighbors($db, k: 5);
+
+        $this->assertSame([1, 2], $idx->shape());
+        $this->assertSame([[1, 0]], $idx->toArray());
+        $this->assertSame(DType::Float32, $dist->dtype());
+    }
+
+    public function testNearestNeighborsInvalidK(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::zeros([1, 2])->nearestNeighbors(NDArray::zeros([3, 2]), k: 0);
+    }
+}
@@ -241,4 +241,59 @@ class FlatIndexingTest extends TestCase
         $this->expectException(IndexException::class);
         $empty->setAt(0, 1);
     }
+
+    public function testGetManyFlatIndices(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]]);
+        $this->assertSame([1, 5, 6], $arr->getMany([0, 4, -1]));
+    }
+
+    public function testGetManyCoordinates(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]]);
+        $this->assertSame([3, 4, 6], $arr->getMany([[0, 2], [1, 0], [-1, -1]]));
+    }
+
+    public function testGetManyOnTransposedView(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]]);
+        $t = $arr->transpose();
+        $this->assertSame([1, 4, 2, 5], $t->getMany([0, 1, 2, 3]));
+        $this->assertSame([3], $t->getMany([[2, 0]]));
+    }
+
+    public function testSetManyCoordinatesWritesThroughView(): void
+    {
+        $arr = NDArray::zeros([2, 3], DType::Int64);
+        $view = $arr->slice([':', '1:']);
+        $view->setMany([[0, 0], [1, 1]], [7, 9]);
+        $this->assertSame([[0, 7, 0], [0, 0, 9]], $arr->toArray());
+    }
+
+    public function testSetManyLastDuplicateWins(): void
+    {
+        $arr = NDArray::zeros([3], DType::Float64);
+        $arr->setMany([1, 1], [2.0, 5.0]);
+        $this->assertSame([0.0, 5.0, 0.0], $arr->toArray());
+    }
+
+    public function testSetManyOutOfBoundsLeavesArrayUnchanged(): void
+    {
+        $arr = NDArray::array([1, 2, 3]);
+
+        try {
+            $arr->setMany([0, 3], [9, 9]);
+            $this->fail('Expected IndexException');
+        } catch (IndexException) {
+        }
+        $this->assertSame([1, 2, 3], $arr->toArray());
+    }
+
+    public function testSetManyValueCountMismatchThrows(): void
+    {
+        $arr = NDArray::array([1, 2, 3]);
+
+        $this->expectException(\InvalidArgumentException::class);
+        $arr->setMany([0, 1], [5]);
+    }
 }
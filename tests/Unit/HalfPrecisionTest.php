@@ -0,0 +1,210 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * @internal
+ *
+ * @coversNothing
+ */
+final class HalfPrecisionTest extends TestCase
+{
+    public function testFloat16RoundTrip(): void
+    {
+        $arr = NDArray::array([[1.0, -2.5], [0.0999755859375, 65504.0]], DType::Float16);
+
+        $this->assertSame(DType::Float16, $arr->dtype());
+        $this->assertSame(2, $arr->itemsize());
+        $this->assertSame(8, $arr->nbytes());
+        $this->assertSame([[1.0, -2.5], [0.0999755859375, 65504.0]], $arr->toArray());
+    }
+
+    public function testFloat16RoundsToNearestEven(): void
+    {
+        $arr = NDArray::array([0.1, 1.0 + 2 ** -11, 70000.0], DType::Float16);
+
+        $this->assertSame([0.0999755859375, 1.0, \INF], $arr->toArray());
+    }
+
+    public function testBFloat16KeepsFloat32Range(): void
+    {
+        $arr = NDArray::array([1.0, 3.0e38, -0.5], DType::BFloat16);
+
+        $values = $arr->toArray();
+        $this->assertSame(1.0, $values[0]);
+        $this->assertEqualsWithDelta(3.0e38, $values[1], 3.0e36);
+        $this->assertSame(-0.5, $values[2]);
+    }
+
+    public function testBitPatternsCrossTheFfiBoundary(): void
+    {
+        $this->assertSame(0x3C00, DType::Float16->toHalfBits(1.0));
+        $this->assertSame(0x3F80, DType::BFloat16->toHalfBits(1.0));
+        $this->assertSame(0x7C00, DType::Float16->toHalfBits(\INF));
+        $this->assertTrue(is_nan(DType::Float16->fromHalfBits(DType::Float16->toHalfBits(\NAN))));
+        $this->assertSame(2 ** -24, DType::Float16->fromHalfBits(0x0001));
+    }
+
+    public function testArithmeticKeepsHalfDtype(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 0.5], DType::Float16);
+        $b = NDArray::array([1.0, 1.0, 1.0], DType::Float16);
+
+        $sum = $a->add($b);
+        $this->assertSame(DType::Float16, $sum->dtype());
+        $this->assertSame([2.0, 3.0, 1.5], $sum->toArray());
+
+        $scaled = $a->multiply(3);
+        $this->assertSame(DType::Float16, $scaled->dtype());
+        $this->assertSame([3.0, 6.0, 1.5], $scaled->toArray());
+    }
+
+    public function testMixedHalfPromotion(): void
+    {
+        $f16 = NDArray::array([1.0, 2.0], DType::Float16);
+        $bf16 = NDArray::array([1.0, 2.0], DType::BFloat16);
+        $i8 = NDArray::array([1, 2], DType::Int8);
+        $i32 = NDArray::array([1, 2], DType::Int32);
+
+        $this->assertSame(DType::Float32, $f16->add($bf16)->dtype());
+        $this->assertSame(DType::Float16, $f16->add($i8)->dtype());
+        $this->assertSame(DType::Float64, $bf16->add($i32)->dtype());
+    }
+
+    public function testAstype(): void
+    {
+        $arr = NDArray::array([1.5, -2.25, 100.0], DType::Float64);
+
+        $f16 = $arr->astype(DType::Float16);
+        $this->assertSame(DType::Float16, $f16->dtype());
+        $this->assertSame([1.5, -2.25, 100.0], $f16->astype(DType::Float32)->toArray());
+
+        $bf16 = $f16->astype(DType::BFloat16);
+        $this->assertSame(DType::BFloat16, $bf16->dtype());
+        $this->assertSame([1.5, -2.25, 100.0], $bf16->toArray());
+
+        $this->assertSame([1, -2, 100], $bf16->astype(DType::Int32)->toArray());
+    }
+
+    public function testReductionsReturnFloat32(): void
+    {
+        $arr = NDArray::array([[1.0, 2.0], [3.0, 4.0]], DType::Float16);
+
+        $this->assertEqualsWithDelta(10.0, $arr->sum(), 1e-6);
+        $this->assertSame(DType::Float32, $arr->sum(axis: 0)->dtype());
+        $this->assertSame([4.0, 6.0], $arr->sum(axis: 0)->toArray());
+    }
+
+    public function testShapeOpsAndIndexingPreserveBits(): void
+    {
+        $arr = NDArray::array([[1.0, 2.0], [3.0, 4.0]], DType::BFloat16);
+
+        $t = $arr->transpose();
+        $this->assertSame(DType::BFloat16, $t->dtype());
+        $this->assertSame([[1.0, 3.0], [2.0, 4.0]], $t->toArray());
+
+        $arr->set([0, 1], 0.5);
+        $this->assertSame(0.5, $arr->get(0, 1));
+        $this->assertSame([1.0, 0.5, 3.0, 4.0], $arr->flatten()->toArray());
+    }
+
+    public function testBytesRoundTrip(): void
+    {
+        $arr = NDArray::array([1.0, -2.0, 0.25], DType::Float16);
+
+        $bytes = $arr->toBytes();
+        $this->assertSame(6, \strlen($bytes));
+        $this->assertSame(pack('v*', 0x3C00, 0xC000, 0x3400), $bytes);
+
+        $restored = NDArray::fromBytes($bytes, [3], DType::Float16);
+        $this->assertSame([1.0, -2.0, 0.25], $restored->toArray());
+    }
+
+    public function testRootsAndPowersKeepHalfDtype(): void
+    {
+        foreach ([DType::Float16, DType::BFloat16] as $dtype) {
+            $arr = NDArray::array([1.0, 4.0, 0.25], $dtype);
+
+            $sqrt = $arr->sqrt();
+            $this->assertSame($dtype, $sqrt->dtype());
+            $this->assertSame([1.0, 2.0, 0.5], $sqrt->toArray());
+
+            $this->assertSame([1.0, 16.0, 0.0625], $arr->powi(2)->toArray());
+            $this->assertSame([1.0, 2.0, 0.5], $arr->powf(0.5)->toArray());
+            $this->assertSame([1.0, 0.25, 4.0], $arr->recip()->toArray());
+            $this->assertSame([2.0, -3.0], NDArray::array([8.0, -27.0], $dtype)->cbrt()->toArray());
+            $this->assertSame([5.0], NDArray::array([3.0], $dtype)->hypot(4.0)->toArray());
+        }
+    }
+
+    public function testExpAndLogKeepHalfDtype(): void
+    {
+        foreach ([DType::Float16, DType::BFloat16] as $dtype) {
+            $arr = NDArray::array([1.0, 8.0], $dtype);
+
+            $exp = NDArray::array([0.0, 1.0], $dtype)->exp();
+            $this->assertSame($dtype, $exp->dtype());
+            $this->assertEqualsWithDelta([1.0, \M_E], $exp->toArray(), 2e-2);
+
+            $this->assertSame([2.0, 256.0], $arr->exp2()->toArray());
+            $this->assertSame([0.0, 3.0], $arr->log2()->toArray());
+            $this->assertEqualsWithDelta([0.0, log(8.0)], $arr->ln()->toArray(), 2e-2);
+            $this->assertEqualsWithDelta([0.0, log10(8.0)], $arr->log10()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([log(2.0), log(9.0)], $arr->ln1p()->toArray(), 2e-2);
+        }
+    }
+
+    public function testTrigonometryKeepsHalfDtype(): void
+    {
+        foreach ([DType::Float16, DType::BFloat16] as $dtype) {
+            $arr = NDArray::array([0.0, \M_PI_2], $dtype);
+
+            $sin = $arr->sin();
+            $this->assertSame($dtype, $sin->dtype());
+            $this->assertEqualsWithDelta([0.0, 1.0], $sin->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([1.0, 0.0], $arr->cos()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([0.0, 1.0], NDArray::array([0.0, \M_PI_4], $dtype)->tan()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([0.0, 90.0], $arr->toDegrees()->toArray(), 0.5);
+            $this->assertEqualsWithDelta([0.0, \M_PI], NDArray::array([0.0, 180.0], $dtype)->toRadians()->toArray(), 1e-2);
+        }
+    }
+
+    public function testHyperbolicAndInverseTrigKeepHalfDtype(): void
+    {
+        foreach ([DType::Float16, DType::BFloat16] as $dtype) {
+            $arr = NDArray::array([0.0, 1.0], $dtype);
+
+            $tanh = $arr->tanh();
+            $this->assertSame($dtype, $tanh->dtype());
+            $this->assertEqualsWithDelta([0.0, tanh(1.0)], $tanh->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([0.0, sinh(1.0)], $arr->sinh()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([1.0, cosh(1.0)], $arr->cosh()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([0.0, \M_PI_2], $arr->asin()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([\M_PI_2, 0.0], $arr->acos()->toArray(), 1e-2);
+            $this->assertEqualsWithDelta([0.0, \M_PI_4], $arr->atan()->toArray(), 1e-2);
+        }
+    }
+
+    public function testRoundingAndSoftmaxKeepHalfDtype(): void
+    {
+        foreach ([DType::Float16, DType::BFloat16] as $dtype) {
+            $arr = NDArray::array([1.25, -1.5], $dtype);
+
+            $floor = $arr->floor();
+            $this->assertSame($dtype, $floor->dtype());
+            $this->assertSame([1.0, -2.0], $floor->toArray());
+            $this->assertSame([2.0, -1.0], $arr->ceil()->toArray());
+            $this->assertSame([1.0, -2.0], $arr->round()->toArray());
+
+            $softmax = NDArray::array([[0.0, 0.0], [1.0, 1.0]], $dtype)->softmax();
+            $this->assertSame($dtype, $softmax->dtype());
+            $this->assertSame([[0.5, 0.5], [0.5, 0.5]], $softmax->toArray());
+        }
+    }
+}
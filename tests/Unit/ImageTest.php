@@ -0,0 +1,212 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\ImageFormat;
+use PhpMlKit\NDArray\ImageLayout;
+use PhpMlKit\NDArray\Interpolation;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for image tensor preprocessing.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class ImageTest extends TestCase
+{
+    public function testResizeBilinearUpscale(): void
+    {
+        $img = NDArray::array([[0, 10], [20, 30]], DType::Float64);
+        $result = $img->resizeImage(4, 4);
+
+        $this->assertSame([4, 4], $result->shape());
+        $this->assertEqualsWithDelta(
+            [[0, 2.5, 7.5, 10], [5, 7.5, 12.5, 15], [15, 17.5, 22.5, 25], [20, 22.5, 27.5, 30]],
+            $result->toArray(),
+            1e-12
+        );
+    }
+
+    public function testResizeNearest(): void
+    {
+        $img = NDArray::array([[0, 10], [20, 30]], DType::Float64);
+
+        $this->assertEqualsWithDelta(
+            [[0, 0, 10, 10], [0, 0, 10, 10], [20, 20, 30, 30], [20, 20, 30, 30]],
+            $img->resizeImage(4, 4, Interpolation::Nearest)->toArray(),
+            1e-12
+        );
+    }
+
+    public function testResizeUint8RoundsAndKeepsDtype(): void
+    {
+        $img = NDArray::arange(16, dtype: DType::Uint8)->reshape([4, 4]);
+        $result = $img->resizeImage(2, 2);
+
+        $this->assertSame(DType::Uint8, $result->dtype());
+        $this->assertSame([[3, 5], [11, 13]], $result->toArray());
+    }
+
+    public function testResizeAntialiasAveragesAllPixels(): void
+    {
+        $img = NDArray::arange(16, dtype: DType::Uint8)->reshape([4, 4]);
+
+        $this->assertSame([[8]], $img->resizeImage(1, 1, antialias: true)->toArray());
+    }
+
+    public function testResizeHwcAndChwLayouts(): void
+    {
+        $hwc = NDArray::array([[[1, 2, 3], [4, 5, 6]], [[7, 8, 9], [10, 11, 12]]], DType::Uint8);
+        $this->assertSame([[[6, 7, 8]]], $hwc->resizeImage(1, 1, antialias: true)->toArray());
+
+        $chw = NDArray::array([[[1, 2], [3, 4]], [[5, 6], [7, 8]]], DType::Float32);
+        $result = $chw->resizeImage(1, 2, layout: ImageLayout::CHW);
+
+        $this->assertSame([2, 1, 2], $result->shape());
+        $this->assertSame(DType::Float32, $result->dtype());
+        $this->assertEqualsWithDelta([[[2, 3]], [[6, 7]]], $result->toArray(), 1e-6);
+    }
+
+    public function testResizeRejectsUnsupportedDtype(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([[1, 2], [3, 4]], DType::Int64)->resizeImage(1, 1);
+    }
+
+    public function testResizeRejects1D(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1.0, 2.0])->resizeImage(1, 1);
+    }
+
+    public function testResizeRejectsZeroSize(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::zeros([2, 2])->resizeImage(0, 2);
+    }
+
+    public function testHwcChwRoundTrip(): void
+    {
+        $hwc = NDArray::arange(12, dtype: DType::Uint8)->reshape([2, 2, 3]);
+        $chw = $hwc->hwcToChw();
+
+        $this->assertSame([3, 2, 2], $chw->shape());
+        $this->assertSame([[[0, 3], [6, 9]], [[1, 4], [7, 10]], [[2, 5], [8, 11]]], $chw->toArray());
+        $this->assertSame($hwc->toArray(), $chw->chwToHwc()->toArray());
+    }
+
+    public function testHwcToChwBatch(): void
+    {
+        $batch = NDArray::zeros([2, 4, 5, 3], DType::Float32);
+
+        $this->assertSame([2, 3, 4, 5], $batch->hwcToChw()->shape());
+    }
+
+    public function testRgbToGray(): void
+    {
+        $rgb = NDArray::array([[[255, 0, 0], [0, 255, 0]], [[0, 0, 255], [10, 20, 30]]], DType::Uint8);
+
+        $this->assertSame([[76, 150], [29, 18]], $rgb->rgbToGray()->toArray());
+        $this->assertSame([2, 2, 1], $rgb->rgbToGray(keepChannelAxis: true)->shape());
+    }
+
+    public function testGrayToRgbChw(): void
+    {
+        $gray = NDArray::array([[1.0, 2.0]]);
+        $rgb = $gray->grayToRgb(ImageLayout::CHW);
+
+        $this->assertSame([3, 1, 2], $rgb->shape());
+        $this->assertEqualsWithDelta([[[1, 2]], [[1, 2]], [[1, 2]]], $rgb->toArray(), 1e-12);
+    }
+
+    public function testRgbToBgrSwapsChannels(): void
+    {
+        $rgb = NDArray::array([[[1, 2, 3], [4, 5, 6]]], DType::Uint8);
+
+        $this->assertSame([[[3, 2, 1], [6, 5, 4]]], $rgb->rgbToBgr()->toArray());
+        $this->assertSame($rgb->toArray(), $rgb->rgbToBgr()->bgrToRgb()->toArray());
+    }
+
+    public function testRgbToGrayRejectsSingleChannel(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::zeros([2, 2])->rgbToGray();
+    }
+
+    public function testPngRoundTrip(): void
+    {
+        $rgba = NDArray::array([[[255, 0, 0, 255], [0, 255, 0, 128]], [[0, 0, 255, 0], [10, 20, 30, 40]]], DType::Uint8);
+
+        $png = $this->encodeOrSkip($rgba, ImageFormat::Png);
+        $this->assertSame("\x89PNG\r\n\x1a\n", substr($png, 0, 8));
+
+        $decoded = NDArray::decodeImage($png);
+        $this->assertSame(DType::Uint8, $decoded->dtype());
+        $this->assertSame($rgba->toArray(), $decoded->toArray());
+    }
+
+    public function testPngGrayscaleDecodesWithChannelAxis(): void
+    {
+        $gray = NDArray::array([[0, 64], [128, 255]], DType::Uint8);
+
+        $decoded = NDArray::decodeImage($this->encodeOrSkip($gray, ImageFormat::Png));
+
+        $this->assertSame([2, 2, 1], $decoded->shape());
+        $this->assertSame([[0, 64], [128, 255]], $decoded->squeeze()->toArray());
+    }
+
+    public function testJpegRoundTripIsClose(): void
+    {
+        $rgb = NDArray::full(100, [16, 16, 3], DType::Uint8);
+
+        $jpeg = $this->encodeOrSkip($rgb, ImageFormat::Jpeg, 95);
+        $this->assertSame("\xFF\xD8", substr($jpeg, 0, 2));
+
+        $decoded = NDArray::decodeImage($jpeg);
+        $this->assertSame([16, 16, 3], $decoded->shape());
+        $this->assertEqualsWithDelta(100.0, $decoded->astype(DType::Float64)->mean(), 2.0);
+    }
+
+    public function testEncodeRejectsFloatImage(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::zeros([2, 2])->encodeImage();
+    }
+
+    public function testEncodeRejectsInvalidQuality(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::zeros([2, 2], DType::Uint8)->encodeImage(ImageFormat::Jpeg, 0);
+    }
+
+    public function testDecodeRejectsUnknownData(): void
+    {
+        $this->expectException(NDArrayException::class);
+        NDArray::decodeImage('not an image');
+    }
+
+    /**
+     * Encode an image, skipping the test when the library was built without codecs.
+     */
+    private function encodeOrSkip(NDArray $image, ImageFormat $format, int $quality = 90): string
+    {
+        try {
+            return $image->encodeImage($format, $quality);
+        } catch (NDArrayException $e) {
+            if (str_contains($e->getMessage(), '`image` feature')) {
+                $this->markTestSkipped('Native library built without the image feature');
+            }
+
+            throw $e;
+        }
+    }
+}
@@ -5,8 +5,11 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
 use PhpMlKit\NDArray\Exceptions\IndexException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\ScatterMode;
 use PHPUnit\Framework\Attributes\DataProvider;
 use PHPUnit\Framework\TestCase;
 
@@ -538,6 +541,41 @@ final class IndexingTest extends TestCase
         $this->assertSame([2, 8, 9], $result->toArray());
     }
 
+    public function testTakeRowsGathersEmbeddings(): void
+    {
+        $table = NDArray::array([[0.0, 0.5], [1.0, 1.5], [2.0, 2.5], [3.0, 3.5]], DType::Float32);
+        $ids = NDArray::array([[3, 0], [-1, 1]], DType::Int32);
+
+        $result = $table->takeRows($ids);
+
+        $this->assertSame([2, 2, 2], $result->shape());
+        $this->assertSame(DType::Float32, $result->dtype());
+        $this->assertSame([[[3.0, 3.5], [0.0, 0.5]], [[3.0, 3.5], [1.0, 1.5]]], $result->toArray());
+    }
+
+    public function testTakeRowsOnTransposedView(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+
+        $result = $arr->transpose()->takeRows([2, 0]);
+
+        $this->assertSame([[3, 6], [1, 4]], $result->toArray());
+    }
+
+    public function testTakeRowsOutOfBoundsThrows(): void
+    {
+        $table = NDArray::zeros([3, 2]);
+
+        $this->expectException(IndexException::class);
+        $table->takeRows([3]);
+    }
+
+    public function testTakeRowsRequires2D(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::zeros([3])->takeRows([0]);
+    }
+
     // =========================================================================
     // take() with axis - selecting slices
     // =========================================================================
@@ -730,6 +768,44 @@ final class IndexingTest extends TestCase
         $this->assertSame([1, 2, 1, 2], $result->toArray());
     }
 
+    public function testPutFlatBroadcastsValuesAgainstIndexShape(): void
+    {
+        $arr = NDArray::zeros([6], DType::Int32);
+        $indices = NDArray::array([[0, 1], [4, -1]], DType::Int64);
+        $result = $arr->put($indices, NDArray::array([[7], [8]], DType::Int64));
+
+        $this->assertSame(DType::Int32, $result->dtype());
+        $this->assertSame([7, 7, 0, 0, 8, 8], $result->toArray());
+    }
+
+    public function testPutAxisReplacesSubArrays(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+
+        $cols = $arr->put([2, 0], NDArray::array([9, 8], DType::Int64), axis: 1);
+        $this->assertSame([[8, 2, 9], [8, 5, 9]], $cols->toArray());
+
+        $rows = $arr->put([-1], 0, axis: 0);
+        $this->assertSame([[1, 2, 3], [0, 0, 0]], $rows->toArray());
+        $this->assertSame([[1, 2, 3], [4, 5, 6]], $arr->toArray());
+    }
+
+    public function testPutAxisShapeMismatchThrows(): void
+    {
+        $arr = NDArray::zeros([2, 3]);
+
+        $this->expectException(ShapeException::class);
+        $arr->put([0, 1], NDArray::array([1.0, 2.0, 3.0]), axis: 1);
+    }
+
+    public function testPutOutOfBoundsThrows(): void
+    {
+        $arr = NDArray::zeros([2, 3]);
+
+        $this->expectException(IndexException::class);
+        $arr->put([2], 1.0, axis: 0);
+    }
+
     public function testPutAlongAxis(): void
     {
         $arr = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
@@ -748,6 +824,98 @@ final class IndexingTest extends TestCase
         $this->assertSame([1, 13, 3, 11], $result->toArray());
     }
 
+    public function testScatterFlatMinMax(): void
+    {
+        $arr = NDArray::array([4.0, 9.0, 1.0]);
+        $updates = NDArray::array([1.0, 5.0, 3.0]);
+
+        $this->assertEqualsWithDelta([4.0, 9.0, 5.0], $arr->scatterMax([0, 2, 0], $updates)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([1.0, 9.0, 1.0], $arr->scatterMin([0, 2, 0], $updates)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta(
+            [1.0, 9.0, 5.0],
+            $arr->scatterMin([0, 2, 0], $updates, includeSelf: false)->toArray(),
+            1e-12
+        );
+    }
+
+    public function testScatterFlatMeanCountsContributions(): void
+    {
+        $zeros = NDArray::zeros([3]);
+        $values = NDArray::array([1.0, 5.0, 3.0, 2.0]);
+
+        $this->assertEqualsWithDelta([2.0, 0.0, 5.0], $zeros->scatterMean([0, 2, 0, 0], $values, includeSelf: false)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([1.5, 0.0, 2.5], $zeros->scatterMean([0, 2, 0, 0], $values)->toArray(), 1e-12);
+    }
+
+    public function testScatterFlatScalarAndIntegerDtype(): void
+    {
+        $arr = NDArray::array([[5, 5], [5, 5]], DType::Int32);
+
+        $result = $arr->scatterMax(NDArray::array([0, 3], DType::Int32), 7);
+
+        $this->assertSame(DType::Int32, $result->dtype());
+        $this->assertSame([[7, 5], [5, 7]], $result->toArray());
+    }
+
+    public function testScatterFlatOutOfBoundsThrows(): void
+    {
+        $this->expectException(IndexException::class);
+        NDArray::zeros([3])->scatterMean([3], 1.0);
+    }
+
+    public function testScatterModes(): void
+    {
+        $zeros = NDArray::zeros([3]);
+        $updates = NDArray::array([1.0, 5.0, 3.0, 2.0]);
+        $indices = [0, 2, 0, 0];
+
+        $this->assertEqualsWithDelta([2.0, 0.0, 5.0], $zeros->scatter($indices, $updates)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([6.0, 0.0, 5.0], $zeros->scatter($indices, $updates, mode: ScatterMode::Add)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([1.5, 0.0, 2.5], $zeros->scatter($indices, $updates, mode: ScatterMode::Mean)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta(
+            [2.0, 0.0, 5.0],
+            $zeros->scatter($indices, $updates, mode: ScatterMode::Mean, includeSelf: false)->toArray(),
+            1e-12
+        );
+        $this->assertEqualsWithDelta(
+            [1.0, 0.0, 5.0],
+            $zeros->scatter($indices, $updates, mode: ScatterMode::Min, includeSelf: false)->toArray(),
+            1e-12
+        );
+        $this->assertEqualsWithDelta(
+            [6.0, 0.0, 5.0],
+            $zeros->scatter($indices, $updates, mode: ScatterMode::Mul, includeSelf: false)->toArray(),
+            1e-12
+        );
+    }
+
+    public function testScatterAlongAxisPoolsRows(): void
+    {
+        $x = NDArray::array([[1, 5], [3, 2], [4, 4]], DType::Int64);
+        $pooled = NDArray::zeros([2, 2], DType::Int64)
+            ->scatter([0, 0, 1], $x, mode: ScatterMode::Max, includeSelf: false);
+
+        $this->assertSame([[3, 5], [4, 4]], $pooled->toArray());
+
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+        $result = $arr->scatter([-1, 2], NDArray::array([[10, 20], [30, 40]]), axis: 1, mode: ScatterMode::Add);
+
+        $this->assertSame(DType::Int64, $result->dtype());
+        $this->assertSame([[1, 2, 33], [4, 5, 76]], $result->toArray());
+    }
+
+    public function testScatterOutOfBoundsThrows(): void
+    {
+        $this->expectException(IndexException::class);
+        NDArray::zeros([2, 2])->scatter([2], NDArray::ones([1, 2]));
+    }
+
+    public function testScatterUpdatesShapeMismatchThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::zeros([2, 3])->scatter([0, 1], NDArray::ones([2, 2]));
+    }
+
     // =========================================================================
     // Static where
     // =========================================================================
@@ -774,6 +942,85 @@ final class IndexingTest extends TestCase
         $this->assertSame([[10, 20], [30, 40]], $resultFalse->toArray());
     }
 
+    public function testStaticWhereScalarKeepsArrayDtype(): void
+    {
+        $cond = NDArray::array([true, false, true], DType::Bool);
+        $x = NDArray::array([1, 2, 3], DType::Int8);
+
+        $result = NDArray::where($cond, $x, 0);
+        $this->assertSame(DType::Int8, $result->dtype());
+        $this->assertSame([1, 0, 3], $result->toArray());
+
+        $result = NDArray::where($cond, -1, $x);
+        $this->assertSame(DType::Int8, $result->dtype());
+        $this->assertSame([-1, 2, -1], $result->toArray());
+    }
+
+    public function testStaticWhereFloatScalarPromotesIntegerArray(): void
+    {
+        $cond = NDArray::array([true, false], DType::Bool);
+        $x = NDArray::array([1, 2], DType::Int32);
+
+        $result = NDArray::where($cond, $x, 0.5);
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertSame([1.0, 0.5], $result->toArray());
+    }
+
+    public function testStaticWhereArraysPromote(): void
+    {
+        $cond = NDArray::array([true, false], DType::Bool);
+        $x = NDArray::array([1, 2], DType::Int16);
+        $y = NDArray::array([0.25, 0.5], DType::Float32);
+
+        $result = NDArray::where($cond, $x, $y);
+        $this->assertSame(DType::Float32, $result->dtype());
+        $this->assertSame([1.0, 0.5], $result->toArray());
+    }
+
+    public function testStaticWhereBroadcastsAllOperands(): void
+    {
+        $cond = NDArray::array([true, false, true], DType::Bool);
+        $x = NDArray::array([[1], [2]], DType::Int64);
+        $y = NDArray::array([[10, 20, 30]], DType::Int64);
+
+        $result = NDArray::where($cond, $x, $y);
+        $this->assertSame([2, 3], $result->shape());
+        $this->assertSame([[1, 20, 1], [2, 20, 2]], $result->toArray());
+    }
+
+    public function testStaticWhereReadsViews(): void
+    {
+        $base = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+        $cond = $base->transpose()->gt(2);
+
+        $result = NDArray::where($cond, $base->transpose(), 0);
+        $this->assertSame([[0, 4], [0, 5], [3, 6]], $result->toArray());
+    }
+
+    public function testStaticWhereNonBoolConditionSelectsNonZero(): void
+    {
+        $cond = NDArray::array([0, 2, 0, -1], DType::Int32);
+
+        $result = NDArray::where($cond, 1, 0);
+        $this->assertSame([0, 1, 0, 1], $result->toArray());
+    }
+
+    public function testStaticWhereAllScalarsIsZeroDimensional(): void
+    {
+        $result = NDArray::where(true, 1, 2);
+        $this->assertSame([], $result->shape());
+        $this->assertSame(1, $result->toScalar());
+    }
+
+    public function testStaticWhereIncompatibleShapesThrows(): void
+    {
+        $cond = NDArray::array([true, false, true, false], DType::Bool);
+        $x = NDArray::array([1, 2, 3], DType::Int64);
+
+        $this->expectException(ShapeException::class);
+        NDArray::where($cond, $x, 0);
+    }
+
     public function testArrayAccessNegativeIndex(): void
     {
         $arr = NDArray::array([10, 20, 30, 40], DType::Int64);
@@ -1056,4 +1303,92 @@ final class IndexingTest extends TestCase
         // 4D -> get(1,0,1,0) -> scalar
         $this->assertSame(11, $arr->get(1, 0, 1, 0));
     }
+
+    // =========================================================================
+    // Boolean Mask Indexing
+    // =========================================================================
+
+    public function testBooleanSelectFullMaskIsFlat(): void
+    {
+        $arr = NDArray::array([[1, -2, 3], [-4, 5, -6]], DType::Int32);
+
+        $selected = $arr[$arr->gt(0)];
+
+        $this->assertSame([3], $selected->shape());
+        $this->assertSame(DType::Int32, $selected->dtype());
+        $this->assertSame([1, 3, 5], $selected->toArray());
+    }
+
+    public function testBooleanSelectLeadingAxisSelectsRows(): void
+    {
+        $arr = NDArray::array([[1, 2], [3, 4], [5, 6]], DType::Float64);
+
+        $rows = $arr->booleanSelect(NDArray::array([true, false, true]));
+
+        $this->assertSame([2, 2], $rows->shape());
+        $this->assertEqualsWithDelta([[1.0, 2.0], [5.0, 6.0]], $rows->toArray(), 1e-12);
+    }
+
+    public function testBooleanSelectOnViewAndEmptyResult(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+        $col = $arr->slice([':', 1]);
+
+        $this->assertSame([5], $col->booleanSelect(NDArray::array([false, true]))->toArray());
+        $this->assertSame([0], $arr[$arr->gt(10)]->shape());
+    }
+
+    public function testBooleanAssignScalar(): void
+    {
+        $arr = NDArray::array([1.5, -2.0, 3.0, -0.5], DType::Float64);
+
+        $arr[$arr->lt(0)] = 0;
+
+        $this->assertEqualsWithDelta([1.5, 0.0, 3.0, 0.0], $arr->toArray(), 1e-12);
+    }
+
+    public function testBooleanAssignValuesAndRows(): void
+    {
+        $arr = NDArray::array([10, 20, 30, 40], DType::Int32);
+        $arr->booleanAssign(NDArray::array([true, false, true, false]), NDArray::array([7, 8]));
+        $this->assertSame([7, 20, 8, 40], $arr->toArray());
+
+        $matrix = NDArray::zeros([3, 2], DType::Int64);
+        $matrix[NDArray::array([false, true, true])] = [5, 6];
+        $this->assertSame([[0, 0], [5, 6], [5, 6]], $matrix->toArray());
+    }
+
+    public function testBooleanAssignThroughViewWritesBase(): void
+    {
+        $arr = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+        $col = $arr->slice([':', 0]);
+
+        $col->booleanAssign(NDArray::array([false, true]), -1);
+
+        $this->assertSame([[1, 2], [-1, 4]], $arr->toArray());
+    }
+
+    public function testBooleanMaskShapeMismatchThrows(): void
+    {
+        $arr = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+
+        $this->expectException(ShapeException::class);
+        $arr->booleanSelect(NDArray::array([true, false, true]));
+    }
+
+    public function testBooleanAssignValuesMismatchThrows(): void
+    {
+        $arr = NDArray::array([1, 2, 3], DType::Int64);
+
+        $this->expectException(ShapeException::class);
+        $arr->booleanAssign(NDArray::array([true, true, false]), NDArray::array([1, 2, 3]));
+    }
+
+    public function testBooleanMaskRequiresBoolDtype(): void
+    {
+        $arr = NDArray::array([1, 2, 3], DType::Int64);
+
+        $this->expectException(DTypeException::class);
+        $arr->booleanSelect(NDArray::array([1, 0, 1], DType::Int64));
+    }
 }
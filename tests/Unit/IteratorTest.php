@@ -4,6 +4,7 @@ declare(strict_types=1);
 
 namespace PhpMlKit\NDArray\Tests\Unit;
 
+use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
 use PhpMlKit\NDArray\FlatIterator;
 use PhpMlKit\NDArray\NDArray;
@@ -431,4 +432,98 @@ class IteratorTest extends TestCase
         $this->assertEquals($sum1, $sum2);
         $this->assertEquals(6, $sum1);
     }
+
+    // ============================================================================
+    // N-dimensional Iterator Tests
+    // ============================================================================
+
+    public function testNdenumerateYieldsIndicesAndValues(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+        $pairs = [];
+
+        foreach ($arr->ndenumerate() as $index => $value) {
+            $pairs[] = [$index, $value];
+        }
+
+        $this->assertEquals([
+            [[0, 0], 1], [[0, 1], 2], [[0, 2], 3],
+            [[1, 0], 4], [[1, 1], 5], [[1, 2], 6],
+        ], $pairs);
+    }
+
+    public function testNdenumerateAcrossBlockBoundaries(): void
+    {
+        $arr = NDArray::arange(0, 10, 1, DType::Float64)->reshape([2, 5]);
+        $values = [];
+        $last = null;
+
+        foreach ($arr->ndenumerate(3) as $index => $value) {
+            $values[] = $value;
+            $last = $index;
+        }
+
+        $this->assertEqualsWithDelta(range(0, 9), $values, 1e-12);
+        $this->assertEquals([1, 4], $last);
+    }
+
+    public function testNdenumerateRespectsViewStrides(): void
+    {
+        $arr = NDArray::array([[1, 2, 3], [4, 5, 6], [7, 8, 9]], DType::Int32);
+        $view = $arr->slice(['::2', '1:'])->transpose(); // [[2, 8], [3, 9]]
+        $pairs = [];
+
+        foreach ($view->ndenumerate(1) as $index => $value) {
+            $pairs[implode(',', $index)] = $value;
+        }
+
+        $this->assertEquals(['0,0' => 2, '0,1' => 8, '1,0' => 3, '1,1' => 9], $pairs);
+    }
+
+    public function testNdenumerateBoolAndComplex(): void
+    {
+        $bools = [];
+        foreach (NDArray::array([true, false], DType::Bool)->ndenumerate() as $value) {
+            $bools[] = $value;
+        }
+        $this->assertSame([true, false], $bools);
+
+        $complex = NDArray::array([new Complex(1.0, -2.0)], DType::Complex128);
+        foreach ($complex->ndenumerate() as $index => $value) {
+            $this->assertSame([0], $index);
+            $this->assertEqualsWithDelta(1.0, $value->real, 1e-12);
+            $this->assertEqualsWithDelta(-2.0, $value->imag, 1e-12);
+        }
+    }
+
+    public function testNdenumerateHalfPrecision(): void
+    {
+        foreach ([DType::Float16, DType::BFloat16] as $dtype) {
+            $arr = NDArray::array([[0.5, -1.5], [2.0, 4.0]], $dtype);
+            $pairs = [];
+
+            foreach ($arr->ndenumerate(3) as $index => $value) {
+                $pairs[implode(',', $index)] = $value;
+            }
+
+            $this->assertEqualsWithDelta(['0,0' => 0.5, '0,1' => -1.5, '1,0' => 2.0, '1,1' => 4.0], $pairs, 1e-6);
+        }
+    }
+
+    public function testNdenumerateEmptyAndScalar(): void
+    {
+        $count = 0;
+        foreach (NDArray::zeros([2, 0])->ndenumerate() as $value) {
+            ++$count;
+        }
+        $this->assertSame(0, $count);
+
+        $scalar = NDArray::fromScalar(7, DType::Int64);
+        $pairs = [];
+        foreach ($scalar->ndenumerate() as $index => $value) {
+            $pairs[] = [$index, $value];
+        }
+        $this->assertSame([[[], 7]], $pairs);
+        $this->assertCount(1, $scalar->ndenumerate());
+    }
 }
@@ -6,8 +6,10 @@ namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\Complex;
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
 use PhpMlKit\NDArray\Exceptions\MathException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\Exceptions\SingularMatrixException;
 use PhpMlKit\NDArray\NDArray;
 use PHPUnit\Framework\TestCase;
 
@@ -81,10 +83,90 @@ class LinearAlgebraTest extends TestCase
         $this->assertEqualsWithDelta([[19, 22], [43, 50]], $result->toArray(), 0.0001);
     }
 
-    public function testMatmulRejectsMoreThanTwoDimensions(): void
+    public function testMatmulWideAccumulationKeepsInputDtype(): void
     {
-        $a = NDArray::array([[[1.0, 2.0]]], DType::Float64);
-        $b = NDArray::array([[1.0, 2.0]], DType::Float64);
+        $n = 200000;
+        $a = NDArray::full(0.1, [1, $n], DType::Float32);
+        $b = NDArray::ones([$n, 1], DType::Float32);
+        $exact = (float) $a->slice([0, 0])->toArray() * $n;
+
+        $narrow = $a->matmul($b);
+        $wide = $a->matmul($b, accumulate: DType::Float64);
+
+        $this->assertSame(DType::Float32, $wide->dtype());
+        $this->assertLessThan(
+            abs($narrow->toArray()[0][0] - $exact),
+            abs($wide->toArray()[0][0] - $exact),
+        );
+    }
+
+    public function testMatmulResultDtype(): void
+    {
+        $a = NDArray::array([[1.5, 2.0], [3.0, 4.0]], DType::Float32);
+
+        $result = $a->matmul($a, accumulate: DType::Float64, dtype: DType::Float64);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([[8.25, 11.0], [16.5, 22.0]], $result->toArray(), 1e-12);
+        $this->assertSame(DType::Float32, $a->matmul($a, dtype: DType::Float32)->dtype());
+    }
+
+    public function testMatmulRejectsComplexAccumulatorForRealInputs(): void
+    {
+        $a = NDArray::array([[1.0, 2.0]], DType::Float32);
+
+        $this->expectException(DTypeException::class);
+        $this->expectExceptionMessage('Cannot accumulate a float32 matmul in complex128');
+        $a->matmul($a->transpose(), accumulate: DType::Complex128);
+    }
+
+    public function testMatmulRejectsIntegerResultDtype(): void
+    {
+        $a = NDArray::array([[1.0, 2.0]]);
+
+        $this->expectException(DTypeException::class);
+        $a->matmul($a->transpose(), dtype: DType::Int64);
+    }
+
+    public function testMatmulBatchedBroadcastsStacks(): void
+    {
+        $a = NDArray::arange(12, dtype: DType::Float64)->reshape([2, 2, 3]);
+        $b = NDArray::arange(6, dtype: DType::Float64)->reshape([3, 2]);
+
+        $result = $a->matmul($b);
+
+        $this->assertSame([2, 2, 2], $result->shape());
+        $this->assertEqualsWithDelta(
+            [[[10, 13], [28, 40]], [[46, 67], [64, 94]]],
+            $result->toArray(),
+            1e-12,
+        );
+    }
+
+    public function testMatmulBatchedWithVectorDropsAxis(): void
+    {
+        $a = NDArray::arange(12, dtype: DType::Float64)->reshape([2, 2, 3]);
+        $v = NDArray::ones([3], DType::Float64);
+
+        $this->assertEqualsWithDelta([[3, 12], [21, 30]], $a->matmul($v)->toArray(), 1e-12);
+        $this->assertSame([2, 3], $v->matmul(NDArray::ones([2, 3, 3]))->shape());
+    }
+
+    public function testMatmulBatchedBroadcastsBatchDimensions(): void
+    {
+        $a = NDArray::ones([2, 1, 2, 3], DType::Float64);
+        $b = NDArray::ones([4, 3, 5], DType::Float64);
+
+        $result = $a->matmul($b);
+
+        $this->assertSame([2, 4, 2, 5], $result->shape());
+        $this->assertEqualsWithDelta(240.0, $result->sum(), 1e-12);
+    }
+
+    public function testMatmulRejectsIncompatibleBatchDimensions(): void
+    {
+        $a = NDArray::ones([2, 2, 3], DType::Float64);
+        $b = NDArray::ones([3, 3, 2], DType::Float64);
 
         $this->expectException(ShapeException::class);
         $a->matmul($b);
@@ -115,6 +197,33 @@ class LinearAlgebraTest extends TestCase
         $a->diagonal();
     }
 
+    public function testTrilAndTriu(): void
+    {
+        $a = NDArray::array([[1, 2, 3], [4, 5, 6], [7, 8, 9]], DType::Int32);
+
+        $this->assertSame([[1, 0, 0], [4, 5, 0], [7, 8, 9]], $a->tril()->toArray());
+        $this->assertSame([[0, 0, 0], [4, 0, 0], [7, 8, 0]], $a->tril(-1)->toArray());
+        $this->assertSame([[0, 2, 3], [0, 0, 6], [0, 0, 0]], $a->triu(1)->toArray());
+        $this->assertSame(DType::Int32, $a->triu()->dtype());
+    }
+
+    public function testTrilBatched(): void
+    {
+        $a = NDArray::ones([2, 2, 3]);
+
+        $this->assertEquals(
+            [[[1, 0, 0], [1, 1, 0]], [[1, 0, 0], [1, 1, 0]]],
+            $a->tril()->toArray()
+        );
+        $this->assertEquals([[1, 1, 1], [0, 1, 1]], $a->slice([0])->triu()->toArray());
+    }
+
+    public function testTrilRequires2D(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1, 2, 3])->tril();
+    }
+
     public function testTrace(): void
     {
         $a = NDArray::array([[1, 2, 3], [4, 5, 6], [7, 8, 9]], DType::Float64);
@@ -444,6 +553,39 @@ class LinearAlgebraTest extends TestCase
         $this->assertEqualsWithDelta(0.514301, $s[1], 1e-6);
     }
 
+    public function testSvdReducedMatrices(): void
+    {
+        $a = NDArray::array([
+            [1, 2],
+            [3, 4],
+            [5, 6],
+        ], DType::Float32);
+
+        [$u, $s, $vt] = $a->svd(fullMatrices: false);
+
+        $this->assertSame([3, 2], $u->shape());
+        $this->assertSame([2], $s->shape());
+        $this->assertSame([2, 2], $vt->shape());
+        $this->assertSame(DType::Float32, $u->dtype());
+
+        $reconstructed = $u->matmul($s->diag())->matmul($vt);
+        $this->assertEqualsWithDelta($a->toArray(), $reconstructed->toArray(), 1e-4);
+    }
+
+    public function testSvdReducedWideMatrix(): void
+    {
+        $a = NDArray::array([
+            [1, 0, 2, 0],
+            [0, 3, 0, 4],
+        ], DType::Float64);
+
+        [$u, $s, $vt] = $a->svd(fullMatrices: false);
+
+        $this->assertSame([2, 2], $u->shape());
+        $this->assertSame([2, 4], $vt->shape());
+        $this->assertEqualsWithDelta($a->toArray(), $u->matmul($s->diag())->matmul($vt)->toArray(), 1e-9);
+    }
+
     public function testSvdOrthogonality(): void
     {
         // Test that U and VT are orthogonal (U^T * U = I, VT * VT^T = I)
@@ -628,6 +770,32 @@ class LinearAlgebraTest extends TestCase
         $a->solve($b);
     }
 
+    public function testSolveRequiresSquareA(): void
+    {
+        $a = NDArray::array([
+            [1, 2, 3],
+            [4, 5, 6],
+        ], DType::Float64);
+        $b = NDArray::array([1, 2], DType::Float64);
+
+        $this->expectException(ShapeException::class);
+        $this->expectExceptionMessage('square coefficient matrix');
+        $a->solve($b);
+    }
+
+    public function testSolveSingularThrows(): void
+    {
+        $a = NDArray::array([
+            [1, 2],
+            [2, 4],
+        ], DType::Float64);
+        $b = NDArray::array([1, 2], DType::Float64);
+
+        $this->expectException(SingularMatrixException::class);
+        $this->expectExceptionMessage('coefficient matrix is singular');
+        $a->solve($b);
+    }
+
     // =========================================================================
     // Inverse Tests
     // =========================================================================
@@ -667,6 +835,63 @@ class LinearAlgebraTest extends TestCase
         $a->inv();
     }
 
+    public function testInvBatched(): void
+    {
+        $a = NDArray::array([
+            [[4, 7], [2, 6]],
+            [[2, 0], [0, 4]],
+        ], DType::Float64);
+
+        $inv = $a->inv();
+
+        $this->assertSame([2, 2, 2], $inv->shape());
+        $this->assertEqualsWithDelta([
+            [[0.6, -0.7], [-0.2, 0.4]],
+            [[0.5, 0.0], [0.0, 0.25]],
+        ], $inv->toArray(), 1e-10);
+    }
+
+    public function testInvSingularThrows(): void
+    {
+        $a = NDArray::array([
+            [1, 2],
+            [2, 4],
+        ], DType::Float64);
+
+        $this->expectException(MathException::class);
+        $this->expectExceptionMessage('singular');
+        $a->inv();
+    }
+
+    public function testInvBatchedReportsSingularMatrix(): void
+    {
+        $a = NDArray::array([
+            [[1, 0], [0, 1]],
+            [[0, 0], [0, 0]],
+        ], DType::Float32);
+
+        $this->expectException(MathException::class);
+        $this->expectExceptionMessage('matrix 1 of the stack is singular');
+        $a->inv();
+    }
+
+    public function testInvSingularReportsCodeAndOperation(): void
+    {
+        $a = NDArray::array([
+            [1, 2],
+            [2, 4],
+        ], DType::Float64);
+
+        try {
+            $a->inv();
+            $this->fail('Expected SingularMatrixException');
+        } catch (SingularMatrixException $e) {
+            $this->assertInstanceOf(MathException::class, $e);
+            $this->assertSame(10, $e->getCode());
+            $this->assertSame('inv', $e->getOperation());
+        }
+    }
+
     // =========================================================================
     // Determinant Tests
     // =========================================================================
@@ -709,6 +934,31 @@ class LinearAlgebraTest extends TestCase
         $a->det();
     }
 
+    public function testDetSingularIsZero(): void
+    {
+        $a = NDArray::array([
+            [1, 2],
+            [2, 4],
+        ], DType::Float64);
+
+        $this->assertEqualsWithDelta(0.0, $a->det(), 1e-12);
+    }
+
+    public function testDetBatched(): void
+    {
+        $a = NDArray::array([
+            [[[4, 7], [2, 6]], [[1, 2], [2, 4]]],
+            [[[2, 0], [0, 3]], [[0, 1], [1, 0]]],
+        ], DType::Float32);
+
+        $det = $a->det();
+
+        $this->assertInstanceOf(NDArray::class, $det);
+        $this->assertSame([2, 2], $det->shape());
+        $this->assertSame(DType::Float32, $det->dtype());
+        $this->assertEqualsWithDelta([[10.0, 0.0], [6.0, -1.0]], $det->toArray(), 1e-5);
+    }
+
     // =========================================================================
     // QR Decomposition Tests
     // =========================================================================
@@ -748,6 +998,55 @@ class LinearAlgebraTest extends TestCase
         $a->qr();
     }
 
+    // =========================================================================
+    // LU Decomposition Tests
+    // =========================================================================
+
+    public function testLu(): void
+    {
+        $a = NDArray::array([
+            [1., 2., 3.],
+            [4., 5., 6.],
+            [7., 8., 10.],
+        ], DType::Float64);
+
+        [$l, $u, $perm] = $a->lu();
+
+        $this->assertSame(DType::Int64, $perm->dtype());
+        $this->assertSame([2, 0, 1], $perm->toArray());
+        $this->assertEqualsWithDelta(
+            $a->take($perm, axis: 0)->toArray(),
+            $l->matmul($u)->toArray(),
+            1e-12,
+        );
+        $this->assertEqualsWithDelta([1., 1., 1.], $l->diagonal()->toArray(), 0.0);
+        $this->assertEqualsWithDelta(0.0, $u->get(1, 0), 0.0);
+    }
+
+    public function testLuRectangularFloat32(): void
+    {
+        $a = NDArray::array([
+            [0., 1., 2.],
+            [3., 4., 5.],
+        ], DType::Float32);
+
+        [$l, $u, $perm] = $a->lu();
+
+        $this->assertSame(DType::Float32, $l->dtype());
+        $this->assertSame([2, 2], $l->shape());
+        $this->assertSame([2, 3], $u->shape());
+        $this->assertSame([1, 0], $perm->toArray());
+        $this->assertEqualsWithDelta([[3., 4., 5.], [0., 1., 2.]], $l->matmul($u)->toArray(), 1e-6);
+    }
+
+    public function testLuRejectsIntegerDtype(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+
+        $this->expectException(DTypeException::class);
+        $a->lu();
+    }
+
     // =========================================================================
     // Cholesky Decomposition Tests
     // =========================================================================
@@ -1551,6 +1850,37 @@ class LinearAlgebraTest extends TestCase
         $this->assertGreaterThan(0, $eigvals[1]);
     }
 
+    public function testEighFloat32(): void
+    {
+        $a = NDArray::array([
+            [2, 1],
+            [1, 2],
+        ], DType::Float32);
+
+        [$eigvals, $eigvecs] = $a->eigh();
+
+        $this->assertSame(DType::Float32, $eigvals->dtype());
+        $this->assertSame(DType::Float32, $eigvecs->dtype());
+        $this->assertEqualsWithDelta([1.0, 3.0], $eigvals->toArray(), 1e-5);
+    }
+
+    public function testEighReadsSelectedTriangleOfTransposedView(): void
+    {
+        // Only the lower triangle is meaningful; the transpose moves it to the upper one.
+        $lower = NDArray::array([
+            [2, 99],
+            [1, 3],
+        ], DType::Float64);
+
+        $eigvals = $lower->transpose()->eigvalsh(upper: true);
+        [$vals, $vecs] = $lower->transpose()->eigh(upper: true);
+
+        $expected = [(5 - sqrt(5)) / 2, (5 + sqrt(5)) / 2];
+        $this->assertEqualsWithDelta($expected, $eigvals->toArray(), 1e-10);
+        $this->assertEqualsWithDelta($expected, $vals->toArray(), 1e-10);
+        $this->assertSame([2, 2], $vecs->shape());
+    }
+
     public function testEighRequires2D(): void
     {
         $a = NDArray::array([1, 2, 3], DType::Float64);
@@ -1628,6 +1958,87 @@ class LinearAlgebraTest extends TestCase
         $a->eigvalsh();
     }
 
+    public function testTensordotExplicitAxesMatchesEinsum(): void
+    {
+        $a = NDArray::arange(24, dtype: DType::Float64)->reshape([2, 3, 4]);
+        $b = NDArray::arange(12, dtype: DType::Float64)->reshape([4, 3]);
+
+        $result = $a->tensordot($b, [[1, -1], [1, 0]]);
+
+        $this->assertSame([2], $result->shape());
+        $this->assertEqualsWithDelta($a->einsum('ijk,kj->i', $b)->toArray(), $result->toArray(), 1e-9);
+    }
+
+    public function testTensordotIntegerAxes(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]]);
+
+        $this->assertEqualsWithDelta(30.0, $a->tensordot($a), 1e-9);
+        $this->assertEqualsWithDelta([[7, 10], [15, 22]], $a->tensordot($a, 1)->toArray(), 1e-9);
+        $this->assertSame([2, 2, 2, 2], $a->tensordot($a, 0)->shape());
+        $this->assertSame(DType::Float64, $a->tensordot($a, 1)->dtype());
+    }
+
+    public function testTensordotRejectsMismatchedAxes(): void
+    {
+        $a = NDArray::ones([2, 3]);
+        $b = NDArray::ones([2, 3]);
+
+        $this->expectException(ShapeException::class);
+        $a->tensordot($b, [[1], [0]]);
+    }
+
+    public function testOuterFlattensInputs(): void
+    {
+        $a = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+        $b = NDArray::array([1.0, 10.0]);
+
+        $result = $a->outer($b);
+
+        $this->assertSame([4, 2], $result->shape());
+        $this->assertEqualsWithDelta([[1, 10], [2, 20], [3, 30], [4, 40]], $result->toArray(), 1e-9);
+    }
+
+    public function testKronBlockMatrix(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]]);
+        $b = NDArray::eye(2, dtype: DType::Int64);
+
+        $result = $a->kron($b);
+
+        $this->assertSame(DType::Int64, $result->dtype());
+        $this->assertSame([[1, 0, 2, 0], [0, 1, 0, 2], [3, 0, 4, 0], [0, 3, 0, 4]], $result->toArray());
+    }
+
+    public function testKronPromotesAndPadsRank(): void
+    {
+        $a = NDArray::array([1, 2], DType::Int32);
+        $b = NDArray::array([[[0.5], [1.5]]], DType::Float32);
+
+        $result = $a->kron($b);
+
+        $this->assertSame(DType::Float32, $result->dtype());
+        $this->assertSame([1, 2, 2], $result->shape());
+        $this->assertEqualsWithDelta([[[0.5, 1.0], [1.5, 3.0]]], $result->toArray(), 1e-6);
+    }
+
+    public function testCrossBroadcastsLeadingAxes(): void
+    {
+        $rows = NDArray::array([[1, 0, 0], [0, 0, 1], [1, 2, 3]]);
+        $y = NDArray::array([0, 1, 0]);
+
+        $result = $rows->cross($y);
+
+        $this->assertSame(DType::Int64, $result->dtype());
+        $this->assertSame([[0, 0, 1], [-1, 0, 0], [-3, 0, 1]], $result->toArray());
+    }
+
+    public function testCrossRequiresThreeVectors(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1.0, 2.0])->cross(NDArray::array([3.0, 4.0]));
+    }
+
     public function testEinsumMatrixMultiply(): void
     {
         $a = NDArray::array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
@@ -1760,4 +2171,152 @@ class LinearAlgebraTest extends TestCase
         $this->assertSame([], $result->shape());
         $this->assertEqualsWithDelta(10.0, $result->toScalar(), 0.0001);
     }
+
+    public function testEinsumBatchedContraction(): void
+    {
+        $q = NDArray::arange(24, dtype: DType::Float64)->reshape([2, 3, 4]);
+        $k = NDArray::arange(40, dtype: DType::Float64)->reshape([2, 5, 4]);
+        $result = $q->einsum('bqd,bkd->bqk', $k);
+
+        $expected = $q->matmul($k->swapaxes(1, 2));
+        $this->assertSame([2, 3, 5], $result->shape());
+        $this->assertEqualsWithDelta($expected->toArray(), $result->toArray(), 1e-9);
+    }
+
+    public function testEinsumThreeOperandChain(): void
+    {
+        $a = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+        $b = NDArray::array([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        $c = NDArray::array([[1.0], [-1.0], [2.0]]);
+
+        $result = einsum('ij,jk,kl->il', $a, $b, $c);
+        $expected = $a->matmul($b)->matmul($c);
+
+        $this->assertSame([2, 1], $result->shape());
+        $this->assertEqualsWithDelta($expected->toArray(), $result->toArray(), 1e-9);
+    }
+
+    public function testEinsumMultipleContractedLabels(): void
+    {
+        $a = NDArray::arange(24, dtype: DType::Float64)->reshape([2, 3, 4]);
+        $b = NDArray::arange(12, dtype: DType::Float64)->reshape([3, 4]);
+        $result = $a->einsum('ijk,jk->i', $b);
+
+        $this->assertSame([2], $result->shape());
+        $this->assertEqualsWithDelta([506.0, 1298.0], $result->toArray(), 1e-9);
+    }
+
+    public function testEinsumImplicitOutputIsAlphabetical(): void
+    {
+        $a = NDArray::array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        $result = $a->einsum('ji');
+
+        $this->assertSame([3, 2], $result->shape());
+        $this->assertEqualsWithDelta([[1, 4], [2, 5], [3, 6]], $result->toArray(), 0.0001);
+    }
+
+    public function testEinsumPromotesIntegerOperand(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+        $b = NDArray::array([1.0, 1.0], DType::Float32);
+        $result = $a->einsum('ij,j->i', $b);
+
+        $this->assertSame(DType::Float32, $result->dtype());
+        $this->assertEqualsWithDelta([3.0, 7.0], $result->toArray(), 1e-9);
+    }
+
+    public function testEinsumOperandCountMismatch(): void
+    {
+        $a = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+
+        $this->expectException(ShapeException::class);
+        $a->einsum('ij,jk->ik');
+    }
+
+    public function testPolyfitRecoversLine(): void
+    {
+        $x = NDArray::array([0, 1, 2, 3], DType::Float64);
+        $y = NDArray::array([1, 3, 5, 7], DType::Float64);
+
+        $p = NDArray::polyfit($x, $y, 1);
+
+        $this->assertSame([2], $p->shape());
+        $this->assertEqualsWithDelta([2.0, 1.0], $p->toArray(), 1e-10);
+    }
+
+    public function testPolyfitMultipleDataSets(): void
+    {
+        $x = NDArray::array([-1.0, 0.0, 1.0, 2.0]);
+        $y = NDArray::array([[2.0, 0.0], [1.0, 1.0], [2.0, 2.0], [5.0, 3.0]]);
+
+        $p = NDArray::polyfit($x, $y, 2);
+
+        $this->assertSame([3, 2], $p->shape());
+        $this->assertEqualsWithDelta([[1.0, 0.0], [0.0, 1.0], [1.0, 1.0]], $p->toArray(), 1e-9);
+    }
+
+    public function testPolyfitLengthMismatchThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::polyfit(NDArray::array([1.0, 2.0, 3.0]), NDArray::array([1.0, 2.0]), 1);
+    }
+
+    public function testPolyval(): void
+    {
+        $p = NDArray::array([1, 2, 3], DType::Int64);
+        $x = NDArray::array([[0.0, 1.0], [2.0, -1.0]]);
+
+        $y = NDArray::polyval($p, $x);
+
+        $this->assertSame(DType::Float64, $y->dtype());
+        $this->assertEqualsWithDelta([[3.0, 6.0], [11.0, 2.0]], $y->toArray(), 1e-12);
+    }
+
+    public function testPolyvalRoundTripsPolyfit(): void
+    {
+        $x = NDArray::array([0.0, 0.5, 1.0, 1.5, 2.0], DType::Float32);
+        $y = NDArray::array([1.0, 0.25, 0.0, 0.25, 1.0], DType::Float32);
+
+        $fitted = NDArray::polyval(NDArray::polyfit($x, $y, 2), $x);
+
+        $this->assertSame(DType::Float32, $fitted->dtype());
+        $this->assertEqualsWithDelta($y->toArray(), $fitted->toArray(), 1e-5);
+    }
+
+    public function testRootsReal(): void
+    {
+        [$re, $im] = NDArray::roots(NDArray::array([1.0, -3.0, 2.0]));
+
+        $values = $re->toArray();
+        sort($values);
+        $this->assertEqualsWithDelta([1.0, 2.0], $values, 1e-10);
+        $this->assertEqualsWithDelta([0.0, 0.0], $im->toArray(), 1e-10);
+    }
+
+    public function testRootsComplexPair(): void
+    {
+        [$re, $im] = NDArray::roots(NDArray::array([1.0, 0.0, 1.0]));
+
+        $imag = $im->toArray();
+        sort($imag);
+        $this->assertEqualsWithDelta([0.0, 0.0], $re->toArray(), 1e-10);
+        $this->assertEqualsWithDelta([-1.0, 1.0], $imag, 1e-10);
+    }
+
+    public function testRootsStripsLeadingAndTrailingZeros(): void
+    {
+        [$re, $im] = NDArray::roots(NDArray::array([0.0, 2.0, -4.0, 0.0]));
+
+        $this->assertSame([2], $re->shape());
+        $this->assertEqualsWithDelta([2.0, 0.0], $re->toArray(), 1e-10);
+        $this->assertEqualsWithDelta([0.0, 0.0], $im->toArray(), 1e-10);
+    }
+
+    public function testRootsOfConstantIsEmpty(): void
+    {
+        [$re, $im] = NDArray::roots(NDArray::array([5.0]));
+
+        $this->assertSame([0], $re->shape());
+        $this->assertSame([0], $im->shape());
+    }
 }
@@ -5,6 +5,8 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
 use PHPUnit\Framework\TestCase;
 
@@ -435,6 +437,42 @@ final class MathFunctionsTest extends TestCase
         $this->assertEquals([2, 5, 8], $result->toArray());
     }
 
+    public function testClampInt64BoundsAreExact(): void
+    {
+        $big = 2 ** 62;
+        $a = NDArray::array([$big - 3, $big, $big + 3], DType::Int64);
+        $result = $a->clamp($big - 1, $big + 1);
+        $this->assertSame(DType::Int64, $result->dtype());
+        $this->assertSame([$big - 1, $big, $big + 1], $result->toArray());
+    }
+
+    public function testClampSaturatesBoundsOutsideDtypeRange(): void
+    {
+        $a = NDArray::array([0, 100, 255], DType::UInt8);
+        $result = $a->clamp(-10, 1000);
+        $this->assertSame(DType::UInt8, $result->dtype());
+        $this->assertSame([0, 100, 255], $result->toArray());
+    }
+
+    public function testUnaryIntegerOpsKeepDtype(): void
+    {
+        $a = NDArray::array([-128, -5, 0, 7], DType::Int8);
+
+        $abs = $a->abs();
+        $this->assertSame(DType::Int8, $abs->dtype());
+        $this->assertSame([-128, 5, 0, 7], $abs->toArray());
+
+        $sign = NDArray::array([0, 3], DType::UInt16)->signum();
+        $this->assertSame(DType::UInt16, $sign->dtype());
+        $this->assertSame([0, 1], $sign->toArray());
+    }
+
+    public function testNegRejectsUnsigned(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1, 2], DType::UInt32)->negative();
+    }
+
     // VIEW/SUBSET TESTS (from MathFunctionsViewTest)
 
     // ========================================================================
@@ -890,4 +928,77 @@ final class MathFunctionsTest extends TestCase
         $this->assertSame([4], $result->shape());
         $this->assertEqualsWithDelta([1, 3, 5, 7], $result->toArray(), 0.0001);
     }
+
+    public function testFloatClassification(): void
+    {
+        $a = NDArray::array([1.0, NAN, INF, -INF], DType::Float32);
+
+        $this->assertSame([false, true, false, false], $a->isnan()->toArray());
+        $this->assertSame([false, false, true, true], $a->isinf()->toArray());
+        $this->assertSame([true, false, false, false], $a->isfinite()->toArray());
+        $this->assertSame(DType::Bool, $a->isfinite()->dtype());
+    }
+
+    public function testFloatClassificationOfIntegers(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int32);
+
+        $this->assertSame([[false, false], [false, false]], $a->isnan()->toArray());
+        $this->assertSame([[true, true], [true, true]], $a->isfinite()->toArray());
+    }
+
+    public function testDiff(): void
+    {
+        $a = NDArray::array([1, 2, 4, 7, 0], DType::Int32);
+
+        $d = $a->diff();
+        $this->assertSame(DType::Int32, $d->dtype());
+        $this->assertSame([1, 2, 3, -7], $d->toArray());
+        $this->assertSame([1, 1, -10], $a->diff(2)->toArray());
+        $this->assertSame([0], $a->diff(9)->shape());
+
+        $m = NDArray::array([[1.0, 3.0, 6.0], [0.0, 5.0, 5.0]]);
+        $this->assertSame([[-1.0, 2.0, -1.0]], $m->diff(axis: 0)->toArray());
+        $this->assertSame([false, true, false], NDArray::array([true, true, false, false])->diff()->toArray());
+    }
+
+    public function testGradient(): void
+    {
+        $f = NDArray::array([1, 2, 4, 7, 11, 16]);
+
+        $g = $f->gradient();
+        $this->assertSame(DType::Float64, $g->dtype());
+        $this->assertSame([1.0, 1.5, 2.5, 3.5, 4.5, 5.0], $g->toArray());
+        $this->assertSame(
+            [0.25, 0.75, 1.25, 1.75, 2.25, 2.75],
+            $f->gradient(spacing: 2.0, edgeOrder: 2)->toArray()
+        );
+
+        $m = NDArray::array([[1.0, 2.0, 6.0], [3.0, 4.0, 5.0]], DType::Float32);
+        $rows = $m->gradient(axis: 0);
+        $this->assertSame(DType::Float32, $rows->dtype());
+        $this->assertSame([[2.0, 2.0, -1.0], [2.0, 2.0, -1.0]], $rows->toArray());
+    }
+
+    public function testGradientNeedsEnoughSamples(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1.0, 2.0])->gradient(edgeOrder: 2);
+    }
+
+    public function testInterp(): void
+    {
+        $x = NDArray::array([[0.0, 1.5], [2.5, 7.0]], DType::Float32);
+
+        $y = $x->interp([1, 2, 3], [3, 2, 0]);
+        $this->assertSame(DType::Float64, $y->dtype());
+        $this->assertSame([[3.0, 2.5], [1.0, 0.0]], $y->toArray());
+        $this->assertSame([[-1.0, 2.5], [1.0, 9.0]], $x->interp([1, 2, 3], [3, 2, 0], -1.0, 9.0)->toArray());
+    }
+
+    public function testInterpRejectsMismatchedSamples(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1.0])->interp([1, 2, 3], [3, 2]);
+    }
 }
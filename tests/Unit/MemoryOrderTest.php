@@ -0,0 +1,119 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Column-major ('F') arrays keep their logical contents while storing
+ * elements column by column.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class MemoryOrderTest extends TestCase
+{
+    public function testArrayWithFortranOrder(): void
+    {
+        $a = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64, 'F');
+
+        $this->assertSame([[1, 2, 3], [4, 5, 6]], $a->toArray());
+        $this->assertSame([1, 2], $a->strides());
+        $this->assertTrue($a->isFortranContiguous());
+        $this->assertFalse($a->isContiguous());
+
+        $flags = $a->flags();
+        $this->assertTrue($flags['f_contiguous']);
+        $this->assertFalse($flags['c_contiguous']);
+        $this->assertTrue($flags['owndata']);
+    }
+
+    public function testFromBufferReadsColumnMajorData(): void
+    {
+        $buffer = \FFI::new('double[6]');
+        foreach ([1.0, 4.0, 2.0, 5.0, 3.0, 6.0] as $i => $v) {
+            $buffer[$i] = $v;
+        }
+
+        $m = NDArray::fromBuffer($buffer, [2, 3], DType::Float64, 'F');
+
+        $this->assertSame([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], $m->toArray());
+        $this->assertSame([[5.0, 7.0, 9.0]], $m->sum(0, true)->toArray());
+    }
+
+    public function testFromBytesReadsColumnMajorData(): void
+    {
+        $m = NDArray::fromBytes(pack('P*', 1, 3, 2, 4), [2, 2], DType::Int64, order: 'F');
+
+        $this->assertSame([[1, 2], [3, 4]], $m->toArray());
+    }
+
+    public function testZerosOnesFullWithFortranOrder(): void
+    {
+        $this->assertTrue(NDArray::zeros([2, 3], order: 'F')->flags()['f_contiguous']);
+        $this->assertTrue(NDArray::ones([2, 3], order: 'F')->isFortranContiguous());
+
+        $full = NDArray::full(7, [3, 2], order: 'F');
+        $this->assertSame([[7, 7], [7, 7], [7, 7]], $full->toArray());
+        $this->assertTrue($full->flags()['f_contiguous']);
+    }
+
+    public function testFortranArrayWorksWithOtherOps(): void
+    {
+        $a = NDArray::array([[1.0, 2.0], [3.0, 4.0]], order: 'F');
+        $b = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+
+        $this->assertSame([[2.0, 4.0], [6.0, 8.0]], $a->add($b)->toArray());
+        $this->assertSame($b->matmul($b)->toArray(), $a->matmul($a)->toArray());
+        $this->assertSame([3.0, 4.0], $a->slice([1])->toArray());
+    }
+
+    public function testReshapeFortranOrder(): void
+    {
+        $a = NDArray::arange(6, dtype: DType::Int64);
+
+        $this->assertSame([[0, 2, 4], [1, 3, 5]], $a->reshape([2, 3], 'F')->toArray());
+
+        // C-contiguous 2-D source: read in column-major order, so a copy is made.
+        $m = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64);
+        $r = $m->reshape([3, 2], 'F');
+        $this->assertSame([[1, 5], [4, 3], [2, 6]], $r->toArray());
+        $this->assertTrue($r->isFortranContiguous());
+    }
+
+    public function testReshapeFortranArrayIsView(): void
+    {
+        $a = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Int64, 'F');
+        $r = $a->reshape([3, 2], 'F');
+
+        $this->assertTrue($r->isView());
+        $this->assertSame([[1, 5], [4, 3], [2, 6]], $r->toArray());
+        $this->assertSame([[1, 2], [3, 4], [5, 6]], $a->reshape([3, 2])->toArray());
+    }
+
+    public function testFlattenAndRavelOrder(): void
+    {
+        $m = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+
+        $this->assertSame([1, 3, 2, 4], $m->flatten('F')->toArray());
+        $this->assertSame([1, 3, 2, 4], $m->ravel('F')->toArray());
+        $this->assertFalse($m->ravel('F')->isView());
+
+        $f = NDArray::array([[1, 2], [3, 4]], DType::Int64, 'F');
+        $this->assertTrue($f->ravel('F')->isView());
+        $this->assertSame([1, 3, 2, 4], $f->ravel('F')->toArray());
+        $this->assertSame([1, 2, 3, 4], $f->ravel()->toArray());
+    }
+
+    public function testInvalidOrderThrows(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        $this->expectExceptionMessage("Order must be 'C' or 'F', got 'K'");
+        NDArray::zeros([2, 2], order: 'K');
+    }
+}
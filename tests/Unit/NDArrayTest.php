@@ -155,6 +155,77 @@ final class NDArrayTest extends TestCase
         $this->assertSame(48, $arr->nbytes());
     }
 
+    public function testFlagsOfOwnedArray(): void
+    {
+        $arr = NDArray::zeros([2, 3], DType::Float32);
+
+        $this->assertSame([
+            'c_contiguous' => true,
+            'f_contiguous' => false,
+            'owndata' => true,
+            'itemsize' => 4,
+            'nbytes' => 24,
+        ], $arr->flags());
+    }
+
+    public function testFlagsOfViews(): void
+    {
+        $arr = NDArray::zeros([2, 3]);
+
+        $transposed = $arr->transpose()->flags();
+        $this->assertFalse($transposed['c_contiguous']);
+        $this->assertTrue($transposed['f_contiguous']);
+        $this->assertFalse($transposed['owndata']);
+
+        $row = $arr->get(1)->flags();
+        $this->assertTrue($row['c_contiguous']);
+        $this->assertTrue($row['f_contiguous']);
+        $this->assertFalse($row['owndata']);
+        $this->assertSame(24, $row['nbytes']);
+
+        $reversed = $arr->get(0)->flip()->flags();
+        $this->assertFalse($reversed['c_contiguous']);
+        $this->assertFalse($reversed['f_contiguous']);
+    }
+
+    public function testBackendInfo(): void
+    {
+        $info = NDArray::backendInfo();
+
+        $this->assertIsBool($info['blas']);
+        $this->assertContains($info['lapack'], ['openblas-static', 'mkl-static']);
+    }
+
+    public function testParallelSettings(): void
+    {
+        $default = NDArray::getParallelThreshold();
+        $this->assertGreaterThan(0, $default);
+        $this->assertGreaterThanOrEqual(1, NDArray::getNumThreads());
+
+        try {
+            NDArray::setParallelThreshold(16);
+            $this->assertSame(16, NDArray::getParallelThreshold());
+
+            NDArray::setNumThreads(2);
+            $this->assertContains(NDArray::getNumThreads(), [1, 2]);
+
+            $a = NDArray::arange(1000, dtype: DType::Float64);
+            $this->assertEqualsWithDelta(499500.0, $a->sum(), 1e-9);
+            $this->assertEqualsWithDelta(2.0 * 999, $a->multiply(2)->max(), 1e-12);
+            $this->assertSame(
+                array_map('sqrt', range(0, 999)),
+                $a->sqrt()->toArray()
+            );
+            $reversed = NDArray::arange(999, -1, -1, dtype: DType::Float64);
+            $this->assertSame($a->toArray(), $reversed->sort(null)->toArray());
+        } finally {
+            NDArray::setParallelThreshold(0);
+            NDArray::setNumThreads(0);
+        }
+
+        $this->assertSame($default, NDArray::getParallelThreshold());
+    }
+
     // =========================================================================
     // toArray Tests
     // =========================================================================
@@ -427,4 +498,36 @@ final class NDArrayTest extends TestCase
 
         $arr->shape(0);
     }
+
+    public function testContentHashIsStableAndHex(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+        $b = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+
+        $this->assertMatchesRegularExpression('/^[0-9a-f]{16}$/', $a->contentHash());
+        $this->assertSame($a->contentHash(), $b->contentHash());
+        $this->assertNotSame($a->contentHash(), $a->contentHash(1));
+    }
+
+    public function testContentHashIgnoresLayout(): void
+    {
+        $a = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Float64);
+        $t = $a->transpose();
+
+        $this->assertSame($t->copy()->contentHash(), $t->contentHash());
+        $this->assertSame(
+            NDArray::array([2, 5], DType::Float64)->contentHash(),
+            $a->slice([':', 1])->contentHash()
+        );
+    }
+
+    public function testContentHashDependsOnDtypeShapeAndData(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int32);
+        $hash = $a->contentHash();
+
+        $this->assertNotSame($hash, $a->reshape([4])->contentHash());
+        $this->assertNotSame($hash, $a->astype(DType::UInt32)->contentHash());
+        $this->assertNotSame($hash, NDArray::array([[1, 2], [3, 5]], DType::Int32)->contentHash());
+    }
 }
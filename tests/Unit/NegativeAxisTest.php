@@ -0,0 +1,102 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Negative axes (-1 = last) resolve the same way in every op family.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class NegativeAxisTest extends TestCase
+{
+    private function matrix(): NDArray
+    {
+        return NDArray::array([[3, 1, 2], [6, 5, 4]], DType::Int64);
+    }
+
+    public function testReductionsAcceptNegativeAxis(): void
+    {
+        $a = $this->matrix();
+
+        $this->assertSame($a->sum(1)->toArray(), $a->sum(-1)->toArray());
+        $this->assertSame($a->max(0)->toArray(), $a->max(-2)->toArray());
+        $this->assertSame($a->argmin(1)->toArray(), $a->argmin(-1)->toArray());
+        $this->assertSame($a->cumsum(1)->toArray(), $a->cumsum(-1)->toArray());
+    }
+
+    public function testSortingAcceptsNegativeAxis(): void
+    {
+        $a = $this->matrix();
+
+        $this->assertSame($a->sort(1)->toArray(), $a->sort(-1)->toArray());
+        $this->assertSame($a->argsort(0)->toArray(), $a->argsort(-2)->toArray());
+    }
+
+    public function testRepeatWithNegativeAxisRepeatsAlongLastAxis(): void
+    {
+        $result = $this->matrix()->repeat(2, -1);
+
+        $this->assertSame([2, 6], $result->shape());
+        $this->assertSame([[3, 3, 1, 1, 2, 2], [6, 6, 5, 5, 4, 4]], $result->toArray());
+    }
+
+    public function testRepeatWithoutAxisFlattens(): void
+    {
+        $this->assertSame([6], $this->matrix()->repeat(1)->shape());
+    }
+
+    public function testShapeOpsAcceptNegativeAxes(): void
+    {
+        $a = $this->matrix();
+
+        $this->assertSame([[2, 1, 3], [4, 5, 6]], $a->flip(-1)->toArray());
+        $this->assertSame([3, 2], $a->permute(-1, 0)->shape());
+        $this->assertSame([3, 2], $a->swapaxes(0, -1)->shape());
+        $this->assertSame([2, 3, 1], $a->expandDims(-1)->shape());
+        $this->assertSame([2, 3], $a->expandDims(-1)->squeeze([-1])->shape());
+    }
+
+    public function testStackingAcceptsNegativeAxes(): void
+    {
+        $a = $this->matrix();
+
+        $this->assertSame([2, 6], NDArray::concatenate([$a, $a], -1)->shape());
+        $this->assertSame([2, 3, 2], NDArray::stack([$a, $a], -1)->shape());
+        $this->assertCount(3, $a->split(3, -1));
+    }
+
+    public function testIndexingAcceptsNegativeAxis(): void
+    {
+        $a = $this->matrix();
+
+        $this->assertSame($a->take([0, 2], 1)->toArray(), $a->take([0, 2], -1)->toArray());
+    }
+
+    public function testRepeatedAxisAfterNormalizationThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        $this->matrix()->flip([1, -1]);
+    }
+
+    public function testOutOfRangeNegativeAxisThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        $this->expectExceptionMessage('Axis -3 out of bounds');
+        $this->matrix()->swapaxes(-3, 0);
+    }
+
+    public function testRepeatOutOfRangeAxisThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        $this->matrix()->repeat(2, -3);
+    }
+}
@@ -9,6 +9,7 @@ use PhpMlKit\NDArray\Exceptions\DTypeException;
 use PhpMlKit\NDArray\Exceptions\IndexException;
 use PhpMlKit\NDArray\Exceptions\NDArrayException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\Exceptions\ValueException;
 use PhpMlKit\NDArray\NDArray;
 use PHPUnit\Framework\TestCase;
 
@@ -213,6 +214,31 @@ final class PanicMappingTest extends TestCase
         }
     }
 
+    public function testBitwiseOnFloatThrowsDTypeExceptionWithOperation(): void
+    {
+        $a = NDArray::array([1.0, 2.0], DType::Float64);
+        $b = NDArray::array([3.0, 4.0], DType::Float64);
+
+        try {
+            $a->bitand($b);
+            $this->fail('Expected DTypeException');
+        } catch (DTypeException $e) {
+            $this->assertSame(3, $e->getCode());
+            $this->assertSame('bitand', $e->getOperation());
+        }
+    }
+
+    public function testInvalidArgumentThrowsValueExceptionWithOperation(): void
+    {
+        try {
+            NDArray::choice(3, 5, replace: false);
+            $this->fail('Expected ValueException');
+        } catch (ValueException $e) {
+            $this->assertSame(11, $e->getCode());
+            $this->assertSame('choice', $e->getOperation());
+        }
+    }
+
     /**
      * @dataProvider reductionOperationsProvider
      */
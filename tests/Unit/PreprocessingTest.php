@@ -0,0 +1,163 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for fused feature preprocessing.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class PreprocessingTest extends TestCase
+{
+    public function testStandardizeColumns(): void
+    {
+        $x = NDArray::array([[1, 10], [2, 20], [3, 30]], DType::Float64);
+        $z = sqrt(1.5);
+
+        $this->assertEqualsWithDelta([[-$z, -$z], [0.0, 0.0], [$z, $z]], $x->standardize()->toArray(), 1e-9);
+    }
+
+    public function testStandardizeRowsWithDdof(): void
+    {
+        $x = NDArray::array([[1.0, 2.0, 3.0]]);
+
+        $this->assertEqualsWithDelta([[-1.0, 0.0, 1.0]], $x->standardize(axis: -1, ddof: 1)->toArray(), 1e-9);
+    }
+
+    public function testStandardizeWithPrecomputedStatistics(): void
+    {
+        $x = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+        $mean = NDArray::array([1.0, 2.0]);
+        $std = NDArray::array([2.0]);
+
+        $this->assertEqualsWithDelta([[0.0, 0.0], [1.0, 1.0]], $x->standardize(mean: $mean, std: $std)->toArray(), 1e-12);
+    }
+
+    public function testStandardizeEpsilonKeepsConstantLanesFinite(): void
+    {
+        $x = NDArray::array([[5.0, 1.0], [5.0, 3.0]]);
+
+        $this->assertEqualsWithDelta([[0.0, -1.0], [0.0, 1.0]], $x->standardize(epsilon: 1e-12)->toArray(), 1e-6);
+    }
+
+    public function testStandardizeIntegerInputReturnsFloat64(): void
+    {
+        $x = NDArray::array([1, 2, 3], DType::Int32);
+
+        $this->assertSame(DType::Float64, $x->standardize()->dtype());
+    }
+
+    public function testStandardizeKeepsFloat32(): void
+    {
+        $x = NDArray::array([1.0, 2.0], DType::Float32);
+
+        $this->assertSame(DType::Float32, $x->standardize()->dtype());
+    }
+
+    public function testStandardizeInPlaceOnView(): void
+    {
+        $x = NDArray::array([[1.0, 7.0], [3.0, 9.0]]);
+        $col = $x->slice([':', 0]);
+
+        $result = $col->standardize(inPlace: true);
+
+        $this->assertSame($col, $result);
+        $this->assertEqualsWithDelta([[-1.0, 7.0], [1.0, 9.0]], $x->toArray(), 1e-12);
+    }
+
+    public function testStandardizeInPlaceRejectsIntegers(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1, 2, 3])->standardize(inPlace: true);
+    }
+
+    public function testStandardizeRejectsMismatchedMean(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::zeros([3, 2])->standardize(mean: NDArray::zeros([3]));
+    }
+
+    public function testMinMaxScaleColumns(): void
+    {
+        $x = NDArray::array([[1, 10], [2, 20], [3, 40]]);
+
+        $this->assertEqualsWithDelta([[0.0, 0.0], [0.5, 1 / 3], [1.0, 1.0]], $x->minMaxScale()->toArray(), 1e-12);
+    }
+
+    public function testMinMaxScaleCustomRangeAndConstantLane(): void
+    {
+        $x = NDArray::array([[2.0, 2.0, 2.0], [0.0, 5.0, 10.0]]);
+
+        $this->assertEqualsWithDelta(
+            [[-1.0, -1.0, -1.0], [-1.0, 0.0, 1.0]],
+            $x->minMaxScale(axis: 1, min: -1.0, max: 1.0)->toArray(),
+            1e-12
+        );
+    }
+
+    public function testMinMaxScaleWithParamsInverts(): void
+    {
+        $x = NDArray::array([[1.0, 10.0], [3.0, 30.0]]);
+
+        [$scaled, $dataMin, $scale] = $x->minMaxScaleWithParams();
+
+        $this->assertSame([2], $dataMin->shape());
+        $this->assertEqualsWithDelta([1.0, 10.0], $dataMin->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([0.5, 0.05], $scale->toArray(), 1e-12);
+        $this->assertEqualsWithDelta($x->toArray(), $scaled->divide($scale)->add($dataMin)->toArray(), 1e-12);
+    }
+
+    public function testMinMaxScaleIgnoresNan(): void
+    {
+        $result = NDArray::array([NAN, 0.0, 4.0])->minMaxScale()->toArray();
+
+        $this->assertNan($result[0]);
+        $this->assertEqualsWithDelta([0.0, 1.0], \array_slice($result, 1), 1e-12);
+    }
+
+    public function testMinMaxScaleRejectsEmptyRange(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::array([1.0, 2.0])->minMaxScale(min: 1.0, max: 1.0);
+    }
+
+    public function testL2NormalizeRows(): void
+    {
+        $x = NDArray::array([[3.0, 4.0], [0.0, 0.0], [1.0, 1.0]]);
+        $h = sqrt(0.5);
+
+        $this->assertEqualsWithDelta([[0.6, 0.8], [0.0, 0.0], [$h, $h]], $x->l2Normalize()->toArray(), 1e-12);
+    }
+
+    public function testL2NormalizeColumns(): void
+    {
+        $x = NDArray::array([[3, 0], [4, 2]], DType::Int64);
+        $result = $x->l2Normalize(axis: 0);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([[0.6, 0.0], [0.8, 1.0]], $result->toArray(), 1e-12);
+    }
+
+    public function testL2NormalizeAvoidsOverflow(): void
+    {
+        $x = NDArray::array([3e200, 4e200]);
+
+        $this->assertEqualsWithDelta([0.6, 0.8], $x->l2Normalize()->toArray(), 1e-12);
+    }
+
+    public function testL2NormalizeComplexThrows(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1.0], DType::Complex128)->l2Normalize();
+    }
+}
@@ -0,0 +1,101 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for int8/uint8 quantize and dequantize.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class QuantizationTest extends TestCase
+{
+    public function testPerTensorRoundsHalfToEvenAndSaturates(): void
+    {
+        $x = NDArray::array([0.0, 0.5, 1.5, 2.5, -300.0, 300.0], DType::Float32);
+        $q = $x->quantize(1.0, 3);
+
+        $this->assertSame(DType::Int8, $q->dtype());
+        $this->assertSame([3, 3, 5, 5, -128, 127], $q->toArray());
+    }
+
+    public function testUint8Target(): void
+    {
+        $x = NDArray::array([-1.0, 0.0, 1.0, 100.0], DType::Float32);
+        $q = $x->quantize(0.5, 128, DType::UInt8);
+
+        $this->assertSame(DType::UInt8, $q->dtype());
+        $this->assertSame([126, 128, 130, 255], $q->toArray());
+    }
+
+    public function testNanMapsToZeroPoint(): void
+    {
+        $x = NDArray::array([NAN, 1.0], DType::Float64);
+
+        $this->assertSame([7, 8], $x->quantize(1.0, 7)->toArray());
+    }
+
+    public function testPerChannelAlongAxis(): void
+    {
+        $w = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Float32);
+
+        $rows = $w->quantize(NDArray::array([0.5, 2.0]), axis: 0);
+        $this->assertSame([[2, 4, 6], [2, 2, 3]], $rows->toArray());
+
+        $cols = $w->quantize(NDArray::array([1.0, 2.0, 4.0]), axis: -1);
+        $this->assertSame([[1, 1, 1], [4, 2, 2]], $cols->toArray());
+    }
+
+    public function testDequantizeRoundTrip(): void
+    {
+        $x = NDArray::array([[-1.0, 0.25], [0.5, 1.0]], DType::Float32);
+        $scale = NDArray::array([0.25, 0.125]);
+        $zeroPoint = NDArray::array([10, -5], DType::Int64);
+
+        $q = $x->quantize($scale, $zeroPoint, axis: 1);
+        $y = $q->dequantize($scale, $zeroPoint, axis: 1);
+
+        $this->assertSame(DType::Float32, $y->dtype());
+        $this->assertEqualsWithDelta($x->toArray(), $y->toArray(), 1e-6);
+    }
+
+    public function testDequantizeRejectsFloatInput(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1.0, 2.0])->dequantize(1.0);
+    }
+
+    public function testRejectsNonPositiveScale(): void
+    {
+        $this->expectException(NDArrayException::class);
+        NDArray::array([1.0, 2.0])->quantize(0.0);
+    }
+
+    public function testRejectsChannelLengthMismatch(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([[1.0, 2.0], [3.0, 4.0]])->quantize(NDArray::array([1.0, 2.0, 3.0]), axis: 0);
+    }
+
+    public function testRejectsNonQuantizedTarget(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::array([1.0])->quantize(1.0, 0, DType::Int16);
+    }
+
+    public function testPerChannelRequiresAxis(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::array([1.0, 2.0])->quantize(NDArray::array([1.0, 2.0]));
+    }
+}
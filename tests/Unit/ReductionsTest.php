@@ -5,7 +5,12 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\IndexException;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
+use PhpMlKit\NDArray\QuantileMethod;
+use PhpMlKit\NDArray\SumMode;
 use PHPUnit\Framework\TestCase;
 
 /**
@@ -401,6 +406,74 @@ final class ReductionsTest extends TestCase
         $this->assertSame([0, 1, 0, 1, 0, 0], $result->toArray());
     }
 
+    public function testSegmentReductions(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4], [5, 6], [7, 8]], DType::Float64);
+        $ids = [0, 2, 0, 2];
+
+        $this->assertEqualsWithDelta([[6.0, 8.0], [0.0, 0.0], [10.0, 12.0]], $a->segmentSum($ids)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([[5.0, 6.0], [7.0, 8.0]], $a->segmentMax([0, 1, 0, 1])->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([[1.0, 2.0], [3.0, 4.0]], $a->segmentMin([0, 1, 0, 1])->toArray(), 1e-12);
+
+        $mean = $a->segmentMean($ids)->toArray();
+        $this->assertEqualsWithDelta([3.0, 4.0], $mean[0], 1e-12);
+        $this->assertNan($mean[1][0]);
+        $this->assertEqualsWithDelta([5.0, 6.0], $mean[2], 1e-12);
+    }
+
+    public function testSegmentSumWithNumSegments(): void
+    {
+        $a = NDArray::array([1, 2, 3], DType::Int32);
+        $result = $a->segmentSum(NDArray::array([1, 1, 0], DType::Int32), numSegments: 4);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([3.0, 3.0, 0.0, 0.0], $result->toArray(), 1e-12);
+    }
+
+    public function testGroupBy(): void
+    {
+        $keys = NDArray::array([3, 1, 3, 2, 1], DType::Int64);
+        $values = NDArray::array([10.0, 20.0, 30.0, 40.0, 50.0]);
+
+        $stats = NDArray::groupBy($keys, $values);
+
+        $this->assertSame([1, 2, 3], $stats['keys']->toArray());
+        $this->assertSame(DType::Int64, $stats['keys']->dtype());
+        $this->assertSame([2, 1, 2], $stats['count']->toArray());
+        $this->assertEqualsWithDelta([70.0, 40.0, 40.0], $stats['sum']->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([35.0, 40.0, 20.0], $stats['mean']->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([20.0, 40.0, 10.0], $stats['min']->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([50.0, 40.0, 30.0], $stats['max']->toArray(), 1e-12);
+    }
+
+    public function testGroupByMultiColumnValues(): void
+    {
+        $stats = NDArray::groupBy([true, false, true], NDArray::array([[1, 2], [3, 4], [5, 6]], DType::Float32));
+
+        $this->assertSame([false, true], $stats['keys']->toArray());
+        $this->assertSame([2, 2], $stats['sum']->shape());
+        $this->assertSame(DType::Float32, $stats['mean']->dtype());
+        $this->assertEqualsWithDelta([[3.0, 4.0], [3.0, 4.0]], $stats['mean']->toArray(), 1e-6);
+    }
+
+    public function testGroupByRowMismatchThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::groupBy([1, 2], NDArray::array([1.0, 2.0, 3.0]));
+    }
+
+    public function testSegmentIdOutOfRangeThrows(): void
+    {
+        $this->expectException(IndexException::class);
+        NDArray::array([1.0, 2.0])->segmentSum([0, 3], numSegments: 2);
+    }
+
+    public function testSegmentIdsLengthMismatchThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1.0, 2.0, 3.0])->segmentMean([0, 1]);
+    }
+
     public function testSumOn1DSlice(): void
     {
         $a = NDArray::array([1, 2, 3, 4, 5, 6], DType::Float64);
@@ -881,4 +954,207 @@ final class ReductionsTest extends TestCase
         $this->assertSame([2, 4], $result->shape());
         $this->assertSame(array_fill(0, 2, array_fill(0, 4, true)), $result->toArray());
     }
+
+    public function testCountNonzero(): void
+    {
+        $arr = NDArray::array([[0.0, 1.5, NAN], [0.0, 0.0, -2.0]]);
+
+        $this->assertSame(3, $arr->countNonzero());
+        $result = $arr->countNonzero(axis: 0);
+        $this->assertSame(DType::Int64, $result->dtype());
+        $this->assertSame([0, 1, 2], $result->toArray());
+        $this->assertSame([[2], [1]], $arr->countNonzero(axis: 1, keepdims: true)->toArray());
+    }
+
+    public function testFloat32SumIsPairwiseByDefault(): void
+    {
+        $x = NDArray::full(0.1, [1_000_000], DType::Float32);
+
+        // Naive left-to-right accumulation drifts to ~100958.
+        $this->assertEqualsWithDelta(100000.0, $x->sum(), 0.05);
+        $this->assertEqualsWithDelta(0.1, $x->mean(), 1e-7);
+    }
+
+    public function testKahanSumMode(): void
+    {
+        $x = NDArray::full(0.1, [1_000_000], DType::Float32);
+
+        $this->assertEqualsWithDelta(100000.0, $x->sum(mode: SumMode::Kahan), 0.01);
+        $this->assertEqualsWithDelta(0.0, $x->var(mode: SumMode::Kahan), 1e-9);
+    }
+
+    public function testSumModeAlongAxis(): void
+    {
+        $arr = NDArray::array([[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]], DType::Float64);
+
+        $this->assertEqualsWithDelta([12.0, 15.0, 18.0, 21.0], $arr->sum(axis: 0, mode: SumMode::Kahan)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([[1.5], [5.5], [9.5]], $arr->mean(axis: 1, keepdims: true, mode: SumMode::Kahan)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([16.0, 16.0, 16.0, 16.0], $arr->var(axis: 0, ddof: 1)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta(sqrt(1.25), $arr->std(axis: 1, mode: SumMode::Kahan)->toArray()[0], 1e-12);
+    }
+
+    public function testVarianceOfLargeOffsetIsStable(): void
+    {
+        $arr = NDArray::array([1e9 + 4, 1e9 + 7, 1e9 + 13, 1e9 + 16], DType::Float64);
+
+        $this->assertEqualsWithDelta(22.5, $arr->var(), 1e-6);
+        $this->assertEqualsWithDelta(22.5, $arr->var(mode: SumMode::Kahan), 1e-6);
+    }
+
+    public function testDeterministicFlag(): void
+    {
+        $this->assertFalse(NDArray::isDeterministic());
+
+        NDArray::setDeterministic();
+
+        try {
+            $this->assertTrue(NDArray::isDeterministic());
+
+            $x = NDArray::random([40, 25], DType::Float32, seed: 7);
+            $f = $x->transpose()->copy()->transpose();
+            $this->assertSame($x->sum(), $f->sum());
+            $this->assertSame($x->var(), $f->var());
+        } finally {
+            NDArray::setDeterministic(false);
+        }
+
+        $this->assertFalse(NDArray::isDeterministic());
+    }
+
+    public function testNanReductionsSkipNan(): void
+    {
+        $x = NDArray::array([[1.0, NAN, 3.0], [NAN, 5.0, 7.0]]);
+
+        $this->assertEqualsWithDelta(16.0, $x->nansum(), 1e-12);
+        $this->assertEqualsWithDelta(4.0, $x->nanmean(), 1e-12);
+        $this->assertEqualsWithDelta(1.0, $x->nanmin(), 1e-12);
+        $this->assertEqualsWithDelta(7.0, $x->nanmax(), 1e-12);
+        $this->assertEqualsWithDelta(sqrt(5.0), $x->nanstd(), 1e-12);
+        $this->assertEqualsWithDelta([4.0, 12.0], $x->nansum(axis: 1)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([[2.0], [6.0]], $x->nanmean(axis: 1, keepdims: true)->toArray(), 1e-12);
+    }
+
+    public function testNanReductionsOnAllNanLanes(): void
+    {
+        $x = NDArray::array([[NAN, NAN], [2.0, NAN]], DType::Float32);
+
+        $this->assertSame(DType::Float32, $x->nansum(axis: 0)->dtype());
+        $this->assertEqualsWithDelta([2.0, 0.0], $x->nansum(axis: 0)->toArray(), 1e-6);
+
+        $max = $x->nanmax(axis: 1)->toArray();
+        $this->assertNan($max[0]);
+        $this->assertEqualsWithDelta(2.0, $max[1], 1e-6);
+        $this->assertNan($x->nanstd(axis: 0, ddof: 1)->toArray()[0]);
+    }
+
+    public function testNanReductionsFallBackForIntegers(): void
+    {
+        $x = NDArray::array([1, 2, 3], DType::Int32);
+
+        $this->assertSame(6, $x->nansum());
+        $this->assertSame(3, $x->nanmax());
+    }
+
+    public function testMedianAndQuantile(): void
+    {
+        $x = NDArray::array([[1, 7, 3, 5], [10, 40, 20, 30]]);
+
+        $this->assertEqualsWithDelta(8.5, $x->median(), 1e-12);
+        $this->assertSame(DType::Float64, $x->median(axis: 1)->dtype());
+        $this->assertEqualsWithDelta([4.0, 25.0], $x->median(axis: 1)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([[2.5], [17.5]], $x->percentile(25, axis: 1, keepdims: true)->toArray(), 1e-12);
+        $this->assertEqualsWithDelta(40.0, $x->quantile(1.0), 1e-12);
+    }
+
+    public function testQuantileMethods(): void
+    {
+        $x = NDArray::array([4.0, 1.0, 3.0, 2.0], DType::Float32);
+
+        $this->assertEqualsWithDelta(2.5, $x->quantile(0.5), 1e-6);
+        $this->assertEqualsWithDelta(2.0, $x->quantile(0.5, method: QuantileMethod::Lower), 1e-6);
+        $this->assertEqualsWithDelta(3.0, $x->quantile(0.5, method: QuantileMethod::Higher), 1e-6);
+        $this->assertEqualsWithDelta(3.0, $x->quantile(0.5, method: QuantileMethod::Nearest), 1e-6);
+        $this->assertEqualsWithDelta(2.5, $x->quantile(0.5, method: QuantileMethod::Midpoint), 1e-6);
+        $this->assertNan(NDArray::array([1.0, NAN, 3.0])->median());
+
+        $this->expectException(\InvalidArgumentException::class);
+        $x->percentile(101);
+    }
+
+    public function testLogsumexp(): void
+    {
+        $x = NDArray::array([[1000.0, 1000.0], [1.0, 2.0]]);
+
+        $result = $x->logsumexp(axis: 1);
+        $this->assertEqualsWithDelta([1000.0 + log(2.0), log(exp(1.0) + exp(2.0))], $result->toArray(), 1e-9);
+        $this->assertSame([2, 1], $x->logsumexp(axis: 1, keepdims: true)->shape());
+        $this->assertEqualsWithDelta(1000.0 + log(2.0), $x->logsumexp(), 1e-9);
+        $this->assertSame(DType::Float32, NDArray::array([1.0, 2.0], DType::Float32)->logsumexp(axis: 0)->dtype());
+    }
+
+    public function testTrapz(): void
+    {
+        $y = NDArray::array([1, 2, 3]);
+
+        $this->assertSame(4.0, $y->trapz());
+        $this->assertSame(8.0, $y->trapz(dx: 2.0));
+        $this->assertSame(8.0, $y->trapz([4, 6, 8]));
+
+        $m = NDArray::array([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]], DType::Float32);
+        $rows = $m->trapz(axis: 0);
+        $this->assertSame(DType::Float32, $rows->dtype());
+        $this->assertSame([1.5, 2.5, 3.5], $rows->toArray());
+        $this->assertSame([1.0, 4.0], $m->trapz(NDArray::array([0.0, 0.5, 1.0]))->toArray());
+    }
+
+    public function testCumtrapz(): void
+    {
+        $y = NDArray::array([1.0, 2.0, 3.0, 4.0]);
+
+        $this->assertSame([1.5, 4.0, 7.5], $y->cumtrapz()->toArray());
+        $this->assertSame([0.0, 1.5, 4.0, 7.5], $y->cumtrapz(includeInitial: true)->toArray());
+        $this->assertSame([3.0, 8.0, 15.0], $y->cumtrapz([0, 2, 4, 6])->toArray());
+    }
+
+    public function testHistogram(): void
+    {
+        $x = NDArray::array([1.0, 2.0, 2.5, 4.0, 7.0]);
+
+        [$counts, $edges] = $x->histogram(3);
+        $this->assertSame(DType::Int64, $counts->dtype());
+        $this->assertSame([3, 1, 1], $counts->toArray());
+        $this->assertEqualsWithDelta([1.0, 3.0, 5.0, 7.0], $edges->toArray(), 1e-12);
+
+        [$counts] = NDArray::array([1.0, NAN, 2.0, 2.5, 4.0, 7.0])->histogram(2, range: [0, 4]);
+        $this->assertSame([1, 3], $counts->toArray());
+
+        [$counts, $edges] = $x->histogram([0, 2, 10], weights: [1, 1, 1, 1, 2]);
+        $this->assertSame(DType::Float64, $counts->dtype());
+        $this->assertEqualsWithDelta([1.0, 5.0], $counts->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([0.0, 2.0, 10.0], $edges->toArray(), 1e-12);
+    }
+
+    public function testHistogramRejectsNanWithoutRange(): void
+    {
+        $x = NDArray::array([1.0, NAN, 2.0]);
+
+        $this->expectException(NDArrayException::class);
+        $this->expectExceptionMessage('autodetected range [NaN, NaN] is not finite');
+        $x->histogram(2);
+    }
+
+    public function testHistogram2d(): void
+    {
+        $x = NDArray::array([1.0, 2.0, 2.5, 4.0, 7.0]);
+        $y = NDArray::array([0.0, 0.0, 1.0, 1.0, 1.0]);
+
+        [$grid, $xEdges, $yEdges] = $x->histogram2d($y, [2, 2], [[0, 8], [0, 1]]);
+        $this->assertSame([2, 2], $grid->shape());
+        $this->assertSame([[2, 1], [0, 2]], $grid->toArray());
+        $this->assertEqualsWithDelta([0.0, 4.0, 8.0], $xEdges->toArray(), 1e-12);
+        $this->assertEqualsWithDelta([0.0, 0.5, 1.0], $yEdges->toArray(), 1e-12);
+
+        $this->expectException(ShapeException::class);
+        $x->histogram2d([1.0, 2.0]);
+    }
 }
@@ -0,0 +1,155 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for rolling-window statistics.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class RollingTest extends TestCase
+{
+    public function testRollingMeanDefaultMinPeriods(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        $this->assertRolling([NAN, NAN, 2.0, 3.0, 4.0], $a->rollingMean(3)->toArray());
+    }
+
+    public function testRollingSumWithMinPeriods(): void
+    {
+        $a = NDArray::array([1, 2, 3, 4, 5], DType::Int64);
+        $result = $a->rollingSum(3, minPeriods: 1);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertRolling([1.0, 3.0, 6.0, 9.0, 12.0], $result->toArray());
+    }
+
+    public function testRollingMinMax(): void
+    {
+        $a = NDArray::array([3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0]);
+
+        $this->assertRolling([NAN, NAN, 1.0, 1.0, 1.0, 1.0, 2.0], $a->rollingMin(3)->toArray());
+        $this->assertRolling([NAN, NAN, 4.0, 4.0, 5.0, 9.0, 9.0], $a->rollingMax(3)->toArray());
+    }
+
+    public function testRollingStd(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 4.0, 7.0]);
+
+        $this->assertRolling([NAN, 0.5, 1.0, 1.5], $a->rollingStd(2)->toArray());
+        $this->assertRolling([NAN, sqrt(0.5), sqrt(2.0), sqrt(4.5)], $a->rollingStd(2, ddof: 1)->toArray());
+    }
+
+    public function testRollingSkipsNan(): void
+    {
+        $a = NDArray::array([1.0, NAN, 3.0, 4.0]);
+
+        $this->assertRolling([1.0, 1.0, 3.0, 3.5], $a->rollingMean(2, minPeriods: 1)->toArray());
+        $this->assertRolling([NAN, NAN, NAN, 3.5], $a->rollingMean(2)->toArray());
+        $this->assertRolling([1.0, 1.0, 3.0, 3.0], $a->rollingMin(2, minPeriods: 1)->toArray());
+    }
+
+    public function testRollingCenter(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        $this->assertRolling([NAN, 2.0, 3.0, 4.0, NAN], $a->rollingMean(3, center: true)->toArray());
+        $this->assertRolling([1.5, 2.0, 3.0, 4.0, 4.5], $a->rollingMean(3, minPeriods: 1, center: true)->toArray());
+    }
+
+    public function testRollingAlongAxis(): void
+    {
+        $a = NDArray::array([[1, 10], [2, 20], [3, 30]], DType::Float64);
+
+        $this->assertRolling(
+            [[NAN, NAN], [1.5, 15.0], [2.5, 25.0]],
+            $a->rollingMean(2, axis: 0)->toArray()
+        );
+        $this->assertRolling(
+            [[NAN, 11.0], [NAN, 22.0], [NAN, 33.0]],
+            $a->rollingSum(2)->toArray()
+        );
+    }
+
+    public function testRollingOnStridedView(): void
+    {
+        $a = NDArray::array([[1, 2, 3], [4, 5, 6]], DType::Float64);
+
+        $result = $a->transpose()->rollingMax(2, axis: 0, minPeriods: 1);
+
+        $this->assertSame([3, 2], $result->shape());
+        $this->assertRolling([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]], $result->toArray());
+    }
+
+    public function testRollingPreservesFloat32(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 3.0], DType::Float32);
+
+        $this->assertSame(DType::Float32, $a->rollingMean(2)->dtype());
+    }
+
+    public function testRollingWindowLargerThanAxis(): void
+    {
+        $a = NDArray::array([1.0, 2.0]);
+
+        $this->assertRolling([NAN, NAN], $a->rollingSum(5)->toArray());
+        $this->assertRolling([1.0, 3.0], $a->rollingSum(5, minPeriods: 1)->toArray());
+    }
+
+    public function testRollingInvalidArguments(): void
+    {
+        $a = NDArray::array([1.0, 2.0, 3.0]);
+
+        $this->expectException(\InvalidArgumentException::class);
+        $a->rollingMean(2, minPeriods: 3);
+    }
+
+    public function testRollingZeroWindowThrows(): void
+    {
+        $this->expectException(\InvalidArgumentException::class);
+        NDArray::array([1.0])->rollingSum(0);
+    }
+
+    public function testRollingAxisOutOfBoundsThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        NDArray::array([1.0, 2.0])->rollingSum(1, axis: 1);
+    }
+
+    public function testRollingComplexThrows(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1.0, 2.0], DType::Complex128)->rollingSum(1);
+    }
+
+    /**
+     * Compare nested float arrays treating NaN as equal to NaN.
+     *
+     * @param array<mixed> $expected
+     * @param array<mixed> $actual
+     */
+    private function assertRolling(array $expected, array $actual): void
+    {
+        $this->assertCount(\count($expected), $actual);
+        foreach ($expected as $i => $value) {
+            if (\is_array($value)) {
+                $this->assertRolling($value, $actual[$i]);
+            } elseif (is_nan($value)) {
+                $this->assertNan($actual[$i], "index {$i}");
+            } else {
+                $this->assertEqualsWithDelta($value, $actual[$i], 1e-6, "index {$i}");
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Tests for unique values and set operations.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class SetOpsTest extends TestCase
+{
+    public function testUniqueSortsAndDeduplicates(): void
+    {
+        $a = NDArray::array([[3, 1], [3, 2]], DType::Int32);
+
+        $u = $a->unique();
+        $this->assertSame(DType::Int32, $u->dtype());
+        $this->assertSame([1, 2, 3], $u->toArray());
+    }
+
+    public function testUniqueWithInverseAndCounts(): void
+    {
+        $a = NDArray::array([[3.0, NAN], [1.0, 3.0], [NAN, 2.0]]);
+
+        $u = $a->unique(returnInverse: true, returnCounts: true);
+        $values = $u['values']->toArray();
+        $this->assertSame([1.0, 2.0, 3.0], \array_slice($values, 0, 3));
+        $this->assertNan($values[3]);
+        $this->assertSame([[2, 3], [0, 2], [3, 1]], $u['inverse']->toArray());
+        $this->assertSame([1, 1, 2, 2], $u['counts']->toArray());
+        $this->assertArrayNotHasKey('inverse', $a->unique(returnCounts: true));
+    }
+
+    public function testUniqueAlongAxis(): void
+    {
+        $a = NDArray::array([[3, 1], [1, 2], [3, 1], [1, 0]], DType::Int32);
+
+        $u = $a->unique(returnInverse: true, returnCounts: true, axis: 0, returnIndex: true);
+        $this->assertSame(DType::Int32, $u['values']->dtype());
+        $this->assertSame([[1, 0], [1, 2], [3, 1]], $u['values']->toArray());
+        $this->assertSame([3, 1, 0], $u['index']->toArray());
+        $this->assertSame([2, 1, 2, 0], $u['inverse']->toArray());
+        $this->assertSame([1, 1, 2], $u['counts']->toArray());
+        $this->assertSame($a->toArray(), $u['values']->take($u['inverse'], 0)->toArray());
+
+        $columns = $a->unique(axis: -1);
+        $this->assertSame([[1, 3], [2, 1], [1, 3], [0, 1]], $columns->toArray());
+    }
+
+    public function testUniqueReturnIndexWithoutAxis(): void
+    {
+        $a = NDArray::array([[3, 1], [3, 2]], DType::Int64);
+
+        $u = $a->unique(returnInverse: true, returnIndex: true);
+        $this->assertSame([1, 2, 3], $u['values']->toArray());
+        $this->assertSame([1, 3, 0], $u['index']->toArray());
+        $this->assertSame([[2, 0], [2, 1]], $u['inverse']->toArray());
+    }
+
+    public function testUniqueRejectsComplex(): void
+    {
+        $this->expectException(DTypeException::class);
+        NDArray::array([1.0, 2.0], DType::Complex128)->unique();
+    }
+
+    public function testSetOperations(): void
+    {
+        $a = NDArray::array([[5, 1], [3, 1]], DType::Int32);
+        $b = NDArray::array([7.0, 3.0, 5.0]);
+
+        $i = $a->intersect1d($b);
+        $this->assertSame(DType::Float64, $i->dtype());
+        $this->assertSame([3.0, 5.0], $i->toArray());
+        $this->assertSame([1, 3, 5, 7], $a->union1d([7, 3])->toArray());
+        $this->assertSame([1, 5], $a->setdiff1d([3])->toArray());
+        $this->assertSame([4, 2], NDArray::array([4, 3, 2])->setdiff1d([3], assumeUnique: true)->toArray());
+    }
+
+    public function testIsin(): void
+    {
+        $a = NDArray::array([[0.0, 3.0], [NAN, 1.0]]);
+
+        $mask = $a->isin([1.0, NAN, 2.0]);
+        $this->assertSame(DType::Bool, $mask->dtype());
+        $this->assertSame([[false, false], [false, true]], $mask->toArray());
+        $this->assertSame([[true, true], [true, false]], $a->isin([1.0], invert: true)->toArray());
+    }
+}
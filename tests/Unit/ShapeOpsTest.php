@@ -318,8 +318,9 @@ final class ShapeOpsTest extends TestCase
         $a = NDArray::array([[[[1]]]], DType::Float64);
         $result = $a->squeeze();
 
-        // Should keep at least 1 dimension
-        $this->assertSame([1], $result->shape());
+        // Squeezing every axis yields a 0-D view
+        $this->assertSame([], $result->shape());
+        $this->assertSame(1.0, $result->toArray());
     }
 
     public function testSqueezeSpecificAxis(): void
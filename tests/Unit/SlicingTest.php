@@ -4,12 +4,16 @@ declare(strict_types=1);
 
 namespace PhpMlKit\NDArray\Tests\Unit;
 
+use PhpMlKit\NDArray\Casting;
 use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\DTypeException;
 use PhpMlKit\NDArray\Exceptions\IndexException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
 use PHPUnit\Framework\TestCase;
 
+use function PhpMlKit\NDArray\copyto;
+
 /**
  * Tests for NDArray slicing and advanced assignment.
  *
@@ -417,6 +421,46 @@ final class SlicingTest extends TestCase
         );
     }
 
+    public function testAssignRowIntoStridedBlock(): void
+    {
+        $arr = NDArray::zeros([4, 3]);
+        $arr->slice(['::2', ':'])->assign(NDArray::array([[1.0, 2.0, 3.0]]));
+
+        $this->assertSame(
+            [
+                [1.0, 2.0, 3.0],
+                [0.0, 0.0, 0.0],
+                [1.0, 2.0, 3.0],
+                [0.0, 0.0, 0.0],
+            ],
+            $arr->toArray()
+        );
+    }
+
+    public function testAssignDropsLeadingUnitAxesOfSource(): void
+    {
+        $arr = NDArray::zeros([2, 3]);
+        $arr->slice([1])->assign(NDArray::array([[[4.0, 5.0, 6.0]]]));
+
+        $this->assertSame([[0.0, 0.0, 0.0], [4.0, 5.0, 6.0]], $arr->toArray());
+    }
+
+    public function testAssignZeroDimSourceFillsSlice(): void
+    {
+        $arr = NDArray::zeros([3, 3]);
+        $arr->slice(['1:', '1:'])->assign(NDArray::array([7.0])->reshape([]));
+
+        $this->assertSame([[0.0, 0.0, 0.0], [0.0, 7.0, 7.0], [0.0, 7.0, 7.0]], $arr->toArray());
+    }
+
+    public function testAssignFromOverlappingViewOfSameArray(): void
+    {
+        $arr = NDArray::array([1, 2, 3, 4, 5]);
+        $arr->slice(['1:'])->assign($arr->slice([':4']));
+
+        $this->assertSame([1, 1, 2, 3, 4], $arr->toArray());
+    }
+
     public function testSliceStepZeroThrows(): void
     {
         $arr = NDArray::zeros([5]);
@@ -540,4 +584,63 @@ final class SlicingTest extends TestCase
         $this->assertSame([2], $slice->shape());
         $this->assertEquals([1, 2], $slice->toArray());
     }
+
+    public function testCopyFromWithWhereMask(): void
+    {
+        $arr = NDArray::zeros([2, 3]);
+        $arr->copyFrom(NDArray::array([1.0, 2.0, 3.0]), where: NDArray::array([true, false, true]));
+
+        $this->assertSame([[1.0, 0.0, 3.0], [1.0, 0.0, 3.0]], $arr->toArray());
+    }
+
+    public function testCopyFromScalarIntoView(): void
+    {
+        $arr = NDArray::array([[1.0, -2.0], [-3.0, 4.0]]);
+        $col = $arr->slice([':', 1]);
+        $col->copyFrom(0.0, where: $col->lt(0));
+
+        $this->assertSame([[1.0, 0.0], [-3.0, 4.0]], $arr->toArray());
+    }
+
+    public function testCopyFromCastsWithinKind(): void
+    {
+        $arr = NDArray::zeros([2], DType::Float32);
+        $arr->copyFrom(NDArray::array([1.5, 2.5], DType::Float64));
+
+        $this->assertSame([1.5, 2.5], $arr->toArray());
+        $this->assertSame(DType::Float32, $arr->dtype());
+    }
+
+    public function testCopyFromRejectsUnsafeCastByDefault(): void
+    {
+        $arr = NDArray::zeros([2], DType::Int32);
+
+        $this->expectException(DTypeException::class);
+        $this->expectExceptionMessage("Cannot cast array data from float64 to int32 according to the rule 'same_kind'");
+        $arr->copyFrom(NDArray::array([1.7, 2.2]));
+    }
+
+    public function testCopyFromUnsafeCastTruncates(): void
+    {
+        $arr = NDArray::zeros([2], DType::Int32);
+        $arr->copyFrom(NDArray::array([1.7, -2.2]), casting: Casting::Unsafe);
+
+        $this->assertSame([1, -2], $arr->toArray());
+    }
+
+    public function testCopyFromMaskShapeMismatchThrows(): void
+    {
+        $arr = NDArray::zeros([2, 3]);
+
+        $this->expectException(ShapeException::class);
+        $arr->copyFrom(1.0, where: NDArray::array([true, false]));
+    }
+
+    public function testCopytoFunction(): void
+    {
+        $arr = NDArray::zeros([3], DType::Int64);
+        copyto($arr, NDArray::array([7, 8, 9], DType::Int64), Casting::No, NDArray::array([false, true, true]));
+
+        $this->assertSame([0, 8, 9], $arr->toArray());
+    }
 }
@@ -134,6 +134,54 @@ final class SortingTest extends TestCase
         $this->assertSame([1, 3, 4, 4, 7], $reconstructed);
     }
 
+    public function testSortDescendingKeepsNaNLast(): void
+    {
+        $a = NDArray::array([[2.0, NAN, 3.0], [1.0, 5.0, 4.0]], DType::Float64);
+        $result = $a->sort(descending: true)->toArray();
+
+        $this->assertSame([3.0, 2.0], \array_slice($result[0], 0, 2));
+        $this->assertNan($result[0][2]);
+        $this->assertSame([5.0, 4.0, 1.0], $result[1]);
+    }
+
+    public function testSortNaNFirst(): void
+    {
+        $a = NDArray::array([2.0, NAN, 3.0, 1.0], DType::Float32);
+
+        $ascending = $a->sort(axis: null, nanFirst: true)->toArray();
+        $this->assertNan($ascending[0]);
+        $this->assertSame([1.0, 2.0, 3.0], \array_slice($ascending, 1));
+
+        $descending = $a->sort(axis: null, descending: true, nanFirst: true)->toArray();
+        $this->assertNan($descending[0]);
+        $this->assertSame([3.0, 2.0, 1.0], \array_slice($descending, 1));
+    }
+
+    public function testArgsortDescendingStableKeepsTieOrder(): void
+    {
+        $a = NDArray::array([2, 3, 1, 3, 2], DType::Int32);
+
+        $this->assertSame([1, 3, 0, 4, 2], $a->argsort(kind: SortKind::Stable, descending: true)->toArray());
+        $this->assertSame([1, 3, 0, 4, 2], $a->argsort(axis: null, kind: SortKind::Stable, descending: true)->toArray());
+    }
+
+    public function testLargeIntegerSortMatchesPhpSort(): void
+    {
+        $values = [];
+        for ($i = 0; $i < 1000; ++$i) {
+            $values[] = (($i * 7919 + 13) % 1009 - 504) * 1000003;
+        }
+        $a = NDArray::array($values, DType::Int64);
+
+        $expected = $values;
+        sort($expected);
+        $this->assertSame($expected, $a->sort()->toArray());
+        $this->assertSame(array_reverse($expected), $a->sort(descending: true)->toArray());
+
+        $indices = $a->argsort(kind: SortKind::Stable)->toArray();
+        $this->assertSame($expected, array_map(static fn (int $i) => $values[$i], $indices));
+    }
+
     public function testSortAxisOutOfBoundsThrows(): void
     {
         $a = NDArray::array([[1, 2, 3]], DType::Int32);
@@ -161,6 +209,19 @@ final class SortingTest extends TestCase
         $this->assertSame([[0, 2], [1, 3]], $topk[1]->toArray());
     }
 
+    public function testTopkUnsortedKeepsIndexOrder(): void
+    {
+        $a = NDArray::array([[1, 7, 3, 5, 0, 6], [9, 2, 8, 4, 10, 1]], DType::Int32);
+
+        [$values, $indices] = $a->topk(3, sorted: false);
+        $this->assertSame([[7, 5, 6], [9, 8, 10]], $values->toArray());
+        $this->assertSame([[1, 3, 5], [0, 2, 4]], $indices->toArray());
+
+        [$values, $indices] = $a->topk(2, axis: null, largest: false, sorted: false);
+        $this->assertSame([0, 1], $values->toArray());
+        $this->assertSame([0, 4], $indices->toArray());
+    }
+
     public function testTopkFlattened(): void
     {
         $a = NDArray::array([[1, 7, 3], [5, 9, 2]], DType::Int32);
@@ -288,4 +349,60 @@ final class SortingTest extends TestCase
         $this->assertSame([3, 2], $topk[0]->toArray());
         $this->assertSame([0, 2], $topk[1]->toArray());
     }
+
+    public function testPartitionPlacesKthAlongLastAxis(): void
+    {
+        $a = NDArray::array([[7, 2, 9, 4, 1, 8], [3, 6, 5, 0, 4, 2]], DType::Int32);
+        $result = $a->partition(2);
+
+        $this->assertSame(DType::Int32, $result->dtype());
+        foreach ([[7, 2, 9, 4, 1, 8], [3, 6, 5, 0, 4, 2]] as $row => $values) {
+            $sorted = $values;
+            sort($sorted);
+            $lane = $result->toArray()[$row];
+
+            $this->assertSame($sorted[2], $lane[2]);
+            $this->assertSame(\array_slice($sorted, 0, 2), self::sorted(\array_slice($lane, 0, 2)));
+            $this->assertSame(\array_slice($sorted, 3), self::sorted(\array_slice($lane, 3)));
+        }
+    }
+
+    public function testPartitionMultipleKthAndAxisNull(): void
+    {
+        $a = NDArray::array([[7, 2, 9], [4, 1, 8]], DType::Float64);
+        $result = $a->partition([0, -1], axis: null);
+
+        $this->assertSame([6], $result->shape());
+        $this->assertSame(1.0, $result->toArray()[0]);
+        $this->assertSame(9.0, $result->toArray()[5]);
+    }
+
+    public function testArgpartitionSelectsSmallestIndices(): void
+    {
+        $a = NDArray::array([7, 2, 9, 4, 1, 8], DType::Int64);
+        $indices = $a->argpartition(2);
+
+        $this->assertSame(DType::Int64, $indices->dtype());
+        $this->assertSame(3, $indices->toArray()[2]);
+        $this->assertSame([1, 3, 4], self::sorted(\array_slice($indices->toArray(), 0, 3)));
+    }
+
+    public function testPartitionKthOutOfBoundsThrows(): void
+    {
+        $a = NDArray::array([1, 2, 3], DType::Int32);
+        $this->expectException(NDArrayException::class);
+        $a->partition(3);
+    }
+
+    /**
+     * @param list<int> $values
+     *
+     * @return list<int>
+     */
+    private static function sorted(array $values): array
+    {
+        sort($values);
+
+        return $values;
+    }
 }
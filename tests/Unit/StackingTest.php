@@ -5,7 +5,6 @@ declare(strict_types=1);
 namespace PhpMlKit\NDArray\Tests\Unit;
 
 use PhpMlKit\NDArray\DType;
-use PhpMlKit\NDArray\Exceptions\DTypeException;
 use PhpMlKit\NDArray\Exceptions\IndexException;
 use PhpMlKit\NDArray\Exceptions\ShapeException;
 use PhpMlKit\NDArray\NDArray;
@@ -136,13 +135,29 @@ final class StackingTest extends TestCase
         NDArray::concatenate([$a, $b], 0);
     }
 
-    public function testConcatenateDifferentDtypesThrows(): void
+    public function testConcatenatePromotesDtypes(): void
     {
-        $a = NDArray::array([1, 2, 3], DType::Float64);
-        $b = NDArray::array([4, 5, 6], DType::Int64);
+        $a = NDArray::array([1, 2, 3], DType::Int64);
+        $b = NDArray::array([4.5, 5.5], DType::Float64);
 
-        $this->expectException(DTypeException::class);
-        NDArray::concatenate([$a, $b], 0);
+        $result = NDArray::concatenate([$a, $b], 0);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([1, 2, 3, 4.5, 5.5], $result->toArray(), 0.0001);
+    }
+
+    public function testConcatenateManyArrays(): void
+    {
+        $arrays = [];
+        for ($i = 0; $i < 20; ++$i) {
+            $arrays[] = NDArray::array([$i, $i + 100], DType::Int32);
+        }
+
+        $result = NDArray::concatenate($arrays, 0);
+
+        $this->assertSame([40], $result->shape());
+        $this->assertSame(DType::Int32, $result->dtype());
+        $this->assertSame([19, 119], \array_slice($result->toArray(), 38));
     }
 
     public function testStack1D(): void
@@ -270,13 +285,15 @@ final class StackingTest extends TestCase
         NDArray::stack([$a, $b], 0);
     }
 
-    public function testStackDifferentDtypesThrows(): void
+    public function testStackPromotesDtypes(): void
     {
         $a = NDArray::array([1, 2], DType::Float64);
         $b = NDArray::array([3, 4], DType::Float32);
 
-        $this->expectException(DTypeException::class);
-        NDArray::stack([$a, $b], 0);
+        $result = NDArray::stack([$a, $b], 0);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([[1, 2], [3, 4]], $result->toArray(), 0.0001);
     }
 
     public function testVstack(): void
@@ -301,6 +318,83 @@ final class StackingTest extends TestCase
         $this->assertEqualsWithDelta([[1, 3], [2, 4]], $result->toArray(), 0.0001);
     }
 
+    public function testVstackPromotes1DToRows(): void
+    {
+        $a = NDArray::array([1, 2, 3], DType::Int64);
+        $b = NDArray::array([4, 5, 6], DType::Int64);
+
+        $result = NDArray::vstack([$a, $b]);
+
+        $this->assertSame([[1, 2, 3], [4, 5, 6]], $result->toArray());
+    }
+
+    public function testHstack1D(): void
+    {
+        $a = NDArray::array([1, 2], DType::Int64);
+        $b = NDArray::array([3], DType::Int64);
+
+        $this->assertSame([1, 2, 3], NDArray::hstack([$a, $b])->toArray());
+    }
+
+    public function testDstack(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+        $b = NDArray::array([[5, 6], [7, 8]], DType::Int64);
+
+        $result = NDArray::dstack([$a, $b]);
+
+        $this->assertSame([2, 2, 2], $result->shape());
+        $this->assertSame([[[1, 5], [2, 6]], [[3, 7], [4, 8]]], $result->toArray());
+        $this->assertSame([1, 2, 2], NDArray::dstack([NDArray::array([1, 2]), NDArray::array([3, 4])])->shape());
+    }
+
+    public function testColumnStack(): void
+    {
+        $a = NDArray::array([1, 2, 3], DType::Int64);
+        $b = NDArray::array([4, 5, 6], DType::Int64);
+
+        $result = NDArray::columnStack([$a, $b]);
+
+        $this->assertSame([[1, 4], [2, 5], [3, 6]], $result->toArray());
+    }
+
+    public function testBlock2x2Grid(): void
+    {
+        $a = NDArray::array([[1, 2], [4, 5]], DType::Int64);
+        $b = NDArray::array([[3], [6]], DType::Int64);
+        $c = NDArray::array([7, 8, 9], DType::Int64);
+
+        $result = NDArray::block([[$a, $b], [$c]]);
+
+        $this->assertSame([[1, 2, 3], [4, 5, 6], [7, 8, 9]], $result->toArray());
+    }
+
+    public function testBlockPromotesDtypesAndFlatList(): void
+    {
+        $result = NDArray::block([NDArray::array([1, 2], DType::Int32), NDArray::array([0.5], DType::Float64)]);
+
+        $this->assertSame(DType::Float64, $result->dtype());
+        $this->assertEqualsWithDelta([1, 2, 0.5], $result->toArray(), 0.0001);
+    }
+
+    public function testBlockMismatchedEdgesThrows(): void
+    {
+        $a = NDArray::zeros([2, 2]);
+        $b = NDArray::zeros([3, 1]);
+
+        $this->expectException(ShapeException::class);
+        NDArray::block([[$a, $b]]);
+    }
+
+    public function testBlockMismatchedDepthThrows(): void
+    {
+        $a = NDArray::zeros([2]);
+
+        $this->expectException(ShapeException::class);
+        $this->expectExceptionMessage('depths are mismatched');
+        NDArray::block([$a, [$a]]);
+    }
+
     public function testSplitEqualSections(): void
     {
         $a = NDArray::array([1, 2, 3, 4, 5, 6], DType::Float64);
@@ -424,6 +518,59 @@ final class StackingTest extends TestCase
         $a->hsplit([5]);  // index 5 > 3 cols
     }
 
+    public function testArraySplitUnevenSections(): void
+    {
+        $a = NDArray::arange(7, dtype: DType::Int64);
+        $parts = $a->arraySplit(3);
+
+        $this->assertCount(3, $parts);
+        $this->assertSame([0, 1, 2], $parts[0]->toArray());
+        $this->assertSame([3, 4], $parts[1]->toArray());
+        $this->assertSame([5, 6], $parts[2]->toArray());
+    }
+
+    public function testArraySplitMoreSectionsThanElements(): void
+    {
+        $a = NDArray::array([1, 2], DType::Float32);
+        $parts = $a->arraySplit(3);
+
+        $this->assertSame([[1], [1], [0]], array_map(static fn (NDArray $p) => $p->shape(), $parts));
+        $this->assertSame(DType::Float32, $parts[2]->dtype());
+    }
+
+    public function testArraySplitAtBoundaries(): void
+    {
+        $a = NDArray::array([[1, 2, 3, 4], [5, 6, 7, 8]], DType::Int64);
+        $parts = $a->arraySplit([1, -1, 10], 1);
+
+        $this->assertCount(4, $parts);
+        $this->assertSame([[1], [5]], $parts[0]->toArray());
+        $this->assertSame([[2, 3], [6, 7]], $parts[1]->toArray());
+        $this->assertSame([[4], [8]], $parts[2]->toArray());
+        $this->assertSame([2, 0], $parts[3]->shape());
+    }
+
+    public function testArraySplitOnStridedView(): void
+    {
+        $a = NDArray::arange(10, dtype: DType::Int64);
+        $parts = $a->flip()->slice(['::2'])->arraySplit(2);
+
+        $this->assertSame([9, 7, 5], $parts[0]->toArray());
+        $this->assertSame([3, 1], $parts[1]->toArray());
+    }
+
+    public function testArraySplitReturnsViews(): void
+    {
+        $a = NDArray::arange(5, dtype: DType::Int64);
+        $parts = $a->arraySplit([2, 9]);
+
+        $this->assertTrue($parts[0]->isView());
+        $this->assertTrue($parts[1]->isView());
+        $this->assertTrue($parts[2]->isView());
+        $this->assertSame([2, 3, 4], $parts[1]->toArray());
+        $this->assertSame([0], $parts[2]->shape());
+    }
+
     public function testConcatenateWithSlices(): void
     {
         $a = NDArray::array([1, 2, 3, 4, 5, 6], DType::Float64);
@@ -0,0 +1,109 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\ShapeException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * 0-D arrays behave like NumPy scalars: they broadcast against any shape,
+ * element-wise ops keep them 0-D, and they have no axes.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class ZeroDimTest extends TestCase
+{
+    private function scalar(float $value = 5.0): NDArray
+    {
+        return NDArray::full($value, []);
+    }
+
+    public function testElementwiseOpsKeepZeroDims(): void
+    {
+        $s = $this->scalar();
+
+        $this->assertSame([], $s->exp()->shape());
+        $this->assertSame([], $s->add($s)->shape());
+        $this->assertSame(10.0, $s->add($s)->toArray());
+        $this->assertSame([], $s->astype(DType::Int64)->shape());
+    }
+
+    public function testZeroDimBroadcastsLikeScalar(): void
+    {
+        $m = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+
+        $this->assertSame([[6.0, 7.0], [8.0, 9.0]], $m->add($this->scalar())->toArray());
+        $this->assertSame([[6.0, 7.0], [8.0, 9.0]], $this->scalar()->add($m)->toArray());
+    }
+
+    public function testZeroDimViewOfElementBroadcasts(): void
+    {
+        $m = NDArray::array([[1.0, 2.0], [3.0, 4.0]]);
+        $element = $m->slice([1, 1])->reshape([]);
+
+        $this->assertSame([], $element->shape());
+        $this->assertSame([[5.0, 6.0], [7.0, 8.0]], $m->add($element)->toArray());
+        $this->assertSame('4', (string) $element);
+    }
+
+    public function testReshapeBetweenZeroDimAndOneElement(): void
+    {
+        $this->assertSame([1, 1], $this->scalar()->reshape([1, 1])->shape());
+        $this->assertSame([], NDArray::array([7.0])->reshape([])->shape());
+        $this->assertSame([1], $this->scalar()->flatten()->shape());
+    }
+
+    public function testFullReductionsReturnTheElement(): void
+    {
+        $s = $this->scalar();
+
+        $this->assertSame(5.0, $s->sum());
+        $this->assertSame(5.0, $s->max());
+        $this->assertSame(0, $s->argmax());
+    }
+
+    public function testAxisReductionOnZeroDimThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        $this->expectExceptionMessage('Axis 0 is out of bounds for array with 0 dimensions');
+        $this->scalar()->sum(0);
+    }
+
+    public function testNegativeAxisOnZeroDimThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        $this->scalar()->cumsum(-1);
+    }
+
+    public function testExpandDimsAndSqueezeRoundTrip(): void
+    {
+        $expanded = $this->scalar()->expandDims(0);
+
+        $this->assertSame([1], $expanded->shape());
+        $this->assertSame([], $expanded->squeeze()->shape());
+        $this->assertSame([], $this->scalar()->squeeze()->shape());
+    }
+
+    public function testDotWithZeroDimScales(): void
+    {
+        $v = NDArray::array([1.0, 2.0, 3.0]);
+
+        $this->assertSame([5.0, 10.0, 15.0], $this->scalar()->dot($v)->toArray());
+        $this->assertSame(25.0, $this->scalar()->dot($this->scalar()));
+    }
+
+    public function testIteratingZeroDimThrows(): void
+    {
+        $this->expectException(ShapeException::class);
+        $this->expectExceptionMessage('Iteration over a 0-d array');
+
+        foreach ($this->scalar() as $_) {
+        }
+    }
+}
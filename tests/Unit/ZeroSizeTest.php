@@ -0,0 +1,139 @@
+<?php
+
+declare(strict_types=1);
+
+namespace PhpMlKit\NDArray\Tests\Unit;
+
+use PhpMlKit\NDArray\DType;
+use PhpMlKit\NDArray\Exceptions\NDArrayException;
+use PhpMlKit\NDArray\NDArray;
+use PHPUnit\Framework\TestCase;
+
+/**
+ * Arrays with a zero-length dimension follow NumPy semantics in every op family.
+ *
+ * @internal
+ *
+ * @coversNothing
+ */
+final class ZeroSizeTest extends TestCase
+{
+    private function empty(DType $dtype = DType::Float64): NDArray
+    {
+        return NDArray::zeros([0, 3], $dtype);
+    }
+
+    public function testSumAndProductOfEmptyAreIdentities(): void
+    {
+        $this->assertSame(0.0, $this->empty()->sum());
+        $this->assertSame(0, $this->empty(DType::Int64)->sum());
+        $this->assertSame(1.0, $this->empty()->product());
+        $this->assertSame([0.0, 0.0, 0.0], $this->empty()->sum(0)->toArray());
+        $this->assertSame([1.0, 1.0, 1.0], $this->empty()->product(0)->toArray());
+        $this->assertSame([], $this->empty()->sum(1)->toArray());
+    }
+
+    public function testMeanVarStdOfEmptyAreNan(): void
+    {
+        $this->assertNan($this->empty()->mean());
+        $this->assertNan($this->empty(DType::Int64)->mean());
+        $this->assertNan($this->empty()->var());
+        $this->assertNan($this->empty()->std());
+
+        foreach ($this->empty()->mean(0)->toArray() as $value) {
+            $this->assertNan($value);
+        }
+        foreach ($this->empty()->var(0)->toArray() as $value) {
+            $this->assertNan($value);
+        }
+    }
+
+    public function testMinOfEmptyThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        $this->expectExceptionMessage('Cannot compute min of an empty array');
+        $this->empty(DType::Int64)->min();
+    }
+
+    public function testMaxOfEmptyThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        $this->empty()->max();
+    }
+
+    public function testArgmaxOfEmptyThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        $this->empty()->argmax();
+    }
+
+    public function testMaxAlongEmptyAxisThrows(): void
+    {
+        $this->expectException(NDArrayException::class);
+        $this->empty()->max(0);
+    }
+
+    public function testExtremaAlongNonEmptyAxisAreEmpty(): void
+    {
+        $this->assertSame([0], $this->empty()->max(1)->shape());
+        $this->assertSame([0], $this->empty()->argmin(1)->shape());
+    }
+
+    public function testCumulativeAndSortingKeepEmptyShape(): void
+    {
+        $this->assertSame([0], $this->empty()->cumsum()->shape());
+        $this->assertSame([0, 3], $this->empty()->cumsum(0)->shape());
+        $this->assertSame([0, 3], $this->empty()->sort(0)->shape());
+        $this->assertSame([0, 3], $this->empty()->argsort()->shape());
+    }
+
+    public function testElementwiseAndBroadcasting(): void
+    {
+        $row = NDArray::array([[1.0, 2.0, 3.0]]);
+
+        $this->assertSame([0, 3], $this->empty()->add($row)->shape());
+        $this->assertSame([0, 3], $this->empty()->exp()->shape());
+    }
+
+    public function testShapeOps(): void
+    {
+        $a = $this->empty();
+
+        $this->assertSame([3, 0], $a->transpose()->shape());
+        $this->assertSame([0], $a->flatten()->shape());
+        $this->assertSame([3, 0], $a->reshape([3, 0])->shape());
+        $this->assertSame([0, 6], $a->repeat(2, 1)->shape());
+    }
+
+    public function testRepeatZeroTimesEmptiesTheAxis(): void
+    {
+        $a = NDArray::array([[1, 2], [3, 4]], DType::Int64);
+
+        $this->assertSame([2, 0], $a->repeat(0, 1)->shape());
+        $this->assertSame([0], $a->repeat(0)->shape());
+    }
+
+    public function testStacking(): void
+    {
+        $full = NDArray::array([[1.0, 2.0, 3.0]]);
+
+        $this->assertSame([[1.0, 2.0, 3.0]], NDArray::concatenate([$this->empty(), $full], 0)->toArray());
+        $this->assertSame([0, 6], NDArray::concatenate([$this->empty(), $this->empty()], 1)->shape());
+        $this->assertSame([2, 0, 3], NDArray::stack([$this->empty(), $this->empty()])->shape());
+    }
+
+    public function testMatmulWithEmptyInnerDimensionIsZeros(): void
+    {
+        $a = NDArray::zeros([2, 0]);
+        $b = NDArray::zeros([0, 3]);
+
+        $this->assertSame([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]], $a->matmul($b)->toArray());
+    }
+
+    public function testToArrayKeepsLeadingDimensions(): void
+    {
+        $this->assertSame([[], []], NDArray::zeros([2, 0])->toArray());
+        $this->assertSame([], $this->empty()->toArray());
+        $this->assertSame([[[], []]], NDArray::zeros([1, 2, 0])->toArray());
+    }
+}